@@ -0,0 +1,214 @@
+//! Assignments used as conditions.
+//!
+//! `if ($user = find($id))` is usually a typo for `==`. The lint reports a
+//! plain `=` assignment whose value a condition uses as a truth value: the
+//! condition itself, or an operand of `!`, `&&`, `||`, `and`, `or`, or `xor`
+//! within it. Conditions are those of `if`, `elseif`, `while`, `do`-`while`,
+//! `for`, and `?:`.
+//!
+//! Wrapping the assignment in its own parentheses marks it as intended and
+//! silences the lint, as does comparing its value:
+//!
+//! ```php
+//! if ($user = find($id)) {}                // reported
+//! if (($user = find($id))) {}              // intended
+//! while (false !== $line = fgets($fh)) {}  // intended: the value is compared
+//! ```
+//!
+//! [`AssignmentInConditionOptions`] can also allow assignments from some
+//! functions, or every assignment in a loop condition.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{AssignOp, BinaryOp, Expr, ExprKind, Program, Span, Stmt, StmtKind, UnaryPrefixOp};
+
+/// The construct whose condition holds the assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConditionKind {
+    If,
+    ElseIf,
+    While,
+    DoWhile,
+    For,
+    Ternary,
+}
+
+impl ConditionKind {
+    fn is_loop(self) -> bool {
+        matches!(
+            self,
+            ConditionKind::While | ConditionKind::DoWhile | ConditionKind::For
+        )
+    }
+}
+
+impl std::fmt::Display for ConditionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ConditionKind::If => "if",
+            ConditionKind::ElseIf => "elseif",
+            ConditionKind::While => "while",
+            ConditionKind::DoWhile => "do-while",
+            ConditionKind::For => "for",
+            ConditionKind::Ternary => "ternary",
+        })
+    }
+}
+
+/// What to accept without a report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssignmentInConditionOptions {
+    /// Functions whose result may be assigned in a condition, e.g. `fgets`
+    /// or `mysqli_fetch_assoc`. Matched case-insensitively against the name
+    /// as written, without a leading `\`.
+    pub allowed_calls: Vec<String>,
+    /// Accept every assignment in a `while`, `do`-`while`, or `for`
+    /// condition, as in `while ($row = $stmt->fetch())`.
+    pub allow_in_loops: bool,
+}
+
+/// One reported assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignmentInCondition {
+    /// The assignment expression.
+    pub span: Span,
+    pub condition: ConditionKind,
+}
+
+impl std::fmt::Display for AssignmentInCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "assignment in {} condition; use `==` to compare or wrap the assignment in parentheses",
+            self.condition
+        )
+    }
+}
+
+/// Report the assignments in `program` used as conditions, in source order.
+///
+/// ```
+/// use php_analysis::assignment_in_condition::{
+///     assignments_in_conditions, AssignmentInConditionOptions,
+/// };
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php if ($a = f()) {} if (($b = f())) {} while ($line = fgets($h)) {}";
+/// let result = php_rs_parser::parse(&arena, src);
+///
+/// let found = assignments_in_conditions(&result.program, &Default::default());
+/// let spans: Vec<_> = found.iter().map(|f| &src[f.span.to_range()]).collect();
+/// assert_eq!(spans, ["$a = f()", "$line = fgets($h)"]);
+///
+/// let options = AssignmentInConditionOptions {
+///     allowed_calls: vec!["fgets".into()],
+///     ..Default::default()
+/// };
+/// assert_eq!(assignments_in_conditions(&result.program, &options).len(), 1);
+/// ```
+pub fn assignments_in_conditions(
+    program: &Program<'_, '_>,
+    options: &AssignmentInConditionOptions,
+) -> Vec<AssignmentInCondition> {
+    let mut lint = Lint {
+        options,
+        found: Vec::new(),
+    };
+    let _ = lint.visit_program(program);
+    lint.found.sort_by_key(|f| f.span);
+    lint.found
+}
+
+struct Lint<'o> {
+    options: &'o AssignmentInConditionOptions,
+    found: Vec<AssignmentInCondition>,
+}
+
+impl Lint<'_> {
+    /// Check `expr` in the context of a `kind` condition, descending only
+    /// through the operators that pass a value on as a truth value.
+    fn condition(&mut self, expr: &Expr<'_, '_>, kind: ConditionKind) {
+        match &expr.kind {
+            ExprKind::Assign(assign)
+                if assign.op == AssignOp::Assign && !self.allowed(assign.value, kind) =>
+            {
+                self.found.push(AssignmentInCondition {
+                    span: expr.span,
+                    condition: kind,
+                });
+            }
+            ExprKind::UnaryPrefix(unary) if unary.op == UnaryPrefixOp::BooleanNot => {
+                self.condition(unary.operand, kind)
+            }
+            ExprKind::Binary(binary)
+                if matches!(
+                    binary.op,
+                    BinaryOp::BooleanAnd
+                        | BinaryOp::BooleanOr
+                        | BinaryOp::LogicalAnd
+                        | BinaryOp::LogicalOr
+                        | BinaryOp::LogicalXor
+                ) =>
+            {
+                self.condition(binary.left, kind);
+                self.condition(binary.right, kind);
+            }
+            _ => {}
+        }
+    }
+
+    fn allowed(&self, value: &Expr<'_, '_>, kind: ConditionKind) -> bool {
+        if self.options.allow_in_loops && kind.is_loop() {
+            return true;
+        }
+        match &value.kind {
+            ExprKind::FunctionCall(call) => match &call.name.kind {
+                ExprKind::Name(name) => {
+                    let name = name.join_parts();
+                    let name = name.trim_start_matches('\\');
+                    self.options
+                        .allowed_calls
+                        .iter()
+                        .any(|allowed| allowed.eq_ignore_ascii_case(name))
+                }
+                _ => false,
+            },
+            ExprKind::Assign(inner) => self.allowed(inner.value, kind),
+            _ => false,
+        }
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Lint<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::If(if_stmt) => {
+                self.condition(&if_stmt.condition, ConditionKind::If);
+                for branch in if_stmt.elseif_branches.iter() {
+                    self.condition(&branch.condition, ConditionKind::ElseIf);
+                }
+            }
+            StmtKind::While(while_stmt) => {
+                self.condition(&while_stmt.condition, ConditionKind::While)
+            }
+            StmtKind::DoWhile(do_while) => {
+                self.condition(&do_while.condition, ConditionKind::DoWhile)
+            }
+            StmtKind::For(for_stmt) => {
+                for condition in for_stmt.condition.iter() {
+                    self.condition(condition, ConditionKind::For);
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        if let ExprKind::Ternary(ternary) = &expr.kind {
+            self.condition(ternary.condition, ConditionKind::Ternary);
+        }
+        walk_expr(self, expr)
+    }
+}
@@ -0,0 +1,425 @@
+//! Attribute arguments evaluated to constant values.
+//!
+//! Frameworks read routes, service wiring, and validation rules from
+//! attributes, whose arguments are constant expressions:
+//!
+//! ```php
+//! #[Route('/users/{id}', methods: ['GET'], name: self::PREFIX . 'show')]
+//! #[Inject(Mailer::class)]
+//! ```
+//!
+//! [`AttributeExt::evaluated_args`] folds each argument with the evaluator
+//! of [`values`](crate::values), resolving `Foo::class`, `self::class`,
+//! `__NAMESPACE__`, and `__CLASS__` in the scope of the attribute;
+//! [`AttributeExt::evaluated_args_with`] also asks a caller-supplied lookup
+//! for constants. [`attributes`] lists every attribute of a file with its
+//! resolved name, what it is attached to, and its arguments, resolving
+//! constants from the file's own [`initializers`].
+
+use std::collections::HashMap;
+
+use php_ast::{
+    Attribute, ClassMember, ClassMemberKind, EnumMember, EnumMemberKind, Expr, ExprKind,
+    MagicConstKind, Name, Param, Program, PropertyHookKind, Span, Stmt, StmtKind,
+};
+
+use crate::constants::key;
+use crate::values::{evaluate_with, initializers, InitializerKind, PhpValue};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, ClassContext};
+
+/// What an attribute is attached to, as PHP's `Attribute::TARGET_*`
+/// constants name it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeTarget {
+    /// A class, interface, trait, or enum, named or anonymous.
+    Class,
+    /// A function or closure.
+    Function,
+    /// A method or property hook.
+    Method,
+    Property,
+    /// A class constant or enum case.
+    ClassConstant,
+    /// A parameter, including a promoted constructor parameter.
+    Parameter,
+    /// A global `const`.
+    Constant,
+}
+
+/// One argument of an attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeArg {
+    /// The parameter a named argument is passed to.
+    pub name: Option<String>,
+    /// `None` when the argument is unpacked with `...` or is not a constant
+    /// expression the evaluator can fold, such as a constant the lookup
+    /// does not know.
+    pub value: Option<PhpValue>,
+    pub span: Span,
+}
+
+/// An attribute and what it is attached to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeUse {
+    /// The attribute class, fully qualified without a leading `\`.
+    pub name: String,
+    pub target: AttributeTarget,
+    /// `App\Users` for a class, `App\route` or `{closure}` for a function,
+    /// `App\Users::show`, `App\Users::$id`, `App\Users::$id::get`, or
+    /// `App\Users::LIMIT` for a member, `App\VERSION` for a constant, `$id`
+    /// for a parameter.
+    pub owner: String,
+    pub args: Vec<AttributeArg>,
+    pub span: Span,
+}
+
+/// Evaluated arguments on [`Attribute`] nodes, re-exported from the crate
+/// root.
+///
+/// ```
+/// use php_analysis::values::PhpValue;
+/// use php_analysis::walker::AnalysisScope;
+/// use php_analysis::AttributeExt;
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php #[Route('/users', methods: ['GET'], handler: Users::class, limit: LIMIT)] function users() {}";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let StmtKind::Function(func) = &result.program.stmts[0].kind else { unreachable!() };
+/// let mut scope = AnalysisScope::default();
+/// scope.names.set_namespace("App");
+/// let args = func.attributes[0].evaluated_args(&scope);
+/// assert_eq!(args[0].value, Some(PhpValue::String(b"/users".to_vec())));
+/// assert_eq!(args[1].name.as_deref(), Some("methods"));
+/// assert_eq!(args[2].value, Some(PhpValue::String(b"App\\Users".to_vec())));
+/// assert_eq!(args[3].value, None);
+/// ```
+pub trait AttributeExt {
+    /// Evaluate the arguments, resolving class names and magic constants in
+    /// `scope`. Constant fetches are unknown.
+    fn evaluated_args(&self, scope: &AnalysisScope) -> Vec<AttributeArg>;
+
+    /// [`evaluated_args`](Self::evaluated_args), asking `lookup` for the
+    /// value of each constant fetch, class constant, or other expression the
+    /// evaluator cannot fold by itself.
+    fn evaluated_args_with(
+        &self,
+        scope: &AnalysisScope,
+        lookup: &mut dyn FnMut(&Expr<'_, '_>) -> Option<PhpValue>,
+    ) -> Vec<AttributeArg>;
+}
+
+impl AttributeExt for Attribute<'_, '_> {
+    fn evaluated_args(&self, scope: &AnalysisScope) -> Vec<AttributeArg> {
+        self.evaluated_args_with(scope, &mut |_| None)
+    }
+
+    fn evaluated_args_with(
+        &self,
+        scope: &AnalysisScope,
+        lookup: &mut dyn FnMut(&Expr<'_, '_>) -> Option<PhpValue>,
+    ) -> Vec<AttributeArg> {
+        self.args
+            .iter()
+            .map(|arg| AttributeArg {
+                name: arg
+                    .name
+                    .as_ref()
+                    .map(|name| name.to_string_repr().into_owned()),
+                value: if arg.unpack {
+                    None
+                } else {
+                    evaluate_with(&arg.value, &mut |e| scoped(e, scope).or_else(|| lookup(e)))
+                },
+                span: arg.span,
+            })
+            .collect()
+    }
+}
+
+/// `Foo::class`, `__NAMESPACE__`, or `__CLASS__` in `scope`.
+fn scoped(expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<PhpValue> {
+    let name = match &expr.kind {
+        ExprKind::ClassConstAccess(access) => match &access.member.kind {
+            ExprKind::Identifier(member) if member.eq_ignore_ascii_case("class") => {
+                scope.resolve_class(&access.class)?
+            }
+            _ => return None,
+        },
+        ExprKind::MagicConst(MagicConstKind::Namespace) => scope.names.namespace().to_string(),
+        ExprKind::MagicConst(MagicConstKind::Class) => scope
+            .class
+            .as_ref()
+            .map(|c| c.name.clone())
+            .unwrap_or_default(),
+        _ => return None,
+    };
+    Some(PhpValue::String(name.into_bytes()))
+}
+
+/// List every attribute in `program` with its evaluated arguments, in
+/// source order.
+///
+/// Constants and class constants are resolved as [`initializers`]
+/// resolves them: those of the same file whose value is known.
+///
+/// ```
+/// use php_analysis::attributes::{attributes, AttributeTarget};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// namespace App;
+/// use Framework\\Route;
+/// class Users {
+///     const PREFIX = '/users';
+///     #[Route(self::PREFIX . '/{id}', methods: ['GET'])]
+///     public function show(#[FromPath] int $id) {}
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let found: Vec<_> = attributes(&result.program)
+///     .into_iter()
+///     .map(|a| {
+///         let args: Vec<_> = a.args.iter().map(|arg| arg.value.as_ref().unwrap().to_string()).collect();
+///         format!("{:?} {}: {}({})", a.target, a.owner, a.name, args.join(", "))
+///     })
+///     .collect();
+/// assert_eq!(found, [
+///     "Method App\\Users::show: Framework\\Route('/users/{id}', ['GET'])",
+///     "Parameter $id: App\\FromPath()",
+/// ]);
+/// ```
+pub fn attributes(program: &Program<'_, '_>) -> Vec<AttributeUse> {
+    let mut collector = Collector::default();
+    for initializer in initializers(program) {
+        match initializer.kind {
+            InitializerKind::Constant => {
+                collector
+                    .constants
+                    .insert(key(&initializer.name), initializer.value);
+            }
+            InitializerKind::ClassConstant => {
+                if let Some((class, name)) = initializer.name.rsplit_once("::") {
+                    let key = (class.to_ascii_lowercase(), name.to_string());
+                    collector.class_constants.insert(key, initializer.value);
+                }
+            }
+            _ => {}
+        }
+    }
+    AnalysisWalker::new(&mut collector).walk(program);
+    collector.found.sort_by_key(|attribute| attribute.span);
+    collector.found
+}
+
+#[derive(Default)]
+struct Collector {
+    found: Vec<AttributeUse>,
+    /// Global constants by [`key`].
+    constants: HashMap<String, PhpValue>,
+    /// Class constants by lower-cased class name and constant name.
+    class_constants: HashMap<(String, String), PhpValue>,
+}
+
+impl Collector {
+    fn lookup(&self, expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<PhpValue> {
+        match &expr.kind {
+            ExprKind::ConstFetch(name) => {
+                let (name, fallback) = scope.names.resolve_constant(&name.to_string_repr());
+                self.constants
+                    .get(&key(&name))
+                    .or_else(|| self.constants.get(&key(&fallback?)))
+                    .cloned()
+            }
+            ExprKind::ClassConstAccess(access) => {
+                let ExprKind::Identifier(member) = &access.member.kind else {
+                    return None;
+                };
+                let class = scope.resolve_class(&access.class)?;
+                self.class_constants
+                    .get(&(class.to_ascii_lowercase(), member.to_string()))
+                    .cloned()
+            }
+            _ => None,
+        }
+    }
+
+    fn push(
+        &mut self,
+        attributes: &[Attribute<'_, '_>],
+        target: AttributeTarget,
+        owner: &str,
+        scope: &AnalysisScope,
+    ) {
+        for attribute in attributes {
+            let args = attribute.evaluated_args_with(scope, &mut |e| self.lookup(e, scope));
+            self.found.push(AttributeUse {
+                name: scope.names.resolve_class(&attribute.name.to_string_repr()),
+                target,
+                owner: owner.to_string(),
+                args,
+                span: attribute.span,
+            });
+        }
+    }
+
+    fn params(&mut self, params: &[Param<'_, '_>], scope: &AnalysisScope) {
+        for param in params {
+            let owner = format!("${}", param.name.or_error());
+            self.push(&param.attributes, AttributeTarget::Parameter, &owner, scope);
+        }
+    }
+
+    /// The attributes of a class-like declaration and of its members.
+    fn class_like(
+        &mut self,
+        name: String,
+        extends: Option<&Name<'_, '_>>,
+        attributes: &[Attribute<'_, '_>],
+        members: &[ClassMember<'_, '_>],
+        scope: &AnalysisScope,
+    ) {
+        let scope = &class_scope(scope, name, extends);
+        let class = &scope.class.as_ref().expect("just entered").name;
+        self.push(attributes, AttributeTarget::Class, class, scope);
+        for member in members {
+            match &member.kind {
+                ClassMemberKind::Method(method) => {
+                    let owner = format!("{class}::{}", method.name.or_error());
+                    self.push(&method.attributes, AttributeTarget::Method, &owner, scope);
+                    self.params(&method.params, scope);
+                }
+                ClassMemberKind::Property(prop) => {
+                    let owner = format!("{class}::${}", prop.name.or_error());
+                    self.push(&prop.attributes, AttributeTarget::Property, &owner, scope);
+                    for hook in prop.hooks.iter() {
+                        let kind = match hook.kind {
+                            PropertyHookKind::Get => "get",
+                            PropertyHookKind::Set => "set",
+                        };
+                        let owner = format!("{owner}::{kind}");
+                        self.push(&hook.attributes, AttributeTarget::Method, &owner, scope);
+                        self.params(&hook.params, scope);
+                    }
+                }
+                ClassMemberKind::ClassConst(constant) => {
+                    let owner = format!("{class}::{}", constant.name.or_error());
+                    let target = AttributeTarget::ClassConstant;
+                    self.push(&constant.attributes, target, &owner, scope);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn enum_members(&mut self, members: &[EnumMember<'_, '_>], scope: &AnalysisScope) {
+        let class = &scope.class.as_ref().expect("inside the enum").name;
+        for member in members {
+            match &member.kind {
+                EnumMemberKind::Case(case) => {
+                    let owner = format!("{class}::{}", case.name.or_error());
+                    self.push(
+                        &case.attributes,
+                        AttributeTarget::ClassConstant,
+                        &owner,
+                        scope,
+                    );
+                }
+                EnumMemberKind::Method(method) => {
+                    let owner = format!("{class}::{}", method.name.or_error());
+                    self.push(&method.attributes, AttributeTarget::Method, &owner, scope);
+                    self.params(&method.params, scope);
+                }
+                EnumMemberKind::ClassConst(constant) => {
+                    let owner = format!("{class}::{}", constant.name.or_error());
+                    let target = AttributeTarget::ClassConstant;
+                    self.push(&constant.attributes, target, &owner, scope);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// `scope` inside the class `name`, where `self::class` names it.
+fn class_scope(
+    scope: &AnalysisScope,
+    name: String,
+    extends: Option<&Name<'_, '_>>,
+) -> AnalysisScope {
+    let mut scope = scope.clone();
+    let parent = extends.map(|n| scope.names.resolve_class(&n.to_string_repr()));
+    scope.class = Some(ClassContext { name, parent });
+    scope
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Function(func) => {
+                let owner = scope.names.qualify(func.name.or_error());
+                self.push(&func.attributes, AttributeTarget::Function, &owner, scope);
+                self.params(&func.params, scope);
+            }
+            StmtKind::Class(class) => {
+                let name = match class.name.and_then(|n| n.as_str()) {
+                    Some(name) => scope.names.qualify(name),
+                    None => "class@anonymous".to_string(),
+                };
+                let extends = class.extends.as_ref();
+                self.class_like(name, extends, &class.attributes, &class.members, scope);
+            }
+            StmtKind::Interface(iface) => {
+                let name = scope.names.qualify(iface.name.or_error());
+                self.class_like(name, None, &iface.attributes, &iface.members, scope);
+            }
+            StmtKind::Trait(trait_decl) => {
+                let name = scope.names.qualify(trait_decl.name.or_error());
+                let (attributes, members) = (&trait_decl.attributes, &trait_decl.members);
+                self.class_like(name, None, attributes, members, scope);
+            }
+            StmtKind::Enum(enum_decl) => {
+                let name = scope.names.qualify(enum_decl.name.or_error());
+                let scope = &class_scope(scope, name, None);
+                let owner = &scope.class.as_ref().expect("just entered").name;
+                self.push(&enum_decl.attributes, AttributeTarget::Class, owner, scope);
+                self.enum_members(&enum_decl.members, scope);
+            }
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    let owner = scope.names.qualify(item.name.or_error());
+                    self.push(&item.attributes, AttributeTarget::Constant, &owner, scope);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        match &expr.kind {
+            ExprKind::Closure(closure) => {
+                self.push(
+                    &closure.attributes,
+                    AttributeTarget::Function,
+                    "{closure}",
+                    scope,
+                );
+                self.params(&closure.params, scope);
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                self.push(
+                    &arrow.attributes,
+                    AttributeTarget::Function,
+                    "{closure}",
+                    scope,
+                );
+                self.params(&arrow.params, scope);
+            }
+            ExprKind::AnonymousClass(class) => {
+                let name = "class@anonymous".to_string();
+                let extends = class.extends.as_ref();
+                self.class_like(name, extends, &class.attributes, &class.members, scope);
+            }
+            _ => {}
+        }
+    }
+}
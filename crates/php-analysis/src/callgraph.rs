@@ -0,0 +1,334 @@
+//! Project-wide call graph over statically resolvable call sites.
+//!
+//! Nodes are executable units (file bodies, functions, methods, closures,
+//! property hooks). An edge is recorded for every call whose target can be
+//! named without type inference:
+//!
+//! - `foo()` / `\Ns\foo()` — namespaced function with PHP's global fallback
+//! - `Foo::bar()`, `self::bar()`, `static::bar()`, `parent::bar()`
+//! - `$this->bar()` / `$this?->bar()` inside a class
+//! - `new Foo(...)` — an edge to `Foo::__construct`
+//!
+//! Calls through variables or computed names are recorded when the name is
+//! a string known at parse time — `new $class`, `$fn()`,
+//! `call_user_func([$class, 'run'])` — see [`class_strings`](crate::class_strings).
+//! Other dynamic calls are not represented.
+//! The graph also records the class hierarchy declared in the project so
+//! method calls resolve through inherited members.
+
+use std::collections::HashMap;
+
+use php_ast::{Expr, ExprKind, Program, Span};
+
+use crate::class_strings::{dynamic_callees, StringValues};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, ClassContext, Unit, UnitKind};
+
+/// Supertypes of built-in throwables, so exception handling resolves
+/// `catch (LogicException)` without stubs.
+const BUILTIN_SUPERTYPES: &[(&str, &str)] = &[
+    ("Exception", "Throwable"),
+    ("Error", "Throwable"),
+    ("ErrorException", "Exception"),
+    ("JsonException", "Exception"),
+    ("LogicException", "Exception"),
+    ("RuntimeException", "Exception"),
+    ("BadFunctionCallException", "LogicException"),
+    ("BadMethodCallException", "BadFunctionCallException"),
+    ("DomainException", "LogicException"),
+    ("InvalidArgumentException", "LogicException"),
+    ("LengthException", "LogicException"),
+    ("OutOfRangeException", "LogicException"),
+    ("OutOfBoundsException", "RuntimeException"),
+    ("OverflowException", "RuntimeException"),
+    ("RangeException", "RuntimeException"),
+    ("UnderflowException", "RuntimeException"),
+    ("UnexpectedValueException", "RuntimeException"),
+    ("TypeError", "Error"),
+    ("ValueError", "Error"),
+    ("ArithmeticError", "Error"),
+    ("DivisionByZeroError", "ArithmeticError"),
+    ("ArgumentCountError", "TypeError"),
+    ("UnhandledMatchError", "Error"),
+];
+
+/// A statically resolved call target.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Callee {
+    /// A function call. `fallback` is the global name PHP tries when the
+    /// namespaced function does not exist (unqualified calls only).
+    Function {
+        name: String,
+        fallback: Option<String>,
+    },
+    /// A method call on a known class.
+    Method { class: String, method: String },
+}
+
+impl Callee {
+    /// Resolve the callee of a call expression, if it can be named statically.
+    pub fn from_expr(expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<Self> {
+        match &expr.kind {
+            ExprKind::FunctionCall(call) => {
+                let ExprKind::Name(name) = &call.name.kind else {
+                    return None;
+                };
+                let (name, fallback) = scope.names.resolve_function(&name.to_string_repr());
+                Some(Callee::Function { name, fallback })
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let (ExprKind::Variable(var), ExprKind::Identifier(method)) =
+                    (&call.object.kind, &call.method.kind)
+                else {
+                    return None;
+                };
+                if var.as_str() != "this" {
+                    return None;
+                }
+                Some(Callee::Method {
+                    class: scope.class.as_ref()?.name.clone(),
+                    method: method.to_string(),
+                })
+            }
+            ExprKind::StaticMethodCall(call) => {
+                let ExprKind::Identifier(method) = &call.method.kind else {
+                    return None;
+                };
+                Some(Callee::Method {
+                    class: scope.resolve_class(&call.class)?,
+                    method: method.to_string(),
+                })
+            }
+            ExprKind::New(new) => Some(Callee::Method {
+                class: scope.resolve_class(&new.class)?,
+                method: "__construct".to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Callee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Callee::Function { name, .. } => f.write_str(name),
+            Callee::Method { class, method } => write!(f, "{class}::{method}"),
+        }
+    }
+}
+
+/// A resolvable call made from a [`CallNode`].
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    pub callee: Callee,
+    pub span: Span,
+    /// Types caught by `try` blocks enclosing the call within its unit.
+    pub caught: Vec<String>,
+}
+
+/// One executable unit in the graph.
+#[derive(Debug, Clone)]
+pub struct CallNode {
+    pub name: String,
+    pub kind: UnitKind,
+    /// Index of the file the unit was declared in, in `add_file` order.
+    pub file: usize,
+    pub span: Span,
+    pub calls: Vec<CallSite>,
+}
+
+/// Call graph and class hierarchy for a set of files.
+///
+/// ```
+/// use php_analysis::callgraph::CallGraph;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php function a() { b(); } function b() {}");
+/// let mut graph = CallGraph::new();
+/// graph.add_file(&result.program);
+/// let a = graph.find("a").unwrap();
+/// let callees: Vec<_> = graph.callees(a).map(|n| graph.node(n).name.as_str()).collect();
+/// assert_eq!(callees, ["b"]);
+/// ```
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    nodes: Vec<CallNode>,
+    /// Lower-cased function/method name → first node declaring it.
+    index: HashMap<String, usize>,
+    /// Lower-cased class name → resolved `extends`/`implements` names.
+    supertypes: HashMap<String, Vec<String>>,
+    files: usize,
+}
+
+impl CallGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every unit of `program` to the graph. Returns the file index used
+    /// in [`CallNode::file`].
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let mut builder = Builder {
+            graph: self,
+            file,
+            stack: Vec::new(),
+            values: Vec::new(),
+        };
+        AnalysisWalker::new(&mut builder).walk(program);
+        file
+    }
+
+    /// All nodes in declaration order.
+    pub fn nodes(&self) -> &[CallNode] {
+        &self.nodes
+    }
+
+    pub fn node(&self, id: usize) -> &CallNode {
+        &self.nodes[id]
+    }
+
+    /// Find a function (`Ns\name`) or method (`Ns\Class::name`) by name,
+    /// case-insensitively. Methods are not looked up through inheritance; use
+    /// [`resolve`](Self::resolve) for that.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.index.get(&name.to_ascii_lowercase()).copied()
+    }
+
+    /// Resolve a callee to the node that would execute, following the global
+    /// function fallback and inherited methods.
+    pub fn resolve(&self, callee: &Callee) -> Option<usize> {
+        match callee {
+            Callee::Function { name, fallback } => self
+                .find(name)
+                .or_else(|| fallback.as_deref().and_then(|f| self.find(f))),
+            Callee::Method { class, method } => {
+                let mut pending = vec![class.clone()];
+                let mut seen = Vec::new();
+                while let Some(class) = pending.pop() {
+                    if let Some(id) = self.find(&format!("{class}::{method}")) {
+                        return Some(id);
+                    }
+                    seen.push(class.to_ascii_lowercase());
+                    pending.extend(
+                        self.declared_supertypes(&class)
+                            .iter()
+                            .filter(|s| !seen.contains(&s.to_ascii_lowercase()))
+                            .cloned(),
+                    );
+                }
+                None
+            }
+        }
+    }
+
+    /// Nodes called from `id`, in call-site order. Unresolvable sites are skipped.
+    pub fn callees(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.nodes[id]
+            .calls
+            .iter()
+            .filter_map(|site| self.resolve(&site.callee))
+    }
+
+    /// Direct supertypes of `class` as declared in the project, or known for
+    /// built-in throwables.
+    pub fn supertypes(&self, class: &str) -> Vec<String> {
+        let declared = self.declared_supertypes(class);
+        if !declared.is_empty() {
+            return declared.to_vec();
+        }
+        BUILTIN_SUPERTYPES
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(class))
+            .map(|(_, parent)| parent.to_string())
+            .collect()
+    }
+
+    /// `true` if `class` is `ancestor` or transitively extends/implements it,
+    /// according to project declarations and the built-in throwable hierarchy.
+    pub fn is_subtype_of(&self, class: &str, ancestor: &str) -> bool {
+        let mut pending = vec![class.to_string()];
+        let mut seen: Vec<String> = Vec::new();
+        while let Some(class) = pending.pop() {
+            if class.eq_ignore_ascii_case(ancestor) {
+                return true;
+            }
+            let key = class.to_ascii_lowercase();
+            if seen.contains(&key) {
+                continue;
+            }
+            seen.push(key);
+            pending.extend(self.supertypes(&class));
+        }
+        false
+    }
+
+    fn declared_supertypes(&self, class: &str) -> &[String] {
+        self.supertypes
+            .get(&class.to_ascii_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+struct Builder<'g> {
+    graph: &'g mut CallGraph,
+    file: usize,
+    /// Node ids of the units enclosing the current position.
+    stack: Vec<usize>,
+    /// Known string values of the variables of each enclosing unit.
+    values: Vec<StringValues>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Builder<'_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        let id = self.graph.nodes.len();
+        self.graph.nodes.push(CallNode {
+            name: unit.name.clone(),
+            kind: unit.kind,
+            file: self.file,
+            span: unit.span,
+            calls: Vec::new(),
+        });
+        if matches!(unit.kind, UnitKind::Function | UnitKind::Method) {
+            self.graph
+                .index
+                .entry(unit.name.to_ascii_lowercase())
+                .or_insert(id);
+        }
+        self.stack.push(id);
+        self.values.push(StringValues::collect(
+            unit.params,
+            unit.body.unwrap_or_default(),
+            scope,
+        ));
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.stack.pop();
+        self.values.pop();
+    }
+
+    fn enter_class(&mut self, class: &ClassContext, supertypes: &[String], _span: Span) {
+        self.graph
+            .supertypes
+            .entry(class.name.to_ascii_lowercase())
+            .or_insert_with(|| supertypes.to_vec());
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        let (Some(&id), Some(values)) = (self.stack.last(), self.values.last()) else {
+            return;
+        };
+        let callees = Callee::from_expr(expr, scope)
+            .into_iter()
+            .chain(dynamic_callees(expr, values, scope));
+        for callee in callees {
+            self.graph.nodes[id].calls.push(CallSite {
+                callee,
+                span: expr.span,
+                caught: scope.caught_types(),
+            });
+        }
+    }
+}
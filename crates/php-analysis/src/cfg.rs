@@ -0,0 +1,618 @@
+//! Intra-procedural control-flow graph over a statement body.
+//!
+//! Each [`Block`] holds the straight-line [`CfgElement`]s executed in order,
+//! and edges to the blocks that may run next. Structured statements (`if`,
+//! loops, `switch`, `try`) contribute their conditions as [`CfgElement::Expr`]
+//! and are otherwise lowered to edges; every other statement is a single
+//! [`CfgElement::Stmt`].
+//!
+//! Modelling choices:
+//!
+//! - The branches of `if`, `elseif`, `while`, and `for` each start with a
+//!   [`CfgElement::Assume`] recording which way the condition went, so
+//!   analyses can narrow on it.
+//! - `return`, `exit`, and `__halt_compiler` jump to [`Cfg::EXIT`]; `throw`
+//!   jumps to the enclosing `catch` blocks, else the enclosing `finally`, else
+//!   the exit. Every block inside a `try` body also has edges to its `catch`
+//!   blocks, since any call may throw.
+//! - `finally` is built twice: once on the normal path, and once (only if
+//!   reachable) for returns and throws, continuing to the outer handler.
+//!   `break`/`continue` jump straight to their loop, bypassing `finally`.
+//! - Loops whose condition is the literal `true` (or a missing `for`
+//!   condition) have no exit edge.
+//! - `goto` jumps to its label as resolved by the body's [`LabelTable`]; a
+//!   `goto` to an undefined label has no successor.
+
+use std::collections::HashMap;
+
+use php_ast::{
+    CatchClause, Expr, ExprKind, ForeachStmt, NamespaceBody, Stmt, StmtKind, SwitchStmt,
+    TryCatchStmt,
+};
+
+use crate::labels::LabelTable;
+
+pub type BlockId = usize;
+
+/// A unit of work inside a [`Block`].
+#[derive(Debug, Clone, Copy)]
+pub enum CfgElement<'a, 'arena, 'src> {
+    /// A non-structured statement (`echo`, an expression statement, `return`, …).
+    Stmt(&'a Stmt<'arena, 'src>),
+    /// An expression evaluated for control flow: a condition, `for` init or
+    /// update, `switch` subject or case value, or `foreach` subject.
+    Expr(&'a Expr<'arena, 'src>),
+    /// Assignment of the key and value variables at the head of each `foreach` iteration.
+    ForeachBind(&'a ForeachStmt<'arena, 'src>),
+    /// Binding of the exception variable on entry to a `catch` block.
+    CatchBind(&'a CatchClause<'arena, 'src>),
+    /// Entry to one branch of an `if`, `elseif`, `while`, or `for`: the
+    /// condition, already evaluated, was truthy (`holds`) or falsy.
+    Assume {
+        condition: &'a Expr<'arena, 'src>,
+        holds: bool,
+    },
+}
+
+/// A basic block.
+#[derive(Debug, Default)]
+pub struct Block<'a, 'arena, 'src> {
+    pub elements: Vec<CfgElement<'a, 'arena, 'src>>,
+    pub succs: Vec<BlockId>,
+    pub preds: Vec<BlockId>,
+}
+
+/// Control-flow graph of one body.
+///
+/// ```
+/// use php_analysis::cfg::Cfg;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php if ($a) { return 1; } else { return 2; } echo 3;");
+/// let cfg = Cfg::build(&result.program.stmts);
+/// assert!(!cfg.falls_through());
+/// assert_eq!(cfg.unreachable_stmts().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Cfg<'a, 'arena, 'src> {
+    blocks: Vec<Block<'a, 'arena, 'src>>,
+    /// Block reached by running off the end of the body.
+    end: BlockId,
+    reachable: Vec<bool>,
+    /// Statements that begin a run of dead code, with the block they start in.
+    candidates: Vec<(&'a Stmt<'arena, 'src>, BlockId)>,
+}
+
+impl<'a, 'arena, 'src> Cfg<'a, 'arena, 'src> {
+    /// The entry block.
+    pub const ENTRY: BlockId = 0;
+    /// The exit block, reached by `return`, uncaught `throw`, `exit`, and falling off the end.
+    pub const EXIT: BlockId = 1;
+
+    pub fn build(body: &'a [Stmt<'arena, 'src>]) -> Self {
+        let mut builder = Builder {
+            blocks: vec![Block::default(), Block::default()],
+            live: vec![true, false],
+            current: Self::ENTRY,
+            loops: Vec::new(),
+            tries: Vec::new(),
+            labels: HashMap::new(),
+            gotos: Vec::new(),
+            candidates: Vec::new(),
+        };
+        builder.build_list(body);
+        let end = builder.current;
+        builder.edge(end, Self::EXIT);
+        let labels = LabelTable::build(body);
+        for (from, label) in std::mem::take(&mut builder.gotos) {
+            if labels.label(label).is_some() {
+                let target = builder.label_block(label);
+                builder.edge(from, target);
+            }
+        }
+
+        let mut reachable = vec![false; builder.blocks.len()];
+        let mut pending = vec![Self::ENTRY];
+        while let Some(id) = pending.pop() {
+            if !std::mem::replace(&mut reachable[id], true) {
+                pending.extend(builder.blocks[id].succs.iter().copied());
+            }
+        }
+
+        Self {
+            blocks: builder.blocks,
+            end,
+            reachable,
+            candidates: builder.candidates,
+        }
+    }
+
+    pub fn blocks(&self) -> &[Block<'a, 'arena, 'src>] {
+        &self.blocks
+    }
+
+    pub fn block(&self, id: BlockId) -> &Block<'a, 'arena, 'src> {
+        &self.blocks[id]
+    }
+
+    /// `true` if `id` can be reached from the entry block.
+    pub fn is_reachable(&self, id: BlockId) -> bool {
+        self.reachable[id]
+    }
+
+    /// `true` if execution can run off the end of the body without a
+    /// `return`, `throw`, or `exit`.
+    pub fn falls_through(&self) -> bool {
+        self.reachable[self.end]
+    }
+
+    /// The first statement of each run of unreachable code, in source order.
+    /// Statements nested inside an already reported statement are omitted.
+    pub fn unreachable_stmts(&self) -> Vec<&'a Stmt<'arena, 'src>> {
+        let mut out: Vec<&'a Stmt<'arena, 'src>> = Vec::new();
+        for &(stmt, block) in &self.candidates {
+            if self.reachable[block] {
+                continue;
+            }
+            let nested = out
+                .iter()
+                .any(|o| o.span.start <= stmt.span.start && stmt.span.end <= o.span.end);
+            if !nested {
+                out.push(stmt);
+            }
+        }
+        out.sort_by_key(|s| s.span.start);
+        out
+    }
+}
+
+#[derive(Clone, Copy)]
+struct LoopTargets {
+    brk: BlockId,
+    cont: BlockId,
+}
+
+struct TryFrame {
+    catches: Vec<BlockId>,
+    /// Entry of the copy of `finally` run on abrupt completion.
+    finally: Option<BlockId>,
+    /// `false` once the try body is built and the catch bodies are being built.
+    in_body: bool,
+}
+
+struct Builder<'a, 'arena, 'src> {
+    blocks: Vec<Block<'a, 'arena, 'src>>,
+    /// Blocks known so far to be reachable from the entry or a label.
+    live: Vec<bool>,
+    current: BlockId,
+    loops: Vec<LoopTargets>,
+    tries: Vec<TryFrame>,
+    labels: HashMap<&'a str, BlockId>,
+    gotos: Vec<(BlockId, &'a str)>,
+    candidates: Vec<(&'a Stmt<'arena, 'src>, BlockId)>,
+}
+
+impl<'a, 'arena, 'src> Builder<'a, 'arena, 'src> {
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(Block::default());
+        self.live.push(false);
+        self.blocks.len() - 1
+    }
+
+    fn edge(&mut self, from: BlockId, to: BlockId) {
+        if !self.blocks[from].succs.contains(&to) {
+            self.blocks[from].succs.push(to);
+            self.blocks[to].preds.push(from);
+        }
+        if self.live[from] {
+            self.mark_live(to);
+        }
+    }
+
+    fn mark_live(&mut self, id: BlockId) {
+        let mut pending = vec![id];
+        while let Some(id) = pending.pop() {
+            if !std::mem::replace(&mut self.live[id], true) {
+                pending.extend(self.blocks[id].succs.iter().copied());
+            }
+        }
+    }
+
+    fn push(&mut self, element: CfgElement<'a, 'arena, 'src>) {
+        let current = self.current;
+        self.blocks[current].elements.push(element);
+    }
+
+    /// Finish the current block with edges to `targets` and continue in a
+    /// fresh (initially unreachable) block.
+    fn jump(&mut self, targets: &[BlockId]) {
+        for &target in targets {
+            self.edge(self.current, target);
+        }
+        self.current = self.new_block();
+    }
+
+    /// Continue in `next`, falling through from the current block.
+    fn fall_into(&mut self, next: BlockId) {
+        self.edge(self.current, next);
+        self.current = next;
+    }
+
+    fn label_block(&mut self, label: &'a str) -> BlockId {
+        match self.labels.get(label) {
+            Some(&id) => id,
+            None => {
+                // A label may be the target of a later `goto`; treat it as live.
+                let id = self.new_block();
+                self.labels.insert(label, id);
+                self.mark_live(id);
+                id
+            }
+        }
+    }
+
+    fn is_dead(&self, id: BlockId) -> bool {
+        !self.live[id]
+    }
+
+    fn throw_targets(&self) -> Vec<BlockId> {
+        let mut targets = Vec::new();
+        for frame in self.tries.iter().rev() {
+            if frame.in_body {
+                targets.extend(frame.catches.iter().copied());
+            }
+            if let Some(finally) = frame.finally {
+                targets.push(finally);
+                return targets;
+            }
+        }
+        targets.push(Cfg::EXIT);
+        targets
+    }
+
+    fn return_target(&self) -> BlockId {
+        self.tries
+            .iter()
+            .rev()
+            .find_map(|frame| frame.finally)
+            .unwrap_or(Cfg::EXIT)
+    }
+
+    fn build_list(&mut self, stmts: &'a [Stmt<'arena, 'src>]) {
+        let mut prev_dead = false;
+        for stmt in stmts {
+            if let StmtKind::Label(_) = stmt.kind {
+                // A label opens its own block, reachable only through a
+                // `goto` or by falling into it; which one holds is known once
+                // every `goto` is resolved, so judge the label by its block.
+                self.build_stmt(stmt);
+                if !prev_dead {
+                    self.candidates.push((stmt, self.current));
+                }
+                prev_dead = false;
+                continue;
+            }
+            let dead = self.is_dead(self.current);
+            if dead && !prev_dead {
+                self.candidates.push((stmt, self.current));
+            }
+            prev_dead = dead;
+            self.build_stmt(stmt);
+        }
+    }
+
+    fn build_stmt(&mut self, stmt: &'a Stmt<'arena, 'src>) {
+        match &stmt.kind {
+            StmtKind::Block(stmts) => self.build_list(stmts),
+            StmtKind::If(if_stmt) => {
+                let after = self.new_block();
+                let branches = std::iter::once((&if_stmt.condition, if_stmt.then_branch)).chain(
+                    if_stmt
+                        .elseif_branches
+                        .iter()
+                        .map(|branch| (&branch.condition, &branch.body)),
+                );
+                for (condition, body) in branches {
+                    self.push(CfgElement::Expr(condition));
+                    let then_block = self.new_block();
+                    let else_block = self.new_block();
+                    self.edge(self.current, then_block);
+                    self.edge(self.current, else_block);
+                    self.current = then_block;
+                    self.assume(condition, true);
+                    self.build_stmt(body);
+                    self.edge(self.current, after);
+                    self.current = else_block;
+                    self.assume(condition, false);
+                }
+                if let Some(else_branch) = if_stmt.else_branch {
+                    self.build_stmt(else_branch);
+                }
+                self.fall_into(after);
+            }
+            StmtKind::While(while_stmt) => {
+                let header = self.new_block();
+                let after = self.new_block();
+                self.fall_into(header);
+                self.push(CfgElement::Expr(&while_stmt.condition));
+                self.branch_on(Some(&while_stmt.condition), after);
+                self.build_loop_body(while_stmt.body, header, after, header);
+                self.current = after;
+            }
+            StmtKind::DoWhile(do_while) => {
+                let body = self.new_block();
+                let condition = self.new_block();
+                let after = self.new_block();
+                self.fall_into(body);
+                self.build_loop_body(do_while.body, condition, after, condition);
+                self.current = condition;
+                self.push(CfgElement::Expr(&do_while.condition));
+                self.edge(condition, body);
+                if !is_const_true(&do_while.condition) {
+                    self.edge(condition, after);
+                }
+                self.current = after;
+            }
+            StmtKind::For(for_stmt) => {
+                for init in for_stmt.init.iter() {
+                    self.push(CfgElement::Expr(init));
+                }
+                let header = self.new_block();
+                let update = self.new_block();
+                let after = self.new_block();
+                self.fall_into(header);
+                for condition in for_stmt.condition.iter() {
+                    self.push(CfgElement::Expr(condition));
+                }
+                self.branch_on(for_stmt.condition.last(), after);
+                self.build_loop_body(for_stmt.body, update, after, update);
+                self.current = update;
+                for expr in for_stmt.update.iter() {
+                    self.push(CfgElement::Expr(expr));
+                }
+                self.edge(update, header);
+                self.current = after;
+            }
+            StmtKind::Foreach(foreach) => {
+                self.push(CfgElement::Expr(&foreach.expr));
+                let header = self.new_block();
+                let body = self.new_block();
+                let after = self.new_block();
+                self.fall_into(header);
+                self.edge(header, body);
+                self.edge(header, after);
+                self.current = body;
+                self.push(CfgElement::ForeachBind(foreach));
+                self.build_loop_body(foreach.body, header, after, header);
+                self.current = after;
+            }
+            StmtKind::Switch(switch) => self.build_switch(switch),
+            StmtKind::TryCatch(try_catch) => self.build_try(try_catch),
+            StmtKind::Break(level) | StmtKind::Continue(level) => {
+                self.push(CfgElement::Stmt(stmt));
+                let depth = loop_depth(*level);
+                let target = self
+                    .loops
+                    .len()
+                    .checked_sub(depth)
+                    .map(|i| self.loops[i])
+                    .map(|t| {
+                        if matches!(stmt.kind, StmtKind::Break(_)) {
+                            t.brk
+                        } else {
+                            t.cont
+                        }
+                    })
+                    .unwrap_or(Cfg::EXIT);
+                self.jump(&[target]);
+            }
+            StmtKind::Return(_) => {
+                self.push(CfgElement::Stmt(stmt));
+                let target = self.return_target();
+                self.jump(&[target]);
+            }
+            StmtKind::Throw(_) => {
+                self.push(CfgElement::Stmt(stmt));
+                let targets = self.throw_targets();
+                self.jump(&targets);
+            }
+            StmtKind::Expression(expr) if is_exit(expr) => {
+                self.push(CfgElement::Stmt(stmt));
+                self.jump(&[Cfg::EXIT]);
+            }
+            StmtKind::Expression(expr) if is_throw(expr) => {
+                self.push(CfgElement::Stmt(stmt));
+                let targets = self.throw_targets();
+                self.jump(&targets);
+            }
+            StmtKind::HaltCompiler(_) => {
+                self.push(CfgElement::Stmt(stmt));
+                self.jump(&[Cfg::EXIT]);
+            }
+            StmtKind::Goto(label) => {
+                self.push(CfgElement::Stmt(stmt));
+                self.gotos.push((self.current, label.or_error()));
+                self.current = self.new_block();
+            }
+            StmtKind::Label(label) => {
+                let block = self.label_block(label);
+                self.fall_into(block);
+            }
+            StmtKind::Declare(declare) => {
+                self.push(CfgElement::Stmt(stmt));
+                if let Some(body) = declare.body {
+                    self.build_stmt(body);
+                }
+            }
+            StmtKind::Namespace(ns) => match &ns.body {
+                NamespaceBody::Braced(stmts) => self.build_list(stmts),
+                NamespaceBody::Simple => self.push(CfgElement::Stmt(stmt)),
+            },
+            _ => self.push(CfgElement::Stmt(stmt)),
+        }
+    }
+
+    /// Record that `condition` evaluated to `holds` on entry to the current
+    /// block.
+    fn assume(&mut self, condition: &'a Expr<'arena, 'src>, holds: bool) {
+        self.push(CfgElement::Assume { condition, holds });
+    }
+
+    /// End a loop header evaluating `condition` (`None` for a `for` without
+    /// one): exit to `after` through a block that assumes it falsy, unless it
+    /// is always true, and continue in a fresh body block that assumes it
+    /// truthy.
+    fn branch_on(&mut self, condition: Option<&'a Expr<'arena, 'src>>, after: BlockId) {
+        let header = self.current;
+        if let Some(condition) = condition.filter(|c| !is_const_true(c)) {
+            let exit = self.new_block();
+            self.edge(header, exit);
+            self.current = exit;
+            self.assume(condition, false);
+            self.edge(exit, after);
+        }
+        let body = self.new_block();
+        self.edge(header, body);
+        self.current = body;
+        if let Some(condition) = condition {
+            self.assume(condition, true);
+        }
+    }
+
+    fn build_loop_body(
+        &mut self,
+        body: &'a Stmt<'arena, 'src>,
+        cont: BlockId,
+        brk: BlockId,
+        back_edge: BlockId,
+    ) {
+        self.loops.push(LoopTargets { brk, cont });
+        self.build_stmt(body);
+        self.loops.pop();
+        self.edge(self.current, back_edge);
+    }
+
+    fn build_switch(&mut self, switch: &'a SwitchStmt<'arena, 'src>) {
+        self.push(CfgElement::Expr(&switch.expr));
+        for case in switch.cases.iter() {
+            if let Some(value) = &case.value {
+                self.push(CfgElement::Expr(value));
+            }
+        }
+        let head = self.current;
+        let after = self.new_block();
+        let entries: Vec<BlockId> = switch.cases.iter().map(|_| self.new_block()).collect();
+        for &entry in &entries {
+            self.edge(head, entry);
+        }
+        if !switch.cases.iter().any(|case| case.value.is_none()) {
+            self.edge(head, after);
+        }
+        // `continue` inside `switch` behaves like `break`.
+        self.loops.push(LoopTargets {
+            brk: after,
+            cont: after,
+        });
+        for (i, case) in switch.cases.iter().enumerate() {
+            self.current = entries[i];
+            self.build_list(&case.body);
+            let next = entries.get(i + 1).copied().unwrap_or(after);
+            self.edge(self.current, next);
+        }
+        self.loops.pop();
+        self.current = after;
+    }
+
+    fn build_try(&mut self, try_catch: &'a TryCatchStmt<'arena, 'src>) {
+        let catches: Vec<BlockId> = try_catch.catches.iter().map(|_| self.new_block()).collect();
+        let finally = try_catch.finally.as_ref().map(|_| self.new_block());
+        let after = self.new_block();
+        let normal_end = if try_catch.finally.is_some() {
+            self.new_block()
+        } else {
+            after
+        };
+        self.tries.push(TryFrame {
+            catches: catches.clone(),
+            finally,
+            in_body: true,
+        });
+
+        let body_start = self.new_block();
+        self.fall_into(body_start);
+        self.build_list(&try_catch.body);
+        let body_end = self.blocks.len();
+        self.edge(self.current, normal_end);
+        for id in body_start..body_end {
+            for &catch in &catches {
+                self.edge(id, catch);
+            }
+        }
+
+        if let Some(frame) = self.tries.last_mut() {
+            frame.in_body = false;
+        }
+        for (clause, &entry) in try_catch.catches.iter().zip(&catches) {
+            self.current = entry;
+            self.push(CfgElement::CatchBind(clause));
+            self.build_list(&clause.body);
+            self.edge(self.current, normal_end);
+        }
+        self.tries.pop();
+
+        if let (Some(stmts), Some(abrupt)) = (&try_catch.finally, finally) {
+            if self.live[normal_end] {
+                self.current = normal_end;
+                self.build_list(stmts);
+                self.edge(self.current, after);
+            }
+            if self.live[abrupt] {
+                self.current = abrupt;
+                self.build_list(stmts);
+                let mut targets = self.throw_targets();
+                let ret = self.return_target();
+                if !targets.contains(&ret) {
+                    targets.push(ret);
+                }
+                for target in targets {
+                    self.edge(self.current, target);
+                }
+            }
+        }
+        self.current = after;
+    }
+}
+
+/// The loop depth a `break`/`continue` argument refers to (default 1).
+fn loop_depth(level: Option<&Expr<'_, '_>>) -> usize {
+    match level.map(|e| &e.kind) {
+        Some(ExprKind::Int(n)) if *n > 0 => *n as usize,
+        _ => 1,
+    }
+}
+
+/// `true` for conditions that are literally always true (`true`, non-zero ints).
+fn is_const_true(expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::Bool(b) => *b,
+        ExprKind::Int(n) => *n != 0,
+        ExprKind::Parenthesized(inner) => is_const_true(inner),
+        _ => false,
+    }
+}
+
+fn is_exit(expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::Exit(_) => true,
+        ExprKind::Parenthesized(inner) => is_exit(inner),
+        _ => false,
+    }
+}
+
+fn is_throw(expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::ThrowExpr(_) => true,
+        ExprKind::Parenthesized(inner) => is_throw(inner),
+        _ => false,
+    }
+}
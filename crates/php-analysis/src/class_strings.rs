@@ -0,0 +1,473 @@
+//! String values known at parse time, for semi-dynamic calls.
+//!
+//! Code such as `new $class`, `$handler()`, or `call_user_func([$class, 'run'])`
+//! often names its target through strings built from literals:
+//!
+//! ```php
+//! $class = 'App\\Handlers\\' . ucfirst('x');  // unknown
+//! $class = __NAMESPACE__ . '\\' . 'Mailer';  // known
+//! $fn = sprintf('%s_%s', 'handle', 'event'); // known
+//! ```
+//!
+//! [`StringValues`] collects, for one executable body, the candidate strings
+//! of every local variable whose assignments are all built from literals,
+//! `Foo::class`, `__NAMESPACE__`, `__CLASS__`, concatenation, interpolation,
+//! ternaries, and `sprintf` with `%s`/`%d` conversions. The tracking is flow
+//! insensitive — a variable's candidates are the union of all its assignments
+//! — and gives up on variables that are parameters, loop targets,
+//! destructured, `global`, `static`, compound-assigned, or assigned by
+//! reference. [`dynamic_callees`] turns the candidates into call targets; the
+//! [`callgraph`](crate::callgraph) records them alongside the statically
+//! named calls.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{
+    ArrayElement, AssignOp, BinaryOp, ClassRef, Expr, ExprKind, MagicConstKind, NameKind, Param,
+    Stmt, StmtKind, StringPart,
+};
+
+use crate::callgraph::Callee;
+use crate::walker::AnalysisScope;
+
+/// Upper bound on the candidates of one expression; larger sets are treated
+/// as unknown.
+pub const MAX_CANDIDATES: usize = 16;
+
+/// Assignment passes after which still-unresolved variables are unknown.
+const MAX_PASSES: usize = 8;
+
+/// Candidate string values of the local variables of one body.
+///
+/// ```
+/// use php_analysis::class_strings::StringValues;
+/// use php_analysis::walker::AnalysisScope;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// $prefix = 'App\\\\';
+/// $class = $ok ? $prefix . 'Mailer' : $prefix . 'Logger';
+/// $other = strtoupper('x');
+/// ";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let values = StringValues::collect(&[], &result.program.stmts, &AnalysisScope::default());
+/// assert_eq!(values.variable("class").unwrap(), ["App\\Mailer", "App\\Logger"]);
+/// assert!(values.variable("other").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StringValues {
+    /// `None` marks a variable with at least one unresolvable write.
+    vars: HashMap<String, Option<Vec<String>>>,
+}
+
+impl StringValues {
+    /// Collect the variables of `body`, without descending into nested
+    /// functions, classes, or closures. `scope` resolves `Foo::class` and
+    /// magic constants.
+    pub fn collect(params: &[Param<'_, '_>], body: &[Stmt<'_, '_>], scope: &AnalysisScope) -> Self {
+        let mut values = StringValues::default();
+        for _ in 0..MAX_PASSES {
+            let mut pass = Pass {
+                previous: &values,
+                scope,
+                vars: HashMap::new(),
+            };
+            for param in params {
+                pass.vars.insert(param.name.to_string(), None);
+            }
+            for stmt in body {
+                let _ = pass.visit_stmt(stmt);
+            }
+            let vars = pass.vars;
+            if vars == values.vars {
+                break;
+            }
+            values.vars = vars;
+        }
+        values
+    }
+
+    /// Candidate values of `$name` (without `$`), or `None` if any write to
+    /// it is not known statically.
+    pub fn variable(&self, name: &str) -> Option<&[String]> {
+        self.vars.get(name)?.as_deref()
+    }
+
+    /// Candidate values of `expr`, or `None` if it is not built only from
+    /// known parts.
+    pub fn eval(&self, expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<Vec<String>> {
+        let one = |s: &str| Some(vec![s.to_string()]);
+        match &expr.kind {
+            ExprKind::String { value: s, .. } | ExprKind::Nowdoc { value: s, .. } => one(s),
+            ExprKind::Int(n) => one(&n.to_string()),
+            ExprKind::InterpolatedString(parts) => {
+                let mut acc = vec![String::new()];
+                for part in parts.iter() {
+                    let part = match part {
+                        StringPart::Literal { value, .. } => vec![value.to_string()],
+                        StringPart::Expr(expr) => self.eval(expr, scope)?,
+                    };
+                    acc = product(&acc, &part)?;
+                }
+                Some(acc)
+            }
+            ExprKind::Parenthesized(inner) => self.eval(inner, scope),
+            ExprKind::Binary(binary) if binary.op == BinaryOp::Concat => product(
+                &self.eval(binary.left, scope)?,
+                &self.eval(binary.right, scope)?,
+            ),
+            ExprKind::Ternary(ternary) => {
+                let then = self.eval(ternary.then_expr.unwrap_or(ternary.condition), scope)?;
+                union(then, self.eval(ternary.else_expr, scope)?)
+            }
+            ExprKind::NullCoalesce(coalesce) => union(
+                self.eval(coalesce.left, scope)?,
+                self.eval(coalesce.right, scope)?,
+            ),
+            ExprKind::Variable(name) => self.variable(name.as_str()).map(<[_]>::to_vec),
+            ExprKind::ClassConstAccess(access) => {
+                let ExprKind::Identifier(member) = &access.member.kind else {
+                    return None;
+                };
+                if !member.eq_ignore_ascii_case("class") {
+                    return None;
+                }
+                one(&scope.resolve_class(&access.class)?)
+            }
+            ExprKind::MagicConst(MagicConstKind::Namespace) => one(scope.names.namespace()),
+            ExprKind::MagicConst(MagicConstKind::Class) => {
+                one(scope.class.as_ref().map_or("", |c| c.name.as_str()))
+            }
+            ExprKind::FunctionCall(call) => {
+                let ExprKind::Name(name) = &call.name.kind else {
+                    return None;
+                };
+                let is_sprintf = matches!(
+                    name.kind(),
+                    NameKind::Unqualified | NameKind::FullyQualified
+                ) && name.join_parts().eq_ignore_ascii_case("sprintf");
+                if !is_sprintf || call.args.iter().any(|a| a.unpack || a.name.is_some()) {
+                    return None;
+                }
+                let mut combos = vec![Vec::new()];
+                for arg in call.args.iter() {
+                    let values = self.eval(&arg.value, scope)?;
+                    if combos.len() * values.len() > MAX_CANDIDATES {
+                        return None;
+                    }
+                    combos = combos
+                        .iter()
+                        .flat_map(|combo| {
+                            values.iter().map(move |v| {
+                                let mut combo: Vec<String> = combo.clone();
+                                combo.push(v.clone());
+                                combo
+                            })
+                        })
+                        .collect();
+                }
+                let mut out = Vec::new();
+                for combo in &combos {
+                    let (format, args) = combo.split_first()?;
+                    push_unique(&mut out, sprintf(format, args)?);
+                }
+                Some(out)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Call targets of a call or instantiation whose callee is a string known at
+/// parse time. Calls that name their target directly are left to
+/// [`Callee::from_expr`].
+///
+/// Handles `new $class`, `$class::method()`, `$fn()`, `('a' . 'b')()`, and
+/// the callable argument of `call_user_func`, `call_user_func_array`,
+/// `forward_static_call`, and `forward_static_call_array` — a function name,
+/// a `'Class::method'` string, or a `[$class, 'method']` array.
+///
+/// ```
+/// use php_analysis::class_strings::{dynamic_callees, StringValues};
+/// use php_analysis::walker::AnalysisScope;
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php $class = 'App\\\\Mailer'; new $class();";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let scope = AnalysisScope::default();
+/// let values = StringValues::collect(&[], &result.program.stmts, &scope);
+/// let StmtKind::Expression(new) = &result.program.stmts[1].kind else { unreachable!() };
+/// let targets: Vec<_> = dynamic_callees(new, &values, &scope)
+///     .iter()
+///     .map(|c| c.to_string())
+///     .collect();
+/// assert_eq!(targets, ["App\\Mailer::__construct"]);
+/// ```
+pub fn dynamic_callees(
+    expr: &Expr<'_, '_>,
+    values: &StringValues,
+    scope: &AnalysisScope,
+) -> Vec<Callee> {
+    match &expr.kind {
+        ExprKind::New(new) => match computed_class(&new.class) {
+            Some(class) => values
+                .eval(class, scope)
+                .unwrap_or_default()
+                .iter()
+                .map(|class| method(class, "__construct"))
+                .collect(),
+            None => Vec::new(),
+        },
+        ExprKind::StaticMethodCall(call) => {
+            let (Some(class), ExprKind::Identifier(name)) =
+                (computed_class(&call.class), &call.method.kind)
+            else {
+                return Vec::new();
+            };
+            values
+                .eval(class, scope)
+                .unwrap_or_default()
+                .iter()
+                .map(|class| method(class, name))
+                .collect()
+        }
+        ExprKind::FunctionCall(call) => match &call.name.kind {
+            ExprKind::Name(name) => {
+                let is_callback_call = matches!(
+                    name.kind(),
+                    NameKind::Unqualified | NameKind::FullyQualified
+                ) && matches!(
+                    name.join_parts().to_ascii_lowercase().as_str(),
+                    "call_user_func"
+                        | "call_user_func_array"
+                        | "forward_static_call"
+                        | "forward_static_call_array"
+                );
+                match call.args.first() {
+                    Some(arg) if is_callback_call && !arg.unpack => {
+                        callable_targets(&arg.value, values, scope)
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            _ => callable_targets(call.name, values, scope),
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// Targets of a callable value: a string or a two-element `[class, method]`
+/// array.
+fn callable_targets(
+    callable: &Expr<'_, '_>,
+    values: &StringValues,
+    scope: &AnalysisScope,
+) -> Vec<Callee> {
+    if let ExprKind::Array(elements) = &callable.kind {
+        let [ArrayElement {
+            key: None,
+            value: class,
+            unpack: false,
+            ..
+        }, ArrayElement {
+            key: None,
+            value: name,
+            unpack: false,
+            ..
+        }] = &elements[..]
+        else {
+            return Vec::new();
+        };
+        let (Some(classes), Some(names)) = (values.eval(class, scope), values.eval(name, scope))
+        else {
+            return Vec::new();
+        };
+        return classes
+            .iter()
+            .flat_map(|class| names.iter().map(move |name| method(class, name)))
+            .collect();
+    }
+    values
+        .eval(callable, scope)
+        .unwrap_or_default()
+        .iter()
+        .map(|target| match target.split_once("::") {
+            Some((class, name)) => method(class, name),
+            None => Callee::Function {
+                name: target.trim_start_matches('\\').to_string(),
+                fallback: None,
+            },
+        })
+        .collect()
+}
+
+/// Strings name classes and functions fully qualified, with an optional
+/// leading `\`.
+fn method(class: &str, method: &str) -> Callee {
+    Callee::Method {
+        class: class.trim_start_matches('\\').to_string(),
+        method: method.to_string(),
+    }
+}
+
+/// `true` for class references that [`Callee::from_expr`] already resolves.
+/// The expression of a class operand computed at run time, if any.
+fn computed_class<'a, 'arena, 'src>(
+    class: &'a ClassRef<'arena, 'src>,
+) -> Option<&'a Expr<'arena, 'src>> {
+    match class {
+        ClassRef::Expr(expr) if !matches!(expr.kind, ExprKind::AnonymousClass(_)) => Some(expr),
+        _ => None,
+    }
+}
+
+/// One assignment pass over a body, resolving variables through the
+/// previous pass.
+struct Pass<'p> {
+    previous: &'p StringValues,
+    scope: &'p AnalysisScope,
+    vars: HashMap<String, Option<Vec<String>>>,
+}
+
+impl Pass<'_> {
+    fn assign(&mut self, name: &str, values: Option<Vec<String>>) {
+        let entry = self
+            .vars
+            .entry(name.to_string())
+            .or_insert_with(|| Some(Vec::new()));
+        *entry = match (entry.take(), values) {
+            (Some(known), Some(values)) => union(known, values),
+            _ => None,
+        };
+    }
+
+    /// Give up on every variable written by a destructuring or loop target.
+    fn forget_targets(&mut self, target: &Expr<'_, '_>) {
+        match &target.kind {
+            ExprKind::Variable(name) => self.assign(name.as_str(), None),
+            ExprKind::List(list) => {
+                for element in list.elements.iter() {
+                    self.forget_targets(&element.value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Pass<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Function(_)
+            | StmtKind::Class(_)
+            | StmtKind::Interface(_)
+            | StmtKind::Trait(_)
+            | StmtKind::Enum(_) => return ControlFlow::Continue(()),
+            StmtKind::Foreach(foreach) => {
+                if let Some(key) = &foreach.key {
+                    self.forget_targets(key);
+                }
+                self.forget_targets(&foreach.value);
+            }
+            StmtKind::Global(vars) => {
+                for var in vars.iter() {
+                    self.forget_targets(var);
+                }
+            }
+            StmtKind::StaticVar(vars) => {
+                for var in vars.iter() {
+                    self.assign(&var.name.to_string(), None);
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::Closure(_) | ExprKind::ArrowFunction(_) | ExprKind::AnonymousClass(_) => {
+                return ControlFlow::Continue(());
+            }
+            ExprKind::Assign(assign) => match &assign.target.kind {
+                ExprKind::Variable(name) => {
+                    let values = (assign.op == AssignOp::Assign && !assign.by_ref)
+                        .then(|| self.previous.eval(assign.value, self.scope))
+                        .flatten();
+                    self.assign(name.as_str(), values);
+                }
+                _ => self.forget_targets(assign.target),
+            },
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+/// Every concatenation of a value of `left` with a value of `right`.
+fn product(left: &[String], right: &[String]) -> Option<Vec<String>> {
+    if left.len() * right.len() > MAX_CANDIDATES {
+        return None;
+    }
+    let mut out = Vec::new();
+    for l in left {
+        for r in right {
+            push_unique(&mut out, format!("{l}{r}"));
+        }
+    }
+    Some(out)
+}
+
+fn union(mut left: Vec<String>, right: Vec<String>) -> Option<Vec<String>> {
+    for value in right {
+        push_unique(&mut left, value);
+    }
+    (left.len() <= MAX_CANDIDATES).then_some(left)
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+/// Format `format` like PHP's `sprintf`, supporting `%%`, `%s`, `%d`, and
+/// positional `%1$s`. Returns `None` for any other conversion, a `%d` whose
+/// argument is not an integer, or a missing argument.
+fn sprintf(format: &str, args: &[String]) -> Option<String> {
+    let mut out = String::new();
+    let mut next = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            out.push('%');
+            continue;
+        }
+        let mut digits = String::new();
+        while let Some(d) = chars.next_if(char::is_ascii_digit) {
+            digits.push(d);
+        }
+        let index = if digits.is_empty() {
+            next += 1;
+            next - 1
+        } else {
+            chars.next_if_eq(&'$')?;
+            digits.parse::<usize>().ok()?.checked_sub(1)?
+        };
+        let arg = args.get(index)?;
+        match chars.next()? {
+            's' => out.push_str(arg),
+            'd' => out.push_str(&arg.parse::<i64>().ok()?.to_string()),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
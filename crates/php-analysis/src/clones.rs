@@ -0,0 +1,303 @@
+//! Copy-paste detection over normalized syntax trees.
+//!
+//! [`CloneDetector`] fingerprints every subtree of each file it is given.
+//! The fingerprint hashes the shape of the subtree: node types, statement
+//! and expression kinds, and operators, but not variable names, identifiers,
+//! or literals, so code copied and then renamed or given other constants
+//! still matches. Two
+//! fragments with the same fingerprint and at least
+//! [`min_nodes`](CloneOptions::min_nodes) nodes are a clone pair. A fragment
+//! is a single subtree or a run of consecutive statements in the same block,
+//! so a copied stretch of a function body is found even when the functions
+//! around it differ.
+//!
+//! Only the largest clones are reported: a pair inside another reported
+//! pair, such as the bodies of two cloned methods, is dropped.
+//!
+//! ```
+//! use php_analysis::clones::{CloneDetector, CloneOptions};
+//!
+//! let arena = bumpalo::Bump::new();
+//! let a = "<?php
+//! function total(array $items) {
+//!     $sum = 0;
+//!     foreach ($items as $item) { $sum += $item->price * $item->quantity; }
+//!     return $sum;
+//! }";
+//! let b = "<?php
+//! function cost(array $lines, float $rate) {
+//!     $total = 0;
+//!     foreach ($lines as $line) { $total += $line->cost * $line->count; }
+//!     return $total * $rate;
+//! }";
+//! let mut detector = CloneDetector::new(CloneOptions { min_nodes: 15 });
+//! detector.add_file(&php_rs_parser::parse(&arena, a).program);
+//! detector.add_file(&php_rs_parser::parse(&arena, b).program);
+//! let pairs = detector.finish();
+//!
+//! // The initialization and the loop, not the different `return`s.
+//! assert_eq!(pairs.len(), 1);
+//! let (first, second) = (pairs[0].first, pairs[0].second);
+//! assert_eq!((first.file, second.file), (0, 1));
+//! assert!(a[first.span.to_range()].starts_with("$sum = 0;"));
+//! assert!(a[first.span.to_range()].ends_with("quantity; }"));
+//! assert!(b[second.span.to_range()].starts_with("$total = 0;"));
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::mem::discriminant;
+
+use php_ast::node::AnyNode;
+use php_ast::{ExprKind, Program, Span};
+
+/// Settings for [`CloneDetector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloneOptions {
+    /// The fewest syntax nodes a fragment needs to be reported. A statement
+    /// such as `$total = $price * 2;` is six nodes.
+    pub min_nodes: usize,
+}
+
+impl Default for CloneOptions {
+    fn default() -> Self {
+        Self { min_nodes: 40 }
+    }
+}
+
+/// Where a cloned fragment is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CloneLocation {
+    /// The index [`CloneDetector::add_file`] returned for the file.
+    pub file: usize,
+    pub span: Span,
+}
+
+impl CloneLocation {
+    fn contains(&self, other: &CloneLocation) -> bool {
+        self.file == other.file && self.span.contains_span(other.span)
+    }
+
+    fn overlaps(&self, other: &CloneLocation) -> bool {
+        self.file == other.file && self.span.intersects(other.span)
+    }
+}
+
+/// Two fragments with the same normalized shape. `first` comes before
+/// `second` in file and source order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClonePair {
+    pub first: CloneLocation,
+    pub second: CloneLocation,
+    /// Syntax nodes in each fragment.
+    pub nodes: usize,
+}
+
+/// A subtree's fingerprint: two independently seeded hashes of its shape.
+type Fingerprint = (u64, u64);
+
+/// A subtree or statement run large enough to report.
+#[derive(Debug)]
+struct Fragment {
+    location: CloneLocation,
+    nodes: usize,
+}
+
+/// The fingerprinted statements of one block, for matching runs.
+#[derive(Debug)]
+struct Block {
+    file: usize,
+    statements: Vec<(Fingerprint, usize, Span)>,
+}
+
+/// Collects fragments across files. See the [module documentation](self).
+#[derive(Debug, Default)]
+pub struct CloneDetector {
+    options: CloneOptions,
+    files: usize,
+    subtrees: HashMap<Fingerprint, Vec<Fragment>>,
+    blocks: Vec<Block>,
+}
+
+impl CloneDetector {
+    pub fn new(options: CloneOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Fingerprint the subtrees of `program`. Returns the file index its
+    /// fragments are located by.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+        self.fingerprint(file, AnyNode::Program(program));
+        file
+    }
+
+    /// Fingerprint `node` and its descendants, recording those large enough
+    /// to report. Returns the fingerprint and node count.
+    fn fingerprint(&mut self, file: usize, node: AnyNode<'_, '_, '_>) -> (Fingerprint, usize) {
+        let mut first = DefaultHasher::new();
+        let mut second = DefaultHasher::new();
+        second.write_u8(0x5a);
+        shape(&node, &mut first);
+        shape(&node, &mut second);
+
+        let mut nodes = 1;
+        let mut statements = Vec::new();
+        for child in node.children() {
+            let (fingerprint, size) = self.fingerprint(file, child);
+            fingerprint.hash(&mut first);
+            fingerprint.hash(&mut second);
+            nodes += size;
+            if let AnyNode::Stmt(stmt) = child {
+                statements.push((fingerprint, size, stmt.span));
+            }
+        }
+        if statements.len() > 1 {
+            self.blocks.push(Block { file, statements });
+        }
+
+        let fingerprint = (first.finish(), second.finish());
+        if nodes >= self.options.min_nodes && !matches!(node, AnyNode::Program(_)) {
+            self.subtrees
+                .entry(fingerprint)
+                .or_default()
+                .push(Fragment {
+                    location: CloneLocation {
+                        file,
+                        span: node.span(),
+                    },
+                    nodes,
+                });
+        }
+        (fingerprint, nodes)
+    }
+
+    /// The clone pairs, largest first, then in source order.
+    pub fn finish(self) -> Vec<ClonePair> {
+        let mut candidates: Vec<ClonePair> = Vec::new();
+        for fragments in self.subtrees.values() {
+            for (i, a) in fragments.iter().enumerate() {
+                for b in &fragments[i + 1..] {
+                    candidates.push(pair(a.location, b.location, a.nodes));
+                }
+            }
+        }
+        self.statement_runs(&mut candidates);
+
+        candidates.sort_by(|a, b| {
+            b.nodes
+                .cmp(&a.nodes)
+                .then_with(|| (a.first, a.second).cmp(&(b.first, b.second)))
+        });
+        let mut pairs: Vec<ClonePair> = Vec::new();
+        for candidate in candidates {
+            if candidate.first.overlaps(&candidate.second) {
+                continue;
+            }
+            let covered = pairs.iter().any(|p| {
+                (p.first.contains(&candidate.first) && p.second.contains(&candidate.second))
+                    || (p.first.contains(&candidate.second) && p.second.contains(&candidate.first))
+            });
+            if !covered {
+                pairs.push(candidate);
+            }
+        }
+        pairs
+    }
+
+    /// Add the longest runs of matching consecutive statements, two or more
+    /// long, in any two blocks.
+    fn statement_runs(&self, candidates: &mut Vec<ClonePair>) {
+        let mut occurrences: HashMap<Fingerprint, Vec<(usize, usize)>> = HashMap::new();
+        for (b, block) in self.blocks.iter().enumerate() {
+            for (s, &(fingerprint, ..)) in block.statements.iter().enumerate() {
+                occurrences.entry(fingerprint).or_default().push((b, s));
+            }
+        }
+        let statement = |(b, s): (usize, usize)| self.blocks[b].statements.get(s);
+        for starts in occurrences.values() {
+            for (i, &a) in starts.iter().enumerate() {
+                for &b in &starts[i + 1..] {
+                    // Only start at the beginning of a run.
+                    let extends_back = a.1 > 0
+                        && b.1 > 0
+                        && statement((a.0, a.1 - 1)).map(|s| s.0)
+                            == statement((b.0, b.1 - 1)).map(|s| s.0);
+                    if extends_back {
+                        continue;
+                    }
+                    let mut len = 0;
+                    let mut nodes = 0;
+                    while let (Some(x), Some(y)) =
+                        (statement((a.0, a.1 + len)), statement((b.0, b.1 + len)))
+                    {
+                        if x.0 != y.0 {
+                            break;
+                        }
+                        nodes += x.1;
+                        len += 1;
+                    }
+                    if len < 2 || nodes < self.options.min_nodes {
+                        continue;
+                    }
+                    let run = |(block, start): (usize, usize)| {
+                        let statements = &self.blocks[block].statements;
+                        CloneLocation {
+                            file: self.blocks[block].file,
+                            span: Span::new(
+                                statements[start].2.start,
+                                statements[start + len - 1].2.end,
+                            ),
+                        }
+                    };
+                    candidates.push(pair(run(a), run(b), nodes));
+                }
+            }
+        }
+    }
+}
+
+fn pair(a: CloneLocation, b: CloneLocation, nodes: usize) -> ClonePair {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    ClonePair {
+        first,
+        second,
+        nodes,
+    }
+}
+
+/// Hash what distinguishes `node` from other nodes of its type, leaving out
+/// names and values.
+fn shape(node: &AnyNode<'_, '_, '_>, hasher: &mut DefaultHasher) {
+    node.kind().hash(hasher);
+    match node {
+        AnyNode::Stmt(stmt) => discriminant(&stmt.kind).hash(hasher),
+        AnyNode::Expr(expr) => {
+            // Any scalar literal may stand in for another.
+            if matches!(
+                expr.kind,
+                ExprKind::Int(_) | ExprKind::Float(_) | ExprKind::String { .. } | ExprKind::Bool(_)
+            ) {
+                "literal".hash(hasher);
+                return;
+            }
+            discriminant(&expr.kind).hash(hasher);
+            match &expr.kind {
+                ExprKind::Binary(binary) => discriminant(&binary.op).hash(hasher),
+                ExprKind::UnaryPrefix(unary) => discriminant(&unary.op).hash(hasher),
+                ExprKind::UnaryPostfix(unary) => discriminant(&unary.op).hash(hasher),
+                ExprKind::Assign(assign) => discriminant(&assign.op).hash(hasher),
+                ExprKind::Cast(kind, _) => discriminant(kind).hash(hasher),
+                _ => {}
+            }
+        }
+        AnyNode::ClassMember(member) => discriminant(&member.kind).hash(hasher),
+        AnyNode::TypeHint(hint) => discriminant(&hint.kind).hash(hasher),
+        _ => {}
+    }
+}
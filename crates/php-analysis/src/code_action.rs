@@ -0,0 +1,339 @@
+//! Code actions: the fixes and refactorings available at a span of a file.
+//!
+//! A [`CodeActionProvider`] turns a parsed file into [`CodeAction`]s — a
+//! title, the diagnostics the action resolves, and the [`TextEdit`]s that
+//! perform it. [`code_actions`] asks every provider for the actions touching
+//! a span, which is what an editor's light bulb shows; [`fix_all`] collects
+//! the edits of every quick fix in the file, which is what a `--fix` run
+//! applies.
+//!
+//! [`default_providers`] covers:
+//!
+//! - [`ParseErrorFixes`] — inserts a missing `;` or closing delimiter the
+//!   parser recovered from;
+//! - [`LooseComparisonFixes`] — the strict forms of
+//!   [`loose_comparison`](crate::loose_comparison) findings that have a safe
+//!   fix;
+//! - [`Refactoring`] — any [`Codemod`], offered where its edits are:
+//!   [`ImplicitNullable`] and [`SortImports`].
+//!
+//! ```
+//! use php_analysis::code_action::{code_actions, default_providers, fix_all};
+//! use php_analysis::codemod::apply_edits;
+//! use php_ast::Span;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php\n$n = count($a)\nif (strlen($s) == 0) {}\n";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let providers = default_providers();
+//!
+//! let at = src.find("==").unwrap() as u32;
+//! let actions = code_actions(&result, Span::new(at, at), &providers);
+//! assert_eq!(actions[0].title, "Use `===`");
+//! assert_eq!(actions[0].diagnostics[0].code.as_deref(), Some("loose-equality"));
+//!
+//! let fixed = apply_edits(src, &fix_all(&result, &providers));
+//! assert_eq!(fixed, "<?php\n$n = count($a);\nif (strlen($s) === 0) {}\n");
+//! ```
+
+use php_ast::Span;
+use php_lexer::TokenKind;
+use php_rs_parser::diagnostics::ParseError;
+use php_rs_parser::ParseResult;
+
+use crate::codemod::implicit_nullable::ImplicitNullable;
+use crate::codemod::sort_imports::SortImports;
+use crate::codemod::{Codemod, TextEdit};
+use crate::loose_comparison::{loose_comparisons, LooseComparisonOptions};
+
+/// What a code action does, mirroring the LSP `CodeActionKind`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeActionKind {
+    /// Resolves a diagnostic.
+    QuickFix,
+    /// Rewrites code without changing what it does.
+    Refactor,
+}
+
+impl CodeActionKind {
+    /// The LSP kind string, e.g. `quickfix`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CodeActionKind::QuickFix => "quickfix",
+            CodeActionKind::Refactor => "refactor.rewrite",
+        }
+    }
+}
+
+impl std::fmt::Display for CodeActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A diagnostic a code action resolves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub span: Span,
+    /// What reported it, e.g. `php-rs-parser` or `php-analysis`.
+    pub source: &'static str,
+    /// The rule, for lint findings, e.g. `loose-equality`.
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn parse_error(error: &ParseError) -> Self {
+        let diagnostic = error.to_diagnostic();
+        Self {
+            span: diagnostic.span,
+            source: "php-rs-parser",
+            code: diagnostic.code.map(String::from),
+            message: diagnostic.message,
+        }
+    }
+}
+
+/// One fix or refactoring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeAction {
+    pub title: String,
+    pub kind: CodeActionKind,
+    /// The diagnostics the action resolves; empty for a refactoring.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Edits against the source the file was parsed from.
+    pub edits: Vec<TextEdit>,
+}
+
+impl CodeAction {
+    /// A quick fix resolving `diagnostic`.
+    pub fn quick_fix(
+        title: impl Into<String>,
+        diagnostic: Diagnostic,
+        edits: Vec<TextEdit>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            kind: CodeActionKind::QuickFix,
+            diagnostics: vec![diagnostic],
+            edits,
+        }
+    }
+
+    /// The span the action applies to: that of its first diagnostic, or
+    /// else the extent of its edits.
+    pub fn span(&self) -> Span {
+        match self.diagnostics.first() {
+            Some(diagnostic) => diagnostic.span,
+            None => self
+                .edits
+                .iter()
+                .map(|edit| edit.span)
+                .reduce(Span::merge)
+                .unwrap_or(Span::DUMMY),
+        }
+    }
+}
+
+/// A source of code actions.
+pub trait CodeActionProvider {
+    /// Short identifier, e.g. `parse-errors`.
+    fn name(&self) -> &'static str;
+
+    /// The actions for the whole of `result`, in source order.
+    fn actions(&self, result: &ParseResult<'_, '_>) -> Vec<CodeAction>;
+}
+
+/// The actions of every provider touching `span`, an empty span included,
+/// in source order. Actions at the same position keep provider order.
+pub fn code_actions(
+    result: &ParseResult<'_, '_>,
+    span: Span,
+    providers: &[Box<dyn CodeActionProvider>],
+) -> Vec<CodeAction> {
+    let mut actions: Vec<CodeAction> = providers
+        .iter()
+        .flat_map(|provider| provider.actions(result))
+        .filter(|action| touches(action.span(), span))
+        .collect();
+    actions.sort_by_key(|action| action.span().start);
+    actions
+}
+
+/// The edits of every quick fix in `result`, for [`apply_edits`]. Where two
+/// fixes overlap, the first one wins.
+///
+/// [`apply_edits`]: crate::codemod::apply_edits
+pub fn fix_all(
+    result: &ParseResult<'_, '_>,
+    providers: &[Box<dyn CodeActionProvider>],
+) -> Vec<TextEdit> {
+    providers
+        .iter()
+        .flat_map(|provider| provider.actions(result))
+        .filter(|action| action.kind == CodeActionKind::QuickFix)
+        .flat_map(|action| action.edits)
+        .collect()
+}
+
+/// Parse-error fixes, loose-comparison fixes, and the implicit-nullable and
+/// import-sorting refactorings.
+pub fn default_providers() -> Vec<Box<dyn CodeActionProvider>> {
+    vec![
+        Box::new(ParseErrorFixes),
+        Box::new(LooseComparisonFixes::default()),
+        Box::new(Refactoring::new(
+            "Make nullable types explicit",
+            ImplicitNullable,
+        )),
+        Box::new(Refactoring::new("Sort imports", SortImports::default())),
+    ]
+}
+
+/// Whether `a` and `b` overlap or meet, so a cursor at either end of a span
+/// touches it.
+fn touches(a: Span, b: Span) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+/// Inserts the `;` or closing delimiter the parser reported missing.
+///
+/// The edit goes after the code preceding the error, so `$a = 1⏎$b` gets
+/// its `;` at the end of the first line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseErrorFixes;
+
+impl CodeActionProvider for ParseErrorFixes {
+    fn name(&self) -> &'static str {
+        "parse-errors"
+    }
+
+    fn actions(&self, result: &ParseResult<'_, '_>) -> Vec<CodeAction> {
+        let source = result.source;
+        result
+            .errors
+            .iter()
+            .filter_map(|error| {
+                let (text, at) = match error {
+                    ParseError::MissingSemicolon { span, .. } => (";", span.start),
+                    ParseError::ExpectedAfter { expected, span, .. } => {
+                        (closing(expected)?, end_before(source, *span))
+                    }
+                    // Only where the missing token plainly belongs, as in
+                    // `foo($a;`, not before some other misplaced token.
+                    ParseError::Expected {
+                        expected,
+                        found:
+                            TokenKind::Semicolon
+                            | TokenKind::RightParen
+                            | TokenKind::RightBracket
+                            | TokenKind::RightBrace
+                            | TokenKind::CloseTag
+                            | TokenKind::Eof,
+                        span,
+                    } => (closing(expected)?, end_before(source, *span)),
+                    ParseError::UnclosedDelimiter {
+                        opened_at, span, ..
+                    } => {
+                        let close = match source.as_bytes().get(opened_at.start as usize)? {
+                            b'(' => ")",
+                            b'[' => "]",
+                            b'{' => "}",
+                            _ => return None,
+                        };
+                        (close, end_before(source, *span))
+                    }
+                    _ => return None,
+                };
+                Some(CodeAction::quick_fix(
+                    format!("Insert `{text}`"),
+                    Diagnostic::parse_error(error),
+                    vec![TextEdit::insert(at, text)],
+                ))
+            })
+            .collect()
+    }
+}
+
+/// The token text of an `expected` that a fix can insert.
+fn closing(expected: &str) -> Option<&'static str> {
+    match expected {
+        "';'" => Some(";"),
+        "')'" => Some(")"),
+        "']'" => Some("]"),
+        "'}'" => Some("}"),
+        _ => None,
+    }
+}
+
+/// The end of the code before `span`: a missing token goes after the
+/// previous one, not before the next.
+fn end_before(source: &str, span: Span) -> u32 {
+    source[..span.start as usize].trim_end().len() as u32
+}
+
+/// Rewrites loose comparisons to their strict forms where that cannot
+/// change the result.
+#[derive(Debug, Clone, Default)]
+pub struct LooseComparisonFixes {
+    pub options: LooseComparisonOptions,
+}
+
+impl CodeActionProvider for LooseComparisonFixes {
+    fn name(&self) -> &'static str {
+        "loose-comparison"
+    }
+
+    fn actions(&self, result: &ParseResult<'_, '_>) -> Vec<CodeAction> {
+        loose_comparisons(&result.program, result.source, &self.options)
+            .into_iter()
+            .filter_map(|found| {
+                let fix = found.fix.clone()?;
+                let title = match fix.replacement.as_str() {
+                    ", true" => "Pass `$strict = true`".to_string(),
+                    strict => format!("Use `{strict}`"),
+                };
+                let diagnostic = Diagnostic {
+                    span: found.span,
+                    source: "php-analysis",
+                    code: Some(found.rule.to_string()),
+                    message: found.to_string(),
+                };
+                Some(CodeAction::quick_fix(title, diagnostic, vec![fix]))
+            })
+            .collect()
+    }
+}
+
+/// Offers a [`Codemod`] as a refactoring wherever it would edit, one action
+/// per edit.
+#[derive(Debug, Clone)]
+pub struct Refactoring<C> {
+    pub title: &'static str,
+    pub codemod: C,
+}
+
+impl<C: Codemod> Refactoring<C> {
+    pub fn new(title: &'static str, codemod: C) -> Self {
+        Self { title, codemod }
+    }
+}
+
+impl<C: Codemod> CodeActionProvider for Refactoring<C> {
+    fn name(&self) -> &'static str {
+        self.codemod.name()
+    }
+
+    fn actions(&self, result: &ParseResult<'_, '_>) -> Vec<CodeAction> {
+        self.codemod
+            .edits(&result.program, result.source)
+            .into_iter()
+            .map(|edit| CodeAction {
+                title: self.title.to_string(),
+                kind: CodeActionKind::Refactor,
+                diagnostics: Vec::new(),
+                edits: vec![edit],
+            })
+            .collect()
+    }
+}
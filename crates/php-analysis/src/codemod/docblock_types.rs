@@ -0,0 +1,608 @@
+//! Turn `@param` and `@return` docblock types into native type hints.
+//!
+//! Where a function or method parameter has no type hint and its `@param`
+//! tag names a type PHP can declare, the type is written into the
+//! signature; likewise a missing return type is taken from `@return`:
+//!
+//! | Docblock                | Before             | After                        |
+//! |-------------------------|--------------------|------------------------------|
+//! | `@param int $n`         | `function f($n)`   | `function f(int $n)`         |
+//! | `@param string\|null $s` | `function f($s)`   | `function f(?string $s)`     |
+//! | `@param Foo $x`         | `($x = null)`      | `(?Foo $x = null)`           |
+//! | `@return self`          | `function make()`  | `function make(): self`      |
+//!
+//! Only exact equivalents are used. Generics, array shapes, `int[]`,
+//! `list<T>`, `positive-int`, `$this`, `resource`, template parameters, and
+//! anything the [`target`](DocblockTypes::target) version cannot declare
+//! are left in the docblock, as are parameters whose default value the type
+//! would reject. Signatures are changed one at a time: a method that
+//! overrides or is overridden by an untyped one must be migrated together
+//! with it, which this codemod does not check.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_class_member, walk_enum_member, walk_expr, walk_stmt, Visitor};
+use php_ast::*;
+use php_lexer::{Lexer, TokenKind};
+use php_rs_parser::PhpVersion;
+use phpdoc_parser::find_tags;
+
+use super::implicit_nullable::is_null;
+use super::{Codemod, TextEdit};
+use crate::directives::split_type;
+use crate::hover::ident_span;
+
+/// Adds native parameter and return types from `@param` and `@return`
+/// tags, and with [`remove_tags`](Self::remove_tags) drops the tags that
+/// then say nothing more than the signature.
+///
+/// ```
+/// use php_analysis::codemod::docblock_types::DocblockTypes;
+/// use php_analysis::codemod::Codemod;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// /**
+///  * @param int $count
+///  * @param string[] $names
+///  * @return bool
+///  */
+/// function f($count, $names) {}";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let codemod = DocblockTypes { remove_tags: true, ..DocblockTypes::default() };
+/// assert_eq!(
+///     codemod.apply(&result.program, src),
+///     "<?php
+/// /**
+///  * @param string[] $names
+///  */
+/// function f(int $count, $names): bool {}",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DocblockTypes {
+    /// The PHP version the code runs on; types it cannot declare, such as
+    /// unions before 8.0, stay in the docblock.
+    pub target: PhpVersion,
+    /// Remove the `@param` and `@return` tags whose type was moved into the
+    /// signature, unless they also carry a description. A docblock left
+    /// empty is removed entirely.
+    pub remove_tags: bool,
+}
+
+impl Codemod for DocblockTypes {
+    fn name(&self) -> &'static str {
+        "docblock-types"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        let mut collector = Collector {
+            options: *self,
+            source,
+            scopes: Vec::new(),
+            edits: Vec::new(),
+        };
+        let _ = collector.visit_program(program);
+        collector.edits
+    }
+}
+
+/// The class-like declaration a function is declared in.
+#[derive(Debug, Default)]
+struct ClassScope {
+    /// Whether `parent` names a class.
+    has_parent: bool,
+    /// `@template` parameters of the class, which look like class names.
+    templates: Vec<String>,
+}
+
+/// A function or method being migrated.
+struct Signature<'a, 'arena, 'src> {
+    name: Ident<'src>,
+    params: &'a [Param<'arena, 'src>],
+    return_type: Option<&'a TypeHint<'arena, 'src>>,
+    doc_comment: Option<&'a Comment<'src>>,
+}
+
+struct Collector<'s> {
+    options: DocblockTypes,
+    source: &'s str,
+    /// Innermost last; `None` for a function, where `self` means nothing.
+    scopes: Vec<Option<ClassScope>>,
+    edits: Vec<TextEdit>,
+}
+
+impl Collector<'_> {
+    fn enter_class(&mut self, doc_comment: Option<&Comment<'_>>, has_parent: bool) {
+        let templates = doc_comment
+            .map(|doc| templates(&phpdoc_parser::parse(doc.text)))
+            .unwrap_or_default();
+        self.scopes.push(Some(ClassScope {
+            has_parent,
+            templates,
+        }));
+    }
+
+    fn migrate(&mut self, signature: Signature<'_, '_, '_>) {
+        let Some(comment) = signature.doc_comment else {
+            return;
+        };
+        let doc = phpdoc_parser::parse(comment.text);
+        let class = self.scopes.last().and_then(Option::as_ref);
+        let mut templates = templates(&doc);
+        if let Some(class) = class {
+            templates.extend(class.templates.iter().cloned());
+        }
+        let context = Context {
+            target: self.options.target,
+            class,
+            templates: &templates,
+        };
+
+        let mut removed = Vec::new();
+        let mut edits = Vec::new();
+        for (i, tag) in doc.tags.iter().enumerate() {
+            let Some(body) = phpdoc_parser::body_text(&tag.body) else {
+                continue;
+            };
+            let (ty, rest) = split_tag(body.trim());
+            let migrated = match tag.name.as_str() {
+                "param" => {
+                    let (variable, description) =
+                        rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                    let name = variable
+                        .trim_start_matches('&')
+                        .trim_start_matches("...")
+                        .strip_prefix('$');
+                    let mut params = signature
+                        .params
+                        .iter()
+                        .filter(|p| Some(p.name.or_error().trim_start_matches('$')) == name);
+                    let param = match (params.next(), params.next()) {
+                        (Some(param), None) => param,
+                        _ => continue,
+                    };
+                    let documented = find_tags(&doc, "param").into_iter().filter(|t| {
+                        phpdoc_parser::body_text(&t.body).is_some_and(|b| {
+                            let (_, rest) = split_tag(b.trim());
+                            rest.split_whitespace().next() == Some(variable)
+                        })
+                    });
+                    if documented.count() > 1 {
+                        continue;
+                    }
+                    self.param(param, ty, &context).map(|edit| {
+                        edits.push(edit);
+                        description.trim().is_empty()
+                    })
+                }
+                "return" if find_tags(&doc, "return").len() > 1 => None,
+                "return" => self.return_type(&signature, ty, &context).map(|edit| {
+                    edits.push(edit);
+                    rest.trim().is_empty()
+                }),
+                _ => None,
+            };
+            if migrated == Some(true) {
+                removed.push(i);
+            }
+        }
+        if self.options.remove_tags {
+            edits.extend(remove_tags(comment, &doc, &removed, self.source));
+        }
+        self.edits.extend(edits);
+    }
+
+    /// The edit typing `param` as the docblock type `doc`.
+    fn param(&self, param: &Param<'_, '_>, doc: &str, context: &Context<'_>) -> Option<TextEdit> {
+        if param.type_hint.is_some() {
+            return None;
+        }
+        let mut ty = native_type(doc, Position::Param, context)?;
+        match &param.default {
+            Some(default) if is_null(default) && !ty.admits_null() => {
+                ty.types.push("null".to_string());
+                ty.check(Position::Param, context)?;
+            }
+            Some(default) if !is_null(default) && !ty.accepts(default) => return None,
+            _ => {}
+        }
+        let name = ident_span(self.source, param.name)?;
+        // Before the `&`, `...`, and `$` that precede the name.
+        let mut offset = name.start as usize;
+        if self.source.as_bytes().get(offset) != Some(&b'$') {
+            offset = offset.checked_sub(1)?;
+        }
+        let head = &self.source[..offset];
+        let mut rest = head;
+        for marker in ["...", "&"] {
+            if let Some(before) = rest.trim_end().strip_suffix(marker) {
+                rest = before;
+                offset = rest.len();
+            }
+        }
+        Some(TextEdit::insert(offset as u32, format!("{} ", ty.render())))
+    }
+
+    /// The edit adding the docblock type `doc` as the return type.
+    fn return_type(
+        &self,
+        signature: &Signature<'_, '_, '_>,
+        doc: &str,
+        context: &Context<'_>,
+    ) -> Option<TextEdit> {
+        if signature.return_type.is_some() {
+            return None;
+        }
+        let name = signature.name.as_str()?;
+        // Constructors and destructors cannot declare a return type.
+        if ["__construct", "__destruct"]
+            .iter()
+            .any(|magic| name.eq_ignore_ascii_case(magic))
+        {
+            return None;
+        }
+        let ty = native_type(doc, Position::Return, context)?;
+        let from = match signature.params.last() {
+            Some(param) => param.span.end,
+            None => ident_span(self.source, signature.name)?.end,
+        };
+        let offset = params_end(self.source, from)?;
+        Some(TextEdit::insert(offset, format!(": {}", ty.render())))
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Function(func) => {
+                self.scopes.push(None);
+                self.migrate(Signature {
+                    name: func.name,
+                    params: &func.params,
+                    return_type: func.return_type.as_ref(),
+                    doc_comment: func.doc_comment.as_ref(),
+                });
+            }
+            StmtKind::Class(class) => {
+                self.enter_class(class.doc_comment.as_ref(), class.extends.is_some())
+            }
+            StmtKind::Interface(interface) => {
+                self.enter_class(interface.doc_comment.as_ref(), false)
+            }
+            StmtKind::Trait(class) => self.enter_class(class.doc_comment.as_ref(), false),
+            StmtKind::Enum(class) => self.enter_class(class.doc_comment.as_ref(), false),
+            _ => return walk_stmt(self, stmt),
+        }
+        let result = walk_stmt(self, stmt);
+        self.scopes.pop();
+        result
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        let ExprKind::AnonymousClass(class) = &expr.kind else {
+            return walk_expr(self, expr);
+        };
+        self.enter_class(class.doc_comment.as_ref(), class.extends.is_some());
+        let result = walk_expr(self, expr);
+        self.scopes.pop();
+        result
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        if let ClassMemberKind::Method(method) = &member.kind {
+            self.migrate(method_signature(method));
+        }
+        walk_class_member(self, member)
+    }
+
+    fn visit_enum_member(&mut self, member: &EnumMember<'arena, 'src>) -> ControlFlow<()> {
+        if let EnumMemberKind::Method(method) = &member.kind {
+            self.migrate(method_signature(method));
+        }
+        walk_enum_member(self, member)
+    }
+}
+
+fn method_signature<'a, 'arena, 'src>(
+    method: &'a MethodDecl<'arena, 'src>,
+) -> Signature<'a, 'arena, 'src> {
+    Signature {
+        name: method.name,
+        params: &method.params,
+        return_type: method.return_type.as_ref(),
+        doc_comment: method.doc_comment.as_ref(),
+    }
+}
+
+/// Split the type off a tag body. A by-reference `&$name` is not part of
+/// an intersection type.
+fn split_tag(body: &str) -> (&str, &str) {
+    let (ty, rest) = split_type(body);
+    let by_ref = ty.match_indices('&').map(|(at, _)| at).find(|&at| {
+        ty[at + 1..].starts_with(['$', '.']) && ty[..at].ends_with(char::is_whitespace)
+    });
+    match by_ref {
+        Some(at) => (ty[..at].trim_end(), &body[at..]),
+        None => (ty, rest),
+    }
+}
+
+/// The names declared by `@template` tags and their variants.
+fn templates(doc: &phpdoc_parser::PhpDoc) -> Vec<String> {
+    doc.tags
+        .iter()
+        .filter(|tag| {
+            let name = tag
+                .name
+                .trim_start_matches("psalm-")
+                .trim_start_matches("phpstan-");
+            name.starts_with("template")
+        })
+        .filter_map(|tag| phpdoc_parser::body_text(&tag.body))
+        .filter_map(|body| body.split_whitespace().next().map(str::to_string))
+        .collect()
+}
+
+/// The end of the `)` closing a parameter list, scanning from `from`: the
+/// end of the last parameter, or of the function name when there is none.
+fn params_end(source: &str, from: u32) -> Option<u32> {
+    let mut lexer = Lexer::new_at(source, from as usize);
+    let mut depth = 0usize;
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::LeftParen => depth += 1,
+            TokenKind::RightParen if depth <= 1 => return Some(token.span.end),
+            TokenKind::RightParen => depth -= 1,
+            TokenKind::LeftBrace | TokenKind::Semicolon | TokenKind::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Param,
+    Return,
+}
+
+struct Context<'a> {
+    target: PhpVersion,
+    class: Option<&'a ClassScope>,
+    templates: &'a [String],
+}
+
+/// A native type as the canonical names of its members, `null` included,
+/// in docblock order.
+#[derive(Debug)]
+struct NativeType {
+    types: Vec<String>,
+}
+
+impl NativeType {
+    fn has(&self, name: &str) -> bool {
+        self.types.iter().any(|t| t == name)
+    }
+
+    fn admits_null(&self) -> bool {
+        self.has("null") || self.has("mixed")
+    }
+
+    /// Whether a parameter of this type may default to the constant
+    /// scalar or array `value`. Other defaults are not checked and count as
+    /// rejected.
+    fn accepts(&self, value: &Expr<'_, '_>) -> bool {
+        let accepted: &[&str] = match &value.kind {
+            ExprKind::Int(_) => &["int", "float"],
+            ExprKind::Float(_) => &["float"],
+            ExprKind::String { .. } => &["string"],
+            ExprKind::Bool(true) => &["bool", "true"],
+            ExprKind::Bool(false) => &["bool", "false"],
+            ExprKind::Array(_) => &["array", "iterable"],
+            ExprKind::UnaryPrefix(unary)
+                if matches!(unary.op, UnaryPrefixOp::Negate | UnaryPrefixOp::Plus) =>
+            {
+                return matches!(unary.operand.kind, ExprKind::Int(_) | ExprKind::Float(_))
+                    && self.accepts(unary.operand);
+            }
+            ExprKind::Parenthesized(inner) => return self.accepts(inner),
+            _ => return false,
+        };
+        self.has("mixed") || accepted.iter().any(|t| self.has(t))
+    }
+
+    /// Check that the type can be declared at `position` in `context`.
+    fn check(&self, position: Position, context: &Context<'_>) -> Option<()> {
+        let at_least = |version: PhpVersion| (context.target >= version).then_some(());
+        let types = &self.types;
+        for (i, ty) in types.iter().enumerate() {
+            if types[..i].iter().any(|t| t.eq_ignore_ascii_case(ty)) {
+                return None;
+            }
+        }
+        let non_null = types.iter().filter(|t| *t != "null").count();
+        for ty in types {
+            match ty.as_str() {
+                "void" | "never" | "mixed" if types.len() > 1 => return None,
+                "void" | "never" | "static" if position == Position::Param => return None,
+                "self" | "static" if context.class.is_none() => return None,
+                "parent" if !context.class.is_some_and(|c| c.has_parent) => return None,
+                _ => {}
+            }
+            match ty.as_str() {
+                "mixed" | "static" => at_least(PhpVersion::Php80)?,
+                "never" => at_least(PhpVersion::Php81)?,
+                "true" => at_least(PhpVersion::Php82)?,
+                "false" if non_null == 1 => at_least(PhpVersion::Php82)?,
+                "false" => at_least(PhpVersion::Php80)?,
+                "null" if non_null == 0 => at_least(PhpVersion::Php82)?,
+                _ => {}
+            }
+        }
+        if non_null > 1 {
+            at_least(PhpVersion::Php80)?;
+        }
+        // Redundant members are compile errors.
+        let classes = types.iter().any(|t| !is_keyword(t));
+        let conflicts = (self.has("bool") && (self.has("true") || self.has("false")))
+            || (self.has("true") && self.has("false"))
+            || (self.has("object") && classes)
+            || (self.has("iterable") && self.has("array"));
+        (!conflicts).then_some(())
+    }
+
+    fn render(&self) -> String {
+        match self.types.as_slice() {
+            [ty, null] | [null, ty] if null == "null" && ty != "null" => format!("?{ty}"),
+            types => types.join("|"),
+        }
+    }
+}
+
+/// Native types and pseudo-types with a reserved name.
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "int"
+            | "float"
+            | "string"
+            | "bool"
+            | "array"
+            | "callable"
+            | "iterable"
+            | "object"
+            | "mixed"
+            | "void"
+            | "never"
+            | "null"
+            | "false"
+            | "true"
+            | "self"
+            | "static"
+            | "parent"
+    )
+}
+
+/// The native type spelled by the docblock type `doc`, if it has one that
+/// can be declared at `position`.
+fn native_type(doc: &str, position: Position, context: &Context<'_>) -> Option<NativeType> {
+    let (nullable, doc) = match doc.strip_prefix('?') {
+        Some(inner) => (true, inner),
+        None => (false, doc),
+    };
+    let mut types = doc
+        .split('|')
+        .map(|part| native_member(part, context))
+        .collect::<Option<Vec<_>>>()?;
+    if nullable {
+        if types.len() > 1 {
+            return None;
+        }
+        types.push("null".to_string());
+    }
+    let ty = NativeType { types };
+    ty.check(position, context)?;
+    Some(ty)
+}
+
+/// The canonical native spelling of one member of a docblock union.
+fn native_member(doc: &str, context: &Context<'_>) -> Option<String> {
+    let is_ident = |segment: &str| {
+        let mut chars = segment.chars();
+        chars
+            .next()
+            .is_some_and(|c| c == '_' || c.is_ascii_alphabetic() || !c.is_ascii())
+            && chars.all(|c| c == '_' || c.is_ascii_alphanumeric() || !c.is_ascii())
+    };
+    let qualified = doc.strip_prefix('\\').unwrap_or(doc);
+    if !qualified.split('\\').all(is_ident) {
+        return None;
+    }
+    if doc.contains('\\') {
+        return Some(doc.to_string());
+    }
+    let keyword = match doc.to_ascii_lowercase().as_str() {
+        "integer" => "int",
+        "double" => "float",
+        "boolean" => "bool",
+        name if is_keyword(name) => return Some(name.to_string()),
+        // Docblock pseudo-types that are not class names.
+        "resource" | "scalar" | "numeric" | "number" | "list" | "empty" | "callback"
+        | "noreturn" | "real" | "this" => return None,
+        _ if context.templates.iter().any(|t| t == doc) => return None,
+        _ => return Some(doc.to_string()),
+    };
+    Some(keyword.to_string())
+}
+
+/// Edits removing the tags at `removed` from `comment`, or the whole
+/// comment when nothing else is left in it. Tags sharing a line with other
+/// text stay.
+fn remove_tags(
+    comment: &Comment<'_>,
+    doc: &phpdoc_parser::PhpDoc,
+    removed: &[usize],
+    source: &str,
+) -> Vec<TextEdit> {
+    if removed.is_empty() {
+        return Vec::new();
+    }
+    let base = comment.span.start;
+    if doc.summary.is_none() && doc.description.is_none() && removed.len() == doc.tags.len() {
+        // Up to the declaration, so its indentation stays.
+        let rest = &source[comment.span.end as usize..];
+        let end = comment.span.end + (rest.len() - rest.trim_start().len()) as u32;
+        return vec![TextEdit::replace(Span::new(base, end), "")];
+    }
+
+    let text = comment.text;
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        lines.push((start, line));
+        start += line.len();
+    }
+    let line_of = |offset: usize| lines.iter().rposition(|&(start, _)| start <= offset);
+    let mut drop = vec![false; lines.len()];
+    for &i in removed {
+        let span = doc.tags[i].span;
+        let span = span.start as usize..span.end as usize;
+        let Some(line) = line_of(span.start) else {
+            continue;
+        };
+        let (start, text) = lines[line];
+        let before = &text[..span.start - start];
+        let after = text.get(span.end - start..).unwrap_or_default();
+        if before.trim().trim_start_matches('*').trim().is_empty() && after.trim().is_empty() {
+            drop[line] = true;
+        }
+    }
+    // Blank lines that only separated the removed tags from the prose.
+    if drop.iter().filter(|d| **d).count() == doc.tags.len() {
+        let blank = |line: &str| line.trim().trim_start_matches('*').trim().is_empty();
+        let mut line = lines.len().saturating_sub(1);
+        while line > 0 && (drop[line - 1] || blank(lines[line - 1].1)) {
+            drop[line - 1] = true;
+            line -= 1;
+        }
+    }
+
+    let mut edits = Vec::new();
+    let mut line = 0;
+    while line < lines.len() {
+        if !drop[line] {
+            line += 1;
+            continue;
+        }
+        let first = line;
+        while line < lines.len() && drop[line] {
+            line += 1;
+        }
+        let start = base + lines[first].0 as u32;
+        let (last_start, last) = lines[line - 1];
+        let end = base + (last_start + last.len()) as u32;
+        edits.push(TextEdit::replace(Span::new(start, end), ""));
+    }
+    edits
+}
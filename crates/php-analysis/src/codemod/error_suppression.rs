@@ -0,0 +1,82 @@
+//! Remove `@` error-suppression operators.
+//!
+//! Only the `@` is deleted, together with any whitespace between it and the
+//! operand; the silenced expression is left untouched. A filter restricts the
+//! rewrite to some [`SuppressedTarget`]s, e.g. only calls to `unlink()`.
+
+use php_ast::{Program, Span};
+
+use super::{Codemod, TextEdit};
+use crate::suppressions::{suppressions, SuppressedTarget};
+
+type TargetFilter = Box<dyn Fn(&SuppressedTarget) -> bool>;
+
+/// Deletes `@` operators whose target passes the filter (all of them by
+/// default).
+///
+/// ```
+/// use php_analysis::codemod::error_suppression::RemoveErrorSuppression;
+/// use php_analysis::codemod::Codemod;
+/// use php_analysis::suppressions::SuppressedTarget;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php @unlink($tmp); $v = @ $arr['k'];";
+/// let result = php_rs_parser::parse(&arena, src);
+/// assert_eq!(
+///     RemoveErrorSuppression::default().apply(&result.program, src),
+///     "<?php unlink($tmp); $v = $arr['k'];",
+/// );
+///
+/// let only_unlink = RemoveErrorSuppression::matching(|target| {
+///     matches!(target, SuppressedTarget::Function(name) if name.eq_ignore_ascii_case("unlink"))
+/// });
+/// assert_eq!(
+///     only_unlink.apply(&result.program, src),
+///     "<?php unlink($tmp); $v = @ $arr['k'];",
+/// );
+/// ```
+#[derive(Default)]
+pub struct RemoveErrorSuppression {
+    filter: Option<TargetFilter>,
+}
+
+impl RemoveErrorSuppression {
+    /// Only remove `@` operators whose target satisfies `filter`.
+    pub fn matching(filter: impl Fn(&SuppressedTarget) -> bool + 'static) -> Self {
+        Self {
+            filter: Some(Box::new(filter)),
+        }
+    }
+}
+
+impl std::fmt::Debug for RemoveErrorSuppression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoveErrorSuppression")
+            .field("filtered", &self.filter.is_some())
+            .finish()
+    }
+}
+
+impl Codemod for RemoveErrorSuppression {
+    fn name(&self) -> &'static str {
+        "remove-error-suppression"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        suppressions(program)
+            .into_iter()
+            .filter(|s| self.filter.as_ref().is_none_or(|filter| filter(&s.target)))
+            .map(|s| {
+                let gap = source
+                    .get(s.operator_span.end as usize..s.expr_span.start as usize)
+                    .unwrap_or("");
+                let end = if gap.trim().is_empty() {
+                    s.expr_span.start
+                } else {
+                    s.operator_span.end
+                };
+                TextEdit::replace(Span::new(s.operator_span.start, end), "")
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+//! Make implicitly nullable parameters explicit.
+//!
+//! A parameter whose type does not admit `null` but whose default is `null`
+//! is implicitly nullable. PHP 8.4 deprecates this; the fix is to spell the
+//! nullability out in the type:
+//!
+//! | Before                  | After                      |
+//! |-------------------------|----------------------------|
+//! | `Foo $x = null`         | `?Foo $x = null`           |
+//! | `int\|string $x = null` | `int\|string\|null $x = null` |
+//! | `A&B $x = null`         | `(A&B)\|null $x = null`    |
+//!
+//! Types that already admit `null` (`?T`, `T|null`, `mixed`, `null`) and
+//! promoted constructor properties, where an implicitly nullable type is a
+//! compile error rather than a deprecation, are left alone.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_param, Visitor};
+use php_ast::{Expr, ExprKind, NameKind, Param, Program, TypeHintKind};
+
+use super::{Codemod, TextEdit};
+use crate::properties::is_promoted;
+
+/// Rewrites `T $x = null` to `?T $x = null` in functions, methods, closures,
+/// and arrow functions.
+///
+/// ```
+/// use php_analysis::codemod::implicit_nullable::ImplicitNullable;
+/// use php_analysis::codemod::Codemod;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php function f(Foo $a = null, int|string $b = NULL, ?Bar $c = null) {}";
+/// let result = php_rs_parser::parse(&arena, src);
+/// assert_eq!(
+///     ImplicitNullable.apply(&result.program, src),
+///     "<?php function f(?Foo $a = null, int|string|null $b = NULL, ?Bar $c = null) {}",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImplicitNullable;
+
+impl Codemod for ImplicitNullable {
+    fn name(&self) -> &'static str {
+        "implicit-nullable"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, _source: &str) -> Vec<TextEdit> {
+        let mut collector = Collector { edits: Vec::new() };
+        let _ = collector.visit_program(program);
+        collector.edits
+    }
+}
+
+struct Collector {
+    edits: Vec<TextEdit>,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector {
+    fn visit_param(&mut self, param: &Param<'arena, 'src>) -> ControlFlow<()> {
+        if let (Some(type_hint), Some(default)) = (&param.type_hint, &param.default) {
+            if !is_promoted(param) && is_null(default) && !type_hint.admits_null() {
+                let span = type_hint.span;
+                match &type_hint.kind {
+                    TypeHintKind::Union(_) => self.edits.push(TextEdit::insert(span.end, "|null")),
+                    TypeHintKind::Intersection(_) => {
+                        self.edits.push(TextEdit::insert(span.start, "("));
+                        self.edits.push(TextEdit::insert(span.end, ")|null"));
+                    }
+                    _ => self.edits.push(TextEdit::insert(span.start, "?")),
+                }
+            }
+        }
+        walk_param(self, param)
+    }
+}
+
+/// `true` for a `null` literal, in any case and optionally written `\null`.
+pub(crate) fn is_null(expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::Null => true,
+        ExprKind::ConstFetch(name) => {
+            matches!(
+                name.kind(),
+                NameKind::Unqualified | NameKind::FullyQualified
+            ) && name.join_parts().eq_ignore_ascii_case("null")
+        }
+        ExprKind::Parenthesized(inner) => is_null(inner),
+        _ => false,
+    }
+}
@@ -0,0 +1,94 @@
+//! Source-to-source rewrites driven by the AST.
+//!
+//! A [`Codemod`] inspects a parsed file and returns [`TextEdit`]s against the
+//! original source text; [`apply_edits`] splices them in. Edits only touch
+//! the bytes they cover, so comments and formatting elsewhere are preserved.
+//!
+//! Available codemods:
+//!
+//! - [`implicit_nullable::ImplicitNullable`] — `Foo $x = null` → `?Foo $x = null`.
+//! - [`error_suppression::RemoveErrorSuppression`] — `@unlink($f)` → `unlink($f)`.
+//! - [`strict_comparison::StrictComparison`] — `count($a) == 0` → `count($a) === 0`.
+//! - [`sort_imports::SortImports`] — sorts, groups, and deduplicates `use` imports.
+//! - [`docblock_types::DocblockTypes`] — `@param int $n` + `f($n)` → `f(int $n)`.
+//! - [`rename_parameter::RenameParameter`] — `f(bool $force)` + `f(force: true)` →
+//!   `f(bool $overwrite)` + `f(overwrite: true)`.
+//! - [`trailing_commas::NormalizeTrailingCommas`] — `f(1, 2,)` → `f(1, 2)`, and a
+//!   comma after the last element of multi-line lists, per kind of list.
+
+pub mod docblock_types;
+pub mod error_suppression;
+pub mod implicit_nullable;
+pub mod rename_parameter;
+pub mod sort_imports;
+pub mod strict_comparison;
+pub mod trailing_commas;
+
+use php_ast::{Program, Span};
+
+/// Replace the source bytes in `span` with `replacement`. An empty span is
+/// an insertion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    pub fn replace(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub fn insert(offset: u32, text: impl Into<String>) -> Self {
+        Self::replace(Span::new(offset, offset), text)
+    }
+}
+
+/// An automated rewrite of one kind of construct.
+pub trait Codemod {
+    /// Short identifier, e.g. `implicit-nullable`.
+    fn name(&self) -> &'static str;
+
+    /// Edits against `source`, the text `program` was parsed from.
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit>;
+
+    /// `source` with [`edits`](Self::edits) applied.
+    fn apply(&self, program: &Program<'_, '_>, source: &str) -> String {
+        apply_edits(source, &self.edits(program, source))
+    }
+}
+
+/// Apply `edits` to `source`. Edits are applied in offset order; insertions
+/// at the same offset keep their relative order, and an edit overlapping an
+/// earlier one is skipped.
+///
+/// ```
+/// use php_analysis::codemod::{apply_edits, TextEdit};
+/// use php_ast::Span;
+///
+/// let edits = [
+///     TextEdit::replace(Span::new(6, 9), "int"),
+///     TextEdit::insert(6, "?"),
+/// ];
+/// assert_eq!(apply_edits("<?php Foo $x;", &edits), "<?php ?int $x;");
+/// ```
+pub fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+    sorted.sort_by_key(|edit| (edit.span.start, !edit.span.is_empty()));
+    let mut out = String::with_capacity(source.len());
+    let mut copied = 0;
+    for edit in sorted {
+        let range = edit.span.to_range();
+        if range.start < copied || range.end > source.len() {
+            continue;
+        }
+        out.push_str(&source[copied..range.start]);
+        out.push_str(&edit.replacement);
+        copied = range.end;
+    }
+    out.push_str(&source[copied..]);
+    out
+}
@@ -0,0 +1,199 @@
+//! Rename a parameter of a function or method, with its named arguments.
+//!
+//! Since PHP 8.0 parameter names are part of a callable's interface: a call
+//! `save(force: true)` breaks when `$force` is renamed. [`RenameParameter`]
+//! renames the parameter, its `@param` tag, the variable in the body, and the
+//! label of named arguments at call sites that resolve to the declaration.
+//! Apply it to every file of a project to update all callers.
+//!
+//! Call sites resolve as in the [call graph](crate::callgraph): function
+//! calls with PHP's global fallback, `Foo::bar()`, `self::`, `static::`,
+//! `parent::`, `$this->bar()`, and `new Foo(...)` for `__construct`. Calls
+//! on other objects, through subclasses, or through variables are left as
+//! they are, and so is the name in strings such as `compact('force')`. A
+//! promoted constructor parameter also names a property; accesses to that
+//! property are not rewritten.
+
+use php_ast::{Arg, Expr, ExprKind, Program, Span};
+
+use super::{Codemod, TextEdit};
+use crate::callgraph::Callee;
+use crate::hover::ident_span;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// Renames parameter `$from` of `target` to `$to`.
+///
+/// ```
+/// use php_analysis::codemod::rename_parameter::RenameParameter;
+/// use php_analysis::codemod::Codemod;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// function save(bool $force) { return $force; }
+/// save(force: true);";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let rename = RenameParameter {
+///     target: "save".to_string(),
+///     from: "force".to_string(),
+///     to: "overwrite".to_string(),
+/// };
+/// assert_eq!(
+///     rename.apply(&result.program, src),
+///     "<?php
+/// function save(bool $overwrite) { return $overwrite; }
+/// save(overwrite: true);",
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RenameParameter {
+    /// Fully-qualified function or method, as `App\helper` or
+    /// `App\User::save`; matched case-insensitively.
+    pub target: String,
+    /// The parameter name without `$`.
+    pub from: String,
+    /// The new name without `$`.
+    pub to: String,
+}
+
+impl RenameParameter {
+    fn is_target(&self, callee: &Callee) -> bool {
+        match callee {
+            Callee::Function { name, fallback } => {
+                name.eq_ignore_ascii_case(&self.target)
+                    || fallback
+                        .as_ref()
+                        .is_some_and(|fallback| fallback.eq_ignore_ascii_case(&self.target))
+            }
+            Callee::Method { .. } => callee.to_string().eq_ignore_ascii_case(&self.target),
+        }
+    }
+}
+
+impl Codemod for RenameParameter {
+    fn name(&self) -> &'static str {
+        "rename-parameter"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        let mut renamer = Renamer {
+            rename: self,
+            source,
+            binds: Vec::new(),
+            edits: Vec::new(),
+        };
+        AnalysisWalker::new(&mut renamer).walk(program);
+        renamer.edits
+    }
+}
+
+struct Renamer<'r, 's> {
+    rename: &'r RenameParameter,
+    source: &'s str,
+    /// Per entered unit, whether `$from` in it is the renamed parameter.
+    binds: Vec<bool>,
+    edits: Vec<TextEdit>,
+}
+
+impl Renamer<'_, '_> {
+    /// Replace the `from` that ends `span`, as in `$from` or `&$from`.
+    fn rename_end_of(&mut self, span: Span) {
+        let start = span.end as usize - self.rename.from.len().min(span.end as usize);
+        if self.source.get(start..span.end as usize) == Some(self.rename.from.as_str()) {
+            let span = Span::new(start as u32, span.end);
+            self.edits
+                .push(TextEdit::replace(span, self.rename.to.as_str()));
+        }
+    }
+
+    /// Replace each `$from` in the `@param` tags of `doc`.
+    fn rename_in_doc(&mut self, doc: &str, doc_start: u32) {
+        let needle = format!("${}", self.rename.from);
+        for (at, _) in doc.match_indices(&needle) {
+            let end = at + needle.len();
+            let is_whole = !doc[end..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || !c.is_ascii());
+            let line_start = doc[..at].rfind('\n').map_or(0, |i| i + 1);
+            if is_whole && doc[line_start..at].contains("@param") {
+                let span = Span::new(doc_start + at as u32 + 1, doc_start + end as u32);
+                self.edits
+                    .push(TextEdit::replace(span, self.rename.to.as_str()));
+            }
+        }
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Renamer<'_, '_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        let from = self.rename.from.as_str();
+        let declares = unit
+            .params
+            .iter()
+            .find(|param| param.name.as_str() == Some(from));
+        let outer = self.binds.last().copied().unwrap_or(false);
+        let binds = match unit.kind {
+            UnitKind::Function | UnitKind::Method
+                if unit.name.eq_ignore_ascii_case(&self.rename.target) =>
+            {
+                if let Some(span) = declares.and_then(|param| ident_span(self.source, param.name)) {
+                    self.edits
+                        .push(TextEdit::replace(span, self.rename.to.as_str()));
+                    if let Some(doc) = unit.doc_comment {
+                        self.rename_in_doc(doc.text, doc.span.start);
+                    }
+                }
+                declares.is_some()
+            }
+            // A closure's own parameter shadows the renamed one; an arrow
+            // function sees it, and a closure sees what it imports.
+            UnitKind::Closure if outer && declares.is_none() => {
+                if unit.body.is_none() {
+                    true
+                } else if let Some(var) = unit.uses.iter().find(|var| var.name == from) {
+                    self.rename_end_of(var.span);
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+        self.binds.push(binds);
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.binds.pop();
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        if let ExprKind::Variable(name) = &expr.kind {
+            if self.binds.last() == Some(&true) && name.as_str() == self.rename.from {
+                self.rename_end_of(expr.span);
+            }
+            return;
+        }
+        let Some(args) = call_args(expr) else {
+            return;
+        };
+        if !Callee::from_expr(expr, scope).is_some_and(|callee| self.rename.is_target(&callee)) {
+            return;
+        }
+        for arg in args {
+            if let Some(name) = &arg.name {
+                if name.to_string_repr() == self.rename.from {
+                    self.edits
+                        .push(TextEdit::replace(name.span(), self.rename.to.as_str()));
+                }
+            }
+        }
+    }
+}
+
+fn call_args<'a, 'arena, 'src>(expr: &'a Expr<'arena, 'src>) -> Option<&'a [Arg<'arena, 'src>]> {
+    match &expr.kind {
+        ExprKind::FunctionCall(call) => Some(&call.args),
+        ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => Some(&call.args),
+        ExprKind::StaticMethodCall(call) => Some(&call.args),
+        ExprKind::New(new) => Some(&new.args),
+        _ => None,
+    }
+}
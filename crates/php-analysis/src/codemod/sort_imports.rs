@@ -0,0 +1,249 @@
+//! Sort and group `use` imports.
+//!
+//! Each run of consecutive `use` statements is rewritten as one block: class
+//! imports, then functions, then constants, each sorted by name and split
+//! into the configured vendor groups, with a blank line between sections.
+//! Duplicate imports are dropped.
+//!
+//! Runs containing a comment are left alone, since rewriting them would
+//! drop it.
+
+use std::cmp::Ordering;
+
+use php_ast::{NamespaceBody, Program, Span, Stmt, StmtKind, UseKind};
+
+use super::{Codemod, TextEdit};
+
+/// Sorts, groups, and deduplicates the `use` imports of a file.
+///
+/// ```
+/// use php_analysis::codemod::sort_imports::{GroupUse, SortImports};
+/// use php_analysis::codemod::Codemod;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php\nuse function App\\f;\nuse Vendor\\Lib;\nuse App\\{Foo, Bar};\nuse Vendor\\Lib;\n";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let fixer = SortImports {
+///     groups: vec!["App".to_string()],
+///     group_use: GroupUse::Split,
+/// };
+/// assert_eq!(
+///     fixer.apply(&result.program, src),
+///     "<?php\nuse Vendor\\Lib;\n\nuse App\\Bar;\nuse App\\Foo;\n\nuse function App\\f;\n",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SortImports {
+    /// Namespace prefixes, such as `App`, whose imports form groups of their
+    /// own in this order, after the imports that match none of them.
+    pub groups: Vec<String>,
+    pub group_use: GroupUse,
+}
+
+/// Whether imports sharing a namespace are written as one group `use`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupUse {
+    /// One import per `use` statement; group `use` statements are split.
+    #[default]
+    Split,
+    /// Imports of one kind and group that share a namespace are merged:
+    /// `use App\{Bar, Foo};`.
+    Merge,
+}
+
+impl Codemod for SortImports {
+    fn name(&self) -> &'static str {
+        "sort-imports"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        let mut edits = Vec::new();
+        self.collect(&program.stmts, source, &mut edits);
+        edits
+    }
+}
+
+/// One imported name.
+#[derive(Debug, PartialEq, Eq)]
+struct Import {
+    kind: UseKind,
+    /// Index into [`SortImports::groups`] plus one, or 0 for no group.
+    group: usize,
+    name: String,
+    alias: Option<String>,
+}
+
+impl SortImports {
+    fn collect(&self, stmts: &[Stmt<'_, '_>], source: &str, edits: &mut Vec<TextEdit>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            match &stmts[i].kind {
+                StmtKind::Use(_) => {
+                    let run = stmts[i..]
+                        .iter()
+                        .take_while(|stmt| matches!(stmt.kind, StmtKind::Use(_)))
+                        .count();
+                    edits.extend(self.rewrite(&stmts[i..i + run], source));
+                    i += run;
+                    continue;
+                }
+                StmtKind::Namespace(ns) => {
+                    if let NamespaceBody::Braced(body) = &ns.body {
+                        self.collect(body, source, edits);
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// The edit rewriting a run of `use` statements, if it changes anything.
+    fn rewrite(&self, run: &[Stmt<'_, '_>], source: &str) -> Option<TextEdit> {
+        let start = run.first()?.span.start as usize;
+        let end = run.last()?.span.end as usize;
+        let text = &source[start..end];
+        if text.contains(['/', '#']) {
+            return None;
+        }
+
+        let mut imports = Vec::new();
+        for stmt in run {
+            let StmtKind::Use(decl) = &stmt.kind else {
+                continue;
+            };
+            for item in decl.uses.iter() {
+                let name = item.name.join_parts();
+                if name.is_empty() {
+                    return None;
+                }
+                let import = Import {
+                    kind: item.kind.unwrap_or(decl.kind),
+                    group: self.group_of(&name),
+                    name: name.into_owned(),
+                    alias: item.alias.map(str::to_string),
+                };
+                if !imports.iter().any(|seen| same_import(seen, &import)) {
+                    imports.push(import);
+                }
+            }
+        }
+        imports.sort_by(compare);
+
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let indent = &source[line_start..start];
+        let indent = if indent.trim().is_empty() { indent } else { "" };
+        let nl = if text.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut replacement = String::with_capacity(text.len());
+        for (i, line) in self.lines(&imports).iter().enumerate() {
+            if i > 0 {
+                replacement.push_str(nl);
+                if !line.is_empty() {
+                    replacement.push_str(indent);
+                }
+            }
+            replacement.push_str(line);
+        }
+        (replacement != text)
+            .then(|| TextEdit::replace(Span::new(start as u32, end as u32), replacement))
+    }
+
+    fn group_of(&self, name: &str) -> usize {
+        self.groups
+            .iter()
+            .position(|prefix| {
+                let prefix = prefix.trim_matches('\\');
+                name.get(..prefix.len())
+                    .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+                    && matches!(name.as_bytes().get(prefix.len()), None | Some(b'\\'))
+            })
+            .map_or(0, |i| i + 1)
+    }
+
+    /// The lines of the rewritten block, with an empty line between sections.
+    fn lines(&self, imports: &[Import]) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut rest = imports;
+        while let Some(first) = rest.first() {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            let section = rest
+                .iter()
+                .take_while(|i| i.kind == first.kind && i.group == first.group)
+                .count();
+            let (imports, after) = rest.split_at(section);
+            rest = after;
+            let keyword = match first.kind {
+                UseKind::Normal => "",
+                UseKind::Function => "function ",
+                UseKind::Const => "const ",
+            };
+            let mut imports = imports;
+            while let Some(import) = imports.first() {
+                let shared = match (self.group_use, namespace(&import.name)) {
+                    (GroupUse::Merge, Some(ns)) => imports
+                        .iter()
+                        .take_while(|i| namespace(&i.name) == Some(ns))
+                        .count(),
+                    _ => 1,
+                };
+                let line = if shared > 1 {
+                    let ns = namespace(&import.name).unwrap_or_default();
+                    let members: Vec<String> = imports[..shared]
+                        .iter()
+                        .map(|i| with_alias(&i.name[ns.len() + 1..], &i.alias))
+                        .collect();
+                    format!("use {keyword}{ns}\\{{{}}};", members.join(", "))
+                } else {
+                    format!("use {keyword}{};", with_alias(&import.name, &import.alias))
+                };
+                lines.push(line);
+                imports = &imports[shared..];
+            }
+        }
+        lines
+    }
+}
+
+/// Class and function names are case-insensitive; constant names are not.
+fn same_import(a: &Import, b: &Import) -> bool {
+    a.kind == b.kind
+        && a.alias == b.alias
+        && match a.kind {
+            UseKind::Const => a.name == b.name,
+            UseKind::Normal | UseKind::Function => a.name.eq_ignore_ascii_case(&b.name),
+        }
+}
+
+/// By kind, then group, then name segment by segment, ignoring case.
+fn compare(a: &Import, b: &Import) -> Ordering {
+    let kind = |kind: UseKind| match kind {
+        UseKind::Normal => 0,
+        UseKind::Function => 1,
+        UseKind::Const => 2,
+    };
+    let segments = |name: &str| {
+        name.split('\\')
+            .map(str::to_ascii_lowercase)
+            .collect::<Vec<_>>()
+    };
+    kind(a.kind)
+        .cmp(&kind(b.kind))
+        .then(a.group.cmp(&b.group))
+        .then_with(|| segments(&a.name).cmp(&segments(&b.name)))
+        .then_with(|| a.name.cmp(&b.name))
+        .then_with(|| a.alias.cmp(&b.alias))
+}
+
+/// The namespace `name` is in, if it is qualified.
+fn namespace(name: &str) -> Option<&str> {
+    name.rsplit_once('\\').map(|(ns, _)| ns)
+}
+
+fn with_alias(name: &str, alias: &Option<String>) -> String {
+    match alias {
+        Some(alias) => format!("{name} as {alias}"),
+        None => name.to_string(),
+    }
+}
@@ -0,0 +1,44 @@
+//! Replace loose comparisons with strict ones where that cannot change the
+//! result.
+//!
+//! Applies the fixes of [`crate::loose_comparison`]: `==`/`!=` become
+//! `===`/`!==`, and array searches gain `$strict = true`. Comparisons whose
+//! operand types are not known stay as they are.
+
+use php_ast::Program;
+
+use super::{Codemod, TextEdit};
+use crate::loose_comparison::{loose_comparisons, LooseComparisonOptions};
+
+/// Applies the safe strict-comparison fixes of the enabled rules (all of
+/// them by default).
+///
+/// ```
+/// use php_analysis::codemod::strict_comparison::StrictComparison;
+/// use php_analysis::codemod::Codemod;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php if (strlen($s) == 0 || trim($mode) != 'r' || $n == 1) {}";
+/// let result = php_rs_parser::parse(&arena, src);
+/// assert_eq!(
+///     StrictComparison::default().apply(&result.program, src),
+///     "<?php if (strlen($s) === 0 || trim($mode) !== 'r' || $n == 1) {}",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StrictComparison {
+    pub options: LooseComparisonOptions,
+}
+
+impl Codemod for StrictComparison {
+    fn name(&self) -> &'static str {
+        "strict-comparison"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        loose_comparisons(program, source, &self.options)
+            .into_iter()
+            .filter_map(|found| found.fix)
+            .collect()
+    }
+}
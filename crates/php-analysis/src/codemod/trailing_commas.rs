@@ -0,0 +1,250 @@
+//! Add or remove the comma after the last element of comma-separated lists.
+//!
+//! The policy of each kind of list is the [`TrailingCommas`] setting of the
+//! printer, so a project checks its sources against the options it formats
+//! with. A list is multi-line when its closing delimiter is on a later line
+//! than its last element. [`TrailingCommas::MultiLine`] adds a missing comma
+//! to multi-line lists and removes one from single-line lists,
+//! [`TrailingCommas::Never`] removes them all, and
+//! [`TrailingCommas::Preserve`] leaves the list as it is.
+//!
+//! A list ending in an omitted destructuring slot, as in `[$a, ,] = $b`, is
+//! left alone: its last comma is part of the target. Commas are only added
+//! where the target PHP version accepts them, which for parameters and the
+//! variables of a closure's `use` is PHP 8.0.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{
+    walk_attribute, walk_class_member, walk_enum_member, walk_expr, walk_property_hook, walk_stmt,
+    Visitor,
+};
+use php_ast::{
+    Attribute, ClassMember, ClassMemberKind, EnumMember, EnumMemberKind, Expr, ExprKind, ListExpr,
+    Program, PropertyHook, Span, Stmt, StmtKind,
+};
+use php_printer::{CommaList, PrintOptions, TrailingCommas};
+use php_rs_parser::PhpVersion;
+
+use super::{Codemod, TextEdit};
+
+/// Adds and removes trailing commas as the trailing-comma settings of
+/// `options` ask.
+///
+/// ```
+/// use php_analysis::codemod::trailing_commas::NormalizeTrailingCommas;
+/// use php_analysis::codemod::Codemod;
+/// use php_printer::{CommaList, PrintOptions, TrailingCommas};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// f(1, 2,);
+/// $a = [
+///     1,
+///     2
+/// ];";
+/// let result = php_rs_parser::parse(&arena, src);
+/// assert_eq!(
+///     NormalizeTrailingCommas::default().apply(&result.program, src),
+///     "<?php
+/// f(1, 2);
+/// $a = [
+///     1,
+///     2,
+/// ];",
+/// );
+///
+/// let never_in_arrays = NormalizeTrailingCommas {
+///     options: PrintOptions::default()
+///         .with_trailing_commas(TrailingCommas::Preserve)
+///         .with_trailing_commas_in(CommaList::Arrays, TrailingCommas::Never),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     never_in_arrays.apply(&result.program, src),
+///     "<?php
+/// f(1, 2,);
+/// $a = [
+///     1,
+///     2
+/// ];",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeTrailingCommas {
+    /// Only the trailing-comma settings are read.
+    pub options: PrintOptions,
+    /// Commas are not added where this version rejects them.
+    pub php_version: PhpVersion,
+}
+
+impl Codemod for NormalizeTrailingCommas {
+    fn name(&self) -> &'static str {
+        "trailing-commas"
+    }
+
+    fn edits(&self, program: &Program<'_, '_>, source: &str) -> Vec<TextEdit> {
+        let mut collector = Collector {
+            fix: self,
+            source,
+            edits: Vec::new(),
+        };
+        let _ = collector.visit_program(program);
+        collector.edits
+    }
+}
+
+struct Collector<'f, 's> {
+    fix: &'f NormalizeTrailingCommas,
+    source: &'s str,
+    edits: Vec<TextEdit>,
+}
+
+impl Collector<'_, '_> {
+    /// Check the list whose last element spans `last` and is followed by
+    /// `comma`.
+    fn check(&mut self, list: CommaList, last: Span, comma: Option<Span>) {
+        let policy = self.fix.options.trailing_commas(list);
+        if policy == TrailingCommas::Preserve {
+            return;
+        }
+        let after = comma.map_or(last.end, |comma| comma.end) as usize;
+        let close = skip_trivia(self.source, after);
+        // A `use` list without braces, or a list cut short by an error.
+        if !matches!(self.source.as_bytes().get(close), Some(b')' | b']' | b'}')) {
+            return;
+        }
+        let multi_line = self.source[last.end as usize..close].contains('\n');
+        let wanted = policy == TrailingCommas::MultiLine && multi_line;
+        match comma {
+            Some(comma) if !wanted => {
+                // `f(1, 2, )` loses the space before `)` with the comma,
+                // `{ 1 => 2, }` keeps the one before `}`.
+                let gap = &self.source[comma.end as usize..close];
+                let end = if self.source.as_bytes()[close] != b'}'
+                    && gap.bytes().all(|b| b == b' ' || b == b'\t')
+                {
+                    close as u32
+                } else {
+                    comma.end
+                };
+                self.edits
+                    .push(TextEdit::replace(Span::new(comma.start, end), ""));
+            }
+            None if wanted && self.accepts(list) => {
+                self.edits.push(TextEdit::insert(last.end, ","));
+            }
+            _ => {}
+        }
+    }
+
+    fn accepts(&self, list: CommaList) -> bool {
+        match list {
+            CommaList::Params | CommaList::ClosureUses => self.fix.php_version >= PhpVersion::Php80,
+            _ => true,
+        }
+    }
+
+    fn check_params(&mut self, params: &[php_ast::Param<'_, '_>]) {
+        if let Some(last) = params.last() {
+            self.check(CommaList::Params, last.span, last.comma);
+        }
+    }
+
+    fn check_args(&mut self, args: &[php_ast::Arg<'_, '_>]) {
+        if let Some(last) = args.last() {
+            self.check(CommaList::Args, last.span, last.comma);
+        }
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Function(func) => self.check_params(&func.params),
+            StmtKind::Use(use_decl) => {
+                if let Some(last) = use_decl.uses.last() {
+                    self.check(CommaList::GroupUses, last.span, last.comma);
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::FunctionCall(call) => self.check_args(&call.args),
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                self.check_args(&call.args)
+            }
+            ExprKind::StaticMethodCall(call) => self.check_args(&call.args),
+            ExprKind::StaticDynMethodCall(call) => self.check_args(&call.args),
+            ExprKind::New(new) => self.check_args(&new.args),
+            ExprKind::Array(elements) | ExprKind::List(ListExpr { elements, .. }) => {
+                if let Some(last) = elements.last() {
+                    if !matches!(last.value.kind, ExprKind::Omit) {
+                        self.check(CommaList::Arrays, last.span, last.comma);
+                    }
+                }
+            }
+            ExprKind::Match(match_expr) => {
+                if let Some(last) = match_expr.arms.last() {
+                    self.check(CommaList::MatchArms, last.span, last.comma);
+                }
+            }
+            ExprKind::Closure(closure) => {
+                self.check_params(&closure.params);
+                if let Some(last) = closure.use_vars.last() {
+                    self.check(CommaList::ClosureUses, last.span, last.comma);
+                }
+            }
+            ExprKind::ArrowFunction(arrow) => self.check_params(&arrow.params),
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        if let ClassMemberKind::Method(method) = &member.kind {
+            self.check_params(&method.params);
+        }
+        walk_class_member(self, member)
+    }
+
+    fn visit_enum_member(&mut self, member: &EnumMember<'arena, 'src>) -> ControlFlow<()> {
+        if let EnumMemberKind::Method(method) = &member.kind {
+            self.check_params(&method.params);
+        }
+        walk_enum_member(self, member)
+    }
+
+    fn visit_property_hook(&mut self, hook: &PropertyHook<'arena, 'src>) -> ControlFlow<()> {
+        self.check_params(&hook.params);
+        walk_property_hook(self, hook)
+    }
+
+    fn visit_attribute(&mut self, attribute: &Attribute<'arena, 'src>) -> ControlFlow<()> {
+        self.check_args(&attribute.args);
+        walk_attribute(self, attribute)
+    }
+}
+
+/// The offset of the first byte at or after `at` that is neither whitespace
+/// nor part of a comment.
+fn skip_trivia(source: &str, mut at: usize) -> usize {
+    let bytes = source.as_bytes();
+    while at < bytes.len() {
+        let rest = &source[at..];
+        if bytes[at].is_ascii_whitespace() {
+            at += 1;
+        } else if rest.starts_with("/*") {
+            at += rest.find("*/").map_or(rest.len(), |end| end + 2);
+        } else if rest.starts_with("//") || rest.starts_with('#') && !rest.starts_with("#[") {
+            at += rest.find('\n').unwrap_or(rest.len());
+        } else {
+            break;
+        }
+    }
+    at
+}
@@ -0,0 +1,300 @@
+//! Project-wide table of global constants.
+//!
+//! [`ConstantTable`] records the constants a project defines, whether with a
+//! `const` statement or a `define()` call:
+//!
+//! ```php
+//! namespace App;
+//! const VERSION = '2.1';                          // App\VERSION
+//! define('DEBUG', false);                         // DEBUG, not namespaced
+//! define(__NAMESPACE__ . '\ROOT', __DIR__);       // App\ROOT
+//! if (!defined('LOCALE')) { define('LOCALE', 'en'); }  // conditional
+//! ```
+//!
+//! The name of a `define()` is resolved when it is built from literals as
+//! described in [`class_strings`](crate::class_strings); calls whose name is
+//! unknown are listed as [`ConstantTable::dynamic_defines`]. A definition is
+//! conditional when it only runs on some paths: inside a function, method,
+//! or closure, a branch or loop, or the right operand of `&&`, `||`, `and`,
+//! `or`, `??`, or a ternary, as in `defined('X') or define('X', 1)`.
+//!
+//! Lookups follow PHP: the namespace part of a name is case-insensitive, the
+//! rest is case-sensitive.
+
+use std::collections::HashMap;
+
+use php_ast::{BinaryOp, Expr, ExprKind, Program, Span, Stmt, StmtKind};
+
+use crate::class_strings::StringValues;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// How a constant is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstantKind {
+    /// `const NAME = value;`
+    Const,
+    /// `define('NAME', value)`
+    Define,
+}
+
+/// One definition of a global constant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstantDefinition {
+    /// The fully-qualified name, without a leading `\`.
+    pub name: String,
+    pub kind: ConstantKind,
+    pub file: usize,
+    /// The `const` item or the `define()` call.
+    pub span: Span,
+    /// The value expression.
+    pub value: Span,
+    /// Only runs on some paths; see the [module docs](self).
+    pub conditional: bool,
+}
+
+/// A `define()` call whose name is not known at parse time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicDefine {
+    pub file: usize,
+    pub span: Span,
+}
+
+/// Global constants across the files of a project.
+///
+/// ```
+/// use php_analysis::constants::{ConstantKind, ConstantTable};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// namespace App;
+/// const VERSION = '2.1';
+/// defined('DEBUG') or define('DEBUG', false);
+/// define($name, 1);
+/// ";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut table = ConstantTable::new();
+/// table.add_file(&result.program);
+///
+/// let version = &table.find("app\\VERSION")[0];
+/// assert_eq!((version.kind, version.conditional), (ConstantKind::Const, false));
+/// let debug = &table.find("DEBUG")[0];
+/// assert_eq!((debug.kind, debug.conditional), (ConstantKind::Define, true));
+/// assert!(table.find("App\\version").is_empty());
+/// assert_eq!(table.dynamic_defines().len(), 1);
+///
+/// // `DEBUG` used inside `namespace App` falls back to the global constant.
+/// assert_eq!(table.resolve("App\\DEBUG", Some("DEBUG"))[0].name, "DEBUG");
+/// ```
+#[derive(Debug, Default)]
+pub struct ConstantTable {
+    definitions: Vec<ConstantDefinition>,
+    /// Lookup key (see [`key`]) → indexes into `definitions`.
+    by_name: HashMap<String, Vec<usize>>,
+    dynamic: Vec<DynamicDefine>,
+    files: usize,
+}
+
+impl ConstantTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let mut collector = Collector {
+            table: self,
+            file,
+            values: Vec::new(),
+            conditional: Vec::new(),
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        file
+    }
+
+    /// Every definition, by file and position.
+    pub fn definitions(&self) -> &[ConstantDefinition] {
+        &self.definitions
+    }
+
+    /// The definitions of the fully-qualified constant `name`. There may be
+    /// several, e.g. a `define()` in each branch of an `if`.
+    pub fn find(&self, name: &str) -> Vec<&ConstantDefinition> {
+        self.by_name
+            .get(&key(name))
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.definitions[i])
+            .collect()
+    }
+
+    /// The definitions a constant reference resolves to, given the
+    /// namespaced name and global fallback from
+    /// [`NameContext::resolve_constant`](crate::names::NameContext::resolve_constant).
+    pub fn resolve(&self, name: &str, fallback: Option<&str>) -> Vec<&ConstantDefinition> {
+        let found = self.find(name);
+        match (found.is_empty(), fallback) {
+            (true, Some(fallback)) => self.find(fallback),
+            _ => found,
+        }
+    }
+
+    /// `define()` calls whose name could not be resolved, by file and
+    /// position.
+    pub fn dynamic_defines(&self) -> &[DynamicDefine] {
+        &self.dynamic
+    }
+
+    fn add(&mut self, definition: ConstantDefinition) {
+        self.by_name
+            .entry(key(&definition.name))
+            .or_default()
+            .push(self.definitions.len());
+        self.definitions.push(definition);
+    }
+}
+
+/// The lookup key of a constant name: the namespace lower-cased, the short
+/// name as is.
+pub(crate) fn key(name: &str) -> String {
+    let name = name.trim_start_matches('\\');
+    match name.rsplit_once('\\') {
+        Some((namespace, short)) => format!("{}\\{short}", namespace.to_ascii_lowercase()),
+        None => name.to_string(),
+    }
+}
+
+struct Collector<'t> {
+    table: &'t mut ConstantTable,
+    file: usize,
+    /// Known string values of the variables of each enclosing unit.
+    values: Vec<StringValues>,
+    /// Regions of the file that only run on some paths.
+    conditional: Vec<Span>,
+}
+
+impl Collector<'_> {
+    fn is_conditional(&self, span: Span) -> bool {
+        self.conditional
+            .iter()
+            .any(|region| region.start <= span.start && span.end <= region.end)
+    }
+
+    fn define(&mut self, expr: &Expr<'_, '_>, scope: &AnalysisScope) {
+        let ExprKind::FunctionCall(call) = &expr.kind else {
+            return;
+        };
+        let ExprKind::Name(name) = &call.name.kind else {
+            return;
+        };
+        let name = name.join_parts();
+        if !name.trim_start_matches('\\').eq_ignore_ascii_case("define") {
+            return;
+        }
+        let (Some(name), Some(value)) = (call.args.first(), call.args.get(1)) else {
+            return;
+        };
+        let names = self
+            .values
+            .last()
+            .and_then(|values| values.eval(&name.value, scope))
+            .filter(|names| !names.is_empty());
+        let Some(names) = names else {
+            self.table.dynamic.push(DynamicDefine {
+                file: self.file,
+                span: expr.span,
+            });
+            return;
+        };
+        // Several candidates mean the name depends on the path taken.
+        let conditional = names.len() > 1 || self.is_conditional(expr.span);
+        for name in names {
+            self.table.add(ConstantDefinition {
+                name: name.trim_start_matches('\\').to_string(),
+                kind: ConstantKind::Define,
+                file: self.file,
+                span: expr.span,
+                value: value.value.span,
+                conditional,
+            });
+        }
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        if unit.kind != UnitKind::Main {
+            self.conditional.push(unit.span);
+        }
+        self.values.push(StringValues::collect(
+            unit.params,
+            unit.body.unwrap_or_default(),
+            scope,
+        ));
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.values.pop();
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Const(items) => {
+                let conditional = self.is_conditional(stmt.span);
+                for item in items.iter() {
+                    let Some(name) = item.name.as_str() else {
+                        continue;
+                    };
+                    self.table.add(ConstantDefinition {
+                        name: scope.names.qualify(name),
+                        kind: ConstantKind::Const,
+                        file: self.file,
+                        span: item.span,
+                        value: item.value.span,
+                        conditional,
+                    });
+                }
+            }
+            StmtKind::If(if_stmt) => {
+                self.conditional.push(if_stmt.then_branch.span);
+                self.conditional
+                    .extend(if_stmt.elseif_branches.iter().map(|branch| branch.span));
+                self.conditional.extend(if_stmt.else_branch.map(|e| e.span));
+            }
+            StmtKind::While(while_stmt) => self.conditional.push(while_stmt.body.span),
+            StmtKind::For(for_stmt) => self.conditional.push(for_stmt.body.span),
+            StmtKind::Foreach(foreach) => self.conditional.push(foreach.body.span),
+            StmtKind::Switch(switch) => self
+                .conditional
+                .extend(switch.cases.iter().map(|case| case.span)),
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        match &expr.kind {
+            ExprKind::Binary(binary)
+                if matches!(
+                    binary.op,
+                    BinaryOp::BooleanAnd
+                        | BinaryOp::BooleanOr
+                        | BinaryOp::LogicalAnd
+                        | BinaryOp::LogicalOr
+                ) =>
+            {
+                self.conditional.push(binary.right.span)
+            }
+            ExprKind::NullCoalesce(coalesce) => self.conditional.push(coalesce.right.span),
+            ExprKind::Ternary(ternary) => {
+                self.conditional.extend(ternary.then_expr.map(|e| e.span));
+                self.conditional.push(ternary.else_expr.span);
+            }
+            ExprKind::Match(match_expr) => self
+                .conditional
+                .extend(match_expr.arms.iter().map(|arm| arm.span)),
+            ExprKind::FunctionCall(_) => self.define(expr, scope),
+            _ => {}
+        }
+    }
+}
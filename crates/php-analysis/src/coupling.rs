@@ -0,0 +1,666 @@
+//! Class coupling: the dependency graph between classes and namespaces.
+//!
+//! [`CouplingAnalysis`] records, for each class, interface, trait, and enum
+//! declared in the project, the classes it names: in `extends` and
+//! `implements`, trait uses, type hints, `new`, `instanceof`, static access,
+//! `catch`, and attributes. [`finish`](CouplingAnalysis::finish) turns that
+//! into a [`DependencyGraph`] with
+//!
+//! - afferent coupling (Ca, the classes that depend on a class), efferent
+//!   coupling (Ce, the classes it depends on), and instability,
+//!   Ce / (Ca + Ce);
+//! - dependency cycles, the strongly connected components of the graph;
+//! - DOT and JSON exports;
+//! - [`CouplingDiagnostic`]s for classes over [`CouplingThresholds`] and for
+//!   cycles.
+//!
+//! [`DependencyGraph::namespaces`] collapses the graph to one node per
+//! namespace. Names outside any class-like, in functions and file bodies, are
+//! not dependencies. `self`, `static`, and `parent` are skipped; the parent
+//! is already an `extends` edge. Classes that are named but not declared in
+//! the project, such as built-ins and vendor code, are
+//! [`external`](CouplingNode::external) nodes without dependencies of their
+//! own; [`DependencyGraph::project_only`] drops them.
+//!
+//! ```
+//! use php_analysis::coupling::CouplingAnalysis;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! namespace App;
+//! class Order { public function customer(): Customer {} }
+//! class Customer { public function firstOrder(): ?Order {} }
+//! class Invoice { public function __construct(Order $order) { throw new \\LogicException(); } }
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let mut analysis = CouplingAnalysis::new();
+//! analysis.add_file(&result.program);
+//! let graph = analysis.finish();
+//!
+//! let order = graph.find("App\\Order").unwrap();
+//! let metrics = &graph.metrics()[order];
+//! assert_eq!((metrics.afferent, metrics.efferent), (2, 1));
+//!
+//! let cycles: Vec<Vec<&str>> = graph
+//!     .cycles()
+//!     .iter()
+//!     .map(|cycle| cycle.iter().map(|&n| graph.nodes()[n].name.as_str()).collect())
+//!     .collect();
+//! assert_eq!(cycles, [["App\\Customer", "App\\Order"]]);
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fmt::Write as _;
+
+use php_ast::{Program, Span, Stmt, StmtKind};
+
+use crate::names::is_special_class;
+use crate::resolve::{resolve_names, NameRole};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, ClassContext};
+
+/// Collects the class dependencies of a project. See the
+/// [module documentation](self).
+#[derive(Debug, Default)]
+pub struct CouplingAnalysis {
+    classes: Vec<DeclaredClass>,
+    files: usize,
+}
+
+#[derive(Debug)]
+struct DeclaredClass {
+    name: String,
+    file: usize,
+    span: Span,
+    /// Fully-qualified names of the classes referenced in the declaration.
+    references: Vec<String>,
+}
+
+impl CouplingAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the class-likes declared in `program` and what they reference.
+    /// Returns the file index the declarations are located by.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+
+        let mut collector = Collector {
+            declared: Vec::new(),
+            attributes: None,
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        let mut classes: Vec<DeclaredClass> = collector
+            .declared
+            .into_iter()
+            .map(|(name, span)| DeclaredClass {
+                name,
+                file,
+                span,
+                references: Vec::new(),
+            })
+            .collect();
+
+        for name in resolve_names(program).iter() {
+            if name.role != NameRole::Class || is_special_class(&name.fqn) {
+                continue;
+            }
+            // A reference belongs to the innermost declaration around it.
+            let owner = classes
+                .iter_mut()
+                .filter(|c| c.span.start <= name.span.start && name.span.end <= c.span.end)
+                .min_by_key(|c| c.span.end - c.span.start);
+            if let Some(owner) = owner {
+                owner.references.push(name.fqn.clone());
+            }
+        }
+        self.classes.extend(classes);
+        file
+    }
+
+    /// Build the class dependency graph.
+    pub fn finish(self) -> DependencyGraph {
+        // Class names are case-insensitive; a node takes the spelling of its
+        // declaration, or of its first reference when it is external.
+        let mut nodes: Vec<CouplingNode> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for class in &self.classes {
+            let key = class.name.to_ascii_lowercase();
+            if index.contains_key(&key) {
+                continue;
+            }
+            index.insert(key, nodes.len());
+            nodes.push(CouplingNode {
+                name: class.name.clone(),
+                external: false,
+                location: Some((class.file, class.span)),
+                dependencies: Vec::new(),
+            });
+        }
+        let mut edges: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); nodes.len()];
+        for class in &self.classes {
+            let from = index[&class.name.to_ascii_lowercase()];
+            for reference in &class.references {
+                let key = reference.to_ascii_lowercase();
+                let to = *index.entry(key).or_insert_with(|| {
+                    nodes.push(CouplingNode {
+                        name: reference.clone(),
+                        external: true,
+                        location: None,
+                        dependencies: Vec::new(),
+                    });
+                    edges.push(BTreeSet::new());
+                    nodes.len() - 1
+                });
+                if to != from {
+                    edges[from].insert(to);
+                }
+            }
+        }
+        DependencyGraph::build(nodes, edges)
+    }
+}
+
+struct Collector {
+    declared: Vec<(String, Span)>,
+    /// Where the attributes of the class-like statement being entered
+    /// start; its span begins at the keyword.
+    attributes: Option<(Span, u32)>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, _scope: &AnalysisScope) {
+        let attributes = match &stmt.kind {
+            StmtKind::Class(decl) => &decl.attributes,
+            StmtKind::Interface(decl) => &decl.attributes,
+            StmtKind::Trait(decl) => &decl.attributes,
+            StmtKind::Enum(decl) => &decl.attributes,
+            _ => return,
+        };
+        self.attributes = attributes.first().map(|a| (stmt.span, a.span.start));
+    }
+
+    fn enter_class(&mut self, class: &ClassContext, _supertypes: &[String], span: Span) {
+        if class.name == "class@anonymous" {
+            return;
+        }
+        let start = match self.attributes.take() {
+            Some((stmt, start)) if stmt == span => start.min(span.start),
+            _ => span.start,
+        };
+        self.declared
+            .push((class.name.clone(), Span::new(start, span.end)));
+    }
+}
+
+/// A class or namespace in a [`DependencyGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouplingNode {
+    /// Fully-qualified name. The global namespace is the empty string.
+    pub name: String,
+    /// Named but not declared in the project. For a namespace, none of its
+    /// classes are declared in the project.
+    pub external: bool,
+    /// File index and span of a class declaration; `None` for external
+    /// classes and for namespaces.
+    pub location: Option<(usize, Span)>,
+    /// The nodes this one depends on, in node order.
+    pub dependencies: Vec<usize>,
+}
+
+/// The coupling of one node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CouplingMetrics {
+    /// Ca: how many nodes depend on this one.
+    pub afferent: usize,
+    /// Ce: how many nodes this one depends on.
+    pub efferent: usize,
+}
+
+impl CouplingMetrics {
+    /// Ce / (Ca + Ce): 0 for a node that depends on nothing, 1 for one
+    /// nothing depends on. A node with no coupling at all is 0.
+    pub fn instability(&self) -> f64 {
+        match self.afferent + self.efferent {
+            0 => 0.0,
+            total => self.efferent as f64 / total as f64,
+        }
+    }
+}
+
+/// Dependencies between classes or namespaces, with nodes sorted by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    nodes: Vec<CouplingNode>,
+}
+
+impl DependencyGraph {
+    /// Sort `nodes` by name and renumber `edges` to match.
+    fn build(nodes: Vec<CouplingNode>, edges: Vec<BTreeSet<usize>>) -> Self {
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by_cached_key(|&n| (nodes[n].name.to_ascii_lowercase(), nodes[n].name.clone()));
+        let mut renumber = vec![0; nodes.len()];
+        for (new, &old) in order.iter().enumerate() {
+            renumber[old] = new;
+        }
+        let mut slots: Vec<Option<CouplingNode>> = nodes.into_iter().map(Some).collect();
+        let nodes = order
+            .iter()
+            .map(|&old| {
+                let mut node = slots[old].take().expect("each node is moved once");
+                let mut dependencies: Vec<usize> =
+                    edges[old].iter().map(|&d| renumber[d]).collect();
+                dependencies.sort_unstable();
+                node.dependencies = dependencies;
+                node
+            })
+            .collect();
+        Self { nodes }
+    }
+
+    pub fn nodes(&self) -> &[CouplingNode] {
+        &self.nodes
+    }
+
+    /// The node named `name`, compared case-insensitively.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        let name = name.strip_prefix('\\').unwrap_or(name);
+        self.nodes
+            .iter()
+            .position(|node| node.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The coupling of every node, indexed like [`nodes`](Self::nodes).
+    pub fn metrics(&self) -> Vec<CouplingMetrics> {
+        let mut metrics: Vec<CouplingMetrics> = self
+            .nodes
+            .iter()
+            .map(|node| CouplingMetrics {
+                afferent: 0,
+                efferent: node.dependencies.len(),
+            })
+            .collect();
+        for node in &self.nodes {
+            for &dependency in &node.dependencies {
+                metrics[dependency].afferent += 1;
+            }
+        }
+        metrics
+    }
+
+    /// The graph without external nodes and the edges to them.
+    pub fn project_only(&self) -> DependencyGraph {
+        let kept: Vec<usize> = (0..self.nodes.len())
+            .filter(|&n| !self.nodes[n].external)
+            .collect();
+        let mut renumber = vec![None; self.nodes.len()];
+        for (new, &old) in kept.iter().enumerate() {
+            renumber[old] = Some(new);
+        }
+        let nodes = kept
+            .iter()
+            .map(|&old| {
+                let node = &self.nodes[old];
+                CouplingNode {
+                    dependencies: node
+                        .dependencies
+                        .iter()
+                        .filter_map(|&d| renumber[d])
+                        .collect(),
+                    ..node.clone()
+                }
+            })
+            .collect();
+        DependencyGraph { nodes }
+    }
+
+    /// Collapse classes into their namespaces. A namespace depends on
+    /// another when one of its classes depends on one of the other's.
+    pub fn namespaces(&self) -> DependencyGraph {
+        let mut nodes: Vec<CouplingNode> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let owner: Vec<usize> = self
+            .nodes
+            .iter()
+            .map(|class| {
+                let namespace = namespace_of(&class.name);
+                let n = *index
+                    .entry(namespace.to_ascii_lowercase())
+                    .or_insert_with(|| {
+                        nodes.push(CouplingNode {
+                            name: namespace.to_string(),
+                            external: true,
+                            location: None,
+                            dependencies: Vec::new(),
+                        });
+                        nodes.len() - 1
+                    });
+                nodes[n].external &= class.external;
+                n
+            })
+            .collect();
+        let mut edges: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); nodes.len()];
+        for (class, node) in self.nodes.iter().enumerate() {
+            for &dependency in &node.dependencies {
+                if owner[class] != owner[dependency] {
+                    edges[owner[class]].insert(owner[dependency]);
+                }
+            }
+        }
+        DependencyGraph::build(nodes, edges)
+    }
+
+    /// Every dependency cycle: the strongly connected components of more
+    /// than one node, each sorted by node index, in order of their first
+    /// node.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: vec![None; self.nodes.len()],
+            low: vec![0; self.nodes.len()],
+            on_stack: vec![false; self.nodes.len()],
+            stack: Vec::new(),
+            next: 0,
+            components: Vec::new(),
+        };
+        for node in 0..self.nodes.len() {
+            if tarjan.index[node].is_none() {
+                tarjan.visit(node);
+            }
+        }
+        let mut cycles: Vec<Vec<usize>> = tarjan
+            .components
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|mut component| {
+                component.sort_unstable();
+                component
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Nodes over `thresholds`, then cycles if they are reported.
+    pub fn check(&self, thresholds: &CouplingThresholds) -> Vec<CouplingDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for (node, metrics) in self.nodes.iter().zip(self.metrics()) {
+            if node.external {
+                continue;
+            }
+            if let Some(max) = thresholds
+                .max_efferent
+                .filter(|&max| metrics.efferent > max)
+            {
+                diagnostics.push(CouplingDiagnostic::Efferent {
+                    name: node.name.clone(),
+                    location: node.location,
+                    count: metrics.efferent,
+                    max,
+                });
+            }
+            if let Some(max) = thresholds
+                .max_afferent
+                .filter(|&max| metrics.afferent > max)
+            {
+                diagnostics.push(CouplingDiagnostic::Afferent {
+                    name: node.name.clone(),
+                    location: node.location,
+                    count: metrics.afferent,
+                    max,
+                });
+            }
+        }
+        if thresholds.cycles {
+            for cycle in self.cycles() {
+                diagnostics.push(CouplingDiagnostic::Cycle {
+                    names: cycle.iter().map(|&n| self.nodes[n].name.clone()).collect(),
+                });
+            }
+        }
+        diagnostics
+    }
+
+    /// The graph in Graphviz DOT. External nodes are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph dependencies {\n");
+        for node in &self.nodes {
+            let _ = write!(out, "    {}", dot_id(display_name(&node.name)));
+            if node.external {
+                out.push_str(" [style=dashed]");
+            }
+            out.push_str(";\n");
+        }
+        for node in &self.nodes {
+            for &dependency in &node.dependencies {
+                let _ = writeln!(
+                    out,
+                    "    {} -> {};",
+                    dot_id(display_name(&node.name)),
+                    dot_id(display_name(&self.nodes[dependency].name)),
+                );
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// The graph as a JSON object: `nodes` with their metrics and
+    /// dependencies by name, and `cycles` as lists of names.
+    ///
+    /// ```
+    /// use php_analysis::coupling::CouplingAnalysis;
+    ///
+    /// let arena = bumpalo::Bump::new();
+    /// let result = php_rs_parser::parse(&arena, "<?php class A extends B {}");
+    /// let mut analysis = CouplingAnalysis::new();
+    /// analysis.add_file(&result.program);
+    /// assert_eq!(
+    ///     analysis.finish().to_json(),
+    ///     concat!(
+    ///         r#"{"nodes":["#,
+    ///         r#"{"name":"A","external":false,"afferent":0,"efferent":1,"instability":1.000,"dependencies":["B"]},"#,
+    ///         r#"{"name":"B","external":true,"afferent":1,"efferent":0,"instability":0.000,"dependencies":[]}"#,
+    ///         r#"],"cycles":[]}"#,
+    ///     ),
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let metrics = self.metrics();
+        let mut out = String::from("{\"nodes\":[");
+        for (n, node) in self.nodes.iter().enumerate() {
+            if n > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"name\":{},\"external\":{},\"afferent\":{},\"efferent\":{},\"instability\":{:.3},\"dependencies\":",
+                json_string(&node.name),
+                node.external,
+                metrics[n].afferent,
+                metrics[n].efferent,
+                metrics[n].instability(),
+            );
+            self.json_names(&node.dependencies, &mut out);
+            out.push('}');
+        }
+        out.push_str("],\"cycles\":[");
+        for (c, cycle) in self.cycles().iter().enumerate() {
+            if c > 0 {
+                out.push(',');
+            }
+            self.json_names(cycle, &mut out);
+        }
+        out.push_str("]}");
+        out
+    }
+
+    fn json_names(&self, nodes: &[usize], out: &mut String) {
+        out.push('[');
+        for (i, &n) in nodes.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str(&json_string(&self.nodes[n].name));
+        }
+        out.push(']');
+    }
+}
+
+struct Tarjan<'g> {
+    graph: &'g DependencyGraph,
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next);
+        self.low[node] = self.next;
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for &dependency in &self.graph.nodes[node].dependencies {
+            match self.index[dependency] {
+                None => {
+                    self.visit(dependency);
+                    self.low[node] = self.low[node].min(self.low[dependency]);
+                }
+                Some(index) if self.on_stack[dependency] => {
+                    self.low[node] = self.low[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[node]) == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// Limits for [`DependencyGraph::check`]. The default reports cycles only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouplingThresholds {
+    /// The most nodes a node may depend on.
+    pub max_efferent: Option<usize>,
+    /// The most nodes that may depend on a node.
+    pub max_afferent: Option<usize>,
+    /// Report dependency cycles.
+    pub cycles: bool,
+}
+
+impl Default for CouplingThresholds {
+    fn default() -> Self {
+        Self {
+            max_efferent: None,
+            max_afferent: None,
+            cycles: true,
+        }
+    }
+}
+
+/// A node over a [`CouplingThresholds`] limit, or a dependency cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CouplingDiagnostic {
+    Efferent {
+        name: String,
+        location: Option<(usize, Span)>,
+        count: usize,
+        max: usize,
+    },
+    Afferent {
+        name: String,
+        location: Option<(usize, Span)>,
+        count: usize,
+        max: usize,
+    },
+    /// The nodes of one cycle, in node order.
+    Cycle { names: Vec<String> },
+}
+
+impl fmt::Display for CouplingDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CouplingDiagnostic::Efferent {
+                name, count, max, ..
+            } => write!(
+                f,
+                "{} depends on {count} others; the limit is {max}",
+                display_name(name)
+            ),
+            CouplingDiagnostic::Afferent {
+                name, count, max, ..
+            } => write!(
+                f,
+                "{count} others depend on {}; the limit is {max}",
+                display_name(name)
+            ),
+            CouplingDiagnostic::Cycle { names } => {
+                f.write_str("dependency cycle between ")?;
+                for (i, name) in names.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(if i + 1 == names.len() { " and " } else { ", " })?;
+                    }
+                    f.write_str(display_name(name))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The namespace part of a fully-qualified class name.
+fn namespace_of(class: &str) -> &str {
+    class
+        .rsplit_once('\\')
+        .map_or("", |(namespace, _)| namespace)
+}
+
+/// The global namespace is shown as `\`.
+fn display_name(name: &str) -> &str {
+    if name.is_empty() {
+        "\\"
+    } else {
+        name
+    }
+}
+
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,478 @@
+//! Iterative data-flow analysis over a [`Cfg`].
+//!
+//! An analysis implements [`Dataflow`]: a fact type, a direction, how facts
+//! from several predecessors combine, and how one [`CfgElement`] changes a
+//! fact. [`solve`] runs a worklist to the fixpoint and returns the fact at
+//! the start and end of every block; [`DataflowResults::element_facts`]
+//! replays a block to get the fact between its elements.
+//!
+//! Problems over a finite set of items (variables, definitions, tainted
+//! sources) whose transfer only adds or removes items can implement the
+//! simpler [`GenKill`] over a [`BitSet`] and run with [`solve_gen_kill`].
+//!
+//! Only blocks reachable from the entry are analysed; the others keep
+//! [`Dataflow::initial`]. The fixpoint terminates as long as facts form a
+//! lattice of finite height and `join` and `transfer` are monotone.
+
+use crate::cfg::{BlockId, Cfg, CfgElement};
+use crate::dominance::reverse_postorder;
+
+/// The direction facts flow in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the entry along edges, e.g. reaching definitions or taint.
+    Forward,
+    /// From the exit against edges, e.g. live variables.
+    Backward,
+}
+
+/// A data-flow problem over the elements of a [`Cfg`].
+pub trait Dataflow<'a, 'arena, 'src> {
+    type Fact: Clone + PartialEq;
+
+    const DIRECTION: Direction;
+
+    /// The fact on entry to [`Cfg::ENTRY`] (forward) or on exit from
+    /// [`Cfg::EXIT`] (backward).
+    fn boundary(&self) -> Self::Fact;
+
+    /// The fact every other block starts from: the identity of
+    /// [`join`](Self::join), i.e. the empty set for a "may" problem or the
+    /// full set for a "must" problem.
+    fn initial(&self) -> Self::Fact;
+
+    /// Combine the fact from another incoming edge into `into`.
+    fn join(&self, into: &mut Self::Fact, other: &Self::Fact);
+
+    /// Apply the effect of `element` to `fact`. For a backward problem,
+    /// `fact` holds after the element and must be turned into the fact
+    /// before it.
+    fn transfer(&self, element: &CfgElement<'a, 'arena, 'src>, fact: &mut Self::Fact);
+}
+
+/// The fixpoint of a [`Dataflow`] problem: one fact at the start and one at
+/// the end of each block, in execution order regardless of direction.
+#[derive(Debug, Clone)]
+pub struct DataflowResults<F> {
+    before: Vec<F>,
+    after: Vec<F>,
+}
+
+impl<F: Clone> DataflowResults<F> {
+    /// The fact before the first element of `block` runs.
+    pub fn before(&self, block: BlockId) -> &F {
+        &self.before[block]
+    }
+
+    /// The fact after the last element of `block` has run.
+    pub fn after(&self, block: BlockId) -> &F {
+        &self.after[block]
+    }
+
+    /// The facts around each element of `block`, in execution order: entry
+    /// `i` holds before element `i` runs, and the last entry after the
+    /// whole block.
+    pub fn element_facts<'a, 'arena, 'src, A>(
+        &self,
+        cfg: &Cfg<'a, 'arena, 'src>,
+        analysis: &A,
+        block: BlockId,
+    ) -> Vec<F>
+    where
+        A: Dataflow<'a, 'arena, 'src, Fact = F>,
+    {
+        let elements = &cfg.block(block).elements;
+        let mut facts = Vec::with_capacity(elements.len() + 1);
+        match A::DIRECTION {
+            Direction::Forward => {
+                let mut fact = self.before[block].clone();
+                facts.push(fact.clone());
+                for element in elements {
+                    analysis.transfer(element, &mut fact);
+                    facts.push(fact.clone());
+                }
+            }
+            Direction::Backward => {
+                let mut fact = self.after[block].clone();
+                facts.push(fact.clone());
+                for element in elements.iter().rev() {
+                    analysis.transfer(element, &mut fact);
+                    facts.push(fact.clone());
+                }
+                facts.reverse();
+            }
+        }
+        facts
+    }
+}
+
+/// Run `analysis` over `cfg` to its fixpoint.
+///
+/// ```
+/// use php_analysis::cfg::{Cfg, CfgElement};
+/// use php_analysis::dataflow::{solve, Dataflow, Direction};
+///
+/// /// The most statements run on any path to each point.
+/// struct LongestPath;
+///
+/// impl Dataflow<'_, '_, '_> for LongestPath {
+///     type Fact = usize;
+///     const DIRECTION: Direction = Direction::Forward;
+///     fn boundary(&self) -> usize { 0 }
+///     fn initial(&self) -> usize { 0 }
+///     fn join(&self, into: &mut usize, other: &usize) { *into = (*into).max(*other) }
+///     fn transfer(&self, element: &CfgElement<'_, '_, '_>, fact: &mut usize) {
+///         if matches!(element, CfgElement::Stmt(_)) { *fact += 1 }
+///     }
+/// }
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php a(); if ($x) { b(); c(); } else { d(); } e();");
+/// let cfg = Cfg::build(&result.program.stmts);
+/// let facts = solve(&cfg, &LongestPath);
+/// assert_eq!(*facts.before(Cfg::EXIT), 4);
+/// ```
+pub fn solve<'a, 'arena, 'src, A>(
+    cfg: &Cfg<'a, 'arena, 'src>,
+    analysis: &A,
+) -> DataflowResults<A::Fact>
+where
+    A: Dataflow<'a, 'arena, 'src>,
+{
+    let len = cfg.blocks().len();
+    let forward = A::DIRECTION == Direction::Forward;
+    let root = if forward { Cfg::ENTRY } else { Cfg::EXIT };
+    let incoming = |id| match forward {
+        true => &cfg.block(id).preds,
+        false => &cfg.block(id).succs,
+    };
+    let outgoing = |id| match forward {
+        true => &cfg.block(id).succs,
+        false => &cfg.block(id).preds,
+    };
+
+    // Visit blocks in reverse postorder of the flow direction so most facts
+    // are final the first time they are read; blocks that cannot reach the
+    // exit of a backward problem are appended after the others.
+    let mut order = if forward {
+        reverse_postorder(len, Cfg::ENTRY, |id| cfg.block(id).succs.clone())
+    } else {
+        let mut order = reverse_postorder(len, Cfg::EXIT, |id| {
+            cfg.block(id)
+                .preds
+                .iter()
+                .copied()
+                .filter(|&pred| cfg.is_reachable(pred))
+                .collect()
+        });
+        let mut seen = vec![false; len];
+        for &id in &order {
+            seen[id] = true;
+        }
+        order.extend((0..len).filter(|&id| cfg.is_reachable(id) && !seen[id]));
+        order
+    };
+    order.retain(|&id| cfg.is_reachable(id));
+
+    // `start[b]` is the fact where flow enters `b`, `end[b]` where it leaves.
+    let mut start = vec![analysis.initial(); len];
+    let mut end = vec![analysis.initial(); len];
+    let mut queued = vec![false; len];
+    let mut worklist = std::collections::VecDeque::with_capacity(order.len());
+    for &id in &order {
+        queued[id] = true;
+        worklist.push_back(id);
+    }
+
+    while let Some(id) = worklist.pop_front() {
+        queued[id] = false;
+        let mut fact: Option<A::Fact> = (id == root).then(|| analysis.boundary());
+        for &from in incoming(id) {
+            if !cfg.is_reachable(from) {
+                continue;
+            }
+            match &mut fact {
+                Some(fact) => analysis.join(fact, &end[from]),
+                None => fact = Some(end[from].clone()),
+            }
+        }
+        let fact = fact.unwrap_or_else(|| analysis.initial());
+
+        let mut out = fact.clone();
+        let elements = &cfg.block(id).elements;
+        if forward {
+            for element in elements {
+                analysis.transfer(element, &mut out);
+            }
+        } else {
+            for element in elements.iter().rev() {
+                analysis.transfer(element, &mut out);
+            }
+        }
+        start[id] = fact;
+        if out != end[id] {
+            end[id] = out;
+            for &to in outgoing(id) {
+                if cfg.is_reachable(to) && !std::mem::replace(&mut queued[to], true) {
+                    worklist.push_back(to);
+                }
+            }
+        }
+    }
+
+    if forward {
+        DataflowResults {
+            before: start,
+            after: end,
+        }
+    } else {
+        DataflowResults {
+            before: end,
+            after: start,
+        }
+    }
+}
+
+/// A fixed-size set of small integers, the fact type of [`GenKill`]
+/// problems.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// The empty set over `0..len`.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// The set holding all of `0..len`.
+    pub fn full(len: usize) -> Self {
+        let mut set = Self::new(len);
+        set.words.fill(u64::MAX);
+        if !len.is_multiple_of(64) {
+            if let Some(last) = set.words.last_mut() {
+                *last = (1 << (len % 64)) - 1;
+            }
+        }
+        set
+    }
+
+    /// The size of the domain, not the number of members.
+    pub fn domain_size(&self) -> usize {
+        self.len
+    }
+
+    /// Add `item`; returns `true` if it was not already present.
+    pub fn insert(&mut self, item: usize) -> bool {
+        assert!(
+            item < self.len,
+            "{item} is outside a BitSet of {}",
+            self.len
+        );
+        let (word, bit) = (item / 64, 1 << (item % 64));
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    /// Remove `item`; returns `true` if it was present.
+    pub fn remove(&mut self, item: usize) -> bool {
+        assert!(
+            item < self.len,
+            "{item} is outside a BitSet of {}",
+            self.len
+        );
+        let (word, bit) = (item / 64, 1 << (item % 64));
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    pub fn contains(&self, item: usize) -> bool {
+        item < self.len && self.words[item / 64] & (1 << (item % 64)) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// The number of members.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The members in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+
+    /// Add every member of `other`, which must have the same domain.
+    pub fn union_with(&mut self, other: &BitSet) {
+        debug_assert_eq!(self.len, other.len);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Keep only members of `other`, which must have the same domain.
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        debug_assert_eq!(self.len, other.len);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+}
+
+/// How a [`GenKill`] problem combines facts where control flow merges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Join {
+    /// An item holds if it holds on some incoming path ("may").
+    Union,
+    /// An item holds only if it holds on every incoming path ("must").
+    Intersection,
+}
+
+/// A data-flow problem whose facts are sets of item indices and whose
+/// transfer adds ("gens") and removes ("kills") items.
+///
+/// ```
+/// use php_analysis::cfg::{Cfg, CfgElement};
+/// use php_analysis::dataflow::{solve_gen_kill, Direction, GenKill, GenKillEffects, Join};
+/// use php_ast::{ExprKind, StmtKind};
+///
+/// /// Which of `$a` (0) and `$b` (1) have been assigned on every path.
+/// struct Assigned;
+///
+/// impl<'a, 'arena, 'src> GenKill<'a, 'arena, 'src> for Assigned {
+///     const DIRECTION: Direction = Direction::Forward;
+///     const JOIN: Join = Join::Intersection;
+///     fn domain_size(&self) -> usize { 2 }
+///     fn gen_kill(&self, element: &CfgElement<'a, 'arena, 'src>, effects: &mut GenKillEffects<'_>) {
+///         if let CfgElement::Stmt(stmt) = element {
+///             if let StmtKind::Expression(expr) = &stmt.kind {
+///                 if let ExprKind::Assign(assign) = &expr.kind {
+///                     match assign.target.name_str() {
+///                         Some("a") => effects.generate(0),
+///                         Some("b") => effects.generate(1),
+///                         _ => {}
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php $a = 1; if ($x) { $b = 2; } echo $a;";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let cfg = Cfg::build(&result.program.stmts);
+/// let facts = solve_gen_kill(&cfg, &Assigned);
+/// let at_exit: Vec<_> = facts.before(Cfg::EXIT).iter().collect();
+/// assert_eq!(at_exit, [0]);
+/// ```
+pub trait GenKill<'a, 'arena, 'src> {
+    const DIRECTION: Direction;
+    const JOIN: Join;
+
+    /// The number of items; facts are sets over `0..domain_size()`.
+    fn domain_size(&self) -> usize;
+
+    /// The fact on entry to [`Cfg::ENTRY`] (forward) or on exit from
+    /// [`Cfg::EXIT`] (backward). Empty by default.
+    fn boundary(&self) -> BitSet {
+        BitSet::new(self.domain_size())
+    }
+
+    /// Record the items `element` gens and kills, in the order they take
+    /// effect. For a backward problem, effects apply from after the element
+    /// to before it: a live-variables client kills the variables an
+    /// element writes, then gens those it reads.
+    fn gen_kill(&self, element: &CfgElement<'a, 'arena, 'src>, effects: &mut GenKillEffects<'_>);
+}
+
+/// The fact a [`GenKill::gen_kill`] call updates.
+#[derive(Debug)]
+pub struct GenKillEffects<'f> {
+    fact: &'f mut BitSet,
+}
+
+impl GenKillEffects<'_> {
+    /// Add `item` to the fact.
+    pub fn generate(&mut self, item: usize) {
+        self.fact.insert(item);
+    }
+
+    /// Remove `item` from the fact.
+    pub fn kill(&mut self, item: usize) {
+        self.fact.remove(item);
+    }
+
+    /// The fact as updated so far.
+    pub fn fact(&self) -> &BitSet {
+        self.fact
+    }
+}
+
+/// Adapts a [`GenKill`] problem to [`Dataflow`].
+#[derive(Debug, Clone, Copy)]
+pub struct GenKillAnalysis<'p, P>(pub &'p P);
+
+impl<'a, 'arena, 'src, P: GenKill<'a, 'arena, 'src>> Dataflow<'a, 'arena, 'src>
+    for GenKillAnalysis<'_, P>
+{
+    type Fact = BitSet;
+
+    const DIRECTION: Direction = P::DIRECTION;
+
+    fn boundary(&self) -> BitSet {
+        self.0.boundary()
+    }
+
+    fn initial(&self) -> BitSet {
+        match P::JOIN {
+            Join::Union => BitSet::new(self.0.domain_size()),
+            Join::Intersection => BitSet::full(self.0.domain_size()),
+        }
+    }
+
+    fn join(&self, into: &mut BitSet, other: &BitSet) {
+        match P::JOIN {
+            Join::Union => into.union_with(other),
+            Join::Intersection => into.intersect_with(other),
+        }
+    }
+
+    fn transfer(&self, element: &CfgElement<'a, 'arena, 'src>, fact: &mut BitSet) {
+        self.0.gen_kill(element, &mut GenKillEffects { fact });
+    }
+}
+
+/// Run a [`GenKill`] problem over `cfg` to its fixpoint. Use
+/// [`GenKillAnalysis`] to replay blocks with
+/// [`DataflowResults::element_facts`].
+pub fn solve_gen_kill<'a, 'arena, 'src, P>(
+    cfg: &Cfg<'a, 'arena, 'src>,
+    problem: &P,
+) -> DataflowResults<BitSet>
+where
+    P: GenKill<'a, 'arena, 'src>,
+{
+    solve(cfg, &GenKillAnalysis(problem))
+}
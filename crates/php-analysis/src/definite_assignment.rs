@@ -0,0 +1,895 @@
+//! Possibly undefined variables.
+//!
+//! Every function, method, closure, property hook, and file body is checked
+//! with two forward [`dataflow`](crate::dataflow) analyses over its
+//! [`Cfg`]: which variables are assigned on *every* path to a point, and
+//! which on *some* path. A read of a variable assigned on no path is
+//! [`Certainty::Undefined`]; one assigned on some paths only is
+//! [`Certainty::PossiblyUndefined`].
+//!
+//! Besides plain assignment, a variable is defined by parameters, closure
+//! `use`, `global`, `static`, `foreach`, `catch`, destructuring, assigning
+//! to one of its elements (`$a[] = 1`), taking a reference to it, and
+//! passing it to a by-reference parameter of a known built-in (such as the
+//! `$matches` of `preg_match()`) or of a function or method declared in the
+//! same file. `unset()` undefines it again.
+//!
+//! Guards are respected: `isset()`, `empty()`, `??`, `??=`, and `@` do not
+//! read their operand, and code guarded by `isset($x)` or `!empty($x)` — in
+//! an `if`, `elseif`, loop condition, ternary, `&&`, or `||` — sees `$x` as
+//! defined. `$this`, superglobals, and a set hook's implicit `$value` are
+//! always defined.
+//!
+//! `extract()`, `parse_str()` without a result argument, `eval`, `include`,
+//! and `$$name = …` can define any variable; after them nothing is
+//! reported until the paths merge with ones that did not run them.
+//!
+//! A `catch` block is entered from the end of each block of its `try` body
+//! (see [`cfg`](crate::cfg)), so an assignment in the `try` body counts as
+//! done in the `catch` even if the exception came earlier.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{
+    Arg, BinaryOp, ClassMember, ClassMemberKind, ClassRef, Expr, ExprKind, Param, Program, Span,
+    Stmt, StmtKind, UnaryPostfixOp, UnaryPrefixOp,
+};
+
+use crate::cfg::{Cfg, CfgElement};
+use crate::dataflow::{solve, BitSet, Dataflow, Direction, Join};
+use crate::normalize::implicit_captures;
+use crate::purity::SUPERGLOBALS;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// How sure a report is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Certainty {
+    /// No path to the read assigns the variable.
+    Undefined,
+    /// Some paths to the read assign the variable, others do not.
+    PossiblyUndefined,
+}
+
+/// A read of a variable that may not be assigned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndefinedVariable {
+    /// The variable name, without `$`.
+    pub name: String,
+    pub span: Span,
+    pub certainty: Certainty,
+    /// The enclosing unit's display name, e.g. `App\User::save` or `{main}`.
+    pub unit: String,
+}
+
+impl std::fmt::Display for UndefinedVariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.certainty {
+            Certainty::Undefined => write!(f, "undefined variable ${}", self.name),
+            Certainty::PossiblyUndefined => {
+                write!(f, "variable ${} might not be defined", self.name)
+            }
+        }
+    }
+}
+
+/// Report the reads of possibly undefined variables in `program`, in source
+/// order.
+///
+/// ```
+/// use php_analysis::definite_assignment::{undefined_variables, Certainty};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// function f($flag) {
+///     if ($flag) { $msg = 'on'; }
+///     echo $msg, $typo;
+///     if (isset($opt)) { echo $opt; }
+///     preg_match('/x/', 'x', $m);
+///     return $m;
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let found = undefined_variables(&result.program);
+/// let found: Vec<_> = found.iter().map(|f| (f.name.as_str(), f.certainty)).collect();
+/// assert_eq!(
+///     found,
+///     [("msg", Certainty::PossiblyUndefined), ("typo", Certainty::Undefined)],
+/// );
+/// ```
+pub fn undefined_variables(program: &Program<'_, '_>) -> Vec<UndefinedVariable> {
+    let mut declared = DeclaredByRef::default();
+    let _ = declared.visit_program(program);
+    let mut checker = Checker {
+        by_ref: declared,
+        found: Vec::new(),
+    };
+    AnalysisWalker::new(&mut checker).walk(program);
+    checker.found.sort_by_key(|f| f.span);
+    checker.found
+}
+
+struct Checker {
+    by_ref: DeclaredByRef,
+    found: Vec<UndefinedVariable>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Checker {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        // Arrow functions are checked as part of the enclosing body, where
+        // their captures are read.
+        if let Some(assignments) = check_unit(unit, &self.by_ref) {
+            self.found.extend(assignments.undefined);
+        }
+    }
+}
+
+/// What one body assigns and reads before assigning.
+pub(crate) struct UnitAssignments {
+    /// Variables assigned on some path, parameters and `use` imports
+    /// included, in order of first mention.
+    pub(crate) assigned: Vec<String>,
+    /// Reads of possibly undefined variables, in source order.
+    pub(crate) undefined: Vec<UndefinedVariable>,
+}
+
+/// Check `unit` on its own, knowing the by-reference parameters of built-ins
+/// and of the functions and methods declared inside it. `None` for an arrow
+/// function, which has no statement body.
+pub(crate) fn unit_assignments(unit: &Unit<'_, '_, '_>) -> Option<UnitAssignments> {
+    let mut declared = DeclaredByRef::default();
+    for stmt in unit.body? {
+        let _ = declared.visit_stmt(stmt);
+    }
+    check_unit(unit, &declared)
+}
+
+fn check_unit(unit: &Unit<'_, '_, '_>, by_ref: &DeclaredByRef) -> Option<UnitAssignments> {
+    let body = unit.body?;
+    let cfg = Cfg::build(body);
+    let vars = Variables::collect(&cfg, unit);
+    let mut boundary = BitSet::new(vars.len());
+    let defined = unit
+        .params
+        .iter()
+        .filter_map(|param| param.name.as_str())
+        .chain(unit.uses.iter().map(|var| var.name))
+        .chain((unit.kind == UnitKind::Hook).then_some("value"));
+    for name in defined {
+        if let Some(i) = vars.index(name) {
+            boundary.insert(i);
+        }
+    }
+
+    let must = Flow {
+        vars: &vars,
+        by_ref,
+        join: Join::Intersection,
+        boundary: boundary.clone(),
+    };
+    let may = Flow {
+        join: Join::Union,
+        ..must.clone()
+    };
+    let must_reads = must.unassigned_reads(&cfg);
+    let may_reads = may.unassigned_reads(&cfg);
+    let mut undefined = Vec::new();
+    for (span, var) in must_reads {
+        let certainty = match may_reads.contains_key(&span) {
+            true => Certainty::Undefined,
+            false => Certainty::PossiblyUndefined,
+        };
+        undefined.push(UndefinedVariable {
+            name: vars.names[var].clone(),
+            span,
+            certainty,
+            unit: unit.name.clone(),
+        });
+    }
+    let assigned = may.ever_assigned(&cfg);
+    Some(UnitAssignments {
+        assigned: assigned.iter().map(|var| vars.names[var].clone()).collect(),
+        undefined,
+    })
+}
+
+/// The variables of one body, indexed for [`BitSet`] facts.
+pub(crate) struct Variables {
+    pub(crate) names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Variables {
+    pub(crate) fn collect(cfg: &Cfg<'_, '_, '_>, unit: &Unit<'_, '_, '_>) -> Self {
+        let mut collector = NameCollector { names: Vec::new() };
+        collector.names.extend(
+            unit.params
+                .iter()
+                .filter_map(|param| param.name.as_str())
+                .chain(unit.uses.iter().map(|var| var.name))
+                .map(str::to_string),
+        );
+        collector.names.push("value".to_string());
+        for block in cfg.blocks() {
+            for element in &block.elements {
+                let _ = match element {
+                    CfgElement::Stmt(stmt) => collector.visit_stmt(stmt),
+                    CfgElement::Expr(expr) => collector.visit_expr(expr),
+                    CfgElement::ForeachBind(foreach) => {
+                        if let Some(key) = &foreach.key {
+                            let _ = collector.visit_expr(key);
+                        }
+                        collector.visit_expr(&foreach.value)
+                    }
+                    CfgElement::CatchBind(catch) => {
+                        if let Some(var) = &catch.var {
+                            collector.names.push(var.name.to_string());
+                        }
+                        ControlFlow::Continue(())
+                    }
+                    CfgElement::Assume { .. } => ControlFlow::Continue(()),
+                };
+            }
+        }
+        let mut vars = Self {
+            names: Vec::new(),
+            index: HashMap::new(),
+        };
+        for name in collector.names {
+            if !vars.index.contains_key(&name) {
+                vars.index.insert(name.clone(), vars.names.len());
+                vars.names.push(name);
+            }
+        }
+        vars
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub(crate) fn index(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+}
+
+struct NameCollector {
+    names: Vec<String>,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for NameCollector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        if let StmtKind::StaticVar(vars) = &stmt.kind {
+            self.names.extend(
+                vars.iter()
+                    .filter_map(|var| var.name.as_str())
+                    .map(str::to_string),
+            );
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::Variable(name) => self.names.push(name.to_string()),
+            ExprKind::Closure(closure) => self
+                .names
+                .extend(closure.use_vars.iter().map(|var| var.name.to_string())),
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+/// Definite (`Join::Intersection`) or possible (`Join::Union`) assignment.
+#[derive(Clone)]
+struct Flow<'v> {
+    vars: &'v Variables,
+    by_ref: &'v DeclaredByRef,
+    join: Join,
+    boundary: BitSet,
+}
+
+impl Flow<'_> {
+    /// The reads of variables outside the fact at the point of the read,
+    /// keyed by span.
+    fn unassigned_reads(&self, cfg: &Cfg<'_, '_, '_>) -> BTreeMap<Span, usize> {
+        let results = solve(cfg, self);
+        let mut reads = Vec::new();
+        for (id, block) in cfg.blocks().iter().enumerate() {
+            if !cfg.is_reachable(id) {
+                continue;
+            }
+            let mut fact = results.before(id).clone();
+            for element in &block.elements {
+                self.eval(&mut fact, Some(&mut reads)).element(element);
+            }
+        }
+        reads.into_iter().map(|(var, span)| (span, var)).collect()
+    }
+
+    /// The variables assigned at any reachable point.
+    fn ever_assigned(&self, cfg: &Cfg<'_, '_, '_>) -> BitSet {
+        let results = solve(cfg, self);
+        let mut assigned = self.boundary.clone();
+        for (id, block) in cfg.blocks().iter().enumerate() {
+            if !cfg.is_reachable(id) {
+                continue;
+            }
+            let mut fact = results.before(id).clone();
+            for element in &block.elements {
+                self.eval(&mut fact, None).element(element);
+                assigned.union_with(&fact);
+            }
+        }
+        assigned
+    }
+
+    fn eval<'f>(
+        &'f self,
+        fact: &'f mut BitSet,
+        reads: Option<&'f mut Vec<(usize, Span)>>,
+    ) -> Eval<'f> {
+        Eval {
+            flow: self,
+            fact,
+            reads,
+        }
+    }
+}
+
+impl<'a, 'arena, 'src> Dataflow<'a, 'arena, 'src> for Flow<'_> {
+    type Fact = BitSet;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> BitSet {
+        self.boundary.clone()
+    }
+
+    fn initial(&self) -> BitSet {
+        match self.join {
+            Join::Union => BitSet::new(self.vars.len()),
+            Join::Intersection => BitSet::full(self.vars.len()),
+        }
+    }
+
+    fn join(&self, into: &mut BitSet, other: &BitSet) {
+        match self.join {
+            Join::Union => into.union_with(other),
+            Join::Intersection => into.intersect_with(other),
+        }
+    }
+
+    fn transfer(&self, element: &CfgElement<'a, 'arena, 'src>, fact: &mut BitSet) {
+        self.eval(fact, None).element(element);
+    }
+}
+
+/// Applies the effect of one element to a fact, in evaluation order,
+/// optionally recording reads of variables outside the fact.
+struct Eval<'f> {
+    flow: &'f Flow<'f>,
+    fact: &'f mut BitSet,
+    reads: Option<&'f mut Vec<(usize, Span)>>,
+}
+
+impl Eval<'_> {
+    fn element(&mut self, element: &CfgElement<'_, '_, '_>) {
+        match element {
+            CfgElement::Stmt(stmt) => self.stmt(stmt),
+            CfgElement::Expr(expr) => self.expr(expr),
+            CfgElement::ForeachBind(foreach) => {
+                if let Some(key) = &foreach.key {
+                    self.lvalue(key);
+                }
+                self.lvalue(&foreach.value);
+            }
+            CfgElement::CatchBind(catch) => {
+                if let Some(var) = &catch.var {
+                    self.define(var.name);
+                }
+            }
+            CfgElement::Assume { condition, holds } => self.assume(condition, *holds),
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt<'_, '_>) {
+        match &stmt.kind {
+            StmtKind::Expression(expr) | StmtKind::Throw(expr) => self.expr(expr),
+            StmtKind::Echo(echo) => echo.exprs.iter().for_each(|expr| self.expr(expr)),
+            StmtKind::Return(expr) | StmtKind::Break(expr) | StmtKind::Continue(expr) => {
+                if let Some(expr) = expr {
+                    self.expr(expr);
+                }
+            }
+            StmtKind::Global(vars) => vars.iter().for_each(|var| self.lvalue(var)),
+            StmtKind::StaticVar(vars) => {
+                for var in vars.iter() {
+                    if let Some(default) = &var.default {
+                        self.expr(default);
+                    }
+                    if let Some(name) = var.name.as_str() {
+                        self.define(name);
+                    }
+                }
+            }
+            StmtKind::Unset(targets) => {
+                for target in targets.iter() {
+                    match &target.kind {
+                        ExprKind::Variable(name) => {
+                            if let Some(i) = self.flow.vars.index(name) {
+                                self.fact.remove(i);
+                            }
+                        }
+                        _ => self.guarded(target),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr<'_, '_>) {
+        match &expr.kind {
+            ExprKind::Variable(name) => self.read(name, expr.span),
+            ExprKind::Assign(assign) => {
+                match assign.op {
+                    php_ast::AssignOp::Assign if assign.by_ref => self.lvalue(assign.value),
+                    php_ast::AssignOp::Assign => self.expr(assign.value),
+                    php_ast::AssignOp::Coalesce => {
+                        self.guarded(assign.target);
+                        self.expr(assign.value);
+                    }
+                    _ => {
+                        self.expr(assign.target);
+                        self.expr(assign.value);
+                    }
+                }
+                self.lvalue(assign.target);
+            }
+            ExprKind::Binary(binary) => match binary.op {
+                BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => {
+                    self.expr(binary.left);
+                    self.branch(|eval| {
+                        eval.assume(binary.left, true);
+                        eval.expr(binary.right);
+                    });
+                }
+                BinaryOp::BooleanOr | BinaryOp::LogicalOr => {
+                    self.expr(binary.left);
+                    self.branch(|eval| {
+                        eval.assume(binary.left, false);
+                        eval.expr(binary.right);
+                    });
+                }
+                _ => {
+                    self.expr(binary.left);
+                    self.expr(binary.right);
+                }
+            },
+            ExprKind::Ternary(ternary) => {
+                self.expr(ternary.condition);
+                let skipped = self.fact.clone();
+                if let Some(then_expr) = ternary.then_expr {
+                    self.assume(ternary.condition, true);
+                    self.expr(then_expr);
+                }
+                let taken = std::mem::replace(self.fact, skipped);
+                self.assume(ternary.condition, false);
+                self.expr(ternary.else_expr);
+                self.merge(&taken);
+            }
+            ExprKind::NullCoalesce(coalesce) => {
+                self.guarded(coalesce.left);
+                self.branch(|eval| eval.expr(coalesce.right));
+            }
+            ExprKind::Isset(exprs) => exprs.iter().for_each(|expr| self.guarded(expr)),
+            ExprKind::Empty(inner) => self.guarded(inner),
+            ExprKind::ErrorSuppress(suppress) => self.guarded(suppress.expr),
+            ExprKind::UnaryPrefix(unary)
+                if matches!(
+                    unary.op,
+                    UnaryPrefixOp::PreIncrement | UnaryPrefixOp::PreDecrement
+                ) =>
+            {
+                self.expr(unary.operand);
+                self.lvalue(unary.operand);
+            }
+            ExprKind::UnaryPostfix(unary)
+                if matches!(
+                    unary.op,
+                    UnaryPostfixOp::PostIncrement | UnaryPostfixOp::PostDecrement
+                ) =>
+            {
+                self.expr(unary.operand);
+                self.lvalue(unary.operand);
+            }
+            ExprKind::FunctionCall(call) => {
+                let name = match &call.name.kind {
+                    ExprKind::Name(name) => Some(name.join_parts()),
+                    _ => {
+                        self.expr(call.name);
+                        None
+                    }
+                };
+                let name = name.as_deref().map(|name| {
+                    let name = name.trim_start_matches('\\');
+                    name.rsplit('\\').next().unwrap_or(name)
+                });
+                let by_ref = name.and_then(|name| self.flow.by_ref.function(name));
+                self.args(&call.args, by_ref.as_ref());
+                let defines_any = name.is_some_and(|name| {
+                    name.eq_ignore_ascii_case("extract")
+                        || (name.eq_ignore_ascii_case("parse_str") && call.args.len() == 1)
+                });
+                if defines_any {
+                    self.define_all();
+                }
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                self.expr(call.object);
+                self.method_call(call.method, &call.args);
+            }
+            ExprKind::StaticMethodCall(call) => {
+                self.class_ref(&call.class);
+                self.method_call(call.method, &call.args);
+            }
+            ExprKind::New(new) => {
+                self.class_ref(&new.class);
+                let by_ref = self.flow.by_ref.method("__construct");
+                self.args(&new.args, by_ref.as_ref());
+            }
+            ExprKind::Array(elements) => {
+                for element in elements.iter() {
+                    if let Some(key) = &element.key {
+                        self.expr(key);
+                    }
+                    if element.by_ref {
+                        self.lvalue(&element.value);
+                    } else {
+                        self.expr(&element.value);
+                    }
+                }
+            }
+            ExprKind::Match(match_expr) => {
+                self.expr(match_expr.subject);
+                let subject = self.fact.clone();
+                let mut merged: Option<BitSet> = None;
+                for arm in match_expr.arms.iter() {
+                    *self.fact = subject.clone();
+                    for condition in arm.conditions.iter().flat_map(|c| c.iter()) {
+                        self.expr(condition);
+                    }
+                    self.expr(&arm.body);
+                    match &mut merged {
+                        Some(merged) => self.flow.join(merged, self.fact),
+                        None => merged = Some(self.fact.clone()),
+                    }
+                }
+                *self.fact = merged.unwrap_or(subject);
+            }
+            ExprKind::Closure(closure) => {
+                for var in closure.use_vars.iter() {
+                    if var.by_ref {
+                        self.define(var.name);
+                    } else {
+                        self.read(var.name, var.span);
+                    }
+                }
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                for var in implicit_captures(arrow) {
+                    self.read(var.name, var.span);
+                }
+            }
+            ExprKind::AnonymousClass(_) => {}
+            ExprKind::Eval(inner) | ExprKind::Include(_, inner) => {
+                self.expr(inner);
+                self.define_all();
+            }
+            _ => {
+                let _ = walk_expr(&mut Operands(self), expr);
+            }
+        }
+    }
+
+    /// An operand of `isset()`, `empty()`, `??`, `??=`, `unset()`, or `@`:
+    /// the variable at its base is not read, but indexes are.
+    fn guarded(&mut self, expr: &Expr<'_, '_>) {
+        match &expr.kind {
+            ExprKind::Variable(_) => {}
+            ExprKind::ArrayAccess(access) => {
+                self.guarded(access.array);
+                if let Some(index) = access.index {
+                    self.expr(index);
+                }
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                self.guarded(access.object);
+                if !matches!(access.property.kind, ExprKind::Identifier(_)) {
+                    self.expr(access.property);
+                }
+            }
+            ExprKind::Parenthesized(inner) => self.guarded(inner),
+            _ => self.expr(expr),
+        }
+    }
+
+    /// A write target, or a variable bound by reference.
+    fn lvalue(&mut self, expr: &Expr<'_, '_>) {
+        match &expr.kind {
+            ExprKind::Variable(name) => self.define(name),
+            ExprKind::ArrayAccess(access) => {
+                if let Some(index) = access.index {
+                    self.expr(index);
+                }
+                self.lvalue(access.array);
+            }
+            ExprKind::List(list) => {
+                for element in list.elements.iter() {
+                    if let Some(key) = &element.key {
+                        self.expr(key);
+                    }
+                    self.lvalue(&element.value);
+                }
+            }
+            ExprKind::VariableVariable(inner) => {
+                self.expr(inner);
+                self.define_all();
+            }
+            ExprKind::Omit => {}
+            _ => self.expr(expr),
+        }
+    }
+
+    fn method_call(&mut self, method: &Expr<'_, '_>, args: &[Arg<'_, '_>]) {
+        let by_ref = match &method.kind {
+            ExprKind::Identifier(name) => self.flow.by_ref.method(name),
+            _ => {
+                self.expr(method);
+                None
+            }
+        };
+        self.args(args, by_ref.as_ref());
+    }
+
+    fn args(&mut self, args: &[Arg<'_, '_>], by_ref: Option<&ByRef>) {
+        for (i, arg) in args.iter().enumerate() {
+            let is_ref = !arg.unpack
+                && arg.name.is_none()
+                && by_ref.is_some_and(|by_ref| by_ref.contains(i));
+            if is_ref {
+                self.lvalue(&arg.value);
+            } else {
+                self.expr(&arg.value);
+            }
+        }
+    }
+
+    fn class_ref(&mut self, class: &ClassRef<'_, '_>) {
+        if let ClassRef::Expr(expr) = class {
+            self.expr(expr);
+        }
+    }
+
+    /// Evaluate `run` on a path that may be skipped, then merge it with the
+    /// path that skips it.
+    fn branch(&mut self, run: impl FnOnce(&mut Self)) {
+        let skipped = self.fact.clone();
+        run(self);
+        self.merge(&skipped);
+    }
+
+    fn merge(&mut self, other: &BitSet) {
+        self.flow.join(self.fact, other);
+    }
+
+    /// Add the variables that `condition` evaluating to `holds` proves
+    /// defined.
+    fn assume(&mut self, condition: &Expr<'_, '_>, holds: bool) {
+        for name in implied_defined(condition, holds) {
+            self.define(name);
+        }
+    }
+
+    fn read(&mut self, name: &str, span: Span) {
+        if always_defined(name) {
+            return;
+        }
+        if let (Some(i), Some(reads)) = (self.flow.vars.index(name), self.reads.as_mut()) {
+            if !self.fact.contains(i) {
+                reads.push((i, span));
+            }
+        }
+    }
+
+    fn define(&mut self, name: &str) {
+        if let Some(i) = self.flow.vars.index(name) {
+            self.fact.insert(i);
+        }
+    }
+
+    fn define_all(&mut self) {
+        *self.fact = BitSet::full(self.flow.vars.len());
+    }
+}
+
+/// Evaluates the direct operands of an expression with no special rules.
+struct Operands<'e, 'f>(&'e mut Eval<'f>);
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Operands<'_, '_> {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        self.0.expr(expr);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_stmt(&mut self, _stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+fn always_defined(name: &str) -> bool {
+    name == "this" || SUPERGLOBALS.contains(&name)
+}
+
+/// The variables `condition` proves defined when it evaluates to `holds`:
+/// the operands of a truthy `isset()` and of a falsy `empty()`, through
+/// `!`, `&&`, and `||`.
+fn implied_defined<'e>(condition: &'e Expr<'_, '_>, holds: bool) -> Vec<&'e str> {
+    match &condition.kind {
+        ExprKind::Parenthesized(inner) => implied_defined(inner, holds),
+        ExprKind::UnaryPrefix(unary) if unary.op == UnaryPrefixOp::BooleanNot => {
+            implied_defined(unary.operand, !holds)
+        }
+        ExprKind::Isset(exprs) if holds => exprs.iter().filter_map(guard_base).collect(),
+        ExprKind::Empty(inner) if !holds => guard_base(inner).into_iter().collect(),
+        ExprKind::Binary(binary) => {
+            let both = match binary.op {
+                BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => holds,
+                BinaryOp::BooleanOr | BinaryOp::LogicalOr => !holds,
+                _ => return Vec::new(),
+            };
+            let mut left = implied_defined(binary.left, holds);
+            let right = implied_defined(binary.right, holds);
+            if both {
+                left.extend(right);
+            } else {
+                left.retain(|name| right.contains(name));
+            }
+            left
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The variable at the base of an `isset()` or `empty()` operand.
+pub(crate) fn guard_base<'e>(expr: &'e Expr<'_, '_>) -> Option<&'e str> {
+    match &expr.kind {
+        ExprKind::Variable(name) => Some(name.as_str()),
+        ExprKind::ArrayAccess(access) => guard_base(access.array),
+        ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+            guard_base(access.object)
+        }
+        ExprKind::Parenthesized(inner) => guard_base(inner),
+        _ => None,
+    }
+}
+
+/// The by-reference parameters of a function: their positions, and the
+/// position of a by-reference variadic parameter.
+#[derive(Debug, Clone, Default)]
+struct ByRef {
+    positions: Vec<usize>,
+    variadic_from: Option<usize>,
+}
+
+impl ByRef {
+    fn contains(&self, position: usize) -> bool {
+        self.positions.contains(&position)
+            || self.variadic_from.is_some_and(|from| position >= from)
+    }
+
+    fn of(params: &[Param<'_, '_>]) -> Option<Self> {
+        let mut by_ref = Self::default();
+        for (i, param) in params.iter().enumerate().filter(|(_, p)| p.by_ref) {
+            match param.variadic {
+                true => by_ref.variadic_from = Some(i),
+                false => by_ref.positions.push(i),
+            }
+        }
+        (!by_ref.positions.is_empty() || by_ref.variadic_from.is_some()).then_some(by_ref)
+    }
+}
+
+/// Built-in functions whose by-reference parameters can receive an
+/// undefined variable, with those parameters' positions and the position
+/// from which all further arguments are by reference.
+const BUILTIN_FUNCTIONS: &[(&str, &[usize], Option<usize>)] = &[
+    ("preg_match", &[2], None),
+    ("preg_match_all", &[2], None),
+    ("preg_replace", &[4], None),
+    ("preg_replace_callback", &[4], None),
+    ("str_replace", &[3], None),
+    ("str_ireplace", &[3], None),
+    ("parse_str", &[1], None),
+    ("mb_parse_str", &[1], None),
+    ("exec", &[1, 2], None),
+    ("system", &[1], None),
+    ("passthru", &[1], None),
+    ("similar_text", &[2], None),
+    ("getimagesize", &[1], None),
+    ("is_callable", &[2], None),
+    ("headers_sent", &[0, 1], None),
+    ("fsockopen", &[2, 3], None),
+    ("proc_open", &[2], None),
+    ("getmxrr", &[1, 2], None),
+    ("openssl_sign", &[1], None),
+    ("sscanf", &[], Some(2)),
+    ("fscanf", &[], Some(2)),
+    ("mysqli_stmt_bind_result", &[], Some(1)),
+];
+
+/// Methods of built-in classes likewise, matched by method name alone.
+const BUILTIN_METHODS: &[(&str, &[usize], Option<usize>)] = &[
+    ("bind_result", &[], Some(0)),
+    ("bindColumn", &[1], None),
+    ("bindParam", &[1], None),
+];
+
+/// By-reference parameters of the functions and methods declared in the
+/// file, keyed by lowercase short name.
+#[derive(Debug, Default)]
+struct DeclaredByRef {
+    functions: HashMap<String, ByRef>,
+    methods: HashMap<String, ByRef>,
+}
+
+impl DeclaredByRef {
+    fn function(&self, name: &str) -> Option<ByRef> {
+        lookup(&self.functions, BUILTIN_FUNCTIONS, name)
+    }
+
+    fn method(&self, name: &str) -> Option<ByRef> {
+        lookup(&self.methods, BUILTIN_METHODS, name)
+    }
+}
+
+fn lookup(
+    declared: &HashMap<String, ByRef>,
+    builtins: &[(&str, &[usize], Option<usize>)],
+    name: &str,
+) -> Option<ByRef> {
+    declared
+        .get(&name.to_ascii_lowercase())
+        .cloned()
+        .or_else(|| {
+            builtins
+                .iter()
+                .find(|(builtin, ..)| builtin.eq_ignore_ascii_case(name))
+                .map(|(_, positions, variadic_from)| ByRef {
+                    positions: positions.to_vec(),
+                    variadic_from: *variadic_from,
+                })
+        })
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for DeclaredByRef {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        if let StmtKind::Function(func) = &stmt.kind {
+            if let (Some(name), Some(by_ref)) = (func.name.as_str(), ByRef::of(&func.params)) {
+                self.functions.insert(name.to_ascii_lowercase(), by_ref);
+            }
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        if let ClassMemberKind::Method(method) = &member.kind {
+            if let (Some(name), Some(by_ref)) = (method.name.as_str(), ByRef::of(&method.params)) {
+                self.methods.insert(name.to_ascii_lowercase(), by_ref);
+            }
+        }
+        php_ast::visitor::walk_class_member(self, member)
+    }
+}
@@ -0,0 +1,416 @@
+//! Structured comment directives: suppressions and inline type annotations.
+//!
+//! [`Directives::collect`] reads the comments of one file into a table keyed
+//! by the line, and where possible the statement, each directive applies to:
+//!
+//! | Comment                                   | [`DirectiveKind`]                 |
+//! |-------------------------------------------|-----------------------------------|
+//! | `// phpcs:ignore Generic.Files -- reason` | [`PhpcsIgnore`][DirectiveKind::PhpcsIgnore] |
+//! | `// phpcs:disable` … `// phpcs:enable`    | [`PhpcsDisable`][DirectiveKind::PhpcsDisable], [`PhpcsEnable`][DirectiveKind::PhpcsEnable] |
+//! | `// phpcs:ignoreFile`                     | [`PhpcsIgnoreFile`][DirectiveKind::PhpcsIgnoreFile] |
+//! | `// @lint-ignore unused-variable`         | [`LintIgnore`][DirectiveKind::LintIgnore] |
+//! | `/** @var list<User> $users */`           | [`Var`][DirectiveKind::Var]       |
+//!
+//! A comment on a line of its own applies to the next line of code and to the
+//! statement starting there; a comment after code applies to its own line and
+//! to the statement ending just before it. `phpcs:disable` and
+//! `phpcs:enable` instead take effect from their own line on, and
+//! `phpcs:ignoreFile` covers the whole file; neither is attached to a
+//! statement.
+//!
+//! Pass the comments from `ParseResult::comments`. Doc-blocks the parser has
+//! attached to a declaration belong to that declaration and are not read.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_stmt, Visitor};
+use php_ast::{Comment, CommentKind, Program, Span, Stmt};
+
+/// What a directive says.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectiveKind {
+    /// `phpcs:ignore`: suppress the listed sniff codes (all when empty) on
+    /// one line.
+    PhpcsIgnore(Vec<String>),
+    /// `phpcs:disable`: suppress the listed codes (all when empty) until a
+    /// matching `phpcs:enable`.
+    PhpcsDisable(Vec<String>),
+    /// `phpcs:enable`: end a `phpcs:disable` for the listed codes (all when
+    /// empty).
+    PhpcsEnable(Vec<String>),
+    /// `phpcs:ignoreFile`: suppress everything in the file.
+    PhpcsIgnoreFile,
+    /// `@lint-ignore`: suppress the listed rules (all when empty) on one line.
+    LintIgnore(Vec<String>),
+    /// `@var`, `@phpstan-var`, or `@psalm-var` in an inline doc-block: the
+    /// type as written and the variable it names, with its `$`, if any.
+    Var {
+        ty: String,
+        variable: Option<String>,
+    },
+}
+
+impl std::fmt::Display for DirectiveKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let with_codes = |f: &mut std::fmt::Formatter<'_>, name: &str, codes: &[String]| {
+            f.write_str(name)?;
+            if !codes.is_empty() {
+                write!(f, " {}", codes.join(", "))?;
+            }
+            Ok(())
+        };
+        match self {
+            DirectiveKind::PhpcsIgnore(codes) => with_codes(f, "phpcs:ignore", codes),
+            DirectiveKind::PhpcsDisable(codes) => with_codes(f, "phpcs:disable", codes),
+            DirectiveKind::PhpcsEnable(codes) => with_codes(f, "phpcs:enable", codes),
+            DirectiveKind::PhpcsIgnoreFile => f.write_str("phpcs:ignoreFile"),
+            DirectiveKind::LintIgnore(rules) => with_codes(f, "@lint-ignore", rules),
+            DirectiveKind::Var {
+                ty,
+                variable: Some(variable),
+            } => write!(f, "@var {ty} {variable}"),
+            DirectiveKind::Var { ty, variable: None } => write!(f, "@var {ty}"),
+        }
+    }
+}
+
+/// One directive and where it applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive {
+    pub kind: DirectiveKind,
+    /// The comment the directive was read from.
+    pub span: Span,
+    /// The 1-based line the directive applies to.
+    pub line: u32,
+    /// The statement the directive applies to, if a statement starts right
+    /// after the comment (or, for a comment after code, ends right before it).
+    pub stmt: Option<Span>,
+}
+
+/// Every directive in one file, in source order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Directives {
+    directives: Vec<Directive>,
+}
+
+impl Directives {
+    /// Read the directives in `comments`, which must be in source order, and
+    /// attach them to the statements of `program`.
+    ///
+    /// ```
+    /// use php_analysis::directives::{DirectiveKind, Directives};
+    ///
+    /// let arena = bumpalo::Bump::new();
+    /// let src = "<?php
+    /// // phpcs:ignore Generic.PHP.ForbiddenFunctions -- legacy API
+    /// $h = mysql_connect();
+    /// /** @var list<User> $users */
+    /// $users = $repo->all();
+    /// ";
+    /// let result = php_rs_parser::parse(&arena, src);
+    /// let directives = Directives::collect(src, &result.comments, &result.program);
+    ///
+    /// assert!(directives.is_suppressed(3, "Generic.PHP.ForbiddenFunctions.Found"));
+    /// assert!(!directives.is_suppressed(5, "Generic.PHP.ForbiddenFunctions.Found"));
+    ///
+    /// let stmt = result.program.stmts[1].span;
+    /// let var = directives.for_stmt(stmt).next().unwrap();
+    /// assert_eq!(
+    ///     var.kind,
+    ///     DirectiveKind::Var { ty: "list<User>".into(), variable: Some("$users".into()) },
+    /// );
+    /// ```
+    pub fn collect(source: &str, comments: &[Comment<'_>], program: &Program<'_, '_>) -> Self {
+        let lines = LineIndex::new(source);
+        let mut stmts = StmtSpans(Vec::new());
+        let _ = stmts.visit_program(program);
+        let mut stmts = stmts.0;
+        // Outermost first among statements starting at the same offset.
+        stmts.sort_by_key(|span| (span.start, std::cmp::Reverse(span.end)));
+
+        let mut directives = Vec::new();
+        for (i, comment) in comments.iter().enumerate() {
+            let kinds = parse_comment(comment);
+            if kinds.is_empty() {
+                continue;
+            }
+            let line_start = lines.line_start(comment.span.start);
+            let trailing = !source[line_start as usize..comment.span.start as usize]
+                .trim()
+                .is_empty();
+            let (line, stmt) = if trailing {
+                let stmt = stmts
+                    .iter()
+                    .filter(|s| s.end <= comment.span.start && s.end > line_start)
+                    .max_by_key(|s| (s.end, std::cmp::Reverse(s.start)))
+                    .copied();
+                (lines.line(comment.span.start), stmt)
+            } else {
+                let next = next_code(source, &comments[i..]);
+                let stmt = stmts.iter().find(|s| s.start == next).copied();
+                (lines.line(next), stmt)
+            };
+            for kind in kinds {
+                let (line, stmt) = match kind {
+                    DirectiveKind::PhpcsDisable(_)
+                    | DirectiveKind::PhpcsEnable(_)
+                    | DirectiveKind::PhpcsIgnoreFile => (lines.line(comment.span.start), None),
+                    _ => (line, stmt),
+                };
+                directives.push(Directive {
+                    kind,
+                    span: comment.span,
+                    line,
+                    stmt,
+                });
+            }
+        }
+        Self { directives }
+    }
+
+    /// All directives, in source order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Directive> {
+        self.directives.iter()
+    }
+
+    /// The directives that apply to the 1-based `line`. `phpcs:disable`,
+    /// `phpcs:enable`, and `phpcs:ignoreFile` are listed on the line they
+    /// appear on.
+    pub fn on_line(&self, line: u32) -> impl Iterator<Item = &Directive> {
+        self.directives.iter().filter(move |d| d.line == line)
+    }
+
+    /// The directives attached to the statement spanning `stmt`.
+    pub fn for_stmt(&self, stmt: Span) -> impl Iterator<Item = &Directive> {
+        self.directives.iter().filter(move |d| d.stmt == Some(stmt))
+    }
+
+    /// `true` when a finding with `code` on the 1-based `line` is suppressed.
+    ///
+    /// A listed code covers itself and every code it is a dot-separated
+    /// prefix of, as in phpcs: `Generic.Files` covers
+    /// `Generic.Files.LineLength.TooLong`.
+    ///
+    /// ```
+    /// use php_analysis::directives::Directives;
+    ///
+    /// let arena = bumpalo::Bump::new();
+    /// let src = "<?php
+    /// // phpcs:disable Generic.Files
+    /// $a = 1;
+    /// // phpcs:enable
+    /// $b = 2; // @lint-ignore unused-variable
+    /// ";
+    /// let result = php_rs_parser::parse(&arena, src);
+    /// let directives = Directives::collect(src, &result.comments, &result.program);
+    /// assert!(directives.is_suppressed(3, "Generic.Files.LineLength.TooLong"));
+    /// assert!(!directives.is_suppressed(3, "Generic.PHP"));
+    /// assert!(!directives.is_suppressed(5, "Generic.Files.LineLength.TooLong"));
+    /// assert!(directives.is_suppressed(5, "unused-variable"));
+    /// ```
+    pub fn is_suppressed(&self, line: u32, code: &str) -> bool {
+        let mut disabled = false;
+        for directive in &self.directives {
+            match &directive.kind {
+                DirectiveKind::PhpcsIgnoreFile => return true,
+                DirectiveKind::PhpcsIgnore(codes) | DirectiveKind::LintIgnore(codes)
+                    if directive.line == line && covers(codes, code) =>
+                {
+                    return true;
+                }
+                DirectiveKind::PhpcsDisable(codes)
+                    if directive.line <= line && covers(codes, code) =>
+                {
+                    disabled = true;
+                }
+                DirectiveKind::PhpcsEnable(codes)
+                    if directive.line <= line && covers(codes, code) =>
+                {
+                    disabled = false;
+                }
+                _ => {}
+            }
+        }
+        disabled
+    }
+}
+
+impl<'a> IntoIterator for &'a Directives {
+    type Item = &'a Directive;
+    type IntoIter = std::slice::Iter<'a, Directive>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn covers(codes: &[String], code: &str) -> bool {
+    codes.is_empty()
+        || codes.iter().any(|listed| {
+            code.strip_prefix(listed.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+}
+
+/// Offset of the first code after `comments[0]`, skipping whitespace and the
+/// comments that directly follow it.
+fn next_code(source: &str, comments: &[Comment<'_>]) -> u32 {
+    let mut pos = comments[0].span.end as usize;
+    let mut rest = comments[1..].iter().peekable();
+    loop {
+        let skipped = source[pos..].len() - source[pos..].trim_start().len();
+        pos += skipped;
+        match rest.peek() {
+            Some(comment) if comment.span.start as usize == pos => {
+                pos = comment.span.end as usize;
+                rest.next();
+            }
+            _ => return pos as u32,
+        }
+    }
+}
+
+/// The directives in one comment.
+fn parse_comment(comment: &Comment<'_>) -> Vec<DirectiveKind> {
+    let content = match comment.kind {
+        CommentKind::Line => comment.text.strip_prefix("//"),
+        CommentKind::Hash => comment.text.strip_prefix('#'),
+        CommentKind::Block | CommentKind::Doc => comment
+            .text
+            .strip_prefix("/*")
+            .map(|text| text.strip_suffix("*/").unwrap_or(text))
+            .map(|text| text.trim_start_matches('*')),
+    }
+    .unwrap_or_default();
+    let first_line = content
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+
+    if let Some(directive) = first_line
+        .strip_prefix('@')
+        .unwrap_or(first_line)
+        .strip_prefix("phpcs:")
+    {
+        let (name, args) = directive
+            .split_once(char::is_whitespace)
+            .unwrap_or((directive, ""));
+        return match name {
+            "ignore" => vec![DirectiveKind::PhpcsIgnore(codes(args, &[',']))],
+            "disable" => vec![DirectiveKind::PhpcsDisable(codes(args, &[',']))],
+            "enable" => vec![DirectiveKind::PhpcsEnable(codes(args, &[',']))],
+            "ignoreFile" => vec![DirectiveKind::PhpcsIgnoreFile],
+            _ => Vec::new(),
+        };
+    }
+    if let Some(args) = first_line.strip_prefix("@lint-ignore") {
+        if args.is_empty() || args.starts_with(char::is_whitespace) {
+            let rules = codes(args, &[',', ' ', '\t']);
+            return vec![DirectiveKind::LintIgnore(rules)];
+        }
+    }
+    if comment.kind == CommentKind::Doc {
+        let doc = phpdoc_parser::parse(comment.text);
+        return doc
+            .tags
+            .iter()
+            .filter(|tag| matches!(tag.name.as_str(), "var" | "phpstan-var" | "psalm-var"))
+            .filter_map(|tag| phpdoc_parser::body_text(&tag.body))
+            .filter_map(|body| parse_var(&body))
+            .collect();
+    }
+    Vec::new()
+}
+
+/// The codes listed in `args`, before any `-- reason`.
+fn codes(args: &str, separators: &[char]) -> Vec<String> {
+    let args = args.split_once("--").map_or(args, |(codes, _)| codes);
+    args.split(separators)
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Read `Type $var`, `Type`, or `$var Type` from an `@var` body.
+fn parse_var(body: &str) -> Option<DirectiveKind> {
+    let body = body.trim();
+    if body.starts_with('$') {
+        let (variable, rest) = body.split_once(char::is_whitespace)?;
+        let (ty, _) = split_type(rest.trim_start());
+        return Some(DirectiveKind::Var {
+            ty: ty.to_string(),
+            variable: Some(variable.to_string()),
+        });
+    }
+    let (ty, rest) = split_type(body);
+    if ty.is_empty() {
+        return None;
+    }
+    let variable = rest
+        .split_whitespace()
+        .next()
+        .filter(|word| word.starts_with('$') && word.len() > 1)
+        .map(str::to_string);
+    Some(DirectiveKind::Var {
+        ty: ty.to_string(),
+        variable,
+    })
+}
+
+/// Split a leading type off `text`. Whitespace inside brackets, as in
+/// `array<int, string>`, and around `|`, `&`, or a callable's `:` does not
+/// end the type.
+pub(crate) fn split_type(text: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    let mut joined = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' | '(' | '{' | '[' => depth += 1,
+            '>' | ')' | '}' | ']' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 && !joined => {
+                let rest = text[i..].trim_start();
+                if rest.starts_with(['|', '&', ':']) {
+                    continue;
+                }
+                return (&text[..i], rest);
+            }
+            _ => {}
+        }
+        if !c.is_whitespace() {
+            joined = matches!(c, '|' | '&' | ':') && depth == 0;
+        }
+    }
+    (text, "")
+}
+
+/// Spans of every statement, closures and class bodies included.
+struct StmtSpans(Vec<Span>);
+
+impl<'arena, 'src> Visitor<'arena, 'src> for StmtSpans {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        self.0.push(stmt.span);
+        walk_stmt(self, stmt)
+    }
+}
+
+/// Byte offsets of line starts, for 1-based line numbers.
+struct LineIndex(Vec<u32>);
+
+impl LineIndex {
+    fn new(source: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i as u32 + 1))
+            .collect();
+        Self(starts)
+    }
+
+    fn line(&self, offset: u32) -> u32 {
+        self.0.partition_point(|&start| start <= offset) as u32
+    }
+
+    fn line_start(&self, offset: u32) -> u32 {
+        self.0[self.line(offset) as usize - 1]
+    }
+}
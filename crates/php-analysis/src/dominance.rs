@@ -0,0 +1,239 @@
+//! Dominator and post-dominator trees over a [`Cfg`].
+//!
+//! Block `a` dominates block `b` when every path from the entry to `b` passes
+//! through `a`; it post-dominates `b` when every path from `b` to the exit
+//! passes through `a`. Both trees are computed with the iterative algorithm
+//! of Cooper, Harvey, and Kennedy ("A Simple, Fast Dominance Algorithm").
+//!
+//! Blocks unreachable from the entry have no dominators. For post-dominance,
+//! blocks that cannot reach the exit (the body of `while (true)` without a
+//! `break`, for example) have no post-dominators.
+
+use crate::cfg::{BlockId, Cfg};
+
+/// A dominator or post-dominator tree.
+///
+/// ```
+/// use php_analysis::cfg::Cfg;
+/// use php_analysis::dominance::Dominators;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php if ($a) { f(); } else { g(); } h();");
+/// let cfg = Cfg::build(&result.program.stmts);
+///
+/// let doms = Dominators::compute(&cfg);
+/// let post = Dominators::post_dominators(&cfg);
+/// let branches = cfg.block(Cfg::ENTRY).succs.clone();
+/// assert_eq!(branches.len(), 2);
+/// for &branch in &branches {
+///     assert_eq!(doms.immediate_dominator(branch), Some(Cfg::ENTRY));
+///     assert!(!doms.dominates(branch, Cfg::EXIT));
+/// }
+/// // The join block after the `if` post-dominates the entry.
+/// let join = cfg.block(branches[0]).succs[0];
+/// assert!(post.dominates(join, Cfg::ENTRY));
+/// assert_eq!(doms.frontier(branches[0]), [join]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dominators {
+    root: BlockId,
+    /// Immediate dominator of each block; the root maps to itself.
+    idom: Vec<Option<BlockId>>,
+    /// Dominance frontier of each block.
+    frontiers: Vec<Vec<BlockId>>,
+}
+
+impl Dominators {
+    /// The dominator tree, rooted at [`Cfg::ENTRY`].
+    pub fn compute(cfg: &Cfg<'_, '_, '_>) -> Self {
+        Self::build(
+            cfg,
+            Cfg::ENTRY,
+            |id| &cfg.block(id).succs,
+            |id| &cfg.block(id).preds,
+            |_| true,
+        )
+    }
+
+    /// The post-dominator tree, rooted at [`Cfg::EXIT`]. Only blocks
+    /// reachable from the entry are considered.
+    pub fn post_dominators(cfg: &Cfg<'_, '_, '_>) -> Self {
+        Self::build(
+            cfg,
+            Cfg::EXIT,
+            |id| &cfg.block(id).preds,
+            |id| &cfg.block(id).succs,
+            |id| cfg.is_reachable(id),
+        )
+    }
+
+    fn build<'c>(
+        cfg: &'c Cfg<'_, '_, '_>,
+        root: BlockId,
+        succs: impl Fn(BlockId) -> &'c [BlockId],
+        preds: impl Fn(BlockId) -> &'c [BlockId],
+        live: impl Fn(BlockId) -> bool,
+    ) -> Self {
+        let len = cfg.blocks().len();
+        if !live(root) {
+            return Self {
+                root,
+                idom: vec![None; len],
+                frontiers: vec![Vec::new(); len],
+            };
+        }
+        let order = reverse_postorder(len, root, |id| {
+            succs(id)
+                .iter()
+                .copied()
+                .filter(|&succ| live(succ))
+                .collect()
+        });
+        let mut rank = vec![usize::MAX; len];
+        for (i, &id) in order.iter().enumerate() {
+            rank[id] = i;
+        }
+
+        let mut idom = vec![None; len];
+        idom[root] = Some(root);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &id in order.iter().skip(1) {
+                let mut new_idom = None;
+                for &pred in preds(id) {
+                    if idom[pred].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => pred,
+                        Some(other) => intersect(&idom, &rank, pred, other),
+                    });
+                }
+                if new_idom.is_some() && idom[id] != new_idom {
+                    idom[id] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut frontiers = vec![Vec::new(); len];
+        for &id in &order {
+            let live_preds: Vec<BlockId> = preds(id)
+                .iter()
+                .copied()
+                .filter(|&pred| idom[pred].is_some())
+                .collect();
+            if live_preds.len() < 2 {
+                continue;
+            }
+            for pred in live_preds {
+                let mut runner = pred;
+                while Some(runner) != idom[id] {
+                    if !frontiers[runner].contains(&id) {
+                        frontiers[runner].push(id);
+                    }
+                    if runner == root {
+                        break;
+                    }
+                    runner = idom[runner].expect("processed block has a dominator");
+                }
+            }
+        }
+        for frontier in &mut frontiers {
+            frontier.sort_unstable();
+        }
+
+        Self {
+            root,
+            idom,
+            frontiers,
+        }
+    }
+
+    /// [`Cfg::ENTRY`] for dominators, [`Cfg::EXIT`] for post-dominators.
+    pub fn root(&self) -> BlockId {
+        self.root
+    }
+
+    /// `true` if `id` is in the tree: reachable from the entry, or for
+    /// post-dominators, able to reach the exit.
+    pub fn contains(&self, id: BlockId) -> bool {
+        self.idom[id].is_some()
+    }
+
+    /// The closest strict dominator of `id`; `None` for the root and for
+    /// blocks outside the tree.
+    pub fn immediate_dominator(&self, id: BlockId) -> Option<BlockId> {
+        self.idom[id].filter(|_| id != self.root)
+    }
+
+    /// `id` and its dominators, innermost first, ending with the root. Empty
+    /// for blocks outside the tree.
+    pub fn dominators(&self, id: BlockId) -> impl Iterator<Item = BlockId> + '_ {
+        let mut next = self.contains(id).then_some(id);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = self.immediate_dominator(current);
+            Some(current)
+        })
+    }
+
+    /// `true` if `a` dominates `b`. Every block in the tree dominates
+    /// itself.
+    pub fn dominates(&self, a: BlockId, b: BlockId) -> bool {
+        self.dominators(b).any(|d| d == a)
+    }
+
+    /// `true` if `a` dominates `b` and is not `b`.
+    pub fn strictly_dominates(&self, a: BlockId, b: BlockId) -> bool {
+        a != b && self.dominates(a, b)
+    }
+
+    /// The blocks where `id`'s dominance ends: those with a predecessor
+    /// (for post-dominators, a successor) that `id` dominates while not
+    /// strictly dominating the block itself. Sorted by block id.
+    pub fn frontier(&self, id: BlockId) -> &[BlockId] {
+        &self.frontiers[id]
+    }
+}
+
+/// The blocks reachable from `root` along `succs`, in reverse postorder.
+pub(crate) fn reverse_postorder(
+    len: usize,
+    root: BlockId,
+    succs: impl Fn(BlockId) -> Vec<BlockId>,
+) -> Vec<BlockId> {
+    let mut visited = vec![false; len];
+    let mut postorder = Vec::with_capacity(len);
+    let mut stack = vec![(root, succs(root).into_iter())];
+    visited[root] = true;
+    while let Some((id, pending)) = stack.last_mut() {
+        let id = *id;
+        match pending.next() {
+            Some(succ) => {
+                if !std::mem::replace(&mut visited[succ], true) {
+                    stack.push((succ, succs(succ).into_iter()));
+                }
+            }
+            None => {
+                postorder.push(id);
+                stack.pop();
+            }
+        }
+    }
+    postorder.reverse();
+    postorder
+}
+
+fn intersect(idom: &[Option<BlockId>], rank: &[usize], mut a: BlockId, mut b: BlockId) -> BlockId {
+    while a != b {
+        while rank[a] > rank[b] {
+            a = idom[a].expect("processed block has a dominator");
+        }
+        while rank[b] > rank[a] {
+            b = idom[b].expect("processed block has a dominator");
+        }
+    }
+    a
+}
@@ -0,0 +1,266 @@
+//! Inventory of dynamic language constructs.
+//!
+//! Lists the places in a file where names are computed at run time or where
+//! behaviour is routed through magic methods — the constructs that limit
+//! what a static analyser can know. Comparing counts across files gives a
+//! quick measure of how analysable a codebase is.
+
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_class_member, walk_enum_member, walk_expr, Visitor};
+use php_ast::{
+    BinaryOp, ClassMember, ClassMemberKind, ClassRef, EnumMember, EnumMemberKind, Expr, ExprKind,
+    MethodDecl, NameKind, Program, Span,
+};
+
+/// A category of dynamic construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DynamicKind {
+    /// `eval(...)`.
+    Eval,
+    /// `$$name` or `${expr}`.
+    VariableVariable,
+    /// `$f()` or another call through a computed callee.
+    VariableFunction,
+    /// `$obj->$method()`, `Foo::$method()`.
+    VariableMethod,
+    /// `$obj->$prop`, `Foo::$$prop`.
+    VariableProperty,
+    /// `Foo::{$name}` class constant fetch.
+    VariableConstant,
+    /// `new $class`, `$class::member`, `$x instanceof $class`.
+    VariableClass,
+    /// `new static`, `static::member`, `$x instanceof static`.
+    LateStaticBinding,
+    /// `include`/`require` of a path that is not a literal.
+    DynamicInclude,
+    /// `extract(...)`.
+    Extract,
+    /// `compact(...)`.
+    Compact,
+    /// `call_user_func`, `call_user_func_array`, `forward_static_call`, `forward_static_call_array`.
+    CallUserFunc,
+    /// A `__call` or `__callStatic` method.
+    MagicCall,
+    /// A `__get`, `__set`, `__isset`, or `__unset` method.
+    MagicProperty,
+}
+
+impl std::fmt::Display for DynamicKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DynamicKind::Eval => "eval",
+            DynamicKind::VariableVariable => "variable variable",
+            DynamicKind::VariableFunction => "variable function call",
+            DynamicKind::VariableMethod => "variable method call",
+            DynamicKind::VariableProperty => "variable property access",
+            DynamicKind::VariableConstant => "variable class constant",
+            DynamicKind::VariableClass => "variable class reference",
+            DynamicKind::LateStaticBinding => "late static binding",
+            DynamicKind::DynamicInclude => "dynamic include",
+            DynamicKind::Extract => "extract",
+            DynamicKind::Compact => "compact",
+            DynamicKind::CallUserFunc => "call_user_func",
+            DynamicKind::MagicCall => "magic __call method",
+            DynamicKind::MagicProperty => "magic property method",
+        })
+    }
+}
+
+/// One occurrence of a dynamic construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicUse {
+    pub kind: DynamicKind,
+    pub span: Span,
+}
+
+/// The dynamic constructs found in one file.
+#[derive(Debug, Clone, Default)]
+pub struct DynamicInventory {
+    /// Occurrences in source order.
+    pub uses: Vec<DynamicUse>,
+}
+
+impl DynamicInventory {
+    /// Number of occurrences of each kind that appears at least once.
+    pub fn counts(&self) -> BTreeMap<DynamicKind, usize> {
+        let mut counts = BTreeMap::new();
+        for u in &self.uses {
+            *counts.entry(u.kind).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// `true` if the file uses no dynamic constructs.
+    pub fn is_empty(&self) -> bool {
+        self.uses.is_empty()
+    }
+}
+
+/// Catalogue the dynamic constructs in `program`.
+///
+/// ```
+/// use php_analysis::dynamic::{inventory, DynamicKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php $f = 'strlen'; echo $f('x'); extract($_GET);");
+/// let kinds: Vec<_> = inventory(&result.program).uses.iter().map(|u| u.kind).collect();
+/// assert_eq!(kinds, [DynamicKind::VariableFunction, DynamicKind::Extract]);
+/// ```
+pub fn inventory(program: &Program<'_, '_>) -> DynamicInventory {
+    let mut collector = Collector { uses: Vec::new() };
+    let _ = collector.visit_program(program);
+    collector
+        .uses
+        .sort_by_key(|u| (u.span.start, u.span.end, u.kind));
+    DynamicInventory {
+        uses: collector.uses,
+    }
+}
+
+struct Collector {
+    uses: Vec<DynamicUse>,
+}
+
+impl Collector {
+    fn record(&mut self, kind: DynamicKind, span: Span) {
+        self.uses.push(DynamicUse { kind, span });
+    }
+
+    /// Record a class reference that is not resolved at compile time.
+    fn class_ref(&mut self, class: &ClassRef<'_, '_>) {
+        match class {
+            ClassRef::Name(_) | ClassRef::SelfKw(_) | ClassRef::ParentKw(_) => {}
+            ClassRef::StaticKw(span) => self.record(DynamicKind::LateStaticBinding, *span),
+            ClassRef::Expr(expr) => match &expr.kind {
+                ExprKind::AnonymousClass(_) => {}
+                ExprKind::Variable(name) if name.as_str() == "this" => {}
+                _ => self.record(DynamicKind::VariableClass, expr.span),
+            },
+        }
+    }
+
+    fn magic_method(&mut self, method: &MethodDecl<'_, '_>, span: Span) {
+        let name = method.name.or_error();
+        let is = |magic: &str| name.eq_ignore_ascii_case(magic);
+        if is("__call") || is("__callStatic") {
+            self.record(DynamicKind::MagicCall, span);
+        } else if is("__get") || is("__set") || is("__isset") || is("__unset") {
+            self.record(DynamicKind::MagicProperty, span);
+        }
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        let span = expr.span;
+        match &expr.kind {
+            ExprKind::Eval(_) => self.record(DynamicKind::Eval, span),
+            ExprKind::VariableVariable(_) => self.record(DynamicKind::VariableVariable, span),
+            ExprKind::FunctionCall(call) => match &call.name.kind {
+                ExprKind::Name(name) => {
+                    let global = match name.kind() {
+                        NameKind::Unqualified | NameKind::FullyQualified => name.join_parts(),
+                        _ => "".into(),
+                    };
+                    let kind = match global.to_ascii_lowercase().as_str() {
+                        "extract" => Some(DynamicKind::Extract),
+                        "compact" => Some(DynamicKind::Compact),
+                        "call_user_func"
+                        | "call_user_func_array"
+                        | "forward_static_call"
+                        | "forward_static_call_array" => Some(DynamicKind::CallUserFunc),
+                        _ => None,
+                    };
+                    if let Some(kind) = kind {
+                        self.record(kind, span);
+                    }
+                }
+                ExprKind::Closure(_) | ExprKind::ArrowFunction(_) => {}
+                ExprKind::Parenthesized(inner)
+                    if matches!(
+                        inner.kind,
+                        ExprKind::Closure(_) | ExprKind::ArrowFunction(_)
+                    ) => {}
+                _ => self.record(DynamicKind::VariableFunction, span),
+            },
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call)
+                if !is_static_member(call.method) =>
+            {
+                self.record(DynamicKind::VariableMethod, span)
+            }
+            ExprKind::StaticMethodCall(call) => self.class_ref(&call.class),
+            ExprKind::StaticDynMethodCall(call) => {
+                self.class_ref(&call.class);
+                self.record(DynamicKind::VariableMethod, span);
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access)
+                if !is_static_member(access.property) =>
+            {
+                self.record(DynamicKind::VariableProperty, span)
+            }
+            ExprKind::StaticPropertyAccess(access) | ExprKind::ClassConstAccess(access) => {
+                self.class_ref(&access.class)
+            }
+            ExprKind::StaticPropertyAccessDynamic { class, .. } => {
+                self.class_ref(class);
+                self.record(DynamicKind::VariableProperty, span);
+            }
+            ExprKind::ClassConstAccessDynamic { class, .. } => {
+                self.class_ref(class);
+                self.record(DynamicKind::VariableConstant, span);
+            }
+            ExprKind::New(new) => self.class_ref(&new.class),
+            ExprKind::Instanceof(instanceof) => self.class_ref(&instanceof.class),
+            ExprKind::Include(_, path) if !is_static_path(path) => {
+                self.record(DynamicKind::DynamicInclude, span)
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        if let ClassMemberKind::Method(method) = &member.kind {
+            self.magic_method(method, member.span);
+        }
+        walk_class_member(self, member)
+    }
+
+    fn visit_enum_member(&mut self, member: &EnumMember<'arena, 'src>) -> ControlFlow<()> {
+        if let EnumMemberKind::Method(method) = &member.kind {
+            self.magic_method(method, member.span);
+        }
+        walk_enum_member(self, member)
+    }
+}
+
+/// `true` for a member name written as an identifier or string literal.
+fn is_static_member(member: &Expr<'_, '_>) -> bool {
+    matches!(
+        member.kind,
+        ExprKind::Identifier(_) | ExprKind::String { .. }
+    )
+}
+
+/// `true` if an include path is built only from literals, magic constants,
+/// constants, and concatenation (e.g. `__DIR__ . '/config.php'`).
+fn is_static_path(path: &Expr<'_, '_>) -> bool {
+    match &path.kind {
+        ExprKind::String { .. }
+        | ExprKind::Nowdoc { .. }
+        | ExprKind::MagicConst(_)
+        | ExprKind::ConstFetch(_) => true,
+        ExprKind::Parenthesized(inner) => is_static_path(inner),
+        ExprKind::Binary(binary) if binary.op == BinaryOp::Concat => {
+            is_static_path(binary.left) && is_static_path(binary.right)
+        }
+        ExprKind::FunctionCall(call) => {
+            // `dirname(__DIR__)` and similar.
+            matches!(&call.name.kind, ExprKind::Name(name) if name.join_parts().eq_ignore_ascii_case("dirname"))
+                && call.args.iter().all(|arg| is_static_path(&arg.value))
+        }
+        _ => false,
+    }
+}
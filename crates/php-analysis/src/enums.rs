@@ -0,0 +1,618 @@
+//! Project-wide enum index and exhaustiveness lint.
+//!
+//! [`EnumIndex`] records every enum declared in the project with its cases,
+//! and every `Enum::Case` reference in executable code, resolved through
+//! namespaces, `use` imports, and `self`/`static` inside the enum. Constant
+//! and property initializers are not walked.
+//!
+//! Without type inference, a `switch` or `match` is taken to be over an enum
+//! when every case label names a case of the same indexed enum. Such a
+//! branch is reported when it misses cases, or when it has a `default`: a
+//! `default` over a closed set of cases hides the ones added later.
+//!
+//! ```php
+//! enum Suit { case Hearts; case Spades; case Clubs; }
+//!
+//! match ($suit) { Suit::Hearts => 'red' };          // misses Spades, Clubs
+//! match ($suit) { Suit::Hearts => 'red', default => 'black' };
+//! // fix: Suit::Hearts => 'red', Suit::Spades, Suit::Clubs => 'black'
+//! ```
+//!
+//! Missing cases are fixed by appending arms that keep today's behaviour: a
+//! `match` arm throwing `\UnhandledMatchError`, or `switch` cases ending in
+//! `break`. A `default` is fixed by replacing it with the cases it stands
+//! for.
+//!
+//! Backed enums whose cases evaluate to the same [`PhpValue`], which PHP
+//! rejects at compile time, are listed by [`EnumIndex::duplicate_values`].
+
+use std::collections::HashMap;
+
+use php_ast::{
+    ClassRef, EnumMember, EnumMemberKind, Expr, ExprKind, Program, Span, Stmt, StmtKind,
+};
+
+use crate::codemod::TextEdit;
+use crate::values::{evaluate_with, PhpValue};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker};
+
+/// An enum declared in the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumInfo {
+    /// Fully-qualified name.
+    pub name: String,
+    pub file: usize,
+    /// The enum declaration.
+    pub span: Span,
+    /// Case names with their declarations, in declaration order.
+    pub cases: Vec<(String, Span)>,
+}
+
+impl EnumInfo {
+    /// `true` if the enum declares `case` (case-sensitively, as PHP does).
+    pub fn has_case(&self, case: &str) -> bool {
+        self.cases.iter().any(|(name, _)| name == case)
+    }
+}
+
+/// A reference to an enum case, `Suit::Hearts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseReference {
+    pub file: usize,
+    pub span: Span,
+    /// Fully-qualified enum name as resolved at the reference.
+    pub enum_name: String,
+    pub case: String,
+}
+
+/// The branching construct an [`EnumBranchFinding`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BranchKind {
+    Switch,
+    Match,
+}
+
+impl std::fmt::Display for BranchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BranchKind::Switch => "switch",
+            BranchKind::Match => "match",
+        })
+    }
+}
+
+/// A `switch` or `match` over an enum that does not list every case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumBranchFinding {
+    pub file: usize,
+    /// The `switch` statement or `match` expression.
+    pub span: Span,
+    pub kind: BranchKind,
+    /// Fully-qualified enum name.
+    pub enum_name: String,
+    /// Cases without a label of their own, in declaration order.
+    pub missing: Vec<String>,
+    /// The `default` keyword, if the branch has one.
+    pub default: Option<Span>,
+    /// Inserts the missing arms or replaces `default` with them. `None` for
+    /// a `default` that no case reaches.
+    pub fix: Option<TextEdit>,
+}
+
+impl std::fmt::Display for EnumBranchFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, name) = (self.kind, &self.enum_name);
+        match (self.default.is_some(), self.missing.is_empty()) {
+            (false, _) => write!(
+                f,
+                "{kind} over {name} does not handle {}",
+                self.missing.join(", ")
+            ),
+            (true, false) => write!(
+                f,
+                "{kind} over {name} uses `default` for {}; list the cases instead",
+                self.missing.join(", ")
+            ),
+            (true, true) => write!(
+                f,
+                "{kind} over {name} handles every case; `default` is unreachable"
+            ),
+        }
+    }
+}
+
+/// A case of a backed enum with the same value as an earlier case.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateCaseValue {
+    pub file: usize,
+    /// The later case.
+    pub span: Span,
+    /// Fully-qualified enum name.
+    pub enum_name: String,
+    /// The earlier case.
+    pub first: String,
+    pub case: String,
+    pub value: PhpValue,
+}
+
+impl std::fmt::Display for DuplicateCaseValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Duplicate value in enum {} for cases {} and {}",
+            self.enum_name, self.first, self.case
+        )
+    }
+}
+
+/// Enum declarations and case references across the files of a project.
+///
+/// ```
+/// use php_analysis::codemod::apply_edits;
+/// use php_analysis::enums::EnumIndex;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// enum Suit { case Hearts; case Spades; }
+/// function color(Suit $s) {
+///     return match ($s) {
+///         Suit::Hearts => 'red',
+///     };
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut index = EnumIndex::new();
+/// index.add_file(&result.program, src);
+///
+/// assert_eq!(index.references("Suit", "Hearts").count(), 1);
+/// let findings = index.exhaustiveness();
+/// assert_eq!(findings[0].to_string(), "match over Suit does not handle Spades");
+/// let fixed = apply_edits(src, &[findings[0].fix.clone().unwrap()]);
+/// assert!(fixed.contains(
+///     "Suit::Spades => throw new \\UnhandledMatchError('Unhandled match case Suit::Spades'),"
+/// ));
+/// ```
+#[derive(Debug, Default)]
+pub struct EnumIndex {
+    enums: Vec<EnumInfo>,
+    /// Lower-cased enum name → index into `enums`; the first declaration wins.
+    by_name: HashMap<String, usize>,
+    /// Every `Class::CONSTANT` with a resolvable class; filtered against the
+    /// indexed enums on query, since an enum may be declared in a later file.
+    accesses: Vec<CaseReference>,
+    branches: Vec<Branch>,
+    duplicates: Vec<DuplicateCaseValue>,
+    files: usize,
+}
+
+impl EnumIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project. `source` is the text `program` was parsed
+    /// from, used to lay out fixes. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>, source: &str) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let mut collector = Collector {
+            index: self,
+            file,
+            source,
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        file
+    }
+
+    /// Every indexed enum, in the order the files were added.
+    pub fn enums(&self) -> &[EnumInfo] {
+        &self.enums
+    }
+
+    /// Find an enum by fully-qualified name, case-insensitively.
+    pub fn find(&self, name: &str) -> Option<&EnumInfo> {
+        let name = name.trim_start_matches('\\').to_ascii_lowercase();
+        self.by_name.get(&name).map(|&i| &self.enums[i])
+    }
+
+    /// The references to cases of indexed enums, by file and position.
+    pub fn all_references(&self) -> impl Iterator<Item = &CaseReference> {
+        self.accesses.iter().filter(|access| {
+            self.find(&access.enum_name)
+                .is_some_and(|info| info.has_case(&access.case))
+        })
+    }
+
+    /// The references to `enum_name::case`.
+    pub fn references<'i>(
+        &'i self,
+        enum_name: &str,
+        case: &'i str,
+    ) -> impl Iterator<Item = &'i CaseReference> {
+        let info = self.find(enum_name);
+        self.accesses.iter().filter(move |access| {
+            access.case == case
+                && info.is_some_and(|info| info.name.eq_ignore_ascii_case(&access.enum_name))
+        })
+    }
+
+    /// Report the `switch` and `match` constructs over indexed enums that
+    /// miss cases or have a `default`, by file and position.
+    pub fn exhaustiveness(&self) -> Vec<EnumBranchFinding> {
+        self.branches
+            .iter()
+            .filter_map(|branch| self.check(branch))
+            .collect()
+    }
+
+    /// The cases of backed enums that repeat the value of an earlier case,
+    /// by file and position.
+    pub fn duplicate_values(&self) -> &[DuplicateCaseValue] {
+        &self.duplicates
+    }
+
+    fn check(&self, branch: &Branch) -> Option<EnumBranchFinding> {
+        let (first_enum, _) = branch.labels.first()?;
+        let info = self.find(first_enum)?;
+        let over_enum = branch.labels.iter().all(|(enum_name, case)| {
+            enum_name.eq_ignore_ascii_case(&info.name) && info.has_case(case)
+        });
+        if !over_enum {
+            return None;
+        }
+        let missing: Vec<String> = info
+            .cases
+            .iter()
+            .map(|(name, _)| name)
+            .filter(|name| !branch.labels.iter().any(|(_, case)| case == *name))
+            .cloned()
+            .collect();
+        if missing.is_empty() && branch.default.is_none() {
+            return None;
+        }
+
+        let class = &branch.class_text;
+        let fix = match (&branch.default, missing.is_empty()) {
+            (_, true) => None,
+            (Some(default), false) => {
+                let labels: Vec<String> = missing.iter().map(|c| format!("{class}::{c}")).collect();
+                let replacement = match branch.kind {
+                    BranchKind::Match => labels.join(", "),
+                    BranchKind::Switch => {
+                        format!(
+                            "case {}",
+                            labels.join(&format!(":{}case ", default.separator))
+                        )
+                    }
+                };
+                Some(TextEdit::replace(default.span, replacement))
+            }
+            (None, false) => {
+                let append = &branch.append;
+                let text = match branch.kind {
+                    BranchKind::Match => missing
+                        .iter()
+                        .map(|c| {
+                            format!(
+                                ",{}{class}::{c} => throw new \\UnhandledMatchError('Unhandled match case {class}::{c}')",
+                                append.separator
+                            )
+                        })
+                        .collect(),
+                    BranchKind::Switch => {
+                        let mut text: String = missing
+                            .iter()
+                            .map(|c| format!("{}case {class}::{c}:", append.separator))
+                            .collect();
+                        text.push_str(&format!("{}break;", append.body_separator));
+                        text
+                    }
+                };
+                Some(TextEdit::insert(append.offset, text))
+            }
+        };
+
+        Some(EnumBranchFinding {
+            file: branch.file,
+            span: branch.span,
+            kind: branch.kind,
+            enum_name: info.name.clone(),
+            missing,
+            default: branch.default.as_ref().map(|default| default.span),
+            fix,
+        })
+    }
+}
+
+/// A `switch` or `match` whose labels all name class constants, pending the
+/// enums of every file.
+#[derive(Debug)]
+struct Branch {
+    file: usize,
+    span: Span,
+    kind: BranchKind,
+    /// Resolved class and constant of each label.
+    labels: Vec<(String, String)>,
+    /// The class of the first label as written, for the fixes.
+    class_text: String,
+    default: Option<DefaultLabel>,
+    append: Append,
+}
+
+#[derive(Debug)]
+struct DefaultLabel {
+    /// The `default` keyword.
+    span: Span,
+    /// Between `case` labels replacing it in a `switch`.
+    separator: String,
+}
+
+/// Where missing arms go: after the last arm or case.
+#[derive(Debug)]
+struct Append {
+    offset: u32,
+    /// Before each new arm or `case`.
+    separator: String,
+    /// Before the `break;` ending new `switch` cases.
+    body_separator: String,
+}
+
+struct Collector<'i, 's> {
+    index: &'i mut EnumIndex,
+    file: usize,
+    source: &'s str,
+}
+
+impl Collector<'_, '_> {
+    /// The resolved class and name of a `Class::CONSTANT` label.
+    fn label(&self, expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<(String, String)> {
+        match &expr.kind {
+            ExprKind::ClassConstAccess(access) => {
+                let ExprKind::Identifier(member) = &access.member.kind else {
+                    return None;
+                };
+                Some((scope.resolve_class(&access.class)?, member.to_string()))
+            }
+            ExprKind::Parenthesized(inner) => self.label(inner, scope),
+            _ => None,
+        }
+    }
+
+    fn branch(
+        &mut self,
+        span: Span,
+        kind: BranchKind,
+        conditions: Vec<&Expr<'_, '_>>,
+        default: Option<DefaultLabel>,
+        append: Append,
+        scope: &AnalysisScope,
+    ) {
+        let mut labels = Vec::new();
+        let mut class_text = None;
+        for condition in conditions {
+            let Some(label) = self.label(condition, scope) else {
+                return;
+            };
+            let text = &self.source[condition.span.to_range()];
+            class_text.get_or_insert_with(|| text.rsplit_once("::").map_or(text, |(c, _)| c));
+            labels.push(label);
+        }
+        let Some(class_text) = class_text else {
+            return;
+        };
+        self.index.branches.push(Branch {
+            file: self.file,
+            span,
+            kind,
+            labels,
+            class_text: class_text.trim_start_matches('(').to_string(),
+            default,
+            append,
+        });
+    }
+
+    /// Record the cases of the backed enum `name` whose value repeats an
+    /// earlier case.
+    fn duplicates(&mut self, name: &str, members: &[EnumMember<'_, '_>], scope: &AnalysisScope) {
+        let constants: HashMap<&str, &Expr<'_, '_>> = members
+            .iter()
+            .filter_map(|member| match &member.kind {
+                EnumMemberKind::ClassConst(constant) => {
+                    Some((constant.name.as_str()?, &constant.value))
+                }
+                _ => None,
+            })
+            .collect();
+        let mut seen: Vec<(&str, PhpValue)> = Vec::new();
+        for member in members {
+            let EnumMemberKind::Case(case) = &member.kind else {
+                continue;
+            };
+            let (Some(case_name), Some(value)) = (case.name.as_str(), &case.value) else {
+                continue;
+            };
+            let Some(value) = enum_value(value, name, &constants, scope, 0) else {
+                continue;
+            };
+            match seen.iter().find(|(_, earlier)| *earlier == value) {
+                Some((first, _)) => self.index.duplicates.push(DuplicateCaseValue {
+                    file: self.file,
+                    span: member.span,
+                    enum_name: name.to_string(),
+                    first: first.to_string(),
+                    case: case_name.to_string(),
+                    value,
+                }),
+                None => seen.push((case_name, value)),
+            }
+        }
+    }
+
+    /// The whitespace at the start of the line holding `offset`.
+    fn indent(&self, offset: u32) -> &str {
+        let line_start = self.source[..offset as usize]
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+        let line = &self.source[line_start..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    fn same_line(&self, a: u32, b: u32) -> bool {
+        !self.source[a as usize..b as usize].contains('\n')
+    }
+}
+
+/// The value of an initializer in the enum `enum_name`, resolving the
+/// enum's own constants. `depth` cuts off constants defined in a cycle.
+fn enum_value(
+    expr: &Expr<'_, '_>,
+    enum_name: &str,
+    constants: &HashMap<&str, &Expr<'_, '_>>,
+    scope: &AnalysisScope,
+    depth: usize,
+) -> Option<PhpValue> {
+    if depth > constants.len() {
+        return None;
+    }
+    evaluate_with(expr, &mut |expr| {
+        let ExprKind::ClassConstAccess(access) = &expr.kind else {
+            return None;
+        };
+        let ExprKind::Identifier(member) = &access.member.kind else {
+            return None;
+        };
+        let own = match &access.class {
+            ClassRef::SelfKw(_) | ClassRef::StaticKw(_) => true,
+            class => scope
+                .resolve_class(class)
+                .is_some_and(|class| class.eq_ignore_ascii_case(enum_name)),
+        };
+        let value = constants.get(member.as_str()).filter(|_| own)?;
+        enum_value(value, enum_name, constants, scope, depth + 1)
+    })
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Enum(enum_decl) => {
+                let name = scope.names.qualify(enum_decl.name.or_error());
+                let cases = enum_decl
+                    .members
+                    .iter()
+                    .filter_map(|member| match &member.kind {
+                        EnumMemberKind::Case(case) => {
+                            Some((case.name.as_str()?.to_string(), member.span))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if enum_decl.scalar_type.is_some() {
+                    self.duplicates(&name, &enum_decl.members, scope);
+                }
+                let key = name.to_ascii_lowercase();
+                if !self.index.by_name.contains_key(&key) {
+                    self.index.by_name.insert(key, self.index.enums.len());
+                    self.index.enums.push(EnumInfo {
+                        name,
+                        file: self.file,
+                        span: stmt.span,
+                        cases,
+                    });
+                }
+            }
+            StmtKind::Switch(switch) => {
+                let Some(last) = switch.cases.last() else {
+                    return;
+                };
+                let indent = self.indent(last.span.start).to_string();
+                let body_indent = match last.body.first() {
+                    Some(first) if !self.same_line(last.span.start, first.span.start) => {
+                        self.indent(first.span.start).to_string()
+                    }
+                    _ => format!("{indent}    "),
+                };
+                let default = switch
+                    .cases
+                    .iter()
+                    .find(|case| case.value.is_none())
+                    .map(|case| DefaultLabel {
+                        span: Span::new(case.span.start, case.span.start + "default".len() as u32),
+                        separator: format!("\n{}", self.indent(case.span.start)),
+                    });
+                let append = Append {
+                    offset: last.span.end,
+                    separator: format!("\n{indent}"),
+                    body_separator: format!("\n{body_indent}"),
+                };
+                let conditions = switch
+                    .cases
+                    .iter()
+                    .filter_map(|case| case.value.as_ref())
+                    .collect();
+                self.branch(
+                    stmt.span,
+                    BranchKind::Switch,
+                    conditions,
+                    default,
+                    append,
+                    scope,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        match &expr.kind {
+            ExprKind::ClassConstAccess(access) => {
+                let ExprKind::Identifier(member) = &access.member.kind else {
+                    return;
+                };
+                if let Some(enum_name) = scope.resolve_class(&access.class) {
+                    self.index.accesses.push(CaseReference {
+                        file: self.file,
+                        span: expr.span,
+                        enum_name,
+                        case: member.to_string(),
+                    });
+                }
+            }
+            ExprKind::Match(match_expr) => {
+                let Some(last) = match_expr.arms.last() else {
+                    return;
+                };
+                let separator = match self.same_line(expr.span.start, last.span.start) {
+                    true => " ".to_string(),
+                    false => format!("\n{}", self.indent(last.span.start)),
+                };
+                let default = match_expr
+                    .arms
+                    .iter()
+                    .find(|arm| arm.conditions.is_none())
+                    .map(|arm| DefaultLabel {
+                        span: Span::new(arm.span.start, arm.span.start + "default".len() as u32),
+                        separator: String::new(),
+                    });
+                let append = Append {
+                    offset: last.span.end,
+                    separator,
+                    body_separator: String::new(),
+                };
+                let conditions = match_expr
+                    .arms
+                    .iter()
+                    .flat_map(|arm| arm.conditions.iter().flat_map(|c| c.iter()))
+                    .collect();
+                self.branch(
+                    expr.span,
+                    BranchKind::Match,
+                    conditions,
+                    default,
+                    append,
+                    scope,
+                );
+            }
+            _ => {}
+        }
+    }
+}
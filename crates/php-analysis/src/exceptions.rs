@@ -0,0 +1,288 @@
+//! Exception flow analysis.
+//!
+//! Collects `throw` sites and `@throws` doc-block tags per unit, propagates
+//! escaping exceptions through the [`CallGraph`], and reports:
+//!
+//! - functions and methods that let an exception escape without declaring it
+//!   (or a supertype) in a `@throws` tag;
+//! - exceptions that escape a file's top-level code, i.e. are not caught by
+//!   any caller within the project.
+//!
+//! `throw new Foo(...)` and re-throwing a caught variable (`catch (E $e) { throw $e; }`)
+//! are understood; throwing arbitrary expressions is not tracked. Closures are
+//! analysed in isolation because their call sites are not resolvable.
+
+use std::collections::btree_map::{BTreeMap, Entry};
+
+use php_ast::{Expr, ExprKind, Program, Span, Stmt, StmtKind};
+
+use crate::callgraph::CallGraph;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// An exception-flow diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExceptionFinding {
+    /// `function` lets `exception` escape but its doc-block has no matching `@throws`.
+    /// `span` is the throw site or the call through which the exception arrives.
+    Undeclared {
+        file: usize,
+        function: String,
+        exception: String,
+        span: Span,
+    },
+    /// `exception` escapes the top-level code of `file`.
+    Uncaught {
+        file: usize,
+        exception: String,
+        span: Span,
+    },
+}
+
+impl ExceptionFinding {
+    pub fn file(&self) -> usize {
+        match self {
+            Self::Undeclared { file, .. } | Self::Uncaught { file, .. } => *file,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Undeclared { span, .. } | Self::Uncaught { span, .. } => *span,
+        }
+    }
+}
+
+impl std::fmt::Display for ExceptionFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Undeclared {
+                function,
+                exception,
+                ..
+            } => write!(
+                f,
+                "{function} may throw {exception}, which is not declared in @throws"
+            ),
+            Self::Uncaught { exception, .. } => {
+                write!(f, "{exception} is not caught by any caller")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ThrowSite {
+    exception: String,
+    span: Span,
+    caught: Vec<String>,
+}
+
+#[derive(Debug)]
+struct UnitInfo {
+    file: usize,
+    kind: UnitKind,
+    throws: Vec<ThrowSite>,
+    declared: Vec<String>,
+}
+
+/// Accumulates files and computes [`ExceptionFinding`]s across all of them.
+///
+/// ```
+/// use php_analysis::exceptions::ExceptionAnalysis;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php function f() { throw new RuntimeException(); }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut analysis = ExceptionAnalysis::new();
+/// analysis.add_file(&result.program);
+/// let findings = analysis.finish();
+/// assert_eq!(findings.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct ExceptionAnalysis {
+    graph: CallGraph,
+    units: Vec<UnitInfo>,
+}
+
+impl ExceptionAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.graph.add_file(program);
+        let mut collector = Collector {
+            file,
+            units: &mut self.units,
+            stack: Vec::new(),
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        file
+    }
+
+    /// The call graph built from the added files.
+    pub fn graph(&self) -> &CallGraph {
+        &self.graph
+    }
+
+    /// Propagate escaping exceptions to a fixpoint and report findings,
+    /// ordered by file and source position.
+    pub fn finish(self) -> Vec<ExceptionFinding> {
+        let graph = &self.graph;
+        let caught_by = |exception: &str, caught: &[String]| {
+            caught.iter().any(|c| graph.is_subtype_of(exception, c))
+        };
+
+        // Lower-cased exception name → (display name, origin span in this unit).
+        let mut escaping: Vec<BTreeMap<String, (String, Span)>> = self
+            .units
+            .iter()
+            .map(|unit| {
+                unit.throws
+                    .iter()
+                    .filter(|t| !caught_by(&t.exception, &t.caught))
+                    .map(|t| {
+                        (
+                            t.exception.to_ascii_lowercase(),
+                            (t.exception.clone(), t.span),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (id, node) in graph.nodes().iter().enumerate() {
+                for site in &node.calls {
+                    let Some(target) = graph.resolve(&site.callee) else {
+                        continue;
+                    };
+                    let incoming: Vec<String> = escaping[target]
+                        .values()
+                        .map(|(name, _)| name.clone())
+                        .filter(|name| !caught_by(name, &site.caught))
+                        .collect();
+                    for name in incoming {
+                        if let Entry::Vacant(entry) = escaping[id].entry(name.to_ascii_lowercase())
+                        {
+                            entry.insert((name, site.span));
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut findings = Vec::new();
+        for ((unit, node), escapes) in self.units.iter().zip(graph.nodes()).zip(&escaping) {
+            for (exception, span) in escapes.values() {
+                match unit.kind {
+                    UnitKind::Main => findings.push(ExceptionFinding::Uncaught {
+                        file: unit.file,
+                        exception: exception.clone(),
+                        span: *span,
+                    }),
+                    UnitKind::Function | UnitKind::Method
+                        if !caught_by(exception, &unit.declared) =>
+                    {
+                        findings.push(ExceptionFinding::Undeclared {
+                            file: unit.file,
+                            function: node.name.clone(),
+                            exception: exception.clone(),
+                            span: *span,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        findings.sort_by_key(|f| (f.file(), f.span().start, f.span().end));
+        findings
+    }
+}
+
+struct Collector<'u> {
+    file: usize,
+    units: &'u mut Vec<UnitInfo>,
+    stack: Vec<usize>,
+}
+
+impl Collector<'_> {
+    fn record_throw(&mut self, thrown: &Expr<'_, '_>, span: Span, scope: &AnalysisScope) {
+        let Some(&id) = self.stack.last() else {
+            return;
+        };
+        let exceptions = match &thrown.kind {
+            ExprKind::New(new) => scope.resolve_class(&new.class).into_iter().collect(),
+            ExprKind::Variable(var) => scope
+                .catches
+                .iter()
+                .rev()
+                .find(|c| c.var.as_deref() == Some(var.as_str()))
+                .map(|c| c.types.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        let caught = scope.caught_types();
+        self.units[id]
+            .throws
+            .extend(exceptions.into_iter().map(|exception| ThrowSite {
+                exception,
+                span,
+                caught: caught.clone(),
+            }));
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        let declared = unit
+            .doc_comment
+            .map(|doc| declared_throws(doc.text, scope))
+            .unwrap_or_default();
+        self.stack.push(self.units.len());
+        self.units.push(UnitInfo {
+            file: self.file,
+            kind: unit.kind,
+            throws: Vec::new(),
+            declared,
+        });
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.stack.pop();
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        if let StmtKind::Throw(thrown) = &stmt.kind {
+            self.record_throw(thrown, stmt.span, scope);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        if let ExprKind::ThrowExpr(thrown) = &expr.kind {
+            self.record_throw(thrown, expr.span, scope);
+        }
+    }
+}
+
+/// Resolved class names listed in the `@throws` tags of a doc-block.
+fn declared_throws(doc: &str, scope: &AnalysisScope) -> Vec<String> {
+    let doc = phpdoc_parser::parse(doc);
+    phpdoc_parser::find_tags(&doc, "throws")
+        .into_iter()
+        .filter_map(|tag| phpdoc_parser::body_text(&tag.body))
+        .flat_map(|body| {
+            body.split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .split('|')
+                .filter(|t| !t.is_empty())
+                .filter_map(|t| scope.resolve_class_ref(t))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -0,0 +1,730 @@
+//! What an editor shows when hovering a position of a file.
+//!
+//! [`hover_at`] finds the declaration name, reference, or variable at an
+//! offset and assembles its hover: the resolved name, the declaration's
+//! signature as [`php_printer::print_signature`] renders it, the summary of
+//! its doc-block, and its type. References resolve against the declarations
+//! of the same file; a name declared elsewhere gets its resolved name only.
+//!
+//! Types of variables come from a light inference over the enclosing unit:
+//! a variable has the declared type of the parameter it names, or the type
+//! of the value last assigned to it before the offset. `new`, constant
+//! expressions, closures, and calls to functions and methods declared in the
+//! file have known types; `$this` is the enclosing class.
+//!
+//! ```
+//! use php_analysis::hover::hover_at;
+//! use php_analysis::symbols::SymbolKind;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! namespace App;
+//! class User {
+//!     /** Persist the user. */
+//!     public function save(): bool { return true; }
+//! }
+//! $user = new User();
+//! $user->save();
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let offset = src.rfind("save").unwrap() as u32;
+//! let hover = hover_at(&result.program, src, offset).unwrap();
+//! assert_eq!(hover.name, "App\\User::save");
+//! assert_eq!(hover.kind, Some(SymbolKind::Method));
+//! assert_eq!(hover.signature.as_deref(), Some("public function save(): bool"));
+//! assert_eq!(hover.summary.as_deref(), Some("Persist the user."));
+//! ```
+
+use std::collections::HashMap;
+
+use php_ast::node::AnyNode;
+use php_ast::*;
+use php_printer::{print_signature, print_type_hint};
+
+use crate::names::NameContext;
+use crate::symbols::SymbolKind;
+use crate::values::evaluate;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// The hover for a name or variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hover {
+    /// The hovered name or variable.
+    pub span: Span,
+    /// Resolved name: `App\User`, `App\User::save`, `App\User::$name`, or
+    /// `$user` for a variable. A member of an unknown class is named alone.
+    pub name: String,
+    /// `None` for a variable.
+    pub kind: Option<SymbolKind>,
+    /// The declaration without its body, e.g. `public function save(): bool`.
+    pub signature: Option<String>,
+    /// The summary of the declaration's doc-block.
+    pub summary: Option<String>,
+    /// The type of the variable, property, or constant, or the return type of
+    /// the function or method.
+    pub ty: Option<String>,
+}
+
+impl Hover {
+    /// Markdown for an editor: the signature in a PHP code block, or the
+    /// name with its type when there is no signature, then the summary.
+    pub fn markdown(&self) -> String {
+        let code = match (&self.signature, &self.ty) {
+            (Some(signature), _) => signature.clone(),
+            (None, Some(ty)) => format!("{ty} {}", self.name),
+            (None, None) => self.name.clone(),
+        };
+        let mut markdown = format!("```php\n{code}\n```");
+        if let Some(summary) = &self.summary {
+            markdown.push_str("\n\n");
+            markdown.push_str(summary);
+        }
+        markdown
+    }
+}
+
+/// The hover for the declaration name, reference, or variable at `offset`,
+/// or `None` when there is nothing to show there.
+///
+/// `program` must come from parsing `source`.
+pub fn hover_at(program: &Program<'_, '_>, source: &str, offset: u32) -> Option<Hover> {
+    let mut decls = Declarations {
+        source,
+        decls: Vec::new(),
+        index: HashMap::new(),
+    };
+    AnalysisWalker::new(&mut decls).walk(program);
+    if let Some(decl) = decls.decls.iter().find(|d| d.span.contains(offset)) {
+        return Some(decl.hover(decl.span));
+    }
+    let mut finder = Finder {
+        decls: &decls,
+        source,
+        offset,
+        frames: Vec::new(),
+        found: None,
+    };
+    AnalysisWalker::new(&mut finder).walk(program);
+    finder.found
+}
+
+/// A type with, when it names one class, that class resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct Type {
+    pub(crate) display: String,
+    pub(crate) class: Option<String>,
+}
+
+impl Type {
+    pub(crate) fn class(name: String) -> Self {
+        Type {
+            display: name.clone(),
+            class: Some(name),
+        }
+    }
+
+    pub(crate) fn hint(
+        hint: &TypeHint<'_, '_>,
+        names: &NameContext,
+        self_class: Option<&str>,
+    ) -> Self {
+        Type {
+            display: print_type_hint(hint),
+            class: hint_class(hint, names, self_class),
+        }
+    }
+
+    /// The type a value of this declared type has: `self` and `static` are
+    /// the class they name.
+    pub(crate) fn of_value(&self) -> Self {
+        match &self.class {
+            Some(class) if matches!(self.display.as_str(), "self" | "static") => {
+                Type::class(class.clone())
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// The class a type hint names, if it names one, possibly nullable.
+fn hint_class(
+    hint: &TypeHint<'_, '_>,
+    names: &NameContext,
+    self_class: Option<&str>,
+) -> Option<String> {
+    match &hint.kind {
+        TypeHintKind::Named(name) => Some(names.resolve_class(&name.to_string_repr())),
+        TypeHintKind::Keyword(BuiltinType::Self_ | BuiltinType::Static, _) => {
+            self_class.map(str::to_string)
+        }
+        TypeHintKind::Nullable(inner) => hint_class(inner, names, self_class),
+        _ => None,
+    }
+}
+
+/// The span of `ident` in `source`, which it must be a slice of.
+pub(crate) fn ident_span(source: &str, ident: Ident<'_>) -> Option<Span> {
+    let name = ident.as_str()?;
+    let start = (name.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    let end = start + name.len();
+    (end <= source.len()).then(|| Span::new(start as u32, end as u32))
+}
+
+fn summary(doc: Option<&Comment<'_>>) -> Option<String> {
+    let doc = phpdoc_parser::parse(doc?.text);
+    let summary = phpdoc_parser::text_content(doc.summary.as_ref()?);
+    let summary = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!summary.is_empty()).then_some(summary)
+}
+
+/// Lookup key of a declaration: names are matched case-insensitively.
+fn key(fqn: &str) -> String {
+    fqn.to_ascii_lowercase()
+}
+
+/// A declaration of the file.
+struct Decl {
+    fqn: String,
+    kind: SymbolKind,
+    /// The declared name.
+    span: Span,
+    signature: Option<String>,
+    summary: Option<String>,
+    ty: Option<Type>,
+}
+
+impl Decl {
+    fn hover(&self, span: Span) -> Hover {
+        Hover {
+            span,
+            name: self.fqn.clone(),
+            kind: Some(self.kind),
+            signature: self.signature.clone(),
+            summary: self.summary.clone(),
+            ty: self.ty.as_ref().map(|ty| ty.display.clone()),
+        }
+    }
+}
+
+struct Declarations<'s> {
+    source: &'s str,
+    decls: Vec<Decl>,
+    index: HashMap<String, usize>,
+}
+
+impl Declarations<'_> {
+    fn get(&self, fqn: &str) -> Option<&Decl> {
+        self.index.get(&key(fqn)).map(|&i| &self.decls[i])
+    }
+
+    fn add(
+        &mut self,
+        fqn: String,
+        kind: SymbolKind,
+        name: Ident<'_>,
+        node: AnyNode<'_, '_, '_>,
+        doc: Option<&Comment<'_>>,
+        ty: Option<Type>,
+    ) {
+        let Some(span) = ident_span(self.source, name) else {
+            return;
+        };
+        self.index.entry(key(&fqn)).or_insert(self.decls.len());
+        self.decls.push(Decl {
+            fqn,
+            kind,
+            span,
+            signature: print_signature(node),
+            summary: summary(doc),
+            ty,
+        });
+    }
+
+    fn value_type(value: &Expr<'_, '_>) -> Option<Type> {
+        evaluate(value).map(|value| Type {
+            display: value.type_name().to_string(),
+            class: None,
+        })
+    }
+
+    fn class_members(&mut self, class: &str, members: &[ClassMember<'_, '_>], names: &NameContext) {
+        for member in members {
+            let node = AnyNode::from(member);
+            match &member.kind {
+                ClassMemberKind::Method(method) => {
+                    let ty = method
+                        .return_type
+                        .as_ref()
+                        .map(|hint| Type::hint(hint, names, Some(class)));
+                    let fqn = format!("{class}::{}", method.name.or_error());
+                    let doc = method.doc_comment.as_ref();
+                    self.add(fqn, SymbolKind::Method, method.name, node, doc, ty);
+                }
+                ClassMemberKind::Property(prop) => {
+                    let ty = match &prop.type_hint {
+                        Some(hint) => Some(Type::hint(hint, names, Some(class))),
+                        None => prop.default.as_ref().and_then(Self::value_type),
+                    };
+                    let fqn = format!("{class}::${}", prop.name.or_error());
+                    let doc = prop.doc_comment.as_ref();
+                    self.add(fqn, SymbolKind::Property, prop.name, node, doc, ty);
+                }
+                ClassMemberKind::ClassConst(constant) => {
+                    self.class_const(class, constant, node, names);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn class_const(
+        &mut self,
+        class: &str,
+        constant: &ClassConstDecl<'_, '_>,
+        node: AnyNode<'_, '_, '_>,
+        names: &NameContext,
+    ) {
+        let ty = match constant.type_hint {
+            Some(hint) => Some(Type::hint(hint, names, Some(class))),
+            None => Self::value_type(&constant.value),
+        };
+        let fqn = format!("{class}::{}", constant.name.or_error());
+        let doc = constant.doc_comment.as_ref();
+        self.add(fqn, SymbolKind::ClassConstant, constant.name, node, doc, ty);
+    }
+
+    /// A parameter promoted to a property by a constructor.
+    fn promoted(&mut self, class: &str, members: &[ClassMember<'_, '_>], names: &NameContext) {
+        for member in members {
+            let ClassMemberKind::Method(method) = &member.kind else {
+                continue;
+            };
+            if !method.name.or_error().eq_ignore_ascii_case("__construct") {
+                continue;
+            }
+            for param in method.params.iter() {
+                if param.visibility.is_none() && !param.is_readonly {
+                    continue;
+                }
+                let ty = param
+                    .type_hint
+                    .as_ref()
+                    .map(|hint| Type::hint(hint, names, Some(class)));
+                let fqn = format!("{class}::${}", param.name.or_error());
+                let node = AnyNode::from(param);
+                self.add(fqn, SymbolKind::Property, param.name, node, None, ty);
+            }
+        }
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Declarations<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        let names = &scope.names;
+        let node = AnyNode::from(stmt);
+        match &stmt.kind {
+            StmtKind::Function(func) => {
+                let ty = func
+                    .return_type
+                    .as_ref()
+                    .map(|hint| Type::hint(hint, names, None));
+                let fqn = names.qualify(func.name.or_error());
+                let doc = func.doc_comment.as_ref();
+                self.add(fqn, SymbolKind::Function, func.name, node, doc, ty);
+            }
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    let fqn = names.qualify(item.name.or_error());
+                    let ty = Self::value_type(&item.value);
+                    let doc = item.doc_comment.as_ref();
+                    let node = AnyNode::from(item);
+                    self.add(fqn, SymbolKind::Constant, item.name, node, doc, ty);
+                }
+            }
+            StmtKind::Class(class) => {
+                let Some(name) = class.name else {
+                    return;
+                };
+                let fqn = names.qualify(name.or_error());
+                let doc = class.doc_comment.as_ref();
+                self.add(fqn.clone(), SymbolKind::Class, name, node, doc, None);
+                self.promoted(&fqn, &class.members, names);
+                self.class_members(&fqn, &class.members, names);
+            }
+            StmtKind::Interface(iface) => {
+                let fqn = names.qualify(iface.name.or_error());
+                let doc = iface.doc_comment.as_ref();
+                self.add(
+                    fqn.clone(),
+                    SymbolKind::Interface,
+                    iface.name,
+                    node,
+                    doc,
+                    None,
+                );
+                self.class_members(&fqn, &iface.members, names);
+            }
+            StmtKind::Trait(trait_decl) => {
+                let fqn = names.qualify(trait_decl.name.or_error());
+                let doc = trait_decl.doc_comment.as_ref();
+                self.add(
+                    fqn.clone(),
+                    SymbolKind::Trait,
+                    trait_decl.name,
+                    node,
+                    doc,
+                    None,
+                );
+                self.promoted(&fqn, &trait_decl.members, names);
+                self.class_members(&fqn, &trait_decl.members, names);
+            }
+            StmtKind::Enum(enum_decl) => {
+                let fqn = names.qualify(enum_decl.name.or_error());
+                let doc = enum_decl.doc_comment.as_ref();
+                self.add(
+                    fqn.clone(),
+                    SymbolKind::Enum,
+                    enum_decl.name,
+                    node,
+                    doc,
+                    None,
+                );
+                for member in enum_decl.members.iter() {
+                    let node = AnyNode::from(member);
+                    match &member.kind {
+                        EnumMemberKind::Case(case) => {
+                            let case_fqn = format!("{fqn}::{}", case.name.or_error());
+                            let ty = Some(Type::class(fqn.clone()));
+                            let doc = case.doc_comment.as_ref();
+                            self.add(case_fqn, SymbolKind::EnumCase, case.name, node, doc, ty);
+                        }
+                        EnumMemberKind::Method(method) => {
+                            let ty = method
+                                .return_type
+                                .as_ref()
+                                .map(|hint| Type::hint(hint, names, Some(&fqn)));
+                            let method_fqn = format!("{fqn}::{}", method.name.or_error());
+                            let doc = method.doc_comment.as_ref();
+                            self.add(method_fqn, SymbolKind::Method, method.name, node, doc, ty);
+                        }
+                        EnumMemberKind::ClassConst(constant) => {
+                            self.class_const(&fqn, constant, node, names);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The variables of a unit, with their types where known.
+#[derive(Default)]
+struct Frame {
+    vars: HashMap<String, Option<Type>>,
+    params: HashMap<String, String>,
+}
+
+struct Finder<'d, 's> {
+    decls: &'d Declarations<'s>,
+    source: &'s str,
+    offset: u32,
+    frames: Vec<Frame>,
+    found: Option<Hover>,
+}
+
+impl Finder<'_, '_> {
+    fn variable(&self, name: &str) -> Option<Type> {
+        self.frames.last()?.vars.get(name).cloned().flatten()
+    }
+
+    /// The type of `expr`, where the file says.
+    fn infer(&self, expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<Type> {
+        match &expr.kind {
+            ExprKind::Variable(name) => self.variable(name.as_str()),
+            ExprKind::Parenthesized(inner) => self.infer(inner, scope),
+            ExprKind::New(new) => scope.resolve_class(&new.class).map(Type::class),
+            ExprKind::Closure(_) | ExprKind::ArrowFunction(_) => {
+                Some(Type::class("Closure".to_string()))
+            }
+            ExprKind::FunctionCall(call) => {
+                let ExprKind::Name(name) = &call.name.kind else {
+                    return None;
+                };
+                self.function(name, scope)?.ty.as_ref().map(Type::of_value)
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let class = self.infer(call.object, scope)?.class?;
+                self.member(&class, call.method, "")?
+                    .ty
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::StaticMethodCall(call) => {
+                let class = scope.resolve_class(&call.class)?;
+                self.member(&class, call.method, "")?
+                    .ty
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                let class = self.infer(access.object, scope)?.class?;
+                self.member(&class, access.property, "$")?
+                    .ty
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::ClassConstAccess(access) => {
+                let class = scope.resolve_class(&access.class)?;
+                self.member(&class, access.member, "")?
+                    .ty
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::ConstFetch(name) => self.constant(name, scope)?.ty.clone(),
+            _ => Declarations::value_type(expr),
+        }
+    }
+
+    /// The declared function `name` calls, trying the global fallback of an
+    /// unqualified name after the namespaced one.
+    fn function(&self, name: &Name<'_, '_>, scope: &AnalysisScope) -> Option<&Decl> {
+        let (fqn, fallback) = scope.names.resolve_function(&name.to_string_repr());
+        self.decls
+            .get(&fqn)
+            .or_else(|| fallback.and_then(|fallback| self.decls.get(&fallback)))
+    }
+
+    fn constant(&self, name: &Name<'_, '_>, scope: &AnalysisScope) -> Option<&Decl> {
+        let (fqn, fallback) = scope.names.resolve_constant(&name.to_string_repr());
+        self.decls
+            .get(&fqn)
+            .or_else(|| fallback.and_then(|fallback| self.decls.get(&fallback)))
+    }
+
+    /// The declared member of `class` named by the identifier `member`;
+    /// `sigil` is `$` for properties.
+    fn member(&self, class: &str, member: &Expr<'_, '_>, sigil: &str) -> Option<&Decl> {
+        let name = member_name(member)?;
+        self.decls.get(&format!("{class}::{sigil}{name}"))
+    }
+
+    /// Hover a reference to `fqn`, with the declaration's details when the
+    /// file declares it.
+    fn reference(&mut self, span: Span, fqn: String, kind: SymbolKind) {
+        self.found = Some(match self.decls.get(&fqn) {
+            Some(decl) => decl.hover(span),
+            None => Hover {
+                span,
+                name: fqn,
+                kind: Some(kind),
+                signature: None,
+                summary: None,
+                ty: None,
+            },
+        });
+    }
+
+    /// Hover the class of `class` if the offset is on it.
+    fn class_ref(&mut self, class: &ClassRef<'_, '_>, scope: &AnalysisScope) -> bool {
+        let span = match class {
+            ClassRef::Name(name) => name.span(),
+            ClassRef::SelfKw(span) | ClassRef::ParentKw(span) | ClassRef::StaticKw(span) => *span,
+            ClassRef::Expr(_) => return false,
+        };
+        if !span.contains(self.offset) {
+            return false;
+        }
+        let Some(fqn) = scope.resolve_class(class) else {
+            return false;
+        };
+        let kind = self.decls.get(&fqn).map_or(SymbolKind::Class, |d| d.kind);
+        self.reference(span, fqn, kind);
+        true
+    }
+
+    /// Hover the member `member` of `class` if the offset is on it; an
+    /// unknown class leaves the member named alone.
+    fn member_ref(
+        &mut self,
+        class: Option<String>,
+        member: &Expr<'_, '_>,
+        sigil: &str,
+        kind: SymbolKind,
+    ) {
+        if !member.span.contains(self.offset) {
+            return;
+        }
+        let Some(name) = member_name(member) else {
+            return;
+        };
+        let fqn = match class {
+            Some(class) => format!("{class}::{sigil}{name}"),
+            None => format!("{sigil}{name}"),
+        };
+        let kind = self.decls.get(&fqn).map_or(kind, |d| d.kind);
+        self.reference(member.span, fqn, kind);
+    }
+
+    /// Hover the class or the member of `Class::member`; `Class::class`
+    /// has no member.
+    fn static_member(
+        &mut self,
+        class: &ClassRef<'_, '_>,
+        member: &Expr<'_, '_>,
+        sigil: &str,
+        kind: SymbolKind,
+        scope: &AnalysisScope,
+    ) {
+        let is_class = member_name(member).is_some_and(|name| name.eq_ignore_ascii_case("class"));
+        if !self.class_ref(class, scope) && !is_class {
+            self.member_ref(scope.resolve_class(class), member, sigil, kind);
+        }
+    }
+
+    fn hover_variable(&mut self, span: Span, name: &str, ty: Option<Type>) {
+        let signature = self.frames.last().and_then(|f| f.params.get(name)).cloned();
+        self.found = Some(Hover {
+            span,
+            name: format!("${name}"),
+            kind: None,
+            signature,
+            summary: None,
+            ty: ty.map(|ty| ty.display),
+        });
+    }
+}
+
+/// The name of a member written as an identifier or, for static
+/// properties, a variable.
+fn member_name<'e>(member: &'e Expr<'_, '_>) -> Option<&'e str> {
+    match &member.kind {
+        ExprKind::Identifier(name) | ExprKind::Variable(name) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Finder<'_, '_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        let mut frame = Frame::default();
+        if let Some(parent) = self.frames.last() {
+            if unit.kind == UnitKind::Closure && unit.body.is_none() {
+                // Arrow functions capture the enclosing scope by value.
+                frame.vars = parent.vars.clone();
+            }
+            for var in unit.uses {
+                let ty = parent.vars.get(var.name).cloned().flatten();
+                frame.vars.insert(var.name.to_string(), ty);
+            }
+        }
+        let class = scope.class.as_ref().map(|c| c.name.as_str());
+        if let Some(class) = class.filter(|_| unit.kind != UnitKind::Main) {
+            frame
+                .vars
+                .insert("this".to_string(), Some(Type::class(class.to_string())));
+        }
+        for param in unit.params {
+            let Some(name) = param.name.as_str() else {
+                continue;
+            };
+            let ty = param
+                .type_hint
+                .as_ref()
+                .map(|hint| Type::hint(hint, &scope.names, class));
+            if let Some(signature) = print_signature(AnyNode::from(param)) {
+                frame.params.insert(name.to_string(), signature);
+            }
+            frame.vars.insert(name.to_string(), ty.clone());
+            if self.found.is_none() {
+                if let Some(span) = ident_span(self.source, param.name) {
+                    if span.contains(self.offset) {
+                        self.frames.push(frame);
+                        self.hover_variable(span, name, ty);
+                        return;
+                    }
+                }
+            }
+        }
+        self.frames.push(frame);
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.frames.pop();
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        if self.found.is_some() {
+            return;
+        }
+        if let ExprKind::Assign(assign) = &expr.kind {
+            if let (AssignOp::Assign, ExprKind::Variable(name)) = (assign.op, &assign.target.kind) {
+                let ty = self.infer(assign.value, scope);
+                if assign.target.span.contains(self.offset) {
+                    self.hover_variable(assign.target.span, name.as_str(), ty);
+                    return;
+                }
+                if expr.span.end <= self.offset {
+                    if let Some(frame) = self.frames.last_mut() {
+                        frame.vars.insert(name.as_str().to_string(), ty);
+                    }
+                }
+            }
+        }
+        if !expr.span.contains(self.offset) {
+            return;
+        }
+        match &expr.kind {
+            ExprKind::Variable(name) => {
+                let ty = self.variable(name.as_str());
+                self.hover_variable(expr.span, name.as_str(), ty);
+            }
+            ExprKind::FunctionCall(call) => {
+                if let ExprKind::Name(name) = &call.name.kind {
+                    if call.name.span.contains(self.offset) {
+                        let (fqn, fallback) = scope.names.resolve_function(&name.to_string_repr());
+                        let fqn = match self.function(name, scope) {
+                            Some(decl) => decl.fqn.clone(),
+                            None => fallback.unwrap_or(fqn),
+                        };
+                        self.reference(call.name.span, fqn, SymbolKind::Function);
+                    }
+                }
+            }
+            ExprKind::ConstFetch(name) => {
+                let (fqn, fallback) = scope.names.resolve_constant(&name.to_string_repr());
+                let fqn = match self.constant(name, scope) {
+                    Some(decl) => decl.fqn.clone(),
+                    None => fallback.unwrap_or(fqn),
+                };
+                self.reference(expr.span, fqn, SymbolKind::Constant);
+            }
+            ExprKind::New(new) => {
+                self.class_ref(&new.class, scope);
+            }
+            ExprKind::Instanceof(instanceof) => {
+                self.class_ref(&instanceof.class, scope);
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let class = self.infer(call.object, scope).and_then(|ty| ty.class);
+                self.member_ref(class, call.method, "", SymbolKind::Method);
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                let class = self.infer(access.object, scope).and_then(|ty| ty.class);
+                self.member_ref(class, access.property, "$", SymbolKind::Property);
+            }
+            ExprKind::StaticMethodCall(call) => {
+                self.static_member(&call.class, call.method, "", SymbolKind::Method, scope);
+            }
+            ExprKind::StaticPropertyAccess(access) => {
+                let kind = SymbolKind::Property;
+                self.static_member(&access.class, access.member, "$", kind, scope);
+            }
+            ExprKind::ClassConstAccess(access) => {
+                let kind = SymbolKind::ClassConstant;
+                self.static_member(&access.class, access.member, "", kind, scope);
+            }
+            _ => {}
+        }
+    }
+}
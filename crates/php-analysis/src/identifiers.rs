@@ -0,0 +1,128 @@
+//! Strict-ASCII identifier lint.
+//!
+//! PHP accepts any byte in `0x80..=0xFF` as an identifier character, so UTF-8
+//! names such as `$größe`, `class Ünicode`, or `function 🐘()` are valid. Many
+//! code bases still require ASCII-only names for portability and grep-ability;
+//! [`non_ascii_identifiers`] reports every name that breaks that rule.
+//!
+//! The lint works on tokens rather than the AST so every occurrence — not
+//! only declarations — gets a precise span. Inside double-quoted strings,
+//! heredocs, and backtick strings, simple interpolations (`"$name"`,
+//! `"$obj->prop"`, `"{$obj->prop}"`) are checked as well.
+
+use php_ast::Span;
+use php_lexer::{Lexer, TokenKind};
+
+/// One identifier containing bytes outside the ASCII range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonAsciiIdentifier {
+    /// The identifier as written, including the `$` of a variable.
+    pub name: String,
+    pub span: Span,
+}
+
+impl std::fmt::Display for NonAsciiIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "non-ASCII identifier `{}`", self.name)
+    }
+}
+
+/// Report every identifier in `source` that contains a non-ASCII byte, in
+/// source order.
+///
+/// ```
+/// use php_analysis::identifiers::non_ascii_identifiers;
+///
+/// let found = non_ascii_identifiers("<?php function größe($wert) { return \"$wert\"; }");
+/// let names: Vec<_> = found.iter().map(|f| f.name.as_str()).collect();
+/// assert_eq!(names, ["größe"]);
+///
+/// let found = non_ascii_identifiers("<?php $привет = 1; echo \"$привет\";");
+/// assert_eq!(found.len(), 2);
+/// ```
+pub fn non_ascii_identifiers(source: &str) -> Vec<NonAsciiIdentifier> {
+    let mut found = Vec::new();
+    let mut lexer = Lexer::new(source);
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::Eof => break,
+            TokenKind::Variable | TokenKind::Identifier => {
+                let text = lexer.token_text(&token);
+                if !text.is_ascii() {
+                    found.push(NonAsciiIdentifier {
+                        name: text.to_string(),
+                        span: token.span,
+                    });
+                }
+            }
+            TokenKind::DoubleQuotedString | TokenKind::Heredoc | TokenKind::BacktickString => {
+                let text = lexer.token_text(&token);
+                if !text.is_ascii() {
+                    scan_interpolations(text, token.span.start, &mut found);
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+/// Check the `$name` and `->name` parts of interpolations inside a string
+/// token whose text starts at byte offset `base`.
+fn scan_interpolations(text: &str, base: u32, found: &mut Vec<NonAsciiIdentifier>) {
+    let bytes = text.as_bytes();
+    let mut report = |start: usize, end: usize| {
+        let name = &text[start..end];
+        if !name.is_ascii() {
+            found.push(NonAsciiIdentifier {
+                name: name.to_string(),
+                span: Span::new(base + start as u32, base + end as u32),
+            });
+        }
+    };
+    // `true` right after a `$name` or `->name`, where `->name` may follow.
+    let mut in_chain = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                i += 2;
+                in_chain = false;
+            }
+            b'$' if bytes.get(i + 1).copied().is_some_and(is_ident_start) => {
+                let end = ident_end(bytes, i + 1);
+                report(i, end);
+                i = end;
+                in_chain = true;
+            }
+            b'-' if in_chain
+                && bytes.get(i + 1) == Some(&b'>')
+                && bytes.get(i + 2).copied().is_some_and(is_ident_start) =>
+            {
+                let end = ident_end(bytes, i + 2);
+                report(i + 2, end);
+                i = end;
+            }
+            _ => {
+                i += 1;
+                in_chain = false;
+            }
+        }
+    }
+}
+
+fn ident_end(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && is_ident_continue(bytes[i]) {
+        i += 1;
+    }
+    i
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b >= 0x80
+}
+
+fn is_ident_continue(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
@@ -0,0 +1,682 @@
+//! Inlay hints: the annotations an editor shows between the tokens of a file.
+//!
+//! [`inlay_hints`] produces three kinds:
+//!
+//! - parameter names before the positional arguments of calls to functions,
+//!   methods, and constructors whose declarations are in [`Signatures`];
+//! - the inferred return type of closures and arrow functions without one,
+//!   and the type of untyped properties from their default value;
+//! - the variables an arrow function captures from the enclosing scope.
+//!
+//! [`Signatures`] holds the declarations calls resolve against. Add each
+//! file of the project to it, and any stub files declaring built-in
+//! functions and classes; re-adding a file replaces what it declared.
+//!
+//! A parameter name is left out where it adds nothing: for an argument that
+//! is a variable of the same name, and after a named or unpacked argument.
+//! Types come from the same light inference as [hovers](crate::hover):
+//! parameter types, assignments of literals, `new`, and calls with declared
+//! return types.
+//!
+//! ```
+//! use php_analysis::inlay_hints::{inlay_hints, Signatures};
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! function send(string $to, string ...$lines): bool { return true; }
+//! $to = 'ops';
+//! $ok = fn() => send($to, 'disk full', 'retrying');
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let mut signatures = Signatures::new();
+//! signatures.add_file("send.php", &result.program);
+//!
+//! let hints: Vec<_> = inlay_hints(&result.program, src, &signatures)
+//!     .into_iter()
+//!     .map(|hint| (&src[hint.offset as usize..][..3], hint.label))
+//!     .collect();
+//! assert_eq!(hints, [
+//!     (" =>", "use ($to)".to_string()),
+//!     (" =>", ": bool".to_string()),
+//!     ("'di", "...lines:".to_string()),
+//! ]);
+//! ```
+
+use std::collections::HashMap;
+
+use php_ast::*;
+use php_lexer::{Lexer, TokenKind};
+
+use crate::hover::{ident_span, Type};
+use crate::normalize::implicit_captures;
+use crate::values::evaluate;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, ClassContext, Unit, UnitKind};
+
+/// What an inlay hint annotates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InlayHintKind {
+    /// The name of the parameter an argument binds to.
+    Parameter,
+    /// An inferred type.
+    Type,
+    /// The variables an arrow function captures.
+    Capture,
+}
+
+/// One annotation, shown at `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlayHint {
+    pub offset: u32,
+    pub label: String,
+    pub kind: InlayHintKind,
+    /// Whether the hint needs a space before it.
+    pub padding_left: bool,
+    /// Whether the hint needs a space after it.
+    pub padding_right: bool,
+}
+
+/// A declared parameter.
+#[derive(Debug, Clone)]
+struct ParamSig {
+    name: String,
+    variadic: bool,
+}
+
+/// A declared function or method.
+#[derive(Debug, Clone)]
+struct Callable {
+    file: String,
+    params: Vec<ParamSig>,
+    returns: Option<Type>,
+}
+
+/// The functions and methods declared across a project, for resolving calls.
+#[derive(Debug, Default)]
+pub struct Signatures {
+    /// By lowercase `name` or `class::method`.
+    callables: HashMap<String, Vec<Callable>>,
+    /// Parent classes, interfaces, and used traits by lowercase class name,
+    /// with the file declaring them.
+    supertypes: HashMap<String, Vec<(String, Vec<String>)>>,
+}
+
+/// The most supertypes followed when looking up an inherited method.
+const MAX_ANCESTORS: usize = 64;
+
+impl Signatures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the declarations of `program`, replacing any earlier ones of
+    /// `path`.
+    pub fn add_file(&mut self, path: impl Into<String>, program: &Program<'_, '_>) {
+        let path = path.into();
+        self.remove_file(&path);
+        let mut collector = Collector {
+            signatures: self,
+            file: path,
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+    }
+
+    /// Forget the declarations of `path`.
+    pub fn remove_file(&mut self, path: &str) {
+        self.callables.retain(|_, callables| {
+            callables.retain(|c| c.file != path);
+            !callables.is_empty()
+        });
+        self.supertypes.retain(|_, supertypes| {
+            supertypes.retain(|(file, _)| file != path);
+            !supertypes.is_empty()
+        });
+    }
+
+    fn callable(&self, name: &str) -> Option<&Callable> {
+        self.callables.get(&name.to_ascii_lowercase())?.first()
+    }
+
+    /// The function a call resolves to, trying the global fallback of an
+    /// unqualified name after the namespaced one.
+    fn function(&self, name: &Name<'_, '_>, scope: &AnalysisScope) -> Option<&Callable> {
+        let (fqn, fallback) = scope.names.resolve_function(&name.to_string_repr());
+        self.callable(&fqn)
+            .or_else(|| fallback.and_then(|fallback| self.callable(&fallback)))
+    }
+
+    /// The method `name` of `class` or of the nearest supertype declaring it.
+    fn method(&self, class: &str, name: &str) -> Option<&Callable> {
+        let mut queue = vec![class.to_string()];
+        let mut seen = 0;
+        while seen < queue.len() && seen < MAX_ANCESTORS {
+            let class = &queue[seen];
+            seen += 1;
+            if let Some(method) = self.callable(&format!("{class}::{name}")) {
+                return Some(method);
+            }
+            if let Some(declared) = self.supertypes.get(&class.to_ascii_lowercase()) {
+                let supertypes = declared.iter().flat_map(|(_, names)| names);
+                queue.extend(supertypes.cloned());
+            }
+        }
+        None
+    }
+
+    fn add(&mut self, name: String, callable: Callable) {
+        self.callables
+            .entry(name.to_ascii_lowercase())
+            .or_default()
+            .push(callable);
+    }
+}
+
+struct Collector<'a> {
+    signatures: &'a mut Signatures,
+    file: String,
+}
+
+impl Collector<'_> {
+    fn callable(
+        &self,
+        params: &[Param<'_, '_>],
+        return_type: Option<&TypeHint<'_, '_>>,
+        scope: &AnalysisScope,
+        class: Option<&str>,
+    ) -> Callable {
+        Callable {
+            file: self.file.clone(),
+            params: params
+                .iter()
+                .map(|param| ParamSig {
+                    name: param.name.or_error().to_string(),
+                    variadic: param.variadic,
+                })
+                .collect(),
+            returns: return_type.map(|hint| Type::hint(hint, &scope.names, class)),
+        }
+    }
+
+    fn supertypes(&mut self, class: &str, supertypes: Vec<String>) {
+        if !supertypes.is_empty() {
+            self.signatures
+                .supertypes
+                .entry(class.to_ascii_lowercase())
+                .or_default()
+                .push((self.file.clone(), supertypes));
+        }
+    }
+
+    fn method(&mut self, class: &str, method: &MethodDecl<'_, '_>, scope: &AnalysisScope) {
+        let return_type = method.return_type.as_ref();
+        let callable = self.callable(&method.params, return_type, scope, Some(class));
+        let name = format!("{class}::{}", method.name.or_error());
+        self.signatures.add(name, callable);
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_> {
+    fn enter_class(&mut self, class: &ClassContext, supertypes: &[String], _span: Span) {
+        if class.name != "class@anonymous" {
+            self.supertypes(&class.name, supertypes.to_vec());
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        let names = &scope.names;
+        let (name, members) = match &stmt.kind {
+            StmtKind::Function(func) => {
+                let return_type = func.return_type.as_ref();
+                let callable = self.callable(&func.params, return_type, scope, None);
+                self.signatures
+                    .add(names.qualify(func.name.or_error()), callable);
+                return;
+            }
+            StmtKind::Class(class) => match class.name {
+                Some(name) => (name, &class.members),
+                None => return,
+            },
+            StmtKind::Interface(iface) => (iface.name, &iface.members),
+            StmtKind::Trait(trait_decl) => (trait_decl.name, &trait_decl.members),
+            StmtKind::Enum(enum_decl) => {
+                let class = names.qualify(enum_decl.name.or_error());
+                for member in enum_decl.members.iter() {
+                    if let EnumMemberKind::Method(method) = &member.kind {
+                        self.method(&class, method, scope);
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+        let class = names.qualify(name.or_error());
+        let mut traits = Vec::new();
+        for member in members.iter() {
+            match &member.kind {
+                ClassMemberKind::Method(method) => self.method(&class, method, scope),
+                ClassMemberKind::TraitUse(used) => traits.extend(
+                    used.traits
+                        .iter()
+                        .map(|name| names.resolve_class(&name.to_string_repr())),
+                ),
+                _ => {}
+            }
+        }
+        self.supertypes(&class, traits);
+    }
+}
+
+/// The inlay hints of `program`, parsed from `source`, in source order.
+pub fn inlay_hints(
+    program: &Program<'_, '_>,
+    source: &str,
+    signatures: &Signatures,
+) -> Vec<InlayHint> {
+    let mut hinter = Hinter {
+        signatures,
+        source,
+        frames: Vec::new(),
+        hints: Vec::new(),
+    };
+    AnalysisWalker::new(&mut hinter).walk(program);
+    let mut hints = hinter.hints;
+    hints.sort_by_key(|hint| hint.offset);
+    hints
+}
+
+/// The variables of a unit, with their types where known.
+#[derive(Default)]
+struct Frame {
+    vars: HashMap<String, Option<Type>>,
+    /// Where a closure without a return type gets its type hint.
+    header_end: Option<u32>,
+    /// The types of the closure's `return` values; `None` for an unknown
+    /// type, and `void` for a bare `return;`.
+    returns: Vec<Option<Type>>,
+    yields: bool,
+}
+
+struct Hinter<'a, 's> {
+    signatures: &'a Signatures,
+    source: &'s str,
+    frames: Vec<Frame>,
+    hints: Vec<InlayHint>,
+}
+
+impl<'a> Hinter<'a, '_> {
+    fn hint(&mut self, offset: u32, label: String, kind: InlayHintKind) {
+        self.hints.push(InlayHint {
+            offset,
+            label,
+            kind,
+            padding_left: kind == InlayHintKind::Capture,
+            padding_right: kind == InlayHintKind::Parameter,
+        });
+    }
+
+    /// The variables a unit starts with: its parameters, `$this` in a class,
+    /// and what a closure imports.
+    fn unit_vars(
+        &self,
+        params: &[Param<'_, '_>],
+        uses: &[ClosureUseVar<'_>],
+        arrow: bool,
+        scope: &AnalysisScope,
+    ) -> HashMap<String, Option<Type>> {
+        let mut vars = HashMap::new();
+        if let Some(parent) = self.frames.last() {
+            if arrow {
+                vars = parent.vars.clone();
+            }
+            for var in uses {
+                let ty = parent.vars.get(var.name).cloned().flatten();
+                vars.insert(var.name.to_string(), ty);
+            }
+        }
+        let class = scope.class.as_ref().map(|c| c.name.as_str());
+        if let Some(class) = class.filter(|_| !self.frames.is_empty()) {
+            vars.insert("this".to_string(), Some(Type::class(class.to_string())));
+        }
+        for param in params {
+            let ty = match &param.type_hint {
+                Some(hint) if !param.variadic => Some(Type::hint(hint, &scope.names, class)),
+                Some(_) => Some(Type {
+                    display: "array".to_string(),
+                    class: None,
+                }),
+                None => None,
+            };
+            vars.insert(param.name.or_error().to_string(), ty);
+        }
+        vars
+    }
+
+    fn variable(&self, name: &str) -> Option<Type> {
+        self.frames.last()?.vars.get(name).cloned().flatten()
+    }
+
+    /// The type of `expr`, where the file says.
+    fn infer(&self, expr: &Expr<'_, '_>, scope: &AnalysisScope) -> Option<Type> {
+        let scalar = |name: &str| {
+            Some(Type {
+                display: name.to_string(),
+                class: None,
+            })
+        };
+        match &expr.kind {
+            ExprKind::Variable(name) => self.variable(name.as_str()),
+            ExprKind::Parenthesized(inner) => self.infer(inner, scope),
+            ExprKind::New(new) => scope.resolve_class(&new.class).map(Type::class),
+            ExprKind::Closure(_) | ExprKind::ArrowFunction(_) => {
+                Some(Type::class("Closure".to_string()))
+            }
+            ExprKind::FunctionCall(call) => {
+                let ExprKind::Name(name) = &call.name.kind else {
+                    return None;
+                };
+                self.signatures.function(name, scope)?.returns.clone()
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let class = self.infer(call.object, scope)?.class?;
+                self.method(&class, call.method)?
+                    .returns
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::StaticMethodCall(call) => {
+                let class = scope.resolve_class(&call.class)?;
+                self.method(&class, call.method)?
+                    .returns
+                    .as_ref()
+                    .map(Type::of_value)
+            }
+            ExprKind::Binary(binary) => match binary.op {
+                BinaryOp::Concat => scalar("string"),
+                BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Mod => {
+                    let left = self.infer(binary.left, scope)?.display;
+                    let right = self.infer(binary.right, scope)?.display;
+                    match (left.as_str(), right.as_str()) {
+                        ("int", "int") => scalar("int"),
+                        ("int" | "float", "int" | "float") if binary.op != BinaryOp::Mod => {
+                            scalar("float")
+                        }
+                        ("int" | "float", "int" | "float") => scalar("int"),
+                        _ => None,
+                    }
+                }
+                BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Identical
+                | BinaryOp::NotIdentical
+                | BinaryOp::Less
+                | BinaryOp::Greater
+                | BinaryOp::LessOrEqual
+                | BinaryOp::GreaterOrEqual
+                | BinaryOp::BooleanAnd
+                | BinaryOp::BooleanOr
+                | BinaryOp::LogicalAnd
+                | BinaryOp::LogicalOr
+                | BinaryOp::LogicalXor => scalar("bool"),
+                BinaryOp::Spaceship => scalar("int"),
+                _ => None,
+            },
+            ExprKind::UnaryPrefix(unary) if unary.op == UnaryPrefixOp::BooleanNot => scalar("bool"),
+            ExprKind::Isset(_) | ExprKind::Empty(_) | ExprKind::Instanceof(_) => scalar("bool"),
+            ExprKind::Cast(kind, _) => match kind {
+                CastKind::Int => scalar("int"),
+                CastKind::Float => scalar("float"),
+                CastKind::String => scalar("string"),
+                CastKind::Bool => scalar("bool"),
+                CastKind::Array => scalar("array"),
+                CastKind::Object => Some(Type::class("stdClass".to_string())),
+                CastKind::Unset | CastKind::Void => scalar("null"),
+                _ => None,
+            },
+            ExprKind::InterpolatedString(_) | ExprKind::Heredoc { .. } => scalar("string"),
+            ExprKind::Array(_) => scalar("array"),
+            _ => evaluate(expr).and_then(|value| scalar(value.type_name())),
+        }
+    }
+
+    /// The declared method named by the identifier `method`.
+    fn method(&self, class: &str, method: &Expr<'_, '_>) -> Option<&'a Callable> {
+        let ExprKind::Identifier(name) = &method.kind else {
+            return None;
+        };
+        self.signatures.method(class, name.as_str())
+    }
+
+    /// Name the parameters the positional `args` bind to.
+    fn arguments(&mut self, callable: Option<&Callable>, args: &[Arg<'_, '_>]) {
+        let Some(callable) = callable else {
+            return;
+        };
+        let mut hints = Vec::new();
+        for (i, arg) in args.iter().enumerate() {
+            if arg.name.is_some() || arg.unpack {
+                break;
+            }
+            let param = match callable.params.get(i) {
+                Some(param) => param,
+                None => break,
+            };
+            if matches!(&arg.value.kind, ExprKind::Variable(name) if name.as_str() == param.name) {
+                continue;
+            }
+            let label = if param.variadic {
+                format!("...{}:", param.name)
+            } else {
+                format!("{}:", param.name)
+            };
+            hints.push((arg.span.start, label));
+            if param.variadic {
+                break;
+            }
+        }
+        for (offset, label) in hints {
+            self.hint(offset, label, InlayHintKind::Parameter);
+        }
+    }
+
+    /// Hint the type of each untyped property initialized to a non-null
+    /// constant.
+    fn properties(&mut self, members: &[ClassMember<'_, '_>]) {
+        for member in members {
+            let ClassMemberKind::Property(prop) = &member.kind else {
+                continue;
+            };
+            let (None, Some(default)) = (&prop.type_hint, &prop.default) else {
+                continue;
+            };
+            let Some(value) = evaluate(default).filter(|v| v.type_name() != "null") else {
+                continue;
+            };
+            let Some(span) = ident_span(self.source, prop.name) else {
+                continue;
+            };
+            // Before the `$`.
+            let offset = span.start.saturating_sub(1);
+            self.hints.push(InlayHint {
+                offset,
+                label: value.type_name().to_string(),
+                kind: InlayHintKind::Type,
+                padding_left: false,
+                padding_right: true,
+            });
+        }
+    }
+
+    fn return_type(&mut self, offset: u32, ty: &Type) {
+        let label = match &ty.class {
+            // Short class names read better inline.
+            Some(class) if *class == ty.display => {
+                class.rsplit('\\').next().unwrap_or(class).to_string()
+            }
+            _ => ty.display.clone(),
+        };
+        self.hint(offset, format!(": {label}"), InlayHintKind::Type);
+    }
+}
+
+/// The end of the `)` closing the parameters of the closure or arrow
+/// function at `start`, and, for a closure, of its `use` list.
+fn header_end(source: &str, start: u32, arrow: bool) -> Option<u32> {
+    let mut lexer = Lexer::new_at(source, start as usize);
+    let mut depth = 0usize;
+    let mut end = None;
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::HashBracket => depth += 1,
+            TokenKind::RightParen => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    end = Some(token.span.end);
+                    if arrow {
+                        return end;
+                    }
+                }
+            }
+            TokenKind::RightBracket => depth = depth.checked_sub(1)?,
+            TokenKind::LeftBrace if depth == 0 => return end,
+            TokenKind::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Hinter<'_, '_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        let arrow = unit.kind == UnitKind::Closure && unit.body.is_none();
+        let vars = self.unit_vars(unit.params, unit.uses, arrow, scope);
+        let header_end = match unit.kind {
+            UnitKind::Closure if !arrow && unit.return_type.is_none() => {
+                header_end(self.source, unit.span.start, false)
+            }
+            _ => None,
+        };
+        self.frames.push(Frame {
+            vars,
+            header_end,
+            ..Frame::default()
+        });
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+        let Some(offset) = frame.header_end.filter(|_| !frame.yields) else {
+            return;
+        };
+        let mut returns = frame.returns.into_iter();
+        let ty = match returns.next() {
+            None => Some(Type {
+                display: "void".to_string(),
+                class: None,
+            }),
+            Some(first) => first.and_then(|first| {
+                returns.try_fold(first, |ty, next| {
+                    next.filter(|next| next.display == ty.display).map(|_| ty)
+                })
+            }),
+        };
+        if let Some(ty) = ty {
+            self.return_type(offset, &ty);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Return(value) => {
+                let ty = match value {
+                    Some(value) => self.infer(value, scope),
+                    None => Some(Type {
+                        display: "void".to_string(),
+                        class: None,
+                    }),
+                };
+                if let Some(frame) = self.frames.last_mut() {
+                    frame.returns.push(ty);
+                }
+            }
+            StmtKind::Class(class) => self.properties(&class.members),
+            StmtKind::Trait(trait_decl) => self.properties(&trait_decl.members),
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        match &expr.kind {
+            ExprKind::Assign(assign) => {
+                if let (AssignOp::Assign, ExprKind::Variable(name)) =
+                    (assign.op, &assign.target.kind)
+                {
+                    let ty = self.infer(assign.value, scope);
+                    if let Some(frame) = self.frames.last_mut() {
+                        frame.vars.insert(name.as_str().to_string(), ty);
+                    }
+                }
+            }
+            ExprKind::FunctionCall(call) => {
+                if let ExprKind::Name(name) = &call.name.kind {
+                    let signatures = self.signatures;
+                    let callable = signatures.function(name, scope);
+                    self.arguments(callable, &call.args);
+                }
+            }
+            ExprKind::New(new) => {
+                let signatures = self.signatures;
+                let callable = scope
+                    .resolve_class(&new.class)
+                    .and_then(|class| signatures.method(&class, "__construct"));
+                self.arguments(callable, &new.args);
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let callable = self
+                    .infer(call.object, scope)
+                    .and_then(|ty| ty.class)
+                    .and_then(|class| self.method(&class, call.method));
+                self.arguments(callable, &call.args);
+            }
+            ExprKind::StaticMethodCall(call) => {
+                let callable = scope
+                    .resolve_class(&call.class)
+                    .and_then(|class| self.method(&class, call.method));
+                self.arguments(callable, &call.args);
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                let Some(offset) = header_end(self.source, expr.span.start, true) else {
+                    return;
+                };
+                let captures: Vec<String> = implicit_captures(arrow)
+                    .iter()
+                    .map(|var| format!("${}", var.name))
+                    .collect();
+                if !captures.is_empty() {
+                    let label = format!("use ({})", captures.join(", "));
+                    self.hint(offset, label, InlayHintKind::Capture);
+                }
+                if arrow.return_type.is_none() {
+                    // The body sees the parameters and the enclosing scope.
+                    let vars = self.unit_vars(&arrow.params, &[], true, scope);
+                    self.frames.push(Frame {
+                        vars,
+                        ..Frame::default()
+                    });
+                    let ty = self.infer(arrow.body, scope);
+                    self.frames.pop();
+                    if let Some(ty) = ty {
+                        self.return_type(offset, &ty);
+                    }
+                }
+            }
+            ExprKind::AnonymousClass(class) => self.properties(&class.members),
+            ExprKind::Yield(_) => {
+                if let Some(frame) = self.frames.last_mut() {
+                    frame.yields = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Inline `@var` type assertions, applied the way PHPStan and Psalm apply
+//! them.
+//!
+//! A `/** @var Type $x */` doc-block in a function body asserts the type of a
+//! variable at the statement that follows it (see [`crate::directives`] for
+//! how a comment is attached to a statement):
+//!
+//! | Statement after the doc-block    | `@var Type $x`              | `@var Type`                    |
+//! |----------------------------------|-----------------------------|--------------------------------|
+//! | `$x = …;`                        | the assigned `$x`           | the assigned variable          |
+//! | `[$a, $b] = …;`                  | `$x` if destructured, else in scope | missing variable name  |
+//! | `foreach ($xs as $k => $x)`      | the key or value `$x`, else in scope | missing variable name |
+//! | anything else                    | `$x` as it is in scope      | missing variable name          |
+//!
+//! Types are kept as written; resolving them is up to the consumer.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_stmt, Visitor};
+use php_ast::{Expr, ExprKind, Program, Span, Stmt, StmtKind};
+
+use crate::directives::{DirectiveKind, Directives};
+
+/// How an asserted variable relates to the statement it annotates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssertionTarget {
+    /// Assigned by the statement, directly or by destructuring.
+    Assigned,
+    /// The key variable of a `foreach`.
+    ForeachKey,
+    /// The value variable of a `foreach`, directly or by destructuring.
+    ForeachValue,
+    /// Not written by the statement: the type holds for the variable as it
+    /// is in scope there.
+    InScope,
+}
+
+impl std::fmt::Display for AssertionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AssertionTarget::Assigned => "assigned",
+            AssertionTarget::ForeachKey => "foreach key",
+            AssertionTarget::ForeachValue => "foreach value",
+            AssertionTarget::InScope => "in scope",
+        })
+    }
+}
+
+/// One variable whose type an inline `@var` asserts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VarAssertion {
+    /// The variable name, without `$`.
+    pub variable: String,
+    /// The type as written in the doc-block.
+    pub ty: String,
+    pub target: AssertionTarget,
+    /// The statement the assertion applies to.
+    pub stmt: Span,
+    /// The doc-block the assertion was read from.
+    pub annotation: Span,
+}
+
+/// An inline `@var` that cannot be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InlineVarIssue {
+    /// `@var Type` without a variable above a statement that does not assign
+    /// exactly one variable.
+    MissingVariableName { annotation: Span, stmt: Span },
+}
+
+impl std::fmt::Display for InlineVarIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InlineVarIssue::MissingVariableName { .. } => {
+                f.write_str("PHPDoc tag @var does not specify a variable name")
+            }
+        }
+    }
+}
+
+impl InlineVarIssue {
+    pub fn span(&self) -> Span {
+        match self {
+            InlineVarIssue::MissingVariableName { annotation, .. } => *annotation,
+        }
+    }
+}
+
+/// The inline `@var` assertions of one file, keyed by statement.
+#[derive(Debug, Clone, Default)]
+pub struct InlineVarTypes {
+    assertions: Vec<VarAssertion>,
+    issues: Vec<InlineVarIssue>,
+    by_stmt: HashMap<Span, Vec<usize>>,
+}
+
+impl InlineVarTypes {
+    /// Every assertion, in source order.
+    pub fn assertions(&self) -> &[VarAssertion] {
+        &self.assertions
+    }
+
+    /// Annotations that could not be applied, in source order.
+    pub fn issues(&self) -> &[InlineVarIssue] {
+        &self.issues
+    }
+
+    /// The assertions that apply to the statement spanning `stmt`.
+    pub fn for_stmt(&self, stmt: Span) -> impl Iterator<Item = &VarAssertion> {
+        self.by_stmt
+            .get(&stmt)
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.assertions[i])
+    }
+
+    /// The type asserted for `variable` (with or without `$`) at the
+    /// statement spanning `stmt`. When several doc-blocks name the same
+    /// variable, the last one wins.
+    pub fn asserted_type(&self, stmt: Span, variable: &str) -> Option<&str> {
+        let variable = variable.strip_prefix('$').unwrap_or(variable);
+        self.for_stmt(stmt)
+            .filter(|a| a.variable == variable)
+            .last()
+            .map(|a| a.ty.as_str())
+    }
+}
+
+/// Apply the inline `@var` directives in `directives` to the statements of
+/// `program`.
+///
+/// ```
+/// use php_analysis::directives::Directives;
+/// use php_analysis::inline_var::{inline_var_types, AssertionTarget};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// /** @var list<User> */
+/// $users = $repo->all();
+/// /** @var User $user */
+/// foreach ($users as $user) {}
+/// ";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let directives = Directives::collect(src, &result.comments, &result.program);
+/// let types = inline_var_types(&directives, &result.program);
+///
+/// let assign = result.program.stmts[0].span;
+/// assert_eq!(types.asserted_type(assign, "$users"), Some("list<User>"));
+/// let foreach = result.program.stmts[1].span;
+/// let user = types.for_stmt(foreach).next().unwrap();
+/// assert_eq!((user.variable.as_str(), user.target), ("user", AssertionTarget::ForeachValue));
+/// ```
+pub fn inline_var_types(directives: &Directives, program: &Program<'_, '_>) -> InlineVarTypes {
+    let mut pending: HashMap<Span, Vec<VarTag<'_>>> = HashMap::new();
+    for directive in directives {
+        if let (DirectiveKind::Var { ty, variable }, Some(stmt)) = (&directive.kind, directive.stmt)
+        {
+            let variable = variable.as_deref().map(|v| v.trim_start_matches('$'));
+            pending.entry(stmt).or_default().push(VarTag {
+                ty,
+                variable,
+                annotation: directive.span,
+            });
+        }
+    }
+
+    let mut applier = Applier {
+        pending,
+        types: InlineVarTypes::default(),
+    };
+    if !applier.pending.is_empty() {
+        let _ = applier.visit_program(program);
+    }
+    let mut types = applier.types;
+    types.assertions.sort_by_key(|a| a.annotation);
+    types.issues.sort_by_key(InlineVarIssue::span);
+    for (i, assertion) in types.assertions.iter().enumerate() {
+        types.by_stmt.entry(assertion.stmt).or_default().push(i);
+    }
+    types
+}
+
+/// One `@var` tag waiting for its statement.
+struct VarTag<'d> {
+    ty: &'d str,
+    /// Without `$`.
+    variable: Option<&'d str>,
+    annotation: Span,
+}
+
+struct Applier<'d> {
+    /// The `@var` tags attached to each statement span, removed once applied.
+    pending: HashMap<Span, Vec<VarTag<'d>>>,
+    types: InlineVarTypes,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Applier<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        if let Some(tags) = self.pending.remove(&stmt.span) {
+            let written = written_variables(stmt);
+            for VarTag {
+                ty,
+                variable,
+                annotation,
+            } in tags
+            {
+                let applied = match variable {
+                    Some(name) => Some((
+                        name.to_string(),
+                        written
+                            .iter()
+                            .find(|(written, _)| *written == name)
+                            .map_or(AssertionTarget::InScope, |&(_, target)| target),
+                    )),
+                    None => match (&stmt.kind, written.as_slice()) {
+                        (StmtKind::Expression(_), [(name, AssertionTarget::Assigned)]) => {
+                            Some((name.to_string(), AssertionTarget::Assigned))
+                        }
+                        _ => None,
+                    },
+                };
+                match applied {
+                    Some((variable, target)) => self.types.assertions.push(VarAssertion {
+                        variable,
+                        ty: ty.to_string(),
+                        target,
+                        stmt: stmt.span,
+                        annotation,
+                    }),
+                    None => self.types.issues.push(InlineVarIssue::MissingVariableName {
+                        annotation,
+                        stmt: stmt.span,
+                    }),
+                }
+            }
+        }
+        walk_stmt(self, stmt)
+    }
+}
+
+/// The variables `stmt` itself writes: the targets of an assignment
+/// statement (`$a = $b = …` writes both), or the key and value of a
+/// `foreach`.
+fn written_variables<'s>(stmt: &'s Stmt<'_, '_>) -> Vec<(&'s str, AssertionTarget)> {
+    let mut written = Vec::new();
+    match &stmt.kind {
+        StmtKind::Expression(expr) => {
+            let mut expr: &Expr<'_, '_> = expr;
+            while let ExprKind::Assign(assign) = &expr.kind {
+                destructured(assign.target, AssertionTarget::Assigned, &mut written);
+                expr = assign.value;
+            }
+        }
+        StmtKind::Foreach(foreach) => {
+            if let Some(key) = &foreach.key {
+                destructured(key, AssertionTarget::ForeachKey, &mut written);
+            }
+            destructured(&foreach.value, AssertionTarget::ForeachValue, &mut written);
+        }
+        _ => {}
+    }
+    written
+}
+
+fn destructured<'s>(
+    expr: &'s Expr<'_, '_>,
+    target: AssertionTarget,
+    written: &mut Vec<(&'s str, AssertionTarget)>,
+) {
+    match &expr.kind {
+        ExprKind::Variable(name) => written.push((name.as_str(), target)),
+        ExprKind::List(list) => {
+            for element in list.elements.iter() {
+                destructured(&element.value, target, written);
+            }
+        }
+        _ => {}
+    }
+}
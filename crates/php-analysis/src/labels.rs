@@ -0,0 +1,312 @@
+//! `goto` label tables and jump validation.
+//!
+//! Labels are scoped to one executable body: a function, method, closure,
+//! hook, or the top level of a file. [`LabelTable::build`] collects the labels
+//! and `goto` statements of a body, without descending into nested
+//! declarations, and records which loops, `switch` statements, and `finally`
+//! blocks enclose each of them.
+//!
+//! [`LabelTable::errors`] applies the engine's compile-time rules:
+//!
+//! - a label may be defined only once per body;
+//! - every `goto` must name a label of its own body;
+//! - a `goto` may leave a loop or `switch` but not jump into one;
+//! - a `goto` may neither jump into nor out of a `finally` block.
+
+use std::collections::HashSet;
+
+use php_ast::{NamespaceBody, Program, Span, Stmt, StmtKind};
+
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit};
+
+/// A `label:` statement.
+#[derive(Debug, Clone)]
+pub struct Label<'a> {
+    pub name: &'a str,
+    pub span: Span,
+    regions: Vec<Region>,
+}
+
+/// A `goto label;` statement.
+#[derive(Debug, Clone)]
+pub struct Goto<'a> {
+    pub label: &'a str,
+    pub span: Span,
+    regions: Vec<Region>,
+}
+
+/// A construct that restricts jumps into or out of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    /// A loop or `switch` body.
+    Loop(usize),
+    /// A `finally` block.
+    Finally(usize),
+}
+
+/// The labels and `goto` statements of one body, in source order.
+///
+/// ```
+/// use php_analysis::labels::{LabelErrorKind, LabelTable};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php goto end; while (true) { inner: break; } goto inner; end:";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let table = LabelTable::build(&result.program.stmts);
+/// assert_eq!(table.labels().len(), 2);
+/// assert_eq!(table.target(&table.gotos()[0]).map(|l| l.name), Some("end"));
+///
+/// let kinds: Vec<_> = table.errors().iter().map(|e| e.kind).collect();
+/// assert_eq!(kinds, [LabelErrorKind::IntoLoop]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LabelTable<'a> {
+    labels: Vec<Label<'a>>,
+    gotos: Vec<Goto<'a>>,
+}
+
+impl<'a> LabelTable<'a> {
+    pub fn build<'arena: 'a, 'src: 'a>(body: &'a [Stmt<'arena, 'src>]) -> Self {
+        let mut collector = Collector {
+            table: LabelTable::default(),
+            regions: Vec::new(),
+            next_region: 0,
+        };
+        collector.stmts(body);
+        collector.table
+    }
+
+    pub fn labels(&self) -> &[Label<'a>] {
+        &self.labels
+    }
+
+    pub fn gotos(&self) -> &[Goto<'a>] {
+        &self.gotos
+    }
+
+    /// The first definition of `name`. Label names are case-sensitive.
+    pub fn label(&self, name: &str) -> Option<&Label<'a>> {
+        self.labels.iter().find(|label| label.name == name)
+    }
+
+    /// The label `goto` jumps to, if it is defined in this body.
+    pub fn target(&self, goto: &Goto<'_>) -> Option<&Label<'a>> {
+        self.label(goto.label)
+    }
+
+    /// Violations of the engine's label rules, in source order.
+    pub fn errors(&self) -> Vec<LabelError> {
+        let mut errors = Vec::new();
+        let mut seen = HashSet::new();
+        for label in &self.labels {
+            if !seen.insert(label.name) {
+                errors.push(LabelError::new(
+                    LabelErrorKind::Duplicate,
+                    label.name,
+                    label.span,
+                ));
+            }
+        }
+        for goto in &self.gotos {
+            let kind = match self.target(goto) {
+                None => Some(LabelErrorKind::Undefined),
+                Some(label) => jump_error(&goto.regions, &label.regions),
+            };
+            if let Some(kind) = kind {
+                errors.push(LabelError::new(kind, goto.label, goto.span));
+            }
+        }
+        errors.sort_by_key(|e| (e.span.start, e.span.end));
+        errors
+    }
+}
+
+/// The rule broken by jumping from `from` to `to`, checked in the order the
+/// engine reports them.
+fn jump_error(from: &[Region], to: &[Region]) -> Option<LabelErrorKind> {
+    let loops = |regions: &[Region]| -> Vec<usize> {
+        regions
+            .iter()
+            .filter_map(|r| match r {
+                Region::Loop(id) => Some(*id),
+                Region::Finally(_) => None,
+            })
+            .collect()
+    };
+    if !loops(from).starts_with(&loops(to)) {
+        return Some(LabelErrorKind::IntoLoop);
+    }
+    let is_finally = |r: &&Region| matches!(r, Region::Finally(_));
+    if to.iter().filter(is_finally).any(|r| !from.contains(r)) {
+        return Some(LabelErrorKind::IntoFinally);
+    }
+    if from.iter().filter(is_finally).any(|r| !to.contains(r)) {
+        return Some(LabelErrorKind::OutOfFinally);
+    }
+    None
+}
+
+/// The rule a label or `goto` breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LabelErrorKind {
+    /// A second definition of a label in the same body.
+    Duplicate,
+    /// A `goto` to a label that is not defined in its body.
+    Undefined,
+    /// A `goto` into a loop or `switch` it is not already inside.
+    IntoLoop,
+    /// A `goto` into a `finally` block from outside it.
+    IntoFinally,
+    /// A `goto` from inside a `finally` block to a label outside it.
+    OutOfFinally,
+}
+
+/// A label rule violation. `span` covers the offending label or `goto`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelError {
+    pub kind: LabelErrorKind,
+    pub label: String,
+    pub span: Span,
+}
+
+impl LabelError {
+    fn new(kind: LabelErrorKind, label: &str, span: Span) -> Self {
+        Self {
+            kind,
+            label: label.to_string(),
+            span,
+        }
+    }
+}
+
+impl std::fmt::Display for LabelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            LabelErrorKind::Duplicate => write!(f, "Label '{}' already defined", self.label),
+            LabelErrorKind::Undefined => {
+                write!(f, "'goto' to undefined label '{}'", self.label)
+            }
+            LabelErrorKind::IntoLoop => {
+                f.write_str("'goto' into loop or switch statement is disallowed")
+            }
+            LabelErrorKind::IntoFinally => f.write_str("jump into a finally block is disallowed"),
+            LabelErrorKind::OutOfFinally => {
+                f.write_str("jump out of a finally block is disallowed")
+            }
+        }
+    }
+}
+
+/// Validate the labels of every body in `program`.
+///
+/// ```
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php function f() { a: a: goto b; }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let messages: Vec<_> = php_analysis::labels::validate(&result.program)
+///     .iter()
+///     .map(|e| e.to_string())
+///     .collect();
+/// assert_eq!(messages, ["Label 'a' already defined", "'goto' to undefined label 'b'"]);
+/// ```
+pub fn validate(program: &Program<'_, '_>) -> Vec<LabelError> {
+    let mut checker = Checker { errors: Vec::new() };
+    AnalysisWalker::new(&mut checker).walk(program);
+    checker
+        .errors
+        .sort_by_key(|e| (e.span.start, e.span.end, e.kind));
+    checker.errors
+}
+
+struct Checker {
+    errors: Vec<LabelError>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Checker {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        if let Some(body) = unit.body {
+            self.errors.extend(LabelTable::build(body).errors());
+        }
+    }
+}
+
+struct Collector<'a> {
+    table: LabelTable<'a>,
+    regions: Vec<Region>,
+    next_region: usize,
+}
+
+impl<'a> Collector<'a> {
+    fn stmts<'arena: 'a, 'src: 'a>(&mut self, stmts: &'a [Stmt<'arena, 'src>]) {
+        for stmt in stmts {
+            self.stmt(stmt);
+        }
+    }
+
+    fn stmt<'arena: 'a, 'src: 'a>(&mut self, stmt: &'a Stmt<'arena, 'src>) {
+        match &stmt.kind {
+            StmtKind::Block(stmts) => self.stmts(stmts),
+            StmtKind::If(if_stmt) => {
+                self.stmt(if_stmt.then_branch);
+                for branch in if_stmt.elseif_branches.iter() {
+                    self.stmt(&branch.body);
+                }
+                if let Some(else_branch) = if_stmt.else_branch {
+                    self.stmt(else_branch);
+                }
+            }
+            StmtKind::While(while_stmt) => {
+                self.in_region(Region::Loop, |c| c.stmt(while_stmt.body))
+            }
+            StmtKind::DoWhile(do_while) => self.in_region(Region::Loop, |c| c.stmt(do_while.body)),
+            StmtKind::For(for_stmt) => self.in_region(Region::Loop, |c| c.stmt(for_stmt.body)),
+            StmtKind::Foreach(foreach) => self.in_region(Region::Loop, |c| c.stmt(foreach.body)),
+            StmtKind::Switch(switch) => self.in_region(Region::Loop, |c| {
+                for case in switch.cases.iter() {
+                    c.stmts(&case.body);
+                }
+            }),
+            StmtKind::TryCatch(try_catch) => {
+                self.stmts(&try_catch.body);
+                for catch in try_catch.catches.iter() {
+                    self.stmts(&catch.body);
+                }
+                if let Some(finally) = &try_catch.finally {
+                    self.in_region(Region::Finally, |c| c.stmts(finally));
+                }
+            }
+            StmtKind::Declare(declare) => {
+                if let Some(body) = declare.body {
+                    self.stmt(body);
+                }
+            }
+            StmtKind::Namespace(ns) => {
+                if let NamespaceBody::Braced(stmts) = &ns.body {
+                    self.stmts(stmts);
+                }
+            }
+            StmtKind::Goto(label) => {
+                if let Some(label) = label.as_str() {
+                    self.table.gotos.push(Goto {
+                        label,
+                        span: stmt.span,
+                        regions: self.regions.clone(),
+                    });
+                }
+            }
+            StmtKind::Label(name) => self.table.labels.push(Label {
+                name,
+                span: stmt.span,
+                regions: self.regions.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    fn in_region(&mut self, region: fn(usize) -> Region, f: impl FnOnce(&mut Self)) {
+        self.regions.push(region(self.next_region));
+        self.next_region += 1;
+        f(self);
+        self.regions.pop();
+    }
+}
@@ -0,0 +1,109 @@
+//! Static analyses over the PHP AST produced by `php-rs-parser`.
+//!
+//! Analyses work on owned names so they can span a whole project: add each
+//! parsed file with `add_file`, then call `finish` to compute the results.
+//!
+//! - [`callgraph`] — project-wide call graph and class hierarchy.
+//! - [`coupling`] — class and namespace dependency graph with afferent and efferent coupling, cycles, DOT and JSON export, and threshold diagnostics.
+//! - [`clones`] — copy-paste detection: pairs of subtrees and statement runs with the same normalized shape.
+//! - [`class_strings`] — parse-time-known strings naming classes and functions.
+//! - [`enums`] — project-wide enum case index and exhaustiveness lint for `switch`/`match` over enums.
+//! - [`exceptions`] — `throw` / `@throws` propagation and undeclared-exception reports.
+//! - [`members`] — effective methods of a class with traits, `insteadof`/`as`, and inheritance flattened.
+//! - [`properties`] — declared and promoted-constructor properties of a class.
+//! - [`values`] — PHP values of constant expressions, with PHP 8 juggling and comparison, and the evaluated initializers of a file.
+//! - [`attributes`] — attributes with their arguments evaluated and `::class` resolved.
+//! - [`symbols`] — project-wide symbol index over prioritized source roots, with ranked fuzzy search.
+//! - [`resolve`] — side table of the fully-qualified name of every class, function, and constant name in a file.
+//! - [`hover`] — the resolved name, signature, doc-block summary, and type of the symbol or variable at an offset.
+//! - [`inlay_hints`] — parameter names at call sites, inferred closure and property types, and arrow-function captures.
+//! - [`constants`] — project-wide global constants from `const` and `define()`, including conditional definitions.
+//! - [`purity`] — side-effect classification of functions and methods.
+//! - [`cfg`] — control-flow graph of a function or file body.
+//! - [`dominance`] — dominator and post-dominator trees and dominance frontiers over a CFG.
+//! - [`dataflow`] — forward and backward fixpoint solver over a CFG, with gen/kill bit-set problems.
+//! - [`labels`] — per-body `goto` label tables and jump validation.
+//! - [`returns`] — missing-return and unreachable-code diagnostics.
+//! - [`definite_assignment`] — reads of variables that may be undefined on some path.
+//! - [`scope_info`] — per-function variable scopes: defined variables, reads before assignment, unused closure `use` imports, and arrow-function captures.
+//! - [`nullability`] — `->` on possibly null variables, narrowed by null checks, `instanceof`, and `isset`.
+//! - [`min_version`] — the lowest PHP version a codebase requires, from the newest syntax it uses.
+//! - [`dynamic`] — per-file inventory of dynamic constructs (`eval`, `$$x`, `$f()`, …).
+//! - [`identifiers`] — strict-ASCII lint for identifiers.
+//! - [`assignment_in_condition`] — lint for `=` used as an `if`, loop, or ternary condition.
+//! - [`loose_comparison`] — `==`/`!=` against literals and non-strict `in_array()`, with fixes to the strict forms.
+//! - [`psr`] — PSR-1 and PSR-12 structural lints: side effects beside declarations, one class per file, class and file names under a PSR-4 mapping, and open and closing tags.
+//! - [`string_literals`] — every string literal with its decoded value, source text, and use: array key, call argument, echo, concatenation, initializer.
+//! - [`suppressions`] — `@` error-suppression operators and what they silence.
+//! - [`directives`] — `phpcs:ignore`, `@lint-ignore`, and inline `@var` comments, keyed by line and statement.
+//! - [`inline_var`] — inline `@var` type assertions applied to the variables they name.
+//! - [`normalize`] — rewrites that lower interpolated strings and heredocs to concatenation, arrow functions to closures, alternative syntax to braces, promoted constructor parameters to properties, and `foreach` destructuring to assignments, separately or all at once with `lower`.
+//! - [`codemod`] — AST-driven source rewrites, such as making implicitly nullable parameters explicit.
+//! - [`code_action`] — quick fixes and refactorings at a span, gathered from parse errors, lints, and codemods.
+//!
+//! The shared [`walker::AnalysisWalker`] tracks namespaces, imports, the
+//! enclosing class, and executable units; [`names::NameContext`] resolves
+//! source names to fully-qualified names.
+//!
+//! # Example
+//!
+//! ```
+//! use php_analysis::exceptions::{ExceptionAnalysis, ExceptionFinding};
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! /** @throws InvalidArgumentException */
+//! function check(int $n) { if ($n < 0) throw new InvalidArgumentException(); }
+//! function run() { check(-1); }
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let mut analysis = ExceptionAnalysis::new();
+//! analysis.add_file(&result.program);
+//! let findings = analysis.finish();
+//! assert!(matches!(
+//!     &findings[..],
+//!     [ExceptionFinding::Undeclared { function, .. }] if function == "run"
+//! ));
+//! ```
+
+pub mod assignment_in_condition;
+pub mod attributes;
+pub mod callgraph;
+pub mod cfg;
+pub mod class_strings;
+pub mod clones;
+pub mod code_action;
+pub mod codemod;
+pub mod constants;
+pub mod coupling;
+pub mod dataflow;
+pub mod definite_assignment;
+pub mod directives;
+pub mod dominance;
+pub mod dynamic;
+pub mod enums;
+pub mod exceptions;
+pub mod hover;
+pub mod identifiers;
+pub mod inlay_hints;
+pub mod inline_var;
+pub mod labels;
+pub mod loose_comparison;
+pub mod members;
+pub mod min_version;
+pub mod names;
+pub mod normalize;
+pub mod nullability;
+pub mod properties;
+pub mod psr;
+pub mod purity;
+pub mod resolve;
+pub mod returns;
+pub mod scope_info;
+pub mod string_literals;
+pub mod suppressions;
+pub mod symbols;
+pub mod values;
+pub mod walker;
+
+pub use attributes::AttributeExt;
@@ -0,0 +1,448 @@
+//! Loose comparisons: `==`/`!=` against literals and non-strict array
+//! searches.
+//!
+//! Two rules, each switchable in [`LooseComparisonOptions`]:
+//!
+//! - [`LooseComparisonRule::Equality`] — `==`, `!=`, or `<>` with a literal
+//!   operand, as in `$status == 'active'`.
+//! - [`LooseComparisonRule::StrictSearch`] — `in_array()`, `array_search()`,
+//!   or two-argument `array_keys()` without the `$strict` argument. An
+//!   explicit `false` is taken as intended.
+//!
+//! A finding carries a fix-it when the strict form provably gives the same
+//! result: both operands (for a search, the needle and every element of a
+//! literal haystack) have the same inferred scalar type, and for strings at
+//! least one side is a literal that is not numeric, so PHP 8 compares them
+//! as strings either way. Types are inferred locally from literals, casts,
+//! operators, and a few built-in functions:
+//!
+//! ```php
+//! if ($status == 'active') {}         // fix: ===
+//! if (count($items) != 0) {}          // fix: !==
+//! if ($input == '10') {}              // reported, no fix: '10' is numeric
+//! in_array(strtolower($ext), ['jpg', 'png']);  // fix: , true
+//! ```
+//!
+//! [`crate::codemod::strict_comparison::StrictComparison`] applies every fix.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, Visitor};
+use php_ast::{
+    Arg, BinaryOp, CastKind, Expr, ExprKind, MagicConstKind, Program, Span, UnaryPrefixOp,
+};
+use php_lexer::{Lexer, TokenKind};
+
+use crate::codemod::TextEdit;
+use crate::values::is_numeric;
+
+/// Which rule a finding comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LooseComparisonRule {
+    /// `==` or `!=` with a literal operand.
+    Equality,
+    /// An array search without `$strict`.
+    StrictSearch,
+}
+
+impl std::fmt::Display for LooseComparisonRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LooseComparisonRule::Equality => "loose-equality",
+            LooseComparisonRule::StrictSearch => "strict-search",
+        })
+    }
+}
+
+/// Which rules to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LooseComparisonOptions {
+    /// Report `==` and `!=` against literals.
+    pub equality: bool,
+    /// Report `in_array()`, `array_search()`, and `array_keys()` without
+    /// `$strict`.
+    pub strict_search: bool,
+}
+
+impl Default for LooseComparisonOptions {
+    fn default() -> Self {
+        Self {
+            equality: true,
+            strict_search: true,
+        }
+    }
+}
+
+/// One reported comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LooseComparison {
+    pub rule: LooseComparisonRule,
+    /// The comparison or call expression.
+    pub span: Span,
+    /// The operator as written (`==`, `!=`, `<>`) or the function name as
+    /// written.
+    pub construct: String,
+    /// The edit to the strict form, when it cannot change the result.
+    pub fix: Option<TextEdit>,
+}
+
+impl std::fmt::Display for LooseComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.rule {
+            LooseComparisonRule::Equality => {
+                let strict = if self.construct == "==" { "===" } else { "!==" };
+                write!(
+                    f,
+                    "loose comparison `{}` against a literal; use `{strict}`",
+                    self.construct
+                )
+            }
+            LooseComparisonRule::StrictSearch => write!(
+                f,
+                "`{}()` compares loosely; pass `true` as the `$strict` argument",
+                self.construct
+            ),
+        }
+    }
+}
+
+/// Report the loose comparisons in `program`, in source order. `source` is
+/// the text `program` was parsed from; fixes are edits against it.
+///
+/// ```
+/// use php_analysis::codemod::apply_edits;
+/// use php_analysis::loose_comparison::loose_comparisons;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php if (trim($a) == 'yes' || $b != '1') {} in_array((int) $id, [1, 2]);";
+/// let result = php_rs_parser::parse(&arena, src);
+///
+/// let found = loose_comparisons(&result.program, src, &Default::default());
+/// let spans: Vec<_> = found.iter().map(|f| &src[f.span.to_range()]).collect();
+/// assert_eq!(spans, ["trim($a) == 'yes'", "$b != '1'", "in_array((int) $id, [1, 2])"]);
+///
+/// // The type of `$b` is unknown, so `$b != '1'` has no safe fix.
+/// let fixes: Vec<_> = found.into_iter().filter_map(|f| f.fix).collect();
+/// assert_eq!(
+///     apply_edits(src, &fixes),
+///     "<?php if (trim($a) === 'yes' || $b != '1') {} in_array((int) $id, [1, 2], true);",
+/// );
+/// ```
+pub fn loose_comparisons(
+    program: &Program<'_, '_>,
+    source: &str,
+    options: &LooseComparisonOptions,
+) -> Vec<LooseComparison> {
+    let mut lint = Lint {
+        source,
+        options,
+        found: Vec::new(),
+    };
+    let _ = lint.visit_program(program);
+    lint.found.sort_by_key(|f| f.span);
+    lint.found
+}
+
+struct Lint<'s, 'o> {
+    source: &'s str,
+    options: &'o LooseComparisonOptions,
+    found: Vec<LooseComparison>,
+}
+
+impl Lint<'_, '_> {
+    fn equality(&mut self, expr: &Expr<'_, '_>, left: &Expr<'_, '_>, right: &Expr<'_, '_>) {
+        if !is_literal(left) && !is_literal(right) {
+            return;
+        }
+        let Some(operator) = operator_span(self.source, left.span.end, right.span.start) else {
+            return;
+        };
+        let construct = &self.source[operator.to_range()];
+        let strict = if construct == "==" { "===" } else { "!==" };
+        let fix =
+            strict_is_safe(infer(left), infer(right)).then(|| TextEdit::replace(operator, strict));
+        self.found.push(LooseComparison {
+            rule: LooseComparisonRule::Equality,
+            span: expr.span,
+            construct: construct.to_string(),
+            fix,
+        });
+    }
+
+    fn search(&mut self, expr: &Expr<'_, '_>, function: &str, args: &[Arg<'_, '_>]) {
+        let bare = function.trim_start_matches('\\');
+        let Some(search) = SEARCHES
+            .iter()
+            .find(|search| search.function.eq_ignore_ascii_case(bare))
+        else {
+            return;
+        };
+        // `array_keys()` without a search value compares nothing.
+        if args.len() < 2 || args.iter().any(|arg| arg.unpack) {
+            return;
+        }
+        if argument(args, 2, "strict").is_some() {
+            return;
+        }
+        let needle = argument(args, search.needle.0, search.needle.1);
+        let haystack = argument(args, search.haystack.0, search.haystack.1);
+        let safe = match (needle, haystack.map(|arg| &arg.value.kind)) {
+            (Some(needle), Some(ExprKind::Array(elements))) => {
+                let needle = infer(&needle.value);
+                !elements.is_empty()
+                    && elements.iter().all(|element| {
+                        !element.unpack && strict_is_safe(needle, infer(&element.value))
+                    })
+            }
+            _ => false,
+        };
+        let fix = safe.then(|| {
+            let named = args.iter().any(|arg| arg.name.is_some());
+            let end = args.last().map_or(expr.span.end, |arg| arg.span.end);
+            TextEdit::insert(end, if named { ", strict: true" } else { ", true" })
+        });
+        self.found.push(LooseComparison {
+            rule: LooseComparisonRule::StrictSearch,
+            span: expr.span,
+            construct: function.to_string(),
+            fix,
+        });
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Lint<'_, '_> {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::Binary(binary) if self.options.equality && binary.op.is_loose_equality() => {
+                self.equality(expr, binary.left, binary.right)
+            }
+            ExprKind::FunctionCall(call) if self.options.strict_search => {
+                if let ExprKind::Name(name) = &call.name.kind {
+                    self.search(expr, &name.join_parts(), &call.args);
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+/// The array searches with a trailing `$strict` parameter, and the position
+/// and name of their needle and haystack parameters.
+struct Search {
+    function: &'static str,
+    needle: (usize, &'static str),
+    haystack: (usize, &'static str),
+}
+
+const SEARCHES: &[Search] = &[
+    Search {
+        function: "in_array",
+        needle: (0, "needle"),
+        haystack: (1, "haystack"),
+    },
+    Search {
+        function: "array_search",
+        needle: (0, "needle"),
+        haystack: (1, "haystack"),
+    },
+    Search {
+        function: "array_keys",
+        needle: (1, "filter_value"),
+        haystack: (0, "array"),
+    },
+];
+
+/// The argument for the parameter `name` at `position`, passed either by
+/// name or positionally.
+fn argument<'a, 'arena, 'src>(
+    args: &'a [Arg<'arena, 'src>],
+    position: usize,
+    name: &str,
+) -> Option<&'a Arg<'arena, 'src>> {
+    args.iter()
+        .find(|arg| {
+            arg.name
+                .as_ref()
+                .is_some_and(|n| n.join_parts().eq_ignore_ascii_case(name))
+        })
+        .or_else(|| args.get(position).filter(|arg| arg.name.is_none()))
+}
+
+/// The span of the `==`, `!=`, or `<>` token between the operands.
+fn operator_span(source: &str, from: u32, to: u32) -> Option<Span> {
+    let mut lexer = Lexer::new_at(source, from as usize);
+    loop {
+        let token = lexer.next_token();
+        if token.kind == TokenKind::Eof || token.span.start >= to {
+            return None;
+        }
+        match token.kind {
+            TokenKind::EqualsEquals | TokenKind::BangEquals => return Some(token.span),
+            kind if kind.is_comment() => {}
+            // Closing parentheses of a parenthesized left operand.
+            TokenKind::RightParen => {}
+            _ => return None,
+        }
+    }
+}
+
+/// The scalar type of an expression, where it is known without any context.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Inferred<'a> {
+    Int,
+    Float,
+    Bool,
+    Null,
+    /// A string, with its value when it is a literal.
+    String(Option<&'a str>),
+}
+
+fn is_literal(expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::Int(_)
+        | ExprKind::Float(_)
+        | ExprKind::String { .. }
+        | ExprKind::Nowdoc { .. }
+        | ExprKind::Bool(_)
+        | ExprKind::Null => true,
+        ExprKind::UnaryPrefix(unary) => {
+            matches!(unary.op, UnaryPrefixOp::Negate | UnaryPrefixOp::Plus)
+                && matches!(unary.operand.kind, ExprKind::Int(_) | ExprKind::Float(_))
+        }
+        ExprKind::Parenthesized(inner) => is_literal(inner),
+        _ => false,
+    }
+}
+
+fn infer<'a>(expr: &'a Expr<'_, '_>) -> Option<Inferred<'a>> {
+    Some(match &expr.kind {
+        ExprKind::Int(_) => Inferred::Int,
+        ExprKind::Float(_) => Inferred::Float,
+        ExprKind::String { value, .. } | ExprKind::Nowdoc { value, .. } => {
+            Inferred::String(Some(value))
+        }
+        ExprKind::InterpolatedString(_) | ExprKind::Heredoc { .. } => Inferred::String(None),
+        ExprKind::Bool(_) => Inferred::Bool,
+        ExprKind::Null => Inferred::Null,
+        ExprKind::Parenthesized(inner) => return infer(inner),
+        ExprKind::Cast(kind, _) => match kind {
+            CastKind::Int => Inferred::Int,
+            CastKind::Float => Inferred::Float,
+            CastKind::String => Inferred::String(None),
+            CastKind::Bool => Inferred::Bool,
+            CastKind::Unset => Inferred::Null,
+            _ => return None,
+        },
+        ExprKind::Binary(binary) => match binary.op {
+            BinaryOp::Concat => Inferred::String(None),
+            BinaryOp::Mod | BinaryOp::Spaceship => Inferred::Int,
+            op if op.is_boolean() => Inferred::Bool,
+            _ => return None,
+        },
+        ExprKind::UnaryPrefix(unary) => match unary.op {
+            UnaryPrefixOp::BooleanNot => Inferred::Bool,
+            UnaryPrefixOp::Negate | UnaryPrefixOp::Plus => match infer(unary.operand)? {
+                Inferred::Int => Inferred::Int,
+                Inferred::Float => Inferred::Float,
+                _ => return None,
+            },
+            _ => return None,
+        },
+        ExprKind::Instanceof(_) | ExprKind::Isset(_) | ExprKind::Empty(_) => Inferred::Bool,
+        ExprKind::ClassConstAccess(access) => match &access.member.kind {
+            ExprKind::Identifier(name) if name.eq_ignore_ascii_case("class") => {
+                Inferred::String(None)
+            }
+            _ => return None,
+        },
+        ExprKind::MagicConst(MagicConstKind::Line) => Inferred::Int,
+        ExprKind::MagicConst(_) => Inferred::String(None),
+        ExprKind::FunctionCall(call) => match &call.name.kind {
+            ExprKind::Name(name) => return builtin_return(&name.join_parts()),
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
+
+/// Return types of built-in functions that never fail with `false` in PHP 8.
+fn builtin_return(name: &str) -> Option<Inferred<'static>> {
+    const INT: &[&str] = &[
+        "count",
+        "strlen",
+        "mb_strlen",
+        "intdiv",
+        "ord",
+        "intval",
+        "crc32",
+    ];
+    const FLOAT: &[&str] = &["floatval", "fdiv", "sqrt", "pi"];
+    const STRING: &[&str] = &[
+        "trim",
+        "ltrim",
+        "rtrim",
+        "strtolower",
+        "strtoupper",
+        "mb_strtolower",
+        "mb_strtoupper",
+        "ucfirst",
+        "lcfirst",
+        "ucwords",
+        "strval",
+        "substr",
+        "str_repeat",
+        "str_pad",
+        "sprintf",
+        "implode",
+        "gettype",
+        "get_debug_type",
+        "chr",
+        "strrev",
+        "nl2br",
+        "htmlspecialchars",
+    ];
+    const BOOL: &[&str] = &[
+        "boolval",
+        "is_int",
+        "is_string",
+        "is_array",
+        "is_bool",
+        "is_float",
+        "is_null",
+        "is_numeric",
+        "is_object",
+        "is_callable",
+        "in_array",
+        "array_key_exists",
+        "str_contains",
+        "str_starts_with",
+        "str_ends_with",
+    ];
+    let name = name.trim_start_matches('\\');
+    let is = |table: &[&str]| table.iter().any(|f| f.eq_ignore_ascii_case(name));
+    if is(INT) {
+        Some(Inferred::Int)
+    } else if is(FLOAT) {
+        Some(Inferred::Float)
+    } else if is(STRING) {
+        Some(Inferred::String(None))
+    } else if is(BOOL) {
+        Some(Inferred::Bool)
+    } else {
+        None
+    }
+}
+
+/// Whether `===` gives the same result as `==` for operands of these types.
+fn strict_is_safe(left: Option<Inferred<'_>>, right: Option<Inferred<'_>>) -> bool {
+    match (left, right) {
+        (Some(Inferred::String(left)), Some(Inferred::String(right))) => {
+            // Two numeric strings compare as numbers: `'1e1' == '10'`.
+            left.is_some_and(|s| !is_numeric(s.as_bytes()))
+                || right.is_some_and(|s| !is_numeric(s.as_bytes()))
+        }
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
@@ -0,0 +1,703 @@
+//! Effective methods of classes, with traits flattened.
+//!
+//! [`MemberTable`] records the classes, traits, and enums declared in a
+//! project and resolves which declaration each method name of a class runs,
+//! following PHP's precedence:
+//!
+//! 1. methods declared in the class itself;
+//! 2. methods of the traits it uses, after `insteadof` exclusions and `as`
+//!    aliases and visibility changes; traits using traits are flattened
+//!    first;
+//! 3. methods inherited from the parent class, flattened the same way.
+//!
+//! An abstract trait method does not replace an inherited concrete one.
+//! Interface methods and classes outside the project are not modelled; a
+//! class with an unknown parent or trait simply lacks those methods.
+//!
+//! [`MemberTable::diagnostics`] reports the trait compositions PHP rejects:
+//! two traits providing the same concrete method without an `insteadof`,
+//! and adaptations naming a trait the `use` does not list or a method the
+//! trait does not have.
+
+use std::collections::HashMap;
+
+use php_ast::{
+    ClassMember, ClassMemberKind, EnumMemberKind, MethodDecl, Program, Span, Stmt, StmtKind,
+    TraitAdaptationKind, TraitUseDecl, Visibility,
+};
+
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker};
+
+/// How a class gets an [`EffectiveMethod`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MethodOrigin {
+    /// Declared in the class.
+    Own,
+    /// Imported from a trait the class uses, possibly under an alias.
+    Trait(String),
+    /// Inherited from the parent class, which declared it, imported it from
+    /// a trait, or inherited it in turn.
+    Inherited(String),
+}
+
+/// The method a name resolves to in a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveMethod {
+    /// The name as callable on the class; differs from `declared_name` for
+    /// an `as` alias.
+    pub name: String,
+    pub origin: MethodOrigin,
+    /// Fully-qualified name of the class or trait declaring the method.
+    pub declared_in: String,
+    pub declared_name: String,
+    /// The visibility in this class, after any `as` change.
+    pub visibility: Visibility,
+    pub is_static: bool,
+    pub is_abstract: bool,
+    /// The file and span of the method declaration.
+    pub file: usize,
+    pub span: Span,
+}
+
+/// A trait composition PHP rejects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberDiagnostic {
+    /// Several used traits provide `method`, the class does not declare it,
+    /// and no `insteadof` picks one. `span` is the class declaration.
+    TraitCollision {
+        file: usize,
+        span: Span,
+        class: String,
+        method: String,
+        traits: Vec<String>,
+    },
+    /// An `insteadof` or `as` adaptation names a method that `trait_name`
+    /// (or, without a trait name, every used trait) lacks.
+    UndefinedTraitMethod {
+        file: usize,
+        span: Span,
+        trait_name: Option<String>,
+        method: String,
+    },
+    /// An adaptation names a trait that its `use` statement does not list.
+    TraitNotUsed {
+        file: usize,
+        span: Span,
+        class: String,
+        trait_name: String,
+    },
+}
+
+impl MemberDiagnostic {
+    pub fn file(&self) -> usize {
+        match self {
+            Self::TraitCollision { file, .. }
+            | Self::UndefinedTraitMethod { file, .. }
+            | Self::TraitNotUsed { file, .. } => *file,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::TraitCollision { span, .. }
+            | Self::UndefinedTraitMethod { span, .. }
+            | Self::TraitNotUsed { span, .. } => *span,
+        }
+    }
+}
+
+impl std::fmt::Display for MemberDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TraitCollision {
+                class,
+                method,
+                traits,
+                ..
+            } => write!(
+                f,
+                "{class}::{method} is provided by traits {}; resolve it with `insteadof`",
+                traits.join(", ")
+            ),
+            Self::UndefinedTraitMethod {
+                trait_name: Some(trait_name),
+                method,
+                ..
+            } => write!(f, "trait {trait_name} has no method {method}"),
+            Self::UndefinedTraitMethod { method, .. } => {
+                write!(f, "no used trait has a method {method}")
+            }
+            Self::TraitNotUsed {
+                class, trait_name, ..
+            } => write!(f, "trait {trait_name} is not used by {class}"),
+        }
+    }
+}
+
+/// Class-like declarations across the files of a project.
+///
+/// ```
+/// use php_analysis::members::{MemberTable, MethodOrigin};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// trait Hello { public function say() {} }
+/// trait World { public function say() {} }
+/// class Base { public function greet() {} }
+/// class Greeter extends Base {
+///     use Hello, World {
+///         Hello::say insteadof World;
+///         World::say as protected sayWorld;
+///     }
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut table = MemberTable::new();
+/// table.add_file(&result.program);
+///
+/// let say = table.resolve_method("Greeter", "say").unwrap();
+/// assert_eq!(say.declared_in, "Hello");
+/// let alias = table.resolve_method("greeter", "SAYWORLD").unwrap();
+/// assert_eq!((alias.declared_in.as_str(), alias.declared_name.as_str()), ("World", "say"));
+/// let greet = table.resolve_method("Greeter", "greet").unwrap();
+/// assert_eq!(greet.origin, MethodOrigin::Inherited("Base".into()));
+/// assert!(table.diagnostics().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct MemberTable {
+    classes: Vec<ClassInfo>,
+    /// Lower-cased name → index into `classes`; the first declaration wins.
+    by_name: HashMap<String, usize>,
+    files: usize,
+}
+
+impl MemberTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let mut collector = Collector { table: self, file };
+        AnalysisWalker::new(&mut collector).walk(program);
+        file
+    }
+
+    /// The effective methods of `class`: its own in declaration order, then
+    /// those from traits, then inherited ones. Empty for an unknown class.
+    pub fn methods(&self, class: &str) -> Vec<EffectiveMethod> {
+        self.flatten(class, &mut Vec::new()).methods
+    }
+
+    /// The method `method` resolves to on `class`, case-insensitively.
+    pub fn resolve_method(&self, class: &str, method: &str) -> Option<EffectiveMethod> {
+        self.methods(class)
+            .into_iter()
+            .find(|m| m.name.eq_ignore_ascii_case(method))
+    }
+
+    /// Trait collisions and undefined adaptation targets in every class,
+    /// trait, and enum, by file and position.
+    pub fn diagnostics(&self) -> Vec<MemberDiagnostic> {
+        let mut diagnostics: Vec<MemberDiagnostic> = self
+            .classes
+            .iter()
+            .flat_map(|class| self.flatten(&class.name, &mut Vec::new()).diagnostics)
+            .collect();
+        diagnostics.sort_by_key(|d| (d.file(), d.span()));
+        diagnostics
+    }
+
+    fn find(&self, name: &str) -> Option<&ClassInfo> {
+        let name = name.trim_start_matches('\\').to_ascii_lowercase();
+        self.by_name.get(&name).map(|&i| &self.classes[i])
+    }
+
+    /// Resolve the methods of `class`. `stack` holds the lower-cased classes
+    /// being resolved, so cyclic `extends` or `use` ends the recursion.
+    fn flatten(&self, class: &str, stack: &mut Vec<String>) -> Flattened {
+        let key = class.to_ascii_lowercase();
+        let Some(info) = self.find(class) else {
+            return Flattened::default();
+        };
+        if stack.contains(&key) {
+            return Flattened::default();
+        }
+        stack.push(key);
+
+        let mut result = Flattened::default();
+        for method in &info.methods {
+            result.add(EffectiveMethod {
+                name: method.name.clone(),
+                origin: MethodOrigin::Own,
+                declared_in: info.name.clone(),
+                declared_name: method.name.clone(),
+                visibility: method.visibility,
+                is_static: method.is_static,
+                is_abstract: method.is_abstract,
+                file: info.file,
+                span: method.span,
+            });
+        }
+
+        let inherited = match &info.parent {
+            Some(parent) => self.flatten(parent, stack).methods,
+            None => Vec::new(),
+        };
+        for trait_use in &info.traits {
+            let from_traits = self.compose(info, trait_use, stack, &mut result.diagnostics);
+            for method in from_traits {
+                let overrides_inherited = !method.is_abstract
+                    || !inherited
+                        .iter()
+                        .any(|i| !i.is_abstract && i.name.eq_ignore_ascii_case(&method.name));
+                if overrides_inherited {
+                    result.add(method);
+                }
+            }
+        }
+        for method in inherited {
+            result.add(EffectiveMethod {
+                origin: MethodOrigin::Inherited(info.parent.clone().unwrap_or_default()),
+                ..method
+            });
+        }
+
+        stack.pop();
+        result
+    }
+
+    /// The methods one `use` statement of `class` imports, after its
+    /// adaptations.
+    fn compose(
+        &self,
+        class: &ClassInfo,
+        trait_use: &TraitUse,
+        stack: &mut Vec<String>,
+        diagnostics: &mut Vec<MemberDiagnostic>,
+    ) -> Vec<EffectiveMethod> {
+        let traits: Vec<(String, Vec<EffectiveMethod>)> = trait_use
+            .traits
+            .iter()
+            .map(|name| {
+                // A trait's own diagnostics are reported for the trait.
+                let methods = self.flatten(name, stack).methods;
+                let name = self
+                    .find(name)
+                    .map_or(name.clone(), |info| info.name.clone());
+                (name, methods)
+            })
+            .collect();
+        let known = |name: &str| self.find(name).is_some();
+        let lookup = |trait_name: &str, method: &str| {
+            traits
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(trait_name))
+                .and_then(|(_, methods)| {
+                    methods.iter().find(|m| m.name.eq_ignore_ascii_case(method))
+                })
+        };
+
+        let used = |name: &str| {
+            trait_use
+                .traits
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(name))
+        };
+        let mut excluded: Vec<(String, String)> = Vec::new();
+        let mut imported = Flattened::default();
+        for adaptation in &trait_use.adaptations {
+            let (named, span) = match adaptation {
+                Adaptation::Precedence {
+                    trait_name,
+                    insteadof,
+                    span,
+                    ..
+                } => (std::iter::once(trait_name).chain(insteadof).collect(), span),
+                Adaptation::Alias {
+                    trait_name, span, ..
+                } => (trait_name.iter().collect::<Vec<_>>(), span),
+            };
+            let unused: Vec<&String> = named.into_iter().filter(|name| !used(name)).collect();
+            if !unused.is_empty() {
+                diagnostics.extend(unused.into_iter().map(|trait_name| {
+                    MemberDiagnostic::TraitNotUsed {
+                        file: class.file,
+                        span: *span,
+                        class: class.name.clone(),
+                        trait_name: trait_name.clone(),
+                    }
+                }));
+                continue;
+            }
+            match adaptation {
+                Adaptation::Precedence {
+                    trait_name,
+                    method,
+                    insteadof,
+                    span,
+                } => {
+                    if known(trait_name) && lookup(trait_name, method).is_none() {
+                        diagnostics.push(MemberDiagnostic::UndefinedTraitMethod {
+                            file: class.file,
+                            span: *span,
+                            trait_name: Some(trait_name.clone()),
+                            method: method.clone(),
+                        });
+                    }
+                    excluded.extend(
+                        insteadof
+                            .iter()
+                            .map(|other| (other.to_ascii_lowercase(), method.to_ascii_lowercase())),
+                    );
+                }
+                Adaptation::Alias {
+                    trait_name,
+                    method,
+                    visibility,
+                    alias,
+                    span,
+                } => {
+                    let source = match trait_name {
+                        Some(trait_name) => lookup(trait_name, method),
+                        None => traits.iter().find_map(|(name, _)| lookup(name, method)),
+                    };
+                    let Some(source) = source else {
+                        let checkable = match trait_name {
+                            Some(trait_name) => known(trait_name),
+                            None => trait_use.traits.iter().all(|name| known(name)),
+                        };
+                        if checkable {
+                            diagnostics.push(MemberDiagnostic::UndefinedTraitMethod {
+                                file: class.file,
+                                span: *span,
+                                trait_name: trait_name.clone(),
+                                method: method.clone(),
+                            });
+                        }
+                        continue;
+                    };
+                    if let Some(alias) = alias {
+                        imported.add(EffectiveMethod {
+                            name: alias.clone(),
+                            origin: MethodOrigin::Trait(source_trait(&traits, source)),
+                            visibility: visibility.unwrap_or(source.visibility),
+                            ..source.clone()
+                        });
+                    }
+                }
+            }
+        }
+
+        for (trait_name, methods) in &traits {
+            for method in methods {
+                let key = (
+                    trait_name.to_ascii_lowercase(),
+                    method.name.to_ascii_lowercase(),
+                );
+                if excluded.contains(&key) {
+                    continue;
+                }
+                let visibility = trait_use
+                    .adaptations
+                    .iter()
+                    .filter_map(|adaptation| match adaptation {
+                        Adaptation::Alias {
+                            trait_name: alias_trait,
+                            method: alias_method,
+                            visibility: Some(visibility),
+                            alias: None,
+                            ..
+                        } if alias_method.eq_ignore_ascii_case(&method.name)
+                            && alias_trait
+                                .as_ref()
+                                .is_none_or(|t| t.eq_ignore_ascii_case(trait_name)) =>
+                        {
+                            Some(*visibility)
+                        }
+                        _ => None,
+                    })
+                    .next_back()
+                    .unwrap_or(method.visibility);
+                let method = EffectiveMethod {
+                    origin: MethodOrigin::Trait(trait_name.clone()),
+                    visibility,
+                    ..method.clone()
+                };
+                match imported.position(&method.name) {
+                    None => imported.add(method),
+                    Some(i) => {
+                        let existing = &mut imported.methods[i];
+                        if existing.is_abstract && !method.is_abstract {
+                            *existing = method;
+                        } else if !existing.is_abstract
+                            && !method.is_abstract
+                            && existing.origin != method.origin
+                            && !class.declares(&method.name)
+                        {
+                            let MethodOrigin::Trait(first) = &existing.origin else {
+                                continue;
+                            };
+                            let first = first.clone();
+                            record_collision(diagnostics, class, &method.name, first, trait_name);
+                        }
+                    }
+                }
+            }
+        }
+        imported.methods
+    }
+}
+
+/// The trait, among those used, that provides `method` (itself possibly
+/// imported from a nested trait).
+fn source_trait(traits: &[(String, Vec<EffectiveMethod>)], method: &EffectiveMethod) -> String {
+    traits
+        .iter()
+        .find(|(_, methods)| methods.iter().any(|m| std::ptr::eq(m, method)))
+        .map_or_else(|| method.declared_in.clone(), |(name, _)| name.clone())
+}
+
+fn record_collision(
+    diagnostics: &mut Vec<MemberDiagnostic>,
+    class: &ClassInfo,
+    method: &str,
+    first: String,
+    other: &str,
+) {
+    let existing = diagnostics.iter_mut().find_map(|d| match d {
+        MemberDiagnostic::TraitCollision {
+            class: c,
+            method: m,
+            traits,
+            ..
+        } if *c == class.name && m.eq_ignore_ascii_case(method) => Some(traits),
+        _ => None,
+    });
+    match existing {
+        Some(traits) => traits.push(other.to_string()),
+        None => diagnostics.push(MemberDiagnostic::TraitCollision {
+            file: class.file,
+            span: class.span,
+            class: class.name.clone(),
+            method: method.to_string(),
+            traits: vec![first, other.to_string()],
+        }),
+    }
+}
+
+/// Methods keyed case-insensitively by name, first added wins.
+#[derive(Debug, Default)]
+struct Flattened {
+    methods: Vec<EffectiveMethod>,
+    diagnostics: Vec<MemberDiagnostic>,
+}
+
+impl Flattened {
+    fn position(&self, name: &str) -> Option<usize> {
+        self.methods
+            .iter()
+            .position(|m| m.name.eq_ignore_ascii_case(name))
+    }
+
+    fn add(&mut self, method: EffectiveMethod) {
+        if self.position(&method.name).is_none() {
+            self.methods.push(method);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ClassInfo {
+    name: String,
+    file: usize,
+    span: Span,
+    parent: Option<String>,
+    traits: Vec<TraitUse>,
+    methods: Vec<MethodInfo>,
+}
+
+impl ClassInfo {
+    fn declares(&self, method: &str) -> bool {
+        self.methods
+            .iter()
+            .any(|m| m.name.eq_ignore_ascii_case(method))
+    }
+}
+
+#[derive(Debug)]
+struct MethodInfo {
+    name: String,
+    visibility: Visibility,
+    is_static: bool,
+    is_abstract: bool,
+    span: Span,
+}
+
+/// A `use A, B { … }` statement with names resolved.
+#[derive(Debug)]
+struct TraitUse {
+    traits: Vec<String>,
+    adaptations: Vec<Adaptation>,
+}
+
+#[derive(Debug)]
+enum Adaptation {
+    Precedence {
+        trait_name: String,
+        method: String,
+        insteadof: Vec<String>,
+        span: Span,
+    },
+    Alias {
+        trait_name: Option<String>,
+        method: String,
+        visibility: Option<Visibility>,
+        alias: Option<String>,
+        span: Span,
+    },
+}
+
+struct Collector<'t> {
+    table: &'t mut MemberTable,
+    file: usize,
+}
+
+impl Collector<'_> {
+    fn add(&mut self, info: ClassInfo) {
+        let key = info.name.to_ascii_lowercase();
+        if !self.table.by_name.contains_key(&key) {
+            self.table.by_name.insert(key, self.table.classes.len());
+            self.table.classes.push(info);
+        }
+    }
+
+    fn class(
+        &self,
+        name: String,
+        span: Span,
+        parent: Option<String>,
+        members: &[ClassMember<'_, '_>],
+        scope: &AnalysisScope,
+    ) -> ClassInfo {
+        let mut info = ClassInfo {
+            name,
+            file: self.file,
+            span,
+            parent,
+            traits: Vec::new(),
+            methods: Vec::new(),
+        };
+        for member in members {
+            match &member.kind {
+                ClassMemberKind::Method(method) => {
+                    info.methods.extend(method_info(method, member.span))
+                }
+                ClassMemberKind::TraitUse(trait_use) => {
+                    info.traits.push(resolve_use(trait_use, scope))
+                }
+                _ => {}
+            }
+        }
+        info
+    }
+}
+
+fn method_info(method: &MethodDecl<'_, '_>, span: Span) -> Option<MethodInfo> {
+    Some(MethodInfo {
+        name: method.name.as_str()?.to_string(),
+        visibility: method.visibility.unwrap_or(Visibility::Public),
+        is_static: method.is_static,
+        is_abstract: method.is_abstract,
+        span,
+    })
+}
+
+fn resolve_use(trait_use: &TraitUseDecl<'_, '_>, scope: &AnalysisScope) -> TraitUse {
+    let resolve = |name: &php_ast::Name<'_, '_>| scope.names.resolve_class(&name.to_string_repr());
+    let adaptations = trait_use
+        .adaptations
+        .iter()
+        .map(|adaptation| match &adaptation.kind {
+            TraitAdaptationKind::Precedence {
+                trait_name,
+                method,
+                insteadof,
+            } => Adaptation::Precedence {
+                trait_name: resolve(trait_name),
+                method: method.to_string_repr().to_string(),
+                insteadof: insteadof.iter().map(resolve).collect(),
+                span: adaptation.span,
+            },
+            TraitAdaptationKind::Alias {
+                trait_name,
+                method,
+                new_modifier,
+                new_name,
+            } => Adaptation::Alias {
+                trait_name: trait_name.as_ref().map(resolve),
+                method: method.to_string_repr().to_string(),
+                visibility: *new_modifier,
+                alias: new_name
+                    .as_ref()
+                    .map(|name| name.to_string_repr().to_string()),
+                span: adaptation.span,
+            },
+        })
+        .collect();
+    TraitUse {
+        traits: trait_use.traits.iter().map(resolve).collect(),
+        adaptations,
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        let info = match &stmt.kind {
+            StmtKind::Class(class) => {
+                let Some(name) = class.name.and_then(|n| n.as_str()) else {
+                    return;
+                };
+                let parent = class
+                    .extends
+                    .as_ref()
+                    .map(|n| scope.names.resolve_class(&n.to_string_repr()));
+                self.class(
+                    scope.names.qualify(name),
+                    stmt.span,
+                    parent,
+                    &class.members,
+                    scope,
+                )
+            }
+            StmtKind::Trait(trait_decl) => {
+                let name = scope.names.qualify(trait_decl.name.or_error());
+                self.class(name, stmt.span, None, &trait_decl.members, scope)
+            }
+            StmtKind::Enum(enum_decl) => {
+                let mut info = self.class(
+                    scope.names.qualify(enum_decl.name.or_error()),
+                    stmt.span,
+                    None,
+                    &[],
+                    scope,
+                );
+                for member in enum_decl.members.iter() {
+                    match &member.kind {
+                        EnumMemberKind::Method(method) => {
+                            info.methods.extend(method_info(method, member.span))
+                        }
+                        EnumMemberKind::TraitUse(trait_use) => {
+                            info.traits.push(resolve_use(trait_use, scope))
+                        }
+                        _ => {}
+                    }
+                }
+                info
+            }
+            _ => return,
+        };
+        self.add(info);
+    }
+}
@@ -0,0 +1,280 @@
+//! The lowest PHP version a codebase can run on.
+//!
+//! [`VersionInference`] finds every use of syntax newer than PHP 7.4 across
+//! the files it is given: the features the parser gates by version, found
+//! by parsing for PHP 7.4, and those it accepts at any version, such as
+//! attributes, `static` return types, and `new` in initializers, found in
+//! the AST. The newest feature used sets the version the code requires.
+//! Library functions and classes are not considered, only syntax.
+//!
+//! ```
+//! use php_analysis::min_version::VersionInference;
+//! use php_rs_parser::{Feature, PhpVersion};
+//!
+//! let mut inference = VersionInference::new();
+//! inference.add_file("<?php function f(int|string $id): static { return $this; }");
+//! inference.add_file("<?php enum Suit { case Hearts; }");
+//! let report = inference.finish();
+//!
+//! assert_eq!(report.required, PhpVersion::Php81);
+//! let features: Vec<_> = report.requirements.iter().map(|r| r.feature).collect();
+//! assert_eq!(features, [Feature::UnionTypes, Feature::StaticReturnType, Feature::Enums]);
+//! assert_eq!(report.file_required(0), PhpVersion::Php80);
+//! ```
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{
+    walk_attribute, walk_catch_clause, walk_class_member, walk_expr, walk_param, walk_stmt,
+    walk_type_hint, Visitor,
+};
+use php_ast::*;
+use php_rs_parser::diagnostics::ParseError;
+use php_rs_parser::{Feature, PhpVersion};
+
+/// A use of `feature` in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Requirement {
+    /// The index [`VersionInference::add_file`] returned for the file.
+    pub file: usize,
+    pub span: Span,
+    pub feature: Feature,
+}
+
+impl Requirement {
+    /// The version the use needs.
+    pub fn version(&self) -> PhpVersion {
+        self.feature.required()
+    }
+}
+
+/// Collects feature uses across files. See the [module documentation](self).
+#[derive(Debug, Default)]
+pub struct VersionInference {
+    files: usize,
+    requirements: Vec<Requirement>,
+}
+
+impl VersionInference {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the features `source` uses. Returns the file index its
+    /// requirements are located by.
+    pub fn add_file(&mut self, source: &str) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let arena = bumpalo::Bump::new();
+        let result = php_rs_parser::parse_versioned(&arena, source, PhpVersion::OLDEST);
+        let mut found: Vec<Requirement> = result
+            .errors
+            .iter()
+            .filter_map(|error| match error {
+                ParseError::VersionTooLow { feature, span, .. } => Some(Requirement {
+                    file,
+                    span: *span,
+                    feature: *feature,
+                }),
+                _ => None,
+            })
+            .collect();
+        let mut collector = Collector {
+            file,
+            source,
+            trait_depth: 0,
+            found: Vec::new(),
+        };
+        let _ = collector.visit_program(&result.program);
+        found.extend(collector.found);
+        found.sort_by_key(|r| (r.span, r.feature));
+        found.dedup();
+        self.requirements.extend(found);
+        file
+    }
+
+    pub fn finish(self) -> VersionReport {
+        let required = self
+            .requirements
+            .iter()
+            .map(Requirement::version)
+            .max()
+            .unwrap_or(PhpVersion::OLDEST);
+        VersionReport {
+            required,
+            requirements: self.requirements,
+        }
+    }
+}
+
+/// The result of [`VersionInference::finish`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReport {
+    /// The oldest version with every feature used; PHP 7.4 when none is.
+    pub required: PhpVersion,
+    /// Every use of a feature newer than PHP 7.4, by file, then in source
+    /// order.
+    pub requirements: Vec<Requirement>,
+}
+
+impl VersionReport {
+    /// The uses that need [`required`](Self::required) itself: the ones to
+    /// remove to support an older version.
+    pub fn deciding(&self) -> impl Iterator<Item = &Requirement> {
+        self.requirements
+            .iter()
+            .filter(|r| r.version() == self.required)
+    }
+
+    /// The oldest version `file` runs on by itself.
+    pub fn file_required(&self, file: usize) -> PhpVersion {
+        self.requirements
+            .iter()
+            .filter(|r| r.file == file)
+            .map(Requirement::version)
+            .max()
+            .unwrap_or(PhpVersion::OLDEST)
+    }
+}
+
+/// Finds the features the parser does not gate.
+struct Collector<'s> {
+    file: usize,
+    source: &'s str,
+    /// Traits being visited; a constant directly in one is counted.
+    trait_depth: usize,
+    found: Vec<Requirement>,
+}
+
+impl Collector<'_> {
+    fn record(&mut self, feature: Feature, span: Span) {
+        self.found.push(Requirement {
+            file: self.file,
+            span,
+            feature,
+        });
+    }
+
+    /// Record each `new` in a constant expression.
+    fn initializer(&mut self, expr: &Expr<'_, '_>) {
+        let mut finder = NewFinder(Vec::new());
+        let _ = finder.visit_expr(expr);
+        for span in finder.0 {
+            self.record(Feature::NewInInitializers, span);
+        }
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    self.initializer(&item.value);
+                }
+            }
+            StmtKind::StaticVar(vars) => {
+                for default in vars.iter().filter_map(|var| var.default.as_ref()) {
+                    self.initializer(default);
+                }
+            }
+            StmtKind::Trait(_) => {
+                self.trait_depth += 1;
+                let result = walk_stmt(self, stmt);
+                self.trait_depth -= 1;
+                return result;
+            }
+            StmtKind::Class(_) | StmtKind::Interface(_) | StmtKind::Enum(_) => {
+                // Members of a class declared inside a trait method belong
+                // to the class.
+                let depth = std::mem::take(&mut self.trait_depth);
+                let result = walk_stmt(self, stmt);
+                self.trait_depth = depth;
+                return result;
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::ClassConstAccess(access) => {
+                let on_object = matches!(access.class, ClassRef::Expr(_))
+                    && matches!(
+                        &access.member.kind,
+                        ExprKind::Identifier(name) if name.eq_ignore_ascii_case("class")
+                    );
+                if on_object {
+                    self.record(Feature::ClassOnObject, expr.span);
+                }
+            }
+            ExprKind::Int(_) => {
+                let text = self
+                    .source
+                    .get(expr.span.start as usize..expr.span.end as usize)
+                    .unwrap_or_default();
+                if text.starts_with("0o") || text.starts_with("0O") {
+                    self.record(Feature::ExplicitOctal, expr.span);
+                }
+            }
+            ExprKind::AnonymousClass(_) => {
+                let depth = std::mem::take(&mut self.trait_depth);
+                let result = walk_expr(self, expr);
+                self.trait_depth = depth;
+                return result;
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_param(&mut self, param: &Param<'arena, 'src>) -> ControlFlow<()> {
+        if let Some(default) = &param.default {
+            self.initializer(default);
+        }
+        walk_param(self, param)
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        if self.trait_depth > 0 && matches!(member.kind, ClassMemberKind::ClassConst(_)) {
+            self.record(Feature::ConstantsInTraits, member.span);
+        }
+        walk_class_member(self, member)
+    }
+
+    fn visit_type_hint(&mut self, type_hint: &TypeHint<'arena, 'src>) -> ControlFlow<()> {
+        // `static` is only accepted as a return type.
+        if let TypeHintKind::Keyword(BuiltinType::Static, span) = &type_hint.kind {
+            self.record(Feature::StaticReturnType, *span);
+        }
+        walk_type_hint(self, type_hint)
+    }
+
+    fn visit_attribute(&mut self, attribute: &Attribute<'arena, 'src>) -> ControlFlow<()> {
+        self.record(Feature::Attributes, attribute.span);
+        for arg in attribute.args.iter() {
+            self.initializer(&arg.value);
+        }
+        walk_attribute(self, attribute)
+    }
+
+    fn visit_catch_clause(&mut self, catch: &CatchClause<'arena, 'src>) -> ControlFlow<()> {
+        if catch.var.is_none() {
+            self.record(Feature::NonCapturingCatch, catch.span);
+        }
+        walk_catch_clause(self, catch)
+    }
+}
+
+/// Spans of the `new` expressions in a constant expression.
+struct NewFinder(Vec<Span>);
+
+impl<'arena, 'src> Visitor<'arena, 'src> for NewFinder {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        if let ExprKind::New(_) = &expr.kind {
+            self.0.push(expr.span);
+        }
+        walk_expr(self, expr)
+    }
+}
@@ -0,0 +1,129 @@
+//! Namespace and import tracking used to turn source names into fully-qualified names.
+//!
+//! Resolved names are returned without a leading backslash (`App\Model\User`).
+//! Lookups are case-insensitive, matching PHP's rules for class and function
+//! names; the spelling of the declaration or import is preserved in the output.
+
+use std::collections::HashMap;
+
+use php_ast::{UseDecl, UseKind};
+
+/// The active namespace plus the `use` imports in effect at a point in a file.
+#[derive(Debug, Clone, Default)]
+pub struct NameContext {
+    namespace: String,
+    classes: HashMap<String, String>,
+    functions: HashMap<String, String>,
+    constants: HashMap<String, String>,
+}
+
+impl NameContext {
+    /// The current namespace, or `""` for the global namespace.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Enter a namespace. Imports do not carry over between namespaces.
+    pub fn set_namespace(&mut self, namespace: &str) {
+        self.namespace = namespace.trim_start_matches('\\').to_string();
+        self.classes.clear();
+        self.functions.clear();
+        self.constants.clear();
+    }
+
+    /// Record every item of a `use` declaration.
+    pub fn add_use(&mut self, decl: &UseDecl<'_, '_>) {
+        for item in decl.uses.iter() {
+            let fqn = item.name.join_parts().into_owned();
+            let alias = match item.alias {
+                Some(alias) => alias.to_string(),
+                None => fqn.rsplit('\\').next().unwrap_or(&fqn).to_string(),
+            };
+            let table = match item.kind.unwrap_or(decl.kind) {
+                UseKind::Normal => &mut self.classes,
+                UseKind::Function => &mut self.functions,
+                UseKind::Const => &mut self.constants,
+            };
+            table.insert(alias.to_ascii_lowercase(), fqn);
+        }
+    }
+
+    /// Prefix `name` with the current namespace.
+    pub fn qualify(&self, name: &str) -> String {
+        if self.namespace.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}\\{}", self.namespace, name)
+        }
+    }
+
+    /// Resolve a class-like name as written in source (`Foo`, `Foo\Bar`,
+    /// `\Foo`, `namespace\Foo`).
+    ///
+    /// `self`, `parent`, and `static` are returned unchanged; callers that know
+    /// the enclosing class substitute them.
+    pub fn resolve_class(&self, raw: &str) -> String {
+        if let Some(fq) = raw.strip_prefix('\\') {
+            return fq.to_string();
+        }
+        if let Some(rest) = strip_namespace_prefix(raw) {
+            return self.qualify(rest);
+        }
+        if is_special_class(raw) {
+            return raw.to_string();
+        }
+        let (first, rest) = match raw.split_once('\\') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (raw, None),
+        };
+        match (self.classes.get(&first.to_ascii_lowercase()), rest) {
+            (Some(fqn), Some(rest)) => format!("{fqn}\\{rest}"),
+            (Some(fqn), None) => fqn.clone(),
+            (None, _) => self.qualify(raw),
+        }
+    }
+
+    /// Resolve a function name used in a call.
+    ///
+    /// Returns the namespaced candidate and, for unqualified names inside a
+    /// namespace, the global fallback PHP tries when the namespaced function
+    /// does not exist.
+    pub fn resolve_function(&self, raw: &str) -> (String, Option<String>) {
+        self.resolve_non_class(raw, &self.functions)
+    }
+
+    /// Resolve a constant name; same fallback rules as [`resolve_function`](Self::resolve_function).
+    pub fn resolve_constant(&self, raw: &str) -> (String, Option<String>) {
+        self.resolve_non_class(raw, &self.constants)
+    }
+
+    fn resolve_non_class(
+        &self,
+        raw: &str,
+        imports: &HashMap<String, String>,
+    ) -> (String, Option<String>) {
+        if raw.starts_with('\\') || raw.contains('\\') {
+            return (self.resolve_class(raw), None);
+        }
+        if let Some(fqn) = imports.get(&raw.to_ascii_lowercase()) {
+            return (fqn.clone(), None);
+        }
+        if self.namespace.is_empty() {
+            (raw.to_string(), None)
+        } else {
+            (self.qualify(raw), Some(raw.to_string()))
+        }
+    }
+}
+
+/// `true` for the `self`, `parent`, and `static` pseudo-class names.
+pub fn is_special_class(name: &str) -> bool {
+    name.eq_ignore_ascii_case("self")
+        || name.eq_ignore_ascii_case("parent")
+        || name.eq_ignore_ascii_case("static")
+}
+
+fn strip_namespace_prefix(raw: &str) -> Option<&str> {
+    let (head, rest) = raw.split_once('\\')?;
+    head.eq_ignore_ascii_case("namespace").then_some(rest)
+}
@@ -0,0 +1,836 @@
+//! Normalising rewrites that shrink the set of forms an analysis must handle.
+//!
+//! # Interpolation
+//!
+//! [`LowerInterpolation`] rewrites string interpolation into explicit
+//! concatenation, so a type or taint analysis only has to understand `.`:
+//!
+//! | Before             | After                     |
+//! |--------------------|---------------------------|
+//! | `"Hi $name!"`      | `'Hi ' . $name . '!'`     |
+//! | `"$id"`            | `(string) $id`            |
+//! | `<<<EOT … EOT`     | the same, for each piece  |
+//! | `<<<'EOT' … EOT`   | a plain string literal    |
+//!
+//! Every piece keeps its own source span: literal pieces span their bytes in
+//! the original string (escapes included), embedded expressions keep theirs,
+//! and each intermediate `.` spans from its first piece to its last. The
+//! outermost node keeps the span of the whole string, quotes included.
+//! Backtick strings are left alone, since they run a command rather than
+//! build a string.
+//!
+//! # Arrow functions
+//!
+//! [`ArrowToClosure`] rewrites each arrow function into the closure PHP
+//! treats it as: `fn($x) => $x + $offset` becomes
+//! `function($x) use ($offset) { return $x + $offset; }`. The `use` list is
+//! [`implicit_captures`]: every variable the body reads that is not a
+//! parameter, `$this`, or a superglobal, including those read by nested arrow
+//! functions and named in nested closures' `use` lists. Captures are by
+//! value, as for arrow functions. The closure keeps the arrow function's
+//! span; the `return` statement spans the body expression.
+//!
+//! # Statements and declarations
+//!
+//! [`AlternativeToBraces`] drops the `endif;` style of control structures,
+//! [`ExpandPromotion`] turns promoted constructor parameters into property
+//! declarations and `$this->x = $x;` assignments, and [`LowerForeach`] moves
+//! `foreach` destructuring into an assignment at the start of the body.
+//!
+//! # Lowering
+//!
+//! [`lower`] applies every rewrite here. The result is still a [`Program`],
+//! so the existing visitors, analyses, and printer work on it unchanged, but
+//! an analysis over it has fewer forms to handle, and every node spans the
+//! source it came from.
+
+use std::ops::ControlFlow;
+
+use bumpalo::Bump;
+use php_ast::fold::{fold_expr, fold_stmt, Fold};
+use php_ast::visitor::{walk_expr, Visitor};
+use php_ast::{
+    ArenaVec, ArrayElement, ArrowFunctionExpr, AssignExpr, AssignOp, Attribute, BinaryExpr,
+    BinaryOp, BuiltinType, CastKind, ClassDecl, ClassMember, ClassMemberKind, ClosureExpr,
+    ClosureUseVar, Comment, DeclareStmt, ElseIfBranch, Expr, ExprKind, ForStmt, ForeachStmt,
+    IfStmt, MethodDecl, NameStr, Param, Program, PropertyAccessExpr, PropertyDecl, Span, Stmt,
+    StmtKind, StringPart, SwitchCase, SwitchStmt, TraitDecl, TypeHintKind, WhileStmt,
+};
+
+use crate::purity::SUPERGLOBALS;
+
+/// A [`Fold`] lowering interpolated strings, heredocs, and nowdocs to string
+/// literals, `(string)` casts, and left-nested [`BinaryOp::Concat`] chains.
+///
+/// ```
+/// use php_analysis::normalize::lower_interpolation;
+/// use php_ast::{BinaryOp, ExprKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = r#"<?php echo "Hi $name!";"#;
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = lower_interpolation(&arena, &result.program);
+///
+/// let StmtKind::Echo(echo) = &lowered.stmts[0].kind else { panic!() };
+/// let ExprKind::Binary(outer) = &echo.exprs[0].kind else { panic!() };
+/// assert_eq!(outer.op, BinaryOp::Concat);
+/// assert!(matches!(outer.right.kind, ExprKind::String { value: "!", .. }));
+/// assert_eq!(&src[outer.right.span.to_range()], "!");
+/// let ExprKind::Binary(inner) = &outer.left.kind else { panic!() };
+/// assert!(matches!(inner.left.kind, ExprKind::String { value: "Hi ", .. }));
+/// assert_eq!(&src[inner.right.span.to_range()], "$name");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LowerInterpolation;
+
+/// Apply [`LowerInterpolation`] to `program`, building the result in `arena`.
+pub fn lower_interpolation<'new, 'src>(
+    arena: &'new Bump,
+    program: &Program<'_, 'src>,
+) -> Program<'new, 'src> {
+    LowerInterpolation.fold_program(arena, program)
+}
+
+impl<'src> Fold<'src> for LowerInterpolation {
+    fn fold_expr<'new>(&mut self, arena: &'new Bump, expr: &Expr<'_, 'src>) -> Expr<'new, 'src> {
+        match &expr.kind {
+            ExprKind::InterpolatedString(parts) | ExprKind::Heredoc { parts, .. } => {
+                self.lower_parts(arena, parts, expr.span)
+            }
+            ExprKind::Nowdoc { raw, value, .. } => Expr {
+                kind: ExprKind::String {
+                    value: arena.alloc_str(value),
+                    raw,
+                },
+                span: expr.span,
+            },
+            _ => fold_expr(self, arena, expr),
+        }
+    }
+}
+
+impl LowerInterpolation {
+    fn lower_parts<'new, 'src>(
+        &mut self,
+        arena: &'new Bump,
+        parts: &[StringPart<'_, 'src>],
+        span: Span,
+    ) -> Expr<'new, 'src> {
+        let mut pieces: Vec<Expr<'new, 'src>> = parts
+            .iter()
+            .filter_map(|part| match part {
+                StringPart::Literal { value: "", .. } => None,
+                StringPart::Literal { value, span } => Some(Expr {
+                    kind: ExprKind::String {
+                        value: arena.alloc_str(value),
+                        raw: "",
+                    },
+                    span: *span,
+                }),
+                StringPart::Expr(inner) => Some(self.fold_expr(arena, inner)),
+            })
+            .collect();
+
+        let kind = match pieces.len() {
+            0 => ExprKind::String { value: "", raw: "" },
+            1 => {
+                let piece = pieces.pop().expect("pieces.len() == 1 checked above");
+                match piece.kind {
+                    ExprKind::String { value, raw } => ExprKind::String { value, raw },
+                    _ => ExprKind::Cast(CastKind::String, arena.alloc(piece)),
+                }
+            }
+            _ => {
+                let mut pieces = pieces.into_iter();
+                let first = pieces.next().expect("pieces.len() > 1 checked above");
+                let concat = pieces.fold(first, |left, right| Expr {
+                    span: Span::new(left.span.start, right.span.end),
+                    kind: ExprKind::Binary(BinaryExpr {
+                        left: arena.alloc(left),
+                        op: BinaryOp::Concat,
+                        right: arena.alloc(right),
+                    }),
+                });
+                concat.kind
+            }
+        };
+        Expr { kind, span }
+    }
+}
+
+/// A [`Fold`] rewriting arrow functions, nested ones included, into
+/// equivalent closures.
+///
+/// ```
+/// use php_analysis::normalize::arrow_functions_to_closures;
+/// use php_ast::{ExprKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php $add = fn($x) => $x + $offset;";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = arrow_functions_to_closures(&arena, &result.program);
+///
+/// let StmtKind::Expression(expr) = &lowered.stmts[0].kind else { panic!() };
+/// let ExprKind::Assign(assign) = &expr.kind else { panic!() };
+/// let ExprKind::Closure(closure) = &assign.value.kind else { panic!() };
+/// let captured: Vec<_> = closure.use_vars.iter().map(|var| var.name).collect();
+/// assert_eq!(captured, ["offset"]);
+/// assert!(matches!(closure.body[0].kind, StmtKind::Return(Some(_))));
+/// assert_eq!(&src[closure.body[0].span.to_range()], "$x + $offset");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArrowToClosure;
+
+/// Apply [`ArrowToClosure`] to `program`, building the result in `arena`.
+pub fn arrow_functions_to_closures<'new, 'src>(
+    arena: &'new Bump,
+    program: &Program<'_, 'src>,
+) -> Program<'new, 'src> {
+    ArrowToClosure.fold_program(arena, program)
+}
+
+impl<'src> Fold<'src> for ArrowToClosure {
+    fn fold_expr<'new>(&mut self, arena: &'new Bump, expr: &Expr<'_, 'src>) -> Expr<'new, 'src> {
+        match &expr.kind {
+            ExprKind::ArrowFunction(arrow) => Expr {
+                kind: ExprKind::Closure(arena.alloc(self.closure(arena, arrow))),
+                span: expr.span,
+            },
+            _ => fold_expr(self, arena, expr),
+        }
+    }
+}
+
+impl ArrowToClosure {
+    /// The closure equivalent to `arrow`. Arrow functions nested in its body
+    /// are rewritten too.
+    pub fn closure<'new, 'src>(
+        &mut self,
+        arena: &'new Bump,
+        arrow: &ArrowFunctionExpr<'_, 'src>,
+    ) -> ClosureExpr<'new, 'src> {
+        let mut params = ArenaVec::with_capacity_in(arrow.params.len(), arena);
+        for param in arrow.params.iter() {
+            params.push(self.fold_param(arena, param));
+        }
+        let captures = implicit_captures(arrow);
+        let mut use_vars = ArenaVec::with_capacity_in(captures.len(), arena);
+        for var in captures {
+            use_vars.push(var);
+        }
+        let mut attributes = ArenaVec::with_capacity_in(arrow.attributes.len(), arena);
+        for attribute in arrow.attributes.iter() {
+            attributes.push(self.fold_attribute(arena, attribute));
+        }
+
+        let value = arena.alloc(self.fold_expr(arena, arrow.body));
+        // A `never` function must not `return`, so its body is a plain
+        // expression statement (a `throw` or a call that does not return).
+        let returns_never = arrow
+            .return_type
+            .as_ref()
+            .is_some_and(|ty| matches!(ty.kind, TypeHintKind::Keyword(BuiltinType::Never, _)));
+        let kind = if returns_never {
+            StmtKind::Expression(value)
+        } else {
+            StmtKind::Return(Some(value))
+        };
+        let mut body = ArenaVec::with_capacity_in(1, arena);
+        body.push(Stmt {
+            kind,
+            span: arrow.body.span,
+        });
+
+        ClosureExpr {
+            is_static: arrow.is_static,
+            by_ref: arrow.by_ref,
+            params,
+            use_vars,
+            return_type: arrow
+                .return_type
+                .as_ref()
+                .map(|ty| self.fold_type_hint(arena, ty)),
+            body,
+            attributes,
+        }
+    }
+}
+
+/// The variables `arrow` captures from the enclosing scope, by value, in
+/// order of first use. Each span covers that first use.
+///
+/// ```
+/// use php_analysis::normalize::implicit_captures;
+/// use php_ast::{ExprKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php fn($x) => $this->f($x, $y, fn($z) => $z + $w, $_GET, $y);";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let StmtKind::Expression(expr) = &result.program.stmts[0].kind else { panic!() };
+/// let ExprKind::ArrowFunction(arrow) = &expr.kind else { panic!() };
+/// let names: Vec<_> = implicit_captures(arrow).iter().map(|var| var.name).collect();
+/// assert_eq!(names, ["y", "w"]);
+/// ```
+pub fn implicit_captures<'src>(arrow: &ArrowFunctionExpr<'_, 'src>) -> Vec<ClosureUseVar<'src>> {
+    let mut finder = CaptureFinder {
+        bound: arrow
+            .params
+            .iter()
+            .filter_map(|param| param.name.as_str())
+            .collect(),
+        captures: Vec::new(),
+    };
+    let _ = finder.visit_expr(arrow.body);
+    finder.captures
+}
+
+struct CaptureFinder<'src> {
+    /// Parameters of the arrow function and of the nested arrow functions
+    /// being visited.
+    bound: Vec<&'src str>,
+    captures: Vec<ClosureUseVar<'src>>,
+}
+
+impl<'src> CaptureFinder<'src> {
+    fn capture(&mut self, name: &'src str, span: Span) {
+        if name == "this"
+            || SUPERGLOBALS.contains(&name)
+            || self.bound.contains(&name)
+            || self.captures.iter().any(|var| var.name == name)
+        {
+            return;
+        }
+        self.captures.push(ClosureUseVar {
+            name,
+            by_ref: false,
+            span,
+            comma: None,
+        });
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for CaptureFinder<'src> {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            // Names the parser produces borrow from the source.
+            ExprKind::Variable(name) => {
+                if let Some(name) = name.__into_src_str() {
+                    self.capture(name, expr.span);
+                }
+                ControlFlow::Continue(())
+            }
+            ExprKind::ArrowFunction(inner) => {
+                let outer = self.bound.len();
+                self.bound
+                    .extend(inner.params.iter().filter_map(|param| param.name.as_str()));
+                let result = self.visit_expr(inner.body);
+                self.bound.truncate(outer);
+                result
+            }
+            ExprKind::Closure(closure) => {
+                for var in closure.use_vars.iter() {
+                    self.capture(var.name, var.span);
+                }
+                ControlFlow::Continue(())
+            }
+            // Only the constructor arguments, which `new` holds, are in scope.
+            ExprKind::AnonymousClass(_) => ControlFlow::Continue(()),
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
+/// A [`Fold`] clearing `uses_alternative` on `if`, `while`, `for`,
+/// `foreach`, `switch`, and `declare`, so `if (…): … endif;` becomes the
+/// braced `if (…) { … }` it means. The bodies are already blocks.
+///
+/// ```
+/// use php_analysis::normalize::alternative_syntax_to_braces;
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php while ($x): $x--; endwhile;";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = alternative_syntax_to_braces(&arena, &result.program);
+///
+/// let StmtKind::While(stmt) = &lowered.stmts[0].kind else { panic!() };
+/// assert!(!stmt.uses_alternative);
+/// assert!(matches!(stmt.body.kind, StmtKind::Block(_)));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlternativeToBraces;
+
+/// Apply [`AlternativeToBraces`] to `program`, building the result in `arena`.
+pub fn alternative_syntax_to_braces<'new, 'src>(
+    arena: &'new Bump,
+    program: &Program<'_, 'src>,
+) -> Program<'new, 'src> {
+    AlternativeToBraces.fold_program(arena, program)
+}
+
+impl<'src> Fold<'src> for AlternativeToBraces {
+    fn fold_stmt<'new>(&mut self, arena: &'new Bump, stmt: &Stmt<'_, 'src>) -> Stmt<'new, 'src> {
+        let kind = match &stmt.kind {
+            StmtKind::If(s) if s.uses_alternative => {
+                let mut elseif_branches =
+                    ArenaVec::with_capacity_in(s.elseif_branches.len(), arena);
+                for branch in s.elseif_branches.iter() {
+                    elseif_branches.push(ElseIfBranch {
+                        condition: self.fold_expr(arena, &branch.condition),
+                        body: self.fold_stmt(arena, &branch.body),
+                        span: branch.span,
+                    });
+                }
+                StmtKind::If(
+                    arena.alloc(IfStmt {
+                        condition: self.fold_expr(arena, &s.condition),
+                        then_branch: arena.alloc(self.fold_stmt(arena, s.then_branch)),
+                        elseif_branches,
+                        else_branch: s
+                            .else_branch
+                            .map(|body| &*arena.alloc(self.fold_stmt(arena, body))),
+                        uses_alternative: false,
+                    }),
+                )
+            }
+            StmtKind::While(s) if s.uses_alternative => StmtKind::While(arena.alloc(WhileStmt {
+                condition: self.fold_expr(arena, &s.condition),
+                body: arena.alloc(self.fold_stmt(arena, s.body)),
+                uses_alternative: false,
+            })),
+            StmtKind::For(s) if s.uses_alternative => StmtKind::For(arena.alloc(ForStmt {
+                init: fold_all(self, arena, &s.init),
+                condition: fold_all(self, arena, &s.condition),
+                update: fold_all(self, arena, &s.update),
+                init_span: s.init_span,
+                condition_span: s.condition_span,
+                update_span: s.update_span,
+                body: arena.alloc(self.fold_stmt(arena, s.body)),
+                uses_alternative: false,
+            })),
+            StmtKind::Foreach(s) if s.uses_alternative => {
+                StmtKind::Foreach(arena.alloc(ForeachStmt {
+                    expr: self.fold_expr(arena, &s.expr),
+                    key: s.key.as_ref().map(|key| self.fold_expr(arena, key)),
+                    value: self.fold_expr(arena, &s.value),
+                    body: arena.alloc(self.fold_stmt(arena, s.body)),
+                    uses_alternative: false,
+                }))
+            }
+            StmtKind::Switch(s) if s.uses_alternative => {
+                let mut cases = ArenaVec::with_capacity_in(s.cases.len(), arena);
+                for case in s.cases.iter() {
+                    let mut body = ArenaVec::with_capacity_in(case.body.len(), arena);
+                    for stmt in case.body.iter() {
+                        body.push(self.fold_stmt(arena, stmt));
+                    }
+                    cases.push(SwitchCase {
+                        value: case
+                            .value
+                            .as_ref()
+                            .map(|value| self.fold_expr(arena, value)),
+                        body,
+                        span: case.span,
+                    });
+                }
+                StmtKind::Switch(arena.alloc(SwitchStmt {
+                    expr: self.fold_expr(arena, &s.expr),
+                    cases,
+                    uses_alternative: false,
+                }))
+            }
+            StmtKind::Declare(s) if s.uses_alternative => {
+                let mut directives = ArenaVec::with_capacity_in(s.directives.len(), arena);
+                for (name, value) in s.directives.iter() {
+                    directives.push((*name, self.fold_expr(arena, value)));
+                }
+                StmtKind::Declare(
+                    arena.alloc(DeclareStmt {
+                        directives,
+                        body: s
+                            .body
+                            .map(|body| &*arena.alloc(self.fold_stmt(arena, body))),
+                        uses_alternative: false,
+                    }),
+                )
+            }
+            _ => return fold_stmt(self, arena, stmt),
+        };
+        Stmt {
+            kind,
+            span: stmt.span,
+        }
+    }
+}
+
+/// A [`Fold`] expanding promoted constructor parameters into the property
+/// declarations and assignments PHP treats them as:
+/// `function __construct(private int $x) {}` becomes `private int $x;`,
+/// declared just before the constructor, and
+/// `function __construct(int $x) { $this->x = $x; }`. Each property and
+/// assignment spans the parameter it comes from. The parameter's
+/// attributes are kept on both, and its hooks move to the property.
+///
+/// ```
+/// use php_analysis::normalize::expand_promoted_properties;
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php class P { function __construct(private int $x) {} }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = expand_promoted_properties(&arena, &result.program);
+///
+/// let StmtKind::Class(class) = &lowered.stmts[0].kind else { panic!() };
+/// let property = class.properties().next().unwrap();
+/// assert_eq!(&src[class.members[0].span.to_range()], "private int $x");
+/// assert_eq!(property.name, "x");
+/// let constructor = class.method("__construct").unwrap();
+/// assert!(constructor.params[0].visibility.is_none());
+/// let body = constructor.body.as_ref().unwrap();
+/// assert!(matches!(body[0].kind, StmtKind::Expression(_)));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpandPromotion;
+
+/// Apply [`ExpandPromotion`] to `program`, building the result in `arena`.
+pub fn expand_promoted_properties<'new, 'src>(
+    arena: &'new Bump,
+    program: &Program<'_, 'src>,
+) -> Program<'new, 'src> {
+    ExpandPromotion.fold_program(arena, program)
+}
+
+impl<'src> Fold<'src> for ExpandPromotion {
+    fn fold_stmt<'new>(&mut self, arena: &'new Bump, stmt: &Stmt<'_, 'src>) -> Stmt<'new, 'src> {
+        let kind = match &stmt.kind {
+            StmtKind::Class(class) => StmtKind::Class(arena.alloc(self.class(arena, class))),
+            StmtKind::Trait(trait_decl) => StmtKind::Trait(arena.alloc(TraitDecl {
+                name: trait_decl.name,
+                members: self.members(arena, &trait_decl.members),
+                attributes: fold_attributes(self, arena, &trait_decl.attributes),
+                doc_comment: trait_decl.doc_comment.as_ref().map(copy_comment),
+            })),
+            _ => return fold_stmt(self, arena, stmt),
+        };
+        Stmt {
+            kind,
+            span: stmt.span,
+        }
+    }
+
+    fn fold_expr<'new>(&mut self, arena: &'new Bump, expr: &Expr<'_, 'src>) -> Expr<'new, 'src> {
+        match &expr.kind {
+            ExprKind::AnonymousClass(class) => Expr {
+                kind: ExprKind::AnonymousClass(arena.alloc(self.class(arena, class))),
+                span: expr.span,
+            },
+            _ => fold_expr(self, arena, expr),
+        }
+    }
+}
+
+impl ExpandPromotion {
+    fn class<'new, 'src>(
+        &mut self,
+        arena: &'new Bump,
+        class: &ClassDecl<'_, 'src>,
+    ) -> ClassDecl<'new, 'src> {
+        let mut implements = ArenaVec::with_capacity_in(class.implements.len(), arena);
+        for name in class.implements.iter() {
+            implements.push(self.fold_name(arena, name));
+        }
+        ClassDecl {
+            name: class.name,
+            modifiers: class.modifiers.clone(),
+            extends: class
+                .extends
+                .as_ref()
+                .map(|name| self.fold_name(arena, name)),
+            implements,
+            members: self.members(arena, &class.members),
+            attributes: fold_attributes(self, arena, &class.attributes),
+            doc_comment: class.doc_comment.as_ref().map(copy_comment),
+        }
+    }
+
+    fn members<'new, 'src>(
+        &mut self,
+        arena: &'new Bump,
+        members: &[ClassMember<'_, 'src>],
+    ) -> ArenaVec<'new, ClassMember<'new, 'src>> {
+        let mut out = ArenaVec::with_capacity_in(members.len(), arena);
+        for member in members {
+            let mut folded = self.fold_class_member(arena, member);
+            if let (ClassMemberKind::Method(original), ClassMemberKind::Method(method)) =
+                (&member.kind, &mut folded.kind)
+            {
+                if original.name.as_str().is_some_and(is_constructor) {
+                    self.expand(arena, original, method, &mut out);
+                }
+            }
+            out.push(folded);
+        }
+        out
+    }
+
+    /// Push a property for each promoted parameter of `original` to `out`,
+    /// and turn the parameters of its fold, `method`, into plain ones
+    /// assigned at the start of the body.
+    fn expand<'new, 'src>(
+        &mut self,
+        arena: &'new Bump,
+        original: &MethodDecl<'_, 'src>,
+        method: &mut MethodDecl<'new, 'src>,
+        out: &mut ArenaVec<'new, ClassMember<'new, 'src>>,
+    ) {
+        let mut assignments = Vec::new();
+        for (source, param) in original.params.iter().zip(method.params.iter_mut()) {
+            let Some(name) = source.name.as_str().filter(|_| is_promoted(source)) else {
+                continue;
+            };
+            out.push(ClassMember {
+                kind: ClassMemberKind::Property(PropertyDecl {
+                    name: source.name,
+                    visibility: param.visibility.take(),
+                    set_visibility: param.set_visibility.take(),
+                    is_static: false,
+                    is_readonly: std::mem::take(&mut param.is_readonly),
+                    type_hint: source
+                        .type_hint
+                        .as_ref()
+                        .map(|ty| self.fold_type_hint(arena, ty)),
+                    default: None,
+                    attributes: fold_attributes(self, arena, &source.attributes),
+                    hooks: std::mem::replace(&mut param.hooks, ArenaVec::new_in(arena)),
+                    doc_comment: None,
+                }),
+                span: source.span,
+            });
+            param.is_final = false;
+            assignments.push(assign_to_property(arena, name, source.span));
+        }
+        if assignments.is_empty() {
+            return;
+        }
+        if let Some(body) = method.body.take() {
+            let mut stmts = ArenaVec::with_capacity_in(assignments.len() + body.len(), arena);
+            for stmt in assignments.into_iter().chain(body) {
+                stmts.push(stmt);
+            }
+            method.body = Some(stmts);
+        }
+    }
+}
+
+fn is_constructor(name: &str) -> bool {
+    name.eq_ignore_ascii_case("__construct")
+}
+
+/// Whether `param` declares a property: it has a visibility, `readonly`,
+/// or `final`.
+fn is_promoted(param: &Param<'_, '_>) -> bool {
+    param.visibility.is_some()
+        || param.set_visibility.is_some()
+        || param.is_readonly
+        || param.is_final
+}
+
+/// `$this->name = $name;`, every node spanning `span`.
+fn assign_to_property<'new, 'src>(
+    arena: &'new Bump,
+    name: &'src str,
+    span: Span,
+) -> Stmt<'new, 'src> {
+    let expr = |kind| Expr { kind, span };
+    let target = expr(ExprKind::PropertyAccess(PropertyAccessExpr {
+        object: arena.alloc(expr(ExprKind::Variable(NameStr::__src("this")))),
+        property: arena.alloc(expr(ExprKind::Identifier(NameStr::__src(name)))),
+    }));
+    let assign = expr(ExprKind::Assign(AssignExpr {
+        target: arena.alloc(target),
+        op: AssignOp::Assign,
+        value: arena.alloc(expr(ExprKind::Variable(NameStr::__src(name)))),
+        by_ref: false,
+    }));
+    Stmt {
+        kind: StmtKind::Expression(arena.alloc(assign)),
+        span,
+    }
+}
+
+/// A [`Fold`] moving `foreach` destructuring into the loop body:
+/// `foreach ($rows as [$id, $name]) { … }` becomes
+/// `foreach ($rows as $__foreach0) { [$id, $name] = $__foreach0; … }`, so
+/// every `foreach` binds plain variables. The temporary variables are
+/// numbered per fold and span the destructuring they replace. Targets with
+/// a by-reference element are left alone, since assigning from a copy
+/// would not bind into the iterated array.
+///
+/// ```
+/// use php_analysis::normalize::lower_foreach_destructuring;
+/// use php_ast::{ExprKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php foreach ($rows as [$id, $name]) { echo $id; }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = lower_foreach_destructuring(&arena, &result.program);
+///
+/// let StmtKind::Foreach(stmt) = &lowered.stmts[0].kind else { panic!() };
+/// assert_eq!(stmt.value.name_str(), Some("__foreach0"));
+/// assert_eq!(&src[stmt.value.span.to_range()], "[$id, $name]");
+/// let StmtKind::Block(body) = &stmt.body.kind else { panic!() };
+/// let StmtKind::Expression(first) = &body[0].kind else { panic!() };
+/// assert!(matches!(first.kind, ExprKind::Assign(_)));
+/// assert_eq!(body.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LowerForeach {
+    temporaries: usize,
+}
+
+/// Apply [`LowerForeach`] to `program`, building the result in `arena`.
+pub fn lower_foreach_destructuring<'new, 'src>(
+    arena: &'new Bump,
+    program: &Program<'_, 'src>,
+) -> Program<'new, 'src> {
+    LowerForeach::default().fold_program(arena, program)
+}
+
+impl<'src> Fold<'src> for LowerForeach {
+    fn fold_stmt<'new>(&mut self, arena: &'new Bump, stmt: &Stmt<'_, 'src>) -> Stmt<'new, 'src> {
+        let StmtKind::Foreach(foreach) = &stmt.kind else {
+            return fold_stmt(self, arena, stmt);
+        };
+        let elements = match &foreach.value.kind {
+            ExprKind::Array(elements) => elements,
+            ExprKind::List(list) => &list.elements,
+            _ => return fold_stmt(self, arena, stmt),
+        };
+        if binds_by_ref(elements) {
+            return fold_stmt(self, arena, stmt);
+        }
+
+        let name: &'new str = arena.alloc_str(&format!("__foreach{}", self.temporaries));
+        self.temporaries += 1;
+        let span = foreach.value.span;
+        let temporary = || Expr {
+            kind: ExprKind::Variable(NameStr::__arena(name)),
+            span,
+        };
+        let destructure = Expr {
+            kind: ExprKind::Assign(AssignExpr {
+                target: arena.alloc(self.fold_expr(arena, &foreach.value)),
+                op: AssignOp::Assign,
+                value: arena.alloc(temporary()),
+                by_ref: false,
+            }),
+            span,
+        };
+
+        let body = self.fold_stmt(arena, foreach.body);
+        let mut stmts = ArenaVec::new_in(arena);
+        stmts.push(Stmt {
+            kind: StmtKind::Expression(arena.alloc(destructure)),
+            span,
+        });
+        match body.kind {
+            StmtKind::Block(inner) => {
+                for stmt in inner {
+                    stmts.push(stmt);
+                }
+            }
+            kind => stmts.push(Stmt {
+                kind,
+                span: body.span,
+            }),
+        }
+
+        Stmt {
+            kind: StmtKind::Foreach(arena.alloc(ForeachStmt {
+                expr: self.fold_expr(arena, &foreach.expr),
+                key: foreach.key.as_ref().map(|key| self.fold_expr(arena, key)),
+                value: temporary(),
+                body: arena.alloc(Stmt {
+                    kind: StmtKind::Block(stmts),
+                    span: body.span,
+                }),
+                uses_alternative: foreach.uses_alternative,
+            })),
+            span: stmt.span,
+        }
+    }
+}
+
+/// Whether a destructuring binds an element by reference, at any depth.
+fn binds_by_ref(elements: &[ArrayElement<'_, '_>]) -> bool {
+    elements.iter().any(|element| {
+        element.by_ref
+            || match &element.value.kind {
+                ExprKind::Array(nested) => binds_by_ref(nested),
+                ExprKind::List(list) => binds_by_ref(&list.elements),
+                _ => false,
+            }
+    })
+}
+
+/// Apply every rewrite in this module, giving the smaller language the
+/// analyses here target: no alternative syntax, no interpolation, no arrow
+/// functions, no promoted parameters, and no `foreach` destructuring. Every
+/// node keeps the span of the source it stands for, so a diagnostic on the
+/// lowered program points into the original file.
+///
+/// ```
+/// use php_analysis::normalize::lower;
+/// use php_ast::{ExprKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = r#"<?php foreach ($rows as [$id]): echo "row $id"; endforeach;"#;
+/// let result = php_rs_parser::parse(&arena, src);
+/// let lowered = lower(&arena, &result.program);
+///
+/// let StmtKind::Foreach(stmt) = &lowered.stmts[0].kind else { panic!() };
+/// assert!(!stmt.uses_alternative);
+/// let StmtKind::Block(body) = &stmt.body.kind else { panic!() };
+/// let StmtKind::Echo(echo) = &body[1].kind else { panic!() };
+/// assert!(matches!(echo.exprs[0].kind, ExprKind::Binary(_)));
+/// assert_eq!(&src[echo.exprs[0].span.to_range()], r#""row $id""#);
+/// ```
+pub fn lower<'new, 'src>(arena: &'new Bump, program: &Program<'_, 'src>) -> Program<'new, 'src> {
+    let program = alternative_syntax_to_braces(arena, program);
+    let program = lower_interpolation(arena, &program);
+    let program = arrow_functions_to_closures(arena, &program);
+    let program = expand_promoted_properties(arena, &program);
+    lower_foreach_destructuring(arena, &program)
+}
+
+fn fold_all<'new, 'src>(
+    folder: &mut impl Fold<'src>,
+    arena: &'new Bump,
+    exprs: &[Expr<'_, 'src>],
+) -> ArenaVec<'new, Expr<'new, 'src>> {
+    let mut out = ArenaVec::with_capacity_in(exprs.len(), arena);
+    for expr in exprs {
+        out.push(folder.fold_expr(arena, expr));
+    }
+    out
+}
+
+fn fold_attributes<'new, 'src>(
+    folder: &mut impl Fold<'src>,
+    arena: &'new Bump,
+    attributes: &[Attribute<'_, 'src>],
+) -> ArenaVec<'new, Attribute<'new, 'src>> {
+    let mut out = ArenaVec::with_capacity_in(attributes.len(), arena);
+    for attribute in attributes {
+        out.push(folder.fold_attribute(arena, attribute));
+    }
+    out
+}
+
+fn copy_comment<'src>(comment: &Comment<'src>) -> Comment<'src> {
+    Comment {
+        kind: comment.kind,
+        text: comment.text,
+        span: comment.span,
+    }
+}
@@ -0,0 +1,600 @@
+//! Dereferences of possibly null variables, with null-check narrowing.
+//!
+//! A forward [`dataflow`](crate::dataflow) analysis tracks which local
+//! variables may hold `null` at each point of a body. A variable may be
+//! null after:
+//!
+//! - entry, for a parameter declared `?T`, `T|null`, or `= null`;
+//! - `$x = null`, `$x = $a?->b`, `$x = $y` with `$y` possibly null, or a
+//!   ternary, `match`, or `??` with such an operand;
+//! - `static $x = null`.
+//!
+//! Any other assignment, `foreach`, `catch`, `global`, or destructuring
+//! makes it non-null; calls are assumed not to return null. Only
+//! nullability is tracked, not the rest of the type.
+//!
+//! Reading a property of or calling a method on a possibly null variable
+//! with `->` is reported; after the report the variable is taken to be
+//! non-null, since execution only continues if it was. Checks narrow the
+//! variable to non-null within the region they guard — the branch of an
+//! `if`, `elseif`, `while`, `for`, or ternary, the right operand of `&&` or
+//! `||`, or the rest of the body after an early `return`:
+//!
+//! ```php
+//! if ($user !== null) { $user->save(); }   // also != null, !is_null()
+//! if ($user instanceof User) { $user->save(); }
+//! if (isset($user)) { … }                 // also !empty(), a truthy $user
+//! if ($user === null) { return; }
+//! $user->save();
+//! ```
+//!
+//! `?->`, `??`, `??=`, `isset()`, and `empty()` never report their
+//! operand.
+
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, Visitor};
+use php_ast::{
+    BinaryOp, BuiltinType, Expr, ExprKind, Program, Span, Stmt, StmtKind, UnaryPrefixOp,
+};
+
+use crate::cfg::{Cfg, CfgElement};
+use crate::codemod::implicit_nullable::is_null;
+use crate::dataflow::{solve, BitSet, Dataflow, Direction};
+use crate::definite_assignment::{guard_base, Variables};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit};
+
+/// How a possibly null variable is dereferenced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DereferenceKind {
+    /// `$x->prop`, read or written.
+    Property,
+    /// `$x->method()`.
+    MethodCall,
+}
+
+/// A `->` on a variable that may be null.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullDereference {
+    /// The variable name, without `$`.
+    pub name: String,
+    pub kind: DereferenceKind,
+    /// The property access or method call.
+    pub span: Span,
+    /// The enclosing unit's display name, e.g. `App\User::save` or `{main}`.
+    pub unit: String,
+}
+
+impl std::fmt::Display for NullDereference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let what = match self.kind {
+            DereferenceKind::Property => "property access",
+            DereferenceKind::MethodCall => "method call",
+        };
+        write!(f, "{what} on possibly null ${}", self.name)
+    }
+}
+
+/// Report the dereferences of possibly null variables in `program`, in
+/// source order.
+///
+/// ```
+/// use php_analysis::nullability::null_dereferences;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// function f(?User $user, ?User $other) {
+///     if ($user !== null) { $user->save(); }
+///     $other->save();
+///     $other->save();
+///     return $user?->name;
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let found = null_dereferences(&result.program);
+/// let found: Vec<_> = found.iter().map(|f| &src[f.span.to_range()]).collect();
+/// assert_eq!(found, ["$other->save()"]);
+/// ```
+pub fn null_dereferences(program: &Program<'_, '_>) -> Vec<NullDereference> {
+    let mut checker = Checker { found: Vec::new() };
+    AnalysisWalker::new(&mut checker).walk(program);
+    checker.found.sort_by_key(|f| f.span);
+    checker.found
+}
+
+struct Checker {
+    found: Vec<NullDereference>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Checker {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        let Some(body) = unit.body else {
+            return;
+        };
+        let cfg = Cfg::build(body);
+        let vars = Variables::collect(&cfg, unit);
+        let mut boundary = BitSet::new(vars.len());
+        for param in unit.params {
+            let nullable_type = param.type_hint.as_ref().is_some_and(|type_hint| {
+                type_hint.admits_null() && !type_hint.is_keyword(BuiltinType::Mixed)
+            });
+            let null_default = param.default.as_ref().is_some_and(is_null);
+            if nullable_type || null_default {
+                if let Some(i) = param.name.as_str().and_then(|name| vars.index(name)) {
+                    boundary.insert(i);
+                }
+            }
+        }
+
+        let flow = Flow {
+            vars: &vars,
+            boundary,
+        };
+        let results = solve(&cfg, &flow);
+        let mut found = BTreeMap::new();
+        for (id, block) in cfg.blocks().iter().enumerate() {
+            if !cfg.is_reachable(id) {
+                continue;
+            }
+            let mut fact = results.before(id).clone();
+            for element in &block.elements {
+                flow.eval(&mut fact, Some(&mut found)).element(element);
+            }
+        }
+        self.found.extend(
+            found
+                .into_iter()
+                .map(|(span, (var, kind))| NullDereference {
+                    name: vars.names[var].clone(),
+                    kind,
+                    span,
+                    unit: unit.name.clone(),
+                }),
+        );
+    }
+}
+
+type Found = BTreeMap<Span, (usize, DereferenceKind)>;
+
+/// The variables that may be null, joined by union.
+struct Flow<'v> {
+    vars: &'v Variables,
+    boundary: BitSet,
+}
+
+impl Flow<'_> {
+    fn eval<'f>(&'f self, fact: &'f mut BitSet, found: Option<&'f mut Found>) -> Eval<'f> {
+        Eval {
+            vars: self.vars,
+            fact,
+            found,
+        }
+    }
+}
+
+impl<'a, 'arena, 'src> Dataflow<'a, 'arena, 'src> for Flow<'_> {
+    type Fact = BitSet;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> BitSet {
+        self.boundary.clone()
+    }
+
+    fn initial(&self) -> BitSet {
+        BitSet::new(self.vars.len())
+    }
+
+    fn join(&self, into: &mut BitSet, other: &BitSet) {
+        into.union_with(other);
+    }
+
+    fn transfer(&self, element: &CfgElement<'a, 'arena, 'src>, fact: &mut BitSet) {
+        self.eval(fact, None).element(element);
+    }
+}
+
+/// Applies the effect of one element to a fact, in evaluation order,
+/// optionally recording dereferences of possibly null variables.
+struct Eval<'f> {
+    vars: &'f Variables,
+    fact: &'f mut BitSet,
+    found: Option<&'f mut Found>,
+}
+
+impl Eval<'_> {
+    fn element(&mut self, element: &CfgElement<'_, '_, '_>) {
+        match element {
+            CfgElement::Stmt(stmt) => self.stmt(stmt),
+            CfgElement::Expr(expr) => self.expr(expr),
+            CfgElement::ForeachBind(foreach) => {
+                if let Some(key) = &foreach.key {
+                    self.assign(key, false);
+                }
+                self.assign(&foreach.value, false);
+            }
+            CfgElement::CatchBind(catch) => {
+                if let Some(var) = &catch.var {
+                    self.set(var.name, false);
+                }
+            }
+            CfgElement::Assume { condition, holds } => self.assume(condition, *holds),
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt<'_, '_>) {
+        match &stmt.kind {
+            StmtKind::Expression(expr) | StmtKind::Throw(expr) => self.expr(expr),
+            StmtKind::Echo(echo) => echo.exprs.iter().for_each(|expr| self.expr(expr)),
+            StmtKind::Return(expr) | StmtKind::Break(expr) | StmtKind::Continue(expr) => {
+                if let Some(expr) = expr {
+                    self.expr(expr);
+                }
+            }
+            StmtKind::Global(vars) => vars.iter().for_each(|var| self.assign(var, false)),
+            StmtKind::StaticVar(vars) => {
+                for var in vars.iter() {
+                    let mut nullable = false;
+                    if let Some(default) = &var.default {
+                        self.expr(default);
+                        nullable = self.may_be_null(default);
+                    }
+                    if let Some(name) = var.name.as_str() {
+                        self.set(name, nullable);
+                    }
+                }
+            }
+            StmtKind::Unset(targets) => {
+                // Reading an unset variable is left to `definite_assignment`.
+                for target in targets.iter() {
+                    match &target.kind {
+                        ExprKind::Variable(name) => self.set(name, false),
+                        _ => self.guarded(target),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr<'_, '_>) {
+        match &expr.kind {
+            ExprKind::PropertyAccess(access) => {
+                self.expr(access.object);
+                self.member(access.property);
+                self.dereference(access.object, expr.span, DereferenceKind::Property);
+            }
+            ExprKind::MethodCall(call) => {
+                self.expr(call.object);
+                self.member(call.method);
+                call.args.iter().for_each(|arg| self.expr(&arg.value));
+                self.dereference(call.object, expr.span, DereferenceKind::MethodCall);
+            }
+            ExprKind::NullsafePropertyAccess(access) => {
+                self.expr(access.object);
+                self.member(access.property);
+            }
+            ExprKind::NullsafeMethodCall(call) => {
+                self.expr(call.object);
+                self.member(call.method);
+                call.args.iter().for_each(|arg| self.expr(&arg.value));
+            }
+            ExprKind::Assign(assign) => match assign.op {
+                php_ast::AssignOp::Assign if assign.by_ref => {
+                    self.guarded(assign.value);
+                    self.assign(assign.target, false);
+                }
+                php_ast::AssignOp::Assign => {
+                    self.expr(assign.value);
+                    let nullable = self.may_be_null(assign.value);
+                    self.assign(assign.target, nullable);
+                }
+                php_ast::AssignOp::Coalesce => {
+                    self.guarded(assign.target);
+                    let was_nullable = self.may_be_null(assign.target);
+                    self.expr(assign.value);
+                    let nullable = was_nullable && self.may_be_null(assign.value);
+                    self.assign(assign.target, nullable);
+                }
+                _ => {
+                    self.expr(assign.target);
+                    self.expr(assign.value);
+                    self.assign(assign.target, false);
+                }
+            },
+            ExprKind::Binary(binary) => match binary.op {
+                BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => {
+                    self.expr(binary.left);
+                    self.branch(|eval| {
+                        eval.assume(binary.left, true);
+                        eval.expr(binary.right);
+                    });
+                }
+                BinaryOp::BooleanOr | BinaryOp::LogicalOr => {
+                    self.expr(binary.left);
+                    self.branch(|eval| {
+                        eval.assume(binary.left, false);
+                        eval.expr(binary.right);
+                    });
+                }
+                _ => {
+                    self.expr(binary.left);
+                    self.expr(binary.right);
+                }
+            },
+            ExprKind::Ternary(ternary) => {
+                self.expr(ternary.condition);
+                let skipped = self.fact.clone();
+                if let Some(then_expr) = ternary.then_expr {
+                    self.assume(ternary.condition, true);
+                    self.expr(then_expr);
+                }
+                let taken = std::mem::replace(self.fact, skipped);
+                self.assume(ternary.condition, false);
+                self.expr(ternary.else_expr);
+                self.fact.union_with(&taken);
+            }
+            ExprKind::NullCoalesce(coalesce) => {
+                self.guarded(coalesce.left);
+                self.branch(|eval| eval.expr(coalesce.right));
+            }
+            ExprKind::Isset(exprs) => exprs.iter().for_each(|expr| self.guarded(expr)),
+            ExprKind::Empty(inner) => self.guarded(inner),
+            ExprKind::Match(match_expr) => {
+                self.expr(match_expr.subject);
+                let subject = self.fact.clone();
+                let mut merged = subject.clone();
+                for arm in match_expr.arms.iter() {
+                    *self.fact = subject.clone();
+                    for condition in arm.conditions.iter().flat_map(|c| c.iter()) {
+                        self.expr(condition);
+                    }
+                    self.expr(&arm.body);
+                    merged.union_with(self.fact);
+                }
+                *self.fact = merged;
+            }
+            ExprKind::Closure(_) | ExprKind::ArrowFunction(_) | ExprKind::AnonymousClass(_) => {}
+            _ => {
+                let _ = walk_expr(&mut Operands(self), expr);
+            }
+        }
+    }
+
+    /// A property or method name after `->`: evaluated only when dynamic.
+    fn member(&mut self, member: &Expr<'_, '_>) {
+        if !matches!(member.kind, ExprKind::Identifier(_)) {
+            self.expr(member);
+        }
+    }
+
+    /// An operand of `isset()`, `empty()`, `??`, `??=`, or `unset()`: a
+    /// `->` chain in it is not reported, but indexes and arguments are
+    /// evaluated.
+    fn guarded(&mut self, expr: &Expr<'_, '_>) {
+        match &expr.kind {
+            ExprKind::Variable(_) => {}
+            ExprKind::ArrayAccess(access) => {
+                self.guarded(access.array);
+                if let Some(index) = access.index {
+                    self.expr(index);
+                }
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                self.guarded(access.object);
+                self.member(access.property);
+            }
+            ExprKind::Parenthesized(inner) => self.guarded(inner),
+            _ => self.expr(expr),
+        }
+    }
+
+    /// Report `object->…` when `object` is a possibly null variable, then
+    /// take it to be non-null.
+    fn dereference(&mut self, object: &Expr<'_, '_>, span: Span, kind: DereferenceKind) {
+        let ExprKind::Variable(name) = &object.kind else {
+            return;
+        };
+        let Some(i) = self.vars.index(name) else {
+            return;
+        };
+        if self.fact.contains(i) {
+            if let Some(found) = self.found.as_mut() {
+                found.entry(span).or_insert((i, kind));
+            }
+            self.fact.remove(i);
+        }
+    }
+
+    /// Record a write to `target`: a variable becomes possibly null or not
+    /// as `nullable` says, destructured variables become non-null.
+    fn assign(&mut self, target: &Expr<'_, '_>, nullable: bool) {
+        match &target.kind {
+            ExprKind::Variable(name) => self.set(name, nullable),
+            ExprKind::List(list) => {
+                for element in list.elements.iter() {
+                    if let Some(key) = &element.key {
+                        self.expr(key);
+                    }
+                    self.assign(&element.value, false);
+                }
+            }
+            ExprKind::Omit => {}
+            _ => self.expr(target),
+        }
+    }
+
+    /// `true` if `expr`, evaluated against the current fact, may be null.
+    fn may_be_null(&self, expr: &Expr<'_, '_>) -> bool {
+        may_be_null(self.vars, self.fact, expr)
+    }
+
+    /// Evaluate `run` on a path that may be skipped, then merge it with the
+    /// path that skips it.
+    fn branch(&mut self, run: impl FnOnce(&mut Self)) {
+        let skipped = self.fact.clone();
+        run(self);
+        self.fact.union_with(&skipped);
+    }
+
+    /// Narrow the variables that `condition` evaluating to `holds` proves
+    /// non-null.
+    fn assume(&mut self, condition: &Expr<'_, '_>, holds: bool) {
+        for name in implied_non_null(condition, holds) {
+            self.set(name, false);
+        }
+    }
+
+    fn set(&mut self, name: &str, nullable: bool) {
+        if let Some(i) = self.vars.index(name) {
+            if nullable {
+                self.fact.insert(i);
+            } else {
+                self.fact.remove(i);
+            }
+        }
+    }
+}
+
+/// Evaluates the direct operands of an expression with no special rules.
+struct Operands<'e, 'f>(&'e mut Eval<'f>);
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Operands<'_, '_> {
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        self.0.expr(expr);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_stmt(&mut self, _stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+/// `true` if `expr` may be null when the variables in `fact` may be.
+fn may_be_null(vars: &Variables, fact: &BitSet, expr: &Expr<'_, '_>) -> bool {
+    match &expr.kind {
+        ExprKind::Variable(name) => vars.index(name).is_some_and(|i| fact.contains(i)),
+        ExprKind::NullsafePropertyAccess(_) | ExprKind::NullsafeMethodCall(_) => true,
+        ExprKind::Parenthesized(inner) => may_be_null(vars, fact, inner),
+        ExprKind::Assign(assign) if assign.op == php_ast::AssignOp::Assign => {
+            may_be_null(vars, fact, assign.value)
+        }
+        ExprKind::NullCoalesce(coalesce) => may_be_null(vars, fact, coalesce.right),
+        ExprKind::Ternary(ternary) => {
+            let branch = |holds: bool, expr: &Expr<'_, '_>| {
+                let mut narrowed = fact.clone();
+                for name in implied_non_null(ternary.condition, holds) {
+                    if let Some(i) = vars.index(name) {
+                        narrowed.remove(i);
+                    }
+                }
+                may_be_null(vars, &narrowed, expr)
+            };
+            ternary
+                .then_expr
+                .is_some_and(|then_expr| branch(true, then_expr))
+                || branch(false, ternary.else_expr)
+        }
+        ExprKind::Match(match_expr) => match_expr
+            .arms
+            .iter()
+            .any(|arm| may_be_null(vars, fact, &arm.body)),
+        _ => is_null(expr),
+    }
+}
+
+/// Type checks that only hold for non-null values.
+const TYPE_CHECKS: &[&str] = &[
+    "is_array",
+    "is_bool",
+    "is_callable",
+    "is_countable",
+    "is_float",
+    "is_int",
+    "is_iterable",
+    "is_numeric",
+    "is_object",
+    "is_resource",
+    "is_scalar",
+    "is_string",
+];
+
+/// The variables `condition` proves non-null when it evaluates to `holds`.
+fn implied_non_null<'e>(condition: &'e Expr<'_, '_>, holds: bool) -> Vec<&'e str> {
+    match &condition.kind {
+        ExprKind::Parenthesized(inner) => implied_non_null(inner, holds),
+        ExprKind::UnaryPrefix(unary) if unary.op == UnaryPrefixOp::BooleanNot => {
+            implied_non_null(unary.operand, !holds)
+        }
+        ExprKind::Variable(_) | ExprKind::Assign(_) if holds => {
+            narrowed_variable(condition).into_iter().collect()
+        }
+        ExprKind::Isset(exprs) if holds => exprs.iter().filter_map(guard_base).collect(),
+        ExprKind::Empty(inner) if !holds => guard_base(inner).into_iter().collect(),
+        ExprKind::Instanceof(instanceof) if holds => {
+            narrowed_variable(instanceof.expr).into_iter().collect()
+        }
+        ExprKind::FunctionCall(call) => {
+            let ExprKind::Name(name) = &call.name.kind else {
+                return Vec::new();
+            };
+            let name = name.join_parts();
+            let name = name.trim_start_matches('\\');
+            let proves = match name.eq_ignore_ascii_case("is_null") {
+                true => !holds,
+                false => holds && TYPE_CHECKS.iter().any(|f| f.eq_ignore_ascii_case(name)),
+            };
+            match call.args.first() {
+                Some(arg) if proves && call.args.len() == 1 && !arg.unpack => {
+                    narrowed_variable(&arg.value).into_iter().collect()
+                }
+                _ => Vec::new(),
+            }
+        }
+        ExprKind::Binary(binary) => match binary.op {
+            BinaryOp::NotIdentical | BinaryOp::NotEqual | BinaryOp::Identical | BinaryOp::Equal => {
+                let proves =
+                    holds == matches!(binary.op, BinaryOp::NotIdentical | BinaryOp::NotEqual);
+                let operand = match (is_null(binary.left), is_null(binary.right)) {
+                    (false, true) => binary.left,
+                    (true, false) => binary.right,
+                    _ => return Vec::new(),
+                };
+                match proves {
+                    true => narrowed_variable(operand).into_iter().collect(),
+                    false => Vec::new(),
+                }
+            }
+            BinaryOp::BooleanAnd
+            | BinaryOp::LogicalAnd
+            | BinaryOp::BooleanOr
+            | BinaryOp::LogicalOr => {
+                let both =
+                    holds == matches!(binary.op, BinaryOp::BooleanAnd | BinaryOp::LogicalAnd);
+                let mut left = implied_non_null(binary.left, holds);
+                let right = implied_non_null(binary.right, holds);
+                if both {
+                    left.extend(right);
+                } else {
+                    left.retain(|name| right.contains(name));
+                }
+                left
+            }
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// The variable a check narrows: `$x` itself, or the target of an
+/// assignment checked in place, as in `($x = find()) !== null`.
+fn narrowed_variable<'e>(expr: &'e Expr<'_, '_>) -> Option<&'e str> {
+    match &expr.kind {
+        ExprKind::Variable(name) => Some(name.as_str()),
+        ExprKind::Parenthesized(inner) => narrowed_variable(inner),
+        ExprKind::Assign(assign) if assign.op == php_ast::AssignOp::Assign => {
+            narrowed_variable(assign.target)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,175 @@
+//! The property set of a class, with promoted constructor parameters
+//! expanded.
+//!
+//! A constructor parameter with a visibility, `readonly`, `final`, or an
+//! asymmetric `(set)` visibility declares a property of the same name and
+//! type and assigns the argument to it before the constructor body runs:
+//!
+//! ```php
+//! class Point {
+//!     public function __construct(private readonly int $x = 0) {}
+//! }
+//! // is
+//! class Point {
+//!     private readonly int $x;
+//!     public function __construct(int $x = 0) { $this->x = $x; }
+//! }
+//! ```
+//!
+//! [`class_properties`] lists the declared and promoted properties of a
+//! class or trait together, so analyses need not expand promotion
+//! themselves. Properties from parents and used traits are not included.
+
+use php_ast::{
+    Attribute, ClassDecl, ClassMember, ClassMemberKind, Expr, Param, PropertyHook, Span, TraitDecl,
+    TypeHint, Visibility,
+};
+
+/// Where a [`PropertyView`] comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum PropertySource<'a, 'arena, 'src> {
+    /// A property declaration.
+    Declared,
+    /// A promoted constructor parameter. The constructor implicitly runs
+    /// `$this->name = $name;` on entry, with the argument or the parameter's
+    /// default.
+    Promoted {
+        param: &'a Param<'arena, 'src>,
+        /// The `__construct` method.
+        constructor: Span,
+    },
+}
+
+/// One property of a class as PHP sees it.
+#[derive(Debug, Clone, Copy)]
+pub struct PropertyView<'a, 'arena, 'src> {
+    /// The name, without `$`.
+    pub name: &'src str,
+    pub type_hint: Option<&'a TypeHint<'arena, 'src>>,
+    /// `public` when not written.
+    pub visibility: Visibility,
+    /// The `(set)` visibility, when asymmetric.
+    pub set_visibility: Option<Visibility>,
+    /// Declared `readonly`, or in a `readonly` class.
+    pub is_readonly: bool,
+    pub is_static: bool,
+    /// The initial value; always `None` for promoted properties, whose
+    /// parameter default is an argument default instead.
+    pub default: Option<&'a Expr<'arena, 'src>>,
+    pub hooks: &'a [PropertyHook<'arena, 'src>],
+    pub attributes: &'a [Attribute<'arena, 'src>],
+    pub source: PropertySource<'a, 'arena, 'src>,
+    /// The declaring member or parameter.
+    pub span: Span,
+}
+
+impl PropertyView<'_, '_, '_> {
+    pub fn is_promoted(&self) -> bool {
+        matches!(self.source, PropertySource::Promoted { .. })
+    }
+}
+
+/// The properties a class declares, directly or by promotion, in source
+/// order.
+///
+/// ```
+/// use php_analysis::properties::class_properties;
+/// use php_ast::{StmtKind, Visibility};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// final readonly class User {
+///     public string $email;
+///     public function __construct(private int $id, string $name) {}
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let StmtKind::Class(class) = &result.program.stmts[0].kind else { unreachable!() };
+///
+/// let properties = class_properties(class);
+/// let names: Vec<_> = properties.iter().map(|p| p.name).collect();
+/// assert_eq!(names, ["email", "id"]);
+/// assert!(properties[1].is_promoted());
+/// assert_eq!(properties[1].visibility, Visibility::Private);
+/// assert!(properties.iter().all(|p| p.is_readonly));
+/// ```
+pub fn class_properties<'a, 'arena, 'src>(
+    class: &'a ClassDecl<'arena, 'src>,
+) -> Vec<PropertyView<'a, 'arena, 'src>> {
+    collect(&class.members, class.modifiers.is_readonly)
+}
+
+/// The properties a trait declares, directly or by promotion, in source
+/// order.
+pub fn trait_properties<'a, 'arena, 'src>(
+    trait_decl: &'a TraitDecl<'arena, 'src>,
+) -> Vec<PropertyView<'a, 'arena, 'src>> {
+    collect(&trait_decl.members, false)
+}
+
+/// `true` if `param` declares a property when it belongs to a constructor.
+pub fn is_promoted(param: &Param<'_, '_>) -> bool {
+    param.visibility.is_some()
+        || param.set_visibility.is_some()
+        || param.is_readonly
+        || param.is_final
+}
+
+fn collect<'a, 'arena, 'src>(
+    members: &'a [ClassMember<'arena, 'src>],
+    readonly_class: bool,
+) -> Vec<PropertyView<'a, 'arena, 'src>> {
+    let mut properties = Vec::new();
+    for member in members {
+        match &member.kind {
+            ClassMemberKind::Property(prop) => {
+                let Some(name) = prop.name.as_str() else {
+                    continue;
+                };
+                properties.push(PropertyView {
+                    name,
+                    type_hint: prop.type_hint.as_ref(),
+                    visibility: prop.visibility.unwrap_or(Visibility::Public),
+                    set_visibility: prop.set_visibility,
+                    is_readonly: prop.is_readonly || readonly_class,
+                    is_static: prop.is_static,
+                    default: prop.default.as_ref(),
+                    hooks: &prop.hooks,
+                    attributes: &prop.attributes,
+                    source: PropertySource::Declared,
+                    span: member.span,
+                });
+            }
+            ClassMemberKind::Method(method)
+                if !method.is_static
+                    && method
+                        .name
+                        .as_str()
+                        .is_some_and(|name| name.eq_ignore_ascii_case("__construct")) =>
+            {
+                for param in method.params.iter().filter(|param| is_promoted(param)) {
+                    let Some(name) = param.name.as_str() else {
+                        continue;
+                    };
+                    properties.push(PropertyView {
+                        name,
+                        type_hint: param.type_hint.as_ref(),
+                        visibility: param.visibility.unwrap_or(Visibility::Public),
+                        set_visibility: param.set_visibility,
+                        is_readonly: param.is_readonly || readonly_class,
+                        is_static: false,
+                        default: None,
+                        hooks: &param.hooks,
+                        attributes: &param.attributes,
+                        source: PropertySource::Promoted {
+                            param,
+                            constructor: member.span,
+                        },
+                        span: param.span,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    properties
+}
@@ -0,0 +1,448 @@
+//! PSR-1 and PSR-12 structural lints: what a file declares and how it opens
+//! and closes.
+//!
+//! Five rules, each switchable in [`PsrOptions`]:
+//!
+//! - [`PsrRule::SideEffects`] — a file that declares classes, functions, or
+//!   constants and also causes side effects (PSR-1 2.3). Declarations inside
+//!   an `if`, as in `if (!function_exists('f')) { function f() {} }`, and
+//!   `define()` calls count as declarations; `namespace`, `use`, and
+//!   `declare` count as neither.
+//! - [`PsrRule::OneClassPerFile`] — every class, interface, trait, or enum
+//!   after the first in a file.
+//! - [`PsrRule::ClassFileName`] — a file whose first class-like is not where
+//!   autoloading looks for it: at the path its [`Psr4`] mapping gives, or,
+//!   without a mapping, in a file named after it.
+//! - [`PsrRule::OpenTag`] — short `<?` open tags (PSR-1 2.1), a byte-order
+//!   mark, and code after a `<?php` on the first line of a file without
+//!   markup (PSR-12 3).
+//! - [`PsrRule::ClosingTag`] — a final `?>` in a file without markup
+//!   (PSR-12 2.2).
+//!
+//! A file has markup when it has inline HTML other than whitespace and a
+//! byte-order mark.
+
+use php_ast::{Expr, ExprKind, NamespaceBody, Program, Span, Stmt, StmtKind};
+use php_lexer::{Lexer, TokenKind};
+
+/// Which rule a violation breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PsrRule {
+    SideEffects,
+    OneClassPerFile,
+    ClassFileName,
+    OpenTag,
+    ClosingTag,
+}
+
+impl std::fmt::Display for PsrRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PsrRule::SideEffects => "side-effects",
+            PsrRule::OneClassPerFile => "one-class-per-file",
+            PsrRule::ClassFileName => "class-file-name",
+            PsrRule::OpenTag => "open-tag",
+            PsrRule::ClosingTag => "closing-tag",
+        })
+    }
+}
+
+/// A PSR-4 autoload mapping from namespace prefixes to directories, as in
+/// the `autoload.psr-4` section of `composer.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Psr4 {
+    prefixes: Vec<(String, String)>,
+}
+
+impl Psr4 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the namespace `prefix`, e.g. `App\`, to `dir`, e.g. `src/`. An
+    /// empty prefix is a fallback for every namespace.
+    pub fn add(&mut self, prefix: impl AsRef<str>, dir: impl AsRef<str>) {
+        let prefix = prefix.as_ref().trim_matches('\\').to_string();
+        let dir = dir
+            .as_ref()
+            .replace('\\', "/")
+            .trim_end_matches('/')
+            .to_string();
+        self.prefixes.push((prefix, dir));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// The path the class `fqcn` autoloads from, under the longest prefix
+    /// that matches whole namespace segments.
+    ///
+    /// ```
+    /// use php_analysis::psr::Psr4;
+    ///
+    /// let mut psr4 = Psr4::new();
+    /// psr4.add("App\\", "src/");
+    /// psr4.add("App\\Tests\\", "tests");
+    /// assert_eq!(psr4.path_of("App\\Model\\User").as_deref(), Some("src/Model/User.php"));
+    /// assert_eq!(psr4.path_of("\\App\\Tests\\UserTest").as_deref(), Some("tests/UserTest.php"));
+    /// assert_eq!(psr4.path_of("Application\\Kernel"), None);
+    /// ```
+    pub fn path_of(&self, fqcn: &str) -> Option<String> {
+        let fqcn = fqcn.trim_start_matches('\\');
+        let (dir, rest) = self
+            .prefixes
+            .iter()
+            .filter_map(|(prefix, dir)| {
+                let rest = if prefix.is_empty() {
+                    fqcn
+                } else {
+                    fqcn.strip_prefix(prefix.as_str())?.strip_prefix('\\')?
+                };
+                Some((prefix.len(), dir, rest))
+            })
+            .max_by_key(|(len, ..)| *len)
+            .map(|(_, dir, rest)| (dir, rest))?;
+        let file = format!("{}.php", rest.replace('\\', "/"));
+        Some(if dir.is_empty() {
+            file
+        } else {
+            format!("{dir}/{file}")
+        })
+    }
+}
+
+/// Which rules to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsrOptions {
+    pub side_effects: bool,
+    pub one_class_per_file: bool,
+    pub class_file_name: bool,
+    pub open_tag: bool,
+    pub closing_tag: bool,
+    /// Where classes autoload from, for `class_file_name`. When empty, only
+    /// the file's base name is checked.
+    pub psr4: Psr4,
+}
+
+impl Default for PsrOptions {
+    fn default() -> Self {
+        Self {
+            side_effects: true,
+            one_class_per_file: true,
+            class_file_name: true,
+            open_tag: true,
+            closing_tag: true,
+            psr4: Psr4::default(),
+        }
+    }
+}
+
+/// One reported violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PsrViolation {
+    pub rule: PsrRule,
+    pub span: Span,
+    pub message: String,
+}
+
+impl std::fmt::Display for PsrViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Report the PSR-1 and PSR-12 structural violations of the file at `path`,
+/// in source order. `source` is the text `program` was parsed from; `path`
+/// is only used by the class-file-name rule, which an empty path skips.
+///
+/// ```
+/// use php_analysis::psr::{psr_violations, PsrOptions};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php\nnamespace App;\n\nclass User {}\nclass Admin {}\n\necho 'loaded';\n";
+/// let result = php_rs_parser::parse(&arena, src);
+///
+/// let mut options = PsrOptions::default();
+/// options.psr4.add("App\\", "src/");
+/// let found = psr_violations(&result.program, src, "src/Users.php", &options);
+/// let rules: Vec<_> = found.iter().map(|v| v.rule.to_string()).collect();
+/// assert_eq!(rules, ["class-file-name", "one-class-per-file", "side-effects"]);
+/// assert_eq!(found[0].message, "`App\\User` should be in `src/User.php`");
+/// ```
+pub fn psr_violations(
+    program: &Program<'_, '_>,
+    source: &str,
+    path: &str,
+    options: &PsrOptions,
+) -> Vec<PsrViolation> {
+    let mut file = FileShape::default();
+    file.stmts(&program.stmts, "");
+    let markup = has_markup(source);
+    let mut found = Vec::new();
+
+    if options.side_effects {
+        if let (Some(_), Some(span)) = (file.first_declaration, file.first_side_effect) {
+            found.push(PsrViolation {
+                rule: PsrRule::SideEffects,
+                span,
+                message: "side effect in a file that also declares symbols".to_string(),
+            });
+        }
+    }
+
+    if options.one_class_per_file {
+        for class in file.classes.iter().skip(1) {
+            found.push(PsrViolation {
+                rule: PsrRule::OneClassPerFile,
+                span: class.span,
+                message: format!("`{}` is not the only class-like in the file", class.fqcn),
+            });
+        }
+    }
+
+    if options.class_file_name && !path.is_empty() {
+        if let Some(class) = file.classes.first() {
+            if let Some(message) = misplaced(class, path, &options.psr4) {
+                found.push(PsrViolation {
+                    rule: PsrRule::ClassFileName,
+                    span: class.span,
+                    message,
+                });
+            }
+        }
+    }
+
+    if options.open_tag {
+        open_tag(source, markup, &mut found);
+    }
+
+    if options.closing_tag && !markup {
+        if let Some(span) = program.close_tag {
+            found.push(PsrViolation {
+                rule: PsrRule::ClosingTag,
+                span,
+                message: "closing `?>` in a file containing only PHP".to_string(),
+            });
+        }
+    }
+
+    found.sort_by_key(|v| v.span.start);
+    found
+}
+
+/// A class-like declared in the file.
+struct ClassLike {
+    name: String,
+    fqcn: String,
+    span: Span,
+}
+
+/// What the statements of a file declare and do.
+#[derive(Default)]
+struct FileShape {
+    first_declaration: Option<Span>,
+    first_side_effect: Option<Span>,
+    classes: Vec<ClassLike>,
+}
+
+impl FileShape {
+    /// Classify `stmts`, in namespace `namespace` until a `namespace;`
+    /// statement changes it.
+    fn stmts(&mut self, stmts: &[Stmt<'_, '_>], namespace: &str) {
+        let mut namespace = namespace.to_string();
+        for stmt in stmts {
+            if let StmtKind::Namespace(decl) = &stmt.kind {
+                let name = decl
+                    .name
+                    .as_ref()
+                    .map_or(String::new(), |n| n.join_parts().into_owned());
+                match &decl.body {
+                    NamespaceBody::Braced(body) => self.stmts(body, &name),
+                    NamespaceBody::Simple => namespace = name,
+                }
+            } else {
+                self.stmt(stmt, &namespace);
+            }
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt<'_, '_>, namespace: &str) {
+        let class_name = match &stmt.kind {
+            StmtKind::Class(class) => class.name.and_then(|n| n.as_str()),
+            StmtKind::Interface(decl) => decl.name.as_str(),
+            StmtKind::Trait(decl) => decl.name.as_str(),
+            StmtKind::Enum(decl) => decl.name.as_str(),
+            _ => None,
+        };
+        if let Some(name) = class_name {
+            let fqcn = if namespace.is_empty() {
+                name.to_string()
+            } else {
+                format!("{namespace}\\{name}")
+            };
+            self.classes.push(ClassLike {
+                name: name.to_string(),
+                fqcn,
+                span: stmt.span,
+            });
+        }
+        match &stmt.kind {
+            StmtKind::Class(_)
+            | StmtKind::Interface(_)
+            | StmtKind::Trait(_)
+            | StmtKind::Enum(_)
+            | StmtKind::Function(_)
+            | StmtKind::Const(_) => self.declaration(stmt.span),
+            StmtKind::Expression(expr) if is_define(expr) => self.declaration(stmt.span),
+            StmtKind::Use(_) | StmtKind::Nop | StmtKind::HaltCompiler(_) => {}
+            StmtKind::InlineHtml(html) if is_blank(html.output()) => {}
+            StmtKind::Declare(declare) => {
+                if let Some(body) = declare.body {
+                    self.stmt(body, namespace);
+                }
+            }
+            StmtKind::Block(stmts) => {
+                for stmt in stmts.iter() {
+                    self.stmt(stmt, namespace);
+                }
+            }
+            // A conditional declaration is not a side effect, so an `if`
+            // counts as whatever its branches do.
+            StmtKind::If(if_stmt) => {
+                let mut branches = FileShape::default();
+                branches.stmt(if_stmt.then_branch, namespace);
+                for elseif in if_stmt.elseif_branches.iter() {
+                    branches.stmt(&elseif.body, namespace);
+                }
+                if let Some(else_branch) = if_stmt.else_branch {
+                    branches.stmt(else_branch, namespace);
+                }
+                if branches.first_side_effect.is_some() {
+                    self.side_effect(stmt.span);
+                } else if branches.first_declaration.is_some() {
+                    self.declaration(stmt.span);
+                }
+                self.classes.extend(branches.classes);
+            }
+            _ => self.side_effect(stmt.span),
+        }
+    }
+
+    fn declaration(&mut self, span: Span) {
+        self.first_declaration.get_or_insert(span);
+    }
+
+    fn side_effect(&mut self, span: Span) {
+        self.first_side_effect.get_or_insert(span);
+    }
+}
+
+/// `define(...)`, which declares a constant.
+fn is_define(expr: &Expr<'_, '_>) -> bool {
+    let ExprKind::FunctionCall(call) = &expr.kind else {
+        return false;
+    };
+    let ExprKind::Name(name) = &call.name.kind else {
+        return false;
+    };
+    name.join_parts()
+        .trim_start_matches('\\')
+        .eq_ignore_ascii_case("define")
+}
+
+/// Why `class` is not where autoloading would look for it in `path`, if it
+/// is not.
+fn misplaced(class: &ClassLike, path: &str, psr4: &Psr4) -> Option<String> {
+    let path = path.replace('\\', "/");
+    if psr4.is_empty() {
+        let stem = path
+            .rsplit('/')
+            .next()
+            .and_then(|file| file.strip_suffix(".php"))
+            .unwrap_or_default();
+        return (stem != class.name)
+            .then(|| format!("`{}` should be in `{}.php`", class.name, class.name));
+    }
+    match psr4.path_of(&class.fqcn) {
+        Some(expected) if path == expected || path.ends_with(&format!("/{expected}")) => None,
+        Some(expected) => Some(format!("`{}` should be in `{expected}`", class.fqcn)),
+        None => Some(format!(
+            "`{}` is outside every PSR-4 namespace prefix",
+            class.fqcn
+        )),
+    }
+}
+
+/// Whether inline HTML is only whitespace, or the byte-order mark that
+/// [`PsrRule::OpenTag`] reports.
+fn is_blank(html: &str) -> bool {
+    html.trim_matches(|c: char| c.is_whitespace() || c == '\u{feff}')
+        .is_empty()
+}
+
+/// Whether `source` has inline HTML other than whitespace.
+fn has_markup(source: &str) -> bool {
+    let mut lexer = Lexer::new(source);
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::Eof => return false,
+            TokenKind::InlineHtml if !is_blank(lexer.token_text(&token)) => return true,
+            _ => {}
+        }
+    }
+}
+
+fn open_tag(source: &str, markup: bool, found: &mut Vec<PsrViolation>) {
+    let mut report = |start: usize, end: usize, message: &str| {
+        found.push(PsrViolation {
+            rule: PsrRule::OpenTag,
+            span: Span::new(start as u32, end as u32),
+            message: message.to_string(),
+        });
+    };
+
+    let bom = "\u{feff}";
+    if source.starts_with(bom) {
+        report(0, bom.len(), "byte-order mark before the opening tag");
+    }
+
+    // PHP does not open on `<?` unless `short_open_tag` is set, so these
+    // are inline HTML. `<?xml` and the like are not short tags.
+    let mut lexer = Lexer::new(source);
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::Eof => break,
+            TokenKind::InlineHtml => {
+                let text = lexer.token_text(&token);
+                let base = token.span.start as usize;
+                for (at, _) in text.match_indices("<?") {
+                    let next = text[at + 2..].chars().next();
+                    if next.is_none_or(char::is_whitespace) {
+                        report(base + at, base + at + 2, "short open tag `<?`; use `<?php`");
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let body = source.strip_prefix(bom).unwrap_or(source);
+    let offset = source.len() - body.len();
+    if markup
+        || !body
+            .get(..5)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("<?php"))
+    {
+        return;
+    }
+    let line = body[5..].split('\n').next().unwrap_or_default();
+    let code = line.len() - line.trim_start().len();
+    if !line.trim().is_empty() {
+        let start = offset + 5 + code;
+        let end = offset + 5 + line.trim_end().len();
+        report(start, end, "code on the line of the opening `<?php`");
+    }
+}
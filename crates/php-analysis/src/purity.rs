@@ -0,0 +1,542 @@
+//! Purity (side-effect) classification of functions and methods.
+//!
+//! A unit is *impure* when it performs an observable effect: output (`echo`,
+//! `print`, inline HTML), access to global state (`global`, superglobals,
+//! static properties), `static` variables, writes to object properties,
+//! `include`/`eval`/`exit`, shell execution, or a call to a built-in known to
+//! perform I/O, depend on the clock or randomness, or change runtime
+//! configuration. Writes to `$this` inside `__construct` are initialisation
+//! and do not count.
+//!
+//! Calls are followed through the [`CallGraph`]. A call whose target cannot be
+//! named statically, or a method call that does not resolve to a project
+//! method, makes the caller [`Purity::Unknown`] rather than impure. Global
+//! functions that are neither declared in the project nor listed as impure
+//! built-ins are assumed to be pure built-ins.
+
+use std::collections::HashMap;
+
+use php_ast::{
+    AssignExpr, Expr, ExprKind, Program, Span, Stmt, StmtKind, UnaryPostfixExpr, UnaryPrefixExpr,
+    UnaryPrefixOp,
+};
+
+use crate::callgraph::{CallGraph, Callee};
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// Built-in functions with observable side effects or non-deterministic results.
+const IMPURE_BUILTINS: &[&str] = &[
+    // output
+    "printf",
+    "vprintf",
+    "fprintf",
+    "vfprintf",
+    "print_r",
+    "var_dump",
+    "var_export",
+    "debug_zval_dump",
+    "debug_print_backtrace",
+    "flush",
+    "ob_start",
+    "ob_end_clean",
+    "ob_end_flush",
+    "ob_get_clean",
+    "ob_flush",
+    "readfile",
+    "fpassthru",
+    "header",
+    "header_remove",
+    "http_response_code",
+    "setcookie",
+    "setrawcookie",
+    // filesystem and streams
+    "fopen",
+    "fclose",
+    "fread",
+    "fwrite",
+    "fputs",
+    "fgets",
+    "fgetc",
+    "fgetcsv",
+    "fputcsv",
+    "fscanf",
+    "fseek",
+    "ftell",
+    "rewind",
+    "feof",
+    "fflush",
+    "ftruncate",
+    "flock",
+    "file",
+    "file_get_contents",
+    "file_put_contents",
+    "file_exists",
+    "is_file",
+    "is_dir",
+    "is_readable",
+    "is_writable",
+    "filemtime",
+    "filesize",
+    "unlink",
+    "mkdir",
+    "rmdir",
+    "rename",
+    "copy",
+    "touch",
+    "chmod",
+    "chown",
+    "tempnam",
+    "tmpfile",
+    "scandir",
+    "glob",
+    "opendir",
+    "readdir",
+    "closedir",
+    "move_uploaded_file",
+    "stream_get_contents",
+    // process, network, and environment
+    "exec",
+    "shell_exec",
+    "system",
+    "passthru",
+    "proc_open",
+    "popen",
+    "pclose",
+    "mail",
+    "error_log",
+    "syslog",
+    "trigger_error",
+    "user_error",
+    "set_error_handler",
+    "set_exception_handler",
+    "register_shutdown_function",
+    "ini_set",
+    "ini_restore",
+    "putenv",
+    "getenv",
+    "set_time_limit",
+    "setlocale",
+    "date_default_timezone_set",
+    "define",
+    "session_start",
+    "session_destroy",
+    "session_regenerate_id",
+    "curl_exec",
+    "fsockopen",
+    "sleep",
+    "usleep",
+    "time_nanosleep",
+    // clock and randomness
+    "time",
+    "microtime",
+    "hrtime",
+    "date",
+    "mktime",
+    "rand",
+    "mt_rand",
+    "random_int",
+    "random_bytes",
+    "lcg_value",
+    "uniqid",
+    "srand",
+    "mt_srand",
+    "shuffle",
+    "str_shuffle",
+    "array_rand",
+];
+
+/// Superglobal variables, without `$`.
+pub(crate) const SUPERGLOBALS: &[&str] = &[
+    "GLOBALS", "_SERVER", "_GET", "_POST", "_FILES", "_COOKIE", "_SESSION", "_REQUEST", "_ENV",
+];
+
+/// Overall classification of a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Purity {
+    /// No side effects, directly or through calls.
+    Pure,
+    /// No known side effects, but some call target could not be resolved.
+    Unknown,
+    /// Performs a side effect, directly or through a call.
+    Impure,
+}
+
+impl std::fmt::Display for Purity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Purity::Pure => "pure",
+            Purity::Unknown => "unknown",
+            Purity::Impure => "impure",
+        })
+    }
+}
+
+/// What a side-effecting construct does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectKind {
+    /// `echo`, `print`, or inline HTML.
+    Output,
+    /// `global`, a superglobal, or a static property.
+    GlobalState,
+    /// A `static` variable declaration.
+    StaticVariable,
+    /// Assignment, increment, or `unset` of an object property.
+    PropertyWrite,
+    /// `include`, `include_once`, `require`, or `require_once`.
+    Include,
+    Eval,
+    /// `exit` / `die`.
+    Exit,
+    /// Backtick shell execution.
+    ShellExec,
+    /// A call to a built-in from the impure list.
+    Builtin {
+        function: String,
+    },
+    /// A call to a project function or method that is not pure.
+    Call {
+        callee: String,
+        purity: Purity,
+    },
+    /// A call whose target cannot be resolved.
+    DynamicCall,
+}
+
+impl EffectKind {
+    /// How this effect contributes to the unit's [`Purity`].
+    pub fn purity(&self) -> Purity {
+        match self {
+            EffectKind::Call { purity, .. } => *purity,
+            EffectKind::DynamicCall => Purity::Unknown,
+            _ => Purity::Impure,
+        }
+    }
+}
+
+impl std::fmt::Display for EffectKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EffectKind::Output => f.write_str("writes output"),
+            EffectKind::GlobalState => f.write_str("accesses global state"),
+            EffectKind::StaticVariable => f.write_str("declares a static variable"),
+            EffectKind::PropertyWrite => f.write_str("writes an object property"),
+            EffectKind::Include => f.write_str("includes a file"),
+            EffectKind::Eval => f.write_str("evaluates code"),
+            EffectKind::Exit => f.write_str("exits"),
+            EffectKind::ShellExec => f.write_str("executes a shell command"),
+            EffectKind::Builtin { function } => write!(f, "calls impure built-in {function}"),
+            EffectKind::Call { callee, purity } => write!(f, "calls {purity} {callee}"),
+            EffectKind::DynamicCall => f.write_str("makes an unresolvable call"),
+        }
+    }
+}
+
+/// One side-effecting construct within a unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Effect {
+    pub kind: EffectKind,
+    pub span: Span,
+}
+
+/// Purity of one function or method.
+#[derive(Debug, Clone)]
+pub struct PurityReport {
+    /// Fully-qualified name, as in [`CallNode::name`](crate::callgraph::CallNode::name).
+    pub name: String,
+    pub kind: UnitKind,
+    pub file: usize,
+    pub span: Span,
+    pub purity: Purity,
+    /// Effects in source order. Empty for pure units.
+    pub effects: Vec<Effect>,
+}
+
+/// Per-symbol purity results returned by [`PurityAnalysis::finish`].
+#[derive(Debug, Default)]
+pub struct PurityResults {
+    reports: Vec<PurityReport>,
+    /// Lower-cased name → first report with that name.
+    index: HashMap<String, usize>,
+}
+
+impl PurityResults {
+    /// Look up a function (`Ns\name`) or method (`Ns\Class::name`), case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&PurityReport> {
+        self.index
+            .get(&name.to_ascii_lowercase())
+            .map(|&i| &self.reports[i])
+    }
+
+    /// Reports for every function and method, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = &PurityReport> {
+        self.reports.iter()
+    }
+}
+
+#[derive(Debug)]
+enum Site {
+    Effect(Effect),
+    Call(Callee, Span),
+    /// `new X`; a class without a project constructor has no effect.
+    New(Callee, Span),
+}
+
+#[derive(Debug)]
+struct UnitInfo {
+    sites: Vec<Site>,
+}
+
+/// Classifies every function and method across a set of files.
+///
+/// ```
+/// use php_analysis::purity::{Purity, PurityAnalysis};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php function add($a, $b) { return $a + $b; } function show($x) { echo add($x, 1); }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut analysis = PurityAnalysis::new();
+/// analysis.add_file(&result.program);
+/// let results = analysis.finish();
+/// assert_eq!(results.get("add").unwrap().purity, Purity::Pure);
+/// assert_eq!(results.get("show").unwrap().purity, Purity::Impure);
+/// ```
+#[derive(Debug, Default)]
+pub struct PurityAnalysis {
+    graph: CallGraph,
+    units: Vec<UnitInfo>,
+}
+
+impl PurityAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.graph.add_file(program);
+        let mut collector = Collector {
+            units: &mut self.units,
+            stack: Vec::new(),
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        file
+    }
+
+    /// The call graph built from the added files.
+    pub fn graph(&self) -> &CallGraph {
+        &self.graph
+    }
+
+    /// Resolve calls, propagate purity to a fixpoint, and build the results.
+    pub fn finish(self) -> PurityResults {
+        let graph = &self.graph;
+        // Each site becomes either a direct effect or an edge to a project node.
+        let mut direct: Vec<Vec<Effect>> = Vec::with_capacity(self.units.len());
+        let mut edges: Vec<Vec<(usize, Span)>> = Vec::with_capacity(self.units.len());
+        for unit in &self.units {
+            let mut effects = Vec::new();
+            let mut calls = Vec::new();
+            for site in &unit.sites {
+                match site {
+                    Site::Effect(effect) => effects.push(effect.clone()),
+                    Site::Call(callee, span) => match graph.resolve(callee) {
+                        Some(target) => calls.push((target, *span)),
+                        None => {
+                            if let Some(kind) = unresolved_effect(callee) {
+                                effects.push(Effect { kind, span: *span });
+                            }
+                        }
+                    },
+                    Site::New(callee, span) => {
+                        if let Some(target) = graph.resolve(callee) {
+                            calls.push((target, *span));
+                        }
+                    }
+                }
+            }
+            direct.push(effects);
+            edges.push(calls);
+        }
+
+        let mut purity: Vec<Purity> = direct
+            .iter()
+            .map(|effects| {
+                effects
+                    .iter()
+                    .map(|e| e.kind.purity())
+                    .max()
+                    .unwrap_or(Purity::Pure)
+            })
+            .collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for id in 0..purity.len() {
+                let inherited = edges[id]
+                    .iter()
+                    .map(|&(target, _)| purity[target])
+                    .max()
+                    .unwrap_or(Purity::Pure);
+                if inherited > purity[id] {
+                    purity[id] = inherited;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut results = PurityResults::default();
+        for (id, node) in graph.nodes().iter().enumerate() {
+            if !matches!(node.kind, UnitKind::Function | UnitKind::Method) {
+                continue;
+            }
+            let mut effects = direct[id].clone();
+            effects.extend(
+                edges[id]
+                    .iter()
+                    .filter(|&&(target, _)| purity[target] != Purity::Pure)
+                    .map(|&(target, span)| Effect {
+                        kind: EffectKind::Call {
+                            callee: graph.node(target).name.clone(),
+                            purity: purity[target],
+                        },
+                        span,
+                    }),
+            );
+            effects.sort_by_key(|e| (e.span.start, e.span.end));
+            results
+                .index
+                .entry(node.name.to_ascii_lowercase())
+                .or_insert(results.reports.len());
+            results.reports.push(PurityReport {
+                name: node.name.clone(),
+                kind: node.kind,
+                file: node.file,
+                span: node.span,
+                purity: purity[id],
+                effects,
+            });
+        }
+        results
+    }
+}
+
+/// The effect of a call that does not resolve to a project unit.
+fn unresolved_effect(callee: &Callee) -> Option<EffectKind> {
+    match callee {
+        Callee::Function { name, fallback } => {
+            let global = fallback.as_deref().unwrap_or(name);
+            IMPURE_BUILTINS
+                .iter()
+                .any(|f| f.eq_ignore_ascii_case(global))
+                .then(|| EffectKind::Builtin {
+                    function: global.to_string(),
+                })
+        }
+        Callee::Method { .. } => Some(EffectKind::DynamicCall),
+    }
+}
+
+struct Collector<'u> {
+    units: &'u mut Vec<UnitInfo>,
+    stack: Vec<usize>,
+}
+
+impl Collector<'_> {
+    fn push(&mut self, site: Site) {
+        if let Some(&id) = self.stack.last() {
+            self.units[id].sites.push(site);
+        }
+    }
+
+    fn effect(&mut self, kind: EffectKind, span: Span) {
+        self.push(Site::Effect(Effect { kind, span }));
+    }
+
+    /// Record a write to `target` if it lands outside the unit's locals.
+    fn write(&mut self, target: &Expr<'_, '_>, scope: &AnalysisScope) {
+        let mut root = target;
+        loop {
+            match &root.kind {
+                ExprKind::ArrayAccess(access) => root = access.array,
+                ExprKind::Parenthesized(inner) => root = inner,
+                ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                    let in_constructor = scope.unit.as_deref().is_some_and(|unit| {
+                        unit.rsplit("::")
+                            .next()
+                            .is_some_and(|m| m.eq_ignore_ascii_case("__construct"))
+                    });
+                    let on_this = matches!(&access.object.kind, ExprKind::Variable(v) if v.as_str() == "this");
+                    if !(in_constructor && on_this) {
+                        self.effect(EffectKind::PropertyWrite, target.span);
+                    }
+                    return;
+                }
+                _ => return,
+            }
+        }
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector<'_> {
+    fn enter_unit(&mut self, _unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        self.stack.push(self.units.len());
+        self.units.push(UnitInfo { sites: Vec::new() });
+    }
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {
+        self.stack.pop();
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Echo(_) | StmtKind::InlineHtml(_) => {
+                self.effect(EffectKind::Output, stmt.span)
+            }
+            StmtKind::Global(_) => self.effect(EffectKind::GlobalState, stmt.span),
+            StmtKind::StaticVar(_) => self.effect(EffectKind::StaticVariable, stmt.span),
+            StmtKind::Unset(targets) => {
+                for target in targets.iter() {
+                    self.write(target, scope);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>, scope: &AnalysisScope) {
+        let span = expr.span;
+        match &expr.kind {
+            ExprKind::Print(_) => self.effect(EffectKind::Output, span),
+            ExprKind::Include(..) => self.effect(EffectKind::Include, span),
+            ExprKind::Eval(_) => self.effect(EffectKind::Eval, span),
+            ExprKind::Exit(_) => self.effect(EffectKind::Exit, span),
+            ExprKind::ShellExec(_) => self.effect(EffectKind::ShellExec, span),
+            ExprKind::Variable(name) if SUPERGLOBALS.contains(&name.as_str()) => {
+                self.effect(EffectKind::GlobalState, span)
+            }
+            ExprKind::StaticPropertyAccess(_) | ExprKind::StaticPropertyAccessDynamic { .. } => {
+                self.effect(EffectKind::GlobalState, span)
+            }
+            ExprKind::Assign(AssignExpr { target, .. }) => self.write(target, scope),
+            ExprKind::UnaryPostfix(UnaryPostfixExpr { operand, .. }) => self.write(operand, scope),
+            ExprKind::UnaryPrefix(UnaryPrefixExpr {
+                op: UnaryPrefixOp::PreIncrement | UnaryPrefixOp::PreDecrement,
+                operand,
+            }) => self.write(operand, scope),
+            ExprKind::FunctionCall(_)
+            | ExprKind::MethodCall(_)
+            | ExprKind::NullsafeMethodCall(_)
+            | ExprKind::StaticMethodCall(_)
+            | ExprKind::StaticDynMethodCall(_) => match Callee::from_expr(expr, scope) {
+                Some(callee) => self.push(Site::Call(callee, span)),
+                None => self.effect(EffectKind::DynamicCall, span),
+            },
+            ExprKind::New(_) => {
+                if let Some(callee) = Callee::from_expr(expr, scope) {
+                    self.push(Site::New(callee, span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Name resolution: the fully-qualified name of every name in a file.
+//!
+//! [`resolve_names`] walks a program tracking `namespace` and `use`
+//! declarations the way PHP does, and returns a side table mapping the span
+//! of each [`Name`] to what it refers to:
+//!
+//! - class names — type hints, `extends`/`implements`, `new`, `instanceof`,
+//!   static access, `catch`, attributes, and trait uses — resolve through
+//!   class imports, or else against the current namespace;
+//! - function names in calls and constant names in fetches resolve through
+//!   their own imports; unqualified ones in a namespace keep the global name
+//!   PHP falls back to at run time;
+//! - names in `use` and `namespace` declarations are already fully
+//!   qualified.
+//!
+//! Declared names are identifiers, not names, and are not in the table; the
+//! walker's [`NameContext::qualify`] gives their fully-qualified names.
+//! `self`, `parent`, and `static` resolve to themselves.
+//!
+//! ```
+//! use php_analysis::resolve::{resolve_names, NameRole};
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! namespace App;
+//! use Lib\\Http\\Request;
+//! use function Lib\\render;
+//! function handle(Request $r): void { render(strlen(PHP_EOL)); }
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let names = resolve_names(&result.program);
+//! let resolved: Vec<_> = names
+//!     .iter()
+//!     .map(|n| (n.role, n.fqn.as_str(), n.fallback.as_deref()))
+//!     .collect();
+//! assert_eq!(resolved, [
+//!     (NameRole::Namespace, "App", None),
+//!     (NameRole::Class, "Lib\\Http\\Request", None),
+//!     (NameRole::Function, "Lib\\render", None),
+//!     (NameRole::Class, "Lib\\Http\\Request", None),
+//!     (NameRole::Function, "Lib\\render", None),
+//!     (NameRole::Function, "App\\strlen", Some("strlen")),
+//!     (NameRole::Constant, "App\\PHP_EOL", Some("PHP_EOL")),
+//! ]);
+//! ```
+
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_program, walk_stmt, Visitor};
+use php_ast::*;
+
+use crate::names::NameContext;
+
+/// What kind of symbol a name refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameRole {
+    /// A class, interface, trait, or enum.
+    Class,
+    Function,
+    Constant,
+    /// The name of a `namespace` declaration.
+    Namespace,
+}
+
+/// A name with what it resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedName {
+    pub span: Span,
+    pub role: NameRole,
+    /// Fully-qualified, without a leading backslash.
+    pub fqn: String,
+    /// For an unqualified function or constant name in a namespace, the
+    /// global name PHP uses when `fqn` is not defined.
+    pub fallback: Option<String>,
+}
+
+/// The resolved names of a file, in source order.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedNames {
+    names: Vec<ResolvedName>,
+}
+
+impl ResolvedNames {
+    pub fn iter(&self) -> impl Iterator<Item = &ResolvedName> {
+        self.names.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The name with exactly this span, e.g. `name.span()` of a [`Name`].
+    pub fn get(&self, span: Span) -> Option<&ResolvedName> {
+        let i = self.names.partition_point(|n| n.span < span);
+        self.names.get(i).filter(|n| n.span == span)
+    }
+
+    /// The name containing `offset`.
+    pub fn at(&self, offset: u32) -> Option<&ResolvedName> {
+        let i = self.names.partition_point(|n| n.span.end <= offset);
+        self.names.get(i).filter(|n| n.span.contains(offset))
+    }
+}
+
+/// Resolve every name of `program`.
+pub fn resolve_names(program: &Program<'_, '_>) -> ResolvedNames {
+    let mut resolver = Resolver::default();
+    let _ = walk_program(&mut resolver, program);
+    let mut names = resolver.names;
+    names.sort_by_key(|n| n.span);
+    ResolvedNames { names }
+}
+
+#[derive(Default)]
+struct Resolver {
+    context: NameContext,
+    names: Vec<ResolvedName>,
+    /// Names already resolved, or not referring to a symbol, that the walk
+    /// will still visit.
+    skip: HashSet<Span>,
+}
+
+impl Resolver {
+    fn push(&mut self, name: &Name<'_, '_>, role: NameRole, fqn: String, fallback: Option<String>) {
+        if matches!(name, Name::Error { .. }) {
+            return;
+        }
+        self.skip.insert(name.span());
+        self.names.push(ResolvedName {
+            span: name.span(),
+            role,
+            fqn,
+            fallback,
+        });
+    }
+
+    /// A name already fully qualified, as in a `use` or `namespace`.
+    fn qualified(&mut self, name: &Name<'_, '_>, role: NameRole) {
+        let fqn = name.join_parts();
+        let fqn = fqn.trim_start_matches('\\').to_string();
+        self.push(name, role, fqn, None);
+    }
+
+    fn function(&mut self, name: &Name<'_, '_>) {
+        let (fqn, fallback) = self.context.resolve_function(&name.to_string_repr());
+        self.push(name, NameRole::Function, fqn, fallback);
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Resolver {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Namespace(ns) => {
+                if let Some(name) = &ns.name {
+                    self.qualified(name, NameRole::Namespace);
+                }
+                let name = ns.name.as_ref().map(|n| n.join_parts().into_owned());
+                self.context.set_namespace(&name.unwrap_or_default());
+                if let NamespaceBody::Braced(stmts) = &ns.body {
+                    for stmt in stmts.iter() {
+                        self.visit_stmt(stmt)?;
+                    }
+                    self.context.set_namespace("");
+                }
+                ControlFlow::Continue(())
+            }
+            StmtKind::Use(decl) => {
+                for item in decl.uses.iter() {
+                    let role = match item.kind.unwrap_or(decl.kind) {
+                        UseKind::Normal => NameRole::Class,
+                        UseKind::Function => NameRole::Function,
+                        UseKind::Const => NameRole::Constant,
+                    };
+                    self.qualified(&item.name, role);
+                }
+                self.context.add_use(decl);
+                ControlFlow::Continue(())
+            }
+            StmtKind::Enum(decl) => {
+                // `enum Suit: string` names a type, not a class.
+                if let Some(scalar) = &decl.scalar_type {
+                    self.skip.insert(scalar.span());
+                }
+                walk_stmt(self, stmt)
+            }
+            _ => walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::FunctionCall(FunctionCallExpr { name: callee, .. })
+            | ExprKind::CallableCreate(CallableCreateExpr {
+                kind: CallableCreateKind::Function(callee),
+            }) => {
+                if let ExprKind::Name(name) = &callee.kind {
+                    self.function(name);
+                }
+            }
+            ExprKind::ConstFetch(name) => {
+                let (fqn, fallback) = self.context.resolve_constant(&name.to_string_repr());
+                self.push(name, NameRole::Constant, fqn, fallback);
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_name(&mut self, name: &Name<'arena, 'src>) -> ControlFlow<()> {
+        if !self.skip.contains(&name.span()) {
+            let fqn = self.context.resolve_class(&name.to_string_repr());
+            self.push(name, NameRole::Class, fqn, None);
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,196 @@
+//! Missing-return and unreachable-code diagnostics.
+//!
+//! Built on the [`Cfg`] of every function, method, closure, and file body:
+//!
+//! - a function, method, or closure with a return type that requires a value
+//!   (anything but `void` and `null`) where some path runs off the end of the
+//!   body — PHP only reports this at run time, when the path is taken;
+//! - a `never` function where some path runs off the end;
+//! - the first statement of every run of code that follows `return`, `throw`,
+//!   `exit`, `break`, `continue`, or `goto` and can never execute.
+//!
+//! Generators are exempt from the missing-return check.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{BuiltinType, Expr, ExprKind, Program, Span, Stmt, StmtKind};
+
+use crate::cfg::Cfg;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit, UnitKind};
+
+/// A control-flow diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReturnFinding {
+    /// `function` declares a return type but a path reaches the end of its body.
+    /// `span` covers the declaration.
+    MissingReturn {
+        file: usize,
+        function: String,
+        span: Span,
+    },
+    /// `function` is declared `never` but a path reaches the end of its body.
+    NeverReturns {
+        file: usize,
+        function: String,
+        span: Span,
+    },
+    /// The statement at `span` can never execute.
+    Unreachable { file: usize, span: Span },
+}
+
+impl ReturnFinding {
+    pub fn file(&self) -> usize {
+        match self {
+            Self::MissingReturn { file, .. }
+            | Self::NeverReturns { file, .. }
+            | Self::Unreachable { file, .. } => *file,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::MissingReturn { span, .. }
+            | Self::NeverReturns { span, .. }
+            | Self::Unreachable { span, .. } => *span,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingReturn { function, .. } => {
+                write!(f, "{function} does not return a value on every path")
+            }
+            Self::NeverReturns { function, .. } => {
+                write!(f, "{function} is declared never but can return implicitly")
+            }
+            Self::Unreachable { .. } => f.write_str("unreachable statement"),
+        }
+    }
+}
+
+/// Runs the checks over a set of files.
+///
+/// ```
+/// use php_analysis::returns::{ReturnAnalysis, ReturnFinding};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php function sign(int $n): int { if ($n > 0) { return 1; } }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let mut analysis = ReturnAnalysis::new();
+/// analysis.add_file(&result.program);
+/// assert!(matches!(
+///     &analysis.finish()[..],
+///     [ReturnFinding::MissingReturn { function, .. }] if function == "sign"
+/// ));
+/// ```
+#[derive(Debug, Default)]
+pub struct ReturnAnalysis {
+    files: usize,
+    findings: Vec<ReturnFinding>,
+}
+
+impl ReturnAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check every body in `program`. Returns its file index.
+    pub fn add_file(&mut self, program: &Program<'_, '_>) -> usize {
+        let file = self.files;
+        self.files += 1;
+        let mut checker = Checker {
+            file,
+            findings: &mut self.findings,
+        };
+        AnalysisWalker::new(&mut checker).walk(program);
+        file
+    }
+
+    /// Findings ordered by file and source position.
+    pub fn finish(mut self) -> Vec<ReturnFinding> {
+        self.findings
+            .sort_by_key(|f| (f.file(), f.span().start, f.span().end));
+        self.findings
+    }
+}
+
+struct Checker<'f> {
+    file: usize,
+    findings: &'f mut Vec<ReturnFinding>,
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Checker<'_> {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {
+        let Some(body) = unit.body else {
+            return;
+        };
+        let cfg = Cfg::build(body);
+        self.findings
+            .extend(
+                cfg.unreachable_stmts()
+                    .into_iter()
+                    .map(|stmt| ReturnFinding::Unreachable {
+                        file: self.file,
+                        span: stmt.span,
+                    }),
+            );
+
+        if matches!(unit.kind, UnitKind::Main | UnitKind::Hook) || !cfg.falls_through() {
+            return;
+        }
+        let Some(return_type) = unit.return_type else {
+            return;
+        };
+        let (file, function, span) = (self.file, unit.name.clone(), unit.span);
+        if return_type.is_keyword(BuiltinType::Never) {
+            self.findings.push(ReturnFinding::NeverReturns {
+                file,
+                function,
+                span,
+            });
+        } else if !return_type.is_keyword(BuiltinType::Void)
+            && !return_type.is_keyword(BuiltinType::Null)
+            && !is_generator(body)
+        {
+            self.findings.push(ReturnFinding::MissingReturn {
+                file,
+                function,
+                span,
+            });
+        }
+    }
+}
+
+/// `true` if `body` contains `yield` outside nested functions and classes.
+fn is_generator(body: &[Stmt<'_, '_>]) -> bool {
+    struct YieldFinder;
+
+    impl<'arena, 'src> Visitor<'arena, 'src> for YieldFinder {
+        fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+            match stmt.kind {
+                StmtKind::Function(_)
+                | StmtKind::Class(_)
+                | StmtKind::Interface(_)
+                | StmtKind::Trait(_)
+                | StmtKind::Enum(_) => ControlFlow::Continue(()),
+                _ => walk_stmt(self, stmt),
+            }
+        }
+
+        fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+            match expr.kind {
+                ExprKind::Yield(_) => ControlFlow::Break(()),
+                ExprKind::Closure(_) | ExprKind::ArrowFunction(_) | ExprKind::AnonymousClass(_) => {
+                    ControlFlow::Continue(())
+                }
+                _ => walk_expr(self, expr),
+            }
+        }
+    }
+
+    body.iter()
+        .any(|stmt| YieldFinder.visit_stmt(stmt).is_break())
+}
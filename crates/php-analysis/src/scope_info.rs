@@ -0,0 +1,331 @@
+//! Variables of a function and of the closures and arrow functions in it.
+//!
+//! [`ScopeInfo::analyze`] describes the variable scope of a function and,
+//! nested under it, of each closure and arrow function it contains: the
+//! variables it defines, reads that may come before any assignment (as
+//! [`definite_assignment`](crate::definite_assignment) reports them), the
+//! `use` imports of a closure that its body never mentions, and the
+//! variables an arrow function captures implicitly.
+//!
+//! ```
+//! use php_analysis::scope_info::{ScopeInfo, ScopeKind};
+//! use php_ast::StmtKind;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! function totals(array $rows, $rate) {
+//!     $sum = 0;
+//!     $label = 'total';
+//!     $add = function ($row) use (&$sum, $rate, $label) { $sum += $row * $rate; };
+//!     array_walk($rows, $add);
+//!     return array_map(fn($row) => $row * $rate + $offset, $rows);
+//! }";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let StmtKind::Function(func) = &result.program.stmts[0].kind else { panic!() };
+//! let info = ScopeInfo::analyze(func);
+//!
+//! assert_eq!(info.defined, ["rows", "rate", "sum", "label", "add"]);
+//! assert_eq!(info.undefined[0].name, "offset");
+//! let [closure, arrow] = &info.nested[..] else { panic!() };
+//! assert_eq!(closure.kind, ScopeKind::Closure);
+//! assert_eq!(closure.unused_uses[0].name, "label");
+//! assert_eq!(arrow.kind, ScopeKind::ArrowFunction);
+//! let captured: Vec<_> = arrow.captures.iter().map(|v| v.name.as_str()).collect();
+//! assert_eq!(captured, ["rate", "offset"]);
+//! ```
+
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{
+    ArrowFunctionExpr, ClosureExpr, Expr, ExprKind, FunctionDecl, MethodDecl, NameKind, Param,
+    Span, Stmt, StmtKind,
+};
+
+use crate::definite_assignment::{unit_assignments, UndefinedVariable};
+use crate::normalize::implicit_captures;
+use crate::walker::{Unit, UnitKind};
+
+/// What kind of body a [`ScopeInfo`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    Function,
+    Method,
+    Closure,
+    ArrowFunction,
+}
+
+/// A variable named at a position, such as a `use` import or the first use
+/// of an implicit capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopedVariable {
+    /// The variable name, without `$`.
+    pub name: String,
+    pub span: Span,
+}
+
+/// The variables of one function, method, closure, or arrow function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeInfo {
+    pub kind: ScopeKind,
+    /// The closure or arrow function expression; `None` for the function or
+    /// method analyzed.
+    pub span: Option<Span>,
+    /// Variables assigned on some path, in order of first mention.
+    /// Parameters and `use` imports come first; an arrow function defines
+    /// its parameters and captures.
+    pub defined: Vec<String>,
+    /// Reads of variables that may not be assigned yet, in source order.
+    /// An arrow function's reads are checked as captures, in the enclosing
+    /// scope.
+    pub undefined: Vec<UndefinedVariable>,
+    /// `use` imports of a closure that its body never mentions. Left empty
+    /// when the body can read variables by name, with `$$name`, `compact()`
+    /// of a computed name, `get_defined_vars()`, `eval`, or `include`.
+    pub unused_uses: Vec<ScopedVariable>,
+    /// Variables an arrow function captures from the enclosing scope, at
+    /// their first use.
+    pub captures: Vec<ScopedVariable>,
+    /// Closures and arrow functions directly inside this body, in source
+    /// order.
+    pub nested: Vec<ScopeInfo>,
+}
+
+impl ScopeInfo {
+    /// Analyze `func` and the closures and arrow functions in its body.
+    pub fn analyze(func: &FunctionDecl<'_, '_>) -> Self {
+        let unit = Unit {
+            kind: UnitKind::Function,
+            name: func.name.or_error().to_string(),
+            span: Span::DUMMY,
+            doc_comment: func.doc_comment.as_ref(),
+            params: &func.params,
+            uses: &[],
+            body: Some(&func.body),
+            return_type: func.return_type.as_ref(),
+        };
+        Self::of_unit(ScopeKind::Function, None, &unit)
+    }
+
+    /// Analyze `method` like [`analyze`](Self::analyze). An abstract method
+    /// defines its parameters only.
+    pub fn analyze_method(method: &MethodDecl<'_, '_>) -> Self {
+        let unit = Unit {
+            kind: UnitKind::Method,
+            name: method.name.or_error().to_string(),
+            span: Span::DUMMY,
+            doc_comment: method.doc_comment.as_ref(),
+            params: &method.params,
+            uses: &[],
+            body: method.body.as_deref(),
+            return_type: method.return_type.as_ref(),
+        };
+        Self::of_unit(ScopeKind::Method, None, &unit)
+    }
+
+    fn closure(span: Span, closure: &ClosureExpr<'_, '_>) -> Self {
+        let unit = Unit {
+            kind: UnitKind::Closure,
+            name: "{closure}".to_string(),
+            span,
+            doc_comment: None,
+            params: &closure.params,
+            uses: &closure.use_vars,
+            body: Some(&closure.body),
+            return_type: closure.return_type.as_ref(),
+        };
+        let mut info = Self::of_unit(ScopeKind::Closure, Some(span), &unit);
+        let mut mentions = Mentions::default();
+        for stmt in closure.body.iter() {
+            let _ = mentions.visit_stmt(stmt);
+        }
+        if !mentions.opaque {
+            info.unused_uses = closure
+                .use_vars
+                .iter()
+                .filter(|var| !mentions.names.contains(var.name))
+                .map(|var| ScopedVariable {
+                    name: var.name.to_string(),
+                    span: var.span,
+                })
+                .collect();
+        }
+        info
+    }
+
+    fn arrow_function(span: Span, arrow: &ArrowFunctionExpr<'_, '_>) -> Self {
+        let captures: Vec<ScopedVariable> = implicit_captures(arrow)
+            .iter()
+            .map(|var| ScopedVariable {
+                name: var.name.to_string(),
+                span: var.span,
+            })
+            .collect();
+        let mut defined = param_names(&arrow.params);
+        for capture in &captures {
+            if !defined.contains(&capture.name) {
+                defined.push(capture.name.clone());
+            }
+        }
+        let mut nested = Nested::default();
+        let _ = nested.visit_expr(arrow.body);
+        Self {
+            kind: ScopeKind::ArrowFunction,
+            span: Some(span),
+            defined,
+            undefined: Vec::new(),
+            unused_uses: Vec::new(),
+            captures,
+            nested: nested.scopes,
+        }
+    }
+
+    fn of_unit(kind: ScopeKind, span: Option<Span>, unit: &Unit<'_, '_, '_>) -> Self {
+        let (defined, undefined) = match unit_assignments(unit) {
+            Some(assignments) => (assignments.assigned, assignments.undefined),
+            None => (param_names(unit.params), Vec::new()),
+        };
+        let mut nested = Nested::default();
+        for stmt in unit.body.unwrap_or_default() {
+            let _ = nested.visit_stmt(stmt);
+        }
+        Self {
+            kind,
+            span,
+            defined,
+            undefined,
+            unused_uses: Vec::new(),
+            captures: Vec::new(),
+            nested: nested.scopes,
+        }
+    }
+}
+
+fn param_names(params: &[Param<'_, '_>]) -> Vec<String> {
+    params
+        .iter()
+        .filter_map(|param| param.name.as_str())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Declarations with a scope of their own, which do not see the enclosing
+/// function's variables.
+fn declares_scope(stmt: &Stmt<'_, '_>) -> bool {
+    matches!(
+        stmt.kind,
+        StmtKind::Function(_)
+            | StmtKind::Class(_)
+            | StmtKind::Interface(_)
+            | StmtKind::Trait(_)
+            | StmtKind::Enum(_)
+    )
+}
+
+/// The closures and arrow functions of a body, not descending into them.
+#[derive(Default)]
+struct Nested {
+    scopes: Vec<ScopeInfo>,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Nested {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        if declares_scope(stmt) {
+            return ControlFlow::Continue(());
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::Closure(closure) => {
+                self.scopes.push(ScopeInfo::closure(expr.span, closure));
+                ControlFlow::Continue(())
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                self.scopes
+                    .push(ScopeInfo::arrow_function(expr.span, arrow));
+                ControlFlow::Continue(())
+            }
+            ExprKind::AnonymousClass(_) => ControlFlow::Continue(()),
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
+/// The variable names a closure body mentions, including those it passes
+/// on to nested closures and arrow functions.
+#[derive(Default)]
+struct Mentions {
+    names: HashSet<String>,
+    /// Whether the body can reach variables by a computed name.
+    opaque: bool,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Mentions {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        if declares_scope(stmt) {
+            return ControlFlow::Continue(());
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        match &expr.kind {
+            ExprKind::Variable(name) => {
+                self.names.insert(name.to_string());
+            }
+            ExprKind::Closure(closure) => {
+                self.names
+                    .extend(closure.use_vars.iter().map(|var| var.name.to_string()));
+                return ControlFlow::Continue(());
+            }
+            ExprKind::AnonymousClass(_) => return ControlFlow::Continue(()),
+            ExprKind::VariableVariable(_) | ExprKind::Eval(_) | ExprKind::Include(..) => {
+                self.opaque = true;
+            }
+            ExprKind::FunctionCall(call) => {
+                let global = match &call.name.kind {
+                    ExprKind::Name(name)
+                        if matches!(
+                            name.kind(),
+                            NameKind::Unqualified | NameKind::FullyQualified
+                        ) =>
+                    {
+                        name.join_parts().to_ascii_lowercase()
+                    }
+                    _ => String::new(),
+                };
+                match global.as_str() {
+                    "get_defined_vars" => self.opaque = true,
+                    "compact" => {
+                        for arg in call.args.iter() {
+                            self.compact(&arg.value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+impl Mentions {
+    /// Record the names `compact()` reads from an argument.
+    fn compact(&mut self, arg: &Expr<'_, '_>) {
+        match &arg.kind {
+            ExprKind::String { value: name, .. } => {
+                self.names.insert(name.to_string());
+            }
+            ExprKind::Array(elements) => {
+                for element in elements.iter() {
+                    self.compact(&element.value);
+                }
+            }
+            _ => self.opaque = true,
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Every string literal in a file, with the syntax around it.
+//!
+//! [`string_literals`] lists single- and double-quoted strings, nowdocs, and
+//! heredocs without interpolation, each with its decoded value, its source
+//! text, and a [`StringContext`] naming what it is used as: an array key, an
+//! argument of a named call, echoed output, a concatenation operand, and so
+//! on. Translation extractors look for the first argument of `__()` or
+//! `->trans()`; secret scanners look at constant initializers and
+//! comparisons. Strings with interpolated variables are not literals and
+//! are left out.
+//!
+//! ```
+//! use php_analysis::string_literals::{string_literals, StringContext};
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php echo __('Welcome back, %s', $name) . '!';";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let found: Vec<_> = string_literals(&result.program, src)
+//!     .into_iter()
+//!     .map(|s| (s.value, s.context))
+//!     .collect();
+//! assert_eq!(found, [
+//!     (
+//!         "Welcome back, %s".to_string(),
+//!         StringContext::Argument { callee: Some("__".to_string()), index: 0, name: None },
+//!     ),
+//!     ("!".to_string(), StringContext::Concat),
+//! ]);
+//! ```
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{
+    walk_class_member, walk_enum_member, walk_expr, walk_param, walk_stmt, Visitor,
+};
+use php_ast::*;
+
+/// How a string literal is quoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringKind {
+    /// `'…'`
+    SingleQuoted,
+    /// `"…"`
+    DoubleQuoted,
+    /// `<<<EOT` or `<<<"EOT"`
+    Heredoc,
+    /// `<<<'EOT'`
+    Nowdoc,
+}
+
+/// What a string literal is used as.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringContext {
+    /// A key in an array literal: `['key' => $v]`.
+    ArrayKey,
+    /// A value in an array literal: `['value']`.
+    ArrayValue,
+    /// An offset: `$row['id']`.
+    Offset,
+    /// An argument of a call. `callee` is the function as written (`__`,
+    /// `App\t`), `->method`, `Class::method`, or `new Class`; `None` when
+    /// it is computed. `index` counts from 0 in source order; `name` is
+    /// set for a named argument.
+    Argument {
+        callee: Option<String>,
+        index: usize,
+        name: Option<String>,
+    },
+    /// Output of `echo`, `print`, or `<?=`.
+    Echo,
+    /// An operand of `.` or the value of `.=`.
+    Concat,
+    /// An operand of `==`, `===`, `!=`, `!==`, `<`, `<=`, `>`, `>=`, or
+    /// `<=>`.
+    Comparison,
+    /// The value of `=`, `??=`, or another assignment besides `.=`.
+    Assignment,
+    /// The value of `return`.
+    Return,
+    /// The initializer of the constant, class constant, enum case, property,
+    /// parameter, or static variable `name`.
+    Initializer { name: String },
+    /// Any other position.
+    Other,
+}
+
+/// A string literal found by [`string_literals`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringLiteral<'src> {
+    /// The string's value, with escapes decoded.
+    pub value: String,
+    /// The literal as written, quotes included.
+    pub raw: &'src str,
+    pub span: Span,
+    pub kind: StringKind,
+    pub context: StringContext,
+}
+
+/// List the string literals of `program`, parsed from `source`, in source
+/// order.
+pub fn string_literals<'src>(
+    program: &Program<'_, '_>,
+    source: &'src str,
+) -> Vec<StringLiteral<'src>> {
+    let mut collector = Collector {
+        source,
+        contexts: HashMap::new(),
+        literals: Vec::new(),
+    };
+    let _ = collector.visit_program(program);
+    collector.literals.sort_by_key(|s| s.span);
+    collector.literals
+}
+
+struct Collector<'src> {
+    source: &'src str,
+    /// Contexts set by a parent for the children it is about to walk.
+    contexts: HashMap<Span, StringContext>,
+    literals: Vec<StringLiteral<'src>>,
+}
+
+impl Collector<'_> {
+    fn set(&mut self, expr: &Expr<'_, '_>, context: StringContext) {
+        self.contexts.insert(expr.span, context);
+    }
+
+    fn arguments(&mut self, callee: Option<String>, args: &[Arg<'_, '_>]) {
+        for (index, arg) in args.iter().enumerate() {
+            let context = StringContext::Argument {
+                callee: callee.clone(),
+                index,
+                name: arg.name.as_ref().map(|n| n.to_string_repr().into_owned()),
+            };
+            self.set(&arg.value, context);
+        }
+    }
+
+    fn initializer(&mut self, name: &str, value: Option<&Expr<'_, '_>>) {
+        if let Some(value) = value {
+            self.set(
+                value,
+                StringContext::Initializer {
+                    name: name.to_string(),
+                },
+            );
+        }
+    }
+
+    fn record(&mut self, expr: &Expr<'_, '_>, value: String, context: StringContext) {
+        let raw = self
+            .source
+            .get(expr.span.start as usize..expr.span.end as usize)
+            .unwrap_or_default();
+        let quote = raw.trim_start_matches(['b', 'B']);
+        let kind = if quote.starts_with('\'') {
+            StringKind::SingleQuoted
+        } else if quote.starts_with('"') {
+            StringKind::DoubleQuoted
+        } else if quote.trim_start_matches('<').trim_start().starts_with('\'') {
+            StringKind::Nowdoc
+        } else {
+            StringKind::Heredoc
+        };
+        self.literals.push(StringLiteral {
+            value,
+            raw,
+            span: expr.span,
+            kind,
+            context,
+        });
+    }
+}
+
+/// The name of a call's callee as written, or `None` when it is computed.
+fn callee_name(expr: &Expr<'_, '_>) -> Option<String> {
+    match &expr.kind {
+        ExprKind::Name(name) => Some(name.to_string_repr().into_owned()),
+        ExprKind::Identifier(name) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+fn class_name(class: &ClassRef<'_, '_>) -> Option<String> {
+    match class {
+        ClassRef::Name(name) => Some(name.to_string_repr().into_owned()),
+        ClassRef::SelfKw(_) => Some("self".to_string()),
+        ClassRef::ParentKw(_) => Some("parent".to_string()),
+        ClassRef::StaticKw(_) => Some("static".to_string()),
+        ClassRef::Expr(_) => None,
+    }
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        match &stmt.kind {
+            StmtKind::Echo(echo) => {
+                for expr in echo.exprs.iter() {
+                    self.set(expr, StringContext::Echo);
+                }
+            }
+            StmtKind::Return(Some(value)) => self.set(value, StringContext::Return),
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    self.initializer(item.name.or_error(), Some(&item.value));
+                }
+            }
+            StmtKind::StaticVar(vars) => {
+                for var in vars.iter() {
+                    self.initializer(var.name.or_error(), var.default.as_ref());
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        let context = self
+            .contexts
+            .remove(&expr.span)
+            .unwrap_or(StringContext::Other);
+        match &expr.kind {
+            ExprKind::String { value, .. } => self.record(expr, value.to_string(), context),
+            ExprKind::Nowdoc { value, .. } => self.record(expr, value.to_string(), context),
+            ExprKind::Heredoc { parts, .. } => {
+                let mut value = String::new();
+                let literal = parts.iter().all(|part| match part {
+                    StringPart::Literal { value: text, .. } => {
+                        value.push_str(text);
+                        true
+                    }
+                    StringPart::Expr(_) => false,
+                });
+                if literal {
+                    self.record(expr, value, context);
+                }
+            }
+            // Parentheses do not change what the inner expression is used as.
+            ExprKind::Parenthesized(inner) => self.set(inner, context),
+            ExprKind::Array(elements) => {
+                for element in elements.iter() {
+                    if let Some(key) = &element.key {
+                        self.set(key, StringContext::ArrayKey);
+                    }
+                    self.set(&element.value, StringContext::ArrayValue);
+                }
+            }
+            ExprKind::ArrayAccess(access) => {
+                if let Some(index) = access.index {
+                    self.set(index, StringContext::Offset);
+                }
+            }
+            ExprKind::FunctionCall(call) => self.arguments(callee_name(call.name), &call.args),
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let callee = callee_name(call.method).map(|m| format!("->{m}"));
+                self.arguments(callee, &call.args);
+            }
+            ExprKind::StaticMethodCall(call) => {
+                let callee = class_name(&call.class)
+                    .zip(callee_name(call.method))
+                    .map(|(class, method)| format!("{class}::{method}"));
+                self.arguments(callee, &call.args);
+            }
+            ExprKind::StaticDynMethodCall(call) => self.arguments(None, &call.args),
+            ExprKind::New(new) => {
+                let callee = class_name(&new.class).map(|class| format!("new {class}"));
+                self.arguments(callee, &new.args);
+            }
+            ExprKind::Print(print) => self.set(print.expr, StringContext::Echo),
+            ExprKind::Binary(binary) => {
+                let context = match binary.op {
+                    BinaryOp::Concat => Some(StringContext::Concat),
+                    BinaryOp::Equal
+                    | BinaryOp::NotEqual
+                    | BinaryOp::Identical
+                    | BinaryOp::NotIdentical
+                    | BinaryOp::Less
+                    | BinaryOp::Greater
+                    | BinaryOp::LessOrEqual
+                    | BinaryOp::GreaterOrEqual
+                    | BinaryOp::Spaceship => Some(StringContext::Comparison),
+                    _ => None,
+                };
+                if let Some(context) = context {
+                    self.set(binary.left, context.clone());
+                    self.set(binary.right, context);
+                }
+            }
+            ExprKind::Assign(assign) => {
+                let context = match assign.op {
+                    AssignOp::Concat => StringContext::Concat,
+                    _ => StringContext::Assignment,
+                };
+                self.set(assign.value, context);
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_param(&mut self, param: &Param<'arena, 'src>) -> ControlFlow<()> {
+        self.initializer(param.name.or_error(), param.default.as_ref());
+        walk_param(self, param)
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember<'arena, 'src>) -> ControlFlow<()> {
+        match &member.kind {
+            ClassMemberKind::Property(prop) => {
+                self.initializer(prop.name.or_error(), prop.default.as_ref());
+            }
+            ClassMemberKind::ClassConst(constant) => {
+                self.initializer(constant.name.or_error(), Some(&constant.value));
+            }
+            _ => {}
+        }
+        walk_class_member(self, member)
+    }
+
+    fn visit_enum_member(&mut self, member: &EnumMember<'arena, 'src>) -> ControlFlow<()> {
+        if let EnumMemberKind::Case(case) = &member.kind {
+            self.initializer(case.name.or_error(), case.value.as_ref());
+        }
+        walk_enum_member(self, member)
+    }
+}
@@ -0,0 +1,151 @@
+//! Inventory of `@` error suppression.
+//!
+//! Each `@` is listed with the span of the operator itself, the expression
+//! it silences, the statement it appears in, and what kind of operation is
+//! being silenced. Audits can group the results by statement or by target;
+//! fixers can delete exactly the operator (see
+//! [`crate::codemod::error_suppression`]).
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use php_ast::{Expr, ExprKind, Program, Span, Stmt};
+
+/// The operation an `@` silences.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SuppressedTarget {
+    /// A call to a function named in the source, e.g. `@fopen(...)`.
+    /// Holds the name as written, without a leading `\`.
+    Function(String),
+    /// A call through a computed callee, e.g. `@$f()`.
+    DynamicCall,
+    /// An instance or static method call, e.g. `@$db->query(...)`. Holds the
+    /// method name when it is written as an identifier.
+    Method(Option<String>),
+    /// `new` of a class.
+    New,
+    /// `include`, `include_once`, `require`, or `require_once`.
+    Include,
+    /// An instance or static property fetch, e.g. `@$obj->prop`.
+    PropertyAccess,
+    /// An array or string offset read, e.g. `@$arr['key']`.
+    ArrayAccess,
+    /// A plain variable read, e.g. `@$undefined`.
+    Variable,
+    /// Anything else.
+    Other,
+}
+
+impl std::fmt::Display for SuppressedTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuppressedTarget::Function(name) => write!(f, "call to {name}()"),
+            SuppressedTarget::DynamicCall => f.write_str("dynamic call"),
+            SuppressedTarget::Method(Some(name)) => write!(f, "method call {name}()"),
+            SuppressedTarget::Method(None) => f.write_str("dynamic method call"),
+            SuppressedTarget::New => f.write_str("object creation"),
+            SuppressedTarget::Include => f.write_str("include"),
+            SuppressedTarget::PropertyAccess => f.write_str("property access"),
+            SuppressedTarget::ArrayAccess => f.write_str("array access"),
+            SuppressedTarget::Variable => f.write_str("variable read"),
+            SuppressedTarget::Other => f.write_str("expression"),
+        }
+    }
+}
+
+/// One `@` operator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    /// The `@` itself.
+    pub operator_span: Span,
+    /// The silenced expression, without the `@`.
+    pub expr_span: Span,
+    /// The innermost statement containing the `@`.
+    pub stmt_span: Span,
+    /// What the `@` silences. Assignments and nested `@` are looked through:
+    /// the target of `@$x = file($path)` is the call to `file()`.
+    pub target: SuppressedTarget,
+}
+
+/// List every `@` in `program`, in source order.
+///
+/// ```
+/// use php_analysis::suppressions::{suppressions, SuppressedTarget};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php $h = @fopen($path, 'r'); echo @$arr['k'];";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let found = suppressions(&result.program);
+/// assert_eq!(found[0].target, SuppressedTarget::Function("fopen".into()));
+/// assert_eq!(found[0].operator_span.to_range(), 11..12);
+/// assert_eq!(found[1].target, SuppressedTarget::ArrayAccess);
+/// ```
+pub fn suppressions(program: &Program<'_, '_>) -> Vec<Suppression> {
+    let mut collector = Collector {
+        stmts: Vec::new(),
+        found: Vec::new(),
+    };
+    let _ = collector.visit_program(program);
+    collector.found.sort_by_key(|s| s.operator_span.start);
+    collector.found
+}
+
+struct Collector {
+    /// Spans of the statements enclosing the current node, innermost last.
+    stmts: Vec<Span>,
+    found: Vec<Suppression>,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        self.stmts.push(stmt.span);
+        let result = walk_stmt(self, stmt);
+        self.stmts.pop();
+        result
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        if let ExprKind::ErrorSuppress(suppress) = &expr.kind {
+            self.found.push(Suppression {
+                operator_span: suppress.operator_span,
+                expr_span: suppress.expr.span,
+                stmt_span: self.stmts.last().copied().unwrap_or(expr.span),
+                target: target(suppress.expr),
+            });
+        }
+        walk_expr(self, expr)
+    }
+}
+
+fn target(expr: &Expr<'_, '_>) -> SuppressedTarget {
+    match &expr.kind {
+        ExprKind::Parenthesized(inner) => target(inner),
+        ExprKind::Assign(assign) => target(assign.value),
+        ExprKind::ErrorSuppress(suppress) => target(suppress.expr),
+        ExprKind::FunctionCall(call) => match &call.name.kind {
+            ExprKind::Name(name) => SuppressedTarget::Function(name.join_parts().into_owned()),
+            _ => SuppressedTarget::DynamicCall,
+        },
+        ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+            SuppressedTarget::Method(identifier(call.method))
+        }
+        ExprKind::StaticMethodCall(call) => SuppressedTarget::Method(identifier(call.method)),
+        ExprKind::StaticDynMethodCall(_) => SuppressedTarget::Method(None),
+        ExprKind::New(_) => SuppressedTarget::New,
+        ExprKind::Include(..) => SuppressedTarget::Include,
+        ExprKind::PropertyAccess(_)
+        | ExprKind::NullsafePropertyAccess(_)
+        | ExprKind::StaticPropertyAccess(_)
+        | ExprKind::StaticPropertyAccessDynamic { .. } => SuppressedTarget::PropertyAccess,
+        ExprKind::ArrayAccess(_) => SuppressedTarget::ArrayAccess,
+        ExprKind::Variable(_) | ExprKind::VariableVariable(_) => SuppressedTarget::Variable,
+        _ => SuppressedTarget::Other,
+    }
+}
+
+fn identifier(member: &Expr<'_, '_>) -> Option<String> {
+    match &member.kind {
+        ExprKind::Identifier(name) => Some(name.as_str().to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,389 @@
+//! Project-wide symbol index with fuzzy search, for "go to symbol in
+//! workspace".
+//!
+//! [`SymbolIndex`] records every class, interface, trait, enum, function,
+//! and `const` declared in the files added to it, and the methods,
+//! properties, class constants, and enum cases of the class-likes, with
+//! their fully-qualified names. Anonymous classes and `define()`d constants
+//! are not indexed.
+//!
+//! Files belong to source roots: path prefixes with a priority, so that a
+//! project's own code ranks above its `vendor/` directory. A file is in the
+//! root with the longest prefix of its path; a file in no root has
+//! priority 0. Re-adding a path replaces the symbols indexed for it, so the
+//! index can follow edits.
+//!
+//! [`SymbolIndex::workspace_symbols`] ranks the symbols matching a query:
+//! exact names first, then prefixes, substrings, and finally names holding
+//! the query's characters in order (`usrctl` finds `UserController`), with
+//! matches at word starts preferred. A query with a `\` is matched against
+//! fully-qualified names. Equal matches are ordered by root priority, then
+//! by shorter name.
+
+use std::collections::HashMap;
+
+use php_ast::{
+    ClassMember, ClassMemberKind, EnumMember, EnumMemberKind, Program, Span, Stmt, StmtKind,
+};
+
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker};
+
+/// What a [`Symbol`] declares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    Function,
+    /// A global constant declared with `const`.
+    Constant,
+    Method,
+    Property,
+    ClassConstant,
+    EnumCase,
+}
+
+/// A declaration in the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// The declared name: `User`, `save`, `name` for `$name`.
+    pub name: String,
+    /// Fully-qualified name: `App\User`, `App\User::save`, `App\User::$name`.
+    pub fqn: String,
+    pub kind: SymbolKind,
+    /// Fully-qualified name of the class-like declaring a member.
+    pub container: Option<String>,
+    pub file: usize,
+    /// The declaration.
+    pub span: Span,
+}
+
+/// A symbol matching a [`SymbolIndex::workspace_symbols`] query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolMatch<'a> {
+    pub symbol: &'a Symbol,
+    /// The path the symbol's file was added under.
+    pub path: &'a str,
+    /// Priority of the root the file is in.
+    pub priority: i32,
+    /// How well the name matches; higher is better.
+    pub score: u32,
+}
+
+#[derive(Debug)]
+struct IndexedFile {
+    path: String,
+    symbols: Vec<Symbol>,
+}
+
+/// Declarations across the files of a project, in prioritized roots.
+///
+/// ```
+/// use php_analysis::symbols::{SymbolIndex, SymbolKind};
+///
+/// let mut index = SymbolIndex::new();
+/// index.add_root("/app/", 10);
+/// index.add_root("/app/vendor/", 0);
+///
+/// let arena = bumpalo::Bump::new();
+/// let vendor = php_rs_parser::parse(&arena, "<?php namespace Lib; class UserCache {}");
+/// index.add_file("/app/vendor/lib/UserCache.php", &vendor.program);
+/// let app = php_rs_parser::parse(&arena, "<?php namespace App;
+/// class UserController { public function show() {} }");
+/// index.add_file("/app/src/UserController.php", &app.program);
+///
+/// let names: Vec<&str> = index
+///     .workspace_symbols("user")
+///     .iter()
+///     .map(|m| m.symbol.fqn.as_str())
+///     .collect();
+/// assert_eq!(names, ["App\\UserController", "Lib\\UserCache"]);
+///
+/// let found = index.workspace_symbols("usrctl");
+/// assert_eq!(found[0].symbol.kind, SymbolKind::Class);
+/// assert_eq!(index.workspace_symbols("App\\UserController::show")[0].symbol.name, "show");
+/// ```
+#[derive(Debug, Default)]
+pub struct SymbolIndex {
+    /// Path prefixes with their priorities.
+    roots: Vec<(String, i32)>,
+    files: Vec<IndexedFile>,
+    by_path: HashMap<String, usize>,
+}
+
+impl SymbolIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a source root: files whose path starts with `prefix` get
+    /// `priority` unless a longer root prefix also matches. Higher
+    /// priorities rank first.
+    pub fn add_root(&mut self, prefix: impl Into<String>, priority: i32) {
+        self.roots.push((prefix.into(), priority));
+    }
+
+    /// Index the declarations of `program`, parsed from the file at `path`,
+    /// replacing any indexed earlier for the same path. Returns its file
+    /// index.
+    pub fn add_file(&mut self, path: impl Into<String>, program: &Program<'_, '_>) -> usize {
+        let path = path.into();
+        let file = match self.by_path.get(&path) {
+            Some(&file) => file,
+            None => {
+                self.files.push(IndexedFile {
+                    path: path.clone(),
+                    symbols: Vec::new(),
+                });
+                self.by_path.insert(path, self.files.len() - 1);
+                self.files.len() - 1
+            }
+        };
+        let mut collector = Collector {
+            file,
+            symbols: Vec::new(),
+        };
+        AnalysisWalker::new(&mut collector).walk(program);
+        self.files[file].symbols = collector.symbols;
+        file
+    }
+
+    /// Drop the symbols of the file at `path`. Its file index stays taken.
+    pub fn remove_file(&mut self, path: &str) {
+        if let Some(&file) = self.by_path.get(path) {
+            self.files[file].symbols.clear();
+        }
+    }
+
+    /// The path a file was added under.
+    pub fn path(&self, file: usize) -> &str {
+        &self.files[file].path
+    }
+
+    /// Every indexed symbol, by file and declaration order.
+    pub fn symbols(&self) -> impl Iterator<Item = &Symbol> {
+        self.files.iter().flat_map(|file| &file.symbols)
+    }
+
+    /// The priority of the root `path` is in.
+    pub fn priority(&self, path: &str) -> i32 {
+        self.roots
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(0, |&(_, priority)| priority)
+    }
+
+    /// The symbols matching `query`, best first. An empty query matches
+    /// every symbol.
+    pub fn workspace_symbols(&self, query: &str) -> Vec<SymbolMatch<'_>> {
+        let qualified = query.contains('\\');
+        let query: Vec<char> = query
+            .trim_start_matches('\\')
+            .chars()
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let mut matches = Vec::new();
+        for file in &self.files {
+            let priority = self.priority(&file.path);
+            for symbol in &file.symbols {
+                let candidate = if qualified { &symbol.fqn } else { &symbol.name };
+                if let Some(score) = fuzzy_score(&query, candidate) {
+                    matches.push(SymbolMatch {
+                        symbol,
+                        path: &file.path,
+                        priority,
+                        score,
+                    });
+                }
+            }
+        }
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(b.priority.cmp(&a.priority))
+                .then(a.symbol.name.len().cmp(&b.symbol.name.len()))
+                .then_with(|| a.symbol.fqn.cmp(&b.symbol.fqn))
+                .then_with(|| a.path.cmp(b.path))
+        });
+        matches
+    }
+}
+
+/// Score `candidate` against a lower-cased `query`, or `None` if it does
+/// not hold the query's characters in order.
+fn fuzzy_score(query: &[char], candidate: &str) -> Option<u32> {
+    const EXACT: u32 = 4000;
+    const PREFIX: u32 = 3000;
+    const SUBSTRING: u32 = 2000;
+    const SUBSEQUENCE: u32 = 1000;
+
+    if query.is_empty() {
+        return Some(0);
+    }
+    let chars: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    if lower == query {
+        return Some(EXACT);
+    }
+    if lower.starts_with(query) {
+        return Some(PREFIX);
+    }
+    if let Some(at) = lower.windows(query.len()).position(|w| w == query) {
+        let bonus = if is_word_start(&chars, at) { 500 } else { 0 };
+        return Some(SUBSTRING + bonus);
+    }
+
+    // Match each query character, preferring a later word start to the
+    // next occurrence as long as the rest of the query still fits after it.
+    let mut at = 0;
+    let mut word_starts = 0u32;
+    let mut first = None;
+    for (q, &c) in query.iter().enumerate() {
+        let next = (at..lower.len()).find(|&i| lower[i] == c)?;
+        let start = (next..lower.len()).find(|&i| {
+            lower[i] == c
+                && is_word_start(&chars, i)
+                && is_subsequence(&query[q + 1..], &lower[i + 1..])
+        });
+        let i = start.unwrap_or(next);
+        if is_word_start(&chars, i) {
+            word_starts += 1;
+        }
+        first.get_or_insert(i);
+        at = i + 1;
+    }
+    let span = (at - first.unwrap_or(0)) as u32;
+    let gaps = span - query.len() as u32;
+    Some(SUBSEQUENCE + (100 * word_starts).min(900) - gaps.min(99))
+}
+
+fn is_subsequence(query: &[char], candidate: &[char]) -> bool {
+    let mut rest = candidate.iter();
+    query.iter().all(|c| rest.any(|d| d == c))
+}
+
+/// `true` at the first character of a name, after `_`, `\`, or `:`, and at
+/// an upper-case letter after a lower-case one or a digit.
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    let Some(previous) = i.checked_sub(1).map(|p| chars[p]) else {
+        return true;
+    };
+    matches!(previous, '_' | '\\' | ':' | '$')
+        || (chars[i].is_ascii_uppercase()
+            && (previous.is_ascii_lowercase() || previous.is_ascii_digit()))
+}
+
+struct Collector {
+    file: usize,
+    symbols: Vec<Symbol>,
+}
+
+impl Collector {
+    fn add(
+        &mut self,
+        name: &str,
+        fqn: String,
+        kind: SymbolKind,
+        container: Option<&str>,
+        span: Span,
+    ) {
+        self.symbols.push(Symbol {
+            name: name.to_string(),
+            fqn,
+            kind,
+            container: container.map(str::to_string),
+            file: self.file,
+            span,
+        });
+    }
+
+    fn class_members(&mut self, class: &str, members: &[ClassMember<'_, '_>]) {
+        for member in members {
+            match &member.kind {
+                ClassMemberKind::Property(prop) => {
+                    if let Some(name) = prop.name.as_str() {
+                        let name = name.trim_start_matches('$');
+                        let fqn = format!("{class}::${name}");
+                        self.add(name, fqn, SymbolKind::Property, Some(class), member.span);
+                    }
+                }
+                ClassMemberKind::Method(method) => {
+                    if let Some(name) = method.name.as_str() {
+                        self.method(class, name, member.span);
+                    }
+                }
+                ClassMemberKind::ClassConst(constant) => {
+                    if let Some(name) = constant.name.as_str() {
+                        let fqn = format!("{class}::{name}");
+                        self.add(
+                            name,
+                            fqn,
+                            SymbolKind::ClassConstant,
+                            Some(class),
+                            member.span,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn enum_members(&mut self, class: &str, members: &[EnumMember<'_, '_>]) {
+        for member in members {
+            let (name, kind) = match &member.kind {
+                EnumMemberKind::Case(case) => (case.name.as_str(), SymbolKind::EnumCase),
+                EnumMemberKind::Method(method) => (method.name.as_str(), SymbolKind::Method),
+                EnumMemberKind::ClassConst(constant) => {
+                    (constant.name.as_str(), SymbolKind::ClassConstant)
+                }
+                _ => continue,
+            };
+            if let Some(name) = name {
+                let fqn = format!("{class}::{name}");
+                self.add(name, fqn, kind, Some(class), member.span);
+            }
+        }
+    }
+
+    fn method(&mut self, class: &str, name: &str, span: Span) {
+        let fqn = format!("{class}::{name}");
+        self.add(name, fqn, SymbolKind::Method, Some(class), span);
+    }
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        let (name, kind) = match &stmt.kind {
+            StmtKind::Class(decl) => (decl.name.and_then(|n| n.as_str()), SymbolKind::Class),
+            StmtKind::Interface(decl) => (decl.name.as_str(), SymbolKind::Interface),
+            StmtKind::Trait(decl) => (decl.name.as_str(), SymbolKind::Trait),
+            StmtKind::Enum(decl) => (decl.name.as_str(), SymbolKind::Enum),
+            StmtKind::Function(decl) => (decl.name.as_str(), SymbolKind::Function),
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    if let Some(name) = item.name.as_str() {
+                        let fqn = scope.names.qualify(name);
+                        self.add(name, fqn, SymbolKind::Constant, None, item.span);
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+        let Some(name) = name else {
+            return;
+        };
+        let fqn = scope.names.qualify(name);
+        self.add(name, fqn.clone(), kind, None, stmt.span);
+        match &stmt.kind {
+            StmtKind::Class(decl) => self.class_members(&fqn, &decl.members),
+            StmtKind::Interface(decl) => self.class_members(&fqn, &decl.members),
+            StmtKind::Trait(decl) => self.class_members(&fqn, &decl.members),
+            StmtKind::Enum(decl) => self.enum_members(&fqn, &decl.members),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,1103 @@
+//! Values of PHP constant expressions.
+//!
+//! [`PhpValue`] is what a constant expression evaluates to: `null`, a
+//! scalar, or an array of them. [`evaluate`] folds an expression to its
+//! value with PHP 8 semantics — integer overflow to float, string-to-number
+//! juggling, `.` on floats, and `==`/`<=>` across types — and gives up on
+//! anything that needs run-time state or would throw, such as division by
+//! zero or arithmetic on a non-numeric string. [`evaluate_with`] also
+//! resolves constants through a caller-supplied lookup, and
+//! [`initializers`] evaluates the initializers of a file:
+//!
+//! ```php
+//! const MINUTE = 60;
+//! const HOUR = 60 * MINUTE;                          // 3600
+//! class Cache { const TTL = [self::SHORT => '5m', 'long' => HOUR]; const SHORT = 'short'; }
+//! function retry(int $times = PHP_INT_MAX >> 60) {}  // 7
+//! ```
+//!
+//! The conversions and comparisons on [`PhpValue`], and the operators
+//! [`binary_op`], [`unary_op`], and [`cast`], follow PHP and are shared by
+//! lints that reason about type juggling and by the `php-eval` interpreter.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+use php_ast::{
+    ArrayElement, BinaryOp, CastKind, ClassMember, ClassMemberKind, ClassRef, EnumMemberKind, Expr,
+    ExprKind, MagicConstKind, Program, Span, Stmt, StmtKind, StringPart, UnaryPrefixOp,
+};
+use php_rs_parser::bytes::encode;
+
+use crate::constants::key;
+use crate::walker::{AnalysisScope, AnalysisVisitor, AnalysisWalker, Unit};
+
+/// A PHP value known at parse time.
+///
+/// `==` on `PhpValue` is PHP's `===`: same type, same value, and for arrays
+/// the same entries in the same order. PHP's `==` is [`loose_eq`](Self::loose_eq).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhpValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    /// A byte string; PHP strings need not be UTF-8.
+    String(Vec<u8>),
+    /// Entries in insertion order, with distinct keys.
+    Array(Vec<(ArrayKey, PhpValue)>),
+}
+
+/// An array key. PHP stores decimal integer strings such as `'7'` as
+/// integer keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArrayKey {
+    Int(i64),
+    String(Vec<u8>),
+}
+
+impl ArrayKey {
+    /// The key PHP uses for `value`: `null` is `''`, booleans and floats are
+    /// integers, and decimal integer strings are integers. `None` for
+    /// arrays and for floats without an integer value.
+    pub fn from_value(value: &PhpValue) -> Option<ArrayKey> {
+        Some(match value {
+            PhpValue::Null => ArrayKey::String(Vec::new()),
+            PhpValue::Bool(b) => ArrayKey::Int(i64::from(*b)),
+            PhpValue::Int(n) => ArrayKey::Int(*n),
+            PhpValue::Float(f) => ArrayKey::Int(float_to_int(*f)?),
+            PhpValue::String(s) => match integer_key(s) {
+                Some(n) => ArrayKey::Int(n),
+                None => ArrayKey::String(s.clone()),
+            },
+            PhpValue::Array(_) => return None,
+        })
+    }
+
+    pub fn to_value(&self) -> PhpValue {
+        match self {
+            ArrayKey::Int(n) => PhpValue::Int(*n),
+            ArrayKey::String(s) => PhpValue::String(s.clone()),
+        }
+    }
+}
+
+impl PhpValue {
+    /// The type as `get_debug_type()` names it.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PhpValue::Null => "null",
+            PhpValue::Bool(_) => "bool",
+            PhpValue::Int(_) => "int",
+            PhpValue::Float(_) => "float",
+            PhpValue::String(_) => "string",
+            PhpValue::Array(_) => "array",
+        }
+    }
+
+    /// `(bool) $value`.
+    pub fn to_bool(&self) -> bool {
+        match self {
+            PhpValue::Null => false,
+            PhpValue::Bool(b) => *b,
+            PhpValue::Int(n) => *n != 0,
+            PhpValue::Float(f) => *f != 0.0,
+            PhpValue::String(s) => !(s.is_empty() || s == b"0"),
+            PhpValue::Array(entries) => !entries.is_empty(),
+        }
+    }
+
+    /// `(int) $value`. `None` for a float, or a string holding one, that is
+    /// out of range or not a number.
+    pub fn to_int(&self) -> Option<i64> {
+        match self {
+            PhpValue::String(s) => parse_numeric(s).map_or(Some(0), |(n, _)| n.to_int()),
+            PhpValue::Array(entries) => Some(i64::from(!entries.is_empty())),
+            _ => self.to_number()?.to_int(),
+        }
+    }
+
+    /// `(float) $value`.
+    pub fn to_float(&self) -> f64 {
+        match self {
+            PhpValue::String(s) => parse_numeric(s).map_or(0.0, |(n, _)| n.to_float()),
+            PhpValue::Array(entries) => f64::from(u8::from(!entries.is_empty())),
+            _ => self.to_number().map_or(0.0, Number::to_float),
+        }
+    }
+
+    /// `(string) $value`. `None` for arrays, which convert to `'Array'` with
+    /// a warning.
+    pub fn to_php_string(&self) -> Option<Vec<u8>> {
+        Some(match self {
+            PhpValue::Null | PhpValue::Bool(false) => Vec::new(),
+            PhpValue::Bool(true) => b"1".to_vec(),
+            PhpValue::Int(n) => n.to_string().into_bytes(),
+            PhpValue::Float(f) => format_float(*f, Some(14)).into_bytes(),
+            PhpValue::String(s) => s.clone(),
+            PhpValue::Array(_) => return None,
+        })
+    }
+
+    /// PHP's `==`.
+    ///
+    /// ```
+    /// use php_analysis::values::PhpValue;
+    ///
+    /// let s = |s: &str| PhpValue::String(s.into());
+    /// assert!(s("1e1").loose_eq(&s("10")));
+    /// assert!(PhpValue::Null.loose_eq(&s("")));
+    /// assert!(!PhpValue::Int(0).loose_eq(&s("a")));
+    /// assert!(PhpValue::Int(100).loose_eq(&s(" 100 ")));
+    /// ```
+    pub fn loose_eq(&self, other: &PhpValue) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+
+    /// PHP's ordering of `$a <=> $b`, after the same juggling as `==`.
+    /// `None` when the values are unordered: a NaN, or arrays with keys
+    /// missing from one another.
+    pub fn compare(&self, other: &PhpValue) -> Option<Ordering> {
+        use PhpValue::*;
+        match (self, other) {
+            (Bool(_), _) | (_, Bool(_)) | (Null, Null) => {
+                Some(self.to_bool().cmp(&other.to_bool()))
+            }
+            (Null, String(s)) => Some(b"".as_slice().cmp(s)),
+            (String(s), Null) => Some(s.as_slice().cmp(b"")),
+            (Null, _) | (_, Null) => Some(self.to_bool().cmp(&other.to_bool())),
+            (String(a), String(b)) => match (numeric_string(a), numeric_string(b)) {
+                (Some(a), Some(b)) => a.compare(b),
+                _ => Some(a.cmp(b)),
+            },
+            (String(s), Int(_) | Float(_)) => match numeric_string(s) {
+                Some(n) => n.compare(other.to_number()?),
+                None => Some(s.as_slice().cmp(&other.to_php_string()?)),
+            },
+            (Int(_) | Float(_), String(_)) => other.compare(self).map(Ordering::reverse),
+            (Int(_) | Float(_), Int(_) | Float(_)) => self.to_number()?.compare(other.to_number()?),
+            (Array(a), Array(b)) => compare_arrays(a, b),
+            (Array(_), _) => Some(Ordering::Greater),
+            (_, Array(_)) => Some(Ordering::Less),
+        }
+    }
+
+    /// The value as an arithmetic operand. `None` where PHP throws a
+    /// `TypeError`: arrays and strings without a leading number.
+    fn to_number(&self) -> Option<Number> {
+        Some(match self {
+            PhpValue::Null => Number::Int(0),
+            PhpValue::Bool(b) => Number::Int(i64::from(*b)),
+            PhpValue::Int(n) => Number::Int(*n),
+            PhpValue::Float(f) => Number::Float(*f),
+            PhpValue::String(s) => parse_numeric(s)?.0,
+            PhpValue::Array(_) => return None,
+        })
+    }
+
+    fn entry(&self, key: &ArrayKey) -> Option<&PhpValue> {
+        match self {
+            PhpValue::Array(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// PHP literal syntax: `null`, `'it\'s'`, `1.0`, `[1, 'key' => 2]`. Floats
+/// are written in their shortest round-trip form.
+impl fmt::Display for PhpValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhpValue::Null => f.write_str("null"),
+            PhpValue::Bool(b) => write!(f, "{b}"),
+            PhpValue::Int(n) => write!(f, "{n}"),
+            PhpValue::Float(x) => {
+                let text = format_float(*x, None);
+                if text.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+                    write!(f, "{text}.0")
+                } else {
+                    f.write_str(&text)
+                }
+            }
+            PhpValue::String(s) => write_string(f, s),
+            PhpValue::Array(entries) => {
+                let is_list = entries
+                    .iter()
+                    .enumerate()
+                    .all(|(i, (key, _))| *key == ArrayKey::Int(i as i64));
+                f.write_str("[")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    match key {
+                        _ if is_list => {}
+                        ArrayKey::Int(n) => write!(f, "{n} => ")?,
+                        ArrayKey::String(s) => {
+                            write_string(f, s)?;
+                            f.write_str(" => ")?;
+                        }
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &[u8]) -> fmt::Result {
+    let text = String::from_utf8_lossy(s);
+    write!(f, "'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// PHP 8's numeric-string test: an integer or float literal with an
+/// optional sign and exponent, optionally surrounded by whitespace.
+pub fn is_numeric(s: &[u8]) -> bool {
+    numeric_string(s).is_some()
+}
+
+/// Evaluate a constant expression. Constants other than a few built-in
+/// ones such as `PHP_INT_MAX` are not known; see [`evaluate_with`].
+///
+/// ```
+/// use php_analysis::values::{evaluate, PhpValue};
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php 0.1 + 0.2 . ' ' . PHP_INT_MAX + 1; ['a' => 1, 2, '5' => 3, 4] <=> [];";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let values: Vec<_> = result
+///     .program
+///     .stmts
+///     .iter()
+///     .map(|stmt| match &stmt.kind {
+///         StmtKind::Expression(expr) => evaluate(expr).unwrap().to_string(),
+///         _ => unreachable!(),
+///     })
+///     .collect();
+/// assert_eq!(values, ["'0.3 9.2233720368548E+18'", "1"]);
+/// ```
+pub fn evaluate(expr: &Expr<'_, '_>) -> Option<PhpValue> {
+    evaluate_with(expr, &mut |_| None)
+}
+
+/// Evaluate a constant expression, asking `lookup` for the value of each
+/// constant fetch, class constant, magic constant, or other expression the
+/// evaluator cannot fold by itself.
+pub fn evaluate_with(
+    expr: &Expr<'_, '_>,
+    lookup: &mut dyn FnMut(&Expr<'_, '_>) -> Option<PhpValue>,
+) -> Option<PhpValue> {
+    Evaluator { lookup }.eval(expr)
+}
+
+struct Evaluator<'l> {
+    lookup: &'l mut dyn FnMut(&Expr<'_, '_>) -> Option<PhpValue>,
+}
+
+impl Evaluator<'_> {
+    fn eval(&mut self, expr: &Expr<'_, '_>) -> Option<PhpValue> {
+        Some(match &expr.kind {
+            ExprKind::Int(n) => PhpValue::Int(*n),
+            ExprKind::Float(f) => PhpValue::Float(*f),
+            ExprKind::String { value, .. } | ExprKind::Nowdoc { value, .. } => {
+                PhpValue::String(encode(value).into_owned())
+            }
+            ExprKind::InterpolatedString(parts) | ExprKind::Heredoc { parts, .. } => {
+                let mut text = Vec::new();
+                for part in parts.iter() {
+                    match part {
+                        StringPart::Literal { value, .. } => text.extend_from_slice(&encode(value)),
+                        StringPart::Expr(_) => return None,
+                    }
+                }
+                PhpValue::String(text)
+            }
+            ExprKind::Bool(b) => PhpValue::Bool(*b),
+            ExprKind::Null => PhpValue::Null,
+            ExprKind::Parenthesized(inner) => return self.eval(inner),
+            ExprKind::Array(elements) => return self.array(elements),
+            ExprKind::UnaryPrefix(unary) => return unary_op(unary.op, &self.eval(unary.operand)?),
+            ExprKind::Binary(binary) => {
+                let left = self.eval(binary.left)?;
+                return match binary.op {
+                    BinaryOp::BooleanAnd | BinaryOp::LogicalAnd if !left.to_bool() => {
+                        Some(PhpValue::Bool(false))
+                    }
+                    BinaryOp::BooleanOr | BinaryOp::LogicalOr if left.to_bool() => {
+                        Some(PhpValue::Bool(true))
+                    }
+                    BinaryOp::BooleanAnd
+                    | BinaryOp::LogicalAnd
+                    | BinaryOp::BooleanOr
+                    | BinaryOp::LogicalOr => {
+                        Some(PhpValue::Bool(self.eval(binary.right)?.to_bool()))
+                    }
+                    op => binary_op(op, &left, &self.eval(binary.right)?),
+                };
+            }
+            ExprKind::Ternary(ternary) => {
+                let condition = self.eval(ternary.condition)?;
+                return match (condition.to_bool(), ternary.then_expr) {
+                    (true, Some(then_expr)) => self.eval(then_expr),
+                    (true, None) => Some(condition),
+                    (false, _) => self.eval(ternary.else_expr),
+                };
+            }
+            ExprKind::NullCoalesce(coalesce) => match self.eval(coalesce.left)? {
+                PhpValue::Null => return self.eval(coalesce.right),
+                value => value,
+            },
+            ExprKind::Cast(kind, inner) => return cast(*kind, self.eval(inner)?),
+            ExprKind::ArrayAccess(access) => {
+                let array = self.eval(access.array)?;
+                let index = self.eval(access.index?)?;
+                return match &array {
+                    PhpValue::String(s) => {
+                        let i = match index {
+                            PhpValue::Int(i) => i,
+                            PhpValue::String(ref i) => integer_key(i)?,
+                            _ => return None,
+                        };
+                        let i = if i < 0 { i + s.len() as i64 } else { i };
+                        let byte = *s.get(usize::try_from(i).ok()?)?;
+                        Some(PhpValue::String(vec![byte]))
+                    }
+                    _ => array.entry(&ArrayKey::from_value(&index)?).cloned(),
+                };
+            }
+            ExprKind::ConstFetch(name) => {
+                return (self.lookup)(expr).or_else(|| builtin_constant(&name.to_string_repr()))
+            }
+            _ => return (self.lookup)(expr),
+        })
+    }
+
+    fn array(&mut self, elements: &[ArrayElement<'_, '_>]) -> Option<PhpValue> {
+        let mut array = ArrayBuilder::default();
+        for element in elements {
+            if element.by_ref {
+                return None;
+            }
+            let value = self.eval(&element.value)?;
+            if element.unpack {
+                // `...` renumbers integer keys and overwrites string keys.
+                let PhpValue::Array(entries) = value else {
+                    return None;
+                };
+                for (key, value) in entries {
+                    match key {
+                        ArrayKey::Int(_) => array.push(value)?,
+                        key @ ArrayKey::String(_) => array.set(key, value),
+                    }
+                }
+                continue;
+            }
+            match &element.key {
+                Some(key) => array.set(ArrayKey::from_value(&self.eval(key)?)?, value),
+                None => array.push(value)?,
+            }
+        }
+        Some(PhpValue::Array(array.entries))
+    }
+}
+
+#[derive(Default)]
+struct ArrayBuilder {
+    entries: Vec<(ArrayKey, PhpValue)>,
+    /// The key of the next appended element, once an integer key is set.
+    next: Option<i128>,
+}
+
+impl ArrayBuilder {
+    fn set(&mut self, key: ArrayKey, value: PhpValue) {
+        if let ArrayKey::Int(n) = key {
+            let next = i128::from(n) + 1;
+            self.next = Some(self.next.map_or(next, |current| current.max(next)));
+        }
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    /// Append with the next integer key. `None` once `PHP_INT_MAX` is used.
+    fn push(&mut self, value: PhpValue) -> Option<()> {
+        let key = i64::try_from(self.next.unwrap_or(0)).ok()?;
+        self.set(ArrayKey::Int(key), value);
+        Some(())
+    }
+}
+
+/// Apply a prefix operator. `None` where PHP throws, and for `++` and
+/// `--`, which need a variable.
+pub fn unary_op(op: UnaryPrefixOp, operand: &PhpValue) -> Option<PhpValue> {
+    Some(match op {
+        UnaryPrefixOp::Negate => match operand.to_number()? {
+            Number::Int(n) => n
+                .checked_neg()
+                .map_or(PhpValue::Float(-(n as f64)), PhpValue::Int),
+            Number::Float(f) => PhpValue::Float(-f),
+        },
+        UnaryPrefixOp::Plus => operand.to_number()?.value(),
+        UnaryPrefixOp::BooleanNot => PhpValue::Bool(!operand.to_bool()),
+        UnaryPrefixOp::BitwiseNot => match operand {
+            PhpValue::Int(n) => PhpValue::Int(!n),
+            PhpValue::Float(f) => PhpValue::Int(!float_to_int(*f)?),
+            PhpValue::String(s) => PhpValue::String(s.iter().map(|b| !b).collect()),
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
+
+/// Apply a binary operator. `None` where PHP throws — division by zero, a
+/// negative shift, arithmetic on an array or a non-numeric string, `.` on
+/// an array — and for `|>`. `&&`, `||`, `and`, and `or` take both values,
+/// so callers that short-circuit test the left operand first.
+pub fn binary_op(op: BinaryOp, left: &PhpValue, right: &PhpValue) -> Option<PhpValue> {
+    let ordered = |accept: &[Ordering], a: &PhpValue, b: &PhpValue| {
+        PhpValue::Bool(a.compare(b).is_some_and(|o| accept.contains(&o)))
+    };
+    Some(match op {
+        BinaryOp::Add => match (left, right) {
+            (PhpValue::Array(a), PhpValue::Array(b)) => {
+                let mut union = a.clone();
+                union.extend(
+                    b.iter()
+                        .filter(|(key, _)| !a.iter().any(|(k, _)| k == key))
+                        .cloned(),
+                );
+                PhpValue::Array(union)
+            }
+            _ => arithmetic(left, right, i64::checked_add, |a, b| a + b)?,
+        },
+        BinaryOp::Sub => arithmetic(left, right, i64::checked_sub, |a, b| a - b)?,
+        BinaryOp::Mul => arithmetic(left, right, i64::checked_mul, |a, b| a * b)?,
+        BinaryOp::Div => {
+            let (a, b) = (left.to_number()?, right.to_number()?);
+            if b.to_float() == 0.0 {
+                return None;
+            }
+            match (a, b) {
+                (Number::Int(a), Number::Int(b)) if a.checked_rem(b) == Some(0) => {
+                    PhpValue::Int(a / b)
+                }
+                _ => PhpValue::Float(a.to_float() / b.to_float()),
+            }
+        }
+        BinaryOp::Mod => {
+            let (a, b) = (left.to_number()?.to_int()?, right.to_number()?.to_int()?);
+            if b == 0 {
+                return None;
+            }
+            PhpValue::Int(a.checked_rem(b).unwrap_or(0))
+        }
+        BinaryOp::Pow => match (left.to_number()?, right.to_number()?) {
+            (Number::Int(a), Number::Int(b)) if b >= 0 => u32::try_from(b)
+                .ok()
+                .and_then(|b| a.checked_pow(b))
+                .map_or(PhpValue::Float((a as f64).powf(b as f64)), PhpValue::Int),
+            (a, b) => PhpValue::Float(a.to_float().powf(b.to_float())),
+        },
+        BinaryOp::Concat => {
+            let mut text = left.to_php_string()?;
+            text.extend(right.to_php_string()?);
+            PhpValue::String(text)
+        }
+        BinaryOp::Equal => PhpValue::Bool(left.loose_eq(right)),
+        BinaryOp::NotEqual => PhpValue::Bool(!left.loose_eq(right)),
+        BinaryOp::Identical => PhpValue::Bool(left == right),
+        BinaryOp::NotIdentical => PhpValue::Bool(left != right),
+        // PHP evaluates `a > b` as `b < a`.
+        BinaryOp::Less => ordered(&[Ordering::Less], left, right),
+        BinaryOp::LessOrEqual => ordered(&[Ordering::Less, Ordering::Equal], left, right),
+        BinaryOp::Greater => ordered(&[Ordering::Less], right, left),
+        BinaryOp::GreaterOrEqual => ordered(&[Ordering::Less, Ordering::Equal], right, left),
+        BinaryOp::Spaceship => PhpValue::Int(match left.compare(right) {
+            Some(Ordering::Less) => -1,
+            Some(Ordering::Equal) => 0,
+            _ => 1,
+        }),
+        BinaryOp::LogicalXor => PhpValue::Bool(left.to_bool() ^ right.to_bool()),
+        BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => {
+            PhpValue::Bool(left.to_bool() && right.to_bool())
+        }
+        BinaryOp::BooleanOr | BinaryOp::LogicalOr => {
+            PhpValue::Bool(left.to_bool() || right.to_bool())
+        }
+        BinaryOp::BitwiseAnd | BinaryOp::BitwiseOr | BinaryOp::BitwiseXor => {
+            if let (PhpValue::String(a), PhpValue::String(b)) = (left, right) {
+                return Some(PhpValue::String(bitwise_strings(op, a, b)));
+            }
+            let (a, b) = (left.to_number()?.to_int()?, right.to_number()?.to_int()?);
+            PhpValue::Int(match op {
+                BinaryOp::BitwiseAnd => a & b,
+                BinaryOp::BitwiseOr => a | b,
+                _ => a ^ b,
+            })
+        }
+        BinaryOp::ShiftLeft | BinaryOp::ShiftRight => {
+            let (a, b) = (left.to_number()?.to_int()?, right.to_number()?.to_int()?);
+            // A negative shift throws an `ArithmeticError`.
+            let b = u32::try_from(b).ok()?;
+            PhpValue::Int(match (op, b) {
+                (BinaryOp::ShiftLeft, 0..=63) => a << b,
+                (BinaryOp::ShiftLeft, _) => 0,
+                (_, 0..=63) => a >> b,
+                _ => a >> 63,
+            })
+        }
+        _ => return None,
+    })
+}
+
+/// `+`, `-`, or `*`, in integers unless an operand is a float or the result
+/// overflows.
+fn arithmetic(
+    left: &PhpValue,
+    right: &PhpValue,
+    int: fn(i64, i64) -> Option<i64>,
+    float: fn(f64, f64) -> f64,
+) -> Option<PhpValue> {
+    let (a, b) = (left.to_number()?, right.to_number()?);
+    Some(match (a, b) {
+        (Number::Int(x), Number::Int(y)) => {
+            int(x, y).map_or(PhpValue::Float(float(x as f64, y as f64)), PhpValue::Int)
+        }
+        _ => PhpValue::Float(float(a.to_float(), b.to_float())),
+    })
+}
+
+/// `&`, `|`, or `^` on two strings, byte by byte. `|` keeps the tail of the
+/// longer string; `&` and `^` stop at the shorter.
+fn bitwise_strings(op: BinaryOp, a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| match op {
+            BinaryOp::BitwiseAnd => x & y,
+            BinaryOp::BitwiseOr => x | y,
+            _ => x ^ y,
+        })
+        .collect();
+    if op == BinaryOp::BitwiseOr {
+        let longer = if a.len() > b.len() { a } else { b };
+        bytes.extend_from_slice(&longer[bytes.len()..]);
+    }
+    bytes
+}
+
+/// `(type) value`. `None` for `(object)`, `(unset)`, and `(void)`, for
+/// `(string)` of an array, and for `(int)` of a float out of range.
+pub fn cast(kind: CastKind, value: PhpValue) -> Option<PhpValue> {
+    Some(match kind {
+        CastKind::Int => PhpValue::Int(value.to_int()?),
+        CastKind::Float => PhpValue::Float(value.to_float()),
+        CastKind::String => PhpValue::String(value.to_php_string()?),
+        CastKind::Bool => PhpValue::Bool(value.to_bool()),
+        CastKind::Array => match value {
+            PhpValue::Array(_) => value,
+            PhpValue::Null => PhpValue::Array(Vec::new()),
+            scalar => PhpValue::Array(vec![(ArrayKey::Int(0), scalar)]),
+        },
+        _ => return None,
+    })
+}
+
+/// The value of a built-in constant that is the same on every 64-bit
+/// platform.
+fn builtin_constant(name: &str) -> Option<PhpValue> {
+    let name = name.trim_start_matches('\\');
+    if name.contains('\\') {
+        return None;
+    }
+    if name.eq_ignore_ascii_case("true") {
+        return Some(PhpValue::Bool(true));
+    } else if name.eq_ignore_ascii_case("false") {
+        return Some(PhpValue::Bool(false));
+    } else if name.eq_ignore_ascii_case("null") {
+        return Some(PhpValue::Null);
+    }
+    Some(match name {
+        "PHP_INT_MAX" => PhpValue::Int(i64::MAX),
+        "PHP_INT_MIN" => PhpValue::Int(i64::MIN),
+        "PHP_INT_SIZE" => PhpValue::Int(8),
+        "PHP_FLOAT_EPSILON" => PhpValue::Float(f64::EPSILON),
+        "PHP_FLOAT_MAX" => PhpValue::Float(f64::MAX),
+        "PHP_FLOAT_MIN" => PhpValue::Float(f64::MIN_POSITIVE),
+        "PHP_FLOAT_DIG" => PhpValue::Int(15),
+        "NAN" => PhpValue::Float(f64::NAN),
+        "INF" => PhpValue::Float(f64::INFINITY),
+        "M_PI" => PhpValue::Float(std::f64::consts::PI),
+        "M_E" => PhpValue::Float(std::f64::consts::E),
+        "E_ERROR" => PhpValue::Int(1),
+        "E_WARNING" => PhpValue::Int(2),
+        "E_NOTICE" => PhpValue::Int(8),
+        "E_USER_ERROR" => PhpValue::Int(256),
+        "E_USER_WARNING" => PhpValue::Int(512),
+        "E_USER_NOTICE" => PhpValue::Int(1024),
+        "E_STRICT" => PhpValue::Int(2048),
+        "E_DEPRECATED" => PhpValue::Int(8192),
+        "E_USER_DEPRECATED" => PhpValue::Int(16384),
+        "E_ALL" => PhpValue::Int(32767),
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    fn to_float(self) -> f64 {
+        match self {
+            Number::Int(n) => n as f64,
+            Number::Float(f) => f,
+        }
+    }
+
+    fn to_int(self) -> Option<i64> {
+        match self {
+            Number::Int(n) => Some(n),
+            Number::Float(f) => float_to_int(f),
+        }
+    }
+
+    fn value(self) -> PhpValue {
+        match self {
+            Number::Int(n) => PhpValue::Int(n),
+            Number::Float(f) => PhpValue::Float(f),
+        }
+    }
+
+    fn compare(self, other: Number) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(a), Number::Int(b)) => Some(a.cmp(&b)),
+            (a, b) => a.to_float().partial_cmp(&b.to_float()),
+        }
+    }
+}
+
+/// The truncated integer value of `f`, if it is in range.
+fn float_to_int(f: f64) -> Option<i64> {
+    (f.is_finite() && (-9.223_372_036_854_776e18..9.223_372_036_854_776e18).contains(&f))
+        .then_some(f as i64)
+}
+
+/// The integer an array key string such as `'42'` or `'-7'` stands for.
+fn integer_key(s: &[u8]) -> Option<i64> {
+    let digits = s.strip_prefix(b"-").unwrap_or(s);
+    let canonical = match digits {
+        [b'0'] => s.len() == 1,
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    };
+    if !canonical {
+        return None;
+    }
+    std::str::from_utf8(s).ok()?.parse().ok()
+}
+
+/// The value of a numeric string.
+fn numeric_string(s: &[u8]) -> Option<Number> {
+    parse_numeric(s).and_then(|(n, whole)| whole.then_some(n))
+}
+
+/// The number at the start of `s` after any whitespace, and whether it is
+/// followed only by whitespace. Integers out of range are floats.
+fn parse_numeric(s: &[u8]) -> Option<(Number, bool)> {
+    let is_space = |b: &&u8| matches!(b, b' ' | b'\t' | b'\n' | b'\r' | b'\x0b' | b'\x0c');
+    let start = s.iter().take_while(is_space).count();
+    let mut i = start;
+    if matches!(s.get(i), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let digits = |i: &mut usize| {
+        let from = *i;
+        while s.get(*i).is_some_and(u8::is_ascii_digit) {
+            *i += 1;
+        }
+        *i - from
+    };
+    let mut mantissa = digits(&mut i);
+    let mut is_float = false;
+    if s.get(i) == Some(&b'.') {
+        let mut j = i + 1;
+        let fraction = digits(&mut j);
+        if mantissa + fraction > 0 {
+            mantissa += fraction;
+            i = j;
+            is_float = true;
+        }
+    }
+    if mantissa == 0 {
+        return None;
+    }
+    if matches!(s.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(s.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        if digits(&mut j) > 0 {
+            i = j;
+            is_float = true;
+        }
+    }
+    let text = std::str::from_utf8(&s[start..i]).ok()?;
+    let number = match text.parse() {
+        Ok(n) if !is_float => Number::Int(n),
+        _ => Number::Float(text.parse().ok()?),
+    };
+    let whole = s[i..].iter().all(|b| is_space(&b));
+    Some((number, whole))
+}
+
+fn compare_arrays(a: &[(ArrayKey, PhpValue)], b: &[(ArrayKey, PhpValue)]) -> Option<Ordering> {
+    if a.len() != b.len() {
+        return Some(a.len().cmp(&b.len()));
+    }
+    for (key, value) in a {
+        let (_, other) = b.iter().find(|(k, _)| k == key)?;
+        match value.compare(other)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+    Some(Ordering::Equal)
+}
+
+/// Format a float as PHP does with `precision` significant digits, or
+/// with the fewest digits that round-trip.
+fn format_float(f: f64, precision: Option<usize>) -> String {
+    if f.is_nan() {
+        return "NAN".to_string();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "INF" } else { "-INF" }.to_string();
+    }
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    if f == 0.0 {
+        return format!("{sign}0");
+    }
+    let scientific = match precision {
+        Some(precision) => format!("{:.*e}", precision - 1, f.abs()),
+        None => format!("{:e}", f.abs()),
+    };
+    let (mantissa, exponent) = scientific.split_once('e').expect("`{:e}` has an exponent");
+    let digits = mantissa.replace('.', "");
+    let digits = digits.trim_end_matches('0');
+    // The position of the decimal point relative to `digits`.
+    let point = exponent.parse::<i32>().expect("`{:e}` exponent") + 1;
+    let limit = precision.map_or(15, |precision| precision as i32);
+    if point < -3 || point > limit {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        let exponent = point - 1;
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        format!("{sign}{first}.{rest}E{exponent_sign}{}", exponent.abs())
+    } else if point <= 0 {
+        format!(
+            "{sign}0.{}{digits}",
+            "0".repeat(point.unsigned_abs() as usize)
+        )
+    } else if point as usize >= digits.len() {
+        format!(
+            "{sign}{digits}{}",
+            "0".repeat(point as usize - digits.len())
+        )
+    } else {
+        let (whole, fraction) = digits.split_at(point as usize);
+        format!("{sign}{whole}.{fraction}")
+    }
+}
+
+/// What an [`Initializer`] initializes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InitializerKind {
+    /// `const NAME = value;`
+    Constant,
+    /// `const NAME = value;` in a class, interface, trait, or enum.
+    ClassConstant,
+    /// `case Name = value;` in a backed enum.
+    EnumCase,
+    /// A property default.
+    Property,
+    /// A parameter default.
+    Parameter,
+    /// `static $name = value;`
+    StaticVariable,
+}
+
+/// An initializer with a value known at parse time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Initializer {
+    pub kind: InitializerKind,
+    /// `App\LIMIT` for a constant, `App\Config::TTL`, `App\Suit::Hearts`, or
+    /// `App\Config::$timeout` for a member, `$name` for a parameter or
+    /// static variable.
+    pub name: String,
+    /// The value expression.
+    pub span: Span,
+    pub value: PhpValue,
+}
+
+/// Evaluate the initializers in `program` that have a value known at parse
+/// time, in source order.
+///
+/// Constants are resolved within the file: global constants defined
+/// earlier, and class constants of classes declared earlier or of the
+/// same class in any order. `__NAMESPACE__` and `__CLASS__` are known.
+///
+/// ```
+/// use php_analysis::values::{initializers, PhpValue};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php
+/// namespace App;
+/// const MINUTE = 60;
+/// class Cache {
+///     const TTL = [self::SHORT => MINUTE * 5];
+///     const SHORT = 'short';
+///     public function get(string $key, int $ttl = self::TTL['short']) {}
+/// }";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let found: Vec<_> = initializers(&result.program)
+///     .into_iter()
+///     .map(|i| format!("{} = {}", i.name, i.value))
+///     .collect();
+/// assert_eq!(found, [
+///     "App\\MINUTE = 60",
+///     "App\\Cache::TTL = ['short' => 300]",
+///     "App\\Cache::SHORT = 'short'",
+///     "$ttl = 300",
+/// ]);
+/// ```
+pub fn initializers(program: &Program<'_, '_>) -> Vec<Initializer> {
+    let mut collector = Collector::default();
+    AnalysisWalker::new(&mut collector).walk(program);
+    collector.found.sort_by_key(|initializer| initializer.span);
+    collector.found
+}
+
+#[derive(Default)]
+struct Collector {
+    found: Vec<Initializer>,
+    /// Global constants by [`key`].
+    constants: HashMap<String, PhpValue>,
+    /// Class constants by lower-cased class name and constant name.
+    class_constants: HashMap<(String, String), PhpValue>,
+}
+
+/// A class member initializer awaiting evaluation.
+struct Member<'e, 'arena, 'src> {
+    kind: InitializerKind,
+    name: &'src str,
+    value: &'e Expr<'arena, 'src>,
+}
+
+impl Collector {
+    /// The value of a constant, or of a magic constant inside `class`.
+    fn lookup(
+        &self,
+        expr: &Expr<'_, '_>,
+        scope: &AnalysisScope,
+        class: Option<&str>,
+    ) -> Option<PhpValue> {
+        match &expr.kind {
+            ExprKind::ConstFetch(name) => {
+                let (name, fallback) = scope.names.resolve_constant(&name.to_string_repr());
+                self.constants
+                    .get(&key(&name))
+                    .or_else(|| self.constants.get(&key(&fallback?)))
+                    .cloned()
+            }
+            ExprKind::ClassConstAccess(access) => {
+                let ExprKind::Identifier(member) = &access.member.kind else {
+                    return None;
+                };
+                let class = match (&access.class, class) {
+                    (ClassRef::SelfKw(_), Some(class)) => class.to_string(),
+                    _ => scope.resolve_class(&access.class)?,
+                };
+                self.class_constants
+                    .get(&(class.to_ascii_lowercase(), member.to_string()))
+                    .cloned()
+            }
+            ExprKind::MagicConst(MagicConstKind::Namespace) => Some(PhpValue::String(
+                scope.names.namespace().as_bytes().to_vec(),
+            )),
+            ExprKind::MagicConst(MagicConstKind::Class) => {
+                let class = class.or(scope.class.as_ref().map(|c| c.name.as_str()));
+                Some(PhpValue::String(
+                    class.unwrap_or_default().as_bytes().to_vec(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn push(&mut self, kind: InitializerKind, name: String, expr: &Expr<'_, '_>, value: PhpValue) {
+        self.found.push(Initializer {
+            kind,
+            name,
+            span: expr.span,
+            value,
+        });
+    }
+
+    /// Evaluate the members of the class `class`. Class constants may refer
+    /// to one another in any order, so they are evaluated until no more
+    /// become known.
+    fn class_like(&mut self, class: &str, members: &[Member<'_, '_, '_>], scope: &AnalysisScope) {
+        let mut values: Vec<Option<PhpValue>> = vec![None; members.len()];
+        loop {
+            let mut progress = false;
+            for (member, slot) in members.iter().zip(&mut values) {
+                if slot.is_some() {
+                    continue;
+                }
+                let Some(value) =
+                    evaluate_with(member.value, &mut |e| self.lookup(e, scope, Some(class)))
+                else {
+                    continue;
+                };
+                if member.kind == InitializerKind::ClassConstant {
+                    let key = (class.to_ascii_lowercase(), member.name.to_string());
+                    self.class_constants.insert(key, value.clone());
+                }
+                *slot = Some(value);
+                progress = true;
+            }
+            if !progress {
+                break;
+            }
+        }
+        for (member, value) in members.iter().zip(values) {
+            if let Some(value) = value {
+                let separator = if member.kind == InitializerKind::Property {
+                    "::$"
+                } else {
+                    "::"
+                };
+                let name = format!("{class}{separator}{}", member.name);
+                self.push(member.kind, name, member.value, value);
+            }
+        }
+    }
+}
+
+/// The constant and property initializers among `members`.
+fn class_members<'e, 'arena, 'src>(
+    members: &'e [ClassMember<'arena, 'src>],
+) -> Vec<Member<'e, 'arena, 'src>> {
+    members
+        .iter()
+        .filter_map(|member| match &member.kind {
+            ClassMemberKind::ClassConst(constant) => Some(Member {
+                kind: InitializerKind::ClassConstant,
+                name: constant.name.as_str()?,
+                value: &constant.value,
+            }),
+            ClassMemberKind::Property(prop) => Some(Member {
+                kind: InitializerKind::Property,
+                name: prop.name.as_str()?,
+                value: prop.default.as_ref()?,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+impl<'arena, 'src> AnalysisVisitor<'arena, 'src> for Collector {
+    fn enter_unit(&mut self, unit: &Unit<'_, 'arena, 'src>, scope: &AnalysisScope) {
+        for param in unit.params {
+            let (Some(name), Some(default)) = (param.name.as_str(), &param.default) else {
+                continue;
+            };
+            let value = evaluate_with(default, &mut |e| self.lookup(e, scope, None));
+            if let Some(value) = value {
+                self.push(
+                    InitializerKind::Parameter,
+                    format!("${name}"),
+                    default,
+                    value,
+                );
+            }
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>, scope: &AnalysisScope) {
+        match &stmt.kind {
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    let Some(name) = item.name.as_str() else {
+                        continue;
+                    };
+                    let value = evaluate_with(&item.value, &mut |e| self.lookup(e, scope, None));
+                    if let Some(value) = value {
+                        let name = scope.names.qualify(name);
+                        self.constants.insert(key(&name), value.clone());
+                        self.push(InitializerKind::Constant, name, &item.value, value);
+                    }
+                }
+            }
+            StmtKind::Class(class) => {
+                if let Some(name) = class.name.as_ref().and_then(|name| name.as_str()) {
+                    let name = scope.names.qualify(name);
+                    self.class_like(&name, &class_members(&class.members), scope);
+                }
+            }
+            StmtKind::Interface(iface) => {
+                let name = scope.names.qualify(iface.name.or_error());
+                self.class_like(&name, &class_members(&iface.members), scope);
+            }
+            StmtKind::Trait(trait_decl) => {
+                let name = scope.names.qualify(trait_decl.name.or_error());
+                self.class_like(&name, &class_members(&trait_decl.members), scope);
+            }
+            StmtKind::Enum(enum_decl) => {
+                let name = scope.names.qualify(enum_decl.name.or_error());
+                let members: Vec<Member<'_, '_, '_>> = enum_decl
+                    .members
+                    .iter()
+                    .filter_map(|member| match &member.kind {
+                        EnumMemberKind::ClassConst(constant) => Some(Member {
+                            kind: InitializerKind::ClassConstant,
+                            name: constant.name.as_str()?,
+                            value: &constant.value,
+                        }),
+                        EnumMemberKind::Case(case) => Some(Member {
+                            kind: InitializerKind::EnumCase,
+                            name: case.name.as_str()?,
+                            value: case.value.as_ref()?,
+                        }),
+                        _ => None,
+                    })
+                    .collect();
+                self.class_like(&name, &members, scope);
+            }
+            StmtKind::StaticVar(vars) => {
+                for var in vars.iter() {
+                    let (Some(name), Some(default)) = (var.name.as_str(), &var.default) else {
+                        continue;
+                    };
+                    let value = evaluate_with(default, &mut |e| self.lookup(e, scope, None));
+                    if let Some(value) = value {
+                        self.push(
+                            InitializerKind::StaticVariable,
+                            format!("${name}"),
+                            default,
+                            value,
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
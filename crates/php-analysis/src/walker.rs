@@ -0,0 +1,426 @@
+//! Unit-aware traversal shared by the analyses.
+//!
+//! [`AnalysisWalker`] drives an [`AnalysisVisitor`] over a program the same way
+//! [`php_ast::visitor::ScopeWalker`] drives a `ScopeVisitor`, but tracks the
+//! context analyses need: resolved namespace imports, the enclosing class and
+//! its parent, the executable *unit* (file body, function, method, closure,
+//! property hook) being walked, and the `catch` clauses guarding the current
+//! position.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_expr, walk_program, walk_property_hook, walk_stmt, Visitor};
+use php_ast::*;
+
+use crate::names::{is_special_class, NameContext};
+
+/// What kind of executable body a [`Unit`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    /// Top-level statements of a file (`{main}`).
+    Main,
+    /// A named function declaration.
+    Function,
+    /// A method of a class, interface, trait, or enum.
+    Method,
+    /// A closure or arrow function.
+    Closure,
+    /// A `get`/`set` property hook.
+    Hook,
+}
+
+/// An executable body entered by the walker.
+#[derive(Debug)]
+pub struct Unit<'a, 'arena, 'src> {
+    pub kind: UnitKind,
+    /// Display name: `{main}`, `App\helper`, `App\User::save`, `{closure}`,
+    /// or `App\User::$name::get`.
+    pub name: String,
+    pub span: Span,
+    /// The declaration's doc-block, when the unit is a function or method.
+    pub doc_comment: Option<&'a Comment<'src>>,
+    pub params: &'a [Param<'arena, 'src>],
+    /// Variables a closure imports with `use`; empty for other units.
+    pub uses: &'a [ClosureUseVar<'src>],
+    /// Statement body; `None` for arrow functions and expression-bodied hooks.
+    pub body: Option<&'a [Stmt<'arena, 'src>]>,
+    pub return_type: Option<&'a TypeHint<'arena, 'src>>,
+}
+
+/// The class-like declaration enclosing the current position.
+#[derive(Debug, Clone)]
+pub struct ClassContext {
+    /// Fully-qualified name; `class@anonymous` for anonymous classes.
+    pub name: String,
+    pub parent: Option<String>,
+}
+
+/// A `catch` clause whose body encloses the current position.
+#[derive(Debug, Clone)]
+pub struct ActiveCatch {
+    /// The caught variable without `$`, if one is bound.
+    pub var: Option<String>,
+    /// Fully-qualified caught types.
+    pub types: Vec<String>,
+}
+
+/// Context available to every [`AnalysisVisitor`] callback.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisScope {
+    pub names: NameContext,
+    pub class: Option<ClassContext>,
+    /// Name of the innermost unit, `None` only between units.
+    pub unit: Option<String>,
+    /// Catch types of every `try` body enclosing the current position within
+    /// the current unit, innermost last.
+    pub guards: Vec<Vec<String>>,
+    /// Catch clause bodies enclosing the current position within the current unit.
+    pub catches: Vec<ActiveCatch>,
+}
+
+impl AnalysisScope {
+    /// Resolve a class reference, substituting `self`, `static`, and `parent`
+    /// from the enclosing class. Returns `None` when the pseudo-class cannot be
+    /// resolved (e.g. `parent` in a class without `extends`).
+    pub fn resolve_class_ref(&self, raw: &str) -> Option<String> {
+        if raw.eq_ignore_ascii_case("parent") {
+            return self.class.as_ref().and_then(|c| c.parent.clone());
+        }
+        if is_special_class(raw) {
+            return self.class.as_ref().map(|c| c.name.clone());
+        }
+        Some(self.names.resolve_class(raw))
+    }
+
+    /// [`resolve_class_ref`](Self::resolve_class_ref) for a parsed class
+    /// operand. Returns `None` for a class computed at run time.
+    pub fn resolve_class(&self, class: &ClassRef<'_, '_>) -> Option<String> {
+        match class {
+            ClassRef::Name(name) => Some(self.names.resolve_class(&name.to_string_repr())),
+            ClassRef::SelfKw(_) | ClassRef::StaticKw(_) => {
+                self.class.as_ref().map(|c| c.name.clone())
+            }
+            ClassRef::ParentKw(_) => self.class.as_ref().and_then(|c| c.parent.clone()),
+            ClassRef::Expr(_) => None,
+        }
+    }
+
+    /// Every type caught by an enclosing `try`, flattened.
+    pub fn caught_types(&self) -> Vec<String> {
+        self.guards.iter().flatten().cloned().collect()
+    }
+}
+
+/// Callbacks invoked by [`AnalysisWalker`]. All methods default to no-ops.
+///
+/// `visit_stmt` and `visit_expr` are called *before* the walker descends into
+/// the node's children.
+pub trait AnalysisVisitor<'arena, 'src> {
+    fn enter_unit(&mut self, _unit: &Unit<'_, 'arena, 'src>, _scope: &AnalysisScope) {}
+
+    fn leave_unit(&mut self, _scope: &AnalysisScope) {}
+
+    /// Called when a class, interface, trait, or enum is entered.
+    /// `supertypes` lists the resolved `extends`/`implements` names.
+    fn enter_class(&mut self, _class: &ClassContext, _supertypes: &[String], _span: Span) {}
+
+    fn visit_stmt(&mut self, _stmt: &Stmt<'arena, 'src>, _scope: &AnalysisScope) {}
+
+    fn visit_expr(&mut self, _expr: &Expr<'arena, 'src>, _scope: &AnalysisScope) {}
+}
+
+/// Drives an [`AnalysisVisitor`] over a program, maintaining [`AnalysisScope`].
+pub struct AnalysisWalker<'v, V> {
+    inner: &'v mut V,
+    scope: AnalysisScope,
+}
+
+impl<'v, V> AnalysisWalker<'v, V> {
+    pub fn new(inner: &'v mut V) -> Self {
+        Self {
+            inner,
+            scope: AnalysisScope::default(),
+        }
+    }
+}
+
+impl<'v, 'arena, 'src, V: AnalysisVisitor<'arena, 'src>> AnalysisWalker<'v, V> {
+    /// Walk `program`. The file body is reported as a [`UnitKind::Main`] unit.
+    pub fn walk(mut self, program: &Program<'arena, 'src>) {
+        let unit = Unit {
+            kind: UnitKind::Main,
+            name: "{main}".to_string(),
+            span: program.span,
+            doc_comment: None,
+            params: &[],
+            uses: &[],
+            body: Some(&program.stmts),
+            return_type: None,
+        };
+        let _ = self.in_unit(&unit, |w| walk_program(w, program));
+    }
+
+    fn in_unit(
+        &mut self,
+        unit: &Unit<'_, 'arena, 'src>,
+        f: impl FnOnce(&mut Self) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let prev_unit = self.scope.unit.replace(unit.name.clone());
+        let prev_guards = std::mem::take(&mut self.scope.guards);
+        let prev_catches = std::mem::take(&mut self.scope.catches);
+        self.inner.enter_unit(unit, &self.scope);
+        let result = f(self);
+        self.inner.leave_unit(&self.scope);
+        self.scope.unit = prev_unit;
+        self.scope.guards = prev_guards;
+        self.scope.catches = prev_catches;
+        result
+    }
+
+    fn in_class(
+        &mut self,
+        name: String,
+        extends: Option<&Name<'arena, 'src>>,
+        implements: &[Name<'arena, 'src>],
+        span: Span,
+        f: impl FnOnce(&mut Self) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let parent = extends.map(|n| self.scope.names.resolve_class(&n.to_string_repr()));
+        let mut supertypes: Vec<String> = parent.iter().cloned().collect();
+        supertypes.extend(
+            implements
+                .iter()
+                .map(|n| self.scope.names.resolve_class(&n.to_string_repr())),
+        );
+        let ctx = ClassContext { name, parent };
+        self.inner.enter_class(&ctx, &supertypes, span);
+        let prev = self.scope.class.replace(ctx);
+        let result = f(self);
+        self.scope.class = prev;
+        result
+    }
+
+    fn member_name(&self, member: &str) -> String {
+        match &self.scope.class {
+            Some(class) => format!("{}::{}", class.name, member),
+            None => member.to_string(),
+        }
+    }
+
+    fn walk_method(&mut self, method: &MethodDecl<'arena, 'src>, span: Span) -> ControlFlow<()> {
+        let Some(body) = &method.body else {
+            return ControlFlow::Continue(());
+        };
+        let unit = Unit {
+            kind: UnitKind::Method,
+            name: self.member_name(method.name.or_error()),
+            span,
+            doc_comment: method.doc_comment.as_ref(),
+            params: &method.params,
+            uses: &[],
+            body: Some(body),
+            return_type: method.return_type.as_ref(),
+        };
+        self.in_unit(&unit, |w| {
+            for param in method.params.iter() {
+                w.visit_param(param)?;
+            }
+            for stmt in body.iter() {
+                w.visit_stmt(stmt)?;
+            }
+            ControlFlow::Continue(())
+        })
+    }
+
+    fn walk_members(&mut self, members: &[ClassMember<'arena, 'src>]) -> ControlFlow<()> {
+        for member in members {
+            match &member.kind {
+                ClassMemberKind::Method(method) => self.walk_method(method, member.span)?,
+                ClassMemberKind::Property(prop) => {
+                    for hook in prop.hooks.iter() {
+                        let hook_name = match hook.kind {
+                            PropertyHookKind::Get => "get",
+                            PropertyHookKind::Set => "set",
+                        };
+                        let unit = Unit {
+                            kind: UnitKind::Hook,
+                            name: self.member_name(&format!(
+                                "${}::{}",
+                                prop.name.or_error(),
+                                hook_name
+                            )),
+                            span: hook.span,
+                            doc_comment: None,
+                            params: &hook.params,
+                            uses: &[],
+                            body: match &hook.body {
+                                PropertyHookBody::Block(stmts) => Some(stmts),
+                                _ => None,
+                            },
+                            return_type: None,
+                        };
+                        self.in_unit(&unit, |w| walk_property_hook(w, hook))?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn walk_try(&mut self, tc: &TryCatchStmt<'arena, 'src>) -> ControlFlow<()> {
+        let mut caught = Vec::new();
+        for catch in tc.catches.iter() {
+            caught.extend(
+                catch
+                    .types
+                    .iter()
+                    .filter_map(|t| self.scope.resolve_class(t)),
+            );
+        }
+        self.scope.guards.push(caught);
+        let result = tc.body.iter().try_for_each(|stmt| self.visit_stmt(stmt));
+        self.scope.guards.pop();
+        result?;
+        for catch in tc.catches.iter() {
+            let types = catch
+                .types
+                .iter()
+                .filter_map(|t| self.scope.resolve_class(t))
+                .collect();
+            self.scope.catches.push(ActiveCatch {
+                var: catch.var.map(|var| var.name.to_string()),
+                types,
+            });
+            let result = catch.body.iter().try_for_each(|stmt| self.visit_stmt(stmt));
+            self.scope.catches.pop();
+            result?;
+        }
+        if let Some(finally) = &tc.finally {
+            for stmt in finally.iter() {
+                self.visit_stmt(stmt)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'v, 'arena, 'src, V: AnalysisVisitor<'arena, 'src>> Visitor<'arena, 'src>
+    for AnalysisWalker<'v, V>
+{
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        self.inner.visit_stmt(stmt, &self.scope);
+        match &stmt.kind {
+            StmtKind::Namespace(ns) => {
+                let name = ns
+                    .name
+                    .as_ref()
+                    .map(|n| n.join_parts().into_owned())
+                    .unwrap_or_default();
+                self.scope.names.set_namespace(&name);
+                if let NamespaceBody::Braced(stmts) = &ns.body {
+                    for stmt in stmts.iter() {
+                        self.visit_stmt(stmt)?;
+                    }
+                    self.scope.names.set_namespace("");
+                }
+                ControlFlow::Continue(())
+            }
+            StmtKind::Use(decl) => {
+                self.scope.names.add_use(decl);
+                ControlFlow::Continue(())
+            }
+            StmtKind::Function(func) => {
+                let unit = Unit {
+                    kind: UnitKind::Function,
+                    name: self.scope.names.qualify(func.name.or_error()),
+                    span: stmt.span,
+                    doc_comment: func.doc_comment.as_ref(),
+                    params: &func.params,
+                    uses: &[],
+                    body: Some(&func.body),
+                    return_type: func.return_type.as_ref(),
+                };
+                self.in_unit(&unit, |w| walk_stmt(w, stmt))
+            }
+            StmtKind::Class(class) => {
+                let name = match class.name.and_then(|n| n.as_str()) {
+                    Some(name) => self.scope.names.qualify(name),
+                    None => "class@anonymous".to_string(),
+                };
+                self.in_class(
+                    name,
+                    class.extends.as_ref(),
+                    &class.implements,
+                    stmt.span,
+                    |w| w.walk_members(&class.members),
+                )
+            }
+            StmtKind::Interface(iface) => {
+                let name = self.scope.names.qualify(iface.name.or_error());
+                self.in_class(name, None, &iface.extends, stmt.span, |w| {
+                    w.walk_members(&iface.members)
+                })
+            }
+            StmtKind::Trait(trait_decl) => {
+                let name = self.scope.names.qualify(trait_decl.name.or_error());
+                self.in_class(name, None, &[], stmt.span, |w| {
+                    w.walk_members(&trait_decl.members)
+                })
+            }
+            StmtKind::Enum(enum_decl) => {
+                let name = self.scope.names.qualify(enum_decl.name.or_error());
+                self.in_class(name, None, &enum_decl.implements, stmt.span, |w| {
+                    for member in enum_decl.members.iter() {
+                        if let EnumMemberKind::Method(method) = &member.kind {
+                            w.walk_method(method, member.span)?;
+                        }
+                    }
+                    ControlFlow::Continue(())
+                })
+            }
+            StmtKind::TryCatch(tc) => self.walk_try(tc),
+            _ => walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+        self.inner.visit_expr(expr, &self.scope);
+        match &expr.kind {
+            ExprKind::Closure(closure) => {
+                let unit = Unit {
+                    kind: UnitKind::Closure,
+                    name: "{closure}".to_string(),
+                    span: expr.span,
+                    doc_comment: None,
+                    params: &closure.params,
+                    uses: &closure.use_vars,
+                    body: Some(&closure.body),
+                    return_type: closure.return_type.as_ref(),
+                };
+                self.in_unit(&unit, |w| walk_expr(w, expr))
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                let unit = Unit {
+                    kind: UnitKind::Closure,
+                    name: "{closure}".to_string(),
+                    span: expr.span,
+                    doc_comment: None,
+                    params: &arrow.params,
+                    uses: &[],
+                    body: None,
+                    return_type: arrow.return_type.as_ref(),
+                };
+                self.in_unit(&unit, |w| walk_expr(w, expr))
+            }
+            ExprKind::AnonymousClass(class) => self.in_class(
+                "class@anonymous".to_string(),
+                class.extends.as_ref(),
+                &class.implements,
+                expr.span,
+                |w| w.walk_members(&class.members),
+            ),
+            _ => walk_expr(self, expr),
+        }
+    }
+}
@@ -0,0 +1,979 @@
+use php_rs_parser::source_map::SourceMap;
+use rayon::prelude::*;
+use std::sync::Mutex;
+
+/// Parse an analysis fixture file.
+///
+/// Format:
+/// ```text
+/// ===source===
+/// <?php ...
+/// ===report===
+/// line:col: message
+/// ```
+///
+/// The analysis to run is chosen by the fixture's directory
+/// (`tests/fixtures/<analysis>/*.phpt`).
+struct AnalysisFixture {
+    source: String,
+    expected: String,
+}
+
+fn parse_analysis_fixture(content: &str) -> AnalysisFixture {
+    let after_source = content
+        .strip_prefix("===source===\n")
+        .expect("fixture must start with ===source===");
+    let report_pos = after_source
+        .find("===report===\n")
+        .expect("fixture must have ===report===");
+    let source = after_source[..report_pos]
+        .strip_suffix('\n')
+        .unwrap_or(&after_source[..report_pos]);
+    let expected = &after_source[report_pos + "===report===\n".len()..];
+    let expected = expected.strip_suffix('\n').unwrap_or(expected);
+    AnalysisFixture {
+        source: source.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+fn format_line(
+    map: &SourceMap<'_>,
+    span: php_ast::Span,
+    message: impl std::fmt::Display,
+) -> String {
+    let (line, col) = map.line_col(span.start).to_one_based();
+    format!("{line}:{col}: {message}")
+}
+
+/// Render a scope and the scopes nested in it, indented by depth.
+fn render_scope(
+    map: &SourceMap<'_>,
+    span: php_ast::Span,
+    info: &php_analysis::scope_info::ScopeInfo,
+    depth: usize,
+    lines: &mut Vec<String>,
+) {
+    let indent = "  ".repeat(depth);
+    let defined: Vec<String> = info.defined.iter().map(|name| format!("${name}")).collect();
+    lines.push(format_line(
+        map,
+        span,
+        format!("{indent}{:?} defines [{}]", info.kind, defined.join(", ")),
+    ));
+    for found in &info.undefined {
+        lines.push(format_line(map, found.span, format!("{indent}  {found}")));
+    }
+    for var in &info.unused_uses {
+        lines.push(format_line(
+            map,
+            var.span,
+            format!("{indent}  unused use ${}", var.name),
+        ));
+    }
+    for var in &info.captures {
+        lines.push(format_line(
+            map,
+            var.span,
+            format!("{indent}  captures ${}", var.name),
+        ));
+    }
+    for nested in &info.nested {
+        let span = nested.span.unwrap_or(span);
+        render_scope(map, span, nested, depth + 1, lines);
+    }
+}
+
+/// Render a lowered string expression with the source text of each piece.
+fn render_lowered(source: &str, expr: &php_ast::Expr<'_, '_>) -> String {
+    use php_ast::{BinaryOp, CastKind, ExprKind};
+    match &expr.kind {
+        ExprKind::Binary(b) if b.op == BinaryOp::Concat => format!(
+            "({} . {})",
+            render_lowered(source, b.left),
+            render_lowered(source, b.right)
+        ),
+        ExprKind::Cast(CastKind::String, inner) => {
+            format!("(string) {}", render_lowered(source, inner))
+        }
+        ExprKind::String { value, .. } => format!("{value:?}@{:?}", &source[expr.span.to_range()]),
+        _ => format!("<{}>", &source[expr.span.to_range()]),
+    }
+}
+
+/// Where a CFG block starts, for reports: its first element, or `-` if empty.
+fn block_label(map: &SourceMap<'_>, cfg: &php_analysis::cfg::Cfg<'_, '_, '_>, id: usize) -> String {
+    use php_analysis::cfg::{Cfg, CfgElement};
+    if id == Cfg::EXIT {
+        return "exit".to_string();
+    }
+    let span = cfg
+        .block(id)
+        .elements
+        .iter()
+        .find_map(|element| match element {
+            CfgElement::Stmt(stmt) => Some(stmt.span),
+            CfgElement::Expr(expr) => Some(expr.span),
+            CfgElement::ForeachBind(foreach) => Some(foreach.value.span),
+            CfgElement::CatchBind(catch) => Some(catch.span),
+            CfgElement::Assume { .. } => None,
+        });
+    match span {
+        Some(span) => {
+            let (line, col) = map.line_col(span.start).to_one_based();
+            format!("{line}:{col}")
+        }
+        None => format!("#{id}"),
+    }
+}
+
+/// Live variables, as a backward gen/kill client of the data-flow framework:
+/// a variable is live where some path reads it before writing it.
+struct LiveVariables {
+    names: Vec<String>,
+}
+
+impl LiveVariables {
+    fn index(&self, name: &str) -> usize {
+        self.names.iter().position(|n| *n == name).unwrap()
+    }
+}
+
+/// Variables read and written by an expression, in evaluation order.
+struct Accesses {
+    reads: Vec<String>,
+    writes: Vec<String>,
+}
+
+impl<'arena, 'src> php_ast::visitor::Visitor<'arena, 'src> for Accesses {
+    fn visit_expr(&mut self, expr: &php_ast::Expr<'arena, 'src>) -> std::ops::ControlFlow<()> {
+        use php_ast::{AssignOp, ExprKind};
+        match &expr.kind {
+            ExprKind::Variable(name) => self.reads.push(name.to_string()),
+            ExprKind::Assign(assign) => {
+                if let ExprKind::Variable(name) = &assign.target.kind {
+                    self.visit_expr(assign.value)?;
+                    if assign.op != AssignOp::Assign {
+                        self.reads.push(name.to_string());
+                    }
+                    self.writes.push(name.to_string());
+                    return std::ops::ControlFlow::Continue(());
+                }
+            }
+            _ => {}
+        }
+        php_ast::visitor::walk_expr(self, expr)
+    }
+}
+
+fn accesses(element: &php_analysis::cfg::CfgElement<'_, '_, '_>) -> Accesses {
+    use php_analysis::cfg::CfgElement;
+    use php_ast::visitor::Visitor;
+    let mut accesses = Accesses {
+        reads: Vec::new(),
+        writes: Vec::new(),
+    };
+    let _ = match element {
+        CfgElement::Stmt(stmt) => accesses.visit_stmt(stmt),
+        CfgElement::Expr(expr) => accesses.visit_expr(expr),
+        CfgElement::ForeachBind(foreach) => {
+            if let Some(name) = foreach.value.name_str() {
+                accesses.writes.push(name.to_string());
+            }
+            std::ops::ControlFlow::Continue(())
+        }
+        CfgElement::CatchBind(_) | CfgElement::Assume { .. } => std::ops::ControlFlow::Continue(()),
+    };
+    accesses
+}
+
+impl<'a, 'arena, 'src> php_analysis::dataflow::GenKill<'a, 'arena, 'src> for LiveVariables {
+    const DIRECTION: php_analysis::dataflow::Direction =
+        php_analysis::dataflow::Direction::Backward;
+    const JOIN: php_analysis::dataflow::Join = php_analysis::dataflow::Join::Union;
+
+    fn domain_size(&self) -> usize {
+        self.names.len()
+    }
+
+    fn gen_kill(
+        &self,
+        element: &php_analysis::cfg::CfgElement<'a, 'arena, 'src>,
+        effects: &mut php_analysis::dataflow::GenKillEffects<'_>,
+    ) {
+        let accesses = accesses(element);
+        for name in accesses.writes {
+            effects.kill(self.index(&name));
+        }
+        for name in accesses.reads {
+            effects.generate(self.index(&name));
+        }
+    }
+}
+
+fn run_analysis(analysis: &str, source: &str) -> String {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, source);
+    let map = &result.source_map;
+    let lines: Vec<String> = match analysis {
+        "callgraph" => {
+            let mut graph = php_analysis::callgraph::CallGraph::new();
+            graph.add_file(&result.program);
+            graph
+                .nodes()
+                .iter()
+                .flat_map(|node| {
+                    node.calls.iter().map(|site| {
+                        format_line(
+                            map,
+                            site.span,
+                            format_args!("{} -> {}", node.name, site.callee),
+                        )
+                    })
+                })
+                .collect()
+        }
+        "clones" => {
+            let options = php_analysis::clones::CloneOptions { min_nodes: 12 };
+            let mut detector = php_analysis::clones::CloneDetector::new(options);
+            detector.add_file(&result.program);
+            let range = |span: php_ast::Span| {
+                let (line, col) = map.line_col(span.start).to_one_based();
+                let (end_line, end_col) = map.line_col(span.end).to_one_based();
+                format!("{line}:{col}-{end_line}:{end_col}")
+            };
+            detector
+                .finish()
+                .iter()
+                .map(|pair| {
+                    format!(
+                        "{} ~ {} ({} nodes)",
+                        range(pair.first.span),
+                        range(pair.second.span),
+                        pair.nodes
+                    )
+                })
+                .collect()
+        }
+        "coupling" => {
+            let mut analysis = php_analysis::coupling::CouplingAnalysis::new();
+            analysis.add_file(&result.program);
+            let classes = analysis.finish();
+            let namespaces = classes.namespaces();
+            let mut lines = Vec::new();
+            for graph in [&classes, &namespaces] {
+                for (node, metrics) in graph.nodes().iter().zip(graph.metrics()) {
+                    let name = |n: &str| if n.is_empty() { "\\" } else { n }.to_string();
+                    let dependencies: Vec<String> = node
+                        .dependencies
+                        .iter()
+                        .map(|&d| name(&graph.nodes()[d].name))
+                        .collect();
+                    let line = format!(
+                        "{}{}: Ca={} Ce={} I={:.2} -> [{}]",
+                        name(&node.name),
+                        if node.external { " (external)" } else { "" },
+                        metrics.afferent,
+                        metrics.efferent,
+                        metrics.instability(),
+                        dependencies.join(", "),
+                    );
+                    lines.push(match node.location {
+                        Some((_, span)) => format_line(map, span, line),
+                        None => line,
+                    });
+                }
+            }
+            let thresholds = php_analysis::coupling::CouplingThresholds {
+                max_efferent: Some(2),
+                max_afferent: Some(2),
+                cycles: true,
+            };
+            for graph in [&classes.project_only(), &namespaces] {
+                lines.extend(graph.check(&thresholds).iter().map(|d| d.to_string()));
+            }
+            lines.extend(namespaces.to_dot().lines().map(str::to_string));
+            lines
+        }
+        "enums" => {
+            let mut index = php_analysis::enums::EnumIndex::new();
+            index.add_file(&result.program, source);
+            let mut lines: Vec<String> = index
+                .all_references()
+                .map(|r| format_line(map, r.span, format_args!("{}::{}", r.enum_name, r.case)))
+                .collect();
+            let findings = index.exhaustiveness();
+            lines.extend(findings.iter().map(|f| format_line(map, f.span, f)));
+            lines.extend(
+                index
+                    .duplicate_values()
+                    .iter()
+                    .map(|d| format_line(map, d.span, format_args!("{d} ({})", d.value))),
+            );
+            let edits: Vec<_> = findings.iter().filter_map(|f| f.fix.clone()).collect();
+            lines.push("---".to_string());
+            let fixed = php_analysis::codemod::apply_edits(source, &edits);
+            lines.extend(fixed.lines().map(str::to_string));
+            lines
+        }
+        "values" => php_analysis::values::initializers(&result.program)
+            .iter()
+            .map(|i| {
+                format_line(
+                    map,
+                    i.span,
+                    format_args!("{} ({:?}) = {}", i.name, i.kind, i.value),
+                )
+            })
+            .collect(),
+        "exceptions" => {
+            let mut analysis = php_analysis::exceptions::ExceptionAnalysis::new();
+            analysis.add_file(&result.program);
+            analysis
+                .finish()
+                .iter()
+                .map(|f| format_line(map, f.span(), f))
+                .collect()
+        }
+        "returns" => {
+            let mut analysis = php_analysis::returns::ReturnAnalysis::new();
+            analysis.add_file(&result.program);
+            analysis
+                .finish()
+                .iter()
+                .map(|f| format_line(map, f.span(), f))
+                .collect()
+        }
+        "dynamic" => php_analysis::dynamic::inventory(&result.program)
+            .uses
+            .iter()
+            .map(|u| format_line(map, u.span, u.kind))
+            .collect(),
+        "identifiers" => php_analysis::identifiers::non_ascii_identifiers(source)
+            .iter()
+            .map(|f| format_line(map, f.span, f))
+            .collect(),
+        "implicit_nullable" => {
+            use php_analysis::codemod::Codemod;
+            let rewritten = php_analysis::codemod::implicit_nullable::ImplicitNullable
+                .apply(&result.program, source);
+            rewritten.lines().map(str::to_string).collect()
+        }
+        "loose_comparison" => php_analysis::loose_comparison::loose_comparisons(
+            &result.program,
+            source,
+            &Default::default(),
+        )
+        .iter()
+        .map(|found| {
+            let fix = found.fix.as_ref().map_or(String::new(), |edit| {
+                format!(" (fix: `{}`)", edit.replacement)
+            });
+            format_line(
+                map,
+                found.span,
+                format_args!("{}: {found}{fix}", found.rule),
+            )
+        })
+        .collect(),
+        "psr" => {
+            // Every fixture is checked as `src/Model/User.php`, with `App\`
+            // autoloading from `src/`.
+            let mut options = php_analysis::psr::PsrOptions::default();
+            options.psr4.add("App\\", "src/");
+            php_analysis::psr::psr_violations(
+                &result.program,
+                source,
+                "src/Model/User.php",
+                &options,
+            )
+            .iter()
+            .map(|v| format_line(map, v.span, format_args!("{}: {v}", v.rule)))
+            .collect()
+        }
+        "string_literals" => {
+            php_analysis::string_literals::string_literals(&result.program, source)
+                .iter()
+                .map(|s| {
+                    format_line(
+                        map,
+                        s.span,
+                        format_args!("{:?} {:?} = {:?} ({:?})", s.kind, s.raw, s.value, s.context),
+                    )
+                })
+                .collect()
+        }
+        "strict_comparison" => {
+            use php_analysis::codemod::Codemod;
+            let rewritten = php_analysis::codemod::strict_comparison::StrictComparison::default()
+                .apply(&result.program, source);
+            rewritten.lines().map(str::to_string).collect()
+        }
+        "docblock_types" => {
+            use php_analysis::codemod::docblock_types::DocblockTypes;
+            use php_analysis::codemod::Codemod;
+            let mut codemod = DocblockTypes {
+                remove_tags: true,
+                ..DocblockTypes::default()
+            };
+            let mut lines: Vec<String> = codemod
+                .apply(&result.program, source)
+                .lines()
+                .map(str::to_string)
+                .collect();
+            lines.push("---".to_string());
+            codemod.target = php_rs_parser::PhpVersion::Php74;
+            codemod.remove_tags = false;
+            let older = codemod.apply(&result.program, source);
+            lines.extend(older.lines().map(str::to_string));
+            lines
+        }
+        "rename_parameter" => {
+            use php_analysis::codemod::rename_parameter::RenameParameter;
+            use php_analysis::codemod::Codemod;
+            let rename = RenameParameter {
+                target: "App\\Cache::__construct".to_string(),
+                from: "force".to_string(),
+                to: "overwrite".to_string(),
+            };
+            let rewritten = rename.apply(&result.program, source);
+            rewritten.lines().map(str::to_string).collect()
+        }
+        "sort_imports" => {
+            use php_analysis::codemod::sort_imports::{GroupUse, SortImports};
+            use php_analysis::codemod::Codemod;
+            let mut fixer = SortImports {
+                groups: vec!["App".to_string()],
+                group_use: GroupUse::Split,
+            };
+            let mut lines: Vec<String> = fixer
+                .apply(&result.program, source)
+                .lines()
+                .map(str::to_string)
+                .collect();
+            lines.push("---".to_string());
+            fixer.group_use = GroupUse::Merge;
+            let merged = fixer.apply(&result.program, source);
+            lines.extend(merged.lines().map(str::to_string));
+            lines
+        }
+        "trailing_commas" => {
+            use php_analysis::codemod::trailing_commas::NormalizeTrailingCommas;
+            use php_analysis::codemod::Codemod;
+            let mut fixer = NormalizeTrailingCommas::default();
+            let mut lines: Vec<String> = fixer
+                .apply(&result.program, source)
+                .lines()
+                .map(str::to_string)
+                .collect();
+            lines.push("---".to_string());
+            fixer.options = php_printer::PrintOptions::default()
+                .with_trailing_commas(php_printer::TrailingCommas::Never)
+                .with_trailing_commas_in(
+                    php_printer::CommaList::Params,
+                    php_printer::TrailingCommas::Preserve,
+                );
+            let removed = fixer.apply(&result.program, source);
+            lines.extend(removed.lines().map(str::to_string));
+            lines
+        }
+        "labels" => php_analysis::labels::validate(&result.program)
+            .iter()
+            .map(|e| format_line(map, e.span, e))
+            .collect(),
+        "members" => {
+            let mut table = php_analysis::members::MemberTable::new();
+            table.add_file(&result.program);
+            let mut lines: Vec<String> = table
+                .diagnostics()
+                .iter()
+                .map(|d| format_line(map, d.span(), d))
+                .collect();
+            for class in ["Greeter", "Child", "Shape", "Suit"] {
+                for method in table.methods(class) {
+                    let origin = match &method.origin {
+                        php_analysis::members::MethodOrigin::Own => "own".to_string(),
+                        php_analysis::members::MethodOrigin::Trait(t) => format!("trait {t}"),
+                        php_analysis::members::MethodOrigin::Inherited(c) => format!("from {c}"),
+                    };
+                    lines.push(format!(
+                        "{class}::{} -> {}::{} ({origin}, {:?}{}{})",
+                        method.name,
+                        method.declared_in,
+                        method.declared_name,
+                        method.visibility,
+                        if method.is_static { ", static" } else { "" },
+                        if method.is_abstract { ", abstract" } else { "" },
+                    ));
+                }
+            }
+            lines
+        }
+        "normalize" => {
+            let out = bumpalo::Bump::new();
+            let lowered = php_analysis::normalize::lower_interpolation(&out, &result.program);
+            lowered
+                .stmts
+                .iter()
+                .filter_map(|stmt| match &stmt.kind {
+                    php_ast::StmtKind::Echo(echo) => Some(echo.exprs.iter()),
+                    _ => None,
+                })
+                .flatten()
+                .map(|expr| format_line(map, expr.span, render_lowered(source, expr)))
+                .collect()
+        }
+        "assignment_in_condition" => {
+            php_analysis::assignment_in_condition::assignments_in_conditions(
+                &result.program,
+                &Default::default(),
+            )
+            .iter()
+            .map(|found| format_line(map, found.span, found))
+            .collect()
+        }
+        "lower" => {
+            let out = bumpalo::Bump::new();
+            let lowered = php_analysis::normalize::lower(&out, &result.program);
+            php_printer::pretty_print(&lowered)
+                .lines()
+                .map(str::to_string)
+                .collect()
+        }
+        "arrow_to_closure" => {
+            let out = bumpalo::Bump::new();
+            let lowered =
+                php_analysis::normalize::arrow_functions_to_closures(&out, &result.program);
+            php_printer::pretty_print(&lowered)
+                .lines()
+                .map(str::to_string)
+                .collect()
+        }
+        "properties" => {
+            use php_analysis::properties::{class_properties, trait_properties};
+            let mut lines = Vec::new();
+            for stmt in result.program.stmts.iter() {
+                let properties = match &stmt.kind {
+                    php_ast::StmtKind::Class(class) => class_properties(class),
+                    php_ast::StmtKind::Trait(trait_decl) => trait_properties(trait_decl),
+                    _ => continue,
+                };
+                for p in properties {
+                    let ty = p.type_hint.map_or("mixed", |t| &source[t.span.to_range()]);
+                    let set = p
+                        .set_visibility
+                        .map_or(String::new(), |v| format!(" set {v:?}"));
+                    let flags = [
+                        (p.is_readonly, " readonly"),
+                        (p.is_static, " static"),
+                        (p.is_promoted(), " promoted"),
+                        (p.default.is_some(), " default"),
+                        (!p.hooks.is_empty(), " hooked"),
+                    ]
+                    .iter()
+                    .filter(|(on, _)| *on)
+                    .map(|(_, flag)| *flag)
+                    .collect::<String>();
+                    lines.push(format_line(
+                        map,
+                        p.span,
+                        format_args!("${}: {ty} ({:?}{set}{flags})", p.name, p.visibility),
+                    ));
+                }
+            }
+            lines
+        }
+        "purity" => {
+            let mut analysis = php_analysis::purity::PurityAnalysis::new();
+            analysis.add_file(&result.program);
+            let results = analysis.finish();
+            let mut lines = Vec::new();
+            for report in results.iter() {
+                lines.push(format_line(
+                    map,
+                    report.span,
+                    format_args!("{} is {}", report.name, report.purity),
+                ));
+                for effect in &report.effects {
+                    lines.push(format!("  {}", format_line(map, effect.span, &effect.kind)));
+                }
+            }
+            lines
+        }
+        "attributes" => php_analysis::attributes::attributes(&result.program)
+            .iter()
+            .map(|a| {
+                let args: Vec<String> = a
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        let value = arg
+                            .value
+                            .as_ref()
+                            .map_or("?".to_string(), |v| v.to_string());
+                        match &arg.name {
+                            Some(name) => format!("{name}: {value}"),
+                            None => value,
+                        }
+                    })
+                    .collect();
+                format_line(
+                    map,
+                    a.span,
+                    format_args!(
+                        "{:?} {}: {}({})",
+                        a.target,
+                        a.owner,
+                        a.name,
+                        args.join(", ")
+                    ),
+                )
+            })
+            .collect(),
+        "suppressions" => php_analysis::suppressions::suppressions(&result.program)
+            .iter()
+            .map(|s| {
+                let (line, col) = map.line_col(s.stmt_span.start).to_one_based();
+                format_line(
+                    map,
+                    s.operator_span,
+                    format_args!("@ on {} in statement at {line}:{col}", s.target),
+                )
+            })
+            .collect(),
+        "constants" => {
+            let mut table = php_analysis::constants::ConstantTable::new();
+            table.add_file(&result.program);
+            let mut lines: Vec<String> = table
+                .definitions()
+                .iter()
+                .map(|d| {
+                    let conditional = if d.conditional { ", conditional" } else { "" };
+                    format_line(
+                        map,
+                        d.span,
+                        format_args!("{} ({:?}{conditional})", d.name, d.kind),
+                    )
+                })
+                .collect();
+            lines.extend(
+                table
+                    .dynamic_defines()
+                    .iter()
+                    .map(|d| format_line(map, d.span, "define() with unknown name")),
+            );
+            lines
+        }
+        "symbols" => {
+            let mut index = php_analysis::symbols::SymbolIndex::new();
+            index.add_file("a.php", &result.program);
+            let mut lines: Vec<String> = index
+                .symbols()
+                .map(|s| format_line(map, s.span, format_args!("{:?} {}", s.kind, s.fqn)))
+                .collect();
+            for query in ["user", "usrctl", "App\\Model\\User::", "ACTIVE", "zzz"] {
+                lines.push(format!("? {query}"));
+                lines.extend(
+                    index
+                        .workspace_symbols(query)
+                        .iter()
+                        .map(|m| format!("  {} ({})", m.symbol.fqn, m.score)),
+                );
+            }
+            lines
+        }
+        "resolve" => php_analysis::resolve::resolve_names(&result.program)
+            .iter()
+            .map(|n| {
+                let written = &source[n.span.to_range()];
+                let message = match &n.fallback {
+                    Some(fallback) => {
+                        format!("{:?} {written} -> {} (or {fallback})", n.role, n.fqn)
+                    }
+                    None => format!("{:?} {written} -> {}", n.role, n.fqn),
+                };
+                format_line(map, n.span, message)
+            })
+            .collect(),
+        "hover" => {
+            // Every distinct hover, found by hovering each byte.
+            let mut hovers: Vec<php_analysis::hover::Hover> = Vec::new();
+            for offset in 0..source.len() as u32 {
+                if let Some(hover) = php_analysis::hover::hover_at(&result.program, source, offset)
+                {
+                    if hovers.last() != Some(&hover) {
+                        hovers.push(hover);
+                    }
+                }
+            }
+            hovers
+                .iter()
+                .map(|h| {
+                    let mut parts = vec![match h.kind {
+                        Some(kind) => format!("{kind:?} {}", h.name),
+                        None => format!("Variable {}", h.name),
+                    }];
+                    parts.extend(h.ty.as_ref().map(|ty| format!("type {ty}")));
+                    parts.extend(h.signature.as_ref().map(|sig| format!("`{sig}`")));
+                    parts.extend(h.summary.clone());
+                    format_line(map, h.span, parts.join(" | "))
+                })
+                .collect()
+        }
+        "inlay_hints" => {
+            use php_analysis::inlay_hints::{inlay_hints, Signatures};
+            let mut signatures = Signatures::new();
+            signatures.add_file("test.php", &result.program);
+            inlay_hints(&result.program, source, &signatures)
+                .iter()
+                .map(|hint| {
+                    let offset = php_ast::Span::new(hint.offset, hint.offset);
+                    format_line(
+                        map,
+                        offset,
+                        format_args!("{:?} `{}`", hint.kind, hint.label),
+                    )
+                })
+                .collect()
+        }
+        "code_action" => {
+            use php_analysis::code_action::{code_actions, default_providers, fix_all};
+            use php_analysis::codemod::apply_edits;
+            let providers = default_providers();
+            let whole = php_ast::Span::new(0, source.len() as u32);
+            let mut lines: Vec<String> = code_actions(&result, whole, &providers)
+                .iter()
+                .map(|action| {
+                    let edits: Vec<String> = action
+                        .edits
+                        .iter()
+                        .map(|edit| format!("`{}`", edit.replacement.escape_debug()))
+                        .collect();
+                    let mut message =
+                        format!("{} {}: {}", action.kind, action.title, edits.join(", "));
+                    for diagnostic in &action.diagnostics {
+                        message.push_str(&format!(" ({})", diagnostic.message));
+                    }
+                    format_line(map, action.span(), message)
+                })
+                .collect();
+            lines.push("--- fixed ---".to_string());
+            let fixed = apply_edits(source, &fix_all(&result, &providers));
+            lines.extend(fixed.lines().map(str::to_string));
+            lines
+        }
+        "dominance" => {
+            use php_analysis::cfg::Cfg;
+            use php_analysis::dominance::Dominators;
+            let cfg = Cfg::build(&result.program.stmts);
+            let doms = Dominators::compute(&cfg);
+            let post = Dominators::post_dominators(&cfg);
+            let label = |id| block_label(map, &cfg, id);
+            let list = |ids: &[usize]| {
+                ids.iter()
+                    .map(|&id| label(id))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            (0..cfg.blocks().len())
+                .filter(|&id| cfg.is_reachable(id) && id != Cfg::ENTRY)
+                .map(|id| {
+                    let idom = doms.immediate_dominator(id).map_or("-".to_string(), label);
+                    let ipdom = post.immediate_dominator(id).map_or("-".to_string(), label);
+                    format!(
+                        "{}: idom {idom}, ipdom {ipdom}, frontier [{}]",
+                        label(id),
+                        list(doms.frontier(id))
+                    )
+                })
+                .collect()
+        }
+        "dataflow" => {
+            use php_analysis::cfg::{Cfg, CfgElement};
+            use php_analysis::dataflow::{solve_gen_kill, GenKillAnalysis};
+            let cfg = Cfg::build(&result.program.stmts);
+            let mut names = Vec::new();
+            for block in cfg.blocks() {
+                for element in &block.elements {
+                    let accesses = accesses(element);
+                    for name in accesses.reads.into_iter().chain(accesses.writes) {
+                        if !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+                }
+            }
+            let live = LiveVariables { names };
+            let facts = solve_gen_kill(&cfg, &live);
+            let mut lines = Vec::new();
+            for (id, block) in cfg.blocks().iter().enumerate() {
+                if !cfg.is_reachable(id) {
+                    continue;
+                }
+                let before = facts.element_facts(&cfg, &GenKillAnalysis(&live), id);
+                for (element, fact) in block.elements.iter().zip(&before) {
+                    if let CfgElement::Stmt(stmt) = element {
+                        let vars: Vec<_> =
+                            fact.iter().map(|i| format!("${}", live.names[i])).collect();
+                        lines.push((stmt.span, format!("live: [{}]", vars.join(", "))));
+                    }
+                }
+            }
+            lines.sort_by_key(|(span, _)| *span);
+            lines
+                .into_iter()
+                .map(|(span, line)| format_line(map, span, line))
+                .collect()
+        }
+        "definite_assignment" => {
+            php_analysis::definite_assignment::undefined_variables(&result.program)
+                .iter()
+                .map(|f| format_line(map, f.span, format!("{f} (in {})", f.unit)))
+                .collect()
+        }
+        "scope_info" => {
+            use php_analysis::scope_info::ScopeInfo;
+            use php_ast::{ClassMemberKind, StmtKind};
+            let mut lines = Vec::new();
+            for stmt in result.program.stmts.iter() {
+                match &stmt.kind {
+                    StmtKind::Function(func) => {
+                        render_scope(map, stmt.span, &ScopeInfo::analyze(func), 0, &mut lines)
+                    }
+                    StmtKind::Class(class) => {
+                        for member in class.members.iter() {
+                            if let ClassMemberKind::Method(method) = &member.kind {
+                                let info = ScopeInfo::analyze_method(method);
+                                render_scope(map, member.span, &info, 0, &mut lines);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            lines
+        }
+        "min_version" => {
+            use php_analysis::min_version::VersionInference;
+            let mut inference = VersionInference::new();
+            inference.add_file(source);
+            let report = inference.finish();
+            let mut lines: Vec<String> = report
+                .requirements
+                .iter()
+                .map(|r| format_line(map, r.span, format!("{} ({})", r.feature, r.version())))
+                .collect();
+            lines.push(format!("requires PHP {}", report.required));
+            lines
+        }
+        "nullability" => php_analysis::nullability::null_dereferences(&result.program)
+            .iter()
+            .map(|f| format_line(map, f.span, format!("{f} (in {})", f.unit)))
+            .collect(),
+        "directives" => {
+            php_analysis::directives::Directives::collect(source, &result.comments, &result.program)
+                .iter()
+                .map(|d| {
+                    let stmt = d.stmt.map_or(String::new(), |stmt| {
+                        let (line, col) = map.line_col(stmt.start).to_one_based();
+                        format!(", statement at {line}:{col}")
+                    });
+                    format_line(
+                        map,
+                        d.span,
+                        format_args!("{} -> line {}{stmt}", d.kind, d.line),
+                    )
+                })
+                .collect()
+        }
+        "inline_var" => {
+            let directives = php_analysis::directives::Directives::collect(
+                source,
+                &result.comments,
+                &result.program,
+            );
+            let types = php_analysis::inline_var::inline_var_types(&directives, &result.program);
+            let assertions = types.assertions().iter().map(|a| {
+                let (line, col) = map.line_col(a.stmt.start).to_one_based();
+                format_line(
+                    map,
+                    a.annotation,
+                    format_args!("${}: {} ({}) at {line}:{col}", a.variable, a.ty, a.target),
+                )
+            });
+            let issues = types
+                .issues()
+                .iter()
+                .map(|issue| format_line(map, issue.span(), issue));
+            assertions.chain(issues).collect()
+        }
+        other => panic!("unknown analysis fixture directory: {other}"),
+    };
+    lines.join("\n")
+}
+
+/// Recursively collect all `.phpt` files under `dir`.
+fn collect_phpt_files(dir: &std::path::Path) -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            paths.extend(collect_phpt_files(&path));
+        } else if path.extension().is_some_and(|ext| ext == "phpt") {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Run all analysis fixture tests from `.phpt` files.
+///
+/// Set `UPDATE_FIXTURES=1` to regenerate expected output.
+#[test]
+fn fixtures() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let update = std::env::var("UPDATE_FIXTURES").is_ok();
+    let mut paths = collect_phpt_files(&dir);
+    paths.sort();
+
+    let failures = Mutex::new(Vec::new());
+
+    paths.par_iter().for_each(|path| {
+        let rel = path
+            .strip_prefix(&dir)
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let analysis = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let content = std::fs::read_to_string(path).unwrap();
+        let fixture = parse_analysis_fixture(&content);
+        let actual = run_analysis(&analysis, &fixture.source);
+
+        if update {
+            let new_content = format!(
+                "===source===\n{}\n===report===\n{}\n",
+                fixture.source, actual
+            );
+            std::fs::write(path, new_content).unwrap();
+        } else if actual != fixture.expected {
+            failures.lock().unwrap().push(format!(
+                "report mismatch in {rel}\nexpected:\n{}\nactual:\n{actual}",
+                fixture.expected
+            ));
+        }
+    });
+
+    let failures = failures.into_inner().unwrap();
+    if !failures.is_empty() {
+        panic!(
+            "{} fixture(s) failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
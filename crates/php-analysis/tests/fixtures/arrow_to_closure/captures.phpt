@@ -0,0 +1,51 @@
+===source===
+<?php
+$add = fn($x) => $x + $offset;
+$nested = fn($a) => fn($b) => $a + $b + $c;
+$uses = fn() => function () use ($total, &$log) { return $total; };
+$static = static fn(int ...$xs): int => array_sum($xs) * $factor;
+$byRef = fn&(array &$arr) => $arr[$key];
+$method = fn() => $this->run($_GET['id'], $$name);
+$anon = fn() => new class($arg) { public function f() { return $hidden; } };
+$throws = fn(): never => throw new LogicException($message);
+$attr = #[Pure] fn() => $x;
+===report===
+<?php
+$add = function($x) use ($offset) {
+    return $x + $offset;
+};
+$nested = function($a) use ($c) {
+    return function($b) use ($a, $c) {
+        return $a + $b + $c;
+    };
+};
+$uses = function() use ($total, $log) {
+    return function() use ($total, &$log) {
+        return $total;
+    };
+};
+$static = static function(int ...$xs) use ($factor): int {
+    return array_sum($xs) * $factor;
+};
+$byRef = function &(array &$arr) use ($key) {
+    return $arr[$key];
+};
+$method = function() use ($name) {
+    return $this->run($_GET['id'], $$name);
+};
+$anon = function() use ($arg) {
+    return new class($arg)
+    {
+        public function f()
+        {
+            return $hidden;
+        }
+    };
+};
+$throws = function() use ($message): never {
+    throw new LogicException($message);
+};
+$attr = #[Pure]
+function() use ($x) {
+    return $x;
+};
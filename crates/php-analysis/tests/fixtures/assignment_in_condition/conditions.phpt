@@ -0,0 +1,28 @@
+===source===
+<?php
+if ($user = find($id)) {}
+if (($user = find($id))) {}
+if ($a == 1) {} elseif ($b = load()) {}
+while ($row = $stmt->fetch()) {}
+do {} while (!$done = step());
+for ($i = 0; $i = next_index(); $i++) {}
+if ($ok && $value = compute()) {}
+if ($x or $y = fallback()) {}
+while (false !== $line = fgets($fh)) {}
+if ($count += 1) {}
+if (f($arg = 1)) {}
+$label = ($name = get_name()) ? $name : 'anon';
+$label = $name = get_name() ? 'yes' : 'no';
+$label = ($name = get_name()) ?: 'anon';
+function check() {
+    $cb = function () use (&$v) { if ($v = pull()) { return $v; } };
+}
+===report===
+2:5: assignment in if condition; use `==` to compare or wrap the assignment in parentheses
+4:25: assignment in elseif condition; use `==` to compare or wrap the assignment in parentheses
+5:8: assignment in while condition; use `==` to compare or wrap the assignment in parentheses
+6:15: assignment in do-while condition; use `==` to compare or wrap the assignment in parentheses
+7:14: assignment in for condition; use `==` to compare or wrap the assignment in parentheses
+8:12: assignment in if condition; use `==` to compare or wrap the assignment in parentheses
+9:11: assignment in if condition; use `==` to compare or wrap the assignment in parentheses
+17:39: assignment in if condition; use `==` to compare or wrap the assignment in parentheses
@@ -0,0 +1,55 @@
+===source===
+<?php
+namespace App\Http;
+
+use Framework\Routing\Route;
+use Framework\Inject as Di;
+use Attribute;
+
+#[Attribute(\Attribute::TARGET_METHOD | \Attribute::TARGET_FUNCTION)]
+final class Cached {}
+
+const PREFIX = '/api';
+
+#[Route(PREFIX . '/users', name: 'users')]
+class Users extends Controller {
+    const DEFAULT_LIMIT = 20;
+
+    #[Di(Mailer::class, parent: parent::class, self: self::class)]
+    private $mailer;
+
+    #[Route(self::PREFIX . '/{id}', methods: ['GET', 'HEAD'], requirements: ['id' => '\d+'])]
+    #[Cached(ttl: 60 * 60)]
+    public function show(#[FromPath('id')] int $id, #[Limit(self::DEFAULT_LIMIT, ...self::LIMITS)] $limit) {}
+
+    #[Deprecated(since: __CLASS__)]
+    const OLD = 1;
+
+    public string $name { #[Getter(__NAMESPACE__)] get => 'n'; }
+}
+
+enum Suit: string {
+    #[Label('Hearts', color: Color::Red)]
+    case Hearts = 'H';
+}
+
+#[Pure]
+function helper(#[\SensitiveParameter] $secret) {
+    return #[Inline(LATER)] fn(#[Unused] $x) => new #[Anonymous(self::class)] class {};
+}
+===report===
+8:3: Class App\Http\Cached: Attribute(?)
+13:3: Class App\Http\Users: Framework\Routing\Route('/api/users', name: 'users')
+17:7: Property App\Http\Users::$mailer: Framework\Inject('App\\Http\\Mailer', parent: 'App\\Http\\Controller', self: 'App\\Http\\Users')
+20:7: Method App\Http\Users::show: Framework\Routing\Route(?, methods: ['GET', 'HEAD'], requirements: ['id' => '\\d+'])
+21:7: Method App\Http\Users::show: App\Http\Cached(ttl: 3600)
+22:28: Parameter $id: App\Http\FromPath('id')
+22:55: Parameter $limit: App\Http\Limit(20, ?)
+24:7: ClassConstant App\Http\Users::OLD: App\Http\Deprecated(since: 'App\\Http\\Users')
+27:29: Method App\Http\Users::$name::get: App\Http\Getter('App\\Http')
+31:7: ClassConstant App\Http\Suit::Hearts: App\Http\Label('Hearts', color: ?)
+35:3: Function App\Http\helper: App\Http\Pure()
+36:19: Parameter $secret: SensitiveParameter()
+37:14: Function {closure}: App\Http\Inline(?)
+37:34: Parameter $x: App\Http\Unused()
+37:55: Class class@anonymous: App\Http\Anonymous('class@anonymous')
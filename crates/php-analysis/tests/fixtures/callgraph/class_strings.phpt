@@ -0,0 +1,41 @@
+===source===
+<?php
+namespace App;
+
+class Mailer {
+    public static function create() {}
+    public function send() {}
+}
+
+function handle_event() {}
+
+function boot(string $param) {
+    $class = __NAMESPACE__ . '\Mailer';
+    new $class();
+    $class::create();
+    call_user_func([$class, 'send']);
+    call_user_func_array('App\Mailer::create', []);
+
+    $fn = sprintf('%s\%s_%s', __NAMESPACE__, 'handle', 'event');
+    $fn();
+
+    $kind = $param ? 'Mailer' : 'Logger';
+    $target = "App\\{$kind}";
+    new $target;
+
+    new $param;
+    $loop = 'App\Mailer';
+    foreach ([] as $loop) {}
+    new $loop;
+}
+===report===
+13:5: App\boot -> App\Mailer::__construct
+14:5: App\boot -> App\Mailer::create
+15:5: App\boot -> App\call_user_func
+15:5: App\boot -> App\Mailer::send
+16:5: App\boot -> App\call_user_func_array
+16:5: App\boot -> App\Mailer::create
+18:11: App\boot -> App\sprintf
+19:5: App\boot -> App\handle_event
+23:5: App\boot -> App\Mailer::__construct
+23:5: App\boot -> App\Logger::__construct
@@ -0,0 +1,24 @@
+===source===
+<?php
+function add(array $a, array $b) {
+    $out = [];
+    foreach ($a as $k => $v) {
+        $out[$k] = $v + $b[$k] * 2;
+    }
+    return $out;
+}
+
+function subtract(array $a, array $b) {
+    $out = [];
+    foreach ($a as $k => $v) {
+        $out[$k] = $v - $b[$k] * 2;
+    }
+    return $out;
+}
+
+// Repeats of one statement overlap themselves and are not reported.
+$total = $price * $quantity + $shipping - $discount;
+$total = $price * $quantity + $shipping - $discount;
+$total = $price * $quantity + $shipping - $discount;
+===report===
+
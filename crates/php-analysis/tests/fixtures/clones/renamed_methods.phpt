@@ -0,0 +1,27 @@
+===source===
+<?php
+class Invoice
+{
+    public function total(array $lines): float
+    {
+        $sum = 0.0;
+        foreach ($lines as $line) {
+            $sum += $line->price * $line->quantity;
+        }
+        return round($sum, 2);
+    }
+}
+
+class Quote
+{
+    public function amount(array $items): int
+    {
+        $result = 0;
+        foreach ($items as $item) {
+            $result += $item->cost * $item->count;
+        }
+        return round($result, 0);
+    }
+}
+===report===
+2:1-12:2 ~ 14:1-24:2 (31 nodes)
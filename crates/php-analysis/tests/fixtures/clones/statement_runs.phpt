@@ -0,0 +1,28 @@
+===source===
+<?php
+function import(string $path) {
+    $handle = fopen($path, 'r');
+    $header = fgetcsv($handle);
+    $rows = [];
+    while (($row = fgetcsv($handle)) !== false) {
+        $rows[] = array_combine($header, $row);
+    }
+    fclose($handle);
+    return new Import($rows);
+}
+
+function preview(string $file, int $limit) {
+    if ($limit < 1) {
+        throw new InvalidArgumentException('limit');
+    }
+    $fp = fopen($file, 'r');
+    $columns = fgetcsv($fp);
+    $records = [];
+    while (($record = fgetcsv($fp)) !== false) {
+        $records[] = array_combine($columns, $record);
+    }
+    fclose($fp);
+    return array_slice($records, 0, $limit);
+}
+===report===
+3:5-9:21 ~ 17:5-23:17 (51 nodes)
@@ -0,0 +1,42 @@
+===source===
+<?php
+use Zeta\Logger;
+use Alpha\Cache;
+
+function find(Cache $cache = null, int $id = 0) {
+    $key = strtolower($id)
+    if (trim($key) == 'all' || $id != '1') {
+        return in_array((int) $id, [1, 2]);
+    }
+    return $cache->get($key) $id;
+}
+
+foo($a, [1, 2;
+if ($a) {
+    echo $a;
+===report===
+2:1: refactor.rewrite Sort imports: `use Alpha\\Cache;\nuse Zeta\\Logger;`
+5:15: refactor.rewrite Make nullable types explicit: `?`
+7:5: quickfix Insert `;`: `;` (expected ';' after expression)
+7:9: quickfix Use `===`: `===` (loose comparison `==` against a literal; use `===`)
+8:16: quickfix Pass `$strict = true`: `, true` (`in_array()` compares loosely; pass `true` as the `$strict` argument)
+10:30: quickfix Insert `;`: `;` (expected ';' after return statement)
+13:14: quickfix Insert `]`: `]` (expected ']', found ';')
+13:14: quickfix Insert `)`: `)` (expected ')', found ';')
+15:13: quickfix Insert `}`: `}` (unclosed delimiter: expected '}')
+--- fixed ---
+<?php
+use Zeta\Logger;
+use Alpha\Cache;
+
+function find(Cache $cache = null, int $id = 0) {
+    $key = strtolower($id);
+    if (trim($key) === 'all' || $id != '1') {
+        return in_array((int) $id, [1, 2], true);
+    }
+    return $cache->get($key); $id;
+}
+
+foo($a, [1, 2]);
+if ($a) {
+    echo $a;}
@@ -0,0 +1,45 @@
+===source===
+<?php
+namespace App\Config;
+
+const VERSION = '2.1', BUILD = 42;
+define('DEBUG', false);
+\define('Vendor\Lib\FLAG', true);
+define(__NAMESPACE__ . '\ROOT', __DIR__);
+
+if (!defined('LOCALE')) {
+    define('LOCALE', 'en');
+} elseif (PHP_OS === 'WIN') {
+    define('LOCALE', 'en_US');
+} else {
+    define('LOCALE_FALLBACK', 'C');
+}
+defined('TIMEZONE') or define('TIMEZONE', 'UTC');
+$prefix = 'APP_';
+define($prefix . 'NAME', 'app');
+define($mode === 'dev' ? 'DEV' : 'PROD', true);
+define($unknown, 1);
+
+function boot() {
+    define('BOOTED', true);
+}
+
+foreach (['A', 'B'] as $letter) {
+    define("LETTER_$letter", $letter);
+}
+===report===
+4:7: App\Config\VERSION (Const)
+4:24: App\Config\BUILD (Const)
+5:1: DEBUG (Define)
+6:1: Vendor\Lib\FLAG (Define)
+7:1: App\Config\ROOT (Define)
+10:5: LOCALE (Define, conditional)
+12:5: LOCALE (Define, conditional)
+14:5: LOCALE_FALLBACK (Define, conditional)
+16:24: TIMEZONE (Define, conditional)
+18:1: APP_NAME (Define)
+19:1: DEV (Define, conditional)
+19:1: PROD (Define, conditional)
+23:5: BOOTED (Define, conditional)
+20:1: define() with unknown name
+27:5: define() with unknown name
@@ -0,0 +1,57 @@
+===source===
+<?php
+namespace App\Billing {
+    use App\Shop\Cart;
+
+    class Invoice { public function __construct(public Cart $cart) {} }
+    class Payment { public function invoice(): Invoice {} }
+}
+
+namespace App\Shop {
+    use App\Billing\Invoice;
+
+    class Cart { public function checkout(): Invoice {} }
+    class Product {}
+    class Line { public Product $product; public Cart $cart; }
+    class Catalog { public function find(): ?Product {} }
+}
+
+namespace App\Reports {
+    use App\Billing\{Invoice, Payment};
+    use App\Shop\{Cart, Product};
+
+    class Revenue {
+        public function run(Invoice $i, Payment $p, Cart $c, Product $x): \ArrayObject {}
+    }
+}
+===report===
+5:5: App\Billing\Invoice: Ca=3 Ce=1 I=0.25 -> [App\Shop\Cart]
+6:5: App\Billing\Payment: Ca=1 Ce=1 I=0.50 -> [App\Billing\Invoice]
+22:5: App\Reports\Revenue: Ca=0 Ce=5 I=1.00 -> [App\Billing\Invoice, App\Billing\Payment, App\Shop\Cart, App\Shop\Product, ArrayObject]
+12:5: App\Shop\Cart: Ca=3 Ce=1 I=0.25 -> [App\Billing\Invoice]
+15:5: App\Shop\Catalog: Ca=0 Ce=1 I=1.00 -> [App\Shop\Product]
+14:5: App\Shop\Line: Ca=0 Ce=2 I=1.00 -> [App\Shop\Cart, App\Shop\Product]
+13:5: App\Shop\Product: Ca=3 Ce=0 I=0.00 -> []
+ArrayObject (external): Ca=1 Ce=0 I=0.00 -> []
+\ (external): Ca=1 Ce=0 I=0.00 -> []
+App\Billing: Ca=2 Ce=1 I=0.33 -> [App\Shop]
+App\Reports: Ca=0 Ce=3 I=1.00 -> [\, App\Billing, App\Shop]
+App\Shop: Ca=2 Ce=1 I=0.33 -> [App\Billing]
+3 others depend on App\Billing\Invoice; the limit is 2
+App\Reports\Revenue depends on 4 others; the limit is 2
+3 others depend on App\Shop\Cart; the limit is 2
+3 others depend on App\Shop\Product; the limit is 2
+dependency cycle between App\Billing\Invoice and App\Shop\Cart
+App\Reports depends on 3 others; the limit is 2
+dependency cycle between App\Billing and App\Shop
+digraph dependencies {
+    "\\" [style=dashed];
+    "App\\Billing";
+    "App\\Reports";
+    "App\\Shop";
+    "App\\Billing" -> "App\\Shop";
+    "App\\Reports" -> "\\";
+    "App\\Reports" -> "App\\Billing";
+    "App\\Reports" -> "App\\Shop";
+    "App\\Shop" -> "App\\Billing";
+}
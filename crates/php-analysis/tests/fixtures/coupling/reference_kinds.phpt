@@ -0,0 +1,57 @@
+===source===
+<?php
+namespace App\Domain;
+
+use App\Infra\Clock;
+use Psr\Log\LoggerInterface;
+
+#[Entity]
+final class Order extends Aggregate implements \JsonSerializable
+{
+    use Timestamps;
+
+    public function __construct(private LoggerInterface $logger) {}
+
+    public function place(Clock $clock): static
+    {
+        try {
+            $at = $clock::now();
+            $listener = new class extends Listener {};
+        } catch (\RuntimeException $e) {
+            return self::failed(parent::class);
+        }
+        if ($at instanceof \DateTimeImmutable) {
+            return $this;
+        }
+        return new Order($this->logger);
+    }
+}
+
+function helper(Clock $clock): Order {}
+
+$order = new Order(new NullLogger());
+===report===
+App\Domain\Aggregate (external): Ca=1 Ce=0 I=0.00 -> []
+App\Domain\Entity (external): Ca=1 Ce=0 I=0.00 -> []
+App\Domain\Listener (external): Ca=1 Ce=0 I=0.00 -> []
+7:3: App\Domain\Order: Ca=0 Ce=9 I=1.00 -> [App\Domain\Aggregate, App\Domain\Entity, App\Domain\Listener, App\Domain\Timestamps, App\Infra\Clock, DateTimeImmutable, JsonSerializable, Psr\Log\LoggerInterface, RuntimeException]
+App\Domain\Timestamps (external): Ca=1 Ce=0 I=0.00 -> []
+App\Infra\Clock (external): Ca=1 Ce=0 I=0.00 -> []
+DateTimeImmutable (external): Ca=1 Ce=0 I=0.00 -> []
+JsonSerializable (external): Ca=1 Ce=0 I=0.00 -> []
+Psr\Log\LoggerInterface (external): Ca=1 Ce=0 I=0.00 -> []
+RuntimeException (external): Ca=1 Ce=0 I=0.00 -> []
+\ (external): Ca=1 Ce=0 I=0.00 -> []
+App\Domain: Ca=0 Ce=3 I=1.00 -> [\, App\Infra, Psr\Log]
+App\Infra (external): Ca=1 Ce=0 I=0.00 -> []
+Psr\Log (external): Ca=1 Ce=0 I=0.00 -> []
+App\Domain depends on 3 others; the limit is 2
+digraph dependencies {
+    "\\" [style=dashed];
+    "App\\Domain";
+    "App\\Infra" [style=dashed];
+    "Psr\\Log" [style=dashed];
+    "App\\Domain" -> "\\";
+    "App\\Domain" -> "App\\Infra";
+    "App\\Domain" -> "Psr\\Log";
+}
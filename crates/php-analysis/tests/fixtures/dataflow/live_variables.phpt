@@ -0,0 +1,26 @@
+===source===
+<?php
+$a = 1;
+$b = 2;
+if ($c) {
+    $a = $b + 1;
+} else {
+    echo $b;
+}
+$i = 0;
+while ($i < $n) {
+    $i += 1;
+    echo $a;
+}
+$b = 3;
+echo $b;
+===report===
+2:1: live: [$c, $n]
+3:1: live: [$a, $c, $n]
+5:5: live: [$b, $n]
+7:5: live: [$a, $b, $n]
+9:1: live: [$a, $n]
+11:5: live: [$a, $i, $n]
+12:5: live: [$a, $i, $n]
+14:1: live: []
+15:1: live: [$b]
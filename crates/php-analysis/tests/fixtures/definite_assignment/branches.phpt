@@ -0,0 +1,58 @@
+===source===
+<?php
+function branches($flag, $items) {
+    if ($flag) {
+        $a = 1;
+    } else {
+        $a = 2;
+    }
+    echo $a;
+
+    if ($flag) {
+        $b = 1;
+    }
+    echo $b;
+
+    foreach ($items as $key => $item) {
+        $last = $item;
+    }
+    echo $key, $item, $last;
+
+    while ($flag) {
+        $looped = true;
+        echo $looped;
+    }
+    echo $looped;
+
+    for ($i = 0; $i < 3; $i++) {
+        $total = ($total ?? 0) + $i;
+    }
+    echo $i;
+
+    try {
+        $result = risky();
+    } catch (Exception $ex) {
+        echo $ex->getMessage();
+    }
+    echo $result;
+
+    $m = match ($flag) {
+        1 => $matched = 'one',
+        default => $matched = 'other',
+    };
+    echo $matched, $m;
+
+    $flag && $short = 1;
+    echo $short;
+
+    unset($a);
+    echo $a;
+}
+===report===
+13:10: variable $b might not be defined (in branches)
+18:10: variable $key might not be defined (in branches)
+18:16: variable $item might not be defined (in branches)
+18:23: variable $last might not be defined (in branches)
+24:10: variable $looped might not be defined (in branches)
+45:10: variable $short might not be defined (in branches)
+48:10: undefined variable $a (in branches)
@@ -0,0 +1,24 @@
+===source===
+<?php
+function guards(array $opts) {
+    if (isset($a)) {
+        echo $a;
+    }
+    echo $b ?? 'default';
+    $c ??= 1;
+    echo $c;
+    if (!empty($d['key'])) {
+        echo $d['key'];
+    }
+    if (!isset($e)) {
+        return;
+    }
+    echo $e;
+    echo isset($f) ? $f : 'none';
+    echo isset($g) && $g > 1;
+    echo !isset($h) || $h;
+    echo @$i;
+    echo $j;
+}
+===report===
+20:10: undefined variable $j (in guards)
@@ -0,0 +1,47 @@
+===source===
+<?php
+function byRef(&$out, $in) {}
+
+class Repo {
+    public function fill(array &$rows) {}
+
+    public function run() {
+        $this->fill($rows);
+        preg_match('/x/', 'x', $matches);
+        byRef($out, $in);
+        echo $rows, $matches, $out, $this;
+        static $count = 0;
+        global $config;
+        echo $count, $config, $_GET;
+        [$first, 'k' => $second] = [1, 'k' => 2];
+        echo $first, $second;
+        $list[] = 1;
+        echo $list;
+    }
+}
+
+function closures() {
+    $x = 1;
+    $f = function () use ($x, $y, &$z) {
+        echo $x, $y, $z, $w;
+    };
+    $g = fn() => $x + $missing;
+    echo $z;
+}
+
+function dynamic($data) {
+    echo $before;
+    extract($data);
+    echo $anything;
+}
+
+echo $undefinedAtTop;
+$defined = 1;
+echo $defined;
+===report===
+10:21: undefined variable $in (in Repo::run)
+24:31: undefined variable $y (in closures)
+25:26: undefined variable $w (in {closure})
+27:23: undefined variable $missing (in closures)
+32:10: undefined variable $before (in dynamic)
+37:6: undefined variable $undefinedAtTop (in {main})
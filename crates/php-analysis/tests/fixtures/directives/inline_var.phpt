@@ -0,0 +1,30 @@
+===source===
+<?php
+/** @var list<User> $users */
+$users = $repo->all();
+/** @var array<int, string> */
+$map = load();
+/** @psalm-var $id positive-int */
+$id = next_id();
+foreach ($rows as $row) {
+    /** @var Row|null $row */
+    /** @phpstan-var callable(int): void $cb */
+    $cb = $row->callback();
+}
+/**
+ * @var Foo $a
+ * @var Bar $b
+ */
+[$a, $b] = pair();
+$late = 1; /** @var int $late */
+/** @var */
+$none = 0;
+===report===
+2:1: @var list<User> $users -> line 3, statement at 3:1
+4:1: @var array<int, string> -> line 5, statement at 5:1
+6:1: @var positive-int $id -> line 7, statement at 7:1
+9:5: @var Row|null $row -> line 11, statement at 11:5
+10:5: @var callable(int): void $cb -> line 11, statement at 11:5
+13:1: @var Foo $a -> line 17, statement at 17:1
+13:1: @var Bar $b -> line 17, statement at 17:1
+18:12: @var int $late -> line 18, statement at 18:1
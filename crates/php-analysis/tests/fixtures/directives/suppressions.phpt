@@ -0,0 +1,24 @@
+===source===
+<?php
+// phpcs:ignoreFile
+// phpcs:ignore Generic.PHP.ForbiddenFunctions, Squiz.Commenting -- legacy API
+$h = mysql_connect();
+$x = eval($code); // phpcs:ignore
+# @phpcs:disable PSR12.Files.FileHeader
+function f() {
+    /* phpcs:enable */
+    // @lint-ignore unused-variable, dead-code
+    $unused = 1;
+    return 2; // @lint-ignore
+}
+// @lint-ignored is not a directive
+// phpcs:unknown
+// A plain comment.
+===report===
+2:1: phpcs:ignoreFile -> line 2
+3:1: phpcs:ignore Generic.PHP.ForbiddenFunctions, Squiz.Commenting -> line 4, statement at 4:1
+5:19: phpcs:ignore -> line 5, statement at 5:1
+6:1: phpcs:disable PSR12.Files.FileHeader -> line 6
+8:5: phpcs:enable -> line 8
+9:5: @lint-ignore unused-variable, dead-code -> line 10, statement at 10:5
+11:15: @lint-ignore -> line 11, statement at 11:5
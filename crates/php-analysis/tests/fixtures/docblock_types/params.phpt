@@ -0,0 +1,92 @@
+===source===
+<?php
+/**
+ * Format a price.
+ *
+ * @param integer $amount In cents.
+ * @param string|null $currency
+ * @param Money\Rounding $rounding
+ * @param bool &$rounded
+ * @param string ...$flags
+ */
+function format($amount, $currency, $rounding = null, &$rounded = false, ...$flags) {}
+
+/**
+ * @param int|string $id
+ * @param ?float $ratio
+ * @param int $limit
+ * @param array $options
+ * @param int[] $ids
+ * @param array<string, int> $counts
+ * @param positive-int $page
+ * @param resource $handle
+ * @param mixed $value
+ * @param int $typed
+ */
+function find($id, $ratio, $limit = 'all', $options = [], $ids, $counts, $page, $handle, $value, string $typed) {}
+
+/**
+ * @param false|null $flag
+ * @param true $yes
+ * @param int $twice
+ * @param string $twice
+ */
+function flags($flag, $yes, $twice) {}
+===report===
+<?php
+/**
+ * Format a price.
+ *
+ * @param integer $amount In cents.
+ */
+function format(int $amount, ?string $currency, ?Money\Rounding $rounding = null, bool &$rounded = false, string ...$flags) {}
+
+/**
+ * @param int $limit
+ * @param int[] $ids
+ * @param array<string, int> $counts
+ * @param positive-int $page
+ * @param resource $handle
+ * @param int $typed
+ */
+function find(int|string $id, ?float $ratio, $limit = 'all', array $options = [], $ids, $counts, $page, $handle, mixed $value, string $typed) {}
+
+/**
+ * @param int $twice
+ * @param string $twice
+ */
+function flags(?false $flag, true $yes, $twice) {}
+---
+<?php
+/**
+ * Format a price.
+ *
+ * @param integer $amount In cents.
+ * @param string|null $currency
+ * @param Money\Rounding $rounding
+ * @param bool &$rounded
+ * @param string ...$flags
+ */
+function format(int $amount, ?string $currency, ?Money\Rounding $rounding = null, bool &$rounded = false, string ...$flags) {}
+
+/**
+ * @param int|string $id
+ * @param ?float $ratio
+ * @param int $limit
+ * @param array $options
+ * @param int[] $ids
+ * @param array<string, int> $counts
+ * @param positive-int $page
+ * @param resource $handle
+ * @param mixed $value
+ * @param int $typed
+ */
+function find($id, ?float $ratio, $limit = 'all', array $options = [], $ids, $counts, $page, $handle, $value, string $typed) {}
+
+/**
+ * @param false|null $flag
+ * @param true $yes
+ * @param int $twice
+ * @param string $twice
+ */
+function flags($flag, $yes, $twice) {}
@@ -0,0 +1,160 @@
+===source===
+<?php
+/**
+ * @template T
+ */
+abstract class Repository extends Base
+{
+    /**
+     * @return static
+     */
+    abstract public function fresh();
+
+    /** @return self */
+    public function copy() { return clone $this; }
+
+    /**
+     * Load an entity.
+     *
+     * @param int $id
+     * @return T|null The entity, if any.
+     */
+    public function find($id) {}
+
+    /**
+     * @return void
+     */
+    public function __construct() {}
+
+    /**
+     * @return parent|null
+     */
+    public function base() {}
+
+    /**
+     * @return $this
+     */
+    public function touch() {}
+
+    /**
+     * @return never
+     */
+    public function fail() { throw new Exception(); }
+}
+
+/**
+ * @return self
+ */
+function make() {}
+
+/**
+ * @return mixed
+ */
+function anything(
+    $a, // keep this comment
+) {}
+
+/** @return int */
+function count_all(): int {}
+===report===
+<?php
+/**
+ * @template T
+ */
+abstract class Repository extends Base
+{
+    abstract public function fresh(): static;
+
+    public function copy(): self { return clone $this; }
+
+    /**
+     * Load an entity.
+     *
+     * @return T|null The entity, if any.
+     */
+    public function find(int $id) {}
+
+    /**
+     * @return void
+     */
+    public function __construct() {}
+
+    public function base(): ?parent {}
+
+    /**
+     * @return $this
+     */
+    public function touch() {}
+
+    public function fail(): never { throw new Exception(); }
+}
+
+/**
+ * @return self
+ */
+function make() {}
+
+function anything(
+    $a, // keep this comment
+): mixed {}
+
+/** @return int */
+function count_all(): int {}
+---
+<?php
+/**
+ * @template T
+ */
+abstract class Repository extends Base
+{
+    /**
+     * @return static
+     */
+    abstract public function fresh();
+
+    /** @return self */
+    public function copy(): self { return clone $this; }
+
+    /**
+     * Load an entity.
+     *
+     * @param int $id
+     * @return T|null The entity, if any.
+     */
+    public function find(int $id) {}
+
+    /**
+     * @return void
+     */
+    public function __construct() {}
+
+    /**
+     * @return parent|null
+     */
+    public function base(): ?parent {}
+
+    /**
+     * @return $this
+     */
+    public function touch() {}
+
+    /**
+     * @return never
+     */
+    public function fail() { throw new Exception(); }
+}
+
+/**
+ * @return self
+ */
+function make() {}
+
+/**
+ * @return mixed
+ */
+function anything(
+    $a, // keep this comment
+) {}
+
+/** @return int */
+function count_all(): int {}
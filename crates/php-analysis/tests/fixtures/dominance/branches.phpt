@@ -0,0 +1,31 @@
+===source===
+<?php
+$a = 1;
+if ($a) {
+    f();
+} elseif ($b) {
+    g();
+} else {
+    return;
+}
+while ($c) {
+    h();
+    if ($d) {
+        break;
+    }
+}
+i();
+===report===
+exit: idom 2:1, ipdom -, frontier []
+#2: idom 2:1, ipdom 10:8, frontier [exit]
+4:5: idom 2:1, ipdom #2, frontier [#2]
+5:11: idom 2:1, ipdom exit, frontier [exit, #2]
+6:5: idom 5:11, ipdom #2, frontier [#2]
+8:5: idom 5:11, ipdom exit, frontier [exit]
+10:8: idom #2, ipdom 16:1, frontier [exit, 10:8]
+16:1: idom 10:8, ipdom exit, frontier [exit]
+#10: idom 10:8, ipdom 16:1, frontier [16:1]
+11:5: idom 10:8, ipdom 16:1, frontier [10:8, 16:1]
+#12: idom #14, ipdom 10:8, frontier [10:8]
+13:9: idom 11:5, ipdom 16:1, frontier [16:1]
+#14: idom 11:5, ipdom #12, frontier [10:8]
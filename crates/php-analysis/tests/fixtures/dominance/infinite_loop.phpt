@@ -0,0 +1,15 @@
+===source===
+<?php
+if ($a) {
+    return;
+}
+while (true) {
+    f();
+}
+===report===
+exit: idom 3:5, ipdom -, frontier []
+#2: idom #4, ipdom -, frontier []
+3:5: idom 2:5, ipdom exit, frontier []
+#4: idom 2:5, ipdom -, frontier []
+5:8: idom #2, ipdom -, frontier [5:8]
+6:5: idom 5:8, ipdom -, frontier [5:8]
@@ -0,0 +1,50 @@
+===source===
+<?php
+namespace App;
+
+eval('$x = 1;');
+$name = 'x';
+echo $$name;
+echo ${'na' . 'me'};
+
+$fn = 'strlen';
+$fn('abc');
+(function () {})();
+call_user_func([$obj, 'run']);
+\call_user_func_array('run', []);
+
+$obj->$method();
+$obj->{'literal'}();
+Foo::$method();
+$obj->$prop = 1;
+echo $obj->{'literal'};
+echo Foo::$$prop;
+echo Foo::{$const};
+
+$instance = new $class();
+echo $class::VERSION;
+$class::create();
+echo $this::class;
+var_dump($x instanceof $class);
+
+extract($_POST);
+$vars = compact('name', 'fn');
+===report===
+4:1: eval
+6:6: variable variable
+7:6: variable variable
+10:1: variable function call
+12:1: call_user_func
+13:1: call_user_func
+15:1: variable method call
+17:1: variable method call
+18:1: variable property access
+20:6: variable property access
+20:11: variable variable
+21:6: variable class constant
+23:17: variable class reference
+24:6: variable class reference
+25:1: variable class reference
+27:24: variable class reference
+29:1: extract
+30:9: compact
@@ -0,0 +1,13 @@
+===source===
+<?php
+require __DIR__ . '/bootstrap.php';
+require_once dirname(__DIR__) . '/vendor/autoload.php';
+include 'config.php';
+include APP_ROOT . '/routes.php';
+include $path;
+include __DIR__ . "/views/$view.php";
+require_once $base . '/helpers.php';
+===report===
+6:1: dynamic include
+7:1: dynamic include
+8:1: dynamic include
@@ -0,0 +1,20 @@
+===source===
+<?php
+class Model {
+    public static function create(): static {
+        return new static();
+    }
+
+    public function copy(): static {
+        $copy = new self();
+        echo static::TABLE, self::TABLE;
+        static::boot();
+        parent::boot();
+        return $this instanceof STATIC ? $copy : $this;
+    }
+}
+===report===
+4:20: late static binding
+9:14: late static binding
+10:9: late static binding
+12:33: late static binding
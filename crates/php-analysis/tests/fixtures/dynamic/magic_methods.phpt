@@ -0,0 +1,27 @@
+===source===
+<?php
+class Proxy {
+    public function __call(string $name, array $args) {}
+    public static function __callStatic(string $name, array $args) {}
+    public function __get(string $name) {}
+    public function __set(string $name, mixed $value) {}
+    public function __construct() {}
+}
+
+enum Status {
+    case Active;
+    public function __call($name, $args) {}
+}
+
+class Plain {
+    public function run(): void {
+        $this->helper();
+        self::$cache = [];
+    }
+}
+===report===
+3:5: magic __call method
+4:5: magic __call method
+5:5: magic property method
+6:5: magic property method
+12:5: magic __call method
@@ -0,0 +1,23 @@
+===source===
+<?php
+namespace App;
+
+enum Accent: string {
+    case Escaped = "\xE9";
+    case Literal = "é";
+    case Octal = "\351";
+    case Utf8Bytes = "\xC3\xA9";
+}
+===report===
+7:5: Duplicate value in enum App\Accent for cases Escaped and Octal ('�')
+8:5: Duplicate value in enum App\Accent for cases Literal and Utf8Bytes ('é')
+---
+<?php
+namespace App;
+
+enum Accent: string {
+    case Escaped = "\xE9";
+    case Literal = "é";
+    case Octal = "\351";
+    case Utf8Bytes = "\xC3\xA9";
+}
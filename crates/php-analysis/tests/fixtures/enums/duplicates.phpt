@@ -0,0 +1,52 @@
+===source===
+<?php
+namespace App;
+
+enum Status: string {
+    const PREFIX = 'status-';
+    case Active = self::PREFIX . 'active';
+    case Enabled = 'status-active';
+    case Paused = 'paused';
+    case Stopped = 'paused';
+}
+
+enum Level: int {
+    case Low = 1 << 0;
+    case Mid = 1 << 1;
+    case High = 2;
+    case Max = Level::TOP;
+    const TOP = PHP_INT_MAX;
+}
+
+enum Plain {
+    case A;
+    case B;
+}
+===report===
+7:5: Duplicate value in enum App\Status for cases Active and Enabled ('status-active')
+9:5: Duplicate value in enum App\Status for cases Paused and Stopped ('paused')
+15:5: Duplicate value in enum App\Level for cases Mid and High (2)
+---
+<?php
+namespace App;
+
+enum Status: string {
+    const PREFIX = 'status-';
+    case Active = self::PREFIX . 'active';
+    case Enabled = 'status-active';
+    case Paused = 'paused';
+    case Stopped = 'paused';
+}
+
+enum Level: int {
+    case Low = 1 << 0;
+    case Mid = 1 << 1;
+    case High = 2;
+    case Max = Level::TOP;
+    const TOP = PHP_INT_MAX;
+}
+
+enum Plain {
+    case A;
+    case B;
+}
@@ -0,0 +1,139 @@
+===source===
+<?php
+namespace App;
+
+enum Suit: string {
+    case Hearts = 'H';
+    case Diamonds = 'D';
+    case Spades = 'S';
+    const Wild = self::Spades;
+
+    public function color(): string {
+        return match ($this) {
+            self::Hearts, self::Diamonds => 'red',
+        };
+    }
+}
+
+function label(Suit $suit): string {
+    return match ($suit) {
+        Suit::Hearts => 'hearts',
+        default => 'other',
+    };
+}
+
+function short(Suit $suit): string {
+    return match ($suit) { Suit::Hearts => 'H', Suit::Spades => 'S' };
+}
+
+function all(Suit $suit): string {
+    return match ($suit) {
+        Suit::Hearts, Suit::Diamonds, Suit::Spades => 'card',
+        default => 'never',
+    };
+}
+
+function play(Suit $suit): void {
+    switch ($suit) {
+        case Suit::Hearts:
+            love();
+            break;
+        case \App\Suit::Diamonds:
+            shine();
+    }
+    switch ($suit) {
+        case Suit::Spades:
+            dig();
+            break;
+        default:
+            skip();
+    }
+}
+
+function unrelated($x) {
+    return match ($x) {
+        Suit::Hearts => 1,
+        Status::Active => 2,
+    };
+}
+===report===
+12:13: App\Suit::Hearts
+12:27: App\Suit::Diamonds
+19:9: App\Suit::Hearts
+25:28: App\Suit::Hearts
+25:49: App\Suit::Spades
+30:9: App\Suit::Hearts
+30:23: App\Suit::Diamonds
+30:39: App\Suit::Spades
+37:14: App\Suit::Hearts
+40:14: App\Suit::Diamonds
+44:14: App\Suit::Spades
+54:9: App\Suit::Hearts
+11:16: match over App\Suit does not handle Spades
+18:12: match over App\Suit uses `default` for Diamonds, Spades; list the cases instead
+25:12: match over App\Suit does not handle Diamonds
+29:12: match over App\Suit handles every case; `default` is unreachable
+36:5: switch over App\Suit does not handle Spades
+43:5: switch over App\Suit uses `default` for Hearts, Diamonds; list the cases instead
+---
+<?php
+namespace App;
+
+enum Suit: string {
+    case Hearts = 'H';
+    case Diamonds = 'D';
+    case Spades = 'S';
+    const Wild = self::Spades;
+
+    public function color(): string {
+        return match ($this) {
+            self::Hearts, self::Diamonds => 'red',
+            self::Spades => throw new \UnhandledMatchError('Unhandled match case self::Spades'),
+        };
+    }
+}
+
+function label(Suit $suit): string {
+    return match ($suit) {
+        Suit::Hearts => 'hearts',
+        Suit::Diamonds, Suit::Spades => 'other',
+    };
+}
+
+function short(Suit $suit): string {
+    return match ($suit) { Suit::Hearts => 'H', Suit::Spades => 'S', Suit::Diamonds => throw new \UnhandledMatchError('Unhandled match case Suit::Diamonds') };
+}
+
+function all(Suit $suit): string {
+    return match ($suit) {
+        Suit::Hearts, Suit::Diamonds, Suit::Spades => 'card',
+        default => 'never',
+    };
+}
+
+function play(Suit $suit): void {
+    switch ($suit) {
+        case Suit::Hearts:
+            love();
+            break;
+        case \App\Suit::Diamonds:
+            shine();
+        case Suit::Spades:
+            break;
+    }
+    switch ($suit) {
+        case Suit::Spades:
+            dig();
+            break;
+        case Suit::Hearts:
+        case Suit::Diamonds:
+            skip();
+    }
+}
+
+function unrelated($x) {
+    return match ($x) {
+        Suit::Hearts => 1,
+        Status::Active => 2,
+    };
+}
@@ -0,0 +1,18 @@
+===source===
+<?php
+/**
+ * @throws LogicException when the value is negative
+ */
+function validate(int $n): void {
+    if ($n < 0) {
+        throw new InvalidArgumentException('negative');
+    }
+}
+
+try {
+    validate(-1);
+} catch (Exception $e) {
+    echo $e->getMessage();
+}
+===report===
+
@@ -0,0 +1,20 @@
+===source===
+<?php
+namespace App {
+    function helper() {
+        throw new \RuntimeException();
+    }
+
+    function run() {
+        helper();
+        strlen('x');
+    }
+}
+
+namespace {
+    App\run();
+}
+===report===
+4:9: App\helper may throw RuntimeException, which is not declared in @throws
+8:9: App\run may throw RuntimeException, which is not declared in @throws
+14:5: RuntimeException is not caught by any caller
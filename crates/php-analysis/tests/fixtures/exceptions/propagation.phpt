@@ -0,0 +1,32 @@
+===source===
+<?php
+namespace App;
+
+use App\Errors\NotFound;
+
+class Repo {
+    /** @throws NotFound */
+    public function find(int $id) {
+        throw new NotFound();
+    }
+
+    public function findOrNull(int $id) {
+        try {
+            return $this->find($id);
+        } catch (NotFound $e) {
+            return null;
+        }
+    }
+
+    public function load(int $id) {
+        return $this->find($id);
+    }
+}
+
+function main() {
+    (new Repo())->load(1);
+    Repo::load(2);
+}
+===report===
+21:16: App\Repo::load may throw App\Errors\NotFound, which is not declared in @throws
+27:5: App\main may throw App\Errors\NotFound, which is not declared in @throws
@@ -0,0 +1,30 @@
+===source===
+<?php
+class DbError extends RuntimeException {}
+
+/** @throws DbError */
+function query() {
+    throw new DbError();
+}
+
+function retry() {
+    try {
+        query();
+    } catch (DbError $e) {
+        log_error($e);
+        throw $e;
+    }
+}
+
+function swallow() {
+    try {
+        query();
+    } catch (RuntimeException) {
+    }
+}
+
+retry();
+swallow();
+===report===
+14:9: retry may throw DbError, which is not declared in @throws
+25:1: DbError is not caught by any caller
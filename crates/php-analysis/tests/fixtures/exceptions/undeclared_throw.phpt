@@ -0,0 +1,9 @@
+===source===
+<?php
+function validate(int $n): void {
+    if ($n < 0) {
+        throw new InvalidArgumentException('negative');
+    }
+}
+===report===
+4:9: validate may throw InvalidArgumentException, which is not declared in @throws
@@ -0,0 +1,101 @@
+===source===
+<?php
+namespace App\Model;
+
+use App\Support\Clock;
+
+const VERSION = '1.0';
+
+/**
+ * Format a user's name
+ * for display.
+ *
+ * @param User $user
+ */
+function display(User $user, int $width = 20): string {
+    return strlen($user->name) > $width ? VERSION : $user->name;
+}
+
+enum Status: string {
+    case Active = 'active';
+}
+
+class User {
+    /** Seconds before a session expires. */
+    public const TTL = 3600;
+
+    public static int $count = 0;
+
+    public function __construct(public string $name, private ?Clock $clock = null) {}
+
+    /** Create a user. */
+    public static function create(string $name): static {
+        self::$count++;
+        return new static($name);
+    }
+
+    public function rename(string $name): self {
+        $this->name = $name;
+        return $this;
+    }
+}
+
+$user = User::create('ada')->rename('Ada');
+$status = Status::Active;
+$ttl = User::TTL;
+$format = fn($u) => display($u);
+echo display($user), $user instanceof User, $unknown->name, Clock::now();
+===report===
+6:7: Constant App\Model\VERSION | type string | `const VERSION = '1.0'`
+14:10: Function App\Model\display | type string | `function display(User $user, int $width = 20): string` | Format a user's name
+14:24: Variable $user | type User | `User $user`
+14:35: Variable $width | type int | `int $width = 20`
+15:12: Function strlen
+15:19: Variable $user | type User | `User $user`
+15:26: Property App\Model\User::$name | type string | `public string $name`
+15:34: Variable $width | type int | `int $width = 20`
+15:43: Constant App\Model\VERSION | type string | `const VERSION = '1.0'`
+15:53: Variable $user | type User | `User $user`
+15:60: Property App\Model\User::$name | type string | `public string $name`
+18:6: Enum App\Model\Status | `enum Status: string`
+19:10: EnumCase App\Model\Status::Active | type App\Model\Status | `case Active = 'active'`
+22:7: Class App\Model\User | `class User`
+24:18: ClassConstant App\Model\User::TTL | type int | `public const TTL = 3600` | Seconds before a session expires.
+26:24: Property App\Model\User::$count | type int | `public static int $count = 0`
+28:21: Method App\Model\User::__construct | `public function __construct(public string $name, private ?Clock $clock = null)`
+28:48: Property App\Model\User::$name | type string | `public string $name`
+28:70: Property App\Model\User::$clock | type ?Clock | `private ?Clock $clock = null`
+31:28: Method App\Model\User::create | type static | `public static function create(string $name): static` | Create a user.
+31:43: Variable $name | type string | `string $name`
+32:9: Class App\Model\User | `class User`
+32:15: Property App\Model\User::$count | type int | `public static int $count = 0`
+33:20: Class App\Model\User | `class User`
+33:27: Variable $name | type string | `string $name`
+36:21: Method App\Model\User::rename | type self | `public function rename(string $name): self`
+36:36: Variable $name | type string | `string $name`
+37:9: Variable $this | type App\Model\User
+37:16: Property App\Model\User::$name | type string | `public string $name`
+37:23: Variable $name | type string | `string $name`
+38:16: Variable $this | type App\Model\User
+42:1: Variable $user | type App\Model\User
+42:9: Class App\Model\User | `class User`
+42:15: Method App\Model\User::create | type static | `public static function create(string $name): static` | Create a user.
+42:30: Method App\Model\User::rename | type self | `public function rename(string $name): self`
+43:1: Variable $status | type App\Model\Status
+43:11: Enum App\Model\Status | `enum Status: string`
+43:19: EnumCase App\Model\Status::Active | type App\Model\Status | `case Active = 'active'`
+44:1: Variable $ttl | type int
+44:8: Class App\Model\User | `class User`
+44:14: ClassConstant App\Model\User::TTL | type int | `public const TTL = 3600` | Seconds before a session expires.
+45:1: Variable $format | type Closure
+45:15: Variable $u | `$u`
+45:21: Function App\Model\display | type string | `function display(User $user, int $width = 20): string` | Format a user's name
+45:29: Variable $u | `$u`
+46:6: Function App\Model\display | type string | `function display(User $user, int $width = 20): string` | Format a user's name
+46:14: Variable $user | type App\Model\User
+46:22: Variable $user | type App\Model\User
+46:39: Class App\Model\User | `class User`
+46:45: Variable $unknown
+46:55: Property $name
+46:61: Class App\Support\Clock
+46:68: Method App\Support\Clock::now
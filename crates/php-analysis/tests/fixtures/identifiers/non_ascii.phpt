@@ -0,0 +1,40 @@
+===source===
+<?php
+namespace Prüfung;
+
+class Ünicode extends Базовый {
+    const ЁЖ = 1;
+    public int $größe = 0;
+    public function run(string $täst, $plain): string {
+        return "$täst {$this->größe} $plain->größe \$größe" . self::ЁЖ;
+    }
+}
+
+function 🐘($🦀) { return $🦀; }
+$привет = new Ünicode();
+echo 'größe', <<<TXT
+    $привет->名前
+    TXT;
+// größe in a comment is fine
+goto метка;
+метка:
+===report===
+2:11: non-ASCII identifier `Prüfung`
+4:7: non-ASCII identifier `Ünicode`
+4:24: non-ASCII identifier `Базовый`
+5:11: non-ASCII identifier `ЁЖ`
+6:16: non-ASCII identifier `$größe`
+7:32: non-ASCII identifier `$täst`
+8:17: non-ASCII identifier `$täst`
+8:32: non-ASCII identifier `größe`
+8:49: non-ASCII identifier `größe`
+8:76: non-ASCII identifier `ЁЖ`
+12:10: non-ASCII identifier `🐘`
+12:15: non-ASCII identifier `$🦀`
+12:31: non-ASCII identifier `$🦀`
+13:1: non-ASCII identifier `$привет`
+13:21: non-ASCII identifier `Ünicode`
+15:5: non-ASCII identifier `$привет`
+15:20: non-ASCII identifier `名前`
+18:6: non-ASCII identifier `метка`
+19:1: non-ASCII identifier `метка`
@@ -0,0 +1,40 @@
+===source===
+<?php
+function named(Foo $a = null, \Ns\Bar $b = null, int $c = NULL, $d = null) {}
+function unions(int|string $a = null, int|null $b = null, (A&B)|C $c = null) {}
+function intersection(A&B $a = null) {}
+function nullable(?Foo $a = null, mixed $b = null, null $c = null, Foo $d = \null) {}
+function defaults(int $a = 0, string $b = 'null', Foo ...$rest) {}
+
+class Service {
+    public function __construct(
+        private Logger $logger = null,
+        readonly Cache $cache = null,
+        Clock $clock = null,
+    ) {}
+
+    abstract protected function handle(self $next = null): void;
+}
+
+$closure = function (array /* items */ $items = null) use ($x) {};
+$arrow = fn(callable $cb = null) => $cb;
+===report===
+<?php
+function named(?Foo $a = null, ?\Ns\Bar $b = null, ?int $c = NULL, $d = null) {}
+function unions(int|string|null $a = null, int|null $b = null, (A&B)|C|null $c = null) {}
+function intersection((A&B)|null $a = null) {}
+function nullable(?Foo $a = null, mixed $b = null, null $c = null, ?Foo $d = \null) {}
+function defaults(int $a = 0, string $b = 'null', Foo ...$rest) {}
+
+class Service {
+    public function __construct(
+        private Logger $logger = null,
+        readonly Cache $cache = null,
+        ?Clock $clock = null,
+    ) {}
+
+    abstract protected function handle(?self $next = null): void;
+}
+
+$closure = function (?array /* items */ $items = null) use ($x) {};
+$arrow = fn(?callable $cb = null) => $cb;
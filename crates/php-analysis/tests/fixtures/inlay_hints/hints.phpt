@@ -0,0 +1,80 @@
+===source===
+<?php
+namespace App;
+
+trait Logs {
+    public function log(string $message, array $context = []): void {}
+}
+
+abstract class Model {
+    public function __construct(protected int $id) {}
+    abstract public function save(bool $force): static;
+}
+
+final class User extends Model {
+    use Logs;
+
+    private $name = 'guest';
+    private $retries = 3;
+    private $cache = null;
+    private ?string $email = null;
+
+    public function save(bool $force): static {
+        $this->log('saving', ['id' => $this->id]);
+        return $this;
+    }
+
+    public static function find(int $id): ?self {
+        return new self($id);
+    }
+}
+
+function retry(callable $task, int $times = 3, int ...$delays): mixed {
+    return $task();
+}
+
+$user = User::find(7);
+$user->save(true);
+$force = false;
+$user->save($force);
+retry(fn() => $user->save(force: true), 5, 10, 20);
+retry(...[$user]);
+strlen('unknown');
+
+$limit = 10;
+$scale = fn(int $n) => $n * $limit;
+$label = function (int $n) use ($limit) {
+    if ($n > $limit) {
+        return 'many';
+    }
+    return "$n items";
+};
+$maybe = function ($n) {
+    if ($n) {
+        return 1;
+    }
+    return 'one';
+};
+$gen = function () { yield 1; };
+$noop = static function () {};
+$make = fn() => new User(1);
+$ok = fn(): bool => $limit > 0;
+===report===
+16:13: Type `string`
+17:13: Type `int`
+22:20: Parameter `message:`
+22:30: Parameter `context:`
+35:20: Parameter `id:`
+36:13: Parameter `force:`
+39:7: Parameter `task:`
+39:11: Capture `use ($user)`
+39:11: Type `: User`
+39:41: Parameter `times:`
+39:44: Parameter `...delays:`
+44:20: Capture `use ($limit)`
+44:20: Type `: int`
+45:40: Type `: string`
+58:27: Type `: void`
+59:13: Type `: User`
+59:26: Parameter `id:`
+60:11: Capture `use ($limit)`
@@ -0,0 +1,47 @@
+===source===
+<?php
+function load(Repository $repo, array $rows) {
+    /** @var list<User> */
+    $users = $repo->all();
+    /** @var User $user */
+    foreach ($users as $user) {
+        /** @var int $count */
+        $total = $count + 1;
+    }
+    /**
+     * @var int $id
+     * @var Row $row
+     */
+    foreach ($rows as $id => &$row) {}
+    /** @var array{int, string} $pair */
+    [$first, $second] = $pair = pair();
+    /**
+     * @var Foo $a
+     * @var Bar $b
+     */
+    list($a, [$b]) = nested();
+    /** @var string */
+    [$x, $y] = split();
+    /** @var Row */
+    foreach ($rows as $row) {}
+    /** @psalm-var non-empty-string $name */
+    echo $name;
+    /** @var int */
+    echo $total;
+}
+/** @var Config $config */
+$config->load();
+===report===
+3:5: $users: list<User> (assigned) at 4:5
+5:5: $user: User (foreach value) at 6:5
+7:9: $count: int (in scope) at 8:9
+10:5: $id: int (foreach key) at 14:5
+10:5: $row: Row (foreach value) at 14:5
+15:5: $pair: array{int, string} (assigned) at 16:5
+17:5: $a: Foo (assigned) at 21:5
+17:5: $b: Bar (assigned) at 21:5
+26:5: $name: non-empty-string (in scope) at 27:5
+31:1: $config: Config (in scope) at 32:1
+22:5: PHPDoc tag @var does not specify a variable name
+24:5: PHPDoc tag @var does not specify a variable name
+28:5: PHPDoc tag @var does not specify a variable name
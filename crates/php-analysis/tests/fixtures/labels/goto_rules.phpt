@@ -0,0 +1,58 @@
+===source===
+<?php
+goto top;
+top:
+top:
+
+foreach ([1, 2] as $x) {
+    inLoop:
+    goto out;
+}
+goto inLoop;
+out:
+
+switch ($x) {
+    case 1:
+        goto caseLabel;
+    case 2:
+        caseLabel:
+        goto afterSwitch;
+}
+afterSwitch:
+
+function f() {
+    goto top;
+    try {
+        goto inFinally;
+    } finally {
+        inFinally:
+        goto done;
+        goto alsoInFinally;
+        alsoInFinally:
+    }
+    done:
+    $fn = function () {
+        goto done;
+    };
+}
+
+class C {
+    public function m() {
+        while (true) {
+            while (true) {
+                goto outer;
+            }
+            outer:
+        }
+        Done:
+        goto done;
+    }
+}
+===report===
+4:1: Label 'top' already defined
+10:1: 'goto' into loop or switch statement is disallowed
+23:5: 'goto' to undefined label 'top'
+25:9: jump into a finally block is disallowed
+28:9: jump out of a finally block is disallowed
+34:9: 'goto' to undefined label 'done'
+47:9: 'goto' to undefined label 'done'
@@ -0,0 +1,61 @@
+===source===
+<?php
+// Literal operands.
+if ($status == 'active') {}
+if ($status <> "draft") {}
+if ($input == '10') {}
+if ($flag == true) {}
+if ($value != null) {}
+if (-1 == $pos) {}
+if ($a == $b) {}
+if ($a === 'x' || $b !== 1) {}
+
+// Inferred types on both sides.
+if (count($items) != 0) {}
+if (strlen($name) == 0.0) {}
+if ((string) $id == '007') {}
+if (strtolower($ext) == 'jpg') {}
+if ($prefix . $id == 'abc') {}
+if (($n % 2) == 1) {}
+if (is_int($x) == false) {}
+if ((int) $x /* id */ == 5) {}
+if (Foo::class == 'Foo') {}
+if ('1e1' == '10') {}
+if (__LINE__ == 3) {}
+
+// Array searches.
+in_array($needle, $haystack);
+in_array($needle, $haystack, true);
+in_array($needle, $haystack, false);
+\in_array(strtolower($ext), ['jpg', 'png']);
+in_array((int) $id, [1, 2, '3']);
+in_array('a', ['a', 'b'], strict: false);
+in_array(needle: 'a', haystack: ['a', 'b']);
+array_search(1, [1, 2, 3],);
+array_keys($map, 'x');
+array_keys($map);
+in_array(...$args);
+===report===
+3:5: loose-equality: loose comparison `==` against a literal; use `===`
+4:5: loose-equality: loose comparison `<>` against a literal; use `!==`
+5:5: loose-equality: loose comparison `==` against a literal; use `===`
+6:5: loose-equality: loose comparison `==` against a literal; use `===`
+7:5: loose-equality: loose comparison `!=` against a literal; use `!==`
+8:5: loose-equality: loose comparison `==` against a literal; use `===`
+13:5: loose-equality: loose comparison `!=` against a literal; use `!==` (fix: `!==`)
+14:5: loose-equality: loose comparison `==` against a literal; use `===`
+15:5: loose-equality: loose comparison `==` against a literal; use `===`
+16:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+17:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+18:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+19:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+20:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+21:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+22:5: loose-equality: loose comparison `==` against a literal; use `===`
+23:5: loose-equality: loose comparison `==` against a literal; use `===` (fix: `===`)
+26:1: strict-search: `in_array()` compares loosely; pass `true` as the `$strict` argument
+29:1: strict-search: `in_array()` compares loosely; pass `true` as the `$strict` argument (fix: `, true`)
+30:1: strict-search: `in_array()` compares loosely; pass `true` as the `$strict` argument
+32:1: strict-search: `in_array()` compares loosely; pass `true` as the `$strict` argument (fix: `, strict: true`)
+33:1: strict-search: `array_search()` compares loosely; pass `true` as the `$strict` argument (fix: `, true`)
+34:1: strict-search: `array_keys()` compares loosely; pass `true` as the `$strict` argument
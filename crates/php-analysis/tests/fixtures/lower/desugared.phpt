@@ -0,0 +1,44 @@
+===source===
+<?php
+final class Point {
+    public function __construct(
+        #[Positive] public readonly int $x,
+        private int $y = 0,
+        string $label = '',
+    ) {
+        $this->check();
+    }
+}
+if ($ready):
+    foreach ($points as [$x, 'y' => $y]):
+        echo "($x, $y)";
+    endforeach;
+endif;
+foreach ($refs as [&$first]) {}
+$scale = fn($p) => $p * $factor;
+===report===
+<?php
+final class Point
+{
+    #[Positive]
+    public readonly int $x;
+
+    private int $y;
+
+    public function __construct(#[Positive] int $x, int $y = 0, string $label = '')
+    {
+        $this->x = $x;
+        $this->y = $y;
+        $this->check();
+    }
+}
+if ($ready) {
+    foreach ($points as $__foreach0) {
+        [$x, 'y' => $y] = $__foreach0;
+        echo '(' . $x . ', ' . $y . ')';
+    }
+}
+foreach ($refs as [&$first]) {}
+$scale = function($p) use ($factor) {
+    return $p * $factor;
+};
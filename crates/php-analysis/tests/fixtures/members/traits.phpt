@@ -0,0 +1,92 @@
+===source===
+<?php
+trait Hello {
+    public function say() {}
+    public function hello() {}
+}
+
+trait World {
+    public function say() {}
+    abstract public function name();
+    public static function create() {}
+}
+
+trait Named {
+    public function name() { return 'named'; }
+}
+
+trait Both {
+    use Hello, Named;
+}
+
+class Base {
+    public function say() {}
+    protected function base() {}
+    public function name() {}
+}
+
+class Greeter extends Base {
+    use Hello, World {
+        Hello::say insteadof World;
+        World::say as protected sayWorld;
+        hello as private;
+    }
+
+    public function own() {}
+}
+
+class Child extends Greeter {
+    use Named;
+}
+
+abstract class Shape {
+    use Both;
+    abstract public function area();
+}
+
+enum Suit {
+    use Hello;
+    case Hearts;
+}
+
+class Clash {
+    use Hello, World;
+}
+
+class Resolved {
+    use Hello, World;
+    public function say() {}
+}
+
+class Broken {
+    use Hello {
+        Hello::missing as other;
+        World::say insteadof Hello;
+        Unknown::x as y;
+    }
+}
+===report===
+51:1: Clash::say is provided by traits Hello, World; resolve it with `insteadof`
+62:9: trait Hello has no method missing
+63:9: trait World is not used by Broken
+64:9: trait Unknown is not used by Broken
+Greeter::own -> Greeter::own (own, Public)
+Greeter::sayWorld -> World::say (trait World, Protected)
+Greeter::say -> Hello::say (trait Hello, Public)
+Greeter::hello -> Hello::hello (trait Hello, Private)
+Greeter::create -> World::create (trait World, Public, static)
+Greeter::base -> Base::base (from Base, Protected)
+Greeter::name -> Base::name (from Base, Public)
+Child::name -> Named::name (trait Named, Public)
+Child::own -> Greeter::own (from Greeter, Public)
+Child::sayWorld -> World::say (from Greeter, Protected)
+Child::say -> Hello::say (from Greeter, Public)
+Child::hello -> Hello::hello (from Greeter, Private)
+Child::create -> World::create (from Greeter, Public, static)
+Child::base -> Base::base (from Greeter, Protected)
+Shape::area -> Shape::area (own, Public, abstract)
+Shape::say -> Hello::say (trait Both, Public)
+Shape::hello -> Hello::hello (trait Both, Public)
+Shape::name -> Named::name (trait Both, Public)
+Suit::say -> Hello::say (trait Hello, Public)
+Suit::hello -> Hello::hello (trait Hello, Public)
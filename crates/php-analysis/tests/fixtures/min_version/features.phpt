@@ -0,0 +1,49 @@
+===source===
+<?php
+
+#[Attribute]
+final class Service
+{
+    public function __construct(
+        private readonly Logger $logger = new NullLogger(),
+    ) {}
+
+    public function with(): static
+    {
+        return clone $this;
+    }
+
+    public function name(object $o): string
+    {
+        try {
+            return $o::class;
+        } catch (Error) {
+            return match (true) { default => '' };
+        }
+    }
+}
+
+trait HasMode
+{
+    const MODE = 0o644;
+}
+
+function id(int|string $id): int|string
+{
+    return $id ?? throw new InvalidArgumentException();
+}
+===report===
+3:3: attributes (8.0)
+7:9: constructor property promotion (8.0)
+7:17: readonly parameters (8.1)
+7:43: new in initializers (8.1)
+10:29: static return type (8.0)
+18:20: ::class on objects (8.0)
+19:17: catch without a variable (8.0)
+20:20: match expressions (8.0)
+27:5: constants in traits (8.2)
+27:18: explicit octal notation (0o) (8.1)
+30:16: union types (8.0)
+30:33: union types (8.0)
+32:19: throw expressions (8.0)
+requires PHP 8.2
@@ -0,0 +1,24 @@
+===source===
+<?php
+echo "Hello $name!";
+echo "$id";
+echo "{$user->name} <{$user['email']}>\n";
+echo "tab\there $x->y[0] end";
+echo "plain";
+echo <<<EOT
+    Dear {$name},
+      total: $total
+    EOT;
+echo <<<'EOT'
+raw $x
+EOT;
+echo `ls $dir`;
+===report===
+2:6: (("Hello "@"Hello " . <$name>) . "!"@"!")
+3:6: (string) <$id>
+4:6: (((<$user->name> . " <"@" <") . <$user['email']>) . ">\n"@">\\n")
+5:6: (("tab\there "@"tab\\there " . <$x->y>) . "[0] end"@"[0] end")
+6:6: "plain"@"\"plain\""
+7:6: ((("Dear "@"Dear " . <$name>) . ",\n  total: "@",\n      total: ") . <$total>)
+11:6: "raw $x"@"<<<'EOT'\nraw $x\nEOT"
+14:6: <`ls $dir`>
@@ -0,0 +1,80 @@
+===source===
+<?php
+function checks(?User $user, User|null $admin, $guest = null, User $owner) {
+    if ($user !== null) {
+        $user->save();
+    }
+    if (null != $admin) {
+        echo $admin->name;
+    }
+    if (!is_null($guest)) {
+        $guest->greet();
+    }
+    $owner->save();
+    $user->save();
+}
+
+function guards(?User $a, ?User $b, ?User $c, ?User $d, ?User $e) {
+    if ($a instanceof Admin) {
+        $a->promote();
+    }
+    if (isset($b) && $b->active) {
+        echo 'active';
+    }
+    if ($c === null || $c->isBanned()) {
+        return;
+    }
+    $c->save();
+    echo $d ? $d->name : 'nobody';
+    echo $d->name;
+    if (!$e) {
+        throw new Exception();
+    }
+    $e->save();
+}
+
+function coalescing(?User $user, ?Cache $cache) {
+    echo $user?->name;
+    echo $user->name ?? 'anonymous';
+    echo isset($user->name), empty($user->email);
+    $cache ??= new Cache();
+    $cache->get('key');
+    $name = $user?->profile;
+    $name->display();
+}
+
+function assignments($found) {
+    $x = null;
+    $x->call();
+    $y = null;
+    $y = new User();
+    $y->call();
+    $z = $found ? null : new User();
+    $z->call();
+    $w = $found ?: new User();
+    $w->call();
+    while (($row = fetch()) !== null) {
+        $row->process();
+    }
+    static $instance = null;
+    if ($instance === null) {
+        $instance = new Service();
+    }
+    return $instance->run();
+}
+
+function loops(?Node $node) {
+    while ($node !== null) {
+        $node->visit();
+        $node = $node->next;
+    }
+    foreach (list_users() as $user) {
+        $user->save();
+    }
+}
+===report===
+13:5: method call on possibly null $user (in checks)
+28:10: property access on possibly null $d (in guards)
+42:5: method call on possibly null $name (in coalescing)
+47:5: method call on possibly null $x (in assignments)
+52:5: method call on possibly null $z (in assignments)
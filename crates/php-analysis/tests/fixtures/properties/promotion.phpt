@@ -0,0 +1,38 @@
+===source===
+<?php
+class User {
+    public static int $count = 0;
+    protected ?string $nickname = null;
+    var $legacy;
+
+    public function __construct(
+        private readonly int $id,
+        public private(set) string $email,
+        readonly array $roles,
+        protected Clock $clock = new Clock(),
+        string $plain = '',
+        public string $display { get => strtoupper($this->display); },
+    ) {}
+
+    public function other(private int $notPromoted) {}
+}
+
+readonly class Money {
+    public function __construct(public int $amount, public string $currency) {}
+}
+
+trait HasName {
+    public function __CONSTRUCT(protected string $name) {}
+}
+===report===
+3:5: $count: int (Public static default)
+4:5: $nickname: ?string (Protected default)
+5:5: $legacy: mixed (Public)
+8:9: $id: int (Private readonly promoted)
+9:9: $email: string (Public set Private promoted)
+10:9: $roles: array (Public readonly promoted)
+11:9: $clock: Clock (Protected promoted)
+13:9: $display: string (Public promoted hooked)
+20:33: $amount: int (Public readonly promoted)
+20:53: $currency: string (Public readonly promoted)
+24:33: $name: string (Protected promoted)
@@ -0,0 +1,7 @@
+===source===
+﻿<?php namespace App\Model;
+
+class User {}
+===report===
+1:1: open-tag: byte-order mark before the opening tag
+1:10: open-tag: code on the line of the opening `<?php`
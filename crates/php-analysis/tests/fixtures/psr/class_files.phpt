@@ -0,0 +1,18 @@
+===source===
+<?php
+namespace App\Models;
+
+interface HasName {}
+
+class User implements HasName {}
+
+if (PHP_VERSION_ID < 80100) {
+    trait Legacy {}
+}
+
+$anonymous = new class {};
+===report===
+4:1: class-file-name: `App\Models\HasName` should be in `src/Models/HasName.php`
+6:1: one-class-per-file: `App\Models\User` is not the only class-like in the file
+9:5: one-class-per-file: `App\Models\Legacy` is not the only class-like in the file
+12:1: side-effects: side effect in a file that also declares symbols
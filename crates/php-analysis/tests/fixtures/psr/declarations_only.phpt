@@ -0,0 +1,16 @@
+===source===
+<?php
+namespace App\Model;
+
+const VERSION = 2;
+
+class User
+{
+    public function name(): string
+    {
+        echo 'inside a method is not a side effect';
+        return '';
+    }
+}
+===report===
+
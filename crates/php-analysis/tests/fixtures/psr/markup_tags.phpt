@@ -0,0 +1,8 @@
+===source===
+<html>
+<? echo $title ?>
+<?xml version="1.0"?>
+<?php echo $body; ?>
+</html>
+===report===
+2:1: open-tag: short open tag `<?`; use `<?php`
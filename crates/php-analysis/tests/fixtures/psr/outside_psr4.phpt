@@ -0,0 +1,7 @@
+===source===
+<?php
+namespace Vendor\Package;
+
+class User {}
+===report===
+4:1: class-file-name: `Vendor\Package\User` is outside every PSR-4 namespace prefix
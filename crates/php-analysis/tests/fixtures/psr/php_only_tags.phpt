@@ -0,0 +1,6 @@
+===source===
+<?php echo "one line";
+?>
+===report===
+1:7: open-tag: code on the line of the opening `<?php`
+2:1: closing-tag: closing `?>` in a file containing only PHP
@@ -0,0 +1,20 @@
+===source===
+<?php
+declare(strict_types=1);
+
+namespace App\Model;
+
+use App\Support\Str;
+
+if (!function_exists('App\Model\helper')) {
+    function helper() {}
+}
+
+define('APP_ROOT', __DIR__);
+
+final class User {}
+
+ini_set('display_errors', '1');
+echo 'loaded';
+===report===
+16:1: side-effects: side effect in a file that also declares symbols
@@ -0,0 +1,44 @@
+===source===
+<?php
+function add(int $a, int $b): int {
+    $sum = $a + $b;
+    $parts = [];
+    $parts[] = $sum;
+    return strlen((string) $sum) + count($parts);
+}
+
+function greet(string $name): void {
+    echo "Hello, $name";
+}
+
+function counter(): int {
+    static $n = 0;
+    return ++$n;
+}
+
+function config(): array {
+    global $config;
+    return $config + $_ENV;
+}
+
+function stamp(): string {
+    return date('c', time());
+}
+
+function load(string $path): mixed {
+    return require $path;
+}
+===report===
+2:1: add is pure
+9:1: greet is impure
+  10:5: writes output
+13:1: counter is impure
+  14:5: declares a static variable
+18:1: config is impure
+  19:5: accesses global state
+  20:22: accesses global state
+23:1: stamp is impure
+  24:12: calls impure built-in date
+  24:22: calls impure built-in time
+27:1: load is impure
+  28:12: includes a file
@@ -0,0 +1,47 @@
+===source===
+<?php
+class Point {
+    private static int $created = 0;
+
+    public function __construct(private int $x, private int $y) {
+        $this->x = $x;
+    }
+
+    public function withX(int $x): static {
+        $copy = clone $this;
+        return $copy;
+    }
+
+    public function moveTo(int $x): void {
+        $this->x = $x;
+    }
+
+    public static function make(): self {
+        self::$created++;
+        return new self(0, 0);
+    }
+
+    public function describe(Logger $logger): string {
+        $logger->info('describe');
+        return $this->format();
+    }
+
+    private function format(): string {
+        return sprintf('(%d, %d)', $this->x, $this->y);
+    }
+}
+
+function origin(): Point {
+    return new Point(0, 0);
+}
+===report===
+5:5: Point::__construct is pure
+9:5: Point::withX is pure
+14:5: Point::moveTo is impure
+  15:9: writes an object property
+18:5: Point::make is impure
+  19:9: accesses global state
+23:5: Point::describe is unknown
+  24:9: makes an unresolvable call
+28:5: Point::format is pure
+33:1: origin is pure
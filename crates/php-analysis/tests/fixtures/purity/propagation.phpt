@@ -0,0 +1,39 @@
+===source===
+<?php
+namespace App;
+
+function log_line(string $msg): void {
+    \file_put_contents('/tmp/log', $msg, FILE_APPEND);
+}
+
+function double(int $x): int {
+    return $x * 2;
+}
+
+function quadruple(int $x): int {
+    return double(double($x));
+}
+
+function process(int $x): int {
+    log_line("processing");
+    return quadruple($x);
+}
+
+function apply(callable $f, int $x): int {
+    return $f($x);
+}
+
+function wrapper(int $x): int {
+    return apply(fn($y) => $y, $x);
+}
+===report===
+4:1: App\log_line is impure
+  5:5: calls impure built-in file_put_contents
+8:1: App\double is pure
+12:1: App\quadruple is pure
+16:1: App\process is impure
+  17:5: calls impure App\log_line
+21:1: App\apply is unknown
+  22:12: makes an unresolvable call
+25:1: App\wrapper is unknown
+  26:12: calls unknown App\apply
@@ -0,0 +1,64 @@
+===source===
+<?php
+namespace App;
+
+class Cache
+{
+    /**
+     * @param bool $force Overwrite an existing entry.
+     * @param int $forced Unrelated.
+     */
+    public function __construct(string $dir, bool $force = false, int $forced = 0)
+    {
+        $write = fn() => $force;
+        $log = function () use ($dir, $force) { return $force; };
+        $other = function ($force) { return $force; };
+        $this->store($dir, force: $force);
+    }
+
+    public function store(string $key, bool $force = false) {}
+
+    public static function make(): static
+    {
+        return new static('/tmp', force: true);
+    }
+}
+
+function force(bool $force) { return $force; }
+
+$cache = new Cache('/tmp', force: true);
+$cache = new \App\Cache(dir: '/tmp', force: false);
+$cache->store('k', force: true);
+force(force: true);
+===report===
+<?php
+namespace App;
+
+class Cache
+{
+    /**
+     * @param bool $overwrite Overwrite an existing entry.
+     * @param int $forced Unrelated.
+     */
+    public function __construct(string $dir, bool $overwrite = false, int $forced = 0)
+    {
+        $write = fn() => $overwrite;
+        $log = function () use ($dir, $overwrite) { return $overwrite; };
+        $other = function ($force) { return $force; };
+        $this->store($dir, force: $overwrite);
+    }
+
+    public function store(string $key, bool $force = false) {}
+
+    public static function make(): static
+    {
+        return new static('/tmp', overwrite: true);
+    }
+}
+
+function force(bool $force) { return $force; }
+
+$cache = new Cache('/tmp', overwrite: true);
+$cache = new \App\Cache(dir: '/tmp', overwrite: false);
+$cache->store('k', force: true);
+force(force: true);
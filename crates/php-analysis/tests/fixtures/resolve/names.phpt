@@ -0,0 +1,67 @@
+===source===
+<?php
+namespace App\Http;
+
+use Psr\Log\LoggerInterface as Logger, Psr\Log\LogLevel;
+use function Lib\{render, escape as e};
+use const Lib\VERSION;
+
+#[Route('/users')]
+final class UserController extends Controller implements \Countable {
+    use Helpers;
+
+    public function __construct(private Logger $logger) {}
+
+    public function show(Request|Model\User|null $user): ?Response {
+        try {
+            $level = LogLevel::INFO;
+            $this->logger->log($level, e(render(VERSION)));
+            return new Response(strlen(\PHP_EOL), namespace\status());
+        } catch (NotFound | \RuntimeException $e) {
+            return $user instanceof Model\User ? self::fail(...) : \Lib\fail(...);
+        } finally {
+            echo DEBUG, \DEBUG, Sub\DEBUG;
+        }
+    }
+}
+
+enum Status: int implements HasLabel {
+    case Ok = 200;
+}
+
+namespace Other {
+    new Controller();
+}
+===report===
+2:11: Namespace App\Http -> App\Http
+4:5: Class Psr\Log\LoggerInterface -> Psr\Log\LoggerInterface
+4:40: Class Psr\Log\LogLevel -> Psr\Log\LogLevel
+5:14: Function Lib\{render -> Lib\render
+5:14: Function Lib\{render, escape -> Lib\escape
+6:11: Constant Lib\VERSION -> Lib\VERSION
+8:3: Class Route -> App\Http\Route
+9:36: Class Controller -> App\Http\Controller
+9:58: Class \Countable -> Countable
+10:9: Class Helpers -> App\Http\Helpers
+12:41: Class Logger -> Psr\Log\LoggerInterface
+14:26: Class Request -> App\Http\Request
+14:34: Class Model\User -> App\Http\Model\User
+14:59: Class Response -> App\Http\Response
+16:22: Class LogLevel -> Psr\Log\LogLevel
+17:40: Function e -> Lib\escape
+17:42: Function render -> Lib\render
+17:49: Constant VERSION -> Lib\VERSION
+18:24: Class Response -> App\Http\Response
+18:33: Function strlen -> App\Http\strlen (or strlen)
+18:40: Constant \PHP_EOL -> PHP_EOL
+18:51: Function namespace\status -> App\Http\status
+19:18: Class NotFound -> App\Http\NotFound
+19:29: Class \RuntimeException -> RuntimeException
+20:37: Class Model\User -> App\Http\Model\User
+20:68: Function \Lib\fail -> Lib\fail
+22:18: Constant DEBUG -> App\Http\DEBUG (or DEBUG)
+22:25: Constant \DEBUG -> DEBUG
+22:33: Constant Sub\DEBUG -> App\Http\Sub\DEBUG
+27:29: Class HasLabel -> App\Http\HasLabel
+31:11: Namespace Other -> Other
+32:9: Class Controller -> Other\Controller
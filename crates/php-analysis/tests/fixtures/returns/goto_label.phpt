@@ -0,0 +1,38 @@
+===source===
+<?php
+function l(): int {
+    goto end;
+    end:
+    return 1;
+}
+
+function m(): int {
+    return 0;
+    retry:
+    return 1;
+}
+
+function n(int $n): int {
+    if ($n) {
+        goto done;
+    }
+    goto done;
+    echo "skipped";
+    done:
+    return $n;
+}
+
+function o(int $n): int {
+    again:
+    if ($n--) {
+        goto again;
+    }
+    return $n;
+    echo "dead";
+    unused:
+    return 0;
+}
+===report===
+10:5: unreachable statement
+19:5: unreachable statement
+30:5: unreachable statement
@@ -0,0 +1,56 @@
+===source===
+<?php
+function sign(int $n): int {
+    if ($n > 0) {
+        return 1;
+    } elseif ($n < 0) {
+        return -1;
+    }
+}
+
+function complete(int $n): int {
+    if ($n > 0) {
+        return 1;
+    } else {
+        return 0;
+    }
+}
+
+function nothing(): void {
+    echo "hi";
+}
+
+function maybe(): ?string {
+    if (rand()) {
+        return "x";
+    }
+}
+
+function untyped() {
+    if (rand()) {
+        return 1;
+    }
+}
+
+function gen(): iterable {
+    yield 1;
+}
+
+$f = function (): int {
+    echo 1;
+};
+
+abstract class Repo {
+    abstract public function find(): object;
+
+    public function get(): array {
+        foreach ($this->items as $item) {
+            return $item;
+        }
+    }
+}
+===report===
+2:1: sign does not return a value on every path
+22:1: maybe does not return a value on every path
+38:6: {closure} does not return a value on every path
+45:5: Repo::get does not return a value on every path
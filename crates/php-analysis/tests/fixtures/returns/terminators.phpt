@@ -0,0 +1,70 @@
+===source===
+<?php
+function loop(): int {
+    while (true) {
+        if (rand()) {
+            return 1;
+        }
+    }
+}
+
+function forever(): int {
+    for (;;) {
+        if (rand()) {
+            break;
+        }
+    }
+}
+
+function fail(): int {
+    throw new RuntimeException();
+}
+
+function quit(): int {
+    exit(1);
+}
+
+function choose(int $n): string {
+    switch ($n) {
+        case 1:
+            return "one";
+        default:
+            return "other";
+    }
+}
+
+function partial(int $n): string {
+    switch ($n) {
+        case 1:
+            return "one";
+    }
+}
+
+function guarded(): int {
+    try {
+        return compute();
+    } catch (Exception $e) {
+        return 0;
+    } finally {
+        cleanup();
+    }
+}
+
+function swallow(): int {
+    try {
+        return compute();
+    } catch (Exception $e) {
+        log_error($e);
+    }
+}
+
+function stop(): never {
+    if (rand()) {
+        exit;
+    }
+}
+===report===
+10:1: forever does not return a value on every path
+35:1: partial does not return a value on every path
+52:1: swallow does not return a value on every path
+60:1: stop is declared never but can return implicitly
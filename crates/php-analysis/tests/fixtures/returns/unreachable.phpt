@@ -0,0 +1,52 @@
+===source===
+<?php
+function a(): int {
+    return 1;
+    echo "dead";
+    echo "also dead";
+}
+
+function b(array $items): void {
+    foreach ($items as $item) {
+        if ($item) {
+            continue;
+            $item++;
+        }
+        break;
+        echo $item;
+    }
+}
+
+function c(): void {
+    throw new Exception();
+    if (true) {
+        echo 1;
+    }
+}
+
+function d(): void {
+    goto end;
+    echo "skipped";
+    end:
+    echo "done";
+}
+
+function e(int $n): int {
+    if ($n) {
+        return 1;
+    } else {
+        return 2;
+    }
+    return 3;
+}
+
+exit;
+echo "after exit";
+===report===
+4:5: unreachable statement
+12:13: unreachable statement
+15:9: unreachable statement
+21:5: unreachable statement
+28:5: unreachable statement
+39:5: unreachable statement
+43:1: unreachable statement
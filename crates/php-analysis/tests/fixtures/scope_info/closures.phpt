@@ -0,0 +1,56 @@
+===source===
+<?php
+function handlers(array $items, $prefix) {
+    $count = 0;
+    $unusedLabel = 'x';
+    $onItem = function ($item) use (&$count, $prefix, $unusedLabel, $late) {
+        $count++;
+        $format = fn($value) => $prefix . $value . $suffix;
+        return $format($item);
+    };
+    $late = 1;
+    foreach ($items as $key => $item) {
+        $onItem($item);
+    }
+    $collect = function () use ($items, $prefix) {
+        return compact('items') + get_defined_vars();
+    };
+    $names = function () use ($prefix, $count) {
+        return compact('prefix');
+    };
+    return fn() => array_map(fn($i) => $i . $prefix, $items);
+}
+
+class Report
+{
+    abstract public function render(string $format);
+
+    public function rows($filter)
+    {
+        if ($filter) {
+            $rows = [];
+        }
+        return $rows;
+    }
+}
+===report===
+2:1: Function defines [$items, $prefix, $count, $unusedLabel, $onItem, $late, $item, $key, $collect, $names]
+5:69:   undefined variable $late
+5:15:   Closure defines [$item, $count, $prefix, $unusedLabel, $late, $format]
+7:52:     undefined variable $suffix
+5:55:     unused use $unusedLabel
+5:69:     unused use $late
+7:19:     ArrowFunction defines [$value, $prefix, $suffix]
+7:33:       captures $prefix
+7:52:       captures $suffix
+14:16:   Closure defines [$items, $prefix]
+17:14:   Closure defines [$prefix, $count]
+17:40:     unused use $count
+20:12:   ArrowFunction defines [$prefix, $items]
+20:45:     captures $prefix
+20:54:     captures $items
+20:30:     ArrowFunction defines [$i, $prefix]
+20:45:       captures $prefix
+25:5: Method defines [$format]
+27:5: Method defines [$filter, $rows]
+32:16:   variable $rows might not be defined
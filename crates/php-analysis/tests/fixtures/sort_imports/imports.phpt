@@ -0,0 +1,78 @@
+===source===
+<?php
+namespace App\Http;
+
+use function App\Support\format;
+use Psr\Log\LoggerInterface;
+use App\Models\{User, Post as Article};
+use const PHP_EOL;
+use Psr\Log\LoggerInterface;
+use App\Models\Comment, Psr\Container\ContainerInterface;
+use function array_map;
+use App\Models\user;
+
+final class Controller {}
+
+namespace App\Other;
+
+use Zeta;
+use Alpha;
+
+namespace App\Commented;
+
+use Zeta; // keep
+use Alpha;
+===report===
+<?php
+namespace App\Http;
+
+use Psr\Container\ContainerInterface;
+use Psr\Log\LoggerInterface;
+
+use App\Models\Comment;
+use App\Models\Post as Article;
+use App\Models\User;
+
+use function array_map;
+
+use function App\Support\format;
+
+use const PHP_EOL;
+
+final class Controller {}
+
+namespace App\Other;
+
+use Alpha;
+use Zeta;
+
+namespace App\Commented;
+
+use Zeta; // keep
+use Alpha;
+---
+<?php
+namespace App\Http;
+
+use Psr\Container\ContainerInterface;
+use Psr\Log\LoggerInterface;
+
+use App\Models\{Comment, Post as Article, User};
+
+use function array_map;
+
+use function App\Support\format;
+
+use const PHP_EOL;
+
+final class Controller {}
+
+namespace App\Other;
+
+use Alpha;
+use Zeta;
+
+namespace App\Commented;
+
+use Zeta; // keep
+use Alpha;
@@ -0,0 +1,14 @@
+===source===
+<?php
+if (count($items) == 0 || $mode <> 'r') {}
+if ($input == '10' || $flag != false) {}
+$found = in_array(strtolower($ext), ['jpg', 'png'], );
+$key = array_search(needle: 1, haystack: [1, 2]);
+$loose = in_array($needle, $haystack);
+===report===
+<?php
+if (count($items) === 0 || $mode <> 'r') {}
+if ($input == '10' || $flag != false) {}
+$found = in_array(strtolower($ext), ['jpg', 'png'], true, );
+$key = array_search(needle: 1, haystack: [1, 2], strict: true);
+$loose = in_array($needle, $haystack);
@@ -0,0 +1,62 @@
+===source===
+<?php
+namespace App;
+
+const API_KEY = 'sk-live-123';
+
+class Mailer
+{
+    private const FROM = "noreply@example.com";
+    public string $subject = 'Hello';
+
+    public function send(string $to, string $mode = 'html'): string
+    {
+        static $sent = 'never';
+        $headers = ['From' => self::FROM, 'X-Mailer', 'Reply-To' => $to];
+        $this->log->info('sending', ['to' => $to]);
+        Logger::write(level: 'debug', message: "sending\n");
+        $t = new Template('mail/welcome.twig');
+        if ($mode === 'text') {
+            $body = strip_tags($this->body);
+        }
+        $body .= 'footer';
+        $path = __DIR__ . '/templates/' . $name;
+        echo __('Sent to %s', $to), "\n";
+        print('done');
+        $x = $row['id'];
+        $fn = 'strlen';
+        $fn('inline');
+        return sprintf("%s", 'ok');
+    }
+}
+
+enum Status: string
+{
+    case Active = 'active';
+}
+===report===
+4:17: SingleQuoted "'sk-live-123'" = "sk-live-123" (Initializer { name: "API_KEY" })
+8:26: DoubleQuoted "\"noreply@example.com\"" = "noreply@example.com" (Initializer { name: "FROM" })
+9:30: SingleQuoted "'Hello'" = "Hello" (Initializer { name: "subject" })
+11:53: SingleQuoted "'html'" = "html" (Initializer { name: "mode" })
+13:24: SingleQuoted "'never'" = "never" (Initializer { name: "sent" })
+14:21: SingleQuoted "'From'" = "From" (ArrayKey)
+14:43: SingleQuoted "'X-Mailer'" = "X-Mailer" (ArrayValue)
+14:55: SingleQuoted "'Reply-To'" = "Reply-To" (ArrayKey)
+15:26: SingleQuoted "'sending'" = "sending" (Argument { callee: Some("->info"), index: 0, name: None })
+15:38: SingleQuoted "'to'" = "to" (ArrayKey)
+16:30: SingleQuoted "'debug'" = "debug" (Argument { callee: Some("Logger::write"), index: 0, name: Some("level") })
+16:48: DoubleQuoted "\"sending\\n\"" = "sending\n" (Argument { callee: Some("Logger::write"), index: 1, name: Some("message") })
+17:27: SingleQuoted "'mail/welcome.twig'" = "mail/welcome.twig" (Argument { callee: Some("new Template"), index: 0, name: None })
+18:23: SingleQuoted "'text'" = "text" (Comparison)
+21:18: SingleQuoted "'footer'" = "footer" (Concat)
+22:27: SingleQuoted "'/templates/'" = "/templates/" (Concat)
+23:17: SingleQuoted "'Sent to %s'" = "Sent to %s" (Argument { callee: Some("__"), index: 0, name: None })
+23:37: DoubleQuoted "\"\\n\"" = "\n" (Echo)
+24:15: SingleQuoted "'done'" = "done" (Echo)
+25:19: SingleQuoted "'id'" = "id" (Offset)
+26:15: SingleQuoted "'strlen'" = "strlen" (Assignment)
+27:13: SingleQuoted "'inline'" = "inline" (Argument { callee: None, index: 0, name: None })
+28:24: DoubleQuoted "\"%s\"" = "%s" (Argument { callee: Some("sprintf"), index: 0, name: None })
+28:30: SingleQuoted "'ok'" = "ok" (Argument { callee: Some("sprintf"), index: 1, name: None })
+34:19: SingleQuoted "'active'" = "active" (Initializer { name: "Active" })
@@ -0,0 +1,19 @@
+===source===
+<?php
+$sql = <<<SQL
+    SELECT * FROM users
+    WHERE id = ?
+    SQL;
+$tpl = <<<'TPL'
+    Hello {$name}
+    TPL;
+$greeting = <<<EOT
+    Hello $name
+    EOT;
+$bin = b'binary';
+$mixed = "Hi $name" . 'tail';
+===report===
+2:8: Heredoc "<<<SQL\n    SELECT * FROM users\n    WHERE id = ?\n    SQL" = "SELECT * FROM users\nWHERE id = ?" (Assignment)
+6:8: Nowdoc "<<<'TPL'\n    Hello {$name}\n    TPL" = "Hello {$name}" (Assignment)
+12:8: SingleQuoted "b'binary'" = "binary" (Assignment)
+13:23: SingleQuoted "'tail'" = "tail" (Concat)
@@ -0,0 +1,34 @@
+===source===
+<?php
+$h = @fopen($path, 'r');
+@unlink($tmp);
+$v = @$config['key'] ?? null;
+@$x = \file_get_contents($url);
+@include 'optional.php';
+$r = @$db->query($sql);
+$s = @Cache::get($k);
+$n = @$obj->missing;
+$o = @new Gateway();
+@$f();
+echo @$undefined, @(1 / $d);
+function load($p) {
+    if (!@is_file($p)) {
+        return @@file($p);
+    }
+}
+===report===
+2:6: @ on call to fopen() in statement at 2:1
+3:1: @ on call to unlink() in statement at 3:1
+4:6: @ on array access in statement at 4:1
+5:1: @ on call to file_get_contents() in statement at 5:1
+6:1: @ on include in statement at 6:1
+7:6: @ on method call query() in statement at 7:1
+8:6: @ on method call get() in statement at 8:1
+9:6: @ on property access in statement at 9:1
+10:6: @ on object creation in statement at 10:1
+11:1: @ on dynamic call in statement at 11:1
+12:6: @ on variable read in statement at 12:1
+12:19: @ on expression in statement at 12:1
+14:10: @ on call to is_file() in statement at 14:5
+15:16: @ on call to file() in statement at 15:9
+15:17: @ on call to file() in statement at 15:9
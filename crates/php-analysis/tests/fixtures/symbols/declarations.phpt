@@ -0,0 +1,63 @@
+===source===
+<?php
+namespace App\Model;
+
+const ACTIVE = 1;
+
+interface HasName { public function name(): string; }
+
+trait Saves { public function save() {} }
+
+final class User implements HasName {
+    use Saves;
+    public const ACTIVE = 'active';
+    private string $email;
+    public function name(): string { return ''; }
+}
+
+enum UserStatus: string {
+    case Active = 'a';
+    const DEFAULT = self::Active;
+    public function label() {}
+}
+
+function user_controller() {
+    $anon = new class {};
+    if (true) {
+        function nested() {}
+    }
+}
+===report===
+4:7: Constant App\Model\ACTIVE
+6:1: Interface App\Model\HasName
+6:21: Method App\Model\HasName::name
+8:1: Trait App\Model\Saves
+8:15: Method App\Model\Saves::save
+10:7: Class App\Model\User
+12:5: ClassConstant App\Model\User::ACTIVE
+13:5: Property App\Model\User::$email
+14:5: Method App\Model\User::name
+17:1: Enum App\Model\UserStatus
+18:5: EnumCase App\Model\UserStatus::Active
+19:5: ClassConstant App\Model\UserStatus::DEFAULT
+20:5: Method App\Model\UserStatus::label
+23:1: Function App\Model\user_controller
+26:9: Function App\Model\nested
+? user
+  App\Model\User (4000)
+  App\Model\UserStatus (3000)
+  App\Model\user_controller (3000)
+? usrctl
+  App\Model\user_controller (1194)
+? App\Model\User::
+  App\Model\User::name (3000)
+  App\Model\User::$email (3000)
+  App\Model\User::ACTIVE (3000)
+  App\Model\UserStatus::label (1394)
+  App\Model\UserStatus::Active (1394)
+  App\Model\UserStatus::DEFAULT (1394)
+? ACTIVE
+  App\Model\ACTIVE (4000)
+  App\Model\User::ACTIVE (4000)
+  App\Model\UserStatus::Active (4000)
+? zzz
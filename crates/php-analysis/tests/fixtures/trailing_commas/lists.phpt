@@ -0,0 +1,159 @@
+===source===
+<?php
+use App\{Model\User, Model\Post,};
+use App\{
+    Cache,
+    Clock
+};
+
+function save(
+    User $user,
+    bool $force
+) {}
+
+save($user, true,);
+save($user, true, );
+save(
+    $user,
+    true // force
+);
+$values = [
+    'a' => 1,
+    'b' => 2
+];
+$pairs = array(1, 2,);
+[$a, , $b,] = $values;
+[$c, ,] = $values;
+list(
+    $d,
+    $e
+) = $values;
+$label = match ($n) {
+    1 => 'one',
+    default => 'many'
+};
+$inline = match ($n) { 1 => 'one', default => 'many', };
+$closure = function (
+    $x
+) use (
+    $user,
+    $force
+) {};
+
+#[Route(
+    '/users',
+    methods: ['GET']
+)]
+class Controller
+{
+    public function __construct(
+        private User $user,
+        private Clock $clock /* injected */
+    ) {}
+}
+===report===
+<?php
+use App\{Model\User, Model\Post};
+use App\{
+    Cache,
+    Clock,
+};
+
+function save(
+    User $user,
+    bool $force,
+) {}
+
+save($user, true);
+save($user, true);
+save(
+    $user,
+    true, // force
+);
+$values = [
+    'a' => 1,
+    'b' => 2,
+];
+$pairs = array(1, 2);
+[$a, , $b] = $values;
+[$c, ,] = $values;
+list(
+    $d,
+    $e,
+) = $values;
+$label = match ($n) {
+    1 => 'one',
+    default => 'many',
+};
+$inline = match ($n) { 1 => 'one', default => 'many' };
+$closure = function (
+    $x,
+) use (
+    $user,
+    $force,
+) {};
+
+#[Route(
+    '/users',
+    methods: ['GET'],
+)]
+class Controller
+{
+    public function __construct(
+        private User $user,
+        private Clock $clock, /* injected */
+    ) {}
+}
+---
+<?php
+use App\{Model\User, Model\Post};
+use App\{
+    Cache,
+    Clock
+};
+
+function save(
+    User $user,
+    bool $force
+) {}
+
+save($user, true);
+save($user, true);
+save(
+    $user,
+    true // force
+);
+$values = [
+    'a' => 1,
+    'b' => 2
+];
+$pairs = array(1, 2);
+[$a, , $b] = $values;
+[$c, ,] = $values;
+list(
+    $d,
+    $e
+) = $values;
+$label = match ($n) {
+    1 => 'one',
+    default => 'many'
+};
+$inline = match ($n) { 1 => 'one', default => 'many' };
+$closure = function (
+    $x
+) use (
+    $user,
+    $force
+) {};
+
+#[Route(
+    '/users',
+    methods: ['GET']
+)]
+class Controller
+{
+    public function __construct(
+        private User $user,
+        private Clock $clock /* injected */
+    ) {}
+}
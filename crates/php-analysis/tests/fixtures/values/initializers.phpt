@@ -0,0 +1,61 @@
+===source===
+<?php
+namespace App;
+
+const MINUTE = 60;
+const HOUR = 60 * MINUTE;
+const DAY = HOUR * 24, WEEK = DAY * 7;
+const LATER = NEXT + 1;
+const NS = __NAMESPACE__ . '\Jobs';
+
+interface Limits {
+    const MAX = PHP_INT_MAX;
+    const OVERFLOW = self::MAX + 1;
+}
+
+class Cache implements Limits {
+    const TTL = [self::SHORT => MINUTE * 5, 'long' => HOUR, 'never' => null];
+    const SHORT = 'short';
+    const KEYS = [...self::TTL, 'extra' => 1, 3 => 'x', 'y'];
+    const NAME = __CLASS__;
+    public int $timeout = Limits::MAX >> 60;
+    public $handler = new Handler();
+    private array $list = ['a', 'b', 5 => 'c', 'd', '7' => 'e', 'f'];
+
+    public function get(string $key, int $ttl = self::TTL['short'], ?int $retries = null, $flags = E_ALL & ~E_NOTICE) {
+        static $calls = 0, $cache = [], $now = time();
+    }
+}
+
+enum Suit: string {
+    case Hearts = 'H';
+    case Spades = 'S';
+    const DEFAULT = self::Hearts;
+}
+
+function f($scale = 1.5 * 2, $ratio = 7 / 2, $half = 1 / 2 ** 1, $neg = -PHP_INT_MAX - 1, $bad = 1 % 0) {}
+===report===
+4:16: App\MINUTE (Constant) = 60
+5:14: App\HOUR (Constant) = 3600
+6:13: App\DAY (Constant) = 86400
+6:31: App\WEEK (Constant) = 604800
+8:12: App\NS (Constant) = 'App\\Jobs'
+11:17: App\Limits::MAX (ClassConstant) = 9223372036854775807
+12:22: App\Limits::OVERFLOW (ClassConstant) = 9.223372036854776E+18
+16:17: App\Cache::TTL (ClassConstant) = ['short' => 300, 'long' => 3600, 'never' => null]
+17:19: App\Cache::SHORT (ClassConstant) = 'short'
+18:18: App\Cache::KEYS (ClassConstant) = ['short' => 300, 'long' => 3600, 'never' => null, 'extra' => 1, 3 => 'x', 4 => 'y']
+19:18: App\Cache::NAME (ClassConstant) = 'App\\Cache'
+20:27: App\Cache::$timeout (Property) = 7
+22:27: App\Cache::$list (Property) = [0 => 'a', 1 => 'b', 5 => 'c', 6 => 'd', 7 => 'e', 8 => 'f']
+24:49: $ttl (Parameter) = 300
+24:85: $retries (Parameter) = null
+24:100: $flags (Parameter) = 32759
+25:25: $calls (StaticVariable) = 0
+25:37: $cache (StaticVariable) = []
+30:19: App\Suit::Hearts (EnumCase) = 'H'
+31:19: App\Suit::Spades (EnumCase) = 'S'
+35:21: $scale (Parameter) = 3.0
+35:39: $ratio (Parameter) = 3.5
+35:54: $half (Parameter) = 0.5
+35:73: $neg (Parameter) = -9223372036854775808
@@ -0,0 +1,68 @@
+===source===
+<?php
+const EQ = [
+    '1e1' == '10',
+    '10' == '010',
+    'abc' == 0,
+    null == false,
+    null == '',
+    null == '0',
+    '1' == '01',
+    100 == ' 100 ',
+    100 == '100abc',
+    [1, 2] == [1 => 2, 0 => 1],
+    [1, 2] === [1 => 2, 0 => 1],
+    1.0 === 1,
+    INF == INF,
+    NAN == NAN,
+    [] == false,
+    'a' < 'b',
+    [1, 2, 3] > [4, 5],
+    1 <=> 2,
+    'abc' <=> null,
+];
+
+const ARITH = [
+    PHP_INT_MAX + 1,
+    PHP_INT_MIN * -1,
+    '5' + '5.5',
+    '12abc' * 2,
+    7 % -3,
+    -7 % 3,
+    2 ** 63,
+    2 ** -1,
+    10 / 4,
+    10 / 5,
+    1 << 64,
+    -8 >> 70,
+    'ab' | '  c',
+    ~5,
+    -0.0,
+];
+
+const STRINGS = [
+    0.1 + 0.2 . '',
+    1e14 . '',
+    1e15 . '',
+    0.0001 . '',
+    0.00001 . '',
+    -1.5e-7 . '',
+    true . false . null . 3,
+    'abc'[1],
+    'abc'[-1],
+    (int) '0x1A',
+    (int) ' 42 ',
+    (int) '1e3',
+    (float) 'abc',
+    (bool) '0.0',
+    (string) 1.0,
+    (array) 'x',
+];
+
+const NOT_CONSTANT = 'abc' + 1;
+const DIVIDE_BY_ZERO = 1 / 0;
+const NEGATIVE_SHIFT = 1 << -1;
+===report===
+2:12: EQ (Constant) = [true, true, false, true, true, false, true, true, false, true, false, false, true, false, true, true, true, -1, 1]
+24:15: ARITH (Constant) = [9.223372036854776E+18, 9.223372036854776E+18, 10.5, 24, 1, -1, 9.223372036854776E+18, 0.5, 2.5, 2, 0, -1, 'abc', -6, -0.0]
+42:17: STRINGS (Constant) = ['0.3', '1.0E+14', '1.0E+15', '0.0001', '1.0E-5', '-1.5E-7', '13', 'b', 'c', 0, 42, 1000, 0.0, true, '1', ['x']]
@@ -1,10 +1,12 @@
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::Span;
 
 use super::{ArenaVec, Attribute, Comment, Expr, Ident, Name, Stmt, TypeHint};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct FunctionDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub params: ArenaVec<'arena, Param<'arena, 'src>>,
@@ -18,11 +20,12 @@ pub struct FunctionDecl<'arena, 'src> {
     /// [`ParseResult::comments`](php_rs_parser::ParseResult::comments) — the
     /// two collections are disjoint. All other comment forms (line, hash,
     /// block) remain in `ParseResult::comments` regardless of position.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Param<'arena, 'src> {
     pub name: Ident<'src>,
     pub type_hint: Option<TypeHint<'arena, 'src>>,
@@ -34,12 +37,16 @@ pub struct Param<'arena, 'src> {
     pub visibility: Option<Visibility>,
     pub set_visibility: Option<Visibility>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "ArenaVec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "ArenaVec::is_empty"))]
     pub hooks: ArenaVec<'arena, PropertyHook<'arena, 'src>>,
     pub span: Span,
+    /// The `,` after the parameter; on the last one, a trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Visibility {
     /// `public` — accessible from anywhere.
     Public,
@@ -49,7 +56,8 @@ pub enum Visibility {
     Private,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClassDecl<'arena, 'src> {
     pub name: Option<Ident<'src>>,
     pub modifiers: ClassModifiers,
@@ -57,24 +65,28 @@ pub struct ClassDecl<'arena, 'src> {
     pub implements: ArenaVec<'arena, Name<'arena, 'src>>,
     pub members: ArenaVec<'arena, ClassMember<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClassModifiers {
     pub is_abstract: bool,
     pub is_final: bool,
     pub is_readonly: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClassMember<'arena, 'src> {
     pub kind: ClassMemberKind<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum ClassMemberKind<'arena, 'src> {
     Property(PropertyDecl<'arena, 'src>),
     Method(MethodDecl<'arena, 'src>),
@@ -82,7 +94,8 @@ pub enum ClassMemberKind<'arena, 'src> {
     TraitUse(TraitUseDecl<'arena, 'src>),
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PropertyDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub visibility: Option<Visibility>,
@@ -92,13 +105,14 @@ pub struct PropertyDecl<'arena, 'src> {
     pub type_hint: Option<TypeHint<'arena, 'src>>,
     pub default: Option<Expr<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "ArenaVec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "ArenaVec::is_empty"))]
     pub hooks: ArenaVec<'arena, PropertyHook<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PropertyHookKind {
     /// `get` hook — called when the property is read.
     Get,
@@ -106,7 +120,8 @@ pub enum PropertyHookKind {
     Set,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PropertyHookBody<'arena, 'src> {
     /// `{ stmts }` — a full statement block.
     Block(ArenaVec<'arena, Stmt<'arena, 'src>>),
@@ -116,7 +131,8 @@ pub enum PropertyHookBody<'arena, 'src> {
     Abstract,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PropertyHook<'arena, 'src> {
     pub kind: PropertyHookKind,
     pub body: PropertyHookBody<'arena, 'src>,
@@ -127,7 +143,8 @@ pub struct PropertyHook<'arena, 'src> {
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MethodDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub visibility: Option<Visibility>,
@@ -139,36 +156,40 @@ pub struct MethodDecl<'arena, 'src> {
     pub return_type: Option<TypeHint<'arena, 'src>>,
     pub body: Option<ArenaVec<'arena, Stmt<'arena, 'src>>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClassConstDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub visibility: Option<Visibility>,
     pub is_final: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub type_hint: Option<&'arena TypeHint<'arena, 'src>>,
     pub value: Expr<'arena, 'src>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TraitUseDecl<'arena, 'src> {
     pub traits: ArenaVec<'arena, Name<'arena, 'src>>,
     pub adaptations: ArenaVec<'arena, TraitAdaptation<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TraitAdaptation<'arena, 'src> {
     pub kind: TraitAdaptationKind<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum TraitAdaptationKind<'arena, 'src> {
     /// `A::foo insteadof B, C;`
     Precedence {
@@ -185,43 +206,49 @@ pub enum TraitAdaptationKind<'arena, 'src> {
     },
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct InterfaceDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub extends: ArenaVec<'arena, Name<'arena, 'src>>,
     pub members: ArenaVec<'arena, ClassMember<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TraitDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub members: ArenaVec<'arena, ClassMember<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EnumDecl<'arena, 'src> {
     pub name: Ident<'src>,
     pub scalar_type: Option<Name<'arena, 'src>>,
     pub implements: ArenaVec<'arena, Name<'arena, 'src>>,
     pub members: ArenaVec<'arena, EnumMember<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EnumMember<'arena, 'src> {
     pub kind: EnumMemberKind<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum EnumMemberKind<'arena, 'src> {
     /// An enum case: `case Foo;` or `case Foo = 'foo';` (backed enum).
     Case(EnumCase<'arena, 'src>),
@@ -233,11 +260,95 @@ pub enum EnumMemberKind<'arena, 'src> {
     TraitUse(TraitUseDecl<'arena, 'src>),
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EnumCase<'arena, 'src> {
     pub name: Ident<'src>,
     pub value: Option<Expr<'arena, 'src>>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
+
+/// Accessors for the members of a class-like declaration, by kind.
+macro_rules! member_accessors {
+    ($decl:ident) => {
+        impl<'arena, 'src> $decl<'arena, 'src> {
+            /// The methods, in source order.
+            pub fn methods(&self) -> impl Iterator<Item = &MethodDecl<'arena, 'src>> + '_ {
+                self.members.iter().filter_map(|member| match &member.kind {
+                    ClassMemberKind::Method(method) => Some(method),
+                    _ => None,
+                })
+            }
+
+            /// The method named `name`, compared case-insensitively as PHP
+            /// does.
+            pub fn method(&self, name: &str) -> Option<&MethodDecl<'arena, 'src>> {
+                self.methods().find(|method| {
+                    method
+                        .name
+                        .as_str()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                })
+            }
+
+            /// The property declarations, in source order, promoted
+            /// constructor parameters excluded.
+            pub fn properties(&self) -> impl Iterator<Item = &PropertyDecl<'arena, 'src>> + '_ {
+                self.members.iter().filter_map(|member| match &member.kind {
+                    ClassMemberKind::Property(property) => Some(property),
+                    _ => None,
+                })
+            }
+
+            /// The class constants, in source order.
+            pub fn constants(&self) -> impl Iterator<Item = &ClassConstDecl<'arena, 'src>> + '_ {
+                self.members.iter().filter_map(|member| match &member.kind {
+                    ClassMemberKind::ClassConst(constant) => Some(constant),
+                    _ => None,
+                })
+            }
+        }
+    };
+}
+
+member_accessors!(ClassDecl);
+member_accessors!(InterfaceDecl);
+member_accessors!(TraitDecl);
+
+impl<'arena, 'src> EnumDecl<'arena, 'src> {
+    /// The cases, in source order.
+    pub fn cases(&self) -> impl Iterator<Item = &EnumCase<'arena, 'src>> + '_ {
+        self.members.iter().filter_map(|member| match &member.kind {
+            EnumMemberKind::Case(case) => Some(case),
+            _ => None,
+        })
+    }
+
+    /// The methods, in source order.
+    pub fn methods(&self) -> impl Iterator<Item = &MethodDecl<'arena, 'src>> + '_ {
+        self.members.iter().filter_map(|member| match &member.kind {
+            EnumMemberKind::Method(method) => Some(method),
+            _ => None,
+        })
+    }
+
+    /// The method named `name`, compared case-insensitively as PHP does.
+    pub fn method(&self, name: &str) -> Option<&MethodDecl<'arena, 'src>> {
+        self.methods().find(|method| {
+            method
+                .name
+                .as_str()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// The constants, in source order.
+    pub fn constants(&self) -> impl Iterator<Item = &ClassConstDecl<'arena, 'src>> + '_ {
+        self.members.iter().filter_map(|member| match &member.kind {
+            EnumMemberKind::ClassConst(constant) => Some(constant),
+            _ => None,
+        })
+    }
+}
@@ -1,8 +1,11 @@
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::Span;
 
-use super::{is_false, ArenaVec, Arg, Attribute, ClassDecl, Param, Stmt, TypeHint};
+#[cfg(feature = "serde")]
+use super::is_false;
+use super::{ArenaVec, Arg, Attribute, ClassDecl, Name, Param, Stmt, TypeHint};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum NameStrInner<'arena, 'src> {
@@ -86,19 +89,23 @@ impl<'arena, 'src> std::fmt::Debug for NameStr<'arena, 'src> {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'arena, 'src> serde::Serialize for NameStr<'arena, 'src> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         self.as_str().serialize(serializer)
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Expr<'arena, 'src> {
     pub kind: ExprKind<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum ExprKind<'arena, 'src> {
     /// Integer literal
     Int(i64),
@@ -106,21 +113,41 @@ pub enum ExprKind<'arena, 'src> {
     /// Float literal
     Float(f64),
 
-    /// String literal
-    String(&'arena str),
+    /// Single- or double-quoted string literal without interpolation.
+    ///
+    /// `value` is the string with escape sequences decoded. `raw` is the
+    /// text between the quotes as written; it is empty for a string a tool
+    /// built rather than parsed.
+    ///
+    /// A runtime string need not be UTF-8: `"\xE9"` is the single byte
+    /// `0xE9`. `value` holds those bytes as `php_rs_parser::bytes::decode`
+    /// does, with each byte that is not valid UTF-8 as a reserved character,
+    /// so `php_rs_parser::bytes::encode(value)` is exactly the string PHP
+    /// builds, and `"\xC3\xA9"` and `"é"` have the same `value`.
+    String { value: &'arena str, raw: &'src str },
 
     /// Interpolated string: `"Hello $name, you are {$age} years old"`
     InterpolatedString(ArenaVec<'arena, StringPart<'arena, 'src>>),
 
     /// Heredoc: `<<<EOT ... EOT`
+    ///
+    /// `raw` is the body as written, between the opening line and the
+    /// closing marker line. `parts` hold its value: the closing marker's
+    /// indentation is removed from every line (PHP 7.3 flexible heredoc) and
+    /// escape sequences are decoded.
     Heredoc {
         label: &'src str,
+        raw: &'src str,
         parts: ArenaVec<'arena, StringPart<'arena, 'src>>,
     },
 
     /// Nowdoc: `<<<'EOT' ... EOT`
+    ///
+    /// `raw` is the body as written; `value` is the body with the closing
+    /// marker's indentation removed from every line.
     Nowdoc {
         label: &'src str,
+        raw: &'src str,
         value: &'arena str,
     },
 
@@ -139,9 +166,19 @@ pub enum ExprKind<'arena, 'src> {
     /// Variable variable: `$$var`, `$$$var`, `${expr}`
     VariableVariable(&'arena Expr<'arena, 'src>),
 
-    /// Identifier (bare name, e.g. function name in a call)
+    /// Member name after `->`, `?->`, or `::`: the `bar` in `$foo->bar` or `Foo::bar()`.
     Identifier(NameStr<'arena, 'src>),
 
+    /// Function name with its parts and qualification: the callee in
+    /// `strlen()` or `\App\helper()`. The class in `new Foo`,
+    /// `$x instanceof Foo`, `catch (Foo $e)`, and `Foo::bar()`, including
+    /// `self`/`parent`/`static`, is a [`ClassRef`] instead.
+    Name(Name<'arena, 'src>),
+
+    /// Constant fetch: a name not used as a callee or class reference, such
+    /// as `PHP_EOL`, `\E_ALL`, or `App\VERSION`.
+    ConstFetch(Name<'arena, 'src>),
+
     /// Assignment: `$x = expr` or `$x += expr`
     Assign(AssignExpr<'arena, 'src>),
 
@@ -166,11 +203,15 @@ pub enum ExprKind<'arena, 'src> {
     /// Array literal: `[1, 2, 3]` or `['a' => 1]`
     Array(ArenaVec<'arena, ArrayElement<'arena, 'src>>),
 
+    /// Destructuring target: `list($a, $b)`, or `[$a, $b]` on the left of `=`
+    /// or as a `foreach` value. Nested targets are `List`s too.
+    List(ListExpr<'arena, 'src>),
+
     /// Array access: `$arr[index]`
     ArrayAccess(ArrayAccessExpr<'arena, 'src>),
 
     /// Print expression: `print expr`
-    Print(&'arena Expr<'arena, 'src>),
+    Print(PrintExpr<'arena, 'src>),
 
     /// Parenthesized expression: `(expr)`
     Parenthesized(&'arena Expr<'arena, 'src>),
@@ -178,8 +219,11 @@ pub enum ExprKind<'arena, 'src> {
     /// Cast expression: `(int)$x`, `(string)$x`, etc.
     Cast(CastKind, &'arena Expr<'arena, 'src>),
 
+    /// Type check: `$x instanceof Foo`
+    Instanceof(InstanceofExpr<'arena, 'src>),
+
     /// Error suppression: `@expr`
-    ErrorSuppress(&'arena Expr<'arena, 'src>),
+    ErrorSuppress(ErrorSuppressExpr<'arena, 'src>),
 
     /// Isset: `isset($a, $b)`
     Isset(ArenaVec<'arena, Expr<'arena, 'src>>),
@@ -199,8 +243,8 @@ pub enum ExprKind<'arena, 'src> {
     /// Magic constant: `__LINE__`, `__FILE__`, etc.
     MagicConst(MagicConstKind),
 
-    /// Clone: `clone $obj`
-    Clone(&'arena Expr<'arena, 'src>),
+    /// Clone: `clone $obj` or `clone($obj)`
+    Clone(CloneExpr<'arena, 'src>),
 
     /// Clone with property overrides: `clone($obj, ['prop' => $val])` — PHP 8.5+
     CloneWith(&'arena Expr<'arena, 'src>, &'arena Expr<'arena, 'src>),
@@ -234,13 +278,13 @@ pub enum ExprKind<'arena, 'src> {
 
     /// Dynamic class constant access: `Foo::{expr}`
     ClassConstAccessDynamic {
-        class: &'arena Expr<'arena, 'src>,
+        class: ClassRef<'arena, 'src>,
         member: &'arena Expr<'arena, 'src>,
     },
 
     /// Dynamic static property access: `A::$$b`, `A::${'b'}`
     StaticPropertyAccessDynamic {
-        class: &'arena Expr<'arena, 'src>,
+        class: ClassRef<'arena, 'src>,
         member: &'arena Expr<'arena, 'src>,
     },
 
@@ -265,7 +309,8 @@ pub enum ExprKind<'arena, 'src> {
     /// First-class callable: `strlen(...)`, `$obj->method(...)`, `Foo::bar(...)`
     CallableCreate(CallableCreateExpr<'arena, 'src>),
 
-    /// Omitted element in destructuring: `[$a, , $c]` or `list($a, , $c)`
+    /// Omitted element in destructuring: `[$a, , $c]` or `list($a, , $c)`.
+    /// Its span is empty, just before the comma that ends the slot.
     Omit,
 
     /// Error placeholder
@@ -282,7 +327,9 @@ impl<'arena, 'src> Expr<'arena, 'src> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum CastKind {
     /// `(int)` or `(integer)` cast.
     Int,
@@ -302,7 +349,25 @@ pub enum CastKind {
     Void,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+impl CastKind {
+    /// The cast as written in PHP, with its parentheses, in its canonical
+    /// spelling: `(int)`, never `(integer)`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CastKind::Int => "(int)",
+            CastKind::Float => "(float)",
+            CastKind::String => "(string)",
+            CastKind::Bool => "(bool)",
+            CastKind::Array => "(array)",
+            CastKind::Object => "(object)",
+            CastKind::Unset => "(unset)",
+            CastKind::Void => "(void)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum IncludeKind {
     /// `include 'file.php'` — emits a warning if the file is not found.
     Include,
@@ -314,7 +379,9 @@ pub enum IncludeKind {
     RequireOnce,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum MagicConstKind {
     /// `__CLASS__` — name of the current class, or empty string outside a class.
     Class,
@@ -336,18 +403,38 @@ pub enum MagicConstKind {
     Property,
 }
 
+impl MagicConstKind {
+    /// The constant as written in PHP, in upper case.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MagicConstKind::Class => "__CLASS__",
+            MagicConstKind::Dir => "__DIR__",
+            MagicConstKind::File => "__FILE__",
+            MagicConstKind::Function => "__FUNCTION__",
+            MagicConstKind::Line => "__LINE__",
+            MagicConstKind::Method => "__METHOD__",
+            MagicConstKind::Namespace => "__NAMESPACE__",
+            MagicConstKind::Trait => "__TRAIT__",
+            MagicConstKind::Property => "__PROPERTY__",
+        }
+    }
+}
+
 // --- Expression sub-types ---
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AssignExpr<'arena, 'src> {
     pub target: &'arena Expr<'arena, 'src>,
     pub op: AssignOp,
     pub value: &'arena Expr<'arena, 'src>,
-    #[serde(skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub by_ref: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum AssignOp {
     /// `=`
     Assign,
@@ -379,14 +466,39 @@ pub enum AssignOp {
     Coalesce,
 }
 
-#[derive(Debug, Serialize)]
+impl AssignOp {
+    /// The operator as written in PHP.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AssignOp::Assign => "=",
+            AssignOp::Plus => "+=",
+            AssignOp::Minus => "-=",
+            AssignOp::Mul => "*=",
+            AssignOp::Div => "/=",
+            AssignOp::Mod => "%=",
+            AssignOp::Pow => "**=",
+            AssignOp::Concat => ".=",
+            AssignOp::BitwiseAnd => "&=",
+            AssignOp::BitwiseOr => "|=",
+            AssignOp::BitwiseXor => "^=",
+            AssignOp::ShiftLeft => "<<=",
+            AssignOp::ShiftRight => ">>=",
+            AssignOp::Coalesce => "??=",
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct BinaryExpr<'arena, 'src> {
     pub left: &'arena Expr<'arena, 'src>,
     pub op: BinaryOp,
     pub right: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum BinaryOp {
     /// `+`
     Add,
@@ -440,19 +552,99 @@ pub enum BinaryOp {
     LogicalOr,
     /// `xor` — boolean XOR.
     LogicalXor,
-    /// `instanceof` — type-check operator; `$x instanceof Foo`.
-    Instanceof,
     /// `|>` — pipe operator (PHP 8.5+); passes the left operand as the first argument of the right callable.
     Pipe,
 }
 
-#[derive(Debug, Serialize)]
+impl BinaryOp {
+    /// The operator as written in PHP. `NotEqual` is always `!=`, though
+    /// `<>` parses to it too.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Mod => "%",
+            BinaryOp::Pow => "**",
+            BinaryOp::Concat => ".",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::Identical => "===",
+            BinaryOp::NotIdentical => "!==",
+            BinaryOp::Less => "<",
+            BinaryOp::Greater => ">",
+            BinaryOp::LessOrEqual => "<=",
+            BinaryOp::GreaterOrEqual => ">=",
+            BinaryOp::Spaceship => "<=>",
+            BinaryOp::BooleanAnd => "&&",
+            BinaryOp::BooleanOr => "||",
+            BinaryOp::BitwiseAnd => "&",
+            BinaryOp::BitwiseOr => "|",
+            BinaryOp::BitwiseXor => "^",
+            BinaryOp::ShiftLeft => "<<",
+            BinaryOp::ShiftRight => ">>",
+            BinaryOp::LogicalAnd => "and",
+            BinaryOp::LogicalOr => "or",
+            BinaryOp::LogicalXor => "xor",
+            BinaryOp::Pipe => "|>",
+        }
+    }
+
+    /// `true` for `==` and `!=`, which compare after type juggling.
+    pub fn is_loose_equality(self) -> bool {
+        matches!(self, BinaryOp::Equal | BinaryOp::NotEqual)
+    }
+
+    /// `true` for `==`, `!=`, `===`, and `!==`.
+    pub fn is_equality(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Equal | BinaryOp::NotEqual | BinaryOp::Identical | BinaryOp::NotIdentical
+        )
+    }
+
+    /// `true` for the operators that always produce a `bool`: equality,
+    /// ordering (but not `<=>`), and the boolean operators.
+    pub fn is_boolean(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Identical
+                | BinaryOp::NotIdentical
+                | BinaryOp::Less
+                | BinaryOp::Greater
+                | BinaryOp::LessOrEqual
+                | BinaryOp::GreaterOrEqual
+                | BinaryOp::BooleanAnd
+                | BinaryOp::BooleanOr
+                | BinaryOp::LogicalAnd
+                | BinaryOp::LogicalOr
+                | BinaryOp::LogicalXor
+        )
+    }
+
+    /// The strict form of a loose equality: `===` for `==`, `!==` for `!=`.
+    pub fn strict_counterpart(self) -> Option<BinaryOp> {
+        match self {
+            BinaryOp::Equal => Some(BinaryOp::Identical),
+            BinaryOp::NotEqual => Some(BinaryOp::NotIdentical),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UnaryPrefixExpr<'arena, 'src> {
     pub op: UnaryPrefixOp,
     pub operand: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum UnaryPrefixOp {
     /// `-expr` — arithmetic negation.
     Negate,
@@ -468,13 +660,53 @@ pub enum UnaryPrefixOp {
     PreDecrement,
 }
 
-#[derive(Debug, Serialize)]
+impl UnaryPrefixOp {
+    /// The operator as written in PHP.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UnaryPrefixOp::Negate => "-",
+            UnaryPrefixOp::Plus => "+",
+            UnaryPrefixOp::BooleanNot => "!",
+            UnaryPrefixOp::BitwiseNot => "~",
+            UnaryPrefixOp::PreIncrement => "++",
+            UnaryPrefixOp::PreDecrement => "--",
+        }
+    }
+}
+
+/// `print expr`. `keyword_span` covers `print`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PrintExpr<'arena, 'src> {
+    pub expr: &'arena Expr<'arena, 'src>,
+    pub keyword_span: Span,
+}
+
+/// `@expr`. `operator_span` covers the `@`, so a fixer can delete exactly it.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ErrorSuppressExpr<'arena, 'src> {
+    pub expr: &'arena Expr<'arena, 'src>,
+    pub operator_span: Span,
+}
+
+/// `clone $obj` or `clone($obj)`. `keyword_span` covers `clone`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CloneExpr<'arena, 'src> {
+    pub object: &'arena Expr<'arena, 'src>,
+    pub keyword_span: Span,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UnaryPostfixExpr<'arena, 'src> {
     pub operand: &'arena Expr<'arena, 'src>,
     pub op: UnaryPostfixOp,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum UnaryPostfixOp {
     /// `$x++` — post-increment; returns the current value then increments.
     PostIncrement,
@@ -482,7 +714,8 @@ pub enum UnaryPostfixOp {
     PostDecrement,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TernaryExpr<'arena, 'src> {
     pub condition: &'arena Expr<'arena, 'src>,
     /// None for short ternary `$x ?: $y`
@@ -490,29 +723,47 @@ pub struct TernaryExpr<'arena, 'src> {
     pub else_expr: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct NullCoalesceExpr<'arena, 'src> {
     pub left: &'arena Expr<'arena, 'src>,
     pub right: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct FunctionCallExpr<'arena, 'src> {
     pub name: &'arena Expr<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ArrayElement<'arena, 'src> {
     pub key: Option<Expr<'arena, 'src>>,
     pub value: Expr<'arena, 'src>,
     pub unpack: bool,
-    #[serde(skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub by_ref: bool,
     pub span: Span,
+    /// The `,` after the element; on the last one, a trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize)]
+/// A destructuring target. Each element's `key` and `by_ref` are those of
+/// `'k' => &$v`; a skipped slot has an [`ExprKind::Omit`] value.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ListExpr<'arena, 'src> {
+    pub elements: ArenaVec<'arena, ArrayElement<'arena, 'src>>,
+    /// Written `list(...)` rather than `[...]`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub uses_list: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ArrayAccessExpr<'arena, 'src> {
     pub array: &'arena Expr<'arena, 'src>,
     pub index: Option<&'arena Expr<'arena, 'src>>,
@@ -520,46 +771,115 @@ pub struct ArrayAccessExpr<'arena, 'src> {
 
 // --- OOP Expression sub-types ---
 
-#[derive(Debug, Serialize)]
+/// The class operand of `new`, `instanceof`, `catch`, and `::` access.
+///
+/// `self`, `parent`, and `static` get their own variants, so late static
+/// binding (`new static`, `static::create()`) is a pattern match rather than
+/// a case-insensitive string comparison.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ClassRef<'arena, 'src> {
+    /// A class name: `Foo`, `\App\Foo`, `namespace\Foo`.
+    Name(&'arena Name<'arena, 'src>),
+    /// `self`
+    SelfKw(Span),
+    /// `parent`
+    ParentKw(Span),
+    /// `static` — late static binding.
+    StaticKw(Span),
+    /// A class computed at run time: `new $class`, `$obj::CONST`,
+    /// `new (expr)`, or an anonymous class.
+    Expr(&'arena Expr<'arena, 'src>),
+}
+
+impl<'arena, 'src> ClassRef<'arena, 'src> {
+    pub fn span(&self) -> Span {
+        match self {
+            ClassRef::Name(name) => name.span(),
+            ClassRef::SelfKw(span) | ClassRef::ParentKw(span) | ClassRef::StaticKw(span) => *span,
+            ClassRef::Expr(expr) => expr.span,
+        }
+    }
+
+    /// The class name, if the reference is written as one.
+    pub fn name(&self) -> Option<&Name<'arena, 'src>> {
+        match self {
+            ClassRef::Name(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// `true` for `static`, which resolves to the called class at run time.
+    pub fn is_late_static_binding(&self) -> bool {
+        matches!(self, ClassRef::StaticKw(_))
+    }
+
+    /// `self`, `parent`, or `static` as written in PHP; `None` otherwise.
+    pub fn keyword(&self) -> Option<&'static str> {
+        match self {
+            ClassRef::SelfKw(_) => Some("self"),
+            ClassRef::ParentKw(_) => Some("parent"),
+            ClassRef::StaticKw(_) => Some("static"),
+            ClassRef::Name(_) | ClassRef::Expr(_) => None,
+        }
+    }
+}
+
+/// `$expr instanceof Class`
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InstanceofExpr<'arena, 'src> {
+    pub expr: &'arena Expr<'arena, 'src>,
+    pub class: ClassRef<'arena, 'src>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct NewExpr<'arena, 'src> {
-    pub class: &'arena Expr<'arena, 'src>,
+    pub class: ClassRef<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PropertyAccessExpr<'arena, 'src> {
     pub object: &'arena Expr<'arena, 'src>,
     pub property: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MethodCallExpr<'arena, 'src> {
     pub object: &'arena Expr<'arena, 'src>,
     pub method: &'arena Expr<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct StaticAccessExpr<'arena, 'src> {
-    pub class: &'arena Expr<'arena, 'src>,
+    pub class: ClassRef<'arena, 'src>,
     pub member: &'arena Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct StaticMethodCallExpr<'arena, 'src> {
-    pub class: &'arena Expr<'arena, 'src>,
+    pub class: ClassRef<'arena, 'src>,
     pub method: &'arena Expr<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct StaticDynMethodCallExpr<'arena, 'src> {
-    pub class: &'arena Expr<'arena, 'src>,
+    pub class: ClassRef<'arena, 'src>,
     pub method: &'arena Expr<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClosureExpr<'arena, 'src> {
     pub is_static: bool,
     pub by_ref: bool,
@@ -570,14 +890,19 @@ pub struct ClosureExpr<'arena, 'src> {
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ClosureUseVar<'src> {
     pub name: &'src str,
     pub by_ref: bool,
     pub span: Span,
+    /// The `,` after the variable; on the last one, a trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ArrowFunctionExpr<'arena, 'src> {
     pub is_static: bool,
     pub by_ref: bool,
@@ -587,21 +912,27 @@ pub struct ArrowFunctionExpr<'arena, 'src> {
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MatchExpr<'arena, 'src> {
     pub subject: &'arena Expr<'arena, 'src>,
     pub arms: ArenaVec<'arena, MatchArm<'arena, 'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MatchArm<'arena, 'src> {
     /// None for `default`
     pub conditions: Option<ArenaVec<'arena, Expr<'arena, 'src>>>,
     pub body: Expr<'arena, 'src>,
     pub span: Span,
+    /// The `,` after the arm; on the last one, a trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct YieldExpr<'arena, 'src> {
     pub key: Option<&'arena Expr<'arena, 'src>>,
     pub value: Option<&'arena Expr<'arena, 'src>>,
@@ -611,12 +942,15 @@ pub struct YieldExpr<'arena, 'src> {
 
 // --- First-class callable ---
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CallableCreateExpr<'arena, 'src> {
     pub kind: CallableCreateKind<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum CallableCreateKind<'arena, 'src> {
     /// `foo(...)`, `$var(...)`, `\Ns\func(...)`
     Function(&'arena Expr<'arena, 'src>),
@@ -632,17 +966,20 @@ pub enum CallableCreateKind<'arena, 'src> {
     },
     /// `Foo::bar(...)`
     StaticMethod {
-        class: &'arena Expr<'arena, 'src>,
+        class: ClassRef<'arena, 'src>,
         method: &'arena Expr<'arena, 'src>,
     },
 }
 
 // --- String interpolation ---
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum StringPart<'arena, 'src> {
-    /// A plain text segment of an interpolated string or heredoc.
-    Literal(&'arena str),
+    /// A plain text segment of an interpolated string or heredoc. `value` is
+    /// the decoded text; `span` covers the segment's source bytes, escapes
+    /// and (in indented heredocs) stripped indentation included.
+    Literal { value: &'arena str, span: Span },
     /// An embedded expression: `$var`, `{$expr}`, or `${var}`.
     Expr(Expr<'arena, 'src>),
 }
@@ -1,11 +1,19 @@
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::Span;
 
-use super::{ArenaVec, Expr, Name, Stmt};
+use super::{
+    ArenaVec, ClassDecl, EnumDecl, Expr, FunctionDecl, InterfaceDecl, Name, NamespaceBody, Stmt,
+    StmtKind, TraitDecl,
+};
+
+#[cfg(feature = "serde")]
+use super::is_false;
 
 /// A comment found in the source file.
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Comment<'src> {
     pub kind: CommentKind,
     /// Raw text of the comment including its delimiters (e.g. `// foo`, `/* bar */`, `/** baz */`).
@@ -14,7 +22,8 @@ pub struct Comment<'src> {
 }
 
 /// Distinguishes the four syntactic forms of PHP comment.
-#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum CommentKind {
     /// `// …` — single-line slash comment
     Line,
@@ -27,22 +36,140 @@ pub enum CommentKind {
 }
 
 /// The root AST node representing a complete PHP file.
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Program<'arena, 'src> {
     pub stmts: ArenaVec<'arena, Stmt<'arena, 'src>>,
     pub span: Span,
+    /// Whether the file ends in inline HTML rather than PHP code: after a
+    /// final `?>`, or without any open tag.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub ends_in_html: bool,
+    /// The final `?>`, when the file ends in inline HTML after it. Any
+    /// text after it is the last [`InlineHtml`](super::InlineHtml)
+    /// statement; there is none when the `?>` ends the file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub close_tag: Option<Span>,
+}
+
+impl Program<'_, '_> {
+    /// The byte offset of the raw data after `__halt_compiler();`, the value
+    /// of PHP's `__COMPILER_HALT_OFFSET__`, or `None` when the file does not
+    /// halt. Slice the source bytes from here to read an embedded payload.
+    ///
+    /// ```
+    /// use php_ast::*;
+    ///
+    /// // `<?php __halt_compiler();` followed by a binary payload.
+    /// let src = b"<?php __halt_compiler();\x00\x01payload";
+    /// let arena = bumpalo::Bump::new();
+    /// let mut stmts = ArenaVec::new_in(&arena);
+    /// stmts.push(Stmt {
+    ///     kind: StmtKind::HaltCompiler(HaltCompilerData {
+    ///         text: "\0\x01payload",
+    ///         span: Span::new(24, 33),
+    ///     }),
+    ///     span: Span::new(6, 24),
+    /// });
+    /// let program = Program { stmts, span: Span::new(0, 33), ends_in_html: false, close_tag: None };
+    /// let offset = program.halt_offset().unwrap() as usize;
+    /// assert_eq!(&src[offset..], b"\x00\x01payload");
+    /// ```
+    pub fn halt_offset(&self) -> Option<u32> {
+        let mut stmts = &self.stmts[..];
+        // The halt is the last statement, possibly of a braced namespace.
+        loop {
+            match &stmts.last()?.kind {
+                StmtKind::HaltCompiler(data) => return Some(data.span.start),
+                StmtKind::Namespace(ns) => match &ns.body {
+                    NamespaceBody::Braced(body) => stmts = body,
+                    NamespaceBody::Simple => return None,
+                },
+                _ => return None,
+            }
+        }
+    }
+}
+
+impl<'arena, 'src> Program<'arena, 'src> {
+    /// The statements at the top level of the file, in source order, with
+    /// those of braced `namespace { … }` blocks in place of the blocks.
+    /// Declarations nested in functions or conditionals are not included.
+    pub fn top_level_stmts(&self) -> impl Iterator<Item = &Stmt<'arena, 'src>> + '_ {
+        self.stmts.iter().flat_map(|stmt| match &stmt.kind {
+            StmtKind::Namespace(ns) => match &ns.body {
+                NamespaceBody::Braced(body) => body.iter(),
+                NamespaceBody::Simple => [].iter(),
+            },
+            _ => std::slice::from_ref(stmt).iter(),
+        })
+    }
+
+    /// The top-level function declarations; see
+    /// [`top_level_stmts`](Self::top_level_stmts).
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionDecl<'arena, 'src>> + '_ {
+        self.top_level_stmts().filter_map(|stmt| match &stmt.kind {
+            StmtKind::Function(decl) => Some(*decl),
+            _ => None,
+        })
+    }
+
+    /// The top-level class declarations.
+    pub fn classes(&self) -> impl Iterator<Item = &ClassDecl<'arena, 'src>> + '_ {
+        self.top_level_stmts().filter_map(|stmt| match &stmt.kind {
+            StmtKind::Class(decl) => Some(*decl),
+            _ => None,
+        })
+    }
+
+    /// The top-level interface declarations.
+    pub fn interfaces(&self) -> impl Iterator<Item = &InterfaceDecl<'arena, 'src>> + '_ {
+        self.top_level_stmts().filter_map(|stmt| match &stmt.kind {
+            StmtKind::Interface(decl) => Some(*decl),
+            _ => None,
+        })
+    }
+
+    /// The top-level trait declarations.
+    pub fn traits(&self) -> impl Iterator<Item = &TraitDecl<'arena, 'src>> + '_ {
+        self.top_level_stmts().filter_map(|stmt| match &stmt.kind {
+            StmtKind::Trait(decl) => Some(*decl),
+            _ => None,
+        })
+    }
+
+    /// The top-level enum declarations.
+    pub fn enums(&self) -> impl Iterator<Item = &EnumDecl<'arena, 'src>> + '_ {
+        self.top_level_stmts().filter_map(|stmt| match &stmt.kind {
+            StmtKind::Enum(decl) => Some(*decl),
+            _ => None,
+        })
+    }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Arg<'arena, 'src> {
+    /// The label of a named argument, `key` in `f(key: 1)`; its span covers
+    /// the label alone.
     pub name: Option<Name<'arena, 'src>>,
+    /// The `:` after a named argument's label.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub colon: Option<Span>,
     pub value: Expr<'arena, 'src>,
     pub unpack: bool,
     pub by_ref: bool,
     pub span: Span,
+    /// The `,` after the argument; on the last one, a trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Attribute<'arena, 'src> {
     pub name: Name<'arena, 'src>,
     pub args: ArenaVec<'arena, Arg<'arena, 'src>>,
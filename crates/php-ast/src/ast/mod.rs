@@ -10,11 +10,13 @@ pub use misc::*;
 pub use names::*;
 pub use stmts::*;
 
+#[cfg(feature = "serde")]
 pub(crate) fn is_false(b: &bool) -> bool {
     !*b
 }
 
-/// Arena-allocated Vec. Thin newtype over bumpalo::collections::Vec that implements Serialize and Debug.
+/// Arena-allocated Vec. Thin newtype over bumpalo::collections::Vec that implements Debug,
+/// and Serialize with the `serde` feature.
 pub struct ArenaVec<'arena, T>(bumpalo::collections::Vec<'arena, T>);
 
 impl<'arena, T> ArenaVec<'arena, T> {
@@ -62,6 +64,7 @@ impl<'arena, T> std::ops::DerefMut for ArenaVec<'arena, T> {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'arena, T: serde::Serialize> serde::Serialize for ArenaVec<'arena, T> {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         self.0.as_slice().serialize(s)
@@ -73,3 +76,55 @@ impl<'arena, T: std::fmt::Debug> std::fmt::Debug for ArenaVec<'arena, T> {
         self.0.as_slice().fmt(f)
     }
 }
+
+macro_rules! impl_spanned {
+    ($($ty:ident $(<$($lt:lifetime),+>)?),+ $(,)?) => {
+        $(
+            impl$(<$($lt),+>)? crate::Spanned for $ty$(<$($lt),+>)? {
+                #[inline]
+                fn span(&self) -> crate::Span {
+                    self.span
+                }
+            }
+        )+
+    };
+}
+
+impl_spanned!(
+    Program<'arena, 'src>,
+    Stmt<'arena, 'src>,
+    Expr<'arena, 'src>,
+    TypeHint<'arena, 'src>,
+    Param<'arena, 'src>,
+    Arg<'arena, 'src>,
+    Attribute<'arena, 'src>,
+    Comment<'src>,
+    ClassMember<'arena, 'src>,
+    EnumMember<'arena, 'src>,
+    PropertyHook<'arena, 'src>,
+    TraitAdaptation<'arena, 'src>,
+    ArrayElement<'arena, 'src>,
+    ClosureUseVar<'src>,
+    MatchArm<'arena, 'src>,
+    ElseIfBranch<'arena, 'src>,
+    SwitchCase<'arena, 'src>,
+    CatchClause<'arena, 'src>,
+    CatchVar<'src>,
+    UseItem<'arena, 'src>,
+    ConstItem<'arena, 'src>,
+    StaticVar<'arena, 'src>,
+);
+
+impl crate::Spanned for Name<'_, '_> {
+    #[inline]
+    fn span(&self) -> crate::Span {
+        Name::span(self)
+    }
+}
+
+impl crate::Spanned for ClassRef<'_, '_> {
+    #[inline]
+    fn span(&self) -> crate::Span {
+        ClassRef::span(self)
+    }
+}
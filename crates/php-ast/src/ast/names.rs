@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::Span;
@@ -80,6 +81,7 @@ impl<'src> std::fmt::Display for Ident<'src> {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'src> serde::Serialize for Ident<'src> {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         if self.0.is_empty() {
@@ -174,7 +176,8 @@ impl<'arena, 'src> Name<'arena, 'src> {
         }
     }
 
-    /// Joins all parts with `\` and prepends `\` if fully qualified.
+    /// Joins all parts with `\` and prepends `\` if fully qualified or
+    /// `namespace\` if relative, giving the name as PHP spells it.
     /// Returns `Cow::Borrowed` for simple names (zero allocation).
     /// Returns an empty `Cow::Borrowed("")` for `Name::Error`.
     #[inline]
@@ -183,10 +186,10 @@ impl<'arena, 'src> Name<'arena, 'src> {
             Self::Simple { value, .. } => Cow::Borrowed(value),
             Self::Complex { parts, kind, .. } => {
                 let joined = parts.join("\\");
-                if *kind == NameKind::FullyQualified {
-                    Cow::Owned(format!("\\{}", joined))
-                } else {
-                    Cow::Owned(joined)
+                match kind {
+                    NameKind::FullyQualified => Cow::Owned(format!("\\{}", joined)),
+                    NameKind::Relative => Cow::Owned(format!("namespace\\{}", joined)),
+                    _ => Cow::Owned(joined),
                 }
             }
             Self::Error { .. } => Cow::Borrowed(""),
@@ -244,6 +247,7 @@ impl<'arena, 'src> std::fmt::Debug for Name<'arena, 'src> {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'arena, 'src> serde::Serialize for Name<'arena, 'src> {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         use serde::ser::SerializeStruct;
@@ -270,7 +274,8 @@ impl<'arena, 'src> serde::Serialize for Name<'arena, 'src> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum NameKind {
     /// A bare identifier with no namespace separator: `Foo`, `strlen`.
     Unqualified,
@@ -287,7 +292,9 @@ pub enum NameKind {
 /// PHP built-in type keyword — zero-cost alternative to `Name::Simple` for the
 /// 20 reserved type names. One byte instead of a `Cow<str>` + `Span` in the AST.
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum BuiltinType {
     /// `int` — integer scalar type.
     Int,
@@ -358,9 +365,18 @@ impl BuiltinType {
             Self::False => "false",
         }
     }
+
+    /// `true` for `self`, `parent`, and `static`, which stand for a class
+    /// relative to the one the type is written in rather than for a kind of
+    /// value.
+    #[inline]
+    pub fn is_relative_class(self) -> bool {
+        matches!(self, Self::Self_ | Self::Parent_ | Self::Static)
+    }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TypeHint<'arena, 'src> {
     pub kind: TypeHintKind<'arena, 'src>,
     pub span: Span,
@@ -368,12 +384,16 @@ pub struct TypeHint<'arena, 'src> {
 
 /// A PHP type hint.
 ///
+/// A DNF type such as `(A&B)|null` is a `Union` with an `Intersection`
+/// member; the span of the intersection covers its parentheses.
+///
 /// `Keyword` is the fast path for the 20 built-in type names (`int`, `string`,
 /// `bool`, `self`, `array`, etc.). It stores only a 1-byte discriminant and a
 /// `Span`, avoiding the `Cow<str>` that `Named(Name::Simple)` would require.
 ///
 /// Serialises identically to `Named` so all existing snapshots remain unchanged.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum TypeHintKind<'arena, 'src> {
     /// A user-defined or qualified class name: `Foo`, `\Ns\Bar`.
     Named(Name<'arena, 'src>),
@@ -387,6 +407,27 @@ pub enum TypeHintKind<'arena, 'src> {
     Intersection(ArenaVec<'arena, TypeHint<'arena, 'src>>),
 }
 
+impl TypeHint<'_, '_> {
+    /// `true` if the type is exactly `keyword`, as in `: void`.
+    pub fn is_keyword(&self, keyword: BuiltinType) -> bool {
+        matches!(self.kind, TypeHintKind::Keyword(kw, _) if kw == keyword)
+    }
+
+    /// `true` if `null` is a value of the type: `?T`, a union with `null`,
+    /// `null` itself, or `mixed`.
+    pub fn admits_null(&self) -> bool {
+        match &self.kind {
+            TypeHintKind::Nullable(_) => true,
+            TypeHintKind::Keyword(builtin, _) => {
+                matches!(builtin, BuiltinType::Mixed | BuiltinType::Null)
+            }
+            TypeHintKind::Union(types) => types.iter().any(TypeHint::admits_null),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
 impl<'arena, 'src> serde::Serialize for TypeHintKind<'arena, 'src> {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         match self {
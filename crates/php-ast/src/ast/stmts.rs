@@ -1,29 +1,34 @@
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::Span;
 
 use super::{
-    ArenaVec, Attribute, ClassDecl, Comment, EnumDecl, Expr, FunctionDecl, Ident, InterfaceDecl,
-    Name, TraitDecl,
+    ArenaVec, Attribute, ClassDecl, ClassRef, Comment, EnumDecl, Expr, FunctionDecl, Ident,
+    InterfaceDecl, Name, TraitDecl,
 };
 
+#[cfg(feature = "serde")]
 fn is_false(b: &bool) -> bool {
     !b
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Stmt<'arena, 'src> {
     pub kind: StmtKind<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
 pub enum StmtKind<'arena, 'src> {
     /// Expression statement (e.g. `foo();`)
     Expression(&'arena Expr<'arena, 'src>),
 
-    /// Echo statement: `echo expr1, expr2;`
-    Echo(ArenaVec<'arena, Expr<'arena, 'src>>),
+    /// Echo statement: `echo expr1, expr2;` or `<?= expr1, expr2 ?>`
+    Echo(EchoStmt<'arena, 'src>),
 
     /// Return statement: `return expr;`
     Return(Option<&'arena Expr<'arena, 'src>>),
@@ -103,107 +108,194 @@ pub enum StmtKind<'arena, 'src> {
     /// Static variable declaration: `static $x = 1;`
     StaticVar(ArenaVec<'arena, StaticVar<'arena, 'src>>),
 
-    /// __halt_compiler(); with remaining data
-    HaltCompiler(&'src str),
+    /// `__halt_compiler();` and the raw data after its `;` or `?>`, to the
+    /// end of the file; see also [`Program::halt_offset`].
+    HaltCompiler(HaltCompilerData<'src>),
 
     /// Nop (empty statement `;`)
     Nop,
 
     /// Inline HTML
-    InlineHtml(&'src str),
+    InlineHtml(InlineHtml<'src>),
 
     /// Error placeholder — parser always produces a tree
     Error,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct EchoStmt<'arena, 'src> {
+    pub exprs: ArenaVec<'arena, Expr<'arena, 'src>>,
+    /// The `<?=` of a short echo tag; `None` for an `echo` statement.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub open_tag: Option<Span>,
+    /// The `?>` ending a short echo tag, right after its expressions or
+    /// their `;`; `None` when other statements or the end of the file
+    /// follow instead.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub close_tag: Option<Span>,
+}
+
+impl EchoStmt<'_, '_> {
+    /// Whether this is `<?= … ?>` rather than an `echo` statement.
+    pub fn is_short_echo(&self) -> bool {
+        self.open_tag.is_some()
+    }
+}
+
+/// The raw data after `__halt_compiler();`, which PHP does not parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HaltCompilerData<'src> {
+    /// The data, borrowed from the source. Not serialized, so trees do not
+    /// repeat an embedded payload; `span` locates it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub text: &'src str,
+    pub span: Span,
+}
+
+/// Text outside the `<?php … ?>` blocks, which PHP outputs as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InlineHtml<'src> {
+    /// The source text, from the `?>` before it (or the start of the file)
+    /// to the next open tag (or the end of the file).
+    pub text: &'src str,
+    /// Whether `text` starts with the line break — `\n`, `\r\n`, or `\r` —
+    /// that the `?>` before it swallows, so PHP does not output it.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub newline_swallowed: bool,
+}
+
+impl<'src> InlineHtml<'src> {
+    /// The text PHP outputs: `text` without the swallowed line break.
+    pub fn output(&self) -> &'src str {
+        if !self.newline_swallowed {
+            return self.text;
+        }
+        let len = if self.text.starts_with("\r\n") { 2 } else { 1 };
+        &self.text[len..]
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct IfStmt<'arena, 'src> {
     pub condition: Expr<'arena, 'src>,
     pub then_branch: &'arena Stmt<'arena, 'src>,
     pub elseif_branches: ArenaVec<'arena, ElseIfBranch<'arena, 'src>>,
     pub else_branch: Option<&'arena Stmt<'arena, 'src>>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ElseIfBranch<'arena, 'src> {
     pub condition: Expr<'arena, 'src>,
     pub body: Stmt<'arena, 'src>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct WhileStmt<'arena, 'src> {
     pub condition: Expr<'arena, 'src>,
     pub body: &'arena Stmt<'arena, 'src>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ForStmt<'arena, 'src> {
     pub init: ArenaVec<'arena, Expr<'arena, 'src>>,
     pub condition: ArenaVec<'arena, Expr<'arena, 'src>>,
     pub update: ArenaVec<'arena, Expr<'arena, 'src>>,
+    /// The three clauses between the parentheses, from the first expression
+    /// to the last. An omitted clause, as in `for (;;)`, has an empty span
+    /// where its `;` or `)` starts.
+    pub init_span: Span,
+    pub condition_span: Span,
+    pub update_span: Span,
     pub body: &'arena Stmt<'arena, 'src>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ForeachStmt<'arena, 'src> {
     pub expr: Expr<'arena, 'src>,
     pub key: Option<Expr<'arena, 'src>>,
     pub value: Expr<'arena, 'src>,
     pub body: &'arena Stmt<'arena, 'src>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DoWhileStmt<'arena, 'src> {
     pub body: &'arena Stmt<'arena, 'src>,
     pub condition: Expr<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SwitchStmt<'arena, 'src> {
     pub expr: Expr<'arena, 'src>,
     pub cases: ArenaVec<'arena, SwitchCase<'arena, 'src>>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SwitchCase<'arena, 'src> {
     pub value: Option<Expr<'arena, 'src>>,
     pub body: ArenaVec<'arena, Stmt<'arena, 'src>>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TryCatchStmt<'arena, 'src> {
     pub body: ArenaVec<'arena, Stmt<'arena, 'src>>,
     pub catches: ArenaVec<'arena, CatchClause<'arena, 'src>>,
     pub finally: Option<ArenaVec<'arena, Stmt<'arena, 'src>>>,
 }
 
-#[derive(Debug, Serialize)]
+/// `catch (A | B $e) { … }`. The types are listed in source order, one per
+/// `|`-separated alternative; the variable is optional since PHP 8.0.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CatchClause<'arena, 'src> {
-    pub types: ArenaVec<'arena, Name<'arena, 'src>>,
-    pub var: Option<&'src str>,
+    pub types: ArenaVec<'arena, ClassRef<'arena, 'src>>,
+    pub var: Option<CatchVar<'src>>,
     pub body: ArenaVec<'arena, Stmt<'arena, 'src>>,
     pub span: Span,
 }
 
-#[derive(Debug, Serialize)]
+/// The variable bound by a `catch` clause.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CatchVar<'src> {
+    /// The name without the leading `$`.
+    pub name: &'src str,
+    /// The variable as written, `$` included.
+    pub span: Span,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct NamespaceDecl<'arena, 'src> {
     pub name: Option<Name<'arena, 'src>>,
     pub body: NamespaceBody<'arena, 'src>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum NamespaceBody<'arena, 'src> {
     /// `namespace Foo { … }` — braced form; the statements are scoped to this namespace.
     Braced(ArenaVec<'arena, Stmt<'arena, 'src>>),
@@ -211,21 +303,24 @@ pub enum NamespaceBody<'arena, 'src> {
     Simple,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DeclareStmt<'arena, 'src> {
     pub directives: ArenaVec<'arena, (&'src str, Expr<'arena, 'src>)>,
     pub body: Option<&'arena Stmt<'arena, 'src>>,
-    #[serde(default, skip_serializing_if = "is_false")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub uses_alternative: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UseDecl<'arena, 'src> {
     pub kind: UseKind,
     pub uses: ArenaVec<'arena, UseItem<'arena, 'src>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum UseKind {
     /// `use Foo\Bar` — imports a class, interface, trait, or enum.
     Normal,
@@ -235,26 +330,33 @@ pub enum UseKind {
     Const,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UseItem<'arena, 'src> {
     pub name: Name<'arena, 'src>,
     pub alias: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub kind: Option<UseKind>,
     pub span: Span,
+    /// The `,` after the item; on the last item of a group use, a
+    /// trailing comma.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ConstItem<'arena, 'src> {
     pub name: Ident<'src>,
     pub value: Expr<'arena, 'src>,
     pub attributes: ArenaVec<'arena, Attribute<'arena, 'src>>,
     pub span: Span,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub doc_comment: Option<Comment<'src>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct StaticVar<'arena, 'src> {
     pub name: Ident<'src>,
     pub default: Option<Expr<'arena, 'src>>,
@@ -0,0 +1,2430 @@
+//! The tree in the shape of the `ext-ast` PHP extension.
+//!
+//! [`from_program`] converts a [`Program`] to the nodes `ast\parse_code()`
+//! returns: each [`Node`] has an `AST_*` [`Kind`], integer [`flags`], a line
+//! number, and children keyed as ext-ast keys them (`expr`, `args`, …) or,
+//! for list kinds, by position. Leaves are plain values: names, strings,
+//! and numbers, with `null` for absent children. Tools written against
+//! ext-ast can then port their matching logic nearly one to one.
+//!
+//! The conversion follows ext-ast's normalisations: `>` and `>=`, `&&`,
+//! `||`, and `??` are `AST_BINARY_OP`s, unary `+`, `-`, and `@` are
+//! `AST_UNARY_OP`s, `isset($a, $b)` is a `BINARY_BOOL_AND` of `AST_ISSET`s,
+//! and `echo`, `global`, `static`, and `unset` lists are nested
+//! `AST_STMT_LIST`s. Line numbers are the line each node starts on.
+//!
+//! [`Node`]'s `Display` is `ast\dump()`'s format:
+//!
+//! ```
+//! use php_ast::ext_ast::{self, flags, Kind};
+//! use php_ast::source_map::SourceMap;
+//! use php_ast::*;
+//!
+//! // `<?php f(1);`
+//! let src = "<?php f(1);";
+//! let arena = bumpalo::Bump::new();
+//! let name = arena.alloc(Expr {
+//!     kind: ExprKind::Name(Name::Simple { value: "f", span: Span::new(6, 7) }),
+//!     span: Span::new(6, 7),
+//! });
+//! let mut args = ArenaVec::new_in(&arena);
+//! args.push(Arg {
+//!     name: None,
+//!     colon: None,
+//!     value: Expr { kind: ExprKind::Int(1), span: Span::new(8, 9) },
+//!     unpack: false,
+//!     by_ref: false,
+//!     span: Span::new(8, 9),
+//!     comma: None,
+//! });
+//! let call = arena.alloc(Expr {
+//!     kind: ExprKind::FunctionCall(FunctionCallExpr { name, args }),
+//!     span: Span::new(6, 10),
+//! });
+//! let mut stmts = ArenaVec::new_in(&arena);
+//! stmts.push(Stmt { kind: StmtKind::Expression(call), span: Span::new(6, 11) });
+//! let program = Program { stmts, span: Span::new(0, 11), ends_in_html: false, close_tag: None };
+//!
+//! let ast = ext_ast::from_program(&program, &SourceMap::new(src));
+//! assert_eq!(ast.kind, Kind::StmtList);
+//! assert_eq!(ast.to_string(), "\
+//! AST_STMT_LIST
+//!     0: AST_CALL
+//!         expr: AST_NAME
+//!             flags: NAME_NOT_FQ (1)
+//!             name: \"f\"
+//!         args: AST_ARG_LIST
+//!             0: 1");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::ast::*;
+use crate::source_map::SourceMap;
+use crate::Span;
+
+macro_rules! kinds {
+    ($($variant:ident => $name:literal,)+) => {
+        /// An ext-ast node kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Kind {
+            $($variant,)+
+        }
+
+        impl Kind {
+            /// Every kind, in declaration order.
+            pub const ALL: &'static [Kind] = &[$(Kind::$variant,)+];
+
+            /// The ext-ast constant name, e.g. `"AST_CALL"`.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Kind::$variant => $name,)+
+                }
+            }
+        }
+    };
+}
+
+kinds! {
+    // Lists, whose children are keyed by position.
+    ArgList => "AST_ARG_LIST",
+    Array => "AST_ARRAY",
+    EncapsList => "AST_ENCAPS_LIST",
+    ExprList => "AST_EXPR_LIST",
+    StmtList => "AST_STMT_LIST",
+    If => "AST_IF",
+    SwitchList => "AST_SWITCH_LIST",
+    CatchList => "AST_CATCH_LIST",
+    ParamList => "AST_PARAM_LIST",
+    ClosureUses => "AST_CLOSURE_USES",
+    PropDecl => "AST_PROP_DECL",
+    ConstDecl => "AST_CONST_DECL",
+    ClassConstDecl => "AST_CLASS_CONST_DECL",
+    NameList => "AST_NAME_LIST",
+    TraitAdaptations => "AST_TRAIT_ADAPTATIONS",
+    Use => "AST_USE",
+    TypeUnion => "AST_TYPE_UNION",
+    TypeIntersection => "AST_TYPE_INTERSECTION",
+    AttributeList => "AST_ATTRIBUTE_LIST",
+    AttributeGroup => "AST_ATTRIBUTE_GROUP",
+    MatchArmList => "AST_MATCH_ARM_LIST",
+    // Declarations.
+    FuncDecl => "AST_FUNC_DECL",
+    Closure => "AST_CLOSURE",
+    Method => "AST_METHOD",
+    ArrowFunc => "AST_ARROW_FUNC",
+    Class => "AST_CLASS",
+    PropertyHook => "AST_PROPERTY_HOOK",
+    // Everything else.
+    Name => "AST_NAME",
+    ClosureVar => "AST_CLOSURE_VAR",
+    NullableType => "AST_NULLABLE_TYPE",
+    MagicConst => "AST_MAGIC_CONST",
+    Type => "AST_TYPE",
+    CallableConvert => "AST_CALLABLE_CONVERT",
+    Var => "AST_VAR",
+    Const => "AST_CONST",
+    Unpack => "AST_UNPACK",
+    Cast => "AST_CAST",
+    Empty => "AST_EMPTY",
+    Isset => "AST_ISSET",
+    ShellExec => "AST_SHELL_EXEC",
+    Clone => "AST_CLONE",
+    Exit => "AST_EXIT",
+    Print => "AST_PRINT",
+    IncludeOrEval => "AST_INCLUDE_OR_EVAL",
+    UnaryOp => "AST_UNARY_OP",
+    PreInc => "AST_PRE_INC",
+    PreDec => "AST_PRE_DEC",
+    PostInc => "AST_POST_INC",
+    PostDec => "AST_POST_DEC",
+    YieldFrom => "AST_YIELD_FROM",
+    ClassName => "AST_CLASS_NAME",
+    Global => "AST_GLOBAL",
+    Unset => "AST_UNSET",
+    Return => "AST_RETURN",
+    Label => "AST_LABEL",
+    Ref => "AST_REF",
+    HaltCompiler => "AST_HALT_COMPILER",
+    Echo => "AST_ECHO",
+    Throw => "AST_THROW",
+    Goto => "AST_GOTO",
+    Break => "AST_BREAK",
+    Continue => "AST_CONTINUE",
+    PropertyHookShortBody => "AST_PROPERTY_HOOK_SHORT_BODY",
+    Dim => "AST_DIM",
+    Prop => "AST_PROP",
+    NullsafeProp => "AST_NULLSAFE_PROP",
+    StaticProp => "AST_STATIC_PROP",
+    Call => "AST_CALL",
+    ClassConst => "AST_CLASS_CONST",
+    Assign => "AST_ASSIGN",
+    AssignRef => "AST_ASSIGN_REF",
+    AssignOp => "AST_ASSIGN_OP",
+    BinaryOp => "AST_BINARY_OP",
+    Pipe => "AST_PIPE",
+    ArrayElem => "AST_ARRAY_ELEM",
+    New => "AST_NEW",
+    Instanceof => "AST_INSTANCEOF",
+    Yield => "AST_YIELD",
+    Static => "AST_STATIC",
+    While => "AST_WHILE",
+    DoWhile => "AST_DO_WHILE",
+    IfElem => "AST_IF_ELEM",
+    Switch => "AST_SWITCH",
+    SwitchCase => "AST_SWITCH_CASE",
+    Declare => "AST_DECLARE",
+    PropElem => "AST_PROP_ELEM",
+    PropGroup => "AST_PROP_GROUP",
+    ConstElem => "AST_CONST_ELEM",
+    UseTrait => "AST_USE_TRAIT",
+    TraitPrecedence => "AST_TRAIT_PRECEDENCE",
+    MethodReference => "AST_METHOD_REFERENCE",
+    Namespace => "AST_NAMESPACE",
+    UseElem => "AST_USE_ELEM",
+    TraitAlias => "AST_TRAIT_ALIAS",
+    ClassConstGroup => "AST_CLASS_CONST_GROUP",
+    Attribute => "AST_ATTRIBUTE",
+    Match => "AST_MATCH",
+    MatchArm => "AST_MATCH_ARM",
+    NamedArg => "AST_NAMED_ARG",
+    MethodCall => "AST_METHOD_CALL",
+    NullsafeMethodCall => "AST_NULLSAFE_METHOD_CALL",
+    StaticCall => "AST_STATIC_CALL",
+    Conditional => "AST_CONDITIONAL",
+    Try => "AST_TRY",
+    Catch => "AST_CATCH",
+    For => "AST_FOR",
+    Foreach => "AST_FOREACH",
+    EnumCase => "AST_ENUM_CASE",
+    Param => "AST_PARAM",
+}
+
+impl Kind {
+    /// Whether ext-ast sets `flags` on this kind; `ast\dump()` prints them
+    /// for these kinds even when they are 0.
+    pub fn uses_flags(self) -> bool {
+        flag_names(self).is_some()
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The `ast\flags\*` constants.
+///
+/// Values are ext-ast's, which it takes from the engine. The exception is
+/// `MAGIC_*`: ext-ast uses the running PHP's token numbers, which differ
+/// between builds, so these are fixed values of our own. Compare against
+/// the constants rather than raw numbers.
+pub mod flags {
+    pub const NAME_FQ: u32 = 0;
+    pub const NAME_NOT_FQ: u32 = 1;
+    pub const NAME_RELATIVE: u32 = 2;
+
+    pub const MODIFIER_PUBLIC: u32 = 1;
+    pub const MODIFIER_PROTECTED: u32 = 2;
+    pub const MODIFIER_PRIVATE: u32 = 4;
+    pub const MODIFIER_STATIC: u32 = 16;
+    pub const MODIFIER_FINAL: u32 = 32;
+    pub const MODIFIER_ABSTRACT: u32 = 64;
+    pub const MODIFIER_READONLY: u32 = 128;
+    pub const MODIFIER_PUBLIC_SET: u32 = 1 << 10;
+    pub const MODIFIER_PROTECTED_SET: u32 = 1 << 11;
+    pub const MODIFIER_PRIVATE_SET: u32 = 1 << 12;
+
+    pub const FUNC_RETURNS_REF: u32 = 1 << 12;
+    pub const FUNC_GENERATOR: u32 = 1 << 24;
+
+    pub const PARAM_REF: u32 = 8;
+    pub const PARAM_VARIADIC: u32 = 16;
+    pub const PARAM_MODIFIER_PUBLIC: u32 = MODIFIER_PUBLIC;
+    pub const PARAM_MODIFIER_PROTECTED: u32 = MODIFIER_PROTECTED;
+    pub const PARAM_MODIFIER_PRIVATE: u32 = MODIFIER_PRIVATE;
+    pub const PARAM_MODIFIER_READONLY: u32 = MODIFIER_READONLY;
+
+    pub const CLASS_INTERFACE: u32 = 1;
+    pub const CLASS_TRAIT: u32 = 2;
+    pub const CLASS_ANONYMOUS: u32 = 4;
+    pub const CLASS_FINAL: u32 = 32;
+    pub const CLASS_ABSTRACT: u32 = 64;
+    pub const CLASS_READONLY: u32 = 1 << 16;
+    pub const CLASS_ENUM: u32 = 1 << 28;
+
+    pub const TYPE_NULL: u32 = 1;
+    pub const TYPE_FALSE: u32 = 2;
+    pub const TYPE_TRUE: u32 = 3;
+    pub const TYPE_LONG: u32 = 4;
+    pub const TYPE_DOUBLE: u32 = 5;
+    pub const TYPE_STRING: u32 = 6;
+    pub const TYPE_ARRAY: u32 = 7;
+    pub const TYPE_OBJECT: u32 = 8;
+    pub const TYPE_CALLABLE: u32 = 12;
+    pub const TYPE_ITERABLE: u32 = 13;
+    pub const TYPE_VOID: u32 = 14;
+    pub const TYPE_STATIC: u32 = 15;
+    pub const TYPE_MIXED: u32 = 16;
+    pub const TYPE_NEVER: u32 = 17;
+    pub const TYPE_BOOL: u32 = 18;
+
+    pub const UNARY_BITWISE_NOT: u32 = 13;
+    pub const UNARY_BOOL_NOT: u32 = 14;
+    pub const UNARY_SILENCE: u32 = 260;
+    pub const UNARY_PLUS: u32 = 261;
+    pub const UNARY_MINUS: u32 = 262;
+
+    pub const BINARY_ADD: u32 = 1;
+    pub const BINARY_SUB: u32 = 2;
+    pub const BINARY_MUL: u32 = 3;
+    pub const BINARY_DIV: u32 = 4;
+    pub const BINARY_MOD: u32 = 5;
+    pub const BINARY_SHIFT_LEFT: u32 = 6;
+    pub const BINARY_SHIFT_RIGHT: u32 = 7;
+    pub const BINARY_CONCAT: u32 = 8;
+    pub const BINARY_BITWISE_OR: u32 = 9;
+    pub const BINARY_BITWISE_AND: u32 = 10;
+    pub const BINARY_BITWISE_XOR: u32 = 11;
+    pub const BINARY_POW: u32 = 12;
+    pub const BINARY_BOOL_XOR: u32 = 15;
+    pub const BINARY_IS_IDENTICAL: u32 = 16;
+    pub const BINARY_IS_NOT_IDENTICAL: u32 = 17;
+    pub const BINARY_IS_EQUAL: u32 = 18;
+    pub const BINARY_IS_NOT_EQUAL: u32 = 19;
+    pub const BINARY_IS_SMALLER: u32 = 20;
+    pub const BINARY_IS_SMALLER_OR_EQUAL: u32 = 21;
+    pub const BINARY_SPACESHIP: u32 = 170;
+    pub const BINARY_IS_GREATER: u32 = 256;
+    pub const BINARY_IS_GREATER_OR_EQUAL: u32 = 257;
+    pub const BINARY_BOOL_OR: u32 = 258;
+    pub const BINARY_BOOL_AND: u32 = 259;
+    pub const BINARY_COALESCE: u32 = 260;
+
+    pub const MAGIC_LINE: u32 = 1;
+    pub const MAGIC_FILE: u32 = 2;
+    pub const MAGIC_DIR: u32 = 3;
+    pub const MAGIC_NAMESPACE: u32 = 4;
+    pub const MAGIC_FUNCTION: u32 = 5;
+    pub const MAGIC_METHOD: u32 = 6;
+    pub const MAGIC_CLASS: u32 = 7;
+    pub const MAGIC_TRAIT: u32 = 8;
+    pub const MAGIC_PROPERTY: u32 = 9;
+
+    pub const USE_NORMAL: u32 = 1;
+    pub const USE_FUNCTION: u32 = 2;
+    pub const USE_CONST: u32 = 4;
+
+    pub const EXEC_EVAL: u32 = 1;
+    pub const EXEC_INCLUDE: u32 = 2;
+    pub const EXEC_INCLUDE_ONCE: u32 = 4;
+    pub const EXEC_REQUIRE: u32 = 8;
+    pub const EXEC_REQUIRE_ONCE: u32 = 16;
+
+    pub const ARRAY_ELEM_REF: u32 = 1;
+    pub const CLOSURE_USE_REF: u32 = 1;
+
+    pub const ARRAY_SYNTAX_LIST: u32 = 1;
+    pub const ARRAY_SYNTAX_LONG: u32 = 2;
+    pub const ARRAY_SYNTAX_SHORT: u32 = 3;
+
+    pub const PARENTHESIZED_CONDITIONAL: u32 = 1;
+}
+
+/// One ext-ast node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<'a> {
+    pub kind: Kind,
+    pub flags: u32,
+    /// 1-based line the node starts on.
+    pub lineno: u32,
+    /// 1-based line the node ends on, for declarations.
+    pub end_lineno: Option<u32>,
+    pub children: Children<'a>,
+}
+
+/// The children of a [`Node`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Children<'a> {
+    /// The children of a list kind, keyed by position.
+    List(Vec<Value<'a>>),
+    /// The children of every other kind, keyed by name in ext-ast's order.
+    Named(Vec<(&'static str, Value<'a>)>),
+}
+
+/// A child of a [`Node`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    Null,
+    Int(i64),
+    Float(f64),
+    String(Cow<'a, str>),
+    Node(Box<Node<'a>>),
+}
+
+impl<'a> Node<'a> {
+    /// The child named `name`, for kinds that are not lists.
+    pub fn child(&self, name: &str) -> Option<&Value<'a>> {
+        match &self.children {
+            Children::Named(children) => children
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value),
+            Children::List(_) => None,
+        }
+    }
+
+    /// `ast\dump()`'s format, with `@ line` after each kind if `linenos`
+    /// is set (`AST_DUMP_LINENOS`). `Display` is `dump(false)`.
+    pub fn dump(&self, linenos: bool) -> String {
+        let mut out = String::new();
+        self.write_dump(&mut out, linenos, 0);
+        out
+    }
+
+    fn write_dump(&self, out: &mut String, linenos: bool, depth: usize) {
+        out.push_str(self.kind.as_str());
+        if linenos {
+            out.push_str(&format!(" @ {}", self.lineno));
+            if let Some(end) = self.end_lineno {
+                out.push_str(&format!("-{end}"));
+            }
+        }
+        let indent = "    ".repeat(depth + 1);
+        if self.kind.uses_flags() || self.flags != 0 {
+            out.push_str(&format!(
+                "\n{indent}flags: {}",
+                format_flags(self.kind, self.flags)
+            ));
+        }
+        let mut child = |key: &dyn fmt::Display, value: &Value<'a>| {
+            out.push_str(&format!("\n{indent}{key}: "));
+            match value {
+                Value::Null => out.push_str("null"),
+                Value::Int(n) => out.push_str(&n.to_string()),
+                Value::Float(n) => out.push_str(&n.to_string()),
+                Value::String(s) => {
+                    out.push('"');
+                    out.push_str(s);
+                    out.push('"');
+                }
+                Value::Node(node) => node.write_dump(out, linenos, depth + 1),
+            }
+        };
+        match &self.children {
+            Children::List(items) => {
+                for (i, value) in items.iter().enumerate() {
+                    child(&i, value);
+                }
+            }
+            Children::Named(children) => {
+                for (key, value) in children {
+                    child(key, value);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.dump(false))
+    }
+}
+
+impl<'a> From<Node<'a>> for Value<'a> {
+    fn from(node: Node<'a>) -> Self {
+        Value::Node(Box::new(node))
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(s: &'a str) -> Self {
+        Value::String(Cow::Borrowed(s))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Node<'_> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut st = s.serialize_struct("Node", 5)?;
+        st.serialize_field("kind", self.kind.as_str())?;
+        st.serialize_field("flags", &self.flags)?;
+        st.serialize_field("lineno", &self.lineno)?;
+        if let Some(end) = self.end_lineno {
+            st.serialize_field("endLineno", &end)?;
+        }
+        st.serialize_field("children", &self.children)?;
+        st.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Children<'_> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        match self {
+            Children::List(items) => items.serialize(s),
+            Children::Named(children) => {
+                let mut map = s.serialize_map(Some(children.len()))?;
+                for (key, value) in children {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value<'_> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => s.serialize_none(),
+            Value::Int(n) => s.serialize_i64(*n),
+            Value::Float(n) => s.serialize_f64(*n),
+            Value::String(v) => s.serialize_str(v),
+            Value::Node(node) => node.serialize(s),
+        }
+    }
+}
+
+/// The names of `kind`'s flags, and whether they combine as a bit set.
+fn flag_names(kind: Kind) -> Option<(&'static [(u32, &'static str)], bool)> {
+    use flags::*;
+    const NAME: &[(u32, &str)] = &[
+        (NAME_FQ, "NAME_FQ"),
+        (NAME_NOT_FQ, "NAME_NOT_FQ"),
+        (NAME_RELATIVE, "NAME_RELATIVE"),
+    ];
+    const MODIFIERS: &[(u32, &str)] = &[
+        (MODIFIER_PUBLIC, "MODIFIER_PUBLIC"),
+        (MODIFIER_PROTECTED, "MODIFIER_PROTECTED"),
+        (MODIFIER_PRIVATE, "MODIFIER_PRIVATE"),
+        (MODIFIER_STATIC, "MODIFIER_STATIC"),
+        (MODIFIER_FINAL, "MODIFIER_FINAL"),
+        (MODIFIER_ABSTRACT, "MODIFIER_ABSTRACT"),
+        (MODIFIER_READONLY, "MODIFIER_READONLY"),
+        (MODIFIER_PUBLIC_SET, "MODIFIER_PUBLIC_SET"),
+        (MODIFIER_PROTECTED_SET, "MODIFIER_PROTECTED_SET"),
+        (MODIFIER_PRIVATE_SET, "MODIFIER_PRIVATE_SET"),
+    ];
+    const FUNC: &[(u32, &str)] = &[
+        (MODIFIER_PUBLIC, "MODIFIER_PUBLIC"),
+        (MODIFIER_PROTECTED, "MODIFIER_PROTECTED"),
+        (MODIFIER_PRIVATE, "MODIFIER_PRIVATE"),
+        (MODIFIER_STATIC, "MODIFIER_STATIC"),
+        (MODIFIER_FINAL, "MODIFIER_FINAL"),
+        (MODIFIER_ABSTRACT, "MODIFIER_ABSTRACT"),
+        (FUNC_RETURNS_REF, "FUNC_RETURNS_REF"),
+        (FUNC_GENERATOR, "FUNC_GENERATOR"),
+    ];
+    const PARAM: &[(u32, &str)] = &[
+        (PARAM_MODIFIER_PUBLIC, "PARAM_MODIFIER_PUBLIC"),
+        (PARAM_MODIFIER_PROTECTED, "PARAM_MODIFIER_PROTECTED"),
+        (PARAM_MODIFIER_PRIVATE, "PARAM_MODIFIER_PRIVATE"),
+        (PARAM_REF, "PARAM_REF"),
+        (PARAM_VARIADIC, "PARAM_VARIADIC"),
+        (PARAM_MODIFIER_READONLY, "PARAM_MODIFIER_READONLY"),
+    ];
+    const CLASS: &[(u32, &str)] = &[
+        (CLASS_INTERFACE, "CLASS_INTERFACE"),
+        (CLASS_TRAIT, "CLASS_TRAIT"),
+        (CLASS_ANONYMOUS, "CLASS_ANONYMOUS"),
+        (CLASS_FINAL, "CLASS_FINAL"),
+        (CLASS_ABSTRACT, "CLASS_ABSTRACT"),
+        (CLASS_READONLY, "CLASS_READONLY"),
+        (CLASS_ENUM, "CLASS_ENUM"),
+    ];
+    const TYPE: &[(u32, &str)] = &[
+        (TYPE_NULL, "TYPE_NULL"),
+        (TYPE_FALSE, "TYPE_FALSE"),
+        (TYPE_TRUE, "TYPE_TRUE"),
+        (TYPE_LONG, "TYPE_LONG"),
+        (TYPE_DOUBLE, "TYPE_DOUBLE"),
+        (TYPE_STRING, "TYPE_STRING"),
+        (TYPE_ARRAY, "TYPE_ARRAY"),
+        (TYPE_OBJECT, "TYPE_OBJECT"),
+        (TYPE_CALLABLE, "TYPE_CALLABLE"),
+        (TYPE_ITERABLE, "TYPE_ITERABLE"),
+        (TYPE_VOID, "TYPE_VOID"),
+        (TYPE_STATIC, "TYPE_STATIC"),
+        (TYPE_MIXED, "TYPE_MIXED"),
+        (TYPE_NEVER, "TYPE_NEVER"),
+        (TYPE_BOOL, "TYPE_BOOL"),
+    ];
+    const UNARY: &[(u32, &str)] = &[
+        (UNARY_BITWISE_NOT, "UNARY_BITWISE_NOT"),
+        (UNARY_BOOL_NOT, "UNARY_BOOL_NOT"),
+        (UNARY_SILENCE, "UNARY_SILENCE"),
+        (UNARY_PLUS, "UNARY_PLUS"),
+        (UNARY_MINUS, "UNARY_MINUS"),
+    ];
+    const BINARY: &[(u32, &str)] = &[
+        (BINARY_ADD, "BINARY_ADD"),
+        (BINARY_SUB, "BINARY_SUB"),
+        (BINARY_MUL, "BINARY_MUL"),
+        (BINARY_DIV, "BINARY_DIV"),
+        (BINARY_MOD, "BINARY_MOD"),
+        (BINARY_SHIFT_LEFT, "BINARY_SHIFT_LEFT"),
+        (BINARY_SHIFT_RIGHT, "BINARY_SHIFT_RIGHT"),
+        (BINARY_CONCAT, "BINARY_CONCAT"),
+        (BINARY_BITWISE_OR, "BINARY_BITWISE_OR"),
+        (BINARY_BITWISE_AND, "BINARY_BITWISE_AND"),
+        (BINARY_BITWISE_XOR, "BINARY_BITWISE_XOR"),
+        (BINARY_POW, "BINARY_POW"),
+        (BINARY_BOOL_XOR, "BINARY_BOOL_XOR"),
+        (BINARY_IS_IDENTICAL, "BINARY_IS_IDENTICAL"),
+        (BINARY_IS_NOT_IDENTICAL, "BINARY_IS_NOT_IDENTICAL"),
+        (BINARY_IS_EQUAL, "BINARY_IS_EQUAL"),
+        (BINARY_IS_NOT_EQUAL, "BINARY_IS_NOT_EQUAL"),
+        (BINARY_IS_SMALLER, "BINARY_IS_SMALLER"),
+        (BINARY_IS_SMALLER_OR_EQUAL, "BINARY_IS_SMALLER_OR_EQUAL"),
+        (BINARY_SPACESHIP, "BINARY_SPACESHIP"),
+        (BINARY_IS_GREATER, "BINARY_IS_GREATER"),
+        (BINARY_IS_GREATER_OR_EQUAL, "BINARY_IS_GREATER_OR_EQUAL"),
+        (BINARY_BOOL_OR, "BINARY_BOOL_OR"),
+        (BINARY_BOOL_AND, "BINARY_BOOL_AND"),
+        (BINARY_COALESCE, "BINARY_COALESCE"),
+    ];
+    const MAGIC: &[(u32, &str)] = &[
+        (MAGIC_LINE, "MAGIC_LINE"),
+        (MAGIC_FILE, "MAGIC_FILE"),
+        (MAGIC_DIR, "MAGIC_DIR"),
+        (MAGIC_NAMESPACE, "MAGIC_NAMESPACE"),
+        (MAGIC_FUNCTION, "MAGIC_FUNCTION"),
+        (MAGIC_METHOD, "MAGIC_METHOD"),
+        (MAGIC_CLASS, "MAGIC_CLASS"),
+        (MAGIC_TRAIT, "MAGIC_TRAIT"),
+        (MAGIC_PROPERTY, "MAGIC_PROPERTY"),
+    ];
+    const USE: &[(u32, &str)] = &[
+        (USE_NORMAL, "USE_NORMAL"),
+        (USE_FUNCTION, "USE_FUNCTION"),
+        (USE_CONST, "USE_CONST"),
+    ];
+    const EXEC: &[(u32, &str)] = &[
+        (EXEC_EVAL, "EXEC_EVAL"),
+        (EXEC_INCLUDE, "EXEC_INCLUDE"),
+        (EXEC_INCLUDE_ONCE, "EXEC_INCLUDE_ONCE"),
+        (EXEC_REQUIRE, "EXEC_REQUIRE"),
+        (EXEC_REQUIRE_ONCE, "EXEC_REQUIRE_ONCE"),
+    ];
+    const ARRAY: &[(u32, &str)] = &[
+        (ARRAY_SYNTAX_LIST, "ARRAY_SYNTAX_LIST"),
+        (ARRAY_SYNTAX_LONG, "ARRAY_SYNTAX_LONG"),
+        (ARRAY_SYNTAX_SHORT, "ARRAY_SYNTAX_SHORT"),
+    ];
+    Some(match kind {
+        Kind::Name => (NAME, false),
+        Kind::Type | Kind::Cast => (TYPE, false),
+        Kind::UnaryOp => (UNARY, false),
+        Kind::BinaryOp | Kind::AssignOp => (BINARY, false),
+        Kind::MagicConst => (MAGIC, false),
+        Kind::Use | Kind::UseElem => (USE, false),
+        Kind::IncludeOrEval => (EXEC, false),
+        Kind::Array => (ARRAY, false),
+        Kind::FuncDecl | Kind::Closure | Kind::Method | Kind::ArrowFunc | Kind::PropertyHook => {
+            (FUNC, true)
+        }
+        Kind::PropGroup | Kind::ClassConstGroup | Kind::TraitAlias => (MODIFIERS, true),
+        Kind::Param => (PARAM, true),
+        Kind::Class => (CLASS, true),
+        Kind::ArrayElem => (&[(ARRAY_ELEM_REF, "ARRAY_ELEM_REF")], true),
+        Kind::ClosureVar => (&[(CLOSURE_USE_REF, "CLOSURE_USE_REF")], true),
+        Kind::Conditional => (
+            &[(PARENTHESIZED_CONDITIONAL, "PARENTHESIZED_CONDITIONAL")],
+            true,
+        ),
+        _ => return None,
+    })
+}
+
+/// `format_flags()` from ext-ast's `util.php`: the flag name, or the names
+/// of the set bits, then the value in parentheses.
+fn format_flags(kind: Kind, value: u32) -> String {
+    let names: Vec<&str> = match flag_names(kind) {
+        Some((names, false)) => names
+            .iter()
+            .filter(|(flag, _)| *flag == value)
+            .map(|(_, name)| *name)
+            .take(1)
+            .collect(),
+        Some((names, true)) => names
+            .iter()
+            .filter(|(flag, _)| value & flag != 0)
+            .map(|(_, name)| *name)
+            .collect(),
+        None => Vec::new(),
+    };
+    if names.is_empty() {
+        value.to_string()
+    } else {
+        format!("{} ({value})", names.join(" | "))
+    }
+}
+
+/// Convert `program` to ext-ast's tree, an `AST_STMT_LIST`. `map` is the
+/// source map of the parsed text, for line numbers and for the spellings
+/// ext-ast keeps and the AST does not (`array()` versus `[]`, attribute
+/// groups, references in `foreach`).
+pub fn from_program<'a>(program: &'a Program<'a, 'a>, map: &SourceMap<'a>) -> Node<'a> {
+    let mut converter = Converter {
+        map,
+        decl_id: 0,
+        generator: false,
+    };
+    let items = program.stmts.iter().map(|s| converter.stmt(s)).collect();
+    Node {
+        kind: Kind::StmtList,
+        flags: 0,
+        lineno: converter.line(program.span),
+        end_lineno: None,
+        children: Children::List(items),
+    }
+}
+
+fn node<'a>(
+    kind: Kind,
+    flags: u32,
+    lineno: u32,
+    children: Vec<(&'static str, Value<'a>)>,
+) -> Value<'a> {
+    Value::from(Node {
+        kind,
+        flags,
+        lineno,
+        end_lineno: None,
+        children: Children::Named(children),
+    })
+}
+
+fn list<'a>(kind: Kind, flags: u32, lineno: u32, items: Vec<Value<'a>>) -> Value<'a> {
+    Value::from(Node {
+        kind,
+        flags,
+        lineno,
+        end_lineno: None,
+        children: Children::List(items),
+    })
+}
+
+fn binary_flag(op: BinaryOp) -> u32 {
+    use flags::*;
+    match op {
+        BinaryOp::Add => BINARY_ADD,
+        BinaryOp::Sub => BINARY_SUB,
+        BinaryOp::Mul => BINARY_MUL,
+        BinaryOp::Div => BINARY_DIV,
+        BinaryOp::Mod => BINARY_MOD,
+        BinaryOp::Pow => BINARY_POW,
+        BinaryOp::Concat => BINARY_CONCAT,
+        BinaryOp::Equal => BINARY_IS_EQUAL,
+        BinaryOp::NotEqual => BINARY_IS_NOT_EQUAL,
+        BinaryOp::Identical => BINARY_IS_IDENTICAL,
+        BinaryOp::NotIdentical => BINARY_IS_NOT_IDENTICAL,
+        BinaryOp::Less => BINARY_IS_SMALLER,
+        BinaryOp::Greater => BINARY_IS_GREATER,
+        BinaryOp::LessOrEqual => BINARY_IS_SMALLER_OR_EQUAL,
+        BinaryOp::GreaterOrEqual => BINARY_IS_GREATER_OR_EQUAL,
+        BinaryOp::Spaceship => BINARY_SPACESHIP,
+        BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => BINARY_BOOL_AND,
+        BinaryOp::BooleanOr | BinaryOp::LogicalOr => BINARY_BOOL_OR,
+        BinaryOp::LogicalXor => BINARY_BOOL_XOR,
+        BinaryOp::BitwiseAnd => BINARY_BITWISE_AND,
+        BinaryOp::BitwiseOr => BINARY_BITWISE_OR,
+        BinaryOp::BitwiseXor => BINARY_BITWISE_XOR,
+        BinaryOp::ShiftLeft => BINARY_SHIFT_LEFT,
+        BinaryOp::ShiftRight => BINARY_SHIFT_RIGHT,
+        // `|>` has its own kind.
+        BinaryOp::Pipe => 0,
+    }
+}
+
+fn assign_flag(op: AssignOp) -> u32 {
+    use flags::*;
+    match op {
+        AssignOp::Assign => 0,
+        AssignOp::Plus => BINARY_ADD,
+        AssignOp::Minus => BINARY_SUB,
+        AssignOp::Mul => BINARY_MUL,
+        AssignOp::Div => BINARY_DIV,
+        AssignOp::Mod => BINARY_MOD,
+        AssignOp::Pow => BINARY_POW,
+        AssignOp::Concat => BINARY_CONCAT,
+        AssignOp::BitwiseAnd => BINARY_BITWISE_AND,
+        AssignOp::BitwiseOr => BINARY_BITWISE_OR,
+        AssignOp::BitwiseXor => BINARY_BITWISE_XOR,
+        AssignOp::ShiftLeft => BINARY_SHIFT_LEFT,
+        AssignOp::ShiftRight => BINARY_SHIFT_RIGHT,
+        AssignOp::Coalesce => BINARY_COALESCE,
+    }
+}
+
+fn visibility_flag(visibility: Option<Visibility>) -> u32 {
+    match visibility {
+        Some(Visibility::Public) => flags::MODIFIER_PUBLIC,
+        Some(Visibility::Protected) => flags::MODIFIER_PROTECTED,
+        Some(Visibility::Private) => flags::MODIFIER_PRIVATE,
+        None => 0,
+    }
+}
+
+fn set_visibility_flag(visibility: Option<Visibility>) -> u32 {
+    match visibility {
+        Some(Visibility::Public) => flags::MODIFIER_PUBLIC_SET,
+        Some(Visibility::Protected) => flags::MODIFIER_PROTECTED_SET,
+        Some(Visibility::Private) => flags::MODIFIER_PRIVATE_SET,
+        None => 0,
+    }
+}
+
+fn use_flag(kind: UseKind) -> u32 {
+    match kind {
+        UseKind::Normal => flags::USE_NORMAL,
+        UseKind::Function => flags::USE_FUNCTION,
+        UseKind::Const => flags::USE_CONST,
+    }
+}
+
+struct Converter<'m, 'a> {
+    map: &'m SourceMap<'a>,
+    /// The next `__declId`, numbered in the order declarations finish.
+    decl_id: i64,
+    /// Whether the function being converted contains `yield`.
+    generator: bool,
+}
+
+impl<'m, 'a> Converter<'m, 'a> {
+    fn line(&self, span: Span) -> u32 {
+        self.map.line_col(span.start).line + 1
+    }
+
+    fn end_line(&self, span: Span) -> u32 {
+        self.map.line_col(span.end.max(span.start + 1) - 1).line + 1
+    }
+
+    fn text(&self, span: Span) -> &'a str {
+        self.map.snippet(span).unwrap_or_default()
+    }
+
+    fn opt_expr(&mut self, expr: Option<&'a Expr<'a, 'a>>) -> Value<'a> {
+        expr.map_or(Value::Null, |e| self.expr(e))
+    }
+
+    fn stmt_list(&mut self, stmts: &'a [Stmt<'a, 'a>], span: Span) -> Value<'a> {
+        let items = stmts.iter().map(|s| self.stmt(s)).collect();
+        list(Kind::StmtList, 0, self.line(span), items)
+    }
+
+    /// A statement body: blocks are `AST_STMT_LIST`s, anything else is
+    /// the statement itself.
+    fn body(&mut self, stmt: &'a Stmt<'a, 'a>) -> Value<'a> {
+        self.stmt(stmt)
+    }
+
+    /// `echo`, `global`, `static`, and `unset` statements: one node per
+    /// operand, in an `AST_STMT_LIST`.
+    fn each(
+        &mut self,
+        kind: Kind,
+        key: &'static str,
+        exprs: &'a [Expr<'a, 'a>],
+        span: Span,
+    ) -> Value<'a> {
+        let items = exprs
+            .iter()
+            .map(|e| node(kind, 0, self.line(e.span), vec![(key, self.expr(e))]))
+            .collect();
+        list(Kind::StmtList, 0, self.line(span), items)
+    }
+
+    fn stmt(&mut self, stmt: &'a Stmt<'a, 'a>) -> Value<'a> {
+        let span = stmt.span;
+        let line = self.line(span);
+        match &stmt.kind {
+            StmtKind::Expression(expr) => self.expr(expr),
+            StmtKind::Echo(echo) => self.each(Kind::Echo, "expr", &echo.exprs, span),
+            StmtKind::Return(expr) => {
+                node(Kind::Return, 0, line, vec![("expr", self.opt_expr(*expr))])
+            }
+            StmtKind::Block(stmts) => self.stmt_list(stmts, span),
+            StmtKind::If(stmt) => {
+                let mut elems = vec![node(
+                    Kind::IfElem,
+                    0,
+                    line,
+                    vec![
+                        ("cond", self.expr(&stmt.condition)),
+                        ("stmts", self.body(stmt.then_branch)),
+                    ],
+                )];
+                for branch in stmt.elseif_branches.iter() {
+                    elems.push(node(
+                        Kind::IfElem,
+                        0,
+                        self.line(branch.span),
+                        vec![
+                            ("cond", self.expr(&branch.condition)),
+                            ("stmts", self.body(&branch.body)),
+                        ],
+                    ));
+                }
+                if let Some(else_branch) = stmt.else_branch {
+                    elems.push(node(
+                        Kind::IfElem,
+                        0,
+                        self.line(else_branch.span),
+                        vec![("cond", Value::Null), ("stmts", self.body(else_branch))],
+                    ));
+                }
+                list(Kind::If, 0, line, elems)
+            }
+            StmtKind::While(stmt) => node(
+                Kind::While,
+                0,
+                line,
+                vec![
+                    ("cond", self.expr(&stmt.condition)),
+                    ("stmts", self.body(stmt.body)),
+                ],
+            ),
+            StmtKind::DoWhile(stmt) => node(
+                Kind::DoWhile,
+                0,
+                line,
+                vec![
+                    ("stmts", self.body(stmt.body)),
+                    ("cond", self.expr(&stmt.condition)),
+                ],
+            ),
+            StmtKind::For(stmt) => node(
+                Kind::For,
+                0,
+                line,
+                vec![
+                    ("init", self.expr_list(&stmt.init)),
+                    ("cond", self.expr_list(&stmt.condition)),
+                    ("loop", self.expr_list(&stmt.update)),
+                    ("stmts", self.body(stmt.body)),
+                ],
+            ),
+            StmtKind::Foreach(stmt) => {
+                let expr = self.expr(&stmt.expr);
+                let mut value = self.expr(&stmt.value);
+                if self.preceded_by_ampersand(stmt.value.span) {
+                    value = node(
+                        Kind::Ref,
+                        0,
+                        self.line(stmt.value.span),
+                        vec![("var", value)],
+                    );
+                }
+                let key = stmt.key.as_ref().map_or(Value::Null, |k| self.expr(k));
+                node(
+                    Kind::Foreach,
+                    0,
+                    line,
+                    vec![
+                        ("expr", expr),
+                        ("value", value),
+                        ("key", key),
+                        ("stmts", self.body(stmt.body)),
+                    ],
+                )
+            }
+            StmtKind::Function(decl) => {
+                let name = Value::from(decl.name.or_error());
+                let flags = if decl.by_ref {
+                    flags::FUNC_RETURNS_REF
+                } else {
+                    0
+                };
+                self.function(
+                    Kind::FuncDecl,
+                    flags,
+                    span,
+                    name,
+                    decl.doc_comment.as_ref(),
+                    &decl.params,
+                    None,
+                    Some(&decl.body),
+                    decl.return_type.as_ref(),
+                    &decl.attributes,
+                )
+            }
+            StmtKind::Break(depth) => {
+                node(Kind::Break, 0, line, vec![("depth", self.opt_expr(*depth))])
+            }
+            StmtKind::Continue(depth) => node(
+                Kind::Continue,
+                0,
+                line,
+                vec![("depth", self.opt_expr(*depth))],
+            ),
+            StmtKind::Switch(stmt) => {
+                let cond = self.expr(&stmt.expr);
+                let cases = stmt
+                    .cases
+                    .iter()
+                    .map(|case| {
+                        let cond = case.value.as_ref().map_or(Value::Null, |v| self.expr(v));
+                        node(
+                            Kind::SwitchCase,
+                            0,
+                            self.line(case.span),
+                            vec![
+                                ("cond", cond),
+                                ("stmts", self.stmt_list(&case.body, case.span)),
+                            ],
+                        )
+                    })
+                    .collect();
+                node(
+                    Kind::Switch,
+                    0,
+                    line,
+                    vec![
+                        ("cond", cond),
+                        ("stmts", list(Kind::SwitchList, 0, line, cases)),
+                    ],
+                )
+            }
+            StmtKind::Goto(label) => node(
+                Kind::Goto,
+                0,
+                line,
+                vec![("label", label.or_error().into())],
+            ),
+            StmtKind::Label(name) => node(Kind::Label, 0, line, vec![("name", (*name).into())]),
+            StmtKind::Declare(stmt) => {
+                let declares = stmt
+                    .directives
+                    .iter()
+                    .map(|(name, value)| {
+                        node(
+                            Kind::ConstElem,
+                            0,
+                            self.line(value.span),
+                            vec![
+                                ("name", (*name).into()),
+                                ("value", self.expr(value)),
+                                ("docComment", Value::Null),
+                            ],
+                        )
+                    })
+                    .collect();
+                let stmts = stmt.body.map_or(Value::Null, |body| self.body(body));
+                node(
+                    Kind::Declare,
+                    0,
+                    line,
+                    vec![
+                        ("declares", list(Kind::ConstDecl, 0, line, declares)),
+                        ("stmts", stmts),
+                    ],
+                )
+            }
+            StmtKind::Unset(exprs) => self.each(Kind::Unset, "var", exprs, span),
+            StmtKind::Throw(expr) => node(Kind::Throw, 0, line, vec![("expr", self.expr(expr))]),
+            StmtKind::TryCatch(stmt) => {
+                let body = self.stmt_list(&stmt.body, span);
+                let catches = stmt
+                    .catches
+                    .iter()
+                    .map(|catch| {
+                        let line = self.line(catch.span);
+                        let types = catch.types.iter().map(|t| self.class_ref(t)).collect();
+                        let var = catch.var.as_ref().map_or(Value::Null, |var| {
+                            node(
+                                Kind::Var,
+                                0,
+                                self.line(var.span),
+                                vec![("name", var.name.trim_start_matches('$').into())],
+                            )
+                        });
+                        node(
+                            Kind::Catch,
+                            0,
+                            line,
+                            vec![
+                                ("class", list(Kind::NameList, 0, line, types)),
+                                ("var", var),
+                                ("stmts", self.stmt_list(&catch.body, catch.span)),
+                            ],
+                        )
+                    })
+                    .collect();
+                let finally = stmt
+                    .finally
+                    .as_ref()
+                    .map_or(Value::Null, |stmts| self.stmt_list(stmts, span));
+                node(
+                    Kind::Try,
+                    0,
+                    line,
+                    vec![
+                        ("try", body),
+                        ("catches", list(Kind::CatchList, 0, line, catches)),
+                        ("finally", finally),
+                    ],
+                )
+            }
+            StmtKind::Global(exprs) => self.each(Kind::Global, "var", exprs, span),
+            StmtKind::Class(decl) => self.class(decl, span, 0),
+            StmtKind::Interface(decl) => {
+                let implements = self.name_list(&decl.extends, line);
+                self.class_like(
+                    flags::CLASS_INTERFACE,
+                    span,
+                    decl.name.or_error().into(),
+                    decl.doc_comment.as_ref(),
+                    Value::Null,
+                    implements,
+                    ClassBody::Class(&decl.members),
+                    &decl.attributes,
+                    Value::Null,
+                )
+            }
+            StmtKind::Trait(decl) => self.class_like(
+                flags::CLASS_TRAIT,
+                span,
+                decl.name.or_error().into(),
+                decl.doc_comment.as_ref(),
+                Value::Null,
+                Value::Null,
+                ClassBody::Class(&decl.members),
+                &decl.attributes,
+                Value::Null,
+            ),
+            StmtKind::Enum(decl) => {
+                let implements = self.name_list(&decl.implements, line);
+                let backing = decl.scalar_type.as_ref().map_or(Value::Null, |name| {
+                    let line = self.line(name.span());
+                    match name.to_string_repr().to_ascii_lowercase().as_str() {
+                        "int" => node(Kind::Type, flags::TYPE_LONG, line, Vec::new()),
+                        "string" => node(Kind::Type, flags::TYPE_STRING, line, Vec::new()),
+                        _ => self.name(name),
+                    }
+                });
+                self.class_like(
+                    flags::CLASS_ENUM,
+                    span,
+                    decl.name.or_error().into(),
+                    decl.doc_comment.as_ref(),
+                    Value::Null,
+                    implements,
+                    ClassBody::Enum(&decl.members),
+                    &decl.attributes,
+                    backing,
+                )
+            }
+            StmtKind::Namespace(decl) => {
+                let name = decl
+                    .name
+                    .as_ref()
+                    .map_or(Value::Null, |name| Value::String(name.join_parts()));
+                let stmts = match &decl.body {
+                    NamespaceBody::Braced(stmts) => self.stmt_list(stmts, span),
+                    NamespaceBody::Simple => Value::Null,
+                };
+                node(
+                    Kind::Namespace,
+                    0,
+                    line,
+                    vec![("name", name), ("stmts", stmts)],
+                )
+            }
+            StmtKind::Use(decl) => {
+                let mixed = decl.uses.iter().any(|item| item.kind.is_some());
+                let items = decl
+                    .uses
+                    .iter()
+                    .map(|item| {
+                        let alias = item.alias.map_or(Value::Null, Value::from);
+                        node(
+                            Kind::UseElem,
+                            item.kind.map_or(0, use_flag),
+                            self.line(item.span),
+                            vec![
+                                ("name", Value::String(item.name.join_parts())),
+                                ("alias", alias),
+                            ],
+                        )
+                    })
+                    .collect();
+                let flags = if mixed { 0 } else { use_flag(decl.kind) };
+                list(Kind::Use, flags, line, items)
+            }
+            StmtKind::Const(items) => {
+                let elems = items
+                    .iter()
+                    .map(|item| {
+                        node(
+                            Kind::ConstElem,
+                            0,
+                            self.line(item.span),
+                            vec![
+                                ("name", item.name.or_error().into()),
+                                ("value", self.expr(&item.value)),
+                                ("docComment", self.doc(item.doc_comment.as_ref())),
+                            ],
+                        )
+                    })
+                    .collect();
+                list(Kind::ConstDecl, 0, line, elems)
+            }
+            StmtKind::StaticVar(vars) => {
+                let items = vars
+                    .iter()
+                    .map(|var| {
+                        let line = self.line(var.span);
+                        let name = var.name.or_error().trim_start_matches('$');
+                        let default = var.default.as_ref().map_or(Value::Null, |d| self.expr(d));
+                        node(
+                            Kind::Static,
+                            0,
+                            line,
+                            vec![
+                                ("var", node(Kind::Var, 0, line, vec![("name", name.into())])),
+                                ("default", default),
+                            ],
+                        )
+                    })
+                    .collect();
+                list(Kind::StmtList, 0, line, items)
+            }
+            StmtKind::HaltCompiler(data) => node(
+                Kind::HaltCompiler,
+                0,
+                line,
+                vec![("offset", Value::Int(data.span.start as i64))],
+            ),
+            StmtKind::InlineHtml(html) => {
+                node(Kind::Echo, 0, line, vec![("expr", html.output().into())])
+            }
+            StmtKind::Nop | StmtKind::Error => Value::Null,
+        }
+    }
+
+    fn expr_list(&mut self, exprs: &'a [Expr<'a, 'a>]) -> Value<'a> {
+        let Some(first) = exprs.first() else {
+            return Value::Null;
+        };
+        let line = self.line(first.span);
+        let items = exprs.iter().map(|e| self.expr(e)).collect();
+        list(Kind::ExprList, 0, line, items)
+    }
+
+    fn name_list(&mut self, names: &'a [Name<'a, 'a>], line: u32) -> Value<'a> {
+        if names.is_empty() {
+            return Value::Null;
+        }
+        let items = names.iter().map(|n| self.name(n)).collect();
+        list(Kind::NameList, 0, line, items)
+    }
+
+    fn preceded_by_ampersand(&self, span: Span) -> bool {
+        self.map.source()[..span.start as usize]
+            .trim_end()
+            .ends_with('&')
+    }
+
+    fn doc(&self, comment: Option<&'a Comment<'a>>) -> Value<'a> {
+        comment.map_or(Value::Null, |c| c.text.into())
+    }
+
+    fn name(&self, name: &'a Name<'a, 'a>) -> Value<'a> {
+        let flags = match name.kind() {
+            NameKind::FullyQualified => flags::NAME_FQ,
+            NameKind::Relative => flags::NAME_RELATIVE,
+            NameKind::Unqualified | NameKind::Qualified => flags::NAME_NOT_FQ,
+            NameKind::Error => return Value::Null,
+        };
+        node(
+            Kind::Name,
+            flags,
+            self.line(name.span()),
+            vec![("name", Value::String(name.join_parts()))],
+        )
+    }
+
+    /// An unqualified name spelled as in the source, e.g. `self`.
+    fn keyword_name(&self, span: Span, fallback: &'static str) -> Value<'a> {
+        let text = self.text(span);
+        let text = if text.is_empty() { fallback } else { text };
+        node(
+            Kind::Name,
+            flags::NAME_NOT_FQ,
+            self.line(span),
+            vec![("name", text.into())],
+        )
+    }
+
+    fn class_ref(&mut self, class: &'a ClassRef<'a, 'a>) -> Value<'a> {
+        match class {
+            ClassRef::Name(name) => self.name(name),
+            ClassRef::SelfKw(span) => self.keyword_name(*span, "self"),
+            ClassRef::ParentKw(span) => self.keyword_name(*span, "parent"),
+            ClassRef::StaticKw(span) => self.keyword_name(*span, "static"),
+            ClassRef::Expr(expr) => self.expr(expr),
+        }
+    }
+
+    fn type_hint(&mut self, hint: Option<&'a TypeHint<'a, 'a>>) -> Value<'a> {
+        let Some(hint) = hint else {
+            return Value::Null;
+        };
+        let line = self.line(hint.span);
+        match &hint.kind {
+            TypeHintKind::Named(name) => self.name(name),
+            TypeHintKind::Keyword(builtin, span) => {
+                let flags = match builtin {
+                    BuiltinType::Int => flags::TYPE_LONG,
+                    BuiltinType::Float => flags::TYPE_DOUBLE,
+                    BuiltinType::String => flags::TYPE_STRING,
+                    BuiltinType::Bool => flags::TYPE_BOOL,
+                    BuiltinType::Void => flags::TYPE_VOID,
+                    BuiltinType::Never => flags::TYPE_NEVER,
+                    BuiltinType::Mixed => flags::TYPE_MIXED,
+                    BuiltinType::Object => flags::TYPE_OBJECT,
+                    BuiltinType::Iterable => flags::TYPE_ITERABLE,
+                    BuiltinType::Callable => flags::TYPE_CALLABLE,
+                    BuiltinType::Array => flags::TYPE_ARRAY,
+                    BuiltinType::Static => flags::TYPE_STATIC,
+                    BuiltinType::Null => flags::TYPE_NULL,
+                    BuiltinType::True => flags::TYPE_TRUE,
+                    BuiltinType::False => flags::TYPE_FALSE,
+                    // Class names to the engine.
+                    BuiltinType::Integer
+                    | BuiltinType::Double
+                    | BuiltinType::Boolean
+                    | BuiltinType::Self_
+                    | BuiltinType::Parent_ => return self.keyword_name(*span, builtin.as_str()),
+                };
+                node(Kind::Type, flags, line, Vec::new())
+            }
+            TypeHintKind::Nullable(inner) => node(
+                Kind::NullableType,
+                0,
+                line,
+                vec![("type", self.type_hint(Some(inner)))],
+            ),
+            TypeHintKind::Union(types) => {
+                let items = types.iter().map(|t| self.type_hint(Some(t))).collect();
+                list(Kind::TypeUnion, 0, line, items)
+            }
+            TypeHintKind::Intersection(types) => {
+                let items = types.iter().map(|t| self.type_hint(Some(t))).collect();
+                list(Kind::TypeIntersection, 0, line, items)
+            }
+        }
+    }
+
+    fn array_items(&mut self, elements: &'a [ArrayElement<'a, 'a>]) -> Vec<Value<'a>> {
+        elements
+            .iter()
+            .map(|element| {
+                let line = self.line(element.span);
+                if matches!(element.value.kind, ExprKind::Omit) {
+                    Value::Null
+                } else if element.unpack {
+                    node(
+                        Kind::Unpack,
+                        0,
+                        line,
+                        vec![("expr", self.expr(&element.value))],
+                    )
+                } else {
+                    let value = self.expr(&element.value);
+                    let key = element.key.as_ref().map_or(Value::Null, |k| self.expr(k));
+                    let flags = if element.by_ref {
+                        flags::ARRAY_ELEM_REF
+                    } else {
+                        0
+                    };
+                    node(
+                        Kind::ArrayElem,
+                        flags,
+                        line,
+                        vec![("value", value), ("key", key)],
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn args(&mut self, args: &'a [Arg<'a, 'a>], line: u32) -> Value<'a> {
+        let items = args
+            .iter()
+            .map(|arg| {
+                let value = self.expr(&arg.value);
+                let line = self.line(arg.span);
+                if arg.unpack {
+                    node(Kind::Unpack, 0, line, vec![("expr", value)])
+                } else if let Some(name) = &arg.name {
+                    node(
+                        Kind::NamedArg,
+                        0,
+                        line,
+                        vec![("name", Value::String(name.join_parts())), ("expr", value)],
+                    )
+                } else {
+                    value
+                }
+            })
+            .collect();
+        list(Kind::ArgList, 0, line, items)
+    }
+
+    /// `#[A, B] #[C]` is two `AST_ATTRIBUTE_GROUP`s in an
+    /// `AST_ATTRIBUTE_LIST`. The AST keeps the attributes flat, so groups
+    /// are recovered from the `]` between them.
+    fn attributes(&mut self, attributes: &'a [Attribute<'a, 'a>]) -> Value<'a> {
+        let Some(first) = attributes.first() else {
+            return Value::Null;
+        };
+        let mut groups: Vec<(u32, Vec<Value<'a>>)> = Vec::new();
+        let mut previous_end = None;
+        for attribute in attributes {
+            let line = self.line(attribute.span);
+            let new_group = previous_end.is_none_or(|end| {
+                self.text(Span::new(end, attribute.span.start))
+                    .contains(']')
+            });
+            if new_group {
+                groups.push((line, Vec::new()));
+            }
+            let has_args = self.text(attribute.span).contains('(');
+            let args = if has_args {
+                self.args(&attribute.args, line)
+            } else {
+                Value::Null
+            };
+            let value = node(
+                Kind::Attribute,
+                0,
+                line,
+                vec![("class", self.name(&attribute.name)), ("args", args)],
+            );
+            if let Some((_, group)) = groups.last_mut() {
+                group.push(value);
+            }
+            previous_end = Some(attribute.span.end);
+        }
+        let groups = groups
+            .into_iter()
+            .map(|(line, items)| list(Kind::AttributeGroup, 0, line, items))
+            .collect();
+        list(Kind::AttributeList, 0, self.line(first.span), groups)
+    }
+
+    fn params(&mut self, params: &'a [Param<'a, 'a>], line: u32) -> Value<'a> {
+        let items = params
+            .iter()
+            .map(|param| {
+                let mut flags =
+                    visibility_flag(param.visibility) | set_visibility_flag(param.set_visibility);
+                if param.by_ref {
+                    flags |= flags::PARAM_REF;
+                }
+                if param.variadic {
+                    flags |= flags::PARAM_VARIADIC;
+                }
+                if param.is_readonly {
+                    flags |= flags::PARAM_MODIFIER_READONLY;
+                }
+                if param.is_final {
+                    flags |= flags::MODIFIER_FINAL;
+                }
+                let default = param.default.as_ref().map_or(Value::Null, |d| self.expr(d));
+                let name = param.name.or_error().trim_start_matches('$');
+                node(
+                    Kind::Param,
+                    flags,
+                    self.line(param.span),
+                    vec![
+                        ("type", self.type_hint(param.type_hint.as_ref())),
+                        ("name", name.into()),
+                        ("default", default),
+                        ("attributes", self.attributes(&param.attributes)),
+                        ("docComment", Value::Null),
+                        ("hooks", self.hooks(&param.hooks)),
+                    ],
+                )
+            })
+            .collect();
+        list(Kind::ParamList, 0, line, items)
+    }
+
+    fn hooks(&mut self, hooks: &'a [PropertyHook<'a, 'a>]) -> Value<'a> {
+        let Some(first) = hooks.first() else {
+            return Value::Null;
+        };
+        let items = hooks
+            .iter()
+            .map(|hook| {
+                let name = match hook.kind {
+                    PropertyHookKind::Get => "get",
+                    PropertyHookKind::Set => "set",
+                };
+                let mut flags = 0;
+                if hook.is_final {
+                    flags |= flags::MODIFIER_FINAL;
+                }
+                if hook.by_ref {
+                    flags |= flags::FUNC_RETURNS_REF;
+                }
+                let line = self.line(hook.span);
+                let params = if hook.params.is_empty() {
+                    Value::Null
+                } else {
+                    self.params(&hook.params, line)
+                };
+                let stmts = match &hook.body {
+                    PropertyHookBody::Block(stmts) => self.stmt_list(stmts, hook.span),
+                    PropertyHookBody::Expression(expr) => node(
+                        Kind::PropertyHookShortBody,
+                        0,
+                        self.line(expr.span),
+                        vec![("expr", self.expr(expr))],
+                    ),
+                    PropertyHookBody::Abstract => Value::Null,
+                };
+                let attributes = self.attributes(&hook.attributes);
+                self.decl(
+                    Kind::PropertyHook,
+                    flags,
+                    hook.span,
+                    vec![
+                        ("name", name.into()),
+                        ("docComment", Value::Null),
+                        ("params", params),
+                        ("stmts", stmts),
+                        ("attributes", attributes),
+                    ],
+                )
+            })
+            .collect();
+        list(Kind::StmtList, 0, self.line(first.span), items)
+    }
+
+    /// Finish a declaration node: its end line and `__declId`.
+    fn decl(
+        &mut self,
+        kind: Kind,
+        flags: u32,
+        span: Span,
+        mut children: Vec<(&'static str, Value<'a>)>,
+    ) -> Value<'a> {
+        children.push(("__declId", Value::Int(self.decl_id)));
+        self.decl_id += 1;
+        Value::from(Node {
+            kind,
+            flags,
+            lineno: self.line(span),
+            end_lineno: Some(self.end_line(span)),
+            children: Children::Named(children),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn function(
+        &mut self,
+        kind: Kind,
+        mut flags: u32,
+        span: Span,
+        name: Value<'a>,
+        doc: Option<&'a Comment<'a>>,
+        params: &'a [Param<'a, 'a>],
+        uses: Option<Value<'a>>,
+        body: Option<&'a [Stmt<'a, 'a>]>,
+        return_type: Option<&'a TypeHint<'a, 'a>>,
+        attributes: &'a [Attribute<'a, 'a>],
+    ) -> Value<'a> {
+        let line = self.line(span);
+        let params = self.params(params, line);
+        let outer = std::mem::replace(&mut self.generator, false);
+        let stmts = body.map_or(Value::Null, |stmts| self.stmt_list(stmts, span));
+        if std::mem::replace(&mut self.generator, outer) {
+            flags |= flags::FUNC_GENERATOR;
+        }
+        let mut children = vec![
+            ("name", name),
+            ("docComment", self.doc(doc)),
+            ("params", params),
+        ];
+        if let Some(uses) = uses {
+            children.push(("uses", uses));
+        }
+        children.push(("stmts", stmts));
+        children.push(("returnType", self.type_hint(return_type)));
+        children.push(("attributes", self.attributes(attributes)));
+        self.decl(kind, flags, span, children)
+    }
+
+    fn class(&mut self, decl: &'a ClassDecl<'a, 'a>, span: Span, mut flags: u32) -> Value<'a> {
+        if decl.modifiers.is_abstract {
+            flags |= flags::CLASS_ABSTRACT;
+        }
+        if decl.modifiers.is_final {
+            flags |= flags::CLASS_FINAL;
+        }
+        if decl.modifiers.is_readonly {
+            flags |= flags::CLASS_READONLY;
+        }
+        let name = decl.name.map_or(Value::Null, |name| name.or_error().into());
+        let extends = decl.extends.as_ref().map_or(Value::Null, |n| self.name(n));
+        let implements = self.name_list(&decl.implements, self.line(span));
+        self.class_like(
+            flags,
+            span,
+            name,
+            decl.doc_comment.as_ref(),
+            extends,
+            implements,
+            ClassBody::Class(&decl.members),
+            &decl.attributes,
+            Value::Null,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn class_like(
+        &mut self,
+        flags: u32,
+        span: Span,
+        name: Value<'a>,
+        doc: Option<&'a Comment<'a>>,
+        extends: Value<'a>,
+        implements: Value<'a>,
+        body: ClassBody<'a>,
+        attributes: &'a [Attribute<'a, 'a>],
+        backing: Value<'a>,
+    ) -> Value<'a> {
+        let line = self.line(span);
+        let members = match body {
+            ClassBody::Class(members) => members
+                .iter()
+                .map(|m| self.class_member(&m.kind, m.span))
+                .collect(),
+            ClassBody::Enum(members) => members
+                .iter()
+                .map(|m| match &m.kind {
+                    EnumMemberKind::Case(case) => {
+                        let expr = case.value.as_ref().map_or(Value::Null, |v| self.expr(v));
+                        node(
+                            Kind::EnumCase,
+                            0,
+                            self.line(m.span),
+                            vec![
+                                ("name", case.name.or_error().into()),
+                                ("expr", expr),
+                                ("docComment", self.doc(case.doc_comment.as_ref())),
+                                ("attributes", self.attributes(&case.attributes)),
+                            ],
+                        )
+                    }
+                    EnumMemberKind::Method(method) => self.method(method, m.span),
+                    EnumMemberKind::ClassConst(constant) => self.class_const(constant, m.span),
+                    EnumMemberKind::TraitUse(trait_use) => self.trait_use(trait_use, m.span),
+                })
+                .collect(),
+        };
+        let stmts = list(Kind::StmtList, 0, line, members);
+        let children = vec![
+            ("name", name),
+            ("docComment", self.doc(doc)),
+            ("extends", extends),
+            ("implements", implements),
+            ("stmts", stmts),
+            ("attributes", self.attributes(attributes)),
+            ("type", backing),
+        ];
+        self.decl(Kind::Class, flags, span, children)
+    }
+
+    fn class_member(&mut self, member: &'a ClassMemberKind<'a, 'a>, span: Span) -> Value<'a> {
+        match member {
+            ClassMemberKind::Property(prop) => {
+                let line = self.line(span);
+                let mut flags =
+                    visibility_flag(prop.visibility) | set_visibility_flag(prop.set_visibility);
+                if prop.visibility.is_none()
+                    && self
+                        .text(span)
+                        .get(..3)
+                        .is_some_and(|kw| kw.eq_ignore_ascii_case("var"))
+                {
+                    flags |= flags::MODIFIER_PUBLIC;
+                }
+                if prop.is_static {
+                    flags |= flags::MODIFIER_STATIC;
+                }
+                if prop.is_readonly {
+                    flags |= flags::MODIFIER_READONLY;
+                }
+                let name = prop.name.or_error().trim_start_matches('$');
+                let default = prop.default.as_ref().map_or(Value::Null, |d| self.expr(d));
+                let elem = node(
+                    Kind::PropElem,
+                    0,
+                    line,
+                    vec![
+                        ("name", name.into()),
+                        ("default", default),
+                        ("docComment", self.doc(prop.doc_comment.as_ref())),
+                        ("hooks", self.hooks(&prop.hooks)),
+                    ],
+                );
+                node(
+                    Kind::PropGroup,
+                    flags,
+                    line,
+                    vec![
+                        ("type", self.type_hint(prop.type_hint.as_ref())),
+                        ("props", list(Kind::PropDecl, 0, line, vec![elem])),
+                        ("attributes", self.attributes(&prop.attributes)),
+                    ],
+                )
+            }
+            ClassMemberKind::Method(method) => self.method(method, span),
+            ClassMemberKind::ClassConst(constant) => self.class_const(constant, span),
+            ClassMemberKind::TraitUse(trait_use) => self.trait_use(trait_use, span),
+        }
+    }
+
+    fn method(&mut self, method: &'a MethodDecl<'a, 'a>, span: Span) -> Value<'a> {
+        let mut flags = match method.visibility {
+            None => flags::MODIFIER_PUBLIC,
+            visibility => visibility_flag(visibility),
+        };
+        if method.is_static {
+            flags |= flags::MODIFIER_STATIC;
+        }
+        if method.is_abstract {
+            flags |= flags::MODIFIER_ABSTRACT;
+        }
+        if method.is_final {
+            flags |= flags::MODIFIER_FINAL;
+        }
+        if method.by_ref {
+            flags |= flags::FUNC_RETURNS_REF;
+        }
+        self.function(
+            Kind::Method,
+            flags,
+            span,
+            method.name.or_error().into(),
+            method.doc_comment.as_ref(),
+            &method.params,
+            None,
+            method.body.as_deref(),
+            method.return_type.as_ref(),
+            &method.attributes,
+        )
+    }
+
+    fn class_const(&mut self, constant: &'a ClassConstDecl<'a, 'a>, span: Span) -> Value<'a> {
+        let line = self.line(span);
+        let mut flags = match constant.visibility {
+            None => flags::MODIFIER_PUBLIC,
+            visibility => visibility_flag(visibility),
+        };
+        if constant.is_final {
+            flags |= flags::MODIFIER_FINAL;
+        }
+        let elem = node(
+            Kind::ConstElem,
+            0,
+            line,
+            vec![
+                ("name", constant.name.or_error().into()),
+                ("value", self.expr(&constant.value)),
+                ("docComment", self.doc(constant.doc_comment.as_ref())),
+            ],
+        );
+        node(
+            Kind::ClassConstGroup,
+            flags,
+            line,
+            vec![
+                ("const", list(Kind::ClassConstDecl, 0, line, vec![elem])),
+                ("attributes", self.attributes(&constant.attributes)),
+                ("type", self.type_hint(constant.type_hint)),
+            ],
+        )
+    }
+
+    fn trait_use(&mut self, trait_use: &'a TraitUseDecl<'a, 'a>, span: Span) -> Value<'a> {
+        let line = self.line(span);
+        let traits = self.name_list(&trait_use.traits, line);
+        let adaptations = if trait_use.adaptations.is_empty() {
+            Value::Null
+        } else {
+            let items = trait_use
+                .adaptations
+                .iter()
+                .map(|adaptation| {
+                    let line = self.line(adaptation.span);
+                    match &adaptation.kind {
+                        TraitAdaptationKind::Precedence {
+                            trait_name,
+                            method,
+                            insteadof,
+                        } => node(
+                            Kind::TraitPrecedence,
+                            0,
+                            line,
+                            vec![
+                                (
+                                    "method",
+                                    self.method_reference(Some(trait_name), method, line),
+                                ),
+                                ("insteadof", self.name_list(insteadof, line)),
+                            ],
+                        ),
+                        TraitAdaptationKind::Alias {
+                            trait_name,
+                            method,
+                            new_modifier,
+                            new_name,
+                        } => {
+                            let alias = new_name
+                                .as_ref()
+                                .map_or(Value::Null, |n| Value::String(n.join_parts()));
+                            node(
+                                Kind::TraitAlias,
+                                visibility_flag(*new_modifier),
+                                line,
+                                vec![
+                                    (
+                                        "method",
+                                        self.method_reference(trait_name.as_ref(), method, line),
+                                    ),
+                                    ("alias", alias),
+                                ],
+                            )
+                        }
+                    }
+                })
+                .collect();
+            list(Kind::TraitAdaptations, 0, line, items)
+        };
+        node(
+            Kind::UseTrait,
+            0,
+            line,
+            vec![("traits", traits), ("adaptations", adaptations)],
+        )
+    }
+
+    fn method_reference(
+        &self,
+        class: Option<&'a Name<'a, 'a>>,
+        method: &'a Name<'a, 'a>,
+        line: u32,
+    ) -> Value<'a> {
+        node(
+            Kind::MethodReference,
+            0,
+            line,
+            vec![
+                ("class", class.map_or(Value::Null, |c| self.name(c))),
+                ("method", Value::String(method.join_parts())),
+            ],
+        )
+    }
+
+    /// The parts of an interpolated string: an `AST_ENCAPS_LIST`, or a
+    /// plain string when there is nothing to interpolate.
+    fn encaps(&mut self, parts: &'a [StringPart<'a, 'a>], line: u32) -> Value<'a> {
+        if let [StringPart::Literal { value, .. }] = parts {
+            return (*value).into();
+        }
+        if parts.is_empty() {
+            return "".into();
+        }
+        let items = parts
+            .iter()
+            .map(|part| match part {
+                StringPart::Literal { value, .. } => (*value).into(),
+                StringPart::Expr(expr) => self.expr(expr),
+            })
+            .collect();
+        list(Kind::EncapsList, 0, line, items)
+    }
+
+    /// `true`, `false`, and `null` are constants to ext-ast.
+    fn literal_const(&self, span: Span, fallback: &'static str) -> Value<'a> {
+        let line = self.line(span);
+        let text = self.text(span);
+        let (flags, text) = match text.strip_prefix('\\') {
+            Some(rest) => (flags::NAME_FQ, rest),
+            None if text.is_empty() => (flags::NAME_NOT_FQ, fallback),
+            None => (flags::NAME_NOT_FQ, text),
+        };
+        node(
+            Kind::Const,
+            0,
+            line,
+            vec![(
+                "name",
+                node(Kind::Name, flags, line, vec![("name", text.into())]),
+            )],
+        )
+    }
+
+    /// The name child of a property or static property: a string for a
+    /// plain name, otherwise the expression.
+    fn member_name(&mut self, member: &'a Expr<'a, 'a>) -> Value<'a> {
+        match &member.kind {
+            ExprKind::Identifier(name) | ExprKind::Variable(name) => name.as_str().into(),
+            ExprKind::VariableVariable(inner) => self.expr(inner),
+            _ => self.expr(member),
+        }
+    }
+
+    fn expr(&mut self, expr: &'a Expr<'a, 'a>) -> Value<'a> {
+        let line = self.line(expr.span);
+        match &expr.kind {
+            ExprKind::Int(n) => Value::Int(*n),
+            ExprKind::Float(n) => Value::Float(*n),
+            ExprKind::String { value, .. } => (*value).into(),
+            ExprKind::InterpolatedString(parts) | ExprKind::Heredoc { parts, .. } => {
+                self.encaps(parts, line)
+            }
+            ExprKind::Nowdoc { value, .. } => (*value).into(),
+            ExprKind::ShellExec(parts) => node(
+                Kind::ShellExec,
+                0,
+                line,
+                vec![("expr", self.encaps(parts, line))],
+            ),
+            ExprKind::Bool(true) => self.literal_const(expr.span, "true"),
+            ExprKind::Bool(false) => self.literal_const(expr.span, "false"),
+            ExprKind::Null => self.literal_const(expr.span, "null"),
+            ExprKind::Variable(name) => {
+                node(Kind::Var, 0, line, vec![("name", name.as_str().into())])
+            }
+            ExprKind::VariableVariable(inner) => {
+                node(Kind::Var, 0, line, vec![("name", self.expr(inner))])
+            }
+            ExprKind::Identifier(name) => name.as_str().into(),
+            ExprKind::Name(name) => self.name(name),
+            ExprKind::ConstFetch(name) => {
+                node(Kind::Const, 0, line, vec![("name", self.name(name))])
+            }
+            ExprKind::Assign(assign) => {
+                let (kind, flags) = match (assign.op, assign.by_ref) {
+                    (AssignOp::Assign, false) => (Kind::Assign, 0),
+                    (AssignOp::Assign, true) => (Kind::AssignRef, 0),
+                    (op, _) => (Kind::AssignOp, assign_flag(op)),
+                };
+                node(
+                    kind,
+                    flags,
+                    line,
+                    vec![
+                        ("var", self.expr(assign.target)),
+                        ("expr", self.expr(assign.value)),
+                    ],
+                )
+            }
+            ExprKind::Binary(binary) => {
+                let left = self.expr(binary.left);
+                let right = self.expr(binary.right);
+                if binary.op == BinaryOp::Pipe {
+                    node(Kind::Pipe, 0, line, vec![("left", left), ("right", right)])
+                } else {
+                    node(
+                        Kind::BinaryOp,
+                        binary_flag(binary.op),
+                        line,
+                        vec![("left", left), ("right", right)],
+                    )
+                }
+            }
+            ExprKind::UnaryPrefix(unary) => {
+                let operand = self.expr(unary.operand);
+                let flags = match unary.op {
+                    UnaryPrefixOp::PreIncrement => {
+                        return node(Kind::PreInc, 0, line, vec![("var", operand)])
+                    }
+                    UnaryPrefixOp::PreDecrement => {
+                        return node(Kind::PreDec, 0, line, vec![("var", operand)])
+                    }
+                    UnaryPrefixOp::Negate => flags::UNARY_MINUS,
+                    UnaryPrefixOp::Plus => flags::UNARY_PLUS,
+                    UnaryPrefixOp::BooleanNot => flags::UNARY_BOOL_NOT,
+                    UnaryPrefixOp::BitwiseNot => flags::UNARY_BITWISE_NOT,
+                };
+                node(Kind::UnaryOp, flags, line, vec![("expr", operand)])
+            }
+            ExprKind::UnaryPostfix(unary) => {
+                let kind = match unary.op {
+                    UnaryPostfixOp::PostIncrement => Kind::PostInc,
+                    UnaryPostfixOp::PostDecrement => Kind::PostDec,
+                };
+                node(kind, 0, line, vec![("var", self.expr(unary.operand))])
+            }
+            ExprKind::Ternary(ternary) => node(
+                Kind::Conditional,
+                0,
+                line,
+                vec![
+                    ("cond", self.expr(ternary.condition)),
+                    ("true", self.opt_expr(ternary.then_expr)),
+                    ("false", self.expr(ternary.else_expr)),
+                ],
+            ),
+            ExprKind::NullCoalesce(coalesce) => node(
+                Kind::BinaryOp,
+                flags::BINARY_COALESCE,
+                line,
+                vec![
+                    ("left", self.expr(coalesce.left)),
+                    ("right", self.expr(coalesce.right)),
+                ],
+            ),
+            ExprKind::FunctionCall(call) => node(
+                Kind::Call,
+                0,
+                line,
+                vec![
+                    ("expr", self.expr(call.name)),
+                    ("args", self.args(&call.args, line)),
+                ],
+            ),
+            ExprKind::Array(elements) => {
+                let text = self.text(expr.span);
+                let flags = if text.starts_with('[') {
+                    flags::ARRAY_SYNTAX_SHORT
+                } else {
+                    flags::ARRAY_SYNTAX_LONG
+                };
+                list(Kind::Array, flags, line, self.array_items(elements))
+            }
+            ExprKind::List(list_expr) => {
+                let flags = if list_expr.uses_list {
+                    flags::ARRAY_SYNTAX_LIST
+                } else {
+                    flags::ARRAY_SYNTAX_SHORT
+                };
+                list(
+                    Kind::Array,
+                    flags,
+                    line,
+                    self.array_items(&list_expr.elements),
+                )
+            }
+            ExprKind::ArrayAccess(access) => node(
+                Kind::Dim,
+                0,
+                line,
+                vec![
+                    ("expr", self.expr(access.array)),
+                    ("dim", self.opt_expr(access.index)),
+                ],
+            ),
+            ExprKind::Print(print) => {
+                node(Kind::Print, 0, line, vec![("expr", self.expr(print.expr))])
+            }
+            ExprKind::Parenthesized(inner) => {
+                let mut value = self.expr(inner);
+                if let Value::Node(node) = &mut value {
+                    if node.kind == Kind::Conditional {
+                        node.flags |= flags::PARENTHESIZED_CONDITIONAL;
+                    }
+                }
+                value
+            }
+            ExprKind::Cast(kind, inner) => {
+                let flags = match kind {
+                    CastKind::Int => flags::TYPE_LONG,
+                    CastKind::Float => flags::TYPE_DOUBLE,
+                    CastKind::String => flags::TYPE_STRING,
+                    CastKind::Bool => flags::TYPE_BOOL,
+                    CastKind::Array => flags::TYPE_ARRAY,
+                    CastKind::Object => flags::TYPE_OBJECT,
+                    CastKind::Unset => flags::TYPE_NULL,
+                    CastKind::Void => flags::TYPE_VOID,
+                };
+                node(Kind::Cast, flags, line, vec![("expr", self.expr(inner))])
+            }
+            ExprKind::Instanceof(instanceof) => node(
+                Kind::Instanceof,
+                0,
+                line,
+                vec![
+                    ("expr", self.expr(instanceof.expr)),
+                    ("class", self.class_ref(&instanceof.class)),
+                ],
+            ),
+            ExprKind::ErrorSuppress(suppress) => node(
+                Kind::UnaryOp,
+                flags::UNARY_SILENCE,
+                line,
+                vec![("expr", self.expr(suppress.expr))],
+            ),
+            ExprKind::Isset(vars) => {
+                let mut issets = vars.iter().map(|var| {
+                    node(
+                        Kind::Isset,
+                        0,
+                        self.line(var.span),
+                        vec![("var", self.expr(var))],
+                    )
+                });
+                let first = issets.next().unwrap_or(Value::Null);
+                issets.fold(first, |left, right| {
+                    node(
+                        Kind::BinaryOp,
+                        flags::BINARY_BOOL_AND,
+                        line,
+                        vec![("left", left), ("right", right)],
+                    )
+                })
+            }
+            ExprKind::Empty(inner) => node(Kind::Empty, 0, line, vec![("expr", self.expr(inner))]),
+            ExprKind::Include(kind, inner) => {
+                let flags = match kind {
+                    IncludeKind::Include => flags::EXEC_INCLUDE,
+                    IncludeKind::IncludeOnce => flags::EXEC_INCLUDE_ONCE,
+                    IncludeKind::Require => flags::EXEC_REQUIRE,
+                    IncludeKind::RequireOnce => flags::EXEC_REQUIRE_ONCE,
+                };
+                node(
+                    Kind::IncludeOrEval,
+                    flags,
+                    line,
+                    vec![("expr", self.expr(inner))],
+                )
+            }
+            ExprKind::Eval(inner) => node(
+                Kind::IncludeOrEval,
+                flags::EXEC_EVAL,
+                line,
+                vec![("expr", self.expr(inner))],
+            ),
+            ExprKind::Exit(inner) => {
+                node(Kind::Exit, 0, line, vec![("expr", self.opt_expr(*inner))])
+            }
+            ExprKind::MagicConst(kind) => {
+                let flags = match kind {
+                    MagicConstKind::Line => flags::MAGIC_LINE,
+                    MagicConstKind::File => flags::MAGIC_FILE,
+                    MagicConstKind::Dir => flags::MAGIC_DIR,
+                    MagicConstKind::Namespace => flags::MAGIC_NAMESPACE,
+                    MagicConstKind::Function => flags::MAGIC_FUNCTION,
+                    MagicConstKind::Method => flags::MAGIC_METHOD,
+                    MagicConstKind::Class => flags::MAGIC_CLASS,
+                    MagicConstKind::Trait => flags::MAGIC_TRAIT,
+                    MagicConstKind::Property => flags::MAGIC_PROPERTY,
+                };
+                node(Kind::MagicConst, flags, line, Vec::new())
+            }
+            ExprKind::Clone(clone) => node(
+                Kind::Clone,
+                0,
+                line,
+                vec![("expr", self.expr(clone.object))],
+            ),
+            // `clone($object, $with)` is a call to the `clone` function.
+            ExprKind::CloneWith(object, with) => {
+                let name = node(
+                    Kind::Name,
+                    flags::NAME_NOT_FQ,
+                    line,
+                    vec![("name", "clone".into())],
+                );
+                let args = vec![self.expr(object), self.expr(with)];
+                node(
+                    Kind::Call,
+                    0,
+                    line,
+                    vec![("expr", name), ("args", list(Kind::ArgList, 0, line, args))],
+                )
+            }
+            ExprKind::New(new) => node(
+                Kind::New,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(&new.class)),
+                    ("args", self.args(&new.args, line)),
+                ],
+            ),
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                let kind = if matches!(expr.kind, ExprKind::PropertyAccess(_)) {
+                    Kind::Prop
+                } else {
+                    Kind::NullsafeProp
+                };
+                node(
+                    kind,
+                    0,
+                    line,
+                    vec![
+                        ("expr", self.expr(access.object)),
+                        ("prop", self.member_name(access.property)),
+                    ],
+                )
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                let kind = if matches!(expr.kind, ExprKind::MethodCall(_)) {
+                    Kind::MethodCall
+                } else {
+                    Kind::NullsafeMethodCall
+                };
+                node(
+                    kind,
+                    0,
+                    line,
+                    vec![
+                        ("expr", self.expr(call.object)),
+                        ("method", self.member_name(call.method)),
+                        ("args", self.args(&call.args, line)),
+                    ],
+                )
+            }
+            ExprKind::StaticPropertyAccess(access) => node(
+                Kind::StaticProp,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(&access.class)),
+                    ("prop", self.member_name(access.member)),
+                ],
+            ),
+            ExprKind::StaticPropertyAccessDynamic { class, member } => node(
+                Kind::StaticProp,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(class)),
+                    ("prop", self.expr(member)),
+                ],
+            ),
+            ExprKind::StaticMethodCall(call) => node(
+                Kind::StaticCall,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(&call.class)),
+                    ("method", self.member_name(call.method)),
+                    ("args", self.args(&call.args, line)),
+                ],
+            ),
+            ExprKind::StaticDynMethodCall(call) => node(
+                Kind::StaticCall,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(&call.class)),
+                    ("method", self.expr(call.method)),
+                    ("args", self.args(&call.args, line)),
+                ],
+            ),
+            ExprKind::ClassConstAccess(access) => {
+                let class = self.class_ref(&access.class);
+                match access.member.name_str() {
+                    Some(name) if name.eq_ignore_ascii_case("class") => {
+                        node(Kind::ClassName, 0, line, vec![("class", class)])
+                    }
+                    _ => node(
+                        Kind::ClassConst,
+                        0,
+                        line,
+                        vec![("class", class), ("const", self.member_name(access.member))],
+                    ),
+                }
+            }
+            ExprKind::ClassConstAccessDynamic { class, member } => node(
+                Kind::ClassConst,
+                0,
+                line,
+                vec![
+                    ("class", self.class_ref(class)),
+                    ("const", self.expr(member)),
+                ],
+            ),
+            ExprKind::Closure(closure) => {
+                let mut flags = 0;
+                if closure.is_static {
+                    flags |= flags::MODIFIER_STATIC;
+                }
+                if closure.by_ref {
+                    flags |= flags::FUNC_RETURNS_REF;
+                }
+                let uses = if closure.use_vars.is_empty() {
+                    Value::Null
+                } else {
+                    let vars = closure
+                        .use_vars
+                        .iter()
+                        .map(|var| {
+                            let flags = if var.by_ref {
+                                flags::CLOSURE_USE_REF
+                            } else {
+                                0
+                            };
+                            let name = var.name.trim_start_matches('$');
+                            node(
+                                Kind::ClosureVar,
+                                flags,
+                                self.line(var.span),
+                                vec![("name", name.into())],
+                            )
+                        })
+                        .collect();
+                    list(Kind::ClosureUses, 0, line, vars)
+                };
+                self.function(
+                    Kind::Closure,
+                    flags,
+                    expr.span,
+                    "{closure}".into(),
+                    None,
+                    &closure.params,
+                    Some(uses),
+                    Some(&closure.body),
+                    closure.return_type.as_ref(),
+                    &closure.attributes,
+                )
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                let mut flags = 0;
+                if arrow.is_static {
+                    flags |= flags::MODIFIER_STATIC;
+                }
+                if arrow.by_ref {
+                    flags |= flags::FUNC_RETURNS_REF;
+                }
+                let params = self.params(&arrow.params, line);
+                let outer = std::mem::replace(&mut self.generator, false);
+                let body = node(
+                    Kind::Return,
+                    0,
+                    self.line(arrow.body.span),
+                    vec![("expr", self.expr(arrow.body))],
+                );
+                if std::mem::replace(&mut self.generator, outer) {
+                    flags |= flags::FUNC_GENERATOR;
+                }
+                let children = vec![
+                    ("name", "{closure}".into()),
+                    ("docComment", Value::Null),
+                    ("params", params),
+                    ("stmts", body),
+                    ("returnType", self.type_hint(arrow.return_type.as_ref())),
+                    ("attributes", self.attributes(&arrow.attributes)),
+                ];
+                self.decl(Kind::ArrowFunc, flags, expr.span, children)
+            }
+            ExprKind::Match(m) => {
+                let arms = m
+                    .arms
+                    .iter()
+                    .map(|arm| {
+                        let cond = arm
+                            .conditions
+                            .as_ref()
+                            .map_or(Value::Null, |conditions| self.expr_list(conditions));
+                        node(
+                            Kind::MatchArm,
+                            0,
+                            self.line(arm.span),
+                            vec![("cond", cond), ("expr", self.expr(&arm.body))],
+                        )
+                    })
+                    .collect();
+                node(
+                    Kind::Match,
+                    0,
+                    line,
+                    vec![
+                        ("cond", self.expr(m.subject)),
+                        ("stmts", list(Kind::MatchArmList, 0, line, arms)),
+                    ],
+                )
+            }
+            ExprKind::ThrowExpr(inner) => {
+                node(Kind::Throw, 0, line, vec![("expr", self.expr(inner))])
+            }
+            ExprKind::Yield(y) => {
+                self.generator = true;
+                if y.is_from {
+                    node(
+                        Kind::YieldFrom,
+                        0,
+                        line,
+                        vec![("expr", self.opt_expr(y.value))],
+                    )
+                } else {
+                    node(
+                        Kind::Yield,
+                        0,
+                        line,
+                        vec![
+                            ("value", self.opt_expr(y.value)),
+                            ("key", self.opt_expr(y.key)),
+                        ],
+                    )
+                }
+            }
+            ExprKind::AnonymousClass(decl) => self.class(decl, expr.span, flags::CLASS_ANONYMOUS),
+            ExprKind::CallableCreate(create) => {
+                let convert = node(Kind::CallableConvert, 0, line, Vec::new());
+                match &create.kind {
+                    CallableCreateKind::Function(name) => node(
+                        Kind::Call,
+                        0,
+                        line,
+                        vec![("expr", self.expr(name)), ("args", convert)],
+                    ),
+                    CallableCreateKind::Method { object, method } => node(
+                        Kind::MethodCall,
+                        0,
+                        line,
+                        vec![
+                            ("expr", self.expr(object)),
+                            ("method", self.member_name(method)),
+                            ("args", convert),
+                        ],
+                    ),
+                    CallableCreateKind::NullsafeMethod { object, method } => node(
+                        Kind::NullsafeMethodCall,
+                        0,
+                        line,
+                        vec![
+                            ("expr", self.expr(object)),
+                            ("method", self.member_name(method)),
+                            ("args", convert),
+                        ],
+                    ),
+                    CallableCreateKind::StaticMethod { class, method } => node(
+                        Kind::StaticCall,
+                        0,
+                        line,
+                        vec![
+                            ("class", self.class_ref(class)),
+                            ("method", self.member_name(method)),
+                            ("args", convert),
+                        ],
+                    ),
+                }
+            }
+            ExprKind::Omit | ExprKind::Error => Value::Null,
+        }
+    }
+}
+
+/// The members of a class-like declaration.
+enum ClassBody<'a> {
+    Class(&'a [ClassMember<'a, 'a>]),
+    Enum(&'a [EnumMember<'a, 'a>]),
+}
@@ -187,6 +187,14 @@ pub trait Fold<'src> {
     fn fold_name<'new>(&mut self, arena: &'new Bump, name: &Name<'_, 'src>) -> Name<'new, 'src> {
         fold_name(self, arena, name)
     }
+
+    fn fold_class_ref<'new>(
+        &mut self,
+        arena: &'new Bump,
+        class: &ClassRef<'_, 'src>,
+    ) -> ClassRef<'new, 'src> {
+        fold_class_ref(self, arena, class)
+    }
 }
 
 // =============================================================================
@@ -201,6 +209,8 @@ pub fn fold_program<'new, 'src, F: Fold<'src> + ?Sized>(
     Program {
         stmts: fold_stmts(folder, arena, &program.stmts),
         span: program.span,
+        ends_in_html: program.ends_in_html,
+        close_tag: program.close_tag,
     }
 }
 
@@ -213,7 +223,11 @@ pub fn fold_stmt<'new, 'src, F: Fold<'src> + ?Sized>(
         StmtKind::Expression(expr) => {
             StmtKind::Expression(arena.alloc(folder.fold_expr(arena, expr)))
         }
-        StmtKind::Echo(exprs) => StmtKind::Echo(fold_exprs(folder, arena, exprs)),
+        StmtKind::Echo(echo) => StmtKind::Echo(EchoStmt {
+            exprs: fold_exprs(folder, arena, &echo.exprs),
+            open_tag: echo.open_tag,
+            close_tag: echo.close_tag,
+        }),
         StmtKind::Return(expr) => {
             StmtKind::Return(expr.map(|e| &*arena.alloc(folder.fold_expr(arena, e))))
         }
@@ -252,6 +266,9 @@ pub fn fold_stmt<'new, 'src, F: Fold<'src> + ?Sized>(
                 init: fold_exprs(folder, arena, &f.init),
                 condition: fold_exprs(folder, arena, &f.condition),
                 update: fold_exprs(folder, arena, &f.update),
+                init_span: f.init_span,
+                condition_span: f.condition_span,
+                update_span: f.update_span,
                 body: arena.alloc(folder.fold_stmt(arena, f.body)),
                 uses_alternative: f.uses_alternative,
             });
@@ -356,6 +373,7 @@ pub fn fold_stmt<'new, 'src, F: Fold<'src> + ?Sized>(
                     alias: item.alias,
                     kind: item.kind,
                     span: item.span,
+                    comma: item.comma,
                 });
             }
             let new_use = arena.alloc(UseDecl {
@@ -388,9 +406,9 @@ pub fn fold_stmt<'new, 'src, F: Fold<'src> + ?Sized>(
             }
             StmtKind::StaticVar(new_vars)
         }
-        StmtKind::HaltCompiler(s) => StmtKind::HaltCompiler(s),
+        StmtKind::HaltCompiler(data) => StmtKind::HaltCompiler(*data),
         StmtKind::Nop => StmtKind::Nop,
-        StmtKind::InlineHtml(s) => StmtKind::InlineHtml(s),
+        StmtKind::InlineHtml(s) => StmtKind::InlineHtml(*s),
         StmtKind::Error => StmtKind::Error,
     };
     Stmt {
@@ -407,16 +425,21 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
     let kind = match &expr.kind {
         ExprKind::Int(n) => ExprKind::Int(*n),
         ExprKind::Float(f) => ExprKind::Float(*f),
-        ExprKind::String(s) => ExprKind::String(arena.alloc_str(s)),
+        ExprKind::String { value, raw } => ExprKind::String {
+            value: arena.alloc_str(value),
+            raw,
+        },
         ExprKind::InterpolatedString(parts) => {
             ExprKind::InterpolatedString(fold_string_parts(folder, arena, parts))
         }
-        ExprKind::Heredoc { label, parts } => ExprKind::Heredoc {
+        ExprKind::Heredoc { label, raw, parts } => ExprKind::Heredoc {
             label,
+            raw,
             parts: fold_string_parts(folder, arena, parts),
         },
-        ExprKind::Nowdoc { label, value } => ExprKind::Nowdoc {
+        ExprKind::Nowdoc { label, raw, value } => ExprKind::Nowdoc {
             label,
+            raw,
             value: arena.alloc_str(value),
         },
         ExprKind::ShellExec(parts) => ExprKind::ShellExec(fold_string_parts(folder, arena, parts)),
@@ -427,6 +450,8 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
             ExprKind::VariableVariable(arena.alloc(folder.fold_expr(arena, inner)))
         }
         ExprKind::Identifier(name) => ExprKind::Identifier(fold_name_str(*name, arena)),
+        ExprKind::Name(name) => ExprKind::Name(folder.fold_name(arena, name)),
+        ExprKind::ConstFetch(name) => ExprKind::ConstFetch(folder.fold_name(arena, name)),
         ExprKind::Assign(assign) => ExprKind::Assign(AssignExpr {
             target: arena.alloc(folder.fold_expr(arena, assign.target)),
             op: assign.op,
@@ -461,33 +486,33 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
             name: arena.alloc(folder.fold_expr(arena, call.name)),
             args: fold_args(folder, arena, &call.args),
         }),
-        ExprKind::Array(elements) => {
-            let mut new_elements = ArenaVec::with_capacity_in(elements.len(), arena);
-            for elem in elements.iter() {
-                new_elements.push(ArrayElement {
-                    key: elem.key.as_ref().map(|k| folder.fold_expr(arena, k)),
-                    value: folder.fold_expr(arena, &elem.value),
-                    unpack: elem.unpack,
-                    by_ref: elem.by_ref,
-                    span: elem.span,
-                });
-            }
-            ExprKind::Array(new_elements)
-        }
+        ExprKind::Array(elements) => ExprKind::Array(fold_array_elements(folder, arena, elements)),
+        ExprKind::List(list) => ExprKind::List(ListExpr {
+            elements: fold_array_elements(folder, arena, &list.elements),
+            uses_list: list.uses_list,
+        }),
         ExprKind::ArrayAccess(access) => ExprKind::ArrayAccess(ArrayAccessExpr {
             array: arena.alloc(folder.fold_expr(arena, access.array)),
             index: access
                 .index
                 .map(|i| &*arena.alloc(folder.fold_expr(arena, i))),
         }),
-        ExprKind::Print(e) => ExprKind::Print(arena.alloc(folder.fold_expr(arena, e))),
+        ExprKind::Print(print) => ExprKind::Print(PrintExpr {
+            expr: arena.alloc(folder.fold_expr(arena, print.expr)),
+            keyword_span: print.keyword_span,
+        }),
         ExprKind::Parenthesized(e) => {
             ExprKind::Parenthesized(arena.alloc(folder.fold_expr(arena, e)))
         }
         ExprKind::Cast(kind, e) => ExprKind::Cast(*kind, arena.alloc(folder.fold_expr(arena, e))),
-        ExprKind::ErrorSuppress(e) => {
-            ExprKind::ErrorSuppress(arena.alloc(folder.fold_expr(arena, e)))
-        }
+        ExprKind::Instanceof(instanceof) => ExprKind::Instanceof(InstanceofExpr {
+            expr: arena.alloc(folder.fold_expr(arena, instanceof.expr)),
+            class: folder.fold_class_ref(arena, &instanceof.class),
+        }),
+        ExprKind::ErrorSuppress(suppress) => ExprKind::ErrorSuppress(ErrorSuppressExpr {
+            expr: arena.alloc(folder.fold_expr(arena, suppress.expr)),
+            operator_span: suppress.operator_span,
+        }),
         ExprKind::Isset(exprs) => ExprKind::Isset(fold_exprs(folder, arena, exprs)),
         ExprKind::Empty(e) => ExprKind::Empty(arena.alloc(folder.fold_expr(arena, e))),
         ExprKind::Include(kind, e) => {
@@ -496,13 +521,16 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
         ExprKind::Eval(e) => ExprKind::Eval(arena.alloc(folder.fold_expr(arena, e))),
         ExprKind::Exit(e) => ExprKind::Exit(e.map(|e| &*arena.alloc(folder.fold_expr(arena, e)))),
         ExprKind::MagicConst(k) => ExprKind::MagicConst(*k),
-        ExprKind::Clone(e) => ExprKind::Clone(arena.alloc(folder.fold_expr(arena, e))),
+        ExprKind::Clone(clone) => ExprKind::Clone(CloneExpr {
+            object: arena.alloc(folder.fold_expr(arena, clone.object)),
+            keyword_span: clone.keyword_span,
+        }),
         ExprKind::CloneWith(obj, overrides) => ExprKind::CloneWith(
             arena.alloc(folder.fold_expr(arena, obj)),
             arena.alloc(folder.fold_expr(arena, overrides)),
         ),
         ExprKind::New(new_expr) => ExprKind::New(NewExpr {
-            class: arena.alloc(folder.fold_expr(arena, new_expr.class)),
+            class: folder.fold_class_ref(arena, &new_expr.class),
             args: fold_args(folder, arena, &new_expr.args),
         }),
         ExprKind::PropertyAccess(access) => ExprKind::PropertyAccess(PropertyAccessExpr {
@@ -529,35 +557,35 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
         }
         ExprKind::StaticPropertyAccess(access) => {
             ExprKind::StaticPropertyAccess(StaticAccessExpr {
-                class: arena.alloc(folder.fold_expr(arena, access.class)),
+                class: folder.fold_class_ref(arena, &access.class),
                 member: arena.alloc(folder.fold_expr(arena, access.member)),
             })
         }
         ExprKind::StaticMethodCall(call) => {
             ExprKind::StaticMethodCall(arena.alloc(StaticMethodCallExpr {
-                class: arena.alloc(folder.fold_expr(arena, call.class)),
+                class: folder.fold_class_ref(arena, &call.class),
                 method: arena.alloc(folder.fold_expr(arena, call.method)),
                 args: fold_args(folder, arena, &call.args),
             }))
         }
         ExprKind::StaticDynMethodCall(call) => {
             ExprKind::StaticDynMethodCall(arena.alloc(StaticDynMethodCallExpr {
-                class: arena.alloc(folder.fold_expr(arena, call.class)),
+                class: folder.fold_class_ref(arena, &call.class),
                 method: arena.alloc(folder.fold_expr(arena, call.method)),
                 args: fold_args(folder, arena, &call.args),
             }))
         }
         ExprKind::ClassConstAccess(access) => ExprKind::ClassConstAccess(StaticAccessExpr {
-            class: arena.alloc(folder.fold_expr(arena, access.class)),
+            class: folder.fold_class_ref(arena, &access.class),
             member: arena.alloc(folder.fold_expr(arena, access.member)),
         }),
         ExprKind::ClassConstAccessDynamic { class, member } => ExprKind::ClassConstAccessDynamic {
-            class: arena.alloc(folder.fold_expr(arena, class)),
+            class: folder.fold_class_ref(arena, class),
             member: arena.alloc(folder.fold_expr(arena, member)),
         },
         ExprKind::StaticPropertyAccessDynamic { class, member } => {
             ExprKind::StaticPropertyAccessDynamic {
-                class: arena.alloc(folder.fold_expr(arena, class)),
+                class: folder.fold_class_ref(arena, class),
                 member: arena.alloc(folder.fold_expr(arena, member)),
             }
         }
@@ -630,7 +658,7 @@ pub fn fold_expr<'new, 'src, F: Fold<'src> + ?Sized>(
                 }
                 CallableCreateKind::StaticMethod { class, method } => {
                     CallableCreateKind::StaticMethod {
-                        class: arena.alloc(folder.fold_expr(arena, class)),
+                        class: folder.fold_class_ref(arena, class),
                         method: arena.alloc(folder.fold_expr(arena, method)),
                     }
                 }
@@ -667,6 +695,7 @@ pub fn fold_param<'new, 'src, F: Fold<'src> + ?Sized>(
         attributes: fold_attrs(folder, arena, &param.attributes),
         hooks: fold_hooks(folder, arena, &param.hooks),
         span: param.span,
+        comma: param.comma,
     }
 }
 
@@ -677,10 +706,12 @@ pub fn fold_arg<'new, 'src, F: Fold<'src> + ?Sized>(
 ) -> Arg<'new, 'src> {
     Arg {
         name: arg.name.as_ref().map(|n| folder.fold_name(arena, n)),
+        colon: arg.colon,
         value: folder.fold_expr(arena, &arg.value),
         unpack: arg.unpack,
         by_ref: arg.by_ref,
         span: arg.span,
+        comma: arg.comma,
     }
 }
 
@@ -809,7 +840,7 @@ pub fn fold_catch_clause<'new, 'src, F: Fold<'src> + ?Sized>(
 ) -> CatchClause<'new, 'src> {
     let mut types = ArenaVec::with_capacity_in(catch.types.len(), arena);
     for ty in catch.types.iter() {
-        types.push(folder.fold_name(arena, ty));
+        types.push(folder.fold_class_ref(arena, ty));
     }
     CatchClause {
         types,
@@ -835,6 +866,7 @@ pub fn fold_match_arm<'new, 'src, F: Fold<'src> + ?Sized>(
         conditions,
         body: folder.fold_expr(arena, &arm.body),
         span: arm.span,
+        comma: arm.comma,
     }
 }
 
@@ -896,6 +928,20 @@ pub fn fold_trait_adaptation<'new, 'src, F: Fold<'src> + ?Sized>(
     }
 }
 
+pub fn fold_class_ref<'new, 'src, F: Fold<'src> + ?Sized>(
+    folder: &mut F,
+    arena: &'new Bump,
+    class: &ClassRef<'_, 'src>,
+) -> ClassRef<'new, 'src> {
+    match class {
+        ClassRef::Name(name) => ClassRef::Name(arena.alloc(folder.fold_name(arena, name))),
+        ClassRef::SelfKw(span) => ClassRef::SelfKw(*span),
+        ClassRef::ParentKw(span) => ClassRef::ParentKw(*span),
+        ClassRef::StaticKw(span) => ClassRef::StaticKw(*span),
+        ClassRef::Expr(expr) => ClassRef::Expr(arena.alloc(folder.fold_expr(arena, expr))),
+    }
+}
+
 pub fn fold_name<'new, 'src, F: Fold<'src> + ?Sized>(
     _folder: &mut F,
     arena: &'new Bump,
@@ -1122,6 +1168,25 @@ fn fold_exprs<'new, 'src, F: Fold<'src> + ?Sized>(
     vec
 }
 
+fn fold_array_elements<'new, 'src, F: Fold<'src> + ?Sized>(
+    folder: &mut F,
+    arena: &'new Bump,
+    elements: &[ArrayElement<'_, 'src>],
+) -> ArenaVec<'new, ArrayElement<'new, 'src>> {
+    let mut vec = ArenaVec::with_capacity_in(elements.len(), arena);
+    for elem in elements {
+        vec.push(ArrayElement {
+            key: elem.key.as_ref().map(|k| folder.fold_expr(arena, k)),
+            value: folder.fold_expr(arena, &elem.value),
+            unpack: elem.unpack,
+            by_ref: elem.by_ref,
+            span: elem.span,
+            comma: elem.comma,
+        });
+    }
+    vec
+}
+
 fn fold_args<'new, 'src, F: Fold<'src> + ?Sized>(
     folder: &mut F,
     arena: &'new Bump,
@@ -1178,7 +1243,10 @@ fn fold_string_parts<'new, 'src, F: Fold<'src> + ?Sized>(
     let mut vec = ArenaVec::with_capacity_in(parts.len(), arena);
     for part in parts {
         vec.push(match part {
-            StringPart::Literal(s) => StringPart::Literal(arena.alloc_str(s)),
+            StringPart::Literal { value, span } => StringPart::Literal {
+                value: arena.alloc_str(value),
+                span: *span,
+            },
             StringPart::Expr(e) => StringPart::Expr(folder.fold_expr(arena, e)),
         });
     }
@@ -3,7 +3,13 @@
 //! This crate provides:
 //! - The complete set of AST node types ([`ast`] module) — statements, expressions, declarations,
 //!   type hints, operators, and all other syntactic constructs for PHP 8.0–8.5.
-//! - A [`Span`] type for tracking byte-offset ranges back to the source text.
+//! - A [`Span`] type for tracking byte-offset ranges back to the source text, and a
+//!   [`source_map::SourceMap`] converting them to line/column positions.
+//! - An [`node::AnyNode`] view over any node type, for tools that walk the tree generically.
+//! - Operator [`precedence`], associativity, and fixity, keyword operators such as `print` and
+//!   `require` included.
+//! - An [`ext_ast`] conversion to the node kinds and flags of the `ext-ast` PHP extension, with
+//!   `ast\dump()`-style output.
 //! - A [`visitor`] module with the [`visitor::Visitor`] and [`visitor::ScopeVisitor`] traits for
 //!   depth-first AST traversal, plus free `walk_*` functions that drive the default recursion.
 //!
@@ -29,9 +35,14 @@
 //! ```
 
 pub mod ast;
+pub mod ext_ast;
 pub mod fold;
+pub mod node;
+pub mod precedence;
+pub mod source_map;
 pub mod span;
 pub mod visitor;
 
 pub use ast::*;
-pub use span::Span;
+pub use node::{AnyNode, NodeKind};
+pub use span::{Span, Spanned};
@@ -0,0 +1,581 @@
+//! Type-erased views over AST nodes.
+//!
+//! [`AnyNode`] wraps a reference to any node type and exposes the operations
+//! generic tooling needs — its [`NodeKind`], its [`Span`], and its direct
+//! children in source order — so dumpers, cursors, and selectors can walk the
+//! tree without matching on every concrete node type.
+//!
+//! ```
+//! use php_ast::node::AnyNode;
+//! use php_ast::*;
+//!
+//! fn dump(node: AnyNode<'_, '_, '_>, depth: usize, out: &mut Vec<String>) {
+//!     out.push(format!("{}{} {:?}", "  ".repeat(depth), node.kind(), node.span().to_range()));
+//!     for child in node.children() {
+//!         dump(child, depth + 1, out);
+//!     }
+//! }
+//!
+//! // `$x = 1;`
+//! let arena = bumpalo::Bump::new();
+//! let target = arena.alloc(Expr { kind: ExprKind::Variable(NameStr::__src("x")), span: Span::new(6, 8) });
+//! let value = arena.alloc(Expr { kind: ExprKind::Int(1), span: Span::new(11, 12) });
+//! let assign = arena.alloc(Expr {
+//!     kind: ExprKind::Assign(AssignExpr { target, op: AssignOp::Assign, value, by_ref: false }),
+//!     span: Span::new(6, 12),
+//! });
+//! let mut stmts = ArenaVec::new_in(&arena);
+//! stmts.push(Stmt { kind: StmtKind::Expression(assign), span: Span::new(6, 13) });
+//! let program = Program { stmts, span: Span::new(0, 13), ends_in_html: false, close_tag: None };
+//!
+//! let mut lines = Vec::new();
+//! dump(AnyNode::from(&program), 0, &mut lines);
+//! assert_eq!(lines, [
+//!     "Program 0..13",
+//!     "  Stmt 6..13",
+//!     "    Expr 6..12",
+//!     "      Expr 6..8",
+//!     "      Expr 11..12",
+//! ]);
+//! ```
+
+use crate::ast::*;
+use crate::{Span, Spanned};
+
+macro_rules! any_node {
+    ($($variant:ident($ty:ty)),+ $(,)?) => {
+        /// The type of node an [`AnyNode`] refers to.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[non_exhaustive]
+        pub enum NodeKind {
+            $($variant,)+
+        }
+
+        impl NodeKind {
+            /// Every node kind, in declaration order.
+            pub const ALL: &'static [NodeKind] = &[$(NodeKind::$variant,)+];
+
+            /// The node type name, e.g. `"ClassMember"`.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(NodeKind::$variant => stringify!($variant),)+
+                }
+            }
+        }
+
+        /// A reference to any AST node.
+        #[derive(Debug, Clone, Copy)]
+        #[non_exhaustive]
+        pub enum AnyNode<'a, 'arena, 'src> {
+            $($variant(&'a $ty),)+
+        }
+
+        impl<'a, 'arena, 'src> AnyNode<'a, 'arena, 'src> {
+            /// The type of the referenced node.
+            pub fn kind(&self) -> NodeKind {
+                match self {
+                    $(AnyNode::$variant(_) => NodeKind::$variant,)+
+                }
+            }
+        }
+
+        impl Spanned for AnyNode<'_, '_, '_> {
+            fn span(&self) -> Span {
+                match self {
+                    $(AnyNode::$variant(node) => node.span(),)+
+                }
+            }
+        }
+
+        $(
+            impl<'a, 'arena, 'src> From<&'a $ty> for AnyNode<'a, 'arena, 'src> {
+                fn from(node: &'a $ty) -> Self {
+                    AnyNode::$variant(node)
+                }
+            }
+        )+
+    };
+}
+
+any_node!(
+    Program(Program<'arena, 'src>),
+    Stmt(Stmt<'arena, 'src>),
+    Expr(Expr<'arena, 'src>),
+    Param(Param<'arena, 'src>),
+    Arg(Arg<'arena, 'src>),
+    Attribute(Attribute<'arena, 'src>),
+    TypeHint(TypeHint<'arena, 'src>),
+    Name(Name<'arena, 'src>),
+    ClassMember(ClassMember<'arena, 'src>),
+    EnumMember(EnumMember<'arena, 'src>),
+    PropertyHook(PropertyHook<'arena, 'src>),
+    TraitAdaptation(TraitAdaptation<'arena, 'src>),
+    ArrayElement(ArrayElement<'arena, 'src>),
+    ClosureUseVar(ClosureUseVar<'src>),
+    MatchArm(MatchArm<'arena, 'src>),
+    ElseIfBranch(ElseIfBranch<'arena, 'src>),
+    SwitchCase(SwitchCase<'arena, 'src>),
+    CatchClause(CatchClause<'arena, 'src>),
+    UseItem(UseItem<'arena, 'src>),
+    ConstItem(ConstItem<'arena, 'src>),
+    StaticVar(StaticVar<'arena, 'src>),
+);
+
+impl std::fmt::Display for NodeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a, 'arena, 'src> AnyNode<'a, 'arena, 'src> {
+    /// The node's span.
+    pub fn span(&self) -> Span {
+        Spanned::span(self)
+    }
+
+    /// The node's direct children, in source order.
+    ///
+    /// Children are visited in the same order as the [`visitor`](crate::visitor)
+    /// walk functions, except that list items with their own node type
+    /// (array elements, `elseif` branches, `switch` cases, `use` items, `const`
+    /// items, and `static` variables) appear as nodes rather than being
+    /// flattened into their parent. `use` of a trait inside a class or enum
+    /// body has no span, so its trait names and adaptations are children of
+    /// the enclosing [`NodeKind::ClassMember`] or [`NodeKind::EnumMember`].
+    pub fn children(&self) -> Vec<AnyNode<'a, 'arena, 'src>> {
+        let mut out = Children(Vec::new());
+        match *self {
+            AnyNode::Program(program) => out.stmts(&program.stmts),
+            AnyNode::Stmt(stmt) => out.stmt(stmt),
+            AnyNode::Expr(expr) => out.expr(expr),
+            AnyNode::Param(param) => {
+                out.attributes(&param.attributes);
+                out.opt_type_hint(&param.type_hint);
+                out.opt_expr(&param.default);
+                out.extend(param.hooks.iter().map(AnyNode::PropertyHook));
+            }
+            AnyNode::Arg(arg) => out.push(AnyNode::Expr(&arg.value)),
+            AnyNode::Attribute(attribute) => {
+                out.push(AnyNode::Name(&attribute.name));
+                out.args(&attribute.args);
+            }
+            AnyNode::TypeHint(hint) => match &hint.kind {
+                TypeHintKind::Nullable(inner) => out.push(AnyNode::TypeHint(inner)),
+                TypeHintKind::Union(types) | TypeHintKind::Intersection(types) => {
+                    out.extend(types.iter().map(AnyNode::TypeHint))
+                }
+                TypeHintKind::Named(name) => out.push(AnyNode::Name(name)),
+                TypeHintKind::Keyword(..) => {}
+            },
+            AnyNode::Name(_) | AnyNode::ClosureUseVar(_) => {}
+            AnyNode::ClassMember(member) => match &member.kind {
+                ClassMemberKind::Property(prop) => out.property(prop),
+                ClassMemberKind::Method(method) => out.method(method),
+                ClassMemberKind::ClassConst(cc) => out.class_const(cc),
+                ClassMemberKind::TraitUse(trait_use) => out.trait_use(trait_use),
+            },
+            AnyNode::EnumMember(member) => match &member.kind {
+                EnumMemberKind::Case(case) => {
+                    out.attributes(&case.attributes);
+                    out.opt_expr(&case.value);
+                }
+                EnumMemberKind::Method(method) => out.method(method),
+                EnumMemberKind::ClassConst(cc) => out.class_const(cc),
+                EnumMemberKind::TraitUse(trait_use) => out.trait_use(trait_use),
+            },
+            AnyNode::PropertyHook(hook) => {
+                out.attributes(&hook.attributes);
+                out.params(&hook.params);
+                match &hook.body {
+                    PropertyHookBody::Block(stmts) => out.stmts(stmts),
+                    PropertyHookBody::Expression(expr) => out.push(AnyNode::Expr(expr)),
+                    PropertyHookBody::Abstract => {}
+                }
+            }
+            AnyNode::TraitAdaptation(adaptation) => match &adaptation.kind {
+                TraitAdaptationKind::Precedence {
+                    trait_name,
+                    method,
+                    insteadof,
+                } => {
+                    out.push(AnyNode::Name(trait_name));
+                    out.push(AnyNode::Name(method));
+                    out.names(insteadof);
+                }
+                TraitAdaptationKind::Alias {
+                    trait_name,
+                    method,
+                    new_name,
+                    ..
+                } => {
+                    out.extend(trait_name.iter().map(AnyNode::Name));
+                    out.push(AnyNode::Name(method));
+                    out.extend(new_name.iter().map(AnyNode::Name));
+                }
+            },
+            AnyNode::ArrayElement(elem) => {
+                out.opt_expr(&elem.key);
+                out.push(AnyNode::Expr(&elem.value));
+            }
+            AnyNode::MatchArm(arm) => {
+                if let Some(conditions) = &arm.conditions {
+                    out.exprs(conditions);
+                }
+                out.push(AnyNode::Expr(&arm.body));
+            }
+            AnyNode::ElseIfBranch(branch) => {
+                out.push(AnyNode::Expr(&branch.condition));
+                out.push(AnyNode::Stmt(&branch.body));
+            }
+            AnyNode::SwitchCase(case) => {
+                out.opt_expr(&case.value);
+                out.stmts(&case.body);
+            }
+            AnyNode::CatchClause(catch) => {
+                for ty in catch.types.iter() {
+                    out.class_ref(ty);
+                }
+                out.stmts(&catch.body);
+            }
+            AnyNode::UseItem(item) => out.push(AnyNode::Name(&item.name)),
+            AnyNode::ConstItem(item) => {
+                out.attributes(&item.attributes);
+                out.push(AnyNode::Expr(&item.value));
+            }
+            AnyNode::StaticVar(var) => out.opt_expr(&var.default),
+        }
+        out.0
+    }
+}
+
+/// Accumulates the children of one node.
+struct Children<'a, 'arena, 'src>(Vec<AnyNode<'a, 'arena, 'src>>);
+
+impl<'a, 'arena, 'src> Children<'a, 'arena, 'src> {
+    fn push(&mut self, node: AnyNode<'a, 'arena, 'src>) {
+        self.0.push(node);
+    }
+
+    fn extend(&mut self, nodes: impl IntoIterator<Item = AnyNode<'a, 'arena, 'src>>) {
+        self.0.extend(nodes);
+    }
+
+    fn stmts(&mut self, stmts: &'a [Stmt<'arena, 'src>]) {
+        self.extend(stmts.iter().map(AnyNode::Stmt));
+    }
+
+    fn exprs(&mut self, exprs: &'a [Expr<'arena, 'src>]) {
+        self.extend(exprs.iter().map(AnyNode::Expr));
+    }
+
+    fn opt_expr(&mut self, expr: &'a Option<Expr<'arena, 'src>>) {
+        self.extend(expr.iter().map(AnyNode::Expr));
+    }
+
+    fn args(&mut self, args: &'a [Arg<'arena, 'src>]) {
+        self.extend(args.iter().map(AnyNode::Arg));
+    }
+
+    fn params(&mut self, params: &'a [Param<'arena, 'src>]) {
+        self.extend(params.iter().map(AnyNode::Param));
+    }
+
+    fn names(&mut self, names: &'a [Name<'arena, 'src>]) {
+        self.extend(names.iter().map(AnyNode::Name));
+    }
+
+    /// A class operand is a name node or an expression node; the `self`,
+    /// `parent`, and `static` keywords have no children to visit.
+    fn class_ref(&mut self, class: &'a ClassRef<'arena, 'src>) {
+        match class {
+            ClassRef::Name(name) => self.push(AnyNode::Name(name)),
+            ClassRef::Expr(expr) => self.push(AnyNode::Expr(expr)),
+            ClassRef::SelfKw(_) | ClassRef::ParentKw(_) | ClassRef::StaticKw(_) => {}
+        }
+    }
+
+    fn attributes(&mut self, attributes: &'a [Attribute<'arena, 'src>]) {
+        self.extend(attributes.iter().map(AnyNode::Attribute));
+    }
+
+    fn opt_type_hint(&mut self, hint: &'a Option<TypeHint<'arena, 'src>>) {
+        self.extend(hint.iter().map(AnyNode::TypeHint));
+    }
+
+    fn class_members(&mut self, members: &'a [ClassMember<'arena, 'src>]) {
+        self.extend(members.iter().map(AnyNode::ClassMember));
+    }
+
+    fn function_like(
+        &mut self,
+        attributes: &'a [Attribute<'arena, 'src>],
+        params: &'a [Param<'arena, 'src>],
+        return_type: &'a Option<TypeHint<'arena, 'src>>,
+    ) {
+        self.attributes(attributes);
+        self.params(params);
+        self.opt_type_hint(return_type);
+    }
+
+    fn method(&mut self, method: &'a MethodDecl<'arena, 'src>) {
+        self.function_like(&method.attributes, &method.params, &method.return_type);
+        if let Some(body) = &method.body {
+            self.stmts(body);
+        }
+    }
+
+    fn class_const(&mut self, cc: &'a ClassConstDecl<'arena, 'src>) {
+        self.attributes(&cc.attributes);
+        if let Some(hint) = cc.type_hint {
+            self.push(AnyNode::TypeHint(hint));
+        }
+        self.push(AnyNode::Expr(&cc.value));
+    }
+
+    fn property(&mut self, prop: &'a PropertyDecl<'arena, 'src>) {
+        self.attributes(&prop.attributes);
+        self.opt_type_hint(&prop.type_hint);
+        self.opt_expr(&prop.default);
+        self.extend(prop.hooks.iter().map(AnyNode::PropertyHook));
+    }
+
+    fn trait_use(&mut self, trait_use: &'a TraitUseDecl<'arena, 'src>) {
+        self.names(&trait_use.traits);
+        self.extend(trait_use.adaptations.iter().map(AnyNode::TraitAdaptation));
+    }
+
+    fn stmt(&mut self, stmt: &'a Stmt<'arena, 'src>) {
+        match &stmt.kind {
+            StmtKind::Expression(expr) | StmtKind::Throw(expr) => self.push(AnyNode::Expr(expr)),
+            StmtKind::Echo(echo) => self.exprs(&echo.exprs),
+            StmtKind::Unset(exprs) | StmtKind::Global(exprs) => self.exprs(exprs),
+            StmtKind::Return(expr) | StmtKind::Break(expr) | StmtKind::Continue(expr) => {
+                self.extend(expr.iter().map(|e| AnyNode::Expr(e)))
+            }
+            StmtKind::Block(stmts) => self.stmts(stmts),
+            StmtKind::If(if_stmt) => {
+                self.push(AnyNode::Expr(&if_stmt.condition));
+                self.push(AnyNode::Stmt(if_stmt.then_branch));
+                self.extend(if_stmt.elseif_branches.iter().map(AnyNode::ElseIfBranch));
+                self.extend(if_stmt.else_branch.iter().map(|s| AnyNode::Stmt(s)));
+            }
+            StmtKind::While(while_stmt) => {
+                self.push(AnyNode::Expr(&while_stmt.condition));
+                self.push(AnyNode::Stmt(while_stmt.body));
+            }
+            StmtKind::For(for_stmt) => {
+                self.exprs(&for_stmt.init);
+                self.exprs(&for_stmt.condition);
+                self.exprs(&for_stmt.update);
+                self.push(AnyNode::Stmt(for_stmt.body));
+            }
+            StmtKind::Foreach(foreach_stmt) => {
+                self.push(AnyNode::Expr(&foreach_stmt.expr));
+                self.opt_expr(&foreach_stmt.key);
+                self.push(AnyNode::Expr(&foreach_stmt.value));
+                self.push(AnyNode::Stmt(foreach_stmt.body));
+            }
+            StmtKind::DoWhile(do_while) => {
+                self.push(AnyNode::Stmt(do_while.body));
+                self.push(AnyNode::Expr(&do_while.condition));
+            }
+            StmtKind::Function(func) => {
+                self.function_like(&func.attributes, &func.params, &func.return_type);
+                self.stmts(&func.body);
+            }
+            StmtKind::Switch(switch_stmt) => {
+                self.push(AnyNode::Expr(&switch_stmt.expr));
+                self.extend(switch_stmt.cases.iter().map(AnyNode::SwitchCase));
+            }
+            StmtKind::TryCatch(tc) => {
+                self.stmts(&tc.body);
+                self.extend(tc.catches.iter().map(AnyNode::CatchClause));
+                if let Some(finally) = &tc.finally {
+                    self.stmts(finally);
+                }
+            }
+            StmtKind::Declare(decl) => {
+                self.extend(decl.directives.iter().map(|(_, expr)| AnyNode::Expr(expr)));
+                if let Some(body) = decl.body {
+                    self.push(AnyNode::Stmt(body));
+                }
+            }
+            StmtKind::Class(class) => {
+                self.attributes(&class.attributes);
+                self.extend(class.extends.iter().map(AnyNode::Name));
+                self.names(&class.implements);
+                self.class_members(&class.members);
+            }
+            StmtKind::Interface(iface) => {
+                self.attributes(&iface.attributes);
+                self.names(&iface.extends);
+                self.class_members(&iface.members);
+            }
+            StmtKind::Trait(trait_decl) => {
+                self.attributes(&trait_decl.attributes);
+                self.class_members(&trait_decl.members);
+            }
+            StmtKind::Enum(enum_decl) => {
+                self.attributes(&enum_decl.attributes);
+                self.extend(enum_decl.scalar_type.iter().map(AnyNode::Name));
+                self.names(&enum_decl.implements);
+                self.extend(enum_decl.members.iter().map(AnyNode::EnumMember));
+            }
+            StmtKind::Namespace(ns) => {
+                self.extend(ns.name.iter().map(AnyNode::Name));
+                if let NamespaceBody::Braced(stmts) = &ns.body {
+                    self.stmts(stmts);
+                }
+            }
+            StmtKind::Const(items) => self.extend(items.iter().map(AnyNode::ConstItem)),
+            StmtKind::StaticVar(vars) => self.extend(vars.iter().map(AnyNode::StaticVar)),
+            StmtKind::Use(decl) => self.extend(decl.uses.iter().map(AnyNode::UseItem)),
+            StmtKind::Goto(_)
+            | StmtKind::Label(_)
+            | StmtKind::Nop
+            | StmtKind::InlineHtml(_)
+            | StmtKind::HaltCompiler(_)
+            | StmtKind::Error => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &'a Expr<'arena, 'src>) {
+        match &expr.kind {
+            ExprKind::Assign(assign) => {
+                self.push(AnyNode::Expr(assign.target));
+                self.push(AnyNode::Expr(assign.value));
+            }
+            ExprKind::Binary(binary) => {
+                self.push(AnyNode::Expr(binary.left));
+                self.push(AnyNode::Expr(binary.right));
+            }
+            ExprKind::UnaryPrefix(unary) => self.push(AnyNode::Expr(unary.operand)),
+            ExprKind::UnaryPostfix(unary) => self.push(AnyNode::Expr(unary.operand)),
+            ExprKind::Ternary(ternary) => {
+                self.push(AnyNode::Expr(ternary.condition));
+                self.extend(ternary.then_expr.iter().map(|e| AnyNode::Expr(e)));
+                self.push(AnyNode::Expr(ternary.else_expr));
+            }
+            ExprKind::NullCoalesce(nc) => {
+                self.push(AnyNode::Expr(nc.left));
+                self.push(AnyNode::Expr(nc.right));
+            }
+            ExprKind::FunctionCall(call) => {
+                self.push(AnyNode::Expr(call.name));
+                self.args(&call.args);
+            }
+            ExprKind::Array(elements) | ExprKind::List(ListExpr { elements, .. }) => {
+                self.extend(elements.iter().map(AnyNode::ArrayElement));
+            }
+            ExprKind::ArrayAccess(access) => {
+                self.push(AnyNode::Expr(access.array));
+                self.extend(access.index.iter().map(|e| AnyNode::Expr(e)));
+            }
+            ExprKind::Print(PrintExpr { expr: inner, .. })
+            | ExprKind::ErrorSuppress(ErrorSuppressExpr { expr: inner, .. })
+            | ExprKind::Clone(CloneExpr { object: inner, .. })
+            | ExprKind::Parenthesized(inner)
+            | ExprKind::Cast(_, inner)
+            | ExprKind::Empty(inner)
+            | ExprKind::Include(_, inner)
+            | ExprKind::Eval(inner)
+            | ExprKind::ThrowExpr(inner)
+            | ExprKind::VariableVariable(inner) => self.push(AnyNode::Expr(inner)),
+            ExprKind::Isset(exprs) => self.exprs(exprs),
+            ExprKind::Exit(inner) => self.extend(inner.iter().map(|e| AnyNode::Expr(e))),
+            ExprKind::CloneWith(object, overrides) => {
+                self.push(AnyNode::Expr(object));
+                self.push(AnyNode::Expr(overrides));
+            }
+            ExprKind::Instanceof(instanceof) => {
+                self.push(AnyNode::Expr(instanceof.expr));
+                self.class_ref(&instanceof.class);
+            }
+            ExprKind::New(new_expr) => {
+                self.class_ref(&new_expr.class);
+                self.args(&new_expr.args);
+            }
+            ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+                self.push(AnyNode::Expr(access.object));
+                self.push(AnyNode::Expr(access.property));
+            }
+            ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => {
+                self.push(AnyNode::Expr(call.object));
+                self.push(AnyNode::Expr(call.method));
+                self.args(&call.args);
+            }
+            ExprKind::StaticPropertyAccess(access) | ExprKind::ClassConstAccess(access) => {
+                self.class_ref(&access.class);
+                self.push(AnyNode::Expr(access.member));
+            }
+            ExprKind::ClassConstAccessDynamic { class, member }
+            | ExprKind::StaticPropertyAccessDynamic { class, member } => {
+                self.class_ref(class);
+                self.push(AnyNode::Expr(member));
+            }
+            ExprKind::StaticMethodCall(call) => {
+                self.class_ref(&call.class);
+                self.push(AnyNode::Expr(call.method));
+                self.args(&call.args);
+            }
+            ExprKind::StaticDynMethodCall(call) => {
+                self.class_ref(&call.class);
+                self.push(AnyNode::Expr(call.method));
+                self.args(&call.args);
+            }
+            ExprKind::Closure(closure) => {
+                self.function_like(&closure.attributes, &closure.params, &closure.return_type);
+                self.extend(closure.use_vars.iter().map(AnyNode::ClosureUseVar));
+                self.stmts(&closure.body);
+            }
+            ExprKind::ArrowFunction(arrow) => {
+                self.function_like(&arrow.attributes, &arrow.params, &arrow.return_type);
+                self.push(AnyNode::Expr(arrow.body));
+            }
+            ExprKind::Match(match_expr) => {
+                self.push(AnyNode::Expr(match_expr.subject));
+                self.extend(match_expr.arms.iter().map(AnyNode::MatchArm));
+            }
+            ExprKind::Yield(yield_expr) => {
+                self.extend(yield_expr.key.iter().map(|e| AnyNode::Expr(e)));
+                self.extend(yield_expr.value.iter().map(|e| AnyNode::Expr(e)));
+            }
+            ExprKind::AnonymousClass(class) => {
+                self.attributes(&class.attributes);
+                self.class_members(&class.members);
+            }
+            ExprKind::InterpolatedString(parts)
+            | ExprKind::Heredoc { parts, .. }
+            | ExprKind::ShellExec(parts) => {
+                self.extend(parts.iter().filter_map(|part| match part {
+                    StringPart::Expr(e) => Some(AnyNode::Expr(e)),
+                    StringPart::Literal { .. } => None,
+                }));
+            }
+            ExprKind::CallableCreate(cc) => match &cc.kind {
+                CallableCreateKind::Function(name) => self.push(AnyNode::Expr(name)),
+                CallableCreateKind::Method { object, method }
+                | CallableCreateKind::NullsafeMethod { object, method } => {
+                    self.push(AnyNode::Expr(object));
+                    self.push(AnyNode::Expr(method));
+                }
+                CallableCreateKind::StaticMethod { class, method } => {
+                    self.class_ref(class);
+                    self.push(AnyNode::Expr(method));
+                }
+            },
+            ExprKind::Name(name) | ExprKind::ConstFetch(name) => self.push(AnyNode::Name(name)),
+            ExprKind::Int(_)
+            | ExprKind::Float(_)
+            | ExprKind::String { .. }
+            | ExprKind::Bool(_)
+            | ExprKind::Null
+            | ExprKind::Omit
+            | ExprKind::Variable(_)
+            | ExprKind::Identifier(_)
+            | ExprKind::MagicConst(_)
+            | ExprKind::Nowdoc { .. }
+            | ExprKind::Error => {}
+        }
+    }
+}
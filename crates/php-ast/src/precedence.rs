@@ -0,0 +1,237 @@
+//! Operator precedence, associativity, and fixity.
+//!
+//! [`Operator`] names every operator-like expression: the symbolic binary,
+//! assignment, and unary operators, and the keyword operators such as `print`,
+//! `yield`, `include`, `throw`, `clone`, and `@` that PHP's grammar ranks in
+//! the same table. Formatters and code generators can decide where an operand
+//! needs parentheses without special-casing the keyword forms.
+//!
+//! Levels follow PHP's operator-precedence table, with `|>` between the
+//! comparisons and `.` as in PHP 8.5:
+//! <https://www.php.net/manual/en/language.operators.precedence.php>
+//!
+//! ```
+//! use php_ast::precedence::{Associativity, Fixity, Operator};
+//! use php_ast::{BinaryOp, IncludeKind};
+//!
+//! let print = Operator::Print;
+//! let and = Operator::Binary(BinaryOp::LogicalAnd);
+//! // `print $a and $b` is `(print $a) and $b`.
+//! assert!(print.precedence() > and.precedence());
+//! assert_eq!(print.fixity(), Fixity::Prefix);
+//!
+//! let require = Operator::Include(IncludeKind::Require);
+//! assert_eq!(require.symbol(), "require");
+//! assert_eq!(Operator::Instanceof.associativity(), Associativity::None);
+//! ```
+
+use crate::ast::{
+    AssignOp, BinaryOp, CastKind, ExprKind, IncludeKind, UnaryPostfixOp, UnaryPrefixOp,
+};
+
+/// An operator, as found at the root of an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Operator {
+    /// A binary operator, symbolic like `+` or a keyword like `and`.
+    Binary(BinaryOp),
+    /// `=` or a compound assignment such as `+=`; also `=&`.
+    Assign(AssignOp),
+    /// `-`, `+`, `!`, `~`, `++`, or `--` before the operand.
+    Prefix(UnaryPrefixOp),
+    /// `++` or `--` after the operand.
+    Postfix(UnaryPostfixOp),
+    /// `instanceof`.
+    Instanceof,
+    /// `? :` and the short `?:`.
+    Ternary,
+    /// `??`.
+    NullCoalesce,
+    /// A cast such as `(int)`.
+    Cast(CastKind),
+    /// `@`, the error-control operator.
+    ErrorSuppress,
+    /// `clone`, with or without property overrides.
+    Clone,
+    /// `print`.
+    Print,
+    /// `yield`, with or without a key or value.
+    Yield,
+    /// `yield from`.
+    YieldFrom,
+    /// `throw` used as an expression.
+    Throw,
+    /// `include`, `include_once`, `require`, or `require_once`.
+    Include(IncludeKind),
+}
+
+/// How a chain of operators at the same level groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`. Prefix operators nest this way
+    /// too, as in `!!$a` or `print print 1`.
+    Right,
+    /// The operator does not chain without parentheses: `a < b < c`,
+    /// `a ? b : c ? d : e`, and `a instanceof B instanceof C` are errors.
+    None,
+}
+
+/// Where an operator stands relative to its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fixity {
+    /// Before its one operand: `!$a`, `print $a`, `require $file`.
+    Prefix,
+    /// Between two operands. The ternary counts as infix, its middle
+    /// operand being delimited by `?` and `:`.
+    Infix,
+    /// After its one operand: `$a++`.
+    Postfix,
+}
+
+impl Operator {
+    /// The operator at the root of `expr`, or `None` for an expression
+    /// that is not an operation, such as a variable, call, or literal.
+    pub fn of(expr: &ExprKind<'_, '_>) -> Option<Self> {
+        Some(match expr {
+            ExprKind::Binary(binary) => Operator::Binary(binary.op),
+            ExprKind::Assign(assign) => Operator::Assign(assign.op),
+            ExprKind::UnaryPrefix(unary) => Operator::Prefix(unary.op),
+            ExprKind::UnaryPostfix(unary) => Operator::Postfix(unary.op),
+            ExprKind::Instanceof(_) => Operator::Instanceof,
+            ExprKind::Ternary(_) => Operator::Ternary,
+            ExprKind::NullCoalesce(_) => Operator::NullCoalesce,
+            ExprKind::Cast(kind, _) => Operator::Cast(*kind),
+            ExprKind::ErrorSuppress(_) => Operator::ErrorSuppress,
+            ExprKind::Clone(_) | ExprKind::CloneWith(_, _) => Operator::Clone,
+            ExprKind::Print(_) => Operator::Print,
+            ExprKind::Yield(yield_expr) if yield_expr.is_from => Operator::YieldFrom,
+            ExprKind::Yield(_) => Operator::Yield,
+            ExprKind::ThrowExpr(_) => Operator::Throw,
+            ExprKind::Include(kind, _) => Operator::Include(*kind),
+            _ => return None,
+        })
+    }
+
+    /// The precedence level; an operator binds tighter than those with a
+    /// lower level. Levels only compare with each other, they have no
+    /// meaning of their own.
+    pub fn precedence(self) -> u8 {
+        match self {
+            Operator::Throw => 1,
+            Operator::Include(_) => 2,
+            Operator::Binary(BinaryOp::LogicalOr) => 3,
+            Operator::Binary(BinaryOp::LogicalXor) => 4,
+            Operator::Binary(BinaryOp::LogicalAnd) => 5,
+            Operator::Print => 6,
+            Operator::Yield => 7,
+            Operator::YieldFrom => 8,
+            Operator::Assign(_) => 9,
+            Operator::Ternary => 10,
+            Operator::NullCoalesce => 11,
+            Operator::Binary(BinaryOp::BooleanOr) => 12,
+            Operator::Binary(BinaryOp::BooleanAnd) => 13,
+            Operator::Binary(BinaryOp::BitwiseOr) => 14,
+            Operator::Binary(BinaryOp::BitwiseXor) => 15,
+            Operator::Binary(BinaryOp::BitwiseAnd) => 16,
+            Operator::Binary(
+                BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Identical
+                | BinaryOp::NotIdentical
+                | BinaryOp::Spaceship,
+            ) => 17,
+            Operator::Binary(
+                BinaryOp::Less
+                | BinaryOp::Greater
+                | BinaryOp::LessOrEqual
+                | BinaryOp::GreaterOrEqual,
+            ) => 18,
+            Operator::Binary(BinaryOp::Pipe) => 19,
+            Operator::Binary(BinaryOp::Concat) => 20,
+            Operator::Binary(BinaryOp::ShiftLeft | BinaryOp::ShiftRight) => 21,
+            Operator::Binary(BinaryOp::Add | BinaryOp::Sub) => 22,
+            Operator::Binary(BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod) => 23,
+            Operator::Prefix(UnaryPrefixOp::BooleanNot) => 24,
+            Operator::Instanceof => 25,
+            Operator::Prefix(_)
+            | Operator::Postfix(_)
+            | Operator::Cast(_)
+            | Operator::ErrorSuppress => 26,
+            Operator::Binary(BinaryOp::Pow) => 27,
+            Operator::Clone => 28,
+        }
+    }
+
+    /// How a chain of operators at this level groups. Prefix and postfix
+    /// operators report how they nest.
+    pub fn associativity(self) -> Associativity {
+        match self {
+            Operator::Binary(
+                BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Identical
+                | BinaryOp::NotIdentical
+                | BinaryOp::Spaceship
+                | BinaryOp::Less
+                | BinaryOp::Greater
+                | BinaryOp::LessOrEqual
+                | BinaryOp::GreaterOrEqual,
+            )
+            | Operator::Instanceof
+            | Operator::Ternary => Associativity::None,
+            Operator::Binary(BinaryOp::Pow) | Operator::Assign(_) | Operator::NullCoalesce => {
+                Associativity::Right
+            }
+            Operator::Binary(_) | Operator::Postfix(_) => Associativity::Left,
+            _ => Associativity::Right,
+        }
+    }
+
+    /// Where the operator stands relative to its operands.
+    pub fn fixity(self) -> Fixity {
+        match self {
+            Operator::Binary(_)
+            | Operator::Assign(_)
+            | Operator::Instanceof
+            | Operator::Ternary
+            | Operator::NullCoalesce => Fixity::Infix,
+            Operator::Postfix(_) => Fixity::Postfix,
+            _ => Fixity::Prefix,
+        }
+    }
+
+    /// The operator as written in PHP: `"+"`, `"instanceof"`, `"?:"` for
+    /// the ternary, `"(int)"`, `"yield from"`, or `"require_once"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Binary(op) => op.as_str(),
+            Operator::Assign(op) => op.as_str(),
+            Operator::Prefix(op) => op.as_str(),
+            Operator::Postfix(UnaryPostfixOp::PostIncrement) => "++",
+            Operator::Postfix(UnaryPostfixOp::PostDecrement) => "--",
+            Operator::Instanceof => "instanceof",
+            Operator::Ternary => "?:",
+            Operator::NullCoalesce => "??",
+            Operator::Cast(kind) => kind.as_str(),
+            Operator::ErrorSuppress => "@",
+            Operator::Clone => "clone",
+            Operator::Print => "print",
+            Operator::Yield => "yield",
+            Operator::YieldFrom => "yield from",
+            Operator::Throw => "throw",
+            Operator::Include(IncludeKind::Include) => "include",
+            Operator::Include(IncludeKind::IncludeOnce) => "include_once",
+            Operator::Include(IncludeKind::Require) => "require",
+            Operator::Include(IncludeKind::RequireOnce) => "require_once",
+        }
+    }
+
+    /// `true` for operators spelled as a keyword, such as `and`, `print`,
+    /// or `instanceof`, which need a space to separate them from an
+    /// adjacent name.
+    pub fn is_keyword(self) -> bool {
+        self.symbol().starts_with(|c: char| c.is_ascii_alphabetic())
+    }
+}
@@ -1,6 +1,11 @@
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+/// A half-open byte range `start..end` into the source text.
+///
+/// Spans order by `start`, then by `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Span {
     pub start: u32,
     pub end: u32,
@@ -27,6 +32,80 @@ impl Span {
     pub fn is_empty(self) -> bool {
         self.start == self.end
     }
+
+    /// `true` if `offset` lies within `start..end` (the end is exclusive).
+    pub fn contains(self, offset: u32) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// `true` if `other` lies entirely within this span. Every span contains itself.
+    pub fn contains_span(self, other: Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// `true` if the two spans share at least one byte.
+    pub fn intersects(self, other: Span) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Move `start` forward and `end` backward by the given amounts. The
+    /// result never inverts: if the amounts overlap, an empty span is returned
+    /// at the clamped start.
+    pub fn shrink(self, start_by: u32, end_by: u32) -> Span {
+        let start = self.start.saturating_add(start_by).min(self.end);
+        let end = self.end.saturating_sub(end_by).max(start);
+        Span { start, end }
+    }
+
+    /// Move `start` backward and `end` forward by the given amounts, saturating at the `u32` bounds.
+    pub fn extend(self, start_by: u32, end_by: u32) -> Span {
+        Span {
+            start: self.start.saturating_sub(start_by),
+            end: self.end.saturating_add(end_by),
+        }
+    }
+
+    /// The span as a `usize` range, for slicing the source text.
+    pub fn to_range(self) -> std::ops::Range<usize> {
+        self.start as usize..self.end as usize
+    }
+}
+
+impl From<Span> for std::ops::Range<usize> {
+    fn from(span: Span) -> Self {
+        span.to_range()
+    }
+}
+
+/// Uniform access to the source span of an AST node.
+///
+/// Implemented by every node type that records its position, so generic
+/// tooling (diagnostics, cursor lookups, sorting) does not need to match on
+/// concrete types.
+///
+/// ```
+/// use php_ast::{Span, Spanned};
+///
+/// fn covering<T: Spanned>(nodes: &[T]) -> Option<Span> {
+///     nodes.iter().map(Spanned::span).reduce(Span::merge)
+/// }
+///
+/// assert_eq!(covering(&[Span::new(4, 8), Span::new(10, 12)]), Some(Span::new(4, 12)));
+/// ```
+pub trait Spanned {
+    fn span(&self) -> Span;
+}
+
+impl Spanned for Span {
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+impl<T: Spanned + ?Sized> Spanned for &T {
+    fn span(&self) -> Span {
+        (**self).span()
+    }
 }
 
 impl Default for Span {
@@ -86,4 +165,61 @@ mod tests {
     fn test_span_default() {
         assert_eq!(Span::default(), Span::DUMMY);
     }
+
+    #[test]
+    fn test_span_contains() {
+        let span = Span::new(5, 10);
+        assert!(span.contains(5));
+        assert!(span.contains(9));
+        assert!(!span.contains(10));
+        assert!(!span.contains(4));
+        assert!(!Span::new(5, 5).contains(5));
+    }
+
+    #[test]
+    fn test_span_contains_span() {
+        let outer = Span::new(5, 20);
+        assert!(outer.contains_span(Span::new(5, 20)));
+        assert!(outer.contains_span(Span::new(8, 12)));
+        assert!(outer.contains_span(Span::new(20, 20)));
+        assert!(!outer.contains_span(Span::new(4, 12)));
+        assert!(!outer.contains_span(Span::new(8, 21)));
+    }
+
+    #[test]
+    fn test_span_intersects() {
+        let a = Span::new(5, 10);
+        assert!(a.intersects(Span::new(9, 15)));
+        assert!(a.intersects(Span::new(0, 6)));
+        assert!(!a.intersects(Span::new(10, 15)));
+        assert!(!a.intersects(Span::new(0, 5)));
+    }
+
+    #[test]
+    fn test_span_shrink() {
+        assert_eq!(Span::new(5, 10).shrink(1, 2), Span::new(6, 8));
+        assert_eq!(Span::new(5, 10).shrink(4, 4), Span::new(9, 9));
+        assert_eq!(Span::new(5, 10).shrink(20, 0), Span::new(10, 10));
+    }
+
+    #[test]
+    fn test_span_extend() {
+        assert_eq!(Span::new(5, 10).extend(2, 3), Span::new(3, 13));
+        assert_eq!(Span::new(1, 10).extend(5, 0), Span::new(0, 10));
+        assert_eq!(Span::new(0, u32::MAX).extend(0, 1), Span::new(0, u32::MAX));
+    }
+
+    #[test]
+    fn test_span_ordering() {
+        let mut spans = vec![Span::new(5, 10), Span::new(0, 20), Span::new(5, 7)];
+        spans.sort();
+        assert_eq!(spans, [Span::new(0, 20), Span::new(5, 7), Span::new(5, 10)]);
+    }
+
+    #[test]
+    fn test_span_to_range() {
+        let range: std::ops::Range<usize> = Span::new(3, 7).into();
+        assert_eq!(range, 3..7);
+        assert_eq!(&"<?php echo"[Span::new(6, 10).to_range()], "echo");
+    }
 }
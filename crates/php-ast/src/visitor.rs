@@ -100,6 +100,10 @@ pub trait Visitor<'arena, 'src> {
         ControlFlow::Continue(())
     }
 
+    fn visit_class_ref(&mut self, class: &ClassRef<'arena, 'src>) -> ControlFlow<()> {
+        walk_class_ref(self, class)
+    }
+
     /// Called for each comment when driven by [`walk_comments`].
     ///
     /// Comments live in [`ParseResult::comments`](php_rs_parser::ParseResult::comments)
@@ -124,6 +128,21 @@ pub fn walk_name<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
     visitor.visit_name(name)
 }
 
+/// Visits the name or expression of a class operand. The `self`, `parent`,
+/// and `static` keywords have no children.
+pub fn walk_class_ref<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
+    visitor: &mut V,
+    class: &ClassRef<'arena, 'src>,
+) -> ControlFlow<()> {
+    match class {
+        ClassRef::Name(name) => visitor.visit_name(name),
+        ClassRef::Expr(expr) => visitor.visit_expr(expr),
+        ClassRef::SelfKw(_) | ClassRef::ParentKw(_) | ClassRef::StaticKw(_) => {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
 /// Calls [`Visitor::visit_comment`] for each comment in `comments`.
 ///
 /// Comments are stored separately from the AST in
@@ -183,8 +202,8 @@ pub fn walk_stmt<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
         StmtKind::Expression(expr) => {
             visitor.visit_expr(expr)?;
         }
-        StmtKind::Echo(exprs) => {
-            for expr in exprs.iter() {
+        StmtKind::Echo(echo) => {
+            for expr in echo.exprs.iter() {
                 visitor.visit_expr(expr)?;
             }
         }
@@ -402,7 +421,7 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
                 visitor.visit_arg(arg)?;
             }
         }
-        ExprKind::Array(elements) => {
+        ExprKind::Array(elements) | ExprKind::List(ListExpr { elements, .. }) => {
             for elem in elements.iter() {
                 if let Some(key) = &elem.key {
                     visitor.visit_expr(key)?;
@@ -416,8 +435,8 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
                 visitor.visit_expr(index)?;
             }
         }
-        ExprKind::Print(expr) => {
-            visitor.visit_expr(expr)?;
+        ExprKind::Print(print) => {
+            visitor.visit_expr(print.expr)?;
         }
         ExprKind::Parenthesized(expr) => {
             visitor.visit_expr(expr)?;
@@ -425,8 +444,12 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
         ExprKind::Cast(_, expr) => {
             visitor.visit_expr(expr)?;
         }
-        ExprKind::ErrorSuppress(expr) => {
-            visitor.visit_expr(expr)?;
+        ExprKind::Instanceof(instanceof) => {
+            visitor.visit_expr(instanceof.expr)?;
+            visitor.visit_class_ref(&instanceof.class)?;
+        }
+        ExprKind::ErrorSuppress(suppress) => {
+            visitor.visit_expr(suppress.expr)?;
         }
         ExprKind::Isset(exprs) => {
             for expr in exprs.iter() {
@@ -447,15 +470,15 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
                 visitor.visit_expr(expr)?;
             }
         }
-        ExprKind::Clone(expr) => {
-            visitor.visit_expr(expr)?;
+        ExprKind::Clone(clone) => {
+            visitor.visit_expr(clone.object)?;
         }
         ExprKind::CloneWith(object, overrides) => {
             visitor.visit_expr(object)?;
             visitor.visit_expr(overrides)?;
         }
         ExprKind::New(new_expr) => {
-            visitor.visit_expr(new_expr.class)?;
+            visitor.visit_class_ref(&new_expr.class)?;
             for arg in new_expr.args.iter() {
                 visitor.visit_arg(arg)?;
             }
@@ -472,23 +495,23 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
             }
         }
         ExprKind::StaticPropertyAccess(access) | ExprKind::ClassConstAccess(access) => {
-            visitor.visit_expr(access.class)?;
+            visitor.visit_class_ref(&access.class)?;
             visitor.visit_expr(access.member)?;
         }
         ExprKind::ClassConstAccessDynamic { class, member }
         | ExprKind::StaticPropertyAccessDynamic { class, member } => {
-            visitor.visit_expr(class)?;
+            visitor.visit_class_ref(class)?;
             visitor.visit_expr(member)?;
         }
         ExprKind::StaticMethodCall(call) => {
-            visitor.visit_expr(call.class)?;
+            visitor.visit_class_ref(&call.class)?;
             visitor.visit_expr(call.method)?;
             for arg in call.args.iter() {
                 visitor.visit_arg(arg)?;
             }
         }
         ExprKind::StaticDynMethodCall(call) => {
-            visitor.visit_expr(call.class)?;
+            visitor.visit_class_ref(&call.class)?;
             visitor.visit_expr(call.method)?;
             for arg in call.args.iter() {
                 visitor.visit_arg(arg)?;
@@ -536,6 +559,12 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
         }
         ExprKind::AnonymousClass(class) => {
             walk_attributes(visitor, &class.attributes)?;
+            if let Some(extends) = &class.extends {
+                visitor.visit_name(extends)?;
+            }
+            for name in class.implements.iter() {
+                visitor.visit_name(name)?;
+            }
             for member in class.members.iter() {
                 visitor.visit_class_member(member)?;
             }
@@ -560,13 +589,14 @@ pub fn walk_expr<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
                 visitor.visit_expr(method)?;
             }
             CallableCreateKind::StaticMethod { class, method } => {
-                visitor.visit_expr(class)?;
+                visitor.visit_class_ref(class)?;
                 visitor.visit_expr(method)?;
             }
         },
+        ExprKind::Name(name) | ExprKind::ConstFetch(name) => visitor.visit_name(name)?,
         ExprKind::Int(_)
         | ExprKind::Float(_)
-        | ExprKind::String(_)
+        | ExprKind::String { .. }
         | ExprKind::Bool(_)
         | ExprKind::Null
         | ExprKind::Omit
@@ -715,7 +745,7 @@ pub fn walk_catch_clause<'arena, 'src, V: Visitor<'arena, 'src> + ?Sized>(
     catch: &CatchClause<'arena, 'src>,
 ) -> ControlFlow<()> {
     for ty in catch.types.iter() {
-        visitor.visit_name(ty)?;
+        visitor.visit_class_ref(ty)?;
     }
     for stmt in catch.body.iter() {
         visitor.visit_stmt(stmt)?;
@@ -1300,6 +1330,8 @@ mod tests {
         let program = Program {
             stmts,
             span: Span::DUMMY,
+            ends_in_html: false,
+            close_tag: None,
         };
 
         let mut v = VarCounter { count: 0 };
@@ -1334,6 +1366,8 @@ mod tests {
         let program = Program {
             stmts,
             span: Span::DUMMY,
+            ends_in_html: false,
+            close_tag: None,
         };
 
         struct FindFirst {
@@ -1417,6 +1451,8 @@ mod tests {
         let program = Program {
             stmts,
             span: Span::DUMMY,
+            ends_in_html: false,
+            close_tag: None,
         };
 
         struct SkipFunctions {
@@ -41,6 +41,8 @@ fn identity_fold_preserves_expression_stmt() {
     let program = Program {
         stmts,
         span: Span::DUMMY,
+        ends_in_html: false,
+        close_tag: None,
     };
 
     let folded = Identity.fold_program(&out, &program);
@@ -59,12 +61,15 @@ fn identity_fold_preserves_arena_strings() {
 
     let s = arena.alloc_str("hello world");
     let expr = Expr {
-        kind: ExprKind::String(s),
+        kind: ExprKind::String {
+            value: s,
+            raw: "hello world",
+        },
         span: Span::DUMMY,
     };
     let folded_expr = Identity.fold_expr(&out, &expr);
     match folded_expr.kind {
-        ExprKind::String(t) => assert_eq!(t, "hello world"),
+        ExprKind::String { value: t, .. } => assert_eq!(t, "hello world"),
         _ => panic!("expected String"),
     }
 }
@@ -129,11 +134,14 @@ fn string_literal_is_reallocated_into_new_arena() {
 
     let s = src_arena.alloc_str("hello world");
     let expr = Expr {
-        kind: ExprKind::String(s),
+        kind: ExprKind::String {
+            value: s,
+            raw: "hello world",
+        },
         span: Span::DUMMY,
     };
     let folded_expr = Identity.fold_expr(&out, &expr);
-    let ExprKind::String(t) = folded_expr.kind else {
+    let ExprKind::String { value: t, .. } = folded_expr.kind else {
         panic!("expected String")
     };
     assert_eq!(t, "hello world");
@@ -169,6 +177,7 @@ fn custom_fold_closure_use_var_no_arena_param() {
         name: "x",
         by_ref: true,
         span: Span::DUMMY,
+        comma: None,
     });
     let closure = arena.alloc(ClosureExpr {
         is_static: false,
@@ -313,12 +322,13 @@ fn nowdoc_value_is_reallocated_label_is_not() {
     let expr = Expr {
         kind: ExprKind::Nowdoc {
             label: "EOT",
+            raw: "nowdoc body",
             value: val,
         },
         span: Span::DUMMY,
     };
     let folded = Identity.fold_expr(&out, &expr);
-    let ExprKind::Nowdoc { label, value } = folded.kind else {
+    let ExprKind::Nowdoc { label, value, .. } = folded.kind else {
         panic!("expected Nowdoc")
     };
     assert_eq!(value, "nowdoc body");
@@ -348,7 +358,10 @@ fn string_part_literal_in_interpolated_string_is_reallocated() {
         span: Span::DUMMY,
     };
     let mut parts = ArenaVec::new_in(&src);
-    parts.push(StringPart::Literal(s));
+    parts.push(StringPart::Literal {
+        value: s,
+        span: Span::new(1, 7),
+    });
     parts.push(StringPart::Expr(var_expr));
     let expr = Expr {
         kind: ExprKind::InterpolatedString(parts),
@@ -359,10 +372,11 @@ fn string_part_literal_in_interpolated_string_is_reallocated() {
         panic!("expected InterpolatedString")
     };
     assert_eq!(p.len(), 2);
-    let StringPart::Literal(t) = &p[0] else {
+    let StringPart::Literal { value: t, span } = &p[0] else {
         panic!("expected Literal part")
     };
     assert_eq!(*t, "hello ");
+    assert_eq!(*span, Span::new(1, 7));
     assert_ne!(t.as_ptr(), s.as_ptr(), "Literal part must be re-allocated");
     assert!(
         matches!(&p[1], StringPart::Expr(_)),
@@ -404,6 +418,8 @@ fn fold_stmt_override_transforms_nop_to_error() {
     let program = Program {
         stmts,
         span: Span::DUMMY,
+        ends_in_html: false,
+        close_tag: None,
     };
     let folded = NopToError.fold_program(&out, &program);
     assert!(
@@ -572,6 +588,7 @@ fn fold_param_override_strips_default() {
         attributes: ArenaVec::new_in(&arena),
         hooks: ArenaVec::new_in(&arena),
         span: Span::DUMMY,
+        comma: None,
     };
     let folded = ClearDefaults.fold_param(&out, &param);
     assert!(
@@ -599,6 +616,7 @@ fn fold_arg_override_clears_named_arg() {
             value: "key",
             span: Span::DUMMY,
         }),
+        colon: Some(Span::DUMMY),
         value: Expr {
             kind: ExprKind::Int(1),
             span: Span::DUMMY,
@@ -606,6 +624,7 @@ fn fold_arg_override_clears_named_arg() {
         unpack: false,
         by_ref: false,
         span: Span::DUMMY,
+        comma: None,
     };
     let folded = StripArgNames.fold_arg(&out, &arg);
     assert!(
@@ -882,6 +901,7 @@ fn fold_match_arm_override_dispatched_for_both_arm_kinds() {
             span: Span::DUMMY,
         },
         span: Span::DUMMY,
+        comma: None,
     });
     // Default arm: `default => 0`
     arms.push(MatchArm {
@@ -891,6 +911,7 @@ fn fold_match_arm_override_dispatched_for_both_arm_kinds() {
             span: Span::DUMMY,
         },
         span: Span::DUMMY,
+        comma: None,
     });
     let match_expr = Expr {
         kind: ExprKind::Match(MatchExpr { subject, arms }),
@@ -923,13 +944,16 @@ fn fold_catch_clause_override_clears_var() {
     let arena = Bump::new();
     let out = Bump::new();
     let mut types = ArenaVec::new_in(&arena);
-    types.push(Name::Simple {
+    types.push(ClassRef::Name(arena.alloc(Name::Simple {
         value: "Exception",
         span: Span::DUMMY,
-    });
+    })));
     let catch = CatchClause {
         types,
-        var: Some("e"),
+        var: Some(CatchVar {
+            name: "e",
+            span: Span::DUMMY,
+        }),
         body: ArenaVec::new_in(&arena),
         span: Span::DUMMY,
     };
@@ -1000,6 +1024,7 @@ fn fold_name_override_is_dispatched() {
             value: "key",
             span: Span::DUMMY,
         }),
+        colon: Some(Span::DUMMY),
         value: Expr {
             kind: ExprKind::Int(1),
             span: Span::DUMMY,
@@ -1007,6 +1032,7 @@ fn fold_name_override_is_dispatched() {
         unpack: false,
         by_ref: false,
         span: Span::DUMMY,
+        comma: None,
     };
     let mut folder = CountNames { count: 0 };
     folder.fold_arg(&out, &arg);
@@ -1033,6 +1059,8 @@ fn return_none_stays_none() {
     let program = Program {
         stmts,
         span: Span::DUMMY,
+        ends_in_html: false,
+        close_tag: None,
     };
     let folded = Identity.fold_program(&out, &program);
     assert!(
@@ -1090,6 +1118,7 @@ fn match_default_arm_conditions_none_stays_none() {
             span: Span::DUMMY,
         },
         span: Span::DUMMY,
+        comma: None,
     };
     let folded = Identity.fold_match_arm(&out, &arm);
     assert!(
@@ -0,0 +1,711 @@
+//! Built-in functions without side effects beyond output.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use php_analysis::values::{self, ArrayKey, PhpValue};
+use php_ast::{BinaryOp, Span};
+
+use crate::interpreter::{next_key, set, to_string};
+use crate::EvalError;
+
+type Result<T> = std::result::Result<T, EvalError>;
+
+/// The longest string or array a built-in builds.
+const MAX_LEN: usize = 1 << 24;
+
+/// A built-in function and its PHP signature.
+pub(crate) struct Builtin {
+    name: &'static str,
+    /// Parameter names; the last one is variadic if `variadic`.
+    params: &'static [&'static str],
+    required: usize,
+    variadic: bool,
+    run: fn(&mut Call<'_>) -> Result<PhpValue>,
+}
+
+/// The arguments of a call to a built-in, and the interpreter state
+/// built-ins may touch.
+pub(crate) struct Call<'i> {
+    builtin: &'static Builtin,
+    args: Vec<PhpValue>,
+    span: Span,
+    output: &'i mut Vec<u8>,
+    constants: &'i mut HashMap<String, PhpValue>,
+}
+
+static BUILTINS: &[Builtin] = &[
+    Builtin::new("abs", &["num"], 1, abs),
+    Builtin::new("array_key_exists", &["key", "array"], 2, array_key_exists),
+    Builtin::new("array_key_first", &["array"], 1, |c| {
+        Ok(c.array(0)?
+            .first()
+            .map_or(PhpValue::Null, |(k, _)| k.to_value()))
+    }),
+    Builtin::new("array_key_last", &["array"], 1, |c| {
+        Ok(c.array(0)?
+            .last()
+            .map_or(PhpValue::Null, |(k, _)| k.to_value()))
+    }),
+    Builtin::new("array_keys", &["array"], 1, |c| {
+        Ok(list(c.array(0)?.into_iter().map(|(k, _)| k.to_value())))
+    }),
+    Builtin::variadic("array_merge", &["arrays"], 0, array_merge),
+    Builtin::new(
+        "array_reverse",
+        &["array", "preserve_keys"],
+        1,
+        array_reverse,
+    ),
+    Builtin::new(
+        "array_search",
+        &["needle", "haystack", "strict"],
+        2,
+        array_search,
+    ),
+    Builtin::new("array_sum", &["array"], 1, array_sum),
+    Builtin::new("array_values", &["array"], 1, |c| {
+        Ok(list(c.array(0)?.into_iter().map(|(_, v)| v)))
+    }),
+    Builtin::new("ceil", &["num"], 1, |c| {
+        Ok(PhpValue::Float(c.number(0)?.ceil()))
+    }),
+    Builtin::new("chr", &["codepoint"], 1, |c| {
+        Ok(PhpValue::String(vec![c.int(0)?.rem_euclid(256) as u8]))
+    }),
+    Builtin::new("count", &["value", "mode"], 1, count),
+    Builtin::new("define", &["constant_name", "value"], 2, |c| {
+        let name = String::from_utf8_lossy(&c.string(0)?).into_owned();
+        let value = c.args[1].clone();
+        Ok(PhpValue::Bool(define(c.constants, &name, value)))
+    }),
+    Builtin::new("explode", &["separator", "string", "limit"], 2, explode),
+    Builtin::new("floor", &["num"], 1, |c| {
+        Ok(PhpValue::Float(c.number(0)?.floor()))
+    }),
+    Builtin::new("gettype", &["value"], 1, |c| {
+        Ok(string(match c.args[0] {
+            PhpValue::Null => "NULL",
+            PhpValue::Bool(_) => "boolean",
+            PhpValue::Int(_) => "integer",
+            PhpValue::Float(_) => "double",
+            PhpValue::String(_) => "string",
+            PhpValue::Array(_) => "array",
+        }))
+    }),
+    Builtin::new("implode", &["separator", "array"], 1, implode),
+    Builtin::new("in_array", &["needle", "haystack", "strict"], 2, |c| {
+        Ok(PhpValue::Bool(search(c)?.is_some()))
+    }),
+    Builtin::new("intdiv", &["num1", "num2"], 2, intdiv),
+    Builtin::new("is_array", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(matches!(c.args[0], PhpValue::Array(_))))
+    }),
+    Builtin::new("is_bool", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(matches!(c.args[0], PhpValue::Bool(_))))
+    }),
+    Builtin::new("is_float", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(matches!(c.args[0], PhpValue::Float(_))))
+    }),
+    Builtin::new("is_int", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(matches!(c.args[0], PhpValue::Int(_))))
+    }),
+    Builtin::new("is_null", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(c.args[0] == PhpValue::Null))
+    }),
+    Builtin::new("is_numeric", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(match &c.args[0] {
+            PhpValue::Int(_) | PhpValue::Float(_) => true,
+            PhpValue::String(s) => values::is_numeric(s),
+            _ => false,
+        }))
+    }),
+    Builtin::new("is_scalar", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(!matches!(
+            c.args[0],
+            PhpValue::Null | PhpValue::Array(_)
+        )))
+    }),
+    Builtin::new("is_string", &["value"], 1, |c| {
+        Ok(PhpValue::Bool(matches!(c.args[0], PhpValue::String(_))))
+    }),
+    Builtin::new("join", &["separator", "array"], 1, implode),
+    Builtin::new("lcfirst", &["string"], 1, |c| {
+        let mut s = c.string(0)?;
+        if let Some(first) = s.first_mut() {
+            first.make_ascii_lowercase();
+        }
+        Ok(PhpValue::String(s))
+    }),
+    Builtin::new("ltrim", &["string", "characters"], 1, |c| {
+        trim(c, true, false)
+    }),
+    Builtin::variadic("max", &["value", "values"], 1, |c| {
+        extreme(c, Ordering::Greater)
+    }),
+    Builtin::variadic("min", &["value", "values"], 1, |c| {
+        extreme(c, Ordering::Less)
+    }),
+    Builtin::new("ord", &["character"], 1, |c| {
+        Ok(PhpValue::Int(
+            c.string(0)?.first().map_or(0, |&b| i64::from(b)),
+        ))
+    }),
+    Builtin::new("range", &["start", "end", "step"], 2, range),
+    Builtin::new("rtrim", &["string", "characters"], 1, |c| {
+        trim(c, false, true)
+    }),
+    Builtin::new("str_contains", &["haystack", "needle"], 2, |c| {
+        let (haystack, needle) = (c.string(0)?, c.string(1)?);
+        Ok(PhpValue::Bool(find(&haystack, &needle, 0).is_some()))
+    }),
+    Builtin::new("str_ends_with", &["haystack", "needle"], 2, |c| {
+        Ok(PhpValue::Bool(c.string(0)?.ends_with(&c.string(1)?)))
+    }),
+    Builtin::new("str_repeat", &["string", "times"], 2, str_repeat),
+    Builtin::new("str_starts_with", &["haystack", "needle"], 2, |c| {
+        Ok(PhpValue::Bool(c.string(0)?.starts_with(&c.string(1)?)))
+    }),
+    Builtin::new("strlen", &["string"], 1, |c| {
+        Ok(PhpValue::Int(c.string(0)?.len() as i64))
+    }),
+    Builtin::new("strpos", &["haystack", "needle", "offset"], 2, strpos),
+    Builtin::new("strrev", &["string"], 1, |c| {
+        Ok(PhpValue::String(c.string(0)?.into_iter().rev().collect()))
+    }),
+    Builtin::new("strtolower", &["string"], 1, |c| {
+        Ok(PhpValue::String(c.string(0)?.to_ascii_lowercase()))
+    }),
+    Builtin::new("strtoupper", &["string"], 1, |c| {
+        Ok(PhpValue::String(c.string(0)?.to_ascii_uppercase()))
+    }),
+    Builtin::new("substr", &["string", "offset", "length"], 2, substr),
+    Builtin::new("trim", &["string", "characters"], 1, |c| {
+        trim(c, true, true)
+    }),
+    Builtin::new("ucfirst", &["string"], 1, |c| {
+        let mut s = c.string(0)?;
+        if let Some(first) = s.first_mut() {
+            first.make_ascii_uppercase();
+        }
+        Ok(PhpValue::String(s))
+    }),
+    Builtin::variadic("var_dump", &["value", "values"], 1, |c| {
+        for value in &c.args {
+            dump(c.output, value, 0);
+        }
+        Ok(PhpValue::Null)
+    }),
+];
+
+/// The built-in function named `name`, lower-cased.
+pub(crate) fn lookup(name: &str) -> Option<&'static Builtin> {
+    BUILTINS.iter().find(|builtin| builtin.name == name)
+}
+
+impl Builtin {
+    const fn new(
+        name: &'static str,
+        params: &'static [&'static str],
+        required: usize,
+        run: fn(&mut Call<'_>) -> Result<PhpValue>,
+    ) -> Self {
+        Self {
+            name,
+            params,
+            required,
+            variadic: false,
+            run,
+        }
+    }
+
+    const fn variadic(
+        name: &'static str,
+        params: &'static [&'static str],
+        required: usize,
+        run: fn(&mut Call<'_>) -> Result<PhpValue>,
+    ) -> Self {
+        Self {
+            variadic: true,
+            ..Self::new(name, params, required, run)
+        }
+    }
+
+    pub(crate) fn call(
+        &'static self,
+        args: Vec<PhpValue>,
+        span: Span,
+        output: &mut Vec<u8>,
+        constants: &mut HashMap<String, PhpValue>,
+    ) -> Result<PhpValue> {
+        let given = args.len();
+        let most = (!self.variadic).then_some(self.params.len());
+        if given < self.required || most.is_some_and(|most| given > most) {
+            let (bound, expected) = match most {
+                Some(most) if most == self.required => ("exactly", most),
+                _ if given < self.required => ("at least", self.required),
+                _ => ("at most", self.params.len()),
+            };
+            let plural = if expected == 1 { "" } else { "s" };
+            let message = format!(
+                "{}() expects {bound} {expected} argument{plural}, {given} given",
+                self.name
+            );
+            return Err(EvalError::Error { message, span });
+        }
+        (self.run)(&mut Call {
+            builtin: self,
+            args,
+            span,
+            output,
+            constants,
+        })
+    }
+}
+
+/// Define a constant unless it exists, as `define()` and `const` do.
+pub(crate) fn define(
+    constants: &mut HashMap<String, PhpValue>,
+    name: &str,
+    value: PhpValue,
+) -> bool {
+    let name = name.trim_start_matches('\\');
+    if constants.contains_key(name) {
+        return false;
+    }
+    constants.insert(name.to_string(), value);
+    true
+}
+
+impl Call<'_> {
+    /// An error about argument `i`, which PHP numbers from 1 and names.
+    fn invalid(&self, i: usize, problem: impl std::fmt::Display) -> EvalError {
+        let params = self.builtin.params;
+        EvalError::Error {
+            message: format!(
+                "{}(): Argument #{} (${}) {problem}",
+                self.builtin.name,
+                i + 1,
+                params[i.min(params.len() - 1)]
+            ),
+            span: self.span,
+        }
+    }
+
+    fn type_error(&self, i: usize, expected: &str) -> EvalError {
+        let given = self.args[i].type_name();
+        self.invalid(i, format!("must be of type {expected}, {given} given"))
+    }
+
+    fn string(&self, i: usize) -> Result<Vec<u8>> {
+        self.args[i]
+            .to_php_string()
+            .ok_or_else(|| self.type_error(i, "string"))
+    }
+
+    fn int(&self, i: usize) -> Result<i64> {
+        match &self.args[i] {
+            PhpValue::Array(_) => None,
+            PhpValue::String(s) if !values::is_numeric(s) => None,
+            value => value.to_int(),
+        }
+        .ok_or_else(|| self.type_error(i, "int"))
+    }
+
+    fn number(&self, i: usize) -> Result<f64> {
+        match &self.args[i] {
+            PhpValue::Array(_) => Err(self.type_error(i, "int|float")),
+            PhpValue::String(s) if !values::is_numeric(s) => Err(self.type_error(i, "int|float")),
+            value => Ok(value.to_float()),
+        }
+    }
+
+    fn array(&self, i: usize) -> Result<Vec<(ArrayKey, PhpValue)>> {
+        match &self.args[i] {
+            PhpValue::Array(entries) => Ok(entries.clone()),
+            _ => Err(self.type_error(i, "array")),
+        }
+    }
+
+    fn bool(&self, i: usize) -> bool {
+        self.args.get(i).is_some_and(PhpValue::to_bool)
+    }
+
+    /// Fail unless `len` elements or bytes fit in [`MAX_LEN`].
+    fn limit(&self, len: u128) -> Result<()> {
+        if len > MAX_LEN as u128 {
+            return Err(EvalError::Unsupported {
+                construct: "a string or array this large",
+                span: self.span,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn string(s: &str) -> PhpValue {
+    PhpValue::String(s.as_bytes().to_vec())
+}
+
+fn list(values: impl IntoIterator<Item = PhpValue>) -> PhpValue {
+    PhpValue::Array(
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (ArrayKey::Int(i as i64), value))
+            .collect(),
+    )
+}
+
+fn abs(c: &mut Call<'_>) -> Result<PhpValue> {
+    match &c.args[0] {
+        PhpValue::Int(n) => Ok(n
+            .checked_abs()
+            .map_or(PhpValue::Float((*n as f64).abs()), PhpValue::Int)),
+        PhpValue::String(s) if values::is_numeric(s) => {
+            c.args[0] = values::unary_op(php_ast::UnaryPrefixOp::Plus, &c.args[0])
+                .expect("numeric strings are numbers");
+            abs(c)
+        }
+        PhpValue::Null | PhpValue::Bool(_) => {
+            c.args[0] = PhpValue::Int(c.args[0].to_int().unwrap_or(0));
+            abs(c)
+        }
+        _ => Ok(PhpValue::Float(c.number(0)?.abs())),
+    }
+}
+
+fn array_key_exists(c: &mut Call<'_>) -> Result<PhpValue> {
+    let entries = c.array(1)?;
+    let key = ArrayKey::from_value(&c.args[0])
+        .ok_or_else(|| c.invalid(0, "must be a valid array offset type"))?;
+    Ok(PhpValue::Bool(entries.iter().any(|(k, _)| *k == key)))
+}
+
+fn array_merge(c: &mut Call<'_>) -> Result<PhpValue> {
+    let mut merged = Vec::new();
+    for i in 0..c.args.len() {
+        for (key, value) in c.array(i)? {
+            let key = match key {
+                ArrayKey::Int(_) => next_key(&merged, c.span)?,
+                key => key,
+            };
+            set(&mut merged, key, value);
+        }
+    }
+    Ok(PhpValue::Array(merged))
+}
+
+fn array_reverse(c: &mut Call<'_>) -> Result<PhpValue> {
+    let preserve = c.bool(1);
+    let mut reversed = Vec::new();
+    for (key, value) in c.array(0)?.into_iter().rev() {
+        let key = match key {
+            ArrayKey::Int(_) if !preserve => next_key(&reversed, c.span)?,
+            key => key,
+        };
+        reversed.push((key, value));
+    }
+    Ok(PhpValue::Array(reversed))
+}
+
+fn array_search(c: &mut Call<'_>) -> Result<PhpValue> {
+    Ok(search(c)?.map_or(PhpValue::Bool(false), |key| key.to_value()))
+}
+
+/// The key of the first element of `haystack` equal to `needle`, for
+/// `in_array()` and `array_search()`.
+fn search(c: &Call<'_>) -> Result<Option<ArrayKey>> {
+    let strict = c.bool(2);
+    let needle = &c.args[0];
+    Ok(c.array(1)?
+        .into_iter()
+        .find(|(_, value)| {
+            if strict {
+                value == needle
+            } else {
+                value.loose_eq(needle)
+            }
+        })
+        .map(|(key, _)| key))
+}
+
+fn array_sum(c: &mut Call<'_>) -> Result<PhpValue> {
+    let mut sum = PhpValue::Int(0);
+    for (_, value) in c.array(0)? {
+        // PHP warns about and skips what cannot be added.
+        if !matches!(value, PhpValue::Array(_)) {
+            if let Some(total) = values::binary_op(BinaryOp::Add, &sum, &value) {
+                sum = total;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+fn count(c: &mut Call<'_>) -> Result<PhpValue> {
+    fn recursive(entries: &[(ArrayKey, PhpValue)]) -> usize {
+        entries
+            .iter()
+            .map(|(_, value)| match value {
+                PhpValue::Array(inner) => 1 + recursive(inner),
+                _ => 1,
+            })
+            .sum()
+    }
+    let PhpValue::Array(entries) = &c.args[0] else {
+        return Err(c.type_error(0, "Countable|array"));
+    };
+    let n = if c.args.len() > 1 && c.int(1)? != 0 {
+        recursive(entries)
+    } else {
+        entries.len()
+    };
+    Ok(PhpValue::Int(n as i64))
+}
+
+fn explode(c: &mut Call<'_>) -> Result<PhpValue> {
+    let (separator, s) = (c.string(0)?, c.string(1)?);
+    if separator.is_empty() {
+        return Err(c.invalid(0, "cannot be empty"));
+    }
+    let limit = if c.args.len() > 2 {
+        c.int(2)?
+    } else {
+        i64::MAX
+    };
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while let Some(at) = find(&s, &separator, start) {
+        if limit > 0 && parts.len() as i64 == limit - 1 {
+            break;
+        }
+        parts.push(PhpValue::String(s[start..at].to_vec()));
+        start = at + separator.len();
+    }
+    parts.push(PhpValue::String(s[start..].to_vec()));
+    if limit < 0 {
+        let keep = parts.len().saturating_sub(limit.unsigned_abs() as usize);
+        parts.truncate(keep);
+    }
+    Ok(list(parts))
+}
+
+fn extreme(c: &mut Call<'_>, wanted: Ordering) -> Result<PhpValue> {
+    let candidates = if c.args.len() == 1 {
+        let entries = c.array(0)?;
+        if entries.is_empty() {
+            return Err(c.invalid(0, "must contain at least one element"));
+        }
+        entries.into_iter().map(|(_, value)| value).collect()
+    } else {
+        c.args.clone()
+    };
+    let mut candidates = candidates.into_iter();
+    let mut best = candidates.next().expect("one candidate or more");
+    for candidate in candidates {
+        if candidate.compare(&best) == Some(wanted) {
+            best = candidate;
+        }
+    }
+    Ok(best)
+}
+
+fn implode(c: &mut Call<'_>) -> Result<PhpValue> {
+    let (separator, pieces) = match c.args.len() {
+        1 => (Vec::new(), c.array(0)?),
+        _ => (c.string(0)?, c.array(1)?),
+    };
+    let mut joined = Vec::new();
+    for (i, (_, piece)) in pieces.iter().enumerate() {
+        if i > 0 {
+            joined.extend_from_slice(&separator);
+        }
+        joined.extend(to_string(piece));
+    }
+    Ok(PhpValue::String(joined))
+}
+
+fn intdiv(c: &mut Call<'_>) -> Result<PhpValue> {
+    let (a, b) = (c.int(0)?, c.int(1)?);
+    let message = match (a, b) {
+        (_, 0) => "Division by zero",
+        (i64::MIN, -1) => "Division of PHP_INT_MIN by -1 is not an integer",
+        _ => return Ok(PhpValue::Int(a / b)),
+    };
+    Err(EvalError::Error {
+        message: message.to_string(),
+        span: c.span,
+    })
+}
+
+fn range(c: &mut Call<'_>) -> Result<PhpValue> {
+    let step = if c.args.len() > 2 { c.int(2)? } else { 1 };
+    if step == 0 {
+        return Err(c.invalid(2, "cannot be 0"));
+    }
+    let step = step.unsigned_abs() as i128;
+    let single_byte = |value: &PhpValue| match value {
+        PhpValue::String(s) if s.len() == 1 && !s[0].is_ascii_digit() => Some(s[0]),
+        _ => None,
+    };
+    if let (Some(start), Some(end)) = (single_byte(&c.args[0]), single_byte(&c.args[1])) {
+        let (start, end) = (i128::from(start), i128::from(end));
+        let bytes = steps(start, end, step).map(|b| PhpValue::String(vec![b as u8]));
+        return Ok(list(bytes));
+    }
+    if c.args[..2]
+        .iter()
+        .any(|value| matches!(value, PhpValue::Float(_)))
+    {
+        return Err(EvalError::Unsupported {
+            construct: "`range()` over floats",
+            span: c.span,
+        });
+    }
+    let (start, end) = (i128::from(c.int(0)?), i128::from(c.int(1)?));
+    c.limit(start.abs_diff(end) / step as u128)?;
+    Ok(list(
+        steps(start, end, step).map(|n| PhpValue::Int(n as i64)),
+    ))
+}
+
+/// `start`, then values `step` apart towards `end`, up to `end`.
+fn steps(start: i128, end: i128, step: i128) -> impl Iterator<Item = i128> {
+    let count = start.abs_diff(end) / step as u128 + 1;
+    let step = if end < start { -step } else { step };
+    (0..count as i128).map(move |i| start + i * step)
+}
+
+fn str_repeat(c: &mut Call<'_>) -> Result<PhpValue> {
+    let (s, times) = (c.string(0)?, c.int(1)?);
+    if times < 0 {
+        return Err(c.invalid(1, "must be greater than or equal to 0"));
+    }
+    c.limit(s.len() as u128 * times as u128)?;
+    Ok(PhpValue::String(s.repeat(times as usize)))
+}
+
+fn strpos(c: &mut Call<'_>) -> Result<PhpValue> {
+    let (haystack, needle) = (c.string(0)?, c.string(1)?);
+    let offset = if c.args.len() > 2 { c.int(2)? } else { 0 };
+    let len = haystack.len() as i64;
+    let from = if offset < 0 { len + offset } else { offset };
+    if !(0..=len).contains(&from) {
+        return Err(c.invalid(2, "must be contained in argument #1 ($haystack)"));
+    }
+    Ok(find(&haystack, &needle, from as usize)
+        .map_or(PhpValue::Bool(false), |at| PhpValue::Int(at as i64)))
+}
+
+fn substr(c: &mut Call<'_>) -> Result<PhpValue> {
+    let s = c.string(0)?;
+    let len = s.len() as i64;
+    let offset = c.int(1)?;
+    let start = if offset < 0 {
+        len.saturating_add(offset).max(0)
+    } else {
+        offset.min(len)
+    };
+    let end = match c.args.get(2) {
+        None | Some(PhpValue::Null) => len,
+        Some(_) => match c.int(2)? {
+            length if length < 0 => len.saturating_add(length),
+            length => start.saturating_add(length).min(len),
+        },
+    };
+    let bytes = if start < end {
+        s[start as usize..end as usize].to_vec()
+    } else {
+        Vec::new()
+    };
+    Ok(PhpValue::String(bytes))
+}
+
+fn trim(c: &mut Call<'_>, left: bool, right: bool) -> Result<PhpValue> {
+    let s = c.string(0)?;
+    let mask = match c.args.get(1) {
+        Some(_) => char_mask(&c.string(1)?),
+        None => char_mask(b" \n\r\t\x0b\0"),
+    };
+    let mut bytes = &s[..];
+    while left && bytes.first().is_some_and(|b| mask[*b as usize]) {
+        bytes = &bytes[1..];
+    }
+    while right && bytes.last().is_some_and(|b| mask[*b as usize]) {
+        bytes = &bytes[..bytes.len() - 1];
+    }
+    Ok(PhpValue::String(bytes.to_vec()))
+}
+
+/// The bytes a `trim()` character list names, with `a..z` ranges.
+fn char_mask(list: &[u8]) -> [bool; 256] {
+    let mut mask = [false; 256];
+    let mut i = 0;
+    while i < list.len() {
+        if list.get(i + 1..i + 3) == Some(b"..") && i + 3 < list.len() && list[i] <= list[i + 3] {
+            for b in list[i]..=list[i + 3] {
+                mask[b as usize] = true;
+            }
+            i += 4;
+        } else {
+            mask[list[i] as usize] = true;
+            i += 1;
+        }
+    }
+    mask
+}
+
+/// The first position of `needle` in `haystack` at or after `from`.
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(from);
+    }
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|at| from + at)
+}
+
+/// `var_dump()` output for `value`, indented by `depth` levels.
+fn dump(out: &mut Vec<u8>, value: &PhpValue, depth: usize) {
+    match value {
+        PhpValue::Null => out.extend_from_slice(b"NULL\n"),
+        PhpValue::Bool(b) => out.extend(format!("bool({b})\n").bytes()),
+        PhpValue::Int(n) => out.extend(format!("int({n})\n").bytes()),
+        PhpValue::Float(_) => {
+            // `var_dump()` drops the `.0` that PHP literal syntax needs.
+            let text = value.to_string();
+            let text = match text.strip_suffix(".0") {
+                Some(whole) if !text.contains('E') => whole,
+                _ => &text,
+            };
+            out.extend(format!("float({text})\n").bytes());
+        }
+        PhpValue::String(s) => {
+            out.extend(format!("string({}) \"", s.len()).bytes());
+            out.extend_from_slice(s);
+            out.extend_from_slice(b"\"\n");
+        }
+        PhpValue::Array(entries) => {
+            let indent = "  ".repeat(depth + 1);
+            out.extend(format!("array({}) {{\n", entries.len()).bytes());
+            for (key, value) in entries {
+                out.extend_from_slice(indent.as_bytes());
+                match key {
+                    ArrayKey::Int(n) => out.extend(format!("[{n}]=>\n").bytes()),
+                    ArrayKey::String(s) => {
+                        out.extend_from_slice(b"[\"");
+                        out.extend_from_slice(s);
+                        out.extend_from_slice(b"\"]=>\n");
+                    }
+                }
+                out.extend_from_slice(indent.as_bytes());
+                dump(out, value, depth + 1);
+            }
+            out.extend("  ".repeat(depth).bytes());
+            out.extend_from_slice(b"}\n");
+        }
+    }
+}
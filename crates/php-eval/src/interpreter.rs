@@ -0,0 +1,1217 @@
+//! The statement and expression evaluator.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use php_analysis::values::{self, ArrayKey, PhpValue};
+use php_ast::{
+    Arg, ArrayElement, AssignExpr, AssignOp, BinaryExpr, BinaryOp, CastKind, ConstItem,
+    DoWhileStmt, Expr, ExprKind, ForStmt, ForeachStmt, FunctionCallExpr, FunctionDecl, IfStmt,
+    MatchExpr, Name, NullCoalesceExpr, Program, Span, Stmt, StmtKind, StringPart, SwitchStmt,
+    TernaryExpr, UnaryPostfixOp, UnaryPrefixOp, WhileStmt,
+};
+use php_rs_parser::bytes::encode;
+
+use crate::builtins;
+use crate::EvalError;
+
+type Result<T> = std::result::Result<T, EvalError>;
+
+/// Runs programs and expressions of the supported subset; see the crate
+/// documentation for what that covers.
+///
+/// Variables, functions, constants, and output persist across calls to
+/// [`run`](Self::run) and [`eval`](Self::eval), so a program can set up
+/// state that later expressions read.
+///
+/// ```
+/// use php_analysis::values::PhpValue;
+/// use php_eval::{EvalError, Interpreter};
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php $total = 0; for ($i = 1; $i <= 4; $i++) { $total += $i ** 2; } $total / 0;");
+/// let mut interpreter = Interpreter::new();
+/// let err = interpreter.run(&result.program).unwrap_err();
+/// assert_eq!(interpreter.variable("total"), Some(&PhpValue::Int(30)));
+/// assert!(matches!(err, EvalError::Error { message, .. } if message == "Division by zero"));
+/// ```
+#[derive(Debug)]
+pub struct Interpreter<'p, 'arena, 'src> {
+    /// User functions by lower-cased name.
+    functions: HashMap<String, &'p FunctionDecl<'arena, 'src>>,
+    constants: HashMap<String, PhpValue>,
+    /// The global scope, then the local scope of each active call.
+    frames: Vec<HashMap<String, PhpValue>>,
+    output: Vec<u8>,
+    steps: u64,
+    step_limit: u64,
+    depth_limit: usize,
+}
+
+/// How a statement finished.
+enum Flow {
+    Normal,
+    Break(u64),
+    Continue(u64),
+    Return(PhpValue),
+}
+
+/// An assignable location: a variable and the keys into it, with `None`
+/// for `[]`.
+struct Place {
+    var: String,
+    path: Vec<Option<ArrayKey>>,
+}
+
+impl Default for Interpreter<'_, '_, '_> {
+    fn default() -> Self {
+        Self {
+            functions: HashMap::new(),
+            constants: HashMap::new(),
+            frames: vec![HashMap::new()],
+            output: Vec::new(),
+            steps: 0,
+            step_limit: Self::DEFAULT_STEP_LIMIT,
+            depth_limit: Self::DEFAULT_DEPTH_LIMIT,
+        }
+    }
+}
+
+impl<'p, 'arena, 'src> Interpreter<'p, 'arena, 'src> {
+    /// Statements, loop iterations, and calls run before
+    /// [`EvalError::StepLimit`].
+    pub const DEFAULT_STEP_LIMIT: u64 = 1_000_000;
+    /// User function calls nested before [`EvalError::DepthLimit`].
+    pub const DEFAULT_DEPTH_LIMIT: usize = 128;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop with [`EvalError::StepLimit`] after `limit` statements, loop
+    /// iterations, and calls in total.
+    pub fn with_step_limit(mut self, limit: u64) -> Self {
+        self.step_limit = limit;
+        self
+    }
+
+    /// Stop with [`EvalError::DepthLimit`] when user function calls nest
+    /// deeper than `limit`.
+    pub fn with_depth_limit(mut self, limit: usize) -> Self {
+        self.depth_limit = limit;
+        self
+    }
+
+    /// Run a program. Functions declared at the top level are available
+    /// before their declaration, as in PHP.
+    pub fn run(&mut self, program: &'p Program<'arena, 'src>) -> Result<()> {
+        for stmt in program.stmts.iter() {
+            if let StmtKind::Function(func) = &stmt.kind {
+                self.declare(func, stmt.span)?;
+            }
+        }
+        self.block(&program.stmts)?;
+        Ok(())
+    }
+
+    /// Evaluate an expression in the global scope.
+    pub fn eval(&mut self, expr: &Expr<'arena, 'src>) -> Result<PhpValue> {
+        self.expr(expr)
+    }
+
+    /// Everything written by `echo`, `print`, `var_dump()`, and inline HTML.
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// The value of a global variable, named without the `$`.
+    pub fn variable(&self, name: &str) -> Option<&PhpValue> {
+        self.frames[0].get(name)
+    }
+
+    /// Set a global variable, named without the `$`.
+    pub fn set_variable(&mut self, name: &str, value: PhpValue) {
+        self.frames[0].insert(name.to_string(), value);
+    }
+
+    /// Define a global constant, as `define()` does. Returns `false`, and
+    /// leaves the constant as it was, if it is already defined.
+    pub fn define(&mut self, name: &str, value: PhpValue) -> bool {
+        builtins::define(&mut self.constants, name, value)
+    }
+
+    fn step(&mut self) -> Result<()> {
+        self.steps += 1;
+        if self.steps > self.step_limit {
+            return Err(EvalError::StepLimit);
+        }
+        Ok(())
+    }
+
+    fn frame(&self) -> &HashMap<String, PhpValue> {
+        self.frames
+            .last()
+            .expect("the global scope is never popped")
+    }
+
+    fn frame_mut(&mut self) -> &mut HashMap<String, PhpValue> {
+        self.frames
+            .last_mut()
+            .expect("the global scope is never popped")
+    }
+
+    fn declare(&mut self, func: &'p FunctionDecl<'arena, 'src>, span: Span) -> Result<()> {
+        let name = func.name.or_error();
+        let key = name.to_ascii_lowercase();
+        match self.functions.get(&key) {
+            Some(declared) if std::ptr::eq(*declared, func) => Ok(()),
+            Some(_) => Err(error(format!("Cannot redeclare {name}()"), span)),
+            None if builtins::lookup(&key).is_some() => {
+                Err(error(format!("Cannot redeclare {name}()"), span))
+            }
+            None => {
+                self.functions.insert(key, func);
+                Ok(())
+            }
+        }
+    }
+
+    // --- Statements ---
+
+    fn block(&mut self, stmts: &'p [Stmt<'arena, 'src>]) -> Result<Flow> {
+        for stmt in stmts {
+            match self.stmt(stmt)? {
+                Flow::Normal => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn stmt(&mut self, stmt: &'p Stmt<'arena, 'src>) -> Result<Flow> {
+        self.step()?;
+        // As in `expr`, the arms that evaluate anything call out.
+        match &stmt.kind {
+            StmtKind::Expression(expr) => {
+                self.expr(expr)?;
+                Ok(Flow::Normal)
+            }
+            StmtKind::Echo(echo) => self.echo(&echo.exprs),
+            StmtKind::Return(Some(expr)) => Ok(Flow::Return(self.expr(expr)?)),
+            StmtKind::Return(None) => Ok(Flow::Return(PhpValue::Null)),
+            StmtKind::Block(stmts) => self.block(stmts),
+            StmtKind::If(if_stmt) => self.if_stmt(if_stmt),
+            StmtKind::While(while_stmt) => self.while_stmt(while_stmt),
+            StmtKind::DoWhile(do_while) => self.do_while(do_while),
+            StmtKind::For(for_stmt) => self.for_stmt(for_stmt),
+            StmtKind::Foreach(foreach) => self.foreach(foreach),
+            StmtKind::Switch(switch) => self.switch(switch),
+            StmtKind::Break(level) => Ok(Flow::Break(self.level(*level)?)),
+            StmtKind::Continue(level) => Ok(Flow::Continue(self.level(*level)?)),
+            StmtKind::Function(func) => {
+                self.declare(func, stmt.span)?;
+                Ok(Flow::Normal)
+            }
+            StmtKind::Const(items) => self.const_stmt(items),
+            StmtKind::Unset(exprs) => {
+                for expr in exprs.iter() {
+                    self.unset(expr)?;
+                }
+                Ok(Flow::Normal)
+            }
+            StmtKind::Declare(declare) => match declare.body {
+                Some(body) => self.stmt(body),
+                None => Ok(Flow::Normal),
+            },
+            StmtKind::InlineHtml(html) => {
+                self.output.extend_from_slice(&encode(html.output()));
+                Ok(Flow::Normal)
+            }
+            StmtKind::HaltCompiler(_) => Ok(Flow::Return(PhpValue::Null)),
+            StmtKind::Nop | StmtKind::Label(_) => Ok(Flow::Normal),
+            kind => Err(unsupported(stmt_construct(kind), stmt.span)),
+        }
+    }
+
+    fn echo(&mut self, exprs: &[Expr<'arena, 'src>]) -> Result<Flow> {
+        for expr in exprs {
+            let value = self.expr(expr)?;
+            self.output.extend(to_string(&value));
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn if_stmt(&mut self, if_stmt: &'p IfStmt<'arena, 'src>) -> Result<Flow> {
+        if self.expr(&if_stmt.condition)?.to_bool() {
+            return self.stmt(if_stmt.then_branch);
+        }
+        for branch in if_stmt.elseif_branches.iter() {
+            if self.expr(&branch.condition)?.to_bool() {
+                return self.stmt(&branch.body);
+            }
+        }
+        match if_stmt.else_branch {
+            Some(else_branch) => self.stmt(else_branch),
+            None => Ok(Flow::Normal),
+        }
+    }
+
+    fn while_stmt(&mut self, while_stmt: &'p WhileStmt<'arena, 'src>) -> Result<Flow> {
+        while self.expr(&while_stmt.condition)?.to_bool() {
+            if let ControlFlow::Break(flow) = self.iteration(while_stmt.body)? {
+                return Ok(flow);
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn do_while(&mut self, do_while: &'p DoWhileStmt<'arena, 'src>) -> Result<Flow> {
+        loop {
+            if let ControlFlow::Break(flow) = self.iteration(do_while.body)? {
+                return Ok(flow);
+            }
+            if !self.expr(&do_while.condition)?.to_bool() {
+                return Ok(Flow::Normal);
+            }
+        }
+    }
+
+    fn for_stmt(&mut self, for_stmt: &'p ForStmt<'arena, 'src>) -> Result<Flow> {
+        for expr in for_stmt.init.iter() {
+            self.expr(expr)?;
+        }
+        loop {
+            // Every condition is evaluated; the last one decides.
+            let mut go = true;
+            for expr in for_stmt.condition.iter() {
+                go = self.expr(expr)?.to_bool();
+            }
+            if !go {
+                return Ok(Flow::Normal);
+            }
+            if let ControlFlow::Break(flow) = self.iteration(for_stmt.body)? {
+                return Ok(flow);
+            }
+            for expr in for_stmt.update.iter() {
+                self.expr(expr)?;
+            }
+        }
+    }
+
+    fn foreach(&mut self, foreach: &'p ForeachStmt<'arena, 'src>) -> Result<Flow> {
+        // PHP warns and skips the loop for anything but an array.
+        let PhpValue::Array(entries) = self.expr(&foreach.expr)? else {
+            return Ok(Flow::Normal);
+        };
+        for (key, value) in entries {
+            if let Some(target) = &foreach.key {
+                self.assign_to(target, key.to_value())?;
+            }
+            self.assign_to(&foreach.value, value)?;
+            if let ControlFlow::Break(flow) = self.iteration(foreach.body)? {
+                return Ok(flow);
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn switch(&mut self, switch: &'p SwitchStmt<'arena, 'src>) -> Result<Flow> {
+        let subject = self.expr(&switch.expr)?;
+        let mut start = None;
+        for (i, case) in switch.cases.iter().enumerate() {
+            if let Some(value) = &case.value {
+                if subject.loose_eq(&self.expr(value)?) {
+                    start = Some(i);
+                    break;
+                }
+            }
+        }
+        let start = start.or_else(|| switch.cases.iter().position(|c| c.value.is_none()));
+        for case in start.map_or(&[][..], |start| &switch.cases[start..]) {
+            // `continue` targets a `switch` as `break` does.
+            match self.block(&case.body)? {
+                Flow::Normal => {}
+                Flow::Break(1) | Flow::Continue(1) => break,
+                Flow::Break(n) => return Ok(Flow::Break(n - 1)),
+                Flow::Continue(n) => return Ok(Flow::Continue(n - 1)),
+                flow @ Flow::Return(_) => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn const_stmt(&mut self, items: &[ConstItem<'arena, 'src>]) -> Result<Flow> {
+        for item in items {
+            let value = self.expr(&item.value)?;
+            // PHP warns and keeps the first definition.
+            builtins::define(&mut self.constants, item.name.or_error(), value);
+        }
+        Ok(Flow::Normal)
+    }
+
+    /// Run one loop iteration, and say whether the loop goes on or
+    /// finishes with a flow for the enclosing statement.
+    fn iteration(&mut self, body: &'p Stmt<'arena, 'src>) -> Result<ControlFlow<Flow>> {
+        Ok(match self.stmt(body)? {
+            Flow::Normal | Flow::Continue(1) => ControlFlow::Continue(()),
+            Flow::Break(1) => ControlFlow::Break(Flow::Normal),
+            Flow::Break(n) => ControlFlow::Break(Flow::Break(n - 1)),
+            Flow::Continue(n) => ControlFlow::Break(Flow::Continue(n - 1)),
+            flow @ Flow::Return(_) => ControlFlow::Break(flow),
+        })
+    }
+
+    /// The number of loops a `break` or `continue` leaves.
+    fn level(&mut self, level: Option<&Expr<'arena, 'src>>) -> Result<u64> {
+        Ok(match level {
+            Some(expr) => match self.expr(expr)? {
+                PhpValue::Int(n) if n >= 1 => n as u64,
+                _ => 1,
+            },
+            None => 1,
+        })
+    }
+
+    fn unset(&mut self, expr: &Expr<'arena, 'src>) -> Result<()> {
+        match &expr.kind {
+            ExprKind::Variable(name) => {
+                self.frame_mut().remove(name.as_str());
+            }
+            ExprKind::ArrayAccess(access) => {
+                let place = self.place(access.array)?;
+                let Some(index) = access.index else {
+                    return Err(error("Cannot use [] for unsetting", expr.span));
+                };
+                let key = array_key(&self.expr(index)?, index.span)?;
+                self.unset_key(&place, key, expr.span)?;
+            }
+            _ => return Err(unsupported(expr_construct(&expr.kind), expr.span)),
+        }
+        Ok(())
+    }
+
+    /// Remove `key` from the array at `place`, if both exist.
+    fn unset_key(&mut self, place: &Place, key: ArrayKey, span: Span) -> Result<()> {
+        match self.slot_mut(place) {
+            Some(PhpValue::Array(entries)) => entries.retain(|(k, _)| *k != key),
+            Some(PhpValue::String(_)) => return Err(error("Cannot unset string offsets", span)),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // --- Expressions ---
+
+    fn expr(&mut self, expr: &Expr<'arena, 'src>) -> Result<PhpValue> {
+        // Each arm calls out rather than evaluating in place, so this frame,
+        // which every level of recursion passes through, stays small.
+        match &expr.kind {
+            ExprKind::Int(n) => Ok(PhpValue::Int(*n)),
+            ExprKind::Float(f) => Ok(PhpValue::Float(*f)),
+            ExprKind::String { value, .. } | ExprKind::Nowdoc { value, .. } => {
+                Ok(PhpValue::String(encode(value).into_owned()))
+            }
+            ExprKind::InterpolatedString(parts) | ExprKind::Heredoc { parts, .. } => {
+                self.interpolate(parts)
+            }
+            ExprKind::Bool(b) => Ok(PhpValue::Bool(*b)),
+            ExprKind::Null => Ok(PhpValue::Null),
+            ExprKind::Variable(_) | ExprKind::ArrayAccess(_) => self.read(expr, false),
+            ExprKind::ConstFetch(name) => self.constant(name, expr),
+            ExprKind::Assign(assign) => self.assign(assign, expr.span),
+            ExprKind::Binary(binary) => self.binary(binary, expr.span),
+            ExprKind::UnaryPrefix(unary) => match unary.op {
+                UnaryPrefixOp::PreIncrement => self.increment(unary.operand, true, true),
+                UnaryPrefixOp::PreDecrement => self.increment(unary.operand, false, true),
+                op => self.unary(op, unary.operand, expr.span),
+            },
+            ExprKind::UnaryPostfix(unary) => {
+                let up = unary.op == UnaryPostfixOp::PostIncrement;
+                self.increment(unary.operand, up, false)
+            }
+            ExprKind::Ternary(ternary) => self.ternary(ternary),
+            ExprKind::NullCoalesce(coalesce) => self.coalesce(coalesce),
+            ExprKind::FunctionCall(call) => self.call(call, expr.span),
+            ExprKind::Array(elements) => self.array(elements),
+            ExprKind::Print(print) => self.print(print.expr),
+            ExprKind::Parenthesized(inner) => self.expr(inner),
+            ExprKind::Cast(kind, inner) => self.cast(*kind, inner, expr.span),
+            ExprKind::ErrorSuppress(suppress) => self.expr(suppress.expr),
+            ExprKind::Isset(exprs) => self.isset(exprs),
+            ExprKind::Empty(inner) => Ok(PhpValue::Bool(!self.read(inner, true)?.to_bool())),
+            ExprKind::Match(match_expr) => self.match_expr(match_expr, expr.span),
+            kind => Err(unsupported(expr_construct(kind), expr.span)),
+        }
+    }
+
+    fn interpolate(&mut self, parts: &[StringPart<'arena, 'src>]) -> Result<PhpValue> {
+        let mut text = Vec::new();
+        for part in parts {
+            match part {
+                StringPart::Literal { value, .. } => text.extend_from_slice(&encode(value)),
+                StringPart::Expr(expr) => text.extend(to_string(&self.expr(expr)?)),
+            }
+        }
+        Ok(PhpValue::String(text))
+    }
+
+    fn unary(
+        &mut self,
+        op: UnaryPrefixOp,
+        operand: &Expr<'arena, 'src>,
+        span: Span,
+    ) -> Result<PhpValue> {
+        let operand = self.expr(operand)?;
+        values::unary_op(op, &operand).ok_or_else(|| {
+            let message = match op {
+                UnaryPrefixOp::BitwiseNot => {
+                    format!("Cannot perform bitwise not on {}", operand.type_name())
+                }
+                // PHP negates by multiplying with -1.
+                _ => format!("Unsupported operand types: {} * int", operand.type_name()),
+            };
+            error(message, span)
+        })
+    }
+
+    fn ternary(&mut self, ternary: &TernaryExpr<'arena, 'src>) -> Result<PhpValue> {
+        let condition = self.expr(ternary.condition)?;
+        match (condition.to_bool(), ternary.then_expr) {
+            (true, Some(then_expr)) => self.expr(then_expr),
+            (true, None) => Ok(condition),
+            (false, _) => self.expr(ternary.else_expr),
+        }
+    }
+
+    fn coalesce(&mut self, coalesce: &NullCoalesceExpr<'arena, 'src>) -> Result<PhpValue> {
+        match self.read(coalesce.left, true)? {
+            PhpValue::Null => self.expr(coalesce.right),
+            value => Ok(value),
+        }
+    }
+
+    fn print(&mut self, expr: &Expr<'arena, 'src>) -> Result<PhpValue> {
+        let value = self.expr(expr)?;
+        self.output.extend(to_string(&value));
+        Ok(PhpValue::Int(1))
+    }
+
+    fn cast(&mut self, kind: CastKind, inner: &Expr<'arena, 'src>, span: Span) -> Result<PhpValue> {
+        let value = self.expr(inner)?;
+        match kind {
+            CastKind::String => Ok(PhpValue::String(to_string(&value))),
+            CastKind::Void => Ok(PhpValue::Null),
+            CastKind::Object => Err(unsupported("`(object)`", span)),
+            CastKind::Unset => Err(unsupported("`(unset)`", span)),
+            _ => values::cast(kind, value)
+                .ok_or_else(|| unsupported("an out-of-range float to int conversion", span)),
+        }
+    }
+
+    fn isset(&mut self, exprs: &[Expr<'arena, 'src>]) -> Result<PhpValue> {
+        for expr in exprs {
+            if self.read(expr, true)? == PhpValue::Null {
+                return Ok(PhpValue::Bool(false));
+            }
+        }
+        Ok(PhpValue::Bool(true))
+    }
+
+    /// Read a variable or array element. Undefined ones are `null`; when
+    /// `quiet`, as in `isset()`, `empty()`, and `??`, so are invalid string
+    /// offsets.
+    fn read(&mut self, expr: &Expr<'arena, 'src>, quiet: bool) -> Result<PhpValue> {
+        match &expr.kind {
+            ExprKind::Variable(name) => Ok(self
+                .frame()
+                .get(name.as_str())
+                .cloned()
+                .unwrap_or(PhpValue::Null)),
+            ExprKind::ArrayAccess(access) => {
+                let container = self.read(access.array, quiet)?;
+                let Some(index) = access.index else {
+                    return Err(error("Cannot use [] for reading", expr.span));
+                };
+                let index = self.expr(index)?;
+                offset(container, &index, quiet, expr.span)
+            }
+            _ => self.expr(expr),
+        }
+    }
+
+    fn constant(
+        &mut self,
+        name: &Name<'arena, 'src>,
+        expr: &Expr<'arena, 'src>,
+    ) -> Result<PhpValue> {
+        let name = name.join_parts();
+        if let Some(value) = self.constants.get(name.as_ref()) {
+            return Ok(value.clone());
+        }
+        values::evaluate(expr)
+            .ok_or_else(|| error(format!("Undefined constant \"{name}\""), expr.span))
+    }
+
+    fn binary(&mut self, binary: &BinaryExpr<'arena, 'src>, span: Span) -> Result<PhpValue> {
+        let left = self.expr(binary.left)?;
+        match binary.op {
+            BinaryOp::BooleanAnd | BinaryOp::LogicalAnd => {
+                return Ok(PhpValue::Bool(
+                    left.to_bool() && self.expr(binary.right)?.to_bool(),
+                ))
+            }
+            BinaryOp::BooleanOr | BinaryOp::LogicalOr => {
+                return Ok(PhpValue::Bool(
+                    left.to_bool() || self.expr(binary.right)?.to_bool(),
+                ))
+            }
+            BinaryOp::Pipe => return Err(unsupported("`|>`", span)),
+            _ => {}
+        }
+        let right = self.expr(binary.right)?;
+        operate(binary.op, &left, &right, span)
+    }
+
+    fn assign(&mut self, assign: &AssignExpr<'arena, 'src>, span: Span) -> Result<PhpValue> {
+        if assign.by_ref {
+            return Err(unsupported("a reference", span));
+        }
+        if let (AssignOp::Assign, ExprKind::List(list)) = (assign.op, &assign.target.kind) {
+            let value = self.expr(assign.value)?;
+            self.destructure(&list.elements, &value)?;
+            return Ok(value);
+        }
+        let place = self.place(assign.target)?;
+        let value = match assign.op {
+            AssignOp::Assign => self.expr(assign.value)?,
+            AssignOp::Coalesce => match self.load(&place) {
+                PhpValue::Null => self.expr(assign.value)?,
+                current => return Ok(current),
+            },
+            op => {
+                let value = self.expr(assign.value)?;
+                operate(compound_op(op), &self.load(&place), &value, span)?
+            }
+        };
+        self.store(&place, value.clone(), span)?;
+        Ok(value)
+    }
+
+    /// Assign `value` to a variable, array element, or `[...]`/`list()`
+    /// pattern.
+    fn assign_to(&mut self, target: &Expr<'arena, 'src>, value: PhpValue) -> Result<()> {
+        match &target.kind {
+            ExprKind::List(list) => self.destructure(&list.elements, &value),
+            _ => {
+                let place = self.place(target)?;
+                self.store(&place, value, target.span)
+            }
+        }
+    }
+
+    fn destructure(
+        &mut self,
+        elements: &[ArrayElement<'arena, 'src>],
+        value: &PhpValue,
+    ) -> Result<()> {
+        let mut next = 0;
+        for element in elements {
+            if matches!(element.value.kind, ExprKind::Omit) {
+                next += 1;
+                continue;
+            }
+            if element.by_ref {
+                return Err(unsupported("a reference", element.span));
+            }
+            let key = match &element.key {
+                Some(key) => array_key(&self.expr(key)?, key.span)?,
+                None => {
+                    next += 1;
+                    ArrayKey::Int(next - 1)
+                }
+            };
+            let item = match value {
+                PhpValue::Array(entries) => entries
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map_or(PhpValue::Null, |(_, v)| v.clone()),
+                _ => PhpValue::Null,
+            };
+            self.assign_to(&element.value, item)?;
+        }
+        Ok(())
+    }
+
+    /// `++` or `--` on a variable or array element, giving the new value
+    /// for the prefix form and the old one for the postfix form.
+    fn increment(
+        &mut self,
+        operand: &Expr<'arena, 'src>,
+        up: bool,
+        prefix: bool,
+    ) -> Result<PhpValue> {
+        let place = self.place(operand)?;
+        let old = self.load(&place);
+        let new = step(&old, up).ok_or_else(|| {
+            let verb = if up { "increment" } else { "decrement" };
+            error(format!("Cannot {verb} {}", old.type_name()), operand.span)
+        })?;
+        self.store(&place, new.clone(), operand.span)?;
+        Ok(if prefix { new } else { old })
+    }
+
+    fn place(&mut self, expr: &Expr<'arena, 'src>) -> Result<Place> {
+        match &expr.kind {
+            ExprKind::Variable(name) => Ok(Place {
+                var: name.to_string(),
+                path: Vec::new(),
+            }),
+            ExprKind::ArrayAccess(access) => {
+                let mut place = self.place(access.array)?;
+                let key = match access.index {
+                    Some(index) => Some(array_key(&self.expr(index)?, index.span)?),
+                    None => None,
+                };
+                place.path.push(key);
+                Ok(place)
+            }
+            kind => Err(unsupported(expr_construct(kind), expr.span)),
+        }
+    }
+
+    /// The value at `place`, or `null` if it does not exist.
+    fn load(&self, place: &Place) -> PhpValue {
+        let mut value = self.frame().get(&place.var);
+        for key in &place.path {
+            value = match (value, key) {
+                (Some(PhpValue::Array(entries)), Some(key)) => {
+                    entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+                }
+                _ => None,
+            };
+        }
+        value.cloned().unwrap_or(PhpValue::Null)
+    }
+
+    /// The existing value at `place`, without creating anything.
+    fn slot_mut(&mut self, place: &Place) -> Option<&mut PhpValue> {
+        let mut value = self.frames.last_mut()?.get_mut(&place.var)?;
+        for key in &place.path {
+            let (PhpValue::Array(entries), Some(key)) = (value, key) else {
+                return None;
+            };
+            value = entries.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)?;
+        }
+        Some(value)
+    }
+
+    /// Write `value` to `place`, turning `null` and `false` on the way into
+    /// arrays as PHP does.
+    fn store(&mut self, place: &Place, value: PhpValue, span: Span) -> Result<()> {
+        let mut slot = self
+            .frame_mut()
+            .entry(place.var.clone())
+            .or_insert(PhpValue::Null);
+        for key in &place.path {
+            if matches!(slot, PhpValue::Null | PhpValue::Bool(false)) {
+                *slot = PhpValue::Array(Vec::new());
+            }
+            slot = match slot {
+                PhpValue::Array(entries) => {
+                    let key = match key {
+                        Some(key) => key.clone(),
+                        None => next_key(entries, span)?,
+                    };
+                    let i = match entries.iter().position(|(k, _)| *k == key) {
+                        Some(i) => i,
+                        None => {
+                            entries.push((key, PhpValue::Null));
+                            entries.len() - 1
+                        }
+                    };
+                    &mut entries[i].1
+                }
+                PhpValue::String(_) => return Err(unsupported("a string offset write", span)),
+                _ => return Err(error("Cannot use a scalar value as an array", span)),
+            };
+        }
+        *slot = value;
+        Ok(())
+    }
+
+    fn array(&mut self, elements: &[ArrayElement<'arena, 'src>]) -> Result<PhpValue> {
+        let mut entries = Vec::new();
+        for element in elements {
+            if element.by_ref {
+                return Err(unsupported("a reference", element.span));
+            }
+            let key = match &element.key {
+                Some(key) => Some(array_key(&self.expr(key)?, key.span)?),
+                None => None,
+            };
+            let value = self.expr(&element.value)?;
+            if element.unpack {
+                // `...` renumbers integer keys and overwrites string keys.
+                let PhpValue::Array(items) = value else {
+                    return Err(error(
+                        "Only arrays and Traversables can be unpacked",
+                        element.span,
+                    ));
+                };
+                for (key, value) in items {
+                    let key = match key {
+                        ArrayKey::Int(_) => next_key(&entries, element.span)?,
+                        key => key,
+                    };
+                    set(&mut entries, key, value);
+                }
+                continue;
+            }
+            let key = match key {
+                Some(key) => key,
+                None => next_key(&entries, element.span)?,
+            };
+            set(&mut entries, key, value);
+        }
+        Ok(PhpValue::Array(entries))
+    }
+
+    fn match_expr(&mut self, match_expr: &MatchExpr<'arena, 'src>, span: Span) -> Result<PhpValue> {
+        let subject = self.expr(match_expr.subject)?;
+        for arm in match_expr.arms.iter() {
+            for condition in arm.conditions.iter().flat_map(|c| c.iter()) {
+                if self.expr(condition)? == subject {
+                    return self.expr(&arm.body);
+                }
+            }
+        }
+        match match_expr.arms.iter().find(|arm| arm.conditions.is_none()) {
+            Some(arm) => self.expr(&arm.body),
+            None => {
+                let case = match &subject {
+                    PhpValue::Null => "NULL".to_string(),
+                    PhpValue::Array(_) => "of type array".to_string(),
+                    scalar => scalar.to_string(),
+                };
+                Err(error(format!("Unhandled match case {case}"), span))
+            }
+        }
+    }
+
+    // --- Calls ---
+
+    fn call(&mut self, call: &FunctionCallExpr<'arena, 'src>, span: Span) -> Result<PhpValue> {
+        let ExprKind::Name(name) = &call.name.kind else {
+            return Err(unsupported("a dynamic function call", call.name.span));
+        };
+        let name = name.join_parts();
+        let key = name.to_ascii_lowercase();
+        let args = self.args(&call.args)?;
+        self.step()?;
+        if let Some(&func) = self.functions.get(&key) {
+            return self.call_user(func, args, span);
+        }
+        let Some(builtin) = builtins::lookup(&key) else {
+            return Err(error(format!("Call to undefined function {name}()"), span));
+        };
+        let mut positional = Vec::with_capacity(args.len());
+        for (name, value) in args {
+            if name.is_some() {
+                return Err(unsupported("a named argument to a built-in function", span));
+            }
+            positional.push(value);
+        }
+        builtin.call(positional, span, &mut self.output, &mut self.constants)
+    }
+
+    /// Evaluate arguments, spreading `...` and keeping names.
+    fn args(&mut self, args: &[Arg<'arena, 'src>]) -> Result<Vec<(Option<String>, PhpValue)>> {
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            if arg.by_ref {
+                return Err(unsupported("a reference", arg.span));
+            }
+            let value = self.expr(&arg.value)?;
+            if arg.unpack {
+                let PhpValue::Array(entries) = value else {
+                    return Err(error(
+                        "Only arrays and Traversables can be unpacked",
+                        arg.span,
+                    ));
+                };
+                for (key, value) in entries {
+                    let name = match key {
+                        ArrayKey::Int(_) => None,
+                        ArrayKey::String(name) => Some(String::from_utf8_lossy(&name).into_owned()),
+                    };
+                    values.push((name, value));
+                }
+                continue;
+            }
+            let name = arg.name.as_ref().map(|name| name.join_parts().into_owned());
+            values.push((name, value));
+        }
+        Ok(values)
+    }
+
+    fn call_user(
+        &mut self,
+        func: &'p FunctionDecl<'arena, 'src>,
+        args: Vec<(Option<String>, PhpValue)>,
+        span: Span,
+    ) -> Result<PhpValue> {
+        if self.frames.len() > self.depth_limit {
+            return Err(EvalError::DepthLimit { span });
+        }
+        let frame = self.bind(func, args, span)?;
+        self.frames.push(frame);
+        let flow = self.block(&func.body);
+        self.frames.pop();
+        Ok(match flow? {
+            Flow::Return(value) => value,
+            _ => PhpValue::Null,
+        })
+    }
+
+    /// The local scope of a call: each parameter bound to its argument or
+    /// default.
+    fn bind(
+        &mut self,
+        func: &FunctionDecl<'arena, 'src>,
+        args: Vec<(Option<String>, PhpValue)>,
+        span: Span,
+    ) -> Result<HashMap<String, PhpValue>> {
+        let name = func.name.or_error();
+        let params = &func.params;
+        let variadic = params.last().filter(|param| param.variadic);
+        let fixed = params.len() - usize::from(variadic.is_some());
+        let mut bound: Vec<Option<PhpValue>> = vec![None; fixed];
+        let mut rest = Vec::new();
+        let passed = args.len();
+        let mut named = false;
+        for (i, (arg_name, value)) in args.into_iter().enumerate() {
+            let Some(arg_name) = arg_name else {
+                match bound.get_mut(i) {
+                    Some(slot) => *slot = Some(value),
+                    None if variadic.is_some() => {
+                        let key = next_key(&rest, span)?;
+                        rest.push((key, value));
+                    }
+                    // Extra arguments to a user function are ignored.
+                    None => {}
+                }
+                continue;
+            };
+            named = true;
+            match params[..fixed]
+                .iter()
+                .position(|param| param.name.or_error() == arg_name)
+            {
+                Some(i) if bound[i].is_some() => {
+                    return Err(error(
+                        format!("Named parameter ${arg_name} overwrites previous argument"),
+                        span,
+                    ))
+                }
+                Some(i) => bound[i] = Some(value),
+                None if variadic.is_some() => {
+                    set(&mut rest, ArrayKey::String(arg_name.into_bytes()), value)
+                }
+                None => return Err(error(format!("Unknown named parameter ${arg_name}"), span)),
+            }
+        }
+
+        let mut frame = HashMap::new();
+        for (i, (param, value)) in params.iter().zip(bound).enumerate() {
+            if param.by_ref {
+                return Err(unsupported("a by-reference parameter", param.span));
+            }
+            let value = match (value, &param.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => self.expr(default)?,
+                (None, None) if named => {
+                    let message = format!(
+                        "{name}(): Argument #{} (${}) not passed",
+                        i + 1,
+                        param.name.or_error()
+                    );
+                    return Err(error(message, span));
+                }
+                (None, None) => {
+                    let required = params
+                        .iter()
+                        .rposition(|p| p.default.is_none() && !p.variadic)
+                        .map_or(0, |i| i + 1);
+                    let bound = if required == params.len() {
+                        "exactly"
+                    } else {
+                        "at least"
+                    };
+                    let message = format!(
+                        "Too few arguments to function {name}(), {passed} passed and {bound} {required} expected"
+                    );
+                    return Err(error(message, span));
+                }
+            };
+            frame.insert(param.name.or_error().to_string(), value);
+        }
+        if let Some(param) = variadic {
+            if param.by_ref {
+                return Err(unsupported("a by-reference parameter", param.span));
+            }
+            frame.insert(param.name.or_error().to_string(), PhpValue::Array(rest));
+        }
+        Ok(frame)
+    }
+}
+
+// --- Values ---
+
+fn error(message: impl Into<String>, span: Span) -> EvalError {
+    EvalError::Error {
+        message: message.into(),
+        span,
+    }
+}
+
+fn unsupported(construct: &'static str, span: Span) -> EvalError {
+    EvalError::Unsupported { construct, span }
+}
+
+/// `(string) $value`, with the `'Array'` PHP gives arrays after a warning.
+pub(crate) fn to_string(value: &PhpValue) -> Vec<u8> {
+    value.to_php_string().unwrap_or_else(|| b"Array".to_vec())
+}
+
+pub(crate) fn array_key(value: &PhpValue, span: Span) -> Result<ArrayKey> {
+    ArrayKey::from_value(value).ok_or_else(|| error("Illegal offset type", span))
+}
+
+/// The key `[]` appends with: one past the largest integer key.
+pub(crate) fn next_key(entries: &[(ArrayKey, PhpValue)], span: Span) -> Result<ArrayKey> {
+    let largest = entries
+        .iter()
+        .filter_map(|(key, _)| match key {
+            ArrayKey::Int(n) => Some(*n),
+            ArrayKey::String(_) => None,
+        })
+        .max();
+    match largest {
+        None => Ok(ArrayKey::Int(0)),
+        Some(n) => n.checked_add(1).map(ArrayKey::Int).ok_or_else(|| {
+            error(
+                "Cannot add element to the array as the next element is already occupied",
+                span,
+            )
+        }),
+    }
+}
+
+pub(crate) fn set(entries: &mut Vec<(ArrayKey, PhpValue)>, key: ArrayKey, value: PhpValue) {
+    match entries.iter_mut().find(|(k, _)| *k == key) {
+        Some(entry) => entry.1 = value,
+        None => entries.push((key, value)),
+    }
+}
+
+/// `$container[$index]` for reading.
+fn offset(container: PhpValue, index: &PhpValue, quiet: bool, span: Span) -> Result<PhpValue> {
+    match container {
+        PhpValue::Array(entries) => {
+            let key = match ArrayKey::from_value(index) {
+                Some(key) => key,
+                None if quiet => return Ok(PhpValue::Null),
+                None => return Err(error("Illegal offset type", span)),
+            };
+            Ok(entries
+                .into_iter()
+                .find(|(k, _)| *k == key)
+                .map_or(PhpValue::Null, |(_, v)| v))
+        }
+        PhpValue::String(s) => {
+            let i = match (index, ArrayKey::from_value(index)) {
+                (PhpValue::Int(_) | PhpValue::String(_), Some(ArrayKey::Int(i))) => i,
+                _ if quiet => return Ok(PhpValue::Null),
+                _ => {
+                    let message = format!(
+                        "Cannot access offset of type {} on string",
+                        index.type_name()
+                    );
+                    return Err(error(message, span));
+                }
+            };
+            let i = if i < 0 { i + s.len() as i64 } else { i };
+            match usize::try_from(i).ok().and_then(|i| s.get(i)) {
+                Some(&byte) => Ok(PhpValue::String(vec![byte])),
+                // PHP warns about an uninitialized offset and gives ''.
+                None if quiet => Ok(PhpValue::Null),
+                None => Ok(PhpValue::String(Vec::new())),
+            }
+        }
+        _ => Ok(PhpValue::Null),
+    }
+}
+
+/// A binary operator, with PHP's error for operands it rejects.
+pub(crate) fn operate(
+    op: BinaryOp,
+    left: &PhpValue,
+    right: &PhpValue,
+    span: Span,
+) -> Result<PhpValue> {
+    if let Some(value) = values::binary_op(op, left, right) {
+        return Ok(value);
+    }
+    let numbers = is_number(left) && is_number(right);
+    let message = match op {
+        BinaryOp::Concat => {
+            let mut text = to_string(left);
+            text.extend(to_string(right));
+            return Ok(PhpValue::String(text));
+        }
+        BinaryOp::Div if numbers => "Division by zero".to_string(),
+        BinaryOp::Mod if numbers && right.to_int() == Some(0) => "Modulo by zero".to_string(),
+        BinaryOp::ShiftLeft | BinaryOp::ShiftRight
+            if numbers && matches!(right.to_int(), Some(n) if n < 0) =>
+        {
+            "Bit shift by negative number".to_string()
+        }
+        _ => format!(
+            "Unsupported operand types: {} {} {}",
+            left.type_name(),
+            op.as_str(),
+            right.type_name()
+        ),
+    };
+    Err(error(message, span))
+}
+
+/// Whether `value` is an arithmetic operand: not an array or a string
+/// without a leading number.
+fn is_number(value: &PhpValue) -> bool {
+    values::unary_op(UnaryPrefixOp::Plus, value).is_some()
+}
+
+fn compound_op(op: AssignOp) -> BinaryOp {
+    match op {
+        AssignOp::Plus => BinaryOp::Add,
+        AssignOp::Minus => BinaryOp::Sub,
+        AssignOp::Mul => BinaryOp::Mul,
+        AssignOp::Div => BinaryOp::Div,
+        AssignOp::Mod => BinaryOp::Mod,
+        AssignOp::Pow => BinaryOp::Pow,
+        AssignOp::Concat => BinaryOp::Concat,
+        AssignOp::BitwiseAnd => BinaryOp::BitwiseAnd,
+        AssignOp::BitwiseOr => BinaryOp::BitwiseOr,
+        AssignOp::BitwiseXor => BinaryOp::BitwiseXor,
+        AssignOp::ShiftLeft => BinaryOp::ShiftLeft,
+        AssignOp::ShiftRight => BinaryOp::ShiftRight,
+        _ => unreachable!("not a compound operator"),
+    }
+}
+
+/// `$value + 1` or `$value - 1` as `++` and `--` compute it: `null`
+/// increments to 1 but does not decrement, and non-numeric strings
+/// increment alphanumerically (`'Az'` to `'Ba'`) but do not decrement.
+/// `None` for arrays.
+fn step(value: &PhpValue, up: bool) -> Option<PhpValue> {
+    let op = if up { BinaryOp::Add } else { BinaryOp::Sub };
+    Some(match value {
+        PhpValue::Null if up => PhpValue::Int(1),
+        PhpValue::Null | PhpValue::Bool(_) => value.clone(),
+        PhpValue::Int(_) | PhpValue::Float(_) => values::binary_op(op, value, &PhpValue::Int(1))?,
+        PhpValue::String(s) if s.is_empty() => {
+            if up {
+                PhpValue::String(b"1".to_vec())
+            } else {
+                PhpValue::Int(-1)
+            }
+        }
+        PhpValue::String(s) if values::is_numeric(s) => {
+            values::binary_op(op, value, &PhpValue::Int(1))?
+        }
+        PhpValue::String(s) if up => PhpValue::String(increment_string(s)),
+        PhpValue::String(_) => value.clone(),
+        PhpValue::Array(_) => return None,
+    })
+}
+
+/// Perl-style string increment: the last alphanumeric run counts up, `z`,
+/// `Z`, and `9` carrying into the character before.
+fn increment_string(s: &[u8]) -> Vec<u8> {
+    let mut s = s.to_vec();
+    let mut carry = None;
+    for byte in s.iter_mut().rev() {
+        let (first, last) = match *byte {
+            b'a'..=b'z' => (b'a', b'z'),
+            b'A'..=b'Z' => (b'A', b'Z'),
+            b'0'..=b'9' => (b'0', b'9'),
+            _ => {
+                carry = None;
+                break;
+            }
+        };
+        if *byte == last {
+            *byte = first;
+            carry = Some(if first == b'0' { b'1' } else { first });
+        } else {
+            *byte += 1;
+            carry = None;
+            break;
+        }
+    }
+    if let Some(digit) = carry {
+        s.insert(0, digit);
+    }
+    s
+}
+
+fn stmt_construct(kind: &StmtKind<'_, '_>) -> &'static str {
+    match kind {
+        StmtKind::Goto(_) => "`goto`",
+        StmtKind::Throw(_) => "`throw`",
+        StmtKind::TryCatch(_) => "`try`",
+        StmtKind::Global(_) => "`global`",
+        StmtKind::StaticVar(_) => "a `static` variable",
+        StmtKind::Class(_) => "a class declaration",
+        StmtKind::Interface(_) => "an interface declaration",
+        StmtKind::Trait(_) => "a trait declaration",
+        StmtKind::Enum(_) => "an enum declaration",
+        StmtKind::Namespace(_) => "a namespace",
+        StmtKind::Use(_) => "`use`",
+        StmtKind::Error => "a statement with a syntax error",
+        _ => "this statement",
+    }
+}
+
+fn expr_construct(kind: &ExprKind<'_, '_>) -> &'static str {
+    match kind {
+        ExprKind::ShellExec(_) => "a shell command",
+        ExprKind::VariableVariable(_) => "a variable variable",
+        ExprKind::Instanceof(_) => "`instanceof`",
+        ExprKind::Include(..) => "`include` or `require`",
+        ExprKind::Eval(_) => "`eval()`",
+        ExprKind::Exit(_) => "`exit`",
+        ExprKind::MagicConst(_) => "a magic constant",
+        ExprKind::Clone(_) | ExprKind::CloneWith(..) => "`clone`",
+        ExprKind::New(_) | ExprKind::AnonymousClass(_) => "`new`",
+        ExprKind::PropertyAccess(_)
+        | ExprKind::NullsafePropertyAccess(_)
+        | ExprKind::StaticPropertyAccess(_)
+        | ExprKind::StaticPropertyAccessDynamic { .. } => "a property",
+        ExprKind::MethodCall(_)
+        | ExprKind::NullsafeMethodCall(_)
+        | ExprKind::StaticMethodCall(_)
+        | ExprKind::StaticDynMethodCall(_) => "a method call",
+        ExprKind::ClassConstAccess(_) | ExprKind::ClassConstAccessDynamic { .. } => {
+            "a class constant"
+        }
+        ExprKind::Closure(_) | ExprKind::ArrowFunction(_) | ExprKind::CallableCreate(_) => {
+            "a closure"
+        }
+        ExprKind::ThrowExpr(_) => "`throw`",
+        ExprKind::Yield(_) => "`yield`",
+        ExprKind::Error => "an expression with a syntax error",
+        _ => "this expression",
+    }
+}
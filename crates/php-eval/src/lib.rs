@@ -0,0 +1,108 @@
+//! Experimental tree-walking interpreter for a side-effect-free subset of
+//! PHP.
+//!
+//! [`Interpreter`] runs scalar and array expressions, control flow, and
+//! user functions straight from the AST, with the value semantics of
+//! [`php_analysis::values`]: PHP 8 type juggling, integer overflow to
+//! float, and `==`/`<=>` across types. Output from `echo`, `print`, and
+//! `var_dump()` is collected in a buffer; nothing else leaves the
+//! interpreter. It serves constant-expression contexts and attribute
+//! arguments that need more than [`php_analysis::values::evaluate`], and
+//! checking that the parser's trees mean what php-src says they mean.
+//!
+//! Anything outside the subset is an [`EvalError::Unsupported`] rather than
+//! a guess: objects, closures, exceptions, references, `global` and
+//! `static` variables, namespaces, includes, `eval`, and `exit`. Type
+//! declarations are not checked and warnings are not reported; a read of
+//! an undefined variable or array key is `null`, as it is in PHP once the
+//! warning is discarded. The AST does not record `&` on a `foreach` value,
+//! so `foreach ($a as &$v)` iterates by value.
+//!
+//! ```
+//! use php_eval::Interpreter;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let src = "<?php
+//! function fib(int $n): int { return $n < 2 ? $n : fib($n - 1) + fib($n - 2); }
+//! $seen = [];
+//! foreach (range(1, 10) as $i) {
+//!     $seen[] = fib($i);
+//! }
+//! echo implode(', ', $seen), \"\\n\";
+//! ";
+//! let result = php_rs_parser::parse(&arena, src);
+//! let mut interpreter = Interpreter::new();
+//! interpreter.run(&result.program).unwrap();
+//! assert_eq!(interpreter.output(), b"1, 1, 2, 3, 5, 8, 13, 21, 34, 55\n");
+//! ```
+
+mod builtins;
+mod interpreter;
+
+use std::fmt;
+
+use php_analysis::values::PhpValue;
+use php_ast::{Expr, Span};
+
+pub use interpreter::Interpreter;
+
+/// Why evaluation stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// A construct outside the interpreted subset, such as `new` or a
+    /// closure.
+    Unsupported { construct: &'static str, span: Span },
+    /// A PHP `Error` — `DivisionByZeroError`, `TypeError`, an unhandled
+    /// `match` case, a call to an undefined function — with the message
+    /// PHP gives it.
+    Error { message: String, span: Span },
+    /// More statements and calls were run than [`Interpreter::with_step_limit`]
+    /// allows.
+    StepLimit,
+    /// User function calls nested deeper than
+    /// [`Interpreter::with_depth_limit`] allows.
+    DepthLimit { span: Span },
+}
+
+impl EvalError {
+    /// Where evaluation stopped, if it stopped at a construct.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            EvalError::Unsupported { span, .. }
+            | EvalError::Error { span, .. }
+            | EvalError::DepthLimit { span } => Some(*span),
+            EvalError::StepLimit => None,
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Unsupported { construct, .. } => {
+                write!(f, "{construct} is not supported by the interpreter")
+            }
+            EvalError::Error { message, .. } => f.write_str(message),
+            EvalError::StepLimit => f.write_str("step limit exceeded"),
+            EvalError::DepthLimit { .. } => f.write_str("call depth limit exceeded"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// Evaluate one expression with a fresh [`Interpreter`]: no variables, no
+/// user functions or constants, only the built-in ones.
+///
+/// ```
+/// use php_analysis::values::PhpValue;
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php match (true) { 3 > 4 => 'a', default => str_repeat('b', 3) };");
+/// let StmtKind::Expression(expr) = &result.program.stmts[0].kind else { unreachable!() };
+/// assert_eq!(php_eval::evaluate(expr), Ok(PhpValue::String(b"bbb".to_vec())));
+/// ```
+pub fn evaluate<'arena, 'src>(expr: &Expr<'arena, 'src>) -> Result<PhpValue, EvalError> {
+    Interpreter::new().eval(expr)
+}
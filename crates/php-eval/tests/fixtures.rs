@@ -0,0 +1,133 @@
+/// Parse an interpreter fixture file.
+///
+/// Format:
+/// ```text
+/// ===source===
+/// <?php ...
+/// ===output===
+/// what the program echoes
+/// ===error===
+/// line:col: the error that stopped it, if any
+/// ```
+///
+/// Expected output is what `php` prints for the source, so a fixture
+/// doubles as a check of the parser against php-src. A final newline in
+/// the output is not significant.
+struct EvalFixture {
+    source: String,
+    expected: String,
+}
+
+fn parse_eval_fixture(content: &str) -> EvalFixture {
+    let after_source = content
+        .strip_prefix("===source===\n")
+        .expect("fixture must start with ===source===");
+    let output_pos = after_source
+        .find("===output===\n")
+        .expect("fixture must have ===output===");
+    let source = after_source[..output_pos]
+        .strip_suffix('\n')
+        .unwrap_or(&after_source[..output_pos]);
+    let expected = &after_source[output_pos + "===output===\n".len()..];
+    let expected = expected.strip_suffix('\n').unwrap_or(expected);
+    EvalFixture {
+        source: source.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+/// The output of running `source`, followed by the error that stopped it.
+fn run(source: &str) -> String {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, source);
+    assert!(
+        result.errors.is_empty(),
+        "fixture source does not parse: {:?}",
+        result.errors
+    );
+    let mut interpreter = php_eval::Interpreter::new();
+    let outcome = interpreter.run(&result.program);
+    let mut actual = String::from_utf8_lossy(interpreter.output()).into_owned();
+    if actual.ends_with('\n') {
+        actual.pop();
+    }
+    if let Err(err) = outcome {
+        if !actual.is_empty() {
+            actual.push('\n');
+        }
+        let (line, col) = result
+            .source_map
+            .line_col(err.span().map_or(0, |span| span.start))
+            .to_one_based();
+        actual.push_str(&format!("===error===\n{line}:{col}: {err}"));
+    }
+    actual
+}
+
+/// Run all interpreter fixture tests from `.phpt` files.
+///
+/// Set `UPDATE_FIXTURES=1` to regenerate expected output.
+#[test]
+fn fixtures() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let update = std::env::var("UPDATE_FIXTURES").is_ok();
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "phpt"))
+        .collect();
+    paths.sort();
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let content = std::fs::read_to_string(path).unwrap();
+        let fixture = parse_eval_fixture(&content);
+        let actual = run(&fixture.source);
+
+        if update {
+            let new_content = format!(
+                "===source===\n{}\n===output===\n{}\n",
+                fixture.source, actual
+            );
+            std::fs::write(path, new_content).unwrap();
+        } else if actual != fixture.expected {
+            failures.push(format!(
+                "output mismatch in {name}\nexpected:\n{}\nactual:\n{actual}",
+                fixture.expected
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} fixture(s) failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+/// Runaway recursion and loops stop at the limits instead of overflowing
+/// the stack or hanging.
+#[test]
+fn limits_stop_runaway_programs() {
+    let arena = bumpalo::Bump::new();
+    let src = "<?php function down($n) { return $n === 0 ? 0 : 1 + down($n - 1); } echo down(100); down(1000);";
+    let result = php_rs_parser::parse(&arena, src);
+    let mut interpreter = php_eval::Interpreter::new();
+    let err = interpreter.run(&result.program).unwrap_err();
+    assert_eq!(interpreter.output(), b"100");
+    assert!(
+        matches!(err, php_eval::EvalError::DepthLimit { .. }),
+        "{err:?}"
+    );
+
+    let src = "<?php while (true) {}";
+    let result = php_rs_parser::parse(&arena, src);
+    let mut interpreter = php_eval::Interpreter::new().with_step_limit(1000);
+    assert_eq!(
+        interpreter.run(&result.program),
+        Err(php_eval::EvalError::StepLimit)
+    );
+}
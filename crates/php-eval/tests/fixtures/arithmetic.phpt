@@ -0,0 +1,58 @@
+===source===
+<?php
+echo 7 + 3, ' ', 7 / 2, ' ', 6 / 3, ' ', 7 % 3, ' ', -7 % 3, ' ', 2 ** 10, ' ', 2 ** -1, "\n";
+echo PHP_INT_MAX + 1, ' ', 0.1 + 0.2, ' ', "10" + 5, ' ', "1.5" + 1, ' ', 'a' . 1 + 2, "\n";
+echo 6 & 3, ' ', 6 | 3, ' ', 6 ^ 3, ' ', 1 << 3, ' ', -8 >> 1, ' ', ~5, "\n";
+$i = 5;
+echo $i++ + ++$i, "\n";
+$s = 'a';
+$s .= 'b';
+$n = 10;
+$n -= 3;
+$n *= 2;
+$n **= 2;
+$n %= 5;
+$u = null;
+$u ??= 'set';
+$u ??= 'ignored';
+echo "$s $n $u\n";
+$x = null;
+$x--;
+$y = null;
+$y++;
+var_dump($x, $y, 0.1 + 0.2, 1.0, -0.0, 1e100, intdiv(7, 2));
+var_dump(0 == 'a', '1' == '01', '10' == '1e1', 100 == '1e2', null == false, 7 <=> 7.0, [1, 2] === [2 => 1]);
+var_dump((int) '12abc', (float) '1e3', (bool) '0', (string) false, (array) 5);
+var_dump(true && '0', 'a' || false, true xor true, !0);
+===output===
+10 3.5 2 1 -1 1024 0.5
+9.2233720368548E+18 0.3 15 2.5 a3
+2 7 5 8 -4 -6
+12
+ab 1 set
+NULL
+int(1)
+float(0.30000000000000004)
+float(1)
+float(-0)
+float(1.0E+100)
+int(3)
+bool(false)
+bool(true)
+bool(true)
+bool(true)
+bool(true)
+int(0)
+bool(false)
+int(12)
+float(1000)
+bool(false)
+string(0) ""
+array(1) {
+  [0]=>
+  int(5)
+}
+bool(false)
+bool(true)
+bool(false)
+bool(true)
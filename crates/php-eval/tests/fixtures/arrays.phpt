@@ -0,0 +1,70 @@
+===source===
+<?php
+$a = [3 => 'x', 'y', 'k' => 'z'];
+$a[] = 'w';
+$a['n']['m'][] = 1;
+$a['n']['m'][] = 2;
+unset($a[3]);
+$copy = $a;
+$copy['k'] = 'changed';
+var_dump($a);
+
+[$p, [$q, $r]] = [1, [2, 3]];
+['a' => $s, 'b' => $t] = ['b' => 'B', 'a' => 'A'];
+list(, $second) = ['first', 'second'];
+[$p, $q] = [$q, $p];
+echo "$p $q $r $s $t $second\n";
+
+foreach (['x' => [1, 2], 'y' => [3, 4]] as $key => [$first, $last]) {
+    echo "$key:$first-$last ";
+}
+echo "\n";
+
+$merged = [...[1, 2], ...['k' => 'v'], 3, '8' => 'eight', true => 'one', null => 'null'];
+var_dump($merged);
+echo count($merged), ' ', implode(',', array_keys(['a' => 1, 'b' => 2])), ' ', implode(',', array_merge([5, 6], ['x' => 1], [7])), ' ', implode(',', [1, 2] + [5, 6, 7]), "\n";
+var_dump(in_array('1e1', [10]), in_array('1e1', [10], true), array_search(2, [5 => 1, 7 => 2]), isset($merged['k'], $merged[9]), empty($merged[0]), array_key_exists('', $merged));
+echo implode(' ', range('a', 'e', 2)), ' ', implode(' ', range(5, 1, 2)), ' ', max([1, 5, 3]), ' ', min(4, '2', 8), ' ', array_sum([1, 2.5, '3']), "\n";
+===output===
+array(4) {
+  [4]=>
+  string(1) "y"
+  ["k"]=>
+  string(1) "z"
+  [5]=>
+  string(1) "w"
+  ["n"]=>
+  array(1) {
+    ["m"]=>
+    array(2) {
+      [0]=>
+      int(1)
+      [1]=>
+      int(2)
+    }
+  }
+}
+2 1 3 A B second
+x:1-2 y:3-4 
+array(6) {
+  [0]=>
+  int(1)
+  [1]=>
+  string(3) "one"
+  ["k"]=>
+  string(1) "v"
+  [2]=>
+  int(3)
+  [8]=>
+  string(5) "eight"
+  [""]=>
+  string(4) "null"
+}
+6 a,b 5,6,1,7 1,2,7
+bool(true)
+bool(false)
+int(7)
+bool(false)
+bool(false)
+bool(true)
+a c e 5 3 1 5 2 6.5
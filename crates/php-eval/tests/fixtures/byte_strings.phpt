@@ -0,0 +1,9 @@
+===source===
+<?php
+var_dump(strlen("\xE9"), strlen("é"), "\xE9" === "é", "\xC3\xA9" === "é", strlen("\351\351"));
+===output===
+int(1)
+int(2)
+bool(false)
+bool(true)
+int(2)
@@ -0,0 +1,92 @@
+===source===
+<?php
+for ($i = 0; $i < 10; $i++) {
+    if ($i % 2) {
+        continue;
+    }
+    if ($i > 6) {
+        break;
+    }
+    echo $i;
+}
+echo "\n";
+
+$i = 0;
+while (true) {
+    $i++;
+    for ($j = 0; $j < 3; $j++) {
+        if ($j == 1) {
+            continue 2;
+        }
+        if ($i == 3) {
+            break 2;
+        }
+        echo "$i$j ";
+    }
+}
+echo "done $i\n";
+
+$n = 0;
+do {
+    echo $n;
+} while (++$n < 3);
+echo "\n";
+
+foreach ([1, 2, 3, '2', 5] as $v) {
+    switch ($v) {
+        case 1:
+            echo 'one ';
+        case 2:
+            echo 'two ';
+            break;
+        default:
+            echo 'other ';
+            continue 2;
+        case 3:
+            echo 'three ';
+    }
+    echo '| ';
+}
+echo "\n";
+
+function grade(int $score): string
+{
+    return match (true) {
+        $score >= 90 => 'A',
+        $score >= 80, $score >= 75 => 'B',
+        default => 'C',
+    };
+}
+echo grade(95), grade(77), grade(10), ' ', match (3) { 1, 2 => 'low', 3, 4 => 'mid' }, "\n";
+
+$zero = 0;
+echo $zero ?: 'zero', ' ', $zero ? 'yes' : 'no', ' ', $undefined ?? 'fallback', "\n";
+
+foreach ([-1, 0, 1] as $n) {
+    if ($n < 0) {
+        echo 'neg ';
+    } elseif ($n === 0) {
+        echo 'zero ';
+    } else {
+        echo 'pos ';
+    }
+}
+echo "\n";
+
+for ($i = 0; $i < 3; $i++):
+    echo $i;
+endfor;
+echo "\n";
+?>
+Inline <?= 'HTML' ?>
+
+===output===
+0246
+10 20 done 3
+012
+one two | two | three | two | other 
+ABC mid
+zero no fallback
+neg zero pos 
+012
+Inline HTML
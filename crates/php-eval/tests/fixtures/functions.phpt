@@ -0,0 +1,62 @@
+===source===
+<?php
+echo add(2, 3), "\n";
+
+function add(int $a, int $b = 10): int
+{
+    return $a + $b;
+}
+
+function greet(string $greeting, string $name = 'world', string $punct = '!'): string
+{
+    return "$greeting, $name$punct";
+}
+
+function total(string $label, int ...$numbers): string
+{
+    return $label . ': ' . array_sum($numbers) . ' from ' . count($numbers);
+}
+
+function fact(int $n): int|float
+{
+    return $n <= 1 ? 1 : $n * fact($n - 1);
+}
+
+function scope(): string
+{
+    return isset($outer) ? 'sees outer' : 'isolated';
+}
+
+echo add(1), "\n";
+echo greet('Hello'), "\n";
+echo greet('Hi', punct: '?'), "\n";
+echo greet(...['name' => 'PHP', 'greeting' => 'Hey']), "\n";
+echo total('sum', 1, 2, 3), "\n";
+echo total('none'), "\n";
+echo total('spread', ...[4, 5]), "\n";
+echo fact(20), ' ', fact(21), "\n";
+$outer = 1;
+echo scope(), "\n";
+if (true) {
+    function later(): string
+    {
+        return 'conditional';
+    }
+}
+echo later(), ' ', STRLEN('abc'), "\n";
+const GREETING = 'hello';
+define('TWICE', GREETING . GREETING);
+echo TWICE, ' ', PHP_INT_SIZE, "\n";
+===output===
+5
+11
+Hello, world!
+Hi, world?
+Hey, PHP!
+sum: 6 from 3
+none: 0 from 0
+spread: 9 from 2
+2432902008176640000 5.1090942171709E+19
+isolated
+conditional 3
+hellohello 8
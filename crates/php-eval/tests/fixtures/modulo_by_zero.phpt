@@ -0,0 +1,8 @@
+===source===
+<?php
+echo "before\n";
+echo 1 % 0;
+===output===
+before
+===error===
+3:6: Modulo by zero
@@ -0,0 +1,50 @@
+===source===
+<?php
+$name = 'World';
+$items = ['apple' => 3, 'list' => [1, 2]];
+echo "Hello $name! {$items['apple']} apples, $items[apple] again, {$items['list'][1]}\n";
+echo <<<EOT
+    Name: $name
+      Indented
+    EOT;
+echo "\n", <<<'EOT'
+$name\x41
+EOT, "\n";
+echo "Tab:\tEnd\x41\101\n";
+$s = 'Az';
+$s++;
+$t = 'zz';
+$t++;
+$u = 'a9';
+$u++;
+$v = '9';
+$v++;
+$w = 'hello';
+echo "$s $t $u $v ", $w[0], $w[-1], "\n";
+echo strlen('héllo'), ' ', strtoupper('abc'), ' ', ucfirst('php'), ' ', strrev('abc'), ' ', str_repeat('=', 3), "\n";
+echo substr('abcdef', 1, 3), ' ', substr('abcdef', -2), ' ', substr('abc', 5) === '' ? 'empty' : 'x', ' ', substr('abcdef', 1, -2), "\n";
+echo gettype(1.0), ' ', gettype(null), ' ', gettype([]), "\n";
+var_dump(explode(',', 'a,b,,c', -1), trim('  x  '), rtrim('xxay', 'a..y'), strpos('hello', 'l'), strpos('hello', 'z'), str_contains('abc', ''));
+===output===
+Hello World! 3 apples, 3 again, 2
+Name: World
+  Indented
+$name\x41
+Tab:	EndAA
+Ba aaa b0 10 ho
+6 ABC Php cba ===
+bcd ef empty bcd
+double NULL array
+array(3) {
+  [0]=>
+  string(1) "a"
+  [1]=>
+  string(1) "b"
+  [2]=>
+  string(0) ""
+}
+string(1) "x"
+string(0) ""
+int(2)
+bool(false)
+bool(true)
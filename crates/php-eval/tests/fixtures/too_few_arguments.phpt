@@ -0,0 +1,7 @@
+===source===
+<?php
+function f($a, $b = 2, $c = 3) { return $a; }
+echo f(c: 1);
+===output===
+===error===
+3:6: f(): Argument #1 ($a) not passed
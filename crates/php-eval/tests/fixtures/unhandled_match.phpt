@@ -0,0 +1,9 @@
+===source===
+<?php
+$value = 'b';
+echo match ($value) {
+    'a' => 1,
+};
+===output===
+===error===
+3:6: Unhandled match case 'b'
@@ -0,0 +1,6 @@
+===source===
+<?php echo "a"; $o = new stdClass;
+===output===
+a
+===error===
+1:22: `new` is not supported by the interpreter
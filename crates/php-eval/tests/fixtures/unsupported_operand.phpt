@@ -0,0 +1,7 @@
+===source===
+<?php
+echo 'start', [] + 1;
+===output===
+start
+===error===
+2:15: Unsupported operand types: array + int
@@ -0,0 +1,135 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::path::Path;
+use std::time::Duration;
+
+use php_lexer::{Lexer, TokenKind};
+
+/// A view template: long runs of markup, full of `<`, with short PHP islands.
+/// Stresses the inline HTML scan.
+fn template_corpus() -> String {
+    let mut src = String::new();
+    for i in 0..400 {
+        src.push_str(&format!(
+            r#"<div class="card card-{i}" data-id="{i}">
+    <header class="card-header"><h2 class="title"><?= $this->escape($items[{i}]['title']) ?></h2></header>
+    <ul class="meta">
+        <li><span class="label">Author</span> <a href="/users/<?= $items[{i}]['author_id'] ?>">profile</a></li>
+        <li><span class="label">Posted</span> <time datetime="2024-01-01">January 1st</time></li>
+    </ul>
+    <?php if ($items[{i}]['featured']): ?>
+        <p class="featured"><strong>Featured</strong> &mdash; <em>editor's pick</em></p>
+    <?php endif; ?>
+    <footer><button type="button" class="btn btn-primary" onclick="return open({i});">Read more</button></footer>
+</div>
+"#
+        ));
+    }
+    src
+}
+
+/// Application code: indentation, identifiers, keywords, single-quoted
+/// strings, and doc comments. Stresses the PHP-mode scanners.
+fn code_corpus() -> String {
+    let mut src = String::from("<?php\n\ndeclare(strict_types=1);\n\nnamespace App\\Service;\n\n");
+    for i in 0..300 {
+        src.push_str(&format!(
+            r#"/**
+ * Handles the lifecycle of order {i}.
+ */
+final class OrderService{i} extends AbstractService implements ServiceInterface
+{{
+    private const STATUS_PENDING = 'pending';
+    private const STATUS_SHIPPED = 'shipped';
+
+    public function __construct(
+        private readonly OrderRepository $repository,
+        private readonly EventDispatcherInterface $dispatcher,
+    ) {{
+    }}
+
+    public function ship(int $orderId, array $options = []): ?Order
+    {{
+        $order = $this->repository->find($orderId);
+        if ($order === null || $order->status !== self::STATUS_PENDING) {{
+            return null;
+        }}
+        // Mark the order and notify listeners.
+        $order->status = self::STATUS_SHIPPED;
+        $order->shippedAt = new \DateTimeImmutable('now');
+        $this->dispatcher->dispatch(new OrderShipped($order, $options['carrier'] ?? 'default'));
+        return $order;
+    }}
+}}
+
+"#
+        ));
+    }
+    src
+}
+
+/// The sources of the `.php` files under `dir`, recursively.
+fn real_corpus(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut sources = Vec::new();
+    let mut dirs: Vec<_> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    while let Some(path) = dirs.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                dirs.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("php") {
+            if let Ok(src) = std::fs::read_to_string(&path) {
+                sources.push(src);
+            }
+        }
+    }
+    sources
+}
+
+fn lex(src: &str) -> usize {
+    let mut lexer = Lexer::new(src);
+    let mut count = 0;
+    while lexer.next_token().kind != TokenKind::Eof {
+        count += 1;
+    }
+    count
+}
+
+fn bench_sources(c: &mut Criterion, name: &str, sources: &[String]) {
+    if sources.is_empty() {
+        eprintln!("corpus '{name}' not found — skipping");
+        return;
+    }
+    let total_bytes: usize = sources.iter().map(String::len).sum();
+
+    let mut group = c.benchmark_group("lex");
+    group.throughput(Throughput::Bytes(total_bytes as u64));
+    group.measurement_time(Duration::from_secs(5));
+    group.bench_function(
+        BenchmarkId::new(name, format!("{} files", sources.len())),
+        |b| {
+            b.iter(|| {
+                for src in sources {
+                    std::hint::black_box(lex(src));
+                }
+            });
+        },
+    );
+    group.finish();
+}
+
+fn bench_lexer(c: &mut Criterion) {
+    bench_sources(c, "template", &[template_corpus()]);
+    bench_sources(c, "code", &[code_corpus()]);
+
+    // The parser's real-world corpora, when checked out.
+    let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("../php-parser/benches/corpus");
+    for name in ["laravel", "symfony", "wordpress"] {
+        bench_sources(c, name, &real_corpus(&base.join(name)));
+    }
+}
+
+criterion_group!(benches, bench_lexer);
+criterion_main!(benches);
@@ -52,6 +52,7 @@ static IS_IDENT_CONTINUE: [bool; 256] = make_ident_continue_table();
 
 /// Discriminant for [`LexerError`] — describes what kind of lexer failure occurred.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum LexerErrorKind {
     /// The lexer reached end-of-file inside a string literal without finding the closing delimiter.
     UnterminatedString,
@@ -87,9 +88,12 @@ impl Token {
     }
 }
 
+/// What the lexer reads next: text outside `<?php … ?>`, or PHP code.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum LexerMode {
+pub enum LexerMode {
+    /// Text to echo as is, up to the next `<?php` or `<?=`.
     InlineHtml,
+    /// PHP code, up to the next `?>`.
     Php,
 }
 
@@ -228,6 +232,27 @@ impl<'src> Lexer<'src> {
     /// content (no `<?php` tag needed — the lexer is pre-set to PHP mode).
     /// Spans produced will be correct absolute offsets into `source`.
     pub fn new_at(source: &'src str, offset: usize) -> Self {
+        Self::with_mode(source, offset, LexerMode::Php)
+    }
+
+    /// Create a lexer starting in `mode` at a given byte offset within `source`.
+    ///
+    /// For PHP embedded in another document: slice the host up to the end of
+    /// the PHP region and start at its first byte, and spans are offsets into
+    /// the host. [`LexerMode::InlineHtml`] reads up to the first `<?php` as
+    /// [`Lexer::new`] does; [`LexerMode::Php`] needs no open tag.
+    ///
+    /// ```
+    /// use php_lexer::{Lexer, LexerMode, TokenKind};
+    ///
+    /// let host = "<p>{{ $name }}</p>";
+    /// let mut lexer = Lexer::with_mode(&host[..12], 6, LexerMode::Php);
+    /// let token = lexer.next_token();
+    /// assert_eq!(token.kind, TokenKind::Variable);
+    /// assert_eq!(lexer.token_text(&token), "$name");
+    /// assert_eq!(lexer.next_token().kind, TokenKind::Eof);
+    /// ```
+    pub fn with_mode(source: &'src str, offset: usize, mode: LexerMode) -> Self {
         debug_assert!(
             source.len() <= u32::MAX as usize,
             "source is {} bytes, which exceeds the u32::MAX span limit",
@@ -236,7 +261,7 @@ impl<'src> Lexer<'src> {
 
         Self {
             source,
-            mode: LexerMode::Php,
+            mode,
             pos: offset,
             peeked: None,
             peeked2: None,
@@ -248,6 +273,12 @@ impl<'src> Lexer<'src> {
         self.source
     }
 
+    /// The mode the next token is read in. Tokens already peeked at have
+    /// been read, so this is the mode after them.
+    pub fn mode(&self) -> LexerMode {
+        self.mode
+    }
+
     pub fn peek(&mut self) -> &Token {
         if self.peeked.is_none() {
             self.peeked = Some(self.read_next_token());
@@ -296,20 +327,20 @@ impl<'src> Lexer<'src> {
         let bytes = self.source.as_bytes();
 
         // Search for <?php or <?= with a single SIMD-accelerated scan:
-        // find the first '<', then check if it's followed by ?php or ?=.
-        // This avoids the two independent full-string scans of the original approach.
+        // find the first `<?`, then check if it's followed by php or =.
+        // Searching for the pair rather than `<` skips the many HTML tags
+        // between PHP islands without stopping at each.
         let mut search = self.pos;
         let tag_pos = loop {
-            match memchr::memchr(b'<', &bytes[search..]) {
+            match memmem::find(&bytes[search..], b"<?") {
                 None => break None,
                 Some(offset) => {
                     let p = search + offset;
-                    let rest = &bytes[p..];
-                    if (rest.len() >= 5
-                        && rest[0] == b'<'
-                        && rest[1] == b'?'
-                        && rest[2..5].eq_ignore_ascii_case(b"php"))
-                        || rest.starts_with(b"<?=")
+                    let rest = &bytes[p + 2..];
+                    if rest
+                        .get(..3)
+                        .is_some_and(|tag| tag.eq_ignore_ascii_case(b"php"))
+                        || rest.first() == Some(&b'=')
                     {
                         break Some(p - self.pos);
                     }
@@ -338,14 +369,8 @@ impl<'src> Lexer<'src> {
     }
 
     fn lex_php(&mut self) -> Token {
-        let remaining = &self.source[self.pos..];
-
-        // Try heredoc/nowdoc before skipping whitespace (heredoc does its own whitespace handling)
-        if let Some(token) = self.try_lex_heredoc(remaining) {
-            return token;
-        }
-
-        // Skip whitespace only (comments are yielded as tokens below)
+        // Skip whitespace only (comments are yielded as tokens below). Heredocs
+        // and nowdocs are recognized by `scan_token` at their `<<<` or `b<<<`.
         self.skip_whitespace();
 
         if self.pos >= self.source.len() {
@@ -407,6 +432,11 @@ impl<'src> Lexer<'src> {
         let bytes = self.source.as_bytes();
         while self.pos < bytes.len() && IS_PHP_WHITESPACE[bytes[self.pos] as usize] {
             self.pos += 1;
+            // Indentation comes in long runs of spaces: step over them eight
+            // bytes at a time.
+            while bytes.get(self.pos..self.pos + 8) == Some(b"        ") {
+                self.pos += 8;
+            }
         }
     }
 
@@ -1332,3 +1362,215 @@ pub fn lex_all(source: &str) -> (Vec<Token>, Vec<LexerError>) {
     let errors = lexer.errors;
     (tokens, errors)
 }
+
+/// Tokenize `source` losslessly, for formatters and syntax highlighters.
+///
+/// Unlike [`lex_all`], nothing is dropped: whitespace comes out as
+/// [`TokenKind::Whitespace`], a `#!` line as [`TokenKind::InlineHtml`], and
+/// bytes that start no token as [`TokenKind::BadCharacter`], next to the
+/// comments, open and close tags, and inline HTML the lexer already yields.
+/// The spans are contiguous, so the token texts concatenate back to
+/// `source`. There is no [`TokenKind::Eof`]. Lexer errors are not reported;
+/// use [`lex_all`] for those. A source too large for [`Span`] offsets
+/// yields no tokens.
+///
+/// ```
+/// use php_lexer::{lex_lossless, TokenKind};
+///
+/// let src = "<?php\n// hi\necho 1; ?>\n<b>";
+/// let tokens = lex_lossless(src);
+/// let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+/// assert_eq!(kinds, [
+///     TokenKind::OpenTag,
+///     TokenKind::Whitespace,
+///     TokenKind::LineComment,
+///     TokenKind::Whitespace,
+///     TokenKind::Echo,
+///     TokenKind::Whitespace,
+///     TokenKind::IntLiteral,
+///     TokenKind::Semicolon,
+///     TokenKind::Whitespace,
+///     TokenKind::CloseTag,
+///     TokenKind::InlineHtml,
+/// ]);
+/// let text: String = tokens
+///     .iter()
+///     .map(|t| &src[t.span.start as usize..t.span.end as usize])
+///     .collect();
+/// assert_eq!(text, src);
+/// ```
+pub fn lex_lossless(source: &str) -> Vec<Token> {
+    if source.len() > u32::MAX as usize {
+        return Vec::new();
+    }
+
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    let mut end = 0;
+    loop {
+        let tok = lexer.next_token();
+        push_trivia(source, end, tok.span.start as usize, &mut tokens);
+        if tok.kind == TokenKind::Eof {
+            break;
+        }
+        end = tok.span.end as usize;
+        tokens.push(tok);
+    }
+    tokens
+}
+
+/// Push tokens for the bytes the lexer skipped between `from` and `to`.
+fn push_trivia(source: &str, mut from: usize, to: usize, tokens: &mut Vec<Token>) {
+    let bytes = source.as_bytes();
+    if from == 0 && bytes[..to].starts_with(b"#!") {
+        let end = memchr::memchr(b'\n', &bytes[..to]).map_or(to, |p| p + 1);
+        tokens.push(Token::new(TokenKind::InlineHtml, Span::new(0, end as u32)));
+        from = end;
+    }
+    while from < to {
+        let whitespace = IS_PHP_WHITESPACE[bytes[from] as usize];
+        let run = bytes[from..to]
+            .iter()
+            .position(|&b| IS_PHP_WHITESPACE[b as usize] != whitespace)
+            .unwrap_or(to - from);
+        let kind = if whitespace {
+            TokenKind::Whitespace
+        } else {
+            TokenKind::BadCharacter
+        };
+        tokens.push(Token::new(
+            kind,
+            Span::new(from as u32, (from + run) as u32),
+        ));
+        from += run;
+    }
+}
+
+/// The most lines [`token_at`] and [`tokens_in`] look back for a restart
+/// point before lexing from the start of the file.
+const MAX_RESTART_LINES: usize = 256;
+
+/// The token containing byte `offset`, lexing only from a restart point
+/// shortly before it rather than from the start of `source`.
+///
+/// Returns `None` for whitespace between tokens and past the end. A token
+/// ends where the next byte begins, so to classify the token left of a
+/// cursor, pass the offset before it. See [`tokens_in`] for how the restart
+/// point is chosen.
+///
+/// ```
+/// use php_lexer::{token_at, TokenKind};
+///
+/// let src = "<?php\n$name = 'World';\necho \"Hello, $name\";\n";
+/// let token = token_at(src, 30).unwrap();
+/// assert_eq!(token.kind, TokenKind::DoubleQuotedString);
+/// assert_eq!(&src[token.span.start as usize..token.span.end as usize], "\"Hello, $name\"");
+/// assert_eq!(token_at(src, 11), None);
+/// ```
+pub fn token_at(source: &str, offset: u32) -> Option<Token> {
+    tokens_in(source, Span::new(offset, offset))
+        .into_iter()
+        .find(|token| token.span.start <= offset && offset < token.span.end)
+}
+
+/// The tokens overlapping `span`, or containing it if it is empty, lexing
+/// only from a restart point shortly before it.
+///
+/// The restart point is the start of a line that follows a line ending in
+/// `;`, `{`, or `}` and does not open a string or heredoc, which is almost
+/// always a boundary between statements, or, when the last tag before that
+/// line is `?>`, that tag. Lexing stops at the end of `span`.
+/// A multi-line string, heredoc, or comment with a line that looks like a
+/// statement end can make the restart point land inside it and the tokens
+/// differ from those of [`lex_all`]; that trade-off suits syntax probes
+/// such as completion triggers, and [`lex_all`] is exact.
+///
+/// ```
+/// use php_ast::Span;
+/// use php_lexer::{tokens_in, TokenKind};
+///
+/// let src = "<?php\nfunction f() {\n    return $a + 1;\n}\n";
+/// let kinds: Vec<TokenKind> = tokens_in(src, Span::new(26, 33)).iter().map(|t| t.kind).collect();
+/// assert_eq!(kinds, [TokenKind::Return, TokenKind::Variable]);
+/// ```
+pub fn tokens_in(source: &str, span: Span) -> Vec<Token> {
+    let start = (span.start as usize).min(source.len());
+    let mut lexer = match restart_point(source, start) {
+        0 => Lexer::new(source),
+        at => Lexer::new_at(source, at),
+    };
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token();
+        if token.kind == TokenKind::Eof
+            || token.span.start > span.end
+            || (token.span.start == span.end && !span.is_empty())
+        {
+            break;
+        }
+        if token.span.end > span.start || (span.is_empty() && token.span.end == span.start) {
+            tokens.push(token);
+        }
+    }
+    tokens
+}
+
+/// Where lexing in PHP mode can restart to reach `offset`: a line start at
+/// or before it, the close tag before it in inline HTML, or else 0 for a
+/// lex from the start.
+fn restart_point(source: &str, offset: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut line = memchr::memrchr(b'\n', &bytes[..offset]).map_or(0, |p| p + 1);
+    for _ in 0..MAX_RESTART_LINES {
+        if line == 0 {
+            return 0;
+        }
+        let prev = memchr::memrchr(b'\n', &bytes[..line - 1]).map_or(0, |p| p + 1);
+        if is_restart_line(&bytes[prev..line - 1], &bytes[line..]) {
+            return match last_tag(&bytes[..line]) {
+                Some(Tag::Open) => line,
+                Some(Tag::Close(at)) => at,
+                None => 0,
+            };
+        }
+        line = prev;
+    }
+    0
+}
+
+/// Whether a line can follow `prev` as the first line of a fresh lex.
+fn is_restart_line(prev: &[u8], rest: &[u8]) -> bool {
+    let ends_statement = prev
+        .iter()
+        .rposition(|&b| !IS_PHP_WHITESPACE[b as usize])
+        .is_some_and(|i| matches!(prev[i], b';' | b'{' | b'}'));
+    // A doc-block line starts with `*`.
+    let continues_comment = rest
+        .iter()
+        .find(|&&b| !IS_PHP_WHITESPACE[b as usize])
+        .is_some_and(|&b| b == b'*');
+    let quotes = |q: u8| prev.iter().filter(|&&b| b == q).count();
+    let opens_string =
+        quotes(b'\'') % 2 == 1 || quotes(b'"') % 2 == 1 || memmem::find(prev, b"<<<").is_some();
+    ends_statement && !continues_comment && !opens_string
+}
+
+enum Tag {
+    Open,
+    /// A `?>` at this offset.
+    Close(usize),
+}
+
+/// The last open or close tag in `before`.
+fn last_tag(before: &[u8]) -> Option<Tag> {
+    let open = memmem::rfind(before, b"<?php")
+        .into_iter()
+        .chain(memmem::rfind(before, b"<?="))
+        .max();
+    match (open, memmem::rfind(before, b"?>")) {
+        (Some(open), Some(close)) if close > open => Some(Tag::Close(close)),
+        (Some(_), _) => Some(Tag::Open),
+        // All inline HTML so far.
+        (None, _) => None,
+    }
+}
@@ -4,7 +4,13 @@
 //! - [`Lexer`] — a lazy, streaming tokenizer. Call [`Lexer::next_token`] to advance one token at
 //!   a time, or use [`Lexer::peek`]/[`Lexer::peek2`] for lookahead without consuming.
 //! - [`TokenKind`] — the complete set of token types produced by the lexer.
+//! - [`LexerMode`] — whether the lexer is reading PHP code or the inline HTML around it;
+//!   [`Lexer::with_mode`] starts in either at any offset, for PHP embedded in other documents.
 //! - [`lex_all`] — convenience function that tokenizes an entire source string at once.
+//! - [`lex_lossless`] — every byte as a token, whitespace included, for formatters and
+//!   syntax highlighters.
+//! - [`token_at`] / [`tokens_in`] — the tokens at an offset or in a span, lexed from a nearby
+//!   restart point instead of the start of the file, for cheap editor probes.
 //!
 //! # Quick start
 //!
@@ -22,5 +28,7 @@
 pub mod lexer;
 pub mod token;
 
-pub use lexer::{lex_all, Lexer, LexerError, LexerErrorKind, Token};
+pub use lexer::{
+    lex_all, lex_lossless, token_at, tokens_in, Lexer, LexerError, LexerErrorKind, LexerMode, Token,
+};
 pub use token::TokenKind;
@@ -4,6 +4,7 @@
 /// guarantees a compact discriminant layout used by [`TokenKind::is_assignment_op`].
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum TokenKind {
     // -------------------------------------------------------------------------
     // Literals
@@ -420,6 +421,15 @@ pub enum TokenKind {
     /// An invalid numeric literal, e.g. `1_000_` (trailing underscore).
     /// The lexer emits this token and records a [`LexerError`](crate::LexerError) for it.
     InvalidNumericLiteral,
+    /// Bytes that start no token, e.g. a stray `\x01`. Only
+    /// [`lex_lossless`](crate::lex_lossless) yields these; the lexer skips them.
+    BadCharacter,
+
+    // -------------------------------------------------------------------------
+    // Trivia (only yielded by `lex_lossless`)
+    // -------------------------------------------------------------------------
+    /// Spaces, tabs, newlines, and form feeds between tokens.
+    Whitespace,
 
     // -------------------------------------------------------------------------
     // Comments (filtered into a side-table by the parser)
@@ -745,180 +755,189 @@ pub fn resolve_keyword(text: &str) -> Option<TokenKind> {
     None
 }
 
-impl std::fmt::Display for TokenKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TokenKind {
+    /// How the token is named in diagnostics, such as `integer` or `'=>'`.
+    pub fn label(self) -> &'static str {
         match self {
-            TokenKind::IntLiteral => write!(f, "integer"),
-            TokenKind::HexIntLiteral => write!(f, "hex integer"),
-            TokenKind::BinIntLiteral => write!(f, "binary integer"),
-            TokenKind::OctIntLiteral | TokenKind::OctIntLiteralNew => write!(f, "octal integer"),
+            TokenKind::IntLiteral => "integer",
+            TokenKind::HexIntLiteral => "hex integer",
+            TokenKind::BinIntLiteral => "binary integer",
+            TokenKind::OctIntLiteral | TokenKind::OctIntLiteralNew => "octal integer",
             TokenKind::FloatLiteral
             | TokenKind::FloatLiteralSimple
-            | TokenKind::FloatLiteralLeadingDot => write!(f, "float"),
-            TokenKind::SingleQuotedString | TokenKind::DoubleQuotedString => write!(f, "string"),
-            TokenKind::BacktickString => write!(f, "backtick string"),
-            TokenKind::Variable => write!(f, "variable"),
-            TokenKind::Dollar => write!(f, "'$'"),
-            TokenKind::Identifier => write!(f, "identifier"),
-            TokenKind::Plus => write!(f, "'+'"),
-            TokenKind::Minus => write!(f, "'-'"),
-            TokenKind::Star => write!(f, "'*'"),
-            TokenKind::Slash => write!(f, "'/'"),
-            TokenKind::Percent => write!(f, "'%'"),
-            TokenKind::StarStar => write!(f, "'**'"),
-            TokenKind::Dot => write!(f, "'.'"),
-            TokenKind::Equals => write!(f, "'='"),
-            TokenKind::PlusEquals => write!(f, "'+='"),
-            TokenKind::MinusEquals => write!(f, "'-='"),
-            TokenKind::StarEquals => write!(f, "'*='"),
-            TokenKind::SlashEquals => write!(f, "'/='"),
-            TokenKind::PercentEquals => write!(f, "'%='"),
-            TokenKind::StarStarEquals => write!(f, "'**='"),
-            TokenKind::DotEquals => write!(f, "'.='"),
-            TokenKind::AmpersandEquals => write!(f, "'&='"),
-            TokenKind::PipeEquals => write!(f, "'|='"),
-            TokenKind::CaretEquals => write!(f, "'^='"),
-            TokenKind::ShiftLeftEquals => write!(f, "'<<='"),
-            TokenKind::ShiftRightEquals => write!(f, "'>>='"),
-            TokenKind::CoalesceEquals => write!(f, "'??='"),
-            TokenKind::EqualsEquals => write!(f, "'=='"),
-            TokenKind::BangEquals => write!(f, "'!='"),
-            TokenKind::EqualsEqualsEquals => write!(f, "'==='"),
-            TokenKind::BangEqualsEquals => write!(f, "'!=='"),
-            TokenKind::LessThan => write!(f, "'<'"),
-            TokenKind::GreaterThan => write!(f, "'>'"),
-            TokenKind::LessThanEquals => write!(f, "'<='"),
-            TokenKind::GreaterThanEquals => write!(f, "'>='"),
-            TokenKind::Spaceship => write!(f, "'<=>'"),
-            TokenKind::AmpersandAmpersand => write!(f, "'&&'"),
-            TokenKind::PipePipe => write!(f, "'||'"),
-            TokenKind::Bang => write!(f, "'!'"),
-            TokenKind::Ampersand => write!(f, "'&'"),
-            TokenKind::Pipe => write!(f, "'|'"),
-            TokenKind::Caret => write!(f, "'^'"),
-            TokenKind::Tilde => write!(f, "'~'"),
-            TokenKind::ShiftLeft => write!(f, "'<<'"),
-            TokenKind::ShiftRight => write!(f, "'>>'"),
-            TokenKind::PlusPlus => write!(f, "'++'"),
-            TokenKind::MinusMinus => write!(f, "'--'"),
-            TokenKind::Question => write!(f, "'?'"),
-            TokenKind::QuestionQuestion => write!(f, "'??'"),
-            TokenKind::Colon => write!(f, "':'"),
-            TokenKind::FatArrow => write!(f, "'=>'"),
-            TokenKind::PipeArrow => write!(f, "'|>'"),
-            TokenKind::LeftParen => write!(f, "'('"),
-            TokenKind::RightParen => write!(f, "')'"),
-            TokenKind::LeftBracket => write!(f, "'['"),
-            TokenKind::RightBracket => write!(f, "']'"),
-            TokenKind::LeftBrace => write!(f, "'{{'"),
-            TokenKind::RightBrace => write!(f, "'}}'"),
-            TokenKind::Semicolon => write!(f, "';'"),
-            TokenKind::Comma => write!(f, "','"),
-            TokenKind::DoubleColon => write!(f, "'::'"),
-            TokenKind::Arrow => write!(f, "'->'"),
-            TokenKind::NullsafeArrow => write!(f, "'?->'"),
-            TokenKind::Backslash => write!(f, "'\\'"),
-            TokenKind::At => write!(f, "'@'"),
-            TokenKind::HashBracket => write!(f, "'#['"),
-            TokenKind::Ellipsis => write!(f, "'...'"),
-            TokenKind::If => write!(f, "'if'"),
-            TokenKind::Else => write!(f, "'else'"),
-            TokenKind::ElseIf => write!(f, "'elseif'"),
-            TokenKind::While => write!(f, "'while'"),
-            TokenKind::Do => write!(f, "'do'"),
-            TokenKind::For => write!(f, "'for'"),
-            TokenKind::Foreach => write!(f, "'foreach'"),
-            TokenKind::As => write!(f, "'as'"),
-            TokenKind::Function => write!(f, "'function'"),
-            TokenKind::Return => write!(f, "'return'"),
-            TokenKind::Echo => write!(f, "'echo'"),
-            TokenKind::Print => write!(f, "'print'"),
-            TokenKind::True => write!(f, "'true'"),
-            TokenKind::False => write!(f, "'false'"),
-            TokenKind::Null => write!(f, "'null'"),
-            TokenKind::And => write!(f, "'and'"),
-            TokenKind::Or => write!(f, "'or'"),
-            TokenKind::Xor => write!(f, "'xor'"),
-            TokenKind::Break => write!(f, "'break'"),
-            TokenKind::Continue => write!(f, "'continue'"),
-            TokenKind::Switch => write!(f, "'switch'"),
-            TokenKind::Case => write!(f, "'case'"),
-            TokenKind::Default => write!(f, "'default'"),
-            TokenKind::EndIf => write!(f, "'endif'"),
-            TokenKind::EndWhile => write!(f, "'endwhile'"),
-            TokenKind::EndFor => write!(f, "'endfor'"),
-            TokenKind::EndForeach => write!(f, "'endforeach'"),
-            TokenKind::Throw => write!(f, "'throw'"),
-            TokenKind::Try => write!(f, "'try'"),
-            TokenKind::Catch => write!(f, "'catch'"),
-            TokenKind::Finally => write!(f, "'finally'"),
-            TokenKind::Instanceof => write!(f, "'instanceof'"),
-            TokenKind::Array => write!(f, "'array'"),
-            TokenKind::List => write!(f, "'list'"),
-            TokenKind::Goto => write!(f, "'goto'"),
-            TokenKind::Declare => write!(f, "'declare'"),
-            TokenKind::Unset => write!(f, "'unset'"),
-            TokenKind::Global => write!(f, "'global'"),
-            TokenKind::EndDeclare => write!(f, "'enddeclare'"),
-            TokenKind::EndSwitch => write!(f, "'endswitch'"),
-            TokenKind::Isset => write!(f, "'isset'"),
-            TokenKind::Empty => write!(f, "'empty'"),
-            TokenKind::Include => write!(f, "'include'"),
-            TokenKind::IncludeOnce => write!(f, "'include_once'"),
-            TokenKind::Require => write!(f, "'require'"),
-            TokenKind::RequireOnce => write!(f, "'require_once'"),
-            TokenKind::Eval => write!(f, "'eval'"),
-            TokenKind::Exit => write!(f, "'exit'"),
-            TokenKind::Die => write!(f, "'die'"),
-            TokenKind::Clone => write!(f, "'clone'"),
-            TokenKind::New => write!(f, "'new'"),
-            TokenKind::Class => write!(f, "'class'"),
-            TokenKind::Abstract => write!(f, "'abstract'"),
-            TokenKind::Final => write!(f, "'final'"),
-            TokenKind::Interface => write!(f, "'interface'"),
-            TokenKind::Trait => write!(f, "'trait'"),
-            TokenKind::Extends => write!(f, "'extends'"),
-            TokenKind::Implements => write!(f, "'implements'"),
-            TokenKind::Public => write!(f, "'public'"),
-            TokenKind::Protected => write!(f, "'protected'"),
-            TokenKind::Private => write!(f, "'private'"),
-            TokenKind::Static => write!(f, "'static'"),
-            TokenKind::Const => write!(f, "'const'"),
-            TokenKind::Fn_ => write!(f, "'fn'"),
-            TokenKind::Match_ => write!(f, "'match'"),
-            TokenKind::Namespace => write!(f, "'namespace'"),
-            TokenKind::Use => write!(f, "'use'"),
-            TokenKind::Readonly => write!(f, "'readonly'"),
-            TokenKind::Enum_ => write!(f, "'enum'"),
-            TokenKind::Yield_ => write!(f, "'yield'"),
-            TokenKind::From => write!(f, "'from'"),
-            TokenKind::Self_ => write!(f, "'self'"),
-            TokenKind::Parent_ => write!(f, "'parent'"),
-            TokenKind::MagicClass => write!(f, "'__CLASS__'"),
-            TokenKind::MagicDir => write!(f, "'__DIR__'"),
-            TokenKind::MagicFile => write!(f, "'__FILE__'"),
-            TokenKind::MagicFunction => write!(f, "'__FUNCTION__'"),
-            TokenKind::MagicLine => write!(f, "'__LINE__'"),
-            TokenKind::MagicMethod => write!(f, "'__METHOD__'"),
-            TokenKind::MagicNamespace => write!(f, "'__NAMESPACE__'"),
-            TokenKind::MagicTrait => write!(f, "'__TRAIT__'"),
-            TokenKind::MagicProperty => write!(f, "'__PROPERTY__'"),
-            TokenKind::HaltCompiler => write!(f, "'__halt_compiler'"),
-            TokenKind::OpenTag => write!(f, "'<?php'"),
-            TokenKind::CloseTag => write!(f, "'?>'"),
-            TokenKind::InlineHtml => write!(f, "inline HTML"),
-            TokenKind::Heredoc => write!(f, "heredoc"),
-            TokenKind::Nowdoc => write!(f, "nowdoc"),
-            TokenKind::InvalidNumericLiteral => write!(f, "invalid numeric literal"),
-            TokenKind::LineComment => write!(f, "line comment"),
-            TokenKind::HashComment => write!(f, "hash comment"),
-            TokenKind::BlockComment => write!(f, "block comment"),
-            TokenKind::DocComment => write!(f, "doc comment"),
-            TokenKind::Eof => write!(f, "end of file"),
+            | TokenKind::FloatLiteralLeadingDot => "float",
+            TokenKind::SingleQuotedString | TokenKind::DoubleQuotedString => "string",
+            TokenKind::BacktickString => "backtick string",
+            TokenKind::Variable => "variable",
+            TokenKind::Dollar => "'$'",
+            TokenKind::Identifier => "identifier",
+            TokenKind::Plus => "'+'",
+            TokenKind::Minus => "'-'",
+            TokenKind::Star => "'*'",
+            TokenKind::Slash => "'/'",
+            TokenKind::Percent => "'%'",
+            TokenKind::StarStar => "'**'",
+            TokenKind::Dot => "'.'",
+            TokenKind::Equals => "'='",
+            TokenKind::PlusEquals => "'+='",
+            TokenKind::MinusEquals => "'-='",
+            TokenKind::StarEquals => "'*='",
+            TokenKind::SlashEquals => "'/='",
+            TokenKind::PercentEquals => "'%='",
+            TokenKind::StarStarEquals => "'**='",
+            TokenKind::DotEquals => "'.='",
+            TokenKind::AmpersandEquals => "'&='",
+            TokenKind::PipeEquals => "'|='",
+            TokenKind::CaretEquals => "'^='",
+            TokenKind::ShiftLeftEquals => "'<<='",
+            TokenKind::ShiftRightEquals => "'>>='",
+            TokenKind::CoalesceEquals => "'??='",
+            TokenKind::EqualsEquals => "'=='",
+            TokenKind::BangEquals => "'!='",
+            TokenKind::EqualsEqualsEquals => "'==='",
+            TokenKind::BangEqualsEquals => "'!=='",
+            TokenKind::LessThan => "'<'",
+            TokenKind::GreaterThan => "'>'",
+            TokenKind::LessThanEquals => "'<='",
+            TokenKind::GreaterThanEquals => "'>='",
+            TokenKind::Spaceship => "'<=>'",
+            TokenKind::AmpersandAmpersand => "'&&'",
+            TokenKind::PipePipe => "'||'",
+            TokenKind::Bang => "'!'",
+            TokenKind::Ampersand => "'&'",
+            TokenKind::Pipe => "'|'",
+            TokenKind::Caret => "'^'",
+            TokenKind::Tilde => "'~'",
+            TokenKind::ShiftLeft => "'<<'",
+            TokenKind::ShiftRight => "'>>'",
+            TokenKind::PlusPlus => "'++'",
+            TokenKind::MinusMinus => "'--'",
+            TokenKind::Question => "'?'",
+            TokenKind::QuestionQuestion => "'??'",
+            TokenKind::Colon => "':'",
+            TokenKind::FatArrow => "'=>'",
+            TokenKind::PipeArrow => "'|>'",
+            TokenKind::LeftParen => "'('",
+            TokenKind::RightParen => "')'",
+            TokenKind::LeftBracket => "'['",
+            TokenKind::RightBracket => "']'",
+            TokenKind::LeftBrace => "'{'",
+            TokenKind::RightBrace => "'}'",
+            TokenKind::Semicolon => "';'",
+            TokenKind::Comma => "','",
+            TokenKind::DoubleColon => "'::'",
+            TokenKind::Arrow => "'->'",
+            TokenKind::NullsafeArrow => "'?->'",
+            TokenKind::Backslash => "'\\'",
+            TokenKind::At => "'@'",
+            TokenKind::HashBracket => "'#['",
+            TokenKind::Ellipsis => "'...'",
+            TokenKind::If => "'if'",
+            TokenKind::Else => "'else'",
+            TokenKind::ElseIf => "'elseif'",
+            TokenKind::While => "'while'",
+            TokenKind::Do => "'do'",
+            TokenKind::For => "'for'",
+            TokenKind::Foreach => "'foreach'",
+            TokenKind::As => "'as'",
+            TokenKind::Function => "'function'",
+            TokenKind::Return => "'return'",
+            TokenKind::Echo => "'echo'",
+            TokenKind::Print => "'print'",
+            TokenKind::True => "'true'",
+            TokenKind::False => "'false'",
+            TokenKind::Null => "'null'",
+            TokenKind::And => "'and'",
+            TokenKind::Or => "'or'",
+            TokenKind::Xor => "'xor'",
+            TokenKind::Break => "'break'",
+            TokenKind::Continue => "'continue'",
+            TokenKind::Switch => "'switch'",
+            TokenKind::Case => "'case'",
+            TokenKind::Default => "'default'",
+            TokenKind::EndIf => "'endif'",
+            TokenKind::EndWhile => "'endwhile'",
+            TokenKind::EndFor => "'endfor'",
+            TokenKind::EndForeach => "'endforeach'",
+            TokenKind::Throw => "'throw'",
+            TokenKind::Try => "'try'",
+            TokenKind::Catch => "'catch'",
+            TokenKind::Finally => "'finally'",
+            TokenKind::Instanceof => "'instanceof'",
+            TokenKind::Array => "'array'",
+            TokenKind::List => "'list'",
+            TokenKind::Goto => "'goto'",
+            TokenKind::Declare => "'declare'",
+            TokenKind::Unset => "'unset'",
+            TokenKind::Global => "'global'",
+            TokenKind::EndDeclare => "'enddeclare'",
+            TokenKind::EndSwitch => "'endswitch'",
+            TokenKind::Isset => "'isset'",
+            TokenKind::Empty => "'empty'",
+            TokenKind::Include => "'include'",
+            TokenKind::IncludeOnce => "'include_once'",
+            TokenKind::Require => "'require'",
+            TokenKind::RequireOnce => "'require_once'",
+            TokenKind::Eval => "'eval'",
+            TokenKind::Exit => "'exit'",
+            TokenKind::Die => "'die'",
+            TokenKind::Clone => "'clone'",
+            TokenKind::New => "'new'",
+            TokenKind::Class => "'class'",
+            TokenKind::Abstract => "'abstract'",
+            TokenKind::Final => "'final'",
+            TokenKind::Interface => "'interface'",
+            TokenKind::Trait => "'trait'",
+            TokenKind::Extends => "'extends'",
+            TokenKind::Implements => "'implements'",
+            TokenKind::Public => "'public'",
+            TokenKind::Protected => "'protected'",
+            TokenKind::Private => "'private'",
+            TokenKind::Static => "'static'",
+            TokenKind::Const => "'const'",
+            TokenKind::Fn_ => "'fn'",
+            TokenKind::Match_ => "'match'",
+            TokenKind::Namespace => "'namespace'",
+            TokenKind::Use => "'use'",
+            TokenKind::Readonly => "'readonly'",
+            TokenKind::Enum_ => "'enum'",
+            TokenKind::Yield_ => "'yield'",
+            TokenKind::From => "'from'",
+            TokenKind::Self_ => "'self'",
+            TokenKind::Parent_ => "'parent'",
+            TokenKind::MagicClass => "'__CLASS__'",
+            TokenKind::MagicDir => "'__DIR__'",
+            TokenKind::MagicFile => "'__FILE__'",
+            TokenKind::MagicFunction => "'__FUNCTION__'",
+            TokenKind::MagicLine => "'__LINE__'",
+            TokenKind::MagicMethod => "'__METHOD__'",
+            TokenKind::MagicNamespace => "'__NAMESPACE__'",
+            TokenKind::MagicTrait => "'__TRAIT__'",
+            TokenKind::MagicProperty => "'__PROPERTY__'",
+            TokenKind::HaltCompiler => "'__halt_compiler'",
+            TokenKind::OpenTag => "'<?php'",
+            TokenKind::CloseTag => "'?>'",
+            TokenKind::InlineHtml => "inline HTML",
+            TokenKind::Heredoc => "heredoc",
+            TokenKind::Nowdoc => "nowdoc",
+            TokenKind::InvalidNumericLiteral => "invalid numeric literal",
+            TokenKind::LineComment => "line comment",
+            TokenKind::BadCharacter => "unexpected character",
+            TokenKind::Whitespace => "whitespace",
+            TokenKind::HashComment => "hash comment",
+            TokenKind::BlockComment => "block comment",
+            TokenKind::DocComment => "doc comment",
+            TokenKind::Eof => "end of file",
         }
     }
 }
 
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,3 +485,164 @@ mod strings_and_variables {
         assert_eq!(toks[6], (TokenKind::IntLiteral, "45".to_string()));
     }
 }
+
+mod unicode_identifiers {
+    use super::*;
+
+    #[test]
+    fn test_multibyte_variables() {
+        let toks = php_tokens("$täst $привет $🦀 $_ü1");
+        assert_eq!(toks[0], (TokenKind::Variable, "$täst".to_string()));
+        assert_eq!(toks[1], (TokenKind::Variable, "$привет".to_string()));
+        assert_eq!(toks[2], (TokenKind::Variable, "$🦀".to_string()));
+        assert_eq!(toks[3], (TokenKind::Variable, "$_ü1".to_string()));
+    }
+
+    #[test]
+    fn test_multibyte_identifiers() {
+        let toks = php_tokens("class Ünicode extends Базовый {} function 🐘() {}");
+        assert_eq!(toks[1], (TokenKind::Identifier, "Ünicode".to_string()));
+        assert_eq!(toks[3], (TokenKind::Identifier, "Базовый".to_string()));
+        assert_eq!(toks[7], (TokenKind::Identifier, "🐘".to_string()));
+    }
+
+    #[test]
+    fn test_multibyte_identifier_spans_are_bytes() {
+        let tokens = collect_tokens("<?php $ü");
+        assert_eq!(tokens[1].kind, TokenKind::Variable);
+        assert_eq!(tokens[1].span, Span::new(6, 9));
+    }
+
+    #[test]
+    fn test_digit_cannot_start_identifier() {
+        assert_eq!(
+            php_kinds("$1ü"),
+            vec![
+                TokenKind::Dollar,
+                TokenKind::IntLiteral,
+                TokenKind::Identifier
+            ]
+        );
+    }
+}
+
+mod lossless {
+    use super::*;
+    use php_lexer::lex_lossless;
+
+    fn texts(source: &str) -> Vec<(TokenKind, &str)> {
+        lex_lossless(source)
+            .into_iter()
+            .map(|t| (t.kind, &source[t.span.start as usize..t.span.end as usize]))
+            .collect()
+    }
+
+    fn assert_lossless(source: &str) {
+        let tokens = lex_lossless(source);
+        let mut end = 0;
+        for token in &tokens {
+            assert_eq!(token.span.start, end, "gap or overlap before {token:?}");
+            end = token.span.end;
+        }
+        assert_eq!(end as usize, source.len());
+    }
+
+    #[test]
+    fn test_shebang_and_bad_characters() {
+        assert_eq!(
+            texts("#!/usr/bin/env php\n<?php \x01\x01 $a"),
+            vec![
+                (TokenKind::InlineHtml, "#!/usr/bin/env php\n"),
+                (TokenKind::OpenTag, "<?php"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::BadCharacter, "\x01\x01"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Variable, "$a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heredoc_and_interpolation_are_covered() {
+        let source = "<?php\n$x = <<<EOT\n  a {$b['c']} \"d\"\n  EOT;\necho \"$e {$f->g}\", `ls`;\n# done ?>\ntail";
+        assert_lossless(source);
+        assert_eq!(
+            texts(source).last(),
+            Some(&(TokenKind::InlineHtml, "\ntail"))
+        );
+    }
+
+    #[test]
+    fn test_empty_and_html_only() {
+        assert!(lex_lossless("").is_empty());
+        assert_eq!(texts("<p>"), vec![(TokenKind::InlineHtml, "<p>")]);
+    }
+
+    #[test]
+    fn test_fixtures_round_trip() {
+        let dir =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../php-parser/tests/fixtures");
+        let mut checked = 0;
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            assert_lossless(&source);
+            checked += 1;
+        }
+        assert!(checked > 0);
+    }
+}
+
+mod partial {
+    use super::*;
+    use php_lexer::{lex_all, token_at, tokens_in};
+
+    /// `token_at` agrees with a full lex at every offset.
+    fn assert_matches_full_lex(source: &str) {
+        let (tokens, _) = lex_all(source);
+        for offset in 0..=source.len() as u32 {
+            let expected = tokens
+                .iter()
+                .find(|t| t.span.start <= offset && offset < t.span.end)
+                .copied();
+            assert_eq!(token_at(source, offset), expected, "at offset {offset}");
+        }
+    }
+
+    #[test]
+    fn test_token_at_matches_full_lex() {
+        assert_matches_full_lex(
+            "#!/usr/bin/env php\n<?php\n/**\n * Doc;\n */\nfunction f($a) {\n    $s = 'a;\n{';\n    return $a . \"b\";\n}\n?>\n<p>html;\n</p>\n<?= f(1); ?>\n",
+        );
+        assert_matches_full_lex("<?php\n$x = <<<EOT\n  text\n  EOT;\n$y = 1;\n");
+        assert_matches_full_lex("plain text;\nmore\n");
+    }
+
+    #[test]
+    fn test_tokens_in_span() {
+        let source = "<?php\n$a = 1;\n$b = [2, 3];\n";
+        let kinds: Vec<TokenKind> = tokens_in(source, Span::new(15, 23))
+            .iter()
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Variable,
+                TokenKind::Equals,
+                TokenKind::LeftBracket,
+                TokenKind::IntLiteral,
+                TokenKind::Comma,
+            ]
+        );
+        // An empty span gets the tokens touching it.
+        let touching: Vec<Span> = tokens_in(source, Span::new(13, 13))
+            .iter()
+            .map(|t| t.span)
+            .collect();
+        assert_eq!(touching, [Span::new(12, 13)]);
+        assert!(tokens_in(source, Span::new(100, 100)).is_empty());
+    }
+}
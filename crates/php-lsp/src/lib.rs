@@ -0,0 +1,719 @@
+//! Language server publishing the parser's diagnostics and answering hover,
+//! code action, inlay hint, and workspace symbol requests.
+//!
+//! The `php-lsp` binary speaks the Language Server Protocol over stdin and
+//! stdout. It keeps the text of every open document, re-parses it on
+//! `textDocument/didOpen` and `textDocument/didChange`, and publishes each
+//! [`ParseError`] as a diagnostic, so an editor shows every error the
+//! parser recovered from as you type. Closing a document clears its
+//! diagnostics.
+//!
+//! `textDocument/hover` shows the signature, doc-block summary, and type of
+//! the name or variable under the cursor, as [`hover_at`] assembles them.
+//!
+//! `textDocument/codeAction` offers the quick fixes and refactorings that
+//! [`code_actions`] gathers for the selected range: insertions of what the
+//! parser found missing, strict forms of loose comparisons, and codemods
+//! such as sorting imports.
+//!
+//! `textDocument/inlayHint` shows the hints [`inlay_hints`] computes:
+//! parameter names at call sites, resolved against the declarations of the
+//! open documents and workspace folders, inferred closure and property
+//! types, and the variables arrow functions capture.
+//!
+//! `workspace/symbol` searches a [`SymbolIndex`] of the open documents and
+//! of the `.php` files under the workspace folders, read from disk on the
+//! first search. Each folder is a source root, with its `vendor/` directory
+//! a root of lower priority, so the project's own declarations rank above
+//! those of its dependencies.
+//!
+//! Positions are sent in UTF-16 code units, the protocol default, or in
+//! UTF-8 bytes when the client offers `utf-8` in
+//! `general.positionEncodings`. The target PHP version defaults to the
+//! latest and can be set with `{"phpVersion": "8.1"}` as
+//! `initializationOptions`.
+//!
+//! [`Server`] handles decoded messages and [`run`] drives it over any
+//! reader and writer, for embedding or tests:
+//!
+//! ```
+//! use php_lsp::{read_message, write_message, Server};
+//! use serde_json::json;
+//!
+//! let mut server = Server::new();
+//! server.handle(&json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}));
+//! let sent = server.handle(&json!({
+//!     "jsonrpc": "2.0",
+//!     "method": "textDocument/didOpen",
+//!     "params": {"textDocument": {"uri": "file:///a.php", "languageId": "php", "version": 1, "text": "<?php\n$a = ;"}},
+//! }));
+//! let diagnostics = &sent[0]["params"]["diagnostics"];
+//! assert_eq!(diagnostics[0]["range"]["start"], json!({"line": 1, "character": 5}));
+//! assert_eq!(diagnostics[0]["message"], "expected expression, found ';' while parsing assigned value");
+//! ```
+//!
+//! [`ParseError`]: php_rs_parser::diagnostics::ParseError
+//! [`SymbolIndex`]: php_analysis::symbols::SymbolIndex
+//! [`hover_at`]: php_analysis::hover::hover_at
+//! [`code_actions`]: php_analysis::code_action::code_actions
+//! [`inlay_hints`]: php_analysis::inlay_hints::inlay_hints
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use php_analysis::code_action::{code_actions, default_providers};
+use php_analysis::hover::hover_at;
+use php_analysis::inlay_hints::{inlay_hints, InlayHintKind, Signatures};
+use php_analysis::symbols::{SymbolIndex, SymbolKind};
+use php_ast::Span;
+use php_rs_parser::diagnostics::{ParseError, Severity};
+use php_rs_parser::source_map::SourceMap;
+use php_rs_parser::{ParseResult, ParserOptions, PhpVersion};
+use serde_json::{json, Value};
+
+/// How `character` in an LSP position counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PositionEncoding {
+    /// UTF-8 bytes, which is what [`Span`] and [`SourceMap`] count.
+    Utf8,
+    /// UTF-16 code units, the protocol default.
+    #[default]
+    Utf16,
+}
+
+/// The state of one language server session.
+#[derive(Default)]
+pub struct Server {
+    /// The text of each open document by URI.
+    documents: HashMap<String, String>,
+    encoding: PositionEncoding,
+    version: PhpVersion,
+    shut_down: bool,
+    /// Workspace folder URIs, ending in `/`.
+    roots: Vec<String>,
+    symbols: SymbolIndex,
+    /// Declarations of the same files, for parameter-name hints.
+    signatures: Signatures,
+    /// The text of each file indexed from disk by URI, for positions.
+    files: HashMap<String, String>,
+    /// Whether the workspace folders have been read.
+    scanned: bool,
+}
+
+/// Root priorities: a workspace folder's own files rank above its `vendor/`.
+const APP_PRIORITY: i32 = 1;
+const VENDOR_PRIORITY: i32 = 0;
+
+/// The most symbols returned for one `workspace/symbol` request.
+const MAX_WORKSPACE_SYMBOLS: usize = 100;
+
+/// The largest message body [`read_message`] accepts, so a bad
+/// `Content-Length` cannot make it allocate without bound.
+const MAX_MESSAGE_LENGTH: usize = 64 << 20;
+
+/// JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the client has sent `shutdown`.
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down
+    }
+
+    /// Handle one message and return the messages to send in reply: the
+    /// response to a request, and any diagnostics to publish.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let Some(id) = message.get("id") else {
+            return self.notification(method, params);
+        };
+        if self.shut_down {
+            return vec![error(id, INVALID_REQUEST, "server is shut down")];
+        }
+        match method {
+            "initialize" => vec![response(id, self.initialize(params))],
+            "textDocument/hover" => vec![response(id, self.hover(params))],
+            "textDocument/codeAction" => vec![response(id, self.code_actions(params))],
+            "textDocument/inlayHint" => vec![response(id, self.inlay_hints(params))],
+            "workspace/symbol" => {
+                let query = params["query"].as_str().unwrap_or_default();
+                vec![response(id, self.workspace_symbols(query))]
+            }
+            "shutdown" => {
+                self.shut_down = true;
+                vec![response(id, Value::Null)]
+            }
+            _ => vec![error(
+                id,
+                METHOD_NOT_FOUND,
+                &format!("unknown method {method}"),
+            )],
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        let offers_utf8 = params["capabilities"]["general"]["positionEncodings"]
+            .as_array()
+            .is_some_and(|encodings| encodings.iter().any(|e| e == "utf-8"));
+        if offers_utf8 {
+            self.encoding = PositionEncoding::Utf8;
+        }
+        if let Some(version) = params["initializationOptions"]["phpVersion"]
+            .as_str()
+            .and_then(php_version)
+        {
+            self.version = version;
+        }
+        let folders = match params["workspaceFolders"].as_array() {
+            Some(folders) => folders.iter().map(|f| &f["uri"]).collect(),
+            None => vec![&params["rootUri"]],
+        };
+        for uri in folders.into_iter().filter_map(Value::as_str) {
+            let mut root = normalize_uri(uri);
+            if !root.ends_with('/') {
+                root.push('/');
+            }
+            self.symbols.add_root(root.clone(), APP_PRIORITY);
+            self.symbols
+                .add_root(format!("{root}vendor/"), VENDOR_PRIORITY);
+            self.roots.push(root);
+        }
+        let encoding = match self.encoding {
+            PositionEncoding::Utf8 => "utf-8",
+            PositionEncoding::Utf16 => "utf-16",
+        };
+        json!({
+            "capabilities": {
+                "positionEncoding": encoding,
+                "textDocumentSync": {"openClose": true, "change": 1},
+                "hoverProvider": true,
+                "codeActionProvider": true,
+                "inlayHintProvider": true,
+                "workspaceSymbolProvider": true,
+            },
+            "serverInfo": {"name": "php-lsp", "version": env!("CARGO_PKG_VERSION")},
+        })
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let document = &params["textDocument"];
+        let Some(uri) = document["uri"].as_str() else {
+            return Vec::new();
+        };
+        match method {
+            "textDocument/didOpen" => {
+                let text = document["text"].as_str().unwrap_or_default();
+                self.update(uri, text.to_string(), document["version"].as_i64())
+            }
+            "textDocument/didChange" => {
+                // Sync is full, so the last change holds the whole text.
+                let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return Vec::new();
+                };
+                self.update(uri, text.to_string(), document["version"].as_i64())
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                // Back to the saved text, if the file was indexed from disk.
+                let key = normalize_uri(uri);
+                match self.files.contains_key(&key).then(|| uri_to_path(&key)) {
+                    Some(Some(path)) => self.index_path(&path),
+                    _ => {
+                        self.symbols.remove_file(&key);
+                        self.signatures.remove_file(&key);
+                    }
+                }
+                vec![publish(uri, None, Vec::new())]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn update(&mut self, uri: &str, text: String, version: Option<i64>) -> Vec<Value> {
+        let arena = bumpalo::Bump::new();
+        let result = parse(&arena, &text, self.version);
+        let diagnostics = diagnostics_of(&result, self.encoding);
+        self.symbols.add_file(normalize_uri(uri), &result.program);
+        self.signatures
+            .add_file(normalize_uri(uri), &result.program);
+        drop(result);
+        self.documents.insert(uri.to_string(), text);
+        vec![publish(uri, version, diagnostics)]
+    }
+
+    /// The `Hover` at a position of an open document, or `null`.
+    fn hover(&self, params: &Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return Value::Null;
+        };
+        let arena = bumpalo::Bump::new();
+        let result = parse(&arena, text, self.version);
+        let map = &result.source_map;
+        let hover = offset(map, &params["position"], self.encoding)
+            .and_then(|offset| hover_at(&result.program, text, offset));
+        match hover {
+            Some(hover) => json!({
+                "contents": {"kind": "markdown", "value": hover.markdown()},
+                "range": {
+                    "start": position(map, hover.span.start, self.encoding),
+                    "end": position(map, hover.span.end, self.encoding),
+                },
+            }),
+            None => Value::Null,
+        }
+    }
+
+    /// The `CodeAction`s touching a range of an open document.
+    fn code_actions(&self, params: &Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return Value::Null;
+        };
+        let arena = bumpalo::Bump::new();
+        let result = parse(&arena, text, self.version);
+        let map = &result.source_map;
+        let range = &params["range"];
+        let (Some(start), Some(end)) = (
+            offset(map, &range["start"], self.encoding),
+            offset(map, &range["end"], self.encoding),
+        ) else {
+            return Value::Null;
+        };
+        let range = |span: Span| {
+            json!({
+                "start": position(map, span.start, self.encoding),
+                "end": position(map, span.end, self.encoding),
+            })
+        };
+        let actions: Vec<Value> =
+            code_actions(&result, Span::new(start, end), &default_providers())
+                .into_iter()
+                .map(|action| {
+                    let diagnostics: Vec<Value> = action
+                        .diagnostics
+                        .iter()
+                        .map(|diagnostic| {
+                            let mut value = json!({
+                                "range": range(diagnostic.span),
+                                "source": diagnostic.source,
+                                "message": diagnostic.message,
+                            });
+                            if let Some(code) = &diagnostic.code {
+                                value["code"] = json!(code);
+                            }
+                            value
+                        })
+                        .collect();
+                    let edits: Vec<Value> = action
+                        .edits
+                        .iter()
+                        .map(|edit| json!({"range": range(edit.span), "newText": edit.replacement}))
+                        .collect();
+                    json!({
+                        "title": action.title,
+                        "kind": action.kind.as_str(),
+                        "diagnostics": diagnostics,
+                        "edit": {"changes": {uri: edits}},
+                    })
+                })
+                .collect();
+        Value::Array(actions)
+    }
+
+    /// The `InlayHint`s in a range of an open document.
+    fn inlay_hints(&mut self, params: &Value) -> Value {
+        self.scan();
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return Value::Null;
+        };
+        let arena = bumpalo::Bump::new();
+        let result = parse(&arena, text, self.version);
+        let map = &result.source_map;
+        let range = &params["range"];
+        let (Some(start), Some(end)) = (
+            offset(map, &range["start"], self.encoding),
+            offset(map, &range["end"], self.encoding),
+        ) else {
+            return Value::Null;
+        };
+        let hints: Vec<Value> = inlay_hints(&result.program, text, &self.signatures)
+            .into_iter()
+            .filter(|hint| (start..=end).contains(&hint.offset))
+            .map(|hint| {
+                let mut value = json!({
+                    "position": position(map, hint.offset, self.encoding),
+                    "label": hint.label,
+                    "paddingLeft": hint.padding_left,
+                    "paddingRight": hint.padding_right,
+                });
+                match hint.kind {
+                    InlayHintKind::Type => value["kind"] = json!(1),
+                    InlayHintKind::Parameter => value["kind"] = json!(2),
+                    InlayHintKind::Capture => {}
+                }
+                value
+            })
+            .collect();
+        Value::Array(hints)
+    }
+
+    /// `SymbolInformation` for the best matches of `query`.
+    fn workspace_symbols(&mut self, query: &str) -> Value {
+        self.scan();
+        let mut maps = HashMap::new();
+        let open: HashMap<String, &String> = self
+            .documents
+            .iter()
+            .map(|(uri, text)| (normalize_uri(uri), text))
+            .collect();
+        let symbols: Vec<Value> = self
+            .symbols
+            .workspace_symbols(query)
+            .into_iter()
+            .filter_map(|m| {
+                let text = open
+                    .get(m.path)
+                    .copied()
+                    .or_else(|| self.files.get(m.path))?;
+                let map = maps.entry(m.path).or_insert_with(|| SourceMap::new(text));
+                let span = m.symbol.span;
+                let mut symbol = json!({
+                    "name": m.symbol.name,
+                    "kind": symbol_kind(m.symbol.kind),
+                    "location": {
+                        "uri": m.path,
+                        "range": {
+                            "start": position(map, span.start, self.encoding),
+                            "end": position(map, span.end, self.encoding),
+                        },
+                    },
+                });
+                if let Some(container) = &m.symbol.container {
+                    symbol["containerName"] = json!(container);
+                }
+                Some(symbol)
+            })
+            .take(MAX_WORKSPACE_SYMBOLS)
+            .collect();
+        Value::Array(symbols)
+    }
+
+    /// Index the workspace folders, once.
+    fn scan(&mut self) {
+        if !self.scanned {
+            self.scanned = true;
+            for root in self.roots.clone() {
+                if let Some(dir) = uri_to_path(&root) {
+                    self.index_dir(&dir);
+                }
+            }
+        }
+    }
+
+    /// Index the `.php` files under `dir`, skipping hidden directories and
+    /// open documents.
+    fn index_dir(&mut self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        paths.sort();
+        for path in paths {
+            let hidden = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with('.'));
+            if hidden {
+                continue;
+            }
+            if path.is_dir() {
+                self.index_dir(&path);
+            } else if path.extension().is_some_and(|ext| ext == "php") {
+                let uri = path_to_uri(&path);
+                let open = self.documents.keys().any(|open| normalize_uri(open) == uri);
+                if !open {
+                    self.index_path(&path);
+                }
+            }
+        }
+    }
+
+    fn index_path(&mut self, path: &Path) {
+        let uri = path_to_uri(path);
+        let Ok(text) = fs::read_to_string(path) else {
+            self.symbols.remove_file(&uri);
+            self.signatures.remove_file(&uri);
+            return;
+        };
+        let arena = bumpalo::Bump::new();
+        let result = parse(&arena, &text, self.version);
+        self.symbols.add_file(uri.clone(), &result.program);
+        self.signatures.add_file(uri.clone(), &result.program);
+        drop(result);
+        self.files.insert(uri, text);
+    }
+
+    /// The current text of an open document.
+    pub fn document(&self, uri: &str) -> Option<&str> {
+        self.documents.get(uri).map(String::as_str)
+    }
+}
+
+/// Parse `source` and convert its errors to LSP `Diagnostic` objects.
+pub fn diagnostics(source: &str, version: PhpVersion, encoding: PositionEncoding) -> Vec<Value> {
+    let arena = bumpalo::Bump::new();
+    let result = parse(&arena, source, version);
+    diagnostics_of(&result, encoding)
+}
+
+/// Parse `source` for `version`, inferring a `;` missing at a line break so
+/// the statements around a line being typed keep their shape.
+fn parse<'a>(
+    arena: &'a bumpalo::Bump,
+    source: &'a str,
+    version: PhpVersion,
+) -> ParseResult<'a, 'a> {
+    let options = ParserOptions {
+        version,
+        infer_semicolons: true,
+        ..ParserOptions::default()
+    };
+    php_rs_parser::parse_with_options(arena, source, &options)
+}
+
+fn diagnostics_of(result: &ParseResult<'_, '_>, encoding: PositionEncoding) -> Vec<Value> {
+    result
+        .errors
+        .iter()
+        .map(|error| {
+            let parsed = error.to_diagnostic();
+            let span = parsed.span;
+            let severity = match parsed.severity {
+                Severity::Error => 1,
+                Severity::Warning => 2,
+            };
+            let mut diagnostic = json!({
+                "range": {
+                    "start": position(&result.source_map, span.start, encoding),
+                    "end": position(&result.source_map, span.end, encoding),
+                },
+                "severity": severity,
+                "source": "php-rs-parser",
+                "message": parsed.message,
+            });
+            if let Some(code) = parsed.code {
+                diagnostic["code"] = json!(code);
+            }
+            if let ParseError::VersionTooLow { feature, .. } = error {
+                diagnostic["codeDescription"] = json!({ "href": feature.docs_url() });
+            }
+            diagnostic
+        })
+        .collect()
+}
+
+/// The LSP position of a byte offset.
+fn position(map: &SourceMap<'_>, offset: u32, encoding: PositionEncoding) -> Value {
+    let offset = offset.min(map.source().len() as u32);
+    let lc = map.line_col(offset);
+    let character = match encoding {
+        PositionEncoding::Utf8 => lc.col,
+        PositionEncoding::Utf16 => {
+            let start = offset - lc.col;
+            map.snippet(Span::new(start, offset))
+                .map_or(lc.col, |text| text.encode_utf16().count() as u32)
+        }
+    };
+    json!({"line": lc.line, "character": character})
+}
+
+/// The byte offset of an LSP position, clamped to the end of its line.
+fn offset(map: &SourceMap<'_>, position: &Value, encoding: PositionEncoding) -> Option<u32> {
+    let line = map.line_span(u32::try_from(position["line"].as_u64()?).ok()?)?;
+    let character = position["character"].as_u64()?;
+    let text = map.snippet(line)?;
+    let col = match encoding {
+        PositionEncoding::Utf8 => character.min(text.len() as u64) as u32,
+        PositionEncoding::Utf16 => {
+            let mut units = 0;
+            text.char_indices()
+                .find(|(_, c)| {
+                    units += c.len_utf16() as u64;
+                    units > character
+                })
+                .map_or(text.len(), |(i, _)| i) as u32
+        }
+    };
+    Some(line.start + col)
+}
+
+/// The LSP `SymbolKind` number.
+fn symbol_kind(kind: SymbolKind) -> u8 {
+    match kind {
+        SymbolKind::Class | SymbolKind::Trait => 5,
+        SymbolKind::Method => 6,
+        SymbolKind::Property => 7,
+        SymbolKind::Enum => 10,
+        SymbolKind::Interface => 11,
+        SymbolKind::Function => 12,
+        SymbolKind::Constant | SymbolKind::ClassConstant => 14,
+        SymbolKind::EnumCase => 22,
+    }
+}
+
+/// The path of a `file:` URI.
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let rest = rest.strip_prefix("localhost").unwrap_or(rest);
+    let bytes = rest.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+/// The `file:` URI of a path, percent-encoding all but unreserved bytes.
+fn path_to_uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for &byte in path.to_string_lossy().as_bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+/// A `file:` URI in the encoding of [`path_to_uri`], so that the same
+/// file has one key in the index whoever spelled its URI; other URIs as is.
+fn normalize_uri(uri: &str) -> String {
+    uri_to_path(uri).map_or_else(|| uri.to_string(), |path| path_to_uri(&path))
+}
+
+fn php_version(version: &str) -> Option<PhpVersion> {
+    Some(match version {
+        "7.4" => PhpVersion::Php74,
+        "8.0" => PhpVersion::Php80,
+        "8.1" => PhpVersion::Php81,
+        "8.2" => PhpVersion::Php82,
+        "8.3" => PhpVersion::Php83,
+        "8.4" => PhpVersion::Php84,
+        "8.5" => PhpVersion::Php85,
+        _ => return None,
+    })
+}
+
+fn response(id: &Value, result: Value) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "result": result})
+}
+
+fn error(id: &Value, code: i64, message: &str) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "error": {"code": code, "message": message}})
+}
+
+fn publish(uri: &str, version: Option<i64>, diagnostics: Vec<Value>) -> Value {
+    let mut params = json!({"uri": uri, "diagnostics": diagnostics});
+    if let Some(version) = version {
+        params["version"] = json!(version);
+    }
+    json!({"jsonrpc": "2.0", "method": "textDocument/publishDiagnostics", "params": params})
+}
+
+/// Read one `Content-Length`-framed message. `Ok(None)` at the end of the
+/// input; a body that is not JSON is returned as [`Value::Null`]. A body
+/// over 64 MiB is rejected with [`io::ErrorKind::InvalidData`].
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let Some(length) = length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "message without a Content-Length header",
+        ));
+    };
+    if length > MAX_MESSAGE_LENGTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Content-Length of {length} bytes exceeds the maximum of {MAX_MESSAGE_LENGTH}"),
+        ));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body).unwrap_or(Value::Null)))
+}
+
+/// Write one message with its `Content-Length` header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()
+}
+
+/// Serve one session until `exit` or the end of the input. Returns whether
+/// the client shut the server down before `exit`, which decides the exit
+/// code.
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> io::Result<bool> {
+    let mut server = Server::new();
+    while let Some(message) = read_message(&mut input)? {
+        if message.is_null() {
+            write_message(
+                &mut output,
+                &error(&Value::Null, PARSE_ERROR, "invalid JSON"),
+            )?;
+            continue;
+        }
+        if message["method"] == "exit" {
+            break;
+        }
+        for reply in server.handle(&message) {
+            write_message(&mut output, &reply)?;
+        }
+    }
+    Ok(server.is_shut_down())
+}
@@ -0,0 +1,16 @@
+//! `php-lsp`: a language server publishing PHP parse diagnostics over stdio.
+
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let stdin = io::stdin();
+    match php_lsp::run(stdin.lock(), io::stdout().lock()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("php-lsp: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use std::io::Cursor;
+
+use php_lsp::{read_message, write_message};
+use serde_json::{json, Value};
+
+/// Run a session over `messages` and return everything the server sent,
+/// with whether it exited cleanly.
+fn session(messages: &[Value]) -> (Vec<Value>, bool) {
+    let mut input = Vec::new();
+    for message in messages {
+        write_message(&mut input, message).unwrap();
+    }
+    let mut output = Vec::new();
+    let clean = php_lsp::run(Cursor::new(input), &mut output).unwrap();
+    let mut reader = Cursor::new(output);
+    let mut sent = Vec::new();
+    while let Some(message) = read_message(&mut reader).unwrap() {
+        sent.push(message);
+    }
+    (sent, clean)
+}
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({"jsonrpc": "2.0", "method": method, "params": params})
+}
+
+fn ranges(publish: &Value) -> Vec<(u64, u64, u64, u64, String)> {
+    assert_eq!(publish["method"], "textDocument/publishDiagnostics");
+    publish["params"]["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| {
+            let range = &d["range"];
+            (
+                range["start"]["line"].as_u64().unwrap(),
+                range["start"]["character"].as_u64().unwrap(),
+                range["end"]["line"].as_u64().unwrap(),
+                range["end"]["character"].as_u64().unwrap(),
+                d["message"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn publishes_diagnostics_as_documents_change() {
+    let uri = "file:///project/index.php";
+    let (sent, clean) = session(&[
+        request(1, "initialize", json!({"capabilities": {}})),
+        notification("initialized", json!({})),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "php", "version": 1, "text": "<?php\n$s = '😀'; $a = ;\nfunction f( {}\n"}}),
+        ),
+        notification(
+            "textDocument/didChange",
+            json!({"textDocument": {"uri": uri, "version": 2}, "contentChanges": [{"text": "<?php\n$a = 1;\n"}]}),
+        ),
+        notification(
+            "textDocument/didClose",
+            json!({"textDocument": {"uri": uri}}),
+        ),
+        request(2, "shutdown", Value::Null),
+        notification("exit", Value::Null),
+    ]);
+    assert!(clean);
+    assert_eq!(sent.len(), 5, "{sent:#?}");
+
+    assert_eq!(sent[0]["id"], 1);
+    let capabilities = &sent[0]["result"]["capabilities"];
+    assert_eq!(capabilities["positionEncoding"], "utf-16");
+    assert_eq!(capabilities["textDocumentSync"]["change"], 1);
+
+    // The emoji is four UTF-8 bytes but two UTF-16 code units.
+    assert_eq!(sent[1]["params"]["uri"], uri);
+    assert_eq!(sent[1]["params"]["version"], 1);
+    let diagnostics = ranges(&sent[1]);
+    assert!(diagnostics.len() >= 2, "{diagnostics:?}");
+    assert_eq!(
+        diagnostics[0],
+        (
+            1,
+            16,
+            1,
+            17,
+            "expected expression, found ';' while parsing assigned value".to_string()
+        )
+    );
+    assert_eq!(diagnostics[1].0, 2);
+
+    assert_eq!(sent[2]["params"]["version"], 2);
+    assert_eq!(ranges(&sent[2]), []);
+    assert_eq!(ranges(&sent[3]), []);
+    assert_eq!(sent[4], json!({"jsonrpc": "2.0", "id": 2, "result": null}));
+}
+
+#[test]
+fn negotiates_utf8_and_rejects_unknown_requests() {
+    let uri = "file:///a.php";
+    let (sent, clean) = session(&[
+        request(
+            1,
+            "initialize",
+            json!({
+                "capabilities": {"general": {"positionEncodings": ["utf-16", "utf-8"]}},
+                "initializationOptions": {"phpVersion": "7.4"},
+            }),
+        ),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "php", "version": 1, "text": "<?php $é = match (1) { default => 2 };"}}),
+        ),
+        request(2, "textDocument/definition", json!({})),
+        notification("exit", Value::Null),
+        request(3, "shutdown", Value::Null),
+    ]);
+    assert!(!clean, "exit without shutdown is not clean");
+    assert_eq!(sent.len(), 3, "{sent:#?}");
+    assert_eq!(
+        sent[0]["result"]["capabilities"]["positionEncoding"],
+        "utf-8"
+    );
+
+    // `match` needs PHP 8.0; the column counts the two bytes of `é`.
+    let diagnostics = ranges(&sent[1]);
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].1, 12);
+    let diagnostic = &sent[1]["params"]["diagnostics"][0];
+    assert_eq!(diagnostic["severity"], 1);
+    assert_eq!(diagnostic["code"], "match-expressions");
+    assert_eq!(
+        diagnostic["codeDescription"]["href"],
+        "https://www.php.net/manual/en/migration80.new-features.php"
+    );
+
+    assert_eq!(sent[2]["id"], 2);
+    assert_eq!(sent[2]["error"]["code"], -32601);
+}
+
+#[test]
+fn rejects_oversized_content_length() {
+    let mut reader = Cursor::new(b"Content-Length: 18446744073709551615\r\n\r\n{}".to_vec());
+    let error = read_message(&mut reader).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn searches_workspace_symbols_across_roots() {
+    let root = std::env::temp_dir().join(format!("php-lsp-symbols-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("vendor/lib")).unwrap();
+    std::fs::create_dir_all(root.join(".git")).unwrap();
+    std::fs::write(
+        root.join("src/User.php"),
+        "<?php\nnamespace App;\n\nclass User {\n    public function save() {}\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("vendor/lib/User.php"),
+        "<?php\nnamespace Lib;\n\nclass User {}\n",
+    )
+    .unwrap();
+    std::fs::write(root.join(".git/User.php"), "<?php class User {}").unwrap();
+
+    let root_uri = format!("file://{}", root.display());
+    let open_uri = format!("{root_uri}/src/Users.php");
+    let (sent, _) = session(&[
+        request(
+            1,
+            "initialize",
+            json!({"capabilities": {}, "workspaceFolders": [{"uri": root_uri, "name": "app"}]}),
+        ),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": open_uri, "languageId": "php", "version": 1, "text": "<?php\nfunction user_count() {}\n"}}),
+        ),
+        request(2, "workspace/symbol", json!({"query": "User"})),
+    ]);
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(
+        sent[0]["result"]["capabilities"]["workspaceSymbolProvider"],
+        true
+    );
+    let symbols = sent[2]["result"].as_array().unwrap();
+    let found: Vec<(&str, u64, &str)> = symbols
+        .iter()
+        .map(|s| {
+            let uri = s["location"]["uri"].as_str().unwrap();
+            (
+                s["name"].as_str().unwrap(),
+                s["kind"].as_u64().unwrap(),
+                &uri[root_uri.len()..],
+            )
+        })
+        .collect();
+    // The app's class ranks above the vendored one; hidden directories
+    // are skipped and open documents are searched.
+    assert_eq!(
+        found,
+        [
+            ("User", 5, "/src/User.php"),
+            ("User", 5, "/vendor/lib/User.php"),
+            ("user_count", 12, "/src/Users.php"),
+        ]
+    );
+    assert_eq!(
+        symbols[0]["location"]["range"],
+        json!({"start": {"line": 3, "character": 0}, "end": {"line": 5, "character": 1}})
+    );
+}
+
+#[test]
+fn hovers_names_and_variables() {
+    let uri = "file:///hover.php";
+    let text = "<?php\n/** Greets. */\nfunction greet(string $who): string { return $who; }\n$s = '😀'; greet($s);\n";
+    let (sent, _) = session(&[
+        request(1, "initialize", json!({"capabilities": {}})),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "php", "version": 1, "text": text}}),
+        ),
+        // `greet` after the emoji, two UTF-16 code units wide.
+        request(
+            2,
+            "textDocument/hover",
+            json!({"textDocument": {"uri": uri}, "position": {"line": 3, "character": 11}}),
+        ),
+        request(
+            3,
+            "textDocument/hover",
+            json!({"textDocument": {"uri": uri}, "position": {"line": 3, "character": 0}}),
+        ),
+        request(
+            4,
+            "textDocument/hover",
+            json!({"textDocument": {"uri": uri}, "position": {"line": 3, "character": 8}}),
+        ),
+    ]);
+    assert_eq!(sent[0]["result"]["capabilities"]["hoverProvider"], true);
+    assert_eq!(
+        sent[2]["result"],
+        json!({
+            "contents": {
+                "kind": "markdown",
+                "value": "```php\nfunction greet(string $who): string\n```\n\nGreets.",
+            },
+            "range": {"start": {"line": 3, "character": 11}, "end": {"line": 3, "character": 16}},
+        })
+    );
+    assert_eq!(
+        sent[3]["result"]["contents"]["value"],
+        "```php\nstring $s\n```"
+    );
+    assert_eq!(sent[4]["result"], Value::Null);
+}
+
+#[test]
+fn offers_code_actions() {
+    let uri = "file:///fix.php";
+    let text = "<?php\n$n = count($a)\nif (strlen($s) == 0) {}\n";
+    let (sent, _) = session(&[
+        request(1, "initialize", json!({"capabilities": {}})),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "php", "version": 1, "text": text}}),
+        ),
+        request(
+            2,
+            "textDocument/codeAction",
+            json!({
+                "textDocument": {"uri": uri},
+                "range": {"start": {"line": 1, "character": 0}, "end": {"line": 1, "character": 14}},
+                "context": {"diagnostics": []},
+            }),
+        ),
+        request(
+            3,
+            "textDocument/codeAction",
+            json!({
+                "textDocument": {"uri": uri},
+                "range": {"start": {"line": 2, "character": 15}, "end": {"line": 2, "character": 15}},
+                "context": {"diagnostics": []},
+            }),
+        ),
+    ]);
+    assert_eq!(
+        sent[0]["result"]["capabilities"]["codeActionProvider"],
+        true
+    );
+    let at = |line, character| json!({"line": line, "character": character});
+    assert_eq!(
+        sent[2]["result"],
+        json!([{
+            "title": "Insert `;`",
+            "kind": "quickfix",
+            "diagnostics": [{
+                "range": {"start": at(1, 14), "end": at(1, 14)},
+                "source": "php-rs-parser",
+                "message": "missing ';' after expression",
+            }],
+            "edit": {"changes": {uri: [{"range": {"start": at(1, 14), "end": at(1, 14)}, "newText": ";"}]}},
+        }])
+    );
+    let action = &sent[3]["result"][0];
+    assert_eq!(action["title"], "Use `===`");
+    assert_eq!(action["diagnostics"][0]["code"], "loose-equality");
+    assert_eq!(
+        action["edit"]["changes"][uri][0],
+        json!({"range": {"start": at(2, 15), "end": at(2, 17)}, "newText": "==="})
+    );
+}
+
+#[test]
+fn shows_inlay_hints_resolved_across_files() {
+    let root = std::env::temp_dir().join(format!("php-lsp-hints-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(
+        root.join("mail.php"),
+        "<?php\nfunction send(string $to, string $subject) {}\n",
+    )
+    .unwrap();
+
+    let root_uri = format!("file://{}", root.display());
+    let uri = format!("{root_uri}/main.php");
+    let text = "<?php\n$to = 'ops';\nsend($to, 'disk full');\n$f = fn() => $to;\n";
+    let (sent, _) = session(&[
+        request(
+            1,
+            "initialize",
+            json!({"capabilities": {}, "workspaceFolders": [{"uri": root_uri, "name": "app"}]}),
+        ),
+        notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "php", "version": 1, "text": text}}),
+        ),
+        request(
+            2,
+            "textDocument/inlayHint",
+            json!({
+                "textDocument": {"uri": uri},
+                "range": {"start": {"line": 0, "character": 0}, "end": {"line": 4, "character": 0}},
+            }),
+        ),
+    ]);
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(sent[0]["result"]["capabilities"]["inlayHintProvider"], true);
+    assert_eq!(
+        sent[2]["result"],
+        json!([
+            {
+                "position": {"line": 2, "character": 10},
+                "label": "subject:",
+                "paddingLeft": false,
+                "paddingRight": true,
+                "kind": 2,
+            },
+            {
+                "position": {"line": 3, "character": 9},
+                "label": "use ($to)",
+                "paddingLeft": true,
+                "paddingRight": false,
+            },
+            {
+                "position": {"line": 3, "character": 9},
+                "label": ": string",
+                "paddingLeft": false,
+                "paddingRight": false,
+                "kind": 1,
+            },
+        ])
+    );
+}
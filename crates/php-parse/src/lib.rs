@@ -0,0 +1,367 @@
+//! Command-line front end to the parser.
+//!
+//! The `php-parse` binary reads PHP from files, directories, and stdin and
+//! runs one of three commands on each input:
+//!
+//! - `dump` prints the tree in the `ast\dump()` format of the
+//!   [`ext_ast`](php_ast::ext_ast) conversion, with line numbers, and reports
+//!   parse errors on stderr;
+//! - `json` prints one JSON object per input and line: its `path`, the
+//!   serialized `program`, and its `errors` as serialized
+//!   [`Diagnostic`](php_rs_parser::diagnostics::Diagnostic)s with their
+//!   1-based `line` and `column`;
+//! - `lint` prints each diagnostic as `path:line:column: error: message`,
+//!   or `warning:` for syntax the target version only deprecates, and exits
+//!   with status 1 if there were any errors.
+//!
+//! A directory stands for the `.php` files under it, hidden directories
+//! skipped, in path order; `-` or no path at all reads stdin. The target
+//! PHP version defaults to the latest and is set with `--php-version 8.1`.
+//! Inputs need not be UTF-8; see [`php_rs_parser::bytes`]. Positions and
+//! spans are always in bytes of the input as read. Unreadable inputs and bad
+//! arguments exit with status 2.
+//!
+//! [`run`] is the whole program over any stdin, stdout, and stderr, for
+//! embedding or tests:
+//!
+//! ```
+//! let mut out = Vec::new();
+//! let mut err = Vec::new();
+//! let args = ["lint".to_string()];
+//! let status = php_parse::run(&args, "<?php\n$a = ;\n".as_bytes(), &mut out, &mut err);
+//! assert_eq!(status, 1);
+//! assert_eq!(
+//!     String::from_utf8(out).unwrap(),
+//!     "<stdin>:2:6: error: expected expression, found ';' while parsing assigned value\n",
+//! );
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use php_ast::{ext_ast, Span};
+use php_rs_parser::bytes::ByteOffsets;
+use php_rs_parser::diagnostics::Severity;
+use php_rs_parser::{ParseResult, ParserOptions, PhpVersion};
+use serde_json::json;
+
+/// The exit status when every input was read and, for `lint`, parsed
+/// without errors.
+pub const EXIT_OK: u8 = 0;
+/// The exit status of `lint` when an input has parse errors; warnings alone
+/// do not count.
+pub const EXIT_PARSE_ERRORS: u8 = 1;
+/// The exit status on bad arguments or an unreadable input.
+pub const EXIT_FAILURE: u8 = 2;
+
+/// The `--help` text.
+pub const USAGE: &str = "\
+usage: php-parse <dump|json|lint> [--php-version <version>] [<path>...]
+
+commands:
+    dump    print the AST of each input
+    json    print each input's AST and parse errors as a line of JSON
+    lint    report parse diagnostics; exit with status 1 if there are errors
+
+Each path is a file, a directory searched for .php files, or - for stdin.
+With no path, stdin is read. The PHP version is 7.4 to 8.5, default 8.5.
+";
+
+/// What to do with each input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Dump,
+    Json,
+    Lint,
+}
+
+impl Command {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Command::Dump => "dump",
+            Command::Json => "json",
+            Command::Lint => "lint",
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parsed command-line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub version: PhpVersion,
+    /// Files and directories, with `-` for stdin. Empty means stdin.
+    pub paths: Vec<PathBuf>,
+}
+
+impl Options {
+    /// Parse the arguments after the program name. `Ok(None)` asks for the
+    /// usage text.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        let mut command = None;
+        let mut version = PhpVersion::default();
+        let mut paths = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--php-version" => {
+                    let value = args.next().ok_or("--php-version needs a value")?;
+                    version = php_version(value)?;
+                }
+                _ if arg.starts_with("--php-version=") => {
+                    version = php_version(&arg["--php-version=".len()..])?;
+                }
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown option `{arg}`"));
+                }
+                _ if command.is_none() => {
+                    command = Some(match arg.as_str() {
+                        "dump" => Command::Dump,
+                        "json" => Command::Json,
+                        "lint" => Command::Lint,
+                        _ => return Err(format!("unknown command `{arg}`")),
+                    });
+                }
+                _ => paths.push(PathBuf::from(arg)),
+            }
+        }
+        let command = command.ok_or("missing command")?;
+        Ok(Some(Self {
+            command,
+            version,
+            paths,
+        }))
+    }
+}
+
+fn php_version(version: &str) -> Result<PhpVersion, String> {
+    Ok(match version {
+        "7.4" => PhpVersion::Php74,
+        "8.0" => PhpVersion::Php80,
+        "8.1" => PhpVersion::Php81,
+        "8.2" => PhpVersion::Php82,
+        "8.3" => PhpVersion::Php83,
+        "8.4" => PhpVersion::Php84,
+        "8.5" => PhpVersion::Php85,
+        _ => return Err(format!("unsupported PHP version `{version}`")),
+    })
+}
+
+/// Run `php-parse` with `args`, the arguments after the program name, and
+/// return the exit status.
+pub fn run(args: &[String], stdin: impl Read, out: &mut impl Write, err: &mut impl Write) -> u8 {
+    let options = match Options::from_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            let _ = out.write_all(USAGE.as_bytes());
+            return EXIT_OK;
+        }
+        Err(message) => {
+            let _ = writeln!(err, "php-parse: {message}\n\n{USAGE}");
+            return EXIT_FAILURE;
+        }
+    };
+    match execute(&options, stdin, out, err) {
+        Ok(status) => status,
+        Err(e) => {
+            let _ = writeln!(err, "php-parse: {e}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Where an input comes from.
+enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+fn execute(
+    options: &Options,
+    mut stdin: impl Read,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> io::Result<u8> {
+    let mut sources = Vec::new();
+    if options.paths.is_empty() {
+        sources.push(Source::Stdin);
+    }
+    for path in &options.paths {
+        if path.as_os_str() == "-" {
+            sources.push(Source::Stdin);
+        } else if path.is_dir() {
+            php_files(path, &mut sources);
+        } else {
+            sources.push(Source::File(path.clone()));
+        }
+    }
+
+    let mut status = EXIT_OK;
+    let headers = options.command == Command::Dump && sources.len() > 1;
+    for source in sources {
+        let (name, text) = match source {
+            Source::Stdin => {
+                let mut text = Vec::new();
+                match stdin.read_to_end(&mut text) {
+                    Ok(_) => ("<stdin>".to_string(), text),
+                    Err(e) => {
+                        writeln!(err, "php-parse: <stdin>: {e}")?;
+                        status = EXIT_FAILURE;
+                        continue;
+                    }
+                }
+            }
+            Source::File(path) => match fs::read(&path) {
+                Ok(text) => (path.display().to_string(), text),
+                Err(e) => {
+                    writeln!(err, "php-parse: {}: {e}", path.display())?;
+                    status = EXIT_FAILURE;
+                    continue;
+                }
+            },
+        };
+
+        let arena = bumpalo::Bump::new();
+        let parser_options = ParserOptions {
+            version: options.version,
+            ..ParserOptions::default()
+        };
+        let result = php_rs_parser::parse_bytes_with_options(&arena, &text, &parser_options);
+        let offsets = ByteOffsets::new(result.source);
+        match options.command {
+            Command::Dump => {
+                if headers {
+                    writeln!(out, "==> {name} <==")?;
+                }
+                let ast = ext_ast::from_program(&result.program, &result.source_map);
+                writeln!(out, "{}", ast.dump(true))?;
+                report(&name, &result, &offsets, err)?;
+            }
+            Command::Json => {
+                writeln!(out, "{}", to_json(&name, &result, &offsets))?;
+            }
+            Command::Lint => {
+                report(&name, &result, &offsets, out)?;
+                let failed = result
+                    .errors
+                    .iter()
+                    .any(|error| error.severity() == Severity::Error);
+                if failed || result.errors_truncated {
+                    status = status.max(EXIT_PARSE_ERRORS);
+                }
+            }
+        }
+    }
+    out.flush()?;
+    Ok(status)
+}
+
+/// Add the `.php` files under `dir` to `sources`, skipping hidden
+/// directories.
+fn php_files(dir: &Path, sources: &mut Vec<Source>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        sources.push(Source::File(dir.to_path_buf()));
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    paths.sort();
+    for path in paths {
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            php_files(&path, sources);
+        } else if path.extension().is_some_and(|ext| ext == "php") {
+            sources.push(Source::File(path));
+        }
+    }
+}
+
+/// Write each diagnostic as `name:line:column: severity: message`, with
+/// 1-based lines and byte columns.
+fn report(
+    name: &str,
+    result: &ParseResult<'_, '_>,
+    offsets: &ByteOffsets,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    for error in &result.errors {
+        let (line, column) = offsets
+            .to_original_line_col(&result.source_map, error.span().start)
+            .to_one_based();
+        let severity = error.severity().as_str();
+        writeln!(out, "{name}:{line}:{column}: {severity}: {error}")?;
+    }
+    if result.errors_truncated {
+        writeln!(
+            out,
+            "{name}: error: too many errors; the rest were not reported"
+        )?;
+    }
+    Ok(())
+}
+
+fn to_json(name: &str, result: &ParseResult<'_, '_>, offsets: &ByteOffsets) -> serde_json::Value {
+    let errors: Vec<_> = result
+        .errors
+        .iter()
+        .map(|error| {
+            let (line, column) = offsets
+                .to_original_line_col(&result.source_map, error.span().start)
+                .to_one_based();
+            let mut value = serde_json::to_value(error.to_diagnostic()).unwrap_or_default();
+            value["line"] = json!(line);
+            value["column"] = json!(column);
+            value
+        })
+        .collect();
+    let mut value = json!({
+        "path": name,
+        "program": result.program,
+        "errors": errors,
+    });
+    if !offsets.is_identity() {
+        to_original_spans(&mut value, offsets);
+    }
+    value
+}
+
+/// Map every serialized span in `value`, an object of just `start` and
+/// `end`, from decoded offsets to offsets into the input.
+fn to_original_spans(value: &mut serde_json::Value, offsets: &ByteOffsets) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            let span = match (fields.get("start"), fields.get("end")) {
+                (Some(start), Some(end)) if fields.len() == 2 => start.as_u64().zip(end.as_u64()),
+                _ => None,
+            };
+            if let Some((start, end)) = span {
+                let span = Span::new(start as u32, end as u32);
+                let span = offsets.to_original_span(span);
+                fields.insert("start".to_string(), json!(span.start));
+                fields.insert("end".to_string(), json!(span.end));
+            } else {
+                fields
+                    .values_mut()
+                    .for_each(|field| to_original_spans(field, offsets));
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| to_original_spans(item, offsets)),
+        _ => {}
+    }
+}
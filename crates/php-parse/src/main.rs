@@ -0,0 +1,16 @@
+//! `php-parse`: dump, print as JSON, or lint PHP files from the command line.
+
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stdin = io::stdin();
+    let status = php_parse::run(
+        &args,
+        stdin.lock(),
+        &mut io::stdout().lock(),
+        &mut io::stderr(),
+    );
+    ExitCode::from(status)
+}
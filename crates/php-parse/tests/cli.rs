@@ -0,0 +1,181 @@
+use serde_json::Value;
+
+/// Run `php-parse` with `args` over `stdin` and return its exit status,
+/// stdout, and stderr.
+fn run(args: &[&str], stdin: &str) -> (u8, String, String) {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let status = php_parse::run(&args, stdin.as_bytes(), &mut out, &mut err);
+    (
+        status,
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn dumps_the_ast() {
+    let (status, out, err) = run(&["dump"], "<?php\necho $a;\n");
+    assert_eq!(status, 0);
+    assert_eq!(
+        out,
+        "AST_STMT_LIST @ 1\n    0: AST_STMT_LIST @ 2\n        0: AST_ECHO @ 2\n            expr: AST_VAR @ 2\n                name: \"a\"\n"
+    );
+    assert_eq!(err, "");
+}
+
+#[test]
+fn dump_reports_parse_errors_on_stderr() {
+    let (status, out, err) = run(&["dump", "-"], "<?php\n$a = ;\n");
+    assert_eq!(status, 0);
+    assert!(out.starts_with("AST_STMT_LIST"));
+    assert_eq!(
+        err,
+        "<stdin>:2:6: error: expected expression, found ';' while parsing assigned value\n"
+    );
+}
+
+#[test]
+fn prints_json() {
+    let (status, out, _) = run(&["json"], "<?php\nif ($a) {\n");
+    assert_eq!(status, 0);
+    assert_eq!(out.lines().count(), 1);
+    let value: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(value["path"], "<stdin>");
+    assert_eq!(value["program"]["stmts"].as_array().unwrap().len(), 1);
+    let error = &value["errors"][0];
+    assert_eq!(
+        (error["line"].as_u64(), error["column"].as_u64()),
+        (Some(3), Some(1))
+    );
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("unclosed delimiter"));
+    assert_eq!(error["severity"], "error");
+    assert_eq!(error["span"]["start"], error["span"]["end"]);
+    assert_eq!(error["labels"][0]["span"]["start"], 14);
+    assert_eq!(error["suggestions"][0]["replacement"], "}");
+}
+
+#[test]
+fn reads_sources_that_are_not_utf8() {
+    let args = ["lint".to_string()];
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let stdin: &[u8] = b"<?php\necho 'caf\xE9';\n$a = ;\n";
+    let status = php_parse::run(&args, stdin, &mut out, &mut err);
+    assert_eq!(status, 1);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "<stdin>:3:6: error: expected expression, found ';' while parsing assigned value\n"
+    );
+    assert!(err.is_empty());
+}
+
+#[test]
+fn reports_positions_in_the_bytes_read() {
+    let stdin: &[u8] = b"<?php\n$s = \"\xe9\xe9\xe9\"; $t = ;\n";
+    let mut out = Vec::new();
+    let status = php_parse::run(&["lint".to_string()], stdin, &mut out, &mut Vec::new());
+    assert_eq!(status, 1);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "<stdin>:2:18: error: expected expression, found ';' while parsing assigned value\n"
+    );
+
+    let mut out = Vec::new();
+    php_parse::run(&["json".to_string()], stdin, &mut out, &mut Vec::new());
+    let value: Value = serde_json::from_slice(&out).unwrap();
+    let error = &value["errors"][0];
+    assert_eq!(error["column"], 18);
+    assert_eq!(error["span"]["start"], 23);
+    assert_eq!(value["program"]["span"]["end"], 24);
+    let assign = &value["program"]["stmts"][0]["kind"]["Expression"];
+    assert_eq!(assign["span"]["start"], 6);
+    assert_eq!(assign["span"]["end"], 16);
+}
+
+#[test]
+fn lints_files_and_directories() {
+    let root = std::env::temp_dir().join(format!("php-parse-lint-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src/sub")).unwrap();
+    std::fs::create_dir_all(root.join(".git")).unwrap();
+    std::fs::write(root.join("src/ok.php"), "<?php\necho 1;\n").unwrap();
+    std::fs::write(root.join("src/sub/bad.php"), "<?php\n\nif ($a) {\n").unwrap();
+    std::fs::write(root.join("src/notes.txt"), "<?php $").unwrap();
+    std::fs::write(root.join(".git/hook.php"), "<?php $").unwrap();
+    std::fs::write(root.join("script"), "<?php $a = ;").unwrap();
+
+    let dir = root.join("src");
+    let script = root.join("script");
+    let (status, out, err) = run(
+        &["lint", dir.to_str().unwrap(), script.to_str().unwrap()],
+        "",
+    );
+    let bad = root.join("src/sub/bad.php");
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(status, 1);
+    assert_eq!(lines.len(), 2, "{out}");
+    assert!(
+        lines[0].starts_with(&format!("{}:4:1: error: unclosed ", bad.display())),
+        "{out}"
+    );
+    assert!(
+        lines[1].starts_with(&format!("{}:1:12: error: ", script.display())),
+        "{out}"
+    );
+    assert_eq!(err, "");
+
+    let (status, out, _) = run(&["lint", root.join("src/ok.php").to_str().unwrap()], "");
+    assert_eq!((status, out.as_str()), (0, ""));
+
+    let (status, _, err) = run(&["lint", root.join("missing.php").to_str().unwrap()], "");
+    assert_eq!(status, 2);
+    assert!(err.starts_with("php-parse: "), "{err}");
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn lints_against_a_php_version() {
+    let src = "<?php\nenum Suit {}\n";
+    assert_eq!(run(&["lint"], src).0, 0);
+    let (status, out, _) = run(&["lint", "--php-version", "8.0"], src);
+    assert_eq!(status, 1);
+    assert!(out.starts_with("<stdin>:2:1: error: "), "{out}");
+    assert_eq!(run(&["lint", "--php-version=8.1"], src).0, 0);
+}
+
+#[test]
+fn lints_deprecated_syntax_as_warnings() {
+    let src = "<?php
+echo $s{0};
+";
+    let (status, out, _) = run(&["lint", "--php-version", "7.4"], src);
+    assert_eq!(status, 0);
+    assert!(out.starts_with("<stdin>:2:8: warning: "), "{out}");
+    let (status, out, _) = run(&["lint", "--php-version", "8.0"], src);
+    assert_eq!(status, 1);
+    assert!(out.starts_with("<stdin>:2:8: error: "), "{out}");
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    for args in [
+        &[][..],
+        &["parse"],
+        &["lint", "--fix"],
+        &["lint", "--php-version", "5.6"],
+    ] {
+        let (status, out, err) = run(args, "");
+        assert_eq!(status, 2, "{args:?}");
+        assert_eq!(out, "");
+        assert!(err.contains("usage: php-parse"), "{err}");
+    }
+    let (status, out, _) = run(&["--help"], "");
+    assert_eq!(status, 0);
+    assert!(out.starts_with("usage: php-parse"));
+}
@@ -0,0 +1,161 @@
+//! Expression parsing: sources that are almost all expressions, one group
+//! per kind of expression, plus the largest files of the real-world corpora
+//! when they are checked out.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::path::Path;
+use std::time::Duration;
+
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+/// `count` functions whose bodies repeat `body`, with `{i}` replaced by the
+/// function's number.
+fn functions(count: usize, body: &str) -> String {
+    let mut src = String::from("<?php\n\n");
+    for i in 0..count {
+        src.push_str(&format!("function f{i}($a, $b, $c, $items, $user) {{\n"));
+        src.push_str(&body.replace("{i}", &i.to_string()));
+        src.push_str("}\n\n");
+    }
+    src
+}
+
+/// Binary, unary, comparison, and logical operators at every precedence.
+fn operators() -> String {
+    functions(
+        300,
+        r#"    $x = $a + $b * $c - $a / ($b % 7) ** 2;
+    $y = ($a << 2 | $b >> 1) & ~$c ^ 0xff;
+    $ok = $a > $b && $b >= $c || !$a && $c <= 10 and $b != null;
+    $cmp = $a <=> $b ?: $c ?? -1;
+    $s = 'id-' . $a . ':' . ($b + {i}) . "-" . $c;
+    $z = $x === $y ? $a * 2 : ($b !== null ? $b : $c);
+    $a += $b; $b .= 'x'; $c ??= $a - $b;
+    $n = (int) $a + (float) $b - (bool) $c;
+    return $x instanceof Countable || $y !== false && ++$a > $b--;
+"#,
+    )
+}
+
+/// Function, method, static, and nullsafe calls, chained.
+fn calls() -> String {
+    functions(
+        300,
+        r#"    $user->getProfile()->getAddress()->setCity(trim($a), strtoupper($b));
+    $result = $this->repository->findBy(['id' => $a], ['name' => 'ASC'], 10, {i});
+    $name = $user?->getName() ?? Str::lower(Config::get('app.name', 'default'));
+    $items = array_map(strtolower(...), array_filter($items, 'is_string'));
+    $query = DB::table('users')->where('id', '>', $a)->orderBy('name')->limit($b)->get();
+    static::create(self::NAME, parent::build($a, $b), new Options(limit: $c, strict: true));
+    return sprintf('%s:%d', $user->name, count($items)) . implode(',', $items);
+"#,
+    )
+}
+
+/// Array literals, destructuring, and subscripts.
+fn arrays() -> String {
+    functions(
+        300,
+        r#"    $config = ['name' => $a, 'tags' => ['x', 'y', $b], 'nested' => ['a' => [1, 2, 3], 'b' => null]];
+    [$first, [$second, $third]] = [$a, [$b, $c]];
+    ['id' => $id, 'name' => $name] = $items[{i}];
+    $matrix = [[1, 0, 0], [0, 1, 0], [0, 0, 1], [$a, $b, $c]];
+    $merged = [...$items, ...$config['tags'], 'extra' => $matrix[1][2]];
+    $items[$a][$b]['count'] = ($items[$a][$b]['count'] ?? 0) + 1;
+    return $config['nested']['a'][0] + $matrix[$a % 3][$b % 3];
+"#,
+    )
+}
+
+/// Closures, arrow functions, `match`, and `new`.
+fn closures() -> String {
+    functions(
+        300,
+        r#"    $double = fn(int $x): int => $x * 2;
+    $sorted = usort($items, function (array $l, array $r) use ($a, &$b): int {
+        return $l['rank'] <=> $r['rank'] ?: strcmp($l['name'], $r['name']);
+    });
+    $label = match (true) {
+        $a > 100 => 'large',
+        $a > 10, $a === {i} => 'medium',
+        default => throw new InvalidArgumentException("bad value $a"),
+    };
+    $handler = new class($a) implements Handler { public function __construct(private int $n) {} };
+    return array_reduce($items, static fn($carry, $item) => $carry + $double($item), 0);
+"#,
+    )
+}
+
+/// Single-quoted, interpolated, heredoc, and concatenated strings.
+fn strings() -> String {
+    functions(
+        300,
+        r#"    $html = '<div class="' . htmlspecialchars($a) . '">' . $b . '</div>';
+    $msg = "User {$user->name} ({$user->id}) has $c items in {$items[0]}";
+    $sql = <<<SQL
+        SELECT * FROM users WHERE id = {$a} AND name = '{$b}'
+        SQL;
+    $path = __DIR__ . '/../storage/' . $a . '/' . basename($b) . ".{i}.log";
+    return "$a-$b-$c" . sprintf("%05d", $c) . 'done';
+"#,
+    )
+}
+
+fn parse_all(sources: &[String]) {
+    for src in sources {
+        let arena = bumpalo::Bump::with_capacity(src.len() * 5);
+        std::hint::black_box(php_rs_parser::parse(&arena, src));
+    }
+}
+
+fn bench_sources(c: &mut Criterion, name: &str, sources: &[String]) {
+    let total_bytes: usize = sources.iter().map(String::len).sum();
+    let mut group = c.benchmark_group("expr");
+    group.throughput(Throughput::Bytes(total_bytes as u64));
+    group.measurement_time(Duration::from_secs(5));
+    group.bench_function(
+        BenchmarkId::new(name, format!("{} files", sources.len())),
+        |b| b.iter(|| parse_all(sources)),
+    );
+    group.finish();
+}
+
+/// The `count` largest `.php` files under `dir`.
+fn largest_files(dir: &Path, count: usize) -> Vec<String> {
+    let mut files: Vec<_> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            e.file_type().is_file() && e.path().extension().and_then(|s| s.to_str()) == Some("php")
+        })
+        .filter_map(|e| Some((e.metadata().ok()?.len(), e.into_path())))
+        .collect();
+    files.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    files
+        .into_iter()
+        .take(count)
+        .filter_map(|(_, path)| std::fs::read_to_string(path).ok())
+        .collect()
+}
+
+fn bench_expressions(c: &mut Criterion) {
+    bench_sources(c, "operators", &[operators()]);
+    bench_sources(c, "calls", &[calls()]);
+    bench_sources(c, "arrays", &[arrays()]);
+    bench_sources(c, "closures", &[closures()]);
+    bench_sources(c, "strings", &[strings()]);
+
+    let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/corpus");
+    for name in ["wordpress", "symfony"] {
+        let sources = largest_files(&base.join(name), 20);
+        if sources.is_empty() {
+            eprintln!("corpus '{name}' not found — skipping");
+            continue;
+        }
+        bench_sources(c, &format!("{name}-largest"), &sources);
+    }
+}
+
+criterion_group!(benches, bench_expressions);
+criterion_main!(benches);
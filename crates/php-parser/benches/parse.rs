@@ -57,6 +57,59 @@ fn bench_corpus(c: &mut Criterion, name: &str, dir: &Path) {
     group.finish();
 }
 
+/// Declaration-heavy code generated in memory, so there is always something
+/// to measure: classes with properties, constants, and methods whose bodies
+/// are mostly calls with arguments.
+fn generated_source() -> String {
+    let mut src = String::from("<?php\n\nnamespace App\\Generated;\n\n");
+    for i in 0..200 {
+        src.push_str(&format!(
+            r#"final class Service{i} extends Base implements Contract
+{{
+    public const VERSION = '{i}';
+    private array $cache = [];
+    protected ?Logger $logger = null;
+
+    public function __construct(private readonly Repository $repository, private int $limit = 10) {{}}
+
+    public function find(int $id, string $field = 'id', bool $strict = true): ?Entity
+    {{
+        $key = sprintf('%s:%d', $field, $id);
+        if (isset($this->cache[$key])) {{
+            return $this->cache[$key];
+        }}
+        $entity = $this->repository->findOneBy([$field => $id], ['id' => 'ASC'], $this->limit);
+        $this->logger?->info('loaded', ['id' => $id, 'class' => self::class]);
+        return $this->cache[$key] = $entity ?? throw new NotFound(message: "no {{$field}}", code: 404);
+    }}
+
+    public function each(callable $callback, int ...$ids): void
+    {{
+        foreach (array_map(fn(int $id) => $this->find($id), $ids) as $entity) {{
+            $callback($entity, count($ids), max(1, min($this->limit, 100)));
+        }}
+    }}
+}}
+
+"#
+        ));
+    }
+    src
+}
+
+fn bench_generated(c: &mut Criterion) {
+    let src = generated_source();
+    let mut group = c.benchmark_group("generated");
+    group.throughput(Throughput::Bytes(src.len() as u64));
+    group.bench_function("classes", |b| {
+        b.iter(|| {
+            let arena = bumpalo::Bump::with_capacity(src.len() * 5);
+            std::hint::black_box(php_rs_parser::parse(&arena, &src));
+        });
+    });
+    group.finish();
+}
+
 fn bench_corpora(c: &mut Criterion) {
     let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/corpus");
 
@@ -65,5 +118,5 @@ fn bench_corpora(c: &mut Criterion) {
     bench_corpus(c, "wordpress", &base.join("wordpress"));
 }
 
-criterion_group!(benches, bench_corpora);
+criterion_group!(benches, bench_generated, bench_corpora);
 criterion_main!(benches);
@@ -0,0 +1,192 @@
+//! Parsing sources that are not valid UTF-8.
+//!
+//! PHP reads source as bytes: a legacy file may hold Latin-1 or other 8-bit
+//! text in string literals, comments, inline HTML, and even identifiers. The
+//! parser works on `&str`, so [`parse_bytes`](crate::parse_bytes) first
+//! [`decode`]s the source without loss: valid UTF-8 is kept as it is, and
+//! every byte that is not part of a valid sequence becomes one character of a
+//! reserved private-use range, U+10FF80 to U+10FFFF. Those characters encode
+//! as four bytes of `0x80` or more, which PHP's lexical rules treat like the
+//! original byte, so the source tokenizes the same way.
+//!
+//! ```
+//! use php_ast::{ExprKind, StmtKind};
+//! use php_rs_parser::bytes::{encode, ByteOffsets};
+//!
+//! // "café" in Latin-1, where é is the single byte 0xE9.
+//! let source = b"<?php echo 'caf\xE9';";
+//! let arena = bumpalo::Bump::new();
+//! let result = php_rs_parser::parse_bytes(&arena, source);
+//! assert!(result.errors.is_empty());
+//!
+//! let StmtKind::Echo(echo) = &result.program.stmts[0].kind else { panic!() };
+//! let ExprKind::String { value, .. } = echo.exprs[0].kind else { panic!() };
+//! assert_eq!(&*encode(value), b"caf\xE9");
+//!
+//! let offsets = ByteOffsets::new(result.source);
+//! assert_eq!(offsets.to_original_span(result.program.span).end, source.len() as u32);
+//! ```
+//!
+//! String values use the same characters for bytes that escapes such as
+//! `\xE9` or `\351` give but that are not valid UTF-8, so [`encode`] turns
+//! a decoded string literal into the bytes PHP builds for it, whichever
+//! entry point parsed it.
+//!
+//! Spans, like the rest of the [`ParseResult`](crate::ParseResult), refer to
+//! the decoded text. [`ByteOffsets`] converts them to offsets into the
+//! original bytes, and [`encode`] turns decoded text — a name, a string
+//! value, an edited file — back into the bytes it came from. A source that
+//! already contains the reserved characters as UTF-8 is the one input that
+//! does not round-trip; they are noncharacters and private-use code points
+//! that PHP code has no reason to hold.
+
+use std::borrow::Cow;
+
+use php_ast::source_map::{LineCol, SourceMap};
+use php_ast::Span;
+
+/// The reserved character for byte `b` is `ESCAPE_BASE + b`; only bytes of
+/// `0x80` or more are ever escaped.
+const ESCAPE_BASE: u32 = 0x10FF00;
+
+/// Extra bytes an escaped byte takes in the decoded text.
+const ESCAPE_GROWTH: u32 = 3;
+
+/// Decode `bytes` as UTF-8, mapping each byte that is not part of a valid
+/// sequence to a reserved character. Borrows when `bytes` is valid UTF-8.
+pub fn decode(bytes: &[u8]) -> Cow<'_, str> {
+    let mut chunks = bytes.utf8_chunks();
+    let Some(first) = chunks.next() else {
+        return Cow::Borrowed("");
+    };
+    if first.invalid().is_empty() {
+        return Cow::Borrowed(first.valid());
+    }
+    let mut out = String::with_capacity(bytes.len() + 8);
+    for chunk in std::iter::once(first).chain(chunks) {
+        out.push_str(chunk.valid());
+        for &b in chunk.invalid() {
+            out.push(escape(b));
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// The bytes `text` was [`decode`]d from. Borrows when `text` holds no
+/// reserved characters.
+pub fn encode(text: &str) -> Cow<'_, [u8]> {
+    if !text.chars().any(|c| unescape(c).is_some()) {
+        return Cow::Borrowed(text.as_bytes());
+    }
+    let mut out = Vec::with_capacity(text.len());
+    let mut buf = [0; 4];
+    for c in text.chars() {
+        match unescape(c) {
+            Some(b) => out.push(b),
+            None => out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes()),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Re-[`decode`] `text`, so reserved characters that together with their
+/// neighbours form valid UTF-8 become the characters they encode. Borrows
+/// when `text` holds no reserved characters.
+pub(crate) fn normalize(text: &str) -> Cow<'_, str> {
+    match encode(text) {
+        Cow::Borrowed(_) => Cow::Borrowed(text),
+        Cow::Owned(bytes) => Cow::Owned(decode(&bytes).into_owned()),
+    }
+}
+
+/// The character that stands for byte `b` in decoded text: `b` itself
+/// below `0x80`, and its reserved character otherwise.
+pub(crate) fn byte_char(b: u8) -> char {
+    if b < 0x80 {
+        b as char
+    } else {
+        escape(b)
+    }
+}
+
+fn escape(b: u8) -> char {
+    char::from_u32(ESCAPE_BASE + b as u32).expect("reserved range is valid")
+}
+
+fn unescape(c: char) -> Option<u8> {
+    let b = (c as u32).checked_sub(ESCAPE_BASE)?;
+    u8::try_from(b).ok().filter(|&b| b >= 0x80)
+}
+
+/// Converts offsets into [`decode`]d text to offsets into the original bytes
+/// and back.
+#[derive(Debug, Clone, Default)]
+pub struct ByteOffsets {
+    /// Decoded offset of every reserved character, in order.
+    escapes: Vec<u32>,
+}
+
+impl ByteOffsets {
+    /// Index the reserved characters of `text`.
+    pub fn new(text: &str) -> Self {
+        let escapes = text
+            .char_indices()
+            .filter(|&(_, c)| unescape(c).is_some())
+            .map(|(i, _)| i as u32)
+            .collect();
+        Self { escapes }
+    }
+
+    /// `true` when `text` was valid UTF-8, so offsets need no conversion.
+    pub fn is_identity(&self) -> bool {
+        self.escapes.is_empty()
+    }
+
+    /// The original offset of decoded offset `offset`. An offset inside a
+    /// reserved character maps to the end of the byte it stands for.
+    pub fn to_original(&self, offset: u32) -> u32 {
+        let before = self.escapes.partition_point(|&e| e < offset);
+        let mut original = offset - before as u32 * ESCAPE_GROWTH;
+        if let Some(&e) = before.checked_sub(1).map(|i| &self.escapes[i]) {
+            // Clamp offsets inside the previous reserved character.
+            let end = e - (before as u32 - 1) * ESCAPE_GROWTH + 1;
+            original = original.max(end);
+        }
+        original
+    }
+
+    /// [`to_original`](Self::to_original) for both ends of `span`.
+    pub fn to_original_span(&self, span: Span) -> Span {
+        Span::new(self.to_original(span.start), self.to_original(span.end))
+    }
+
+    /// The line and column in the original bytes of decoded offset
+    /// `offset`, where `source_map` indexes the decoded text. Lines are the
+    /// same in both; columns count original bytes.
+    pub fn to_original_line_col(&self, source_map: &SourceMap<'_>, offset: u32) -> LineCol {
+        let LineCol { line, col } = source_map.line_col(offset);
+        let col = self.to_original(offset) - self.to_original(offset - col);
+        LineCol { line, col }
+    }
+
+    /// The decoded offset of original offset `offset`.
+    pub fn to_decoded(&self, offset: u32) -> u32 {
+        // Escapes whose byte starts before `offset`; their original offsets
+        // are increasing, so binary search.
+        let (mut lo, mut hi) = (0, self.escapes.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.escapes[mid] - mid as u32 * ESCAPE_GROWTH < offset {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        offset + lo as u32 * ESCAPE_GROWTH
+    }
+
+    /// [`to_decoded`](Self::to_decoded) for both ends of `span`.
+    pub fn to_decoded_span(&self, span: Span) -> Span {
+        Span::new(self.to_decoded(span.start), self.to_decoded(span.end))
+    }
+}
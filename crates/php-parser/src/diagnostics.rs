@@ -1,23 +1,43 @@
 use php_ast::Span;
 use php_lexer::TokenKind;
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
+use crate::source_map::SourceMap;
+use crate::{Feature, PhpVersion};
+
 /// Diagnostic severity. Mirrors `php -l`'s split between fatal errors and
 /// warnings (e.g. `final private method` is a PHP warning, not a fatal).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Severity {
     #[default]
     Error,
     Warning,
 }
 
+impl Severity {
+    /// `"error"` or `"warning"`, as rendered and serialized.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
 /// A parse error or diagnostic emitted during parsing.
 ///
 /// The parser recovers from all errors and always produces a complete AST,
 /// so errors are informational rather than fatal. Each variant carries a
 /// [`Span`] identifying the source location.
-#[derive(Debug, Clone, Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum ParseError {
     /// A specific token was expected but a different one was found.
     #[error("expected {expected}, found {found}")]
@@ -28,8 +48,16 @@ pub enum ParseError {
     },
 
     /// An expression was expected but not found (e.g. empty parentheses).
-    #[error("expected expression")]
-    ExpectedExpression { span: Span },
+    ///
+    /// `span` covers the offending token, so its text is the source slice at
+    /// `span`. `context` names the innermost construct being parsed, e.g.
+    /// `argument 2 of call to foo`.
+    #[error("expected expression, found {found}{}", while_parsing(.context))]
+    ExpectedExpression {
+        found: TokenKind,
+        context: Option<Cow<'static, str>>,
+        span: Span,
+    },
 
     /// A statement was expected but not found.
     #[error("expected statement")]
@@ -39,6 +67,21 @@ pub enum ParseError {
     #[error("expected opening PHP tag")]
     ExpectedOpenTag { span: Span },
 
+    /// A file without an open tag starts with a `namespace`, `declare`, or
+    /// `use` statement, so PHP outputs its code as text. A warning; `span`
+    /// covers the keyword, and the file still parses as inline HTML.
+    #[error("file starts with PHP code but has no '<?php' open tag")]
+    MissingOpenTag { span: Span },
+
+    /// An open tag written other than `<?php`: mistyped, as `<? php` or
+    /// `?php` at the start of a file that has no open tag, or in unusual
+    /// case, as `<?PHP`. A warning; `span` covers the tag as written.
+    #[error("open tag '{found}' should be written '<?php'")]
+    MisspelledOpenTag {
+        found: Cow<'static, str>,
+        span: Span,
+    },
+
     /// A string literal was opened but never closed.
     #[error("unterminated string literal")]
     UnterminatedString { span: Span },
@@ -51,6 +94,27 @@ pub enum ParseError {
         span: Span,
     },
 
+    /// A statement ended at a line break without its `;`, and the next line
+    /// clearly starts a new statement (`$x = 1⏎$y = 2;`). `span` is the empty
+    /// span where the `;` belongs; both statements are kept in the AST.
+    /// Reported only with [`ParserOptions::infer_semicolons`](crate::ParserOptions::infer_semicolons).
+    #[error("missing ';' after {after}")]
+    MissingSemicolon {
+        after: Cow<'static, str>,
+        span: Span,
+    },
+
+    /// A statement starts with an identifier one or two edits away from a
+    /// keyword or from a function or class declared in the file, and does
+    /// not parse, as in `funtion f() {}`. Reported next to the error that
+    /// follows the identifier; `span` covers the identifier.
+    #[error("unexpected '{found}', did you mean '{suggestion}'?")]
+    Misspelled {
+        found: Cow<'static, str>,
+        suggestion: Cow<'static, str>,
+        span: Span,
+    },
+
     /// A delimiter (parenthesis, bracket, brace) was opened but never closed.
     #[error("unclosed {delimiter} opened at {opened_at:?}")]
     UnclosedDelimiter {
@@ -59,6 +123,19 @@ pub enum ParseError {
         span: Span,
     },
 
+    /// An alternative-syntax block, such as `if (...):` or `foreach (...):`,
+    /// ends without its `end*` keyword. `span` covers the token where the
+    /// block was found to end: a `}` or `end*` keyword that belongs to an
+    /// enclosing construct, the last `?>`, or the end of the file.
+    /// `opened_at` covers the keyword that opened the block.
+    #[error("expected {expected} to close the block, found {found}")]
+    UnclosedBlock {
+        expected: Cow<'static, str>,
+        found: TokenKind,
+        opened_at: Span,
+        span: Span,
+    },
+
     /// A construct that is syntactically valid but semantically forbidden
     /// (e.g. `(unset)` cast, deprecated syntax). Equivalent to a PHP fatal.
     #[error("{message}")]
@@ -77,38 +154,661 @@ pub enum ParseError {
 
     /// Syntax that requires a newer PHP version than the targeted one.
     /// Emitted by [`crate::parse_versioned`] when the source uses features
-    /// unavailable in the specified [`crate::PhpVersion`].
+    /// unavailable in the specified [`crate::PhpVersion`]. `required` is the
+    /// first version with `feature`; [`code`](Self::code) names the feature.
+    ///
+    /// ```
+    /// use php_rs_parser::diagnostics::ParseError;
+    /// use php_rs_parser::{Feature, PhpVersion};
+    ///
+    /// let arena = bumpalo::Bump::new();
+    /// let src = "<?php class A { public readonly int $id; }";
+    /// let result = php_rs_parser::parse_versioned(&arena, src, PhpVersion::Php80);
+    /// let error = &result.errors[0];
+    /// assert!(matches!(
+    ///     error,
+    ///     ParseError::VersionTooLow { feature: Feature::ReadonlyProperties, required: PhpVersion::Php81, .. },
+    /// ));
+    /// assert_eq!(error.code(), Some("readonly-properties"));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "'readonly properties' requires PHP 8.1 or higher (targeting PHP 8.0)",
+    /// );
+    /// ```
     #[error("'{feature}' requires PHP {required} or higher (targeting PHP {used})")]
     VersionTooLow {
+        feature: Feature,
+        required: PhpVersion,
+        used: PhpVersion,
+        span: Span,
+    },
+
+    /// Syntax PHP removed in `removed`, such as `$a{'b'}` offsets or the
+    /// `(real)` cast. Emitted when the targeted version `used` is at least
+    /// `deprecated`, the first supported version that deprecates it: a
+    /// warning before `removed`, an error from then on.
+    ///
+    /// ```
+    /// use php_rs_parser::diagnostics::Severity;
+    /// use php_rs_parser::PhpVersion;
+    ///
+    /// let arena = bumpalo::Bump::new();
+    /// let src = "<?php echo $s{0};";
+    /// let result = php_rs_parser::parse_versioned(&arena, src, PhpVersion::Php74);
+    /// assert_eq!(result.errors[0].severity(), Severity::Warning);
+    /// let result = php_rs_parser::parse_versioned(&arena, src, PhpVersion::Php80);
+    /// assert_eq!(
+    ///     result.errors[0].to_string(),
+    ///     "array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.0)",
+    /// );
+    /// assert_eq!(result.errors[0].severity(), Severity::Error);
+    /// ```
+    #[error("{}", removed_message(feature, *.removed, *.used))]
+    RemovedSyntax {
         feature: Cow<'static, str>,
-        required: Cow<'static, str>,
-        used: Cow<'static, str>,
+        deprecated: PhpVersion,
+        removed: PhpVersion,
+        used: PhpVersion,
         span: Span,
     },
+
+    /// A [`ParserOptions`](crate::ParserOptions) limit was exceeded. For the
+    /// file size and token limits, `span` is where the limit was reached and
+    /// the program is empty; otherwise it covers the offending token.
+    #[error("{limit} of {actual} {} exceeds the configured maximum of {max} {}", .limit.unit(), .limit.unit())]
+    LimitExceeded {
+        limit: Limit,
+        max: usize,
+        actual: usize,
+        span: Span,
+    },
+
+    /// Parsing stopped because [`ParserOptions::timeout`](crate::ParserOptions::timeout)
+    /// elapsed. `span` is the empty span where it stopped; the AST covers the
+    /// source before it, and constructs still open there are closed as if the
+    /// file ended.
+    #[error("parsing timed out; the rest of the file was not parsed")]
+    TimedOut { span: Span },
 }
 
 impl ParseError {
     pub fn span(&self) -> Span {
         match self {
             ParseError::Expected { span, .. }
-            | ParseError::ExpectedExpression { span }
+            | ParseError::ExpectedExpression { span, .. }
             | ParseError::ExpectedStatement { span }
             | ParseError::ExpectedOpenTag { span }
+            | ParseError::MissingOpenTag { span }
+            | ParseError::MisspelledOpenTag { span, .. }
             | ParseError::UnterminatedString { span }
             | ParseError::ExpectedAfter { span, .. }
+            | ParseError::MissingSemicolon { span, .. }
+            | ParseError::Misspelled { span, .. }
             | ParseError::UnclosedDelimiter { span, .. }
+            | ParseError::UnclosedBlock { span, .. }
             | ParseError::Forbidden { span, .. }
             | ParseError::ForbiddenWarning { span, .. }
-            | ParseError::VersionTooLow { span, .. } => *span,
+            | ParseError::VersionTooLow { span, .. }
+            | ParseError::RemovedSyntax { span, .. }
+            | ParseError::LimitExceeded { span, .. }
+            | ParseError::TimedOut { span } => *span,
         }
     }
 
-    /// Returns the diagnostic severity. Currently only [`ParseError::ForbiddenWarning`]
-    /// is at warning level; every other variant is an error.
+    /// Returns the diagnostic severity: [`ParseError::ForbiddenWarning`], the
+    /// open tag warnings, and [`ParseError::RemovedSyntax`] for syntax that is
+    /// only deprecated in the targeted version are warnings; everything else
+    /// is an error.
     pub fn severity(&self) -> Severity {
         match self {
-            ParseError::ForbiddenWarning { .. } => Severity::Warning,
+            ParseError::ForbiddenWarning { .. }
+            | ParseError::MissingOpenTag { .. }
+            | ParseError::MisspelledOpenTag { .. } => Severity::Warning,
+            ParseError::RemovedSyntax { removed, used, .. } if used < removed => Severity::Warning,
             _ => Severity::Error,
         }
     }
 }
+
+impl ParseError {
+    /// A stable identifier for the kind of diagnostic, where there is one:
+    /// the [`Feature::code`] of a [`VersionTooLow`](Self::VersionTooLow).
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            ParseError::VersionTooLow { feature, .. } => Some(feature.code()),
+            _ => None,
+        }
+    }
+}
+
+fn removed_message(feature: &str, removed: PhpVersion, used: PhpVersion) -> String {
+    if used >= removed {
+        format!("{feature} was removed in PHP {removed} (targeting PHP {used})")
+    } else {
+        format!("{feature} is deprecated and removed in PHP {removed} (targeting PHP {used})")
+    }
+}
+
+/// The [`ParserOptions`](crate::ParserOptions) limit named by
+/// [`ParseError::LimitExceeded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Limit {
+    /// [`ParserOptions::max_file_size`](crate::ParserOptions::max_file_size).
+    FileSize,
+    /// [`ParserOptions::max_tokens`](crate::ParserOptions::max_tokens).
+    Tokens,
+    /// [`ParserOptions::max_string_length`](crate::ParserOptions::max_string_length).
+    StringLength,
+    /// [`ParserOptions::max_identifier_length`](crate::ParserOptions::max_identifier_length).
+    IdentifierLength,
+}
+
+impl Limit {
+    /// The unit the limit is measured in.
+    pub fn unit(self) -> &'static str {
+        match self {
+            Limit::Tokens => "tokens",
+            Limit::FileSize | Limit::StringLength | Limit::IdentifierLength => "bytes",
+        }
+    }
+
+    /// The name of the [`ParserOptions`](crate::ParserOptions) field that
+    /// sets the limit.
+    pub fn option(self) -> &'static str {
+        match self {
+            Limit::FileSize => "max_file_size",
+            Limit::Tokens => "max_tokens",
+            Limit::StringLength => "max_string_length",
+            Limit::IdentifierLength => "max_identifier_length",
+        }
+    }
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Limit::FileSize => "file size",
+            Limit::Tokens => "token count",
+            Limit::StringLength => "string literal length",
+            Limit::IdentifierLength => "identifier length",
+        })
+    }
+}
+
+/// Sort diagnostics into source order: by span start, then span end. The
+/// sort is stable, so diagnostics at the same span keep the order they were
+/// emitted in.
+///
+/// [`crate::parse`] and friends already return errors in this order; use this
+/// after merging diagnostics from several sources.
+pub fn sort_diagnostics(errors: &mut [ParseError]) {
+    errors.sort_by_key(ParseError::span);
+}
+
+/// Group diagnostics by the 1-based line their span starts on, in line order.
+/// Within a line, the input order is kept.
+///
+/// ```
+/// use php_rs_parser::diagnostics::group_by_line;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php\n$a = ;\n$b = ;\n");
+/// let lines: Vec<_> = group_by_line(&result.errors, &result.source_map)
+///     .into_iter()
+///     .map(|(line, errors)| (line, errors.len()))
+///     .collect();
+/// assert_eq!(lines, [(2, 1), (3, 1)]);
+/// ```
+pub fn group_by_line<'e>(
+    errors: &'e [ParseError],
+    source_map: &SourceMap<'_>,
+) -> BTreeMap<u32, Vec<&'e ParseError>> {
+    let mut lines: BTreeMap<u32, Vec<&ParseError>> = BTreeMap::new();
+    for error in errors {
+        let (line, _) = source_map.line_col(error.span().start).to_one_based();
+        lines.entry(line).or_default().push(error);
+    }
+    lines
+}
+
+impl ParseError {
+    /// Render as one `line:col: severity: message` line with a 1-based
+    /// position. The format is stable, for diffing tool output in CI.
+    ///
+    /// ```
+    /// let arena = bumpalo::Bump::new();
+    /// let result = php_rs_parser::parse(&arena, "<?php\n$a = ;");
+    /// assert_eq!(
+    ///     result.errors[0].render(&result.source_map),
+    ///     "2:6: error: expected expression, found ';' while parsing assigned value",
+    /// );
+    /// ```
+    pub fn render(&self, source_map: &SourceMap<'_>) -> String {
+        let (line, col) = source_map.line_col(self.span().start).to_one_based();
+        format!("{line}:{col}: {}: {self}", self.severity().as_str())
+    }
+
+    /// The structured form of this error, for tools that present
+    /// diagnostics. See [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        Diagnostic::from(self)
+    }
+}
+
+/// A diagnostic as presented to users: what the terminal renderer, the
+/// `php-parse` CLI, and the language server all consume. Built from a
+/// [`ParseError`] with [`ParseError::to_diagnostic`], or by other tools for
+/// their own findings. With the `serde` feature it serializes to JSON.
+///
+/// ```
+/// let options = php_rs_parser::ParserOptions {
+///     infer_semicolons: true,
+///     ..Default::default()
+/// };
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_with_options(&arena, "<?php\n$a = 1\n$b = 2;\n", &options);
+/// let diagnostic = result.errors[0].to_diagnostic();
+/// assert_eq!(diagnostic.message, "missing ';' after expression");
+/// assert_eq!(diagnostic.suggestions[0].replacement, ";");
+/// assert_eq!(diagnostic.suggestions[0].span, diagnostic.span);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// A stable identifier for the kind of diagnostic, where there is one.
+    pub code: Option<&'static str>,
+    pub severity: Severity,
+    /// The headline, without position or severity.
+    pub message: String,
+    /// The span the diagnostic is about.
+    pub span: Span,
+    /// Related spans, such as where an unclosed delimiter was opened.
+    pub labels: Vec<Label>,
+    /// Extra context shown after the source, one sentence each.
+    pub notes: Vec<String>,
+    /// Edits that would resolve the diagnostic.
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// A related span of a [`Diagnostic`] and what it shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+
+/// A fix for a [`Diagnostic`]: replace the source at `span`, which is empty
+/// for an insertion, with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Suggestion {
+    /// What the edit does, e.g. `insert ';'`.
+    pub message: String,
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl Diagnostic {
+    /// A diagnostic with no code, labels, notes, or suggestions.
+    pub fn new(severity: Severity, message: impl Into<String>, span: Span) -> Self {
+        Self {
+            code: None,
+            severity,
+            message: message.into(),
+            span,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(error: &ParseError) -> Self {
+        let mut diagnostic = Diagnostic::new(error.severity(), error.to_string(), error.span());
+        diagnostic.code = error.code();
+        match error {
+            ParseError::UnclosedDelimiter {
+                delimiter,
+                opened_at,
+                span,
+            } => {
+                diagnostic.message = format!("unclosed delimiter: expected {delimiter}");
+                diagnostic.labels.push(Label {
+                    span: *opened_at,
+                    message: "opened here".to_string(),
+                });
+                let close = delimiter.trim_matches('\'');
+                diagnostic.suggestions.push(Suggestion {
+                    message: format!("insert {delimiter}"),
+                    span: Span::new(span.start, span.start),
+                    replacement: close.to_string(),
+                });
+            }
+            ParseError::UnclosedBlock {
+                expected,
+                opened_at,
+                span,
+                ..
+            } => {
+                diagnostic.labels.push(Label {
+                    span: *opened_at,
+                    message: "block opened here".to_string(),
+                });
+                let close = expected.trim_matches('\'');
+                diagnostic.suggestions.push(Suggestion {
+                    message: format!("insert '{close};'"),
+                    span: Span::new(span.start, span.start),
+                    replacement: format!("{close}; "),
+                });
+            }
+            ParseError::Misspelled {
+                suggestion, span, ..
+            } => {
+                diagnostic.suggestions.push(Suggestion {
+                    message: format!("replace with '{suggestion}'"),
+                    span: *span,
+                    replacement: suggestion.to_string(),
+                });
+            }
+            ParseError::MissingOpenTag { span } => {
+                diagnostic
+                    .notes
+                    .push("text before the first '<?php' is output as is".to_string());
+                diagnostic.suggestions.push(Suggestion {
+                    message: "insert '<?php'".to_string(),
+                    span: Span::new(span.start, span.start),
+                    replacement: "<?php\n\n".to_string(),
+                });
+            }
+            ParseError::MisspelledOpenTag { span, .. } => {
+                diagnostic.suggestions.push(Suggestion {
+                    message: "replace with '<?php'".to_string(),
+                    span: *span,
+                    replacement: "<?php".to_string(),
+                });
+            }
+            ParseError::MissingSemicolon { span, .. } => {
+                diagnostic.suggestions.push(Suggestion {
+                    message: "insert ';'".to_string(),
+                    span: *span,
+                    replacement: ";".to_string(),
+                });
+            }
+            ParseError::RemovedSyntax {
+                deprecated,
+                removed,
+                used,
+                ..
+            } if used < removed => {
+                diagnostic
+                    .notes
+                    .push(format!("deprecated since PHP {deprecated}"));
+            }
+            ParseError::VersionTooLow {
+                feature, required, ..
+            } => {
+                diagnostic.notes.push(format!(
+                    "{feature} arrived in PHP {required}: {}",
+                    feature.docs_url()
+                ));
+            }
+            ParseError::LimitExceeded { limit, .. } => {
+                diagnostic.notes.push(format!(
+                    "raise `ParserOptions::{}` to parse larger inputs",
+                    limit.option()
+                ));
+            }
+            _ => {}
+        }
+        diagnostic
+    }
+}
+
+/// Options for [`render_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions<'a> {
+    /// The file name shown before each position, as in `--> src/a.php:2:6`.
+    pub path: Option<&'a str>,
+    /// Color severities, underlines, and the gutter with ANSI escapes.
+    pub color: bool,
+}
+
+/// Render `errors` for a terminal: each diagnostic's severity and message,
+/// then the source lines it points at with the offending text underlined,
+/// related locations such as where an unclosed delimiter was opened, and
+/// notes. Diagnostics are separated by a blank line.
+///
+/// ```
+/// use php_rs_parser::diagnostics::render;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php\n$total = $price *;\n");
+/// assert_eq!(
+///     render(&result.source_map, &result.errors),
+///     "\
+/// error: expected expression, found ';' while parsing assigned value
+///  --> 2:18
+///   |
+/// 2 | $total = $price *;
+///   |                  ^
+/// ",
+/// );
+/// ```
+pub fn render(source_map: &SourceMap<'_>, errors: &[ParseError]) -> String {
+    render_with(source_map, errors, &RenderOptions::default())
+}
+
+/// [`render`] with a file name or colors.
+pub fn render_with(
+    source_map: &SourceMap<'_>,
+    errors: &[ParseError],
+    options: &RenderOptions<'_>,
+) -> String {
+    let mut out = String::new();
+    for (i, error) in errors.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        render_one(&mut out, source_map, &error.to_diagnostic(), options);
+    }
+    out
+}
+
+/// A span to underline: `^` for the diagnostic's own span, `-` for a
+/// related one.
+struct Mark<'d> {
+    span: Span,
+    primary: bool,
+    text: Option<&'d str>,
+}
+
+/// ANSI escapes, or nothing when color is off.
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn paint(&self, out: &mut String, code: &str, text: &str) {
+        if self.color {
+            out.push_str("\x1b[");
+            out.push_str(code);
+            out.push('m');
+            out.push_str(text);
+            out.push_str("\x1b[0m");
+        } else {
+            out.push_str(text);
+        }
+    }
+}
+
+const BOLD: &str = "1";
+const RED: &str = "1;31";
+const YELLOW: &str = "1;33";
+const BLUE: &str = "1;34";
+
+fn render_one(
+    out: &mut String,
+    source_map: &SourceMap<'_>,
+    diagnostic: &Diagnostic,
+    options: &RenderOptions<'_>,
+) {
+    let style = Style {
+        color: options.color,
+    };
+    let severity = diagnostic.severity.as_str();
+    let accent = match diagnostic.severity {
+        Severity::Error => RED,
+        Severity::Warning => YELLOW,
+    };
+    let mut labels: Vec<Mark> = diagnostic
+        .labels
+        .iter()
+        .map(|label| Mark {
+            span: label.span,
+            primary: false,
+            text: Some(&label.message),
+        })
+        .collect();
+    labels.push(Mark {
+        span: diagnostic.span,
+        primary: true,
+        text: None,
+    });
+
+    match diagnostic.code {
+        Some(code) => style.paint(out, accent, &format!("{severity}[{code}]")),
+        None => style.paint(out, accent, severity),
+    }
+    style.paint(out, BOLD, &format!(": {}", diagnostic.message));
+    out.push('\n');
+
+    // Each line a label starts or ends on, with the columns it underlines
+    // there; lines in between are elided.
+    let mut marks: BTreeMap<u32, Vec<(u32, u32, &Mark, bool)>> = BTreeMap::new();
+    for label in &labels {
+        let start = source_map.line_col(label.span.start);
+        let mut end = source_map.line_col(label.span.end);
+        if end.col == 0 && end.line > start.line {
+            // A span ending with a newline ends on the line before.
+            end = source_map.line_col(label.span.end - 1);
+        }
+        let line_len = |line: u32| source_map.line_span(line).map_or(0, |s| s.end - s.start);
+        if start.line == end.line {
+            marks
+                .entry(start.line)
+                .or_default()
+                .push((start.col, end.col, label, true));
+        } else {
+            marks.entry(start.line).or_default().push((
+                start.col,
+                line_len(start.line).max(start.col),
+                label,
+                false,
+            ));
+            marks
+                .entry(end.line)
+                .or_default()
+                .push((0, end.col, label, true));
+        }
+    }
+    let last_line = marks.keys().next_back().copied().unwrap_or(0);
+    let width = (last_line + 1).to_string().len();
+    let gutter = " ".repeat(width);
+
+    let (line, col) = source_map.line_col(diagnostic.span.start).to_one_based();
+    out.push_str(&gutter);
+    style.paint(out, BLUE, "-->");
+    match options.path {
+        Some(path) => out.push_str(&format!(" {path}:{line}:{col}\n")),
+        None => out.push_str(&format!(" {line}:{col}\n")),
+    }
+    let bar = |out: &mut String| {
+        out.push_str(&gutter);
+        out.push(' ');
+        style.paint(out, BLUE, "|");
+    };
+    bar(out);
+    out.push('\n');
+
+    let mut previous: Option<u32> = None;
+    for (&line, line_marks) in &marks {
+        if previous.is_some_and(|p| line > p + 1) {
+            style.paint(out, BLUE, "...");
+            out.push('\n');
+        }
+        previous = Some(line);
+        let text = source_map
+            .line_span(line)
+            .and_then(|span| source_map.snippet(span))
+            .unwrap_or("");
+        style.paint(out, BLUE, &format!("{:>width$} |", line + 1));
+        if !text.is_empty() {
+            out.push(' ');
+            out.push_str(&expand_tabs(text));
+        }
+        out.push('\n');
+
+        for &(start, end, label, ends_here) in line_marks {
+            let from = display_width(text, start);
+            let to = display_width(text, end).max(from + 1);
+            let (mark, color) = if label.primary {
+                ("^", accent)
+            } else {
+                ("-", BLUE)
+            };
+            bar(out);
+            out.push(' ');
+            out.push_str(&" ".repeat(from));
+            style.paint(out, color, &mark.repeat(to - from));
+            // Only the line a label ends on carries its text.
+            if let Some(text) = label.text.filter(|_| ends_here) {
+                out.push(' ');
+                style.paint(out, color, text);
+            }
+            out.push('\n');
+        }
+    }
+
+    for note in &diagnostic.notes {
+        out.push_str(&gutter);
+        out.push(' ');
+        style.paint(out, BLUE, "=");
+        style.paint(out, BOLD, " note");
+        out.push_str(&format!(": {note}\n"));
+    }
+    for suggestion in &diagnostic.suggestions {
+        out.push_str(&gutter);
+        out.push(' ');
+        style.paint(out, BLUE, "=");
+        style.paint(out, BOLD, " help");
+        out.push_str(&format!(": {}\n", suggestion.message));
+    }
+}
+
+/// Columns are counted in characters, with a tab as four.
+const TAB: &str = "    ";
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', TAB)
+}
+
+/// The display column of byte column `col` in `line`.
+fn display_width(line: &str, col: u32) -> usize {
+    let mut col = (col as usize).min(line.len());
+    while !line.is_char_boundary(col) {
+        col -= 1;
+    }
+    let prefix = &line[..col];
+    prefix.chars().count() + prefix.matches('\t').count() * (TAB.len() - 1)
+}
+
+fn while_parsing(context: &Option<Cow<'static, str>>) -> String {
+    match context {
+        Some(context) => format!(" while parsing {context}"),
+        None => String::new(),
+    }
+}
@@ -5,10 +5,10 @@ use php_lexer::TokenKind;
 
 use crate::diagnostics::ParseError;
 use crate::instrument;
-use crate::parser::Parser;
+use crate::parser::{ParseContext, Parser};
 use crate::precedence::{self, ASSIGNMENT_BP};
 use crate::stmt;
-use crate::version::PhpVersion;
+use crate::version::{Feature, PhpVersion};
 
 use super::{parse_assign_continuation, parse_expr, parse_expr_bp};
 
@@ -29,6 +29,52 @@ const CAST_KEYWORDS: &[(&str, CastKind)] = &[
     ("void", CastKind::Void),
 ];
 
+/// Wrap a parsed name as an expression. A name that failed to parse (the
+/// error was already reported) becomes `ExprKind::Error`.
+fn name_expr<'arena, 'src>(name: Name<'arena, 'src>) -> Expr<'arena, 'src> {
+    let span = name.span();
+    let kind = if matches!(name, Name::Error { .. }) {
+        ExprKind::Error
+    } else {
+        ExprKind::Name(name)
+    };
+    Expr { kind, span }
+}
+
+/// A name in expression position: the callee before `(`, the class before
+/// `::`, and otherwise a constant fetch — the same split PHP makes at compile
+/// time.
+fn name_or_const_expr<'arena, 'src>(
+    parser: &Parser<'arena, 'src>,
+    name: Name<'arena, 'src>,
+) -> Expr<'arena, 'src> {
+    let mut expr = name_expr(name);
+    if !matches!(
+        parser.current_kind(),
+        TokenKind::LeftParen | TokenKind::DoubleColon
+    ) {
+        if let ExprKind::Name(name) = expr.kind {
+            expr.kind = ExprKind::ConstFetch(name);
+        }
+    }
+    expr
+}
+
+/// An unqualified name spelled by the token at `span`.
+fn simple_name_expr<'arena, 'src>(parser: &Parser<'arena, 'src>, span: Span) -> Expr<'arena, 'src> {
+    let value = &parser.source[span.to_range()];
+    Expr {
+        kind: ExprKind::Name(Name::Simple { value, span }),
+        span,
+    }
+}
+
+/// Consume a keyword used as a name: `self`, `parent`, `static`, `enum`, `readonly`.
+fn keyword_name_expr<'arena, 'src>(parser: &mut Parser<'arena, 'src>) -> Expr<'arena, 'src> {
+    let token = parser.advance();
+    simple_name_expr(parser, token.span)
+}
+
 /// Result of parsing an argument list — either regular args or a `(...)` callable marker.
 pub(crate) enum ArgListResult<'arena, 'src> {
     Args(ArenaVec<'arena, Arg<'arena, 'src>>),
@@ -49,25 +95,8 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         )
         && parser.peek_kind() == Some(TokenKind::Backslash)
     {
-        let token = parser.advance();
-        let src = parser.source;
-        let first = &src[token.span.start as usize..token.span.end as usize];
-        let mut parts: Vec<&str> = vec![first];
-        while parser.eat(TokenKind::Backslash).is_some() {
-            if let Some((part, _)) = parser.eat_identifier_or_keyword() {
-                parts.push(part);
-            }
-        }
-        let span = Span::new(token.span.start, parser.previous_end());
-        let ident = if parts.len() == 1 {
-            NameStr::__src(parts[0])
-        } else {
-            NameStr::__arena(parser.arena.alloc_str(&parts.join("\\")))
-        };
-        return Expr {
-            kind: ExprKind::Identifier(ident),
-            span,
-        };
+        let name = parser.parse_name();
+        return name_or_const_expr(parser, name);
     }
 
     // Attributed closure/arrow function: #[Attr] function(...) { } or #[Attr] fn(...) => ...
@@ -124,7 +153,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         }
         let span = token.span.merge(operand.span);
         return Expr {
-            kind: ExprKind::ErrorSuppress(parser.alloc(operand)),
+            kind: ExprKind::ErrorSuppress(ErrorSuppressExpr {
+                expr: parser.alloc(operand),
+                operator_span: token.span,
+            }),
             span,
         };
     }
@@ -323,7 +355,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 parser.arena.alloc_str(&decoded)
             };
             Expr {
-                kind: ExprKind::String(value),
+                kind: ExprKind::String { value, raw: inner },
                 span: token.span,
             }
         }
@@ -341,7 +373,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             // strings, so it is safe to treat the missing closing quote as absent here.
             let Some(without_open) = stripped.strip_prefix('"') else {
                 return Expr {
-                    kind: ExprKind::String(parser.arena.alloc_str("")),
+                    kind: ExprKind::String { value: "", raw: "" },
                     span: token.span,
                 };
             };
@@ -367,9 +399,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 // No interpolation and no escapes — verbatim source slice
                 let offset = inner.as_ptr() as usize - src.as_ptr() as usize;
                 Expr {
-                    kind: ExprKind::String(
-                        parser.arena.alloc_str(&src[offset..offset + inner.len()]),
-                    ),
+                    kind: ExprKind::String {
+                        value: parser.arena.alloc_str(&src[offset..offset + inner.len()]),
+                        raw: inner,
+                    },
                     span: token.span,
                 }
             } else {
@@ -390,8 +423,8 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                         .next()
                         .expect("parts.len() == 1 checked above")
                     {
-                        StringPart::Literal(s) => Expr {
-                            kind: ExprKind::String(s),
+                        StringPart::Literal { value, .. } => Expr {
+                            kind: ExprKind::String { value, raw: inner },
                             span: token.span,
                         },
                         part => {
@@ -444,9 +477,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 // No escapes — verbatim source slice
                 let offset = inner.as_ptr() as usize - src.as_ptr() as usize;
                 let mut parts = parser.alloc_vec_with_capacity(1);
-                parts.push(StringPart::Literal(
-                    parser.arena.alloc_str(&src[offset..offset + inner.len()]),
-                ));
+                parts.push(StringPart::Literal {
+                    value: parser.arena.alloc_str(&src[offset..offset + inner.len()]),
+                    span: Span::new(offset as u32, (offset + inner.len()) as u32),
+                });
                 Expr {
                     kind: ExprKind::ShellExec(parts),
                     span: token.span,
@@ -473,28 +507,27 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         TokenKind::Heredoc => {
             let token = parser.advance();
             let src = parser.source();
-            let text = &src[token.span.start as usize..token.span.end as usize];
-            let (label, body_start_in_text, body_end_in_text, indent) = parse_heredoc_content(text);
-            let body_offset = token.span.start + body_start_in_text as u32;
-            let raw_body = &src[body_offset as usize..token.span.start as usize + body_end_in_text];
-            validate_heredoc_indentation(raw_body, &indent, body_offset, parser.errors_mut());
-            if super::interpolation::has_interpolation(raw_body) {
+            let heredoc = parse_heredoc_content(src, token.span);
+            let (label, raw, indent) = (heredoc.label, heredoc.raw, heredoc.indent);
+            let body_offset = heredoc.body_offset;
+            validate_heredoc_indentation(&heredoc, parser.errors_mut());
+            if super::interpolation::has_interpolation(raw) {
                 if !indent.is_empty() {
-                    // Indented heredoc — raw_body is a verbatim source slice but each line
-                    // is prefixed with `indent`. The indented sub-parser works directly on
-                    // the source, skipping the indent at each line start, so we can use
-                    // parse_complex_interpolation without wrapping.
+                    // Indented heredoc — raw is a verbatim source slice but each line
+                    // is prefixed with the indentation. The indented sub-parser works
+                    // directly on the source, skipping it at each line start, so we
+                    // can use parse_complex_interpolation without wrapping.
                     let parts = super::interpolation::parse_interpolated_parts_indented(
                         parser.arena,
                         src,
-                        raw_body,
+                        raw,
                         body_offset,
-                        &indent,
+                        indent.len(),
                         parser.version,
                         parser.errors_mut(),
                     );
                     Expr {
-                        kind: ExprKind::Heredoc { label, parts },
+                        kind: ExprKind::Heredoc { label, raw, parts },
                         span: token.span,
                     }
                 } else {
@@ -502,33 +535,32 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                     let parts = super::interpolation::parse_interpolated_parts(
                         parser.arena,
                         src,
-                        raw_body,
+                        raw,
                         body_offset,
                         parser.version,
                         parser.errors_mut(),
                     );
                     Expr {
-                        kind: ExprKind::Heredoc { label, parts },
+                        kind: ExprKind::Heredoc { label, raw, parts },
                         span: token.span,
                     }
                 }
             } else {
-                // No interpolation — build the (possibly de-indented) body string,
-                // then process escape sequences
-                let de_indented = if !indent.is_empty() {
-                    raw_body
-                        .lines()
-                        .map(|line| line.strip_prefix(&indent).unwrap_or(line))
-                        .collect::<Vec<_>>()
-                        .join("\n")
-                } else {
-                    raw_body.to_string()
-                };
-                let body_str = super::interpolation::process_heredoc_escapes(&de_indented);
+                // No interpolation — de-indent the body and decode its escape
+                // sequences in one pass
+                let body_str = super::interpolation::decode_heredoc_body(
+                    raw,
+                    body_offset,
+                    indent.len(),
+                    parser.errors_mut(),
+                );
                 let mut parts = parser.alloc_vec_with_capacity(1);
-                parts.push(StringPart::Literal(parser.arena.alloc_str(&body_str)));
+                parts.push(StringPart::Literal {
+                    value: parser.arena.alloc_str(&body_str),
+                    span: Span::new(body_offset, body_offset + raw.len() as u32),
+                });
                 Expr {
-                    kind: ExprKind::Heredoc { label, parts },
+                    kind: ExprKind::Heredoc { label, raw, parts },
                     span: token.span,
                 }
             }
@@ -537,24 +569,15 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         // Nowdoc
         TokenKind::Nowdoc => {
             let token = parser.advance();
-            let src = parser.source();
-            let text = &src[token.span.start as usize..token.span.end as usize];
-            let (label, body_start_in_text, body_end_in_text, indent) = parse_heredoc_content(text);
-            let body_offset = token.span.start + body_start_in_text as u32;
-            let raw_body = &text[body_start_in_text..body_end_in_text];
-            validate_heredoc_indentation(raw_body, &indent, body_offset, parser.errors_mut());
-            let value: &'arena str = if !indent.is_empty() {
-                let s = raw_body
-                    .lines()
-                    .map(|line| line.strip_prefix(&indent).unwrap_or(line))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-                parser.arena.alloc_str(&s)
-            } else {
-                parser.arena.alloc_str(raw_body)
-            };
+            let heredoc = parse_heredoc_content(parser.source(), token.span);
+            validate_heredoc_indentation(&heredoc, parser.errors_mut());
+            let value = strip_heredoc_indentation(heredoc.raw, heredoc.indent.len());
             Expr {
-                kind: ExprKind::Nowdoc { label, value },
+                kind: ExprKind::Nowdoc {
+                    label: heredoc.label,
+                    raw: heredoc.raw,
+                    value: parser.arena.alloc_str(&value),
+                },
                 span: token.span,
             }
         }
@@ -615,84 +638,24 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         // Identifiers (function names, class names, etc.)
         // Also handles qualified names: App\Models\User
         TokenKind::Identifier => {
-            let token = parser.advance();
-            let src = parser.source;
-            let text = &src[token.span.start as usize..token.span.end as usize];
-
-            // Check if this is a qualified name: Foo\Bar\Baz
-            if parser.check(TokenKind::Backslash) {
-                let mut parts: Vec<&str> = vec![text];
-                while parser.eat(TokenKind::Backslash).is_some() {
-                    if let Some((part, _)) = parser.eat_identifier_or_keyword() {
-                        parts.push(part);
-                    }
-                }
-                let span = Span::new(token.span.start, parser.previous_end());
-                Expr {
-                    kind: ExprKind::Identifier(NameStr::__arena(
-                        parser.arena.alloc_str(&parts.join("\\")),
-                    )),
-                    span,
-                }
-            } else {
-                Expr {
-                    kind: ExprKind::Identifier(NameStr::__src(text)),
-                    span: token.span,
-                }
-            }
+            let name = parser.parse_name();
+            name_or_const_expr(parser, name)
         }
 
         // Backslash — fully qualified name: \Foo\Bar
         TokenKind::Backslash => {
-            let start = parser.start_span();
             let name = parser.parse_name();
-            let span = Span::new(start, name.span().end);
-            if matches!(name, Name::Error { .. }) {
-                Expr {
-                    kind: ExprKind::Error,
-                    span,
-                }
-            } else {
-                let ident = match name.to_string_repr() {
-                    Cow::Borrowed(s) => NameStr::__src(s),
-                    Cow::Owned(ref s) => NameStr::__arena(parser.arena.alloc_str(s)),
-                };
-                Expr {
-                    kind: ExprKind::Identifier(ident),
-                    span,
-                }
-            }
+            name_or_const_expr(parser, name)
         }
 
         // `enum` as an identifier — valid in any expression position where a name is
         // expected (e.g. `Enum::class`, `Enum::someMethod()`, `new Enum`, `Enum()`).
         // The statement-level enum declaration is handled earlier in stmt.rs; by the
         // time we reach a primary expression, `enum` refers to a user-named symbol.
-        TokenKind::Enum_ => {
-            let token = parser.advance();
-            let src = parser.source;
-            let text = &src[token.span.start as usize..token.span.end as usize];
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__src(text)),
-                span: token.span,
-            }
-        }
+        TokenKind::Enum_ => keyword_name_expr(parser),
 
         // self, parent, static — used as class names (e.g. self::method())
-        TokenKind::Self_ => {
-            let token = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("self")),
-                span: token.span,
-            }
-        }
-        TokenKind::Parent_ => {
-            let token = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("parent")),
-                span: token.span,
-            }
-        }
+        TokenKind::Self_ | TokenKind::Parent_ => keyword_name_expr(parser),
         TokenKind::Static => {
             let token = parser.advance();
             // Check if this is `static function` (static closure)
@@ -703,10 +666,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             if parser.check(TokenKind::Fn_) {
                 return parse_arrow_function(parser, true, token.span.start, parser.alloc_vec());
             }
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("static")),
-                span: token.span,
-            }
+            simple_name_expr(parser, token.span)
         }
 
         // Print expression
@@ -715,7 +675,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             let expr = parse_expr_bp(parser, ASSIGNMENT_BP);
             let span = token.span.merge(expr.span);
             Expr {
-                kind: ExprKind::Print(parser.alloc(expr)),
+                kind: ExprKind::Print(PrintExpr {
+                    expr: parser.alloc(expr),
+                    keyword_span: token.span,
+                }),
                 span,
             }
         }
@@ -738,7 +701,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         // Match expression — PHP 8.0+
         TokenKind::Match_ => {
             let span = parser.current_span();
-            parser.require_version(PhpVersion::Php80, "match expressions", span);
+            parser.require_feature(Feature::MatchExpressions, span);
             parse_match_expr(parser)
         }
 
@@ -746,8 +709,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         TokenKind::Throw => {
             let token = parser.advance();
             let span = token.span;
-            parser.require_version(PhpVersion::Php80, "throw expressions", span);
-            let expr = parse_expr_bp(parser, ASSIGNMENT_BP);
+            parser.require_feature(Feature::ThrowExpressions, span);
+            // `throw` has the lowest precedence of all: `throw $a or $b`
+            // throws `$a or $b`.
+            let expr = parse_expr_bp(parser, 0);
             let merged = span.merge(expr.span);
             Expr {
                 kind: ExprKind::ThrowExpr(parser.alloc(expr)),
@@ -847,10 +812,11 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             }
         }
 
-        // include / include_once / require / require_once
+        // include / include_once / require / require_once. They rank below
+        // `and`, `or`, and `xor`: `require $a or die()` requires `$a or die()`.
         TokenKind::Include => {
             let token = parser.advance();
-            let inner = parse_expr_bp(parser, ASSIGNMENT_BP);
+            let inner = parse_expr_bp(parser, 0);
             let span = token.span.merge(inner.span);
             Expr {
                 kind: ExprKind::Include(IncludeKind::Include, parser.alloc(inner)),
@@ -859,7 +825,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         }
         TokenKind::IncludeOnce => {
             let token = parser.advance();
-            let inner = parse_expr_bp(parser, ASSIGNMENT_BP);
+            let inner = parse_expr_bp(parser, 0);
             let span = token.span.merge(inner.span);
             Expr {
                 kind: ExprKind::Include(IncludeKind::IncludeOnce, parser.alloc(inner)),
@@ -868,7 +834,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         }
         TokenKind::Require => {
             let token = parser.advance();
-            let inner = parse_expr_bp(parser, ASSIGNMENT_BP);
+            let inner = parse_expr_bp(parser, 0);
             let span = token.span.merge(inner.span);
             Expr {
                 kind: ExprKind::Include(IncludeKind::Require, parser.alloc(inner)),
@@ -877,7 +843,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         }
         TokenKind::RequireOnce => {
             let token = parser.advance();
-            let inner = parse_expr_bp(parser, ASSIGNMENT_BP);
+            let inner = parse_expr_bp(parser, 0);
             let span = token.span.merge(inner.span);
             Expr {
                 kind: ExprKind::Include(IncludeKind::RequireOnce, parser.alloc(inner)),
@@ -888,17 +854,11 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         // exit / die
         TokenKind::Exit | TokenKind::Die => {
             let token = parser.advance();
-            let src = parser.source;
-            let name_text =
-                NameStr::__src(&src[token.span.start as usize..token.span.end as usize]);
             if parser.check(TokenKind::LeftParen) {
-                match parse_arg_list_or_callable(parser) {
+                match parse_arg_list_or_callable(parser, Some(token.span)) {
                     ArgListResult::CallableMarker => {
                         // exit(...) - first class callable
-                        let callee = Expr {
-                            kind: ExprKind::Identifier(name_text),
-                            span: token.span,
-                        };
+                        let callee = simple_name_expr(parser, token.span);
                         let span = Span::new(token.span.start, parser.previous_end());
                         Expr {
                             kind: ExprKind::CallableCreate(CallableCreateExpr {
@@ -928,10 +888,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                             }
                         } else {
                             // exit(status: 42), exit(...$args), exit($a, $b) - function call form
-                            let callee = Expr {
-                                kind: ExprKind::Identifier(name_text),
-                                span: token.span,
-                            };
+                            let callee = simple_name_expr(parser, token.span);
                             Expr {
                                 kind: ExprKind::FunctionCall(FunctionCallExpr {
                                     name: parser.alloc(callee),
@@ -969,22 +926,12 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                         .any(|(kw, _)| kw.eq_ignore_ascii_case(t))
                 });
             if !looks_like_cast && parser.check(TokenKind::LeftParen) {
-                let src = parser.source;
-                let name_text =
-                    NameStr::__src(&src[token.span.start as usize..token.span.end as usize]);
-                match parse_arg_list_or_callable(parser) {
+                match parse_arg_list_or_callable(parser, Some(token.span)) {
                     ArgListResult::CallableMarker => {
                         // clone(...) — first-class callable (PHP 8.5)
-                        parser.require_version(
-                            PhpVersion::Php85,
-                            "clone(...) first-class callable",
-                            token.span,
-                        );
+                        parser.require_feature(Feature::CloneCallable, token.span);
                         let span = Span::new(token.span.start, parser.previous_end());
-                        let callee = Expr {
-                            kind: ExprKind::Identifier(name_text),
-                            span: token.span,
-                        };
+                        let callee = simple_name_expr(parser, token.span);
                         Expr {
                             kind: ExprKind::CallableCreate(CallableCreateExpr {
                                 kind: CallableCreateKind::Function(parser.alloc(callee)),
@@ -1009,16 +956,15 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                                 .expect("is_simple: args.len() == 1 checked above")
                                 .value;
                             Expr {
-                                kind: ExprKind::Clone(parser.alloc(object)),
+                                kind: ExprKind::Clone(CloneExpr {
+                                    object: parser.alloc(object),
+                                    keyword_span: token.span,
+                                }),
                                 span,
                             }
                         } else if is_clone_with {
                             // clone($obj, [...]) — PHP 8.5 clone with property overrides
-                            parser.require_version(
-                                PhpVersion::Php85,
-                                "clone with property overrides",
-                                token.span,
-                            );
+                            parser.require_feature(Feature::CloneWith, token.span);
                             let mut iter = args.into_iter();
                             let object = iter
                                 .next()
@@ -1037,10 +983,7 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                             }
                         } else {
                             // 3+ args, named args, or spread — user-defined function named "clone"
-                            let callee = Expr {
-                                kind: ExprKind::Identifier(name_text),
-                                span: token.span,
-                            };
+                            let callee = simple_name_expr(parser, token.span);
                             Expr {
                                 kind: ExprKind::FunctionCall(FunctionCallExpr {
                                     name: parser.alloc(callee),
@@ -1058,7 +1001,10 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 }
                 let span = token.span.merge(operand.span);
                 Expr {
-                    kind: ExprKind::Clone(parser.alloc(operand)),
+                    kind: ExprKind::Clone(CloneExpr {
+                        object: parser.alloc(operand),
+                        keyword_span: token.span,
+                    }),
                     span,
                 }
             }
@@ -1095,27 +1041,12 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
 
         // namespace\Foo\Bar — relative name in expression context
         TokenKind::Namespace if parser.peek_kind() == Some(TokenKind::Backslash) => {
-            let start = parser.start_span();
             let name = parser.parse_name();
-            let span = Span::new(start, name.span().end);
-            if matches!(name, Name::Error { .. }) {
-                Expr {
-                    kind: ExprKind::Error,
-                    span,
-                }
-            } else {
-                let text = parser
-                    .arena
-                    .alloc_str(&format!("namespace\\{}", name.join_parts()));
-                Expr {
-                    kind: ExprKind::Identifier(NameStr::__arena(text)),
-                    span,
-                }
-            }
+            name_or_const_expr(parser, name)
         }
         TokenKind::Namespace => {
             let span = parser.current_span();
-            parser.error(ParseError::ExpectedExpression { span });
+            parser.error_expected_expression(span);
             Expr {
                 kind: ExprKind::Error,
                 span,
@@ -1123,18 +1054,12 @@ pub(super) fn parse_atom<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         }
 
         // readonly used as identifier (function name, etc.)
-        TokenKind::Readonly => {
-            let token = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("readonly")),
-                span: token.span,
-            }
-        }
+        TokenKind::Readonly => keyword_name_expr(parser),
 
         // Error: unexpected token
         _ => {
             let span = parser.current_span();
-            parser.error(ParseError::ExpectedExpression { span });
+            parser.error_expected_expression(span);
             Expr {
                 kind: ExprKind::Error,
                 span,
@@ -1170,7 +1095,7 @@ fn parse_new_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'a
         // `new class(...)` rejects first-class callable syntax (PHP fatal).
         let args = if parser.check(TokenKind::LeftParen) {
             let paren_start = parser.current_span().start;
-            match parse_arg_list_or_callable(parser) {
+            match parse_arg_list_or_callable(parser, None) {
                 ArgListResult::Args(args) => args,
                 ArgListResult::CallableMarker => {
                     parser.error(ParseError::Forbidden {
@@ -1221,7 +1146,7 @@ fn parse_new_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'a
 
         return Expr {
             kind: ExprKind::New(NewExpr {
-                class: parser.alloc(anon_class_expr),
+                class: ClassRef::Expr(parser.alloc(anon_class_expr)),
                 args,
             }),
             span: Span::new(start, end),
@@ -1230,27 +1155,7 @@ fn parse_new_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'a
 
     // Parse the class name — can be an identifier, self, parent, static, qualified name, or parenthesized expr
     let class = match parser.current_kind() {
-        TokenKind::Self_ => {
-            let t = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("self")),
-                span: t.span,
-            }
-        }
-        TokenKind::Parent_ => {
-            let t = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("parent")),
-                span: t.span,
-            }
-        }
-        TokenKind::Static => {
-            let t = parser.advance();
-            Expr {
-                kind: ExprKind::Identifier(NameStr::__arena("static")),
-                span: t.span,
-            }
-        }
+        TokenKind::Self_ | TokenKind::Parent_ | TokenKind::Static => keyword_name_expr(parser),
         TokenKind::Variable => {
             // new $className()
             let t = parser.advance();
@@ -1288,33 +1193,16 @@ fn parse_new_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'a
                 span: paren_span,
             }
         }
-        _ => {
-            // Parse as a name (possibly qualified)
-            let name = parser.parse_name();
-            let span = name.span();
-            if matches!(name, Name::Error { .. }) {
-                Expr {
-                    kind: ExprKind::Error,
-                    span,
-                }
-            } else {
-                let ident = match name.to_string_repr() {
-                    Cow::Borrowed(s) => NameStr::__src(s),
-                    Cow::Owned(ref s) => NameStr::__arena(parser.arena.alloc_str(s)),
-                };
-                Expr {
-                    kind: ExprKind::Identifier(ident),
-                    span,
-                }
-            }
-        }
+        // Parse as a name (possibly qualified)
+        _ => name_expr(parser.parse_name()),
     };
 
     // Optional argument list. `new Foo(...)` is rejected: PHP forbids first-class
     // callable syntax in `new` expressions ("Cannot create Closure for new expression").
     let args = if parser.check(TokenKind::LeftParen) {
         let paren_start = parser.current_span().start;
-        match parse_arg_list_or_callable(parser) {
+        let callee = matches!(class.kind, ExprKind::Name(_)).then_some(class.span);
+        match parse_arg_list_or_callable(parser, callee) {
             ArgListResult::Args(args) => args,
             ArgListResult::CallableMarker => {
                 parser.error(ParseError::Forbidden {
@@ -1331,7 +1219,7 @@ fn parse_new_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'a
     let span = Span::new(start, parser.previous_end());
     Expr {
         kind: ExprKind::New(NewExpr {
-            class: parser.alloc(class),
+            class: super::class_ref(parser, class),
             args,
         }),
         span,
@@ -1419,12 +1307,22 @@ fn parse_closure_use_list<'arena, 'src>(
         }
         let var_start = parser.start_span();
         let by_ref = parser.eat(TokenKind::Ampersand).is_some();
-        if let Some(token) = parser.eat(TokenKind::Variable) {
+        let var = parser.eat(TokenKind::Variable).map(|token| {
             let name = parser.variable_name(token);
             let span = Span::new(var_start, token.span.end);
-            vars.push(ClosureUseVar { name, by_ref, span });
+            ClosureUseVar {
+                name,
+                by_ref,
+                span,
+                comma: None,
+            }
+        });
+        let comma = parser.eat(TokenKind::Comma);
+        if let Some(mut var) = var {
+            var.comma = comma.map(|token| token.span);
+            vars.push(var);
         }
-        if parser.eat(TokenKind::Comma).is_none() {
+        if comma.is_none() {
             break;
         }
     }
@@ -1521,14 +1419,15 @@ fn parse_match_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<
         let body = parse_expr(parser);
         let arm_span = Span::new(arm_start, body.span.end);
 
+        // Match arms separated by commas
+        let comma = parser.eat(TokenKind::Comma);
         arms.push(MatchArm {
             conditions,
             body,
             span: arm_span,
+            comma: comma.map(|token| token.span),
         });
-
-        // Match arms separated by commas
-        if parser.eat(TokenKind::Comma).is_none() {
+        if comma.is_none() {
             break;
         }
     }
@@ -1648,21 +1547,25 @@ fn isset_target_valid(kind: &ExprKind<'_, '_>) -> bool {
 // =============================================================================
 
 /// Parse an argument list `(arg, arg, ...)` or detect `(...)` first-class callable syntax.
+///
+/// `callee` spans the called name, when it is written out, for diagnostics.
 pub(crate) fn parse_arg_list_or_callable<'arena, 'src>(
     parser: &'_ mut Parser<'arena, 'src>,
+    callee: Option<Span>,
 ) -> ArgListResult<'arena, 'src> {
     parser.advance(); // consume (
 
     // Detect first-class callable: (...)
     if parser.check(TokenKind::Ellipsis) && parser.peek_kind() == Some(TokenKind::RightParen) {
         let span = parser.current_span();
-        parser.require_version(PhpVersion::Php81, "first-class callable syntax", span);
+        parser.require_feature(Feature::FirstClassCallable, span);
         parser.advance(); // consume ...
         parser.advance(); // consume )
         return ArgListResult::CallableMarker;
     }
 
-    let mut args = parser.alloc_vec_with_capacity(4);
+    let base = parser.start_args();
+    let mut count = 0;
     let mut seen_named = false;
 
     if !parser.check(TokenKind::RightParen) {
@@ -1670,7 +1573,10 @@ pub(crate) fn parse_arg_list_or_callable<'arena, 'src>(
             if parser.check(TokenKind::RightParen) {
                 break; // trailing comma
             }
-            let arg = parse_arg(parser);
+            count += 1;
+            let position = count;
+            let mut arg =
+                parser.with_context(ParseContext::Argument { position, callee }, parse_arg);
 
             if arg.name.is_some() {
                 seen_named = true;
@@ -1682,22 +1588,24 @@ pub(crate) fn parse_arg_list_or_callable<'arena, 'src>(
                 });
             }
 
-            args.push(arg);
-            if parser.eat(TokenKind::Comma).is_none() {
+            let comma = parser.eat(TokenKind::Comma);
+            arg.comma = comma.map(|token| token.span);
+            parser.push_arg(arg);
+            if comma.is_none() {
                 break;
             }
         }
     }
 
     parser.expect(TokenKind::RightParen);
-    ArgListResult::Args(args)
+    ArgListResult::Args(parser.finish_args(base))
 }
 
 fn parse_arg<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Arg<'arena, 'src> {
     let start = parser.start_span();
 
     // Check for named argument: name: (PHP allows any keyword as a named arg label)
-    let name = if parser.peek_kind() == Some(TokenKind::Colon) {
+    let named = if parser.peek_kind() == Some(TokenKind::Colon) {
         let kind = parser.current_kind();
         if kind == TokenKind::Identifier
             || parser.is_semi_reserved_keyword()
@@ -1735,19 +1643,26 @@ fn parse_arg<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Arg<'arena,
         {
             let name_token = parser.advance();
             let span = name_token.span;
-            parser.require_version(PhpVersion::Php80, "named arguments", span);
-            parser.advance(); // consume :
+            parser.require_feature(Feature::NamedArguments, span);
+            let colon = parser.advance();
             let src = parser.source;
-            Some(Name::Simple {
-                value: &src[name_token.span.start as usize..name_token.span.end as usize],
-                span: name_token.span,
-            })
+            Some((
+                Name::Simple {
+                    value: &src[name_token.span.start as usize..name_token.span.end as usize],
+                    span: name_token.span,
+                },
+                colon.span,
+            ))
         } else {
             None
         }
     } else {
         None
     };
+    let (name, colon) = match named {
+        Some((name, colon)) => (Some(name), Some(colon)),
+        None => (None, None),
+    };
 
     // Check for unpack: ...expr
     let unpack = parser.eat(TokenKind::Ellipsis).is_some();
@@ -1767,10 +1682,12 @@ fn parse_arg<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Arg<'arena,
 
     Arg {
         name,
+        colon,
         value,
         unpack,
         by_ref,
         span,
+        comma: None,
     }
 }
 
@@ -1779,8 +1696,9 @@ pub(super) fn parse_function_call<'arena, 'src>(
     callee: Expr<'arena, 'src>,
 ) -> Expr<'arena, 'src> {
     let start = callee.span.start;
+    let name = matches!(callee.kind, ExprKind::Name(_)).then_some(callee.span);
 
-    match parse_arg_list_or_callable(parser) {
+    match parse_arg_list_or_callable(parser, name) {
         ArgListResult::CallableMarker => {
             let span = Span::new(start, parser.previous_end());
             Expr {
@@ -1823,9 +1741,11 @@ fn parse_array_literal<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Ex
             if parser.check(TokenKind::RightBracket) {
                 break; // trailing comma case
             }
-            // Empty element (skipped position for destructuring): [, $b] or [$a, , $c]
+            // Empty element (skipped position for destructuring): [, $b] or [$a, , $c].
+            // Its span is empty, just before the comma that ends the slot.
             if parser.check(TokenKind::Comma) {
-                let span = parser.current_span();
+                let at = parser.current_span().start;
+                let span = Span::new(at, at);
                 elements.push(ArrayElement {
                     key: None,
                     value: Expr {
@@ -1835,12 +1755,20 @@ fn parse_array_literal<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Ex
                     unpack: false,
                     by_ref: false,
                     span,
+                    comma: None,
                 });
             } else {
-                elements.push(parse_array_element(parser));
+                let position = elements.len() + 1;
+                elements.push(
+                    parser
+                        .with_context(ParseContext::ArrayElement { position }, parse_array_element),
+                );
             }
-            if parser.eat(TokenKind::Comma).is_none() {
+            let Some(comma) = parser.eat(TokenKind::Comma) else {
                 break;
+            };
+            if let Some(element) = elements.last_mut() {
+                element.comma = Some(comma.span);
             }
         }
     }
@@ -1870,9 +1798,15 @@ fn parse_array_call<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<
             if parser.check(TokenKind::RightParen) {
                 break; // trailing comma
             }
-            elements.push(parse_array_element(parser));
-            if parser.eat(TokenKind::Comma).is_none() {
+            let position = elements.len() + 1;
+            elements.push(
+                parser.with_context(ParseContext::ArrayElement { position }, parse_array_element),
+            );
+            let Some(comma) = parser.eat(TokenKind::Comma) else {
                 break;
+            };
+            if let Some(element) = elements.last_mut() {
+                element.comma = Some(comma.span);
             }
         }
     }
@@ -1916,6 +1850,7 @@ fn parse_array_element<'arena, 'src>(
             unpack: false,
             by_ref,
             span: elem_span,
+            comma: None,
         }
     } else {
         // value only (or unpack, or by-ref value)
@@ -1927,6 +1862,7 @@ fn parse_array_element<'arena, 'src>(
             unpack,
             by_ref: by_ref_value,
             span: elem_span,
+            comma: None,
         }
     }
 }
@@ -1944,8 +1880,10 @@ fn parse_list_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'
                 break;
             }
             if parser.check(TokenKind::Comma) {
-                // empty element — skipped destructuring position
-                let span = parser.current_span();
+                // empty element — skipped destructuring position, an empty
+                // span before its comma
+                let at = parser.current_span().start;
+                let span = Span::new(at, at);
                 elements.push(ArrayElement {
                     key: None,
                     value: Expr {
@@ -1955,13 +1893,17 @@ fn parse_list_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'
                     unpack: false,
                     by_ref: false,
                     span,
+                    comma: None,
                 });
             } else {
                 elements.push(parse_list_element(parser));
             }
 
-            if parser.eat(TokenKind::Comma).is_none() {
+            let Some(comma) = parser.eat(TokenKind::Comma) else {
                 break;
+            };
+            if let Some(element) = elements.last_mut() {
+                element.comma = Some(comma.span);
             }
         }
     }
@@ -1984,7 +1926,10 @@ fn parse_list_expr<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'
     }
 
     Expr {
-        kind: ExprKind::Array(elements),
+        kind: ExprKind::List(ListExpr {
+            elements,
+            uses_list: true,
+        }),
         span,
     }
 }
@@ -2006,6 +1951,7 @@ fn parse_list_element<'arena, 'src>(
             unpack: false,
             by_ref: true,
             span: elem_span,
+            comma: None,
         };
     }
 
@@ -2022,6 +1968,7 @@ fn parse_list_element<'arena, 'src>(
             unpack: false,
             by_ref,
             span: elem_span,
+            comma: None,
         }
     } else {
         let elem_span = Span::new(elem_start, first.span.end);
@@ -2031,6 +1978,7 @@ fn parse_list_element<'arena, 'src>(
             unpack: false,
             by_ref: false,
             span: elem_span,
+            comma: None,
         }
     }
 }
@@ -2070,22 +2018,26 @@ fn try_parse_cast<'arena, 'src>(
     parser.advance(); // consume the cast keyword
     parser.eat(TokenKind::RightParen)?;
 
-    if cast_kind == CastKind::Unset && parser.version >= PhpVersion::Php80 {
-        parser.error(ParseError::Forbidden {
-            message: "the (unset) cast is no longer supported".into(),
-            span: kw_span,
-        });
+    if cast_kind == CastKind::Unset {
+        parser.removed_syntax(
+            "the (unset) cast",
+            PhpVersion::Php74,
+            PhpVersion::Php80,
+            kw_span,
+        );
     }
     if cast_kind == CastKind::Void {
-        parser.require_version(PhpVersion::Php85, "void cast", kw_span);
+        parser.require_feature(Feature::VoidCast, kw_span);
+        parser.void_casts += 1;
     }
-    // (real) was removed in PHP 8.0
     let kw_text = &parser.source[kw_span.start as usize..kw_span.end as usize];
-    if kw_text.eq_ignore_ascii_case("real") && parser.version >= PhpVersion::Php80 {
-        parser.error(ParseError::Forbidden {
-            message: "the (real) cast is no longer supported, use (float) instead".into(),
-            span: kw_span,
-        });
+    if kw_text.eq_ignore_ascii_case("real") {
+        parser.removed_syntax(
+            "the (real) cast",
+            PhpVersion::Php74,
+            PhpVersion::Php80,
+            kw_span,
+        );
     }
 
     let mut operand = parse_expr_bp(parser, precedence::HIGH_PREFIX_BP);
@@ -2100,14 +2052,25 @@ fn try_parse_cast<'arena, 'src>(
     })
 }
 
-/// Extract label and body from heredoc/nowdoc raw token text.
-/// Input: `<<<LABEL\nbody\nLABEL` or `<<<'LABEL'\nbody\nLABEL`
-/// Returns `(label, body, stripped)` where `stripped` is true if indentation was removed.
-/// Returns `(label, body_start_in_text, body_end_in_text, indent)`.
-/// `body_start_in_text` and `body_end_in_text` are byte offsets within `text` bounding
-/// the verbatim heredoc content (with indentation intact, trailing newline stripped).
-/// `indent` is empty for non-indented heredocs.
-fn parse_heredoc_content(text: &str) -> (&str, usize, usize, String) {
+/// The pieces of a heredoc or nowdoc token, as slices of the source.
+struct HeredocContent<'src> {
+    label: &'src str,
+    /// The body, with indentation intact and the newline before the closing
+    /// marker dropped.
+    raw: &'src str,
+    /// Byte offset of `raw` within the source.
+    body_offset: u32,
+    /// Whitespace before the closing marker; empty for a marker at column 0.
+    indent: &'src str,
+    /// Byte offset of `indent` within the source.
+    indent_offset: u32,
+}
+
+/// Split a heredoc/nowdoc token, `<<<LABEL\nbody\nLABEL` or
+/// `<<<'LABEL'\nbody\nLABEL`, into its label, body, and closing marker
+/// indentation (PHP 7.3+: the marker may be indented).
+fn parse_heredoc_content(source: &str, span: Span) -> HeredocContent<'_> {
+    let text = &source[span.start as usize..span.end as usize];
     // Skip optional `b` binary prefix, then <<<
     let b_prefix = if text.starts_with('b') { 1 } else { 0 };
     let prefix_len = b_prefix + 3; // optional 'b' + "<<<".len()
@@ -2136,17 +2099,14 @@ fn parse_heredoc_content(text: &str) -> (&str, usize, usize, String) {
 
     // Skip to first newline (end of label line)
     let newline_pos = rest.find('\n').unwrap_or(rest.len());
-    let body_start_in_text = rest_start + newline_pos + 1;
-    let body = &text[body_start_in_text..];
+    let body_start = (rest_start + newline_pos + 1).min(text.len());
+    let body = &text[body_start..];
 
-    // Find the end-marker line by scanning line-by-line (PHP 7.3+: marker may be indented).
+    // Find the end-marker line by scanning line-by-line.
     let mut line_start = 0;
     let mut end_line_start = body.len();
-    let mut indent = String::new();
-    loop {
-        if line_start >= body.len() {
-            break;
-        }
+    let mut indent_len = 0;
+    while line_start < body.len() {
         let line_end = body[line_start..]
             .find('\n')
             .map(|p| line_start + p)
@@ -2161,8 +2121,7 @@ fn parse_heredoc_content(text: &str) -> (&str, usize, usize, String) {
                     .is_empty())
         {
             end_line_start = line_start;
-            let indent_len = line.len() - trimmed.len();
-            indent = line[..indent_len].to_string();
+            indent_len = line.len() - trimmed.len();
             break;
         }
         line_start = if line_end < body.len() {
@@ -2176,33 +2135,89 @@ fn parse_heredoc_content(text: &str) -> (&str, usize, usize, String) {
     let content = &body[..end_line_start];
     let content = content.strip_suffix('\n').unwrap_or(content);
     let content = content.strip_suffix('\r').unwrap_or(content);
-    let body_end_in_text = body_start_in_text + content.len();
-
-    (label, body_start_in_text, body_end_in_text, indent)
+    let indent_start = body_start + end_line_start;
+
+    HeredocContent {
+        label,
+        raw: content,
+        body_offset: span.start + body_start as u32,
+        indent: &text[indent_start..indent_start + indent_len],
+        indent_offset: span.start + indent_start as u32,
+    }
 }
 
-/// Validate that every non-empty body line of an indented heredoc/nowdoc starts with `indent`.
-/// Emits `ParseError::Forbidden` for each line that violates the indentation requirement.
-/// Empty lines are always valid.
-fn validate_heredoc_indentation(
-    raw_body: &str,
-    indent: &str,
-    body_offset: u32,
-    errors: &mut Vec<ParseError>,
-) {
-    if indent.is_empty() {
+/// Check a flexible heredoc/nowdoc body against PHP 7.3 rules: the closing
+/// marker's indentation may not mix tabs and spaces, and every body line
+/// must start with at least as much of the same whitespace. Lines holding
+/// only whitespace may be shorter. Emits `ParseError::Forbidden` with PHP's
+/// message, once for the marker and once per offending line.
+fn validate_heredoc_indentation(heredoc: &HeredocContent<'_>, errors: &mut Vec<ParseError>) {
+    let indent = heredoc.indent.as_bytes();
+    let Some(&indent_char) = indent.first() else {
+        return;
+    };
+    if indent.iter().any(|&b| b != indent_char) {
+        errors.push(ParseError::Forbidden {
+            message: MIXED_INDENTATION.into(),
+            span: Span::new(
+                heredoc.indent_offset,
+                heredoc.indent_offset + indent.len() as u32,
+            ),
+        });
         return;
     }
-    let mut offset = body_offset as usize;
-    for line in raw_body.split('\n') {
-        if !line.is_empty() && !line.starts_with(indent) {
+    let mut offset = heredoc.body_offset;
+    for line in heredoc.raw.split('\n') {
+        let content = line.strip_suffix('\r').unwrap_or(line);
+        let message = content
+            .bytes()
+            .take(indent.len())
+            .find(|&b| b != indent_char)
+            .map(|b| {
+                if b == b' ' || b == b'\t' {
+                    MIXED_INDENTATION.into()
+                } else {
+                    format!(
+                        "Invalid body indentation level (expecting an indentation level of at least {})",
+                        indent.len()
+                    )
+                }
+            });
+        if let Some(message) = message {
             errors.push(ParseError::Forbidden {
-                message: "Invalid body indentation level".into(),
-                span: Span::new(offset as u32, (offset + line.len()) as u32),
+                message: message.into(),
+                span: Span::new(offset, offset + content.len() as u32),
             });
         }
-        offset += line.len() + 1; // +1 for the '\n'
+        offset += line.len() as u32 + 1; // +1 for the '\n'
+    }
+}
+
+const MIXED_INDENTATION: &str = "Invalid indentation - tabs and spaces cannot be mixed";
+
+/// Remove up to `indent_len` leading spaces or tabs from every line of a
+/// heredoc/nowdoc body, as PHP does with the closing marker's indentation.
+fn strip_heredoc_indentation(raw: &str, indent_len: usize) -> Cow<'_, str> {
+    if indent_len == 0 {
+        return Cow::Borrowed(raw);
+    }
+    let mut out = String::with_capacity(raw.len());
+    for (i, line) in raw.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&line[heredoc_indent_width(line, indent_len)..]);
     }
+    Cow::Owned(out)
+}
+
+/// Number of leading spaces or tabs, at most `indent_len`, that PHP strips
+/// from a heredoc body line.
+pub(crate) fn heredoc_indent_width(line: &str, indent_len: usize) -> usize {
+    line.bytes()
+        .take(indent_len)
+        .take_while(|&b| b == b' ' || b == b'\t')
+        .count()
 }
 
 /// Parse an integer literal from raw bytes, skipping underscores, without heap allocation.
@@ -1,5 +1,8 @@
+use std::borrow::Cow;
+
 use php_ast::*;
 
+use super::atom::heredoc_indent_width;
 use crate::diagnostics::ParseError;
 use crate::version::PhpVersion;
 
@@ -37,11 +40,15 @@ pub fn parse_interpolated_parts<'arena, 'src>(
                 if i + 1 < len && bytes[i + 1] == b'{' {
                     if let Some(buf) = owned.take() {
                         if !buf.is_empty() {
-                            parts.push(StringPart::Literal(arena.alloc_str(&buf)));
+                            parts.push(literal_part(arena, &buf, base_offset, literal_start, i));
                         }
                     } else if i > literal_start {
-                        parts.push(StringPart::Literal(
-                            arena.alloc_str(&inner[literal_start..i]),
+                        parts.push(literal_part(
+                            arena,
+                            &inner[literal_start..i],
+                            base_offset,
+                            literal_start,
+                            i,
                         ));
                     }
                     i += 2; // skip ${
@@ -157,11 +164,15 @@ pub fn parse_interpolated_parts<'arena, 'src>(
                     // Flush literal run.
                     if let Some(buf) = owned.take() {
                         if !buf.is_empty() {
-                            parts.push(StringPart::Literal(arena.alloc_str(&buf)));
+                            parts.push(literal_part(arena, &buf, base_offset, literal_start, i));
                         }
                     } else if i > literal_start {
-                        parts.push(StringPart::Literal(
-                            arena.alloc_str(&inner[literal_start..i]),
+                        parts.push(literal_part(
+                            arena,
+                            &inner[literal_start..i],
+                            base_offset,
+                            literal_start,
+                            i,
                         ));
                     }
 
@@ -267,11 +278,15 @@ pub fn parse_interpolated_parts<'arena, 'src>(
                 // Complex syntax: {$expr}
                 if let Some(buf) = owned.take() {
                     if !buf.is_empty() {
-                        parts.push(StringPart::Literal(arena.alloc_str(&buf)));
+                        parts.push(literal_part(arena, &buf, base_offset, literal_start, i));
                     }
                 } else if i > literal_start {
-                    parts.push(StringPart::Literal(
-                        arena.alloc_str(&inner[literal_start..i]),
+                    parts.push(literal_part(
+                        arena,
+                        &inner[literal_start..i],
+                        base_offset,
+                        literal_start,
+                        i,
                     ));
                 }
 
@@ -369,11 +384,15 @@ pub fn parse_interpolated_parts<'arena, 'src>(
     // Flush remaining literal run.
     if let Some(buf) = owned {
         if !buf.is_empty() {
-            parts.push(StringPart::Literal(arena.alloc_str(&buf)));
+            parts.push(literal_part(arena, &buf, base_offset, literal_start, i));
         }
     } else if i > literal_start {
-        parts.push(StringPart::Literal(
-            arena.alloc_str(&inner[literal_start..i]),
+        parts.push(literal_part(
+            arena,
+            &inner[literal_start..i],
+            base_offset,
+            literal_start,
+            i,
         ));
     }
 
@@ -383,10 +402,10 @@ pub fn parse_interpolated_parts<'arena, 'src>(
 /// Parse the inner content of an indented heredoc body into parts.
 /// `raw_body` must be a verbatim subslice of `source` (with indentation intact).
 /// `body_offset` is the byte offset of `raw_body[0]` within `source`.
-/// `indent` is the indentation prefix stripped from each line (e.g. `"    "`).
+/// `indent_len` is the width of the closing marker's indentation.
 ///
-/// At the start and after every newline, `indent.len()` bytes are skipped so that
-/// all source offsets remain accurate. Complex `{$expr}` interpolations are parsed
+/// At the start and after every newline, up to `indent_len` spaces or tabs are
+/// skipped so that all source offsets remain accurate. Complex `{$expr}` interpolations are parsed
 /// with a direct sub-parser on `source`, eliminating the wrapping/reoffset overhead
 /// of the old `parse_interpolated_parts_heredoc` path.
 pub fn parse_interpolated_parts_indented<'arena, 'src>(
@@ -394,11 +413,10 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
     source: &'src str,
     raw_body: &'src str,
     body_offset: u32,
-    indent: &str,
+    indent_len: usize,
     version: PhpVersion,
     errors: &mut Vec<ParseError>,
 ) -> ArenaVec<'arena, StringPart<'arena, 'src>> {
-    let indent_len = indent.len();
     let mut parts: ArenaVec<'arena, StringPart<'arena, 'src>> =
         ArenaVec::with_capacity_in(4, arena);
     let mut literal = String::new();
@@ -406,11 +424,9 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
     let len = bytes.len();
 
     // Skip leading indent on the first line
-    let mut i = if indent_len > 0 && len >= indent_len && raw_body[..indent_len] == *indent {
-        indent_len
-    } else {
-        0
-    };
+    let mut i = heredoc_indent_width(raw_body, indent_len);
+    // Start of the current literal run within `raw_body`.
+    let mut literal_start = i;
 
     while i < len {
         match bytes[i] {
@@ -421,15 +437,12 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
                 // Preserve the newline in the literal, then skip the indent on the next line
                 literal.push('\n');
                 i += 1;
-                if indent_len > 0 && i + indent_len <= len && raw_body[i..i + indent_len] == *indent
-                {
-                    i += indent_len;
-                }
+                i += heredoc_indent_width(&raw_body[i..], indent_len);
             }
             b'$' => {
                 if i + 1 < len && is_var_start(bytes[i + 1]) {
                     if !literal.is_empty() {
-                        parts.push(StringPart::Literal(arena.alloc_str(&literal)));
+                        parts.push(literal_part(arena, &literal, body_offset, literal_start, i));
                         literal.clear();
                     }
                     let var_start = i;
@@ -511,6 +524,7 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
                         }
                     }
                     parts.push(StringPart::Expr(expr));
+                    literal_start = i;
                 } else {
                     literal.push('$');
                     i += 1;
@@ -518,7 +532,7 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
             }
             b'{' if i + 1 < len && bytes[i + 1] == b'$' => {
                 if !literal.is_empty() {
-                    parts.push(StringPart::Literal(arena.alloc_str(&literal)));
+                    parts.push(literal_part(arena, &literal, body_offset, literal_start, i));
                     literal.clear();
                 }
                 let brace_offset = body_offset + i as u32;
@@ -574,6 +588,7 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
                     });
                 }
                 parts.push(StringPart::Expr(expr));
+                literal_start = i;
             }
             _ => {
                 if bytes[i] < 0x80 {
@@ -592,13 +607,30 @@ pub fn parse_interpolated_parts_indented<'arena, 'src>(
     }
 
     if !literal.is_empty() {
-        parts.push(StringPart::Literal(arena.alloc_str(&literal)));
+        parts.push(literal_part(arena, &literal, body_offset, literal_start, i));
     }
 
     parts
 }
+/// A literal part covering `start..end` of the string body at `base_offset`.
+fn literal_part<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    value: &str,
+    base_offset: u32,
+    start: usize,
+    end: usize,
+) -> StringPart<'arena, 'src> {
+    StringPart::Literal {
+        value: arena.alloc_str(&crate::bytes::normalize(value)),
+        span: Span::new(base_offset + start as u32, base_offset + end as u32),
+    }
+}
+
 /// Decode one backslash escape at `bytes[i]` (`bytes[i]` must be `b'\\'`).
 /// Returns the new position after the escape.
+/// A `\xNN` or octal escape of `0x80` or more pushes the reserved character
+/// of its byte (see [`crate::bytes`]); the finished literal is
+/// [`normalize`](crate::bytes::normalize)d.
 /// `text` is the `&str` whose bytes are `bytes`.
 /// `stop_at_dquote`: when true, the malformed `\u{…}` recovery also stops at `"`.
 fn decode_escape_at(
@@ -661,7 +693,7 @@ fn decode_escape_at(
             }
             if j > start {
                 if let Ok(val) = u8::from_str_radix(&text[start..j], 16) {
-                    out.push(val as char);
+                    out.push(crate::bytes::byte_char(val));
                 }
             } else {
                 out.push('\\');
@@ -735,9 +767,19 @@ fn decode_escape_at(
             while j < len && j - start < 3 && bytes[j] >= b'0' && bytes[j] <= b'7' {
                 j += 1;
             }
-            if let Ok(val) = u8::from_str_radix(&text[start..j], 8) {
-                out.push(val as char);
+            let digits = &text[start..j];
+            let val = u16::from_str_radix(digits, 8).unwrap_or(0);
+            if val > 0o377 {
+                // PHP keeps the low byte and warns at compile time.
+                errors.push(ParseError::ForbiddenWarning {
+                    message: format!(
+                        "Octal escape sequence overflow \\{digits} is greater than \\377"
+                    )
+                    .into(),
+                    span: Span::new(span_base + i as u32, span_base + j as u32),
+                });
             }
+            out.push(crate::bytes::byte_char((val & 0xFF) as u8));
             j
         }
         _ => {
@@ -791,30 +833,49 @@ pub fn has_interpolation(inner: &str) -> bool {
     false
 }
 
-/// Process escape sequences in a heredoc string without interpolation.
-pub fn process_heredoc_escapes(inner: &str) -> String {
-    let bytes = inner.as_bytes();
-    let len = bytes.len();
-    let mut i = 0;
-    let mut out = String::with_capacity(len);
-    let mut errors = Vec::new();
-
-    while i < len {
-        if bytes[i] == b'\\' {
-            i = decode_escape_at(bytes, inner, i, &mut out, &mut errors, 0, false);
-        } else if bytes[i] < 0x80 {
-            out.push(bytes[i] as char);
-            i += 1;
-        } else {
-            let seq_start = i;
-            i += 1;
-            while i < len && is_utf8_continuation(bytes[i]) {
+/// Decode the escape sequences of a heredoc body without interpolation,
+/// removing up to `indent_len` leading spaces or tabs from every line.
+/// `body_offset` is the source offset of `raw`, so malformed escapes are
+/// reported where they were written.
+pub fn decode_heredoc_body(
+    raw: &str,
+    body_offset: u32,
+    indent_len: usize,
+    errors: &mut Vec<ParseError>,
+) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut line_start = 0;
+    for (n, line) in raw.split('\n').enumerate() {
+        if n > 0 {
+            out.push('\n');
+        }
+        let indent = heredoc_indent_width(line, indent_len);
+        let text = &line[indent..];
+        let span_base = body_offset + (line_start + indent) as u32;
+        let bytes = text.as_bytes();
+        let len = bytes.len();
+        let mut i = 0;
+        while i < len {
+            if bytes[i] == b'\\' {
+                i = decode_escape_at(bytes, text, i, &mut out, errors, span_base, false);
+            } else if bytes[i] < 0x80 {
+                out.push(bytes[i] as char);
+                i += 1;
+            } else {
+                let seq_start = i;
                 i += 1;
+                while i < len && is_utf8_continuation(bytes[i]) {
+                    i += 1;
+                }
+                out.push_str(&text[seq_start..i]);
             }
-            out.push_str(&inner[seq_start..i]);
         }
+        line_start += line.len() + 1;
+    }
+    match crate::bytes::normalize(&out) {
+        Cow::Borrowed(_) => out,
+        Cow::Owned(normalized) => normalized,
     }
-    out
 }
 
 fn is_var_start(b: u8) -> bool {
@@ -876,7 +937,10 @@ fn parse_simple_index<'arena, 'src>(
     let key_start = idx_offset as usize;
     let key_end = idx_end as usize;
     Expr {
-        kind: ExprKind::String(arena.alloc_str(&source[key_start..key_end])),
+        kind: ExprKind::String {
+            value: arena.alloc_str(&source[key_start..key_end]),
+            raw: &source[key_start..key_end],
+        },
         span,
     }
 }
@@ -906,6 +970,8 @@ fn is_php_interp_nonzero_int(s: &str) -> bool {
 
 /// Parse a complex interpolation expression using a sub-parser that starts directly
 /// in the original source at the given offset, avoiding string allocation and span reoffset.
+/// The sub-parser's source ends at `end`, so it lexes only the interpolation rather than
+/// the rest of the file, which made files with many interpolations quadratic.
 fn parse_complex_interpolation<'arena, 'src>(
     arena: &'arena bumpalo::Bump,
     source: &'src str,
@@ -913,6 +979,7 @@ fn parse_complex_interpolation<'arena, 'src>(
     end: u32,
     version: PhpVersion,
 ) -> Expr<'arena, 'src> {
+    let source = source.get(..end as usize).unwrap_or(source);
     let mut sub = crate::parser::Parser::new_at(arena, source, offset as usize, version);
     let expr = crate::expr::parse_expr(&mut sub);
     if matches!(expr.kind, ExprKind::Error) {
@@ -3,11 +3,9 @@ use php_lexer::TokenKind;
 
 use crate::diagnostics::ParseError;
 use crate::instrument;
-use crate::parser::{Parser, MAX_DEPTH};
-use crate::precedence::{
-    self, ASSIGNMENT_BP, MEMBER_ACCESS_BP, NULL_COALESCE_LEFT_BP, SCOPE_RESOLUTION_BP, TERNARY_BP,
-};
-use crate::version::PhpVersion;
+use crate::parser::{ParseContext, Parser, MAX_DEPTH};
+use crate::precedence::{self, ASSIGNMENT_BP, TERNARY_BP};
+use crate::version::{Feature, PhpVersion};
 use php_ast::span::Span;
 
 mod atom;
@@ -76,6 +74,7 @@ fn is_valid_assignment_target(kind: &ExprKind<'_, '_>) -> bool {
         | ExprKind::StaticPropertyAccess(_)
         | ExprKind::StaticPropertyAccessDynamic { .. }
         | ExprKind::Array(_)
+        | ExprKind::List(_)
         | ExprKind::Error => true,
         ExprKind::Parenthesized(inner) => is_valid_assignment_target(&inner.kind),
         _ => false,
@@ -84,7 +83,7 @@ fn is_valid_assignment_target(kind: &ExprKind<'_, '_>) -> bool {
 
 /// True if a destructuring-element value is itself a writable target.
 /// `Omit` (the gap slot in `[$a, , $c]`) is allowed; nested destructuring
-/// (`Array`) recurses; everything else falls back to the lvalue rule.
+/// (`List`) is a target; everything else falls back to the lvalue rule.
 fn is_valid_destructure_element(kind: &ExprKind<'_, '_>) -> bool {
     matches!(kind, ExprKind::Omit) || is_valid_assignment_target(kind)
 }
@@ -123,49 +122,52 @@ pub(crate) fn find_new_in_initializer<'arena, 'src>(expr: &Expr<'arena, 'src>) -
     finder.found
 }
 
-/// True if the array-literal at `span` was written with `list(...)` syntax
-/// rather than the short `[...]` form. Determined by the first source byte
-/// at the span start.
-fn is_list_syntax(source: &str, span: Span) -> bool {
-    source
-        .as_bytes()
-        .get(span.start as usize)
-        .map(|b| b.eq_ignore_ascii_case(&b'l'))
-        .unwrap_or(false)
+/// Turn an array literal in target position — the left of an assignment or
+/// a `foreach` value — into an [`ExprKind::List`], along with the array
+/// literals nested in its elements.
+pub(crate) fn mark_destructuring(expr: &mut Expr<'_, '_>) {
+    if matches!(expr.kind, ExprKind::Array(_)) {
+        let ExprKind::Array(elements) = std::mem::replace(&mut expr.kind, ExprKind::Error) else {
+            unreachable!()
+        };
+        expr.kind = ExprKind::List(ListExpr {
+            elements,
+            uses_list: false,
+        });
+    }
+    if let ExprKind::List(list) = &mut expr.kind {
+        for element in list.elements.iter_mut() {
+            mark_destructuring(&mut element.value);
+        }
+    }
 }
 
-/// Walk the destructure target (top-level `Array(_)`) and report mixing of
-/// `list(...)` and `[...]` syntax — PHP fatals "Cannot mix [] and list()".
+/// Walk the destructure target and report mixing of `list(...)` and `[...]`
+/// syntax — PHP fatals "Cannot mix [] and list()".
 fn check_mixed_list_short_syntax<'arena, 'src>(
     parser: &mut Parser<'arena, 'src>,
     lhs: &Expr<'arena, 'src>,
 ) {
-    let ExprKind::Array(_) = lhs.kind else {
-        return;
-    };
-    let outer_is_list = is_list_syntax(parser.source(), lhs.span);
     fn walk<'arena, 'src>(
         parser: &mut Parser<'arena, 'src>,
-        expr: &Expr<'arena, 'src>,
-        outer_is_list: bool,
+        list: &ListExpr<'arena, 'src>,
+        uses_list: bool,
     ) {
-        if let ExprKind::Array(elems) = &expr.kind {
-            if is_list_syntax(parser.source(), expr.span) != outer_is_list {
-                parser.error(ParseError::Forbidden {
-                    message: "Cannot mix [] and list()".into(),
-                    span: expr.span,
-                });
-                return;
-            }
-            for el in elems.iter() {
-                walk(parser, &el.value, outer_is_list);
+        for el in list.elements.iter() {
+            if let ExprKind::List(inner) = &el.value.kind {
+                if inner.uses_list != uses_list {
+                    parser.error(ParseError::Forbidden {
+                        message: "Cannot mix [] and list()".into(),
+                        span: el.value.span,
+                    });
+                    continue;
+                }
+                walk(parser, inner, uses_list);
             }
         }
     }
-    if let ExprKind::Array(elems) = &lhs.kind {
-        for el in elems.iter() {
-            walk(parser, &el.value, outer_is_list);
-        }
+    if let ExprKind::List(list) = &lhs.kind {
+        walk(parser, list, list.uses_list);
     }
 }
 
@@ -205,10 +207,11 @@ fn is_temporary_write_target(kind: &ExprKind<'_, '_>) -> bool {
 /// to block it.
 fn parse_assign_continuation<'arena, 'src>(
     parser: &mut Parser<'arena, 'src>,
-    lhs: Expr<'arena, 'src>,
+    mut lhs: Expr<'arena, 'src>,
 ) -> Expr<'arena, 'src> {
     debug_assert!(parser.current_kind().is_assignment_op());
     let span = parser.current_span();
+    mark_destructuring(&mut lhs);
     // PHP rejects pre/post-increment/decrement as an assignment target at parse time.
     if matches!(
         lhs.kind,
@@ -237,7 +240,10 @@ fn parse_assign_continuation<'arena, 'src>(
     }
     // Short-list destructure: `[, , ,] = …` — PHP fatals on "Cannot use empty list"
     // when every element is omitted (or the array has no elements at all).
-    if let ExprKind::Array(elems) = &lhs.kind {
+    if let ExprKind::List(ListExpr {
+        elements: elems, ..
+    }) = &lhs.kind
+    {
         if elems.is_empty() || elems.iter().all(|e| matches!(e.value.kind, ExprKind::Omit)) {
             parser.error(ParseError::Forbidden {
                 message: "Cannot use empty list".into(),
@@ -279,7 +285,9 @@ fn parse_assign_continuation<'arena, 'src>(
             op_token.kind
         ),
     };
-    let rhs = parse_expr_bp(parser, ASSIGNMENT_BP);
+    let rhs = parser.with_context(ParseContext::AssignedValue, |p| {
+        parse_expr_bp(p, ASSIGNMENT_BP)
+    });
     if by_ref && matches!(rhs.kind, ExprKind::New(..)) {
         parser.error(ParseError::Forbidden {
             message: "Cannot use by-reference assignment with 'new' expression".into(),
@@ -329,6 +337,13 @@ pub fn parse_expr_bp<'arena, 'src>(
 
     loop {
         let kind = parser.current_kind();
+        // A single gate for every way to continue: tokens that cannot follow
+        // an operand (`;`, `,`, `)`, …) have a left binding power of zero, and
+        // most iterations end here.
+        let left_bp = precedence::left_binding_power(kind);
+        if left_bp == 0 || left_bp < min_bp {
+            break;
+        }
 
         // Fast-path: Direct token kind dispatch for most-common operators.
         // Compiler converts this to jump table (O(1)) instead of sequential branches.
@@ -336,38 +351,25 @@ pub fn parse_expr_bp<'arena, 'src>(
         let should_continue = match kind {
             // Postfix operators (++, --)
             TokenKind::PlusPlus | TokenKind::MinusMinus => {
-                if let Some(left_bp) = precedence::postfix_binding_power(kind) {
-                    if left_bp < min_bp {
-                        break;
-                    }
-                    let op_token = parser.advance();
-                    let op = match op_token.kind {
-                        TokenKind::PlusPlus => UnaryPostfixOp::PostIncrement,
-                        TokenKind::MinusMinus => UnaryPostfixOp::PostDecrement,
-                        _ => unreachable!(
-                            "outer arm already matched PlusPlus/MinusMinus, got {:?}",
-                            op_token.kind
-                        ),
-                    };
-                    let span = lhs.span.merge(op_token.span);
-                    lhs = Expr {
-                        kind: ExprKind::UnaryPostfix(UnaryPostfixExpr {
-                            operand: parser.alloc(lhs),
-                            op,
-                        }),
-                        span,
-                    };
-                    true
+                let op_token = parser.advance();
+                let op = if op_token.kind == TokenKind::PlusPlus {
+                    UnaryPostfixOp::PostIncrement
                 } else {
-                    false
-                }
+                    UnaryPostfixOp::PostDecrement
+                };
+                let span = lhs.span.merge(op_token.span);
+                lhs = Expr {
+                    kind: ExprKind::UnaryPostfix(UnaryPostfixExpr {
+                        operand: parser.alloc(lhs),
+                        op,
+                    }),
+                    span,
+                };
+                true
             }
 
             // Ternary operator (non-associative in PHP 8.0+)
             TokenKind::Question => {
-                if TERNARY_BP < min_bp {
-                    break;
-                }
                 // PHP 8.0+: unparenthesized `a ? b : c ? d : e` is a fatal parse error,
                 // but chains of short ternaries (`a ?: b ?: c`) remain valid because `?:`
                 // is left-associative and unambiguous.
@@ -375,19 +377,17 @@ pub fn parse_expr_bp<'arena, 'src>(
                 // So: fire the error when LHS is an already-parsed ternary UNLESS both the
                 // LHS ternary and the incoming ternary are short (then_expr=None and next
                 // token after `?` is `:`).
-                if parser.version >= PhpVersion::Php80 {
-                    if let ExprKind::Ternary(lhs_tern) = &lhs.kind {
-                        let lhs_is_short = lhs_tern.then_expr.is_none();
-                        let incoming_is_short = parser.peek_kind() == Some(TokenKind::Colon);
-                        if !(lhs_is_short && incoming_is_short) {
-                            let span = parser.current_span();
-                            parser.error(ParseError::Forbidden {
-                                message: "Unparenthesized `a ? b : c ? d : e` is not supported. \
-                                          Use parentheses to make the order explicit."
-                                    .into(),
-                                span,
-                            });
-                        }
+                if let ExprKind::Ternary(lhs_tern) = &lhs.kind {
+                    let lhs_is_short = lhs_tern.then_expr.is_none();
+                    let incoming_is_short = parser.peek_kind() == Some(TokenKind::Colon);
+                    if !(lhs_is_short && incoming_is_short) {
+                        let span = parser.current_span();
+                        parser.removed_syntax(
+                            "nesting ternaries without parentheses (`a ? b : c ? d : e`)",
+                            PhpVersion::Php74,
+                            PhpVersion::Php80,
+                            span,
+                        );
                     }
                 }
                 parser.advance(); // consume ?
@@ -423,13 +423,10 @@ pub fn parse_expr_bp<'arena, 'src>(
 
             // Arrow operators (property/method access)
             TokenKind::Arrow | TokenKind::NullsafeArrow => {
-                if MEMBER_ACCESS_BP < min_bp {
-                    break;
-                }
                 let is_nullsafe = kind == TokenKind::NullsafeArrow;
                 if is_nullsafe {
                     let span = parser.current_span();
-                    parser.require_version(PhpVersion::Php80, "nullsafe operator (?->)", span);
+                    parser.require_feature(Feature::NullsafeOperator, span);
                 }
                 parser.advance(); // consume -> or ?->
 
@@ -438,7 +435,10 @@ pub fn parse_expr_bp<'arena, 'src>(
 
                 // Check if it's a method call
                 if parser.check(TokenKind::LeftParen) {
-                    match parse_arg_list_or_callable(parser) {
+                    match parse_arg_list_or_callable(
+                        parser,
+                        matches!(member.kind, ExprKind::Identifier(_)).then_some(member.span),
+                    ) {
                         ArgListResult::CallableMarker => {
                             let span = Span::new(lhs.span.start, parser.previous_end());
                             let callable_kind = if is_nullsafe {
@@ -500,9 +500,6 @@ pub fn parse_expr_bp<'arena, 'src>(
 
             // Null coalescing operator (produces NullCoalesce node, not Binary)
             TokenKind::QuestionQuestion => {
-                if NULL_COALESCE_LEFT_BP < min_bp {
-                    break;
-                }
                 parser.advance();
                 // PHP grammar quirk: the right operand of ?? can contain assignment but not
                 // unparenthesized ternary.  Use TERNARY_BP + 1 to block ternary, then
@@ -526,9 +523,6 @@ pub fn parse_expr_bp<'arena, 'src>(
 
             // Assignment operators (right-associative)
             _ if kind.is_assignment_op() => {
-                if ASSIGNMENT_BP < min_bp {
-                    break;
-                }
                 lhs = parse_assign_continuation(parser, lhs);
                 true
             }
@@ -544,9 +538,6 @@ pub fn parse_expr_bp<'arena, 'src>(
         // bp=90: must parse through the bp=45 gate used by promoted-property defaults
         // (which only intends to block `{}` curly-brace subscript access, bp=44).
         if kind == TokenKind::DoubleColon {
-            if SCOPE_RESOLUTION_BP < min_bp {
-                break;
-            }
             parser.advance(); // consume ::
 
             // Check what follows ::
@@ -561,13 +552,13 @@ pub fn parse_expr_bp<'arena, 'src>(
                         kind: ExprKind::Variable(NameStr::__src(var_name)),
                         span: var_span,
                     });
-                    match parse_arg_list_or_callable(parser) {
+                    match parse_arg_list_or_callable(parser, None) {
                         ArgListResult::CallableMarker => {
                             let span = Span::new(lhs.span.start, parser.previous_end());
                             lhs = Expr {
                                 kind: ExprKind::CallableCreate(CallableCreateExpr {
                                     kind: CallableCreateKind::StaticMethod {
-                                        class: parser.alloc(lhs),
+                                        class: class_ref(parser, lhs),
                                         method,
                                     },
                                 }),
@@ -579,7 +570,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                             lhs = Expr {
                                 kind: ExprKind::StaticDynMethodCall(parser.alloc(
                                     StaticDynMethodCallExpr {
-                                        class: parser.alloc(lhs),
+                                        class: class_ref(parser, lhs),
                                         method,
                                         args,
                                     },
@@ -597,7 +588,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                     let span = Span::new(lhs.span.start, var_span.end);
                     lhs = Expr {
                         kind: ExprKind::StaticPropertyAccess(StaticAccessExpr {
-                            class: parser.alloc(lhs),
+                            class: class_ref(parser, lhs),
                             member,
                         }),
                         span,
@@ -609,7 +600,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                 let span = Span::new(lhs.span.start, member.span.end);
                 lhs = Expr {
                     kind: ExprKind::StaticPropertyAccessDynamic {
-                        class: parser.alloc(lhs),
+                        class: class_ref(parser, lhs),
                         member: parser.alloc(member),
                     },
                     span,
@@ -617,23 +608,19 @@ pub fn parse_expr_bp<'arena, 'src>(
             } else if parser.check(TokenKind::LeftBrace) {
                 // Dynamic class constant/method: A::{'b'}(), Foo::{bar()}
                 let brace_span = parser.current_span();
-                parser.require_version(
-                    PhpVersion::Php83,
-                    "dynamic class constant fetch",
-                    brace_span,
-                );
+                parser.require_feature(Feature::DynamicClassConstantFetch, brace_span);
                 parser.advance(); // consume {
                 let member = parse_expr(parser);
                 parser.expect(TokenKind::RightBrace);
                 if parser.check(TokenKind::LeftParen) {
                     // Dynamic static method call: A::{'b'}()
-                    match parse_arg_list_or_callable(parser) {
+                    match parse_arg_list_or_callable(parser, None) {
                         ArgListResult::CallableMarker => {
                             let span = Span::new(lhs.span.start, parser.previous_end());
                             lhs = Expr {
                                 kind: ExprKind::CallableCreate(CallableCreateExpr {
                                     kind: CallableCreateKind::StaticMethod {
-                                        class: parser.alloc(lhs),
+                                        class: class_ref(parser, lhs),
                                         method: parser.alloc(member),
                                     },
                                 }),
@@ -644,7 +631,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                             let lhs_start = lhs.span.start;
                             let callee = Expr {
                                 kind: ExprKind::ClassConstAccessDynamic {
-                                    class: parser.alloc(lhs),
+                                    class: class_ref(parser, lhs),
                                     member: parser.alloc(member),
                                 },
                                 span: Span::new(lhs_start, parser.previous_end()),
@@ -664,7 +651,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                     let span = Span::new(lhs.span.start, parser.previous_end());
                     lhs = Expr {
                         kind: ExprKind::ClassConstAccessDynamic {
-                            class: parser.alloc(lhs),
+                            class: class_ref(parser, lhs),
                             member: parser.alloc(member),
                         },
                         span,
@@ -676,7 +663,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                 let span = Span::new(lhs.span.start, token.span.end);
                 lhs = Expr {
                     kind: ExprKind::ClassConstAccess(StaticAccessExpr {
-                        class: parser.alloc(lhs),
+                        class: class_ref(parser, lhs),
                         member: parser.alloc(Expr {
                             kind: ExprKind::Identifier(NameStr::__src("class")),
                             span: token.span,
@@ -709,13 +696,13 @@ pub fn parse_expr_bp<'arena, 'src>(
                         kind: ExprKind::Identifier(NameStr::__src(member_name)),
                         span: member_span,
                     });
-                    match parse_arg_list_or_callable(parser) {
+                    match parse_arg_list_or_callable(parser, Some(member_span)) {
                         ArgListResult::CallableMarker => {
                             let span = Span::new(lhs.span.start, parser.previous_end());
                             lhs = Expr {
                                 kind: ExprKind::CallableCreate(CallableCreateExpr {
                                     kind: CallableCreateKind::StaticMethod {
-                                        class: parser.alloc(lhs),
+                                        class: class_ref(parser, lhs),
                                         method,
                                     },
                                 }),
@@ -727,7 +714,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                             lhs = Expr {
                                 kind: ExprKind::StaticMethodCall(parser.alloc(
                                     StaticMethodCallExpr {
-                                        class: parser.alloc(lhs),
+                                        class: class_ref(parser, lhs),
                                         method,
                                         args,
                                     },
@@ -745,7 +732,7 @@ pub fn parse_expr_bp<'arena, 'src>(
                     let span = Span::new(lhs.span.start, parser.previous_end());
                     lhs = Expr {
                         kind: ExprKind::ClassConstAccess(StaticAccessExpr {
-                            class: parser.alloc(lhs),
+                            class: class_ref(parser, lhs),
                             member,
                         }),
                         span,
@@ -757,9 +744,6 @@ pub fn parse_expr_bp<'arena, 'src>(
 
         // Array access: $arr[index]
         if kind == TokenKind::LeftBracket {
-            if MEMBER_ACCESS_BP < min_bp {
-                break;
-            }
             parser.advance(); // consume [
             let index = if parser.check(TokenKind::RightBracket) {
                 None
@@ -781,7 +765,7 @@ pub fn parse_expr_bp<'arena, 'src>(
 
         // Curly brace array/string access: $a{'b'} — removed in PHP 8.0.
         if kind == TokenKind::LeftBrace {
-            if MEMBER_ACCESS_BP < min_bp || parser.no_brace_subscript {
+            if parser.no_brace_subscript {
                 break;
             }
             let brace_span = parser.current_span();
@@ -793,12 +777,12 @@ pub fn parse_expr_bp<'arena, 'src>(
                 Some(parser.alloc(e))
             };
             parser.expect(TokenKind::RightBrace);
-            parser.error(ParseError::Forbidden {
-                message:
-                    "Array and string offset access syntax with curly braces is no longer supported"
-                        .into(),
-                span: brace_span,
-            });
+            parser.removed_syntax(
+                "array and string offset access with curly braces",
+                PhpVersion::Php74,
+                PhpVersion::Php80,
+                brace_span,
+            );
             let span = Span::new(lhs.span.start, parser.previous_end());
             lhs = Expr {
                 kind: ExprKind::ArrayAccess(ArrayAccessExpr {
@@ -812,18 +796,12 @@ pub fn parse_expr_bp<'arena, 'src>(
 
         // Function call: name(args)
         if kind == TokenKind::LeftParen {
-            if MEMBER_ACCESS_BP < min_bp {
-                break;
-            }
             lhs = parse_function_call(parser, lhs);
             continue;
         }
 
         // Infix binary operators
-        if let Some((left_bp, right_bp)) = precedence::infix_binding_power(kind) {
-            if left_bp < min_bp {
-                break;
-            }
+        if let Some((_, right_bp)) = precedence::infix_binding_power(kind) {
             // PHP 8.0+: chaining non-associative operators within the same precedence group
             // is a fatal error (e.g. `1 < 2 < 3`, `$a === $b == $c`).
             // Cross-group chains (e.g. `$a > $b == $c`) and instanceof chains are
@@ -844,14 +822,8 @@ pub fn parse_expr_bp<'arena, 'src>(
             }
             let op_token = parser.advance();
             if op_token.kind == TokenKind::PipeArrow {
-                parser.require_version(PhpVersion::Php85, "pipe operator (|>)", op_token.span);
+                parser.require_feature(Feature::PipeOperator, op_token.span);
             }
-            let op = token_to_binary_op(op_token.kind).unwrap_or_else(|| {
-                unreachable!(
-                    "infix_binding_power returned Some for {:?} but token_to_binary_op returned None",
-                    op_token.kind
-                )
-            });
             // PHP grammar quirk: assignment escapes rightward through every binary operator.
             // e.g. `$a && $b = $c`   →  `$a && ($b = $c)`
             //      `$a + $b + $c = 5` → `$a + $b + ($c = 5)`
@@ -861,6 +833,23 @@ pub fn parse_expr_bp<'arena, 'src>(
             if parser.current_kind().is_assignment_op() {
                 rhs = parse_assign_continuation(parser, rhs);
             }
+            if op_token.kind == TokenKind::Instanceof {
+                let span = lhs.span.merge(rhs.span);
+                lhs = Expr {
+                    kind: ExprKind::Instanceof(InstanceofExpr {
+                        expr: parser.alloc(lhs),
+                        class: class_ref(parser, rhs),
+                    }),
+                    span,
+                };
+                continue;
+            }
+            let op = token_to_binary_op(op_token.kind).unwrap_or_else(|| {
+                unreachable!(
+                    "infix_binding_power returned Some for {:?} but token_to_binary_op returned None",
+                    op_token.kind
+                )
+            });
             // Check if pipe operator has unparenthesized arrow function on RHS
             if op == BinaryOp::Pipe && matches!(rhs.kind, ExprKind::ArrowFunction(_)) {
                 parser.error(ParseError::Forbidden {
@@ -890,6 +879,40 @@ pub fn parse_expr_bp<'arena, 'src>(
     lhs
 }
 
+/// Classify the class operand of `new`, `instanceof`, or `::`.
+///
+/// `self`, `parent`, and `static` parse as simple names and are matched
+/// case-insensitively, as PHP does. The right operand of `instanceof` parses
+/// as a constant fetch; it names a class.
+pub(crate) fn class_ref<'arena, 'src>(
+    parser: &Parser<'arena, 'src>,
+    expr: Expr<'arena, 'src>,
+) -> ClassRef<'arena, 'src> {
+    match expr.kind {
+        ExprKind::Name(name) | ExprKind::ConstFetch(name) => class_ref_from_name(parser, name),
+        _ => ClassRef::Expr(parser.alloc(expr)),
+    }
+}
+
+/// [`class_ref`] for an operand already parsed as a name, e.g. a catch type.
+pub(crate) fn class_ref_from_name<'arena, 'src>(
+    parser: &Parser<'arena, 'src>,
+    name: Name<'arena, 'src>,
+) -> ClassRef<'arena, 'src> {
+    if let Name::Simple { value, span } = name {
+        if value.eq_ignore_ascii_case("self") {
+            return ClassRef::SelfKw(span);
+        }
+        if value.eq_ignore_ascii_case("parent") {
+            return ClassRef::ParentKw(span);
+        }
+        if value.eq_ignore_ascii_case("static") {
+            return ClassRef::StaticKw(span);
+        }
+    }
+    ClassRef::Name(parser.alloc(name))
+}
+
 /// Parse a member name after -> or ?->. Accepts identifiers and semi-reserved keywords.
 fn parse_member_name<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Expr<'arena, 'src> {
     // Plain identifier or any keyword PHP allows as a member name.
@@ -979,7 +1002,6 @@ fn token_to_binary_op(kind: TokenKind) -> Option<BinaryOp> {
         TokenKind::And => Some(BinaryOp::LogicalAnd),
         TokenKind::Or => Some(BinaryOp::LogicalOr),
         TokenKind::Xor => Some(BinaryOp::LogicalXor),
-        TokenKind::Instanceof => Some(BinaryOp::Instanceof),
         TokenKind::PipeArrow => Some(BinaryOp::Pipe),
         _ => None,
     }
@@ -47,6 +47,44 @@
 //! assert!(!result.errors.is_empty()); // enums require PHP 8.1
 //! ```
 //!
+//! # Resource limits
+//!
+//! Use [`parse_with_options`] to bound the work done on untrusted input.
+//! [`ParserOptions`] caps the file size, token count, string literal length,
+//! and identifier length; exceeding a cap produces a
+//! [`diagnostics::ParseError::LimitExceeded`] diagnostic.
+//!
+//! ```
+//! let options = php_rs_parser::ParserOptions {
+//!     max_identifier_length: Some(8),
+//!     ..Default::default()
+//! };
+//! let arena = bumpalo::Bump::new();
+//! let result = php_rs_parser::parse_with_options(&arena, "<?php $averyverylongname = 1;", &options);
+//! assert_eq!(result.errors.len(), 1);
+//! assert_eq!(result.program.stmts.len(), 1); // parsing continued
+//! ```
+//!
+//! A parse can also be bounded in time: [`parse_with_deadline`] stops once a
+//! [`std::time::Duration`] has elapsed and returns the statements parsed so
+//! far, with [`ParseResult::timed_out`] set. Likewise,
+//! [`ParserOptions::max_errors`] stops parsing a file that is mostly errors,
+//! such as minified or generated code, and sets [`ParseResult::truncated`].
+//!
+//! # PHP embedded in other documents
+//!
+//! [`parse_islands`] parses the PHP regions of a template or other host
+//! document as one program, with spans into the host.
+//!
+//! [`parse_expr_str`] and [`parse_stmt_str`] parse a single expression or
+//! statement given without `<?php`, for REPLs and template expressions.
+//!
+//! # Sources that are not UTF-8
+//!
+//! [`parse_bytes`] takes the source as bytes, for legacy files in Latin-1 or
+//! another 8-bit encoding; the [`bytes`] module maps the result back to the
+//! original bytes.
+//!
 //! # Reusing arenas across re-parses (LSP usage)
 //!
 //! Use [`ParserContext`] to avoid allocator churn when the same document is
@@ -64,23 +102,74 @@
 //! let result = ctx.reparse("<?php echo 2;");
 //! assert!(result.errors.is_empty());
 //! ```
+//!
+//! # Parsing many files
+//!
+//! A [`Session`] parses any number of files with one set of options, keeps
+//! each result under a [`FileId`] handle for its interned path, and collects
+//! the diagnostics of all of them.
+//!
+//! ```
+//! let arena = bumpalo::Bump::new();
+//! let mut session = php_rs_parser::Session::new(&arena);
+//! let file = session.parse("index.php", "<?php echo 1;");
+//! assert_eq!(session.path(file), "index.php");
+//! assert!(!session.has_errors());
+//! ```
+//!
+//! # Stability
+//!
+//! `php-rs-parser`, `php-ast`, `php-lexer`, `phpdoc-parser`, and
+//! `php-printer` are versioned together and follow semantic versioning
+//! against their documented API; before 1.0 a minor release is a major one.
+//! Three rules keep new PHP syntax from being a breaking change:
+//!
+//! - Enums that grow with the language — AST node kinds, operators, casts,
+//!   builtin types, tokens, [`PhpVersion`], [`Feature`], and
+//!   [`diagnostics::ParseError`] — are `#[non_exhaustive]`, so a `match` on
+//!   them needs a wildcard arm and keeps compiling when a variant is added.
+//! - [`ParseResult`] is `#[non_exhaustive]`: read its fields, but build one
+//!   only through the `parse*` functions, so new fields are not breaking.
+//! - Items hidden from the documentation — `#[doc(hidden)]` methods,
+//!   `__`-prefixed constructors, and the profiling `instrument` module — are
+//!   internals and may change in any release.
+//!
+//! The [`prelude`] collects the common entry points in one import.
+//! `tests/public_api.rs` pins the signatures of the stable surface, so a
+//! change that would break downstream code fails the test suite first.
 
+pub mod bytes;
 pub mod diagnostics;
 pub(crate) mod expr;
+#[doc(hidden)]
 pub mod instrument;
-pub(crate) mod parser;
+pub(crate) mod open_tag;
+pub(crate) mod options;
+pub mod parser;
 pub use phpdoc_parser as phpdoc;
 pub(crate) mod precedence;
+pub mod prelude;
+pub(crate) mod reparse;
+pub mod session;
 pub mod source_map;
 pub(crate) mod stmt;
+pub(crate) mod suggest;
 pub mod version;
 
 use diagnostics::ParseError;
-use php_ast::{Comment, Program};
+pub use options::ParserOptions;
+use php_ast::{Comment, Expr, Program, Span, Stmt};
+pub use reparse::{
+    reparse_function, reparse_function_versioned, FunctionReparse, ReparsedFunction,
+};
+pub use session::{FileId, Session};
 use source_map::SourceMap;
-pub use version::PhpVersion;
+use std::borrow::Cow;
+use std::time::Duration;
+pub use version::{Feature, PhpVersion};
 
 /// The result of parsing a PHP source string.
+#[non_exhaustive]
 pub struct ParseResult<'arena, 'src> {
     /// The original source text. Useful for extracting text from spans
     /// via `&result.source[span.start as usize..span.end as usize]`.
@@ -103,16 +192,26 @@ pub struct ParseResult<'arena, 'src> {
     /// [`php_ast::visitor::walk_comments`] with a [`Visitor`] that also
     /// overrides the declaration visit methods.
     pub comments: Vec<Comment<'src>>,
-    /// Parse errors and diagnostics. Empty on a successful parse.
+    /// Parse errors and diagnostics in source order (by span start, then end).
+    /// Empty on a successful parse.
     pub errors: Vec<ParseError>,
     /// `true` when the error list was capped at the internal limit and further
     /// errors were silently dropped. Callers that need a complete error list
     /// (e.g. linters) should treat this as an incomplete result.
     pub errors_truncated: bool,
+    /// `true` when parsing stopped early because the
+    /// [`ParserOptions::timeout`] elapsed. `program` then covers only the
+    /// source before the [`diagnostics::ParseError::TimedOut`] in `errors`.
+    pub timed_out: bool,
+    /// `true` when parsing stopped early because
+    /// [`ParserOptions::max_errors`] errors had been reported. `program` then
+    /// covers only the source up to the last of them.
+    pub truncated: bool,
     /// Pre-computed line index for resolving byte offsets in [`Span`](php_ast::Span)
-    /// to line/column positions. Use [`SourceMap::offset_to_line_col`] or
-    /// [`SourceMap::span_to_line_col`] to convert.
-    pub source_map: SourceMap,
+    /// to line/column positions. Use [`SourceMap::line_col`] or
+    /// [`SourceMap::span_to_line_col`] to convert, [`SourceMap::line_span`]
+    /// and [`SourceMap::snippet`] to get at the text.
+    pub source_map: SourceMap<'src>,
 }
 
 /// Parse PHP `source` using the latest supported PHP version (currently 8.5).
@@ -127,12 +226,16 @@ pub fn parse<'arena, 'src>(
     let mut parser = parser::Parser::new(arena, source);
     let program = parser.parse_program();
     let errors_truncated = parser.errors_truncated();
+    let timed_out = parser.timed_out();
+    let truncated = parser.truncated();
     ParseResult {
         source,
         program,
         comments: parser.take_comments(),
         errors: parser.into_errors(),
         errors_truncated,
+        timed_out,
+        truncated,
         source_map: SourceMap::new(source),
     }
 }
@@ -147,15 +250,300 @@ pub fn parse_versioned<'arena, 'src>(
     source: &'src str,
     version: PhpVersion,
 ) -> ParseResult<'arena, 'src> {
-    let mut parser = parser::Parser::with_version(arena, source, version);
+    parse_with_options(
+        arena,
+        source,
+        &ParserOptions {
+            version,
+            ..ParserOptions::default()
+        },
+    )
+}
+
+/// Parse `source` with the given [`ParserOptions`]: a target PHP version and
+/// optional resource limits for untrusted input.
+///
+/// A source over the file size or token limit is not parsed; the result has
+/// an empty program and one [`diagnostics::ParseError::LimitExceeded`].
+pub fn parse_with_options<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    options: &ParserOptions,
+) -> ParseResult<'arena, 'src> {
+    into_result(parser::Parser::with_options(arena, source, options), source)
+}
+
+fn into_result<'arena, 'src>(
+    mut parser: parser::Parser<'arena, 'src>,
+    source: &'src str,
+) -> ParseResult<'arena, 'src> {
     let program = parser.parse_program();
     let errors_truncated = parser.errors_truncated();
+    let timed_out = parser.timed_out();
+    let truncated = parser.truncated();
     ParseResult {
         source,
         program,
         comments: parser.take_comments(),
         errors: parser.into_errors(),
         errors_truncated,
+        timed_out,
+        truncated,
+        source_map: SourceMap::new(source),
+    }
+}
+
+/// Parse PHP `source` given as bytes, using the latest supported PHP version
+/// (currently 8.5).
+///
+/// For files that are not valid UTF-8, such as legacy Latin-1 code: the
+/// source is decoded without loss as described in the [`bytes`] module, and
+/// [`ParseResult::source`] is the decoded text that spans refer to. Valid
+/// UTF-8 is parsed in place, exactly as by [`parse`].
+pub fn parse_bytes<'arena>(
+    arena: &'arena bumpalo::Bump,
+    source: &'arena [u8],
+) -> ParseResult<'arena, 'arena> {
+    parse_bytes_with_options(arena, source, &ParserOptions::default())
+}
+
+/// [`parse_bytes`] with the given [`ParserOptions`].
+///
+/// [`ParserOptions::max_file_size`] applies to the length of `source` in
+/// bytes, before decoding; a source over it is rejected without being
+/// decoded, and the result's `source` is empty. The other limits apply to the
+/// decoded text, in which each byte that is not valid UTF-8 takes four bytes.
+///
+/// Spans, and offsets in errors, refer to [`ParseResult::source`], the
+/// decoded text. Map them to offsets into `source` with
+/// [`bytes::ByteOffsets`].
+pub fn parse_bytes_with_options<'arena>(
+    arena: &'arena bumpalo::Bump,
+    source: &'arena [u8],
+    options: &ParserOptions,
+) -> ParseResult<'arena, 'arena> {
+    if let Some(error) = parser::file_size_error(source.len(), options) {
+        return into_result(
+            parser::Parser::rejected(arena, "", options.version, error),
+            "",
+        );
+    }
+    let source: &'arena str = match bytes::decode(source) {
+        Cow::Borrowed(text) => text,
+        Cow::Owned(text) => arena.alloc_str(&text),
+    };
+    let options = ParserOptions {
+        max_file_size: None,
+        ..*options
+    };
+    parse_with_options(arena, source, &options)
+}
+
+/// Parse `source`, giving up once `timeout` has elapsed.
+///
+/// For services that must not let one adversarial file stall a worker. When
+/// the time runs out, the result holds the statements parsed so far,
+/// [`ParseResult::timed_out`] is set, and `errors` ends with a
+/// [`diagnostics::ParseError::TimedOut`] at the point where parsing stopped.
+/// Shorthand for [`parse_with_options`] with [`ParserOptions::timeout`] set.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_with_deadline(&arena, "<?php echo 1;", Duration::from_secs(1));
+/// assert!(!result.timed_out);
+///
+/// let src = format!("<?php {}", "echo 1;\n".repeat(10_000));
+/// let result = php_rs_parser::parse_with_deadline(&arena, &src, Duration::ZERO);
+/// assert!(result.timed_out);
+/// assert_eq!(result.errors.len(), 1);
+/// ```
+pub fn parse_with_deadline<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    timeout: Duration,
+) -> ParseResult<'arena, 'src> {
+    parse_with_options(
+        arena,
+        source,
+        &ParserOptions {
+            timeout: Some(timeout),
+            ..ParserOptions::default()
+        },
+    )
+}
+
+/// Parse the PHP islands of a host document using the latest supported PHP
+/// version (currently 8.5).
+///
+/// For templates and other documents that embed PHP: `islands` are the byte
+/// ranges of `source` holding PHP code, without `<?php` tags, in order and
+/// not overlapping. They parse as one program, so control flow may open in
+/// one island and close in a later one; host text between them is left out.
+/// Spans in the result, errors included, are offsets into `source`. See
+/// [`parser::Parser::new_islands`].
+///
+/// ```
+/// use php_ast::{Span, StmtKind};
+///
+/// let host = "<ul>{% foreach ($rows as $row): %}<li>{% endforeach %}</ul>";
+/// let islands: Vec<Span> = ["foreach ($rows as $row):", "endforeach"]
+///     .iter()
+///     .map(|code| {
+///         let start = host.find(code).unwrap() as u32;
+///         Span::new(start, start + code.len() as u32)
+///     })
+///     .collect();
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_islands(&arena, host, &islands);
+/// assert!(result.errors.is_empty());
+/// assert!(matches!(result.program.stmts[0].kind, StmtKind::Foreach(_)));
+/// assert_eq!(result.program.span, Span::new(islands[0].start, islands[1].end));
+/// ```
+pub fn parse_islands<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    islands: &[Span],
+) -> ParseResult<'arena, 'src> {
+    parse_islands_versioned(arena, source, islands, PhpVersion::default())
+}
+
+/// [`parse_islands`] targeting the given PHP `version`.
+pub fn parse_islands_versioned<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    islands: &[Span],
+    version: PhpVersion,
+) -> ParseResult<'arena, 'src> {
+    let mut parser = parser::Parser::new_islands(arena, source, islands, version);
+    let program = parser.parse_program();
+    let errors_truncated = parser.errors_truncated();
+    let timed_out = parser.timed_out();
+    let truncated = parser.truncated();
+    ParseResult {
+        source,
+        program,
+        comments: parser.take_comments(),
+        errors: parser.into_errors(),
+        errors_truncated,
+        timed_out,
+        truncated,
+        source_map: SourceMap::new(source),
+    }
+}
+
+/// The result of parsing a fragment of PHP code with [`parse_expr_str`] or
+/// [`parse_stmt_str`].
+#[non_exhaustive]
+pub struct FragmentResult<'src, T> {
+    /// The fragment's source text.
+    pub source: &'src str,
+    /// The parsed expression or statement. Always produced, even when errors
+    /// are present.
+    pub node: T,
+    /// Comments in the fragment, in source order, except doc-blocks attached
+    /// to a declaration (see [`ParseResult::comments`]).
+    pub comments: Vec<Comment<'src>>,
+    /// Parse errors and diagnostics in source order. Input left over after
+    /// the expression or statement is reported as an
+    /// [`diagnostics::ParseError::Expected`] "end of input".
+    pub errors: Vec<ParseError>,
+    /// `true` when the error list was capped at the internal limit.
+    pub errors_truncated: bool,
+    /// Line index for resolving spans, as in [`ParseResult::source_map`].
+    pub source_map: SourceMap<'src>,
+}
+
+/// Parse `source` as a single PHP expression, using the latest supported PHP
+/// version (currently 8.5).
+///
+/// For template engines, REPLs, and other tools holding a fragment of code
+/// rather than a file: the source is PHP code from its first byte, with no
+/// `<?php` tag, and must hold exactly one expression. Spans are offsets into
+/// `source`.
+///
+/// ```
+/// use php_ast::{BinaryOp, ExprKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_expr_str(&arena, "1 + $x * foo()");
+/// assert!(result.errors.is_empty());
+/// let ExprKind::Binary(binary) = &result.node.kind else { panic!() };
+/// assert_eq!(binary.op, BinaryOp::Add);
+///
+/// let result = php_rs_parser::parse_expr_str(&arena, "$a $b");
+/// assert_eq!(result.errors.len(), 1);
+/// ```
+pub fn parse_expr_str<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+) -> FragmentResult<'src, Expr<'arena, 'src>> {
+    parse_expr_str_versioned(arena, source, PhpVersion::default())
+}
+
+/// [`parse_expr_str`] targeting the given PHP `version`.
+pub fn parse_expr_str_versioned<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    version: PhpVersion,
+) -> FragmentResult<'src, Expr<'arena, 'src>> {
+    parse_fragment(arena, source, version, |p| p.parse_expr())
+}
+
+/// Parse `source` as a single PHP statement, using the latest supported PHP
+/// version (currently 8.5).
+///
+/// Like [`parse_expr_str`], the source has no `<?php` tag. The statement may
+/// be a declaration or a block, and ends with its `;` as it would in a file.
+///
+/// ```
+/// use php_ast::StmtKind;
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_stmt_str(&arena, "foreach ($rows as $row) { echo $row; }");
+/// assert!(result.errors.is_empty());
+/// assert!(matches!(result.node.kind, StmtKind::Foreach(_)));
+/// ```
+pub fn parse_stmt_str<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+) -> FragmentResult<'src, Stmt<'arena, 'src>> {
+    parse_stmt_str_versioned(arena, source, PhpVersion::default())
+}
+
+/// [`parse_stmt_str`] targeting the given PHP `version`.
+pub fn parse_stmt_str_versioned<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    version: PhpVersion,
+) -> FragmentResult<'src, Stmt<'arena, 'src>> {
+    parse_fragment(arena, source, version, |p| p.parse_stmt())
+}
+
+fn parse_fragment<'arena, 'src: 'arena, T>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    version: PhpVersion,
+    parse: impl FnOnce(&mut parser::Parser<'arena, 'src>) -> T,
+) -> FragmentResult<'src, T> {
+    let mut parser = parser::Parser::new_at(arena, source, 0, version);
+    let node = parse(&mut parser);
+    if !parser.check(parser::TokenKind::Eof) {
+        parser.error(ParseError::Expected {
+            expected: "end of input".into(),
+            found: parser.current_kind(),
+            span: parser.current_span(),
+        });
+    }
+    let errors_truncated = parser.errors_truncated();
+    FragmentResult {
+        source,
+        node,
+        comments: parser.take_comments(),
+        errors: parser.into_errors(),
+        errors_truncated,
         source_map: SourceMap::new(source),
     }
 }
@@ -221,6 +609,18 @@ impl ParserContext {
         self.arena.reset();
         parse_versioned(&self.arena, source, version)
     }
+
+    /// Reset the arena and parse `source` with the given [`ParserOptions`].
+    ///
+    /// See [`reparse`](ParserContext::reparse) for lifetime safety notes.
+    pub fn reparse_with_options<'a, 'src>(
+        &'a mut self,
+        source: &'src str,
+        options: &ParserOptions,
+    ) -> ParseResult<'a, 'src> {
+        self.arena.reset();
+        parse_with_options(&self.arena, source, options)
+    }
 }
 
 impl Default for ParserContext {
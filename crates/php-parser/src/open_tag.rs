@@ -0,0 +1,121 @@
+//! Files that are PHP code but do not open as PHP.
+//!
+//! Everything before the first `<?php` is inline HTML, so a file whose open
+//! tag is missing or mistyped parses as a single
+//! [`InlineHtml`](php_ast::StmtKind::InlineHtml) statement, and PHP prints it
+//! instead of running it. That is valid PHP, so the AST stays as it is; the
+//! checks here add a warning with a fix when the text is clearly code.
+
+use php_ast::Span;
+
+use crate::diagnostics::ParseError;
+
+/// A warning for a file without any open tag whose text starts like PHP:
+/// a mistyped tag such as `<? php` or `?php`, or, with no tag at all, a
+/// `namespace`, `declare`, or `use` statement. A leading `#!` line is
+/// skipped. `None` when the text does not look like code.
+pub(crate) fn missing_open_tag(source: &str) -> Option<ParseError> {
+    let mut start = 0;
+    if source.starts_with("#!") {
+        start = source.find('\n').map_or(source.len(), |i| i + 1);
+    }
+    start += source[start..].len() - source[start..].trim_start().len();
+    let rest = &source[start..];
+
+    if let Some(len) = mistyped_tag(rest) {
+        return Some(ParseError::MisspelledOpenTag {
+            found: rest[..len].to_string().into(),
+            span: Span::new(start as u32, (start + len) as u32),
+        });
+    }
+
+    let line = rest.lines().next().unwrap_or("").trim_end();
+    let keyword_len = line.bytes().take_while(|b| b.is_ascii_alphabetic()).count();
+    let (keyword, tail) = line.split_at(keyword_len);
+    let is_code = match keyword.to_ascii_lowercase().as_str() {
+        "namespace" => namespace_tail(tail),
+        "declare" => declare_tail(tail),
+        "use" => use_tail(tail),
+        _ => false,
+    };
+    is_code.then(|| ParseError::MissingOpenTag {
+        span: Span::new(start as u32, (start + keyword_len) as u32),
+    })
+}
+
+/// A warning for an open tag spelled other than `<?php`, such as `<?PHP`.
+/// `span` covers the tag's `<?php` or `<?=` token.
+pub(crate) fn open_tag_case(source: &str, span: Span) -> Option<ParseError> {
+    let tag = &source[span.start as usize..span.end as usize];
+    (tag.len() == 5 && tag != "<?php").then(|| ParseError::MisspelledOpenTag {
+        found: tag.to_string().into(),
+        span,
+    })
+}
+
+/// The length of a mistyped `<?php` at the start of `text`: a space inside
+/// it (`<? php`, `< ?php`) or a missing `<` (`?php`), followed by whitespace.
+fn mistyped_tag(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let skip_blanks = |mut i: usize| {
+        while matches!(bytes.get(i), Some(b' ' | b'\t')) {
+            i += 1;
+        }
+        i
+    };
+    let mut i = 0;
+    if bytes.first() == Some(&b'<') {
+        i = skip_blanks(1);
+    }
+    if bytes.get(i) != Some(&b'?') {
+        return None;
+    }
+    i = skip_blanks(i + 1);
+    let end = i + 3;
+    let is_php = bytes
+        .get(i..end)
+        .is_some_and(|word| word.eq_ignore_ascii_case(b"php"));
+    // `<?php` itself would have been lexed as an open tag.
+    let followed_by_space = bytes.get(end).is_none_or(u8::is_ascii_whitespace);
+    (is_php && followed_by_space).then_some(end)
+}
+
+/// `namespace App;`, `namespace App {`, or `namespace {`.
+fn namespace_tail(tail: &str) -> bool {
+    let spaced = tail.starts_with([' ', '\t']);
+    let tail = tail.trim_start();
+    let name_len = tail
+        .bytes()
+        .take_while(|&b| is_name_byte(b) || b == b'\\')
+        .count();
+    let (name, end) = tail.split_at(name_len);
+    match end.trim() {
+        ";" => spaced && !name.is_empty(),
+        "{" => name.is_empty() || spaced,
+        _ => false,
+    }
+}
+
+/// `declare(strict_types=1);` and other directives, up to `;`, `{`, or `:`.
+fn declare_tail(tail: &str) -> bool {
+    let tail = tail.trim_start();
+    tail.starts_with('(') && tail.ends_with([';', '{', ':']) && tail.contains(')')
+}
+
+/// `use Model;`, `use App\Model;`, `use function App\f;`, or a group use on
+/// one line. Words without a namespace separator read as prose, as in
+/// `use it wisely;`, unless there is just one.
+fn use_tail(tail: &str) -> bool {
+    let Some(body) = tail.strip_suffix(';') else {
+        return false;
+    };
+    let names_only = body
+        .bytes()
+        .all(|b| is_name_byte(b) || matches!(b, b'\\' | b' ' | b'\t' | b',' | b'{' | b'}'));
+    let qualified = body.contains('\\') || body.split_whitespace().count() == 1;
+    tail.starts_with([' ', '\t']) && names_only && qualified
+}
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
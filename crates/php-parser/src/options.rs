@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::version::PhpVersion;
+
+/// Configuration for [`crate::parse_with_options`].
+///
+/// Every limit defaults to `None` (unbounded). Services that parse untrusted
+/// input can set them to bound the work done per file:
+///
+/// - a source larger than `max_file_size` bytes, or one that lexes to more
+///   than `max_tokens` tokens, is rejected before parsing: the result holds an
+///   empty program and a single [`ParseError::LimitExceeded`];
+/// - a string literal longer than `max_string_length` bytes, or a name longer
+///   than `max_identifier_length` bytes, is reported at its span and parsing
+///   continues;
+/// - once `timeout` has elapsed, parsing stops: the result holds the program
+///   parsed so far and a [`ParseError::TimedOut`];
+/// - once `max_errors` errors have been reported, parsing stops: the result
+///   holds the program parsed so far and [`ParseResult::truncated`] is set.
+///
+/// ```
+/// use php_rs_parser::ParserOptions;
+///
+/// let options = ParserOptions {
+///     max_file_size: Some(16),
+///     ..ParserOptions::default()
+/// };
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse_with_options(&arena, "<?php echo 'too long';", &options);
+/// assert!(result.program.stmts.is_empty());
+/// assert_eq!(
+///     result.errors[0].to_string(),
+///     "file size of 22 bytes exceeds the configured maximum of 16 bytes",
+/// );
+/// ```
+///
+/// [`ParseError::LimitExceeded`]: crate::diagnostics::ParseError::LimitExceeded
+/// [`ParseError::TimedOut`]: crate::diagnostics::ParseError::TimedOut
+/// [`ParseResult::truncated`]: crate::ParseResult::truncated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// PHP version to target; see [`crate::parse_versioned`].
+    pub version: PhpVersion,
+    /// Maximum source length in bytes.
+    pub max_file_size: Option<usize>,
+    /// Maximum number of tokens, comments included.
+    pub max_tokens: Option<usize>,
+    /// Maximum length in bytes of one string literal token, quotes and
+    /// heredoc labels included.
+    pub max_string_length: Option<usize>,
+    /// Maximum length in bytes of an identifier or variable name, without `$`.
+    pub max_identifier_length: Option<usize>,
+    /// Maximum wall-clock time for lexing and parsing, measured from the
+    /// start of the parse. The clock is read once lexing is done and then
+    /// every 1024 tokens, so a parse may overrun by the time those take.
+    /// Reading it needs
+    /// [`std::time::Instant`], which panics on `wasm32-unknown-unknown`.
+    pub timeout: Option<Duration>,
+    /// Maximum number of errors, warnings not counted. Parsing stops at the
+    /// token after the last one, so a file of cascading errors does not
+    /// build up an error list and AST for all of it. Without this, errors
+    /// past an internal cap of 100 are dropped and parsing goes on to the
+    /// end; see [`crate::ParseResult::errors_truncated`]. `Some(0)` counts
+    /// as `Some(1)`.
+    pub max_errors: Option<usize>,
+    /// Recover from a `;` missing at a line break before a token that plainly
+    /// starts the next statement, as in `$x = 1⏎$y = 2;`: report
+    /// [`ParseError::MissingSemicolon`] with an insert-`;` fix-it and parse on
+    /// as if the `;` were there, keeping both statements. Not done inside an
+    /// open `(` or `[`. Off by default, where the error is reported at the
+    /// unexpected token instead.
+    ///
+    /// [`ParseError::MissingSemicolon`]: crate::diagnostics::ParseError::MissingSemicolon
+    pub infer_semicolons: bool,
+}
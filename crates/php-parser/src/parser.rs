@@ -1,14 +1,67 @@
+//! The recursive-descent parser, exposed for embedders.
+//!
+//! [`crate::parse`] and its siblings cover ordinary PHP files. Front-ends
+//! that embed PHP fragments in another syntax — template languages, DSLs,
+//! PHP-like dialects — can drive a [`Parser`] directly: position it with
+//! [`Parser::new_at`], look at tokens with [`check`](Parser::check) and
+//! [`current_kind`](Parser::current_kind), consume them with
+//! [`eat`](Parser::eat) and [`expect`](Parser::expect), and hand off to the
+//! PHP grammar for names, types, attributes, expressions, and statements.
+//!
+//! ```
+//! use php_rs_parser::parser::{Parser, TokenKind};
+//!
+//! // A template tag `{{ type App\User|null }}` whose body is a PHP type.
+//! let arena = bumpalo::Bump::new();
+//! let src = "{{ type App\\User|null }}";
+//! let mut parser = Parser::new_at(&arena, src, 2, Default::default());
+//! assert_eq!(parser.current_text(), "type");
+//! parser.advance();
+//! let ty = parser.parse_type_hint();
+//! assert_eq!(&src[ty.span.start as usize..ty.span.end as usize], "App\\User|null");
+//! parser.expect(TokenKind::RightBrace);
+//! parser.expect(TokenKind::RightBrace);
+//! assert!(parser.check(TokenKind::Eof));
+//! assert!(parser.into_errors().is_empty());
+//! ```
+//!
+//! # Stability
+//!
+//! The documented methods of [`Parser`] follow the crate's semantic
+//! versioning: construction (`new`, `with_version`, `with_options`,
+//! `new_at`, `new_islands`), accessors (`source`, `arena`, `version`),
+//! arena allocation (`alloc`, `alloc_vec`, `alloc_vec_with_capacity`,
+//! `alloc_vec_one`), token navigation (`current_*`, `peek*`, `advance`,
+//! `check`, `eat`, `eat_identifier_or_keyword`, `expect`, `expect_semicolon`,
+//! `expect_closing`, `previous_end`, `start_span`, `variable_*`), error
+//! reporting (`error`, `error_with`, `require_feature`, `into_errors`,
+//! `errors_truncated`, `timed_out`, `truncated`), comment collection
+//! (`take_comments`), and the grammar entry points (`parse_program`,
+//! `parse_stmt`, `parse_expr`, `parse_name`, `parse_type_hint`,
+//! `parse_attributes`). Recovery helpers and parser state are hidden from
+//! the documentation and may change in any release.
+//!
+//! Tokens are the [`php_lexer`] ones, re-exported here as [`Token`] and
+//! [`TokenKind`]. The AST produced is the same as for [`crate::parse`]; spans
+//! are byte offsets into the whole source passed to the constructor.
+
+use std::time::Instant;
+
 use php_ast::*;
-use php_lexer::{Lexer, LexerError, LexerErrorKind, Token, TokenKind};
+use php_lexer::{Lexer, LexerError, LexerErrorKind};
+pub use php_lexer::{Token, TokenKind};
 
-use crate::diagnostics::ParseError;
+use crate::diagnostics::{Limit, ParseError, Severity};
 use crate::expr;
 use crate::instrument;
+use crate::options::ParserOptions;
 use crate::stmt;
-use crate::version::PhpVersion;
+use crate::version::{Feature, PhpVersion};
 
 const MAX_ERRORS: usize = 100;
 pub(crate) const MAX_DEPTH: u32 = 50;
+/// The deadline is checked each time this many tokens have been consumed.
+const DEADLINE_CHECK_INTERVAL: usize = 1024;
 
 fn comment_kind(kind: TokenKind) -> CommentKind {
     match kind {
@@ -34,13 +87,182 @@ fn lex_error_to_parse_error(e: LexerError) -> ParseError {
     }
 }
 
+/// A name hashed and compared ignoring ASCII case, as PHP compares method
+/// and constant names, without lowercasing it into a new string.
+#[derive(Clone, Copy)]
+pub(crate) struct CaseInsensitive<'src>(pub(crate) &'src str);
+
+impl std::hash::Hash for CaseInsensitive<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for byte in self.0.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl PartialEq for CaseInsensitive<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+}
+
+impl Eq for CaseInsensitive<'_> {}
+
+/// Move the items of `scratch` from `base` on into an arena vector of
+/// exactly their number.
+fn drain_into_arena<'arena, T>(
+    arena: &'arena bumpalo::Bump,
+    scratch: &mut Vec<T>,
+    base: usize,
+) -> ArenaVec<'arena, T> {
+    let base = base.min(scratch.len());
+    let mut items = ArenaVec::with_capacity_in(scratch.len() - base, arena);
+    for item in scratch.drain(base..) {
+        items.push(item);
+    }
+    items
+}
+
+/// The error for a source of `len` bytes over the file size limit.
+pub(crate) fn file_size_error(len: usize, options: &ParserOptions) -> Option<ParseError> {
+    let max = options.max_file_size.filter(|&max| len > max)?;
+    Some(ParseError::LimitExceeded {
+        limit: Limit::FileSize,
+        max,
+        actual: len,
+        span: Span::new(0, 0),
+    })
+}
+
+/// Lex `source`, or report the first file size or token limit it exceeds.
+fn lex_within_limits(
+    source: &str,
+    options: &ParserOptions,
+) -> Result<(Vec<Token>, Vec<LexerError>), ParseError> {
+    if let Some(error) = file_size_error(source.len(), options) {
+        return Err(error);
+    }
+    let Some(max) = options.max_tokens else {
+        return Ok(php_lexer::lex_all(source));
+    };
+    if source.len() > u32::MAX as usize {
+        return Ok(php_lexer::lex_all(source));
+    }
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    loop {
+        let tok = lexer.next_token();
+        if tok.kind == TokenKind::Eof {
+            tokens.push(tok);
+            tokens.push(tok);
+            return Ok((tokens, lexer.errors));
+        }
+        if tokens.len() == max {
+            // Count the rest without storing it, so the report is exact.
+            let mut actual = max + 1;
+            while lexer.next_token().kind != TokenKind::Eof {
+                actual += 1;
+            }
+            return Err(ParseError::LimitExceeded {
+                limit: Limit::Tokens,
+                max,
+                actual,
+                span: tok.span,
+            });
+        }
+        tokens.push(tok);
+    }
+}
+
+/// Report string literals and names longer than the configured maximums.
+fn length_limit_errors(source: &str, tokens: &[Token], options: &ParserOptions) -> Vec<ParseError> {
+    if options.max_string_length.is_none() && options.max_identifier_length.is_none() {
+        return Vec::new();
+    }
+    let mut errors = Vec::new();
+    for tok in tokens {
+        let (limit, max, actual) = match tok.kind {
+            TokenKind::SingleQuotedString
+            | TokenKind::DoubleQuotedString
+            | TokenKind::BacktickString
+            | TokenKind::Heredoc
+            | TokenKind::Nowdoc => match options.max_string_length {
+                Some(max) => (Limit::StringLength, max, tok.span.len() as usize),
+                None => continue,
+            },
+            TokenKind::Identifier | TokenKind::Variable => match options.max_identifier_length {
+                Some(max) => {
+                    let text = &source[tok.span.to_range()];
+                    let name = text.strip_prefix('$').unwrap_or(text);
+                    (Limit::IdentifierLength, max, name.len())
+                }
+                None => continue,
+            },
+            _ => continue,
+        };
+        if actual > max {
+            errors.push(ParseError::LimitExceeded {
+                limit,
+                max,
+                actual,
+                span: tok.span,
+            });
+        }
+    }
+    errors
+}
+
+/// A construct the parser is in the middle of, for diagnostics.
+///
+/// The innermost one is named in [`ParseError::ExpectedExpression`]. The stack
+/// is cleared at each statement so the context of an enclosing expression does
+/// not leak into, say, a closure body passed as an argument.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ParseContext {
+    /// The 1-based `position`th argument of a call. `callee` spans the called
+    /// name when it is written out.
+    Argument {
+        position: usize,
+        callee: Option<Span>,
+    },
+    /// The 1-based `position`th element of an array literal.
+    ArrayElement { position: usize },
+    /// The parenthesized condition of the named statement (`if`, `while`, …).
+    Condition(&'static str),
+    /// The right-hand side of an assignment.
+    AssignedValue,
+    /// The value of a `return` statement.
+    ReturnValue,
+}
+
+impl ParseContext {
+    fn describe(self, source: &str) -> String {
+        match self {
+            ParseContext::Argument {
+                position,
+                callee: Some(callee),
+            } => format!(
+                "argument {position} of call to {}",
+                &source[callee.start as usize..callee.end as usize]
+            ),
+            ParseContext::Argument { position, .. } => format!("argument {position} of call"),
+            ParseContext::ArrayElement { position } => format!("array element {position}"),
+            ParseContext::Condition(keyword) => format!("{keyword} condition"),
+            ParseContext::AssignedValue => "assigned value".to_string(),
+            ParseContext::ReturnValue => "return value".to_string(),
+        }
+    }
+}
+
+/// A PHP parser over one pre-lexed source. See the [module docs](self).
 pub struct Parser<'arena, 'src> {
     current: Token,
     /// End offset of the most recently consumed token.
     /// Updated on every `advance()`, used for precise span construction.
     previous_end: u32,
     /// Block nesting depth (0 = top-level scope)
-    pub depth: u32,
+    pub(crate) depth: u32,
     /// Expression nesting depth — guards against stack overflow on deeply nested input
     pub(crate) expr_depth: u32,
     /// Loop/switch nesting depth — tracks valid break/continue targets.
@@ -52,16 +274,33 @@ pub struct Parser<'arena, 'src> {
     /// True only when parsing the parameter list of a `__construct` method.
     /// Used to reject `readonly` parameters outside constructors.
     pub(crate) in_constructor: bool,
+    /// Set when a catch type is `self` or `parent`, so the program-level
+    /// scope check only walks files that need it.
+    pub(crate) catch_class_keywords: bool,
+    /// Number of `(void)` casts parsed so far, so an expression statement
+    /// only walks its tree for a misused one when it contains any.
+    pub(crate) void_casts: u32,
+    /// `{` tokens consumed and not yet closed by a `}`.
+    pub(crate) open_braces: u32,
+    /// See [`ParserOptions::infer_semicolons`].
+    infer_semicolons: bool,
+    /// `(`, `[`, `#[`, and `{` tokens consumed and not yet closed, innermost
+    /// last. Tracked only with `infer_semicolons`.
+    pub(crate) delimiters: Vec<TokenKind>,
+    /// Tokens that end each alternative-syntax block being parsed (`endif`,
+    /// `else`, …), innermost last, so a block missing its own `end*` keyword
+    /// can stop at one that belongs to an enclosing block.
+    pub(crate) block_ends: Vec<&'static [TokenKind]>,
     tokens: Vec<Token>,
     /// Index of NEXT token in the tokens array (current = tokens[pos - 1])
     pos: usize,
-    pub arena: &'arena bumpalo::Bump,
-    pub source: &'src str,
+    pub(crate) arena: &'arena bumpalo::Bump,
+    pub(crate) source: &'src str,
     errors: Vec<ParseError>,
     /// All comments found in the source, collected during lexing.
     comments: Vec<Comment<'src>>,
     /// PHP version being targeted — used for version-specific error reporting.
-    pub version: PhpVersion,
+    pub(crate) version: PhpVersion,
     /// When true, the `{` curly-brace subscript operator is suppressed in the Pratt loop.
     /// Used when parsing property/parameter default values so that a following hook block
     /// `{ get => ...; }` is not consumed as part of the default expression.
@@ -69,6 +308,34 @@ pub struct Parser<'arena, 'src> {
     /// Position after the most recent `}` at this or outer scope depth.
     /// Prevents doc comments inside closed scopes from leaking to outer statements.
     last_scope_close: u32,
+    /// Constructs being parsed, innermost last. Those of the current
+    /// statement start at `context_base`; the ones below belong to the
+    /// statements it is nested in.
+    contexts: Vec<ParseContext>,
+    context_base: usize,
+    /// When set, parsing stops at the first check past this instant.
+    deadline: Option<Instant>,
+    /// Set once the deadline has passed; see [`timed_out`](Self::timed_out).
+    timed_out: bool,
+    /// Errors kept before further ones are dropped: the internal cap, or
+    /// [`ParserOptions::max_errors`] when that is larger.
+    error_cap: usize,
+    /// See [`ParserOptions::max_errors`].
+    max_errors: Option<usize>,
+    /// Errors reported so far, warnings not included.
+    error_count: usize,
+    /// Set once `max_errors` errors have been reported; see
+    /// [`truncated`](Self::truncated).
+    truncated: bool,
+    /// Set with `truncated` until the next token is consumed, where parsing
+    /// then stops.
+    stop_pending: bool,
+    /// Scratch stacks for argument and parameter lists. A list pushes its
+    /// items above those of the lists it is nested in and moves them into an
+    /// exactly sized arena vector when it ends, so the buffers are reused
+    /// across the whole file instead of growing a vector in the arena per list.
+    scratch_args: Vec<Arg<'arena, 'src>>,
+    scratch_params: Vec<Param<'arena, 'src>>,
 }
 
 impl<'arena, 'src> Parser<'arena, 'src> {
@@ -83,32 +350,96 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         source: &'src str,
         version: PhpVersion,
     ) -> Self {
-        let (all_tokens, lex_errors) = php_lexer::lex_all(source);
+        Self::with_options(
+            arena,
+            source,
+            &ParserOptions {
+                version,
+                ..ParserOptions::default()
+            },
+        )
+    }
+
+    /// Create a parser with the given version and resource limits. A source
+    /// over the file size or token limit yields a parser over an empty token
+    /// stream whose only error is the [`ParseError::LimitExceeded`].
+    pub fn with_options(
+        arena: &'arena bumpalo::Bump,
+        source: &'src str,
+        options: &ParserOptions,
+    ) -> Self {
+        let started = options.timeout.map(|_| Instant::now());
+        let (all_tokens, lex_errors) = match lex_within_limits(source, options) {
+            Ok(lexed) => lexed,
+            Err(limit_error) => return Self::rejected(arena, source, options.version, limit_error),
+        };
 
-        // Separate comment tokens from the main token stream.
+        // Separate comment tokens from the main token stream, in place.
         // lex_all appends two Eof sentinels; they pass through the filter unchanged.
         let mut comments: Vec<Comment<'src>> = Vec::new();
-        let mut tokens: Vec<Token> = Vec::with_capacity(all_tokens.len());
-        for tok in all_tokens {
-            if tok.kind.is_comment() {
-                let text = &source[tok.span.start as usize..tok.span.end as usize];
-                comments.push(Comment {
-                    kind: comment_kind(tok.kind),
-                    text,
-                    span: tok.span,
-                });
-            } else {
-                tokens.push(tok);
+        let mut tokens = all_tokens;
+        tokens.retain(|tok| {
+            if !tok.kind.is_comment() {
+                return true;
             }
-        }
-
-        // Seed current with the first token and pos with 1
-        let current = tokens.first().copied().unwrap_or_else(|| Token::eof(0));
+            comments.push(Comment {
+                kind: comment_kind(tok.kind),
+                text: &source[tok.span.start as usize..tok.span.end as usize],
+                span: tok.span,
+            });
+            false
+        });
 
         let mut errors: Vec<ParseError> = lex_errors
             .into_iter()
             .map(lex_error_to_parse_error)
             .collect();
+        errors.extend(length_limit_errors(source, &tokens, options));
+
+        let mut parser =
+            Self::from_tokens(arena, source, options.version, tokens, comments, errors);
+        if let Some(max_errors) = options.max_errors {
+            parser.set_max_errors(max_errors);
+        }
+        parser.infer_semicolons = options.infer_semicolons;
+        if let (Some(started), Some(timeout)) = (started, options.timeout) {
+            // A timeout too large to represent is no timeout at all.
+            parser.deadline = started.checked_add(timeout);
+            parser.check_deadline();
+        }
+        parser
+    }
+
+    /// A parser over an empty token stream whose only error is `error`.
+    pub(crate) fn rejected(
+        arena: &'arena bumpalo::Bump,
+        source: &'src str,
+        version: PhpVersion,
+        error: ParseError,
+    ) -> Self {
+        let eof = Token::eof(0);
+        let mut parser = Self::from_tokens(
+            arena,
+            source,
+            version,
+            vec![eof, eof],
+            Vec::new(),
+            Vec::new(),
+        );
+        parser.errors.push(error);
+        parser
+    }
+
+    fn from_tokens(
+        arena: &'arena bumpalo::Bump,
+        source: &'src str,
+        version: PhpVersion,
+        tokens: Vec<Token>,
+        comments: Vec<Comment<'src>>,
+        mut errors: Vec<ParseError>,
+    ) -> Self {
+        // Seed current with the first token and pos with 1
+        let current = tokens.first().copied().unwrap_or_else(|| Token::eof(0));
         errors.truncate(MAX_ERRORS);
 
         Self {
@@ -125,10 +456,86 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             loop_depth: 0,
             function_depth: 0,
             in_constructor: false,
+            catch_class_keywords: false,
+            void_casts: 0,
+            open_braces: 0,
+            infer_semicolons: false,
+            delimiters: Vec::new(),
+            block_ends: Vec::new(),
             version,
             no_brace_subscript: false,
             last_scope_close: 0,
+            contexts: Vec::new(),
+            context_base: 0,
+            deadline: None,
+            timed_out: false,
+            error_cap: MAX_ERRORS,
+            max_errors: None,
+            error_count: 0,
+            truncated: false,
+            stop_pending: false,
+            scratch_args: Vec::new(),
+            scratch_params: Vec::new(),
+        }
+    }
+
+    /// Create a parser over the PHP islands of a host document: `islands`
+    /// are byte ranges of `source`, in order and not overlapping, that hold
+    /// PHP code without `<?php` tags — the bodies of a template's tags, say.
+    ///
+    /// Each island is lexed on its own, and the parser reads them as one file
+    /// with a zero-width `<?php` before and `?>` after each. A statement can thus span islands (`foreach ($rows as
+    /// $row):` in one, `endforeach;` in a later one), and the last statement
+    /// of an island needs no `;`. Host text between islands is not part of
+    /// the program. Spans are byte offsets into `source`.
+    pub fn new_islands(
+        arena: &'arena bumpalo::Bump,
+        source: &'src str,
+        islands: &[Span],
+        version: PhpVersion,
+    ) -> Self {
+        let mut tokens = Vec::new();
+        let mut comments: Vec<Comment<'src>> = Vec::new();
+        let mut errors = Vec::new();
+        let mut previous_end = 0;
+        for island in islands {
+            let end = (island.end as usize).clamp(previous_end, source.len());
+            let start = (island.start as usize).clamp(previous_end, end);
+            tokens.push(Token::new(
+                TokenKind::OpenTag,
+                Span::new(start as u32, start as u32),
+            ));
+
+            let mut lexer = Lexer::new_at(&source[..end], start);
+            loop {
+                let tok = lexer.next_token();
+                if tok.kind == TokenKind::Eof {
+                    break;
+                }
+                if tok.kind.is_comment() {
+                    let text = &source[tok.span.start as usize..tok.span.end as usize];
+                    comments.push(Comment {
+                        kind: comment_kind(tok.kind),
+                        text,
+                        span: tok.span,
+                    });
+                } else {
+                    tokens.push(tok);
+                }
+            }
+            errors.extend(lexer.errors.into_iter().map(lex_error_to_parse_error));
+            tokens.push(Token::new(
+                TokenKind::CloseTag,
+                Span::new(end as u32, end as u32),
+            ));
+            previous_end = end;
         }
+
+        // Two Eof sentinels for safe peek2
+        let eof = Token::eof(previous_end as u32);
+        tokens.push(eof);
+        tokens.push(eof);
+        Self::from_tokens(arena, source, version, tokens, comments, errors)
     }
 
     /// Create a parser starting in PHP mode at `offset` within `source`.
@@ -202,29 +609,89 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             loop_depth: 0,
             function_depth: 0,
             in_constructor: false,
+            catch_class_keywords: false,
+            void_casts: 0,
+            open_braces: 0,
+            infer_semicolons: false,
+            delimiters: Vec::new(),
+            block_ends: Vec::new(),
             version,
             no_brace_subscript: false,
             last_scope_close: 0,
+            contexts: Vec::new(),
+            context_base: 0,
+            deadline: None,
+            timed_out: false,
+            error_cap: MAX_ERRORS,
+            max_errors: None,
+            error_count: 0,
+            truncated: false,
+            stop_pending: false,
+            scratch_args: Vec::new(),
+            scratch_params: Vec::new(),
         }
     }
 
-    /// Emit a `VersionTooLow` error if the targeted PHP version is less than `min`.
-    /// Parsing always continues — the error is non-fatal.
-    pub fn require_version(&mut self, min: PhpVersion, feature: &'static str, span: Span) {
-        if self.version < min {
+    /// Emit a `VersionTooLow` error if the targeted PHP version predates
+    /// `feature`. Parsing always continues — the error is non-fatal.
+    pub fn require_feature(&mut self, feature: Feature, span: Span) {
+        if self.version < feature.required() {
             self.error(ParseError::VersionTooLow {
+                feature,
+                required: feature.required(),
+                used: self.version,
+                span,
+            });
+        }
+    }
+
+    /// Emit a `RemovedSyntax` diagnostic if the targeted PHP version is at
+    /// least `deprecated`; it is an error from `removed` on and a warning
+    /// before. Parsing always continues.
+    #[doc(hidden)]
+    pub fn removed_syntax(
+        &mut self,
+        feature: &'static str,
+        deprecated: PhpVersion,
+        removed: PhpVersion,
+        span: Span,
+    ) {
+        if self.version >= deprecated {
+            self.error(ParseError::RemovedSyntax {
                 feature: feature.into(),
-                required: min.to_string().into(),
-                used: self.version.to_string().into(),
+                deprecated,
+                removed,
+                used: self.version,
                 span,
             });
         }
     }
 
+    /// The source text passed to the constructor.
     pub fn source(&self) -> &'src str {
         self.source
     }
 
+    /// The arena AST nodes are allocated in.
+    pub fn arena(&self) -> &'arena bumpalo::Bump {
+        self.arena
+    }
+
+    /// The PHP version being targeted.
+    pub fn version(&self) -> PhpVersion {
+        self.version
+    }
+
+    /// Parse one expression at the current position.
+    pub fn parse_expr(&mut self) -> Expr<'arena, 'src> {
+        expr::parse_expr(self)
+    }
+
+    /// Parse one statement at the current position, including declarations.
+    pub fn parse_stmt(&mut self) -> Stmt<'arena, 'src> {
+        stmt::parse_stmt(self)
+    }
+
     // =========================================================================
     // Arena helpers
     // =========================================================================
@@ -241,6 +708,32 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     pub fn alloc_vec_with_capacity<T>(&self, cap: usize) -> ArenaVec<'arena, T> {
         ArenaVec::with_capacity_in(cap, self.arena)
     }
+    /// Start an argument list on the scratch stack; returns the base to pass
+    /// to [`push_arg`](Self::push_arg) and [`finish_args`](Self::finish_args).
+    #[inline]
+    pub(crate) fn start_args(&self) -> usize {
+        self.scratch_args.len()
+    }
+    #[inline]
+    pub(crate) fn push_arg(&mut self, arg: Arg<'arena, 'src>) {
+        self.scratch_args.push(arg);
+    }
+    /// The arguments pushed since `base`, moved into the arena.
+    pub(crate) fn finish_args(&mut self, base: usize) -> ArenaVec<'arena, Arg<'arena, 'src>> {
+        drain_into_arena(self.arena, &mut self.scratch_args, base)
+    }
+    /// [`start_args`](Self::start_args) for parameter lists.
+    #[inline]
+    pub(crate) fn start_params(&self) -> usize {
+        self.scratch_params.len()
+    }
+    #[inline]
+    pub(crate) fn push_param(&mut self, param: Param<'arena, 'src>) {
+        self.scratch_params.push(param);
+    }
+    pub(crate) fn finish_params(&mut self, base: usize) -> ArenaVec<'arena, Param<'arena, 'src>> {
+        drain_into_arena(self.arena, &mut self.scratch_params, base)
+    }
     #[inline]
     pub fn alloc_vec_one<T>(&self, val: T) -> ArenaVec<'arena, T> {
         let mut v = ArenaVec::with_capacity_in(1, self.arena);
@@ -275,14 +768,115 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     pub fn advance(&mut self) -> Token {
         let prev = self.current;
         self.previous_end = prev.span.end;
-        if prev.kind == TokenKind::RightBrace {
-            self.last_scope_close = prev.span.end;
+        match prev.kind {
+            TokenKind::LeftBrace => self.open_braces += 1,
+            TokenKind::RightBrace => {
+                self.last_scope_close = prev.span.end;
+                self.open_braces = self.open_braces.saturating_sub(1);
+            }
+            _ => {}
+        }
+        if self.infer_semicolons {
+            self.track_delimiter(prev.kind);
         }
         self.current = self.tokens[self.pos];
         self.pos += 1;
+        if self.stop_pending {
+            self.stop_pending = false;
+            self.skip_to_end();
+        }
+        if self.deadline.is_some() && self.pos.is_multiple_of(DEADLINE_CHECK_INTERVAL) {
+            self.check_deadline();
+        }
         prev
     }
 
+    /// Open or close a delimiter for consumed token `kind`. A closer with no
+    /// matching delimiter open is ignored; one that matches an outer
+    /// delimiter closes the unclosed ones inside it too.
+    fn track_delimiter(&mut self, kind: TokenKind) {
+        let closes = |open: TokenKind| match kind {
+            TokenKind::RightParen => open == TokenKind::LeftParen,
+            TokenKind::RightBracket => {
+                matches!(open, TokenKind::LeftBracket | TokenKind::HashBracket)
+            }
+            _ => open == TokenKind::LeftBrace,
+        };
+        match kind {
+            TokenKind::LeftParen
+            | TokenKind::LeftBracket
+            | TokenKind::HashBracket
+            | TokenKind::LeftBrace => self.delimiters.push(kind),
+            TokenKind::RightParen | TokenKind::RightBracket | TokenKind::RightBrace => {
+                if let Some(i) = self.delimiters.iter().rposition(|&open| closes(open)) {
+                    self.delimiters.truncate(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Stop parsing if the deadline has passed: report [`ParseError::TimedOut`]
+    /// at the current token and skip to the end of input, so every construct
+    /// in progress is closed as if the file ended there.
+    #[cold]
+    fn check_deadline(&mut self) {
+        let Some(deadline) = self.deadline else {
+            return;
+        };
+        if Instant::now() < deadline {
+            return;
+        }
+        self.deadline = None;
+        self.timed_out = true;
+        self.errors.push(ParseError::TimedOut {
+            span: Span::new(self.current.span.start, self.current.span.start),
+        });
+        self.skip_to_end();
+    }
+
+    /// Move to the end of input, so every construct in progress is closed as
+    /// if the file ended at the current token.
+    fn skip_to_end(&mut self) {
+        // The same position parsing reaches at the end of input: current on
+        // the first of the two Eof sentinels.
+        self.pos = self.tokens.len() - 1;
+        self.current = self.tokens[self.pos - 1];
+    }
+
+    /// `true` when parsing stopped early because the
+    /// [`ParserOptions::timeout`] elapsed.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Stop parsing once `max` errors have been reported; see
+    /// [`ParserOptions::max_errors`]. Errors found before parsing count too:
+    /// when there are enough of them, the rest are dropped and nothing is
+    /// parsed.
+    fn set_max_errors(&mut self, max: usize) {
+        let max = max.max(1);
+        self.max_errors = Some(max);
+        self.error_cap = MAX_ERRORS.max(max);
+        let mut count = 0;
+        let reached = self.errors.iter().position(|err| {
+            count += usize::from(err.severity() == Severity::Error);
+            count == max
+        });
+        self.error_count = count;
+        if let Some(last) = reached {
+            self.errors.truncate(last + 1);
+            self.truncated = true;
+            self.skip_to_end();
+        }
+    }
+
+    /// `true` when parsing stopped early because
+    /// [`ParserOptions::max_errors`] errors had been reported.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
     /// End offset of the most recently consumed token.
     /// Use this instead of `current_span().start` for precise span ends.
     #[inline]
@@ -307,7 +901,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         }
     }
 
-    /// Like [`variable_name`] but returns an [`Ident`] — empty/zero-length
+    /// Like [`variable_name`](Self::variable_name) but returns an [`Ident`] — empty/zero-length
     /// inputs yield [`Ident::ERROR`].
     #[inline]
     pub fn variable_ident(&self, token: Token) -> Ident<'src> {
@@ -342,7 +936,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             Some(self.advance())
         } else {
             self.error(ParseError::Expected {
-                expected: format!("{}", kind).into(),
+                expected: kind.label().into(),
                 found: self.current_kind(),
                 span: self.current_span(),
             });
@@ -361,16 +955,128 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         } else if self.check(TokenKind::CloseTag) {
             // `?>` acts as implicit semicolon — don't consume it
             None
+        } else if self.at_inferable_semicolon() {
+            let at = self.previous_end;
+            self.error_with(|| ParseError::MissingSemicolon {
+                after: after.to_string().into(),
+                span: Span::new(at, at),
+            });
+            None
         } else {
-            self.error(ParseError::ExpectedAfter {
+            let span = self.current_span();
+            self.error_with(|| ParseError::ExpectedAfter {
                 expected: "';'".into(),
-                after: format!("{}", after).into(),
-                span: self.current_span(),
+                after: after.to_string().into(),
+                span,
             });
             None
         }
     }
 
+    /// True, with [`ParserOptions::infer_semicolons`], when a `;` is missing
+    /// at a line break and the current token plainly starts the next
+    /// statement (or closes the enclosing block), so the parser can act as
+    /// if the `;` were there instead of reporting an unexpected token. Not
+    /// inside an open `(` or `[`, where the line break more likely follows
+    /// an unclosed list than a finished statement.
+    fn at_inferable_semicolon(&self) -> bool {
+        if !self.infer_semicolons
+            || matches!(
+                self.delimiters.last(),
+                Some(TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::HashBracket)
+            )
+        {
+            return false;
+        }
+        let starts_statement = matches!(
+            self.current_kind(),
+            TokenKind::Variable
+                | TokenKind::Dollar
+                | TokenKind::Identifier
+                | TokenKind::Backslash
+                | TokenKind::RightBrace
+                | TokenKind::Eof
+                | TokenKind::If
+                | TokenKind::While
+                | TokenKind::Do
+                | TokenKind::For
+                | TokenKind::Foreach
+                | TokenKind::Switch
+                | TokenKind::Try
+                | TokenKind::Return
+                | TokenKind::Echo
+                | TokenKind::Print
+                | TokenKind::Break
+                | TokenKind::Continue
+                | TokenKind::Throw
+                | TokenKind::Goto
+                | TokenKind::Unset
+                | TokenKind::Global
+                | TokenKind::Static
+                | TokenKind::Function
+                | TokenKind::Fn_
+                | TokenKind::Class
+                | TokenKind::Abstract
+                | TokenKind::Final
+                | TokenKind::Readonly
+                | TokenKind::Interface
+                | TokenKind::Trait
+                | TokenKind::Enum_
+                | TokenKind::Namespace
+                | TokenKind::Use
+                | TokenKind::Const
+                | TokenKind::Declare
+                | TokenKind::New
+                | TokenKind::Include
+                | TokenKind::IncludeOnce
+                | TokenKind::Require
+                | TokenKind::RequireOnce
+                | TokenKind::Yield_
+                | TokenKind::HashBracket
+        );
+        starts_statement
+            && self.source[self.previous_end as usize..self.current.span.start as usize]
+                .contains('\n')
+    }
+
+    /// Run `f` with `context` pushed onto the diagnostic context stack.
+    pub(crate) fn with_context<R>(
+        &mut self,
+        context: ParseContext,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.contexts.push(context);
+        let result = f(self);
+        self.contexts.pop();
+        result
+    }
+
+    /// Start an empty context stack for a nested statement, returning the
+    /// outer one's base for [`restore_contexts`](Self::restore_contexts).
+    pub(crate) fn enter_statement_contexts(&mut self) -> usize {
+        std::mem::replace(&mut self.context_base, self.contexts.len())
+    }
+
+    pub(crate) fn restore_contexts(&mut self, outer_base: usize) {
+        self.contexts.truncate(self.context_base);
+        self.context_base = outer_base;
+    }
+
+    /// Report a [`ParseError::ExpectedExpression`] for the current token.
+    pub(crate) fn error_expected_expression(&mut self, span: Span) {
+        if !self.keeps_errors() {
+            return;
+        }
+        let error = ParseError::ExpectedExpression {
+            found: self.current_kind(),
+            context: self.contexts[self.context_base..]
+                .last()
+                .map(|context| context.describe(self.source).into()),
+            span,
+        };
+        self.push_error(error);
+    }
+
     /// Run `f` with `no_brace_subscript` temporarily set to `true`, then restore
     /// the previous value. Used to parse property/parameter default expressions
     /// without consuming a following `{ get => ...; }` hook block as subscript.
@@ -388,7 +1094,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             Some(self.advance())
         } else {
             self.error(ParseError::UnclosedDelimiter {
-                delimiter: format!("'{}'", kind).into(),
+                delimiter: kind.label().into(),
                 opened_at,
                 span: self.current_span(),
             });
@@ -433,24 +1139,61 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     // Error handling
     // =========================================================================
 
+    /// Record `err`. Errors past the internal cap are dropped, see
+    /// [`errors_truncated`](Self::errors_truncated), as are errors reported
+    /// after a timeout or once [`truncated`](Self::truncated) is set.
     pub fn error(&mut self, err: ParseError) {
-        if self.errors.len() < MAX_ERRORS {
-            self.errors.push(err);
+        if self.keeps_errors() {
+            self.push_error(err);
+        }
+    }
+
+    /// [`error`](Self::error) for errors whose message is formatted: `build`
+    /// only runs when the error will be kept.
+    pub fn error_with(&mut self, build: impl FnOnce() -> ParseError) {
+        if self.keeps_errors() {
+            self.push_error(build());
+        }
+    }
+
+    fn keeps_errors(&self) -> bool {
+        self.errors.len() < self.error_cap && !self.timed_out && !self.truncated
+    }
+
+    /// Push a kept error, counting it towards `max_errors`. Parsing stops
+    /// when the next token is consumed rather than here, as the caller may
+    /// still be about to skip the token it reported.
+    fn push_error(&mut self, err: ParseError) {
+        if err.severity() == Severity::Error {
+            self.error_count += 1;
+            if self.max_errors == Some(self.error_count) {
+                self.truncated = true;
+                self.stop_pending = true;
+            }
         }
+        self.errors.push(err);
     }
 
+    /// `true` once the internal error cap has been reached.
     pub fn errors_truncated(&self) -> bool {
-        self.errors.len() >= MAX_ERRORS
+        self.errors.len() >= self.error_cap
     }
 
+    #[doc(hidden)]
     pub fn errors_mut(&mut self) -> &mut Vec<ParseError> {
         &mut self.errors
     }
 
-    pub fn into_errors(self) -> Vec<ParseError> {
+    /// The collected errors in source order, with exact duplicates from
+    /// overlapping recovery paths removed.
+    pub fn into_errors(mut self) -> Vec<ParseError> {
+        crate::diagnostics::sort_diagnostics(&mut self.errors);
+        self.errors.dedup();
         self.errors
     }
 
+    /// Take the comments lexed so far, except doc-blocks already attached to
+    /// declarations.
     pub fn take_comments(&mut self) -> Vec<Comment<'src>> {
         std::mem::take(&mut self.comments)
     }
@@ -459,6 +1202,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     /// The comment is removed from the comments list so it won't be taken again.
     /// Only returns comments that appeared after the last scope close (closing `}`),
     /// preventing doc comments inside closed scopes from leaking to outer statements.
+    #[doc(hidden)]
     pub fn take_doc_comment(&mut self, before: u32) -> Option<Comment<'src>> {
         // Search backwards for the last Doc comment before `before`
         // that also appeared AFTER the last scope close (closing `}`)
@@ -471,6 +1215,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     }
 
     /// Panic-mode error recovery: advance until we hit a likely statement boundary.
+    #[doc(hidden)]
     pub fn synchronize(&mut self) {
         loop {
             match self.current_kind() {
@@ -525,6 +1270,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
 
     /// Recover to the next class-body anchor token.
     /// Used when a class/interface/trait member fails to parse.
+    #[doc(hidden)]
     pub fn synchronize_class_body(&mut self) {
         loop {
             match self.current_kind() {
@@ -553,6 +1299,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
 
     /// Recover to the next enum-body anchor token.
     /// Used when an enum member fails to parse.
+    #[doc(hidden)]
     pub fn synchronize_enum_body(&mut self) {
         loop {
             match self.current_kind() {
@@ -589,6 +1336,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     // Helper: check if token is a keyword usable as identifier in member context
     // =========================================================================
 
+    #[doc(hidden)]
     pub fn is_semi_reserved_keyword(&self) -> bool {
         matches!(
             self.current_kind(),
@@ -785,7 +1533,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
 
         // Union: A|B|C or (A&B)|C (DNF)
         if self.check(TokenKind::Pipe) {
-            self.require_version(PhpVersion::Php80, "union types", self.current_span());
+            self.require_feature(Feature::UnionTypes, self.current_span());
             let mut end = first.span.end;
             let mut types = self.alloc_vec_one(first);
             while self.eat(TokenKind::Pipe).is_some() {
@@ -823,17 +1571,20 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                     }
                 }
             }
-            // Check for duplicate types in union
-            let mut seen_types: std::collections::HashSet<String> =
-                std::collections::HashSet::new();
-            for ty in types.iter() {
-                if let Some(type_str) = self.type_hint_to_string(ty) {
-                    if !seen_types.insert(type_str.clone()) {
-                        self.error(ParseError::Forbidden {
-                            message: format!("Duplicate type '{}' in union type", type_str).into(),
-                            span: ty.span,
-                        });
-                    }
+            // Check for duplicate types in union. Unions are short, so a
+            // pairwise scan beats building a set.
+            for (i, ty) in types.iter().enumerate() {
+                let Some(key) = self.type_hint_key(ty) else {
+                    continue;
+                };
+                if types[..i]
+                    .iter()
+                    .any(|earlier| self.type_hint_key(earlier) == Some(key))
+                {
+                    self.error_with(|| ParseError::Forbidden {
+                        message: format!("Duplicate type '{}' in union type", key).into(),
+                        span: ty.span,
+                    });
                 }
             }
             // DNF types (parenthesized intersection in union) require PHP 8.2
@@ -841,7 +1592,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                 .iter()
                 .any(|t| matches!(t.kind, TypeHintKind::Intersection(_)));
             if has_dnf {
-                self.require_version(PhpVersion::Php82, "DNF types", span);
+                self.require_feature(Feature::DnfTypes, span);
             }
             return TypeHint {
                 kind: TypeHintKind::Union(types),
@@ -868,7 +1619,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             );
             if looks_like_type {
                 let span = self.current_span();
-                self.require_version(PhpVersion::Php81, "intersection types", span);
+                self.require_feature(Feature::IntersectionTypes, span);
 
                 // Check for invalid outer-form DNF: (A|B)&C
                 if let TypeHintKind::Union(_) = &first.kind {
@@ -888,7 +1639,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
 
                 // Check if there's a union after this intersection (DNF: A&B|C&D)
                 if self.check(TokenKind::Pipe) {
-                    self.require_version(PhpVersion::Php82, "DNF types", Span::new(start, end));
+                    self.require_feature(Feature::DnfTypes, Span::new(start, end));
                     // Validate that mixed is not used in intersection types
                     for ty in types.iter() {
                         if let TypeHintKind::Keyword(BuiltinType::Mixed, _) = &ty.kind {
@@ -982,10 +1733,10 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         if let TypeHintKind::Keyword(builtin, span) = &first.kind {
             match builtin {
                 BuiltinType::Null => {
-                    self.require_version(PhpVersion::Php82, "null as standalone type", *span);
+                    self.require_feature(Feature::StandaloneNull, *span);
                 }
                 BuiltinType::False => {
-                    self.require_version(PhpVersion::Php82, "false as standalone type", *span);
+                    self.require_feature(Feature::StandaloneFalse, *span);
                 }
                 _ => {}
             }
@@ -1007,51 +1758,24 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         first
     }
 
-    /// Convert a type hint to a string representation for comparison purposes.
-    fn type_hint_to_string(&self, ty: &TypeHint<'arena, 'src>) -> Option<String> {
+    /// The text a simple type hint is compared by when looking for
+    /// duplicates: the keyword for a builtin, the name as written otherwise.
+    fn type_hint_key(&self, ty: &TypeHint<'arena, 'src>) -> Option<&'src str> {
         match &ty.kind {
-            TypeHintKind::Keyword(builtin, _) => {
-                let name = match builtin {
-                    BuiltinType::Int => "int",
-                    BuiltinType::Integer => "integer",
-                    BuiltinType::Float => "float",
-                    BuiltinType::Double => "double",
-                    BuiltinType::String => "string",
-                    BuiltinType::Bool => "bool",
-                    BuiltinType::Boolean => "boolean",
-                    BuiltinType::Void => "void",
-                    BuiltinType::Never => "never",
-                    BuiltinType::Mixed => "mixed",
-                    BuiltinType::Object => "object",
-                    BuiltinType::Iterable => "iterable",
-                    BuiltinType::Callable => "callable",
-                    BuiltinType::Array => "array",
-                    BuiltinType::Self_ => "self",
-                    BuiltinType::Parent_ => "parent",
-                    BuiltinType::Static => "static",
-                    BuiltinType::Null => "null",
-                    BuiltinType::True => "true",
-                    BuiltinType::False => "false",
-                };
-                Some(name.to_string())
-            }
+            TypeHintKind::Keyword(builtin, _) => Some(builtin.as_str()),
             TypeHintKind::Named(name) => match name {
-                Name::Simple { value, .. } => Some(value.to_string()),
-                Name::Complex { parts, kind, .. } => {
-                    let prefix = match kind {
-                        NameKind::Unqualified | NameKind::Qualified => "",
-                        NameKind::FullyQualified => "\\",
-                        NameKind::Relative => "namespace\\",
-                        NameKind::Error => return None,
-                    };
-                    let joined = parts.iter().copied().collect::<Vec<_>>().join("\\");
-                    Some(format!("{}{}", prefix, joined))
-                }
                 Name::Error { .. } => None,
+                Name::Complex {
+                    kind: NameKind::Error,
+                    ..
+                } => None,
+                _ => {
+                    let span = name.span();
+                    self.source.get(span.start as usize..span.end as usize)
+                }
             },
-            TypeHintKind::Nullable(_) | TypeHintKind::Union(_) | TypeHintKind::Intersection(_) => {
-                None
-            }
+            // Composite types are not compared.
+            _ => None,
         }
     }
 
@@ -1077,7 +1801,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                 TypeHintKind::Intersection(types) => {
                     // For parenthesized intersections, require PHP 8.1
                     if types.len() > 1 {
-                        self.require_version(PhpVersion::Php81, "intersection types", span);
+                        self.require_feature(Feature::IntersectionTypes, span);
                     }
                     TypeHint {
                         kind: TypeHintKind::Intersection(types),
@@ -1086,7 +1810,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                 }
                 TypeHintKind::Union(types) => {
                     // For parenthesized unions, require PHP 8.2
-                    self.require_version(PhpVersion::Php82, "parenthesized union types", span);
+                    self.require_feature(Feature::ParenthesizedUnionTypes, span);
                     TypeHint {
                         kind: TypeHintKind::Union(types),
                         span,
@@ -1268,6 +1992,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     }
 
     /// Parse a simple (non-composite) type: named type from Name or builtin keyword.
+    #[doc(hidden)]
     pub fn parse_simple_type(&mut self) -> TypeHint<'arena, 'src> {
         let start = self.start_span();
 
@@ -1302,10 +2027,10 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                 let token = self.advance();
                 match builtin {
                     BuiltinType::Never => {
-                        self.require_version(PhpVersion::Php81, "never type", token.span);
+                        self.require_feature(Feature::NeverType, token.span);
                     }
                     BuiltinType::Mixed => {
-                        self.require_version(PhpVersion::Php80, "mixed type", token.span);
+                        self.require_feature(Feature::MixedType, token.span);
                     }
                     _ => {}
                 }
@@ -1348,7 +2073,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             }
             TokenKind::Null => {
                 let token = self.advance();
-                self.require_version(PhpVersion::Php80, "null type", token.span);
+                self.require_feature(Feature::NullType, token.span);
                 TypeHint {
                     kind: TypeHintKind::Keyword(BuiltinType::Null, token.span),
                     span: token.span,
@@ -1356,7 +2081,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             }
             TokenKind::True => {
                 let token = self.advance();
-                self.require_version(PhpVersion::Php82, "true type", token.span);
+                self.require_feature(Feature::TrueType, token.span);
                 TypeHint {
                     kind: TypeHintKind::Keyword(BuiltinType::True, token.span),
                     span: token.span,
@@ -1364,7 +2089,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             }
             TokenKind::False => {
                 let token = self.advance();
-                self.require_version(PhpVersion::Php80, "false type", token.span);
+                self.require_feature(Feature::FalseType, token.span);
                 TypeHint {
                     kind: TypeHintKind::Keyword(BuiltinType::False, token.span),
                     span: token.span,
@@ -1383,6 +2108,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     }
 
     /// Check if the current token could start a type hint.
+    #[doc(hidden)]
     pub fn could_be_type_hint(&mut self) -> bool {
         match self.current_kind() {
             TokenKind::Question
@@ -1429,7 +2155,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
 
                 let args = if self.check(TokenKind::LeftParen) {
                     let paren_start = self.current_span().start;
-                    match crate::expr::parse_arg_list_or_callable(self) {
+                    match crate::expr::parse_arg_list_or_callable(self, Some(name.span())) {
                         crate::expr::ArgListResult::Args(args) => args,
                         crate::expr::ArgListResult::CallableMarker => {
                             // PHP: "Cannot create Closure as attribute argument".
@@ -1457,17 +2183,43 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         attributes
     }
 
-    /// Parse `<?= expr ?>` — the short echo tag produces an implicit echo statement.
-    pub(crate) fn parse_short_echo(&mut self) -> Option<Stmt<'arena, 'src>> {
+    /// Consume an `InlineHtml` token as a statement.
+    pub(crate) fn parse_inline_html(&mut self) -> Stmt<'arena, 'src> {
+        let token = self.advance();
+        let start = token.span.start as usize;
+        let text = &self.source[start..token.span.end as usize];
+        // `?>` takes one line break with it, as PHP's T_CLOSE_TAG does.
+        let newline_swallowed =
+            self.source[..start].ends_with("?>") && text.starts_with(['\n', '\r']);
+        Stmt {
+            kind: StmtKind::InlineHtml(InlineHtml {
+                text,
+                newline_swallowed,
+            }),
+            span: token.span,
+        }
+    }
+
+    /// Parse `<?= expr, … ?>` after its `<?=` tag: an echo statement marked
+    /// with its tags.
+    pub(crate) fn parse_short_echo(&mut self, open_tag: Span) -> Option<Stmt<'arena, 'src>> {
         if self.check(TokenKind::Eof) || self.check(TokenKind::CloseTag) {
             return None;
         }
-        let start = self.start_span();
-        let expr = expr::parse_expr(self);
+        let mut exprs = self.alloc_vec();
+        exprs.push(expr::parse_expr(self));
+        while self.eat(TokenKind::Comma).is_some() {
+            exprs.push(expr::parse_expr(self));
+        }
         self.expect_semicolon("short echo tag");
-        let span = Span::new(start, self.previous_end());
+        let close_tag = self.check(TokenKind::CloseTag).then(|| self.current_span());
+        let span = Span::new(open_tag.start, self.previous_end());
         Some(Stmt {
-            kind: StmtKind::Echo(self.alloc_vec_one(expr)),
+            kind: StmtKind::Echo(EchoStmt {
+                exprs,
+                open_tag: Some(open_tag),
+                close_tag,
+            }),
             span,
         })
     }
@@ -1476,26 +2228,28 @@ impl<'arena, 'src> Parser<'arena, 'src> {
     // Top-level parsing
     // =========================================================================
 
+    fn check_open_tag_case(&mut self, span: Span) {
+        if let Some(warning) = crate::open_tag::open_tag_case(self.source, span) {
+            self.error(warning);
+        }
+    }
+
     pub fn parse_program(&mut self) -> Program<'arena, 'src> {
         let start = self.start_span();
         let mut stmts = self.alloc_vec_with_capacity(16);
 
         // Handle optional inline HTML before PHP tag
         if self.check(TokenKind::InlineHtml) {
-            let token = self.advance();
-            let text = &self.source[token.span.start as usize..token.span.end as usize];
-            stmts.push(Stmt {
-                kind: StmtKind::InlineHtml(text),
-                span: token.span,
-            });
+            stmts.push(self.parse_inline_html());
         }
 
         // Expect and consume the open tag
         if self.check(TokenKind::OpenTag) {
             let tag = self.advance();
+            self.check_open_tag_case(tag.span);
             // <?= produces an implicit echo
             if self.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                if let Some(echo_stmt) = self.parse_short_echo() {
+                if let Some(echo_stmt) = self.parse_short_echo(tag.span) {
                     stmts.push(echo_stmt);
                 }
             }
@@ -1503,6 +2257,11 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             self.error(ParseError::ExpectedOpenTag {
                 span: self.current_span(),
             });
+        } else if !stmts.is_empty() {
+            // The whole file is inline HTML.
+            if let Some(warning) = crate::open_tag::missing_open_tag(self.source) {
+                self.error(warning);
+            }
         }
 
         // Parse statements until EOF
@@ -1511,18 +2270,14 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             if self.check(TokenKind::CloseTag) {
                 self.advance();
                 if self.check(TokenKind::InlineHtml) {
-                    let token = self.advance();
-                    let text = &self.source[token.span.start as usize..token.span.end as usize];
-                    stmts.push(Stmt {
-                        kind: StmtKind::InlineHtml(text),
-                        span: token.span,
-                    });
+                    stmts.push(self.parse_inline_html());
                 }
                 if self.check(TokenKind::OpenTag) {
                     let tag = self.advance();
+                    self.check_open_tag_case(tag.span);
                     // <?= produces an implicit echo
                     if self.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                        if let Some(echo_stmt) = self.parse_short_echo() {
+                        if let Some(echo_stmt) = self.parse_short_echo(tag.span) {
                             stmts.push(echo_stmt);
                         }
                     }
@@ -1540,6 +2295,11 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         }
 
         self.validate_namespace_layout(&stmts);
+        self.validate_implements(&stmts);
+        self.validate_catch_scopes(&stmts);
+        for error in crate::suggest::misspellings(self.source, &stmts, &self.errors) {
+            self.error(error);
+        }
 
         let span = if stmts.is_empty() {
             Span::new(start, self.current.span.end)
@@ -1554,7 +2314,41 @@ impl<'arena, 'src> Parser<'arena, 'src> {
             )
         };
 
-        Program { stmts, span }
+        // What follows `__halt_compiler()` is data, not HTML, even after `?>`.
+        let halted = matches!(
+            stmts.last().map(|s| &s.kind),
+            Some(StmtKind::HaltCompiler(_))
+        );
+        let (ends_in_html, close_tag) = if halted {
+            (false, None)
+        } else {
+            self.end_state()
+        };
+        Program {
+            stmts,
+            span,
+            ends_in_html,
+            close_tag,
+        }
+    }
+
+    /// Whether the input ends in inline HTML, and the `?>` before it. The
+    /// lexer follows a `?>` with inline HTML or an open tag and an open tag
+    /// with PHP, so the last two tokens tell.
+    fn end_state(&self) -> (bool, Option<Span>) {
+        let mut tokens = self
+            .tokens
+            .iter()
+            .rev()
+            .skip_while(|token| token.kind == TokenKind::Eof);
+        match tokens.next() {
+            Some(last) if last.kind == TokenKind::CloseTag => (true, Some(last.span)),
+            Some(last) if last.kind == TokenKind::InlineHtml => match tokens.next() {
+                Some(tag) if tag.kind == TokenKind::CloseTag => (true, Some(tag.span)),
+                _ => (true, None),
+            },
+            _ => (false, None),
+        }
     }
 
     /// Enforce PHP's program-level namespace rules:
@@ -1658,6 +2452,299 @@ impl<'arena, 'src> Parser<'arena, 'src> {
         }
     }
 
+    /// Reject `implements` lists naming a class declared in the same file:
+    /// PHP fails with "E cannot implement A - it is not an interface". Only
+    /// names that resolve, through the enclosing namespace and `use` imports,
+    /// to a top-level class declaration are checked; anything declared
+    /// elsewhere is left to a later semantic layer.
+    fn validate_implements(&mut self, stmts: &[Stmt<'arena, 'src>]) {
+        use std::collections::{HashMap, HashSet};
+
+        /// Visit each top-level declaration with its namespace and imports.
+        fn for_each_decl<'a, 'arena, 'src>(
+            stmts: &'a [Stmt<'arena, 'src>],
+            f: &mut impl FnMut(&'a Stmt<'arena, 'src>, &str, &HashMap<String, String>),
+        ) {
+            let mut namespace = String::new();
+            let mut imports = HashMap::new();
+            for stmt in stmts {
+                match &stmt.kind {
+                    StmtKind::Namespace(decl) => {
+                        let name = decl
+                            .name
+                            .as_ref()
+                            .map(|n| n.join_parts().into_owned())
+                            .unwrap_or_default();
+                        match &decl.body {
+                            php_ast::NamespaceBody::Braced(inner) => {
+                                let mut inner_imports = HashMap::new();
+                                for s in inner.iter() {
+                                    collect_imports(s, &mut inner_imports);
+                                    f(s, &name, &inner_imports);
+                                }
+                            }
+                            php_ast::NamespaceBody::Simple => {
+                                namespace = name;
+                                imports.clear();
+                            }
+                        }
+                    }
+                    _ => {
+                        collect_imports(stmt, &mut imports);
+                        f(stmt, &namespace, &imports);
+                    }
+                }
+            }
+        }
+
+        fn collect_imports(stmt: &Stmt<'_, '_>, imports: &mut HashMap<String, String>) {
+            let StmtKind::Use(decl) = &stmt.kind else {
+                return;
+            };
+            for item in decl.uses.iter() {
+                if item.kind.unwrap_or(decl.kind) != php_ast::UseKind::Normal {
+                    continue;
+                }
+                let parts = item.name.parts_slice();
+                let alias = item
+                    .alias
+                    .unwrap_or_else(|| parts.last().copied().unwrap_or(""));
+                imports.insert(
+                    alias.to_ascii_lowercase(),
+                    item.name.join_parts().to_ascii_lowercase(),
+                );
+            }
+        }
+
+        /// Lower-cased fully-qualified name, or `None` for `self`-like names.
+        fn resolve(
+            name: &php_ast::Name<'_, '_>,
+            namespace: &str,
+            imports: &HashMap<String, String>,
+        ) -> Option<String> {
+            let joined = name.join_parts().to_ascii_lowercase();
+            let qualify = |rest: &str| {
+                if namespace.is_empty() {
+                    rest.to_string()
+                } else {
+                    format!("{}\\{}", namespace.to_ascii_lowercase(), rest)
+                }
+            };
+            match name.kind() {
+                php_ast::NameKind::FullyQualified => Some(joined),
+                php_ast::NameKind::Relative => Some(qualify(&joined)),
+                php_ast::NameKind::Unqualified | php_ast::NameKind::Qualified => {
+                    if matches!(joined.as_str(), "self" | "static" | "parent") {
+                        return None;
+                    }
+                    let (first, rest) = match joined.split_once('\\') {
+                        Some((first, rest)) => (first, Some(rest)),
+                        None => (joined.as_str(), None),
+                    };
+                    Some(match (imports.get(first), rest) {
+                        (Some(target), Some(rest)) => format!("{target}\\{rest}"),
+                        (Some(target), None) => target.clone(),
+                        (None, _) => qualify(&joined),
+                    })
+                }
+                php_ast::NameKind::Error => None,
+            }
+        }
+
+        let mut classes = HashSet::new();
+        for_each_decl(stmts, &mut |stmt, namespace, _| {
+            if let StmtKind::Class(decl) = &stmt.kind {
+                if let Some(name) = decl.name.as_ref().and_then(|n| n.as_str()) {
+                    let fqn = if namespace.is_empty() {
+                        name.to_string()
+                    } else {
+                        format!("{namespace}\\{name}")
+                    };
+                    classes.insert(fqn.to_ascii_lowercase());
+                }
+            }
+        });
+        if classes.is_empty() {
+            return;
+        }
+
+        let mut errors = Vec::new();
+        for_each_decl(stmts, &mut |stmt, namespace, imports| {
+            let (name, implements) = match &stmt.kind {
+                StmtKind::Class(decl) => (
+                    decl.name.as_ref().and_then(|n| n.as_str()),
+                    &decl.implements,
+                ),
+                StmtKind::Enum(decl) => (decl.name.as_str(), &decl.implements),
+                _ => return,
+            };
+            for interface in implements.iter() {
+                if resolve(interface, namespace, imports).is_some_and(|fqn| classes.contains(&fqn))
+                {
+                    errors.push(ParseError::Forbidden {
+                        message: format!(
+                            "{} cannot implement {} - it is not an interface",
+                            name.unwrap_or("class@anonymous"),
+                            interface.to_string_repr()
+                        )
+                        .into(),
+                        span: interface.span(),
+                    });
+                }
+            }
+        });
+        for error in errors {
+            self.error(error);
+        }
+    }
+
+    /// Reject `self` and `parent` in catch types where PHP knows at compile
+    /// time that they cannot resolve: `self` or `parent` in a function outside
+    /// any class ("Cannot use "self" when no class scope is active"), and
+    /// `parent` in a method of a class, interface, or enum without a parent.
+    /// Closures, trait methods, and top-level code are bound to a class only at
+    /// run time and are not checked.
+    fn validate_catch_scopes(&mut self, stmts: &[Stmt<'arena, 'src>]) {
+        if !self.catch_class_keywords {
+            return;
+        }
+        use php_ast::visitor::{
+            walk_class_member, walk_enum_member, walk_expr, walk_stmt, Visitor,
+        };
+        use std::ops::ControlFlow;
+
+        /// What `self` and `parent` refer to at the current position.
+        #[derive(Clone, Copy)]
+        enum Scope {
+            /// Top-level code, a closure, or a trait: decided at run time.
+            Unknown,
+            /// A function declared outside any class.
+            Function,
+            /// The body of a class-like declaration.
+            Class { has_parent: bool, is_trait: bool },
+            /// A method or property hook of a class, interface, or enum.
+            Method { has_parent: bool },
+        }
+
+        struct Checker {
+            scope: Scope,
+            errors: Vec<ParseError>,
+        }
+
+        impl Checker {
+            fn within(
+                &mut self,
+                scope: Scope,
+                f: impl FnOnce(&mut Self) -> ControlFlow<()>,
+            ) -> ControlFlow<()> {
+                let saved = std::mem::replace(&mut self.scope, scope);
+                let result = f(self);
+                self.scope = saved;
+                result
+            }
+
+            fn method_scope(&self) -> Scope {
+                match self.scope {
+                    Scope::Class {
+                        has_parent,
+                        is_trait: false,
+                    } => Scope::Method { has_parent },
+                    _ => Scope::Unknown,
+                }
+            }
+        }
+
+        impl<'arena, 'src> Visitor<'arena, 'src> for Checker {
+            fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+                let class = |has_parent, is_trait| Scope::Class {
+                    has_parent,
+                    is_trait,
+                };
+                match &stmt.kind {
+                    StmtKind::Function(_) => {
+                        return self.within(Scope::Function, |c| walk_stmt(c, stmt));
+                    }
+                    StmtKind::Class(decl) => {
+                        let scope = class(decl.extends.is_some(), false);
+                        return self.within(scope, |c| walk_stmt(c, stmt));
+                    }
+                    StmtKind::Interface(_) | StmtKind::Enum(_) => {
+                        return self.within(class(false, false), |c| walk_stmt(c, stmt));
+                    }
+                    StmtKind::Trait(_) => {
+                        return self.within(class(false, true), |c| walk_stmt(c, stmt));
+                    }
+                    StmtKind::TryCatch(tc) => {
+                        for ty in tc.catches.iter().flat_map(|catch| catch.types.iter()) {
+                            let message = match (ty, self.scope) {
+                                (ClassRef::SelfKw(_), Scope::Function)
+                                | (ClassRef::ParentKw(_), Scope::Function) => format!(
+                                    "Cannot use \"{}\" when no class scope is active",
+                                    ty.keyword().unwrap_or_default()
+                                ),
+                                (ClassRef::ParentKw(_), Scope::Method { has_parent: false }) => {
+                                    "Cannot use \"parent\" when current class scope has no parent"
+                                        .to_string()
+                                }
+                                _ => continue,
+                            };
+                            self.errors.push(ParseError::Forbidden {
+                                message: message.into(),
+                                span: ty.span(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+                walk_stmt(self, stmt)
+            }
+
+            fn visit_class_member(
+                &mut self,
+                member: &ClassMember<'arena, 'src>,
+            ) -> ControlFlow<()> {
+                if let ClassMemberKind::Method(_) | ClassMemberKind::Property(_) = member.kind {
+                    return self.within(self.method_scope(), |c| walk_class_member(c, member));
+                }
+                walk_class_member(self, member)
+            }
+
+            fn visit_enum_member(&mut self, member: &EnumMember<'arena, 'src>) -> ControlFlow<()> {
+                if let EnumMemberKind::Method(_) = member.kind {
+                    return self.within(self.method_scope(), |c| walk_enum_member(c, member));
+                }
+                walk_enum_member(self, member)
+            }
+
+            fn visit_expr(&mut self, expr: &Expr<'arena, 'src>) -> ControlFlow<()> {
+                match &expr.kind {
+                    ExprKind::Closure(_) | ExprKind::ArrowFunction(_) => {
+                        self.within(Scope::Unknown, |c| walk_expr(c, expr))
+                    }
+                    ExprKind::AnonymousClass(decl) => {
+                        let scope = Scope::Class {
+                            has_parent: decl.extends.is_some(),
+                            is_trait: false,
+                        };
+                        self.within(scope, |c| walk_expr(c, expr))
+                    }
+                    _ => walk_expr(self, expr),
+                }
+            }
+        }
+
+        let mut checker = Checker {
+            scope: Scope::Unknown,
+            errors: Vec::new(),
+        };
+        for stmt in stmts {
+            let _ = checker.visit_stmt(stmt);
+        }
+        for error in checker.errors {
+            self.error(error);
+        }
+    }
+
     fn validate_use_scope(&mut self, stmts: &[Stmt<'arena, 'src>]) {
         use std::collections::HashSet;
         // Intra-statement duplicates (incl. group-use) are caught at parse time
@@ -1681,7 +2768,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                     .alias
                     .unwrap_or_else(|| parts.last().copied().unwrap_or(""));
                 if item.alias.is_none() && parts.len() == 1 {
-                    self.error(ParseError::ForbiddenWarning {
+                    self.error_with(|| ParseError::ForbiddenWarning {
                         message: format!(
                             "The use statement with non-compound name '{}' has no effect",
                             effective_alias
@@ -1695,7 +2782,7 @@ impl<'arena, 'src> Parser<'arena, 'src> {
                     && added_this_stmt.insert((kind_tag, effective_alias))
                 {
                     let full = item.name.join_parts();
-                    self.error(ParseError::Forbidden {
+                    self.error_with(|| ParseError::Forbidden {
                         message: format!(
                             "Cannot use {} as {} because the name is already in use",
                             full, effective_alias
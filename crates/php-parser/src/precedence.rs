@@ -125,6 +125,47 @@ const fn build_bp_table() -> [Option<(u8, u8)>; 256] {
     table
 }
 
+/// The left binding power of a token that continues an expression after an
+/// operand: an infix, postfix, assignment, ternary, or `??` operator, member
+/// or static access, a subscript, or a call. Zero for every other token, so
+/// the Pratt loop stops on `;`, `,`, `)` and the like with one table load.
+#[inline(always)]
+pub fn left_binding_power(kind: TokenKind) -> u8 {
+    const LEFT_BP_TABLE: [u8; 256] = build_left_bp_table();
+    LEFT_BP_TABLE[kind as u8 as usize]
+}
+
+const fn build_left_bp_table() -> [u8; 256] {
+    let infix = build_bp_table();
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        if let Some((left_bp, _)) = infix[i] {
+            table[i] = left_bp;
+        }
+        i += 1;
+    }
+
+    // The assignment operators are contiguous, see `TokenKind::is_assignment_op`.
+    let mut kind = TokenKind::Equals as u8 as usize;
+    while kind <= TokenKind::CoalesceEquals as u8 as usize {
+        table[kind] = ASSIGNMENT_BP;
+        kind += 1;
+    }
+    table[TokenKind::Question as u8 as usize] = TERNARY_BP;
+    table[TokenKind::QuestionQuestion as u8 as usize] = NULL_COALESCE_LEFT_BP;
+    table[TokenKind::PlusPlus as u8 as usize] = POSTFIX_BP;
+    table[TokenKind::MinusMinus as u8 as usize] = POSTFIX_BP;
+    table[TokenKind::Arrow as u8 as usize] = MEMBER_ACCESS_BP;
+    table[TokenKind::NullsafeArrow as u8 as usize] = MEMBER_ACCESS_BP;
+    table[TokenKind::LeftBracket as u8 as usize] = MEMBER_ACCESS_BP;
+    table[TokenKind::LeftBrace as u8 as usize] = MEMBER_ACCESS_BP;
+    table[TokenKind::LeftParen as u8 as usize] = MEMBER_ACCESS_BP;
+    table[TokenKind::DoubleColon as u8 as usize] = SCOPE_RESOLUTION_BP;
+
+    table
+}
+
 /// Returns the prefix binding power for a token, or None if it's not a prefix operator.
 /// Returns ((), right_bp).
 #[inline(always)]
@@ -148,15 +189,8 @@ pub const LOW_PREFIX_BP: u8 = 41;
 /// (cast), @, clone) — above `instanceof` (45/46), below `**` (60).
 pub const HIGH_PREFIX_BP: u8 = 47;
 
-/// Returns the postfix binding power for a token, or None if it's not a postfix operator.
-/// Returns (left_bp, ()).
-#[inline(always)]
-pub fn postfix_binding_power(kind: TokenKind) -> Option<u8> {
-    match kind {
-        TokenKind::PlusPlus | TokenKind::MinusMinus => Some(43),
-        _ => None,
-    }
-}
+/// Left binding power of postfix `++` and `--`.
+pub const POSTFIX_BP: u8 = 43;
 
 /// Assignment binding power — handled specially because it's right-associative
 /// and the LHS must be a valid assignment target.
@@ -325,4 +359,21 @@ mod tests {
         let (inst_left, _) = infix_binding_power(TokenKind::Instanceof).unwrap();
         assert!(inst_left > cmp_right);
     }
+
+    #[test]
+    fn test_left_binding_power_matches_operator_tables() {
+        assert_eq!(left_binding_power(TokenKind::Semicolon), 0);
+        assert_eq!(left_binding_power(TokenKind::Comma), 0);
+        assert_eq!(left_binding_power(TokenKind::RightParen), 0);
+        assert_eq!(left_binding_power(TokenKind::Plus), 35);
+        assert_eq!(left_binding_power(TokenKind::StarStar), 60);
+        assert_eq!(left_binding_power(TokenKind::Equals), ASSIGNMENT_BP);
+        assert_eq!(left_binding_power(TokenKind::CoalesceEquals), ASSIGNMENT_BP);
+        assert_eq!(left_binding_power(TokenKind::PlusPlus), POSTFIX_BP);
+        assert_eq!(left_binding_power(TokenKind::Arrow), MEMBER_ACCESS_BP);
+        assert_eq!(
+            left_binding_power(TokenKind::DoubleColon),
+            SCOPE_RESOLUTION_BP
+        );
+    }
 }
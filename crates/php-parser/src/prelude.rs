@@ -0,0 +1,71 @@
+//! The items most programs using the parser need, in one import.
+//!
+//! Most programs parse a file, look at what it declares, and either report on
+//! it or walk it with a [`Visitor`]:
+//!
+//! ```
+//! use php_rs_parser::prelude::*;
+//!
+//! let arena = Bump::new();
+//! let src = "<?php
+//! namespace App;
+//! function boot() {}
+//! class User {
+//!     public function name(): string { return ''; }
+//!     public function email(): string { return ''; }
+//! }
+//! ";
+//! let result = parse(&arena, src);
+//! assert!(result.errors.is_empty());
+//!
+//! let functions: Vec<_> = result.program.functions().map(|f| f.name.or_error()).collect();
+//! assert_eq!(functions, ["boot"]);
+//! for class in result.program.classes() {
+//!     let methods: Vec<_> = class.methods().map(|m| m.name.or_error()).collect();
+//!     assert_eq!(methods, ["name", "email"]);
+//! }
+//! ```
+//!
+//! Names in the AST are as written; `php_analysis::resolve::resolve_names`
+//! qualifies them against the file's `namespace` and `use` declarations, and
+//! the `php-printer` crate prints a program back to source.
+//!
+//! ```
+//! use php_rs_parser::prelude::*;
+//!
+//! struct EchoCounter(usize);
+//!
+//! impl<'arena, 'src> Visitor<'arena, 'src> for EchoCounter {
+//!     fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> std::ops::ControlFlow<()> {
+//!         if matches!(stmt.kind, StmtKind::Echo(_)) {
+//!             self.0 += 1;
+//!         }
+//!         walk_stmt(self, stmt)
+//!     }
+//! }
+//!
+//! let arena = Bump::new();
+//! let result = parse_versioned(&arena, "<?php echo 1; echo 2;", PhpVersion::Php80);
+//! assert!(result.errors.is_empty());
+//!
+//! let mut counter = EchoCounter(0);
+//! let _ = counter.visit_program(&result.program);
+//! assert_eq!(counter.0, 2);
+//! ```
+//!
+//! Everything here is covered by the crate's semantic versioning; an item is
+//! only removed from the prelude in a major release.
+
+pub use bumpalo::Bump;
+pub use php_ast::visitor::{walk_expr, walk_program, walk_stmt, ScopeVisitor, Visitor};
+pub use php_ast::{
+    ClassDecl, ClassMember, ClassMemberKind, EnumDecl, Expr, ExprKind, FunctionDecl, Ident,
+    InterfaceDecl, MethodDecl, Name, Program, Span, Spanned, Stmt, StmtKind, TraitDecl,
+};
+
+pub use crate::diagnostics::{Diagnostic, ParseError, Severity};
+pub use crate::source_map::SourceMap;
+pub use crate::{
+    parse, parse_expr_str, parse_stmt_str, parse_versioned, parse_with_options, FileId,
+    FragmentResult, ParseResult, ParserContext, ParserOptions, PhpVersion, Session,
+};
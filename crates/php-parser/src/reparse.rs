@@ -0,0 +1,144 @@
+use php_ast::{ClassMember, Comment, Span, Stmt};
+use php_lexer::{Lexer, TokenKind};
+
+use crate::diagnostics::ParseError;
+use crate::parser::Parser;
+use crate::stmt;
+use crate::version::PhpVersion;
+
+/// The declaration produced by [`reparse_function`].
+#[derive(Debug)]
+pub enum ReparsedFunction<'arena, 'src> {
+    /// A function declared outside any class-like body: a
+    /// [`StmtKind::Function`](php_ast::StmtKind::Function) statement.
+    Function(Stmt<'arena, 'src>),
+    /// A member of a class, interface, trait, or enum body — normally a
+    /// [`ClassMemberKind::Method`](php_ast::ClassMemberKind::Method).
+    Method(ClassMember<'arena, 'src>),
+}
+
+/// The result of [`reparse_function`].
+pub struct FunctionReparse<'arena, 'src> {
+    /// The declaration, or `None` if the range held no declaration at all.
+    pub function: Option<ReparsedFunction<'arena, 'src>>,
+    /// Comments inside the range.
+    pub comments: Vec<Comment<'src>>,
+    /// Errors inside the range, in source order.
+    pub errors: Vec<ParseError>,
+}
+
+/// Re-parse the single function or method declared at `span` of `source`.
+///
+/// `span` is the declaration's byte range as recorded in a previous parse —
+/// the [`Stmt`] span of a function, or the [`ClassMember`] span of a method,
+/// attributes and modifiers included — adjusted for edits made inside it.
+/// Only the tokens before `span.end` are lexed. Whether the declaration is a
+/// method is decided by scanning the tokens before `span.start` for an
+/// enclosing class, interface, trait, or enum body; nothing outside the range
+/// is parsed.
+///
+/// Spans in the result are offsets into the whole of `source`, so the
+/// declaration can replace the old one in a cached tree. Tokens left over
+/// before `span.end` are reported as an error. A doc-block before the
+/// declaration lies outside the range, so `doc_comment` is `None`; keep the
+/// one from the previous parse.
+///
+/// ```
+/// use php_rs_parser::{reparse_function, ReparsedFunction};
+/// use php_ast::{ClassMemberKind, Span};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php class A {\n    public function run() { return 1; }\n}";
+/// let start = src.find("public").unwrap() as u32;
+/// let end = src.find("}\n}").unwrap() as u32 + 1;
+/// let result = reparse_function(&arena, src, Span::new(start, end));
+/// assert!(result.errors.is_empty());
+/// let Some(ReparsedFunction::Method(member)) = result.function else { panic!() };
+/// let ClassMemberKind::Method(method) = &member.kind else { panic!() };
+/// assert_eq!(method.name.as_str(), Some("run"));
+/// assert_eq!(member.span, Span::new(start, end));
+/// ```
+pub fn reparse_function<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    span: Span,
+) -> FunctionReparse<'arena, 'src> {
+    reparse_function_versioned(arena, source, span, PhpVersion::default())
+}
+
+/// [`reparse_function`] targeting the given PHP `version`.
+pub fn reparse_function_versioned<'arena, 'src>(
+    arena: &'arena bumpalo::Bump,
+    source: &'src str,
+    span: Span,
+    version: PhpVersion,
+) -> FunctionReparse<'arena, 'src> {
+    let end = (span.end as usize).min(source.len());
+    let start = (span.start as usize).min(end);
+    let enclosing = enclosing_body(source, start);
+    let mut parser = Parser::new_at(arena, &source[..end], start, version);
+    let function = match enclosing {
+        Body::Other => (!parser.check(TokenKind::Eof))
+            .then(|| ReparsedFunction::Function(stmt::parse_stmt(&mut parser))),
+        Body::Class { is_interface } => {
+            let members = stmt::parse_class_members(&mut parser, is_interface);
+            members.into_iter().next().map(ReparsedFunction::Method)
+        }
+    };
+    if !parser.check(TokenKind::Eof) {
+        parser.error(ParseError::Expected {
+            expected: "end of declaration".into(),
+            found: parser.current_kind(),
+            span: parser.current_span(),
+        });
+    }
+    FunctionReparse {
+        function,
+        comments: parser.take_comments(),
+        errors: parser.into_errors(),
+    }
+}
+
+/// The kind of `{ }` body directly enclosing a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Body {
+    /// The top level, a function body, or a control-flow block.
+    Other,
+    /// A class, interface, trait, or enum body.
+    Class { is_interface: bool },
+}
+
+/// Find the body enclosing byte offset `offset` from the tokens before it.
+fn enclosing_body(source: &str, offset: usize) -> Body {
+    let mut lexer = Lexer::new(&source[..offset]);
+    let mut stack = Vec::new();
+    // The class-like keyword whose `{` has not been seen yet.
+    let mut pending = None;
+    let mut previous = TokenKind::Eof;
+    loop {
+        let token = lexer.next_token();
+        match token.kind {
+            TokenKind::Eof => break,
+            TokenKind::Class | TokenKind::Trait | TokenKind::Enum_
+                if !matches!(previous, TokenKind::DoubleColon | TokenKind::Arrow) =>
+            {
+                pending = Some(Body::Class {
+                    is_interface: false,
+                });
+            }
+            TokenKind::Interface => {
+                pending = Some(Body::Class { is_interface: true });
+            }
+            TokenKind::Semicolon => pending = None,
+            TokenKind::LeftBrace => stack.push(pending.take().unwrap_or(Body::Other)),
+            TokenKind::RightBrace => {
+                stack.pop();
+            }
+            _ => {}
+        }
+        if !token.kind.is_comment() {
+            previous = token.kind;
+        }
+    }
+    stack.last().copied().unwrap_or(Body::Other)
+}
@@ -0,0 +1,201 @@
+//! Parsing many files into one context.
+//!
+//! A [`Session`] keeps the parse results of every file an analyzer has seen,
+//! with their source maps and diagnostics, under one set of
+//! [`ParserOptions`]. Each file is named by a path, interned once, and
+//! referred to afterwards by a [`FileId`].
+//!
+//! ```
+//! use php_rs_parser::session::Session;
+//!
+//! let arena = bumpalo::Bump::new();
+//! let mut session = Session::new(&arena);
+//! let a = session.parse("src/a.php", "<?php function a() {}");
+//! let b = session.parse("src/b.php", "<?php\na(;\n");
+//!
+//! assert_eq!(session.file_id("src/a.php"), Some(a));
+//! assert_eq!(session.path(b), "src/b.php");
+//! assert_eq!(session.program(a).stmts.len(), 1);
+//!
+//! assert!(session.has_errors());
+//! assert!(session.diagnostics().all(|(file, _)| file == b));
+//! assert!(session.render_diagnostics().contains("--> src/b.php:2:3"));
+//! ```
+//!
+//! Sources, paths, and ASTs live in the arena the session was created with.
+//! Parsing a path again replaces its result under the same [`FileId`]; the
+//! memory of the old one is only freed with the arena.
+
+use std::collections::HashMap;
+
+use php_ast::Program;
+
+use crate::diagnostics::{self, ParseError, RenderOptions, Severity};
+use crate::source_map::SourceMap;
+use crate::{parse_with_options, ParseResult, ParserOptions};
+
+/// A handle to a file parsed by a [`Session`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(u32);
+
+impl FileId {
+    /// The position of the file in [`Session::files`], in the order the
+    /// paths were first parsed.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Parse results for many files, sharing options and an arena. See the
+/// [module docs](self).
+pub struct Session<'arena> {
+    arena: &'arena bumpalo::Bump,
+    options: ParserOptions,
+    files: Vec<File<'arena>>,
+    ids: HashMap<&'arena str, FileId>,
+}
+
+struct File<'arena> {
+    path: &'arena str,
+    result: ParseResult<'arena, 'arena>,
+}
+
+impl<'arena> Session<'arena> {
+    /// An empty session parsing with the default [`ParserOptions`].
+    pub fn new(arena: &'arena bumpalo::Bump) -> Self {
+        Self::with_options(arena, ParserOptions::default())
+    }
+
+    /// An empty session parsing every file with `options`.
+    pub fn with_options(arena: &'arena bumpalo::Bump, options: ParserOptions) -> Self {
+        Self {
+            arena,
+            options,
+            files: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    /// The options every file is parsed with.
+    pub fn options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    /// Parse `source` as the file at `path` and return its handle. A path
+    /// parsed before keeps its [`FileId`] and gets the new result.
+    pub fn parse(&mut self, path: &str, source: &str) -> FileId {
+        let source = self.arena.alloc_str(source);
+        let result = parse_with_options(self.arena, source, &self.options);
+        if let Some(&id) = self.ids.get(path) {
+            self.files[id.index()].result = result;
+            return id;
+        }
+        let path = self.arena.alloc_str(path);
+        let id = FileId(self.files.len() as u32);
+        self.files.push(File { path, result });
+        self.ids.insert(path, id);
+        id
+    }
+
+    /// The handle of the file parsed at `path`, if any.
+    pub fn file_id(&self, path: &str) -> Option<FileId> {
+        self.ids.get(path).copied()
+    }
+
+    /// Every file in the session, in the order the paths were first parsed.
+    pub fn files(&self) -> impl ExactSizeIterator<Item = FileId> {
+        (0..self.files.len() as u32).map(FileId)
+    }
+
+    /// Number of files in the session.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// `true` when nothing has been parsed yet.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// The path `id` was parsed as.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` comes from another session.
+    pub fn path(&self, id: FileId) -> &'arena str {
+        self.files[id.index()].path
+    }
+
+    /// The full parse result of `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` comes from another session.
+    pub fn result(&self, id: FileId) -> &ParseResult<'arena, 'arena> {
+        &self.files[id.index()].result
+    }
+
+    /// The AST of `id`.
+    pub fn program(&self, id: FileId) -> &Program<'arena, 'arena> {
+        &self.result(id).program
+    }
+
+    /// The source text of `id`.
+    pub fn source(&self, id: FileId) -> &'arena str {
+        self.result(id).source
+    }
+
+    /// The line index of `id`.
+    pub fn source_map(&self, id: FileId) -> &SourceMap<'arena> {
+        &self.result(id).source_map
+    }
+
+    /// The diagnostics of `id`, in source order.
+    pub fn errors(&self, id: FileId) -> &[ParseError] {
+        &self.result(id).errors
+    }
+
+    /// The diagnostics of every file, grouped by file in [`files`](Self::files)
+    /// order and in source order within a file.
+    pub fn diagnostics(&self) -> impl Iterator<Item = (FileId, &ParseError)> {
+        self.files()
+            .flat_map(move |id| self.errors(id).iter().map(move |error| (id, error)))
+    }
+
+    /// `true` when any file has a diagnostic of [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics()
+            .any(|(_, error)| error.severity() == Severity::Error)
+    }
+
+    /// Render the diagnostics of every file with [`diagnostics::render_with`],
+    /// each under its path.
+    pub fn render_diagnostics(&self) -> String {
+        self.render_diagnostics_with(false)
+    }
+
+    /// [`render_diagnostics`](Self::render_diagnostics), with ANSI colors
+    /// when `color` is set.
+    pub fn render_diagnostics_with(&self, color: bool) -> String {
+        let mut out = String::new();
+        for id in self.files() {
+            let errors = self.errors(id);
+            if errors.is_empty() {
+                continue;
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let options = RenderOptions {
+                path: Some(self.path(id)),
+                color,
+            };
+            out.push_str(&diagnostics::render_with(
+                self.source_map(id),
+                errors,
+                &options,
+            ));
+        }
+        out
+    }
+}
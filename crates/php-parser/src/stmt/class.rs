@@ -1,11 +1,13 @@
+use std::collections::HashSet;
+
 use php_ast::*;
 use php_lexer::TokenKind;
 
 use crate::diagnostics::ParseError;
 use crate::expr;
 use crate::instrument;
-use crate::parser::Parser;
-use crate::version::PhpVersion;
+use crate::parser::{CaseInsensitive, Parser};
+use crate::version::{Feature, PhpVersion};
 
 // =============================================================================
 // Class declaration
@@ -24,18 +26,27 @@ fn visibility_rank(v: php_ast::Visibility) -> u8 {
     }
 }
 
-fn is_reserved_class_name(name: &str) -> bool {
+/// `true` for `private` and `protected`, which interface members may not use.
+fn is_restricted(visibility: Option<Visibility>) -> bool {
     matches!(
-        name.to_ascii_lowercase().as_str(),
+        visibility,
+        Some(Visibility::Private | Visibility::Protected)
+    )
+}
+
+fn is_reserved_class_name(name: &str) -> bool {
+    const RESERVED: &[&str] = &[
         // self / parent / static / readonly: meta-names that PHP rejects as
         // class identifiers in declarations and extends/implements lists.
-        "self" | "parent" | "static" | "readonly"
-            // PHP's reserved type names — invalid as class names anywhere
-            // ("Cannot use 'string' as a class name as it is reserved").
-            | "int" | "float" | "bool" | "string" | "true" | "false" | "null"
-            | "void" | "iterable" | "object" | "mixed" | "never" | "array"
-            | "numeric" | "resource"
-    )
+        "self", "parent", "static", "readonly",
+        // PHP's reserved type names — invalid as class names anywhere
+        // ("Cannot use 'string' as a class name as it is reserved").
+        "int", "float", "bool", "string", "true", "false", "null", "void", "iterable", "object",
+        "mixed", "never", "array", "numeric", "resource",
+    ];
+    RESERVED
+        .iter()
+        .any(|reserved| name.eq_ignore_ascii_case(reserved))
 }
 
 /// Validate a name used in extends/implements is not self/parent/static
@@ -45,7 +56,7 @@ fn validate_class_ref<'arena, 'src>(
 ) {
     if let Name::Simple { value, span } = name {
         if is_reserved_class_name(value) {
-            parser.error(ParseError::Forbidden {
+            parser.error_with(|| ParseError::Forbidden {
                 message: format!("cannot use '{}' as class name", value).into(),
                 span: *span,
             });
@@ -76,7 +87,7 @@ pub(super) fn parse_class<'arena, 'src>(
 
     if let Some(text) = name.as_str() {
         if is_reserved_class_name(text) {
-            parser.error(ParseError::Forbidden {
+            parser.error_with(|| ParseError::Forbidden {
                 message: format!("Cannot use \"{}\" as a class name as it is reserved", text)
                     .into(),
                 span: name_span,
@@ -355,9 +366,9 @@ pub fn parse_class_members<'arena, 'src>(
     let mut members = parser.alloc_vec_with_capacity(4);
     // Track method names (case-insensitive) to detect redeclarations.
     // PHP rejects with "Cannot redeclare A::f()".
-    let mut seen_methods: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut seen_methods: HashSet<CaseInsensitive<'src>> = HashSet::new();
     // Track property names (case-sensitive) for "Cannot redeclare A::$prop".
-    let mut seen_properties: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut seen_properties: HashSet<&'src str> = HashSet::new();
     while !parser.check(TokenKind::RightBrace) && !parser.check(TokenKind::Eof) {
         if parser.check(TokenKind::Semicolon) {
             parser.advance();
@@ -394,7 +405,14 @@ pub fn parse_class_members<'arena, 'src>(
         }
 
         if parser.check(TokenKind::Const) {
-            parse_class_const_member(parser, &mut members, member_attrs, member_start, &mods);
+            parse_class_const_member(
+                parser,
+                &mut members,
+                member_attrs,
+                member_start,
+                &mods,
+                in_interface,
+            );
             continue;
         }
 
@@ -403,9 +421,8 @@ pub fn parse_class_members<'arena, 'src>(
                 parse_method_member(parser, member_attrs, member_start, &mods, in_interface);
             if let ClassMemberKind::Method(decl) = &member.kind {
                 if let Some(name) = decl.name.as_str() {
-                    let key = name.to_ascii_lowercase();
-                    if !seen_methods.insert(key) {
-                        parser.error(ParseError::Forbidden {
+                    if !seen_methods.insert(CaseInsensitive(name)) {
+                        parser.error_with(|| ParseError::Forbidden {
                             message: format!("Cannot redeclare method {}()", name).into(),
                             span: member.span,
                         });
@@ -438,8 +455,8 @@ pub fn parse_class_members<'arena, 'src>(
             for m in members.iter().skip(before) {
                 if let ClassMemberKind::Property(decl) = &m.kind {
                     if let Some(name) = decl.name.as_str() {
-                        if !seen_properties.insert(name.to_string()) {
-                            parser.error(ParseError::Forbidden {
+                        if !seen_properties.insert(name) {
+                            parser.error_with(|| ParseError::Forbidden {
                                 message: format!("Cannot redeclare property ${}", name).into(),
                                 span: m.span,
                             });
@@ -623,7 +640,7 @@ fn parse_class_member_modifiers<'arena, 'src>(
                     });
                 }
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php81, "readonly properties", span);
+                parser.require_feature(Feature::ReadonlyProperties, span);
                 parser.advance();
                 is_readonly = true;
             }
@@ -648,13 +665,9 @@ fn parse_class_member_modifiers<'arena, 'src>(
     // Static asymmetric visibility requires PHP 8.5; instance requires PHP 8.4.
     if let Some(span) = asym_vis_span {
         if is_static {
-            parser.require_version(
-                PhpVersion::Php85,
-                "asymmetric visibility on static properties",
-                span,
-            );
+            parser.require_feature(Feature::StaticAsymmetricVisibility, span);
         } else {
-            parser.require_version(PhpVersion::Php84, "asymmetric visibility", span);
+            parser.require_feature(Feature::AsymmetricVisibility, span);
         }
     }
 
@@ -674,6 +687,7 @@ fn parse_class_const_member<'arena, 'src>(
     member_attrs: ArenaVec<'arena, Attribute<'arena, 'src>>,
     member_start: u32,
     mods: &ClassMemberModifiers,
+    in_interface: bool,
 ) {
     if mods.is_static {
         parser.error(ParseError::Forbidden {
@@ -703,7 +717,7 @@ fn parse_class_const_member<'arena, 'src>(
         && parser.peek_kind() != Some(TokenKind::Comma)
     {
         let span = parser.current_span();
-        parser.require_version(PhpVersion::Php83, "typed class constants", span);
+        parser.require_feature(Feature::TypedClassConstants, span);
         Some(parser.parse_type_hint())
     } else {
         None
@@ -740,6 +754,28 @@ fn parse_class_const_member<'arena, 'src>(
     }
     parser.expect(TokenKind::Semicolon);
     let span = Span::new(member_start, parser.previous_end());
+    for (name, _) in const_items.iter() {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        if in_interface && is_restricted(mods.visibility) {
+            parser.error_with(|| ParseError::Forbidden {
+                message: format!("Access type for interface constant {} must be public", name)
+                    .into(),
+                span,
+            });
+        }
+        if mods.is_final && mods.visibility == Some(Visibility::Private) {
+            parser.error_with(|| ParseError::Forbidden {
+                message: format!(
+                    "Private constant {} cannot be final as it is not visible to other classes",
+                    name
+                )
+                .into(),
+                span,
+            });
+        }
+    }
     if !member_attrs.is_empty() && const_items.len() > 1 {
         parser.error(ParseError::Forbidden {
             message: "cannot use attributes on multi-constant declaration".into(),
@@ -848,6 +884,16 @@ fn parse_method_member<'arena, 'src>(
             span: Span::new(member_start, parser.previous_end()),
         });
     }
+    if in_interface && is_restricted(mods.visibility) {
+        if let Some(name) = method_name.as_str() {
+            let span = Span::new(member_start, parser.previous_end());
+            parser.error_with(|| ParseError::Forbidden {
+                message: format!("Access type for interface method {}() must be public", name)
+                    .into(),
+                span,
+            });
+        }
+    }
     if mods.is_readonly {
         parser.error(ParseError::Forbidden {
             message: "Cannot use the readonly modifier on a method".into(),
@@ -889,11 +935,12 @@ fn parse_method_member<'arena, 'src>(
     // declared casing (e.g. "__cLoNe" → "__cLoNe()").
     if mods.is_static {
         if let Some(name) = method_name.as_str() {
-            let lower = name.to_ascii_lowercase();
-            if matches!(lower.as_str(), "__construct" | "__destruct" | "__clone") {
-                parser.error(ParseError::Forbidden {
+            let magic = ["__construct", "__destruct", "__clone"];
+            if magic.iter().any(|m| name.eq_ignore_ascii_case(m)) {
+                let span = Span::new(member_start, parser.previous_end());
+                parser.error_with(|| ParseError::Forbidden {
                     message: format!("Method {}() cannot be static", name).into(),
-                    span: Span::new(member_start, parser.previous_end()),
+                    span,
                 });
             }
         }
@@ -956,7 +1003,7 @@ fn parse_property_member<'arena, 'src>(
     }
     let hooks = if had_hooks_block {
         let span = parser.current_span();
-        parser.require_version(PhpVersion::Php84, "property hooks", span);
+        parser.require_feature(Feature::PropertyHooks, span);
         parse_property_hooks(parser)
     } else {
         parser.alloc_vec()
@@ -984,7 +1031,7 @@ fn parse_property_member<'arena, 'src>(
             if hook.kind == PropertyHookKind::Set {
                 if let Some(param) = hook.params.first() {
                     if param.type_hint.is_some() {
-                        parser.error(ParseError::Forbidden {
+                        parser.error_with(|| ParseError::Forbidden {
                             message: format!(
                                 "Type of parameter ${} of hook set must be compatible with property type",
                                 param.name
@@ -1003,7 +1050,13 @@ fn parse_property_member<'arena, 'src>(
             span: Span::new(member_start, parser.previous_end()),
         });
     }
-    if in_interface && hooks.is_empty() {
+    if in_interface && parser.version < PhpVersion::Php84 {
+        // Before 8.4 interfaces cannot declare properties at all, hooked or not.
+        parser.error(ParseError::Forbidden {
+            message: "Interfaces may not include properties".into(),
+            span: Span::new(member_start, parser.previous_end()),
+        });
+    } else if in_interface && hooks.is_empty() {
         parser.error(ParseError::Forbidden {
             message: "Interfaces may only include hooked properties".into(),
             span: Span::new(member_start, parser.previous_end()),
@@ -1122,7 +1175,7 @@ pub(super) fn parse_interface<'arena, 'src>(
 
     if let Some(text) = name.as_str() {
         if is_reserved_class_name(text) {
-            parser.error(ParseError::Forbidden {
+            parser.error_with(|| ParseError::Forbidden {
                 message: format!("cannot use '{}' as interface name", text).into(),
                 span: name_span,
             });
@@ -1,10 +1,12 @@
+use std::collections::HashSet;
+
 use php_ast::*;
 use php_lexer::TokenKind;
 
 use crate::diagnostics::ParseError;
 use crate::expr;
-use crate::parser::Parser;
-use crate::version::PhpVersion;
+use crate::parser::{CaseInsensitive, Parser};
+use crate::version::Feature;
 
 pub(super) fn parse_enum<'arena, 'src>(
     parser: &'_ mut Parser<'arena, 'src>,
@@ -45,7 +47,7 @@ pub(super) fn parse_enum<'arena, 'src>(
     let mut members = parser.alloc_vec_with_capacity(4);
     // Track case names (case-insensitive, since constants are too) to catch
     // PHP's "Cannot redefine class constant E::X".
-    let mut seen_cases: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut seen_cases: HashSet<CaseInsensitive<'src>> = HashSet::new();
     while !parser.check(TokenKind::RightBrace) && !parser.check(TokenKind::Eof) {
         if parser.check(TokenKind::Semicolon) {
             parser.advance();
@@ -109,7 +111,7 @@ pub(super) fn parse_enum<'arena, 'src>(
                 None
             };
             if scalar_type.is_some() && value.is_none() {
-                parser.error(ParseError::Forbidden {
+                parser.error_with(|| ParseError::Forbidden {
                     message: format!(
                         "Case {} of backed enum {} must have a value",
                         case_name, name
@@ -122,7 +124,7 @@ pub(super) fn parse_enum<'arena, 'src>(
                 let span = equals_token
                     .expect("value.is_some() guarantees equals_token.is_some()")
                     .span;
-                parser.error(ParseError::Forbidden {
+                parser.error_with(|| ParseError::Forbidden {
                     message: format!(
                         "Case {} of pure enum {} must not have a value",
                         case_name, name
@@ -134,9 +136,8 @@ pub(super) fn parse_enum<'arena, 'src>(
             parser.expect(TokenKind::Semicolon);
             let span = Span::new(member_start, parser.previous_end());
             if let Some(text) = case_name.as_str() {
-                let key = text.to_ascii_lowercase();
-                if !seen_cases.insert(key) {
-                    parser.error(ParseError::Forbidden {
+                if !seen_cases.insert(CaseInsensitive(text)) {
+                    parser.error_with(|| ParseError::Forbidden {
                         message: format!("Cannot redefine class constant {}::{}", name, text)
                             .into(),
                         span: case_name_span,
@@ -233,7 +234,7 @@ pub(super) fn parse_enum<'arena, 'src>(
                 && parser.peek_kind() != Some(TokenKind::Comma)
             {
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php83, "typed enum constants", span);
+                parser.require_feature(Feature::TypedEnumConstants, span);
                 let th = parser.parse_type_hint();
                 Some(parser.alloc(th))
             } else {
@@ -319,6 +320,13 @@ pub(super) fn parse_enum<'arena, 'src>(
             };
 
             let span = Span::new(member_start, parser.previous_end());
+            if is_final && visibility == Some(Visibility::Private) {
+                parser.error(ParseError::ForbiddenWarning {
+                    message: "Private methods cannot be final as they are never overridden by other classes"
+                        .into(),
+                    span,
+                });
+            }
             members.push(EnumMember {
                 kind: EnumMemberKind::Method(MethodDecl {
                     name: method_name,
@@ -4,8 +4,8 @@ use php_lexer::TokenKind;
 use crate::diagnostics::ParseError;
 use crate::expr;
 use crate::instrument;
-use crate::parser::Parser;
-use crate::version::PhpVersion;
+use crate::parser::{ParseContext, Parser};
+use crate::version::Feature;
 
 mod class;
 mod enum_decl;
@@ -23,6 +23,17 @@ pub use class::{parse_class_members, parse_name_list};
 /// pathologically deep input may observe a stack overflow. Use
 /// [`std::thread::Builder::stack_size`] to set a larger stack when needed.
 pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena, 'src> {
+    let outer = parser.enter_statement_contexts();
+    let delimiters = parser.delimiters.len();
+    let stmt = parse_stmt_inner(parser);
+    parser.restore_contexts(outer);
+    // Delimiters the statement left open are unclosed; they do not enclose
+    // the statements after it.
+    parser.delimiters.truncate(delimiters);
+    stmt
+}
+
+fn parse_stmt_inner<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena, 'src> {
     instrument::record_parse_stmt();
 
     // Handle attributes: #[...] before declarations
@@ -35,19 +46,14 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
         TokenKind::CloseTag => {
             parser.advance(); // consume ?>
             if parser.check(TokenKind::InlineHtml) {
-                let token = parser.advance();
-                let text = &parser.source[token.span.start as usize..token.span.end as usize];
                 // Leave the following OpenTag (if any) for the next parse_stmt call
-                return Stmt {
-                    kind: StmtKind::InlineHtml(text),
-                    span: token.span,
-                };
+                return parser.parse_inline_html();
             }
             // No inline HTML; fall through to consume any following OpenTag
             if parser.check(TokenKind::OpenTag) {
                 let tag = parser.advance();
                 if parser.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                    if let Some(echo_stmt) = parser.parse_short_echo() {
+                    if let Some(echo_stmt) = parser.parse_short_echo(tag.span) {
                         return echo_stmt;
                     }
                 }
@@ -62,7 +68,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
         TokenKind::OpenTag => {
             let tag = parser.advance();
             if parser.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                if let Some(echo_stmt) = parser.parse_short_echo() {
+                if let Some(echo_stmt) = parser.parse_short_echo(tag.span) {
                     return echo_stmt;
                 }
             }
@@ -132,7 +138,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
             {
                 // `abstract readonly class` — valid in PHP 8.4
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php84, "abstract readonly class", span);
+                parser.require_feature(Feature::AbstractReadonlyClasses, span);
                 parser.advance(); // consume 'readonly'
                 class::parse_class(
                     parser,
@@ -182,7 +188,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
                 )
             } else if parser.check(TokenKind::Readonly) {
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php82, "readonly class", span);
+                parser.require_feature(Feature::ReadonlyClasses, span);
                 parser.advance();
                 if parser.check(TokenKind::Class) {
                     class::parse_class(
@@ -224,7 +230,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
         TokenKind::Readonly => {
             if parser.peek_kind() == Some(TokenKind::Class) {
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php82, "readonly class", span);
+                parser.require_feature(Feature::ReadonlyClasses, span);
                 parser.advance(); // consume 'readonly'
                 class::parse_class(
                     parser,
@@ -239,7 +245,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
             {
                 // `readonly final class` — same as `final readonly class`
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php82, "readonly class", span);
+                parser.require_feature(Feature::ReadonlyClasses, span);
                 parser.advance(); // consume 'readonly'
                 parser.advance(); // consume 'final'
                 class::parse_class(
@@ -256,7 +262,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
             {
                 // `readonly abstract class` — valid in PHP 8.4
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php84, "abstract readonly class", span);
+                parser.require_feature(Feature::AbstractReadonlyClasses, span);
                 parser.advance(); // consume 'readonly'
                 parser.advance(); // consume 'abstract'
                 class::parse_class(
@@ -281,7 +287,7 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
             // `new Enum`, etc. — it's an ordinary name referring to a class/function.
             if matches!(parser.peek_kind(), Some(TokenKind::Identifier)) {
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php81, "enums", span);
+                parser.require_feature(Feature::Enums, span);
                 enum_decl::parse_enum(parser, parser.alloc_vec())
             } else {
                 parse_expression_stmt(parser)
@@ -315,7 +321,10 @@ pub fn parse_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
                 let span = Span::new(start, parser.previous_end());
                 Stmt {
                     kind: StmtKind::Expression(parser.alloc(Expr {
-                        kind: ExprKind::Identifier(NameStr::__arena("static")),
+                        kind: ExprKind::Name(Name::Simple {
+                            value: &parser.source[token.span.to_range()],
+                            span: token.span,
+                        }),
                         span: token.span,
                     })),
                     span,
@@ -415,7 +424,7 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             {
                 // `abstract readonly class` — valid in PHP 8.4
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php84, "abstract readonly class", span);
+                parser.require_feature(Feature::AbstractReadonlyClasses, span);
                 parser.advance(); // consume 'readonly'
                 return class::parse_class(
                     parser,
@@ -463,7 +472,7 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 )
             } else if parser.check(TokenKind::Readonly) {
                 let span = parser.current_span();
-                parser.require_version(PhpVersion::Php82, "readonly class", span);
+                parser.require_feature(Feature::ReadonlyClasses, span);
                 parser.advance();
                 class::parse_class(
                     parser,
@@ -501,7 +510,7 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             let readonly_span = parser.current_span();
             parser.advance();
             if parser.check(TokenKind::Class) {
-                parser.require_version(PhpVersion::Php82, "readonly class", readonly_span);
+                parser.require_feature(Feature::ReadonlyClasses, readonly_span);
                 class::parse_class(
                     parser,
                     ClassModifiers {
@@ -513,7 +522,7 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
             } else if parser.check(TokenKind::Final) && parser.peek_kind() == Some(TokenKind::Class)
             {
                 // `readonly final class` — same as `final readonly class`
-                parser.require_version(PhpVersion::Php82, "readonly class", readonly_span);
+                parser.require_feature(Feature::ReadonlyClasses, readonly_span);
                 parser.advance(); // consume 'final'
                 return class::parse_class(
                     parser,
@@ -528,7 +537,7 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
                 && parser.peek_kind() == Some(TokenKind::Class)
             {
                 // `readonly abstract class` — valid in PHP 8.4
-                parser.require_version(PhpVersion::Php84, "abstract readonly class", readonly_span);
+                parser.require_feature(Feature::AbstractReadonlyClasses, readonly_span);
                 parser.advance(); // consume 'abstract'
                 return class::parse_class(
                     parser,
@@ -557,13 +566,13 @@ fn parse_attributed_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
         TokenKind::Trait => return class::parse_trait(parser, attributes),
         TokenKind::Enum_ => {
             let span = parser.current_span();
-            parser.require_version(PhpVersion::Php81, "enums", span);
+            parser.require_feature(Feature::Enums, span);
             return enum_decl::parse_enum(parser, attributes);
         }
         TokenKind::Const => {
             // Attributes on top-level constants require PHP 8.5.
             let attr_span = parser.current_span();
-            parser.require_version(PhpVersion::Php85, "attributes on constants", attr_span);
+            parser.require_feature(Feature::AttributesOnConstants, attr_span);
             let stmt = parse_const_with_attrs(parser, attributes);
             // Multi-const declarations cannot carry attributes.
             if let StmtKind::Const(ref items) = stmt.kind {
@@ -610,17 +619,12 @@ pub fn parse_block<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'
         if parser.check(TokenKind::CloseTag) {
             parser.advance();
             if parser.check(TokenKind::InlineHtml) {
-                let token = parser.advance();
-                let text = &parser.source[token.span.start as usize..token.span.end as usize];
-                stmts.push(Stmt {
-                    kind: StmtKind::InlineHtml(text),
-                    span: token.span,
-                });
+                stmts.push(parser.parse_inline_html());
             }
             if parser.check(TokenKind::OpenTag) {
                 let tag = parser.advance();
                 if parser.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                    if let Some(echo_stmt) = parser.parse_short_echo() {
+                    if let Some(echo_stmt) = parser.parse_short_echo(tag.span) {
                         stmts.push(echo_stmt);
                     }
                 }
@@ -654,28 +658,42 @@ fn parse_stmt_or_block<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> St
     }
 }
 
-/// Parse statements until an end keyword (for alternative syntax)
+/// The keywords that close an alternative-syntax block; none of them can
+/// start a statement.
+const BLOCK_END_KEYWORDS: &[TokenKind] = &[
+    TokenKind::EndIf,
+    TokenKind::EndWhile,
+    TokenKind::EndFor,
+    TokenKind::EndForeach,
+    TokenKind::EndSwitch,
+    TokenKind::EndDeclare,
+];
+
+/// Parse statements until an end keyword (for alternative syntax).
+///
+/// A block missing its end keyword stops early rather than running to the
+/// end of the file, see [`ends_block_early`].
 fn parse_stmts_until_end<'arena, 'src>(
     parser: &'_ mut Parser<'arena, 'src>,
-    ends: &[TokenKind],
+    ends: &'static [TokenKind],
 ) -> ArenaVec<'arena, Stmt<'arena, 'src>> {
+    let outer_braces = parser.open_braces;
+    parser.block_ends.push(ends);
     let mut stmts = parser.alloc_vec_with_capacity(8);
-    while !ends.contains(&parser.current_kind()) && !parser.check(TokenKind::Eof) {
+    while !ends.contains(&parser.current_kind())
+        && !parser.check(TokenKind::Eof)
+        && !ends_block_early(parser, outer_braces)
+    {
         // Handle close tag -> inline HTML -> open tag sequences
         if parser.check(TokenKind::CloseTag) {
             parser.advance();
             if parser.check(TokenKind::InlineHtml) {
-                let token = parser.advance();
-                let text = &parser.source[token.span.start as usize..token.span.end as usize];
-                stmts.push(Stmt {
-                    kind: StmtKind::InlineHtml(text),
-                    span: token.span,
-                });
+                stmts.push(parser.parse_inline_html());
             }
             if parser.check(TokenKind::OpenTag) {
                 let tag = parser.advance();
                 if parser.source[tag.span.start as usize..tag.span.end as usize] == *"<?=" {
-                    if let Some(echo_stmt) = parser.parse_short_echo() {
+                    if let Some(echo_stmt) = parser.parse_short_echo(tag.span) {
                         stmts.push(echo_stmt);
                     }
                 }
@@ -688,9 +706,60 @@ fn parse_stmts_until_end<'arena, 'src>(
             parser.advance();
         }
     }
+    parser.block_ends.pop();
     stmts
 }
 
+/// Whether the current token ends the innermost alternative-syntax block
+/// before its own end keyword: a `}` closing a brace opened before the
+/// block (`outer_braces` were open when it started), an end keyword, `else`,
+/// or `case` that an enclosing block expects, an end keyword that no block
+/// expects, or the `?>` after which the file has no more PHP code.
+fn ends_block_early(parser: &mut Parser<'_, '_>, outer_braces: u32) -> bool {
+    let kind = parser.current_kind();
+    match kind {
+        TokenKind::RightBrace => outer_braces > 0,
+        TokenKind::CloseTag => match parser.peek_kind() {
+            Some(TokenKind::InlineHtml) => parser.peek2_kind() == Some(TokenKind::Eof),
+            next => next == Some(TokenKind::Eof),
+        },
+        _ => {
+            BLOCK_END_KEYWORDS.contains(&kind)
+                || parser.block_ends.iter().any(|ends| ends.contains(&kind))
+        }
+    }
+}
+
+/// Consume the `end` keyword and `;` that close an alternative-syntax block
+/// opened by the keyword at `opened_at`. A different end keyword that no
+/// enclosing block expects is taken to be a typo for `end` and consumed;
+/// anything else is left to the enclosing construct.
+fn expect_block_end(parser: &mut Parser<'_, '_>, end: TokenKind, opened_at: Span) {
+    if parser.eat(end).is_some() {
+        parser.expect_semicolon(end);
+        return;
+    }
+    let found = parser.current_kind();
+    let span = parser.current_span();
+    let claimed = parser.block_ends.iter().any(|ends| ends.contains(&found));
+    if BLOCK_END_KEYWORDS.contains(&found) && !claimed {
+        parser.error(ParseError::Expected {
+            expected: end.label().into(),
+            found,
+            span,
+        });
+        parser.advance();
+        parser.expect_semicolon(found);
+    } else {
+        parser.error(ParseError::UnclosedBlock {
+            expected: end.label().into(),
+            found,
+            opened_at,
+            span,
+        });
+    }
+}
+
 // =============================================================================
 // Echo statement
 // =============================================================================
@@ -713,7 +782,11 @@ fn parse_echo<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena
     let span = Span::new(start, parser.previous_end());
 
     Stmt {
-        kind: StmtKind::Echo(exprs),
+        kind: StmtKind::Echo(EchoStmt {
+            exprs,
+            open_tag: None,
+            close_tag: None,
+        }),
         span,
     }
 }
@@ -729,7 +802,7 @@ fn parse_return<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'are
     let expr = if parser.check(TokenKind::Semicolon) {
         None
     } else {
-        Some(expr::parse_expr(parser))
+        Some(parser.with_context(ParseContext::ReturnValue, expr::parse_expr))
     };
 
     parser.expect_semicolon("return statement");
@@ -749,11 +822,11 @@ fn parse_if<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
     instrument::record_parse_if();
 
     let start = parser.start_span();
-    parser.advance(); // consume 'if'
+    let keyword = parser.advance().span;
 
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
-    let condition = expr::parse_expr(parser);
+    let condition = parser.with_context(ParseContext::Condition("if"), expr::parse_expr);
     parser.expect_closing(TokenKind::RightParen, open_span);
 
     // Alternative syntax: if (...): ... endif;
@@ -771,7 +844,8 @@ fn parse_if<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
         while parser.eat(TokenKind::ElseIf).is_some() {
             let elseif_start = parser.start_span();
             parser.expect(TokenKind::LeftParen);
-            let elseif_cond = expr::parse_expr(parser);
+            let elseif_cond =
+                parser.with_context(ParseContext::Condition("elseif"), expr::parse_expr);
             parser.expect(TokenKind::RightParen);
             parser.expect(TokenKind::Colon);
             let elseif_stmts = parse_stmts_until_end(
@@ -801,8 +875,7 @@ fn parse_if<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
             None
         };
 
-        parser.expect(TokenKind::EndIf);
-        parser.expect_semicolon(TokenKind::EndIf);
+        expect_block_end(parser, TokenKind::EndIf, keyword);
         let span = Span::new(start, parser.previous_end());
 
         return Stmt {
@@ -825,7 +898,7 @@ fn parse_if<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
     while parser.eat(TokenKind::ElseIf).is_some() {
         let elseif_start = parser.start_span();
         parser.expect(TokenKind::LeftParen);
-        let elseif_cond = expr::parse_expr(parser);
+        let elseif_cond = parser.with_context(ParseContext::Condition("elseif"), expr::parse_expr);
         parser.expect(TokenKind::RightParen);
         let elseif_body = parse_stmt_or_block(parser);
         let elseif_span = Span::new(elseif_start, elseif_body.span.end);
@@ -872,18 +945,17 @@ fn parse_while<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'aren
     instrument::record_parse_loop();
 
     let start = parser.start_span();
-    parser.advance();
+    let keyword = parser.advance().span;
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
-    let condition = expr::parse_expr(parser);
+    let condition = parser.with_context(ParseContext::Condition("while"), expr::parse_expr);
     parser.expect_closing(TokenKind::RightParen, open_span);
 
     if parser.eat(TokenKind::Colon).is_some() {
         parser.loop_depth += 1;
         let stmts = parse_stmts_until_end(parser, &[TokenKind::EndWhile]);
         parser.loop_depth -= 1;
-        parser.expect(TokenKind::EndWhile);
-        parser.expect_semicolon(TokenKind::EndWhile);
+        expect_block_end(parser, TokenKind::EndWhile, keyword);
         let span = Span::new(start, parser.previous_end());
         let body = parser.alloc(Stmt {
             kind: StmtKind::Block(stmts),
@@ -926,7 +998,7 @@ fn parse_do_while<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'a
     parser.expect(TokenKind::While);
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
-    let condition = expr::parse_expr(parser);
+    let condition = parser.with_context(ParseContext::Condition("do-while"), expr::parse_expr);
     parser.expect_closing(TokenKind::RightParen, open_span);
     parser.expect_semicolon("do-while statement");
     let span = Span::new(start, parser.previous_end());
@@ -940,22 +1012,21 @@ fn parse_for<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
     instrument::record_parse_loop();
 
     let start = parser.start_span();
-    parser.advance();
+    let keyword = parser.advance().span;
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
-    let init = parse_expr_list_until(parser, TokenKind::Semicolon);
+    let (init, init_span) = parse_for_clause(parser, TokenKind::Semicolon);
     parser.expect(TokenKind::Semicolon);
-    let condition = parse_expr_list_until(parser, TokenKind::Semicolon);
+    let (condition, condition_span) = parse_for_clause(parser, TokenKind::Semicolon);
     parser.expect(TokenKind::Semicolon);
-    let update = parse_expr_list_until(parser, TokenKind::RightParen);
+    let (update, update_span) = parse_for_clause(parser, TokenKind::RightParen);
     parser.expect_closing(TokenKind::RightParen, open_span);
 
     if parser.eat(TokenKind::Colon).is_some() {
         parser.loop_depth += 1;
         let stmts = parse_stmts_until_end(parser, &[TokenKind::EndFor]);
         parser.loop_depth -= 1;
-        parser.expect(TokenKind::EndFor);
-        parser.expect_semicolon(TokenKind::EndFor);
+        expect_block_end(parser, TokenKind::EndFor, keyword);
         let span = Span::new(start, parser.previous_end());
         let body = parser.alloc(Stmt {
             kind: StmtKind::Block(stmts),
@@ -966,6 +1037,9 @@ fn parse_for<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
                 init,
                 condition,
                 update,
+                init_span,
+                condition_span,
+                update_span,
                 body,
                 uses_alternative: true,
             })),
@@ -983,6 +1057,9 @@ fn parse_for<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
             init,
             condition,
             update,
+            init_span,
+            condition_span,
+            update_span,
             body,
             uses_alternative: false,
         })),
@@ -990,6 +1067,21 @@ fn parse_for<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
     }
 }
 
+/// One clause of a `for` header and its span, empty at `stop` when the
+/// clause is omitted.
+fn parse_for_clause<'arena, 'src>(
+    parser: &'_ mut Parser<'arena, 'src>,
+    stop: TokenKind,
+) -> (ArenaVec<'arena, Expr<'arena, 'src>>, Span) {
+    let at = parser.current_span().start;
+    let exprs = parse_expr_list_until(parser, stop);
+    let span = match (exprs.first(), exprs.last()) {
+        (Some(first), Some(last)) => Span::new(first.span.start, last.span.end),
+        _ => Span::new(at, at),
+    };
+    (exprs, span)
+}
+
 fn parse_expr_list_until<'arena, 'src>(
     parser: &'_ mut Parser<'arena, 'src>,
     stop: TokenKind,
@@ -1012,7 +1104,7 @@ fn parse_foreach<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'ar
     instrument::record_parse_foreach();
 
     let start = parser.start_span();
-    parser.advance();
+    let keyword = parser.advance().span;
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
     let collection = expr::parse_expr(parser);
@@ -1023,7 +1115,7 @@ fn parse_foreach<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'ar
     }
     let first = expr::parse_expr(parser);
 
-    let (key, value) = if parser.eat(TokenKind::FatArrow).is_some() {
+    let (key, mut value) = if parser.eat(TokenKind::FatArrow).is_some() {
         if parser.check(TokenKind::Ampersand) {
             parser.advance();
         }
@@ -1032,6 +1124,7 @@ fn parse_foreach<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'ar
     } else {
         (None, first)
     };
+    expr::mark_destructuring(&mut value);
 
     parser.expect_closing(TokenKind::RightParen, open_span);
 
@@ -1039,8 +1132,7 @@ fn parse_foreach<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'ar
         parser.loop_depth += 1;
         let stmts = parse_stmts_until_end(parser, &[TokenKind::EndForeach]);
         parser.loop_depth -= 1;
-        parser.expect(TokenKind::EndForeach);
-        parser.expect_semicolon(TokenKind::EndForeach);
+        expect_block_end(parser, TokenKind::EndForeach, keyword);
         let span = Span::new(start, parser.previous_end());
         let body = parser.alloc(Stmt {
             kind: StmtKind::Block(stmts),
@@ -1104,7 +1196,7 @@ fn parse_function<'arena, 'src>(
                 | TokenKind::Self_
                 | TokenKind::Parent_
         ) {
-            parser.error(ParseError::Forbidden {
+            parser.error_with(|| ParseError::Forbidden {
                 message: format!("cannot use '{}' as function name; it is reserved", text).into(),
                 span,
             });
@@ -1263,10 +1355,10 @@ pub(crate) fn check_returns_against_type<'arena, 'src>(
 pub fn parse_param_list<'arena, 'src>(
     parser: &'_ mut Parser<'arena, 'src>,
 ) -> ArenaVec<'arena, Param<'arena, 'src>> {
-    let mut params = parser.alloc_vec_with_capacity(4);
     if parser.check(TokenKind::RightParen) {
-        return params;
+        return parser.alloc_vec();
     }
+    let base = parser.start_params();
 
     loop {
         if parser.check(TokenKind::RightParen) {
@@ -1285,9 +1377,11 @@ pub fn parse_param_list<'arena, 'src>(
         // Current token is variable (no type hint)
         {
             // Try fast path: just parse $var with no type or default
-            if let Some(param) = try_parse_simple_param_fastpath_minimal(parser, param_start) {
-                params.push(param);
-                if parser.eat(TokenKind::Comma).is_none() {
+            if let Some(mut param) = try_parse_simple_param_fastpath_minimal(parser, param_start) {
+                let comma = parser.eat(TokenKind::Comma);
+                param.comma = comma.map(|token| token.span);
+                parser.push_param(param);
+                if comma.is_none() {
                     break;
                 }
                 continue;
@@ -1323,7 +1417,7 @@ pub fn parse_param_list<'arena, 'src>(
                         && parser.peek2_text() == Some("set")
                     {
                         let span = Span::new(param_start, parser.previous_end());
-                        parser.require_version(PhpVersion::Php84, "asymmetric visibility", span);
+                        parser.require_feature(Feature::AsymmetricVisibility, span);
                         if set_visibility.is_some() {
                             parser.error(ParseError::Forbidden {
                                 message: "cannot use multiple set-visibility modifiers".into(),
@@ -1339,11 +1433,7 @@ pub fn parse_param_list<'arena, 'src>(
                         set_visibility = Some(vis);
                     } else {
                         let span = parser.current_span();
-                        parser.require_version(
-                            PhpVersion::Php80,
-                            "constructor property promotion",
-                            span,
-                        );
+                        parser.require_feature(Feature::ConstructorPromotion, span);
                         if visibility.is_some() {
                             parser.error(ParseError::Forbidden {
                                 message: "cannot use multiple visibility modifiers".into(),
@@ -1356,11 +1446,7 @@ pub fn parse_param_list<'arena, 'src>(
                 }
                 TokenKind::Final => {
                     first_modifier_span.get_or_insert(current_span);
-                    parser.require_version(
-                        PhpVersion::Php85,
-                        "final promoted properties",
-                        current_span,
-                    );
+                    parser.require_feature(Feature::FinalPromotedProperties, current_span);
                     if is_final {
                         parser.error(ParseError::Forbidden {
                             message: "duplicate modifier 'final'".into(),
@@ -1372,7 +1458,7 @@ pub fn parse_param_list<'arena, 'src>(
                 }
                 TokenKind::Readonly => {
                     first_modifier_span.get_or_insert(current_span);
-                    parser.require_version(PhpVersion::Php81, "readonly parameters", current_span);
+                    parser.require_feature(Feature::ReadonlyParameters, current_span);
                     if is_readonly {
                         parser.error(ParseError::Forbidden {
                             message: "duplicate modifier 'readonly'".into(),
@@ -1480,7 +1566,8 @@ pub fn parse_param_list<'arena, 'src>(
             param_end
         };
 
-        params.push(Param {
+        let comma = parser.eat(TokenKind::Comma);
+        parser.push_param(Param {
             name,
             type_hint,
             default,
@@ -1493,14 +1580,14 @@ pub fn parse_param_list<'arena, 'src>(
             attributes: param_attrs,
             hooks,
             span: Span::new(param_start, param_end),
+            comma: comma.map(|token| token.span),
         });
-
-        if parser.eat(TokenKind::Comma).is_none() {
+        if comma.is_none() {
             break;
         }
     }
 
-    params
+    parser.finish_params(base)
 }
 
 /// Minimal fast path: parse just $var with no type hint, no default, no visibility
@@ -1544,6 +1631,7 @@ fn try_parse_simple_param_fastpath_minimal<'arena, 'src>(
         attributes: parser.alloc_vec(),
         hooks: parser.alloc_vec(),
         span: Span::new(param_start, name_span_end),
+        comma: None,
     })
 }
 
@@ -1600,7 +1688,7 @@ fn validate_break_continue<'arena, 'src>(
         None => {
             // Implicit level 1
             if loop_depth == 0 {
-                parser.error(ParseError::Forbidden {
+                parser.error_with(|| ParseError::Forbidden {
                     message: format!("Cannot '{}' 1 level", kw).into(),
                     span,
                 });
@@ -1609,7 +1697,7 @@ fn validate_break_continue<'arena, 'src>(
         Some(e) => {
             if let ExprKind::Int(n) = e.kind {
                 if n <= 0 {
-                    parser.error(ParseError::Forbidden {
+                    parser.error_with(|| ParseError::Forbidden {
                         message: format!("'{}' operator accepts only positive integers", kw).into(),
                         span,
                     });
@@ -1619,7 +1707,7 @@ fn validate_break_continue<'arena, 'src>(
                     } else {
                         format!("{} levels", n)
                     };
-                    parser.error(ParseError::Forbidden {
+                    parser.error_with(|| ParseError::Forbidden {
                         message: format!("Cannot '{}' {}", kw, levels).into(),
                         span,
                     });
@@ -1639,10 +1727,10 @@ fn parse_switch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'are
     instrument::record_parse_switch();
 
     let start = parser.start_span();
-    parser.advance();
+    let keyword = parser.advance().span;
     let open = parser.expect(TokenKind::LeftParen);
     let open_span = open.map(|t| t.span).unwrap_or(parser.current_span());
-    let switch_expr = expr::parse_expr(parser);
+    let switch_expr = parser.with_context(ParseContext::Condition("switch"), expr::parse_expr);
     parser.expect_closing(TokenKind::RightParen, open_span);
 
     let alt_syntax = parser.eat(TokenKind::Colon).is_some();
@@ -1658,6 +1746,14 @@ fn parse_switch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'are
     } else {
         &[TokenKind::RightBrace]
     };
+    // A block in a case body that misses its end keyword stops at the next
+    // case, and an alternative-syntax switch stops like a block.
+    let outer_braces = parser.open_braces;
+    parser.block_ends.push(if alt_syntax {
+        &[TokenKind::Case, TokenKind::Default, TokenKind::EndSwitch]
+    } else {
+        &[TokenKind::Case, TokenKind::Default]
+    });
     let mut cases = parser.alloc_vec_with_capacity(8);
     let mut seen_default_span: Option<Span> = None;
 
@@ -1694,6 +1790,9 @@ fn parse_switch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'are
             && !end_tokens.contains(&parser.current_kind())
             && !parser.check(TokenKind::Eof)
         {
+            if alt_syntax && ends_block_early(parser, outer_braces) {
+                break;
+            }
             body.push(parse_stmt(parser));
         }
 
@@ -1705,10 +1804,10 @@ fn parse_switch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'are
     }
 
     parser.loop_depth -= 1;
+    parser.block_ends.pop();
 
     if alt_syntax {
-        parser.expect(TokenKind::EndSwitch);
-        parser.expect_semicolon(TokenKind::EndSwitch);
+        expect_block_end(parser, TokenKind::EndSwitch, keyword);
     } else {
         parser.expect(TokenKind::RightBrace);
     }
@@ -1762,14 +1861,17 @@ fn parse_try_catch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'
         parser.expect(TokenKind::LeftParen);
 
         let mut types = parser.alloc_vec();
-        types.push(parser.parse_name());
+        types.push(parse_catch_type(parser));
         while parser.eat(TokenKind::Pipe).is_some() {
-            types.push(parser.parse_name());
+            types.push(parse_catch_type(parser));
         }
 
         let var = if parser.check(TokenKind::Variable) {
             let t = parser.advance();
-            Some(parser.variable_name(t))
+            Some(CatchVar {
+                name: parser.variable_name(t),
+                span: t.span,
+            })
         } else {
             None
         };
@@ -1829,6 +1931,23 @@ fn parse_try_catch<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'
     }
 }
 
+/// One alternative of a catch type list. `static` lexes as a name but is not
+/// a class name here, as in PHP.
+fn parse_catch_type<'arena, 'src>(parser: &mut Parser<'arena, 'src>) -> ClassRef<'arena, 'src> {
+    let name = parser.parse_name();
+    let class = expr::class_ref_from_name(parser, name);
+    match class {
+        ClassRef::SelfKw(_) | ClassRef::ParentKw(_) => parser.catch_class_keywords = true,
+        ClassRef::StaticKw(span) => parser.error(ParseError::Expected {
+            expected: "class name".into(),
+            found: TokenKind::Static,
+            span,
+        }),
+        _ => {}
+    }
+    class
+}
+
 // =============================================================================
 // Goto / Declare / Unset / Global
 // =============================================================================
@@ -1851,7 +1970,7 @@ fn parse_goto<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena
 
 fn parse_declare<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena, 'src> {
     let start = parser.start_span();
-    parser.advance();
+    let keyword = parser.advance().span;
     parser.expect(TokenKind::LeftParen);
     let mut directives = parser.alloc_vec();
     loop {
@@ -1876,8 +1995,7 @@ fn parse_declare<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'ar
         (None, false)
     } else if parser.eat(TokenKind::Colon).is_some() {
         let stmts = parse_stmts_until_end(parser, &[TokenKind::EndDeclare]);
-        parser.expect(TokenKind::EndDeclare);
-        parser.expect_semicolon(TokenKind::EndDeclare);
+        expect_block_end(parser, TokenKind::EndDeclare, keyword);
         let block = parser.alloc(Stmt {
             kind: StmtKind::Block(stmts),
             span: Span::new(start, parser.previous_end()),
@@ -2155,7 +2273,7 @@ fn parse_use<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
                 UseKind::Const => &mut seen_const,
             };
             if !local_name.is_empty() && !seen.insert(local_name) {
-                parser.error(ParseError::Forbidden {
+                parser.error_with(|| ParseError::Forbidden {
                     message: format!(
                         "cannot import {} as {} because the name is already in use",
                         combined_name.parts_slice().join("\\"),
@@ -2165,14 +2283,15 @@ fn parse_use<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
                     span: use_span,
                 });
             }
+            let comma = parser.eat(TokenKind::Comma);
             uses.push(UseItem {
                 name: combined_name,
                 alias,
                 kind: item_kind,
                 span: use_span,
+                comma: comma.map(|token| token.span),
             });
-
-            if parser.eat(TokenKind::Comma).is_none() {
+            if comma.is_none() {
                 break;
             }
         }
@@ -2193,9 +2312,13 @@ fn parse_use<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
             alias,
             kind: None,
             span: item_span,
+            comma: None,
         });
 
-        while parser.eat(TokenKind::Comma).is_some() {
+        while let Some(comma) = parser.eat(TokenKind::Comma) {
+            if let Some(item) = uses.last_mut() {
+                item.comma = Some(comma.span);
+            }
             if parser.check(TokenKind::Semicolon) {
                 break;
             } // trailing comma
@@ -2216,6 +2339,7 @@ fn parse_use<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena,
                 alias,
                 kind: None,
                 span: next_span,
+                comma: None,
             });
         }
     }
@@ -2319,8 +2443,11 @@ fn parse_halt_compiler<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> St
 
     // Everything after the terminator is raw data. Use previous_end() to capture
     // the position right after the terminator, preserving any whitespace.
-    let current_pos = parser.previous_end() as usize;
-    let remaining = &parser.source[current_pos..];
+    let data_span = Span::new(parser.previous_end(), parser.source.len() as u32);
+    let data = HaltCompilerData {
+        text: &parser.source[data_span.to_range()],
+        span: data_span,
+    };
 
     // Advance to EOF so the parser stops
     while !parser.check(TokenKind::Eof) {
@@ -2329,7 +2456,7 @@ fn parse_halt_compiler<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> St
 
     let span = Span::new(start, parser.previous_end());
     Stmt {
-        kind: StmtKind::HaltCompiler(remaining),
+        kind: StmtKind::HaltCompiler(data),
         span,
     }
 }
@@ -2398,12 +2525,10 @@ fn parse_expression_stmt_or_label<'arena, 'src>(
     let start = parser.start_span();
     let expr = expr::parse_expr(parser);
 
-    if let ExprKind::Identifier(name) = expr.kind {
+    if let ExprKind::ConstFetch(Name::Simple { value, .. }) = expr.kind {
         if parser.eat(TokenKind::Colon).is_some() {
             let span = Span::new(start, parser.previous_end());
-            let label: &'arena str = name
-                .__into_arena_str()
-                .unwrap_or_else(|| parser.arena.alloc_str(name.as_str()));
+            let label: &'arena str = parser.arena.alloc_str(value);
             return Stmt {
                 kind: StmtKind::Label(label),
                 span,
@@ -2429,6 +2554,7 @@ fn parse_expression_stmt_or_label<'arena, 'src>(
 
 fn parse_expression_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) -> Stmt<'arena, 'src> {
     let start = parser.start_span();
+    let void_casts = parser.void_casts;
     let expr = expr::parse_expr(parser);
 
     if matches!(expr.kind, ExprKind::Error) {
@@ -2452,7 +2578,11 @@ fn parse_expression_stmt<'arena, 'src>(parser: &'_ mut Parser<'arena, 'src>) ->
     // A void cast is invalid in a position where its result is consumed as a value.
     // Valid: `(void)foo();` (statement), `(void)$a or $b;` (logical-op left).
     // Invalid: `$x = (void)$y;`, `(void)1 + 2;`, `(void)(void)$x;`.
-    if let Some(span) = check_void_cast_stmt_expr(&expr) {
+    let has_void_cast = parser.void_casts != void_casts;
+    if let Some(span) = has_void_cast
+        .then(|| check_void_cast_stmt_expr(&expr))
+        .flatten()
+    {
         parser.error(ParseError::Forbidden {
             message: "(void) cast cannot be used in an expression context".into(),
             span,
@@ -0,0 +1,257 @@
+//! "Did you mean …" suggestions for misspelled keywords and names.
+//!
+//! A statement such as `funtion f() {}` or `elsif ($a) {}` starts with a
+//! bare identifier that PHP reads as a constant or a function call, so the
+//! error it reports lands on whatever follows. When that identifier is one or
+//! two edits away from a statement keyword, or from a function or class
+//! declared in the file, [`misspellings`] reports a
+//! [`ParseError::Misspelled`] naming the likely intended word.
+
+use std::ops::ControlFlow;
+
+use php_ast::visitor::{walk_stmt, Visitor};
+use php_ast::{Span, Stmt, StmtKind};
+use php_lexer::token::resolve_keyword;
+
+use crate::diagnostics::{ParseError, Severity};
+
+/// Shorter identifiers are too close to too many keywords to guess at.
+const MIN_LENGTH: usize = 4;
+
+/// Keywords that start a statement or a declaration, in the order ties are
+/// broken.
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "function",
+    "class",
+    "interface",
+    "trait",
+    "enum",
+    "abstract",
+    "final",
+    "readonly",
+    "namespace",
+    "use",
+    "const",
+    "if",
+    "elseif",
+    "else",
+    "endif",
+    "while",
+    "endwhile",
+    "for",
+    "endfor",
+    "foreach",
+    "endforeach",
+    "switch",
+    "endswitch",
+    "declare",
+    "enddeclare",
+    "return",
+    "break",
+    "continue",
+    "echo",
+    "print",
+    "throw",
+    "try",
+    "catch",
+    "finally",
+    "global",
+    "static",
+    "unset",
+    "goto",
+    "include",
+    "include_once",
+    "require",
+    "require_once",
+    "yield",
+];
+
+/// A [`ParseError::Misspelled`] for each statement in `stmts` that starts
+/// with an identifier close to a keyword or declared name, where the first
+/// error after the identifier is right after it or after the argument list
+/// following it.
+pub(crate) fn misspellings(
+    source: &str,
+    stmts: &[Stmt<'_, '_>],
+    errors: &[ParseError],
+) -> Vec<ParseError> {
+    let mut error_starts: Vec<u32> = errors
+        .iter()
+        .filter(|error| error.severity() == Severity::Error)
+        .map(|error| error.span().start)
+        .collect();
+    if error_starts.is_empty() {
+        return Vec::new();
+    }
+    error_starts.sort_unstable();
+
+    let mut collector = Collector::default();
+    for stmt in stmts {
+        let _ = collector.visit_stmt(stmt);
+    }
+
+    let mut found = Vec::new();
+    for span in collector.candidates {
+        let Some((word, end)) = leading_identifier(source, span.start) else {
+            continue;
+        };
+        let lower = word.to_ascii_lowercase();
+        if word.len() < MIN_LENGTH
+            || resolve_keyword(word).is_some()
+            || collector.declared.contains(&lower)
+        {
+            continue;
+        }
+        // A statement starting where an error was reported is what recovery
+        // made of the rest of a broken one, as `f() {}` in `funtion f() {}`.
+        if error_starts.binary_search(&span.start).is_ok() {
+            continue;
+        }
+        let next = error_starts.partition_point(|&start| (start as usize) < end);
+        let Some(&error_at) = error_starts.get(next) else {
+            continue;
+        };
+        if !error_follows(source, end, error_at) {
+            continue;
+        }
+        let names = STATEMENT_KEYWORDS
+            .iter()
+            .copied()
+            .chain(collector.names.iter().map(String::as_str));
+        if let Some(suggestion) = closest(&lower, names) {
+            found.push(ParseError::Misspelled {
+                found: word.to_string().into(),
+                suggestion: suggestion.to_string().into(),
+                span: Span::new(span.start, end as u32),
+            });
+        }
+    }
+    found
+}
+
+/// Declared functions and classes, and the statements that are candidates.
+#[derive(Default)]
+struct Collector {
+    /// Declared names as written, for suggestions.
+    names: Vec<String>,
+    /// Lower-cased declared names, which are never misspellings.
+    declared: std::collections::HashSet<String>,
+    /// Spans of expression statements.
+    candidates: Vec<Span>,
+}
+
+impl<'arena, 'src> Visitor<'arena, 'src> for Collector {
+    fn visit_stmt(&mut self, stmt: &Stmt<'arena, 'src>) -> ControlFlow<()> {
+        let name = match &stmt.kind {
+            StmtKind::Expression(_) => {
+                self.candidates.push(stmt.span);
+                None
+            }
+            StmtKind::Function(decl) => decl.name.as_str(),
+            StmtKind::Class(decl) => decl.name.as_ref().and_then(|name| name.as_str()),
+            StmtKind::Interface(decl) => decl.name.as_str(),
+            StmtKind::Trait(decl) => decl.name.as_str(),
+            StmtKind::Enum(decl) => decl.name.as_str(),
+            _ => None,
+        };
+        if let Some(name) = name {
+            if self.declared.insert(name.to_ascii_lowercase()) {
+                self.names.push(name.to_string());
+            }
+        }
+        walk_stmt(self, stmt)
+    }
+}
+
+/// The identifier starting at `start` and the offset after it. Qualified
+/// names are not candidates.
+fn leading_identifier(source: &str, start: u32) -> Option<(&str, usize)> {
+    let bytes = source.as_bytes();
+    let start = start as usize;
+    let first = *bytes.get(start)?;
+    if !(first.is_ascii_alphabetic() || first == b'_') {
+        return None;
+    }
+    let end = start
+        + bytes[start..]
+            .iter()
+            .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80)
+            .count();
+    if bytes.get(end) == Some(&b'\\') {
+        return None;
+    }
+    Some((&source[start..end], end))
+}
+
+/// Whether `error_at` is the first token after offset `end`, or the first
+/// token after the parenthesized list that follows it.
+fn error_follows(source: &str, end: usize, error_at: u32) -> bool {
+    let bytes = source.as_bytes();
+    let skip_space = |mut i: usize| {
+        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        i
+    };
+    let next = skip_space(end);
+    if next == error_at as usize {
+        return true;
+    }
+    if bytes.get(next) != Some(&b'(') {
+        return false;
+    }
+    let mut depth = 0usize;
+    for (i, &b) in bytes.iter().enumerate().skip(next) {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return skip_space(i + 1) == error_at as usize;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The name closest to `word` within the allowed distance: one edit for
+/// words of up to four characters, two for longer ones. Ties go to the
+/// first name.
+fn closest<'n>(word: &str, names: impl Iterator<Item = &'n str>) -> Option<&'n str> {
+    let limit = if word.len() <= 4 { 1 } else { 2 };
+    let mut best: Option<(usize, &str)> = None;
+    for name in names {
+        let distance = edit_distance(word, &name.to_ascii_lowercase());
+        if distance <= limit && best.is_none_or(|(d, _)| distance < d) {
+            best = Some((distance, name));
+        }
+    }
+    best.map(|(_, name)| name)
+}
+
+/// Optimal string alignment distance: insertions, deletions,
+/// substitutions, and transpositions of adjacent characters each cost one.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
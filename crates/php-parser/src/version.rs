@@ -11,6 +11,7 @@ use std::fmt;
 ///
 /// Defaults to [`PhpVersion::Php85`] (the latest supported version).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum PhpVersion {
     /// PHP 7.4 — arrow functions, typed properties, spread in array expressions, numeric literal separator.
     Php74,
@@ -43,3 +44,196 @@ impl fmt::Display for PhpVersion {
         }
     }
 }
+
+impl PhpVersion {
+    /// The oldest version the parser targets.
+    pub const OLDEST: PhpVersion = PhpVersion::Php74;
+
+    /// The php.net page listing the features new in this version.
+    pub fn new_features_url(self) -> &'static str {
+        match self {
+            PhpVersion::Php74 => "https://www.php.net/manual/en/migration74.new-features.php",
+            PhpVersion::Php80 => "https://www.php.net/manual/en/migration80.new-features.php",
+            PhpVersion::Php81 => "https://www.php.net/manual/en/migration81.new-features.php",
+            PhpVersion::Php82 => "https://www.php.net/manual/en/migration82.new-features.php",
+            PhpVersion::Php83 => "https://www.php.net/manual/en/migration83.new-features.php",
+            PhpVersion::Php84 => "https://www.php.net/manual/en/migration84.new-features.php",
+            PhpVersion::Php85 => "https://www.php.net/manual/en/migration85.new-features.php",
+        }
+    }
+}
+
+/// Syntax newer than PHP 7.4, with the version that introduced it.
+///
+/// The parser reports the features it gates as
+/// [`ParseError::VersionTooLow`](crate::diagnostics::ParseError::VersionTooLow)
+/// when the targeted version is older. A few, such as attributes, which
+/// PHP 7.4 reads as a comment, parse the same either way and are not
+/// gated; version inference over the AST detects those.
+///
+/// ```
+/// use php_rs_parser::{Feature, PhpVersion};
+///
+/// assert_eq!(Feature::Enums.required(), PhpVersion::Php81);
+/// assert_eq!(Feature::Enums.code(), "enums");
+/// assert_eq!(Feature::NullsafeOperator.to_string(), "nullsafe operator (?->)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    UnionTypes,
+    MixedType,
+    NullType,
+    FalseType,
+    MatchExpressions,
+    NamedArguments,
+    NullsafeOperator,
+    ThrowExpressions,
+    ConstructorPromotion,
+    /// Not gated by the parser.
+    Attributes,
+    /// Not gated by the parser.
+    StaticReturnType,
+    /// `$object::class`. Not gated by the parser.
+    ClassOnObject,
+    /// `catch (E)` without a variable. Not gated by the parser.
+    NonCapturingCatch,
+    Enums,
+    ReadonlyProperties,
+    ReadonlyParameters,
+    IntersectionTypes,
+    NeverType,
+    FirstClassCallable,
+    /// `new` in parameter defaults, attribute arguments, and static
+    /// variable and global constant initializers. Not gated by the parser.
+    NewInInitializers,
+    /// `0o` integer literals. Not gated by the parser.
+    ExplicitOctal,
+    ReadonlyClasses,
+    DnfTypes,
+    ParenthesizedUnionTypes,
+    StandaloneNull,
+    StandaloneFalse,
+    TrueType,
+    /// Not gated by the parser.
+    ConstantsInTraits,
+    TypedClassConstants,
+    TypedEnumConstants,
+    DynamicClassConstantFetch,
+    PropertyHooks,
+    AsymmetricVisibility,
+    AbstractReadonlyClasses,
+    PipeOperator,
+    VoidCast,
+    AttributesOnConstants,
+    StaticAsymmetricVisibility,
+    FinalPromotedProperties,
+    CloneCallable,
+    CloneWith,
+}
+
+impl Feature {
+    /// The first PHP version with the feature.
+    pub fn required(self) -> PhpVersion {
+        self.info().2
+    }
+
+    /// A stable kebab-case identifier, e.g. `union-types`, used as the
+    /// diagnostic code.
+    pub fn code(self) -> &'static str {
+        self.info().1
+    }
+
+    /// The php.net page describing the release that introduced the feature.
+    pub fn docs_url(self) -> &'static str {
+        self.required().new_features_url()
+    }
+
+    /// Display name, code, and required version.
+    fn info(self) -> (&'static str, &'static str, PhpVersion) {
+        use PhpVersion::*;
+        match self {
+            Feature::UnionTypes => ("union types", "union-types", Php80),
+            Feature::MixedType => ("mixed type", "mixed-type", Php80),
+            Feature::NullType => ("null type", "null-type", Php80),
+            Feature::FalseType => ("false type", "false-type", Php80),
+            Feature::MatchExpressions => ("match expressions", "match-expressions", Php80),
+            Feature::NamedArguments => ("named arguments", "named-arguments", Php80),
+            Feature::NullsafeOperator => ("nullsafe operator (?->)", "nullsafe-operator", Php80),
+            Feature::ThrowExpressions => ("throw expressions", "throw-expressions", Php80),
+            Feature::ConstructorPromotion => (
+                "constructor property promotion",
+                "constructor-promotion",
+                Php80,
+            ),
+            Feature::Attributes => ("attributes", "attributes", Php80),
+            Feature::StaticReturnType => ("static return type", "static-return-type", Php80),
+            Feature::ClassOnObject => ("::class on objects", "class-on-object", Php80),
+            Feature::NonCapturingCatch => {
+                ("catch without a variable", "non-capturing-catch", Php80)
+            }
+            Feature::Enums => ("enums", "enums", Php81),
+            Feature::ReadonlyProperties => ("readonly properties", "readonly-properties", Php81),
+            Feature::ReadonlyParameters => ("readonly parameters", "readonly-parameters", Php81),
+            Feature::IntersectionTypes => ("intersection types", "intersection-types", Php81),
+            Feature::NeverType => ("never type", "never-type", Php81),
+            Feature::FirstClassCallable => {
+                ("first-class callable syntax", "first-class-callable", Php81)
+            }
+            Feature::NewInInitializers => ("new in initializers", "new-in-initializers", Php81),
+            Feature::ExplicitOctal => ("explicit octal notation (0o)", "explicit-octal", Php81),
+            Feature::ReadonlyClasses => ("readonly class", "readonly-classes", Php82),
+            Feature::DnfTypes => ("DNF types", "dnf-types", Php82),
+            Feature::ParenthesizedUnionTypes => (
+                "parenthesized union types",
+                "parenthesized-union-types",
+                Php82,
+            ),
+            Feature::StandaloneNull => ("null as standalone type", "standalone-null", Php82),
+            Feature::StandaloneFalse => ("false as standalone type", "standalone-false", Php82),
+            Feature::TrueType => ("true type", "true-type", Php82),
+            Feature::ConstantsInTraits => ("constants in traits", "constants-in-traits", Php82),
+            Feature::TypedClassConstants => {
+                ("typed class constants", "typed-class-constants", Php83)
+            }
+            Feature::TypedEnumConstants => ("typed enum constants", "typed-enum-constants", Php83),
+            Feature::DynamicClassConstantFetch => (
+                "dynamic class constant fetch",
+                "dynamic-class-constant-fetch",
+                Php83,
+            ),
+            Feature::PropertyHooks => ("property hooks", "property-hooks", Php84),
+            Feature::AsymmetricVisibility => {
+                ("asymmetric visibility", "asymmetric-visibility", Php84)
+            }
+            Feature::AbstractReadonlyClasses => (
+                "abstract readonly class",
+                "abstract-readonly-classes",
+                Php84,
+            ),
+            Feature::PipeOperator => ("pipe operator (|>)", "pipe-operator", Php85),
+            Feature::VoidCast => ("void cast", "void-cast", Php85),
+            Feature::AttributesOnConstants => {
+                ("attributes on constants", "attributes-on-constants", Php85)
+            }
+            Feature::StaticAsymmetricVisibility => (
+                "asymmetric visibility on static properties",
+                "static-asymmetric-visibility",
+                Php85,
+            ),
+            Feature::FinalPromotedProperties => (
+                "final promoted properties",
+                "final-promoted-properties",
+                Php85,
+            ),
+            Feature::CloneCallable => ("clone(...) first-class callable", "clone-callable", Php85),
+            Feature::CloneWith => ("clone with property overrides", "clone-with", Php85),
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.info().0)
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests for `parse_bytes`: sources that are not valid UTF-8.
+
+use php_ast::{ExprKind, Span, StmtKind};
+use php_rs_parser::bytes::{decode, encode, ByteOffsets};
+use php_rs_parser::ParserOptions;
+
+#[test]
+fn latin1_identifiers_comments_and_html_parse_cleanly() {
+    let source =
+        b"<p>r\xE9sum\xE9</p><?php\n// \xA9 2004\nfunction gr\xFC\xDFe($\xE4) { return $\xE4; }\n";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_bytes(&arena, source);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    let StmtKind::InlineHtml(html) = result.program.stmts[0].kind else {
+        panic!("expected inline HTML");
+    };
+    assert_eq!(&*encode(html.text), b"<p>r\xE9sum\xE9</p>");
+    let StmtKind::Function(function) = &result.program.stmts[1].kind else {
+        panic!("expected a function");
+    };
+    assert_eq!(&*encode(function.name.as_str().unwrap()), b"gr\xFC\xDFe");
+    assert_eq!(&*encode(result.source), source);
+}
+
+#[test]
+fn spans_convert_to_original_offsets() {
+    let source = b"<?php $s = '\xE9\xE9'; $t = 1;";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_bytes(&arena, source);
+    let offsets = ByteOffsets::new(result.source);
+    assert!(!offsets.is_identity());
+
+    let StmtKind::Expression(assign) = &result.program.stmts[1].kind else {
+        panic!("expected an expression");
+    };
+    let original = offsets.to_original_span(assign.span);
+    assert_eq!(&source[original.to_range()], b"$t = 1");
+    assert_eq!(offsets.to_decoded_span(original), assign.span);
+
+    let StmtKind::Expression(assign) = &result.program.stmts[0].kind else {
+        panic!("expected an expression");
+    };
+    let ExprKind::Assign(assign) = &assign.kind else {
+        panic!("expected an assignment");
+    };
+    let literal = offsets.to_original_span(assign.value.span);
+    assert_eq!(literal, Span::new(11, 15));
+    // An offset inside a reserved character maps past its byte.
+    assert_eq!(offsets.to_original(assign.value.span.start + 2), 13);
+}
+
+#[test]
+fn line_col_counts_original_bytes() {
+    let source = b"<?php\n$s = \"\xE9\xE9\xE9\"; $t = ;";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_bytes(&arena, source);
+    let offsets = ByteOffsets::new(result.source);
+    let error = &result.errors[0];
+    let position = offsets.to_original_line_col(&result.source_map, error.span().start);
+    assert_eq!(position.to_one_based(), (2, 18));
+    assert_eq!(result.source_map.line_col(error.span().start).col + 1, 27);
+}
+
+#[test]
+fn valid_utf8_is_parsed_in_place() {
+    let source = "<?php echo 'café';";
+    assert!(matches!(
+        decode(source.as_bytes()),
+        std::borrow::Cow::Borrowed(_)
+    ));
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_bytes(&arena, source.as_bytes());
+    assert!(std::ptr::eq(result.source, source));
+    assert!(ByteOffsets::new(result.source).is_identity());
+}
+
+#[test]
+fn file_size_limit_applies_to_the_raw_bytes() {
+    // Ten invalid bytes decode to forty bytes of reserved characters.
+    let source = b"<?php '\xE9\xE9\xE9\xE9\xE9\xE9\xE9\xE9\xE9\xE9';";
+    let arena = bumpalo::Bump::new();
+    let options = ParserOptions {
+        max_file_size: Some(source.len()),
+        ..ParserOptions::default()
+    };
+    let result = php_rs_parser::parse_bytes_with_options(&arena, source, &options);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    let options = ParserOptions {
+        max_file_size: Some(source.len() - 1),
+        ..ParserOptions::default()
+    };
+    let result = php_rs_parser::parse_bytes_with_options(&arena, source, &options);
+    assert!(result.program.stmts.is_empty());
+    assert_eq!(result.source, "");
+    assert_eq!(
+        result.errors[0].to_string(),
+        format!(
+            "file size of {} bytes exceeds the configured maximum of {} bytes",
+            source.len(),
+            source.len() - 1,
+        ),
+    );
+}
+
+#[test]
+fn escaped_bytes_encode_to_the_runtime_string() {
+    let source = "<?php \"\\xE9\"; \"\\351\"; \"\\xC3\\xA9\"; \"é\"; <<<EOT\n  a\\xE9\n  EOT;\n";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, source);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    let values: Vec<&str> = result
+        .program
+        .stmts
+        .iter()
+        .map(|stmt| {
+            let StmtKind::Expression(expr) = &stmt.kind else {
+                panic!("expected an expression");
+            };
+            match &expr.kind {
+                ExprKind::String { value, .. } => *value,
+                ExprKind::Heredoc { parts, .. } => match parts[..] {
+                    [php_ast::StringPart::Literal { value, .. }] => value,
+                    _ => panic!("expected one literal part"),
+                },
+                _ => panic!("expected a string"),
+            }
+        })
+        .collect();
+    assert_eq!(&*encode(values[0]), b"\xE9");
+    assert_eq!(&*encode(values[1]), b"\xE9");
+    assert_ne!(values[0], "é");
+    // Escaped bytes that form valid UTF-8 decode to the character.
+    assert_eq!(values[2], "é");
+    assert_eq!(values[3], "é");
+    assert_eq!(&*encode(values[4]), b"a\xE9");
+}
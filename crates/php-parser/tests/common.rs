@@ -70,6 +70,19 @@ pub fn parse_fixture(content: &str) -> (Option<(u32, u32)>, &str) {
     (min_php, source)
 }
 
+/// Whether the `===config===` section of a fixture sets
+/// `infer_semicolons=true`, to parse it with
+/// `ParserOptions::infer_semicolons`.
+#[allow(dead_code)]
+pub fn infer_semicolons(content: &str) -> bool {
+    let header = &content[..content.find("===source===\n").unwrap_or(content.len())];
+    header.find("===config===\n").is_some_and(|start| {
+        header[start..]
+            .lines()
+            .any(|line| line == "infer_semicolons=true")
+    })
+}
+
 /// Recursively collect all `.phpt` files under `dir`.
 ///
 /// Note: This function appears unused in some test binaries (e.g., malformed_php.rs)
@@ -0,0 +1,131 @@
+//! The declaration helpers on `Program` and the class-like declarations.
+
+use php_ast::{BuiltinType, TypeHintKind};
+use php_rs_parser::prelude::*;
+
+fn names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    names.collect()
+}
+
+#[test]
+fn top_level_declarations_span_braced_and_simple_namespaces() {
+    let arena = Bump::new();
+    let src = "<?php
+namespace A {
+    function f() {}
+    class C {}
+}
+namespace B {
+    interface I {}
+    trait T {}
+    enum E {}
+    function g() {}
+}
+";
+    let result = parse(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    let program = &result.program;
+    assert_eq!(
+        names(program.functions().map(|f| f.name.or_error())),
+        ["f", "g"]
+    );
+    assert_eq!(program.classes().count(), 1);
+    assert_eq!(
+        names(program.interfaces().map(|i| i.name.or_error())),
+        ["I"]
+    );
+    assert_eq!(names(program.traits().map(|t| t.name.or_error())), ["T"]);
+    assert_eq!(names(program.enums().map(|e| e.name.or_error())), ["E"]);
+
+    let simple = parse(
+        &arena,
+        "<?php namespace A; function f() {} namespace B; function g() {}",
+    );
+    assert_eq!(
+        names(simple.program.functions().map(|f| f.name.or_error())),
+        ["f", "g"]
+    );
+}
+
+#[test]
+fn nested_declarations_are_not_top_level() {
+    let arena = Bump::new();
+    let src = "<?php
+if (!function_exists('f')) { function f() {} }
+function outer() { function inner() {} }
+";
+    let result = parse(&arena, src);
+    assert_eq!(
+        names(result.program.functions().map(|f| f.name.or_error())),
+        ["outer"]
+    );
+}
+
+#[test]
+fn class_members_by_kind() {
+    let arena = Bump::new();
+    let src = "<?php
+class C {
+    use T;
+    const A = 1;
+    public int $x = 0;
+    public function __construct(public int $y) {}
+    public function Run() {}
+}
+enum E: string {
+    case One = 'one';
+    const DEFAULT = self::One;
+    public function label(): string { return $this->value; }
+}
+";
+    let result = parse(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    let class = result.program.classes().next().unwrap();
+    assert_eq!(
+        names(class.methods().map(|m| m.name.or_error())),
+        ["__construct", "Run"]
+    );
+    assert_eq!(names(class.properties().map(|p| p.name.or_error())), ["x"]);
+    assert_eq!(names(class.constants().map(|c| c.name.or_error())), ["A"]);
+    assert!(class.method("run").is_some());
+    assert!(class.method("missing").is_none());
+
+    let enum_decl = result.program.enums().next().unwrap();
+    assert_eq!(names(enum_decl.cases().map(|c| c.name.or_error())), ["One"]);
+    assert_eq!(
+        names(enum_decl.constants().map(|c| c.name.or_error())),
+        ["DEFAULT"]
+    );
+    assert!(enum_decl.method("LABEL").is_some());
+}
+
+#[test]
+fn type_hint_queries() {
+    let arena = Bump::new();
+    let src = "<?php function f(?Foo $a, (A&B)|null $b, int $c, mixed $d): static {}";
+    let result = parse(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    let f = result.program.functions().next().unwrap();
+    let hints: Vec<_> = f
+        .params
+        .iter()
+        .map(|param| param.type_hint.as_ref().unwrap())
+        .collect();
+    assert_eq!(
+        hints
+            .iter()
+            .map(|hint| hint.admits_null())
+            .collect::<Vec<_>>(),
+        [true, true, false, true]
+    );
+    let TypeHintKind::Union(members) = &hints[1].kind else {
+        panic!("expected a union: {:?}", hints[1]);
+    };
+    assert!(matches!(members[0].kind, TypeHintKind::Intersection(_)));
+    assert_eq!(&src[members[0].span.to_range()], "(A&B)");
+
+    let return_type = f.return_type.as_ref().unwrap();
+    assert!(return_type.is_keyword(BuiltinType::Static));
+    assert!(BuiltinType::Static.is_relative_class());
+    assert!(!BuiltinType::Int.is_relative_class());
+}
@@ -0,0 +1,157 @@
+//! Integration tests for the ext-ast conversion using the real parser.
+
+use php_ast::ext_ast::{self, flags, Children, Kind, Value};
+use php_ast::source_map::SourceMap;
+
+/// Parse PHP source and return its ext-ast dump.
+fn dump(src: &str, linenos: bool) -> String {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, src);
+    assert!(
+        result.errors.is_empty(),
+        "parse errors: {:?}",
+        result.errors
+    );
+    ext_ast::from_program(&result.program, &SourceMap::new(src)).dump(linenos)
+}
+
+#[test]
+fn call_with_binary_op() {
+    assert_eq!(
+        dump("<?php\nfoo($a + 1, \\B\\C, ...$rest);\n", false),
+        r#"AST_STMT_LIST
+    0: AST_CALL
+        expr: AST_NAME
+            flags: NAME_NOT_FQ (1)
+            name: "foo"
+        args: AST_ARG_LIST
+            0: AST_BINARY_OP
+                flags: BINARY_ADD (1)
+                left: AST_VAR
+                    name: "a"
+                right: 1
+            1: AST_CONST
+                name: AST_NAME
+                    flags: NAME_FQ (0)
+                    name: "B\C"
+            2: AST_UNPACK
+                expr: AST_VAR
+                    name: "rest""#
+    );
+}
+
+#[test]
+fn normalises_operators_like_ext_ast() {
+    assert_eq!(
+        dump("<?php $a > $b ?? -$c; isset($x, $y);", false),
+        r#"AST_STMT_LIST
+    0: AST_BINARY_OP
+        flags: BINARY_COALESCE (260)
+        left: AST_BINARY_OP
+            flags: BINARY_IS_GREATER (256)
+            left: AST_VAR
+                name: "a"
+            right: AST_VAR
+                name: "b"
+        right: AST_UNARY_OP
+            flags: UNARY_MINUS (262)
+            expr: AST_VAR
+                name: "c"
+    1: AST_BINARY_OP
+        flags: BINARY_BOOL_AND (259)
+        left: AST_ISSET
+            var: AST_VAR
+                name: "x"
+        right: AST_ISSET
+            var: AST_VAR
+                name: "y""#
+    );
+}
+
+#[test]
+fn class_with_method_and_line_numbers() {
+    let src = "<?php\nfinal class A extends B {\n    public const X = [1];\n    private static function f(int &$x): ?static {\n        yield;\n    }\n}\n";
+    assert_eq!(
+        dump(src, true),
+        r#"AST_STMT_LIST @ 1
+    0: AST_CLASS @ 2-7
+        flags: CLASS_FINAL (32)
+        name: "A"
+        docComment: null
+        extends: AST_NAME @ 2
+            flags: NAME_NOT_FQ (1)
+            name: "B"
+        implements: null
+        stmts: AST_STMT_LIST @ 2
+            0: AST_CLASS_CONST_GROUP @ 3
+                flags: MODIFIER_PUBLIC (1)
+                const: AST_CLASS_CONST_DECL @ 3
+                    0: AST_CONST_ELEM @ 3
+                        name: "X"
+                        value: AST_ARRAY @ 3
+                            flags: ARRAY_SYNTAX_SHORT (3)
+                            0: AST_ARRAY_ELEM @ 3
+                                flags: 0
+                                value: 1
+                                key: null
+                        docComment: null
+                attributes: null
+                type: null
+            1: AST_METHOD @ 4-6
+                flags: MODIFIER_PRIVATE | MODIFIER_STATIC | FUNC_GENERATOR (16777236)
+                name: "f"
+                docComment: null
+                params: AST_PARAM_LIST @ 4
+                    0: AST_PARAM @ 4
+                        flags: PARAM_REF (8)
+                        type: AST_TYPE @ 4
+                            flags: TYPE_LONG (4)
+                        name: "x"
+                        default: null
+                        attributes: null
+                        docComment: null
+                        hooks: null
+                stmts: AST_STMT_LIST @ 4
+                    0: AST_YIELD @ 5
+                        value: null
+                        key: null
+                returnType: AST_NULLABLE_TYPE @ 4
+                    type: AST_TYPE @ 4
+                        flags: TYPE_STATIC (15)
+                attributes: null
+                __declId: 0
+        attributes: null
+        type: null
+        __declId: 1"#
+    );
+}
+
+#[test]
+fn anonymous_class_and_attribute_groups() {
+    let ast = dump("<?php new #[A, B(1)] #[C] class {};", false);
+    assert!(ast.contains("flags: CLASS_ANONYMOUS (4)"), "{ast}");
+    assert_eq!(ast.matches("AST_ATTRIBUTE_GROUP").count(), 2, "{ast}");
+    assert!(
+        ast.contains("class: AST_NAME\n                            flags: NAME_NOT_FQ (1)\n                            name: \"A\"\n                        args: null"),
+        "{ast}"
+    );
+}
+
+#[test]
+fn child_access_and_flags() {
+    let src = "<?php use function A\\f;";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, src);
+    let ast = ext_ast::from_program(&result.program, &SourceMap::new(src));
+    let Children::List(stmts) = &ast.children else {
+        panic!("statement list has positional children");
+    };
+    let Value::Node(use_stmt) = &stmts[0] else {
+        panic!("expected a node, got {:?}", stmts[0]);
+    };
+    assert_eq!(use_stmt.kind, Kind::Use);
+    assert_eq!(use_stmt.flags, flags::USE_FUNCTION);
+    assert!(Kind::ALL
+        .iter()
+        .any(|k| k.as_str() == "AST_CALLABLE_CONVERT"));
+}
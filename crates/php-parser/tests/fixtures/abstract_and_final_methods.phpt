@@ -89,37 +89,39 @@ abstract class Base {
                   "body": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "MethodCall": {
-                                "object": {
-                                  "kind": {
-                                    "Variable": "this"
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "MethodCall": {
+                                  "object": {
+                                    "kind": {
+                                      "Variable": "this"
+                                    },
+                                    "span": {
+                                      "start": 137,
+                                      "end": 142
+                                    }
                                   },
-                                  "span": {
-                                    "start": 137,
-                                    "end": 142
-                                  }
-                                },
-                                "method": {
-                                  "kind": {
-                                    "Identifier": "template"
+                                  "method": {
+                                    "kind": {
+                                      "Identifier": "template"
+                                    },
+                                    "span": {
+                                      "start": 144,
+                                      "end": 152
+                                    }
                                   },
-                                  "span": {
-                                    "start": 144,
-                                    "end": 152
-                                  }
-                                },
-                                "args": []
+                                  "args": []
+                                }
+                              },
+                              "span": {
+                                "start": 137,
+                                "end": 154
                               }
-                            },
-                            "span": {
-                              "start": 137,
-                              "end": 154
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 132,
@@ -30,7 +30,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": " HTML "
+                    "InlineHtml": {
+                      "text": " HTML "
+                    }
                   },
                   "span": {
                     "start": 28,
@@ -56,5 +58,10 @@
   "span": {
     "start": 0,
     "end": 51
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 52,
+    "end": 54
   }
 }
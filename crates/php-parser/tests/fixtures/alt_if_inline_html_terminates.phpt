@@ -20,7 +20,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": " HTML "
+                    "InlineHtml": {
+                      "text": " HTML "
+                    }
                   },
                   "span": {
                     "start": 19,
@@ -48,5 +50,10 @@
   "span": {
     "start": 0,
     "end": 37
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 38,
+    "end": 40
   }
 }
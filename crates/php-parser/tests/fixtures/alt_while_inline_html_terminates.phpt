@@ -20,7 +20,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": " HTML "
+                    "InlineHtml": {
+                      "text": " HTML "
+                    }
                   },
                   "span": {
                     "start": 22,
@@ -46,5 +48,10 @@
   "span": {
     "start": 0,
     "end": 43
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 44,
+    "end": 46
   }
 }
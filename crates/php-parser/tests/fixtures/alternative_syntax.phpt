@@ -64,17 +64,22 @@ endswitch;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "positive"
-                        },
-                        "span": {
-                          "start": 28,
-                          "end": 38
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "positive",
+                              "raw": "positive"
+                            }
+                          },
+                          "span": {
+                            "start": 28,
+                            "end": 38
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 23,
@@ -124,17 +129,22 @@ endswitch;
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "negative"
-                            },
-                            "span": {
-                              "start": 66,
-                              "end": 76
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "negative",
+                                  "raw": "negative"
+                                }
+                              },
+                              "span": {
+                                "start": 66,
+                                "end": 76
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 61,
@@ -159,17 +169,22 @@ endswitch;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "zero"
-                        },
-                        "span": {
-                          "start": 93,
-                          "end": 99
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "zero",
+                              "raw": "zero"
+                            }
+                          },
+                          "span": {
+                            "start": 93,
+                            "end": 99
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 88,
@@ -228,17 +243,19 @@ endswitch;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 133,
-                          "end": 135
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 133,
+                            "end": 135
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 128,
@@ -375,22 +392,36 @@ endswitch;
               }
             }
           ],
+          "init_span": {
+            "start": 162,
+            "end": 168
+          },
+          "condition_span": {
+            "start": 170,
+            "end": 176
+          },
+          "update_span": {
+            "start": 178,
+            "end": 182
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 194,
-                          "end": 196
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 194,
+                            "end": 196
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 189,
@@ -439,17 +470,19 @@ endswitch;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "item"
-                        },
-                        "span": {
-                          "start": 242,
-                          "end": 247
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "item"
+                          },
+                          "span": {
+                            "start": 242,
+                            "end": 247
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 237,
@@ -487,7 +520,10 @@ endswitch;
             {
               "value": {
                 "kind": {
-                  "String": "red"
+                  "String": {
+                    "value": "red",
+                    "raw": "red"
+                  }
                 },
                 "span": {
                   "start": 287,
@@ -497,17 +533,22 @@ endswitch;
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "red"
-                        },
-                        "span": {
-                          "start": 307,
-                          "end": 312
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "red",
+                              "raw": "red"
+                            }
+                          },
+                          "span": {
+                            "start": 307,
+                            "end": 312
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 302,
@@ -534,17 +575,22 @@ endswitch;
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "other"
-                        },
-                        "span": {
-                          "start": 355,
-                          "end": 362
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
+                          },
+                          "span": {
+                            "start": 355,
+                            "end": 362
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 350,
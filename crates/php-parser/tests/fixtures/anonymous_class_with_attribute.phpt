@@ -22,41 +22,43 @@
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": [
-                            {
-                              "name": {
-                                "parts": [
-                                  "Attr"
-                                ],
-                                "kind": "Unqualified",
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": [
+                              {
+                                "name": {
+                                  "parts": [
+                                    "Attr"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 17,
+                                    "end": 21
+                                  }
+                                },
+                                "args": [],
                                 "span": {
                                   "start": 17,
                                   "end": 21
                                 }
-                              },
-                              "args": [],
-                              "span": {
-                                "start": 17,
-                                "end": 21
                               }
-                            }
-                          ]
+                            ]
+                          }
+                        },
+                        "span": {
+                          "start": 11,
+                          "end": 33
                         }
-                      },
-                      "span": {
-                        "start": 11,
-                        "end": 33
                       }
                     },
                     "args": []
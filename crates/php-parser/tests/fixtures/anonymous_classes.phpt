@@ -53,23 +53,25 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 39,
+                          "end": 51
                         }
-                      },
-                      "span": {
-                        "start": 39,
-                        "end": 51
                       }
                     },
                     "args": []
@@ -112,23 +114,25 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 86,
+                          "end": 104
                         }
-                      },
-                      "span": {
-                        "start": 86,
-                        "end": 104
                       }
                     },
                     "args": [
@@ -148,6 +152,10 @@ $obj = new class($x) extends Base implements Iface {
                         "span": {
                           "start": 96,
                           "end": 97
+                        },
+                        "comma": {
+                          "start": 97,
+                          "end": 98
                         }
                       },
                       {
@@ -208,32 +216,34 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": {
-                            "parts": [
-                              "Foo"
-                            ],
-                            "kind": "Unqualified",
-                            "span": {
-                              "start": 148,
-                              "end": 151
-                            }
-                          },
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": {
+                              "parts": [
+                                "Foo"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 148,
+                                "end": 151
+                              }
+                            },
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 130,
+                          "end": 154
                         }
-                      },
-                      "span": {
-                        "start": 130,
-                        "end": 154
                       }
                     },
                     "args": []
@@ -276,44 +286,46 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [
-                            {
-                              "parts": [
-                                "Bar"
-                              ],
-                              "kind": "Unqualified",
-                              "span": {
-                                "start": 204,
-                                "end": 207
-                              }
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
                             },
-                            {
-                              "parts": [
-                                "Baz"
-                              ],
-                              "kind": "Unqualified",
-                              "span": {
-                                "start": 209,
-                                "end": 212
+                            "extends": null,
+                            "implements": [
+                              {
+                                "parts": [
+                                  "Bar"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 204,
+                                  "end": 207
+                                }
+                              },
+                              {
+                                "parts": [
+                                  "Baz"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 209,
+                                  "end": 212
+                                }
                               }
-                            }
-                          ],
-                          "members": [],
-                          "attributes": []
+                            ],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 183,
+                          "end": 215
                         }
-                      },
-                      "span": {
-                        "start": 183,
-                        "end": 215
                       }
                     },
                     "args": []
@@ -356,88 +368,93 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [
-                            {
-                              "kind": {
-                                "Property": {
-                                  "name": "x",
-                                  "visibility": "Public",
-                                  "set_visibility": null,
-                                  "is_static": false,
-                                  "is_readonly": false,
-                                  "type_hint": null,
-                                  "default": {
-                                    "kind": {
-                                      "Int": 10
-                                    },
-                                    "span": {
-                                      "start": 269,
-                                      "end": 271
-                                    }
-                                  },
-                                  "attributes": []
-                                }
-                              },
-                              "span": {
-                                "start": 257,
-                                "end": 271
-                              }
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
                             },
-                            {
-                              "kind": {
-                                "Method": {
-                                  "name": "hello",
-                                  "visibility": "Public",
-                                  "is_static": false,
-                                  "is_abstract": false,
-                                  "is_final": false,
-                                  "by_ref": false,
-                                  "params": [],
-                                  "return_type": null,
-                                  "body": [
-                                    {
+                            "extends": null,
+                            "implements": [],
+                            "members": [
+                              {
+                                "kind": {
+                                  "Property": {
+                                    "name": "x",
+                                    "visibility": "Public",
+                                    "set_visibility": null,
+                                    "is_static": false,
+                                    "is_readonly": false,
+                                    "type_hint": null,
+                                    "default": {
                                       "kind": {
-                                        "Return": {
-                                          "kind": {
-                                            "String": "hi"
-                                          },
-                                          "span": {
-                                            "start": 318,
-                                            "end": 322
-                                          }
-                                        }
+                                        "Int": 10
                                       },
                                       "span": {
-                                        "start": 311,
-                                        "end": 323
+                                        "start": 269,
+                                        "end": 271
                                       }
-                                    }
-                                  ],
-                                  "attributes": []
+                                    },
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 257,
+                                  "end": 271
                                 }
                               },
-                              "span": {
-                                "start": 277,
-                                "end": 329
+                              {
+                                "kind": {
+                                  "Method": {
+                                    "name": "hello",
+                                    "visibility": "Public",
+                                    "is_static": false,
+                                    "is_abstract": false,
+                                    "is_final": false,
+                                    "by_ref": false,
+                                    "params": [],
+                                    "return_type": null,
+                                    "body": [
+                                      {
+                                        "kind": {
+                                          "Return": {
+                                            "kind": {
+                                              "String": {
+                                                "value": "hi",
+                                                "raw": "hi"
+                                              }
+                                            },
+                                            "span": {
+                                              "start": 318,
+                                              "end": 322
+                                            }
+                                          }
+                                        },
+                                        "span": {
+                                          "start": 311,
+                                          "end": 323
+                                        }
+                                      }
+                                    ],
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 277,
+                                  "end": 329
+                                }
                               }
-                            }
-                          ],
-                          "attributes": []
+                            ],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 241,
+                          "end": 331
                         }
-                      },
-                      "span": {
-                        "start": 241,
-                        "end": 331
                       }
                     },
                     "args": []
@@ -480,213 +497,215 @@ $obj = new class($x) extends Base implements Iface {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": {
-                            "parts": [
-                              "Base"
-                            ],
-                            "kind": "Unqualified",
-                            "span": {
-                              "start": 422,
-                              "end": 426
-                            }
-                          },
-                          "implements": [
-                            {
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": {
                               "parts": [
-                                "Iface"
+                                "Base"
                               ],
                               "kind": "Unqualified",
                               "span": {
-                                "start": 438,
-                                "end": 443
+                                "start": 422,
+                                "end": 426
                               }
-                            }
-                          ],
-                          "members": [
-                            {
-                              "kind": {
-                                "Property": {
-                                  "name": "val",
-                                  "visibility": "Private",
-                                  "set_visibility": null,
-                                  "is_static": false,
-                                  "is_readonly": false,
-                                  "type_hint": null,
-                                  "default": null,
-                                  "attributes": []
+                            },
+                            "implements": [
+                              {
+                                "parts": [
+                                  "Iface"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 438,
+                                  "end": 443
                                 }
-                              },
-                              "span": {
-                                "start": 450,
-                                "end": 462
                               }
-                            },
-                            {
-                              "kind": {
-                                "Method": {
-                                  "name": "__construct",
-                                  "visibility": "Public",
-                                  "is_static": false,
-                                  "is_abstract": false,
-                                  "is_final": false,
-                                  "by_ref": false,
-                                  "params": [
-                                    {
-                                      "name": "val",
-                                      "type_hint": null,
-                                      "default": null,
-                                      "by_ref": false,
-                                      "variadic": false,
-                                      "is_readonly": false,
-                                      "is_final": false,
-                                      "visibility": null,
-                                      "set_visibility": null,
-                                      "attributes": [],
-                                      "span": {
-                                        "start": 496,
-                                        "end": 500
+                            ],
+                            "members": [
+                              {
+                                "kind": {
+                                  "Property": {
+                                    "name": "val",
+                                    "visibility": "Private",
+                                    "set_visibility": null,
+                                    "is_static": false,
+                                    "is_readonly": false,
+                                    "type_hint": null,
+                                    "default": null,
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 450,
+                                  "end": 462
+                                }
+                              },
+                              {
+                                "kind": {
+                                  "Method": {
+                                    "name": "__construct",
+                                    "visibility": "Public",
+                                    "is_static": false,
+                                    "is_abstract": false,
+                                    "is_final": false,
+                                    "by_ref": false,
+                                    "params": [
+                                      {
+                                        "name": "val",
+                                        "type_hint": null,
+                                        "default": null,
+                                        "by_ref": false,
+                                        "variadic": false,
+                                        "is_readonly": false,
+                                        "is_final": false,
+                                        "visibility": null,
+                                        "set_visibility": null,
+                                        "attributes": [],
+                                        "span": {
+                                          "start": 496,
+                                          "end": 500
+                                        }
                                       }
-                                    }
-                                  ],
-                                  "return_type": null,
-                                  "body": [
-                                    {
-                                      "kind": {
-                                        "Expression": {
-                                          "kind": {
-                                            "Assign": {
-                                              "target": {
-                                                "kind": {
-                                                  "PropertyAccess": {
-                                                    "object": {
-                                                      "kind": {
-                                                        "Variable": "this"
-                                                      },
-                                                      "span": {
-                                                        "start": 512,
-                                                        "end": 517
-                                                      }
-                                                    },
-                                                    "property": {
-                                                      "kind": {
-                                                        "Identifier": "val"
+                                    ],
+                                    "return_type": null,
+                                    "body": [
+                                      {
+                                        "kind": {
+                                          "Expression": {
+                                            "kind": {
+                                              "Assign": {
+                                                "target": {
+                                                  "kind": {
+                                                    "PropertyAccess": {
+                                                      "object": {
+                                                        "kind": {
+                                                          "Variable": "this"
+                                                        },
+                                                        "span": {
+                                                          "start": 512,
+                                                          "end": 517
+                                                        }
                                                       },
-                                                      "span": {
-                                                        "start": 519,
-                                                        "end": 522
+                                                      "property": {
+                                                        "kind": {
+                                                          "Identifier": "val"
+                                                        },
+                                                        "span": {
+                                                          "start": 519,
+                                                          "end": 522
+                                                        }
                                                       }
                                                     }
+                                                  },
+                                                  "span": {
+                                                    "start": 512,
+                                                    "end": 522
                                                   }
                                                 },
-                                                "span": {
-                                                  "start": 512,
-                                                  "end": 522
-                                                }
-                                              },
-                                              "op": "Assign",
-                                              "value": {
-                                                "kind": {
-                                                  "Variable": "val"
-                                                },
-                                                "span": {
-                                                  "start": 525,
-                                                  "end": 529
+                                                "op": "Assign",
+                                                "value": {
+                                                  "kind": {
+                                                    "Variable": "val"
+                                                  },
+                                                  "span": {
+                                                    "start": 525,
+                                                    "end": 529
+                                                  }
                                                 }
                                               }
+                                            },
+                                            "span": {
+                                              "start": 512,
+                                              "end": 529
                                             }
-                                          },
-                                          "span": {
-                                            "start": 512,
-                                            "end": 529
                                           }
+                                        },
+                                        "span": {
+                                          "start": 512,
+                                          "end": 530
                                         }
-                                      },
-                                      "span": {
-                                        "start": 512,
-                                        "end": 530
                                       }
-                                    }
-                                  ],
-                                  "attributes": []
+                                    ],
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 468,
+                                  "end": 536
                                 }
                               },
-                              "span": {
-                                "start": 468,
-                                "end": 536
-                              }
-                            },
-                            {
-                              "kind": {
-                                "Method": {
-                                  "name": "getValue",
-                                  "visibility": "Public",
-                                  "is_static": false,
-                                  "is_abstract": false,
-                                  "is_final": false,
-                                  "by_ref": false,
-                                  "params": [],
-                                  "return_type": null,
-                                  "body": [
-                                    {
-                                      "kind": {
-                                        "Return": {
-                                          "kind": {
-                                            "PropertyAccess": {
-                                              "object": {
-                                                "kind": {
-                                                  "Variable": "this"
-                                                },
-                                                "span": {
-                                                  "start": 585,
-                                                  "end": 590
-                                                }
-                                              },
-                                              "property": {
-                                                "kind": {
-                                                  "Identifier": "val"
+                              {
+                                "kind": {
+                                  "Method": {
+                                    "name": "getValue",
+                                    "visibility": "Public",
+                                    "is_static": false,
+                                    "is_abstract": false,
+                                    "is_final": false,
+                                    "by_ref": false,
+                                    "params": [],
+                                    "return_type": null,
+                                    "body": [
+                                      {
+                                        "kind": {
+                                          "Return": {
+                                            "kind": {
+                                              "PropertyAccess": {
+                                                "object": {
+                                                  "kind": {
+                                                    "Variable": "this"
+                                                  },
+                                                  "span": {
+                                                    "start": 585,
+                                                    "end": 590
+                                                  }
                                                 },
-                                                "span": {
-                                                  "start": 592,
-                                                  "end": 595
+                                                "property": {
+                                                  "kind": {
+                                                    "Identifier": "val"
+                                                  },
+                                                  "span": {
+                                                    "start": 592,
+                                                    "end": 595
+                                                  }
                                                 }
                                               }
+                                            },
+                                            "span": {
+                                              "start": 585,
+                                              "end": 595
                                             }
-                                          },
-                                          "span": {
-                                            "start": 585,
-                                            "end": 595
                                           }
+                                        },
+                                        "span": {
+                                          "start": 578,
+                                          "end": 596
                                         }
-                                      },
-                                      "span": {
-                                        "start": 578,
-                                        "end": 596
                                       }
-                                    }
-                                  ],
-                                  "attributes": []
+                                    ],
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 541,
+                                  "end": 602
                                 }
-                              },
-                              "span": {
-                                "start": 541,
-                                "end": 602
                               }
-                            }
-                          ],
-                          "attributes": []
+                            ],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 400,
+                          "end": 604
                         }
-                      },
-                      "span": {
-                        "start": 400,
-                        "end": 604
                       }
                     },
                     "args": [
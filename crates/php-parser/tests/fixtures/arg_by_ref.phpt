@@ -12,7 +12,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 7
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -12,42 +12,48 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 9
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -84,57 +90,67 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 31
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 25,
                           "end": 31
+                        },
+                        "comma": {
+                          "start": 31,
+                          "end": 32
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 25,
-                        "end": 31
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 33,
+                            "end": 33
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 33,
+                          "end": 33
+                        },
+                        "comma": {
                           "start": 33,
                           "end": 34
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 33,
-                        "end": 34
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "third"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "third"
+                          },
+                          "span": {
+                            "start": 35,
+                            "end": 41
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 35,
                           "end": 41
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 35,
-                        "end": 41
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 24,
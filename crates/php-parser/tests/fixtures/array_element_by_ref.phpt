@@ -10,43 +10,49 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 8,
+                            "end": 10
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 8,
+                          "start": 7,
                           "end": 10
+                        },
+                        "comma": {
+                          "start": 10,
+                          "end": 11
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 7,
-                        "end": 10
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 12,
+                            "end": 14
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 12,
                           "end": 14
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 12,
-                        "end": 14
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
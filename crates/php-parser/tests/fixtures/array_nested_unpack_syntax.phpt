@@ -33,6 +33,10 @@
                                 "span": {
                                   "start": 15,
                                   "end": 16
+                                },
+                                "comma": {
+                                  "start": 16,
+                                  "end": 17
                                 }
                               },
                               {
@@ -76,6 +80,10 @@
                 "span": {
                   "start": 7,
                   "end": 21
+                },
+                "comma": {
+                  "start": 21,
+                  "end": 22
                 }
               },
               {
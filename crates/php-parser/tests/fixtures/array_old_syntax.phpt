@@ -23,6 +23,10 @@
                 "span": {
                   "start": 12,
                   "end": 13
+                },
+                "comma": {
+                  "start": 13,
+                  "end": 14
                 }
               },
               {
@@ -40,6 +44,10 @@
                 "span": {
                   "start": 15,
                   "end": 16
+                },
+                "comma": {
+                  "start": 16,
+                  "end": 17
                 }
               },
               {
@@ -80,7 +88,10 @@
               {
                 "key": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 28,
@@ -100,12 +111,19 @@
                 "span": {
                   "start": 28,
                   "end": 36
+                },
+                "comma": {
+                  "start": 36,
+                  "end": 37
                 }
               },
               {
                 "key": {
                   "kind": {
-                    "String": "b"
+                    "String": {
+                      "value": "b",
+                      "raw": "b"
+                    }
                   },
                   "span": {
                     "start": 38,
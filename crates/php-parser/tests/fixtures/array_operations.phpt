@@ -45,6 +45,10 @@ echo $val;
                       "span": {
                         "start": 15,
                         "end": 16
+                      },
+                      "comma": {
+                        "start": 16,
+                        "end": 17
                       }
                     },
                     {
@@ -62,6 +66,10 @@ echo $val;
                       "span": {
                         "start": 18,
                         "end": 19
+                      },
+                      "comma": {
+                        "start": 19,
+                        "end": 20
                       }
                     },
                     {
@@ -79,6 +87,10 @@ echo $val;
                       "span": {
                         "start": 21,
                         "end": 22
+                      },
+                      "comma": {
+                        "start": 22,
+                        "end": 23
                       }
                     },
                     {
@@ -96,6 +108,10 @@ echo $val;
                       "span": {
                         "start": 24,
                         "end": 25
+                      },
+                      "comma": {
+                        "start": 25,
+                        "end": 26
                       }
                     },
                     {
@@ -289,7 +305,10 @@ echo $val;
                     {
                       "key": {
                         "kind": {
-                          "String": "a"
+                          "String": {
+                            "value": "a",
+                            "raw": "a"
+                          }
                         },
                         "span": {
                           "start": 103,
@@ -309,12 +328,19 @@ echo $val;
                       "span": {
                         "start": 103,
                         "end": 111
+                      },
+                      "comma": {
+                        "start": 111,
+                        "end": 112
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 113,
@@ -385,7 +411,10 @@ echo $val;
                     },
                     "index": {
                       "kind": {
-                        "String": "a"
+                        "String": {
+                          "value": "a",
+                          "raw": "a"
+                        }
                       },
                       "span": {
                         "start": 136,
@@ -414,17 +443,19 @@ echo $val;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "sum"
-            },
-            "span": {
-              "start": 147,
-              "end": 151
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "sum"
+              },
+              "span": {
+                "start": 147,
+                "end": 151
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 142,
@@ -433,17 +464,19 @@ echo $val;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "val"
-            },
-            "span": {
-              "start": 158,
-              "end": 162
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "val"
+              },
+              "span": {
+                "start": 158,
+                "end": 162
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 153,
@@ -39,6 +39,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 18,
                         "end": 19
+                      },
+                      "comma": {
+                        "start": 19,
+                        "end": 20
                       }
                     },
                     {
@@ -56,6 +60,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 21,
                         "end": 22
+                      },
+                      "comma": {
+                        "start": 22,
+                        "end": 23
                       }
                     },
                     {
@@ -128,6 +136,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 41,
                         "end": 52
+                      },
+                      "comma": {
+                        "start": 52,
+                        "end": 53
                       }
                     },
                     {
@@ -145,6 +157,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 54,
                         "end": 55
+                      },
+                      "comma": {
+                        "start": 55,
+                        "end": 56
                       }
                     },
                     {
@@ -205,7 +221,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                     {
                       "key": {
                         "kind": {
-                          "String": "a"
+                          "String": {
+                            "value": "a",
+                            "raw": "a"
+                          }
                         },
                         "span": {
                           "start": 74,
@@ -225,6 +244,10 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 74,
                         "end": 82
+                      },
+                      "comma": {
+                        "start": 82,
+                        "end": 83
                       }
                     },
                     {
@@ -242,12 +265,19 @@ $combined = ['a' => 1, ...$other, 'b' => 2];
                       "span": {
                         "start": 84,
                         "end": 93
+                      },
+                      "comma": {
+                        "start": 93,
+                        "end": 94
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 95,
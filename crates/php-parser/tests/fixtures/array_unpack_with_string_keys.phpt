@@ -24,7 +24,10 @@
                     {
                       "key": {
                         "kind": {
-                          "String": "x"
+                          "String": {
+                            "value": "x",
+                            "raw": "x"
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -81,6 +81,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 31,
                         "end": 32
+                      },
+                      "comma": {
+                        "start": 32,
+                        "end": 33
                       }
                     },
                     {
@@ -98,6 +102,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 34,
                         "end": 35
+                      },
+                      "comma": {
+                        "start": 35,
+                        "end": 36
                       }
                     },
                     {
@@ -158,7 +166,10 @@ $trailing = [1, 2, 3,];
                     {
                       "key": {
                         "kind": {
-                          "String": "name"
+                          "String": {
+                            "value": "name",
+                            "raw": "name"
+                          }
                         },
                         "span": {
                           "start": 49,
@@ -167,7 +178,10 @@ $trailing = [1, 2, 3,];
                       },
                       "value": {
                         "kind": {
-                          "String": "PHP"
+                          "String": {
+                            "value": "PHP",
+                            "raw": "PHP"
+                          }
                         },
                         "span": {
                           "start": 59,
@@ -178,12 +192,19 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 49,
                         "end": 64
+                      },
+                      "comma": {
+                        "start": 64,
+                        "end": 65
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "version"
+                          "String": {
+                            "value": "version",
+                            "raw": "version"
+                          }
                         },
                         "span": {
                           "start": 66,
@@ -263,6 +284,10 @@ $trailing = [1, 2, 3,];
                               "span": {
                                 "start": 95,
                                 "end": 96
+                              },
+                              "comma": {
+                                "start": 96,
+                                "end": 97
                               }
                             },
                             {
@@ -293,6 +318,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 94,
                         "end": 100
+                      },
+                      "comma": {
+                        "start": 100,
+                        "end": 101
                       }
                     },
                     {
@@ -315,6 +344,10 @@ $trailing = [1, 2, 3,];
                               "span": {
                                 "start": 103,
                                 "end": 104
+                              },
+                              "comma": {
+                                "start": 104,
+                                "end": 105
                               }
                             },
                             {
@@ -452,7 +485,10 @@ $trailing = [1, 2, 3,];
                     },
                     "index": {
                       "kind": {
-                        "String": "name"
+                        "String": {
+                          "value": "name",
+                          "raw": "name"
+                        }
                       },
                       "span": {
                         "start": 146,
@@ -587,6 +623,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 191,
                         "end": 192
+                      },
+                      "comma": {
+                        "start": 192,
+                        "end": 193
                       }
                     },
                     {
@@ -604,6 +644,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 194,
                         "end": 195
+                      },
+                      "comma": {
+                        "start": 195,
+                        "end": 196
                       }
                     },
                     {
@@ -621,6 +665,10 @@ $trailing = [1, 2, 3,];
                       "span": {
                         "start": 197,
                         "end": 198
+                      },
+                      "comma": {
+                        "start": 198,
+                        "end": 199
                       }
                     }
                   ]
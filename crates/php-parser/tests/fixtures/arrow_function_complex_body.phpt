@@ -38,6 +38,10 @@
                         "span": {
                           "start": 14,
                           "end": 16
+                        },
+                        "comma": {
+                          "start": 16,
+                          "end": 17
                         }
                       },
                       {
@@ -293,7 +293,10 @@ $x ??= 'default';
               "op": "Concat",
               "value": {
                 "kind": {
-                  "String": "suffix"
+                  "String": {
+                    "value": "suffix",
+                    "raw": "suffix"
+                  }
                 },
                 "span": {
                   "start": 76,
@@ -515,7 +518,10 @@ $x ??= 'default';
               "op": "Coalesce",
               "value": {
                 "kind": {
-                  "String": "default"
+                  "String": {
+                    "value": "default",
+                    "raw": "default"
+                  }
                 },
                 "span": {
                   "start": 149,
@@ -120,7 +120,10 @@ class User {
                   },
                   "default": {
                     "kind": {
-                      "String": ""
+                      "String": {
+                        "value": "",
+                        "raw": ""
+                      }
                     },
                     "span": {
                       "start": 136,
@@ -79,6 +79,10 @@ function f(
               "span": {
                 "start": 37,
                 "end": 61
+              },
+              "comma": {
+                "start": 61,
+                "end": 62
               }
             },
             {
@@ -0,0 +1,1195 @@
+===description===
+One attribute on every target PHP accepts, A1 to A24, so a target that stops
+recording its attributes shows up here.
+===source===
+<?php
+#[A1] const X = 1;
+#[A2] function f(#[A3] $p) {}
+#[A4] class C { #[A5] const K = 1; #[A6] public $p; #[A7] public function m() {} public function __construct(#[A8] public int $q) {} }
+#[A9] interface I { #[A10] const K = 1; #[A11] public function m(); }
+#[A12] trait T { #[A13] public $p; }
+#[A14] enum E { #[A15] case A; #[A16] const K = 1; }
+$a = new #[A17] class {};
+$b = #[A18] function () {};
+$c = #[A19] fn () => 1;
+$d = new #[A20] readonly class {};
+$e = #[A21] static fn () => 1;
+class H { public int $x { #[A22] get => 1; #[A23] set(#[A24] int $v) {} } }
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Const": [
+          {
+            "name": "X",
+            "value": {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 22,
+                "end": 23
+              }
+            },
+            "attributes": [
+              {
+                "name": {
+                  "parts": [
+                    "A1"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 8,
+                    "end": 10
+                  }
+                },
+                "args": [],
+                "span": {
+                  "start": 8,
+                  "end": 10
+                }
+              }
+            ],
+            "span": {
+              "start": 18,
+              "end": 23
+            }
+          }
+        ]
+      },
+      "span": {
+        "start": 12,
+        "end": 24
+      }
+    },
+    {
+      "kind": {
+        "Function": {
+          "name": "f",
+          "params": [
+            {
+              "name": "p",
+              "type_hint": null,
+              "default": null,
+              "by_ref": false,
+              "variadic": false,
+              "is_readonly": false,
+              "is_final": false,
+              "visibility": null,
+              "set_visibility": null,
+              "attributes": [
+                {
+                  "name": {
+                    "parts": [
+                      "A3"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 44,
+                      "end": 46
+                    }
+                  },
+                  "args": [],
+                  "span": {
+                    "start": 44,
+                    "end": 46
+                  }
+                }
+              ],
+              "span": {
+                "start": 42,
+                "end": 50
+              }
+            }
+          ],
+          "body": [],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": [
+            {
+              "name": {
+                "parts": [
+                  "A2"
+                ],
+                "kind": "Unqualified",
+                "span": {
+                  "start": 27,
+                  "end": 29
+                }
+              },
+              "args": [],
+              "span": {
+                "start": 27,
+                "end": 29
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 31,
+        "end": 54
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "C",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "K",
+                  "visibility": null,
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 87,
+                      "end": 88
+                    }
+                  },
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A5"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 73,
+                          "end": 75
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 73,
+                        "end": 75
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 71,
+                "end": 89
+              }
+            },
+            {
+              "kind": {
+                "Property": {
+                  "name": "p",
+                  "visibility": "Public",
+                  "set_visibility": null,
+                  "is_static": false,
+                  "is_readonly": false,
+                  "type_hint": null,
+                  "default": null,
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A6"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 92,
+                          "end": 94
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 92,
+                        "end": 94
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 90,
+                "end": 105
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "m",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": [],
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A7"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 109,
+                          "end": 111
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 109,
+                        "end": 111
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 107,
+                "end": 135
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "__construct",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [
+                    {
+                      "name": "q",
+                      "type_hint": {
+                        "kind": {
+                          "Named": {
+                            "parts": [
+                              "int"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 177,
+                              "end": 180
+                            }
+                          }
+                        },
+                        "span": {
+                          "start": 177,
+                          "end": 180
+                        }
+                      },
+                      "default": null,
+                      "by_ref": false,
+                      "variadic": false,
+                      "is_readonly": false,
+                      "is_final": false,
+                      "visibility": "Public",
+                      "set_visibility": null,
+                      "attributes": [
+                        {
+                          "name": {
+                            "parts": [
+                              "A8"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 166,
+                              "end": 168
+                            }
+                          },
+                          "args": [],
+                          "span": {
+                            "start": 166,
+                            "end": 168
+                          }
+                        }
+                      ],
+                      "span": {
+                        "start": 164,
+                        "end": 183
+                      }
+                    }
+                  ],
+                  "return_type": null,
+                  "body": [],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 136,
+                "end": 187
+              }
+            }
+          ],
+          "attributes": [
+            {
+              "name": {
+                "parts": [
+                  "A4"
+                ],
+                "kind": "Unqualified",
+                "span": {
+                  "start": 57,
+                  "end": 59
+                }
+              },
+              "args": [],
+              "span": {
+                "start": 57,
+                "end": 59
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 61,
+        "end": 189
+      }
+    },
+    {
+      "kind": {
+        "Interface": {
+          "name": "I",
+          "extends": [],
+          "members": [
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "K",
+                  "visibility": null,
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 227,
+                      "end": 228
+                    }
+                  },
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A10"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 212,
+                          "end": 215
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 212,
+                        "end": 215
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 210,
+                "end": 229
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "m",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": null,
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A11"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 232,
+                          "end": 235
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 232,
+                        "end": 235
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 230,
+                "end": 257
+              }
+            }
+          ],
+          "attributes": [
+            {
+              "name": {
+                "parts": [
+                  "A9"
+                ],
+                "kind": "Unqualified",
+                "span": {
+                  "start": 192,
+                  "end": 194
+                }
+              },
+              "args": [],
+              "span": {
+                "start": 192,
+                "end": 194
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 196,
+        "end": 259
+      }
+    },
+    {
+      "kind": {
+        "Trait": {
+          "name": "T",
+          "members": [
+            {
+              "kind": {
+                "Property": {
+                  "name": "p",
+                  "visibility": "Public",
+                  "set_visibility": null,
+                  "is_static": false,
+                  "is_readonly": false,
+                  "type_hint": null,
+                  "default": null,
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A13"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 279,
+                          "end": 282
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 279,
+                        "end": 282
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 277,
+                "end": 293
+              }
+            }
+          ],
+          "attributes": [
+            {
+              "name": {
+                "parts": [
+                  "A12"
+                ],
+                "kind": "Unqualified",
+                "span": {
+                  "start": 262,
+                  "end": 265
+                }
+              },
+              "args": [],
+              "span": {
+                "start": 262,
+                "end": 265
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 267,
+        "end": 296
+      }
+    },
+    {
+      "kind": {
+        "Enum": {
+          "name": "E",
+          "scalar_type": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "Case": {
+                  "name": "A",
+                  "value": null,
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A15"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 315,
+                          "end": 318
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 315,
+                        "end": 318
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 320,
+                "end": 327
+              }
+            },
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "K",
+                  "visibility": null,
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 345,
+                      "end": 346
+                    }
+                  },
+                  "attributes": [
+                    {
+                      "name": {
+                        "parts": [
+                          "A16"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 330,
+                          "end": 333
+                        }
+                      },
+                      "args": [],
+                      "span": {
+                        "start": 330,
+                        "end": 333
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 335,
+                "end": 347
+              }
+            }
+          ],
+          "attributes": [
+            {
+              "name": {
+                "parts": [
+                  "A14"
+                ],
+                "kind": "Unqualified",
+                "span": {
+                  "start": 299,
+                  "end": 302
+                }
+              },
+              "args": [],
+              "span": {
+                "start": 299,
+                "end": 302
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 304,
+        "end": 349
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "a"
+                },
+                "span": {
+                  "start": 350,
+                  "end": 352
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "New": {
+                    "class": {
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": [
+                              {
+                                "name": {
+                                  "parts": [
+                                    "A17"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 361,
+                                    "end": 364
+                                  }
+                                },
+                                "args": [],
+                                "span": {
+                                  "start": 361,
+                                  "end": 364
+                                }
+                              }
+                            ]
+                          }
+                        },
+                        "span": {
+                          "start": 355,
+                          "end": 374
+                        }
+                      }
+                    },
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 355,
+                  "end": 374
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 350,
+            "end": 374
+          }
+        }
+      },
+      "span": {
+        "start": 350,
+        "end": 375
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "b"
+                },
+                "span": {
+                  "start": 376,
+                  "end": 378
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Closure": {
+                    "is_static": false,
+                    "by_ref": false,
+                    "params": [],
+                    "use_vars": [],
+                    "return_type": null,
+                    "body": [],
+                    "attributes": [
+                      {
+                        "name": {
+                          "parts": [
+                            "A18"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 383,
+                            "end": 386
+                          }
+                        },
+                        "args": [],
+                        "span": {
+                          "start": 383,
+                          "end": 386
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 381,
+                  "end": 402
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 376,
+            "end": 402
+          }
+        }
+      },
+      "span": {
+        "start": 376,
+        "end": 403
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "c"
+                },
+                "span": {
+                  "start": 404,
+                  "end": 406
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "ArrowFunction": {
+                    "is_static": false,
+                    "by_ref": false,
+                    "params": [],
+                    "return_type": null,
+                    "body": {
+                      "kind": {
+                        "Int": 1
+                      },
+                      "span": {
+                        "start": 425,
+                        "end": 426
+                      }
+                    },
+                    "attributes": [
+                      {
+                        "name": {
+                          "parts": [
+                            "A19"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 411,
+                            "end": 414
+                          }
+                        },
+                        "args": [],
+                        "span": {
+                          "start": 411,
+                          "end": 414
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 409,
+                  "end": 426
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 404,
+            "end": 426
+          }
+        }
+      },
+      "span": {
+        "start": 404,
+        "end": 427
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "d"
+                },
+                "span": {
+                  "start": 428,
+                  "end": 430
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "New": {
+                    "class": {
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": true
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": [
+                              {
+                                "name": {
+                                  "parts": [
+                                    "A20"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 439,
+                                    "end": 442
+                                  }
+                                },
+                                "args": [],
+                                "span": {
+                                  "start": 439,
+                                  "end": 442
+                                }
+                              }
+                            ]
+                          }
+                        },
+                        "span": {
+                          "start": 433,
+                          "end": 461
+                        }
+                      }
+                    },
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 433,
+                  "end": 461
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 428,
+            "end": 461
+          }
+        }
+      },
+      "span": {
+        "start": 428,
+        "end": 462
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "e"
+                },
+                "span": {
+                  "start": 463,
+                  "end": 465
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "ArrowFunction": {
+                    "is_static": true,
+                    "by_ref": false,
+                    "params": [],
+                    "return_type": null,
+                    "body": {
+                      "kind": {
+                        "Int": 1
+                      },
+                      "span": {
+                        "start": 491,
+                        "end": 492
+                      }
+                    },
+                    "attributes": [
+                      {
+                        "name": {
+                          "parts": [
+                            "A21"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 470,
+                            "end": 473
+                          }
+                        },
+                        "args": [],
+                        "span": {
+                          "start": 470,
+                          "end": 473
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 468,
+                  "end": 492
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 463,
+            "end": 492
+          }
+        }
+      },
+      "span": {
+        "start": 463,
+        "end": 493
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "H",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "Property": {
+                  "name": "x",
+                  "visibility": "Public",
+                  "set_visibility": null,
+                  "is_static": false,
+                  "is_readonly": false,
+                  "type_hint": {
+                    "kind": {
+                      "Named": {
+                        "parts": [
+                          "int"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 511,
+                          "end": 514
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 511,
+                      "end": 514
+                    }
+                  },
+                  "default": null,
+                  "attributes": [],
+                  "hooks": [
+                    {
+                      "kind": "Get",
+                      "body": {
+                        "Expression": {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 534,
+                            "end": 535
+                          }
+                        }
+                      },
+                      "is_final": false,
+                      "by_ref": false,
+                      "params": [],
+                      "attributes": [
+                        {
+                          "name": {
+                            "parts": [
+                              "A22"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 522,
+                              "end": 525
+                            }
+                          },
+                          "args": [],
+                          "span": {
+                            "start": 522,
+                            "end": 525
+                          }
+                        }
+                      ],
+                      "span": {
+                        "start": 520,
+                        "end": 536
+                      }
+                    },
+                    {
+                      "kind": "Set",
+                      "body": {
+                        "Block": []
+                      },
+                      "is_final": false,
+                      "by_ref": false,
+                      "params": [
+                        {
+                          "name": "v",
+                          "type_hint": {
+                            "kind": {
+                              "Named": {
+                                "parts": [
+                                  "int"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 555,
+                                  "end": 558
+                                }
+                              }
+                            },
+                            "span": {
+                              "start": 555,
+                              "end": 558
+                            }
+                          },
+                          "default": null,
+                          "by_ref": false,
+                          "variadic": false,
+                          "is_readonly": false,
+                          "is_final": false,
+                          "visibility": null,
+                          "set_visibility": null,
+                          "attributes": [
+                            {
+                              "name": {
+                                "parts": [
+                                  "A24"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 550,
+                                  "end": 553
+                                }
+                              },
+                              "args": [],
+                              "span": {
+                                "start": 550,
+                                "end": 553
+                              }
+                            }
+                          ],
+                          "span": {
+                            "start": 548,
+                            "end": 561
+                          }
+                        }
+                      ],
+                      "attributes": [
+                        {
+                          "name": {
+                            "parts": [
+                              "A23"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 539,
+                              "end": 542
+                            }
+                          },
+                          "args": [],
+                          "span": {
+                            "start": 539,
+                            "end": 542
+                          }
+                        }
+                      ],
+                      "span": {
+                        "start": 537,
+                        "end": 565
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 504,
+                "end": 567
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 494,
+        "end": 569
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 569
+  }
+}
@@ -28,7 +28,10 @@
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "/api"
+                      "String": {
+                        "value": "/api",
+                        "raw": "/api"
+                      }
                     },
                     "span": {
                       "start": 14,
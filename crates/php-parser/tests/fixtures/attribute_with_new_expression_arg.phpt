@@ -30,12 +30,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Config"
-                          },
-                          "span": {
-                            "start": 17,
-                            "end": 23
+                          "Name": {
+                            "parts": [
+                              "Config"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 17,
+                              "end": 23
+                            }
                           }
                         },
                         "args": [
@@ -50,6 +53,10 @@
                                 "end": 29
                               }
                             },
+                            "colon": {
+                              "start": 29,
+                              "end": 30
+                            },
                             "value": {
                               "kind": {
                                 "Bool": false
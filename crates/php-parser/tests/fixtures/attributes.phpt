@@ -90,6 +90,10 @@ enum Color {
               "span": {
                 "start": 60,
                 "end": 66
+              },
+              "comma": {
+                "start": 66,
+                "end": 67
               }
             },
             {
@@ -235,7 +239,10 @@ enum Color {
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "/api/users"
+                      "String": {
+                        "value": "/api/users",
+                        "raw": "/api/users"
+                      }
                     },
                     "span": {
                       "start": 142,
@@ -247,6 +254,10 @@ enum Color {
                   "span": {
                     "start": 142,
                     "end": 154
+                  },
+                  "comma": {
+                    "start": 154,
+                    "end": 155
                   }
                 },
                 {
@@ -260,6 +271,10 @@ enum Color {
                       "end": 163
                     }
                   },
+                  "colon": {
+                    "start": 163,
+                    "end": 164
+                  },
                   "value": {
                     "kind": {
                       "Array": [
@@ -267,7 +282,10 @@ enum Color {
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "GET"
+                              "String": {
+                                "value": "GET",
+                                "raw": "GET"
+                              }
                             },
                             "span": {
                               "start": 166,
@@ -278,13 +296,20 @@ enum Color {
                           "span": {
                             "start": 166,
                             "end": 171
+                          },
+                          "comma": {
+                            "start": 171,
+                            "end": 172
                           }
                         },
                         {
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "POST"
+                              "String": {
+                                "value": "POST",
+                                "raw": "POST"
+                              }
                             },
                             "span": {
                               "start": 173,
@@ -491,7 +516,10 @@ enum Color {
                           "name": null,
                           "value": {
                             "kind": {
-                              "String": "name"
+                              "String": {
+                                "value": "name",
+                                "raw": "name"
+                              }
                             },
                             "span": {
                               "start": 382,
@@ -672,7 +700,10 @@ enum Color {
                           "name": null,
                           "value": {
                             "kind": {
-                              "String": "Use getName() instead"
+                              "String": {
+                                "value": "Use getName() instead",
+                                "raw": "Use getName() instead"
+                              }
                             },
                             "span": {
                               "start": 488,
@@ -13,7 +13,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "\\\\"
+                "Literal": {
+                  "value": "\\\\",
+                  "span": {
+                    "start": 7,
+                    "end": 11
+                  }
+                }
               }
             ]
           },
@@ -34,7 +40,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "\\$"
+                "Literal": {
+                  "value": "\\$",
+                  "span": {
+                    "start": 15,
+                    "end": 19
+                  }
+                }
               }
             ]
           },
@@ -55,7 +67,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "\\\\`"
+                "Literal": {
+                  "value": "\\\\`",
+                  "span": {
+                    "start": 23,
+                    "end": 27
+                  }
+                }
               }
             ]
           },
@@ -76,7 +94,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "\\"
+                "Literal": {
+                  "value": "\\",
+                  "span": {
+                    "start": 31,
+                    "end": 33
+                  }
+                }
               },
               {
                 "Expr": {
@@ -12,7 +12,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "echo \\test"
+                "Literal": {
+                  "value": "echo \\test",
+                  "span": {
+                    "start": 7,
+                    "end": 18
+                  }
+                }
               }
             ]
           },
@@ -33,7 +39,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "echo \\"
+                "Literal": {
+                  "value": "echo \\",
+                  "span": {
+                    "start": 22,
+                    "end": 29
+                  }
+                }
               }
             ]
           },
@@ -54,7 +66,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "\\\\"
+                "Literal": {
+                  "value": "\\\\",
+                  "span": {
+                    "start": 33,
+                    "end": 37
+                  }
+                }
               }
             ]
           },
@@ -12,7 +12,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "echo $var"
+                "Literal": {
+                  "value": "echo $var",
+                  "span": {
+                    "start": 7,
+                    "end": 17
+                  }
+                }
               }
             ]
           },
@@ -33,7 +39,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "$test"
+                "Literal": {
+                  "value": "$test",
+                  "span": {
+                    "start": 21,
+                    "end": 27
+                  }
+                }
               }
             ]
           },
@@ -54,7 +66,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "prefix$suffix"
+                "Literal": {
+                  "value": "prefix$suffix",
+                  "span": {
+                    "start": 31,
+                    "end": 45
+                  }
+                }
               }
             ]
           },
@@ -62,7 +62,10 @@ $nothing = null;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "hello"
+                  "String": {
+                    "value": "hello",
+                    "raw": "hello"
+                  }
                 },
                 "span": {
                   "start": 23,
@@ -24,9 +24,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello World",
                     "parts": [
                       {
-                        "Literal": "Hello World"
+                        "Literal": {
+                          "value": "Hello World",
+                          "span": {
+                            "start": 18,
+                            "end": 29
+                          }
+                        }
                       }
                     ]
                   }
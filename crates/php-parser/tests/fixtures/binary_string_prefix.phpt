@@ -23,7 +23,10 @@ $c = B"case insensitive";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "binary string"
+                  "String": {
+                    "value": "binary string",
+                    "raw": "binary string"
+                  }
                 },
                 "span": {
                   "start": 11,
@@ -60,7 +63,10 @@ $c = B"case insensitive";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "binary single"
+                  "String": {
+                    "value": "binary single",
+                    "raw": "binary single"
+                  }
                 },
                 "span": {
                   "start": 34,
@@ -97,7 +103,10 @@ $c = B"case insensitive";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "case insensitive"
+                  "String": {
+                    "value": "case insensitive",
+                    "raw": "case insensitive"
+                  }
                 },
                 "span": {
                   "start": 57,
@@ -103,6 +103,18 @@ do $A; while ($a);
           "init": [],
           "condition": [],
           "update": [],
+          "init_span": {
+            "start": 48,
+            "end": 48
+          },
+          "condition_span": {
+            "start": 49,
+            "end": 49
+          },
+          "update_span": {
+            "start": 50,
+            "end": 50
+          },
           "body": {
             "kind": {
               "Expression": {
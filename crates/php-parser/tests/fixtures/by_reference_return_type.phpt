@@ -31,7 +31,10 @@ function &getReference(array &$arr): mixed {
                       },
                       "index": {
                         "kind": {
-                          "String": "value"
+                          "String": {
+                            "value": "value",
+                            "raw": "value"
+                          }
                         },
                         "span": {
                           "start": 57,
@@ -19,7 +19,16 @@ try {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "risky"
+                          "Name": {
+                            "parts": [
+                              "risky"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 16,
+                              "end": 21
+                            }
+                          }
                         },
                         "span": {
                           "start": 16,
@@ -45,47 +54,61 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "TypeError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 34,
-                    "end": 43
+                  "Name": {
+                    "parts": [
+                      "TypeError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 34,
+                      "end": 43
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "ValueError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 44,
-                    "end": 54
+                  "Name": {
+                    "parts": [
+                      "ValueError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 44,
+                      "end": 54
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "RuntimeException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 55,
-                    "end": 71
+                  "Name": {
+                    "parts": [
+                      "RuntimeException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 55,
+                      "end": 71
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "LogicException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 72,
-                    "end": 86
+                  "Name": {
+                    "parts": [
+                      "LogicException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 72,
+                      "end": 86
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 87,
+                  "end": 89
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -94,7 +117,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handle"
+                              "Name": {
+                                "parts": [
+                                  "handle"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 97,
+                                  "end": 103
+                                }
+                              }
                             },
                             "span": {
                               "start": 97,
@@ -19,7 +19,16 @@ try {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "risky"
+                          "Name": {
+                            "parts": [
+                              "risky"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 16,
+                              "end": 21
+                            }
+                          }
                         },
                         "span": {
                           "start": 16,
@@ -45,37 +54,49 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "A"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 34,
-                    "end": 35
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 34,
+                      "end": 35
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "B"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 36,
-                    "end": 37
+                  "Name": {
+                    "parts": [
+                      "B"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 36,
+                      "end": 37
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "C"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 38,
-                    "end": 39
+                  "Name": {
+                    "parts": [
+                      "C"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 38,
+                      "end": 39
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 40,
+                  "end": 42
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -84,7 +105,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handle"
+                              "Name": {
+                                "parts": [
+                                  "handle"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 50,
+                                  "end": 56
+                                }
+                              }
                             },
                             "span": {
                               "start": 50,
@@ -25,7 +25,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": "body"
+                    "InlineHtml": {
+                      "text": "body"
+                    }
                   },
                   "span": {
                     "start": 26,
@@ -51,5 +53,10 @@
   "span": {
     "start": 0,
     "end": 46
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 47,
+    "end": 49
   }
 }
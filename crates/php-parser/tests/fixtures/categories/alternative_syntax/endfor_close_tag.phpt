@@ -90,12 +90,26 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 25
+          },
+          "update_span": {
+            "start": 27,
+            "end": 31
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": "x"
+                    "InlineHtml": {
+                      "text": "x"
+                    }
                   },
                   "span": {
                     "start": 36,
@@ -121,5 +135,10 @@
   "span": {
     "start": 0,
     "end": 49
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 50,
+    "end": 52
   }
 }
@@ -30,20 +30,30 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "x"
-                        },
-                        "span": {
-                          "start": 33,
-                          "end": 35
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 33,
+                            "end": 35
+                          }
                         }
+                      ],
+                      "open_tag": {
+                        "start": 29,
+                        "end": 32
+                      },
+                      "close_tag": {
+                        "start": 36,
+                        "end": 38
                       }
-                    ]
+                    }
                   },
                   "span": {
-                    "start": 33,
+                    "start": 29,
                     "end": 35
                   }
                 }
@@ -66,5 +76,10 @@
   "span": {
     "start": 0,
     "end": 54
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 55,
+    "end": 57
   }
 }
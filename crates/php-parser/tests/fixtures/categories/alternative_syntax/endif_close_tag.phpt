@@ -20,7 +20,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": "hello"
+                    "InlineHtml": {
+                      "text": "hello"
+                    }
                   },
                   "span": {
                     "start": 17,
@@ -48,5 +50,10 @@
   "span": {
     "start": 0,
     "end": 33
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 34,
+    "end": 36
   }
 }
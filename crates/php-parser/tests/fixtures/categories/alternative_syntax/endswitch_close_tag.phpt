@@ -29,17 +29,19 @@
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 32,
-                          "end": 33
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 32,
+                            "end": 33
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 27,
@@ -74,5 +76,10 @@
   "span": {
     "start": 0,
     "end": 51
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 52,
+    "end": 54
   }
 }
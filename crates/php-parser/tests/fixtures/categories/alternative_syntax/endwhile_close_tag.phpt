@@ -20,7 +20,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": "loop"
+                    "InlineHtml": {
+                      "text": "loop"
+                    }
                   },
                   "span": {
                     "start": 20,
@@ -46,5 +48,10 @@
   "span": {
     "start": 0,
     "end": 38
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 39,
+    "end": 41
   }
 }
@@ -90,22 +90,36 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 40,
-                          "end": 42
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 40,
+                            "end": 42
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 35,
@@ -30,17 +30,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "v"
-                        },
-                        "span": {
-                          "start": 33,
-                          "end": 35
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "v"
+                          },
+                          "span": {
+                            "start": 33,
+                            "end": 35
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 28,
@@ -20,17 +20,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 20,
-                          "end": 21
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 20,
+                            "end": 21
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 15,
@@ -60,17 +62,19 @@
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "Int": 2
-                            },
-                            "span": {
-                              "start": 41,
-                              "end": 42
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "Int": 2
+                              },
+                              "span": {
+                                "start": 41,
+                                "end": 42
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 36,
@@ -95,17 +99,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 3
-                        },
-                        "span": {
-                          "start": 55,
-                          "end": 56
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 3
+                          },
+                          "span": {
+                            "start": 55,
+                            "end": 56
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 50,
@@ -29,17 +29,22 @@
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "one"
-                        },
-                        "span": {
-                          "start": 32,
-                          "end": 37
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
+                          },
+                          "span": {
+                            "start": 32,
+                            "end": 37
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 27,
@@ -66,17 +71,22 @@
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "other"
-                        },
-                        "span": {
-                          "start": 60,
-                          "end": 67
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
+                          },
+                          "span": {
+                            "start": 60,
+                            "end": 67
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 55,
@@ -24,7 +24,10 @@
                     {
                       "key": {
                         "kind": {
-                          "String": "map"
+                          "String": {
+                            "value": "map",
+                            "raw": "map"
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -96,12 +99,19 @@
                       "span": {
                         "start": 12,
                         "end": 37
+                      },
+                      "comma": {
+                        "start": 37,
+                        "end": 38
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "filter"
+                          "String": {
+                            "value": "filter",
+                            "raw": "filter"
+                          }
                         },
                         "span": {
                           "start": 39,
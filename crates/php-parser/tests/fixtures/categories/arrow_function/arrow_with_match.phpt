@@ -89,7 +89,10 @@
                               ],
                               "body": {
                                 "kind": {
-                                  "String": "neg"
+                                  "String": {
+                                    "value": "neg",
+                                    "raw": "neg"
+                                  }
                                 },
                                 "span": {
                                   "start": 52,
@@ -99,6 +102,10 @@
                               "span": {
                                 "start": 42,
                                 "end": 57
+                              },
+                              "comma": {
+                                "start": 57,
+                                "end": 58
                               }
                             },
                             {
@@ -135,7 +142,10 @@
                               ],
                               "body": {
                                 "kind": {
-                                  "String": "zero"
+                                  "String": {
+                                    "value": "zero",
+                                    "raw": "zero"
+                                  }
                                 },
                                 "span": {
                                   "start": 71,
@@ -145,13 +155,20 @@
                               "span": {
                                 "start": 59,
                                 "end": 77
+                              },
+                              "comma": {
+                                "start": 77,
+                                "end": 78
                               }
                             },
                             {
                               "conditions": null,
                               "body": {
                                 "kind": {
-                                  "String": "pos"
+                                  "String": {
+                                    "value": "pos",
+                                    "raw": "pos"
+                                  }
                                 },
                                 "span": {
                                   "start": 90,
@@ -31,12 +31,15 @@
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "Exception"
-                                },
-                                "span": {
-                                  "start": 29,
-                                  "end": 38
+                                "Name": {
+                                  "parts": [
+                                    "Exception"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 29,
+                                    "end": 38
+                                  }
                                 }
                               },
                               "args": []
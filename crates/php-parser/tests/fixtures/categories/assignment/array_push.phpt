@@ -31,7 +31,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "new"
+                  "String": {
+                    "value": "new",
+                    "raw": "new"
+                  }
                 },
                 "span": {
                   "start": 15,
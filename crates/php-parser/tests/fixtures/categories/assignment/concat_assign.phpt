@@ -20,7 +20,10 @@
               "op": "Concat",
               "value": {
                 "kind": {
-                  "String": " world"
+                  "String": {
+                    "value": " world",
+                    "raw": " world"
+                  }
                 },
                 "span": {
                   "start": 14,
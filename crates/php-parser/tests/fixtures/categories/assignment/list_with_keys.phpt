@@ -10,58 +10,70 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "x",
+                              "raw": "x"
+                            }
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 10
+                          }
                         },
-                        "span": {
-                          "start": 7,
-                          "end": 10
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 14,
+                            "end": 16
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 14,
+                          "start": 7,
                           "end": 16
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 16
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "y"
                         },
-                        "span": {
-                          "start": 18,
-                          "end": 21
+                        "comma": {
+                          "start": 16,
+                          "end": 17
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "y"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "y",
+                              "raw": "y"
+                            }
+                          },
+                          "span": {
+                            "start": 18,
+                            "end": 21
+                          }
                         },
+                        "value": {
+                          "kind": {
+                            "Variable": "y"
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 27
+                          }
+                        },
+                        "unpack": false,
                         "span": {
-                          "start": 25,
+                          "start": 18,
                           "end": 27
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 18,
-                        "end": 27
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -74,7 +86,16 @@
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "getPoint"
+                        "Name": {
+                          "parts": [
+                            "getPoint"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 31,
+                            "end": 39
+                          }
+                        }
                       },
                       "span": {
                         "start": 31,
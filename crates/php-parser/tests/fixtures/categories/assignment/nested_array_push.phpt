@@ -42,7 +42,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "deep"
+                  "String": {
+                    "value": "deep",
+                    "raw": "deep"
+                  }
                 },
                 "span": {
                   "start": 17,
@@ -20,7 +20,10 @@
               "op": "Coalesce",
               "value": {
                 "kind": {
-                  "String": "default"
+                  "String": {
+                    "value": "default",
+                    "raw": "default"
+                  }
                 },
                 "span": {
                   "start": 13,
@@ -28,7 +28,10 @@
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "/api"
+                      "String": {
+                        "value": "/api",
+                        "raw": "/api"
+                      }
                     },
                     "span": {
                       "start": 14,
@@ -40,6 +43,10 @@
                   "span": {
                     "start": 14,
                     "end": 20
+                  },
+                  "comma": {
+                    "start": 20,
+                    "end": 21
                   }
                 },
                 {
@@ -53,6 +60,10 @@
                       "end": 29
                     }
                   },
+                  "colon": {
+                    "start": 29,
+                    "end": 30
+                  },
                   "value": {
                     "kind": {
                       "Array": [
@@ -60,7 +71,10 @@
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "GET"
+                              "String": {
+                                "value": "GET",
+                                "raw": "GET"
+                              }
                             },
                             "span": {
                               "start": 32,
@@ -71,13 +85,20 @@
                           "span": {
                             "start": 32,
                             "end": 37
+                          },
+                          "comma": {
+                            "start": 37,
+                            "end": 38
                           }
                         },
                         {
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "POST"
+                              "String": {
+                                "value": "POST",
+                                "raw": "POST"
+                              }
                             },
                             "span": {
                               "start": 39,
@@ -34,7 +34,10 @@ min_php=8.1
                           "name": null,
                           "value": {
                             "kind": {
-                              "String": "Hearts"
+                              "String": {
+                                "value": "Hearts",
+                                "raw": "Hearts"
+                              }
                             },
                             "span": {
                               "start": 32,
@@ -40,6 +40,10 @@
                       "end": 24
                     }
                   },
+                  "colon": {
+                    "start": 24,
+                    "end": 25
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -54,6 +58,10 @@
                   "span": {
                     "start": 21,
                     "end": 27
+                  },
+                  "comma": {
+                    "start": 27,
+                    "end": 28
                   }
                 },
                 {
@@ -67,6 +75,10 @@
                       "end": 32
                     }
                   },
+                  "colon": {
+                    "start": 32,
+                    "end": 33
+                  },
                   "value": {
                     "kind": {
                       "Int": 100
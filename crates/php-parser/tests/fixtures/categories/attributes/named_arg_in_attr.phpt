@@ -35,9 +35,16 @@
                       "end": 18
                     }
                   },
+                  "colon": {
+                    "start": 18,
+                    "end": 19
+                  },
                   "value": {
                     "kind": {
-                      "String": "/api"
+                      "String": {
+                        "value": "/api",
+                        "raw": "/api"
+                      }
                     },
                     "span": {
                       "start": 20,
@@ -49,6 +56,10 @@
                   "span": {
                     "start": 14,
                     "end": 26
+                  },
+                  "comma": {
+                    "start": 26,
+                    "end": 27
                   }
                 },
                 {
@@ -62,6 +73,10 @@
                       "end": 35
                     }
                   },
+                  "colon": {
+                    "start": 35,
+                    "end": 36
+                  },
                   "value": {
                     "kind": {
                       "Array": [
@@ -69,7 +84,10 @@
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "GET"
+                              "String": {
+                                "value": "GET",
+                                "raw": "GET"
+                              }
                             },
                             "span": {
                               "start": 38,
@@ -32,7 +32,10 @@
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "positional"
+                      "String": {
+                        "value": "positional",
+                        "raw": "positional"
+                      }
                     },
                     "span": {
                       "start": 13,
@@ -44,6 +47,10 @@
                   "span": {
                     "start": 13,
                     "end": 25
+                  },
+                  "comma": {
+                    "start": 25,
+                    "end": 26
                   }
                 },
                 {
@@ -57,9 +64,16 @@
                       "end": 30
                     }
                   },
+                  "colon": {
+                    "start": 30,
+                    "end": 31
+                  },
                   "value": {
                     "kind": {
-                      "String": "value"
+                      "String": {
+                        "value": "value",
+                        "raw": "value"
+                      }
                     },
                     "span": {
                       "start": 32,
@@ -71,6 +85,10 @@
                   "span": {
                     "start": 27,
                     "end": 39
+                  },
+                  "comma": {
+                    "start": 39,
+                    "end": 40
                   }
                 },
                 {
@@ -84,6 +102,10 @@
                       "end": 45
                     }
                   },
+                  "colon": {
+                    "start": 45,
+                    "end": 46
+                  },
                   "value": {
                     "kind": {
                       "Bool": true
@@ -101,6 +101,18 @@ switch ($x) {
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
@@ -191,6 +203,18 @@ switch ($x) {
                           }
                         }
                       ],
+                      "init_span": {
+                        "start": 45,
+                        "end": 51
+                      },
+                      "condition_span": {
+                        "start": 53,
+                        "end": 60
+                      },
+                      "update_span": {
+                        "start": 62,
+                        "end": 66
+                      },
                       "body": {
                         "kind": {
                           "Block": [
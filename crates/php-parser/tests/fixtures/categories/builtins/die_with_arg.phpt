@@ -9,7 +9,10 @@
           "kind": {
             "Exit": {
               "kind": {
-                "String": "error"
+                "String": {
+                  "value": "error",
+                  "raw": "error"
+                }
               },
               "span": {
                 "start": 10,
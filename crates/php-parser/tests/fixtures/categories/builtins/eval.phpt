@@ -9,7 +9,10 @@
           "kind": {
             "Eval": {
               "kind": {
-                "String": "echo 1;"
+                "String": {
+                  "value": "echo 1;",
+                  "raw": "echo 1;"
+                }
               },
               "span": {
                 "start": 11,
@@ -24,7 +24,10 @@
                     "op": "Concat",
                     "right": {
                       "kind": {
-                        "String": "/file.php"
+                        "String": {
+                          "value": "/file.php",
+                          "raw": "/file.php"
+                        }
                       },
                       "span": {
                         "start": 21,
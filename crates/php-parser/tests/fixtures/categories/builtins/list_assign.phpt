@@ -10,42 +10,49 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 15,
+                            "end": 17
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 15,
                           "end": 17
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 15,
-                        "end": 17
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
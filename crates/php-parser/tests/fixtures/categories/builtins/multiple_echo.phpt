@@ -5,35 +5,37 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "a"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "a"
+              },
+              "span": {
+                "start": 11,
+                "end": 13
+              }
             },
-            "span": {
-              "start": 11,
-              "end": 13
-            }
-          },
-          {
-            "kind": {
-              "Variable": "b"
-            },
-            "span": {
-              "start": 15,
-              "end": 17
-            }
-          },
-          {
-            "kind": {
-              "Variable": "c"
+            {
+              "kind": {
+                "Variable": "b"
+              },
+              "span": {
+                "start": 15,
+                "end": 17
+              }
             },
-            "span": {
-              "start": 19,
-              "end": 21
+            {
+              "kind": {
+                "Variable": "c"
+              },
+              "span": {
+                "start": 19,
+                "end": 21
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
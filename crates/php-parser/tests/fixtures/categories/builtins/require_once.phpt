@@ -11,7 +11,10 @@
               "RequireOnce",
               {
                 "kind": {
-                  "String": "autoload.php"
+                  "String": {
+                    "value": "autoload.php",
+                    "raw": "autoload.php"
+                  }
                 },
                 "span": {
                   "start": 19,
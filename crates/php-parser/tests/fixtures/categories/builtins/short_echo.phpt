@@ -5,20 +5,30 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "value"
-            },
-            "span": {
-              "start": 4,
-              "end": 10
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "value"
+              },
+              "span": {
+                "start": 4,
+                "end": 10
+              }
             }
+          ],
+          "open_tag": {
+            "start": 0,
+            "end": 3
+          },
+          "close_tag": {
+            "start": 11,
+            "end": 13
           }
-        ]
+        }
       },
       "span": {
-        "start": 4,
+        "start": 0,
         "end": 10
       }
     }
@@ -26,5 +36,10 @@
   "span": {
     "start": 0,
     "end": 10
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 11,
+    "end": 13
   }
 }
@@ -16,7 +16,16 @@ min_php=8.5
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 12,
+                            "end": 15
+                          }
+                        }
                       },
                       "span": {
                         "start": 12,
@@ -22,74 +22,76 @@
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": {
-                            "parts": [
-                              "Base"
-                            ],
-                            "kind": "Unqualified",
-                            "span": {
-                              "start": 34,
-                              "end": 38
-                            }
-                          },
-                          "implements": [
-                            {
-                              "parts": [
-                                "Iface1"
-                              ],
-                              "kind": "Unqualified",
-                              "span": {
-                                "start": 50,
-                                "end": 56
-                              }
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
                             },
-                            {
+                            "extends": {
                               "parts": [
-                                "Iface2"
+                                "Base"
                               ],
                               "kind": "Unqualified",
                               "span": {
-                                "start": 58,
-                                "end": 64
+                                "start": 34,
+                                "end": 38
                               }
-                            }
-                          ],
-                          "members": [
-                            {
-                              "kind": {
-                                "Method": {
-                                  "name": "run",
-                                  "visibility": "Public",
-                                  "is_static": false,
-                                  "is_abstract": false,
-                                  "is_final": false,
-                                  "by_ref": false,
-                                  "params": [],
-                                  "return_type": null,
-                                  "body": [],
-                                  "attributes": []
+                            },
+                            "implements": [
+                              {
+                                "parts": [
+                                  "Iface1"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 50,
+                                  "end": 56
                                 }
                               },
-                              "span": {
-                                "start": 67,
-                                "end": 91
+                              {
+                                "parts": [
+                                  "Iface2"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 58,
+                                  "end": 64
+                                }
+                              }
+                            ],
+                            "members": [
+                              {
+                                "kind": {
+                                  "Method": {
+                                    "name": "run",
+                                    "visibility": "Public",
+                                    "is_static": false,
+                                    "is_abstract": false,
+                                    "is_final": false,
+                                    "by_ref": false,
+                                    "params": [],
+                                    "return_type": null,
+                                    "body": [],
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 67,
+                                  "end": 91
+                                }
                               }
-                            }
-                          ],
-                          "attributes": []
+                            ],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 13,
+                          "end": 93
                         }
-                      },
-                      "span": {
-                        "start": 13,
-                        "end": 93
                       }
                     },
                     "args": [
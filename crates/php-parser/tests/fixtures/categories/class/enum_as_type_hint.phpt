@@ -160,12 +160,15 @@ function baz(Enum $x): Enum { return $x; }
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "Enum"
-                                },
-                                "span": {
-                                  "start": 117,
-                                  "end": 121
+                                "Name": {
+                                  "parts": [
+                                    "Enum"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 117,
+                                    "end": 121
+                                  }
                                 }
                               },
                               "args": []
@@ -65,6 +65,10 @@ class Foo {
                       "span": {
                         "start": 59,
                         "end": 87
+                      },
+                      "comma": {
+                        "start": 87,
+                        "end": 88
                       }
                     },
                     {
@@ -98,6 +102,10 @@ class Foo {
                       "span": {
                         "start": 97,
                         "end": 120
+                      },
+                      "comma": {
+                        "start": 120,
+                        "end": 121
                       }
                     },
                     {
@@ -131,6 +139,10 @@ class Foo {
                       "span": {
                         "start": 130,
                         "end": 153
+                      },
+                      "comma": {
+                        "start": 153,
+                        "end": 154
                       }
                     }
                   ],
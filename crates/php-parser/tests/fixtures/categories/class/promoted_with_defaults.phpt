@@ -56,6 +56,10 @@
                       "span": {
                         "start": 46,
                         "end": 68
+                      },
+                      "comma": {
+                        "start": 68,
+                        "end": 69
                       }
                     },
                     {
@@ -80,7 +84,10 @@
                       },
                       "default": {
                         "kind": {
-                          "String": "default"
+                          "String": {
+                            "value": "default",
+                            "raw": "default"
+                          }
                         },
                         "span": {
                           "start": 90,
@@ -58,6 +58,10 @@ min_php=8.2
                       "span": {
                         "start": 57,
                         "end": 70
+                      },
+                      "comma": {
+                        "start": 70,
+                        "end": 71
                       }
                     },
                     {
@@ -21,32 +21,38 @@
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Assign": {
-                        "target": {
-                          "kind": {
-                            "Variable": "b"
+                    "object": {
+                      "kind": {
+                        "Assign": {
+                          "target": {
+                            "kind": {
+                              "Variable": "b"
+                            },
+                            "span": {
+                              "start": 17,
+                              "end": 19
+                            }
                           },
-                          "span": {
-                            "start": 17,
-                            "end": 19
-                          }
-                        },
-                        "op": "Assign",
-                        "value": {
-                          "kind": {
-                            "Variable": "c"
-                          },
-                          "span": {
-                            "start": 22,
-                            "end": 24
+                          "op": "Assign",
+                          "value": {
+                            "kind": {
+                              "Variable": "c"
+                            },
+                            "span": {
+                              "start": 22,
+                              "end": 24
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 17,
+                        "end": 24
                       }
                     },
-                    "span": {
-                      "start": 17,
-                      "end": 24
+                    "keyword_span": {
+                      "start": 11,
+                      "end": 16
                     }
                   }
                 },
@@ -24,23 +24,29 @@ $d = clone (array) $y;
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Cast": [
-                        "Object",
-                        {
-                          "kind": {
-                            "Variable": "b"
-                          },
-                          "span": {
-                            "start": 26,
-                            "end": 28
+                    "object": {
+                      "kind": {
+                        "Cast": [
+                          "Object",
+                          {
+                            "kind": {
+                              "Variable": "b"
+                            },
+                            "span": {
+                              "start": 26,
+                              "end": 28
+                            }
                           }
-                        }
-                      ]
+                        ]
+                      },
+                      "span": {
+                        "start": 17,
+                        "end": 28
+                      }
                     },
-                    "span": {
-                      "start": 17,
-                      "end": 28
+                    "keyword_span": {
+                      "start": 11,
+                      "end": 16
                     }
                   }
                 },
@@ -80,23 +86,29 @@ $d = clone (array) $y;
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Cast": [
-                        "Int",
-                        {
-                          "kind": {
-                            "Variable": "x"
-                          },
-                          "span": {
-                            "start": 47,
-                            "end": 49
+                    "object": {
+                      "kind": {
+                        "Cast": [
+                          "Int",
+                          {
+                            "kind": {
+                              "Variable": "x"
+                            },
+                            "span": {
+                              "start": 47,
+                              "end": 49
+                            }
                           }
-                        }
-                      ]
+                        ]
+                      },
+                      "span": {
+                        "start": 41,
+                        "end": 49
+                      }
                     },
-                    "span": {
-                      "start": 41,
-                      "end": 49
+                    "keyword_span": {
+                      "start": 35,
+                      "end": 40
                     }
                   }
                 },
@@ -136,23 +148,29 @@ $d = clone (array) $y;
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Cast": [
-                        "Array",
-                        {
-                          "kind": {
-                            "Variable": "y"
-                          },
-                          "span": {
-                            "start": 70,
-                            "end": 72
+                    "object": {
+                      "kind": {
+                        "Cast": [
+                          "Array",
+                          {
+                            "kind": {
+                              "Variable": "y"
+                            },
+                            "span": {
+                              "start": 70,
+                              "end": 72
+                            }
                           }
-                        }
-                      ]
+                        ]
+                      },
+                      "span": {
+                        "start": 62,
+                        "end": 72
+                      }
                     },
-                    "span": {
-                      "start": 62,
-                      "end": 72
+                    "keyword_span": {
+                      "start": 56,
+                      "end": 61
                     }
                   }
                 },
@@ -12,7 +12,16 @@ min_php=8.5
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 11
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -31,6 +40,10 @@ min_php=8.5
                       "end": 18
                     }
                   },
+                  "colon": {
+                    "start": 18,
+                    "end": 19
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
@@ -45,6 +58,10 @@ min_php=8.5
                   "span": {
                     "start": 12,
                     "end": 22
+                  },
+                  "comma": {
+                    "start": 22,
+                    "end": 23
                   }
                 },
                 {
@@ -58,13 +75,20 @@ min_php=8.5
                       "end": 38
                     }
                   },
+                  "colon": {
+                    "start": 38,
+                    "end": 39
+                  },
                   "value": {
                     "kind": {
                       "Array": [
                         {
                           "key": {
                             "kind": {
-                              "String": "foo"
+                              "String": {
+                                "value": "foo",
+                                "raw": "foo"
+                              }
                             },
                             "span": {
                               "start": 41,
@@ -23,12 +23,18 @@ min_php=8.5
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Variable": "obj"
+                    "object": {
+                      "kind": {
+                        "Variable": "obj"
+                      },
+                      "span": {
+                        "start": 20,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 20,
-                      "end": 24
+                    "keyword_span": {
+                      "start": 14,
+                      "end": 19
                     }
                   }
                 },
@@ -12,7 +12,16 @@ min_php=8.5
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 11
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -36,6 +45,10 @@ min_php=8.5
                   "span": {
                     "start": 12,
                     "end": 14
+                  },
+                  "comma": {
+                    "start": 14,
+                    "end": 15
                   }
                 },
                 {
@@ -54,6 +67,10 @@ min_php=8.5
                   "span": {
                     "start": 16,
                     "end": 18
+                  },
+                  "comma": {
+                    "start": 18,
+                    "end": 19
                   }
                 },
                 {
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "array_filter"
+                  "Name": {
+                    "parts": [
+                      "array_filter"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 18
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -34,6 +43,10 @@
                   "span": {
                     "start": 19,
                     "end": 23
+                  },
+                  "comma": {
+                    "start": 23,
+                    "end": 24
                   }
                 },
                 {
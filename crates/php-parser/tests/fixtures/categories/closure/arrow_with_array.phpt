@@ -60,6 +60,10 @@
                             "span": {
                               "start": 23,
                               "end": 25
+                            },
+                            "comma": {
+                              "start": 25,
+                              "end": 26
                             }
                           },
                           {
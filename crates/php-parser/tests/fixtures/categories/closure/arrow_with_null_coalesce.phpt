@@ -56,7 +56,10 @@
                           },
                           "right": {
                             "kind": {
-                              "String": "default"
+                              "String": {
+                                "value": "default",
+                                "raw": "default"
+                              }
                             },
                             "span": {
                               "start": 28,
@@ -31,6 +31,10 @@
                         "span": {
                           "start": 28,
                           "end": 31
+                        },
+                        "comma": {
+                          "start": 31,
+                          "end": 32
                         }
                       },
                       {
@@ -139,6 +139,18 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 15
+          },
+          "condition_span": {
+            "start": 17,
+            "end": 22
+          },
+          "update_span": {
+            "start": 24,
+            "end": 41
+          },
           "body": {
             "kind": {
               "Block": []
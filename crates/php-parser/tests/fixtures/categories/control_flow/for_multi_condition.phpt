@@ -79,6 +79,18 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 15
+          },
+          "condition_span": {
+            "start": 17,
+            "end": 23
+          },
+          "update_span": {
+            "start": 25,
+            "end": 29
+          },
           "body": {
             "kind": {
               "Block": []
@@ -148,6 +148,18 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 27
+          },
+          "condition_span": {
+            "start": 29,
+            "end": 36
+          },
+          "update_span": {
+            "start": 38,
+            "end": 42
+          },
           "body": {
             "kind": {
               "Block": []
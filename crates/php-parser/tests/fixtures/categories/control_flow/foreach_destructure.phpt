@@ -18,42 +18,48 @@
           "key": null,
           "value": {
             "kind": {
-              "Array": [
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "key"
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "key"
+                      },
+                      "span": {
+                        "start": 24,
+                        "end": 28
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 24,
                       "end": 28
+                    },
+                    "comma": {
+                      "start": 28,
+                      "end": 29
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 24,
-                    "end": 28
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "value"
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "value"
+                      },
+                      "span": {
+                        "start": 30,
+                        "end": 36
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 30,
                       "end": 36
                     }
-                  },
-                  "unpack": false,
-                  "span": {
-                    "start": 30,
-                    "end": 36
                   }
-                }
-              ]
+                ]
+              }
             },
             "span": {
               "start": 23,
@@ -65,17 +71,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "key"
-                        },
-                        "span": {
-                          "start": 46,
-                          "end": 50
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "key"
+                          },
+                          "span": {
+                            "start": 46,
+                            "end": 50
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 41,
@@ -26,42 +26,48 @@
           },
           "value": {
             "kind": {
-              "Array": [
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "a"
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 30,
+                        "end": 32
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 30,
                       "end": 32
+                    },
+                    "comma": {
+                      "start": 32,
+                      "end": 33
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 30,
-                    "end": 32
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "b"
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "b"
+                      },
+                      "span": {
+                        "start": 34,
+                        "end": 36
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 34,
                       "end": 36
                     }
-                  },
-                  "unpack": false,
-                  "span": {
-                    "start": 34,
-                    "end": 36
                   }
-                }
-              ]
+                ]
+              }
             },
             "span": {
               "start": 29,
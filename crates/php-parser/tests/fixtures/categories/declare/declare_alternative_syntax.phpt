@@ -25,17 +25,22 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "tick"
-                        },
-                        "span": {
-                          "start": 29,
-                          "end": 35
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "tick",
+                              "raw": "tick"
+                            }
+                          },
+                          "span": {
+                            "start": 29,
+                            "end": 35
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 24,
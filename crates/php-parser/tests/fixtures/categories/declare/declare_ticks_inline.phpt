@@ -22,17 +22,22 @@
           ],
           "body": {
             "kind": {
-              "Echo": [
-                {
-                  "kind": {
-                    "String": "tick"
-                  },
-                  "span": {
-                    "start": 28,
-                    "end": 34
+              "Echo": {
+                "exprs": [
+                  {
+                    "kind": {
+                      "String": {
+                        "value": "tick",
+                        "raw": "tick"
+                      }
+                    },
+                    "span": {
+                      "start": 28,
+                      "end": 34
+                    }
                   }
-                }
-              ]
+                ]
+              }
             },
             "span": {
               "start": 23,
@@ -5,7 +5,9 @@
   "stmts": [
     {
       "kind": {
-        "InlineHtml": "<html>no php here</html>"
+        "InlineHtml": {
+          "text": "<html>no php here</html>"
+        }
       },
       "span": {
         "start": 0,
@@ -16,5 +18,6 @@
   "span": {
     "start": 0,
     "end": 24
-  }
+  },
+  "ends_in_html": true
 }
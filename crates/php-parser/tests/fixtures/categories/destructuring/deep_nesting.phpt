@@ -10,130 +10,150 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 9
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
-                                },
-                                "span": {
-                                  "start": 12,
-                                  "end": 14
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 12,
-                                "end": 14
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Array": [
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "c"
-                                        },
-                                        "span": {
-                                          "start": 17,
-                                          "end": 19
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 17,
-                                        "end": 19
-                                      }
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
                                     },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Array": [
-                                            {
-                                              "key": null,
-                                              "value": {
-                                                "kind": {
-                                                  "Variable": "d"
-                                                },
-                                                "span": {
-                                                  "start": 22,
-                                                  "end": 24
+                                    "span": {
+                                      "start": 12,
+                                      "end": 14
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 12,
+                                    "end": 14
+                                  },
+                                  "comma": {
+                                    "start": 14,
+                                    "end": 15
+                                  }
+                                },
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "List": {
+                                        "elements": [
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "c"
+                                              },
+                                              "span": {
+                                                "start": 17,
+                                                "end": 19
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 17,
+                                              "end": 19
+                                            },
+                                            "comma": {
+                                              "start": 19,
+                                              "end": 20
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "List": {
+                                                  "elements": [
+                                                    {
+                                                      "key": null,
+                                                      "value": {
+                                                        "kind": {
+                                                          "Variable": "d"
+                                                        },
+                                                        "span": {
+                                                          "start": 22,
+                                                          "end": 24
+                                                        }
+                                                      },
+                                                      "unpack": false,
+                                                      "span": {
+                                                        "start": 22,
+                                                        "end": 24
+                                                      }
+                                                    }
+                                                  ]
                                                 }
                                               },
-                                              "unpack": false,
                                               "span": {
-                                                "start": 22,
-                                                "end": 24
+                                                "start": 21,
+                                                "end": 25
                                               }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 21,
+                                              "end": 25
                                             }
-                                          ]
-                                        },
-                                        "span": {
-                                          "start": 21,
-                                          "end": 25
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 21,
-                                        "end": 25
+                                          }
+                                        ]
                                       }
+                                    },
+                                    "span": {
+                                      "start": 16,
+                                      "end": 26
                                     }
-                                  ]
-                                },
-                                "span": {
-                                  "start": 16,
-                                  "end": 26
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 16,
+                                    "end": 26
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 16,
-                                "end": 26
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 27
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 27
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 27
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -10,58 +10,70 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "name"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "name",
+                              "raw": "name"
+                            }
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 13
+                          }
                         },
-                        "span": {
-                          "start": 7,
-                          "end": 13
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "name"
+                        "value": {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 17,
+                            "end": 22
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 17,
+                          "start": 7,
                           "end": 22
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 22
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "age"
                         },
-                        "span": {
-                          "start": 24,
-                          "end": 29
+                        "comma": {
+                          "start": 22,
+                          "end": 23
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "age"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "age",
+                              "raw": "age"
+                            }
+                          },
+                          "span": {
+                            "start": 24,
+                            "end": 29
+                          }
                         },
+                        "value": {
+                          "kind": {
+                            "Variable": "age"
+                          },
+                          "span": {
+                            "start": 33,
+                            "end": 37
+                          }
+                        },
+                        "unpack": false,
                         "span": {
-                          "start": 33,
+                          "start": 24,
                           "end": 37
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 24,
-                        "end": 37
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
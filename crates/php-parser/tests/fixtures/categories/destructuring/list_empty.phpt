@@ -2,7 +2,6 @@
 <?php list() = $arr;
 ===errors===
 Cannot use empty list
-Cannot use empty list
 ===ast===
 {
   "stmts": [
@@ -13,7 +12,10 @@ Cannot use empty list
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": []
+                  "List": {
+                    "elements": [],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -12,25 +12,31 @@ Cannot use empty list
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": []
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [],
+                              "uses_list": true
+                            }
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 17
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 17
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 17
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -10,77 +10,91 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 20,
+                                      "end": 22
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 20,
+                                    "end": 22
+                                  },
+                                  "comma": {
+                                    "start": 22,
+                                    "end": 23
+                                  }
                                 },
-                                "span": {
-                                  "start": 20,
-                                  "end": 22
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 24,
+                                      "end": 26
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 24,
+                                    "end": 26
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 20,
-                                "end": 22
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
-                                },
-                                "span": {
-                                  "start": 24,
-                                  "end": 26
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 24,
-                                "end": 26
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 15,
+                            "end": 27
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 15,
                           "end": 27
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 15,
-                        "end": 27
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
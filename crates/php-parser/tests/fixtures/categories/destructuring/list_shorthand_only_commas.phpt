@@ -12,53 +12,67 @@ Cannot use empty list
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 7,
+                            "end": 7
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 7,
+                          "end": 7
+                        },
+                        "comma": {
                           "start": 7,
                           "end": 8
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 8
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 9,
+                            "end": 9
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 9,
+                          "end": 9
+                        },
+                        "comma": {
                           "start": 9,
                           "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 9,
-                        "end": 10
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 11,
+                            "end": 11
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 11,
+                          "end": 11
+                        },
+                        "comma": {
                           "start": 11,
                           "end": 12
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 12
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
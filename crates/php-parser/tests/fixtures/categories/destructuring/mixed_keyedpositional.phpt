@@ -10,58 +10,67 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "Int": 0
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "Int": 0
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 8
+                          }
                         },
-                        "span": {
-                          "start": 7,
-                          "end": 8
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 12,
+                            "end": 18
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 12,
+                          "start": 7,
                           "end": 18
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 18
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "key"
                         },
-                        "span": {
-                          "start": 20,
-                          "end": 25
+                        "comma": {
+                          "start": 18,
+                          "end": 19
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "val"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "key",
+                              "raw": "key"
+                            }
+                          },
+                          "span": {
+                            "start": 20,
+                            "end": 25
+                          }
                         },
+                        "value": {
+                          "kind": {
+                            "Variable": "val"
+                          },
+                          "span": {
+                            "start": 29,
+                            "end": 33
+                          }
+                        },
+                        "unpack": false,
                         "span": {
-                          "start": 29,
+                          "start": 20,
                           "end": 33
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 20,
-                        "end": 33
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -10,112 +10,130 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 8,
+                                      "end": 10
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 8,
+                                    "end": 10
+                                  },
+                                  "comma": {
+                                    "start": 10,
+                                    "end": 11
+                                  }
                                 },
-                                "span": {
-                                  "start": 8,
-                                  "end": 10
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 12,
+                                      "end": 14
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 12,
+                                    "end": 14
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 8,
-                                "end": 10
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
-                                },
-                                "span": {
-                                  "start": 12,
-                                  "end": 14
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 12,
-                                "end": 14
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 15
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 15
+                        },
+                        "comma": {
+                          "start": 15,
+                          "end": 16
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 15
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 18,
+                                      "end": 20
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 18,
+                                    "end": 20
+                                  },
+                                  "comma": {
+                                    "start": 20,
+                                    "end": 21
+                                  }
                                 },
-                                "span": {
-                                  "start": 18,
-                                  "end": 20
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "d"
+                                    },
+                                    "span": {
+                                      "start": 22,
+                                      "end": 24
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 22,
+                                    "end": 24
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 18,
-                                "end": 20
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "d"
-                                },
-                                "span": {
-                                  "start": 22,
-                                  "end": 24
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 22,
-                                "end": 24
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 17,
+                            "end": 25
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 17,
                           "end": 25
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 17,
-                        "end": 25
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -32,7 +32,10 @@
                     "op": "Concat",
                     "right": {
                       "kind": {
-                        "String": "Name"
+                        "String": {
+                          "value": "Name",
+                          "raw": "Name"
+                        }
                       },
                       "span": {
                         "start": 23,
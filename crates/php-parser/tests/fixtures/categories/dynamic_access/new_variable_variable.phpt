@@ -24,7 +24,10 @@ $obj2 = new ${$key}();
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Foo"
+                  "String": {
+                    "value": "Foo",
+                    "raw": "Foo"
+                  }
                 },
                 "span": {
                   "start": 15,
@@ -63,20 +66,22 @@ $obj2 = new ${$key}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "VariableVariable": {
-                          "kind": {
-                            "Variable": "class"
-                          },
-                          "span": {
-                            "start": 35,
-                            "end": 41
+                      "Expr": {
+                        "kind": {
+                          "VariableVariable": {
+                            "kind": {
+                              "Variable": "class"
+                            },
+                            "span": {
+                              "start": 35,
+                              "end": 41
+                            }
                           }
+                        },
+                        "span": {
+                          "start": 34,
+                          "end": 41
                         }
-                      },
-                      "span": {
-                        "start": 34,
-                        "end": 41
                       }
                     },
                     "args": []
@@ -117,7 +122,10 @@ $obj2 = new ${$key}();
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "class"
+                  "String": {
+                    "value": "class",
+                    "raw": "class"
+                  }
                 },
                 "span": {
                   "start": 52,
@@ -156,20 +164,22 @@ $obj2 = new ${$key}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "VariableVariable": {
-                          "kind": {
-                            "Variable": "key"
-                          },
-                          "span": {
-                            "start": 75,
-                            "end": 79
+                      "Expr": {
+                        "kind": {
+                          "VariableVariable": {
+                            "kind": {
+                              "Variable": "key"
+                            },
+                            "span": {
+                              "start": 75,
+                              "end": 79
+                            }
                           }
+                        },
+                        "span": {
+                          "start": 73,
+                          "end": 80
                         }
-                      },
-                      "span": {
-                        "start": 73,
-                        "end": 80
                       }
                     },
                     "args": []
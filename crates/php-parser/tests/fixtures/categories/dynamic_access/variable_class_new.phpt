@@ -9,12 +9,14 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Variable": "className"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 20
+                "Expr": {
+                  "kind": {
+                    "Variable": "className"
+                  },
+                  "span": {
+                    "start": 10,
+                    "end": 20
+                  }
                 }
               },
               "args": []
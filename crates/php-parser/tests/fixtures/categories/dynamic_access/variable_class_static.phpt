@@ -9,12 +9,14 @@
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Variable": "class"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 12
+                "Expr": {
+                  "kind": {
+                    "Variable": "class"
+                  },
+                  "span": {
+                    "start": 6,
+                    "end": 12
+                  }
                 }
               },
               "member": {
@@ -59,6 +59,10 @@ min_php=8.1
                           "span": {
                             "start": 51,
                             "end": 52
+                          },
+                          "comma": {
+                            "start": 52,
+                            "end": 53
                           }
                         },
                         {
@@ -76,6 +80,10 @@ min_php=8.1
                           "span": {
                             "start": 54,
                             "end": 55
+                          },
+                          "comma": {
+                            "start": 55,
+                            "end": 56
                           }
                         },
                         {
@@ -122,7 +130,10 @@ min_php=8.1
                         {
                           "key": {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 76,
@@ -142,12 +153,19 @@ min_php=8.1
                           "span": {
                             "start": 76,
                             "end": 84
+                          },
+                          "comma": {
+                            "start": 84,
+                            "end": 85
                           }
                         },
                         {
                           "key": {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 86,
@@ -27,7 +27,10 @@ min_php=8.1
                   "name": "Active",
                   "value": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 42,
@@ -52,10 +55,7 @@ min_php=8.1
                     "kind": {
                       "ClassConstAccess": {
                         "class": {
-                          "kind": {
-                            "Identifier": "self"
-                          },
-                          "span": {
+                          "SelfKw": {
                             "start": 63,
                             "end": 67
                           }
@@ -61,7 +61,10 @@ min_php=8.1
                           "name": null,
                           "value": {
                             "kind": {
-                              "String": "value"
+                              "String": {
+                                "value": "value",
+                                "raw": "value"
+                              }
                             },
                             "span": {
                               "start": 37,
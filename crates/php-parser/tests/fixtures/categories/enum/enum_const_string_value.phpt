@@ -20,7 +20,10 @@ min_php=8.1
                   "is_final": false,
                   "value": {
                     "kind": {
-                      "String": "status_"
+                      "String": {
+                        "value": "status_",
+                        "raw": "status_"
+                      }
                     },
                     "span": {
                       "start": 35,
@@ -38,7 +38,10 @@ min_php=8.3
                   },
                   "value": {
                     "kind": {
-                      "String": "fit"
+                      "String": {
+                        "value": "fit",
+                        "raw": "fit"
+                      }
                     },
                     "span": {
                       "start": 47,
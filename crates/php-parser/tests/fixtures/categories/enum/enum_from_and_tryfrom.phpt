@@ -9,12 +9,15 @@
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Status"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 12
+                "Name": {
+                  "parts": [
+                    "Status"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 12
+                  }
                 }
               },
               "method": {
@@ -65,12 +68,15 @@
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Status"
-                },
-                "span": {
-                  "start": 23,
-                  "end": 29
+                "Name": {
+                  "parts": [
+                    "Status"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 23,
+                    "end": 29
+                  }
                 }
               },
               "method": {
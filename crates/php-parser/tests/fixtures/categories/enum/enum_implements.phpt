@@ -69,7 +69,10 @@ min_php=8.1
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": "red"
+                            "String": {
+                              "value": "red",
+                              "raw": "red"
+                            }
                           },
                           "span": {
                             "start": 90,
@@ -38,7 +38,10 @@ min_php=8.1
                   "name": "Hearts",
                   "value": {
                     "kind": {
-                      "String": "H"
+                      "String": {
+                        "value": "H",
+                        "raw": "H"
+                      }
                     },
                     "span": {
                       "start": 63,
@@ -60,10 +60,7 @@ min_php=8.1
                           "kind": {
                             "ClassConstAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "self"
-                                },
-                                "span": {
+                                "SelfKw": {
                                   "start": 77,
                                   "end": 81
                                 }
@@ -27,7 +27,10 @@ min_php=8.1
                   "name": "Red",
                   "value": {
                     "kind": {
-                      "String": "red"
+                      "String": {
+                        "value": "red",
+                        "raw": "red"
+                      }
                     },
                     "span": {
                       "start": 38,
@@ -48,7 +51,10 @@ min_php=8.1
                   "name": "Blue",
                   "value": {
                     "kind": {
-                      "String": "blue"
+                      "String": {
+                        "value": "blue",
+                        "raw": "blue"
+                      }
                     },
                     "span": {
                       "start": 57,
@@ -106,12 +112,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Color"
-                                  },
-                                  "span": {
-                                    "start": 84,
-                                    "end": 89
+                                  "Name": {
+                                    "parts": [
+                                      "Color"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 84,
+                                      "end": 89
+                                    }
                                   }
                                 },
                                 "member": {
@@ -143,6 +152,10 @@ min_php=8.1
                         "span": {
                           "start": 84,
                           "end": 99
+                        },
+                        "comma": {
+                          "start": 99,
+                          "end": 100
                         }
                       },
                       {
@@ -151,12 +164,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Color"
-                                  },
-                                  "span": {
-                                    "start": 101,
-                                    "end": 106
+                                  "Name": {
+                                    "parts": [
+                                      "Color"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 101,
+                                      "end": 106
+                                    }
                                   }
                                 },
                                 "member": {
@@ -188,6 +204,10 @@ min_php=8.1
                         "span": {
                           "start": 101,
                           "end": 117
+                        },
+                        "comma": {
+                          "start": 117,
+                          "end": 118
                         }
                       },
                       {
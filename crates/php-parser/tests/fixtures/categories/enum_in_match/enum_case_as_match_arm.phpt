@@ -81,12 +81,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Status"
-                                  },
-                                  "span": {
-                                    "start": 67,
-                                    "end": 73
+                                  "Name": {
+                                    "parts": [
+                                      "Status"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 67,
+                                      "end": 73
+                                    }
                                   }
                                 },
                                 "member": {
@@ -108,7 +111,10 @@ min_php=8.1
                         ],
                         "body": {
                           "kind": {
-                            "String": "on"
+                            "String": {
+                              "value": "on",
+                              "raw": "on"
+                            }
                           },
                           "span": {
                             "start": 85,
@@ -118,6 +124,10 @@ min_php=8.1
                         "span": {
                           "start": 67,
                           "end": 89
+                        },
+                        "comma": {
+                          "start": 89,
+                          "end": 90
                         }
                       },
                       {
@@ -126,12 +136,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Status"
-                                  },
-                                  "span": {
-                                    "start": 91,
-                                    "end": 97
+                                  "Name": {
+                                    "parts": [
+                                      "Status"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 91,
+                                      "end": 97
+                                    }
                                   }
                                 },
                                 "member": {
@@ -153,7 +166,10 @@ min_php=8.1
                         ],
                         "body": {
                           "kind": {
-                            "String": "off"
+                            "String": {
+                              "value": "off",
+                              "raw": "off"
+                            }
                           },
                           "span": {
                             "start": 111,
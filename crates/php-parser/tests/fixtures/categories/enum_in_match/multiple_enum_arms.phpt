@@ -39,12 +39,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Status"
-                                  },
-                                  "span": {
-                                    "start": 28,
-                                    "end": 34
+                                  "Name": {
+                                    "parts": [
+                                      "Status"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 28,
+                                      "end": 34
+                                    }
                                   }
                                 },
                                 "member": {
@@ -67,12 +70,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Status"
-                                  },
-                                  "span": {
-                                    "start": 44,
-                                    "end": 50
+                                  "Name": {
+                                    "parts": [
+                                      "Status"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 44,
+                                      "end": 50
+                                    }
                                   }
                                 },
                                 "member": {
@@ -94,7 +100,10 @@ min_php=8.1
                         ],
                         "body": {
                           "kind": {
-                            "String": "live"
+                            "String": {
+                              "value": "live",
+                              "raw": "live"
+                            }
                           },
                           "span": {
                             "start": 63,
@@ -104,6 +113,10 @@ min_php=8.1
                         "span": {
                           "start": 28,
                           "end": 69
+                        },
+                        "comma": {
+                          "start": 69,
+                          "end": 70
                         }
                       },
                       {
@@ -112,12 +125,15 @@ min_php=8.1
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "Status"
-                                  },
-                                  "span": {
-                                    "start": 71,
-                                    "end": 77
+                                  "Name": {
+                                    "parts": [
+                                      "Status"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 71,
+                                      "end": 77
+                                    }
                                   }
                                 },
                                 "member": {
@@ -139,7 +155,10 @@ min_php=8.1
                         ],
                         "body": {
                           "kind": {
-                            "String": "off"
+                            "String": {
+                              "value": "off",
+                              "raw": "off"
+                            }
                           },
                           "span": {
                             "start": 91,
@@ -8,31 +8,37 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "ArrayAccess": {
-                  "array": {
-                    "kind": {
-                      "Variable": "arr"
+              "expr": {
+                "kind": {
+                  "ArrayAccess": {
+                    "array": {
+                      "kind": {
+                        "Variable": "arr"
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 11
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 11
-                    }
-                  },
-                  "index": {
-                    "kind": {
-                      "Variable": "key"
-                    },
-                    "span": {
-                      "start": 12,
-                      "end": 16
+                    "index": {
+                      "kind": {
+                        "Variable": "key"
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 16
+                      }
                     }
                   }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 17
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 17
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
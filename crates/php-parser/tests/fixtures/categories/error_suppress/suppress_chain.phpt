@@ -8,51 +8,57 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "PropertyAccess": {
-                  "object": {
-                    "kind": {
-                      "MethodCall": {
-                        "object": {
-                          "kind": {
-                            "Variable": "a"
+              "expr": {
+                "kind": {
+                  "PropertyAccess": {
+                    "object": {
+                      "kind": {
+                        "MethodCall": {
+                          "object": {
+                            "kind": {
+                              "Variable": "a"
+                            },
+                            "span": {
+                              "start": 7,
+                              "end": 9
+                            }
                           },
-                          "span": {
-                            "start": 7,
-                            "end": 9
-                          }
-                        },
-                        "method": {
-                          "kind": {
-                            "Identifier": "b"
+                          "method": {
+                            "kind": {
+                              "Identifier": "b"
+                            },
+                            "span": {
+                              "start": 11,
+                              "end": 12
+                            }
                           },
-                          "span": {
-                            "start": 11,
-                            "end": 12
-                          }
-                        },
-                        "args": []
+                          "args": []
+                        }
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 14
                       }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 14
-                    }
-                  },
-                  "property": {
-                    "kind": {
-                      "Identifier": "c"
-                    },
-                    "span": {
-                      "start": 16,
-                      "end": 17
+                    "property": {
+                      "kind": {
+                        "Identifier": "c"
+                      },
+                      "span": {
+                        "start": 16,
+                        "end": 17
+                      }
                     }
                   }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 17
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 17
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
@@ -8,23 +8,32 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "Include": [
-                  "Include",
-                  {
-                    "kind": {
-                      "String": "optional.php"
-                    },
-                    "span": {
-                      "start": 15,
-                      "end": 29
+              "expr": {
+                "kind": {
+                  "Include": [
+                    "Include",
+                    {
+                      "kind": {
+                        "String": {
+                          "value": "optional.php",
+                          "raw": "optional.php"
+                        }
+                      },
+                      "span": {
+                        "start": 15,
+                        "end": 29
+                      }
                     }
-                  }
-                ]
+                  ]
+                },
+                "span": {
+                  "start": 7,
+                  "end": 29
+                }
               },
-              "span": {
-                "start": 7,
-                "end": 29
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
@@ -8,51 +8,60 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "MethodCall": {
-                  "object": {
-                    "kind": {
-                      "Parenthesized": {
-                        "kind": {
-                          "New": {
-                            "class": {
-                              "kind": {
-                                "Identifier": "Foo"
+              "expr": {
+                "kind": {
+                  "MethodCall": {
+                    "object": {
+                      "kind": {
+                        "Parenthesized": {
+                          "kind": {
+                            "New": {
+                              "class": {
+                                "Name": {
+                                  "parts": [
+                                    "Foo"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 12,
+                                    "end": 15
+                                  }
+                                }
                               },
-                              "span": {
-                                "start": 12,
-                                "end": 15
-                              }
-                            },
-                            "args": []
+                              "args": []
+                            }
+                          },
+                          "span": {
+                            "start": 8,
+                            "end": 17
                           }
-                        },
-                        "span": {
-                          "start": 8,
-                          "end": 17
                         }
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 18
                       }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 18
-                    }
-                  },
-                  "method": {
-                    "kind": {
-                      "Identifier": "init"
+                    "method": {
+                      "kind": {
+                        "Identifier": "init"
+                      },
+                      "span": {
+                        "start": 20,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 20,
-                      "end": 24
-                    }
-                  },
-                  "args": []
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 26
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 26
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
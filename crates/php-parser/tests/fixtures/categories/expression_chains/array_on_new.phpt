@@ -14,12 +14,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Collection"
-                          },
-                          "span": {
-                            "start": 11,
-                            "end": 21
+                          "Name": {
+                            "parts": [
+                              "Collection"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 11,
+                              "end": 21
+                            }
                           }
                         },
                         "args": [
@@ -43,6 +46,10 @@
                                     "span": {
                                       "start": 23,
                                       "end": 24
+                                    },
+                                    "comma": {
+                                      "start": 24,
+                                      "end": 25
                                     }
                                   },
                                   {
@@ -60,6 +67,10 @@
                                     "span": {
                                       "start": 25,
                                       "end": 26
+                                    },
+                                    "comma": {
+                                      "start": 26,
+                                      "end": 27
                                     }
                                   },
                                   {
@@ -39,7 +39,16 @@ foo()->bar(1);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 45,
+                      "end": 48
+                    }
+                  }
                 },
                 "span": {
                   "start": 45,
@@ -86,7 +95,16 @@ foo()->bar(1);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 99,
+                      "end": 102
+                    }
+                  }
                 },
                 "span": {
                   "start": 99,
@@ -110,6 +128,10 @@ foo()->bar(1);
                   "span": {
                     "start": 104,
                     "end": 105
+                  },
+                  "comma": {
+                    "start": 106,
+                    "end": 107
                   }
                 },
                 {
@@ -150,12 +172,15 @@ foo()->bar(1);
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Math"
-                },
-                "span": {
-                  "start": 152,
-                  "end": 156
+                "Name": {
+                  "parts": [
+                    "Math"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 152,
+                    "end": 156
+                  }
                 }
               },
               "method": {
@@ -206,12 +231,15 @@ foo()->bar(1);
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Math"
-                },
-                "span": {
-                  "start": 212,
-                  "end": 216
+                "Name": {
+                  "parts": [
+                    "Math"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 212,
+                    "end": 216
+                  }
                 }
               },
               "method": {
@@ -506,7 +534,16 @@ foo()->bar(1);
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 510,
+                            "end": 513
+                          }
+                        }
                       },
                       "span": {
                         "start": 510,
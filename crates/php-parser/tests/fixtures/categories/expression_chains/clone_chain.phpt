@@ -8,32 +8,38 @@
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "MethodCall": {
-                  "object": {
-                    "kind": {
-                      "Variable": "obj"
+              "object": {
+                "kind": {
+                  "MethodCall": {
+                    "object": {
+                      "kind": {
+                        "Variable": "obj"
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 16
+                      }
                     },
-                    "span": {
-                      "start": 12,
-                      "end": 16
-                    }
-                  },
-                  "method": {
-                    "kind": {
-                      "Identifier": "getPrototype"
+                    "method": {
+                      "kind": {
+                        "Identifier": "getPrototype"
+                      },
+                      "span": {
+                        "start": 18,
+                        "end": 30
+                      }
                     },
-                    "span": {
-                      "start": 18,
-                      "end": 30
-                    }
-                  },
-                  "args": []
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 12,
+                  "end": 32
                 }
               },
-              "span": {
-                "start": 12,
-                "end": 32
+              "keyword_span": {
+                "start": 6,
+                "end": 11
               }
             }
           },
@@ -15,36 +15,41 @@ $arr = [Enum::class, Enum::FOO];
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "ClassConstAccess": {
-                "class": {
-                  "kind": {
-                    "Identifier": "Enum"
-                  },
-                  "span": {
-                    "start": 11,
-                    "end": 15
-                  }
-                },
-                "member": {
-                  "kind": {
-                    "Identifier": "class"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "ClassConstAccess": {
+                  "class": {
+                    "Name": {
+                      "parts": [
+                        "Enum"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 11,
+                        "end": 15
+                      }
+                    }
                   },
-                  "span": {
-                    "start": 17,
-                    "end": 22
+                  "member": {
+                    "kind": {
+                      "Identifier": "class"
+                    },
+                    "span": {
+                      "start": 17,
+                      "end": 22
+                    }
                   }
                 }
+              },
+              "span": {
+                "start": 11,
+                "end": 22
               }
-            },
-            "span": {
-              "start": 11,
-              "end": 22
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -70,12 +75,15 @@ $arr = [Enum::class, Enum::FOO];
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Enum"
-                      },
-                      "span": {
-                        "start": 29,
-                        "end": 33
+                      "Name": {
+                        "parts": [
+                          "Enum"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 29,
+                          "end": 33
+                        }
                       }
                     },
                     "member": {
@@ -114,7 +122,16 @@ $arr = [Enum::class, Enum::FOO];
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "register"
+                  "Name": {
+                    "parts": [
+                      "register"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 42,
+                      "end": 50
+                    }
+                  }
                 },
                 "span": {
                   "start": 42,
@@ -128,12 +145,15 @@ $arr = [Enum::class, Enum::FOO];
                     "kind": {
                       "ClassConstAccess": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Enum"
-                          },
-                          "span": {
-                            "start": 51,
-                            "end": 55
+                          "Name": {
+                            "parts": [
+                              "Enum"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 51,
+                              "end": 55
+                            }
                           }
                         },
                         "member": {
@@ -192,12 +212,15 @@ $arr = [Enum::class, Enum::FOO];
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Enum"
-                      },
-                      "span": {
-                        "start": 74,
-                        "end": 78
+                      "Name": {
+                        "parts": [
+                          "Enum"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 74,
+                          "end": 78
+                        }
                       }
                     },
                     "args": []
@@ -227,12 +250,15 @@ $arr = [Enum::class, Enum::FOO];
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Enum"
-                },
-                "span": {
-                  "start": 82,
-                  "end": 86
+                "Name": {
+                  "parts": [
+                    "Enum"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 82,
+                    "end": 86
+                  }
                 }
               },
               "method": {
@@ -277,12 +303,15 @@ $arr = [Enum::class, Enum::FOO];
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "\\Enum"
-                      },
-                      "span": {
-                        "start": 103,
-                        "end": 108
+                      "Name": {
+                        "parts": [
+                          "Enum"
+                        ],
+                        "kind": "FullyQualified",
+                        "span": {
+                          "start": 103,
+                          "end": 108
+                        }
                       }
                     },
                     "member": {
@@ -333,12 +362,17 @@ $arr = [Enum::class, Enum::FOO];
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "App\\Models\\Enum"
-                      },
-                      "span": {
-                        "start": 122,
-                        "end": 137
+                      "Name": {
+                        "parts": [
+                          "App",
+                          "Models",
+                          "Enum"
+                        ],
+                        "kind": "Qualified",
+                        "span": {
+                          "start": 122,
+                          "end": 137
+                        }
                       }
                     },
                     "member": {
@@ -390,7 +424,16 @@ $arr = [Enum::class, Enum::FOO];
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "enum"
+                        "Name": {
+                          "parts": [
+                            "enum"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 151,
+                            "end": 155
+                          }
+                        }
                       },
                       "span": {
                         "start": 151,
@@ -435,8 +478,8 @@ $arr = [Enum::class, Enum::FOO];
               "op": "Assign",
               "value": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "obj"
                       },
@@ -445,14 +488,16 @@ $arr = [Enum::class, Enum::FOO];
                         "end": 168
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Enum"
-                      },
-                      "span": {
-                        "start": 180,
-                        "end": 184
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Enum"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 180,
+                          "end": 184
+                        }
                       }
                     }
                   }
@@ -499,12 +544,15 @@ $arr = [Enum::class, Enum::FOO];
                         "kind": {
                           "ClassConstAccess": {
                             "class": {
-                              "kind": {
-                                "Identifier": "Enum"
-                              },
-                              "span": {
-                                "start": 194,
-                                "end": 198
+                              "Name": {
+                                "parts": [
+                                  "Enum"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 194,
+                                  "end": 198
+                                }
                               }
                             },
                             "member": {
@@ -527,6 +575,10 @@ $arr = [Enum::class, Enum::FOO];
                       "span": {
                         "start": 194,
                         "end": 205
+                      },
+                      "comma": {
+                        "start": 205,
+                        "end": 206
                       }
                     },
                     {
@@ -535,12 +587,15 @@ $arr = [Enum::class, Enum::FOO];
                         "kind": {
                           "ClassConstAccess": {
                             "class": {
-                              "kind": {
-                                "Identifier": "Enum"
-                              },
-                              "span": {
-                                "start": 207,
-                                "end": 211
+                              "Name": {
+                                "parts": [
+                                  "Enum"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 207,
+                                  "end": 211
+                                }
                               }
                             },
                             "member": {
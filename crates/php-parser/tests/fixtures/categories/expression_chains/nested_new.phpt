@@ -9,12 +9,15 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 13
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 13
+                  }
                 }
               },
               "args": [
@@ -24,12 +27,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Bar"
-                          },
-                          "span": {
-                            "start": 18,
-                            "end": 21
+                          "Name": {
+                            "parts": [
+                              "Bar"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 18,
+                              "end": 21
+                            }
                           }
                         },
                         "args": []
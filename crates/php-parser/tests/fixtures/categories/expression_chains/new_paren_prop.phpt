@@ -14,12 +14,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 11,
-                            "end": 14
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 11,
+                              "end": 14
+                            }
                           }
                         },
                         "args": []
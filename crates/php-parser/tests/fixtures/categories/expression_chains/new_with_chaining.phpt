@@ -17,12 +17,15 @@
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "Foo"
-                                },
-                                "span": {
-                                  "start": 11,
-                                  "end": 14
+                                "Name": {
+                                  "parts": [
+                                    "Foo"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 11,
+                                    "end": 14
+                                  }
                                 }
                               },
                               "args": [
@@ -42,6 +45,10 @@
                                   "span": {
                                     "start": 15,
                                     "end": 16
+                                  },
+                                  "comma": {
+                                    "start": 16,
+                                    "end": 17
                                   }
                                 },
                                 {
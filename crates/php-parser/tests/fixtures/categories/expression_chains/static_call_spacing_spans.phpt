@@ -18,7 +18,16 @@ $obj?->prop;
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 74,
+                      "end": 77
+                    }
+                  }
                 },
                 "span": {
                   "start": 74,
@@ -42,6 +51,10 @@ $obj?->prop;
                   "span": {
                     "start": 78,
                     "end": 79
+                  },
+                  "comma": {
+                    "start": 79,
+                    "end": 80
                   }
                 },
                 {
@@ -82,12 +95,15 @@ $obj?->prop;
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Math"
-                },
-                "span": {
-                  "start": 125,
-                  "end": 129
+                "Name": {
+                  "parts": [
+                    "Math"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 125,
+                    "end": 129
+                  }
                 }
               },
               "method": {
@@ -24,12 +24,15 @@ min_php=8.1
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Fiber"
-                      },
-                      "span": {
-                        "start": 19,
-                        "end": 24
+                      "Name": {
+                        "parts": [
+                          "Fiber"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 19,
+                          "end": 24
+                        }
                       }
                     },
                     "args": [
@@ -67,12 +70,15 @@ min_php=8.1
                                       "kind": {
                                         "StaticMethodCall": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Fiber"
-                                            },
-                                            "span": {
-                                              "start": 44,
-                                              "end": 49
+                                            "Name": {
+                                              "parts": [
+                                                "Fiber"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 44,
+                                                "end": 49
+                                              }
                                             }
                                           },
                                           "method": {
@@ -89,7 +95,10 @@ min_php=8.1
                                               "name": null,
                                               "value": {
                                                 "kind": {
-                                                  "String": "hello"
+                                                  "String": {
+                                                    "value": "hello",
+                                                    "raw": "hello"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 59,
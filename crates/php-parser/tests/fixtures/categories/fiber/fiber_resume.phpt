@@ -33,7 +33,10 @@ min_php=8.1
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "world"
+                      "String": {
+                        "value": "world",
+                        "raw": "world"
+                      }
                     },
                     "span": {
                       "start": 21,
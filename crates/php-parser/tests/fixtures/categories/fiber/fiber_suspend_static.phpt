@@ -24,12 +24,15 @@ min_php=8.1
                 "kind": {
                   "StaticMethodCall": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Fiber"
-                      },
-                      "span": {
-                        "start": 13,
-                        "end": 18
+                      "Name": {
+                        "parts": [
+                          "Fiber"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 13,
+                          "end": 18
+                        }
                       }
                     },
                     "method": {
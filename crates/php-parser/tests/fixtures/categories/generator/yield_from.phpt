@@ -33,6 +33,10 @@
                               "span": {
                                 "start": 35,
                                 "end": 36
+                              },
+                              "comma": {
+                                "start": 36,
+                                "end": 37
                               }
                             },
                             {
@@ -50,6 +54,10 @@
                               "span": {
                                 "start": 38,
                                 "end": 39
+                              },
+                              "comma": {
+                                "start": 39,
+                                "end": 40
                               }
                             },
                             {
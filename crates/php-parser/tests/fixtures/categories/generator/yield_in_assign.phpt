@@ -29,7 +29,10 @@
                           "Yield": {
                             "key": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 36,
@@ -38,7 +41,10 @@
                             },
                             "value": {
                               "kind": {
-                                "String": "value"
+                                "String": {
+                                  "value": "value",
+                                  "raw": "value"
+                                }
                               },
                               "span": {
                                 "start": 45,
@@ -16,7 +16,10 @@
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "a"
+                          "String": {
+                            "value": "a",
+                            "raw": "a"
+                          }
                         },
                         "span": {
                           "start": 29,
@@ -53,7 +56,10 @@
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 45,
@@ -33,9 +33,16 @@ bar(<<<X
                         "kind": {
                           "Heredoc": {
                             "label": "X",
+                            "raw": "\tbody",
                             "parts": [
                               {
-                                "Literal": "body"
+                                "Literal": {
+                                  "value": "body",
+                                  "span": {
+                                    "start": 17,
+                                    "end": 22
+                                  }
+                                }
                               }
                             ]
                           }
@@ -49,13 +56,20 @@ bar(<<<X
                       "span": {
                         "start": 12,
                         "end": 25
+                      },
+                      "comma": {
+                        "start": 25,
+                        "end": 26
                       }
                     },
                     {
                       "key": null,
                       "value": {
                         "kind": {
-                          "String": "other"
+                          "String": {
+                            "value": "other",
+                            "raw": "other"
+                          }
                         },
                         "span": {
                           "start": 27,
@@ -95,7 +109,16 @@ bar(<<<X
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "bar"
+                  "Name": {
+                    "parts": [
+                      "bar"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 37,
+                      "end": 40
+                    }
+                  }
                 },
                 "span": {
                   "start": 37,
@@ -112,9 +135,16 @@ bar(<<<X
                           "kind": {
                             "Heredoc": {
                               "label": "X",
+                              "raw": "\tbody",
                               "parts": [
                                 {
-                                  "Literal": "body"
+                                  "Literal": {
+                                    "value": "body",
+                                    "span": {
+                                      "start": 46,
+                                      "end": 51
+                                    }
+                                  }
                                 }
                               ]
                             }
@@ -127,7 +157,10 @@ bar(<<<X
                         "op": "Concat",
                         "right": {
                           "kind": {
-                            "String": "suffix"
+                            "String": {
+                              "value": "suffix",
+                              "raw": "suffix"
+                            }
                           },
                           "span": {
                             "start": 57,
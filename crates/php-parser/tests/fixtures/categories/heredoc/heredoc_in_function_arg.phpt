@@ -13,7 +13,16 @@ EOT
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -27,9 +36,16 @@ EOT
                     "kind": {
                       "Heredoc": {
                         "label": "EOT",
+                        "raw": "hello",
                         "parts": [
                           {
-                            "Literal": "hello"
+                            "Literal": {
+                              "value": "hello",
+                              "span": {
+                                "start": 17,
+                                "end": 22
+                              }
+                            }
                           }
                         ]
                       }
@@ -24,9 +24,16 @@
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "    hello",
                     "parts": [
                       {
-                        "Literal": "hello"
+                        "Literal": {
+                          "value": "hello",
+                          "span": {
+                            "start": 18,
+                            "end": 27
+                          }
+                        }
                       }
                     ]
                   }
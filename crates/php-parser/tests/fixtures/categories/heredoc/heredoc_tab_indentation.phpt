@@ -0,0 +1,86 @@
+===source===
+<?php
+$x = <<<END
+		line $a
+		  more
+		END;
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "END",
+                    "raw": "\t\tline $a\n\t\t  more",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "line ",
+                          "span": {
+                            "start": 20,
+                            "end": 25
+                          }
+                        }
+                      },
+                      {
+                        "Expr": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 27
+                          }
+                        }
+                      },
+                      {
+                        "Literal": {
+                          "value": "\n  more",
+                          "span": {
+                            "start": 27,
+                            "end": 36
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 42
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 42
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 43
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 43
+  }
+}
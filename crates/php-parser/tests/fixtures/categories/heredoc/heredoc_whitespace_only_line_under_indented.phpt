@@ -0,0 +1,113 @@
+===source===
+<?php
+$x = <<<END
+    first
+  
+    second
+    END;
+$y = <<<'END'
+    first
+
+      nested
+    END;
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "END",
+                    "raw": "    first\n  \n    second",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "first\n\nsecond",
+                          "span": {
+                            "start": 18,
+                            "end": 41
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 49
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 49
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 50
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "y"
+                },
+                "span": {
+                  "start": 51,
+                  "end": 53
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Nowdoc": {
+                    "label": "END",
+                    "raw": "    first\n\n      nested",
+                    "value": "first\n\n  nested"
+                  }
+                },
+                "span": {
+                  "start": 56,
+                  "end": 96
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 51,
+            "end": 96
+          }
+        }
+      },
+      "span": {
+        "start": 51,
+        "end": 97
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 97
+  }
+}
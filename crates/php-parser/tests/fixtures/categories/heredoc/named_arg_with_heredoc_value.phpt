@@ -13,7 +13,16 @@ EOT
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -32,13 +41,24 @@ EOT
                       "end": 13
                     }
                   },
+                  "colon": {
+                    "start": 13,
+                    "end": 14
+                  },
                   "value": {
                     "kind": {
                       "Heredoc": {
                         "label": "EOT",
+                        "raw": "hello",
                         "parts": [
                           {
-                            "Literal": "hello"
+                            "Literal": {
+                              "value": "hello",
+                              "span": {
+                                "start": 22,
+                                "end": 27
+                              }
+                            }
                           }
                         ]
                       }
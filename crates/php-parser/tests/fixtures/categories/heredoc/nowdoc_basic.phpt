@@ -24,6 +24,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "hello world",
                     "value": "hello world"
                   }
                 },
@@ -24,7 +24,10 @@ $str = "{$x["key\è"]}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "test\\è"
+                  "String": {
+                    "value": "test\\è",
+                    "raw": "test\\è"
+                  }
                 },
                 "span": {
                   "start": 13,
@@ -77,7 +80,10 @@ $str = "{$x["key\è"]}";
                             },
                             "index": {
                               "kind": {
-                                "String": "key\\è"
+                                "String": {
+                                  "value": "key\\è",
+                                  "raw": "key\\è"
+                                }
                               },
                               "span": {
                                 "start": 36,
@@ -29,7 +29,10 @@ $d = "\\è";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "é\\è"
+                  "String": {
+                    "value": "é\\è",
+                    "raw": "é\\è"
+                  }
                 },
                 "span": {
                   "start": 75,
@@ -82,7 +85,10 @@ $d = "\\è";
                             },
                             "index": {
                               "kind": {
-                                "String": "è\\é"
+                                "String": {
+                                  "value": "è\\é",
+                                  "raw": "è\\é"
+                                }
                               },
                               "span": {
                                 "start": 94,
@@ -134,7 +140,10 @@ $d = "\\è";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "test\\è\\é\\ù"
+                  "String": {
+                    "value": "test\\è\\é\\ù",
+                    "raw": "test\\è\\é\\ù"
+                  }
                 },
                 "span": {
                   "start": 154,
@@ -171,7 +180,10 @@ $d = "\\è";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "\\è"
+                  "String": {
+                    "value": "\\è",
+                    "raw": "\\\\è"
+                  }
                 },
                 "span": {
                   "start": 215,
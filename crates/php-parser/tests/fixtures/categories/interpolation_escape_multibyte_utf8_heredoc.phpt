@@ -42,9 +42,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "test\\è",
                     "parts": [
                       {
-                        "Literal": "test\\è"
+                        "Literal": {
+                          "value": "test\\è",
+                          "span": {
+                            "start": 87,
+                            "end": 94
+                          }
+                        }
                       }
                     ]
                   }
@@ -86,9 +93,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "prefix {$x[\"key\\è\"]} suffix",
                     "parts": [
                       {
-                        "Literal": "prefix "
+                        "Literal": {
+                          "value": "prefix ",
+                          "span": {
+                            "start": 113,
+                            "end": 120
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -105,7 +119,10 @@ EOT;
                               },
                               "index": {
                                 "kind": {
-                                  "String": "key\\è"
+                                  "String": {
+                                    "value": "key\\è",
+                                    "raw": "key\\è"
+                                  }
                                 },
                                 "span": {
                                   "start": 124,
@@ -121,7 +138,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": " suffix"
+                        "Literal": {
+                          "value": " suffix",
+                          "span": {
+                            "start": 134,
+                            "end": 141
+                          }
+                        }
                       }
                     ]
                   }
@@ -163,9 +186,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\è\\é\\ù mixed",
                     "parts": [
                       {
-                        "Literal": "\\è\\é\\ù mixed"
+                        "Literal": {
+                          "value": "\\è\\é\\ù mixed",
+                          "span": {
+                            "start": 180,
+                            "end": 195
+                          }
+                        }
                       }
                     ]
                   }
@@ -207,9 +237,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\\\è escaped",
                     "parts": [
                       {
-                        "Literal": "\\è escaped"
+                        "Literal": {
+                          "value": "\\è escaped",
+                          "span": {
+                            "start": 253,
+                            "end": 265
+                          }
+                        }
                       }
                     ]
                   }
@@ -36,9 +36,16 @@ $c = <<<'EOT'
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "    test\\è with indent",
                     "parts": [
                       {
-                        "Literal": "test\\è with indent"
+                        "Literal": {
+                          "value": "test\\è with indent",
+                          "span": {
+                            "start": 18,
+                            "end": 41
+                          }
+                        }
                       }
                     ]
                   }
@@ -80,9 +87,16 @@ $c = <<<'EOT'
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "    multi-line {$x[\"key\\è\"]}\n    content \\é here",
                     "parts": [
                       {
-                        "Literal": "multi-line "
+                        "Literal": {
+                          "value": "multi-line ",
+                          "span": {
+                            "start": 68,
+                            "end": 79
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -99,7 +113,10 @@ $c = <<<'EOT'
                               },
                               "index": {
                                 "kind": {
-                                  "String": "key\\è"
+                                  "String": {
+                                    "value": "key\\è",
+                                    "raw": "key\\è"
+                                  }
                                 },
                                 "span": {
                                   "start": 83,
@@ -115,7 +132,13 @@ $c = <<<'EOT'
                         }
                       },
                       {
-                        "Literal": "\ncontent \\é here"
+                        "Literal": {
+                          "value": "\ncontent \\é here",
+                          "span": {
+                            "start": 93,
+                            "end": 114
+                          }
+                        }
                       }
                     ]
                   }
@@ -157,6 +180,7 @@ $c = <<<'EOT'
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "    indented nowdoc \\è literal",
                     "value": "indented nowdoc \\è literal"
                   }
                 },
@@ -42,6 +42,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "test\\è",
                     "value": "test\\è"
                   }
                 },
@@ -82,6 +83,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "prefix {$x[\"key\\è\"]} suffix",
                     "value": "prefix {$x[\"key\\è\"]} suffix"
                   }
                 },
@@ -122,6 +124,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "\\è\\é\\ù mixed",
                     "value": "\\è\\é\\ù mixed"
                   }
                 },
@@ -162,6 +165,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "\\\\è escaped",
                     "value": "\\\\è escaped"
                   }
                 },
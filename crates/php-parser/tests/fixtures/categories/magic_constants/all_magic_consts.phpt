@@ -5,80 +5,82 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "MagicConst": "Line"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "MagicConst": "Line"
+              },
+              "span": {
+                "start": 11,
+                "end": 19
+              }
             },
-            "span": {
-              "start": 11,
-              "end": 19
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "File"
-            },
-            "span": {
-              "start": 21,
-              "end": 29
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Dir"
+            {
+              "kind": {
+                "MagicConst": "File"
+              },
+              "span": {
+                "start": 21,
+                "end": 29
+              }
             },
-            "span": {
-              "start": 31,
-              "end": 38
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Function"
+            {
+              "kind": {
+                "MagicConst": "Dir"
+              },
+              "span": {
+                "start": 31,
+                "end": 38
+              }
             },
-            "span": {
-              "start": 40,
-              "end": 52
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Class"
+            {
+              "kind": {
+                "MagicConst": "Function"
+              },
+              "span": {
+                "start": 40,
+                "end": 52
+              }
             },
-            "span": {
-              "start": 54,
-              "end": 63
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Trait"
+            {
+              "kind": {
+                "MagicConst": "Class"
+              },
+              "span": {
+                "start": 54,
+                "end": 63
+              }
             },
-            "span": {
-              "start": 65,
-              "end": 74
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Method"
+            {
+              "kind": {
+                "MagicConst": "Trait"
+              },
+              "span": {
+                "start": 65,
+                "end": 74
+              }
             },
-            "span": {
-              "start": 76,
-              "end": 86
-            }
-          },
-          {
-            "kind": {
-              "MagicConst": "Namespace"
+            {
+              "kind": {
+                "MagicConst": "Method"
+              },
+              "span": {
+                "start": 76,
+                "end": 86
+              }
             },
-            "span": {
-              "start": 88,
-              "end": 101
+            {
+              "kind": {
+                "MagicConst": "Namespace"
+              },
+              "span": {
+                "start": 88,
+                "end": 101
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -24,7 +24,10 @@
                     "op": "Concat",
                     "right": {
                       "kind": {
-                        "String": "/config.php"
+                        "String": {
+                          "value": "/config.php",
+                          "raw": "/config.php"
+                        }
                       },
                       "span": {
                         "start": 24,
@@ -23,7 +23,10 @@
                   "Binary": {
                     "left": {
                       "kind": {
-                        "String": "loaded from "
+                        "String": {
+                          "value": "loaded from ",
+                          "raw": "loaded from "
+                        }
                       },
                       "span": {
                         "start": 11,
@@ -45,6 +45,10 @@
                         "span": {
                           "start": 24,
                           "end": 36
+                        },
+                        "comma": {
+                          "start": 36,
+                          "end": 37
                         }
                       },
                       {
@@ -71,6 +75,10 @@
                         "span": {
                           "start": 38,
                           "end": 44
+                        },
+                        "comma": {
+                          "start": 44,
+                          "end": 45
                         }
                       },
                       {
@@ -35,7 +35,10 @@
                         "conditions": [
                           {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 23,
@@ -55,13 +58,20 @@
                         "span": {
                           "start": 23,
                           "end": 31
+                        },
+                        "comma": {
+                          "start": 31,
+                          "end": 32
                         }
                       },
                       {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 33,
@@ -81,6 +91,10 @@
                         "span": {
                           "start": 33,
                           "end": 41
+                        },
+                        "comma": {
+                          "start": 41,
+                          "end": 42
                         }
                       },
                       {
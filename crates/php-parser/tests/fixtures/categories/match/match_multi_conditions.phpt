@@ -63,7 +63,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "low"
+                            "String": {
+                              "value": "low",
+                              "raw": "low"
+                            }
                           },
                           "span": {
                             "start": 34,
@@ -73,6 +76,10 @@
                         "span": {
                           "start": 23,
                           "end": 39
+                        },
+                        "comma": {
+                          "start": 39,
+                          "end": 40
                         }
                       },
                       {
@@ -98,7 +105,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "high"
+                            "String": {
+                              "value": "high",
+                              "raw": "high"
+                            }
                           },
                           "span": {
                             "start": 49,
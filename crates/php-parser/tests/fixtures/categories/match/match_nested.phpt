@@ -70,7 +70,10 @@
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "aa"
+                                      "String": {
+                                        "value": "aa",
+                                        "raw": "aa"
+                                      }
                                     },
                                     "span": {
                                       "start": 45,
@@ -80,13 +83,20 @@
                                   "span": {
                                     "start": 40,
                                     "end": 49
+                                  },
+                                  "comma": {
+                                    "start": 49,
+                                    "end": 50
                                   }
                                 },
                                 {
                                   "conditions": null,
                                   "body": {
                                     "kind": {
-                                      "String": "ab"
+                                      "String": {
+                                        "value": "ab",
+                                        "raw": "ab"
+                                      }
                                     },
                                     "span": {
                                       "start": 62,
@@ -109,13 +119,20 @@
                         "span": {
                           "start": 23,
                           "end": 68
+                        },
+                        "comma": {
+                          "start": 68,
+                          "end": 69
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "x"
+                            "String": {
+                              "value": "x",
+                              "raw": "x"
+                            }
                           },
                           "span": {
                             "start": 81,
@@ -45,7 +45,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "one"
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
                           },
                           "span": {
                             "start": 28,
@@ -55,6 +58,10 @@
                         "span": {
                           "start": 23,
                           "end": 33
+                        },
+                        "comma": {
+                          "start": 33,
+                          "end": 34
                         }
                       },
                       {
@@ -71,7 +78,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "two"
+                            "String": {
+                              "value": "two",
+                              "raw": "two"
+                            }
                           },
                           "span": {
                             "start": 40,
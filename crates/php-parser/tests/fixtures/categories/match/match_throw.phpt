@@ -45,7 +45,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "ok"
+                            "String": {
+                              "value": "ok",
+                              "raw": "ok"
+                            }
                           },
                           "span": {
                             "start": 28,
@@ -55,6 +58,10 @@
                         "span": {
                           "start": 23,
                           "end": 32
+                        },
+                        "comma": {
+                          "start": 32,
+                          "end": 33
                         }
                       },
                       {
@@ -65,12 +72,15 @@
                               "kind": {
                                 "New": {
                                   "class": {
-                                    "kind": {
-                                      "Identifier": "Exception"
-                                    },
-                                    "span": {
-                                      "start": 55,
-                                      "end": 64
+                                    "Name": {
+                                      "parts": [
+                                        "Exception"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 55,
+                                        "end": 64
+                                      }
                                     }
                                   },
                                   "args": []
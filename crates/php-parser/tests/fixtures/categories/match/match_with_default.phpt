@@ -65,7 +65,10 @@
                         ],
                         "body": {
                           "kind": {
-                            "String": "pos"
+                            "String": {
+                              "value": "pos",
+                              "raw": "pos"
+                            }
                           },
                           "span": {
                             "start": 35,
@@ -75,13 +78,20 @@
                         "span": {
                           "start": 25,
                           "end": 40
+                        },
+                        "comma": {
+                          "start": 40,
+                          "end": 41
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "other"
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
                           },
                           "span": {
                             "start": 53,
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -29,6 +38,10 @@
                       "end": 12
                     }
                   },
+                  "colon": {
+                    "start": 12,
+                    "end": 13
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -29,6 +38,10 @@
                       "end": 14
                     }
                   },
+                  "colon": {
+                    "start": 14,
+                    "end": 15
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
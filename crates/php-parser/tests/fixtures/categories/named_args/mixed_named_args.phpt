@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -34,6 +43,10 @@
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
@@ -52,6 +65,10 @@
                   "span": {
                     "start": 13,
                     "end": 14
+                  },
+                  "comma": {
+                    "start": 14,
+                    "end": 15
                   }
                 },
                 {
@@ -65,9 +82,16 @@
                       "end": 20
                     }
                   },
+                  "colon": {
+                    "start": 20,
+                    "end": 21
+                  },
                   "value": {
                     "kind": {
-                      "String": "test"
+                      "String": {
+                        "value": "test",
+                        "raw": "test"
+                      }
                     },
                     "span": {
                       "start": 22,
@@ -79,6 +103,10 @@
                   "span": {
                     "start": 16,
                     "end": 28
+                  },
+                  "comma": {
+                    "start": 28,
+                    "end": 29
                   }
                 },
                 {
@@ -92,6 +120,10 @@
                       "end": 35
                     }
                   },
+                  "colon": {
+                    "start": 35,
+                    "end": 36
+                  },
                   "value": {
                     "kind": {
                       "Bool": true
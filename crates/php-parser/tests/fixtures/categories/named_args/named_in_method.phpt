@@ -38,9 +38,16 @@
                       "end": 22
                     }
                   },
+                  "colon": {
+                    "start": 22,
+                    "end": 23
+                  },
                   "value": {
                     "kind": {
-                      "String": "val"
+                      "String": {
+                        "value": "val",
+                        "raw": "val"
+                      }
                     },
                     "span": {
                       "start": 24,
@@ -9,12 +9,15 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 13
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 13
+                  }
                 }
               },
               "args": [
@@ -29,6 +32,10 @@
                       "end": 15
                     }
                   },
+                  "colon": {
+                    "start": 15,
+                    "end": 16
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -43,6 +50,10 @@
                   "span": {
                     "start": 14,
                     "end": 18
+                  },
+                  "comma": {
+                    "start": 18,
+                    "end": 19
                   }
                 },
                 {
@@ -56,6 +67,10 @@
                       "end": 21
                     }
                   },
+                  "colon": {
+                    "start": 21,
+                    "end": 22
+                  },
                   "value": {
                     "kind": {
                       "Int": 2
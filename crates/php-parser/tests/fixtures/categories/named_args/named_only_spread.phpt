@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -26,7 +35,10 @@
                         {
                           "key": {
                             "kind": {
-                              "String": "name"
+                              "String": {
+                                "value": "name",
+                                "raw": "name"
+                              }
                             },
                             "span": {
                               "start": 14,
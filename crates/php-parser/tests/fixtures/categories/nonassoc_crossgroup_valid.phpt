@@ -129,8 +129,8 @@ $a instanceof Foo == $c;
             "Binary": {
               "left": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "a"
                       },
@@ -139,14 +139,16 @@ $a instanceof Foo == $c;
                         "end": 39
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 51,
-                        "end": 54
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 51,
+                          "end": 54
+                        }
                       }
                     }
                   }
@@ -86,8 +86,8 @@ $a ?? $b instanceof X;
               },
               "right": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "b"
                       },
@@ -96,14 +96,16 @@ $a ?? $b instanceof X;
                         "end": 30
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "X"
-                      },
-                      "span": {
-                        "start": 42,
-                        "end": 43
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "X"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 42,
+                          "end": 43
+                        }
                       }
                     }
                   }
@@ -16,7 +16,10 @@
                         "Binary": {
                           "left": {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 6,
@@ -26,7 +29,10 @@
                           "op": "Concat",
                           "right": {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 12,
@@ -60,7 +66,10 @@
               "op": "Concat",
               "right": {
                 "kind": {
-                  "String": "d"
+                  "String": {
+                    "value": "d",
+                    "raw": "d"
+                  }
                 },
                 "span": {
                   "start": 23,
@@ -8,32 +8,38 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "Assign": {
-                  "target": {
-                    "kind": {
-                      "Variable": "x"
+              "expr": {
+                "kind": {
+                  "Assign": {
+                    "target": {
+                      "kind": {
+                        "Variable": "x"
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 9
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 9
-                    }
-                  },
-                  "op": "Assign",
-                  "value": {
-                    "kind": {
-                      "Int": 1
-                    },
-                    "span": {
-                      "start": 12,
-                      "end": 13
+                    "op": "Assign",
+                    "value": {
+                      "kind": {
+                        "Int": 1
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 13
+                      }
                     }
                   }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 13
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 13
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
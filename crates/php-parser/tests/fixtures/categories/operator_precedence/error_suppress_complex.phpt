@@ -8,32 +8,38 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "MethodCall": {
-                  "object": {
-                    "kind": {
-                      "Variable": "obj"
+              "expr": {
+                "kind": {
+                  "MethodCall": {
+                    "object": {
+                      "kind": {
+                        "Variable": "obj"
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 11
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 11
-                    }
-                  },
-                  "method": {
-                    "kind": {
-                      "Identifier": "method"
+                    "method": {
+                      "kind": {
+                        "Identifier": "method"
+                      },
+                      "span": {
+                        "start": 13,
+                        "end": 19
+                      }
                     },
-                    "span": {
-                      "start": 13,
-                      "end": 19
-                    }
-                  },
-                  "args": []
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 21
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 21
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
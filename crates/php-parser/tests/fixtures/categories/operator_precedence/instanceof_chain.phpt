@@ -10,8 +10,8 @@
             "Binary": {
               "left": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "a"
                       },
@@ -20,14 +20,16 @@
                         "end": 8
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 20,
-                        "end": 23
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 20,
+                          "end": 23
+                        }
                       }
                     }
                   }
@@ -40,8 +42,8 @@
               "op": "BooleanAnd",
               "right": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "b"
                       },
@@ -50,14 +52,16 @@
                         "end": 29
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Bar"
-                      },
-                      "span": {
-                        "start": 41,
-                        "end": 44
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Bar"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 41,
+                          "end": 44
+                        }
                       }
                     }
                   }
@@ -42,17 +42,19 @@ $r = ($x <> 5) ? 'a' : 'b';
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 27,
-                          "end": 28
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 27,
+                            "end": 28
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 22,
@@ -132,7 +134,10 @@ $r = ($x <> 5) ? 'a' : 'b';
                     },
                     "then_expr": {
                       "kind": {
-                        "String": "a"
+                        "String": {
+                          "value": "a",
+                          "raw": "a"
+                        }
                       },
                       "span": {
                         "start": 49,
@@ -141,7 +146,10 @@ $r = ($x <> 5) ? 'a' : 'b';
                     },
                     "else_expr": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 55,
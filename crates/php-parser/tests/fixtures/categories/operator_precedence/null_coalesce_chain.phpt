@@ -43,7 +43,10 @@
                           },
                           "right": {
                             "kind": {
-                              "String": "default"
+                              "String": {
+                                "value": "default",
+                                "raw": "default"
+                              }
                             },
                             "span": {
                               "start": 24,
@@ -23,6 +23,10 @@
                 "span": {
                   "start": 7,
                   "end": 12
+                },
+                "comma": {
+                  "start": 12,
+                  "end": 13
                 }
               },
               {
@@ -40,6 +44,10 @@
                 "span": {
                   "start": 14,
                   "end": 19
+                },
+                "comma": {
+                  "start": 19,
+                  "end": 20
                 }
               },
               {
@@ -57,6 +65,10 @@
                 "span": {
                   "start": 21,
                   "end": 22
+                },
+                "comma": {
+                  "start": 22,
+                  "end": 23
                 }
               },
               {
@@ -48,6 +48,10 @@ function createUser(string $name, int $age): User {}
               "span": {
                 "start": 170,
                 "end": 182
+              },
+              "comma": {
+                "start": 182,
+                "end": 183
               }
             },
             {
@@ -10,16 +10,22 @@ PHP: (@$a) instanceof Foo. @ is at the high unary tier (with ~ and casts), above
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "ErrorSuppress": {
-                    "kind": {
-                      "Variable": "a"
+                    "expr": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 9
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 9
+                    "operator_span": {
+                      "start": 6,
+                      "end": 7
                     }
                   }
                 },
@@ -28,14 +34,16 @@ PHP: (@$a) instanceof Foo. @ is at the high unary tier (with ~ and casts), above
                   "end": 9
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 21,
-                  "end": 24
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 21,
+                    "end": 24
+                  }
                 }
               }
             }
@@ -10,8 +10,8 @@ PHP: ((bool)$a) instanceof Foo.
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Cast": [
                     "Bool",
@@ -31,14 +31,16 @@ PHP: ((bool)$a) instanceof Foo.
                   "end": 14
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 26,
-                  "end": 29
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 26,
+                    "end": 29
+                  }
                 }
               }
             }
@@ -10,8 +10,8 @@ PHP: ((int)$a) instanceof Foo. Cast binds tighter than instanceof.
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Cast": [
                     "Int",
@@ -31,14 +31,16 @@ PHP: ((int)$a) instanceof Foo. Cast binds tighter than instanceof.
                   "end": 13
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 25,
-                  "end": 28
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 25,
+                    "end": 28
+                  }
                 }
               }
             }
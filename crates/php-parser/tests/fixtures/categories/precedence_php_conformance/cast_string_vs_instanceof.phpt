@@ -10,8 +10,8 @@ PHP: ((string)$a) instanceof Foo.
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Cast": [
                     "String",
@@ -31,14 +31,16 @@ PHP: ((string)$a) instanceof Foo.
                   "end": 16
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 28,
-                  "end": 31
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 28,
+                    "end": 31
+                  }
                 }
               }
             }
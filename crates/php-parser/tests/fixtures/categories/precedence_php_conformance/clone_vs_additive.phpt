@@ -15,12 +15,18 @@ clone $a + 1;
               "left": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Variable": "a"
+                    "object": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 14
+                      }
                     },
-                    "span": {
-                      "start": 12,
-                      "end": 14
+                    "keyword_span": {
+                      "start": 6,
+                      "end": 11
                     }
                   }
                 },
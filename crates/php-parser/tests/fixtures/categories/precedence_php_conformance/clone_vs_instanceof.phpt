@@ -10,16 +10,22 @@ clone $a instanceof Box;
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Variable": "a"
+                    "object": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 14
+                      }
                     },
-                    "span": {
-                      "start": 12,
-                      "end": 14
+                    "keyword_span": {
+                      "start": 6,
+                      "end": 11
                     }
                   }
                 },
@@ -28,14 +34,16 @@ clone $a instanceof Box;
                   "end": 14
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Box"
-                },
-                "span": {
-                  "start": 26,
-                  "end": 29
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Box"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 26,
+                    "end": 29
+                  }
                 }
               }
             }
@@ -11,31 +11,37 @@ clone $a->b;
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "PropertyAccess": {
-                  "object": {
-                    "kind": {
-                      "Variable": "a"
+              "object": {
+                "kind": {
+                  "PropertyAccess": {
+                    "object": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 14
+                      }
                     },
-                    "span": {
-                      "start": 12,
-                      "end": 14
-                    }
-                  },
-                  "property": {
-                    "kind": {
-                      "Identifier": "b"
-                    },
-                    "span": {
-                      "start": 16,
-                      "end": 17
+                    "property": {
+                      "kind": {
+                        "Identifier": "b"
+                      },
+                      "span": {
+                        "start": 16,
+                        "end": 17
+                      }
                     }
                   }
+                },
+                "span": {
+                  "start": 12,
+                  "end": 17
                 }
               },
-              "span": {
-                "start": 12,
-                "end": 17
+              "keyword_span": {
+                "start": 6,
+                "end": 11
               }
             }
           },
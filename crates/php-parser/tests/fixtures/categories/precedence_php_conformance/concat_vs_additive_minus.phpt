@@ -13,7 +13,10 @@ PHP: "x" . ($a - 1).
             "Binary": {
               "left": {
                 "kind": {
-                  "String": "x"
+                  "String": {
+                    "value": "x",
+                    "raw": "x"
+                  }
                 },
                 "span": {
                   "start": 6,
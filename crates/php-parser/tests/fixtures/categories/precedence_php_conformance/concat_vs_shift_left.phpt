@@ -43,7 +43,10 @@ PHP: (1 << 2) . "3" = "43". Shift binds tighter than concat.
               "op": "Concat",
               "right": {
                 "kind": {
-                  "String": "3"
+                  "String": {
+                    "value": "3",
+                    "raw": "3"
+                  }
                 },
                 "span": {
                   "start": 15,
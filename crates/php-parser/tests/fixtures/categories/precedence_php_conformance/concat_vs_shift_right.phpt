@@ -13,7 +13,10 @@ PHP: "x" . (16 >> 2).
             "Binary": {
               "left": {
                 "kind": {
-                  "String": "x"
+                  "String": {
+                    "value": "x",
+                    "raw": "x"
+                  }
                 },
                 "span": {
                   "start": 6,
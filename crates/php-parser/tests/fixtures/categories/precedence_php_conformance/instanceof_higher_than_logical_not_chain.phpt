@@ -17,8 +17,8 @@ PHP: (!($a instanceof Foo)) && (!($b instanceof Bar)).
                     "op": "BooleanNot",
                     "operand": {
                       "kind": {
-                        "Binary": {
-                          "left": {
+                        "Instanceof": {
+                          "expr": {
                             "kind": {
                               "Variable": "a"
                             },
@@ -27,14 +27,16 @@ PHP: (!($a instanceof Foo)) && (!($b instanceof Bar)).
                               "end": 9
                             }
                           },
-                          "op": "Instanceof",
-                          "right": {
-                            "kind": {
-                              "Identifier": "Foo"
-                            },
-                            "span": {
-                              "start": 21,
-                              "end": 24
+                          "class": {
+                            "Name": {
+                              "parts": [
+                                "Foo"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 21,
+                                "end": 24
+                              }
                             }
                           }
                         }
@@ -58,8 +60,8 @@ PHP: (!($a instanceof Foo)) && (!($b instanceof Bar)).
                     "op": "BooleanNot",
                     "operand": {
                       "kind": {
-                        "Binary": {
-                          "left": {
+                        "Instanceof": {
+                          "expr": {
                             "kind": {
                               "Variable": "b"
                             },
@@ -68,14 +70,16 @@ PHP: (!($a instanceof Foo)) && (!($b instanceof Bar)).
                               "end": 31
                             }
                           },
-                          "op": "Instanceof",
-                          "right": {
-                            "kind": {
-                              "Identifier": "Bar"
-                            },
-                            "span": {
-                              "start": 43,
-                              "end": 46
+                          "class": {
+                            "Name": {
+                              "parts": [
+                                "Bar"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 43,
+                                "end": 46
+                              }
                             }
                           }
                         }
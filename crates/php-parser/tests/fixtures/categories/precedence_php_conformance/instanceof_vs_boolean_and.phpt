@@ -13,8 +13,8 @@ $a instanceof Foo && $b instanceof Bar;
             "Binary": {
               "left": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "a"
                       },
@@ -23,14 +23,16 @@ $a instanceof Foo && $b instanceof Bar;
                         "end": 8
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 20,
-                        "end": 23
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 20,
+                          "end": 23
+                        }
                       }
                     }
                   }
@@ -43,8 +45,8 @@ $a instanceof Foo && $b instanceof Bar;
               "op": "BooleanAnd",
               "right": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "b"
                       },
@@ -53,14 +55,16 @@ $a instanceof Foo && $b instanceof Bar;
                         "end": 29
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Bar"
-                      },
-                      "span": {
-                        "start": 41,
-                        "end": 44
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Bar"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 41,
+                          "end": 44
+                        }
                       }
                     }
                   }
@@ -13,8 +13,8 @@ $a instanceof Foo < 5;
             "Binary": {
               "left": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "a"
                       },
@@ -23,14 +23,16 @@ $a instanceof Foo < 5;
                         "end": 8
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 20,
-                        "end": 23
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 20,
+                          "end": 23
+                        }
                       }
                     }
                   }
@@ -10,8 +10,8 @@ $a ** $b instanceof Foo;
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Binary": {
                     "left": {
@@ -40,14 +40,16 @@ $a ** $b instanceof Foo;
                   "end": 14
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 26,
-                  "end": 29
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 26,
+                    "end": 29
+                  }
                 }
               }
             }
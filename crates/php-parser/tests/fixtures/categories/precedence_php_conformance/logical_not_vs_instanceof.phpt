@@ -14,8 +14,8 @@ PHP: !($a instanceof Foo). ! is BELOW instanceof (exception to unary tier).
               "op": "BooleanNot",
               "operand": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "a"
                       },
@@ -24,14 +24,16 @@ PHP: !($a instanceof Foo). ! is BELOW instanceof (exception to unary tier).
                         "end": 9
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 21,
-                        "end": 24
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 21,
+                          "end": 24
+                        }
                       }
                     }
                   }
@@ -11,17 +11,20 @@ new Foo() instanceof Foo;
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 10,
-                        "end": 13
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 10,
+                          "end": 13
+                        }
                       }
                     },
                     "args": []
@@ -32,14 +35,16 @@ new Foo() instanceof Foo;
                   "end": 15
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 27,
-                  "end": 30
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 27,
+                    "end": 30
+                  }
                 }
               }
             }
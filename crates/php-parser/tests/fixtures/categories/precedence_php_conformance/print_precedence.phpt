@@ -12,32 +12,38 @@ print 1 + 2;
         "Expression": {
           "kind": {
             "Print": {
-              "kind": {
-                "Binary": {
-                  "left": {
-                    "kind": {
-                      "Int": 1
+              "expr": {
+                "kind": {
+                  "Binary": {
+                    "left": {
+                      "kind": {
+                        "Int": 1
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 13
+                      }
                     },
-                    "span": {
-                      "start": 12,
-                      "end": 13
-                    }
-                  },
-                  "op": "Add",
-                  "right": {
-                    "kind": {
-                      "Int": 2
-                    },
-                    "span": {
-                      "start": 16,
-                      "end": 17
+                    "op": "Add",
+                    "right": {
+                      "kind": {
+                        "Int": 2
+                      },
+                      "span": {
+                        "start": 16,
+                        "end": 17
+                      }
                     }
                   }
+                },
+                "span": {
+                  "start": 12,
+                  "end": 17
                 }
               },
-              "span": {
-                "start": 12,
-                "end": 17
+              "keyword_span": {
+                "start": 6,
+                "end": 11
               }
             }
           },
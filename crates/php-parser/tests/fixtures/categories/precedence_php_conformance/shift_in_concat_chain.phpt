@@ -16,7 +16,10 @@ PHP: "a" . (1 << 3) . "b".
                   "Binary": {
                     "left": {
                       "kind": {
-                        "String": "a"
+                        "String": {
+                          "value": "a",
+                          "raw": "a"
+                        }
                       },
                       "span": {
                         "start": 6,
@@ -63,7 +66,10 @@ PHP: "a" . (1 << 3) . "b".
               "op": "Concat",
               "right": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 21,
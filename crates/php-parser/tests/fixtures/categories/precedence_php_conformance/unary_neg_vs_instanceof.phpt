@@ -10,8 +10,8 @@ PHP: (-$a) instanceof Foo. - binds tighter than instanceof.
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "UnaryPrefix": {
                     "op": "Negate",
@@ -31,14 +31,16 @@ PHP: (-$a) instanceof Foo. - binds tighter than instanceof.
                   "end": 9
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 21,
-                  "end": 24
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 21,
+                    "end": 24
+                  }
                 }
               }
             }
@@ -10,8 +10,8 @@ PHP: (++$a) instanceof Foo.
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "UnaryPrefix": {
                     "op": "PreIncrement",
@@ -31,14 +31,16 @@ PHP: (++$a) instanceof Foo.
                   "end": 10
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 22,
-                  "end": 25
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 22,
+                    "end": 25
+                  }
                 }
               }
             }
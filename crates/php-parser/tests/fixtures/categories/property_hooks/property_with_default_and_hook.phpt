@@ -45,7 +45,10 @@ min_php=8.4
                   },
                   "default": {
                     "kind": {
-                      "String": "default"
+                      "String": {
+                        "value": "default",
+                        "raw": "default"
+                      }
                     },
                     "span": {
                       "start": 40,
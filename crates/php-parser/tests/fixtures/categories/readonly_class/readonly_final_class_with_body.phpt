@@ -58,6 +58,10 @@ min_php=8.2
                       "span": {
                         "start": 63,
                         "end": 76
+                      },
+                      "comma": {
+                        "start": 76,
+                        "end": 77
                       }
                     },
                     {
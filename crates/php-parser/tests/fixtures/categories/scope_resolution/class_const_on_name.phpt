@@ -5,36 +5,41 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "ClassConstAccess": {
-                "class": {
-                  "kind": {
-                    "Identifier": "Foo"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "ClassConstAccess": {
+                  "class": {
+                    "Name": {
+                      "parts": [
+                        "Foo"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 11,
+                        "end": 14
+                      }
+                    }
                   },
-                  "span": {
-                    "start": 11,
-                    "end": 14
-                  }
-                },
-                "member": {
-                  "kind": {
-                    "Identifier": "class"
-                  },
-                  "span": {
-                    "start": 16,
-                    "end": 21
+                  "member": {
+                    "kind": {
+                      "Identifier": "class"
+                    },
+                    "span": {
+                      "start": 16,
+                      "end": 21
+                    }
                   }
                 }
+              },
+              "span": {
+                "start": 11,
+                "end": 21
               }
-            },
-            "span": {
-              "start": 11,
-              "end": 21
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
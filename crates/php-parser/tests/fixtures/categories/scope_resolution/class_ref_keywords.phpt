@@ -0,0 +1,238 @@
+===description===
+`self`, `parent`, and `static` as class operands get their own ClassRef
+variants, matched case-insensitively; other names and computed classes do not.
+===source===
+<?php
+new Self;
+PARENT::create();
+$x instanceof static;
+$x instanceof \Foo\Bar;
+$x instanceof $class;
+try {} catch (self | Exception $e) {}
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "New": {
+              "class": {
+                "SelfKw": {
+                  "start": 10,
+                  "end": 14
+                }
+              },
+              "args": []
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 14
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 15
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "StaticMethodCall": {
+              "class": {
+                "ParentKw": {
+                  "start": 16,
+                  "end": 22
+                }
+              },
+              "method": {
+                "kind": {
+                  "Identifier": "create"
+                },
+                "span": {
+                  "start": 24,
+                  "end": 30
+                }
+              },
+              "args": []
+            }
+          },
+          "span": {
+            "start": 16,
+            "end": 32
+          }
+        }
+      },
+      "span": {
+        "start": 16,
+        "end": 33
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Instanceof": {
+              "expr": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 34,
+                  "end": 36
+                }
+              },
+              "class": {
+                "StaticKw": {
+                  "start": 48,
+                  "end": 54
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 34,
+            "end": 54
+          }
+        }
+      },
+      "span": {
+        "start": 34,
+        "end": 55
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Instanceof": {
+              "expr": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 56,
+                  "end": 58
+                }
+              },
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo",
+                    "Bar"
+                  ],
+                  "kind": "FullyQualified",
+                  "span": {
+                    "start": 70,
+                    "end": 78
+                  }
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 56,
+            "end": 78
+          }
+        }
+      },
+      "span": {
+        "start": 56,
+        "end": 79
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Instanceof": {
+              "expr": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 80,
+                  "end": 82
+                }
+              },
+              "class": {
+                "Expr": {
+                  "kind": {
+                    "Variable": "class"
+                  },
+                  "span": {
+                    "start": 94,
+                    "end": 100
+                  }
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 80,
+            "end": 100
+          }
+        }
+      },
+      "span": {
+        "start": 80,
+        "end": 101
+      }
+    },
+    {
+      "kind": {
+        "TryCatch": {
+          "body": [],
+          "catches": [
+            {
+              "types": [
+                {
+                  "SelfKw": {
+                    "start": 116,
+                    "end": 120
+                  }
+                },
+                {
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 123,
+                      "end": 132
+                    }
+                  }
+                }
+              ],
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 133,
+                  "end": 135
+                }
+              },
+              "body": [],
+              "span": {
+                "start": 115,
+                "end": 139
+              }
+            }
+          ],
+          "finally": null
+        }
+      },
+      "span": {
+        "start": 102,
+        "end": 139
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 139
+  }
+}
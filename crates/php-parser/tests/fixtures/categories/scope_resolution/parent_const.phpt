@@ -42,10 +42,7 @@
                           "kind": {
                             "ClassConstAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "parent"
-                                },
-                                "span": {
+                                "ParentKw": {
                                   "start": 59,
                                   "end": 65
                                 }
@@ -42,10 +42,7 @@
                           "kind": {
                             "StaticMethodCall": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "parent"
-                                },
-                                "span": {
+                                "ParentKw": {
                                   "start": 52,
                                   "end": 58
                                 }
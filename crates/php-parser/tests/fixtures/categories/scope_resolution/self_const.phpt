@@ -56,10 +56,7 @@
                           "kind": {
                             "ClassConstAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "self"
-                                },
-                                "span": {
+                                "SelfKw": {
                                   "start": 60,
                                   "end": 64
                                 }
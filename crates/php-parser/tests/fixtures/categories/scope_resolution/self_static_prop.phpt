@@ -59,10 +59,7 @@
                           "kind": {
                             "StaticPropertyAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "self"
-                                },
-                                "span": {
+                                "SelfKw": {
                                   "start": 69,
                                   "end": 73
                                 }
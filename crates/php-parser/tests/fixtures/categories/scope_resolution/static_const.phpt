@@ -33,10 +33,7 @@
                           "kind": {
                             "ClassConstAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "static"
-                                },
-                                "span": {
+                                "StaticKw": {
                                   "start": 47,
                                   "end": 53
                                 }
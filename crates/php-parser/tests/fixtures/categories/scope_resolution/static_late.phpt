@@ -33,10 +33,7 @@
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "static"
-                                },
-                                "span": {
+                                "StaticKw": {
                                   "start": 63,
                                   "end": 69
                                 }
@@ -32,17 +32,19 @@ endforeach;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 24,
-                          "end": 25
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 24,
+                            "end": 25
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 19,
@@ -83,17 +85,19 @@ endforeach;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 2
-                        },
-                        "span": {
-                          "start": 55,
-                          "end": 56
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 2
+                          },
+                          "span": {
+                            "start": 55,
+                            "end": 56
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 50,
@@ -202,22 +206,36 @@ endforeach;
               }
             }
           ],
+          "init_span": {
+            "start": 73,
+            "end": 79
+          },
+          "condition_span": {
+            "start": 81,
+            "end": 88
+          },
+          "update_span": {
+            "start": 90,
+            "end": 94
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 3
-                        },
-                        "span": {
-                          "start": 106,
-                          "end": 107
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 3
+                          },
+                          "span": {
+                            "start": 106,
+                            "end": 107
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 101,
@@ -266,17 +284,19 @@ endforeach;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 4
-                        },
-                        "span": {
-                          "start": 148,
-                          "end": 149
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 4
+                          },
+                          "span": {
+                            "start": 148,
+                            "end": 149
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 143,
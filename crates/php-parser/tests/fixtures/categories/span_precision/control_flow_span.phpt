@@ -26,17 +26,19 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 21,
-                          "end": 22
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 21,
+                            "end": 22
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 16,
@@ -76,17 +78,19 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 2
-                        },
-                        "span": {
-                          "start": 44,
-                          "end": 45
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 2
+                          },
+                          "span": {
+                            "start": 44,
+                            "end": 45
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 39,
@@ -194,22 +198,36 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
               }
             }
           ],
+          "init_span": {
+            "start": 54,
+            "end": 60
+          },
+          "condition_span": {
+            "start": 62,
+            "end": 69
+          },
+          "update_span": {
+            "start": 71,
+            "end": 75
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 3
-                        },
-                        "span": {
-                          "start": 84,
-                          "end": 85
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 3
+                          },
+                          "span": {
+                            "start": 84,
+                            "end": 85
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 79,
@@ -257,17 +275,19 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 4
-                        },
-                        "span": {
-                          "start": 117,
-                          "end": 118
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 4
+                          },
+                          "span": {
+                            "start": 117,
+                            "end": 118
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 112,
@@ -341,17 +361,19 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
           "body": [
             {
               "kind": {
-                "Echo": [
-                  {
-                    "kind": {
-                      "Int": 5
-                    },
-                    "span": {
-                      "start": 164,
-                      "end": 165
+                "Echo": {
+                  "exprs": [
+                    {
+                      "kind": {
+                        "Int": 5
+                      },
+                      "span": {
+                        "start": 164,
+                        "end": 165
+                      }
                     }
-                  }
-                ]
+                  ]
+                }
               },
               "span": {
                 "start": 159,
@@ -363,31 +385,41 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 176,
-                    "end": 185
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 176,
+                      "end": 185
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 186,
+                  "end": 188
+                }
+              },
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 6
-                        },
-                        "span": {
-                          "start": 197,
-                          "end": 198
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 6
+                          },
+                          "span": {
+                            "start": 197,
+                            "end": 198
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 192,
@@ -404,17 +436,19 @@ try { echo 5; } catch (Exception $e) { echo 6; } finally { echo 7; }
           "finally": [
             {
               "kind": {
-                "Echo": [
-                  {
-                    "kind": {
-                      "Int": 7
-                    },
-                    "span": {
-                      "start": 217,
-                      "end": 218
+                "Echo": {
+                  "exprs": [
+                    {
+                      "kind": {
+                        "Int": 7
+                      },
+                      "span": {
+                        "start": 217,
+                        "end": 218
+                      }
                     }
-                  }
-                ]
+                  ]
+                }
               },
               "span": {
                 "start": 212,
@@ -27,7 +27,16 @@ $h = Foo::bar(...);
                     "kind": {
                       "Function": {
                         "kind": {
-                          "Identifier": "strlen"
+                          "Name": {
+                            "parts": [
+                              "strlen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 11,
+                              "end": 17
+                            }
+                          }
                         },
                         "span": {
                           "start": 11,
@@ -136,12 +145,15 @@ $h = Foo::bar(...);
                     "kind": {
                       "StaticMethod": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 53,
-                            "end": 56
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 53,
+                              "end": 56
+                            }
                           }
                         },
                         "method": {
@@ -11,7 +11,16 @@ foo(1, 2);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -35,6 +44,10 @@ foo(1, 2);
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
@@ -124,12 +124,15 @@ Foo::bar(1);
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 41,
-                  "end": 44
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 41,
+                    "end": 44
+                  }
                 }
               },
               "method": {
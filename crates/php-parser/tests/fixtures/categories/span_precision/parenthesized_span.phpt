@@ -119,7 +119,10 @@ eval('code');
           "kind": {
             "Eval": {
               "kind": {
-                "String": "code"
+                "String": {
+                  "value": "code",
+                  "raw": "code"
+                }
               },
               "span": {
                 "start": 46,
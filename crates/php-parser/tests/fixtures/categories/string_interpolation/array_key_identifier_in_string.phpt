@@ -36,7 +36,10 @@
                             },
                             "index": {
                               "kind": {
-                                "String": "foo"
+                                "String": {
+                                  "value": "foo",
+                                  "raw": "foo"
+                                }
                               },
                               "span": {
                                 "start": 17,
@@ -52,7 +55,13 @@
                       }
                     },
                     {
-                      "Literal": " here"
+                      "Literal": {
+                        "value": " here",
+                        "span": {
+                          "start": 21,
+                          "end": 26
+                        }
+                      }
                     }
                   ]
                 },
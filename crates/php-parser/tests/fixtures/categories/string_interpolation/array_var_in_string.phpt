@@ -22,7 +22,13 @@
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "item "
+                      "Literal": {
+                        "value": "item ",
+                        "span": {
+                          "start": 12,
+                          "end": 17
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -55,7 +61,13 @@
                       }
                     },
                     {
-                      "Literal": " here"
+                      "Literal": {
+                        "value": " here",
+                        "span": {
+                          "start": 24,
+                          "end": 29
+                        }
+                      }
                     }
                   ]
                 },
@@ -33,7 +33,13 @@
                       }
                     },
                     {
-                      "Literal": "s"
+                      "Literal": {
+                        "value": "s",
+                        "span": {
+                          "start": 19,
+                          "end": 20
+                        }
+                      }
                     }
                   ]
                 },
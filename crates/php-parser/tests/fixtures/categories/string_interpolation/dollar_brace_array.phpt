@@ -52,7 +52,13 @@
                       }
                     },
                     {
-                      "Literal": "bar"
+                      "Literal": {
+                        "value": "bar",
+                        "span": {
+                          "start": 21,
+                          "end": 24
+                        }
+                      }
                     }
                   ]
                 },
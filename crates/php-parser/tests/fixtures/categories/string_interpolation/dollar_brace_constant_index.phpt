@@ -36,7 +36,10 @@
                             },
                             "index": {
                               "kind": {
-                                "String": "PHP_INT_MAX"
+                                "String": {
+                                  "value": "PHP_INT_MAX",
+                                  "raw": "PHP_INT_MAX"
+                                }
                               },
                               "span": {
                                 "start": 18,
@@ -33,7 +33,13 @@
                       }
                     },
                     {
-                      "Literal": "bar"
+                      "Literal": {
+                        "value": "bar",
+                        "span": {
+                          "start": 18,
+                          "end": 21
+                        }
+                      }
                     }
                   ]
                 },
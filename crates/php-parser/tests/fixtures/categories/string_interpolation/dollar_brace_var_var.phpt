@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "x"
+                  "String": {
+                    "value": "x",
+                    "raw": "x"
+                  }
                 },
                 "span": {
                   "start": 14,
@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": ""
+                  "String": {
+                    "value": "",
+                    "raw": ""
+                  }
                 },
                 "span": {
                   "start": 11,
@@ -23,9 +23,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "",
                     "parts": [
                       {
-                        "Literal": ""
+                        "Literal": {
+                          "value": "",
+                          "span": {
+                            "start": 18,
+                            "end": 18
+                          }
+                        }
                       }
                     ]
                   }
@@ -0,0 +1,51 @@
+===source===
+<?php $s = "\x41\101\u{1F600}\q";
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "s"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "String": {
+                    "value": "AA😀\\q",
+                    "raw": "\\x41\\101\\u{1F600}\\q"
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 32
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 32
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 33
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 33
+  }
+}
@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "cost is $5"
+                  "String": {
+                    "value": "cost is $5",
+                    "raw": "cost is \\$5"
+                  }
                 },
                 "span": {
                   "start": 11,
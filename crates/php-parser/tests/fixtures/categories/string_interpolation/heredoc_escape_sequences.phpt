@@ -36,9 +36,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\n\\r\\t\\v\\e\\f",
                     "parts": [
                       {
-                        "Literal": "\n\r\t\u000b\u001b\f"
+                        "Literal": {
+                          "value": "\n\r\t\u000b\u001b\f",
+                          "span": {
+                            "start": 18,
+                            "end": 30
+                          }
+                        }
                       }
                     ]
                   }
@@ -80,9 +87,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\\\",
                     "parts": [
                       {
-                        "Literal": "\\"
+                        "Literal": {
+                          "value": "\\",
+                          "span": {
+                            "start": 48,
+                            "end": 50
+                          }
+                        }
                       }
                     ]
                   }
@@ -124,9 +138,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\$dollar",
                     "parts": [
                       {
-                        "Literal": "$dollar"
+                        "Literal": {
+                          "value": "$dollar",
+                          "span": {
+                            "start": 68,
+                            "end": 76
+                          }
+                        }
                       }
                     ]
                   }
@@ -168,9 +189,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Mixed \\n newline \\u{41} unicode",
                     "parts": [
                       {
-                        "Literal": "Mixed \n newline A unicode"
+                        "Literal": {
+                          "value": "Mixed \n newline A unicode",
+                          "span": {
+                            "start": 94,
+                            "end": 125
+                          }
+                        }
                       }
                     ]
                   }
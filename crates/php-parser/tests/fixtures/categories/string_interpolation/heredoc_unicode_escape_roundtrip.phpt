@@ -33,9 +33,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\u{41}\\u{42}\\u{43}",
                     "parts": [
                       {
-                        "Literal": "ABC"
+                        "Literal": {
+                          "value": "ABC",
+                          "span": {
+                            "start": 18,
+                            "end": 36
+                          }
+                        }
                       }
                     ]
                   }
@@ -77,9 +84,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\u{7E}",
                     "parts": [
                       {
-                        "Literal": "~"
+                        "Literal": {
+                          "value": "~",
+                          "span": {
+                            "start": 54,
+                            "end": 60
+                          }
+                        }
                       }
                     ]
                   }
@@ -121,9 +135,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Normal text \\u{41} with escape",
                     "parts": [
                       {
-                        "Literal": "Normal text A with escape"
+                        "Literal": {
+                          "value": "Normal text A with escape",
+                          "span": {
+                            "start": 78,
+                            "end": 108
+                          }
+                        }
                       }
                     ]
                   }
@@ -275,7 +275,10 @@
                       },
                       "index": {
                         "kind": {
-                          "String": "-0"
+                          "String": {
+                            "value": "-0",
+                            "raw": "-0"
+                          }
                         },
                         "span": {
                           "start": 187,
@@ -323,7 +326,10 @@
                       },
                       "index": {
                         "kind": {
-                          "String": "-00"
+                          "String": {
+                            "value": "-00",
+                            "raw": "-00"
+                          }
                         },
                         "span": {
                           "start": 197,
@@ -371,7 +377,10 @@
                       },
                       "index": {
                         "kind": {
-                          "String": "00"
+                          "String": {
+                            "value": "00",
+                            "raw": "00"
+                          }
                         },
                         "span": {
                           "start": 208,
@@ -419,7 +428,10 @@
                       },
                       "index": {
                         "kind": {
-                          "String": "07"
+                          "String": {
+                            "value": "07",
+                            "raw": "07"
+                          }
                         },
                         "span": {
                           "start": 218,
@@ -467,7 +479,10 @@
                       },
                       "index": {
                         "kind": {
-                          "String": "-0x0"
+                          "String": {
+                            "value": "-0x0",
+                            "raw": "-0x0"
+                          }
                         },
                         "span": {
                           "start": 228,
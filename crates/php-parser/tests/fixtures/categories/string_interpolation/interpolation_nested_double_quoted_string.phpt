@@ -23,7 +23,13 @@ $x = "URI: {$_SERVER["REQUEST_URI"]}";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "URI: "
+                      "Literal": {
+                        "value": "URI: ",
+                        "span": {
+                          "start": 12,
+                          "end": 17
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -40,7 +46,10 @@ $x = "URI: {$_SERVER["REQUEST_URI"]}";
                             },
                             "index": {
                               "kind": {
-                                "String": "REQUEST_URI"
+                                "String": {
+                                  "value": "REQUEST_URI",
+                                  "raw": "REQUEST_URI"
+                                }
                               },
                               "span": {
                                 "start": 27,
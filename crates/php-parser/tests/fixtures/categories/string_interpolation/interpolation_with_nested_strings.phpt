@@ -42,7 +42,10 @@ $c = "x: {$map[$inner["nested"]]}";
                                   },
                                   "index": {
                                     "kind": {
-                                      "String": "outer"
+                                      "String": {
+                                        "value": "outer",
+                                        "raw": "outer"
+                                      }
                                     },
                                     "span": {
                                       "start": 18,
@@ -58,7 +61,10 @@ $c = "x: {$map[$inner["nested"]]}";
                             },
                             "index": {
                               "kind": {
-                                "String": "inner"
+                                "String": {
+                                  "value": "inner",
+                                  "raw": "inner"
+                                }
                               },
                               "span": {
                                 "start": 27,
@@ -112,7 +118,13 @@ $c = "x: {$map[$inner["nested"]]}";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "prefix "
+                      "Literal": {
+                        "value": "prefix ",
+                        "span": {
+                          "start": 45,
+                          "end": 52
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -129,7 +141,10 @@ $c = "x: {$map[$inner["nested"]]}";
                             },
                             "index": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 58,
@@ -183,7 +198,13 @@ $c = "x: {$map[$inner["nested"]]}";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "x: "
+                      "Literal": {
+                        "value": "x: ",
+                        "span": {
+                          "start": 74,
+                          "end": 77
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -212,7 +233,10 @@ $c = "x: {$map[$inner["nested"]]}";
                                   },
                                   "index": {
                                     "kind": {
-                                      "String": "nested"
+                                      "String": {
+                                        "value": "nested",
+                                        "raw": "nested"
+                                      }
                                     },
                                     "span": {
                                       "start": 90,
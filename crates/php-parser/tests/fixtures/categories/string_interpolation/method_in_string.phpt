@@ -22,7 +22,13 @@
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Name: "
+                      "Literal": {
+                        "value": "Name: ",
+                        "span": {
+                          "start": 12,
+                          "end": 18
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -22,7 +22,13 @@
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Value: "
+                      "Literal": {
+                        "value": "Value: ",
+                        "span": {
+                          "start": 12,
+                          "end": 19
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -39,7 +45,10 @@
                             },
                             "index": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 25,
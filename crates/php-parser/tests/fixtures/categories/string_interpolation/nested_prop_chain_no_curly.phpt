@@ -22,7 +22,13 @@
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "val "
+                      "Literal": {
+                        "value": "val ",
+                        "span": {
+                          "start": 12,
+                          "end": 16
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -55,7 +61,13 @@
                       }
                     },
                     {
-                      "Literal": "->b end"
+                      "Literal": {
+                        "value": "->b end",
+                        "span": {
+                          "start": 23,
+                          "end": 30
+                        }
+                      }
                     }
                   ]
                 },
@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "\n\r\t\u000b\u001b\f\\$\""
+                  "String": {
+                    "value": "\n\r\t\u000b\u001b\f\\$\"",
+                    "raw": "\\n\\r\\t\\v\\e\\f\\\\\\$\\\""
+                  }
                 },
                 "span": {
                   "start": 11,
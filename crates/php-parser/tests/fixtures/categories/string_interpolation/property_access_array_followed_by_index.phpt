@@ -56,7 +56,10 @@ echo "{$obj->items[0]}";
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 45,
@@ -67,13 +70,20 @@ echo "{$obj->items[0]}";
                           "span": {
                             "start": 45,
                             "end": 48
+                          },
+                          "comma": {
+                            "start": 48,
+                            "end": 49
                           }
                         },
                         {
                           "key": null,
                           "value": {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 50,
@@ -129,12 +139,15 @@ echo "{$obj->items[0]}";
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 69,
-                        "end": 72
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 69,
+                          "end": 72
+                        }
                       }
                     },
                     "args": []
@@ -160,51 +173,59 @@ echo "{$obj->items[0]}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Expr": {
-                    "kind": {
-                      "PropertyAccess": {
-                        "object": {
-                          "kind": {
-                            "Variable": "obj"
-                          },
-                          "span": {
-                            "start": 153,
-                            "end": 157
-                          }
-                        },
-                        "property": {
-                          "kind": {
-                            "Identifier": "items"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Expr": {
+                      "kind": {
+                        "PropertyAccess": {
+                          "object": {
+                            "kind": {
+                              "Variable": "obj"
+                            },
+                            "span": {
+                              "start": 153,
+                              "end": 157
+                            }
                           },
-                          "span": {
-                            "start": 159,
-                            "end": 164
+                          "property": {
+                            "kind": {
+                              "Identifier": "items"
+                            },
+                            "span": {
+                              "start": 159,
+                              "end": 164
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 153,
+                        "end": 164
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": "[0]",
+                      "span": {
+                        "start": 164,
+                        "end": 167
                       }
-                    },
-                    "span": {
-                      "start": 153,
-                      "end": 164
                     }
                   }
-                },
-                {
-                  "Literal": "[0]"
-                }
-              ]
-            },
-            "span": {
-              "start": 152,
-              "end": 168
+                ]
+              },
+              "span": {
+                "start": 152,
+                "end": 168
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 147,
@@ -213,67 +234,69 @@ echo "{$obj->items[0]}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Expr": {
-                    "kind": {
-                      "ArrayAccess": {
-                        "array": {
-                          "kind": {
-                            "PropertyAccess": {
-                              "object": {
-                                "kind": {
-                                  "Variable": "obj"
-                                },
-                                "span": {
-                                  "start": 231,
-                                  "end": 235
-                                }
-                              },
-                              "property": {
-                                "kind": {
-                                  "Identifier": "items"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Expr": {
+                      "kind": {
+                        "ArrayAccess": {
+                          "array": {
+                            "kind": {
+                              "PropertyAccess": {
+                                "object": {
+                                  "kind": {
+                                    "Variable": "obj"
+                                  },
+                                  "span": {
+                                    "start": 231,
+                                    "end": 235
+                                  }
                                 },
-                                "span": {
-                                  "start": 237,
-                                  "end": 242
+                                "property": {
+                                  "kind": {
+                                    "Identifier": "items"
+                                  },
+                                  "span": {
+                                    "start": 237,
+                                    "end": 242
+                                  }
                                 }
                               }
+                            },
+                            "span": {
+                              "start": 231,
+                              "end": 242
                             }
                           },
-                          "span": {
-                            "start": 231,
-                            "end": 242
-                          }
-                        },
-                        "index": {
-                          "kind": {
-                            "Int": 0
-                          },
-                          "span": {
-                            "start": 243,
-                            "end": 244
+                          "index": {
+                            "kind": {
+                              "Int": 0
+                            },
+                            "span": {
+                              "start": 243,
+                              "end": 244
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 231,
+                        "end": 245
                       }
-                    },
-                    "span": {
-                      "start": 231,
-                      "end": 245
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 229,
-              "end": 247
+                ]
+              },
+              "span": {
+                "start": 229,
+                "end": 247
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 224,
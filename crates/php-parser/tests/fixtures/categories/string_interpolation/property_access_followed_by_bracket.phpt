@@ -48,7 +48,10 @@ echo "$obj->parameterName[]";
                   },
                   "default": {
                     "kind": {
-                      "String": "test"
+                      "String": {
+                        "value": "test",
+                        "raw": "test"
+                      }
                     },
                     "span": {
                       "start": 53,
@@ -91,12 +94,15 @@ echo "$obj->parameterName[]";
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 74,
-                        "end": 77
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 74,
+                          "end": 77
+                        }
                       }
                     },
                     "args": []
@@ -122,51 +128,59 @@ echo "$obj->parameterName[]";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Expr": {
-                    "kind": {
-                      "PropertyAccess": {
-                        "object": {
-                          "kind": {
-                            "Variable": "obj"
-                          },
-                          "span": {
-                            "start": 87,
-                            "end": 91
-                          }
-                        },
-                        "property": {
-                          "kind": {
-                            "Identifier": "parameterName"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Expr": {
+                      "kind": {
+                        "PropertyAccess": {
+                          "object": {
+                            "kind": {
+                              "Variable": "obj"
+                            },
+                            "span": {
+                              "start": 87,
+                              "end": 91
+                            }
                           },
-                          "span": {
-                            "start": 93,
-                            "end": 106
+                          "property": {
+                            "kind": {
+                              "Identifier": "parameterName"
+                            },
+                            "span": {
+                              "start": 93,
+                              "end": 106
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 87,
+                        "end": 106
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": "[]",
+                      "span": {
+                        "start": 106,
+                        "end": 108
                       }
-                    },
-                    "span": {
-                      "start": 87,
-                      "end": 106
                     }
                   }
-                },
-                {
-                  "Literal": "[]"
-                }
-              ]
-            },
-            "span": {
-              "start": 86,
-              "end": 109
+                ]
+              },
+              "span": {
+                "start": 86,
+                "end": 109
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 81,
@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "😂"
+                  "String": {
+                    "value": "😂",
+                    "raw": "\\u{1F602}"
+                  }
                 },
                 "span": {
                   "start": 11,
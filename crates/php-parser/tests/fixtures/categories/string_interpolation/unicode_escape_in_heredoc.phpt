@@ -24,9 +24,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "\\u{41}",
                     "parts": [
                       {
-                        "Literal": "A"
+                        "Literal": {
+                          "value": "A",
+                          "span": {
+                            "start": 18,
+                            "end": 24
+                          }
+                        }
                       }
                     ]
                   }
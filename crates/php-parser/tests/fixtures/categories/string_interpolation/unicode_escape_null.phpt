@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "\u0000"
+                  "String": {
+                    "value": "\u0000",
+                    "raw": "\\u{0}"
+                  }
                 },
                 "span": {
                   "start": 11,
@@ -20,7 +20,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "􏿿"
+                  "String": {
+                    "value": "􏿿",
+                    "raw": "\\u{10FFFF}"
+                  }
                 },
                 "span": {
                   "start": 11,
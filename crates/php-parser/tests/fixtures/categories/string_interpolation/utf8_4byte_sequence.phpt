@@ -22,7 +22,10 @@ echo "Math alphanumeric: $𝕾𝖈𝖔𝖕𝖙";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "4byte"
+                  "String": {
+                    "value": "4byte",
+                    "raw": "4byte"
+                  }
                 },
                 "span": {
                   "start": 30,
@@ -44,32 +47,40 @@ echo "Math alphanumeric: $𝕾𝖈𝖔𝖕𝖙";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Math alphanumeric: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "𝕾𝖈𝖔𝖕𝖙"
-                    },
-                    "span": {
-                      "start": 64,
-                      "end": 85
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Math alphanumeric: ",
+                      "span": {
+                        "start": 45,
+                        "end": 64
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "𝕾𝖈𝖔𝖕𝖙"
+                      },
+                      "span": {
+                        "start": 64,
+                        "end": 85
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 44,
-              "end": 86
+                ]
+              },
+              "span": {
+                "start": 44,
+                "end": 86
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 39,
@@ -22,7 +22,10 @@ $结果 = `$命令`;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "ls -la"
+                  "String": {
+                    "value": "ls -la",
+                    "raw": "ls -la"
+                  }
                 },
                 "span": {
                   "start": 16,
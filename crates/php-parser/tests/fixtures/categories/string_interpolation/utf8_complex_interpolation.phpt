@@ -31,7 +31,10 @@ echo "Complex: {$объект->свойство}";
                   "type_hint": null,
                   "default": {
                     "kind": {
-                      "String": "prop"
+                      "String": {
+                        "value": "prop",
+                        "raw": "prop"
+                      }
                     },
                     "span": {
                       "start": 56,
@@ -74,12 +77,15 @@ echo "Complex: {$объект->свойство}";
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Класс"
-                      },
-                      "span": {
-                        "start": 86,
-                        "end": 96
+                      "Name": {
+                        "parts": [
+                          "Класс"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 86,
+                          "end": 96
+                        }
                       }
                     },
                     "args": []
@@ -105,51 +111,59 @@ echo "Complex: {$объект->свойство}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Complex: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "PropertyAccess": {
-                        "object": {
-                          "kind": {
-                            "Variable": "объект"
-                          },
-                          "span": {
-                            "start": 116,
-                            "end": 129
-                          }
-                        },
-                        "property": {
-                          "kind": {
-                            "Identifier": "свойство"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Complex: ",
+                      "span": {
+                        "start": 106,
+                        "end": 115
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "PropertyAccess": {
+                          "object": {
+                            "kind": {
+                              "Variable": "объект"
+                            },
+                            "span": {
+                              "start": 116,
+                              "end": 129
+                            }
                           },
-                          "span": {
-                            "start": 131,
-                            "end": 147
+                          "property": {
+                            "kind": {
+                              "Identifier": "свойство"
+                            },
+                            "span": {
+                              "start": 131,
+                              "end": 147
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 116,
+                        "end": 147
                       }
-                    },
-                    "span": {
-                      "start": 116,
-                      "end": 147
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 105,
-              "end": 149
+                ]
+              },
+              "span": {
+                "start": 105,
+                "end": 149
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 100,
@@ -24,7 +24,10 @@ echo "Vars: $α$β$γ";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "alpha"
+                  "String": {
+                    "value": "alpha",
+                    "raw": "alpha"
+                  }
                 },
                 "span": {
                   "start": 12,
@@ -61,7 +64,10 @@ echo "Vars: $α$β$γ";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "beta"
+                  "String": {
+                    "value": "beta",
+                    "raw": "beta"
+                  }
                 },
                 "span": {
                   "start": 27,
@@ -98,7 +104,10 @@ echo "Vars: $α$β$γ";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "gamma"
+                  "String": {
+                    "value": "gamma",
+                    "raw": "gamma"
+                  }
                 },
                 "span": {
                   "start": 41,
@@ -120,54 +129,62 @@ echo "Vars: $α$β$γ";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Vars: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "α"
-                    },
-                    "span": {
-                      "start": 62,
-                      "end": 65
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Vars: ",
+                      "span": {
+                        "start": 56,
+                        "end": 62
+                      }
                     }
-                  }
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "β"
-                    },
-                    "span": {
-                      "start": 65,
-                      "end": 68
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "α"
+                      },
+                      "span": {
+                        "start": 62,
+                        "end": 65
+                      }
                     }
-                  }
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "γ"
-                    },
-                    "span": {
-                      "start": 68,
-                      "end": 71
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "β"
+                      },
+                      "span": {
+                        "start": 65,
+                        "end": 68
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "γ"
+                      },
+                      "span": {
+                        "start": 68,
+                        "end": 71
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 55,
-              "end": 72
+                ]
+              },
+              "span": {
+                "start": 55,
+                "end": 72
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 50,
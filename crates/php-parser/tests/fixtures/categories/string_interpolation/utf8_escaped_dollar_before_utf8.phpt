@@ -22,7 +22,10 @@ echo "Not a var: \$переменная is escaped";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "value"
+                  "String": {
+                    "value": "value",
+                    "raw": "value"
+                  }
                 },
                 "span": {
                   "start": 30,
@@ -44,17 +47,22 @@ echo "Not a var: \$переменная is escaped";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "Not a var: $переменная is escaped"
-            },
-            "span": {
-              "start": 44,
-              "end": 90
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "Not a var: $переменная is escaped",
+                  "raw": "Not a var: \\$переменная is escaped"
+                }
+              },
+              "span": {
+                "start": 44,
+                "end": 90
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 39,
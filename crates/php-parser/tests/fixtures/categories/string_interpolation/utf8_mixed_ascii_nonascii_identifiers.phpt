@@ -27,7 +27,10 @@ echo "Complex: {$naïve}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "simple"
+                  "String": {
+                    "value": "simple",
+                    "raw": "simple"
+                  }
                 },
                 "span": {
                   "start": 16,
@@ -64,7 +67,10 @@ echo "Complex: {$naïve}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "drink"
+                  "String": {
+                    "value": "drink",
+                    "raw": "drink"
+                  }
                 },
                 "span": {
                   "start": 35,
@@ -101,7 +107,10 @@ echo "Complex: {$naïve}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "cold"
+                  "String": {
+                    "value": "cold",
+                    "raw": "cold"
+                  }
                 },
                 "span": {
                   "start": 52,
@@ -123,32 +132,40 @@ echo "Complex: {$naïve}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Simple: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "naïve"
-                    },
-                    "span": {
-                      "start": 74,
-                      "end": 81
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Simple: ",
+                      "span": {
+                        "start": 66,
+                        "end": 74
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "naïve"
+                      },
+                      "span": {
+                        "start": 74,
+                        "end": 81
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 65,
-              "end": 82
+                ]
+              },
+              "span": {
+                "start": 65,
+                "end": 82
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 60,
@@ -157,32 +174,40 @@ echo "Complex: {$naïve}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Dollar-brace ascii-start: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "café"
-                    },
-                    "span": {
-                      "start": 118,
-                      "end": 123
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Dollar-brace ascii-start: ",
+                      "span": {
+                        "start": 90,
+                        "end": 116
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "café"
+                      },
+                      "span": {
+                        "start": 118,
+                        "end": 123
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 89,
-              "end": 125
+                ]
+              },
+              "span": {
+                "start": 89,
+                "end": 125
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 84,
@@ -191,32 +216,40 @@ echo "Complex: {$naïve}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Dollar-brace nonascii-start: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "ïce"
-                    },
-                    "span": {
-                      "start": 164,
-                      "end": 168
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Dollar-brace nonascii-start: ",
+                      "span": {
+                        "start": 133,
+                        "end": 162
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "ïce"
+                      },
+                      "span": {
+                        "start": 164,
+                        "end": 168
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 132,
-              "end": 170
+                ]
+              },
+              "span": {
+                "start": 132,
+                "end": 170
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 127,
@@ -225,32 +258,40 @@ echo "Complex: {$naïve}";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Complex: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "naïve"
-                    },
-                    "span": {
-                      "start": 188,
-                      "end": 195
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Complex: ",
+                      "span": {
+                        "start": 178,
+                        "end": 187
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "naïve"
+                      },
+                      "span": {
+                        "start": 188,
+                        "end": 195
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 177,
-              "end": 197
+                ]
+              },
+              "span": {
+                "start": 177,
+                "end": 197
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 172,
@@ -23,7 +23,10 @@ echo "Names: $name and $имя together";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Alice"
+                  "String": {
+                    "value": "Alice",
+                    "raw": "Alice"
+                  }
                 },
                 "span": {
                   "start": 14,
@@ -60,7 +63,10 @@ echo "Names: $name and $имя together";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Bob"
+                  "String": {
+                    "value": "Bob",
+                    "raw": "Bob"
+                  }
                 },
                 "span": {
                   "start": 33,
@@ -82,49 +88,69 @@ echo "Names: $name and $имя together";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Names: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "name"
-                    },
-                    "span": {
-                      "start": 53,
-                      "end": 58
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Names: ",
+                      "span": {
+                        "start": 46,
+                        "end": 53
+                      }
                     }
-                  }
-                },
-                {
-                  "Literal": " and "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "имя"
-                    },
-                    "span": {
-                      "start": 63,
-                      "end": 70
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "name"
+                      },
+                      "span": {
+                        "start": 53,
+                        "end": 58
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": " and ",
+                      "span": {
+                        "start": 58,
+                        "end": 63
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "имя"
+                      },
+                      "span": {
+                        "start": 63,
+                        "end": 70
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": " together",
+                      "span": {
+                        "start": 70,
+                        "end": 79
+                      }
                     }
                   }
-                },
-                {
-                  "Literal": " together"
-                }
-              ]
-            },
-            "span": {
-              "start": 45,
-              "end": 80
+                ]
+              },
+              "span": {
+                "start": 45,
+                "end": 80
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 40,
@@ -26,7 +26,10 @@ echo "Value: $массив[ключ]";
                     {
                       "key": {
                         "kind": {
-                          "String": "ключ"
+                          "String": {
+                            "value": "ключ",
+                            "raw": "ключ"
+                          }
                         },
                         "span": {
                           "start": 23,
@@ -35,7 +38,10 @@ echo "Value: $массив[ключ]";
                       },
                       "value": {
                         "kind": {
-                          "String": "значение"
+                          "String": {
+                            "value": "значение",
+                            "raw": "значение"
+                          }
                         },
                         "span": {
                           "start": 37,
@@ -70,51 +76,62 @@ echo "Value: $массив[ключ]";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "ArrayAccess": {
-                        "array": {
-                          "kind": {
-                            "Variable": "массив"
-                          },
-                          "span": {
-                            "start": 71,
-                            "end": 84
-                          }
-                        },
-                        "index": {
-                          "kind": {
-                            "String": "ключ"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 64,
+                        "end": 71
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "ArrayAccess": {
+                          "array": {
+                            "kind": {
+                              "Variable": "массив"
+                            },
+                            "span": {
+                              "start": 71,
+                              "end": 84
+                            }
                           },
-                          "span": {
-                            "start": 85,
-                            "end": 93
+                          "index": {
+                            "kind": {
+                              "String": {
+                                "value": "ключ",
+                                "raw": "ключ"
+                              }
+                            },
+                            "span": {
+                              "start": 85,
+                              "end": 93
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 71,
+                        "end": 94
                       }
-                    },
-                    "span": {
-                      "start": 71,
-                      "end": 94
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 63,
-              "end": 95
+                ]
+              },
+              "span": {
+                "start": 63,
+                "end": 95
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 58,
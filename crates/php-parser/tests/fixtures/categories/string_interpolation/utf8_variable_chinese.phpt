@@ -22,7 +22,10 @@ echo "Value: $变量";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "chinese"
+                  "String": {
+                    "value": "chinese",
+                    "raw": "chinese"
+                  }
                 },
                 "span": {
                   "start": 16,
@@ -44,32 +47,40 @@ echo "Value: $变量";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "变量"
-                    },
-                    "span": {
-                      "start": 40,
-                      "end": 47
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 33,
+                        "end": 40
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "变量"
+                      },
+                      "span": {
+                        "start": 40,
+                        "end": 47
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 32,
-              "end": 48
+                ]
+              },
+              "span": {
+                "start": 32,
+                "end": 48
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 27,
@@ -23,7 +23,10 @@ echo "Value: $название and $город end";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Russian"
+                  "String": {
+                    "value": "Russian",
+                    "raw": "Russian"
+                  }
                 },
                 "span": {
                   "start": 26,
@@ -60,7 +63,10 @@ echo "Value: $название and $город end";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Moscow"
+                  "String": {
+                    "value": "Moscow",
+                    "raw": "Moscow"
+                  }
                 },
                 "span": {
                   "start": 51,
@@ -82,49 +88,69 @@ echo "Value: $название and $город end";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "название"
-                    },
-                    "span": {
-                      "start": 74,
-                      "end": 91
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 67,
+                        "end": 74
+                      }
                     }
-                  }
-                },
-                {
-                  "Literal": " and "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "город"
-                    },
-                    "span": {
-                      "start": 96,
-                      "end": 107
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "название"
+                      },
+                      "span": {
+                        "start": 74,
+                        "end": 91
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": " and ",
+                      "span": {
+                        "start": 91,
+                        "end": 96
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "город"
+                      },
+                      "span": {
+                        "start": 96,
+                        "end": 107
+                      }
+                    }
+                  },
+                  {
+                    "Literal": {
+                      "value": " end",
+                      "span": {
+                        "start": 107,
+                        "end": 111
+                      }
                     }
                   }
-                },
-                {
-                  "Literal": " end"
-                }
-              ]
-            },
-            "span": {
-              "start": 66,
-              "end": 112
+                ]
+              },
+              "span": {
+                "start": 66,
+                "end": 112
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 61,
@@ -22,7 +22,10 @@ echo "Value: $μεταβλητή";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "greek"
+                  "String": {
+                    "value": "greek",
+                    "raw": "greek"
+                  }
                 },
                 "span": {
                   "start": 28,
@@ -44,32 +47,40 @@ echo "Value: $μεταβλητή";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "μεταβλητή"
-                    },
-                    "span": {
-                      "start": 50,
-                      "end": 69
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 43,
+                        "end": 50
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "μεταβλητή"
+                      },
+                      "span": {
+                        "start": 50,
+                        "end": 69
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 42,
-              "end": 70
+                ]
+              },
+              "span": {
+                "start": 42,
+                "end": 70
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 37,
@@ -24,7 +24,10 @@ EOT;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "heredoc"
+                  "String": {
+                    "value": "heredoc",
+                    "raw": "heredoc"
+                  }
                 },
                 "span": {
                   "start": 30,
@@ -63,9 +66,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Значение: $переменная конец",
                     "parts": [
                       {
-                        "Literal": "Значение: "
+                        "Literal": {
+                          "value": "Значение: ",
+                          "span": {
+                            "start": 53,
+                            "end": 71
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -79,7 +89,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": " конец"
+                        "Literal": {
+                          "value": " конец",
+                          "span": {
+                            "start": 92,
+                            "end": 103
+                          }
+                        }
                       }
                     ]
                   }
@@ -31,7 +31,10 @@ echo "Value: $объект->свойство";
                   "type_hint": null,
                   "default": {
                     "kind": {
-                      "String": "property"
+                      "String": {
+                        "value": "property",
+                        "raw": "property"
+                      }
                     },
                     "span": {
                       "start": 58,
@@ -74,12 +77,15 @@ echo "Value: $объект->свойство";
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Объект"
-                      },
-                      "span": {
-                        "start": 92,
-                        "end": 104
+                      "Name": {
+                        "parts": [
+                          "Объект"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 92,
+                          "end": 104
+                        }
                       }
                     },
                     "args": []
@@ -105,51 +111,59 @@ echo "Value: $объект->свойство";
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "PropertyAccess": {
-                        "object": {
-                          "kind": {
-                            "Variable": "объект"
-                          },
-                          "span": {
-                            "start": 121,
-                            "end": 134
-                          }
-                        },
-                        "property": {
-                          "kind": {
-                            "Identifier": "свойство"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 114,
+                        "end": 121
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "PropertyAccess": {
+                          "object": {
+                            "kind": {
+                              "Variable": "объект"
+                            },
+                            "span": {
+                              "start": 121,
+                              "end": 134
+                            }
                           },
-                          "span": {
-                            "start": 136,
-                            "end": 152
+                          "property": {
+                            "kind": {
+                              "Identifier": "свойство"
+                            },
+                            "span": {
+                              "start": 136,
+                              "end": 152
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 121,
+                        "end": 152
                       }
-                    },
-                    "span": {
-                      "start": 121,
-                      "end": 152
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 113,
-              "end": 153
+                ]
+              },
+              "span": {
+                "start": 113,
+                "end": 153
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 108,
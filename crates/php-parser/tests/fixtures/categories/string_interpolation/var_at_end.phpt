@@ -22,7 +22,13 @@
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "hello "
+                      "Literal": {
+                        "value": "hello ",
+                        "span": {
+                          "start": 12,
+                          "end": 18
+                        }
+                      }
                     },
                     {
                       "Expr": {
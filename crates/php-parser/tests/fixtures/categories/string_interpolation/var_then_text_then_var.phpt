@@ -33,7 +33,13 @@
                       }
                     },
                     {
-                      "Literal": "-"
+                      "Literal": {
+                        "value": "-",
+                        "span": {
+                          "start": 14,
+                          "end": 15
+                        }
+                      }
                     },
                     {
                       "Expr": {
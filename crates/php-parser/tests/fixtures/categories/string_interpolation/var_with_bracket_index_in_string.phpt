@@ -52,7 +52,13 @@
                       }
                     },
                     {
-                      "Literal": " end"
+                      "Literal": {
+                        "value": " end",
+                        "span": {
+                          "start": 19,
+                          "end": 23
+                        }
+                      }
                     }
                   ]
                 },
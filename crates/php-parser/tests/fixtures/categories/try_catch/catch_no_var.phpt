@@ -14,7 +14,16 @@
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 15
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -40,13 +49,15 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 28,
-                    "end": 37
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 28,
+                      "end": 37
+                    }
                   }
                 }
               ],
@@ -54,17 +65,22 @@
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "error"
-                        },
-                        "span": {
-                          "start": 46,
-                          "end": 53
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "error",
+                              "raw": "error"
+                            }
+                          },
+                          "span": {
+                            "start": 46,
+                            "end": 53
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 41,
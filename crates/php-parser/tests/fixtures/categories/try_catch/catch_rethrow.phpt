@@ -14,7 +14,16 @@
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 15
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -40,17 +49,25 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 28,
-                    "end": 37
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 28,
+                      "end": 37
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 38,
+                  "end": 40
+                }
+              },
               "body": [
                 {
                   "kind": {
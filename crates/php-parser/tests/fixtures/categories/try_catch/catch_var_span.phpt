@@ -0,0 +1,92 @@
+===description===
+The catch variable records its span, `$` included, and each `|`-separated
+type is listed in order.
+===source===
+<?php
+try {} catch (A | \B\C $error) {} catch (D) {}
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "TryCatch": {
+          "body": [],
+          "catches": [
+            {
+              "types": [
+                {
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 20,
+                      "end": 21
+                    }
+                  }
+                },
+                {
+                  "Name": {
+                    "parts": [
+                      "B",
+                      "C"
+                    ],
+                    "kind": "FullyQualified",
+                    "span": {
+                      "start": 24,
+                      "end": 28
+                    }
+                  }
+                }
+              ],
+              "var": {
+                "name": "error",
+                "span": {
+                  "start": 29,
+                  "end": 35
+                }
+              },
+              "body": [],
+              "span": {
+                "start": 19,
+                "end": 39
+              }
+            },
+            {
+              "types": [
+                {
+                  "Name": {
+                    "parts": [
+                      "D"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 47,
+                      "end": 48
+                    }
+                  }
+                }
+              ],
+              "var": null,
+              "body": [],
+              "span": {
+                "start": 46,
+                "end": 52
+              }
+            }
+          ],
+          "finally": null
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 52
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 52
+  }
+}
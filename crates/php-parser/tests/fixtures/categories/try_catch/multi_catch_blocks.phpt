@@ -14,7 +14,16 @@
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 15
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -40,17 +49,25 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "A"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 28,
-                    "end": 29
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 28,
+                      "end": 29
+                    }
                   }
                 }
               ],
-              "var": "a",
+              "var": {
+                "name": "a",
+                "span": {
+                  "start": 30,
+                  "end": 32
+                }
+              },
               "body": [],
               "span": {
                 "start": 27,
@@ -60,17 +77,25 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "B"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 45,
-                    "end": 46
+                  "Name": {
+                    "parts": [
+                      "B"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 45,
+                      "end": 46
+                    }
                   }
                 }
               ],
-              "var": "b",
+              "var": {
+                "name": "b",
+                "span": {
+                  "start": 47,
+                  "end": 49
+                }
+              },
               "body": [],
               "span": {
                 "start": 44,
@@ -80,17 +105,25 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "C"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 62,
-                    "end": 63
+                  "Name": {
+                    "parts": [
+                      "C"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 62,
+                      "end": 63
+                    }
                   }
                 }
               ],
-              "var": "c",
+              "var": {
+                "name": "c",
+                "span": {
+                  "start": 64,
+                  "end": 66
+                }
+              },
               "body": [],
               "span": {
                 "start": 61,
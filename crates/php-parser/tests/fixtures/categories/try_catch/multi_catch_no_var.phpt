@@ -14,7 +14,16 @@
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 15
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -40,23 +49,27 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "TypeError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 28,
-                    "end": 37
+                  "Name": {
+                    "parts": [
+                      "TypeError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 28,
+                      "end": 37
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "ValueError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 40,
-                    "end": 50
+                  "Name": {
+                    "parts": [
+                      "ValueError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 40,
+                      "end": 50
+                    }
                   }
                 }
               ],
@@ -69,7 +82,16 @@
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "log"
+                              "Name": {
+                                "parts": [
+                                  "log"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 54,
+                                  "end": 57
+                                }
+                              }
                             },
                             "span": {
                               "start": 54,
@@ -14,7 +14,16 @@
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 15
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -40,41 +49,53 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "TypeError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 28,
-                    "end": 37
+                  "Name": {
+                    "parts": [
+                      "TypeError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 28,
+                      "end": 37
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "ValueError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 40,
-                    "end": 50
+                  "Name": {
+                    "parts": [
+                      "ValueError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 40,
+                      "end": 50
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 51,
+                  "end": 53
+                }
+              },
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "e"
-                        },
-                        "span": {
-                          "start": 62,
-                          "end": 64
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "e"
+                          },
+                          "span": {
+                            "start": 62,
+                            "end": 64
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 57,
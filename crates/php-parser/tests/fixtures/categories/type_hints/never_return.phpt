@@ -15,12 +15,15 @@
                   "kind": {
                     "New": {
                       "class": {
-                        "kind": {
-                          "Identifier": "Exception"
-                        },
-                        "span": {
-                          "start": 42,
-                          "end": 51
+                        "Name": {
+                          "parts": [
+                            "Exception"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 42,
+                            "end": 51
+                          }
                         }
                       },
                       "args": []
@@ -43,7 +43,10 @@ min_php=8.3
                   },
                   "value": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 41,
@@ -51,17 +51,22 @@ if ($x === 1) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "one"
-                        },
-                        "span": {
-                          "start": 31,
-                          "end": 36
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
+                          },
+                          "span": {
+                            "start": 31,
+                            "end": 36
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 26,
@@ -111,17 +116,22 @@ if ($x === 1) {
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "two"
-                            },
-                            "span": {
-                              "start": 69,
-                              "end": 74
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "two",
+                                  "raw": "two"
+                                }
+                              },
+                              "span": {
+                                "start": 69,
+                                "end": 74
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 64,
@@ -175,17 +185,22 @@ if ($x === 1) {
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "three"
-                            },
-                            "span": {
-                              "start": 107,
-                              "end": 114
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "three",
+                                  "raw": "three"
+                                }
+                              },
+                              "span": {
+                                "start": 107,
+                                "end": 114
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 102,
@@ -239,17 +254,22 @@ if ($x === 1) {
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "four"
-                            },
-                            "span": {
-                              "start": 147,
-                              "end": 153
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "four",
+                                  "raw": "four"
+                                }
+                              },
+                              "span": {
+                                "start": 147,
+                                "end": 153
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 142,
@@ -274,17 +294,22 @@ if ($x === 1) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "other"
-                        },
-                        "span": {
-                          "start": 173,
-                          "end": 180
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
+                          },
+                          "span": {
+                            "start": 173,
+                            "end": 180
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 168,
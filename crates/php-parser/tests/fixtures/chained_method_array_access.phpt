@@ -117,7 +117,10 @@
                     },
                     "index": {
                       "kind": {
-                        "String": "key"
+                        "String": {
+                          "value": "key",
+                          "raw": "key"
+                        }
                       },
                       "span": {
                         "start": 41,
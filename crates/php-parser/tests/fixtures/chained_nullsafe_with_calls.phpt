@@ -176,6 +176,10 @@ $z = $a?->items[0]?->name;
                               "span": {
                                 "start": 51,
                                 "end": 52
+                              },
+                              "comma": {
+                                "start": 52,
+                                "end": 53
                               }
                             },
                             {
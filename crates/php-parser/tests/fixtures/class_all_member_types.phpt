@@ -306,6 +306,10 @@ abstract class Complete {
                       "span": {
                         "start": 270,
                         "end": 282
+                      },
+                      "comma": {
+                        "start": 282,
+                        "end": 283
                       }
                     },
                     {
@@ -444,10 +448,7 @@ abstract class Complete {
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "self"
-                                },
-                                "span": {
+                                "SelfKw": {
                                   "start": 413,
                                   "end": 417
                                 }
@@ -457,7 +458,10 @@ abstract class Complete {
                                   "name": null,
                                   "value": {
                                     "kind": {
-                                      "String": "default"
+                                      "String": {
+                                        "value": "default",
+                                        "raw": "default"
+                                      }
                                     },
                                     "span": {
                                       "start": 418,
@@ -231,7 +231,10 @@ class User extends Model implements Loggable, Serializable {
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": ""
+                            "String": {
+                              "value": "",
+                              "raw": ""
+                            }
                           },
                           "span": {
                             "start": 283,
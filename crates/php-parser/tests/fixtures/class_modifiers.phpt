@@ -186,6 +186,10 @@ readonly class Value {
                       "span": {
                         "start": 202,
                         "end": 221
+                      },
+                      "comma": {
+                        "start": 221,
+                        "end": 222
                       }
                     },
                     {
@@ -219,6 +223,10 @@ readonly class Value {
                       "span": {
                         "start": 231,
                         "end": 246
+                      },
+                      "comma": {
+                        "start": 246,
+                        "end": 247
                       }
                     }
                   ],
@@ -9,12 +9,15 @@
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 9
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 9
+                  }
                 }
               },
               "member": {
@@ -45,10 +48,7 @@
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "self"
-                },
-                "span": {
+                "SelfKw": {
                   "start": 18,
                   "end": 22
                 }
@@ -81,10 +81,7 @@
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 31,
                   "end": 37
                 }
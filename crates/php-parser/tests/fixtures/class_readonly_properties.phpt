@@ -134,6 +134,10 @@ class Point {
                       "span": {
                         "start": 112,
                         "end": 120
+                      },
+                      "comma": {
+                        "start": 120,
+                        "end": 121
                       }
                     },
                     {
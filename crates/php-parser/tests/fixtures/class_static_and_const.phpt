@@ -31,7 +31,10 @@ class Config {
                   "is_final": false,
                   "value": {
                     "kind": {
-                      "String": "1.0"
+                      "String": {
+                        "value": "1.0",
+                        "raw": "1.0"
+                      }
                     },
                     "span": {
                       "start": 48,
@@ -150,10 +153,7 @@ class Config {
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 177,
                                         "end": 181
                                       }
@@ -21,12 +21,18 @@
               "value": {
                 "kind": {
                   "Clone": {
-                    "kind": {
-                      "Variable": "obj"
+                    "object": {
+                      "kind": {
+                        "Variable": "obj"
+                      },
+                      "span": {
+                        "start": 20,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 20,
-                      "end": 24
+                    "keyword_span": {
+                      "start": 14,
+                      "end": 19
                     }
                   }
                 },
@@ -0,0 +1,37 @@
+===source===
+<?php echo 1; ?>
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 13
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 13
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 14,
+    "end": 16
+  }
+}
@@ -0,0 +1,84 @@
+===source===
+<?php echo 1; ?>
+<p>
+<?php echo 2; ?><p>
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 13
+      }
+    },
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "\r\n<p>\r\n",
+          "newline_swallowed": true
+        }
+      },
+      "span": {
+        "start": 16,
+        "end": 23
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 34,
+                "end": 35
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 29,
+        "end": 36
+      }
+    },
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "\r<p>",
+          "newline_swallowed": true
+        }
+      },
+      "span": {
+        "start": 39,
+        "end": 43
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 43
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 37,
+    "end": 39
+  }
+}
@@ -5,17 +5,19 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 1
-            },
-            "span": {
-              "start": 11,
-              "end": 12
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -24,7 +26,9 @@
     },
     {
       "kind": {
-        "InlineHtml": "<html>"
+        "InlineHtml": {
+          "text": "<html>"
+        }
       },
       "span": {
         "start": 15,
@@ -33,17 +37,19 @@
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 2
-            },
-            "span": {
-              "start": 32,
-              "end": 33
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 32,
+                "end": 33
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 27,
@@ -54,5 +60,10 @@
   "span": {
     "start": 0,
     "end": 33
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 34,
+    "end": 36
   }
 }
@@ -55,6 +55,10 @@
                         "span": {
                           "start": 20,
                           "end": 26
+                        },
+                        "comma": {
+                          "start": 26,
+                          "end": 27
                         }
                       },
                       {
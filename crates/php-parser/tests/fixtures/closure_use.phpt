@@ -31,6 +31,10 @@
                         "span": {
                           "start": 27,
                           "end": 29
+                        },
+                        "comma": {
+                          "start": 29,
+                          "end": 30
                         }
                       },
                       {
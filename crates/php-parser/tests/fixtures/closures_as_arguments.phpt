@@ -25,7 +25,16 @@ $mapped = array_map(function($x) { return $x * 2; }, $items);
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "array_filter"
+                        "Name": {
+                          "parts": [
+                            "array_filter"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 18,
+                            "end": 30
+                          }
+                        }
                       },
                       "span": {
                         "start": 18,
@@ -49,6 +58,10 @@ $mapped = array_map(function($x) { return $x * 2; }, $items);
                         "span": {
                           "start": 31,
                           "end": 37
+                        },
+                        "comma": {
+                          "start": 37,
+                          "end": 38
                         }
                       },
                       {
@@ -162,7 +175,16 @@ $mapped = array_map(function($x) { return $x * 2; }, $items);
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "array_map"
+                        "Name": {
+                          "parts": [
+                            "array_map"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 68,
+                            "end": 77
+                          }
+                        }
                       },
                       "span": {
                         "start": 68,
@@ -249,6 +271,10 @@ $mapped = array_map(function($x) { return $x * 2; }, $items);
                         "span": {
                           "start": 78,
                           "end": 109
+                        },
+                        "comma": {
+                          "start": 109,
+                          "end": 110
                         }
                       },
                       {
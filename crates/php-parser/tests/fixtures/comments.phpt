@@ -145,6 +145,10 @@ function add($a, $b) {
               "span": {
                 "start": 135,
                 "end": 137
+              },
+              "comma": {
+                "start": 137,
+                "end": 138
               }
             },
             {
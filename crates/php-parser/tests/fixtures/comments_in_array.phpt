@@ -55,6 +55,10 @@ $z = [
                       "span": {
                         "start": 53,
                         "end": 54
+                      },
+                      "comma": {
+                        "start": 54,
+                        "end": 55
                       }
                     },
                     {
@@ -72,6 +76,10 @@ $z = [
                       "span": {
                         "start": 100,
                         "end": 101
+                      },
+                      "comma": {
+                        "start": 101,
+                        "end": 102
                       }
                     },
                     {
@@ -89,6 +97,10 @@ $z = [
                       "span": {
                         "start": 130,
                         "end": 131
+                      },
+                      "comma": {
+                        "start": 131,
+                        "end": 132
                       }
                     },
                     {
@@ -106,6 +118,10 @@ $z = [
                       "span": {
                         "start": 181,
                         "end": 182
+                      },
+                      "comma": {
+                        "start": 182,
+                        "end": 183
                       }
                     }
                   ]
@@ -149,7 +165,10 @@ $z = [
                     {
                       "key": {
                         "kind": {
-                          "String": "key1"
+                          "String": {
+                            "value": "key1",
+                            "raw": "key1"
+                          }
                         },
                         "span": {
                           "start": 199,
@@ -158,7 +177,10 @@ $z = [
                       },
                       "value": {
                         "kind": {
-                          "String": "value1"
+                          "String": {
+                            "value": "value1",
+                            "raw": "value1"
+                          }
                         },
                         "span": {
                           "start": 233,
@@ -169,12 +191,19 @@ $z = [
                       "span": {
                         "start": 199,
                         "end": 241
+                      },
+                      "comma": {
+                        "start": 241,
+                        "end": 242
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "key2"
+                          "String": {
+                            "value": "key2",
+                            "raw": "key2"
+                          }
                         },
                         "span": {
                           "start": 247,
@@ -183,7 +212,10 @@ $z = [
                       },
                       "value": {
                         "kind": {
-                          "String": "value2"
+                          "String": {
+                            "value": "value2",
+                            "raw": "value2"
+                          }
                         },
                         "span": {
                           "start": 257,
@@ -194,6 +226,10 @@ $z = [
                       "span": {
                         "start": 247,
                         "end": 265
+                      },
+                      "comma": {
+                        "start": 265,
+                        "end": 266
                       }
                     }
                   ]
@@ -249,6 +285,10 @@ $z = [
                       "span": {
                         "start": 327,
                         "end": 328
+                      },
+                      "comma": {
+                        "start": 328,
+                        "end": 329
                       }
                     },
                     {
@@ -266,6 +306,10 @@ $z = [
                       "span": {
                         "start": 344,
                         "end": 345
+                      },
+                      "comma": {
+                        "start": 345,
+                        "end": 346
                       }
                     }
                   ]
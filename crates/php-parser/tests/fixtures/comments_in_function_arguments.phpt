@@ -103,7 +103,16 @@ foo(
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 45,
+                      "end": 48
+                    }
+                  }
                 },
                 "span": {
                   "start": 45,
@@ -127,6 +136,10 @@ foo(
                   "span": {
                     "start": 52,
                     "end": 54
+                  },
+                  "comma": {
+                    "start": 54,
+                    "end": 55
                   }
                 },
                 {
@@ -145,6 +158,10 @@ foo(
                   "span": {
                     "start": 82,
                     "end": 84
+                  },
+                  "comma": {
+                    "start": 84,
+                    "end": 85
                   }
                 },
                 {
@@ -163,6 +180,10 @@ foo(
                   "span": {
                     "start": 108,
                     "end": 110
+                  },
+                  "comma": {
+                    "start": 110,
+                    "end": 111
                   }
                 },
                 {
@@ -181,6 +202,10 @@ foo(
                   "span": {
                     "start": 114,
                     "end": 116
+                  },
+                  "comma": {
+                    "start": 131,
+                    "end": 132
                   }
                 },
                 {
@@ -258,6 +283,10 @@ foo(
                         "span": {
                           "start": 228,
                           "end": 230
+                        },
+                        "comma": {
+                          "start": 230,
+                          "end": 231
                         }
                       },
                       {
@@ -335,7 +364,16 @@ foo(
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 335,
+                      "end": 338
+                    }
+                  }
                 },
                 "span": {
                   "start": 335,
@@ -350,7 +388,16 @@ foo(
                       "FunctionCall": {
                         "name": {
                           "kind": {
-                            "Identifier": "bar"
+                            "Name": {
+                              "parts": [
+                                "bar"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 342,
+                                "end": 345
+                              }
+                            }
                           },
                           "span": {
                             "start": 342,
@@ -374,6 +421,10 @@ foo(
                             "span": {
                               "start": 367,
                               "end": 369
+                            },
+                            "comma": {
+                              "start": 369,
+                              "end": 370
                             }
                           },
                           {
@@ -407,6 +458,10 @@ foo(
                   "span": {
                     "start": 342,
                     "end": 381
+                  },
+                  "comma": {
+                    "start": 381,
+                    "end": 382
                   }
                 },
                 {
@@ -468,6 +523,10 @@ foo(
               "span": {
                 "start": 466,
                 "end": 472
+              },
+              "comma": {
+                "start": 472,
+                "end": 473
               }
             },
             {
@@ -541,6 +600,10 @@ foo(
                         "span": {
                           "start": 561,
                           "end": 563
+                        },
+                        "comma": {
+                          "start": 563,
+                          "end": 564
                         }
                       },
                       {
@@ -647,6 +710,10 @@ foo(
               "span": {
                 "start": 670,
                 "end": 679
+              },
+              "comma": {
+                "start": 679,
+                "end": 680
               }
             },
             {
@@ -680,6 +747,10 @@ foo(
               "span": {
                 "start": 692,
                 "end": 698
+              },
+              "comma": {
+                "start": 698,
+                "end": 699
               }
             }
           ],
@@ -713,6 +784,10 @@ foo(
               "span": {
                 "start": 751,
                 "end": 753
+              },
+              "comma": {
+                "start": 753,
+                "end": 754
               }
             },
             {
@@ -779,6 +854,10 @@ foo(
                       "span": {
                         "start": 882,
                         "end": 892
+                      },
+                      "comma": {
+                        "start": 892,
+                        "end": 893
                       }
                     },
                     {
@@ -795,6 +874,10 @@ foo(
                       "span": {
                         "start": 913,
                         "end": 923
+                      },
+                      "comma": {
+                        "start": 923,
+                        "end": 924
                       }
                     }
                   ],
@@ -859,7 +942,10 @@ foo(
                         ],
                         "body": {
                           "kind": {
-                            "String": "one"
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
                           },
                           "span": {
                             "start": 1026,
@@ -869,6 +955,10 @@ foo(
                         "span": {
                           "start": 1009,
                           "end": 1031
+                        },
+                        "comma": {
+                          "start": 1031,
+                          "end": 1032
                         }
                       },
                       {
@@ -885,7 +975,10 @@ foo(
                         ],
                         "body": {
                           "kind": {
-                            "String": "two"
+                            "String": {
+                              "value": "two",
+                              "raw": "two"
+                            }
                           },
                           "span": {
                             "start": 1040,
@@ -895,6 +988,10 @@ foo(
                         "span": {
                           "start": 1035,
                           "end": 1045
+                        },
+                        "comma": {
+                          "start": 1045,
+                          "end": 1046
                         }
                       }
                     ]
@@ -925,7 +1022,16 @@ foo(
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 1100,
+                      "end": 1103
+                    }
+                  }
                 },
                 "span": {
                   "start": 1100,
@@ -972,7 +1078,16 @@ foo(
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 1209,
+                      "end": 1212
+                    }
+                  }
                 },
                 "span": {
                   "start": 1209,
@@ -996,6 +1111,10 @@ foo(
                   "span": {
                     "start": 1216,
                     "end": 1218
+                  },
+                  "comma": {
+                    "start": 1218,
+                    "end": 1219
                   }
                 },
                 {
@@ -1014,6 +1133,10 @@ foo(
                   "span": {
                     "start": 1239,
                     "end": 1241
+                  },
+                  "comma": {
+                    "start": 1241,
+                    "end": 1242
                   }
                 },
                 {
@@ -53,6 +53,10 @@ class Test {
               "span": {
                 "start": 25,
                 "end": 54
+              },
+              "comma": {
+                "start": 54,
+                "end": 55
               }
             },
             {
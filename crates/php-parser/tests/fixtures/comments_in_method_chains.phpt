@@ -380,6 +380,10 @@ $obj
                   "span": {
                     "start": 435,
                     "end": 437
+                  },
+                  "comma": {
+                    "start": 437,
+                    "end": 438
                   }
                 },
                 {
@@ -528,52 +532,59 @@ $obj
                 "kind": {
                   "StaticMethodCall": {
                     "class": {
-                      "kind": {
-                        "StaticMethodCall": {
-                          "class": {
-                            "kind": {
-                              "StaticMethodCall": {
-                                "class": {
-                                  "kind": {
-                                    "Identifier": "MyClass"
-                                  },
-                                  "span": {
-                                    "start": 616,
-                                    "end": 623
-                                  }
-                                },
-                                "method": {
-                                  "kind": {
-                                    "Identifier": "staticMethod"
-                                  },
-                                  "span": {
-                                    "start": 628,
-                                    "end": 640
+                      "Expr": {
+                        "kind": {
+                          "StaticMethodCall": {
+                            "class": {
+                              "Expr": {
+                                "kind": {
+                                  "StaticMethodCall": {
+                                    "class": {
+                                      "Name": {
+                                        "parts": [
+                                          "MyClass"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 616,
+                                          "end": 623
+                                        }
+                                      }
+                                    },
+                                    "method": {
+                                      "kind": {
+                                        "Identifier": "staticMethod"
+                                      },
+                                      "span": {
+                                        "start": 628,
+                                        "end": 640
+                                      }
+                                    },
+                                    "args": []
                                   }
                                 },
-                                "args": []
+                                "span": {
+                                  "start": 616,
+                                  "end": 642
+                                }
                               }
                             },
-                            "span": {
-                              "start": 616,
-                              "end": 642
-                            }
-                          },
-                          "method": {
-                            "kind": {
-                              "Identifier": "anotherStatic"
+                            "method": {
+                              "kind": {
+                                "Identifier": "anotherStatic"
+                              },
+                              "span": {
+                                "start": 660,
+                                "end": 673
+                              }
                             },
-                            "span": {
-                              "start": 660,
-                              "end": 673
-                            }
-                          },
-                          "args": []
+                            "args": []
+                          }
+                        },
+                        "span": {
+                          "start": 616,
+                          "end": 675
                         }
-                      },
-                      "span": {
-                        "start": 616,
-                        "end": 675
                       }
                     },
                     "method": {
@@ -960,6 +971,10 @@ $obj
                         "span": {
                           "start": 1129,
                           "end": 1131
+                        },
+                        "comma": {
+                          "start": 1131,
+                          "end": 1132
                         }
                       },
                       {
@@ -845,100 +845,102 @@ class Precedence {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [
-                            {
-                              "kind": {
-                                "TraitUse": {
-                                  "traits": [
-                                    {
-                                      "parts": [
-                                        "Trait1"
-                                      ],
-                                      "kind": "Unqualified",
-                                      "span": {
-                                        "start": 1512,
-                                        "end": 1518
-                                      }
-                                    },
-                                    {
-                                      "parts": [
-                                        "Trait2"
-                                      ],
-                                      "kind": "Unqualified",
-                                      "span": {
-                                        "start": 1538,
-                                        "end": 1544
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [
+                              {
+                                "kind": {
+                                  "TraitUse": {
+                                    "traits": [
+                                      {
+                                        "parts": [
+                                          "Trait1"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 1512,
+                                          "end": 1518
+                                        }
+                                      },
+                                      {
+                                        "parts": [
+                                          "Trait2"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 1538,
+                                          "end": 1544
+                                        }
                                       }
-                                    }
-                                  ],
-                                  "adaptations": [
-                                    {
-                                      "kind": {
-                                        "Precedence": {
-                                          "trait_name": {
-                                            "parts": [
-                                              "Trait1"
-                                            ],
-                                            "kind": "Unqualified",
-                                            "span": {
-                                              "start": 1572,
-                                              "end": 1578
-                                            }
-                                          },
-                                          "method": {
-                                            "parts": [
-                                              "method1"
-                                            ],
-                                            "kind": "Unqualified",
-                                            "span": {
-                                              "start": 1580,
-                                              "end": 1587
-                                            }
-                                          },
-                                          "insteadof": [
-                                            {
+                                    ],
+                                    "adaptations": [
+                                      {
+                                        "kind": {
+                                          "Precedence": {
+                                            "trait_name": {
                                               "parts": [
-                                                "Trait2"
+                                                "Trait1"
                                               ],
                                               "kind": "Unqualified",
                                               "span": {
-                                                "start": 1598,
-                                                "end": 1604
+                                                "start": 1572,
+                                                "end": 1578
                                               }
-                                            }
-                                          ]
+                                            },
+                                            "method": {
+                                              "parts": [
+                                                "method1"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 1580,
+                                                "end": 1587
+                                              }
+                                            },
+                                            "insteadof": [
+                                              {
+                                                "parts": [
+                                                  "Trait2"
+                                                ],
+                                                "kind": "Unqualified",
+                                                "span": {
+                                                  "start": 1598,
+                                                  "end": 1604
+                                                }
+                                              }
+                                            ]
+                                          }
+                                        },
+                                        "span": {
+                                          "start": 1572,
+                                          "end": 1605
                                         }
-                                      },
-                                      "span": {
-                                        "start": 1572,
-                                        "end": 1605
                                       }
-                                    }
-                                  ]
+                                    ]
+                                  }
+                                },
+                                "span": {
+                                  "start": 1492,
+                                  "end": 1611
                                 }
-                              },
-                              "span": {
-                                "start": 1492,
-                                "end": 1611
                               }
-                            }
-                          ],
-                          "attributes": []
+                            ],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 1478,
+                          "end": 1613
                         }
-                      },
-                      "span": {
-                        "start": 1478,
-                        "end": 1613
                       }
                     },
                     "args": []
@@ -86,6 +86,10 @@ class Test {
               "span": {
                 "start": 26,
                 "end": 59
+              },
+              "comma": {
+                "start": 59,
+                "end": 60
               }
             },
             {
@@ -147,6 +151,10 @@ class Test {
               "span": {
                 "start": 65,
                 "end": 96
+              },
+              "comma": {
+                "start": 96,
+                "end": 97
               }
             },
             {
@@ -85,6 +85,10 @@ class Test {
               "span": {
                 "start": 25,
                 "end": 58
+              },
+              "comma": {
+                "start": 58,
+                "end": 59
               }
             },
             {
@@ -14,55 +14,65 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 7,
+                            "end": 7
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 7,
+                          "end": 7
+                        },
+                        "comma": {
                           "start": 7,
                           "end": 8
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 8
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 9,
+                            "end": 9
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 9,
+                          "end": 9
+                        },
+                        "comma": {
                           "start": 9,
                           "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 9,
-                        "end": 10
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "third"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "third"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 17
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 17
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 17
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -99,112 +109,130 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 29,
+                                      "end": 31
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 29,
+                                    "end": 31
+                                  },
+                                  "comma": {
+                                    "start": 31,
+                                    "end": 32
+                                  }
                                 },
-                                "span": {
-                                  "start": 29,
-                                  "end": 31
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 33,
+                                      "end": 35
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 33,
+                                    "end": 35
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 29,
-                                "end": 31
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
-                                },
-                                "span": {
-                                  "start": 33,
-                                  "end": 35
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 33,
-                                "end": 35
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 28,
+                            "end": 36
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 28,
                           "end": 36
+                        },
+                        "comma": {
+                          "start": 36,
+                          "end": 37
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 28,
-                        "end": 36
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
-                                },
-                                "span": {
-                                  "start": 39,
-                                  "end": 41
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 39,
-                                "end": 41
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "d"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 39,
+                                      "end": 41
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 39,
+                                    "end": 41
+                                  },
+                                  "comma": {
+                                    "start": 41,
+                                    "end": 42
+                                  }
                                 },
-                                "span": {
-                                  "start": 43,
-                                  "end": 45
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "d"
+                                    },
+                                    "span": {
+                                      "start": 43,
+                                      "end": 45
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 43,
+                                    "end": 45
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 43,
-                                "end": 45
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 38,
+                            "end": 46
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 38,
                           "end": 46
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 38,
-                        "end": 46
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 27,
@@ -241,84 +269,101 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "name"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "name",
+                              "raw": "name"
+                            }
+                          },
+                          "span": {
+                            "start": 60,
+                            "end": 66
+                          }
                         },
-                        "span": {
-                          "start": 60,
-                          "end": 66
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "name"
+                        "value": {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 70,
+                            "end": 75
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 70,
+                          "start": 60,
                           "end": 75
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 60,
-                        "end": 75
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "address"
                         },
-                        "span": {
-                          "start": 77,
-                          "end": 86
+                        "comma": {
+                          "start": 75,
+                          "end": 76
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": {
-                                "kind": {
-                                  "String": "city"
-                                },
-                                "span": {
-                                  "start": 91,
-                                  "end": 97
-                                }
-                              },
-                              "value": {
-                                "kind": {
-                                  "Variable": "city"
-                                },
-                                "span": {
-                                  "start": 101,
-                                  "end": 106
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "address",
+                              "raw": "address"
+                            }
+                          },
+                          "span": {
+                            "start": 77,
+                            "end": 86
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": {
+                                    "kind": {
+                                      "String": {
+                                        "value": "city",
+                                        "raw": "city"
+                                      }
+                                    },
+                                    "span": {
+                                      "start": 91,
+                                      "end": 97
+                                    }
+                                  },
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "city"
+                                    },
+                                    "span": {
+                                      "start": 101,
+                                      "end": 106
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 91,
+                                    "end": 106
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 91,
-                                "end": 106
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 90,
+                            "end": 107
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 90,
+                          "start": 77,
                           "end": 107
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 77,
-                        "end": 107
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 59,
@@ -355,58 +400,64 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "Int": 1
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 119,
+                            "end": 120
+                          }
                         },
-                        "span": {
-                          "start": 119,
-                          "end": 120
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "second"
+                        "value": {
+                          "kind": {
+                            "Variable": "second"
+                          },
+                          "span": {
+                            "start": 124,
+                            "end": 131
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 124,
+                          "start": 119,
                           "end": 131
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 119,
-                        "end": 131
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "Int": 0
                         },
-                        "span": {
-                          "start": 133,
-                          "end": 134
+                        "comma": {
+                          "start": 131,
+                          "end": 132
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                      {
+                        "key": {
+                          "kind": {
+                            "Int": 0
+                          },
+                          "span": {
+                            "start": 133,
+                            "end": 134
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 138,
+                            "end": 144
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 138,
+                          "start": 133,
                           "end": 144
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 133,
-                        "end": 144
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 118,
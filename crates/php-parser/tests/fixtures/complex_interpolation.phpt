@@ -5,52 +5,60 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Value: "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "MethodCall": {
-                        "object": {
-                          "kind": {
-                            "Variable": "obj"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Value: ",
+                      "span": {
+                        "start": 12,
+                        "end": 19
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "MethodCall": {
+                          "object": {
+                            "kind": {
+                              "Variable": "obj"
+                            },
+                            "span": {
+                              "start": 20,
+                              "end": 24
+                            }
                           },
-                          "span": {
-                            "start": 20,
-                            "end": 24
-                          }
-                        },
-                        "method": {
-                          "kind": {
-                            "Identifier": "getName"
+                          "method": {
+                            "kind": {
+                              "Identifier": "getName"
+                            },
+                            "span": {
+                              "start": 26,
+                              "end": 33
+                            }
                           },
-                          "span": {
-                            "start": 26,
-                            "end": 33
-                          }
-                        },
-                        "args": []
+                          "args": []
+                        }
+                      },
+                      "span": {
+                        "start": 20,
+                        "end": 35
                       }
-                    },
-                    "span": {
-                      "start": 20,
-                      "end": 35
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 11,
-              "end": 37
+                ]
+              },
+              "span": {
+                "start": 11,
+                "end": 37
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
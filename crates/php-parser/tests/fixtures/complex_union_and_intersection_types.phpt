@@ -232,6 +232,10 @@ class Container {
                       "span": {
                         "start": 148,
                         "end": 189
+                      },
+                      "comma": {
+                        "start": 189,
+                        "end": 190
                       }
                     },
                     {
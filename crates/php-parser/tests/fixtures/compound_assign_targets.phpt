@@ -134,7 +134,10 @@
                     },
                     "index": {
                       "kind": {
-                        "String": "key"
+                        "String": {
+                          "value": "key",
+                          "raw": "key"
+                        }
                       },
                       "span": {
                         "start": 44,
@@ -151,7 +154,10 @@
               "op": "Concat",
               "value": {
                 "kind": {
-                  "String": "suffix"
+                  "String": {
+                    "value": "suffix",
+                    "raw": "suffix"
+                  }
                 },
                 "span": {
                   "start": 54,
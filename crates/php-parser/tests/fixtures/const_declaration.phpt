@@ -37,7 +37,10 @@
             "name": "APP_NAME",
             "value": {
               "kind": {
-                "String": "MyApp"
+                "String": {
+                  "value": "MyApp",
+                  "raw": "MyApp"
+                }
               },
               "span": {
                 "start": 40,
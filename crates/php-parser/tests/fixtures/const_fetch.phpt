@@ -0,0 +1,375 @@
+===source===
+<?php
+echo FOO, \BAR, App\BAZ, namespace\QUX, FOO[0];
+foo(FOO);
+Foo::bar();
+var_dump($x instanceof Foo, $x instanceof \App\Foo);
+label:
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "ConstFetch": {
+                  "parts": [
+                    "FOO"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 11,
+                    "end": 14
+                  }
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 14
+              }
+            },
+            {
+              "kind": {
+                "ConstFetch": {
+                  "parts": [
+                    "BAR"
+                  ],
+                  "kind": "FullyQualified",
+                  "span": {
+                    "start": 16,
+                    "end": 20
+                  }
+                }
+              },
+              "span": {
+                "start": 16,
+                "end": 20
+              }
+            },
+            {
+              "kind": {
+                "ConstFetch": {
+                  "parts": [
+                    "App",
+                    "BAZ"
+                  ],
+                  "kind": "Qualified",
+                  "span": {
+                    "start": 22,
+                    "end": 29
+                  }
+                }
+              },
+              "span": {
+                "start": 22,
+                "end": 29
+              }
+            },
+            {
+              "kind": {
+                "ConstFetch": {
+                  "parts": [
+                    "QUX"
+                  ],
+                  "kind": "Relative",
+                  "span": {
+                    "start": 31,
+                    "end": 44
+                  }
+                }
+              },
+              "span": {
+                "start": 31,
+                "end": 44
+              }
+            },
+            {
+              "kind": {
+                "ArrayAccess": {
+                  "array": {
+                    "kind": {
+                      "ConstFetch": {
+                        "parts": [
+                          "FOO"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 46,
+                          "end": 49
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 46,
+                      "end": 49
+                    }
+                  },
+                  "index": {
+                    "kind": {
+                      "Int": 0
+                    },
+                    "span": {
+                      "start": 50,
+                      "end": 51
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 46,
+                "end": 52
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 53
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 54,
+                      "end": 57
+                    }
+                  }
+                },
+                "span": {
+                  "start": 54,
+                  "end": 57
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "ConstFetch": {
+                        "parts": [
+                          "FOO"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 58,
+                          "end": 61
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 58,
+                      "end": 61
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 58,
+                    "end": 61
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 54,
+            "end": 62
+          }
+        }
+      },
+      "span": {
+        "start": 54,
+        "end": 63
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "StaticMethodCall": {
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 64,
+                    "end": 67
+                  }
+                }
+              },
+              "method": {
+                "kind": {
+                  "Identifier": "bar"
+                },
+                "span": {
+                  "start": 69,
+                  "end": 72
+                }
+              },
+              "args": []
+            }
+          },
+          "span": {
+            "start": 64,
+            "end": 74
+          }
+        }
+      },
+      "span": {
+        "start": 64,
+        "end": 75
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "var_dump"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 76,
+                      "end": 84
+                    }
+                  }
+                },
+                "span": {
+                  "start": 76,
+                  "end": 84
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Instanceof": {
+                        "expr": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 85,
+                            "end": 87
+                          }
+                        },
+                        "class": {
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 99,
+                              "end": 102
+                            }
+                          }
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 85,
+                      "end": 102
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 85,
+                    "end": 102
+                  },
+                  "comma": {
+                    "start": 102,
+                    "end": 103
+                  }
+                },
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Instanceof": {
+                        "expr": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 104,
+                            "end": 106
+                          }
+                        },
+                        "class": {
+                          "Name": {
+                            "parts": [
+                              "App",
+                              "Foo"
+                            ],
+                            "kind": "FullyQualified",
+                            "span": {
+                              "start": 118,
+                              "end": 126
+                            }
+                          }
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 104,
+                      "end": 126
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 104,
+                    "end": 126
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 76,
+            "end": 127
+          }
+        }
+      },
+      "span": {
+        "start": 76,
+        "end": 128
+      }
+    },
+    {
+      "kind": {
+        "Label": "label"
+      },
+      "span": {
+        "start": 129,
+        "end": 135
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 135
+  }
+}
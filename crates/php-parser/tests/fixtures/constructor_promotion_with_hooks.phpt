@@ -65,6 +65,10 @@ class Point {
                       "span": {
                         "start": 61,
                         "end": 83
+                      },
+                      "comma": {
+                        "start": 83,
+                        "end": 84
                       }
                     },
                     {
@@ -98,6 +102,10 @@ class Point {
                       "span": {
                         "start": 93,
                         "end": 119
+                      },
+                      "comma": {
+                        "start": 119,
+                        "end": 120
                       }
                     },
                     {
@@ -139,6 +147,10 @@ class Point {
                       "span": {
                         "start": 129,
                         "end": 149
+                      },
+                      "comma": {
+                        "start": 149,
+                        "end": 150
                       }
                     }
                   ],
@@ -64,17 +64,22 @@ foreach ($map as $key => $value) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "positive"
-                        },
-                        "span": {
-                          "start": 29,
-                          "end": 39
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "positive",
+                              "raw": "positive"
+                            }
+                          },
+                          "span": {
+                            "start": 29,
+                            "end": 39
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 24,
@@ -124,17 +129,22 @@ foreach ($map as $key => $value) {
                   "Block": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "negative"
-                            },
-                            "span": {
-                              "start": 70,
-                              "end": 80
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "negative",
+                                  "raw": "negative"
+                                }
+                              },
+                              "span": {
+                                "start": 70,
+                                "end": 80
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 65,
@@ -159,17 +169,22 @@ foreach ($map as $key => $value) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "zero"
-                        },
-                        "span": {
-                          "start": 100,
-                          "end": 106
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "zero",
+                              "raw": "zero"
+                            }
+                          },
+                          "span": {
+                            "start": 100,
+                            "end": 106
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 95,
@@ -383,22 +398,36 @@ foreach ($map as $key => $value) {
               }
             }
           ],
+          "init_span": {
+            "start": 154,
+            "end": 160
+          },
+          "condition_span": {
+            "start": 162,
+            "end": 169
+          },
+          "update_span": {
+            "start": 171,
+            "end": 175
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 188,
-                          "end": 190
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 188,
+                            "end": 190
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 183,
@@ -446,17 +475,19 @@ foreach ($map as $key => $value) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "item"
-                        },
-                        "span": {
-                          "start": 232,
-                          "end": 237
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "item"
+                          },
+                          "span": {
+                            "start": 232,
+                            "end": 237
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 227,
@@ -512,17 +543,19 @@ foreach ($map as $key => $value) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "key"
-                        },
-                        "span": {
-                          "start": 286,
-                          "end": 290
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "key"
+                          },
+                          "span": {
+                            "start": 286,
+                            "end": 290
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 281,
@@ -531,17 +564,19 @@ foreach ($map as $key => $value) {
                 },
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "value"
-                        },
-                        "span": {
-                          "start": 301,
-                          "end": 307
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "value"
+                          },
+                          "span": {
+                            "start": 301,
+                            "end": 307
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 296,
@@ -13,5 +13,10 @@
   "span": {
     "start": 0,
     "end": 48
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 46,
+    "end": 48
   }
 }
@@ -9,7 +9,16 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "foo"
+            "ConstFetch": {
+              "parts": [
+                "foo"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 6,
+                "end": 9
+              }
+            }
           },
           "span": {
             "start": 6,
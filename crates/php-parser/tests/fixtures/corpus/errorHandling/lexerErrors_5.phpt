@@ -7,7 +7,7 @@ if ($b) {
 }
 ===errors===
 unterminated block comment
-unclosed ''}'' opened at Span { start: 15, end: 16 }
+unclosed '}' opened at Span { start: 15, end: 16 }
 ===ast===
 {
   "stmts": [
@@ -18,7 +18,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -46,7 +55,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "bar"
+                  "Name": {
+                    "parts": [
+                      "bar"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 13,
+                      "end": 16
+                    }
+                  }
                 },
                 "span": {
                   "start": 13,
@@ -74,7 +92,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "baz"
+                  "Name": {
+                    "parts": [
+                      "baz"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 19,
+                      "end": 22
+                    }
+                  }
                 },
                 "span": {
                   "start": 19,
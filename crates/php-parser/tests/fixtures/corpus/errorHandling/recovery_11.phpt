@@ -12,12 +12,15 @@ expected ';' after expression
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "T"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 11
+                "Name": {
+                  "parts": [
+                    "T"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 11
+                  }
                 }
               },
               "args": []
@@ -15,10 +15,12 @@ expected ';' after expression
           "kind": {
             "New": {
               "class": {
-                "kind": "Error",
-                "span": {
-                  "start": 9,
-                  "end": 9
+                "Expr": {
+                  "kind": "Error",
+                  "span": {
+                    "start": 9,
+                    "end": 9
+                  }
                 }
               },
               "args": []
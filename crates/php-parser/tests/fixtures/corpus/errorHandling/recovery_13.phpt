@@ -2,7 +2,7 @@
 <?php
 $foo instanceof
 ===errors===
-expected expression
+expected expression, found end of file
 expected ';' after expression
 ===ast===
 {
@@ -11,8 +11,8 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Variable": "foo"
                 },
@@ -21,12 +21,13 @@ expected ';' after expression
                   "end": 10
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": "Error",
-                "span": {
-                  "start": 21,
-                  "end": 21
+              "class": {
+                "Expr": {
+                  "kind": "Error",
+                  "span": {
+                    "start": 21,
+                    "end": 21
+                  }
                 }
               }
             }
@@ -2,7 +2,7 @@
 <?php
 $
 ===errors===
-expected expression
+expected expression, found end of file
 expected ';' after expression
 ===ast===
 {
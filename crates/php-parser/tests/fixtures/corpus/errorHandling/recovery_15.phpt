@@ -2,7 +2,7 @@
 <?php
 Foo::$
 ===errors===
-expected expression
+expected expression, found end of file
 expected ';' after expression
 ===ast===
 {
@@ -13,12 +13,15 @@ expected ';' after expression
           "kind": {
             "StaticPropertyAccessDynamic": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 9
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 9
+                  }
                 }
               },
               "member": {
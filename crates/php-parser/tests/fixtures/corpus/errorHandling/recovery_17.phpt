@@ -17,27 +17,27 @@ throw $x
 goto label
 ===errors===
 expected ';', found 'use'
+The use statement with non-compound name 'A' has no effect
 expected ';', found 'use'
+The use statement with non-compound name 'a' has no effect
 expected ';', found 'use'
 expected ';', found 'const'
 expected ';', found 'break'
-expected expression
+expected expression, found 'break'
 expected ';' after break statement
 Cannot 'break' 2 levels
 expected ';' after break statement
-expected expression
+expected expression, found 'continue'
 expected ';' after continue statement
 Cannot 'continue' 2 levels
 expected ';' after continue statement
-expected expression
+expected expression, found 'return' while parsing return value
 expected ';' after return statement
 expected ';' after return statement
 expected ';' after echo statement
 expected ';', found 'throw'
 expected ';' after throw statement
 expected ';', found end of file
-The use statement with non-compound name 'A' has no effect
-The use statement with non-compound name 'a' has no effect
 ===ast===
 {
   "stmts": [
@@ -278,17 +278,19 @@ The use statement with non-compound name 'a' has no effect
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "a"
-            },
-            "span": {
-              "start": 118,
-              "end": 120
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "a"
+              },
+              "span": {
+                "start": 118,
+                "end": 120
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 113,
@@ -52,6 +52,10 @@ The use statement with non-compound name 'A' has no effect
               "span": {
                 "start": 14,
                 "end": 15
+              },
+              "comma": {
+                "start": 15,
+                "end": 16
               }
             }
           ]
@@ -83,6 +87,10 @@ The use statement with non-compound name 'A' has no effect
               "span": {
                 "start": 36,
                 "end": 37
+              },
+              "comma": {
+                "start": 37,
+                "end": 38
               }
             }
           ]
@@ -113,6 +121,10 @@ The use statement with non-compound name 'A' has no effect
               "span": {
                 "start": 46,
                 "end": 47
+              },
+              "comma": {
+                "start": 47,
+                "end": 48
               }
             }
           ]
@@ -447,17 +459,19 @@ The use statement with non-compound name 'A' has no effect
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "a"
-            },
-            "span": {
-              "start": 294,
-              "end": 296
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "a"
+              },
+              "span": {
+                "start": 294,
+                "end": 296
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 289,
@@ -500,6 +514,18 @@ The use statement with non-compound name 'A' has no effect
               }
             }
           ],
+          "init_span": {
+            "start": 306,
+            "end": 308
+          },
+          "condition_span": {
+            "start": 312,
+            "end": 314
+          },
+          "update_span": {
+            "start": 318,
+            "end": 320
+          },
           "body": {
             "kind": "Nop",
             "span": {
@@ -5,8 +5,8 @@ foreach ($foo) { $bar; }
 foreach ($foo as ) { $bar; }
 ===errors===
 expected 'as', found ')'
-expected expression
-expected expression
+expected expression, found ')'
+expected expression, found ')'
 ===ast===
 {
   "stmts": [
@@ -37,7 +37,7 @@ expected variable, found ')'
 expected '{', found ';'
 expected '}', found end of file
 expected ';' after expression
-unclosed ''}'' opened at Span { start: 171, end: 176 }
+unclosed '}' opened at Span { start: 171, end: 176 }
 ===ast===
 {
   "stmts": [
@@ -145,6 +145,10 @@ unclosed ''}'' opened at Span { start: 171, end: 176 }
               "span": {
                 "start": 54,
                 "end": 64
+              },
+              "comma": {
+                "start": 64,
+                "end": 65
               }
             },
             {
@@ -13,15 +13,15 @@ $array = [
 expected ']', found variable
 expected ';' after expression
 expected ';' after expression
-expected expression
+expected expression, found ']'
 expected ']', found variable
 expected ';' after expression
 expected ';' after expression
-expected expression
+expected expression, found ']'
 expected ']', found variable
 expected ';' after expression
 expected ';' after expression
-expected expression
+expected expression, found ']'
 ===ast===
 {
   "stmts": [
@@ -243,7 +243,10 @@ expected expression
                     {
                       "key": {
                         "kind": {
-                          "String": "key"
+                          "String": {
+                            "value": "key",
+                            "raw": "key"
+                          }
                         },
                         "span": {
                           "start": 120,
@@ -2,9 +2,9 @@
 <?php
 $a = ["a "thing"];
 ===errors===
-unterminated string literal
 expected ']', found identifier
 expected ';' after expression
+unterminated string literal
 expected ';' after expression
 expected ';' after expression
 ===ast===
@@ -32,7 +32,10 @@ expected ';' after expression
                       "key": null,
                       "value": {
                         "kind": {
-                          "String": "a "
+                          "String": {
+                            "value": "a ",
+                            "raw": "a "
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -69,7 +72,16 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "thing"
+            "ConstFetch": {
+              "parts": [
+                "thing"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 16,
+                "end": 21
+              }
+            }
           },
           "span": {
             "start": 16,
@@ -86,7 +98,10 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "String": "];"
+            "String": {
+              "value": "];",
+              "raw": "];"
+            }
           },
           "span": {
             "start": 21,
@@ -16,7 +16,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -44,7 +53,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "bar"
+                  "Name": {
+                    "parts": [
+                      "bar"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 14,
+                      "end": 17
+                    }
+                  }
                 },
                 "span": {
                   "start": 14,
@@ -72,7 +90,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "baz"
+                  "Name": {
+                    "parts": [
+                      "baz"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 20,
+                      "end": 23
+                    }
+                  }
                 },
                 "span": {
                   "start": 20,
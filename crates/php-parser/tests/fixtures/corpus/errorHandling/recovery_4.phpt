@@ -3,7 +3,7 @@
 abc;
 1 + ;
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
@@ -11,7 +11,16 @@ expected expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "abc"
+            "ConstFetch": {
+              "parts": [
+                "abc"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 6,
+                "end": 9
+              }
+            }
           },
           "span": {
             "start": 6,
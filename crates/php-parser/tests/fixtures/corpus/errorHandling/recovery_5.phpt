@@ -4,7 +4,7 @@ function test() {
     1 +
 }
 ===errors===
-expected expression
+expected expression, found '}'
 expected ';' after expression
 ===ast===
 {
@@ -8,7 +8,7 @@ $j = 1;
 $k = 2;
 ===errors===
 expected '(', found variable
-unclosed '')'' opened at Span { start: 22, end: 24 }
+unclosed ')' opened at Span { start: 22, end: 24 }
 ===ast===
 {
   "stmts": [
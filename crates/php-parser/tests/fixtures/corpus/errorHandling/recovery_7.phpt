@@ -8,7 +8,7 @@ while () {
 $k = 2;
 // The output here drops the loop - would require Error node to handle this
 ===errors===
-expected expression
+expected expression, found ')' while parsing while condition
 ===ast===
 {
   "stmts": [
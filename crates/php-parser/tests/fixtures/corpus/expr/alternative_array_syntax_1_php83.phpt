@@ -13,15 +13,15 @@ A::B{0};
 new $array{'className'};
 new $a->b{'c'}();
 ===errors===
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
-Array and string offset access syntax with curly braces is no longer supported
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
 ===ast===
 {
   "stmts": [
@@ -41,7 +41,10 @@ Array and string offset access syntax with curly braces is no longer supported
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 10,
@@ -80,7 +83,10 @@ Array and string offset access syntax with curly braces is no longer supported
                     },
                     "index": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 19,
@@ -143,7 +149,10 @@ Array and string offset access syntax with curly braces is no longer supported
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 33,
@@ -199,7 +208,10 @@ Array and string offset access syntax with curly braces is no longer supported
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 47,
@@ -228,12 +240,15 @@ Array and string offset access syntax with curly braces is no longer supported
                 "kind": {
                   "StaticPropertyAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 53,
-                        "end": 54
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 53,
+                          "end": 54
+                        }
                       }
                     },
                     "member": {
@@ -254,7 +269,10 @@ Array and string offset access syntax with curly braces is no longer supported
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 59,
@@ -281,7 +299,16 @@ Array and string offset access syntax with curly braces is no longer supported
             "ArrayAccess": {
               "array": {
                 "kind": {
-                  "Identifier": "A"
+                  "ConstFetch": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 65,
+                      "end": 66
+                    }
+                  }
                 },
                 "span": {
                   "start": 65,
@@ -319,12 +346,15 @@ Array and string offset access syntax with curly braces is no longer supported
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 71,
-                        "end": 72
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 71,
+                          "end": 72
+                        }
                       }
                     },
                     "member": {
@@ -374,12 +404,14 @@ Array and string offset access syntax with curly braces is no longer supported
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Variable": "array"
-                      },
-                      "span": {
-                        "start": 84,
-                        "end": 90
+                      "Expr": {
+                        "kind": {
+                          "Variable": "array"
+                        },
+                        "span": {
+                          "start": 84,
+                          "end": 90
+                        }
                       }
                     },
                     "args": []
@@ -392,7 +424,10 @@ Array and string offset access syntax with curly braces is no longer supported
               },
               "index": {
                 "kind": {
-                  "String": "className"
+                  "String": {
+                    "value": "className",
+                    "raw": "className"
+                  }
                 },
                 "span": {
                   "start": 91,
@@ -427,12 +462,14 @@ Array and string offset access syntax with curly braces is no longer supported
                             "kind": {
                               "New": {
                                 "class": {
-                                  "kind": {
-                                    "Variable": "a"
-                                  },
-                                  "span": {
-                                    "start": 109,
-                                    "end": 111
+                                  "Expr": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 109,
+                                      "end": 111
+                                    }
                                   }
                                 },
                                 "args": []
@@ -461,7 +498,10 @@ Array and string offset access syntax with curly braces is no longer supported
                     },
                     "index": {
                       "kind": {
-                        "String": "c"
+                        "String": {
+                          "value": "c",
+                          "raw": "c"
+                        }
                       },
                       "span": {
                         "start": 115,
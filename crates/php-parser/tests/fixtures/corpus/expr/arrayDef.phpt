@@ -40,7 +40,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 22,
@@ -75,7 +78,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 34,
@@ -86,6 +92,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 34,
                   "end": 37
+                },
+                "comma": {
+                  "start": 37,
+                  "end": 38
                 }
               }
             ]
@@ -110,7 +120,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 48,
@@ -121,13 +134,20 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 48,
                   "end": 51
+                },
+                "comma": {
+                  "start": 51,
+                  "end": 52
                 }
               },
               {
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "b"
+                    "String": {
+                      "value": "b",
+                      "raw": "b"
+                    }
                   },
                   "span": {
                     "start": 53,
@@ -162,7 +182,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 65,
@@ -173,6 +196,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 65,
                   "end": 68
+                },
+                "comma": {
+                  "start": 68,
+                  "end": 69
                 }
               },
               {
@@ -191,12 +218,19 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 70,
                   "end": 73
+                },
+                "comma": {
+                  "start": 73,
+                  "end": 74
                 }
               },
               {
                 "key": {
                   "kind": {
-                    "String": "c"
+                    "String": {
+                      "value": "c",
+                      "raw": "c"
+                    }
                   },
                   "span": {
                     "start": 75,
@@ -205,7 +239,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 },
                 "value": {
                   "kind": {
-                    "String": "d"
+                    "String": {
+                      "value": "d",
+                      "raw": "d"
+                    }
                   },
                   "span": {
                     "start": 82,
@@ -216,12 +253,19 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 75,
                   "end": 85
+                },
+                "comma": {
+                  "start": 85,
+                  "end": 86
                 }
               },
               {
                 "key": {
                   "kind": {
-                    "String": "e"
+                    "String": {
+                      "value": "e",
+                      "raw": "e"
+                    }
                   },
                   "span": {
                     "start": 87,
@@ -294,6 +338,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 128,
                   "end": 129
+                },
+                "comma": {
+                  "start": 129,
+                  "end": 130
                 }
               },
               {
@@ -311,6 +359,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 "span": {
                   "start": 131,
                   "end": 132
+                },
+                "comma": {
+                  "start": 132,
+                  "end": 133
                 }
               },
               {
@@ -351,7 +403,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
               {
                 "key": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 139,
@@ -360,7 +415,10 @@ array('a', &$b, 'c' => 'd', 'e' => &$f);
                 },
                 "value": {
                   "kind": {
-                    "String": "b"
+                    "String": {
+                      "value": "b",
+                      "raw": "b"
+                    }
                   },
                   "span": {
                     "start": 146,
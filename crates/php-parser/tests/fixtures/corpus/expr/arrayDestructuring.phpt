@@ -15,42 +15,48 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 8,
+                            "end": 10
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 8,
                           "end": 10
+                        },
+                        "comma": {
+                          "start": 10,
+                          "end": 11
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 8,
-                        "end": 10
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 12,
+                            "end": 14
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 12,
                           "end": 14
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 12,
-                        "end": 14
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -76,6 +82,10 @@
                       "span": {
                         "start": 19,
                         "end": 21
+                      },
+                      "comma": {
+                        "start": 21,
+                        "end": 22
                       }
                     },
                     {
@@ -122,102 +132,128 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 29,
+                            "end": 29
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 29,
+                          "end": 29
+                        },
+                        "comma": {
                           "start": 29,
                           "end": 30
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 29,
-                        "end": 30
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 31,
+                            "end": 33
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 31,
                           "end": 33
+                        },
+                        "comma": {
+                          "start": 33,
+                          "end": 34
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 31,
-                        "end": 33
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 35,
+                            "end": 35
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 35,
+                          "end": 35
+                        },
+                        "comma": {
                           "start": 35,
                           "end": 36
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 35,
-                        "end": 36
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 37,
+                            "end": 37
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 37,
+                          "end": 37
+                        },
+                        "comma": {
                           "start": 37,
                           "end": 38
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 37,
-                        "end": 38
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 39,
+                            "end": 41
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 39,
                           "end": 41
+                        },
+                        "comma": {
+                          "start": 41,
+                          "end": 42
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 39,
-                        "end": 41
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 43,
+                            "end": 43
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 43,
+                          "end": 43
+                        },
+                        "comma": {
                           "start": 43,
                           "end": 44
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 43,
-                        "end": 44
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 28,
@@ -254,125 +290,147 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 55,
+                            "end": 55
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 55,
+                          "end": 55
+                        },
+                        "comma": {
                           "start": 55,
                           "end": 56
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 55,
-                        "end": 56
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Array": [
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "a"
-                                        },
-                                        "span": {
-                                          "start": 59,
-                                          "end": 61
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 59,
-                                        "end": 61
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "List": {
+                                        "elements": [
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "a"
+                                              },
+                                              "span": {
+                                                "start": 59,
+                                                "end": 61
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 59,
+                                              "end": 61
+                                            },
+                                            "comma": {
+                                              "start": 61,
+                                              "end": 62
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": "Omit",
+                                              "span": {
+                                                "start": 63,
+                                                "end": 63
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 63,
+                                              "end": 63
+                                            },
+                                            "comma": {
+                                              "start": 63,
+                                              "end": 64
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "x"
+                                              },
+                                              "span": {
+                                                "start": 65,
+                                                "end": 67
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 65,
+                                              "end": 67
+                                            }
+                                          }
+                                        ]
                                       }
                                     },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": "Omit",
-                                        "span": {
-                                          "start": 63,
-                                          "end": 64
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 63,
-                                        "end": 64
-                                      }
-                                    },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "x"
-                                        },
-                                        "span": {
-                                          "start": 65,
-                                          "end": 67
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 65,
-                                        "end": 67
-                                      }
+                                    "span": {
+                                      "start": 58,
+                                      "end": 68
                                     }
-                                  ]
-                                },
-                                "span": {
-                                  "start": 58,
-                                  "end": 68
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 58,
+                                    "end": 68
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 58,
-                                "end": 68
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 57,
+                            "end": 69
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 57,
                           "end": 69
+                        },
+                        "comma": {
+                          "start": 69,
+                          "end": 70
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 57,
-                        "end": 69
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 71,
+                            "end": 73
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 71,
                           "end": 73
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 71,
-                        "end": 73
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 54,
@@ -409,58 +467,70 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
+                          },
+                          "span": {
+                            "start": 84,
+                            "end": 87
+                          }
                         },
-                        "span": {
-                          "start": 84,
-                          "end": 87
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 91,
+                            "end": 93
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 91,
+                          "start": 84,
                           "end": 93
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 84,
-                        "end": 93
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "b"
                         },
-                        "span": {
-                          "start": 95,
-                          "end": 98
+                        "comma": {
+                          "start": 93,
+                          "end": 94
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "b",
+                              "raw": "b"
+                            }
+                          },
+                          "span": {
+                            "start": 95,
+                            "end": 98
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 102,
+                            "end": 104
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 102,
+                          "start": 95,
                           "end": 104
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 95,
-                        "end": 104
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 83,
@@ -4,7 +4,7 @@
 [1, , 2];
 array(1, , 2);
 ===errors===
-expected expression
+expected expression, found ',' while parsing array element 2
 ===ast===
 {
   "stmts": [
@@ -28,6 +28,10 @@ expected expression
                 "span": {
                   "start": 8,
                   "end": 9
+                },
+                "comma": {
+                  "start": 9,
+                  "end": 10
                 }
               },
               {
@@ -36,11 +40,15 @@ expected expression
                   "kind": "Omit",
                   "span": {
                     "start": 11,
-                    "end": 12
+                    "end": 11
                   }
                 },
                 "unpack": false,
                 "span": {
+                  "start": 11,
+                  "end": 11
+                },
+                "comma": {
                   "start": 11,
                   "end": 12
                 }
@@ -95,6 +103,10 @@ expected expression
                 "span": {
                   "start": 23,
                   "end": 24
+                },
+                "comma": {
+                  "start": 24,
+                  "end": 25
                 }
               },
               {
@@ -110,6 +122,10 @@ expected expression
                 "span": {
                   "start": 26,
                   "end": 27
+                },
+                "comma": {
+                  "start": 26,
+                  "end": 27
                 }
               },
               {
@@ -56,6 +56,10 @@ function arrGen() {
                       "span": {
                         "start": 16,
                         "end": 17
+                      },
+                      "comma": {
+                        "start": 17,
+                        "end": 18
                       }
                     },
                     {
@@ -73,6 +77,10 @@ function arrGen() {
                       "span": {
                         "start": 19,
                         "end": 20
+                      },
+                      "comma": {
+                        "start": 20,
+                        "end": 21
                       }
                     },
                     {
@@ -138,6 +146,10 @@ function arrGen() {
                         "span": {
                           "start": 56,
                           "end": 57
+                        },
+                        "comma": {
+                          "start": 57,
+                          "end": 58
                         }
                       },
                       {
@@ -274,6 +286,18 @@ function arrGen() {
                       }
                     }
                   ],
+                  "init_span": {
+                    "start": 91,
+                    "end": 98
+                  },
+                  "condition_span": {
+                    "start": 100,
+                    "end": 107
+                  },
+                  "update_span": {
+                    "start": 109,
+                    "end": 113
+                  },
                   "body": {
                     "kind": {
                       "Block": [
@@ -391,6 +415,10 @@ function arrGen() {
                         "span": {
                           "start": 149,
                           "end": 150
+                        },
+                        "comma": {
+                          "start": 150,
+                          "end": 151
                         }
                       },
                       {
@@ -408,6 +436,10 @@ function arrGen() {
                         "span": {
                           "start": 152,
                           "end": 153
+                        },
+                        "comma": {
+                          "start": 153,
+                          "end": 154
                         }
                       },
                       {
@@ -500,7 +532,16 @@ function arrGen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "getArr"
+                          "Name": {
+                            "parts": [
+                              "getArr"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 177,
+                              "end": 183
+                            }
+                          }
                         },
                         "span": {
                           "start": 177,
@@ -546,7 +587,16 @@ function arrGen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "arrGen"
+                          "Name": {
+                            "parts": [
+                              "arrGen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 192,
+                              "end": 198
+                            }
+                          }
                         },
                         "span": {
                           "start": 192,
@@ -591,12 +641,15 @@ function arrGen() {
                   "kind": {
                     "New": {
                       "class": {
-                        "kind": {
-                          "Identifier": "ArrayIterator"
-                        },
-                        "span": {
-                          "start": 211,
-                          "end": 224
+                        "Name": {
+                          "parts": [
+                            "ArrayIterator"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 211,
+                            "end": 224
+                          }
                         }
                       },
                       "args": [
@@ -609,7 +662,10 @@ function arrGen() {
                                   "key": null,
                                   "value": {
                                     "kind": {
-                                      "String": "a"
+                                      "String": {
+                                        "value": "a",
+                                        "raw": "a"
+                                      }
                                     },
                                     "span": {
                                       "start": 226,
@@ -620,13 +676,20 @@ function arrGen() {
                                   "span": {
                                     "start": 226,
                                     "end": 229
+                                  },
+                                  "comma": {
+                                    "start": 229,
+                                    "end": 230
                                   }
                                 },
                                 {
                                   "key": null,
                                   "value": {
                                     "kind": {
-                                      "String": "b"
+                                      "String": {
+                                        "value": "b",
+                                        "raw": "b"
+                                      }
                                     },
                                     "span": {
                                       "start": 231,
@@ -637,13 +700,20 @@ function arrGen() {
                                   "span": {
                                     "start": 231,
                                     "end": 234
+                                  },
+                                  "comma": {
+                                    "start": 234,
+                                    "end": 235
                                   }
                                 },
                                 {
                                   "key": null,
                                   "value": {
                                     "kind": {
-                                      "String": "c"
+                                      "String": {
+                                        "value": "c",
+                                        "raw": "c"
+                                      }
                                     },
                                     "span": {
                                       "start": 236,
@@ -717,6 +787,10 @@ function arrGen() {
                 "span": {
                   "start": 245,
                   "end": 246
+                },
+                "comma": {
+                  "start": 246,
+                  "end": 247
                 }
               },
               {
@@ -734,6 +808,10 @@ function arrGen() {
                 "span": {
                   "start": 248,
                   "end": 257
+                },
+                "comma": {
+                  "start": 257,
+                  "end": 258
                 }
               },
               {
@@ -743,7 +821,16 @@ function arrGen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "getArr"
+                          "Name": {
+                            "parts": [
+                              "getArr"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 262,
+                              "end": 268
+                            }
+                          }
                         },
                         "span": {
                           "start": 262,
@@ -762,6 +849,10 @@ function arrGen() {
                 "span": {
                   "start": 259,
                   "end": 270
+                },
+                "comma": {
+                  "start": 270,
+                  "end": 271
                 }
               },
               {
@@ -779,6 +870,10 @@ function arrGen() {
                 "span": {
                   "start": 272,
                   "end": 273
+                },
+                "comma": {
+                  "start": 273,
+                  "end": 274
                 }
               },
               {
@@ -796,6 +891,10 @@ function arrGen() {
                 "span": {
                   "start": 275,
                   "end": 276
+                },
+                "comma": {
+                  "start": 276,
+                  "end": 277
                 }
               },
               {
@@ -813,6 +912,10 @@ function arrGen() {
                 "span": {
                   "start": 278,
                   "end": 279
+                },
+                "comma": {
+                  "start": 279,
+                  "end": 280
                 }
               },
               {
@@ -830,6 +933,10 @@ function arrGen() {
                 "span": {
                   "start": 281,
                   "end": 282
+                },
+                "comma": {
+                  "start": 282,
+                  "end": 283
                 }
               },
               {
@@ -847,6 +954,10 @@ function arrGen() {
                 "span": {
                   "start": 284,
                   "end": 286
+                },
+                "comma": {
+                  "start": 286,
+                  "end": 287
                 }
               },
               {
@@ -856,7 +967,16 @@ function arrGen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "arrGen"
+                          "Name": {
+                            "parts": [
+                              "arrGen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 291,
+                              "end": 297
+                            }
+                          }
                         },
                         "span": {
                           "start": 291,
@@ -910,6 +1030,10 @@ function arrGen() {
                 "span": {
                   "start": 303,
                   "end": 304
+                },
+                "comma": {
+                  "start": 304,
+                  "end": 305
                 }
               },
               {
@@ -927,6 +1051,10 @@ function arrGen() {
                 "span": {
                   "start": 306,
                   "end": 315
+                },
+                "comma": {
+                  "start": 315,
+                  "end": 316
                 }
               },
               {
@@ -944,13 +1072,20 @@ function arrGen() {
                 "span": {
                   "start": 317,
                   "end": 326
+                },
+                "comma": {
+                  "start": 326,
+                  "end": 327
                 }
               },
               {
                 "key": null,
                 "value": {
                   "kind": {
-                    "String": "end"
+                    "String": {
+                      "value": "end",
+                      "raw": "end"
+                    }
                   },
                   "span": {
                     "start": 328,
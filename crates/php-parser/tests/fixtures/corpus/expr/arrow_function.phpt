@@ -255,6 +255,10 @@ fn($a, $b) => $a && $b;
                   "span": {
                     "start": 84,
                     "end": 86
+                  },
+                  "comma": {
+                    "start": 86,
+                    "end": 87
                   }
                 },
                 {
@@ -369,6 +373,10 @@ fn($a, $b) => $a && $b;
                   "span": {
                     "start": 129,
                     "end": 131
+                  },
+                  "comma": {
+                    "start": 131,
+                    "end": 132
                   }
                 },
                 {
@@ -454,6 +462,10 @@ fn($a, $b) => $a && $b;
                   "span": {
                     "start": 154,
                     "end": 156
+                  },
+                  "comma": {
+                    "start": 156,
+                    "end": 157
                   }
                 },
                 {
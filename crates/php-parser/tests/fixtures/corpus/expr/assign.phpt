@@ -677,25 +677,28 @@ $a--;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 278,
+                            "end": 280
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 278,
                           "end": 280
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 278,
-                        "end": 280
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 273,
@@ -732,57 +735,68 @@ $a--;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 293,
+                            "end": 295
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 293,
                           "end": 295
+                        },
+                        "comma": {
+                          "start": 295,
+                          "end": 296
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 293,
-                        "end": 295
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 297,
+                            "end": 297
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 297,
+                          "end": 297
+                        },
+                        "comma": {
                           "start": 297,
                           "end": 298
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 297,
-                        "end": 298
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 299,
+                            "end": 301
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 299,
                           "end": 301
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 299,
-                        "end": 301
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 288,
@@ -819,92 +833,110 @@ $a--;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 314,
+                            "end": 316
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 314,
                           "end": 316
+                        },
+                        "comma": {
+                          "start": 316,
+                          "end": 317
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 314,
-                        "end": 316
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": "Omit",
-                                "span": {
-                                  "start": 323,
-                                  "end": 324
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 323,
-                                "end": 324
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": "Omit",
+                                    "span": {
+                                      "start": 323,
+                                      "end": 323
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 323,
+                                    "end": 323
+                                  },
+                                  "comma": {
+                                    "start": 323,
+                                    "end": 324
+                                  }
                                 },
-                                "span": {
-                                  "start": 325,
-                                  "end": 327
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 325,
+                                      "end": 327
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 325,
+                                    "end": 327
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 325,
-                                "end": 327
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 318,
+                            "end": 328
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 318,
                           "end": 328
+                        },
+                        "comma": {
+                          "start": 328,
+                          "end": 329
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 318,
-                        "end": 328
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "d"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "d"
+                          },
+                          "span": {
+                            "start": 330,
+                            "end": 332
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 330,
                           "end": 332
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 330,
-                        "end": 332
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 309,
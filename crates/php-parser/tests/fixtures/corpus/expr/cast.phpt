@@ -14,8 +14,8 @@ min_php=8.5
 (string)  $a;
 (unset)   $a;
 ===errors===
-the (real) cast is no longer supported, use (float) instead
-the (unset) cast is no longer supported
+the (real) cast was removed in PHP 8.0 (targeting PHP 8.5)
+the (unset) cast was removed in PHP 8.0 (targeting PHP 8.5)
 ===ast===
 {
   "stmts": [
@@ -10,12 +10,18 @@ clone $a;
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "Variable": "a"
+              "object": {
+                "kind": {
+                  "Variable": "a"
+                },
+                "span": {
+                  "start": 13,
+                  "end": 15
+                }
               },
-              "span": {
-                "start": 13,
-                "end": 15
+              "keyword_span": {
+                "start": 7,
+                "end": 12
               }
             }
           },
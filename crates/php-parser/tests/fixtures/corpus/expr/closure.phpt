@@ -136,6 +136,10 @@ function() use($a) : \Foo\Bar {};
                   "span": {
                     "start": 68,
                     "end": 70
+                  },
+                  "comma": {
+                    "start": 70,
+                    "end": 71
                   }
                 },
                 {
@@ -19,6 +19,10 @@ function() use($a,) {};
                   "span": {
                     "start": 21,
                     "end": 23
+                  },
+                  "comma": {
+                    "start": 23,
+                    "end": 24
                   }
                 }
               ],
@@ -351,8 +351,8 @@ $a instanceof $b;
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Variable": "a"
                 },
@@ -361,14 +361,16 @@ $a instanceof $b;
                   "end": 99
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "B"
-                },
-                "span": {
-                  "start": 111,
-                  "end": 112
+              "class": {
+                "Name": {
+                  "parts": [
+                    "B"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 111,
+                    "end": 112
+                  }
                 }
               }
             }
@@ -388,8 +390,8 @@ $a instanceof $b;
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Variable": "a"
                 },
@@ -398,14 +400,15 @@ $a instanceof $b;
                   "end": 116
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Variable": "b"
-                },
-                "span": {
-                  "start": 128,
-                  "end": 130
+              "class": {
+                "Expr": {
+                  "kind": {
+                    "Variable": "b"
+                  },
+                  "span": {
+                    "start": 128,
+                    "end": 130
+                  }
                 }
               }
             }
@@ -192,7 +192,10 @@ const T_35 = !false;
                 "Binary": {
                   "left": {
                     "kind": {
-                      "String": "foo"
+                      "String": {
+                        "value": "foo",
+                        "raw": "foo"
+                      }
                     },
                     "span": {
                       "start": 81,
@@ -202,7 +205,10 @@ const T_35 = !false;
                   "op": "Concat",
                   "right": {
                     "kind": {
-                      "String": "bar"
+                      "String": {
+                        "value": "bar",
+                        "raw": "bar"
+                      }
                     },
                     "span": {
                       "start": 89,
@@ -320,7 +326,10 @@ const T_35 = !false;
                             "Binary": {
                               "left": {
                                 "kind": {
-                                  "String": "foo"
+                                  "String": {
+                                    "value": "foo",
+                                    "raw": "foo"
+                                  }
                                 },
                                 "span": {
                                   "start": 137,
@@ -427,9 +436,16 @@ const T_35 = !false;
               "kind": {
                 "Heredoc": {
                   "label": "ENDOFSTRING",
+                  "raw": "This is a test string",
                   "parts": [
                     {
-                      "Literal": "This is a test string"
+                      "Literal": {
+                        "value": "This is a test string",
+                        "span": {
+                          "start": 207,
+                          "end": 228
+                        }
+                      }
                     }
                   ]
                 }
@@ -1207,7 +1223,10 @@ const T_35 = !false;
                   "op": "NotEqual",
                   "right": {
                     "kind": {
-                      "String": "0"
+                      "String": {
+                        "value": "0",
+                        "raw": "0"
+                      }
                     },
                     "span": {
                       "start": 564,
@@ -1254,7 +1273,10 @@ const T_35 = !false;
                   "op": "Equal",
                   "right": {
                     "kind": {
-                      "String": "1"
+                      "String": {
+                        "value": "1",
+                        "raw": "1"
+                      }
                     },
                     "span": {
                       "start": 587,
@@ -1361,7 +1383,10 @@ const T_35 = !false;
                       "Binary": {
                         "left": {
                           "kind": {
-                            "String": "1"
+                            "String": {
+                              "value": "1",
+                              "raw": "1"
+                            }
                           },
                           "span": {
                             "start": 629,
@@ -1388,7 +1413,10 @@ const T_35 = !false;
                   "op": "Add",
                   "right": {
                     "kind": {
-                      "String": "3"
+                      "String": {
+                        "value": "3",
+                        "raw": "3"
+                      }
                     },
                     "span": {
                       "start": 639,
@@ -1488,6 +1516,10 @@ const T_35 = !false;
                           "span": {
                             "start": 679,
                             "end": 680
+                          },
+                          "comma": {
+                            "start": 680,
+                            "end": 681
                           }
                         },
                         {
@@ -1505,6 +1537,10 @@ const T_35 = !false;
                           "span": {
                             "start": 682,
                             "end": 683
+                          },
+                          "comma": {
+                            "start": 683,
+                            "end": 684
                           }
                         },
                         {
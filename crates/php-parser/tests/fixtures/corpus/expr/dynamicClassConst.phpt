@@ -13,12 +13,15 @@ $foo::{bar()};
           "kind": {
             "ClassConstAccessDynamic": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 9
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 9
+                  }
                 }
               },
               "member": {
@@ -26,7 +29,16 @@ $foo::{bar()};
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "bar"
+                        "Name": {
+                          "parts": [
+                            "bar"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 12,
+                            "end": 15
+                          }
+                        }
                       },
                       "span": {
                         "start": 12,
@@ -60,12 +72,14 @@ $foo::{bar()};
           "kind": {
             "ClassConstAccessDynamic": {
               "class": {
-                "kind": {
-                  "Variable": "foo"
-                },
-                "span": {
-                  "start": 20,
-                  "end": 24
+                "Expr": {
+                  "kind": {
+                    "Variable": "foo"
+                  },
+                  "span": {
+                    "start": 20,
+                    "end": 24
+                  }
                 }
               },
               "member": {
@@ -73,7 +87,16 @@ $foo::{bar()};
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "bar"
+                        "Name": {
+                          "parts": [
+                            "bar"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 27,
+                            "end": 30
+                          }
+                        }
                       },
                       "span": {
                         "start": 27,
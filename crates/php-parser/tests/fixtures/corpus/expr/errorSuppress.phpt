@@ -9,12 +9,18 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "Variable": "a"
+              "expr": {
+                "kind": {
+                  "Variable": "a"
+                },
+                "span": {
+                  "start": 7,
+                  "end": 9
+                }
               },
-              "span": {
-                "start": 7,
-                "end": 9
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
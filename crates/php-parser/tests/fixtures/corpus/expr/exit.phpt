@@ -58,7 +58,10 @@ DIE($a, $b);
           "kind": {
             "Exit": {
               "kind": {
-                "String": "Die!"
+                "String": {
+                  "value": "Die!",
+                  "raw": "Die!"
+                }
               },
               "span": {
                 "start": 25,
@@ -117,7 +120,10 @@ DIE($a, $b);
           "kind": {
             "Exit": {
               "kind": {
-                "String": "Exit!"
+                "String": {
+                  "value": "Exit!",
+                  "raw": "Exit!"
+                }
               },
               "span": {
                 "start": 50,
@@ -143,7 +149,16 @@ DIE($a, $b);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "exit"
+                  "Name": {
+                    "parts": [
+                      "exit"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 61,
+                      "end": 65
+                    }
+                  }
                 },
                 "span": {
                   "start": 61,
@@ -162,6 +177,10 @@ DIE($a, $b);
                       "end": 72
                     }
                   },
+                  "colon": {
+                    "start": 72,
+                    "end": 73
+                  },
                   "value": {
                     "kind": {
                       "Int": 42
@@ -199,7 +218,16 @@ DIE($a, $b);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "exit"
+                  "Name": {
+                    "parts": [
+                      "exit"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 79,
+                      "end": 83
+                    }
+                  }
                 },
                 "span": {
                   "start": 79,
@@ -246,7 +274,16 @@ DIE($a, $b);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "exit"
+                  "Name": {
+                    "parts": [
+                      "exit"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 95,
+                      "end": 99
+                    }
+                  }
                 },
                 "span": {
                   "start": 95,
@@ -270,6 +307,10 @@ DIE($a, $b);
                   "span": {
                     "start": 100,
                     "end": 102
+                  },
+                  "comma": {
+                    "start": 102,
+                    "end": 103
                   }
                 },
                 {
@@ -311,7 +352,16 @@ DIE($a, $b);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "\\exit"
+                  "Name": {
+                    "parts": [
+                      "exit"
+                    ],
+                    "kind": "FullyQualified",
+                    "span": {
+                      "start": 109,
+                      "end": 114
+                    }
+                  }
                 },
                 "span": {
                   "start": 109,
@@ -359,7 +409,16 @@ DIE($a, $b);
               "kind": {
                 "Function": {
                   "kind": {
-                    "Identifier": "exit"
+                    "Name": {
+                      "parts": [
+                        "exit"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 120,
+                        "end": 124
+                      }
+                    }
                   },
                   "span": {
                     "start": 120,
@@ -387,7 +446,16 @@ DIE($a, $b);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "DIE"
+                  "Name": {
+                    "parts": [
+                      "DIE"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 131,
+                      "end": 134
+                    }
+                  }
                 },
                 "span": {
                   "start": 131,
@@ -411,6 +479,10 @@ DIE($a, $b);
                   "span": {
                     "start": 135,
                     "end": 137
+                  },
+                  "comma": {
+                    "start": 137,
+                    "end": 138
                   }
                 },
                 {
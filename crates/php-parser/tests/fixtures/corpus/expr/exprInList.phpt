@@ -17,74 +17,81 @@ Assignments can only happen to writable values
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Parenthesized": {
-                            "kind": {
-                              "Variable": "a"
-                            },
-                            "span": {
-                              "start": 31,
-                              "end": 33
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Parenthesized": {
+                              "kind": {
+                                "Variable": "a"
+                              },
+                              "span": {
+                                "start": 31,
+                                "end": 33
+                              }
                             }
+                          },
+                          "span": {
+                            "start": 30,
+                            "end": 34
                           }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 30,
                           "end": 34
+                        },
+                        "comma": {
+                          "start": 34,
+                          "end": 35
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 30,
-                        "end": 34
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Parenthesized": {
-                            "kind": {
-                              "Parenthesized": {
-                                "kind": {
-                                  "Parenthesized": {
-                                    "kind": {
-                                      "Variable": "b"
-                                    },
-                                    "span": {
-                                      "start": 39,
-                                      "end": 41
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Parenthesized": {
+                              "kind": {
+                                "Parenthesized": {
+                                  "kind": {
+                                    "Parenthesized": {
+                                      "kind": {
+                                        "Variable": "b"
+                                      },
+                                      "span": {
+                                        "start": 39,
+                                        "end": 41
+                                      }
                                     }
+                                  },
+                                  "span": {
+                                    "start": 38,
+                                    "end": 42
                                   }
-                                },
-                                "span": {
-                                  "start": 38,
-                                  "end": 42
                                 }
+                              },
+                              "span": {
+                                "start": 37,
+                                "end": 43
                               }
-                            },
-                            "span": {
-                              "start": 37,
-                              "end": 43
                             }
+                          },
+                          "span": {
+                            "start": 36,
+                            "end": 44
                           }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 36,
                           "end": 44
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 36,
-                        "end": 44
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 25,
@@ -121,45 +128,48 @@ Assignments can only happen to writable values
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Binary": {
-                            "left": {
-                              "kind": {
-                                "Int": 1
-                              },
-                              "span": {
-                                "start": 101,
-                                "end": 102
-                              }
-                            },
-                            "op": "Add",
-                            "right": {
-                              "kind": {
-                                "Int": 1
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Binary": {
+                              "left": {
+                                "kind": {
+                                  "Int": 1
+                                },
+                                "span": {
+                                  "start": 101,
+                                  "end": 102
+                                }
                               },
-                              "span": {
-                                "start": 105,
-                                "end": 106
+                              "op": "Add",
+                              "right": {
+                                "kind": {
+                                  "Int": 1
+                                },
+                                "span": {
+                                  "start": 105,
+                                  "end": 106
+                                }
                               }
                             }
+                          },
+                          "span": {
+                            "start": 101,
+                            "end": 106
                           }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 101,
                           "end": 106
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 101,
-                        "end": 106
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 96,
@@ -18,7 +18,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 8
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -46,7 +55,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 12,
+                      "end": 13
+                    }
+                  }
                 },
                 "span": {
                   "start": 12,
@@ -93,7 +111,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 19,
+                      "end": 20
+                    }
+                  }
                 },
                 "span": {
                   "start": 19,
@@ -117,6 +144,10 @@ call-time pass-by-reference is not allowed
                   "span": {
                     "start": 21,
                     "end": 23
+                  },
+                  "comma": {
+                    "start": 23,
+                    "end": 24
                   }
                 },
                 {
@@ -158,7 +189,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 30,
+                      "end": 31
+                    }
+                  }
                 },
                 "span": {
                   "start": 30,
@@ -205,7 +245,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "f"
+                  "Name": {
+                    "parts": [
+                      "f"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 38,
+                      "end": 39
+                    }
+                  }
                 },
                 "span": {
                   "start": 38,
@@ -229,6 +278,10 @@ call-time pass-by-reference is not allowed
                   "span": {
                     "start": 40,
                     "end": 42
+                  },
+                  "comma": {
+                    "start": 42,
+                    "end": 43
                   }
                 },
                 {
@@ -13,7 +13,16 @@ $a::class;
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "A"
+            "ConstFetch": {
+              "parts": [
+                "A"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 7,
+                "end": 8
+              }
+            }
           },
           "span": {
             "start": 7,
@@ -32,12 +41,15 @@ $a::class;
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 11
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 11
+                  }
                 }
               },
               "member": {
@@ -68,12 +80,15 @@ $a::class;
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 16,
-                  "end": 17
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 16,
+                    "end": 17
+                  }
                 }
               },
               "member": {
@@ -104,12 +119,14 @@ $a::class;
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Variable": "a"
-                },
-                "span": {
-                  "start": 26,
-                  "end": 28
+                "Expr": {
+                  "kind": {
+                    "Variable": "a"
+                  },
+                  "span": {
+                    "start": 26,
+                    "end": 28
+                  }
                 }
               },
               "member": {
@@ -140,12 +157,14 @@ $a::class;
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Variable": "a"
-                },
-                "span": {
-                  "start": 33,
-                  "end": 35
+                "Expr": {
+                  "kind": {
+                    "Variable": "a"
+                  },
+                  "span": {
+                    "start": 33,
+                    "end": 35
+                  }
                 }
               },
               "member": {
@@ -23,7 +23,10 @@ $foo::BAR[2][1][0];
             "ArrayAccess": {
               "array": {
                 "kind": {
-                  "String": "abc"
+                  "String": {
+                    "value": "abc",
+                    "raw": "abc"
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -65,7 +68,10 @@ $foo::BAR[2][1][0];
                         "ArrayAccess": {
                           "array": {
                             "kind": {
-                              "String": "abc"
+                              "String": {
+                                "value": "abc",
+                                "raw": "abc"
+                              }
                             },
                             "span": {
                               "start": 17,
@@ -149,6 +155,10 @@ $foo::BAR[2][1][0];
                       "span": {
                         "start": 35,
                         "end": 36
+                      },
+                      "comma": {
+                        "start": 36,
+                        "end": 37
                       }
                     },
                     {
@@ -166,6 +176,10 @@ $foo::BAR[2][1][0];
                       "span": {
                         "start": 38,
                         "end": 39
+                      },
+                      "comma": {
+                        "start": 39,
+                        "end": 40
                       }
                     },
                     {
@@ -243,6 +257,10 @@ $foo::BAR[2][1][0];
                                   "span": {
                                     "start": 49,
                                     "end": 50
+                                  },
+                                  "comma": {
+                                    "start": 50,
+                                    "end": 51
                                   }
                                 },
                                 {
@@ -260,6 +278,10 @@ $foo::BAR[2][1][0];
                                   "span": {
                                     "start": 52,
                                     "end": 53
+                                  },
+                                  "comma": {
+                                    "start": 53,
+                                    "end": 54
                                   }
                                 },
                                 {
@@ -363,6 +385,10 @@ $foo::BAR[2][1][0];
                       "span": {
                         "start": 75,
                         "end": 76
+                      },
+                      "comma": {
+                        "start": 76,
+                        "end": 77
                       }
                     },
                     {
@@ -380,6 +406,10 @@ $foo::BAR[2][1][0];
                       "span": {
                         "start": 78,
                         "end": 79
+                      },
+                      "comma": {
+                        "start": 79,
+                        "end": 80
                       }
                     },
                     {
@@ -457,6 +487,10 @@ $foo::BAR[2][1][0];
                                   "span": {
                                     "start": 94,
                                     "end": 95
+                                  },
+                                  "comma": {
+                                    "start": 95,
+                                    "end": 96
                                   }
                                 },
                                 {
@@ -474,6 +508,10 @@ $foo::BAR[2][1][0];
                                   "span": {
                                     "start": 97,
                                     "end": 98
+                                  },
+                                  "comma": {
+                                    "start": 98,
+                                    "end": 99
                                   }
                                 },
                                 {
@@ -561,7 +599,16 @@ $foo::BAR[2][1][0];
             "ArrayAccess": {
               "array": {
                 "kind": {
-                  "Identifier": "FOO"
+                  "ConstFetch": {
+                    "parts": [
+                      "FOO"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 114,
+                      "end": 117
+                    }
+                  }
                 },
                 "span": {
                   "start": 114,
@@ -599,12 +646,15 @@ $foo::BAR[2][1][0];
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 122,
-                        "end": 125
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 122,
+                          "end": 125
+                        }
                       }
                     },
                     "member": {
@@ -660,12 +710,14 @@ $foo::BAR[2][1][0];
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Variable": "foo"
-                                  },
-                                  "span": {
-                                    "start": 135,
-                                    "end": 139
+                                  "Expr": {
+                                    "kind": {
+                                      "Variable": "foo"
+                                    },
+                                    "span": {
+                                      "start": 135,
+                                      "end": 139
+                                    }
                                   }
                                 },
                                 "member": {
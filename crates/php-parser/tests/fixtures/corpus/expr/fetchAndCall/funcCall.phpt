@@ -22,7 +22,16 @@ a()['b'];
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "a"
+                  "Name": {
+                    "parts": [
+                      "a"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 35,
+                      "end": 36
+                    }
+                  }
                 },
                 "span": {
                   "start": 35,
@@ -80,7 +89,10 @@ a()['b'];
                 "kind": {
                   "VariableVariable": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 48,
@@ -206,7 +218,10 @@ a()['b'];
                     },
                     "index": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 74,
@@ -272,7 +287,10 @@ a()['b'];
                     },
                     "index": {
                       "kind": {
-                        "String": "c"
+                        "String": {
+                          "value": "c",
+                          "raw": "c"
+                        }
                       },
                       "span": {
                         "start": 88,
@@ -310,7 +328,16 @@ a()['b'];
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "a"
+                        "Name": {
+                          "parts": [
+                            "a"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 120,
+                            "end": 121
+                          }
+                        }
                       },
                       "span": {
                         "start": 120,
@@ -327,7 +354,10 @@ a()['b'];
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 124,
@@ -12,7 +12,16 @@ bar(class: 0);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -31,6 +40,10 @@ bar(class: 0);
                       "end": 11
                     }
                   },
+                  "colon": {
+                    "start": 11,
+                    "end": 12
+                  },
                   "value": {
                     "kind": {
                       "Variable": "b"
@@ -45,6 +58,10 @@ bar(class: 0);
                   "span": {
                     "start": 10,
                     "end": 15
+                  },
+                  "comma": {
+                    "start": 15,
+                    "end": 16
                   }
                 },
                 {
@@ -58,6 +75,10 @@ bar(class: 0);
                       "end": 18
                     }
                   },
+                  "colon": {
+                    "start": 18,
+                    "end": 19
+                  },
                   "value": {
                     "kind": {
                       "Variable": "d"
@@ -95,7 +116,16 @@ bar(class: 0);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "bar"
+                  "Name": {
+                    "parts": [
+                      "bar"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 25,
+                      "end": 28
+                    }
+                  }
                 },
                 "span": {
                   "start": 25,
@@ -114,6 +144,10 @@ bar(class: 0);
                       "end": 34
                     }
                   },
+                  "colon": {
+                    "start": 34,
+                    "end": 35
+                  },
                   "value": {
                     "kind": {
                       "Int": 0
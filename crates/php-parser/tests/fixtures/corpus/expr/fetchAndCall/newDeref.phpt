@@ -19,12 +19,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "A"
-                          },
-                          "span": {
-                            "start": 12,
-                            "end": 13
+                          "Name": {
+                            "parts": [
+                              "A"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 13
+                            }
                           }
                         },
                         "args": []
@@ -74,12 +77,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "A"
-                          },
-                          "span": {
-                            "start": 24,
-                            "end": 25
+                          "Name": {
+                            "parts": [
+                              "A"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 24,
+                              "end": 25
+                            }
                           }
                         },
                         "args": []
@@ -130,12 +136,15 @@
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "A"
-                          },
-                          "span": {
-                            "start": 38,
-                            "end": 39
+                          "Name": {
+                            "parts": [
+                              "A"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 38,
+                              "end": 39
+                            }
                           }
                         },
                         "args": []
@@ -154,7 +163,10 @@
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 41,
@@ -188,12 +200,15 @@
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "A"
-                                },
-                                "span": {
-                                  "start": 52,
-                                  "end": 53
+                                "Name": {
+                                  "parts": [
+                                    "A"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 52,
+                                    "end": 53
+                                  }
                                 }
                               },
                               "args": []
@@ -212,7 +227,10 @@
                     },
                     "index": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 55,
@@ -228,7 +246,10 @@
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 60,
@@ -87,7 +87,10 @@ $a->b()['c'];
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 49,
@@ -160,7 +163,10 @@ $a->b()['c'];
               },
               "method": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 96,
@@ -256,7 +262,10 @@ $a->b()['c'];
                     },
                     "index": {
                       "kind": {
-                        "String": "c"
+                        "String": {
+                          "value": "c",
+                          "raw": "c"
+                        }
                       },
                       "span": {
                         "start": 121,
@@ -320,7 +329,10 @@ $a->b()['c'];
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 161,
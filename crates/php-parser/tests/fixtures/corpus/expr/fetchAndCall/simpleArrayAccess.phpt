@@ -24,7 +24,10 @@ ${$a}['b'];
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 10,
@@ -63,7 +66,10 @@ ${$a}['b'];
                     },
                     "index": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 19,
@@ -79,7 +85,10 @@ ${$a}['b'];
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 24,
@@ -171,7 +180,10 @@ ${$a}['b'];
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 47,
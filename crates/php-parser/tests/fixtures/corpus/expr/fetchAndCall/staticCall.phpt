@@ -25,12 +25,15 @@ $a['b']::c();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 33,
-                  "end": 34
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 33,
+                    "end": 34
+                  }
                 }
               },
               "method": {
@@ -65,17 +68,23 @@ $a['b']::c();
                 "kind": {
                   "ClassConstAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 41,
-                        "end": 42
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 41,
+                          "end": 42
+                        }
                       }
                     },
                     "member": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 45,
@@ -109,12 +118,15 @@ $a['b']::c();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 53,
-                  "end": 54
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 53,
+                    "end": 54
+                  }
                 }
               },
               "method": {
@@ -152,12 +164,15 @@ $a['b']::c();
                       "kind": {
                         "StaticPropertyAccess": {
                           "class": {
-                            "kind": {
-                              "Identifier": "A"
-                            },
-                            "span": {
-                              "start": 62,
-                              "end": 63
+                            "Name": {
+                              "parts": [
+                                "A"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 62,
+                                "end": 63
+                              }
                             }
                           },
                           "member": {
@@ -178,7 +193,10 @@ $a['b']::c();
                     },
                     "index": {
                       "kind": {
-                        "String": "c"
+                        "String": {
+                          "value": "c",
+                          "raw": "c"
+                        }
                       },
                       "span": {
                         "start": 68,
@@ -221,12 +239,15 @@ $a['b']::c();
                             "kind": {
                               "StaticPropertyAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "A"
-                                  },
-                                  "span": {
-                                    "start": 76,
-                                    "end": 77
+                                  "Name": {
+                                    "parts": [
+                                      "A"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 76,
+                                      "end": 77
+                                    }
                                   }
                                 },
                                 "member": {
@@ -247,7 +268,10 @@ $a['b']::c();
                           },
                           "index": {
                             "kind": {
-                              "String": "c"
+                              "String": {
+                                "value": "c",
+                                "raw": "c"
+                              }
                             },
                             "span": {
                               "start": 82,
@@ -263,7 +287,10 @@ $a['b']::c();
                     },
                     "index": {
                       "kind": {
-                        "String": "d"
+                        "String": {
+                          "value": "d",
+                          "raw": "d"
+                        }
                       },
                       "span": {
                         "start": 87,
@@ -300,12 +327,15 @@ $a['b']::c();
                 "kind": {
                   "StaticMethodCall": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 119,
-                        "end": 120
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 119,
+                          "end": 120
+                        }
                       }
                     },
                     "method": {
@@ -327,7 +357,10 @@ $a['b']::c();
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 126,
@@ -353,10 +386,7 @@ $a['b']::c();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 158,
                   "end": 164
                 }
@@ -390,12 +420,14 @@ $a['b']::c();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Variable": "a"
-                },
-                "span": {
-                  "start": 171,
-                  "end": 173
+                "Expr": {
+                  "kind": {
+                    "Variable": "a"
+                  },
+                  "span": {
+                    "start": 171,
+                    "end": 173
+                  }
                 }
               },
               "method": {
@@ -427,20 +459,25 @@ $a['b']::c();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "VariableVariable": {
-                    "kind": {
-                      "String": "a"
-                    },
-                    "span": {
-                      "start": 182,
-                      "end": 185
+                "Expr": {
+                  "kind": {
+                    "VariableVariable": {
+                      "kind": {
+                        "String": {
+                          "value": "a",
+                          "raw": "a"
+                        }
+                      },
+                      "span": {
+                        "start": 182,
+                        "end": 185
+                      }
                     }
+                  },
+                  "span": {
+                    "start": 180,
+                    "end": 186
                   }
-                },
-                "span": {
-                  "start": 180,
-                  "end": 186
                 }
               },
               "method": {
@@ -472,31 +509,36 @@ $a['b']::c();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "ArrayAccess": {
-                    "array": {
-                      "kind": {
-                        "Variable": "a"
-                      },
-                      "span": {
-                        "start": 193,
-                        "end": 195
-                      }
-                    },
-                    "index": {
-                      "kind": {
-                        "String": "b"
+                "Expr": {
+                  "kind": {
+                    "ArrayAccess": {
+                      "array": {
+                        "kind": {
+                          "Variable": "a"
+                        },
+                        "span": {
+                          "start": 193,
+                          "end": 195
+                        }
                       },
-                      "span": {
-                        "start": 196,
-                        "end": 199
+                      "index": {
+                        "kind": {
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
+                        },
+                        "span": {
+                          "start": 196,
+                          "end": 199
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 193,
+                    "end": 200
                   }
-                },
-                "span": {
-                  "start": 193,
-                  "end": 200
                 }
               },
               "method": {
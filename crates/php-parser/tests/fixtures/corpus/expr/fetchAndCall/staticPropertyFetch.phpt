@@ -19,12 +19,15 @@ A::$b['c'];
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 35,
-                  "end": 36
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 35,
+                    "end": 36
+                  }
                 }
               },
               "member": {
@@ -55,12 +58,15 @@ A::$b['c'];
           "kind": {
             "StaticPropertyAccessDynamic": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 42,
-                  "end": 43
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 42,
+                    "end": 43
+                  }
                 }
               },
               "member": {
@@ -99,19 +105,25 @@ A::$b['c'];
           "kind": {
             "StaticPropertyAccessDynamic": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 50,
-                  "end": 51
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 50,
+                    "end": 51
+                  }
                 }
               },
               "member": {
                 "kind": {
                   "VariableVariable": {
                     "kind": {
-                      "String": "b"
+                      "String": {
+                        "value": "b",
+                        "raw": "b"
+                      }
                     },
                     "span": {
                       "start": 55,
@@ -146,12 +158,15 @@ A::$b['c'];
                 "kind": {
                   "StaticPropertyAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 78,
-                        "end": 79
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 78,
+                          "end": 79
+                        }
                       }
                     },
                     "member": {
@@ -172,7 +187,10 @@ A::$b['c'];
               },
               "index": {
                 "kind": {
-                  "String": "c"
+                  "String": {
+                    "value": "c",
+                    "raw": "c"
+                  }
                 },
                 "span": {
                   "start": 84,
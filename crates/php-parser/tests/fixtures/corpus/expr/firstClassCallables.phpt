@@ -22,7 +22,16 @@ Cannot create Closure as attribute argument
               "kind": {
                 "Function": {
                   "kind": {
-                    "Identifier": "foo"
+                    "Name": {
+                      "parts": [
+                        "foo"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 6,
+                        "end": 9
+                      }
+                    }
                   },
                   "span": {
                     "start": 6,
@@ -91,12 +100,15 @@ Cannot create Closure as attribute argument
               "kind": {
                 "StaticMethod": {
                   "class": {
-                    "kind": {
-                      "Identifier": "A"
-                    },
-                    "span": {
-                      "start": 33,
-                      "end": 34
+                    "Name": {
+                      "parts": [
+                        "A"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 33,
+                        "end": 34
+                      }
                     }
                   },
                   "method": {
@@ -16,7 +16,10 @@ eval('A');
               "Include",
               {
                 "kind": {
-                  "String": "A.php"
+                  "String": {
+                    "value": "A.php",
+                    "raw": "A.php"
+                  }
                 },
                 "span": {
                   "start": 14,
@@ -44,7 +47,10 @@ eval('A');
               "IncludeOnce",
               {
                 "kind": {
-                  "String": "A.php"
+                  "String": {
+                    "value": "A.php",
+                    "raw": "A.php"
+                  }
                 },
                 "span": {
                   "start": 36,
@@ -72,7 +78,10 @@ eval('A');
               "Require",
               {
                 "kind": {
-                  "String": "A.php"
+                  "String": {
+                    "value": "A.php",
+                    "raw": "A.php"
+                  }
                 },
                 "span": {
                   "start": 53,
@@ -100,7 +109,10 @@ eval('A');
               "RequireOnce",
               {
                 "kind": {
-                  "String": "A.php"
+                  "String": {
+                    "value": "A.php",
+                    "raw": "A.php"
+                  }
                 },
                 "span": {
                   "start": 75,
@@ -126,7 +138,10 @@ eval('A');
           "kind": {
             "Eval": {
               "kind": {
-                "String": "A"
+                "String": {
+                  "value": "A",
+                  "raw": "A"
+                }
               },
               "span": {
                 "start": 89,
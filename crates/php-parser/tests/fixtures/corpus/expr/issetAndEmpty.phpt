@@ -115,7 +115,16 @@ empty(array(1, 2, 3));
                 "FunctionCall": {
                   "name": {
                     "kind": {
-                      "Identifier": "foo"
+                      "Name": {
+                        "parts": [
+                          "foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 54,
+                          "end": 57
+                        }
+                      }
                     },
                     "span": {
                       "start": 54,
@@ -164,6 +173,10 @@ empty(array(1, 2, 3));
                     "span": {
                       "start": 74,
                       "end": 75
+                    },
+                    "comma": {
+                      "start": 75,
+                      "end": 76
                     }
                   },
                   {
@@ -181,6 +194,10 @@ empty(array(1, 2, 3));
                     "span": {
                       "start": 77,
                       "end": 78
+                    },
+                    "comma": {
+                      "start": 78,
+                      "end": 79
                     }
                   },
                   {
@@ -15,26 +15,29 @@ list('k' => &$v) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "v"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "v"
+                          },
+                          "span": {
+                            "start": 13,
+                            "end": 15
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 13,
+                          "start": 12,
                           "end": 15
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 12,
-                        "end": 15
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -71,34 +74,40 @@ list('k' => &$v) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "k"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "k",
+                              "raw": "k"
+                            }
+                          },
+                          "span": {
+                            "start": 28,
+                            "end": 31
+                          }
                         },
-                        "span": {
-                          "start": 28,
-                          "end": 31
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "v"
+                        "value": {
+                          "kind": {
+                            "Variable": "v"
+                          },
+                          "span": {
+                            "start": 36,
+                            "end": 38
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 36,
+                          "start": 28,
                           "end": 38
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 28,
-                        "end": 38
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 23,
@@ -135,26 +144,28 @@ list('k' => &$v) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "v"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "v"
+                          },
+                          "span": {
+                            "start": 48,
+                            "end": 50
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 48,
+                          "start": 47,
                           "end": 50
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 47,
-                        "end": 50
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 46,
@@ -191,34 +202,39 @@ list('k' => &$v) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "k"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "k",
+                              "raw": "k"
+                            }
+                          },
+                          "span": {
+                            "start": 59,
+                            "end": 62
+                          }
                         },
-                        "span": {
-                          "start": 59,
-                          "end": 62
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "v"
+                        "value": {
+                          "kind": {
+                            "Variable": "v"
+                          },
+                          "span": {
+                            "start": 67,
+                            "end": 69
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 67,
+                          "start": 59,
                           "end": 69
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 59,
-                        "end": 69
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 58,
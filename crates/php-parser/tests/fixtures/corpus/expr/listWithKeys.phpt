@@ -13,33 +13,39 @@ list('a' => list($b => $c), 'd' => $e) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
+                          },
+                          "span": {
+                            "start": 12,
+                            "end": 15
+                          }
                         },
-                        "span": {
-                          "start": 12,
-                          "end": 15
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 19,
+                            "end": 21
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 19,
+                          "start": 12,
                           "end": 21
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 12,
-                        "end": 21
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -53,7 +59,10 @@ list('a' => list($b => $c), 'd' => $e) = $x;
                     {
                       "key": {
                         "kind": {
-                          "String": "a"
+                          "String": {
+                            "value": "a",
+                            "raw": "a"
+                          }
                         },
                         "span": {
                           "start": 26,
@@ -62,7 +71,10 @@ list('a' => list($b => $c), 'd' => $e) = $x;
                       },
                       "value": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 33,
@@ -102,84 +114,100 @@ list('a' => list($b => $c), 'd' => $e) = $x;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
+                          },
+                          "span": {
+                            "start": 44,
+                            "end": 47
+                          }
                         },
-                        "span": {
-                          "start": 44,
-                          "end": 47
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": {
-                                "kind": {
-                                  "Variable": "b"
-                                },
-                                "span": {
-                                  "start": 56,
-                                  "end": 58
-                                }
-                              },
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
-                                },
-                                "span": {
-                                  "start": 62,
-                                  "end": 64
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 56,
+                                      "end": 58
+                                    }
+                                  },
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 62,
+                                      "end": 64
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 56,
+                                    "end": 64
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 56,
-                                "end": 64
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 51,
+                            "end": 65
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 51,
+                          "start": 44,
                           "end": 65
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 44,
-                        "end": 65
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "d"
                         },
-                        "span": {
-                          "start": 67,
-                          "end": 70
+                        "comma": {
+                          "start": 65,
+                          "end": 66
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "e"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "d",
+                              "raw": "d"
+                            }
+                          },
+                          "span": {
+                            "start": 67,
+                            "end": 70
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "e"
+                          },
+                          "span": {
+                            "start": 74,
+                            "end": 76
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 74,
+                          "start": 67,
                           "end": 76
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 67,
-                        "end": 76
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 39,
@@ -10,81 +10,97 @@ echo match (1) {
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Match": {
-                "subject": {
-                  "kind": {
-                    "Int": 1
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Match": {
+                  "subject": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 19,
+                      "end": 20
+                    }
                   },
-                  "span": {
-                    "start": 19,
-                    "end": 20
-                  }
-                },
-                "arms": [
-                  {
-                    "conditions": [
-                      {
+                  "arms": [
+                    {
+                      "conditions": [
+                        {
+                          "kind": {
+                            "Int": 0
+                          },
+                          "span": {
+                            "start": 28,
+                            "end": 29
+                          }
+                        }
+                      ],
+                      "body": {
                         "kind": {
-                          "Int": 0
+                          "String": {
+                            "value": "Foo",
+                            "raw": "Foo"
+                          }
                         },
                         "span": {
-                          "start": 28,
-                          "end": 29
+                          "start": 33,
+                          "end": 38
                         }
-                      }
-                    ],
-                    "body": {
-                      "kind": {
-                        "String": "Foo"
                       },
                       "span": {
-                        "start": 33,
+                        "start": 28,
                         "end": 38
+                      },
+                      "comma": {
+                        "start": 38,
+                        "end": 39
                       }
                     },
-                    "span": {
-                      "start": 28,
-                      "end": 38
-                    }
-                  },
-                  {
-                    "conditions": [
-                      {
+                    {
+                      "conditions": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 44,
+                            "end": 45
+                          }
+                        }
+                      ],
+                      "body": {
                         "kind": {
-                          "Int": 1
+                          "String": {
+                            "value": "Bar",
+                            "raw": "Bar"
+                          }
                         },
                         "span": {
-                          "start": 44,
-                          "end": 45
+                          "start": 49,
+                          "end": 54
                         }
-                      }
-                    ],
-                    "body": {
-                      "kind": {
-                        "String": "Bar"
                       },
                       "span": {
-                        "start": 49,
+                        "start": 44,
                         "end": 54
+                      },
+                      "comma": {
+                        "start": 54,
+                        "end": 55
                       }
-                    },
-                    "span": {
-                      "start": 44,
-                      "end": 54
                     }
-                  }
-                ]
+                  ]
+                }
+              },
+              "span": {
+                "start": 12,
+                "end": 57
               }
-            },
-            "span": {
-              "start": 12,
-              "end": 57
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 7,
@@ -59,7 +59,10 @@ $value = match (1) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "Foo"
+                            "String": {
+                              "value": "Foo",
+                              "raw": "Foo"
+                            }
                           },
                           "span": {
                             "start": 66,
@@ -69,6 +72,10 @@ $value = match (1) {
                         "span": {
                           "start": 58,
                           "end": 71
+                        },
+                        "comma": {
+                          "start": 71,
+                          "end": 72
                         }
                       }
                     ]
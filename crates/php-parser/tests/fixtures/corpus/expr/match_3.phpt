@@ -41,12 +41,15 @@ $result = match ($operator) {
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "BinaryOperator"
-                                  },
-                                  "span": {
-                                    "start": 41,
-                                    "end": 55
+                                  "Name": {
+                                    "parts": [
+                                      "BinaryOperator"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 41,
+                                      "end": 55
+                                    }
                                   }
                                 },
                                 "member": {
@@ -98,6 +101,10 @@ $result = match ($operator) {
                         "span": {
                           "start": 41,
                           "end": 75
+                        },
+                        "comma": {
+                          "start": 75,
+                          "end": 76
                         }
                       }
                     ]
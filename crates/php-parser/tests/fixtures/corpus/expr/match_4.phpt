@@ -50,7 +50,10 @@ $value = match ($char) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "1"
+                            "String": {
+                              "value": "1",
+                              "raw": "1"
+                            }
                           },
                           "span": {
                             "start": 41,
@@ -60,13 +63,20 @@ $value = match ($char) {
                         "span": {
                           "start": 36,
                           "end": 44
+                        },
+                        "comma": {
+                          "start": 44,
+                          "end": 45
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "default"
+                            "String": {
+                              "value": "default",
+                              "raw": "default"
+                            }
                           },
                           "span": {
                             "start": 61,
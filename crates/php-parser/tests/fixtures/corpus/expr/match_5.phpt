@@ -59,7 +59,10 @@ $value = match (1) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "Foo"
+                            "String": {
+                              "value": "Foo",
+                              "raw": "Foo"
+                            }
                           },
                           "span": {
                             "start": 41,
@@ -69,13 +72,20 @@ $value = match (1) {
                         "span": {
                           "start": 32,
                           "end": 46
+                        },
+                        "comma": {
+                          "start": 46,
+                          "end": 47
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "Bar"
+                            "String": {
+                              "value": "Bar",
+                              "raw": "Bar"
+                            }
                           },
                           "span": {
                             "start": 64,
@@ -85,6 +95,10 @@ $value = match (1) {
                         "span": {
                           "start": 52,
                           "end": 69
+                        },
+                        "comma": {
+                          "start": 69,
+                          "end": 70
                         }
                       }
                     ]
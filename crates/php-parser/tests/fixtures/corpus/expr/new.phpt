@@ -24,12 +24,15 @@ new $a->b['c']();
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 11,
-                  "end": 12
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 11,
+                    "end": 12
+                  }
                 }
               },
               "args": []
@@ -52,12 +55,15 @@ new $a->b['c']();
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 18,
-                  "end": 19
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 18,
+                    "end": 19
+                  }
                 }
               },
               "args": [
@@ -99,12 +105,14 @@ new $a->b['c']();
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Variable": "a"
-                },
-                "span": {
-                  "start": 55,
-                  "end": 57
+                "Expr": {
+                  "kind": {
+                    "Variable": "a"
+                  },
+                  "span": {
+                    "start": 55,
+                    "end": 57
+                  }
                 }
               },
               "args": []
@@ -133,12 +141,14 @@ new $a->b['c']();
                       "kind": {
                         "New": {
                           "class": {
-                            "kind": {
-                              "Variable": "a"
-                            },
-                            "span": {
-                              "start": 65,
-                              "end": 67
+                            "Expr": {
+                              "kind": {
+                                "Variable": "a"
+                              },
+                              "span": {
+                                "start": 65,
+                                "end": 67
+                              }
                             }
                           },
                           "args": []
@@ -151,7 +161,10 @@ new $a->b['c']();
                     },
                     "index": {
                       "kind": {
-                        "String": "b"
+                        "String": {
+                          "value": "b",
+                          "raw": "b"
+                        }
                       },
                       "span": {
                         "start": 68,
@@ -185,23 +198,28 @@ new $a->b['c']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 80,
+                            "end": 81
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 80,
-                        "end": 81
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 76,
+                    "end": 81
                   }
-                },
-                "span": {
-                  "start": 76,
-                  "end": 81
                 }
               },
               "method": {
@@ -236,12 +254,14 @@ new $a->b['c']();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Variable": "a"
-                      },
-                      "span": {
-                        "start": 115,
-                        "end": 117
+                      "Expr": {
+                        "kind": {
+                          "Variable": "a"
+                        },
+                        "span": {
+                          "start": 115,
+                          "end": 117
+                        }
                       }
                     },
                     "args": []
@@ -287,12 +307,14 @@ new $a->b['c']();
                       "kind": {
                         "New": {
                           "class": {
-                            "kind": {
-                              "Variable": "a"
-                            },
-                            "span": {
-                              "start": 128,
-                              "end": 130
+                            "Expr": {
+                              "kind": {
+                                "Variable": "a"
+                              },
+                              "span": {
+                                "start": 128,
+                                "end": 130
+                              }
                             }
                           },
                           "args": []
@@ -357,12 +379,14 @@ new $a->b['c']();
                             "kind": {
                               "New": {
                                 "class": {
-                                  "kind": {
-                                    "Variable": "a"
-                                  },
-                                  "span": {
-                                    "start": 144,
-                                    "end": 146
+                                  "Expr": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 144,
+                                      "end": 146
+                                    }
                                   }
                                 },
                                 "args": []
@@ -391,7 +415,10 @@ new $a->b['c']();
                     },
                     "index": {
                       "kind": {
-                        "String": "c"
+                        "String": {
+                          "value": "c",
+                          "raw": "c"
+                        }
                       },
                       "span": {
                         "start": 150,
@@ -427,12 +454,15 @@ new $a->b['c']();
               "kind": {
                 "New": {
                   "class": {
-                    "kind": {
-                      "Identifier": "A"
-                    },
-                    "span": {
-                      "start": 222,
-                      "end": 223
+                    "Name": {
+                      "parts": [
+                        "A"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 222,
+                        "end": 223
+                      }
                     }
                   },
                   "args": []
@@ -30,12 +30,15 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 11,
-                        "end": 12
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 11,
+                          "end": 12
+                        }
                       }
                     },
                     "args": []
@@ -77,12 +80,15 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 25,
-                        "end": 26
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 25,
+                          "end": 26
+                        }
                       }
                     },
                     "args": []
@@ -122,23 +128,28 @@ new class {}();
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 41,
+                            "end": 42
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 41,
-                        "end": 42
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 37,
+                    "end": 44
                   }
-                },
-                "span": {
-                  "start": 37,
-                  "end": 44
                 }
               },
               "member": {
@@ -169,23 +180,28 @@ new class {}();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 55,
+                            "end": 56
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 55,
-                        "end": 56
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 51,
+                    "end": 58
                   }
-                },
-                "span": {
-                  "start": 51,
-                  "end": 58
                 }
               },
               "method": {
@@ -217,23 +233,28 @@ new class {}();
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 71,
+                            "end": 72
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 71,
-                        "end": 72
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 67,
+                    "end": 74
                   }
-                },
-                "span": {
-                  "start": 67,
-                  "end": 74
                 }
               },
               "member": {
@@ -267,12 +288,15 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 86,
-                        "end": 87
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 86,
+                          "end": 87
+                        }
                       }
                     },
                     "args": []
@@ -314,12 +338,15 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 98,
-                        "end": 99
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 98,
+                          "end": 99
+                        }
                       }
                     },
                     "args": []
@@ -353,23 +380,25 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 106,
+                          "end": 118
                         }
-                      },
-                      "span": {
-                        "start": 106,
-                        "end": 118
                       }
                     },
                     "args": []
@@ -411,23 +440,25 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 125,
+                          "end": 137
                         }
-                      },
-                      "span": {
-                        "start": 125,
-                        "end": 137
                       }
                     },
                     "args": []
@@ -467,34 +498,38 @@ new class {}();
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Expr": {
+                          "kind": {
+                            "AnonymousClass": {
+                              "name": null,
+                              "modifiers": {
+                                "is_abstract": false,
+                                "is_final": false,
+                                "is_readonly": false
+                              },
+                              "extends": null,
+                              "implements": [],
+                              "members": [],
+                              "attributes": []
+                            }
                           },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                          "span": {
+                            "start": 146,
+                            "end": 158
+                          }
                         }
                       },
-                      "span": {
-                        "start": 146,
-                        "end": 158
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 146,
+                    "end": 158
                   }
-                },
-                "span": {
-                  "start": 146,
-                  "end": 158
                 }
               },
               "member": {
@@ -525,34 +560,38 @@ new class {}();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Expr": {
+                          "kind": {
+                            "AnonymousClass": {
+                              "name": null,
+                              "modifiers": {
+                                "is_abstract": false,
+                                "is_final": false,
+                                "is_readonly": false
+                              },
+                              "extends": null,
+                              "implements": [],
+                              "members": [],
+                              "attributes": []
+                            }
                           },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                          "span": {
+                            "start": 165,
+                            "end": 177
+                          }
                         }
                       },
-                      "span": {
-                        "start": 165,
-                        "end": 177
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 165,
+                    "end": 177
                   }
-                },
-                "span": {
-                  "start": 165,
-                  "end": 177
                 }
               },
               "method": {
@@ -584,34 +623,38 @@ new class {}();
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Expr": {
+                          "kind": {
+                            "AnonymousClass": {
+                              "name": null,
+                              "modifiers": {
+                                "is_abstract": false,
+                                "is_final": false,
+                                "is_readonly": false
+                              },
+                              "extends": null,
+                              "implements": [],
+                              "members": [],
+                              "attributes": []
+                            }
                           },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                          "span": {
+                            "start": 186,
+                            "end": 198
+                          }
                         }
                       },
-                      "span": {
-                        "start": 186,
-                        "end": 198
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 186,
+                    "end": 198
                   }
-                },
-                "span": {
-                  "start": 186,
-                  "end": 198
                 }
               },
               "member": {
@@ -645,23 +688,25 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 206,
+                          "end": 218
                         }
-                      },
-                      "span": {
-                        "start": 206,
-                        "end": 218
                       }
                     },
                     "args": []
@@ -703,23 +748,25 @@ new class {}();
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": false
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [],
-                          "attributes": []
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": false
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 223,
+                          "end": 235
                         }
-                      },
-                      "span": {
-                        "start": 223,
-                        "end": 235
                       }
                     },
                     "args": []
@@ -14,10 +14,12 @@ expected identifier, found ';'
           "kind": {
             "New": {
               "class": {
-                "kind": "Error",
-                "span": {
-                  "start": 9,
-                  "end": 10
+                "Expr": {
+                  "kind": "Error",
+                  "span": {
+                    "start": 9,
+                    "end": 10
+                  }
                 }
               },
               "args": []
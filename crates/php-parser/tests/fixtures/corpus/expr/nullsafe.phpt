@@ -110,12 +110,14 @@ new $a?->b;
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Variable": "a"
-                      },
-                      "span": {
-                        "start": 31,
-                        "end": 33
+                      "Expr": {
+                        "kind": {
+                          "Variable": "a"
+                        },
+                        "span": {
+                          "start": 31,
+                          "end": 33
+                        }
                       }
                     },
                     "args": []
@@ -213,7 +215,13 @@ new $a?->b;
                 }
               },
               {
-                "Literal": "?->b"
+                "Literal": {
+                  "value": "?->b",
+                  "span": {
+                    "start": 54,
+                    "end": 58
+                  }
+                }
               }
             ]
           },
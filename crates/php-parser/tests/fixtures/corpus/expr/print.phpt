@@ -9,12 +9,18 @@ print $a;
         "Expression": {
           "kind": {
             "Print": {
-              "kind": {
-                "Variable": "a"
+              "expr": {
+                "kind": {
+                  "Variable": "a"
+                },
+                "span": {
+                  "start": 12,
+                  "end": 14
+                }
               },
-              "span": {
-                "start": 12,
-                "end": 14
+              "keyword_span": {
+                "start": 6,
+                "end": 11
               }
             }
           },
@@ -14,7 +14,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": ""
+                "Literal": {
+                  "value": "",
+                  "span": {
+                    "start": 7,
+                    "end": 7
+                  }
+                }
               }
             ]
           },
@@ -35,7 +41,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "test"
+                "Literal": {
+                  "value": "test",
+                  "span": {
+                    "start": 11,
+                    "end": 15
+                  }
+                }
               }
             ]
           },
@@ -56,7 +68,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "test "
+                "Literal": {
+                  "value": "test ",
+                  "span": {
+                    "start": 19,
+                    "end": 24
+                  }
+                }
               },
               {
                 "Expr": {
@@ -88,7 +106,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "test \\`"
+                "Literal": {
+                  "value": "test \\`",
+                  "span": {
+                    "start": 30,
+                    "end": 37
+                  }
+                }
               }
             ]
           },
@@ -109,7 +133,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "test \""
+                "Literal": {
+                  "value": "test \"",
+                  "span": {
+                    "start": 41,
+                    "end": 48
+                  }
+                }
               }
             ]
           },
@@ -12,7 +12,16 @@ $a ?? throw new Exception;
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "test"
+                  "Name": {
+                    "parts": [
+                      "test"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -80,12 +89,15 @@ $a ?? throw new Exception;
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Exception"
-                          },
-                          "span": {
-                            "start": 38,
-                            "end": 47
+                          "Name": {
+                            "parts": [
+                              "Exception"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 38,
+                              "end": 47
+                            }
                           }
                         },
                         "args": []
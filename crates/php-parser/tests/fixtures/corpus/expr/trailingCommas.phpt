@@ -17,7 +17,16 @@ isset($a, $b, );
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -41,6 +50,10 @@ isset($a, $b, );
                   "span": {
                     "start": 11,
                     "end": 13
+                  },
+                  "comma": {
+                    "start": 13,
+                    "end": 14
                   }
                 },
                 {
@@ -59,6 +72,10 @@ isset($a, $b, );
                   "span": {
                     "start": 15,
                     "end": 17
+                  },
+                  "comma": {
+                    "start": 17,
+                    "end": 18
                   }
                 }
               ]
@@ -115,6 +132,10 @@ isset($a, $b, );
                   "span": {
                     "start": 32,
                     "end": 34
+                  },
+                  "comma": {
+                    "start": 34,
+                    "end": 35
                   }
                 },
                 {
@@ -133,6 +154,10 @@ isset($a, $b, );
                   "span": {
                     "start": 36,
                     "end": 38
+                  },
+                  "comma": {
+                    "start": 38,
+                    "end": 39
                   }
                 }
               ]
@@ -155,12 +180,15 @@ isset($a, $b, );
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 43,
-                  "end": 46
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 43,
+                    "end": 46
+                  }
                 }
               },
               "method": {
@@ -189,6 +217,10 @@ isset($a, $b, );
                   "span": {
                     "start": 52,
                     "end": 54
+                  },
+                  "comma": {
+                    "start": 54,
+                    "end": 55
                   }
                 },
                 {
@@ -207,6 +239,10 @@ isset($a, $b, );
                   "span": {
                     "start": 56,
                     "end": 58
+                  },
+                  "comma": {
+                    "start": 58,
+                    "end": 59
                   }
                 }
               ]
@@ -229,12 +265,15 @@ isset($a, $b, );
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 67,
-                  "end": 70
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 67,
+                    "end": 70
+                  }
                 }
               },
               "args": [
@@ -254,6 +293,10 @@ isset($a, $b, );
                   "span": {
                     "start": 71,
                     "end": 73
+                  },
+                  "comma": {
+                    "start": 73,
+                    "end": 74
                   }
                 },
                 {
@@ -272,6 +315,10 @@ isset($a, $b, );
                   "span": {
                     "start": 75,
                     "end": 77
+                  },
+                  "comma": {
+                    "start": 77,
+                    "end": 78
                   }
                 }
               ]
@@ -27,7 +27,16 @@ __FUNCIONT__->length();
             "PropertyAccess": {
               "object": {
                 "kind": {
-                  "Identifier": "A"
+                  "ConstFetch": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 8
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -63,7 +72,16 @@ __FUNCIONT__->length();
             "MethodCall": {
               "object": {
                 "kind": {
-                  "Identifier": "A"
+                  "ConstFetch": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 18,
+                      "end": 19
+                    }
+                  }
                 },
                 "span": {
                   "start": 18,
@@ -100,7 +118,16 @@ __FUNCIONT__->length();
             "ArrayAccess": {
               "array": {
                 "kind": {
-                  "Identifier": "A"
+                  "ConstFetch": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 31,
+                      "end": 32
+                    }
+                  }
                 },
                 "span": {
                   "start": 31,
@@ -142,7 +169,16 @@ __FUNCIONT__->length();
                         "ArrayAccess": {
                           "array": {
                             "kind": {
-                              "Identifier": "A"
+                              "ConstFetch": {
+                                "parts": [
+                                  "A"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 37,
+                                  "end": 38
+                                }
+                              }
                             },
                             "span": {
                               "start": 37,
@@ -212,12 +248,15 @@ __FUNCIONT__->length();
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 50,
-                        "end": 51
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 50,
+                          "end": 51
+                        }
                       }
                     },
                     "member": {
@@ -273,12 +312,15 @@ __FUNCIONT__->length();
                             "kind": {
                               "ClassConstAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "A"
-                                  },
-                                  "span": {
-                                    "start": 59,
-                                    "end": 60
+                                  "Name": {
+                                    "parts": [
+                                      "A"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 59,
+                                      "end": 60
+                                    }
                                   }
                                 },
                                 "member": {
@@ -360,12 +402,15 @@ __FUNCIONT__->length();
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 74,
-                        "end": 75
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 74,
+                          "end": 75
+                        }
                       }
                     },
                     "member": {
@@ -415,12 +460,15 @@ __FUNCIONT__->length();
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 88,
-                        "end": 89
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 88,
+                          "end": 89
+                        }
                       }
                     },
                     "member": {
@@ -468,31 +516,36 @@ __FUNCIONT__->length();
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "ClassConstAccess": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 104,
-                        "end": 105
-                      }
-                    },
-                    "member": {
-                      "kind": {
-                        "Identifier": "B"
+                "Expr": {
+                  "kind": {
+                    "ClassConstAccess": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 104,
+                            "end": 105
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 107,
-                        "end": 108
+                      "member": {
+                        "kind": {
+                          "Identifier": "B"
+                        },
+                        "span": {
+                          "start": 107,
+                          "end": 108
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 104,
+                    "end": 108
                   }
-                },
-                "span": {
-                  "start": 104,
-                  "end": 108
                 }
               },
               "member": {
@@ -523,31 +576,36 @@ __FUNCIONT__->length();
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "ClassConstAccess": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 113,
-                        "end": 114
-                      }
-                    },
-                    "member": {
-                      "kind": {
-                        "Identifier": "B"
+                "Expr": {
+                  "kind": {
+                    "ClassConstAccess": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 113,
+                            "end": 114
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 116,
-                        "end": 117
+                      "member": {
+                        "kind": {
+                          "Identifier": "B"
+                        },
+                        "span": {
+                          "start": 116,
+                          "end": 117
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 113,
+                    "end": 117
                   }
-                },
-                "span": {
-                  "start": 113,
-                  "end": 117
                 }
               },
               "member": {
@@ -578,31 +636,36 @@ __FUNCIONT__->length();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "ClassConstAccess": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 123,
-                        "end": 124
-                      }
-                    },
-                    "member": {
-                      "kind": {
-                        "Identifier": "B"
+                "Expr": {
+                  "kind": {
+                    "ClassConstAccess": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 123,
+                            "end": 124
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 126,
-                        "end": 127
+                      "member": {
+                        "kind": {
+                          "Identifier": "B"
+                        },
+                        "span": {
+                          "start": 126,
+                          "end": 127
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 123,
+                    "end": 127
                   }
-                },
-                "span": {
-                  "start": 123,
-                  "end": 127
                 }
               },
               "method": {
@@ -707,7 +770,16 @@ __FUNCIONT__->length();
             "MethodCall": {
               "object": {
                 "kind": {
-                  "Identifier": "__FUNCIONT__"
+                  "ConstFetch": {
+                    "parts": [
+                      "__FUNCIONT__"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 174,
+                      "end": 186
+                    }
+                  }
                 },
                 "span": {
                   "start": 174,
@@ -26,7 +26,16 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "id"
+                        "Name": {
+                          "parts": [
+                            "id"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
+                        }
                       },
                       "span": {
                         "start": 7,
@@ -38,7 +47,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 10,
@@ -106,7 +118,16 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "id"
+                              "Name": {
+                                "parts": [
+                                  "id"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 26,
+                                  "end": 28
+                                }
+                              }
                             },
                             "span": {
                               "start": 26,
@@ -118,7 +139,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                               "name": null,
                               "value": {
                                 "kind": {
-                                  "String": "id"
+                                  "String": {
+                                    "value": "id",
+                                    "raw": "id"
+                                  }
                                 },
                                 "span": {
                                   "start": 29,
@@ -145,7 +169,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 35,
@@ -216,7 +243,16 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                               "FunctionCall": {
                                 "name": {
                                   "kind": {
-                                    "Identifier": "id"
+                                    "Name": {
+                                      "parts": [
+                                        "id"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 51,
+                                        "end": 53
+                                      }
+                                    }
                                   },
                                   "span": {
                                     "start": 51,
@@ -244,7 +280,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 58,
@@ -318,7 +357,16 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                     "FunctionCall": {
                                       "name": {
                                         "kind": {
-                                          "Identifier": "id"
+                                          "Name": {
+                                            "parts": [
+                                              "id"
+                                            ],
+                                            "kind": "Unqualified",
+                                            "span": {
+                                              "start": 74,
+                                              "end": 76
+                                            }
+                                          }
                                         },
                                         "span": {
                                           "start": 74,
@@ -335,7 +383,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                                   "key": null,
                                                   "value": {
                                                     "kind": {
-                                                      "String": "udef"
+                                                      "String": {
+                                                        "value": "udef",
+                                                        "raw": "udef"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 78,
@@ -346,13 +397,20 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                                   "span": {
                                                     "start": 78,
                                                     "end": 84
+                                                  },
+                                                  "comma": {
+                                                    "start": 84,
+                                                    "end": 85
                                                   }
                                                 },
                                                 {
                                                   "key": null,
                                                   "value": {
                                                     "kind": {
-                                                      "String": "id"
+                                                      "String": {
+                                                        "value": "id",
+                                                        "raw": "id"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 86,
@@ -416,7 +474,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 98,
@@ -547,7 +608,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                               "name": null,
                               "value": {
                                 "kind": {
-                                  "String": "id"
+                                  "String": {
+                                    "value": "id",
+                                    "raw": "id"
+                                  }
                                 },
                                 "span": {
                                   "start": 144,
@@ -574,7 +638,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 150,
@@ -790,7 +857,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 234,
@@ -880,13 +950,20 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                               "span": {
                                                 "start": 251,
                                                 "end": 255
+                                              },
+                                              "comma": {
+                                                "start": 255,
+                                                "end": 256
                                               }
                                             },
                                             {
                                               "key": null,
                                               "value": {
                                                 "kind": {
-                                                  "String": "id"
+                                                  "String": {
+                                                    "value": "id",
+                                                    "raw": "id"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 257,
@@ -919,7 +996,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                     "name": null,
                                     "value": {
                                       "kind": {
-                                        "String": "id"
+                                        "String": {
+                                          "value": "id",
+                                          "raw": "id"
+                                        }
                                       },
                                       "span": {
                                         "start": 265,
@@ -973,7 +1053,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 276,
@@ -1044,7 +1127,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                               "FunctionCall": {
                                 "name": {
                                   "kind": {
-                                    "String": "id"
+                                    "String": {
+                                      "value": "id",
+                                      "raw": "id"
+                                    }
                                   },
                                   "span": {
                                     "start": 293,
@@ -1064,7 +1150,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                               "name": null,
                               "value": {
                                 "kind": {
-                                  "String": "id"
+                                  "String": {
+                                    "value": "id",
+                                    "raw": "id"
+                                  }
                                 },
                                 "span": {
                                   "start": 300,
@@ -1091,7 +1180,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 306,
@@ -1164,7 +1256,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                   "Binary": {
                                     "left": {
                                       "kind": {
-                                        "String": "i"
+                                        "String": {
+                                          "value": "i",
+                                          "raw": "i"
+                                        }
                                       },
                                       "span": {
                                         "start": 324,
@@ -1174,7 +1269,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                                     "op": "Concat",
                                     "right": {
                                       "kind": {
-                                        "String": "d"
+                                        "String": {
+                                          "value": "d",
+                                          "raw": "d"
+                                        }
                                       },
                                       "span": {
                                         "start": 330,
@@ -1207,7 +1305,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 337,
@@ -1272,7 +1373,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "String": "\\id"
+                        "String": {
+                          "value": "\\id",
+                          "raw": "\\id"
+                        }
                       },
                       "span": {
                         "start": 354,
@@ -1284,7 +1388,10 @@ id(['udef', 'id'])[1]()('var_dump')(5);
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "var_dump"
+                            "String": {
+                              "value": "var_dump",
+                              "raw": "var_dump"
+                            }
                           },
                           "span": {
                             "start": 360,
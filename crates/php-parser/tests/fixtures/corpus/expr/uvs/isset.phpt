@@ -38,6 +38,10 @@ isset("str"->a);
                                       "span": {
                                         "start": 15,
                                         "end": 16
+                                      },
+                                      "comma": {
+                                        "start": 16,
+                                        "end": 17
                                       }
                                     },
                                     {
@@ -130,7 +134,10 @@ isset("str"->a);
                           {
                             "key": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 39,
@@ -139,7 +146,10 @@ isset("str"->a);
                             },
                             "value": {
                               "kind": {
-                                "String": "b"
+                                "String": {
+                                  "value": "b",
+                                  "raw": "b"
+                                }
                               },
                               "span": {
                                 "start": 46,
@@ -198,7 +208,10 @@ isset("str"->a);
                   "PropertyAccess": {
                     "object": {
                       "kind": {
-                        "String": "str"
+                        "String": {
+                          "value": "str",
+                          "raw": "str"
+                        }
                       },
                       "span": {
                         "start": 62,
@@ -18,7 +18,10 @@ Cannot use temporary expression in write context
             "MethodCall": {
               "object": {
                 "kind": {
-                  "String": "string"
+                  "String": {
+                    "value": "string",
+                    "raw": "string"
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -57,7 +60,13 @@ Cannot use temporary expression in write context
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "foo"
+                      "Literal": {
+                        "value": "foo",
+                        "span": {
+                          "start": 28,
+                          "end": 31
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -108,7 +117,13 @@ Cannot use temporary expression in write context
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "foo"
+                      "Literal": {
+                        "value": "foo",
+                        "span": {
+                          "start": 42,
+                          "end": 45
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -167,12 +182,18 @@ Cannot use temporary expression in write context
                               "Parenthesized": {
                                 "kind": {
                                   "Clone": {
-                                    "kind": {
-                                      "Variable": "obj"
+                                    "object": {
+                                      "kind": {
+                                        "Variable": "obj"
+                                      },
+                                      "span": {
+                                        "start": 69,
+                                        "end": 73
+                                      }
                                     },
-                                    "span": {
-                                      "start": 69,
-                                      "end": 73
+                                    "keyword_span": {
+                                      "start": 63,
+                                      "end": 68
                                     }
                                   }
                                 },
@@ -278,6 +299,10 @@ Cannot use temporary expression in write context
                             "span": {
                               "start": 86,
                               "end": 87
+                            },
+                            "comma": {
+                              "start": 87,
+                              "end": 88
                             }
                           },
                           {
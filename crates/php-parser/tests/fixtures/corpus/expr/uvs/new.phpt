@@ -15,12 +15,14 @@ new $weird[0]->foo::$className;
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Variable": "className"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 20
+                "Expr": {
+                  "kind": {
+                    "Variable": "className"
+                  },
+                  "span": {
+                    "start": 10,
+                    "end": 20
+                  }
                 }
               },
               "args": []
@@ -46,12 +48,14 @@ new $weird[0]->foo::$className;
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Variable": "array"
-                      },
-                      "span": {
-                        "start": 26,
-                        "end": 32
+                      "Expr": {
+                        "kind": {
+                          "Variable": "array"
+                        },
+                        "span": {
+                          "start": 26,
+                          "end": 32
+                        }
                       }
                     },
                     "args": []
@@ -64,7 +68,10 @@ new $weird[0]->foo::$className;
               },
               "index": {
                 "kind": {
-                  "String": "className"
+                  "String": {
+                    "value": "className",
+                    "raw": "className"
+                  }
                 },
                 "span": {
                   "start": 33,
@@ -93,12 +100,14 @@ new $weird[0]->foo::$className;
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Variable": "obj"
-                      },
-                      "span": {
-                        "start": 51,
-                        "end": 55
+                      "Expr": {
+                        "kind": {
+                          "Variable": "obj"
+                        },
+                        "span": {
+                          "start": 51,
+                          "end": 55
+                        }
                       }
                     },
                     "args": []
@@ -137,23 +146,28 @@ new $weird[0]->foo::$className;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "Test"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "Test"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 72,
+                            "end": 76
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 72,
-                        "end": 76
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 68,
+                    "end": 76
                   }
-                },
-                "span": {
-                  "start": 68,
-                  "end": 76
                 }
               },
               "member": {
@@ -184,23 +198,27 @@ new $weird[0]->foo::$className;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "New": {
-                    "class": {
-                      "kind": {
-                        "Variable": "test"
+                "Expr": {
+                  "kind": {
+                    "New": {
+                      "class": {
+                        "Expr": {
+                          "kind": {
+                            "Variable": "test"
+                          },
+                          "span": {
+                            "start": 94,
+                            "end": 99
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 94,
-                        "end": 99
-                      }
-                    },
-                    "args": []
+                      "args": []
+                    }
+                  },
+                  "span": {
+                    "start": 90,
+                    "end": 99
                   }
-                },
-                "span": {
-                  "start": 90,
-                  "end": 99
                 }
               },
               "member": {
@@ -231,61 +249,65 @@ new $weird[0]->foo::$className;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "PropertyAccess": {
-                    "object": {
-                      "kind": {
-                        "ArrayAccess": {
-                          "array": {
-                            "kind": {
-                              "New": {
-                                "class": {
-                                  "kind": {
-                                    "Variable": "weird"
+                "Expr": {
+                  "kind": {
+                    "PropertyAccess": {
+                      "object": {
+                        "kind": {
+                          "ArrayAccess": {
+                            "array": {
+                              "kind": {
+                                "New": {
+                                  "class": {
+                                    "Expr": {
+                                      "kind": {
+                                        "Variable": "weird"
+                                      },
+                                      "span": {
+                                        "start": 117,
+                                        "end": 123
+                                      }
+                                    }
                                   },
-                                  "span": {
-                                    "start": 117,
-                                    "end": 123
-                                  }
-                                },
-                                "args": []
+                                  "args": []
+                                }
+                              },
+                              "span": {
+                                "start": 113,
+                                "end": 123
                               }
                             },
-                            "span": {
-                              "start": 113,
-                              "end": 123
-                            }
-                          },
-                          "index": {
-                            "kind": {
-                              "Int": 0
-                            },
-                            "span": {
-                              "start": 124,
-                              "end": 125
+                            "index": {
+                              "kind": {
+                                "Int": 0
+                              },
+                              "span": {
+                                "start": 124,
+                                "end": 125
+                              }
                             }
                           }
+                        },
+                        "span": {
+                          "start": 113,
+                          "end": 126
                         }
                       },
-                      "span": {
-                        "start": 113,
-                        "end": 126
-                      }
-                    },
-                    "property": {
-                      "kind": {
-                        "Identifier": "foo"
-                      },
-                      "span": {
-                        "start": 128,
-                        "end": 131
+                      "property": {
+                        "kind": {
+                          "Identifier": "foo"
+                        },
+                        "span": {
+                          "start": 128,
+                          "end": 131
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 113,
+                    "end": 131
                   }
-                },
-                "span": {
-                  "start": 113,
-                  "end": 131
                 }
               },
               "member": {
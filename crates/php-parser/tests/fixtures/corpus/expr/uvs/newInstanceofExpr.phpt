@@ -13,40 +13,45 @@ $obj instanceof ('Foo' . $bar);
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Parenthesized": {
-                    "kind": {
-                      "Binary": {
-                        "left": {
-                          "kind": {
-                            "String": "Foo"
-                          },
-                          "span": {
-                            "start": 12,
-                            "end": 17
-                          }
-                        },
-                        "op": "Concat",
-                        "right": {
-                          "kind": {
-                            "Variable": "bar"
+                "Expr": {
+                  "kind": {
+                    "Parenthesized": {
+                      "kind": {
+                        "Binary": {
+                          "left": {
+                            "kind": {
+                              "String": {
+                                "value": "Foo",
+                                "raw": "Foo"
+                              }
+                            },
+                            "span": {
+                              "start": 12,
+                              "end": 17
+                            }
                           },
-                          "span": {
-                            "start": 20,
-                            "end": 24
+                          "op": "Concat",
+                          "right": {
+                            "kind": {
+                              "Variable": "bar"
+                            },
+                            "span": {
+                              "start": 20,
+                              "end": 24
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 12,
+                        "end": 24
                       }
-                    },
-                    "span": {
-                      "start": 12,
-                      "end": 24
                     }
+                  },
+                  "span": {
+                    "start": 11,
+                    "end": 25
                   }
-                },
-                "span": {
-                  "start": 11,
-                  "end": 25
                 }
               },
               "args": []
@@ -69,40 +74,45 @@ $obj instanceof ('Foo' . $bar);
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Parenthesized": {
-                    "kind": {
-                      "Binary": {
-                        "left": {
-                          "kind": {
-                            "String": "Foo"
+                "Expr": {
+                  "kind": {
+                    "Parenthesized": {
+                      "kind": {
+                        "Binary": {
+                          "left": {
+                            "kind": {
+                              "String": {
+                                "value": "Foo",
+                                "raw": "Foo"
+                              }
+                            },
+                            "span": {
+                              "start": 32,
+                              "end": 37
+                            }
                           },
-                          "span": {
-                            "start": 32,
-                            "end": 37
-                          }
-                        },
-                        "op": "Concat",
-                        "right": {
-                          "kind": {
-                            "Variable": "bar"
-                          },
-                          "span": {
-                            "start": 40,
-                            "end": 44
+                          "op": "Concat",
+                          "right": {
+                            "kind": {
+                              "Variable": "bar"
+                            },
+                            "span": {
+                              "start": 40,
+                              "end": 44
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 32,
+                        "end": 44
                       }
-                    },
-                    "span": {
-                      "start": 32,
-                      "end": 44
                     }
+                  },
+                  "span": {
+                    "start": 31,
+                    "end": 45
                   }
-                },
-                "span": {
-                  "start": 31,
-                  "end": 45
                 }
               },
               "args": [
@@ -142,8 +152,8 @@ $obj instanceof ('Foo' . $bar);
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Variable": "obj"
                 },
@@ -152,42 +162,46 @@ $obj instanceof ('Foo' . $bar);
                   "end": 57
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Parenthesized": {
-                    "kind": {
-                      "Binary": {
-                        "left": {
-                          "kind": {
-                            "String": "Foo"
-                          },
-                          "span": {
-                            "start": 70,
-                            "end": 75
-                          }
-                        },
-                        "op": "Concat",
-                        "right": {
-                          "kind": {
-                            "Variable": "bar"
+              "class": {
+                "Expr": {
+                  "kind": {
+                    "Parenthesized": {
+                      "kind": {
+                        "Binary": {
+                          "left": {
+                            "kind": {
+                              "String": {
+                                "value": "Foo",
+                                "raw": "Foo"
+                              }
+                            },
+                            "span": {
+                              "start": 70,
+                              "end": 75
+                            }
                           },
-                          "span": {
-                            "start": 78,
-                            "end": 82
+                          "op": "Concat",
+                          "right": {
+                            "kind": {
+                              "Variable": "bar"
+                            },
+                            "span": {
+                              "start": 78,
+                              "end": 82
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 70,
+                        "end": 82
                       }
-                    },
-                    "span": {
-                      "start": 70,
-                      "end": 82
                     }
+                  },
+                  "span": {
+                    "start": 69,
+                    "end": 83
                   }
-                },
-                "span": {
-                  "start": 69,
-                  "end": 83
                 }
               }
             }
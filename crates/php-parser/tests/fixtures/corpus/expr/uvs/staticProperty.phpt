@@ -17,12 +17,15 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 7
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 7
+                  }
                 }
               },
               "member": {
@@ -53,12 +56,14 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Variable": "A"
-                },
-                "span": {
-                  "start": 13,
-                  "end": 15
+                "Expr": {
+                  "kind": {
+                    "Variable": "A"
+                  },
+                  "span": {
+                    "start": 13,
+                    "end": 15
+                  }
                 }
               },
               "member": {
@@ -89,12 +94,17 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "String": "A"
-                },
-                "span": {
-                  "start": 21,
-                  "end": 24
+                "Expr": {
+                  "kind": {
+                    "String": {
+                      "value": "A",
+                      "raw": "A"
+                    }
+                  },
+                  "span": {
+                    "start": 21,
+                    "end": 24
+                  }
                 }
               },
               "member": {
@@ -125,40 +135,48 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Parenthesized": {
-                    "kind": {
-                      "Binary": {
-                        "left": {
-                          "kind": {
-                            "String": "A"
-                          },
-                          "span": {
-                            "start": 31,
-                            "end": 34
-                          }
-                        },
-                        "op": "Concat",
-                        "right": {
-                          "kind": {
-                            "String": ""
+                "Expr": {
+                  "kind": {
+                    "Parenthesized": {
+                      "kind": {
+                        "Binary": {
+                          "left": {
+                            "kind": {
+                              "String": {
+                                "value": "A",
+                                "raw": "A"
+                              }
+                            },
+                            "span": {
+                              "start": 31,
+                              "end": 34
+                            }
                           },
-                          "span": {
-                            "start": 37,
-                            "end": 39
+                          "op": "Concat",
+                          "right": {
+                            "kind": {
+                              "String": {
+                                "value": "",
+                                "raw": ""
+                              }
+                            },
+                            "span": {
+                              "start": 37,
+                              "end": 39
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 31,
+                        "end": 39
                       }
-                    },
-                    "span": {
-                      "start": 31,
-                      "end": 39
                     }
+                  },
+                  "span": {
+                    "start": 30,
+                    "end": 40
                   }
-                },
-                "span": {
-                  "start": 30,
-                  "end": 40
                 }
               },
               "member": {
@@ -189,31 +207,36 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "ArrayAccess": {
-                    "array": {
-                      "kind": {
-                        "String": "A"
-                      },
-                      "span": {
-                        "start": 46,
-                        "end": 49
-                      }
-                    },
-                    "index": {
-                      "kind": {
-                        "Int": 0
+                "Expr": {
+                  "kind": {
+                    "ArrayAccess": {
+                      "array": {
+                        "kind": {
+                          "String": {
+                            "value": "A",
+                            "raw": "A"
+                          }
+                        },
+                        "span": {
+                          "start": 46,
+                          "end": 49
+                        }
                       },
-                      "span": {
-                        "start": 50,
-                        "end": 51
+                      "index": {
+                        "kind": {
+                          "Int": 0
+                        },
+                        "span": {
+                          "start": 50,
+                          "end": 51
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 46,
+                    "end": 52
                   }
-                },
-                "span": {
-                  "start": 46,
-                  "end": 52
                 }
               },
               "member": {
@@ -244,12 +267,15 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccessDynamic": {
               "class": {
-                "kind": {
-                  "Identifier": "A"
-                },
-                "span": {
-                  "start": 58,
-                  "end": 59
+                "Name": {
+                  "parts": [
+                    "A"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 58,
+                    "end": 59
+                  }
                 }
               },
               "member": {
@@ -291,12 +317,15 @@ A::$A::$b;
                 "kind": {
                   "StaticPropertyAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 66,
-                        "end": 67
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 66,
+                          "end": 67
+                        }
                       }
                     },
                     "member": {
@@ -351,31 +380,36 @@ A::$A::$b;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "StaticPropertyAccess": {
-                    "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 77,
-                        "end": 78
-                      }
-                    },
-                    "member": {
-                      "kind": {
-                        "Identifier": "A"
+                "Expr": {
+                  "kind": {
+                    "StaticPropertyAccess": {
+                      "class": {
+                        "Name": {
+                          "parts": [
+                            "A"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 77,
+                            "end": 78
+                          }
+                        }
                       },
-                      "span": {
-                        "start": 80,
-                        "end": 82
+                      "member": {
+                        "kind": {
+                          "Identifier": "A"
+                        },
+                        "span": {
+                          "start": 80,
+                          "end": 82
+                        }
                       }
                     }
+                  },
+                  "span": {
+                    "start": 77,
+                    "end": 82
                   }
-                },
-                "span": {
-                  "start": 77,
-                  "end": 82
                 }
               },
               "member": {
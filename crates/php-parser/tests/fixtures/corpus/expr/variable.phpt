@@ -33,7 +33,10 @@ $$a['b'];
           "kind": {
             "VariableVariable": {
               "kind": {
-                "String": "a"
+                "String": {
+                  "value": "a",
+                  "raw": "a"
+                }
               },
               "span": {
                 "start": 13,
@@ -61,7 +64,16 @@ $$a['b'];
                 "FunctionCall": {
                   "name": {
                     "kind": {
-                      "Identifier": "foo"
+                      "Name": {
+                        "parts": [
+                          "foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 21,
+                          "end": 24
+                        }
+                      }
                     },
                     "span": {
                       "start": 21,
@@ -170,7 +182,10 @@ $$a['b'];
               },
               "index": {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 44,
@@ -157,7 +157,10 @@ list($x) = $y;
       "kind": {
         "Expression": {
           "kind": {
-            "String": "foo"
+            "String": {
+              "value": "foo",
+              "raw": "foo"
+            }
           },
           "span": {
             "start": 59,
@@ -174,7 +177,10 @@ list($x) = $y;
       "kind": {
         "Expression": {
           "kind": {
-            "String": "bar"
+            "String": {
+              "value": "bar",
+              "raw": "bar"
+            }
           },
           "span": {
             "start": 66,
@@ -191,7 +197,10 @@ list($x) = $y;
       "kind": {
         "Expression": {
           "kind": {
-            "String": "foo\nbar"
+            "String": {
+              "value": "foo\nbar",
+              "raw": "foo\nbar"
+            }
           },
           "span": {
             "start": 73,
@@ -208,7 +217,10 @@ list($x) = $y;
       "kind": {
         "Expression": {
           "kind": {
-            "String": "foo\nbar"
+            "String": {
+              "value": "foo\nbar",
+              "raw": "foo\\nbar"
+            }
           },
           "span": {
             "start": 84,
@@ -227,7 +239,13 @@ list($x) = $y;
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "foo\nbar"
+                "Literal": {
+                  "value": "foo\nbar",
+                  "span": {
+                    "start": 97,
+                    "end": 105
+                  }
+                }
               },
               {
                 "Expr": {
@@ -259,7 +277,13 @@ list($x) = $y;
           "kind": {
             "ShellExec": [
               {
-                "Literal": "foo\nbar"
+                "Literal": {
+                  "value": "foo\nbar",
+                  "span": {
+                    "start": 113,
+                    "end": 121
+                  }
+                }
               }
             ]
           },
@@ -280,7 +304,13 @@ list($x) = $y;
           "kind": {
             "ShellExec": [
               {
-                "Literal": "foo\nbar"
+                "Literal": {
+                  "value": "foo\nbar",
+                  "span": {
+                    "start": 125,
+                    "end": 133
+                  }
+                }
               },
               {
                 "Expr": {
@@ -312,6 +342,7 @@ list($x) = $y;
           "kind": {
             "Nowdoc": {
               "label": "ABC",
+              "raw": "",
               "value": ""
             }
           },
@@ -332,6 +363,7 @@ list($x) = $y;
           "kind": {
             "Nowdoc": {
               "label": "ABC",
+              "raw": "foo bar",
               "value": "foo bar"
             }
           },
@@ -352,6 +384,7 @@ list($x) = $y;
           "kind": {
             "Nowdoc": {
               "label": "ABC",
+              "raw": "    foo bar",
               "value": "foo bar"
             }
           },
@@ -372,9 +405,16 @@ list($x) = $y;
           "kind": {
             "Heredoc": {
               "label": "ABC",
+              "raw": "foo\\nbar",
               "parts": [
                 {
-                  "Literal": "foo\nbar"
+                  "Literal": {
+                    "value": "foo\nbar",
+                    "span": {
+                      "start": 214,
+                      "end": 222
+                    }
+                  }
                 }
               ]
             }
@@ -396,9 +436,16 @@ list($x) = $y;
           "kind": {
             "Heredoc": {
               "label": "ABC",
+              "raw": "    foo\\nbar",
               "parts": [
                 {
-                  "Literal": "foo\nbar"
+                  "Literal": {
+                    "value": "foo\nbar",
+                    "span": {
+                      "start": 235,
+                      "end": 247
+                    }
+                  }
                 }
               ]
             }
@@ -420,9 +467,16 @@ list($x) = $y;
           "kind": {
             "Heredoc": {
               "label": "ABC",
+              "raw": "foo\\nbar{$x}baz",
               "parts": [
                 {
-                  "Literal": "foo\nbar"
+                  "Literal": {
+                    "value": "foo\nbar",
+                    "span": {
+                      "start": 264,
+                      "end": 272
+                    }
+                  }
                 },
                 {
                   "Expr": {
@@ -436,7 +490,13 @@ list($x) = $y;
                   }
                 },
                 {
-                  "Literal": "baz"
+                  "Literal": {
+                    "value": "baz",
+                    "span": {
+                      "start": 276,
+                      "end": 279
+                    }
+                  }
                 }
               ]
             }
@@ -458,9 +518,16 @@ list($x) = $y;
           "kind": {
             "Heredoc": {
               "label": "ABC",
+              "raw": "    foo\\nbar{$x}baz",
               "parts": [
                 {
-                  "Literal": "foo\nbar"
+                  "Literal": {
+                    "value": "foo\nbar",
+                    "span": {
+                      "start": 296,
+                      "end": 304
+                    }
+                  }
                 },
                 {
                   "Expr": {
@@ -474,7 +541,13 @@ list($x) = $y;
                   }
                 },
                 {
-                  "Literal": "baz"
+                  "Literal": {
+                    "value": "baz",
+                    "span": {
+                      "start": 308,
+                      "end": 311
+                    }
+                  }
                 }
               ]
             }
@@ -531,25 +604,28 @@ list($x) = $y;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 340,
+                            "end": 342
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 340,
                           "end": 342
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 340,
-                        "end": 342
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 335,
@@ -586,25 +662,27 @@ list($x) = $y;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 351,
+                            "end": 353
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 351,
                           "end": 353
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 351,
-                        "end": 353
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 350,
@@ -14,6 +14,8 @@ B"Hi";
 '!\'!\\!\a!';
 "!\"!\\!\$!\n!\r!\t!\f!\v!\e!\a";
 "!\xFF!\377!\400!\0!";
+===errors===
+Octal escape sequence overflow \400 is greater than \377
 ===ast===
 {
   "stmts": [
@@ -21,7 +23,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": ""
+            "String": {
+              "value": "",
+              "raw": ""
+            }
           },
           "span": {
             "start": 7,
@@ -38,7 +43,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": ""
+            "String": {
+              "value": "",
+              "raw": ""
+            }
           },
           "span": {
             "start": 11,
@@ -55,7 +63,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": ""
+            "String": {
+              "value": "",
+              "raw": ""
+            }
           },
           "span": {
             "start": 15,
@@ -72,7 +83,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": ""
+            "String": {
+              "value": "",
+              "raw": ""
+            }
           },
           "span": {
             "start": 20,
@@ -89,7 +103,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 25,
@@ -106,7 +123,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 31,
@@ -123,7 +143,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 38,
@@ -140,7 +163,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 45,
@@ -157,7 +183,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 51,
@@ -174,7 +203,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Hi"
+            "String": {
+              "value": "Hi",
+              "raw": "Hi"
+            }
           },
           "span": {
             "start": 58,
@@ -191,7 +223,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "!'!\\!\\a!"
+            "String": {
+              "value": "!'!\\!\\a!",
+              "raw": "!\\'!\\\\!\\a!"
+            }
           },
           "span": {
             "start": 65,
@@ -208,7 +243,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "!\"!\\!$!\n!\r!\t!\f!\u000b!\u001b!\\a"
+            "String": {
+              "value": "!\"!\\!$!\n!\r!\t!\f!\u000b!\u001b!\\a",
+              "raw": "!\\\"!\\\\!\\$!\\n!\\r!\\t!\\f!\\v!\\e!\\a"
+            }
           },
           "span": {
             "start": 79,
@@ -225,7 +263,10 @@ B"Hi";
       "kind": {
         "Expression": {
           "kind": {
-            "String": "!ÿ!ÿ!!\u0000!"
+            "String": {
+              "value": "!􏿿!􏿿!\u0000!\u0000!",
+              "raw": "!\\xFF!\\377!\\400!\\0!"
+            }
           },
           "span": {
             "start": 113,
@@ -39,6 +39,7 @@ EOS;
           "kind": {
             "Nowdoc": {
               "label": "EOS",
+              "raw": "",
               "value": ""
             }
           },
@@ -59,9 +60,16 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "",
               "parts": [
                 {
-                  "Literal": ""
+                  "Literal": {
+                    "value": "",
+                    "span": {
+                      "start": 45,
+                      "end": 45
+                    }
+                  }
                 }
               ]
             }
@@ -83,6 +91,7 @@ EOS;
           "kind": {
             "Nowdoc": {
               "label": "EOS",
+              "raw": "Test '\" $a \\n",
               "value": "Test '\" $a \\n"
             }
           },
@@ -103,9 +112,16 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "Test '\" \\$a \\n",
               "parts": [
                 {
-                  "Literal": "Test '\" $a \n"
+                  "Literal": {
+                    "value": "Test '\" $a \n",
+                    "span": {
+                      "start": 115,
+                      "end": 129
+                    }
+                  }
                 }
               ]
             }
@@ -127,9 +143,16 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "Test $a",
               "parts": [
                 {
-                  "Literal": "Test "
+                  "Literal": {
+                    "value": "Test ",
+                    "span": {
+                      "start": 163,
+                      "end": 168
+                    }
+                  }
                 },
                 {
                   "Expr": {
@@ -162,9 +185,16 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "Test $a and $b->c test",
               "parts": [
                 {
-                  "Literal": "Test "
+                  "Literal": {
+                    "value": "Test ",
+                    "span": {
+                      "start": 183,
+                      "end": 188
+                    }
+                  }
                 },
                 {
                   "Expr": {
@@ -178,7 +208,13 @@ EOS;
                   }
                 },
                 {
-                  "Literal": " and "
+                  "Literal": {
+                    "value": " and ",
+                    "span": {
+                      "start": 190,
+                      "end": 195
+                    }
+                  }
                 },
                 {
                   "Expr": {
@@ -211,7 +247,13 @@ EOS;
                   }
                 },
                 {
-                  "Literal": " test"
+                  "Literal": {
+                    "value": " test",
+                    "span": {
+                      "start": 200,
+                      "end": 205
+                    }
+                  }
                 }
               ]
             }
@@ -233,9 +275,16 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "Binary",
               "parts": [
                 {
-                  "Literal": "Binary"
+                  "Literal": {
+                    "value": "Binary",
+                    "span": {
+                      "start": 220,
+                      "end": 226
+                    }
+                  }
                 }
               ]
             }
@@ -257,6 +306,7 @@ EOS;
           "kind": {
             "Heredoc": {
               "label": "EOS",
+              "raw": "$x\\r",
               "parts": [
                 {
                   "Expr": {
@@ -270,7 +320,13 @@ EOS;
                   }
                 },
                 {
-                  "Literal": "\r"
+                  "Literal": {
+                    "value": "\r",
+                    "span": {
+                      "start": 242,
+                      "end": 244
+                    }
+                  }
                 }
               ]
             }
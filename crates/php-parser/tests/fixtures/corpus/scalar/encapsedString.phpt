@@ -125,7 +125,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "B"
+                          "String": {
+                            "value": "B",
+                            "raw": "B"
+                          }
                         },
                         "span": {
                           "start": 26,
@@ -269,7 +272,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "9223372036854775808"
+                          "String": {
+                            "value": "9223372036854775808",
+                            "raw": "9223372036854775808"
+                          }
                         },
                         "span": {
                           "start": 56,
@@ -317,7 +323,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "000"
+                          "String": {
+                            "value": "000",
+                            "raw": "000"
+                          }
                         },
                         "span": {
                           "start": 83,
@@ -365,7 +374,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "0x0"
+                          "String": {
+                            "value": "0x0",
+                            "raw": "0x0"
+                          }
                         },
                         "span": {
                           "start": 94,
@@ -413,7 +425,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "0b0"
+                          "String": {
+                            "value": "0b0",
+                            "raw": "0b0"
+                          }
                         },
                         "span": {
                           "start": 105,
@@ -538,7 +553,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "B"
+                          "String": {
+                            "value": "B",
+                            "raw": "B"
+                          }
                         },
                         "span": {
                           "start": 135,
@@ -615,7 +633,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "'B'"
+                          "String": {
+                            "value": "'B'",
+                            "raw": "'B'"
+                          }
                         },
                         "span": {
                           "start": 156,
@@ -686,7 +707,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "\\{"
+                "Literal": {
+                  "value": "\\{",
+                  "span": {
+                    "start": 174,
+                    "end": 176
+                  }
+                }
               },
               {
                 "Expr": {
@@ -700,7 +727,13 @@ B"$A";
                 }
               },
               {
-                "Literal": "}"
+                "Literal": {
+                  "value": "}",
+                  "span": {
+                    "start": 178,
+                    "end": 179
+                  }
+                }
               }
             ]
           },
@@ -721,7 +754,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "\\{ "
+                "Literal": {
+                  "value": "\\{ ",
+                  "span": {
+                    "start": 183,
+                    "end": 186
+                  }
+                }
               },
               {
                 "Expr": {
@@ -735,7 +774,13 @@ B"$A";
                 }
               },
               {
-                "Literal": " }"
+                "Literal": {
+                  "value": " }",
+                  "span": {
+                    "start": 188,
+                    "end": 190
+                  }
+                }
               }
             ]
           },
@@ -756,7 +801,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "\\"
+                "Literal": {
+                  "value": "\\",
+                  "span": {
+                    "start": 194,
+                    "end": 196
+                  }
+                }
               },
               {
                 "Expr": {
@@ -788,7 +839,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "\\{ "
+                "Literal": {
+                  "value": "\\{ ",
+                  "span": {
+                    "start": 204,
+                    "end": 208
+                  }
+                }
               },
               {
                 "Expr": {
@@ -802,7 +859,13 @@ B"$A";
                 }
               },
               {
-                "Literal": " }"
+                "Literal": {
+                  "value": " }",
+                  "span": {
+                    "start": 210,
+                    "end": 212
+                  }
+                }
               }
             ]
           },
@@ -842,7 +905,13 @@ B"$A";
                 }
               },
               {
-                "Literal": "[B]"
+                "Literal": {
+                  "value": "[B]",
+                  "span": {
+                    "start": 221,
+                    "end": 224
+                  }
+                }
               }
             ]
           },
@@ -863,7 +932,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "$"
+                "Literal": {
+                  "value": "$",
+                  "span": {
+                    "start": 228,
+                    "end": 229
+                  }
+                }
               },
               {
                 "Expr": {
@@ -880,7 +955,10 @@ B"$A";
                       },
                       "index": {
                         "kind": {
-                          "String": "B"
+                          "String": {
+                            "value": "B",
+                            "raw": "B"
+                          }
                         },
                         "span": {
                           "start": 232,
@@ -914,7 +992,13 @@ B"$A";
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "A "
+                "Literal": {
+                  "value": "A ",
+                  "span": {
+                    "start": 238,
+                    "end": 240
+                  }
+                }
               },
               {
                 "Expr": {
@@ -928,7 +1012,13 @@ B"$A";
                 }
               },
               {
-                "Literal": " C"
+                "Literal": {
+                  "value": " C",
+                  "span": {
+                    "start": 242,
+                    "end": 244
+                  }
+                }
               }
             ]
           },
@@ -120,7 +120,16 @@ Invalid numeric literal
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "_100"
+            "ConstFetch": {
+              "parts": [
+                "_100"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 105,
+                "end": 109
+              }
+            }
           },
           "span": {
             "start": 105,
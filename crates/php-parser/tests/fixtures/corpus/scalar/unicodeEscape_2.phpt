@@ -11,7 +11,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "\u0000"
+            "String": {
+              "value": "\u0000",
+              "raw": "\\u{0}"
+            }
           },
           "span": {
             "start": 7,
@@ -30,7 +33,13 @@
           "kind": {
             "InterpolatedString": [
               {
-                "Literal": "Ĕ"
+                "Literal": {
+                  "value": "Ĕ",
+                  "span": {
+                    "start": 17,
+                    "end": 24
+                  }
+                }
               },
               {
                 "Expr": {
@@ -62,7 +71,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "😂"
+                "Literal": {
+                  "value": "😂",
+                  "span": {
+                    "start": 32,
+                    "end": 41
+                  }
+                }
               },
               {
                 "Expr": {
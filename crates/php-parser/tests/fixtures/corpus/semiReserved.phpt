@@ -439,12 +439,15 @@ class Foo {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Test"
-                      },
-                      "span": {
-                        "start": 403,
-                        "end": 407
+                      "Name": {
+                        "parts": [
+                          "Test"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 403,
+                          "end": 407
+                        }
                       }
                     },
                     "args": []
@@ -548,12 +551,15 @@ class Foo {
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Test"
-                },
-                "span": {
-                  "start": 437,
-                  "end": 441
+                "Name": {
+                  "parts": [
+                    "Test"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 437,
+                    "end": 441
+                  }
                 }
               },
               "method": {
@@ -585,12 +591,15 @@ class Foo {
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Test"
-                },
-                "span": {
-                  "start": 451,
-                  "end": 455
+                "Name": {
+                  "parts": [
+                    "Test"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 451,
+                    "end": 455
+                  }
                 }
               },
               "method": {
@@ -694,12 +703,15 @@ class Foo {
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "Test"
-                },
-                "span": {
-                  "start": 496,
-                  "end": 500
+                "Name": {
+                  "parts": [
+                    "Test"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 496,
+                    "end": 500
+                  }
                 }
               },
               "member": {
@@ -730,12 +742,15 @@ class Foo {
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "Test"
-                },
-                "span": {
-                  "start": 509,
-                  "end": 513
+                "Name": {
+                  "parts": [
+                    "Test"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 509,
+                    "end": 513
+                  }
                 }
               },
               "member": {
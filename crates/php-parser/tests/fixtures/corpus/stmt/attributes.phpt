@@ -134,6 +134,10 @@ $b = #[A13] static fn() => 0;
                       "end": 47
                     }
                   },
+                  "colon": {
+                    "start": 47,
+                    "end": 48
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -218,6 +222,10 @@ $b = #[A13] static fn() => 0;
                       "span": {
                         "start": 130,
                         "end": 142
+                      },
+                      "comma": {
+                        "start": 142,
+                        "end": 143
                       }
                     }
                   ],
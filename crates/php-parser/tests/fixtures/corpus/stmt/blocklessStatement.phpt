@@ -110,6 +110,18 @@ declare (a='b') $C;
           "init": [],
           "condition": [],
           "update": [],
+          "init_span": {
+            "start": 50,
+            "end": 50
+          },
+          "condition_span": {
+            "start": 51,
+            "end": 51
+          },
+          "update_span": {
+            "start": 52,
+            "end": 52
+          },
           "body": {
             "kind": {
               "Expression": {
@@ -260,7 +272,10 @@ declare (a='b') $C;
               "a",
               {
                 "kind": {
-                  "String": "b"
+                  "String": {
+                    "value": "b",
+                    "raw": "b"
+                  }
                 },
                 "span": {
                   "start": 133,
@@ -28,44 +28,46 @@ class A {
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": false
-                    },
-                    "extends": null,
-                    "implements": [],
-                    "members": [
-                      {
-                        "kind": {
-                          "Method": {
-                            "name": "test",
-                            "visibility": "Public",
-                            "is_static": false,
-                            "is_abstract": false,
-                            "is_final": false,
-                            "by_ref": false,
-                            "params": [],
-                            "return_type": null,
-                            "body": [],
-                            "attributes": []
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": false
+                      },
+                      "extends": null,
+                      "implements": [],
+                      "members": [
+                        {
+                          "kind": {
+                            "Method": {
+                              "name": "test",
+                              "visibility": "Public",
+                              "is_static": false,
+                              "is_abstract": false,
+                              "is_final": false,
+                              "by_ref": false,
+                              "params": [],
+                              "return_type": null,
+                              "body": [],
+                              "attributes": []
+                            }
+                          },
+                          "span": {
+                            "start": 23,
+                            "end": 48
                           }
-                        },
-                        "span": {
-                          "start": 23,
-                          "end": 48
                         }
-                      }
-                    ],
-                    "attributes": []
+                      ],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 7,
+                    "end": 50
                   }
-                },
-                "span": {
-                  "start": 7,
-                  "end": 50
                 }
               },
               "args": []
@@ -88,53 +90,55 @@ class A {
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": false
-                    },
-                    "extends": {
-                      "parts": [
-                        "A"
-                      ],
-                      "kind": "Unqualified",
-                      "span": {
-                        "start": 70,
-                        "end": 71
-                      }
-                    },
-                    "implements": [
-                      {
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": false
+                      },
+                      "extends": {
                         "parts": [
-                          "B"
+                          "A"
                         ],
                         "kind": "Unqualified",
                         "span": {
-                          "start": 83,
-                          "end": 84
+                          "start": 70,
+                          "end": 71
                         }
                       },
-                      {
-                        "parts": [
-                          "C"
-                        ],
-                        "kind": "Unqualified",
-                        "span": {
-                          "start": 86,
-                          "end": 87
+                      "implements": [
+                        {
+                          "parts": [
+                            "B"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 83,
+                            "end": 84
+                          }
+                        },
+                        {
+                          "parts": [
+                            "C"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 86,
+                            "end": 87
+                          }
                         }
-                      }
-                    ],
-                    "members": [],
-                    "attributes": []
+                      ],
+                      "members": [],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 52,
+                    "end": 90
                   }
-                },
-                "span": {
-                  "start": 52,
-                  "end": 90
                 }
               },
               "args": []
@@ -157,42 +161,44 @@ class A {
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": false
-                    },
-                    "extends": null,
-                    "implements": [],
-                    "members": [
-                      {
-                        "kind": {
-                          "Property": {
-                            "name": "foo",
-                            "visibility": "Public",
-                            "set_visibility": null,
-                            "is_static": false,
-                            "is_readonly": false,
-                            "type_hint": null,
-                            "default": null,
-                            "attributes": []
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": false
+                      },
+                      "extends": null,
+                      "implements": [],
+                      "members": [
+                        {
+                          "kind": {
+                            "Property": {
+                              "name": "foo",
+                              "visibility": "Public",
+                              "set_visibility": null,
+                              "is_static": false,
+                              "is_readonly": false,
+                              "type_hint": null,
+                              "default": null,
+                              "attributes": []
+                            }
+                          },
+                          "span": {
+                            "start": 110,
+                            "end": 121
                           }
-                        },
-                        "span": {
-                          "start": 110,
-                          "end": 121
                         }
-                      }
-                    ],
-                    "attributes": []
+                      ],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 92,
+                    "end": 124
                   }
-                },
-                "span": {
-                  "start": 92,
-                  "end": 124
                 }
               },
               "args": []
@@ -215,56 +221,58 @@ class A {
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": false
-                    },
-                    "extends": {
-                      "parts": [
-                        "A"
-                      ],
-                      "kind": "Unqualified",
-                      "span": {
-                        "start": 152,
-                        "end": 153
-                      }
-                    },
-                    "implements": [],
-                    "members": [
-                      {
-                        "kind": {
-                          "TraitUse": {
-                            "traits": [
-                              {
-                                "parts": [
-                                  "T"
-                                ],
-                                "kind": "Unqualified",
-                                "span": {
-                                  "start": 164,
-                                  "end": 165
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": false
+                      },
+                      "extends": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 152,
+                          "end": 153
+                        }
+                      },
+                      "implements": [],
+                      "members": [
+                        {
+                          "kind": {
+                            "TraitUse": {
+                              "traits": [
+                                {
+                                  "parts": [
+                                    "T"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 164,
+                                    "end": 165
+                                  }
                                 }
-                              }
-                            ],
-                            "adaptations": []
+                              ],
+                              "adaptations": []
+                            }
+                          },
+                          "span": {
+                            "start": 160,
+                            "end": 166
                           }
-                        },
-                        "span": {
-                          "start": 160,
-                          "end": 166
                         }
-                      }
-                    ],
-                    "attributes": []
+                      ],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 126,
+                    "end": 168
                   }
-                },
-                "span": {
-                  "start": 126,
-                  "end": 168
                 }
               },
               "args": [
@@ -284,6 +292,10 @@ class A {
                   "span": {
                     "start": 136,
                     "end": 138
+                  },
+                  "comma": {
+                    "start": 138,
+                    "end": 139
                   }
                 },
                 {
@@ -348,56 +360,61 @@ class A {
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "AnonymousClass": {
-                                    "name": null,
-                                    "modifiers": {
-                                      "is_abstract": false,
-                                      "is_final": false,
-                                      "is_readonly": false
-                                    },
-                                    "extends": {
-                                      "parts": [
-                                        "A"
-                                      ],
-                                      "kind": "Unqualified",
-                                      "span": {
-                                        "start": 250,
-                                        "end": 251
-                                      }
-                                    },
-                                    "implements": [],
-                                    "members": [
-                                      {
-                                        "kind": {
-                                          "ClassConst": {
-                                            "name": "A",
-                                            "visibility": null,
-                                            "is_final": false,
-                                            "value": {
-                                              "kind": {
-                                                "String": "B"
+                                "Expr": {
+                                  "kind": {
+                                    "AnonymousClass": {
+                                      "name": null,
+                                      "modifiers": {
+                                        "is_abstract": false,
+                                        "is_final": false,
+                                        "is_readonly": false
+                                      },
+                                      "extends": {
+                                        "parts": [
+                                          "A"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 250,
+                                          "end": 251
+                                        }
+                                      },
+                                      "implements": [],
+                                      "members": [
+                                        {
+                                          "kind": {
+                                            "ClassConst": {
+                                              "name": "A",
+                                              "visibility": null,
+                                              "is_final": false,
+                                              "value": {
+                                                "kind": {
+                                                  "String": {
+                                                    "value": "B",
+                                                    "raw": "B"
+                                                  }
+                                                },
+                                                "span": {
+                                                  "start": 276,
+                                                  "end": 279
+                                                }
                                               },
-                                              "span": {
-                                                "start": 276,
-                                                "end": 279
-                                              }
-                                            },
-                                            "attributes": []
+                                              "attributes": []
+                                            }
+                                          },
+                                          "span": {
+                                            "start": 266,
+                                            "end": 280
                                           }
-                                        },
-                                        "span": {
-                                          "start": 266,
-                                          "end": 280
                                         }
-                                      }
-                                    ],
-                                    "attributes": []
+                                      ],
+                                      "attributes": []
+                                    }
+                                  },
+                                  "span": {
+                                    "start": 225,
+                                    "end": 290
                                   }
-                                },
-                                "span": {
-                                  "start": 225,
-                                  "end": 290
                                 }
                               },
                               "args": [
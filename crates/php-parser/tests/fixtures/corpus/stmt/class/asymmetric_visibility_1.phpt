@@ -112,6 +112,10 @@ Property with asymmetric visibility must have type
                       "span": {
                         "start": 144,
                         "end": 169
+                      },
+                      "comma": {
+                        "start": 169,
+                        "end": 170
                       }
                     },
                     {
@@ -128,6 +132,10 @@ Property with asymmetric visibility must have type
                       "span": {
                         "start": 179,
                         "end": 201
+                      },
+                      "comma": {
+                        "start": 201,
+                        "end": 202
                       }
                     },
                     {
@@ -144,6 +152,10 @@ Property with asymmetric visibility must have type
                       "span": {
                         "start": 211,
                         "end": 228
+                      },
+                      "comma": {
+                        "start": 228,
+                        "end": 229
                       }
                     }
                   ],
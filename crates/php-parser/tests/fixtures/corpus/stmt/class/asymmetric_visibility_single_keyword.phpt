@@ -182,6 +182,10 @@ class Foo {
                       "span": {
                         "start": 158,
                         "end": 183
+                      },
+                      "comma": {
+                        "start": 183,
+                        "end": 184
                       }
                     },
                     {
@@ -215,6 +219,10 @@ class Foo {
                       "span": {
                         "start": 193,
                         "end": 216
+                      },
+                      "comma": {
+                        "start": 216,
+                        "end": 217
                       }
                     }
                   ],
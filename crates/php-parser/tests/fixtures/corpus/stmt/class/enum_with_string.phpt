@@ -35,7 +35,10 @@ Case Diamonds of backed enum Suit must have a value
                   "name": "Hearts",
                   "value": {
                     "kind": {
-                      "String": "H"
+                      "String": {
+                        "value": "H",
+                        "raw": "H"
+                      }
                     },
                     "span": {
                       "start": 45,
@@ -69,7 +72,10 @@ Case Diamonds of backed enum Suit must have a value
                   "name": "Clubs",
                   "value": {
                     "kind": {
-                      "String": "C"
+                      "String": {
+                        "value": "C",
+                        "raw": "C"
+                      }
                     },
                     "span": {
                       "start": 86,
@@ -90,7 +96,10 @@ Case Diamonds of backed enum Suit must have a value
                   "name": "Spades",
                   "value": {
                     "kind": {
-                      "String": "S"
+                      "String": {
+                        "value": "S",
+                        "raw": "S"
+                      }
                     },
                     "span": {
                       "start": 109,
@@ -87,17 +87,19 @@ Type of parameter $value of hook set must be compatible with property type
                         "Block": [
                           {
                             "kind": {
-                              "Echo": [
-                                {
-                                  "kind": {
-                                    "Variable": "value"
-                                  },
-                                  "span": {
-                                    "start": 84,
-                                    "end": 90
+                              "Echo": {
+                                "exprs": [
+                                  {
+                                    "kind": {
+                                      "Variable": "value"
+                                    },
+                                    "span": {
+                                      "start": 84,
+                                      "end": 90
+                                    }
                                   }
-                                }
-                              ]
+                                ]
+                              }
                             },
                             "span": {
                               "start": 79,
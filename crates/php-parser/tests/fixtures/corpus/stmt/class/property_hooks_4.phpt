@@ -6,8 +6,8 @@ class Test {
     public $prop { FOO => bar; }
 }
 ===errors===
-expected 'get' or 'set', found identifier
 Property hook list must not be empty
+expected 'get' or 'set', found identifier
 ===ast===
 {
   "stmts": [
@@ -19,8 +19,8 @@ duplicate 'get' hook
 expected 'get' or 'set', found 'private'
 duplicate 'get' hook
 expected 'get' or 'set', found 'abstract'
-expected 'get' or 'set', found 'static'
 duplicate 'get' hook
+expected 'get' or 'set', found 'static'
 expected 'get' or 'set', found 'readonly'
 duplicate 'get' hook
 ===ast===
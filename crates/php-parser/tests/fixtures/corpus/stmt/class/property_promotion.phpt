@@ -78,6 +78,10 @@ class Point {
                       "span": {
                         "start": 62,
                         "end": 83
+                      },
+                      "comma": {
+                        "start": 83,
+                        "end": 84
                       }
                     },
                     {
@@ -119,6 +123,10 @@ class Point {
                       "span": {
                         "start": 93,
                         "end": 116
+                      },
+                      "comma": {
+                        "start": 116,
+                        "end": 117
                       }
                     },
                     {
@@ -143,7 +151,10 @@ class Point {
                       },
                       "default": {
                         "kind": {
-                          "String": "hello"
+                          "String": {
+                            "value": "hello",
+                            "raw": "hello"
+                          }
                         },
                         "span": {
                           "start": 146,
@@ -160,6 +171,10 @@ class Point {
                       "span": {
                         "start": 126,
                         "end": 153
+                      },
+                      "comma": {
+                        "start": 153,
+                        "end": 154
                       }
                     },
                     {
@@ -201,6 +216,10 @@ class Point {
                       "span": {
                         "start": 163,
                         "end": 189
+                      },
+                      "comma": {
+                        "start": 189,
+                        "end": 190
                       }
                     },
                     {
@@ -241,6 +260,10 @@ class Point {
                       "span": {
                         "start": 199,
                         "end": 227
+                      },
+                      "comma": {
+                        "start": 227,
+                        "end": 228
                       }
                     },
                     {
@@ -289,6 +312,10 @@ class Point {
                       "span": {
                         "start": 237,
                         "end": 264
+                      },
+                      "comma": {
+                        "start": 264,
+                        "end": 265
                       }
                     },
                     {
@@ -305,6 +332,10 @@ class Point {
                       "span": {
                         "start": 274,
                         "end": 282
+                      },
+                      "comma": {
+                        "start": 282,
+                        "end": 283
                       }
                     }
                   ],
@@ -13,23 +13,25 @@ new readonly class {};
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": true
-                    },
-                    "extends": null,
-                    "implements": [],
-                    "members": [],
-                    "attributes": []
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": true
+                      },
+                      "extends": null,
+                      "implements": [],
+                      "members": [],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 7,
+                    "end": 28
                   }
-                },
-                "span": {
-                  "start": 7,
-                  "end": 28
                 }
               },
               "args": []
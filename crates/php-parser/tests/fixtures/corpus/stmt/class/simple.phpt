@@ -67,7 +67,10 @@ class A extends B implements C, D {
                   "is_final": false,
                   "value": {
                     "kind": {
-                      "String": "B"
+                      "String": {
+                        "value": "B",
+                        "raw": "B"
+                      }
                     },
                     "span": {
                       "start": 57,
@@ -90,7 +93,10 @@ class A extends B implements C, D {
                   "is_final": false,
                   "value": {
                     "kind": {
-                      "String": "D"
+                      "String": {
+                        "value": "D",
+                        "raw": "D"
+                      }
                     },
                     "span": {
                       "start": 66,
@@ -116,7 +122,10 @@ class A extends B implements C, D {
                   "type_hint": null,
                   "default": {
                     "kind": {
-                      "String": "b"
+                      "String": {
+                        "value": "b",
+                        "raw": "b"
+                      }
                     },
                     "span": {
                       "start": 88,
@@ -142,7 +151,10 @@ class A extends B implements C, D {
                   "type_hint": null,
                   "default": {
                     "kind": {
-                      "String": "d"
+                      "String": {
+                        "value": "d",
+                        "raw": "d"
+                      }
                     },
                     "span": {
                       "start": 98,
@@ -90,7 +90,10 @@ class Test {
                   },
                   "value": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 69,
@@ -131,7 +134,10 @@ class Test {
                   },
                   "value": {
                     "kind": {
-                      "String": "b"
+                      "String": {
+                        "value": "b",
+                        "raw": "b"
+                      }
                     },
                     "span": {
                       "start": 78,
@@ -64,7 +64,10 @@ cannot use attributes on multi-constant declaration
             "name": "C",
             "value": {
               "kind": {
-                "String": "A"
+                "String": {
+                  "value": "A",
+                  "raw": "A"
+                }
               },
               "span": {
                 "start": 33,
@@ -81,7 +84,16 @@ cannot use attributes on multi-constant declaration
             "name": "D",
             "value": {
               "kind": {
-                "Identifier": "E"
+                "ConstFetch": {
+                  "parts": [
+                    "E"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 42,
+                    "end": 43
+                  }
+                }
               },
               "span": {
                 "start": 42,
@@ -16,7 +16,10 @@ const VERSION = '1.0.0';
             "name": "VERSION",
             "value": {
               "kind": {
-                "String": "1.0.0"
+                "String": {
+                  "value": "1.0.0",
+                  "raw": "1.0.0"
+                }
               },
               "span": {
                 "start": 52,
@@ -20,7 +20,10 @@ enddeclare;
               "X",
               {
                 "kind": {
-                  "String": "Y"
+                  "String": {
+                    "value": "Y",
+                    "raw": "Y"
+                  }
                 },
                 "span": {
                   "start": 18,
@@ -45,7 +48,10 @@ enddeclare;
               "A",
               {
                 "kind": {
-                  "String": "B"
+                  "String": {
+                    "value": "B",
+                    "raw": "B"
+                  }
                 },
                 "span": {
                   "start": 36,
@@ -57,7 +63,10 @@ enddeclare;
               "C",
               {
                 "kind": {
-                  "String": "D"
+                  "String": {
+                    "value": "D",
+                    "raw": "D"
+                  }
                 },
                 "span": {
                   "start": 43,
@@ -71,17 +80,22 @@ enddeclare;
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "foo"
-                        },
-                        "span": {
-                          "start": 59,
-                          "end": 64
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "foo",
+                              "raw": "foo"
+                            }
+                          },
+                          "span": {
+                            "start": 59,
+                            "end": 64
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 54,
@@ -110,7 +124,10 @@ enddeclare;
               "A",
               {
                 "kind": {
-                  "String": "B"
+                  "String": {
+                    "value": "B",
+                    "raw": "B"
+                  }
                 },
                 "span": {
                   "start": 80,
@@ -122,7 +139,10 @@ enddeclare;
               "C",
               {
                 "kind": {
-                  "String": "D"
+                  "String": {
+                    "value": "D",
+                    "raw": "D"
+                  }
                 },
                 "span": {
                   "start": 87,
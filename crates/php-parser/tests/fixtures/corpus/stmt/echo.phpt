@@ -8,17 +8,22 @@ echo 'Hallo', ' ', 'World', '!';
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "Hallo World!"
-            },
-            "span": {
-              "start": 12,
-              "end": 26
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "Hallo World!",
+                  "raw": "Hallo World!"
+                }
+              },
+              "span": {
+                "start": 12,
+                "end": 26
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 7,
@@ -27,44 +32,58 @@ echo 'Hallo', ' ', 'World', '!';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "Hallo"
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "Hallo",
+                  "raw": "Hallo"
+                }
+              },
+              "span": {
+                "start": 33,
+                "end": 40
+              }
             },
-            "span": {
-              "start": 33,
-              "end": 40
-            }
-          },
-          {
-            "kind": {
-              "String": " "
+            {
+              "kind": {
+                "String": {
+                  "value": " ",
+                  "raw": " "
+                }
+              },
+              "span": {
+                "start": 42,
+                "end": 45
+              }
             },
-            "span": {
-              "start": 42,
-              "end": 45
-            }
-          },
-          {
-            "kind": {
-              "String": "World"
-            },
-            "span": {
-              "start": 47,
-              "end": 54
-            }
-          },
-          {
-            "kind": {
-              "String": "!"
+            {
+              "kind": {
+                "String": {
+                  "value": "World",
+                  "raw": "World"
+                }
+              },
+              "span": {
+                "start": 47,
+                "end": 54
+              }
             },
-            "span": {
-              "start": 56,
-              "end": 59
+            {
+              "kind": {
+                "String": {
+                  "value": "!",
+                  "raw": "!"
+                }
+              },
+              "span": {
+                "start": 56,
+                "end": 59
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 28,
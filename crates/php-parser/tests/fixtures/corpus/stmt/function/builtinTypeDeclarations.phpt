@@ -40,6 +40,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 20,
                 "end": 27
+              },
+              "comma": {
+                "start": 27,
+                "end": 28
               }
             },
             {
@@ -73,6 +77,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 29,
                 "end": 35
+              },
+              "comma": {
+                "start": 35,
+                "end": 36
               }
             },
             {
@@ -106,6 +114,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 37,
                 "end": 45
+              },
+              "comma": {
+                "start": 45,
+                "end": 46
               }
             },
             {
@@ -139,6 +151,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 47,
                 "end": 56
+              },
+              "comma": {
+                "start": 56,
+                "end": 57
               }
             },
             {
@@ -172,6 +188,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 58,
                 "end": 69
+              },
+              "comma": {
+                "start": 69,
+                "end": 70
               }
             },
             {
@@ -205,6 +225,10 @@ function test(bool $a, Int $b, FLOAT $c, StRiNg $d, iterable $e, object $f, mixe
               "span": {
                 "start": 71,
                 "end": 80
+              },
+              "comma": {
+                "start": 80,
+                "end": 81
               }
             },
             {
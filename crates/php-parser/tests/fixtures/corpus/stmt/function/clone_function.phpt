@@ -56,6 +56,10 @@ cannot use positional argument after named argument
               "span": {
                 "start": 22,
                 "end": 36
+              },
+              "comma": {
+                "start": 36,
+                "end": 37
               }
             },
             {
@@ -133,12 +137,18 @@ cannot use positional argument after named argument
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "Variable": "x"
+              "object": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 83,
+                  "end": 85
+                }
               },
-              "span": {
-                "start": 83,
-                "end": 85
+              "keyword_span": {
+                "start": 77,
+                "end": 82
               }
             }
           },
@@ -158,12 +168,18 @@ cannot use positional argument after named argument
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "Variable": "x"
+              "object": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 93,
+                  "end": 95
+                }
               },
-              "span": {
-                "start": 93,
-                "end": 95
+              "keyword_span": {
+                "start": 87,
+                "end": 92
               }
             }
           },
@@ -183,12 +199,18 @@ cannot use positional argument after named argument
         "Expression": {
           "kind": {
             "Clone": {
-              "kind": {
-                "Variable": "x"
+              "object": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 104,
+                  "end": 106
+                }
               },
-              "span": {
-                "start": 104,
-                "end": 106
+              "keyword_span": {
+                "start": 98,
+                "end": 103
               }
             }
           },
@@ -223,7 +245,10 @@ cannot use positional argument after named argument
                     {
                       "key": {
                         "kind": {
-                          "String": "foo"
+                          "String": {
+                            "value": "foo",
+                            "raw": "foo"
+                          }
                         },
                         "span": {
                           "start": 123,
@@ -243,12 +268,19 @@ cannot use positional argument after named argument
                       "span": {
                         "start": 123,
                         "end": 136
+                      },
+                      "comma": {
+                        "start": 136,
+                        "end": 137
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "bar"
+                          "String": {
+                            "value": "bar",
+                            "raw": "bar"
+                          }
                         },
                         "span": {
                           "start": 138,
@@ -333,7 +365,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 175,
+                      "end": 180
+                    }
+                  }
                 },
                 "span": {
                   "start": 175,
@@ -357,6 +398,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 181,
                     "end": 183
+                  },
+                  "comma": {
+                    "start": 183,
+                    "end": 184
                   }
                 },
                 {
@@ -375,6 +420,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 185,
                     "end": 191
+                  },
+                  "comma": {
+                    "start": 191,
+                    "end": 192
                   }
                 },
                 {
@@ -393,6 +442,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 193,
                     "end": 208
+                  },
+                  "comma": {
+                    "start": 208,
+                    "end": 209
                   }
                 },
                 {
@@ -411,6 +464,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 210,
                     "end": 224
+                  },
+                  "comma": {
+                    "start": 224,
+                    "end": 225
                   }
                 }
               ]
@@ -434,7 +491,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 229,
+                      "end": 234
+                    }
+                  }
                 },
                 "span": {
                   "start": 229,
@@ -453,6 +519,10 @@ cannot use positional argument after named argument
                       "end": 241
                     }
                   },
+                  "colon": {
+                    "start": 241,
+                    "end": 242
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
@@ -467,6 +537,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 235,
                     "end": 245
+                  },
+                  "comma": {
+                    "start": 245,
+                    "end": 246
                   }
                 },
                 {
@@ -480,13 +554,20 @@ cannot use positional argument after named argument
                       "end": 261
                     }
                   },
+                  "colon": {
+                    "start": 261,
+                    "end": 262
+                  },
                   "value": {
                     "kind": {
                       "Array": [
                         {
                           "key": {
                             "kind": {
-                              "String": "foo"
+                              "String": {
+                                "value": "foo",
+                                "raw": "foo"
+                              }
                             },
                             "span": {
                               "start": 265,
@@ -506,12 +587,19 @@ cannot use positional argument after named argument
                           "span": {
                             "start": 265,
                             "end": 278
+                          },
+                          "comma": {
+                            "start": 278,
+                            "end": 279
                           }
                         },
                         {
                           "key": {
                             "kind": {
-                              "String": "bar"
+                              "String": {
+                                "value": "bar",
+                                "raw": "bar"
+                              }
                             },
                             "span": {
                               "start": 280,
@@ -568,7 +656,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 298,
+                      "end": 303
+                    }
+                  }
                 },
                 "span": {
                   "start": 298,
@@ -592,6 +689,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 304,
                     "end": 306
+                  },
+                  "comma": {
+                    "start": 306,
+                    "end": 307
                   }
                 },
                 {
@@ -605,13 +706,20 @@ cannot use positional argument after named argument
                       "end": 322
                     }
                   },
+                  "colon": {
+                    "start": 322,
+                    "end": 323
+                  },
                   "value": {
                     "kind": {
                       "Array": [
                         {
                           "key": {
                             "kind": {
-                              "String": "foo"
+                              "String": {
+                                "value": "foo",
+                                "raw": "foo"
+                              }
                             },
                             "span": {
                               "start": 326,
@@ -631,12 +739,19 @@ cannot use positional argument after named argument
                           "span": {
                             "start": 326,
                             "end": 339
+                          },
+                          "comma": {
+                            "start": 339,
+                            "end": 340
                           }
                         },
                         {
                           "key": {
                             "kind": {
-                              "String": "bar"
+                              "String": {
+                                "value": "bar",
+                                "raw": "bar"
+                              }
                             },
                             "span": {
                               "start": 341,
@@ -693,7 +808,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 359,
+                      "end": 364
+                    }
+                  }
                 },
                 "span": {
                   "start": 359,
@@ -712,6 +836,10 @@ cannot use positional argument after named argument
                       "end": 371
                     }
                   },
+                  "colon": {
+                    "start": 371,
+                    "end": 372
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
@@ -749,7 +877,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 378,
+                      "end": 383
+                    }
+                  }
                 },
                 "span": {
                   "start": 378,
@@ -768,6 +905,10 @@ cannot use positional argument after named argument
                       "end": 390
                     }
                   },
+                  "colon": {
+                    "start": 390,
+                    "end": 391
+                  },
                   "value": {
                     "kind": {
                       "Variable": "x"
@@ -782,6 +923,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 384,
                     "end": 394
+                  },
+                  "comma": {
+                    "start": 394,
+                    "end": 395
                   }
                 },
                 {
@@ -792,7 +937,10 @@ cannot use positional argument after named argument
                         {
                           "key": {
                             "kind": {
-                              "String": "foo"
+                              "String": {
+                                "value": "foo",
+                                "raw": "foo"
+                              }
                             },
                             "span": {
                               "start": 398,
@@ -812,12 +960,19 @@ cannot use positional argument after named argument
                           "span": {
                             "start": 398,
                             "end": 411
+                          },
+                          "comma": {
+                            "start": 411,
+                            "end": 412
                           }
                         },
                         {
                           "key": {
                             "kind": {
-                              "String": "bar"
+                              "String": {
+                                "value": "bar",
+                                "raw": "bar"
+                              }
                             },
                             "span": {
                               "start": 413,
@@ -874,7 +1029,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "clone"
+                  "Name": {
+                    "parts": [
+                      "clone"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 431,
+                      "end": 436
+                    }
+                  }
                 },
                 "span": {
                   "start": 431,
@@ -890,7 +1054,10 @@ cannot use positional argument after named argument
                         {
                           "key": {
                             "kind": {
-                              "String": "object"
+                              "String": {
+                                "value": "object",
+                                "raw": "object"
+                              }
                             },
                             "span": {
                               "start": 441,
@@ -910,12 +1077,19 @@ cannot use positional argument after named argument
                           "span": {
                             "start": 441,
                             "end": 455
+                          },
+                          "comma": {
+                            "start": 455,
+                            "end": 456
                           }
                         },
                         {
                           "key": {
                             "kind": {
-                              "String": "withProperties"
+                              "String": {
+                                "value": "withProperties",
+                                "raw": "withProperties"
+                              }
                             },
                             "span": {
                               "start": 457,
@@ -928,7 +1102,10 @@ cannot use positional argument after named argument
                                 {
                                   "key": {
                                     "kind": {
-                                      "String": "foo"
+                                      "String": {
+                                        "value": "foo",
+                                        "raw": "foo"
+                                      }
                                     },
                                     "span": {
                                       "start": 479,
@@ -948,12 +1125,19 @@ cannot use positional argument after named argument
                                   "span": {
                                     "start": 479,
                                     "end": 492
+                                  },
+                                  "comma": {
+                                    "start": 492,
+                                    "end": 493
                                   }
                                 },
                                 {
                                   "key": {
                                     "kind": {
-                                      "String": "bar"
+                                      "String": {
+                                        "value": "bar",
+                                        "raw": "bar"
+                                      }
                                     },
                                     "span": {
                                       "start": 494,
@@ -1024,7 +1208,16 @@ cannot use positional argument after named argument
               "kind": {
                 "Function": {
                   "kind": {
-                    "Identifier": "clone"
+                    "Name": {
+                      "parts": [
+                        "clone"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 513,
+                        "end": 518
+                      }
+                    }
                   },
                   "span": {
                     "start": 513,
@@ -40,6 +40,10 @@ function a(
               "span": {
                 "start": 23,
                 "end": 32
+              },
+              "comma": {
+                "start": 32,
+                "end": 33
               }
             },
             {
@@ -47,7 +51,10 @@ function a(
               "type_hint": null,
               "default": {
                 "kind": {
-                  "String": "foo"
+                  "String": {
+                    "value": "foo",
+                    "raw": "foo"
+                  }
                 },
                 "span": {
                   "start": 43,
@@ -64,6 +71,10 @@ function a(
               "span": {
                 "start": 38,
                 "end": 48
+              },
+              "comma": {
+                "start": 48,
+                "end": 49
               }
             },
             {
@@ -73,12 +84,15 @@ function a(
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "A"
-                      },
-                      "span": {
-                        "start": 59,
-                        "end": 60
+                      "Name": {
+                        "parts": [
+                          "A"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 59,
+                          "end": 60
+                        }
                       }
                     },
                     "member": {
@@ -107,6 +121,10 @@ function a(
               "span": {
                 "start": 54,
                 "end": 63
+              },
+              "comma": {
+                "start": 63,
+                "end": 64
               }
             },
             {
@@ -142,6 +160,10 @@ function a(
               "span": {
                 "start": 69,
                 "end": 76
+              },
+              "comma": {
+                "start": 76,
+                "end": 77
               }
             },
             {
@@ -177,6 +199,10 @@ function a(
               "span": {
                 "start": 82,
                 "end": 91
+              },
+              "comma": {
+                "start": 91,
+                "end": 92
               }
             },
             {
@@ -201,6 +227,10 @@ function a(
               "span": {
                 "start": 97,
                 "end": 109
+              },
+              "comma": {
+                "start": 109,
+                "end": 110
               }
             },
             {
@@ -225,6 +255,10 @@ function a(
               "span": {
                 "start": 115,
                 "end": 122
+              },
+              "comma": {
+                "start": 122,
+                "end": 123
               }
             },
             {
@@ -237,7 +271,10 @@ function a(
                       "key": null,
                       "value": {
                         "kind": {
-                          "String": "foo"
+                          "String": {
+                            "value": "foo",
+                            "raw": "foo"
+                          }
                         },
                         "span": {
                           "start": 134,
@@ -267,6 +304,10 @@ function a(
               "span": {
                 "start": 128,
                 "end": 140
+              },
+              "comma": {
+                "start": 140,
+                "end": 141
               }
             },
             {
@@ -279,7 +320,10 @@ function a(
                       "key": null,
                       "value": {
                         "kind": {
-                          "String": "foo"
+                          "String": {
+                            "value": "foo",
+                            "raw": "foo"
+                          }
                         },
                         "span": {
                           "start": 152,
@@ -290,12 +334,19 @@ function a(
                       "span": {
                         "start": 152,
                         "end": 157
+                      },
+                      "comma": {
+                        "start": 157,
+                        "end": 158
                       }
                     },
                     {
                       "key": {
                         "kind": {
-                          "String": "bar"
+                          "String": {
+                            "value": "bar",
+                            "raw": "bar"
+                          }
                         },
                         "span": {
                           "start": 159,
@@ -304,7 +355,10 @@ function a(
                       },
                       "value": {
                         "kind": {
-                          "String": "baz"
+                          "String": {
+                            "value": "baz",
+                            "raw": "baz"
+                          }
                         },
                         "span": {
                           "start": 168,
@@ -50,6 +50,10 @@ function test(?Foo $bar, ?string $foo) : ?Baz {
               "span": {
                 "start": 21,
                 "end": 30
+              },
+              "comma": {
+                "start": 30,
+                "end": 31
               }
             },
             {
@@ -25,6 +25,10 @@ function foo($bar, ) {
               "span": {
                 "start": 20,
                 "end": 24
+              },
+              "comma": {
+                "start": 24,
+                "end": 25
               }
             }
           ],
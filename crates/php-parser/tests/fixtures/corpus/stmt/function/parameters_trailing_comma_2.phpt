@@ -46,6 +46,10 @@ class Foo
                       "span": {
                         "start": 44,
                         "end": 49
+                      },
+                      "comma": {
+                        "start": 49,
+                        "end": 50
                       }
                     },
                     {
@@ -62,6 +66,10 @@ class Foo
                       "span": {
                         "start": 51,
                         "end": 57
+                      },
+                      "comma": {
+                        "start": 57,
+                        "end": 58
                       }
                     }
                   ],
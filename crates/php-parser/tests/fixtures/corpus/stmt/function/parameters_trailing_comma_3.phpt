@@ -26,6 +26,10 @@ fn($foo, ) => $bar;
                   "span": {
                     "start": 9,
                     "end": 13
+                  },
+                  "comma": {
+                    "start": 13,
+                    "end": 14
                   }
                 }
               ],
@@ -30,7 +30,10 @@ function a() {
                     "kind": {
                       "VariableVariable": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 39,
@@ -82,7 +85,10 @@ function a() {
                     "name": "d",
                     "default": {
                       "kind": {
-                        "String": "e"
+                        "String": {
+                          "value": "e",
+                          "raw": "e"
+                        }
                       },
                       "span": {
                         "start": 70,
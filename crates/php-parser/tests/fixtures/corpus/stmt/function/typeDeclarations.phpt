@@ -24,6 +24,10 @@ function a($b, array $c, callable $d, E $f) {}
               "span": {
                 "start": 18,
                 "end": 20
+              },
+              "comma": {
+                "start": 20,
+                "end": 21
               }
             },
             {
@@ -57,6 +61,10 @@ function a($b, array $c, callable $d, E $f) {}
               "span": {
                 "start": 22,
                 "end": 30
+              },
+              "comma": {
+                "start": 30,
+                "end": 31
               }
             },
             {
@@ -90,6 +98,10 @@ function a($b, array $c, callable $d, E $f) {}
               "span": {
                 "start": 32,
                 "end": 43
+              },
+              "comma": {
+                "start": 43,
+                "end": 44
               }
             },
             {
@@ -50,6 +50,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 25,
                 "end": 33
+              },
+              "comma": {
+                "start": 33,
+                "end": 34
               }
             },
             {
@@ -83,6 +87,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 35,
                 "end": 42
+              },
+              "comma": {
+                "start": 42,
+                "end": 43
               }
             },
             {
@@ -116,6 +124,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 44,
                 "end": 53
+              },
+              "comma": {
+                "start": 53,
+                "end": 54
               }
             },
             {
@@ -149,6 +161,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 55,
                 "end": 65
+              },
+              "comma": {
+                "start": 65,
+                "end": 66
               }
             },
             {
@@ -182,6 +198,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 82,
                 "end": 94
+              },
+              "comma": {
+                "start": 94,
+                "end": 95
               }
             },
             {
@@ -215,6 +235,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 111,
                 "end": 121
+              },
+              "comma": {
+                "start": 121,
+                "end": 122
               }
             },
             {
@@ -248,6 +272,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 138,
                 "end": 147
+              },
+              "comma": {
+                "start": 147,
+                "end": 148
               }
             },
             {
@@ -281,6 +309,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 164,
                 "end": 172
+              },
+              "comma": {
+                "start": 172,
+                "end": 173
               }
             },
             {
@@ -314,6 +346,10 @@ function test2(): never {} // PHP 8.1
               "span": {
                 "start": 189,
                 "end": 198
+              },
+              "comma": {
+                "start": 198,
+                "end": 199
               }
             }
           ],
@@ -43,6 +43,10 @@ class Constructor {
               "span": {
                 "start": 21,
                 "end": 23
+              },
+              "comma": {
+                "start": 23,
+                "end": 24
               }
             },
             {
@@ -92,6 +96,10 @@ class Constructor {
               "span": {
                 "start": 51,
                 "end": 53
+              },
+              "comma": {
+                "start": 53,
+                "end": 54
               }
             },
             {
@@ -141,6 +149,10 @@ class Constructor {
               "span": {
                 "start": 82,
                 "end": 84
+              },
+              "comma": {
+                "start": 84,
+                "end": 85
               }
             },
             {
@@ -207,6 +219,10 @@ class Constructor {
               "span": {
                 "start": 117,
                 "end": 119
+              },
+              "comma": {
+                "start": 119,
+                "end": 120
               }
             },
             {
@@ -273,6 +289,10 @@ class Constructor {
               "span": {
                 "start": 153,
                 "end": 155
+              },
+              "comma": {
+                "start": 155,
+                "end": 156
               }
             },
             {
@@ -347,6 +367,10 @@ class Constructor {
               "span": {
                 "start": 189,
                 "end": 191
+              },
+              "comma": {
+                "start": 191,
+                "end": 192
               }
             },
             {
@@ -421,6 +445,10 @@ class Constructor {
               "span": {
                 "start": 226,
                 "end": 228
+              },
+              "comma": {
+                "start": 228,
+                "end": 229
               }
             },
             {
@@ -515,6 +543,10 @@ class Constructor {
               "span": {
                 "start": 268,
                 "end": 270
+              },
+              "comma": {
+                "start": 270,
+                "end": 271
               }
             },
             {
@@ -597,7 +597,16 @@ function gen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "func"
+                          "Name": {
+                            "parts": [
+                              "func"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 493,
+                              "end": 497
+                            }
+                          }
                         },
                         "span": {
                           "start": 493,
@@ -723,12 +732,15 @@ function gen() {
                   "kind": {
                     "New": {
                       "class": {
-                        "kind": {
-                          "Identifier": "Foo"
-                        },
-                        "span": {
-                          "start": 547,
-                          "end": 550
+                        "Name": {
+                          "parts": [
+                            "Foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 547,
+                            "end": 550
+                          }
                         }
                       },
                       "args": [
@@ -32,7 +32,10 @@ function gen() {
                           "Binary": {
                             "left": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 34,
@@ -42,7 +45,10 @@ function gen() {
                             "op": "Concat",
                             "right": {
                               "kind": {
-                                "String": "b"
+                                "String": {
+                                  "value": "b",
+                                  "raw": "b"
+                                }
                               },
                               "span": {
                                 "start": 40,
@@ -81,7 +87,10 @@ function gen() {
                           "Binary": {
                             "left": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 55,
@@ -126,7 +135,10 @@ function gen() {
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "k"
+                          "String": {
+                            "value": "k",
+                            "raw": "k"
+                          }
                         },
                         "span": {
                           "start": 77,
@@ -138,7 +150,10 @@ function gen() {
                           "Binary": {
                             "left": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 84,
@@ -148,7 +163,10 @@ function gen() {
                             "op": "Concat",
                             "right": {
                               "kind": {
-                                "String": "b"
+                                "String": {
+                                  "value": "b",
+                                  "raw": "b"
+                                }
                               },
                               "span": {
                                 "start": 90,
@@ -183,7 +201,10 @@ function gen() {
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "k"
+                          "String": {
+                            "value": "k",
+                            "raw": "k"
+                          }
                         },
                         "span": {
                           "start": 105,
@@ -195,7 +216,10 @@ function gen() {
                           "Binary": {
                             "left": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 112,
@@ -240,7 +264,16 @@ function gen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "var_dump"
+                          "Name": {
+                            "parts": [
+                              "var_dump"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 128,
+                              "end": 136
+                            }
+                          }
                         },
                         "span": {
                           "start": 128,
@@ -260,7 +293,10 @@ function gen() {
                                       "Yield": {
                                         "key": {
                                           "kind": {
-                                            "String": "k"
+                                            "String": {
+                                              "value": "k",
+                                              "raw": "k"
+                                            }
                                           },
                                           "span": {
                                             "start": 144,
@@ -272,7 +308,10 @@ function gen() {
                                             "Binary": {
                                               "left": {
                                                 "kind": {
-                                                  "String": "a"
+                                                  "String": {
+                                                    "value": "a",
+                                                    "raw": "a"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 151,
@@ -282,7 +321,10 @@ function gen() {
                                               "op": "Concat",
                                               "right": {
                                                 "kind": {
-                                                  "String": "b"
+                                                  "String": {
+                                                    "value": "b",
+                                                    "raw": "b"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 157,
@@ -349,7 +391,10 @@ function gen() {
                           "Yield": {
                             "key": {
                               "kind": {
-                                "String": "k1"
+                                "String": {
+                                  "value": "k1",
+                                  "raw": "k1"
+                                }
                               },
                               "span": {
                                 "start": 180,
@@ -361,7 +406,10 @@ function gen() {
                                 "Yield": {
                                   "key": {
                                     "kind": {
-                                      "String": "k2"
+                                      "String": {
+                                        "value": "k2",
+                                        "raw": "k2"
+                                      }
                                     },
                                     "span": {
                                       "start": 194,
@@ -373,7 +421,10 @@ function gen() {
                                       "Binary": {
                                         "left": {
                                           "kind": {
-                                            "String": "a"
+                                            "String": {
+                                              "value": "a",
+                                              "raw": "a"
+                                            }
                                           },
                                           "span": {
                                             "start": 202,
@@ -383,7 +434,10 @@ function gen() {
                                         "op": "Concat",
                                         "right": {
                                           "kind": {
-                                            "String": "b"
+                                            "String": {
+                                              "value": "b",
+                                              "raw": "b"
+                                            }
                                           },
                                           "span": {
                                             "start": 208,
@@ -437,7 +491,10 @@ function gen() {
                           "Yield": {
                             "key": {
                               "kind": {
-                                "String": "k1"
+                                "String": {
+                                  "value": "k1",
+                                  "raw": "k1"
+                                }
                               },
                               "span": {
                                 "start": 229,
@@ -452,7 +509,10 @@ function gen() {
                                       "key": null,
                                       "value": {
                                         "kind": {
-                                          "String": "k2"
+                                          "String": {
+                                            "value": "k2",
+                                            "raw": "k2"
+                                          }
                                         },
                                         "span": {
                                           "start": 244,
@@ -486,7 +546,10 @@ function gen() {
                           "Binary": {
                             "left": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 253,
@@ -496,7 +559,10 @@ function gen() {
                             "op": "Concat",
                             "right": {
                               "kind": {
-                                "String": "b"
+                                "String": {
+                                  "value": "b",
+                                  "raw": "b"
+                                }
                               },
                               "span": {
                                 "start": 259,
@@ -531,7 +597,16 @@ function gen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "var_dump"
+                          "Name": {
+                            "parts": [
+                              "var_dump"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 268,
+                              "end": 276
+                            }
+                          }
                         },
                         "span": {
                           "start": 268,
@@ -551,7 +626,10 @@ function gen() {
                                       "Yield": {
                                         "key": {
                                           "kind": {
-                                            "String": "k1"
+                                            "String": {
+                                              "value": "k1",
+                                              "raw": "k1"
+                                            }
                                           },
                                           "span": {
                                             "start": 284,
@@ -563,7 +641,10 @@ function gen() {
                                             "Yield": {
                                               "key": {
                                                 "kind": {
-                                                  "String": "k2"
+                                                  "String": {
+                                                    "value": "k2",
+                                                    "raw": "k2"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 298,
@@ -575,7 +656,10 @@ function gen() {
                                                   "Binary": {
                                                     "left": {
                                                       "kind": {
-                                                        "String": "a"
+                                                        "String": {
+                                                          "value": "a",
+                                                          "raw": "a"
+                                                        }
                                                       },
                                                       "span": {
                                                         "start": 306,
@@ -585,7 +669,10 @@ function gen() {
                                                     "op": "Concat",
                                                     "right": {
                                                       "kind": {
-                                                        "String": "b"
+                                                        "String": {
+                                                          "value": "b",
+                                                          "raw": "b"
+                                                        }
                                                       },
                                                       "span": {
                                                         "start": 312,
@@ -656,7 +743,16 @@ function gen() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "var_dump"
+                          "Name": {
+                            "parts": [
+                              "var_dump"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 323,
+                              "end": 331
+                            }
+                          }
                         },
                         "span": {
                           "start": 323,
@@ -675,7 +771,10 @@ function gen() {
                                       "Yield": {
                                         "key": {
                                           "kind": {
-                                            "String": "k1"
+                                            "String": {
+                                              "value": "k1",
+                                              "raw": "k1"
+                                            }
                                           },
                                           "span": {
                                             "start": 339,
@@ -690,7 +789,10 @@ function gen() {
                                                   "key": null,
                                                   "value": {
                                                     "kind": {
-                                                      "String": "k2"
+                                                      "String": {
+                                                        "value": "k2",
+                                                        "raw": "k2"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 354,
@@ -724,7 +826,10 @@ function gen() {
                                       "Binary": {
                                         "left": {
                                           "kind": {
-                                            "String": "a"
+                                            "String": {
+                                              "value": "a",
+                                              "raw": "a"
+                                            }
                                           },
                                           "span": {
                                             "start": 363,
@@ -734,7 +839,10 @@ function gen() {
                                         "op": "Concat",
                                         "right": {
                                           "kind": {
-                                            "String": "b"
+                                            "String": {
+                                              "value": "b",
+                                              "raw": "b"
+                                            }
                                           },
                                           "span": {
                                             "start": 369,
@@ -8,7 +8,12 @@ expected ';' or '?>' after __halt_compiler()
   "stmts": [
     {
       "kind": {
-        "HaltCompiler": ""
+        "HaltCompiler": {
+          "span": {
+            "start": 23,
+            "end": 23
+          }
+        }
       },
       "span": {
         "start": 6,
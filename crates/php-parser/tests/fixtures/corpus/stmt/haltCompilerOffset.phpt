@@ -14,7 +14,16 @@ Foo
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "var_dump"
+                  "Name": {
+                    "parts": [
+                      "var_dump"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 7,
+                      "end": 15
+                    }
+                  }
                 },
                 "span": {
                   "start": 7,
@@ -26,7 +35,16 @@ Foo
                   "name": null,
                   "value": {
                     "kind": {
-                      "Identifier": "__HALT_COMPILER_OFFSET__"
+                      "ConstFetch": {
+                        "parts": [
+                          "__HALT_COMPILER_OFFSET__"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 16,
+                          "end": 40
+                        }
+                      }
                     },
                     "span": {
                       "start": 16,
@@ -56,7 +74,12 @@ Foo
     },
     {
       "kind": {
-        "HaltCompiler": "\nFoo"
+        "HaltCompiler": {
+          "span": {
+            "start": 61,
+            "end": 65
+          }
+        }
       },
       "span": {
         "start": 43,
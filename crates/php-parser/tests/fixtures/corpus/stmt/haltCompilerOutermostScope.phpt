@@ -5,7 +5,7 @@ if (true) {
 }
 ===errors===
 __halt_compiler() can only be used at the outermost scope
-unclosed ''}'' opened at Span { start: 16, end: 17 }
+unclosed '}' opened at Span { start: 16, end: 17 }
 ===ast===
 {
   "stmts": [
@@ -26,7 +26,12 @@ unclosed ''}'' opened at Span { start: 16, end: 17 }
               "Block": [
                 {
                   "kind": {
-                    "HaltCompiler": "\n}"
+                    "HaltCompiler": {
+                      "span": {
+                        "start": 40,
+                        "end": 42
+                      }
+                    }
                   },
                   "span": {
                     "start": 22,
@@ -27,7 +27,12 @@ Hallo World!
     },
     {
       "kind": {
-        "HaltCompiler": "\nHallo World!"
+        "HaltCompiler": {
+          "span": {
+            "start": 31,
+            "end": 44
+          }
+        }
       },
       "span": {
         "start": 11,
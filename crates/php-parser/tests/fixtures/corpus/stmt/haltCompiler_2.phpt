@@ -25,7 +25,12 @@ __halt_compiler();Hallo World!
     },
     {
       "kind": {
-        "HaltCompiler": "Hallo World!"
+        "HaltCompiler": {
+          "span": {
+            "start": 29,
+            "end": 41
+          }
+        }
       },
       "span": {
         "start": 11,
@@ -47,7 +47,12 @@ __halt_compiler();
     },
     {
       "kind": {
-        "HaltCompiler": ""
+        "HaltCompiler": {
+          "span": {
+            "start": 42,
+            "end": 42
+          }
+        }
       },
       "span": {
         "start": 24,
@@ -11,17 +11,22 @@ echo "foobar";
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "foobar"
-            },
-            "span": {
-              "start": 31,
-              "end": 39
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "foobar",
+                  "raw": "foobar"
+                }
+              },
+              "span": {
+                "start": 31,
+                "end": 39
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 26,
@@ -30,7 +35,10 @@ echo "foobar";
     },
     {
       "kind": {
-        "InlineHtml": "\n#!/usr/bin/env php"
+        "InlineHtml": {
+          "text": "\n#!/usr/bin/env php",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 44,
@@ -41,5 +49,10 @@ echo "foobar";
   "span": {
     "start": 19,
     "end": 63
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 42,
+    "end": 44
   }
 }
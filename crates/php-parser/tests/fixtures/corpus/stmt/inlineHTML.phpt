@@ -30,7 +30,10 @@ $d;
     },
     {
       "kind": {
-        "InlineHtml": "\nB\n"
+        "InlineHtml": {
+          "text": "\nB\n",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 12,
@@ -56,7 +59,10 @@ $d;
     },
     {
       "kind": {
-        "InlineHtml": "\n"
+        "InlineHtml": {
+          "text": "\n",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 27,
@@ -103,6 +103,18 @@ endfor;
               }
             }
           ],
+          "init_span": {
+            "start": 32,
+            "end": 38
+          },
+          "condition_span": {
+            "start": 40,
+            "end": 47
+          },
+          "update_span": {
+            "start": 49,
+            "end": 53
+          },
           "body": {
             "kind": {
               "Block": []
@@ -182,6 +194,18 @@ endfor;
               }
             }
           ],
+          "init_span": {
+            "start": 88,
+            "end": 94
+          },
+          "condition_span": {
+            "start": 96,
+            "end": 102
+          },
+          "update_span": {
+            "start": 104,
+            "end": 110
+          },
           "body": {
             "kind": {
               "Block": []
@@ -204,6 +228,18 @@ endfor;
           "init": [],
           "condition": [],
           "update": [],
+          "init_span": {
+            "start": 138,
+            "end": 138
+          },
+          "condition_span": {
+            "start": 139,
+            "end": 139
+          },
+          "update_span": {
+            "start": 140,
+            "end": 140
+          },
           "body": {
             "kind": {
               "Block": []
@@ -226,6 +262,18 @@ endfor;
           "init": [],
           "condition": [],
           "update": [],
+          "init_span": {
+            "start": 173,
+            "end": 173
+          },
+          "condition_span": {
+            "start": 174,
+            "end": 174
+          },
+          "update_span": {
+            "start": 175,
+            "end": 175
+          },
           "body": {
             "kind": {
               "Block": []
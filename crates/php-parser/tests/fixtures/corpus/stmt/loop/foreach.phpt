@@ -201,42 +201,49 @@ endforeach;
           "key": null,
           "value": {
             "kind": {
-              "Array": [
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "a"
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "a"
+                      },
+                      "span": {
+                        "start": 153,
+                        "end": 155
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 153,
                       "end": 155
+                    },
+                    "comma": {
+                      "start": 155,
+                      "end": 156
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 153,
-                    "end": 155
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "b"
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "b"
+                      },
+                      "span": {
+                        "start": 157,
+                        "end": 159
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 157,
                       "end": 159
                     }
-                  },
-                  "unpack": false,
-                  "span": {
-                    "start": 157,
-                    "end": 159
                   }
-                }
-              ]
+                ],
+                "uses_list": true
+              }
             },
             "span": {
               "start": 148,
@@ -282,57 +289,68 @@ endforeach;
           },
           "value": {
             "kind": {
-              "Array": [
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "b"
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "b"
+                      },
+                      "span": {
+                        "start": 191,
+                        "end": 193
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 191,
                       "end": 193
+                    },
+                    "comma": {
+                      "start": 193,
+                      "end": 194
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 191,
-                    "end": 193
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": "Omit",
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": "Omit",
+                      "span": {
+                        "start": 195,
+                        "end": 195
+                      }
+                    },
+                    "unpack": false,
                     "span": {
+                      "start": 195,
+                      "end": 195
+                    },
+                    "comma": {
                       "start": 195,
                       "end": 196
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 195,
-                    "end": 196
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "c"
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "c"
+                      },
+                      "span": {
+                        "start": 197,
+                        "end": 199
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 197,
                       "end": 199
                     }
-                  },
-                  "unpack": false,
-                  "span": {
-                    "start": 197,
-                    "end": 199
                   }
-                }
-              ]
+                ],
+                "uses_list": true
+              }
             },
             "span": {
               "start": 186,
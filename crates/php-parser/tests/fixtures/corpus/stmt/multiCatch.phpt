@@ -36,27 +36,37 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "X"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 29,
-                    "end": 30
+                  "Name": {
+                    "parts": [
+                      "X"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 29,
+                      "end": 30
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "Y"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 31,
-                    "end": 32
+                  "Name": {
+                    "parts": [
+                      "Y"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 31,
+                      "end": 32
+                    }
                   }
                 }
               ],
-              "var": "e1",
+              "var": {
+                "name": "e1",
+                "span": {
+                  "start": 33,
+                  "end": 36
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -84,28 +94,38 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "A"
-                  ],
-                  "kind": "FullyQualified",
-                  "span": {
-                    "start": 57,
-                    "end": 59
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "FullyQualified",
+                    "span": {
+                      "start": 57,
+                      "end": 59
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "B",
-                    "C"
-                  ],
-                  "kind": "Qualified",
-                  "span": {
-                    "start": 60,
-                    "end": 63
+                  "Name": {
+                    "parts": [
+                      "B",
+                      "C"
+                    ],
+                    "kind": "Qualified",
+                    "span": {
+                      "start": 60,
+                      "end": 63
+                    }
                   }
                 }
               ],
-              "var": "e2",
+              "var": {
+                "name": "e2",
+                "span": {
+                  "start": 64,
+                  "end": 67
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -104,6 +104,10 @@ Cannot use A as B because the name is already in use
               "span": {
                 "start": 34,
                 "end": 42
+              },
+              "comma": {
+                "start": 42,
+                "end": 43
               }
             },
             {
@@ -67,6 +67,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 24,
                 "end": 27
+              },
+              "comma": {
+                "start": 27,
+                "end": 28
               }
             },
             {
@@ -118,6 +122,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 43,
                 "end": 46
+              },
+              "comma": {
+                "start": 46,
+                "end": 47
               }
             },
             {
@@ -169,6 +177,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 68,
                 "end": 71
+              },
+              "comma": {
+                "start": 71,
+                "end": 72
               }
             },
             {
@@ -219,6 +231,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 91,
                 "end": 94
+              },
+              "comma": {
+                "start": 94,
+                "end": 95
               }
             },
             {
@@ -270,6 +286,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 109,
                 "end": 112
+              },
+              "comma": {
+                "start": 112,
+                "end": 113
               }
             },
             {
@@ -291,6 +311,10 @@ Cannot use A\B\D as D because the name is already in use
               "span": {
                 "start": 114,
                 "end": 126
+              },
+              "comma": {
+                "start": 126,
+                "end": 127
               }
             },
             {
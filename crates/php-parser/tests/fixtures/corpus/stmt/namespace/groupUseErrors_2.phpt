@@ -28,6 +28,10 @@ expected namespace separator before '{', found '{'
               "span": {
                 "start": 39,
                 "end": 42
+              },
+              "comma": {
+                "start": 42,
+                "end": 43
               }
             },
             {
@@ -26,6 +26,10 @@ use function A\{b,};
               "span": {
                 "start": 13,
                 "end": 14
+              },
+              "comma": {
+                "start": 14,
+                "end": 15
               }
             }
           ]
@@ -57,6 +61,10 @@ use function A\{b,};
               "span": {
                 "start": 34,
                 "end": 35
+              },
+              "comma": {
+                "start": 35,
+                "end": 36
               }
             }
           ]
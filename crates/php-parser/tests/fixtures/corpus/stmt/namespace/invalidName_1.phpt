@@ -1,9 +1,9 @@
 ===source===
 <?php use A as self;
 ===errors===
+The use statement with non-compound name 'A' has no effect
 expected identifier, found 'self'
 expected ';', found 'self'
-The use statement with non-compound name 'A' has no effect
 ===ast===
 {
   "stmts": [
@@ -41,7 +41,16 @@ The use statement with non-compound name 'A' has no effect
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "self"
+            "Name": {
+              "parts": [
+                "self"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 15,
+                "end": 19
+              }
+            }
           },
           "span": {
             "start": 15,
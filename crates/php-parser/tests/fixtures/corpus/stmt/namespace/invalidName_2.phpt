@@ -1,9 +1,9 @@
 ===source===
 <?php use B as PARENT;
 ===errors===
+The use statement with non-compound name 'B' has no effect
 expected identifier, found 'parent'
 expected ';', found 'parent'
-The use statement with non-compound name 'B' has no effect
 ===ast===
 {
   "stmts": [
@@ -41,7 +41,16 @@ The use statement with non-compound name 'B' has no effect
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "parent"
+            "Name": {
+              "parts": [
+                "PARENT"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 15,
+                "end": 21
+              }
+            }
           },
           "span": {
             "start": 15,
@@ -1,10 +1,10 @@
 ===source===
 <?php use C as static;
 ===errors===
+The use statement with non-compound name 'C' has no effect
 expected identifier, found 'static'
 expected ';', found 'static'
 Cannot use 'static' as a standalone expression
-The use statement with non-compound name 'C' has no effect
 ===ast===
 {
   "stmts": [
@@ -42,7 +42,16 @@ The use statement with non-compound name 'C' has no effect
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "static"
+            "Name": {
+              "parts": [
+                "static"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 15,
+                "end": 21
+              }
+            }
           },
           "span": {
             "start": 15,
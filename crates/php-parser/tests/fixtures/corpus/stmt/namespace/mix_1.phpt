@@ -34,17 +34,19 @@ Cannot mix bracketed namespace declarations with unbracketed namespace declarati
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 1
-            },
-            "span": {
-              "start": 24,
-              "end": 25
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 24,
+                "end": 25
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 19,
@@ -68,17 +70,19 @@ Cannot mix bracketed namespace declarations with unbracketed namespace declarati
             "Braced": [
               {
                 "kind": {
-                  "Echo": [
-                    {
-                      "kind": {
-                        "Int": 2
-                      },
-                      "span": {
-                        "start": 50,
-                        "end": 51
+                  "Echo": {
+                    "exprs": [
+                      {
+                        "kind": {
+                          "Int": 2
+                        },
+                        "span": {
+                          "start": 50,
+                          "end": 51
+                        }
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 45,
@@ -96,17 +100,19 @@ Cannot mix bracketed namespace declarations with unbracketed namespace declarati
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 3
-            },
-            "span": {
-              "start": 60,
-              "end": 61
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 3
+              },
+              "span": {
+                "start": 60,
+                "end": 61
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 55,
@@ -7,8 +7,8 @@ echo 2;
 namespace B;
 echo 3;
 ===errors===
-Cannot mix bracketed namespace declarations with unbracketed namespace declarations
 No code may exist outside of namespace {}
+Cannot mix bracketed namespace declarations with unbracketed namespace declarations
 ===ast===
 {
   "stmts": [
@@ -29,17 +29,19 @@ No code may exist outside of namespace {}
             "Braced": [
               {
                 "kind": {
-                  "Echo": [
-                    {
-                      "kind": {
-                        "Int": 1
-                      },
-                      "span": {
-                        "start": 29,
-                        "end": 30
+                  "Echo": {
+                    "exprs": [
+                      {
+                        "kind": {
+                          "Int": 1
+                        },
+                        "span": {
+                          "start": 29,
+                          "end": 30
+                        }
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 24,
@@ -57,17 +59,19 @@ No code may exist outside of namespace {}
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 2
-            },
-            "span": {
-              "start": 39,
-              "end": 40
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 39,
+                "end": 40
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 34,
@@ -97,17 +101,19 @@ No code may exist outside of namespace {}
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 3
-            },
-            "span": {
-              "start": 60,
-              "end": 61
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 3
+              },
+              "span": {
+                "start": 60,
+                "end": 61
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 55,
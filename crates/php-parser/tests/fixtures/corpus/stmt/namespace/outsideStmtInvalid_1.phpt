@@ -10,17 +10,19 @@ Namespace declaration statement has to be the very first statement or after any
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 1
-            },
-            "span": {
-              "start": 11,
-              "end": 12
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -29,17 +31,19 @@ Namespace declaration statement has to be the very first statement or after any
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 2
-            },
-            "span": {
-              "start": 19,
-              "end": 20
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 19,
+                "end": 20
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 14,
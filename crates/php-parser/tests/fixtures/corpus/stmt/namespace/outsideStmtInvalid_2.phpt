@@ -32,17 +32,19 @@ No code may exist outside of namespace {}
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 1
-            },
-            "span": {
-              "start": 26,
-              "end": 27
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 26,
+                "end": 27
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 21,
@@ -64,7 +64,16 @@ No code may exist outside of namespace {}
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 39,
+                      "end": 42
+                    }
+                  }
                 },
                 "span": {
                   "start": 39,
@@ -18,7 +18,10 @@ Hi!
               "A",
               {
                 "kind": {
-                  "String": "B"
+                  "String": {
+                    "value": "B",
+                    "raw": "B"
+                  }
                 },
                 "span": {
                   "start": 16,
@@ -60,7 +63,12 @@ Hi!
     },
     {
       "kind": {
-        "HaltCompiler": "\nHi!"
+        "HaltCompiler": {
+          "span": {
+            "start": 59,
+            "end": 63
+          }
+        }
       },
       "span": {
         "start": 39,
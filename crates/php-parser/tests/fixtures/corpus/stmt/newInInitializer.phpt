@@ -29,12 +29,15 @@ New expressions are not supported in this context
               "kind": {
                 "New": {
                   "class": {
-                    "kind": {
-                      "Identifier": "Foo"
-                    },
-                    "span": {
-                      "start": 21,
-                      "end": 24
+                    "Name": {
+                      "parts": [
+                        "Foo"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 21,
+                        "end": 24
+                      }
                     }
                   },
                   "args": []
@@ -70,12 +73,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 47,
-                        "end": 50
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 47,
+                          "end": 50
+                        }
                       }
                     },
                     "args": []
@@ -109,12 +115,15 @@ New expressions are not supported in this context
                       "kind": {
                         "New": {
                           "class": {
-                            "kind": {
-                              "Identifier": "Foo"
-                            },
-                            "span": {
-                              "start": 74,
-                              "end": 77
+                            "Name": {
+                              "parts": [
+                                "Foo"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 74,
+                                "end": 77
+                              }
                             }
                           },
                           "args": []
@@ -170,12 +179,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 129,
-                            "end": 132
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 129,
+                              "end": 132
+                            }
                           }
                         },
                         "args": []
@@ -207,12 +219,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 157,
-                            "end": 160
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 157,
+                              "end": 160
+                            }
                           }
                         },
                         "args": []
@@ -251,12 +266,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 93,
-                            "end": 96
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 93,
+                              "end": 96
+                            }
                           }
                         },
                         "args": []
@@ -32,7 +32,16 @@ finally { }
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "doTry"
+                          "Name": {
+                            "parts": [
+                              "doTry"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 17,
+                              "end": 22
+                            }
+                          }
                         },
                         "span": {
                           "start": 17,
@@ -58,17 +67,25 @@ finally { }
             {
               "types": [
                 {
-                  "parts": [
-                    "A"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 35,
-                    "end": 36
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 35,
+                      "end": 36
+                    }
                   }
                 }
               ],
-              "var": "b",
+              "var": {
+                "name": "b",
+                "span": {
+                  "start": 37,
+                  "end": 39
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -77,7 +94,16 @@ finally { }
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "doCatchA"
+                              "Name": {
+                                "parts": [
+                                  "doCatchA"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 47,
+                                  "end": 55
+                                }
+                              }
                             },
                             "span": {
                               "start": 47,
@@ -107,17 +133,25 @@ finally { }
             {
               "types": [
                 {
-                  "parts": [
-                    "B"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 68,
-                    "end": 69
+                  "Name": {
+                    "parts": [
+                      "B"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 68,
+                      "end": 69
+                    }
                   }
                 }
               ],
-              "var": "c",
+              "var": {
+                "name": "c",
+                "span": {
+                  "start": 70,
+                  "end": 72
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -126,7 +160,16 @@ finally { }
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "doCatchB"
+                              "Name": {
+                                "parts": [
+                                  "doCatchB"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 80,
+                                  "end": 88
+                                }
+                              }
                             },
                             "span": {
                               "start": 80,
@@ -162,7 +205,16 @@ finally { }
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "doFinally"
+                          "Name": {
+                            "parts": [
+                              "doFinally"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 108,
+                              "end": 117
+                            }
+                          }
                         },
                         "span": {
                           "start": 108,
@@ -199,17 +251,25 @@ finally { }
             {
               "types": [
                 {
-                  "parts": [
-                    "A"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 153,
-                    "end": 154
+                  "Name": {
+                    "parts": [
+                      "A"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 153,
+                      "end": 154
+                    }
                   }
                 }
               ],
-              "var": "b",
+              "var": {
+                "name": "b",
+                "span": {
+                  "start": 155,
+                  "end": 157
+                }
+              },
               "body": [],
               "span": {
                 "start": 152,
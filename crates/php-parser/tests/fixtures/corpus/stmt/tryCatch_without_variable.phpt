@@ -17,13 +17,15 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 23,
-                    "end": 32
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 23,
+                      "end": 32
+                    }
                   }
                 }
               ],
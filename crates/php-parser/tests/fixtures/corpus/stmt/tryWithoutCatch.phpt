@@ -20,7 +20,16 @@ expected catch or finally clause, found end of file
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 17,
+                              "end": 20
+                            }
+                          }
                         },
                         "span": {
                           "start": 17,
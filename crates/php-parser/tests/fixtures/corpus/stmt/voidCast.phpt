@@ -28,7 +28,16 @@ $x = (void) $y;
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 12,
+                            "end": 15
+                          }
+                        }
                       },
                       "span": {
                         "start": 12,
@@ -67,7 +76,16 @@ $x = (void) $y;
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 28,
+                            "end": 31
+                          }
+                        }
                       },
                       "span": {
                         "start": 28,
@@ -156,7 +174,16 @@ $x = (void) $y;
                       "FunctionCall": {
                         "name": {
                           "kind": {
-                            "Identifier": "a"
+                            "Name": {
+                              "parts": [
+                                "a"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 94,
+                                "end": 95
+                              }
+                            }
                           },
                           "span": {
                             "start": 94,
@@ -212,6 +239,18 @@ $x = (void) $y;
               }
             }
           ],
+          "init_span": {
+            "start": 88,
+            "end": 97
+          },
+          "condition_span": {
+            "start": 99,
+            "end": 101
+          },
+          "update_span": {
+            "start": 103,
+            "end": 111
+          },
           "body": {
             "kind": {
               "Block": []
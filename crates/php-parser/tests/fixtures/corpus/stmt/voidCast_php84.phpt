@@ -19,8 +19,8 @@ $x = (void) $y;
 'void cast' requires PHP 8.5 or higher (targeting PHP 8.4)
 'void cast' requires PHP 8.5 or higher (targeting PHP 8.4)
 'void cast' requires PHP 8.5 or higher (targeting PHP 8.4)
-'void cast' requires PHP 8.5 or higher (targeting PHP 8.4)
 (void) cast cannot be used in an expression context
+'void cast' requires PHP 8.5 or higher (targeting PHP 8.4)
 ===ast===
 {
   "stmts": [
@@ -35,7 +35,16 @@ $x = (void) $y;
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 12,
+                            "end": 15
+                          }
+                        }
                       },
                       "span": {
                         "start": 12,
@@ -74,7 +83,16 @@ $x = (void) $y;
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "foo"
+                        "Name": {
+                          "parts": [
+                            "foo"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 28,
+                            "end": 31
+                          }
+                        }
                       },
                       "span": {
                         "start": 28,
@@ -163,7 +181,16 @@ $x = (void) $y;
                       "FunctionCall": {
                         "name": {
                           "kind": {
-                            "Identifier": "a"
+                            "Name": {
+                              "parts": [
+                                "a"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 94,
+                                "end": 95
+                              }
+                            }
                           },
                           "span": {
                             "start": 94,
@@ -219,6 +246,18 @@ $x = (void) $y;
               }
             }
           ],
+          "init_span": {
+            "start": 88,
+            "end": 97
+          },
+          "condition_span": {
+            "start": 99,
+            "end": 101
+          },
+          "update_span": {
+            "start": 103,
+            "end": 111
+          },
           "body": {
             "kind": {
               "Block": []
@@ -28,17 +28,22 @@ declare(ticks=1) {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "tick"
-                        },
-                        "span": {
-                          "start": 34,
-                          "end": 40
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "tick",
+                              "raw": "tick"
+                            }
+                          },
+                          "span": {
+                            "start": 34,
+                            "end": 40
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 29,
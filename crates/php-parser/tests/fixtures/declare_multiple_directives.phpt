@@ -11,7 +11,10 @@
               "encoding",
               {
                 "kind": {
-                  "String": "UTF-8"
+                  "String": {
+                    "value": "UTF-8",
+                    "raw": "UTF-8"
+                  }
                 },
                 "span": {
                   "start": 23,
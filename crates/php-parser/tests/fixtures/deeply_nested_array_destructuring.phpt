@@ -12,112 +12,130 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 8,
+                                      "end": 10
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 8,
+                                    "end": 10
+                                  },
+                                  "comma": {
+                                    "start": 10,
+                                    "end": 11
+                                  }
                                 },
-                                "span": {
-                                  "start": 8,
-                                  "end": 10
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 8,
-                                "end": 10
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Array": [
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "b"
-                                        },
-                                        "span": {
-                                          "start": 13,
-                                          "end": 15
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 13,
-                                        "end": 15
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "List": {
+                                        "elements": [
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "b"
+                                              },
+                                              "span": {
+                                                "start": 13,
+                                                "end": 15
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 13,
+                                              "end": 15
+                                            },
+                                            "comma": {
+                                              "start": 15,
+                                              "end": 16
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "c"
+                                              },
+                                              "span": {
+                                                "start": 17,
+                                                "end": 19
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 17,
+                                              "end": 19
+                                            }
+                                          }
+                                        ]
                                       }
                                     },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "c"
-                                        },
-                                        "span": {
-                                          "start": 17,
-                                          "end": 19
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 17,
-                                        "end": 19
-                                      }
+                                    "span": {
+                                      "start": 12,
+                                      "end": 20
                                     }
-                                  ]
-                                },
-                                "span": {
-                                  "start": 12,
-                                  "end": 20
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 12,
+                                    "end": 20
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 12,
-                                "end": 20
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 21
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 21
+                        },
+                        "comma": {
+                          "start": 21,
+                          "end": 22
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 21
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "d"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "d"
+                          },
+                          "span": {
+                            "start": 23,
+                            "end": 25
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 23,
                           "end": 25
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 23,
-                        "end": 25
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -154,147 +172,171 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Array": [
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "e"
-                                        },
-                                        "span": {
-                                          "start": 39,
-                                          "end": 41
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 39,
-                                        "end": 41
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "List": {
+                                        "elements": [
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "e"
+                                              },
+                                              "span": {
+                                                "start": 39,
+                                                "end": 41
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 39,
+                                              "end": 41
+                                            },
+                                            "comma": {
+                                              "start": 41,
+                                              "end": 42
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "f"
+                                              },
+                                              "span": {
+                                                "start": 43,
+                                                "end": 45
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 43,
+                                              "end": 45
+                                            }
+                                          }
+                                        ]
                                       }
                                     },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "f"
-                                        },
-                                        "span": {
-                                          "start": 43,
-                                          "end": 45
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 43,
-                                        "end": 45
-                                      }
+                                    "span": {
+                                      "start": 38,
+                                      "end": 46
                                     }
-                                  ]
-                                },
-                                "span": {
-                                  "start": 38,
-                                  "end": 46
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 38,
-                                "end": 46
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "g"
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 38,
+                                    "end": 46
+                                  },
+                                  "comma": {
+                                    "start": 46,
+                                    "end": 47
+                                  }
                                 },
-                                "span": {
-                                  "start": 48,
-                                  "end": 50
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "g"
+                                    },
+                                    "span": {
+                                      "start": 48,
+                                      "end": 50
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 48,
+                                    "end": 50
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 48,
-                                "end": 50
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 37,
+                            "end": 51
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 37,
                           "end": 51
+                        },
+                        "comma": {
+                          "start": 51,
+                          "end": 52
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 37,
-                        "end": 51
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "h"
-                                },
-                                "span": {
-                                  "start": 54,
-                                  "end": 56
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 54,
-                                "end": 56
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "i"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "h"
+                                    },
+                                    "span": {
+                                      "start": 54,
+                                      "end": 56
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 54,
+                                    "end": 56
+                                  },
+                                  "comma": {
+                                    "start": 56,
+                                    "end": 57
+                                  }
                                 },
-                                "span": {
-                                  "start": 58,
-                                  "end": 60
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "i"
+                                    },
+                                    "span": {
+                                      "start": 58,
+                                      "end": 60
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 58,
+                                    "end": 60
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 58,
-                                "end": 60
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 53,
+                            "end": 61
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 53,
                           "end": 61
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 53,
-                        "end": 61
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 36,
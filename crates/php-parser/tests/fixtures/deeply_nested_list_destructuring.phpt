@@ -11,112 +11,133 @@ list(list($a, $b), list($c, $d)) = $pairs;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 16,
+                                      "end": 18
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 16,
+                                    "end": 18
+                                  },
+                                  "comma": {
+                                    "start": 18,
+                                    "end": 19
+                                  }
                                 },
-                                "span": {
-                                  "start": 16,
-                                  "end": 18
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 20,
+                                      "end": 22
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 20,
+                                    "end": 22
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 16,
-                                "end": 18
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
-                                },
-                                "span": {
-                                  "start": 20,
-                                  "end": 22
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 20,
-                                "end": 22
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 23
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 23
+                        },
+                        "comma": {
+                          "start": 23,
+                          "end": 24
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 23
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 30,
+                                      "end": 32
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 30,
+                                    "end": 32
+                                  },
+                                  "comma": {
+                                    "start": 32,
+                                    "end": 33
+                                  }
                                 },
-                                "span": {
-                                  "start": 30,
-                                  "end": 32
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "d"
+                                    },
+                                    "span": {
+                                      "start": 34,
+                                      "end": 36
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 34,
+                                    "end": 36
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 30,
-                                "end": 32
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "d"
-                                },
-                                "span": {
-                                  "start": 34,
-                                  "end": 36
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 34,
-                                "end": 36
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 37
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 25,
                           "end": 37
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 25,
-                        "end": 37
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -105,7 +105,10 @@ $r = match(1) {
                                             ],
                                             "body": {
                                               "kind": {
-                                                "String": "deep"
+                                                "String": {
+                                                  "value": "deep",
+                                                  "raw": "deep"
+                                                }
                                               },
                                               "span": {
                                                 "start": 83,
@@ -115,13 +118,20 @@ $r = match(1) {
                                             "span": {
                                               "start": 78,
                                               "end": 89
+                                            },
+                                            "comma": {
+                                              "start": 89,
+                                              "end": 90
                                             }
                                           },
                                           {
                                             "conditions": null,
                                             "body": {
                                               "kind": {
-                                                "String": "x"
+                                                "String": {
+                                                  "value": "x",
+                                                  "raw": "x"
+                                                }
                                               },
                                               "span": {
                                                 "start": 114,
@@ -144,13 +154,20 @@ $r = match(1) {
                                   "span": {
                                     "start": 50,
                                     "end": 127
+                                  },
+                                  "comma": {
+                                    "start": 127,
+                                    "end": 128
                                   }
                                 },
                                 {
                                   "conditions": null,
                                   "body": {
                                     "kind": {
-                                      "String": "y"
+                                      "String": {
+                                        "value": "y",
+                                        "raw": "y"
+                                      }
                                     },
                                     "span": {
                                       "start": 148,
@@ -173,13 +190,20 @@ $r = match(1) {
                         "span": {
                           "start": 26,
                           "end": 157
+                        },
+                        "comma": {
+                          "start": 157,
+                          "end": 158
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "z"
+                            "String": {
+                              "value": "z",
+                              "raw": "z"
+                            }
                           },
                           "span": {
                             "start": 174,
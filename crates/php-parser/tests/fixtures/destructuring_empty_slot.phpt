@@ -10,57 +10,67 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 9
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 11,
+                            "end": 11
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 11,
+                          "end": 11
+                        },
+                        "comma": {
                           "start": 11,
                           "end": 12
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 12
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 13,
+                            "end": 15
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 13,
                           "end": 15
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 13,
-                        "end": 15
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
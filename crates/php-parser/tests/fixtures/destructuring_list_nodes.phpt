@@ -0,0 +1,506 @@
+===source===
+<?php
+list($a, list($b, , $c)) = $x;
+[$a, ['k' => &$b]] = $y;
+foreach ($rows as $i => [$id, $name]) {}
+$pairs = [[1, 2], [3, 4]];
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 11,
+                          "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 20,
+                                      "end": 22
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 20,
+                                    "end": 22
+                                  },
+                                  "comma": {
+                                    "start": 22,
+                                    "end": 23
+                                  }
+                                },
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": "Omit",
+                                    "span": {
+                                      "start": 24,
+                                      "end": 24
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 24,
+                                    "end": 24
+                                  },
+                                  "comma": {
+                                    "start": 24,
+                                    "end": 25
+                                  }
+                                },
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 26,
+                                      "end": 28
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 26,
+                                    "end": 28
+                                  }
+                                }
+                              ],
+                              "uses_list": true
+                            }
+                          },
+                          "span": {
+                            "start": 15,
+                            "end": 29
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 15,
+                          "end": 29
+                        }
+                      }
+                    ],
+                    "uses_list": true
+                  }
+                },
+                "span": {
+                  "start": 6,
+                  "end": 30
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 33,
+                  "end": 35
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 35
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 36
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 38,
+                            "end": 40
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 38,
+                          "end": 40
+                        },
+                        "comma": {
+                          "start": 40,
+                          "end": 41
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": {
+                                    "kind": {
+                                      "String": {
+                                        "value": "k",
+                                        "raw": "k"
+                                      }
+                                    },
+                                    "span": {
+                                      "start": 43,
+                                      "end": 46
+                                    }
+                                  },
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 51,
+                                      "end": 53
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "by_ref": true,
+                                  "span": {
+                                    "start": 43,
+                                    "end": 53
+                                  }
+                                }
+                              ]
+                            }
+                          },
+                          "span": {
+                            "start": 42,
+                            "end": 54
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 42,
+                          "end": 54
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 37,
+                  "end": 55
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Variable": "y"
+                },
+                "span": {
+                  "start": 58,
+                  "end": 60
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 37,
+            "end": 60
+          }
+        }
+      },
+      "span": {
+        "start": 37,
+        "end": 61
+      }
+    },
+    {
+      "kind": {
+        "Foreach": {
+          "expr": {
+            "kind": {
+              "Variable": "rows"
+            },
+            "span": {
+              "start": 71,
+              "end": 76
+            }
+          },
+          "key": {
+            "kind": {
+              "Variable": "i"
+            },
+            "span": {
+              "start": 80,
+              "end": 82
+            }
+          },
+          "value": {
+            "kind": {
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "id"
+                      },
+                      "span": {
+                        "start": 87,
+                        "end": 90
+                      }
+                    },
+                    "unpack": false,
+                    "span": {
+                      "start": 87,
+                      "end": 90
+                    },
+                    "comma": {
+                      "start": 90,
+                      "end": 91
+                    }
+                  },
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "name"
+                      },
+                      "span": {
+                        "start": 92,
+                        "end": 97
+                      }
+                    },
+                    "unpack": false,
+                    "span": {
+                      "start": 92,
+                      "end": 97
+                    }
+                  }
+                ]
+              }
+            },
+            "span": {
+              "start": 86,
+              "end": 98
+            }
+          },
+          "body": {
+            "kind": {
+              "Block": []
+            },
+            "span": {
+              "start": 100,
+              "end": 102
+            }
+          }
+        }
+      },
+      "span": {
+        "start": 62,
+        "end": 102
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "pairs"
+                },
+                "span": {
+                  "start": 103,
+                  "end": 109
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Array": [
+                    {
+                      "key": null,
+                      "value": {
+                        "kind": {
+                          "Array": [
+                            {
+                              "key": null,
+                              "value": {
+                                "kind": {
+                                  "Int": 1
+                                },
+                                "span": {
+                                  "start": 114,
+                                  "end": 115
+                                }
+                              },
+                              "unpack": false,
+                              "span": {
+                                "start": 114,
+                                "end": 115
+                              },
+                              "comma": {
+                                "start": 115,
+                                "end": 116
+                              }
+                            },
+                            {
+                              "key": null,
+                              "value": {
+                                "kind": {
+                                  "Int": 2
+                                },
+                                "span": {
+                                  "start": 117,
+                                  "end": 118
+                                }
+                              },
+                              "unpack": false,
+                              "span": {
+                                "start": 117,
+                                "end": 118
+                              }
+                            }
+                          ]
+                        },
+                        "span": {
+                          "start": 113,
+                          "end": 119
+                        }
+                      },
+                      "unpack": false,
+                      "span": {
+                        "start": 113,
+                        "end": 119
+                      },
+                      "comma": {
+                        "start": 119,
+                        "end": 120
+                      }
+                    },
+                    {
+                      "key": null,
+                      "value": {
+                        "kind": {
+                          "Array": [
+                            {
+                              "key": null,
+                              "value": {
+                                "kind": {
+                                  "Int": 3
+                                },
+                                "span": {
+                                  "start": 122,
+                                  "end": 123
+                                }
+                              },
+                              "unpack": false,
+                              "span": {
+                                "start": 122,
+                                "end": 123
+                              },
+                              "comma": {
+                                "start": 123,
+                                "end": 124
+                              }
+                            },
+                            {
+                              "key": null,
+                              "value": {
+                                "kind": {
+                                  "Int": 4
+                                },
+                                "span": {
+                                  "start": 125,
+                                  "end": 126
+                                }
+                              },
+                              "unpack": false,
+                              "span": {
+                                "start": 125,
+                                "end": 126
+                              }
+                            }
+                          ]
+                        },
+                        "span": {
+                          "start": 121,
+                          "end": 127
+                        }
+                      },
+                      "unpack": false,
+                      "span": {
+                        "start": 121,
+                        "end": 127
+                      }
+                    }
+                  ]
+                },
+                "span": {
+                  "start": 112,
+                  "end": 128
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 103,
+            "end": 128
+          }
+        }
+      },
+      "span": {
+        "start": 103,
+        "end": 129
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 129
+  }
+}
@@ -107,59 +107,69 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 54,
+                            "end": 56
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 54,
                           "end": 56
+                        },
+                        "comma": {
+                          "start": 56,
+                          "end": 57
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 54,
-                        "end": 56
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 68,
+                            "end": 70
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 68,
                           "end": 70
+                        },
+                        "comma": {
+                          "start": 70,
+                          "end": 71
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 68,
-                        "end": 70
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 84,
+                            "end": 86
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 84,
                           "end": 86
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 84,
-                        "end": 86
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 41,
@@ -196,59 +206,70 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 140,
+                            "end": 142
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 140,
                           "end": 142
+                        },
+                        "comma": {
+                          "start": 142,
+                          "end": 143
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 140,
-                        "end": 142
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "y"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "y"
+                          },
+                          "span": {
+                            "start": 153,
+                            "end": 155
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 153,
                           "end": 155
+                        },
+                        "comma": {
+                          "start": 155,
+                          "end": 156
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 153,
-                        "end": 155
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "z"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "z"
+                          },
+                          "span": {
+                            "start": 167,
+                            "end": 169
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 167,
                           "end": 169
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 167,
-                        "end": 169
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 124,
@@ -285,83 +306,102 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "name"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "name",
+                              "raw": "name"
+                            }
+                          },
+                          "span": {
+                            "start": 221,
+                            "end": 227
+                          }
                         },
-                        "span": {
-                          "start": 221,
-                          "end": 227
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "name"
+                        "value": {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 231,
+                            "end": 236
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 231,
+                          "start": 221,
                           "end": 236
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 221,
-                        "end": 236
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "age"
                         },
-                        "span": {
-                          "start": 250,
-                          "end": 255
+                        "comma": {
+                          "start": 236,
+                          "end": 237
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "age"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "age",
+                              "raw": "age"
+                            }
+                          },
+                          "span": {
+                            "start": 250,
+                            "end": 255
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "age"
+                          },
+                          "span": {
+                            "start": 259,
+                            "end": 263
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 259,
+                          "start": 250,
                           "end": 263
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 250,
-                        "end": 263
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "email"
                         },
-                        "span": {
-                          "start": 278,
-                          "end": 285
+                        "comma": {
+                          "start": 263,
+                          "end": 264
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "email"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "email",
+                              "raw": "email"
+                            }
+                          },
+                          "span": {
+                            "start": 278,
+                            "end": 285
+                          }
                         },
+                        "value": {
+                          "kind": {
+                            "Variable": "email"
+                          },
+                          "span": {
+                            "start": 289,
+                            "end": 295
+                          }
+                        },
+                        "unpack": false,
                         "span": {
-                          "start": 289,
+                          "start": 278,
                           "end": 295
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 278,
-                        "end": 295
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 206,
@@ -398,83 +438,96 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "Int": 0
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "Int": 0
+                          },
+                          "span": {
+                            "start": 373,
+                            "end": 374
+                          }
                         },
-                        "span": {
-                          "start": 373,
-                          "end": 374
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 378,
+                            "end": 384
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 378,
+                          "start": 373,
                           "end": 384
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 373,
-                        "end": 384
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "Int": 1
                         },
-                        "span": {
-                          "start": 401,
-                          "end": 402
+                        "comma": {
+                          "start": 384,
+                          "end": 385
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "second"
+                      {
+                        "key": {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 401,
+                            "end": 402
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "second"
+                          },
+                          "span": {
+                            "start": 406,
+                            "end": 413
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 406,
+                          "start": 401,
                           "end": 413
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 401,
-                        "end": 413
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "third"
                         },
-                        "span": {
-                          "start": 427,
-                          "end": 434
+                        "comma": {
+                          "start": 413,
+                          "end": 414
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "third"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "third",
+                              "raw": "third"
+                            }
+                          },
+                          "span": {
+                            "start": 427,
+                            "end": 434
+                          }
+                        },
+                        "value": {
+                          "kind": {
+                            "Variable": "third"
+                          },
+                          "span": {
+                            "start": 438,
+                            "end": 444
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 438,
+                          "start": 427,
                           "end": 444
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 427,
-                        "end": 444
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 355,
@@ -511,77 +564,89 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
-                                },
-                                "span": {
-                                  "start": 525,
-                                  "end": 527
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 525,
-                                "end": 527
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 525,
+                                      "end": 527
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 525,
+                                    "end": 527
+                                  },
+                                  "comma": {
+                                    "start": 527,
+                                    "end": 528
+                                  }
                                 },
-                                "span": {
-                                  "start": 529,
-                                  "end": 531
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 529,
+                                      "end": 531
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 529,
+                                    "end": 531
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 529,
-                                "end": 531
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 512,
+                            "end": 532
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 512,
                           "end": 532
+                        },
+                        "comma": {
+                          "start": 532,
+                          "end": 533
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 512,
-                        "end": 532
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 549,
+                            "end": 551
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 549,
                           "end": 551
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 549,
-                        "end": 551
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 496,
@@ -626,42 +691,48 @@ list(
           "key": null,
           "value": {
             "kind": {
-              "Array": [
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "name"
+              "List": {
+                "elements": [
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "name"
+                      },
+                      "span": {
+                        "start": 643,
+                        "end": 648
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 643,
                       "end": 648
+                    },
+                    "comma": {
+                      "start": 648,
+                      "end": 649
                     }
                   },
-                  "unpack": false,
-                  "span": {
-                    "start": 643,
-                    "end": 648
-                  }
-                },
-                {
-                  "key": null,
-                  "value": {
-                    "kind": {
-                      "Variable": "value"
+                  {
+                    "key": null,
+                    "value": {
+                      "kind": {
+                        "Variable": "value"
+                      },
+                      "span": {
+                        "start": 659,
+                        "end": 665
+                      }
                     },
+                    "unpack": false,
                     "span": {
                       "start": 659,
                       "end": 665
                     }
-                  },
-                  "unpack": false,
-                  "span": {
-                    "start": 659,
-                    "end": 665
                   }
-                }
-              ]
+                ]
+              }
             },
             "span": {
               "start": 631,
@@ -673,17 +744,19 @@ list(
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "name"
-                        },
-                        "span": {
-                          "start": 677,
-                          "end": 682
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 677,
+                            "end": 682
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 672,
@@ -711,43 +784,49 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "ref"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "ref"
+                          },
+                          "span": {
+                            "start": 745,
+                            "end": 749
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 745,
+                          "start": 744,
                           "end": 749
+                        },
+                        "comma": {
+                          "start": 749,
+                          "end": 750
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 744,
-                        "end": 749
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "normal"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "normal"
+                          },
+                          "span": {
+                            "start": 762,
+                            "end": 769
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 762,
                           "end": 769
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 762,
-                        "end": 769
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 733,
@@ -784,57 +863,67 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 827,
+                            "end": 829
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 827,
                           "end": 829
+                        },
+                        "comma": {
+                          "start": 829,
+                          "end": 830
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 827,
-                        "end": 829
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 843,
+                            "end": 843
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 843,
+                          "end": 843
+                        },
+                        "comma": {
                           "start": 843,
                           "end": 844
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 843,
-                        "end": 844
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 859,
+                            "end": 861
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 859,
                           "end": 861
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 859,
-                        "end": 861
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 811,
@@ -871,72 +960,86 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 909,
+                            "end": 915
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 909,
                           "end": 915
+                        },
+                        "comma": {
+                          "start": 915,
+                          "end": 916
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 909,
-                        "end": 915
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 919,
+                            "end": 919
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 919,
+                          "end": 919
+                        },
+                        "comma": {
                           "start": 919,
                           "end": 920
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 919,
-                        "end": 920
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 938,
+                            "end": 938
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 938,
+                          "end": 938
+                        },
+                        "comma": {
                           "start": 938,
                           "end": 939
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 938,
-                        "end": 939
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "fourth"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "fourth"
+                          },
+                          "span": {
+                            "start": 956,
+                            "end": 963
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 956,
                           "end": 963
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 956,
-                        "end": 963
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 905,
@@ -973,77 +1076,91 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
-                                },
-                                "span": {
-                                  "start": 1039,
-                                  "end": 1041
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1039,
-                                "end": 1041
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 1039,
+                                      "end": 1041
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1039,
+                                    "end": 1041
+                                  },
+                                  "comma": {
+                                    "start": 1041,
+                                    "end": 1042
+                                  }
                                 },
-                                "span": {
-                                  "start": 1043,
-                                  "end": 1045
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 1043,
+                                      "end": 1045
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1043,
+                                    "end": 1045
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1043,
-                                "end": 1045
-                              }
+                              ],
+                              "uses_list": true
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 1022,
+                            "end": 1046
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1022,
                           "end": 1046
+                        },
+                        "comma": {
+                          "start": 1046,
+                          "end": 1047
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 1022,
-                        "end": 1046
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 1060,
+                            "end": 1062
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1060,
                           "end": 1062
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 1060,
-                        "end": 1062
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 1002,
@@ -1080,112 +1197,130 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "a"
-                                },
-                                "span": {
-                                  "start": 1145,
-                                  "end": 1147
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1145,
-                                "end": 1147
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "b"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "a"
+                                    },
+                                    "span": {
+                                      "start": 1145,
+                                      "end": 1147
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1145,
+                                    "end": 1147
+                                  },
+                                  "comma": {
+                                    "start": 1147,
+                                    "end": 1148
+                                  }
                                 },
-                                "span": {
-                                  "start": 1177,
-                                  "end": 1179
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "b"
+                                    },
+                                    "span": {
+                                      "start": 1177,
+                                      "end": 1179
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1177,
+                                    "end": 1179
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1177,
-                                "end": 1179
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 1126,
+                            "end": 1183
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1126,
                           "end": 1183
+                        },
+                        "comma": {
+                          "start": 1183,
+                          "end": 1184
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 1126,
-                        "end": 1183
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "c"
-                                },
-                                "span": {
-                                  "start": 1221,
-                                  "end": 1223
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1221,
-                                "end": 1223
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "d"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "c"
+                                    },
+                                    "span": {
+                                      "start": 1221,
+                                      "end": 1223
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1221,
+                                    "end": 1223
+                                  },
+                                  "comma": {
+                                    "start": 1223,
+                                    "end": 1224
+                                  }
                                 },
-                                "span": {
-                                  "start": 1229,
-                                  "end": 1231
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "d"
+                                    },
+                                    "span": {
+                                      "start": 1229,
+                                      "end": 1231
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 1229,
+                                    "end": 1231
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 1229,
-                                "end": 1231
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 1204,
+                            "end": 1235
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1204,
                           "end": 1235
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 1204,
-                        "end": 1235
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 1109,
@@ -1222,42 +1357,52 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 1286,
+                            "end": 1288
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1286,
                           "end": 1288
+                        },
+                        "comma": {
+                          "start": 1288,
+                          "end": 1289
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 1286,
-                        "end": 1288
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 1305,
+                            "end": 1307
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1305,
                           "end": 1307
+                        },
+                        "comma": {
+                          "start": 1307,
+                          "end": 1308
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 1305,
-                        "end": 1307
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 1282,
@@ -1294,59 +1439,69 @@ list(
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 1358,
+                            "end": 1360
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1358,
                           "end": 1360
+                        },
+                        "comma": {
+                          "start": 1360,
+                          "end": 1361
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 1358,
-                        "end": 1360
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 1381,
+                            "end": 1383
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1381,
                           "end": 1383
+                        },
+                        "comma": {
+                          "start": 1383,
+                          "end": 1384
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 1381,
-                        "end": 1383
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 1399,
+                            "end": 1401
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 1399,
                           "end": 1401
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 1399,
-                        "end": 1401
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 1354,
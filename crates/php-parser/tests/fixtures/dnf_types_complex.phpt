@@ -501,6 +501,10 @@ class Foo {
                       "span": {
                         "start": 133,
                         "end": 147
+                      },
+                      "comma": {
+                        "start": 147,
+                        "end": 148
                       }
                     },
                     {
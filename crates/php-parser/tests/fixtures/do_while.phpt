@@ -11,17 +11,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 16,
-                          "end": 18
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 16,
+                            "end": 18
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 11,
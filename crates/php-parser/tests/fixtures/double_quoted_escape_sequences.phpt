@@ -5,17 +5,22 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "line1\nline2\ttab"
-            },
-            "span": {
-              "start": 11,
-              "end": 30
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "line1\nline2\ttab",
+                  "raw": "line1\\nline2\\ttab"
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 30
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -28,12 +28,15 @@ $d = $class::${'prop_' . $name};
                 "kind": {
                   "ClassConstAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 11,
-                        "end": 14
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 11,
+                          "end": 14
+                        }
                       }
                     },
                     "member": {
@@ -84,12 +87,15 @@ $d = $class::${'prop_' . $name};
                 "kind": {
                   "ClassConstAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 30,
-                        "end": 33
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 30,
+                          "end": 33
+                        }
                       }
                     },
                     "member": {
@@ -97,7 +103,10 @@ $d = $class::${'prop_' . $name};
                         "Binary": {
                           "left": {
                             "kind": {
-                              "String": "CONST_"
+                              "String": {
+                                "value": "CONST_",
+                                "raw": "CONST_"
+                              }
                             },
                             "span": {
                               "start": 36,
@@ -160,12 +169,14 @@ $d = $class::${'prop_' . $name};
                 "kind": {
                   "StaticPropertyAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Variable": "class"
-                      },
-                      "span": {
-                        "start": 62,
-                        "end": 68
+                      "Expr": {
+                        "kind": {
+                          "Variable": "class"
+                        },
+                        "span": {
+                          "start": 62,
+                          "end": 68
+                        }
                       }
                     },
                     "member": {
@@ -224,12 +235,14 @@ $d = $class::${'prop_' . $name};
                 "kind": {
                   "StaticPropertyAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Variable": "class"
-                      },
-                      "span": {
-                        "start": 86,
-                        "end": 92
+                      "Expr": {
+                        "kind": {
+                          "Variable": "class"
+                        },
+                        "span": {
+                          "start": 86,
+                          "end": 92
+                        }
                       }
                     },
                     "member": {
@@ -239,7 +252,10 @@ $d = $class::${'prop_' . $name};
                             "Binary": {
                               "left": {
                                 "kind": {
-                                  "String": "prop_"
+                                  "String": {
+                                    "value": "prop_",
+                                    "raw": "prop_"
+                                  }
                                 },
                                 "span": {
                                   "start": 96,
@@ -59,7 +59,10 @@
                   "Binary": {
                     "left": {
                       "kind": {
-                        "String": "get"
+                        "String": {
+                          "value": "get",
+                          "raw": "get"
+                        }
                       },
                       "span": {
                         "start": 30,
@@ -68,7 +68,10 @@
                     "op": "Concat",
                     "right": {
                       "kind": {
-                        "String": "Suffix"
+                        "String": {
+                          "value": "Suffix",
+                          "raw": "Suffix"
+                        }
                       },
                       "span": {
                         "start": 34,
@@ -12,12 +12,15 @@
                 "kind": {
                   "ClassConstAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 6,
-                        "end": 9
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 6,
+                          "end": 9
+                        }
                       }
                     },
                     "member": {
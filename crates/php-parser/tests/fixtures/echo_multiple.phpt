@@ -5,35 +5,37 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Int": 1
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
             },
-            "span": {
-              "start": 11,
-              "end": 12
-            }
-          },
-          {
-            "kind": {
-              "Int": 2
-            },
-            "span": {
-              "start": 14,
-              "end": 15
-            }
-          },
-          {
-            "kind": {
-              "Int": 3
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 14,
+                "end": 15
+              }
             },
-            "span": {
-              "start": 17,
-              "end": 18
+            {
+              "kind": {
+                "Int": 3
+              },
+              "span": {
+                "start": 17,
+                "end": 18
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
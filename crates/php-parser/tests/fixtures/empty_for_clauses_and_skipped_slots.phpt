@@ -0,0 +1,397 @@
+===source===
+<?php
+for (;;) {}
+for ($i = 0, $j = 1; ; $i++, $j--) {}
+[, $b, , $d] = $x;
+list($a, , $c) = $y;
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "For": {
+          "init": [],
+          "condition": [],
+          "update": [],
+          "init_span": {
+            "start": 11,
+            "end": 11
+          },
+          "condition_span": {
+            "start": 12,
+            "end": 12
+          },
+          "update_span": {
+            "start": 13,
+            "end": 13
+          },
+          "body": {
+            "kind": {
+              "Block": []
+            },
+            "span": {
+              "start": 15,
+              "end": 17
+            }
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 17
+      }
+    },
+    {
+      "kind": {
+        "For": {
+          "init": [
+            {
+              "kind": {
+                "Assign": {
+                  "target": {
+                    "kind": {
+                      "Variable": "i"
+                    },
+                    "span": {
+                      "start": 23,
+                      "end": 25
+                    }
+                  },
+                  "op": "Assign",
+                  "value": {
+                    "kind": {
+                      "Int": 0
+                    },
+                    "span": {
+                      "start": 28,
+                      "end": 29
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 23,
+                "end": 29
+              }
+            },
+            {
+              "kind": {
+                "Assign": {
+                  "target": {
+                    "kind": {
+                      "Variable": "j"
+                    },
+                    "span": {
+                      "start": 31,
+                      "end": 33
+                    }
+                  },
+                  "op": "Assign",
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 36,
+                      "end": 37
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 31,
+                "end": 37
+              }
+            }
+          ],
+          "condition": [],
+          "update": [
+            {
+              "kind": {
+                "UnaryPostfix": {
+                  "operand": {
+                    "kind": {
+                      "Variable": "i"
+                    },
+                    "span": {
+                      "start": 41,
+                      "end": 43
+                    }
+                  },
+                  "op": "PostIncrement"
+                }
+              },
+              "span": {
+                "start": 41,
+                "end": 45
+              }
+            },
+            {
+              "kind": {
+                "UnaryPostfix": {
+                  "operand": {
+                    "kind": {
+                      "Variable": "j"
+                    },
+                    "span": {
+                      "start": 47,
+                      "end": 49
+                    }
+                  },
+                  "op": "PostDecrement"
+                }
+              },
+              "span": {
+                "start": 47,
+                "end": 51
+              }
+            }
+          ],
+          "init_span": {
+            "start": 23,
+            "end": 37
+          },
+          "condition_span": {
+            "start": 39,
+            "end": 39
+          },
+          "update_span": {
+            "start": 41,
+            "end": 51
+          },
+          "body": {
+            "kind": {
+              "Block": []
+            },
+            "span": {
+              "start": 53,
+              "end": 55
+            }
+          }
+        }
+      },
+      "span": {
+        "start": 18,
+        "end": 55
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 57,
+                            "end": 57
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 57,
+                          "end": 57
+                        },
+                        "comma": {
+                          "start": 57,
+                          "end": 58
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 59,
+                            "end": 61
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 59,
+                          "end": 61
+                        },
+                        "comma": {
+                          "start": 61,
+                          "end": 62
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 63,
+                            "end": 63
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 63,
+                          "end": 63
+                        },
+                        "comma": {
+                          "start": 63,
+                          "end": 64
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "d"
+                          },
+                          "span": {
+                            "start": 65,
+                            "end": 67
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 65,
+                          "end": 67
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 56,
+                  "end": 68
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 71,
+                  "end": 73
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 56,
+            "end": 73
+          }
+        }
+      },
+      "span": {
+        "start": 56,
+        "end": 74
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 80,
+                            "end": 82
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 80,
+                          "end": 82
+                        },
+                        "comma": {
+                          "start": 82,
+                          "end": 83
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 84,
+                            "end": 84
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 84,
+                          "end": 84
+                        },
+                        "comma": {
+                          "start": 84,
+                          "end": 85
+                        }
+                      },
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 86,
+                            "end": 88
+                          }
+                        },
+                        "unpack": false,
+                        "span": {
+                          "start": 86,
+                          "end": 88
+                        }
+                      }
+                    ],
+                    "uses_list": true
+                  }
+                },
+                "span": {
+                  "start": 75,
+                  "end": 89
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Variable": "y"
+                },
+                "span": {
+                  "start": 92,
+                  "end": 94
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 75,
+            "end": 94
+          }
+        }
+      },
+      "span": {
+        "start": 75,
+        "end": 95
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 95
+  }
+}
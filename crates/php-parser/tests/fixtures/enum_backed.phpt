@@ -32,7 +32,10 @@ enum Status: string {
                   "name": "Active",
                   "value": {
                     "kind": {
-                      "String": "active"
+                      "String": {
+                        "value": "active",
+                        "raw": "active"
+                      }
                     },
                     "span": {
                       "start": 46,
@@ -53,7 +56,10 @@ enum Status: string {
                   "name": "Inactive",
                   "value": {
                     "kind": {
-                      "String": "inactive"
+                      "String": {
+                        "value": "inactive",
+                        "raw": "inactive"
+                      }
                     },
                     "span": {
                       "start": 76,
@@ -74,7 +80,10 @@ enum Status: string {
                   "name": "Pending",
                   "value": {
                     "kind": {
-                      "String": "pending"
+                      "String": {
+                        "value": "pending",
+                        "raw": "pending"
+                      }
                     },
                     "span": {
                       "start": 107,
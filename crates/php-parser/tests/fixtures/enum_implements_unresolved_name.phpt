@@ -0,0 +1,130 @@
+===description===
+`Base` is a class in namespace `A`, but inside namespace `B` the name
+resolves to `B\Base`, which is not declared in this file — no error.
+===source===
+<?php
+namespace A {
+    class Base {}
+}
+namespace B {
+    enum E implements Base {
+        case X;
+    }
+}
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Namespace": {
+          "name": {
+            "parts": [
+              "A"
+            ],
+            "kind": "Unqualified",
+            "span": {
+              "start": 16,
+              "end": 17
+            }
+          },
+          "body": {
+            "Braced": [
+              {
+                "kind": {
+                  "Class": {
+                    "name": "Base",
+                    "modifiers": {
+                      "is_abstract": false,
+                      "is_final": false,
+                      "is_readonly": false
+                    },
+                    "extends": null,
+                    "implements": [],
+                    "members": [],
+                    "attributes": []
+                  }
+                },
+                "span": {
+                  "start": 24,
+                  "end": 37
+                }
+              }
+            ]
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 39
+      }
+    },
+    {
+      "kind": {
+        "Namespace": {
+          "name": {
+            "parts": [
+              "B"
+            ],
+            "kind": "Unqualified",
+            "span": {
+              "start": 50,
+              "end": 51
+            }
+          },
+          "body": {
+            "Braced": [
+              {
+                "kind": {
+                  "Enum": {
+                    "name": "E",
+                    "scalar_type": null,
+                    "implements": [
+                      {
+                        "parts": [
+                          "Base"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 76,
+                          "end": 80
+                        }
+                      }
+                    ],
+                    "members": [
+                      {
+                        "kind": {
+                          "Case": {
+                            "name": "X",
+                            "value": null,
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 91,
+                          "end": 98
+                        }
+                      }
+                    ],
+                    "attributes": []
+                  }
+                },
+                "span": {
+                  "start": 58,
+                  "end": 104
+                }
+              }
+            ]
+          }
+        }
+      },
+      "span": {
+        "start": 40,
+        "end": 106
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 106
+  }
+}
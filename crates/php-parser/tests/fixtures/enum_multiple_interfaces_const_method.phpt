@@ -62,7 +62,10 @@ enum Status: string implements Loggable, Serializable {
                   "name": "Active",
                   "value": {
                     "kind": {
-                      "String": "active"
+                      "String": {
+                        "value": "active",
+                        "raw": "active"
+                      }
                     },
                     "span": {
                       "start": 80,
@@ -83,7 +86,10 @@ enum Status: string implements Loggable, Serializable {
                   "name": "Inactive",
                   "value": {
                     "kind": {
-                      "String": "inactive"
+                      "String": {
+                        "value": "inactive",
+                        "raw": "inactive"
+                      }
                     },
                     "span": {
                       "start": 110,
@@ -108,10 +114,7 @@ enum Status: string implements Loggable, Serializable {
                     "kind": {
                       "ClassConstAccess": {
                         "class": {
-                          "kind": {
-                            "Identifier": "self"
-                          },
-                          "span": {
+                          "SelfKw": {
                             "start": 143,
                             "end": 147
                           }
@@ -262,10 +265,7 @@ enum Status: string implements Loggable, Serializable {
                                 "kind": {
                                   "ClassConstAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 296,
                                         "end": 300
                                       }
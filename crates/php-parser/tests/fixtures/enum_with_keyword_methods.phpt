@@ -134,7 +134,10 @@ enum Status {
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": ""
+                            "String": {
+                              "value": "",
+                              "raw": ""
+                            }
                           },
                           "span": {
                             "start": 151,
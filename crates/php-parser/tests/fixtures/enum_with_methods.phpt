@@ -53,7 +53,10 @@ enum Suit: string implements HasColor {
                   "name": "Hearts",
                   "value": {
                     "kind": {
-                      "String": "H"
+                      "String": {
+                        "value": "H",
+                        "raw": "H"
+                      }
                     },
                     "span": {
                       "start": 64,
@@ -74,7 +77,10 @@ enum Suit: string implements HasColor {
                   "name": "Diamonds",
                   "value": {
                     "kind": {
-                      "String": "D"
+                      "String": {
+                        "value": "D",
+                        "raw": "D"
+                      }
                     },
                     "span": {
                       "start": 89,
@@ -95,7 +101,10 @@ enum Suit: string implements HasColor {
                   "name": "Clubs",
                   "value": {
                     "kind": {
-                      "String": "C"
+                      "String": {
+                        "value": "C",
+                        "raw": "C"
+                      }
                     },
                     "span": {
                       "start": 111,
@@ -116,7 +125,10 @@ enum Suit: string implements HasColor {
                   "name": "Spades",
                   "value": {
                     "kind": {
-                      "String": "S"
+                      "String": {
+                        "value": "S",
+                        "raw": "S"
+                      }
                     },
                     "span": {
                       "start": 134,
@@ -204,12 +216,15 @@ enum Suit: string implements HasColor {
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Suit"
-                                            },
-                                            "span": {
-                                              "start": 243,
-                                              "end": 247
+                                            "Name": {
+                                              "parts": [
+                                                "Suit"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 243,
+                                                "end": 247
+                                              }
                                             }
                                           },
                                           "member": {
@@ -232,12 +247,15 @@ enum Suit: string implements HasColor {
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Suit"
-                                            },
-                                            "span": {
-                                              "start": 257,
-                                              "end": 261
+                                            "Name": {
+                                              "parts": [
+                                                "Suit"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 257,
+                                                "end": 261
+                                              }
                                             }
                                           },
                                           "member": {
@@ -259,7 +277,10 @@ enum Suit: string implements HasColor {
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "red"
+                                      "String": {
+                                        "value": "red",
+                                        "raw": "red"
+                                      }
                                     },
                                     "span": {
                                       "start": 275,
@@ -269,6 +290,10 @@ enum Suit: string implements HasColor {
                                   "span": {
                                     "start": 243,
                                     "end": 280
+                                  },
+                                  "comma": {
+                                    "start": 280,
+                                    "end": 281
                                   }
                                 },
                                 {
@@ -277,12 +302,15 @@ enum Suit: string implements HasColor {
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Suit"
-                                            },
-                                            "span": {
-                                              "start": 294,
-                                              "end": 298
+                                            "Name": {
+                                              "parts": [
+                                                "Suit"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 294,
+                                                "end": 298
+                                              }
                                             }
                                           },
                                           "member": {
@@ -305,12 +333,15 @@ enum Suit: string implements HasColor {
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Suit"
-                                            },
-                                            "span": {
-                                              "start": 307,
-                                              "end": 311
+                                            "Name": {
+                                              "parts": [
+                                                "Suit"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 307,
+                                                "end": 311
+                                              }
                                             }
                                           },
                                           "member": {
@@ -332,7 +363,10 @@ enum Suit: string implements HasColor {
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "black"
+                                      "String": {
+                                        "value": "black",
+                                        "raw": "black"
+                                      }
                                     },
                                     "span": {
                                       "start": 323,
@@ -342,6 +376,10 @@ enum Suit: string implements HasColor {
                                   "span": {
                                     "start": 294,
                                     "end": 330
+                                  },
+                                  "comma": {
+                                    "start": 330,
+                                    "end": 331
                                   }
                                 }
                               ]
@@ -4,7 +4,7 @@ $x = ;
 $y = 42;
 echo $y;
 ===errors===
-expected expression
+expected expression, found ';' while parsing assigned value
 ===ast===
 {
   "stmts": [
@@ -82,17 +82,19 @@ expected expression
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "y"
-            },
-            "span": {
-              "start": 27,
-              "end": 29
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "y"
+              },
+              "span": {
+                "start": 27,
+                "end": 29
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 22,
@@ -8,42 +8,60 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "FunctionCall": {
-                  "name": {
-                    "kind": {
-                      "Identifier": "file_get_contents"
+              "expr": {
+                "kind": {
+                  "FunctionCall": {
+                    "name": {
+                      "kind": {
+                        "Name": {
+                          "parts": [
+                            "file_get_contents"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 7,
+                            "end": 24
+                          }
+                        }
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 24
-                    }
-                  },
-                  "args": [
-                    {
-                      "name": null,
-                      "value": {
-                        "kind": {
-                          "String": "missing.txt"
+                    "args": [
+                      {
+                        "name": null,
+                        "value": {
+                          "kind": {
+                            "String": {
+                              "value": "missing.txt",
+                              "raw": "missing.txt"
+                            }
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 38
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": false,
                         "span": {
                           "start": 25,
                           "end": 38
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": false,
-                      "span": {
-                        "start": 25,
-                        "end": 38
                       }
-                    }
-                  ]
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 39
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 39
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
@@ -8,59 +8,71 @@
         "Expression": {
           "kind": {
             "ErrorSuppress": {
-              "kind": {
-                "MethodCall": {
-                  "object": {
-                    "kind": {
-                      "Variable": "obj"
+              "expr": {
+                "kind": {
+                  "MethodCall": {
+                    "object": {
+                      "kind": {
+                        "Variable": "obj"
+                      },
+                      "span": {
+                        "start": 7,
+                        "end": 11
+                      }
                     },
-                    "span": {
-                      "start": 7,
-                      "end": 11
-                    }
-                  },
-                  "method": {
-                    "kind": {
-                      "Identifier": "riskyMethod"
+                    "method": {
+                      "kind": {
+                        "Identifier": "riskyMethod"
+                      },
+                      "span": {
+                        "start": 13,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 13,
-                      "end": 24
-                    }
-                  },
-                  "args": [
-                    {
-                      "name": null,
-                      "value": {
-                        "kind": {
-                          "ErrorSuppress": {
-                            "kind": {
-                              "Variable": "nested"
-                            },
-                            "span": {
-                              "start": 26,
-                              "end": 33
+                    "args": [
+                      {
+                        "name": null,
+                        "value": {
+                          "kind": {
+                            "ErrorSuppress": {
+                              "expr": {
+                                "kind": {
+                                  "Variable": "nested"
+                                },
+                                "span": {
+                                  "start": 26,
+                                  "end": 33
+                                }
+                              },
+                              "operator_span": {
+                                "start": 25,
+                                "end": 26
+                              }
                             }
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 33
                           }
                         },
+                        "unpack": false,
+                        "by_ref": false,
                         "span": {
                           "start": 25,
                           "end": 33
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": false,
-                      "span": {
-                        "start": 25,
-                        "end": 33
                       }
-                    }
-                  ]
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 7,
+                  "end": 34
                 }
               },
-              "span": {
-                "start": 7,
-                "end": 34
+              "operator_span": {
+                "start": 6,
+                "end": 7
               }
             }
           },
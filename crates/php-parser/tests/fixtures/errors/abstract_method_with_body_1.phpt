@@ -31,17 +31,22 @@ abstract method cannot contain a body
                   "body": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "body"
-                            },
-                            "span": {
-                              "start": 58,
-                              "end": 64
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "body",
+                                  "raw": "body"
+                                }
+                              },
+                              "span": {
+                                "start": 58,
+                                "end": 64
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 53,
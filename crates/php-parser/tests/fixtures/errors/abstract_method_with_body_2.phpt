@@ -50,7 +50,10 @@ abstract method cannot contain a body
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": ""
+                            "String": {
+                              "value": "",
+                              "raw": ""
+                            }
                           },
                           "span": {
                             "start": 75,
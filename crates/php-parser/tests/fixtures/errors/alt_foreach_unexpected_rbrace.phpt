@@ -3,7 +3,7 @@
     echo $b;
 } endforeach;
 ===errors===
-expected expression
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -34,17 +34,19 @@ expected expression
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "b"
-                        },
-                        "span": {
-                          "start": 35,
-                          "end": 37
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 35,
+                            "end": 37
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 30,
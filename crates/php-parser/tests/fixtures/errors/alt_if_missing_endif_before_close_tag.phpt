@@ -0,0 +1,98 @@
+===source===
+<?php if ($a): ?>
+<p>shown</p>
+<?php echo 1; ?>
+<footer></footer>
+===errors===
+expected 'endif' to close the block, found '?>'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "If": {
+          "condition": {
+            "kind": {
+              "Variable": "a"
+            },
+            "span": {
+              "start": 10,
+              "end": 12
+            }
+          },
+          "then_branch": {
+            "kind": {
+              "Block": [
+                {
+                  "kind": {
+                    "InlineHtml": {
+                      "text": "\n<p>shown</p>\n",
+                      "newline_swallowed": true
+                    }
+                  },
+                  "span": {
+                    "start": 17,
+                    "end": 31
+                  }
+                },
+                {
+                  "kind": {
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 42,
+                            "end": 43
+                          }
+                        }
+                      ]
+                    }
+                  },
+                  "span": {
+                    "start": 37,
+                    "end": 44
+                  }
+                }
+              ]
+            },
+            "span": {
+              "start": 6,
+              "end": 44
+            }
+          },
+          "elseif_branches": [],
+          "else_branch": null,
+          "uses_alternative": true
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 44
+      }
+    },
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "\n<footer></footer>",
+          "newline_swallowed": true
+        }
+      },
+      "span": {
+        "start": 47,
+        "end": 65
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 65
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 45,
+    "end": 47
+  }
+}
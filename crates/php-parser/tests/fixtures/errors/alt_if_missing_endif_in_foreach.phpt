@@ -0,0 +1,151 @@
+===source===
+<?php
+foreach ($rows as $row):
+    if ($row):
+        echo $row;
+endforeach;
+echo 1 +;
+===errors===
+expected 'endif' to close the block, found 'endforeach'
+expected expression, found ';'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Foreach": {
+          "expr": {
+            "kind": {
+              "Variable": "rows"
+            },
+            "span": {
+              "start": 15,
+              "end": 20
+            }
+          },
+          "key": null,
+          "value": {
+            "kind": {
+              "Variable": "row"
+            },
+            "span": {
+              "start": 24,
+              "end": 28
+            }
+          },
+          "body": {
+            "kind": {
+              "Block": [
+                {
+                  "kind": {
+                    "If": {
+                      "condition": {
+                        "kind": {
+                          "Variable": "row"
+                        },
+                        "span": {
+                          "start": 39,
+                          "end": 43
+                        }
+                      },
+                      "then_branch": {
+                        "kind": {
+                          "Block": [
+                            {
+                              "kind": {
+                                "Echo": {
+                                  "exprs": [
+                                    {
+                                      "kind": {
+                                        "Variable": "row"
+                                      },
+                                      "span": {
+                                        "start": 59,
+                                        "end": 63
+                                      }
+                                    }
+                                  ]
+                                }
+                              },
+                              "span": {
+                                "start": 54,
+                                "end": 64
+                              }
+                            }
+                          ]
+                        },
+                        "span": {
+                          "start": 35,
+                          "end": 64
+                        }
+                      },
+                      "elseif_branches": [],
+                      "else_branch": null,
+                      "uses_alternative": true
+                    }
+                  },
+                  "span": {
+                    "start": 35,
+                    "end": 64
+                  }
+                }
+              ]
+            },
+            "span": {
+              "start": 6,
+              "end": 76
+            }
+          },
+          "uses_alternative": true
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 76
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Binary": {
+                  "left": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 82,
+                      "end": 83
+                    }
+                  },
+                  "op": "Add",
+                  "right": {
+                    "kind": "Error",
+                    "span": {
+                      "start": 85,
+                      "end": 86
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 82,
+                "end": 86
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 77,
+        "end": 86
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 86
+  }
+}
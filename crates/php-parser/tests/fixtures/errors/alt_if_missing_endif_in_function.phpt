@@ -0,0 +1,161 @@
+===source===
+<?php
+function a($x) {
+    if ($x):
+        echo 1;
+}
+
+function b() {
+    return 2 +;
+}
+===errors===
+expected 'endif' to close the block, found '}'
+expected expression, found ';' while parsing return value
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Function": {
+          "name": "a",
+          "params": [
+            {
+              "name": "x",
+              "type_hint": null,
+              "default": null,
+              "by_ref": false,
+              "variadic": false,
+              "is_readonly": false,
+              "is_final": false,
+              "visibility": null,
+              "set_visibility": null,
+              "attributes": [],
+              "span": {
+                "start": 17,
+                "end": 19
+              }
+            }
+          ],
+          "body": [
+            {
+              "kind": {
+                "If": {
+                  "condition": {
+                    "kind": {
+                      "Variable": "x"
+                    },
+                    "span": {
+                      "start": 31,
+                      "end": 33
+                    }
+                  },
+                  "then_branch": {
+                    "kind": {
+                      "Block": [
+                        {
+                          "kind": {
+                            "Echo": {
+                              "exprs": [
+                                {
+                                  "kind": {
+                                    "Int": 1
+                                  },
+                                  "span": {
+                                    "start": 49,
+                                    "end": 50
+                                  }
+                                }
+                              ]
+                            }
+                          },
+                          "span": {
+                            "start": 44,
+                            "end": 51
+                          }
+                        }
+                      ]
+                    },
+                    "span": {
+                      "start": 27,
+                      "end": 51
+                    }
+                  },
+                  "elseif_branches": [],
+                  "else_branch": null,
+                  "uses_alternative": true
+                }
+              },
+              "span": {
+                "start": 27,
+                "end": 51
+              }
+            }
+          ],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 53
+      }
+    },
+    {
+      "kind": {
+        "Function": {
+          "name": "b",
+          "params": [],
+          "body": [
+            {
+              "kind": {
+                "Return": {
+                  "kind": {
+                    "Binary": {
+                      "left": {
+                        "kind": {
+                          "Int": 2
+                        },
+                        "span": {
+                          "start": 81,
+                          "end": 82
+                        }
+                      },
+                      "op": "Add",
+                      "right": {
+                        "kind": "Error",
+                        "span": {
+                          "start": 84,
+                          "end": 85
+                        }
+                      }
+                    }
+                  },
+                  "span": {
+                    "start": 81,
+                    "end": 85
+                  }
+                }
+              },
+              "span": {
+                "start": 74,
+                "end": 85
+              }
+            }
+          ],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 55,
+        "end": 87
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 87
+  }
+}
@@ -0,0 +1,150 @@
+===source===
+<?php
+switch ($a):
+    case 1:
+        if ($b):
+            echo 1;
+    case 2:
+        echo 2;
+endswitch;
+===errors===
+expected 'endif' to close the block, found 'case'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Switch": {
+          "expr": {
+            "kind": {
+              "Variable": "a"
+            },
+            "span": {
+              "start": 14,
+              "end": 16
+            }
+          },
+          "cases": [
+            {
+              "value": {
+                "kind": {
+                  "Int": 1
+                },
+                "span": {
+                  "start": 28,
+                  "end": 29
+                }
+              },
+              "body": [
+                {
+                  "kind": {
+                    "If": {
+                      "condition": {
+                        "kind": {
+                          "Variable": "b"
+                        },
+                        "span": {
+                          "start": 43,
+                          "end": 45
+                        }
+                      },
+                      "then_branch": {
+                        "kind": {
+                          "Block": [
+                            {
+                              "kind": {
+                                "Echo": {
+                                  "exprs": [
+                                    {
+                                      "kind": {
+                                        "Int": 1
+                                      },
+                                      "span": {
+                                        "start": 65,
+                                        "end": 66
+                                      }
+                                    }
+                                  ]
+                                }
+                              },
+                              "span": {
+                                "start": 60,
+                                "end": 67
+                              }
+                            }
+                          ]
+                        },
+                        "span": {
+                          "start": 39,
+                          "end": 67
+                        }
+                      },
+                      "elseif_branches": [],
+                      "else_branch": null,
+                      "uses_alternative": true
+                    }
+                  },
+                  "span": {
+                    "start": 39,
+                    "end": 67
+                  }
+                }
+              ],
+              "span": {
+                "start": 23,
+                "end": 67
+              }
+            },
+            {
+              "value": {
+                "kind": {
+                  "Int": 2
+                },
+                "span": {
+                  "start": 77,
+                  "end": 78
+                }
+              },
+              "body": [
+                {
+                  "kind": {
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 2
+                          },
+                          "span": {
+                            "start": 93,
+                            "end": 94
+                          }
+                        }
+                      ]
+                    }
+                  },
+                  "span": {
+                    "start": 88,
+                    "end": 95
+                  }
+                }
+              ],
+              "span": {
+                "start": 72,
+                "end": 95
+              }
+            }
+          ],
+          "uses_alternative": true
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 106
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 106
+  }
+}
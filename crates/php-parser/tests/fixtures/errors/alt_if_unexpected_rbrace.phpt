@@ -3,7 +3,7 @@
     echo 1;
 } endif;
 ===errors===
-expected expression
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -24,17 +24,19 @@ expected expression
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 26,
-                          "end": 27
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 26,
+                            "end": 27
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 21,
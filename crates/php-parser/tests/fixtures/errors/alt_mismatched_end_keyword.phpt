@@ -0,0 +1,110 @@
+===source===
+<?php
+if ($a):
+    echo 1;
+endwhile;
+echo 2 +;
+===errors===
+expected 'endif', found 'endwhile'
+expected expression, found ';'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "If": {
+          "condition": {
+            "kind": {
+              "Variable": "a"
+            },
+            "span": {
+              "start": 10,
+              "end": 12
+            }
+          },
+          "then_branch": {
+            "kind": {
+              "Block": [
+                {
+                  "kind": {
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 24,
+                            "end": 25
+                          }
+                        }
+                      ]
+                    }
+                  },
+                  "span": {
+                    "start": 19,
+                    "end": 26
+                  }
+                }
+              ]
+            },
+            "span": {
+              "start": 6,
+              "end": 26
+            }
+          },
+          "elseif_branches": [],
+          "else_branch": null,
+          "uses_alternative": true
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 36
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Binary": {
+                  "left": {
+                    "kind": {
+                      "Int": 2
+                    },
+                    "span": {
+                      "start": 42,
+                      "end": 43
+                    }
+                  },
+                  "op": "Add",
+                  "right": {
+                    "kind": "Error",
+                    "span": {
+                      "start": 45,
+                      "end": 46
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 42,
+                "end": 46
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 37,
+        "end": 46
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 46
+  }
+}
@@ -3,7 +3,7 @@
     echo 1;
 } endwhile;
 ===errors===
-expected expression
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -24,17 +24,19 @@ expected expression
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Int": 1
-                        },
-                        "span": {
-                          "start": 29,
-                          "end": 30
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 29,
+                            "end": 30
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 24,
@@ -1,7 +1,7 @@
 ===source===
 <?php [$a, , ,] = ; echo $a;
 ===errors===
-expected expression
+expected expression, found ';' while parsing assigned value
 ===ast===
 {
   "stmts": [
@@ -12,55 +12,69 @@ expected expression
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 9
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 11,
+                            "end": 11
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 11,
+                          "end": 11
+                        },
+                        "comma": {
                           "start": 11,
                           "end": 12
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 12
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 13,
+                            "end": 13
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 13,
+                          "end": 13
+                        },
+                        "comma": {
                           "start": 13,
                           "end": 14
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 13,
-                        "end": 14
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -90,17 +104,19 @@ expected expression
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Variable": "a"
-            },
-            "span": {
-              "start": 25,
-              "end": 27
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "a"
+              },
+              "span": {
+                "start": 25,
+                "end": 27
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 20,
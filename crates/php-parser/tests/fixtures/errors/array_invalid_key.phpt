@@ -1,7 +1,7 @@
 ===source===
 <?php $a = [=> 'value'];
 ===errors===
-expected expression
+expected expression, found '=>' while parsing array element 1
 ===ast===
 {
   "stmts": [
@@ -33,7 +33,10 @@ expected expression
                       },
                       "value": {
                         "kind": {
-                          "String": "value"
+                          "String": {
+                            "value": "value",
+                            "raw": "value"
+                          }
                         },
                         "span": {
                           "start": 15,
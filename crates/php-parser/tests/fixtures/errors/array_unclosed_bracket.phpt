@@ -38,6 +38,10 @@ expected ']', found ';'
                       "span": {
                         "start": 12,
                         "end": 13
+                      },
+                      "comma": {
+                        "start": 13,
+                        "end": 14
                       }
                     },
                     {
@@ -55,6 +59,10 @@ expected ']', found ';'
                       "span": {
                         "start": 15,
                         "end": 16
+                      },
+                      "comma": {
+                        "start": 16,
+                        "end": 17
                       }
                     },
                     {
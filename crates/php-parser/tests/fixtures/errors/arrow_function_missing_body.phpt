@@ -1,7 +1,7 @@
 ===source===
 <?php fn($x) =>;
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
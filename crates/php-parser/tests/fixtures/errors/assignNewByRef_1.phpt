@@ -27,12 +27,15 @@ Cannot use by-reference assignment with 'new' expression
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "B"
-                      },
-                      "span": {
-                        "start": 16,
-                        "end": 17
+                      "Name": {
+                        "parts": [
+                          "B"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 16,
+                          "end": 17
+                        }
                       }
                     },
                     "args": []
@@ -15,7 +15,16 @@ Cannot use expression as assignment target.
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "func"
+                        "Name": {
+                          "parts": [
+                            "func"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 6,
+                            "end": 10
+                          }
+                        }
                       },
                       "span": {
                         "start": 6,
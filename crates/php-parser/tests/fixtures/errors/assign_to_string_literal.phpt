@@ -12,7 +12,10 @@ Cannot use expression as assignment target.
             "Assign": {
               "target": {
                 "kind": {
-                  "String": "string"
+                  "String": {
+                    "value": "string",
+                    "raw": "string"
+                  }
                 },
                 "span": {
                   "start": 6,
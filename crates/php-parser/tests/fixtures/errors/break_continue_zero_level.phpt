@@ -99,6 +99,18 @@ for ($i = 0; $i < 10; $i++) {
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
@@ -220,6 +232,18 @@ for ($i = 0; $i < 10; $i++) {
               }
             }
           ],
+          "init_span": {
+            "start": 56,
+            "end": 62
+          },
+          "condition_span": {
+            "start": 64,
+            "end": 71
+          },
+          "update_span": {
+            "start": 73,
+            "end": 77
+          },
           "body": {
             "kind": {
               "Block": [
@@ -95,6 +95,18 @@ Cannot 'break' 5 levels
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
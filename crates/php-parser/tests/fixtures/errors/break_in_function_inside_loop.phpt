@@ -97,6 +97,18 @@ Cannot 'break' 1 level
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
@@ -12,7 +12,16 @@ call-time pass-by-reference is not allowed
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "sort"
+                  "Name": {
+                    "parts": [
+                      "sort"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
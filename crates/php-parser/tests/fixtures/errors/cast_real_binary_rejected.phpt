@@ -2,7 +2,7 @@
 <?php
 $a = (real) 1.5;
 ===errors===
-the (real) cast is no longer supported, use (float) instead
+the (real) cast was removed in PHP 8.0 (targeting PHP 8.5)
 ===ast===
 {
   "stmts": [
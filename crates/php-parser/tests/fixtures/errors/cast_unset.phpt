@@ -1,7 +1,7 @@
 ===source===
 <?php (unset)$x;
 ===errors===
-the (unset) cast is no longer supported
+the (unset) cast was removed in PHP 8.0 (targeting PHP 8.5)
 ===ast===
 {
   "stmts": [
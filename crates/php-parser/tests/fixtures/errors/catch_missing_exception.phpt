@@ -15,11 +15,13 @@ expected ')', found '{'
             {
               "types": [
                 {
-                  "parts": [],
-                  "kind": "Error",
-                  "span": {
-                    "start": 20,
-                    "end": 21
+                  "Name": {
+                    "parts": [],
+                    "kind": "Error",
+                    "span": {
+                      "start": 20,
+                      "end": 21
+                    }
                   }
                 }
               ],
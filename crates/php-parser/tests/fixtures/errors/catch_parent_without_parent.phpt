@@ -0,0 +1,274 @@
+===description===
+`parent` as a catch type in a method of a class without `extends` cannot
+resolve: "Cannot use "parent" when current class scope has no parent".
+Trait methods are bound to the using class and are accepted.
+===source===
+<?php
+class A {
+    public function run() {
+        try {} catch (parent | self $e) {}
+    }
+}
+class B extends A {
+    public function run() {
+        try {} catch (parent $e) {}
+    }
+}
+trait T {
+    public function run() {
+        try {} catch (parent $e) {}
+    }
+}
+===errors===
+Cannot use "parent" when current class scope has no parent
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Class": {
+          "name": "A",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "Method": {
+                  "name": "run",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": [
+                    {
+                      "kind": {
+                        "TryCatch": {
+                          "body": [],
+                          "catches": [
+                            {
+                              "types": [
+                                {
+                                  "ParentKw": {
+                                    "start": 66,
+                                    "end": 72
+                                  }
+                                },
+                                {
+                                  "SelfKw": {
+                                    "start": 75,
+                                    "end": 79
+                                  }
+                                }
+                              ],
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 80,
+                                  "end": 82
+                                }
+                              },
+                              "body": [],
+                              "span": {
+                                "start": 65,
+                                "end": 86
+                              }
+                            }
+                          ],
+                          "finally": null
+                        }
+                      },
+                      "span": {
+                        "start": 52,
+                        "end": 86
+                      }
+                    }
+                  ],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 20,
+                "end": 92
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 94
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "B",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": {
+            "parts": [
+              "A"
+            ],
+            "kind": "Unqualified",
+            "span": {
+              "start": 111,
+              "end": 112
+            }
+          },
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "Method": {
+                  "name": "run",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": [
+                    {
+                      "kind": {
+                        "TryCatch": {
+                          "body": [],
+                          "catches": [
+                            {
+                              "types": [
+                                {
+                                  "ParentKw": {
+                                    "start": 165,
+                                    "end": 171
+                                  }
+                                }
+                              ],
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 172,
+                                  "end": 174
+                                }
+                              },
+                              "body": [],
+                              "span": {
+                                "start": 164,
+                                "end": 178
+                              }
+                            }
+                          ],
+                          "finally": null
+                        }
+                      },
+                      "span": {
+                        "start": 151,
+                        "end": 178
+                      }
+                    }
+                  ],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 119,
+                "end": 184
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 95,
+        "end": 186
+      }
+    },
+    {
+      "kind": {
+        "Trait": {
+          "name": "T",
+          "members": [
+            {
+              "kind": {
+                "Method": {
+                  "name": "run",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": [
+                    {
+                      "kind": {
+                        "TryCatch": {
+                          "body": [],
+                          "catches": [
+                            {
+                              "types": [
+                                {
+                                  "ParentKw": {
+                                    "start": 247,
+                                    "end": 253
+                                  }
+                                }
+                              ],
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 254,
+                                  "end": 256
+                                }
+                              },
+                              "body": [],
+                              "span": {
+                                "start": 246,
+                                "end": 260
+                              }
+                            }
+                          ],
+                          "finally": null
+                        }
+                      },
+                      "span": {
+                        "start": 233,
+                        "end": 260
+                      }
+                    }
+                  ],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 201,
+                "end": 266
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 187,
+        "end": 268
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 268
+  }
+}
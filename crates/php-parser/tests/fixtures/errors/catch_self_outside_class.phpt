@@ -0,0 +1,202 @@
+===description===
+A function declared outside any class has no `self` or `parent`, so PHP
+rejects them as catch types at compile time. Top-level code and closures
+are bound to a class only at run time and are accepted.
+===source===
+<?php
+function handle() {
+    try {} catch (self | parent $e) {}
+}
+try {} catch (self $e) {}
+$f = function () {
+    try {} catch (self $e) {}
+};
+===errors===
+Cannot use "self" when no class scope is active
+Cannot use "parent" when no class scope is active
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Function": {
+          "name": "handle",
+          "params": [],
+          "body": [
+            {
+              "kind": {
+                "TryCatch": {
+                  "body": [],
+                  "catches": [
+                    {
+                      "types": [
+                        {
+                          "SelfKw": {
+                            "start": 44,
+                            "end": 48
+                          }
+                        },
+                        {
+                          "ParentKw": {
+                            "start": 51,
+                            "end": 57
+                          }
+                        }
+                      ],
+                      "var": {
+                        "name": "e",
+                        "span": {
+                          "start": 58,
+                          "end": 60
+                        }
+                      },
+                      "body": [],
+                      "span": {
+                        "start": 43,
+                        "end": 64
+                      }
+                    }
+                  ],
+                  "finally": null
+                }
+              },
+              "span": {
+                "start": 30,
+                "end": 64
+              }
+            }
+          ],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 66
+      }
+    },
+    {
+      "kind": {
+        "TryCatch": {
+          "body": [],
+          "catches": [
+            {
+              "types": [
+                {
+                  "SelfKw": {
+                    "start": 81,
+                    "end": 85
+                  }
+                }
+              ],
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 86,
+                  "end": 88
+                }
+              },
+              "body": [],
+              "span": {
+                "start": 80,
+                "end": 92
+              }
+            }
+          ],
+          "finally": null
+        }
+      },
+      "span": {
+        "start": 67,
+        "end": 92
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "f"
+                },
+                "span": {
+                  "start": 93,
+                  "end": 95
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Closure": {
+                    "is_static": false,
+                    "by_ref": false,
+                    "params": [],
+                    "use_vars": [],
+                    "return_type": null,
+                    "body": [
+                      {
+                        "kind": {
+                          "TryCatch": {
+                            "body": [],
+                            "catches": [
+                              {
+                                "types": [
+                                  {
+                                    "SelfKw": {
+                                      "start": 130,
+                                      "end": 134
+                                    }
+                                  }
+                                ],
+                                "var": {
+                                  "name": "e",
+                                  "span": {
+                                    "start": 135,
+                                    "end": 137
+                                  }
+                                },
+                                "body": [],
+                                "span": {
+                                  "start": 129,
+                                  "end": 141
+                                }
+                              }
+                            ],
+                            "finally": null
+                          }
+                        },
+                        "span": {
+                          "start": 116,
+                          "end": 141
+                        }
+                      }
+                    ],
+                    "attributes": []
+                  }
+                },
+                "span": {
+                  "start": 98,
+                  "end": 143
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 93,
+            "end": 143
+          }
+        }
+      },
+      "span": {
+        "start": 93,
+        "end": 144
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 144
+  }
+}
@@ -0,0 +1,52 @@
+===description===
+`static` is not a class name in a catch type list.
+===source===
+<?php
+try {} catch (static $e) {}
+===errors===
+expected class name, found 'static'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "TryCatch": {
+          "body": [],
+          "catches": [
+            {
+              "types": [
+                {
+                  "StaticKw": {
+                    "start": 20,
+                    "end": 26
+                  }
+                }
+              ],
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 27,
+                  "end": 29
+                }
+              },
+              "body": [],
+              "span": {
+                "start": 19,
+                "end": 33
+              }
+            }
+          ],
+          "finally": null
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 33
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 33
+  }
+}
@@ -13,13 +13,15 @@ expected ')', found '{'
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 21,
-                    "end": 30
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 21,
+                      "end": 30
+                    }
                   }
                 }
               ],
@@ -30,12 +30,14 @@ class constant access is not valid as a standalone interpolation expression
                         "kind": {
                           "ClassConstAccessDynamic": {
                             "class": {
-                              "kind": {
-                                "Variable": "obj"
-                              },
-                              "span": {
-                                "start": 13,
-                                "end": 17
+                              "Expr": {
+                                "kind": {
+                                  "Variable": "obj"
+                                },
+                                "span": {
+                                  "start": 13,
+                                  "end": 17
+                                }
                               }
                             },
                             "member": {
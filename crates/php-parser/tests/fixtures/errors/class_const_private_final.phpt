@@ -0,0 +1,88 @@
+===description===
+A private constant is never visible to subclasses, so `final` is
+meaningless: "Private constant A::X cannot be final as it is not visible
+to other classes".
+===source===
+<?php
+class A {
+    final private const X = 1;
+    final protected const Y = 2;
+}
+===errors===
+Private constant X cannot be final as it is not visible to other classes
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Class": {
+          "name": "A",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "X",
+                  "visibility": "Private",
+                  "is_final": true,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 44,
+                      "end": 45
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 20,
+                "end": 46
+              }
+            },
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "Y",
+                  "visibility": "Protected",
+                  "is_final": true,
+                  "value": {
+                    "kind": {
+                      "Int": 2
+                    },
+                    "span": {
+                      "start": 77,
+                      "end": 78
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 51,
+                "end": 79
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 81
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 81
+  }
+}
@@ -107,7 +107,10 @@ expected variable, found ')'
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": "ok"
+                            "String": {
+                              "value": "ok",
+                              "raw": "ok"
+                            }
                           },
                           "span": {
                             "start": 95,
@@ -65,6 +65,10 @@ expected ')', found '{'
                       "span": {
                         "start": 42,
                         "end": 48
+                      },
+                      "comma": {
+                        "start": 48,
+                        "end": 49
                       }
                     },
                     {
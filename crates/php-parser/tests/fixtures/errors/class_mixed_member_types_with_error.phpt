@@ -120,7 +120,10 @@ expected class member, found '@'
                   },
                   "default": {
                     "kind": {
-                      "String": "hello"
+                      "String": {
+                        "value": "hello",
+                        "raw": "hello"
+                      }
                     },
                     "span": {
                       "start": 104,
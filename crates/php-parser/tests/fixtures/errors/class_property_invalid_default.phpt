@@ -5,7 +5,7 @@ class Foo {
     public string $name = "valid";
 }
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
@@ -110,7 +110,10 @@ expected expression
                   },
                   "default": {
                     "kind": {
-                      "String": "valid"
+                      "String": {
+                        "value": "valid",
+                        "raw": "valid"
+                      }
                     },
                     "span": {
                       "start": 69,
@@ -1,7 +1,7 @@
 ===source===
 <?php $x = + + ;
 ===errors===
-expected expression
+expected expression, found ';' while parsing assigned value
 ===ast===
 {
   "stmts": [
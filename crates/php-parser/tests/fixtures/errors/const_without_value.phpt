@@ -2,7 +2,7 @@
 <?php const X;
 ===errors===
 expected '=', found ';'
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
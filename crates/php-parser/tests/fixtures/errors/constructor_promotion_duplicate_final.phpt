@@ -65,6 +65,10 @@ duplicate modifier 'final'
                       "span": {
                         "start": 59,
                         "end": 87
+                      },
+                      "comma": {
+                        "start": 87,
+                        "end": 88
                       }
                     }
                   ],
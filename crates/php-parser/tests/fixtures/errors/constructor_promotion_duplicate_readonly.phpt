@@ -65,6 +65,10 @@ duplicate modifier 'readonly'
                       "span": {
                         "start": 59,
                         "end": 93
+                      },
+                      "comma": {
+                        "start": 93,
+                        "end": 94
                       }
                     }
                   ],
@@ -65,6 +65,10 @@ cannot use multiple visibility modifiers
                       "span": {
                         "start": 59,
                         "end": 82
+                      },
+                      "comma": {
+                        "start": 82,
+                        "end": 83
                       }
                     }
                   ],
@@ -1,7 +1,7 @@
 ===source===
 <?php foo(1,, 2);
 ===errors===
-expected expression
+expected expression, found ',' while parsing argument 2 of call to foo
 ===ast===
 {
   "stmts": [
@@ -12,7 +12,16 @@ expected expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -36,6 +45,10 @@ expected expression
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
@@ -52,6 +65,10 @@ expected expression
                   "span": {
                     "start": 12,
                     "end": 13
+                  },
+                  "comma": {
+                    "start": 12,
+                    "end": 13
                   }
                 },
                 {
@@ -0,0 +1,69 @@
+===description===
+The `final private` method warning applies to enum methods as well as
+class methods (see errors/final_private_method.phpt).
+===source===
+<?php
+enum E {
+    case A;
+    final private function f() {}
+}
+===errors===
+Private methods cannot be final as they are never overridden by other classes
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Enum": {
+          "name": "E",
+          "scalar_type": null,
+          "implements": [],
+          "members": [
+            {
+              "kind": {
+                "Case": {
+                  "name": "A",
+                  "value": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 19,
+                "end": 26
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "f",
+                  "visibility": "Private",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": true,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": [],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 31,
+                "end": 60
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 62
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 62
+  }
+}
@@ -46,7 +46,10 @@ Case Active of backed enum Status must have a value
                   "name": "Inactive",
                   "value": {
                     "kind": {
-                      "String": "inactive"
+                      "String": {
+                        "value": "inactive",
+                        "raw": "inactive"
+                      }
                     },
                     "span": {
                       "start": 65,
@@ -7,7 +7,7 @@ enum Status: int {
     case Inactive = 2;
 }
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
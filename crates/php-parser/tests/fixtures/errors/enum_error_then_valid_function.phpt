@@ -3,8 +3,8 @@ min_php=8.1
 ===source===
 <?php enum Status { case Active = ; } function use_status() { return Status::Active; }
 ===errors===
-expected expression
 Case Active of pure enum Status must not have a value
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
@@ -55,12 +55,15 @@ Case Active of pure enum Status must not have a value
                   "kind": {
                     "ClassConstAccess": {
                       "class": {
-                        "kind": {
-                          "Identifier": "Status"
-                        },
-                        "span": {
-                          "start": 69,
-                          "end": 75
+                        "Name": {
+                          "parts": [
+                            "Status"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 69,
+                            "end": 75
+                          }
                         }
                       },
                       "member": {
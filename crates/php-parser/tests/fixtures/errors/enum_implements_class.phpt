@@ -0,0 +1,275 @@
+===description===
+An `implements` list may only name interfaces. When a name resolves,
+through the namespace and `use` imports, to a class declared in the same
+file, PHP fails with "E cannot implement A - it is not an interface".
+===source===
+<?php
+namespace App;
+
+use App\Models\Base as Model;
+
+class Base {}
+
+enum Status implements Base, \JsonSerializable {
+    case Active;
+}
+
+class Widget implements \App\Base {}
+
+namespace App\Models;
+
+class Base {}
+
+enum Kind implements Base {
+    case A;
+}
+===errors===
+Status cannot implement Base - it is not an interface
+Widget cannot implement \App\Base - it is not an interface
+Kind cannot implement Base - it is not an interface
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Namespace": {
+          "name": {
+            "parts": [
+              "App"
+            ],
+            "kind": "Unqualified",
+            "span": {
+              "start": 16,
+              "end": 19
+            }
+          },
+          "body": "Simple"
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 20
+      }
+    },
+    {
+      "kind": {
+        "Use": {
+          "kind": "Normal",
+          "uses": [
+            {
+              "name": {
+                "parts": [
+                  "App",
+                  "Models",
+                  "Base"
+                ],
+                "kind": "Qualified",
+                "span": {
+                  "start": 26,
+                  "end": 41
+                }
+              },
+              "alias": "Model",
+              "span": {
+                "start": 26,
+                "end": 50
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 22,
+        "end": 51
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "Base",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 53,
+        "end": 66
+      }
+    },
+    {
+      "kind": {
+        "Enum": {
+          "name": "Status",
+          "scalar_type": null,
+          "implements": [
+            {
+              "parts": [
+                "Base"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 91,
+                "end": 95
+              }
+            },
+            {
+              "parts": [
+                "JsonSerializable"
+              ],
+              "kind": "FullyQualified",
+              "span": {
+                "start": 97,
+                "end": 114
+              }
+            }
+          ],
+          "members": [
+            {
+              "kind": {
+                "Case": {
+                  "name": "Active",
+                  "value": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 121,
+                "end": 133
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 68,
+        "end": 135
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "Widget",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [
+            {
+              "parts": [
+                "App",
+                "Base"
+              ],
+              "kind": "FullyQualified",
+              "span": {
+                "start": 161,
+                "end": 170
+              }
+            }
+          ],
+          "members": [],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 137,
+        "end": 173
+      }
+    },
+    {
+      "kind": {
+        "Namespace": {
+          "name": {
+            "parts": [
+              "App",
+              "Models"
+            ],
+            "kind": "Qualified",
+            "span": {
+              "start": 185,
+              "end": 195
+            }
+          },
+          "body": "Simple"
+        }
+      },
+      "span": {
+        "start": 175,
+        "end": 196
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "Base",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": null,
+          "implements": [],
+          "members": [],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 198,
+        "end": 211
+      }
+    },
+    {
+      "kind": {
+        "Enum": {
+          "name": "Kind",
+          "scalar_type": null,
+          "implements": [
+            {
+              "parts": [
+                "Base"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 234,
+                "end": 238
+              }
+            }
+          ],
+          "members": [
+            {
+              "kind": {
+                "Case": {
+                  "name": "A",
+                  "value": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 245,
+                "end": 252
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 213,
+        "end": 254
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 254
+  }
+}
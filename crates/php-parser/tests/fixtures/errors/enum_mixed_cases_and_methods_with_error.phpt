@@ -79,7 +79,10 @@ expected enum member, found '@'
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": "Status"
+                            "String": {
+                              "value": "Status",
+                              "raw": "Status"
+                            }
                           },
                           "span": {
                             "start": 114,
@@ -1,7 +1,7 @@
 ===source===
 <?php + ; $x = 1;
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
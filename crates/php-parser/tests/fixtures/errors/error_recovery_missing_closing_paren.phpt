@@ -12,7 +12,16 @@ expected ')', found ';'
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -36,6 +45,10 @@ expected ')', found ';'
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
@@ -0,0 +1,259 @@
+===source===
+<?php
+foo(function () { if () {} });
+$obj->bar([1, 2, ...]);
+return *;
+===errors===
+expected expression, found ')' while parsing if condition
+expected expression, found ']' while parsing array element 3
+expected expression, found '*' while parsing return value
+expected expression, found ';' while parsing return value
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
+                },
+                "span": {
+                  "start": 6,
+                  "end": 9
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Closure": {
+                        "is_static": false,
+                        "by_ref": false,
+                        "params": [],
+                        "use_vars": [],
+                        "return_type": null,
+                        "body": [
+                          {
+                            "kind": {
+                              "If": {
+                                "condition": {
+                                  "kind": "Error",
+                                  "span": {
+                                    "start": 28,
+                                    "end": 29
+                                  }
+                                },
+                                "then_branch": {
+                                  "kind": {
+                                    "Block": []
+                                  },
+                                  "span": {
+                                    "start": 30,
+                                    "end": 32
+                                  }
+                                },
+                                "elseif_branches": [],
+                                "else_branch": null
+                              }
+                            },
+                            "span": {
+                              "start": 24,
+                              "end": 32
+                            }
+                          }
+                        ],
+                        "attributes": []
+                      }
+                    },
+                    "span": {
+                      "start": 10,
+                      "end": 34
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 10,
+                    "end": 34
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 35
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 36
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "MethodCall": {
+              "object": {
+                "kind": {
+                  "Variable": "obj"
+                },
+                "span": {
+                  "start": 37,
+                  "end": 41
+                }
+              },
+              "method": {
+                "kind": {
+                  "Identifier": "bar"
+                },
+                "span": {
+                  "start": 43,
+                  "end": 46
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Array": [
+                        {
+                          "key": null,
+                          "value": {
+                            "kind": {
+                              "Int": 1
+                            },
+                            "span": {
+                              "start": 48,
+                              "end": 49
+                            }
+                          },
+                          "unpack": false,
+                          "span": {
+                            "start": 48,
+                            "end": 49
+                          },
+                          "comma": {
+                            "start": 49,
+                            "end": 50
+                          }
+                        },
+                        {
+                          "key": null,
+                          "value": {
+                            "kind": {
+                              "Int": 2
+                            },
+                            "span": {
+                              "start": 51,
+                              "end": 52
+                            }
+                          },
+                          "unpack": false,
+                          "span": {
+                            "start": 51,
+                            "end": 52
+                          },
+                          "comma": {
+                            "start": 52,
+                            "end": 53
+                          }
+                        },
+                        {
+                          "key": null,
+                          "value": {
+                            "kind": "Error",
+                            "span": {
+                              "start": 57,
+                              "end": 58
+                            }
+                          },
+                          "unpack": true,
+                          "span": {
+                            "start": 54,
+                            "end": 58
+                          }
+                        }
+                      ]
+                    },
+                    "span": {
+                      "start": 47,
+                      "end": 58
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 47,
+                    "end": 58
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 37,
+            "end": 59
+          }
+        }
+      },
+      "span": {
+        "start": 37,
+        "end": 60
+      }
+    },
+    {
+      "kind": {
+        "Return": {
+          "kind": {
+            "Binary": {
+              "left": {
+                "kind": "Error",
+                "span": {
+                  "start": 68,
+                  "end": 69
+                }
+              },
+              "op": "Mul",
+              "right": {
+                "kind": "Error",
+                "span": {
+                  "start": 69,
+                  "end": 70
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 68,
+            "end": 70
+          }
+        }
+      },
+      "span": {
+        "start": 61,
+        "end": 70
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 70
+  }
+}
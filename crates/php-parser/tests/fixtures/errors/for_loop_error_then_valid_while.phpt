@@ -1,10 +1,10 @@
 ===source===
 <?php for (;;; ) {} while (true) { break; }
 ===errors===
-expected expression
-unclosed '')'' opened at Span { start: 10, end: 11 }
-expected expression
-expected expression
+expected expression, found ';'
+unclosed ')' opened at Span { start: 10, end: 11 }
+expected expression, found ')'
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -22,6 +22,18 @@ expected expression
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 11
+          },
+          "condition_span": {
+            "start": 12,
+            "end": 12
+          },
+          "update_span": {
+            "start": 13,
+            "end": 14
+          },
           "body": {
             "kind": "Nop",
             "span": {
@@ -1,7 +1,7 @@
 ===source===
 <?php function test() {
 ===errors===
-unclosed ''}'' opened at Span { start: 22, end: 23 }
+unclosed '}' opened at Span { start: 22, end: 23 }
 ===ast===
 {
   "stmts": [
@@ -3,7 +3,7 @@ min_php=8.4
 ===source===
 <?php function test(int $x { }
 ===errors===
-unclosed '')'' opened at Span { start: 19, end: 20 }
+unclosed ')' opened at Span { start: 19, end: 20 }
 ===ast===
 {
   "stmts": [
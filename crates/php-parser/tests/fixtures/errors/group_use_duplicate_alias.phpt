@@ -26,6 +26,10 @@ cannot import A\C as Foo because the name is already in use
               "span": {
                 "start": 13,
                 "end": 21
+              },
+              "comma": {
+                "start": 21,
+                "end": 22
               }
             },
             {
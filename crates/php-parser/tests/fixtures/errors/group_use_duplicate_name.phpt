@@ -26,6 +26,10 @@ cannot import A\Foo as Foo because the name is already in use
               "span": {
                 "start": 13,
                 "end": 16
+              },
+              "comma": {
+                "start": 16,
+                "end": 17
               }
             },
             {
@@ -2,7 +2,7 @@
 <?php if (true) { __halt_compiler(); }
 ===errors===
 __halt_compiler() can only be used at the outermost scope
-unclosed ''}'' opened at Span { start: 16, end: 17 }
+unclosed '}' opened at Span { start: 16, end: 17 }
 ===ast===
 {
   "stmts": [
@@ -23,7 +23,12 @@ unclosed ''}'' opened at Span { start: 16, end: 17 }
               "Block": [
                 {
                   "kind": {
-                    "HaltCompiler": " }"
+                    "HaltCompiler": {
+                      "span": {
+                        "start": 36,
+                        "end": 38
+                      }
+                    }
                   },
                   "span": {
                     "start": 18,
@@ -0,0 +1,67 @@
+===source===
+<?php
+$x = <<<END
+	    line
+	    END;
+===errors===
+Invalid indentation - tabs and spaces cannot be mixed
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "END",
+                    "raw": "\t    line",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "line",
+                          "span": {
+                            "start": 18,
+                            "end": 27
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 36
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 36
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 37
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 37
+  }
+}
@@ -26,6 +26,7 @@ empty index in string interpolation
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "$arr[]",
                     "parts": [
                       {
                         "Expr": {
@@ -4,7 +4,7 @@ $x = <<<END
   line with only 2 spaces
     END;
 ===errors===
-Invalid body indentation level
+Invalid body indentation level (expecting an indentation level of at least 4)
 ===ast===
 {
   "stmts": [
@@ -27,9 +27,16 @@ Invalid body indentation level
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "  line with only 2 spaces",
                     "parts": [
                       {
-                        "Literal": "  line with only 2 spaces"
+                        "Literal": {
+                          "value": "line with only 2 spaces",
+                          "span": {
+                            "start": 18,
+                            "end": 43
+                          }
+                        }
                       }
                     ]
                   }
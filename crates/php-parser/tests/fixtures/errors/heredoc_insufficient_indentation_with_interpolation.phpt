@@ -5,7 +5,7 @@ $x = <<<END
   hello $y
     END;
 ===errors===
-Invalid body indentation level
+Invalid body indentation level (expecting an indentation level of at least 4)
 ===ast===
 {
   "stmts": [
@@ -26,7 +26,10 @@ Invalid body indentation level
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "world"
+                  "String": {
+                    "value": "world",
+                    "raw": "world"
+                  }
                 },
                 "span": {
                   "start": 11,
@@ -65,9 +68,16 @@ Invalid body indentation level
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "  hello $y",
                     "parts": [
                       {
-                        "Literal": "  hello "
+                        "Literal": {
+                          "value": "hello ",
+                          "span": {
+                            "start": 34,
+                            "end": 40
+                          }
+                        }
                       },
                       {
                         "Expr": {
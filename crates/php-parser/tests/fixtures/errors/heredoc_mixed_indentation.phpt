@@ -4,7 +4,7 @@ $x = <<<END
 	    mixed line
     END;
 ===errors===
-Invalid body indentation level
+Invalid indentation - tabs and spaces cannot be mixed
 ===ast===
 {
   "stmts": [
@@ -27,9 +27,16 @@ Invalid body indentation level
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "\t    mixed line",
                     "parts": [
                       {
-                        "Literal": "\t    mixed line"
+                        "Literal": {
+                          "value": " mixed line",
+                          "span": {
+                            "start": 18,
+                            "end": 33
+                          }
+                        }
                       }
                     ]
                   }
@@ -0,0 +1,68 @@
+===source===
+<?php
+$x = <<<END
+    line
+	line
+    END;
+===errors===
+Invalid indentation - tabs and spaces cannot be mixed
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "END",
+                    "raw": "    line\n\tline",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "line\nline",
+                          "span": {
+                            "start": 18,
+                            "end": 32
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 40
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 40
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 41
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 41
+  }
+}
@@ -0,0 +1,68 @@
+===source===
+<?php
+$s = <<<EOT
+    ok
+    \u{}
+    EOT;
+===errors===
+Invalid UTF-8 codepoint escape sequence: empty code point
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "s"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "EOT",
+                    "raw": "    ok\n    \\u{}",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "ok\n",
+                          "span": {
+                            "start": 18,
+                            "end": 33
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 41
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 41
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 42
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 42
+  }
+}
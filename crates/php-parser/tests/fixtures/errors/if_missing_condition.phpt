@@ -2,9 +2,9 @@
 <?php if { }
 ===errors===
 expected '(', found '{'
-expected expression
-Array and string offset access syntax with curly braces is no longer supported
-unclosed '')'' opened at Span { start: 9, end: 10 }
+expected expression, found '{' while parsing if condition
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+unclosed ')' opened at Span { start: 9, end: 10 }
 expected statement
 ===ast===
 {
@@ -1,9 +1,9 @@
 ===source===
 <?php $x ?
 ===errors===
-expected expression
+expected expression, found end of file
 expected ':', found end of file
-expected expression
+expected expression, found end of file
 expected ';' after expression
 ===ast===
 {
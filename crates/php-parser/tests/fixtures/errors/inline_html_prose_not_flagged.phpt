@@ -0,0 +1,27 @@
+===description===
+A file without an open tag whose first line only reads like a `use` or
+`namespace` statement stays inline HTML without a warning.
+===source===
+use it wisely;
+namespace matters here.
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "use it wisely;\nnamespace matters here."
+        }
+      },
+      "span": {
+        "start": 0,
+        "end": 38
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 38
+  },
+  "ends_in_html": true
+}
@@ -1,7 +1,7 @@
 ===source===
 <?php interface Foo { const X = ; } class Baz {}
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
@@ -25,17 +25,22 @@ interface method cannot contain a body
                   "body": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "String": "body"
-                            },
-                            "span": {
-                              "start": 51,
-                              "end": 57
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "String": {
+                                  "value": "body",
+                                  "raw": "body"
+                                }
+                              },
+                              "span": {
+                                "start": 51,
+                                "end": 57
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 46,
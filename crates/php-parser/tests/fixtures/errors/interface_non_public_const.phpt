@@ -0,0 +1,105 @@
+===description===
+Interface constants must be public. PHP rejects `private` and `protected`
+constants with "Access type for interface constant I::A must be public".
+===source===
+<?php
+interface I {
+    protected const A = 1, B = 2;
+    const C = 3;
+}
+===errors===
+Access type for interface constant A must be public
+Access type for interface constant B must be public
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Interface": {
+          "name": "I",
+          "extends": [],
+          "members": [
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "A",
+                  "visibility": "Protected",
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 44,
+                      "end": 45
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 24,
+                "end": 53
+              }
+            },
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "B",
+                  "visibility": "Protected",
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 2
+                    },
+                    "span": {
+                      "start": 51,
+                      "end": 52
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 24,
+                "end": 53
+              }
+            },
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "C",
+                  "visibility": null,
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 3
+                    },
+                    "span": {
+                      "start": 68,
+                      "end": 69
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 58,
+                "end": 70
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 72
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 72
+  }
+}
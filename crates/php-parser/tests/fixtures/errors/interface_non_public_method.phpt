@@ -0,0 +1,97 @@
+===description===
+Interface methods must be public. PHP rejects `private` and `protected`
+methods with "Access type for interface method I::f() must be public".
+===source===
+<?php
+interface I {
+    private function f();
+    protected function g();
+    public function h();
+}
+===errors===
+Access type for interface method f() must be public
+Access type for interface method g() must be public
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Interface": {
+          "name": "I",
+          "extends": [],
+          "members": [
+            {
+              "kind": {
+                "Method": {
+                  "name": "f",
+                  "visibility": "Private",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 24,
+                "end": 45
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "g",
+                  "visibility": "Protected",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 50,
+                "end": 73
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "h",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [],
+                  "return_type": null,
+                  "body": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 78,
+                "end": 98
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 100
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 100
+  }
+}
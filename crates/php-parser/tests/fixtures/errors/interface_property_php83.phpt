@@ -0,0 +1,72 @@
+===config===
+min_php=8.3
+===description===
+Before PHP 8.4 interfaces cannot declare properties at all: "Interfaces
+may not include properties". From 8.4 only hooked properties are allowed
+(see interface_non_hooked_property.phpt).
+===source===
+<?php
+interface I {
+    public int $x;
+}
+===errors===
+Interfaces may not include properties
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Interface": {
+          "name": "I",
+          "extends": [],
+          "members": [
+            {
+              "kind": {
+                "Property": {
+                  "name": "x",
+                  "visibility": "Public",
+                  "set_visibility": null,
+                  "is_static": false,
+                  "is_readonly": false,
+                  "type_hint": {
+                    "kind": {
+                      "Named": {
+                        "parts": [
+                          "int"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 31,
+                          "end": 34
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 31,
+                      "end": 34
+                    }
+                  },
+                  "default": null,
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 24,
+                "end": 37
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 40
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 40
+  }
+}
@@ -3,7 +3,7 @@
 $a = 1 + * 2;
 $b = 3;
 ===errors===
-expected expression
+expected expression, found '*' while parsing assigned value
 ===ast===
 {
   "stmts": [
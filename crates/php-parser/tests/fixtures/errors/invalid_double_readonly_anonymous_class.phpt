@@ -14,12 +14,15 @@ expected class name, found '{'
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "readonly"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 18
+                "Name": {
+                  "parts": [
+                    "readonly"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 18
+                  }
                 }
               },
               "args": []
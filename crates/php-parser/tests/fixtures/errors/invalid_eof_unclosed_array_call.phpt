@@ -2,7 +2,7 @@
 <?php
 $x = array(1, 2,
 ===errors===
-expected expression
+expected expression, found end of file while parsing array element 3
 expected ')', found end of file
 expected ';' after expression
 ===ast===
@@ -41,6 +41,10 @@ expected ';' after expression
                       "span": {
                         "start": 17,
                         "end": 18
+                      },
+                      "comma": {
+                        "start": 18,
+                        "end": 19
                       }
                     },
                     {
@@ -58,6 +62,10 @@ expected ';' after expression
                       "span": {
                         "start": 20,
                         "end": 21
+                      },
+                      "comma": {
+                        "start": 21,
+                        "end": 22
                       }
                     },
                     {
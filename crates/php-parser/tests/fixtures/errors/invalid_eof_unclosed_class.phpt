@@ -4,7 +4,6 @@ class Foo {
     public function bar() {
 ===errors===
 expected '}', found end of file
-expected '}', found end of file
 ===ast===
 {
   "stmts": [
@@ -3,7 +3,7 @@
 if ($x > 1) {
     echo "hello";
 ===errors===
-unclosed ''}'' opened at Span { start: 18, end: 19 }
+unclosed '}' opened at Span { start: 18, end: 19 }
 ===ast===
 {
   "stmts": [
@@ -44,17 +44,22 @@ unclosed ''}'' opened at Span { start: 18, end: 19 }
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "hello"
-                        },
-                        "span": {
-                          "start": 29,
-                          "end": 36
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "hello",
+                              "raw": "hello"
+                            }
+                          },
+                          "span": {
+                            "start": 29,
+                            "end": 36
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 24,
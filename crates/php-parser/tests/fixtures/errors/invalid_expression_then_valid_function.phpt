@@ -1,7 +1,7 @@
 ===source===
 <?php $x = ; function foo() { return 42; }
 ===errors===
-expected expression
+expected expression, found ';' while parsing assigned value
 ===ast===
 {
   "stmts": [
@@ -35,17 +35,19 @@ expected 'as', found variable
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "item"
-                        },
-                        "span": {
-                          "start": 40,
-                          "end": 45
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "item"
+                          },
+                          "span": {
+                            "start": 40,
+                            "end": 45
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 35,
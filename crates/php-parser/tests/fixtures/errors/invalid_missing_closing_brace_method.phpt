@@ -11,7 +11,7 @@ class Foo {
     }
 }
 ===errors===
-expected expression
+expected expression, found 'public'
 expected '}', found end of file
 ===ast===
 {
@@ -6,7 +6,7 @@ function foo(int $a, $b {
     return $a + $b;
 }
 ===errors===
-unclosed '')'' opened at Span { start: 18, end: 19 }
+unclosed ')' opened at Span { start: 18, end: 19 }
 ===ast===
 {
   "stmts": [
@@ -46,6 +46,10 @@ unclosed '')'' opened at Span { start: 18, end: 19 }
               "span": {
                 "start": 19,
                 "end": 25
+              },
+              "comma": {
+                "start": 25,
+                "end": 26
               }
             },
             {
@@ -4,11 +4,11 @@ if ($x > 1 {
     echo "hello";
 }
 ===errors===
-expected expression
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+expected expression, found 'echo' while parsing if condition
 expected '}', found 'echo'
-Array and string offset access syntax with curly braces is no longer supported
-unclosed '')'' opened at Span { start: 9, end: 10 }
-expected expression
+unclosed ')' opened at Span { start: 9, end: 10 }
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -63,17 +63,22 @@ expected expression
           },
           "then_branch": {
             "kind": {
-              "Echo": [
-                {
-                  "kind": {
-                    "String": "hello"
-                  },
-                  "span": {
-                    "start": 28,
-                    "end": 35
+              "Echo": {
+                "exprs": [
+                  {
+                    "kind": {
+                      "String": {
+                        "value": "hello",
+                        "raw": "hello"
+                      }
+                    },
+                    "span": {
+                      "start": 28,
+                      "end": 35
+                    }
                   }
-                }
-              ]
+                ]
+              }
             },
             "span": {
               "start": 23,
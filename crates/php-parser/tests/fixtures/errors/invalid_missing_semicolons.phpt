@@ -11,17 +11,22 @@ expected ';' after expression
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "hello"
-            },
-            "span": {
-              "start": 11,
-              "end": 18
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "hello",
+                  "raw": "hello"
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 18
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -30,17 +35,22 @@ expected ';' after expression
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "world"
-            },
-            "span": {
-              "start": 24,
-              "end": 31
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "world",
+                  "raw": "world"
+                }
+              },
+              "span": {
+                "start": 24,
+                "end": 31
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 19,
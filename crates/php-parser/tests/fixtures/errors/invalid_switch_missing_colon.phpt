@@ -38,17 +38,22 @@ expected ';', found 'echo'
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "one"
-                        },
-                        "span": {
-                          "start": 44,
-                          "end": 49
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
+                          },
+                          "span": {
+                            "start": 44,
+                            "end": 49
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 39,
@@ -83,17 +88,22 @@ expected ';', found 'echo'
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "two"
-                        },
-                        "span": {
-                          "start": 91,
-                          "end": 96
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "two",
+                              "raw": "two"
+                            }
+                          },
+                          "span": {
+                            "start": 91,
+                            "end": 96
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 86,
@@ -40,6 +40,10 @@ expected ']', found ';'
                       "span": {
                         "start": 12,
                         "end": 13
+                      },
+                      "comma": {
+                        "start": 13,
+                        "end": 14
                       }
                     },
                     {
@@ -57,6 +61,10 @@ expected ']', found ';'
                       "span": {
                         "start": 15,
                         "end": 16
+                      },
+                      "comma": {
+                        "start": 16,
+                        "end": 17
                       }
                     },
                     {
@@ -7,8 +7,8 @@ catch (Exception $e) {
     bar();
 }
 ===errors===
-expected expression
 expected catch or finally clause, found end of file
+expected expression, found 'catch'
 ===ast===
 {
   "stmts": [
@@ -23,7 +23,16 @@ expected catch or finally clause, found end of file
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 16,
+                              "end": 19
+                            }
+                          }
                         },
                         "span": {
                           "start": 16,
@@ -1,10 +1,10 @@
 ===source===
 <?php list(...$x) = $arr;
 ===errors===
-expected expression
+expected expression, found '...'
 expected ')', found '...'
 expected ';' after expression
-expected expression
+expected expression, found '...'
 ===ast===
 {
   "stmts": [
@@ -12,23 +12,26 @@ expected expression
       "kind": {
         "Expression": {
           "kind": {
-            "Array": [
-              {
-                "key": null,
-                "value": {
-                  "kind": "Error",
+            "List": {
+              "elements": [
+                {
+                  "key": null,
+                  "value": {
+                    "kind": "Error",
+                    "span": {
+                      "start": 11,
+                      "end": 14
+                    }
+                  },
+                  "unpack": false,
                   "span": {
                     "start": 11,
                     "end": 14
                   }
-                },
-                "unpack": false,
-                "span": {
-                  "start": 11,
-                  "end": 14
                 }
-              }
-            ]
+              ],
+              "uses_list": true
+            }
           },
           "span": {
             "start": 6,
@@ -1,7 +1,7 @@
 ===source===
 <?php function f() { if () { return 1; } return 2; }
 ===errors===
-expected expression
+expected expression, found ')' while parsing if condition
 ===ast===
 {
   "stmts": [
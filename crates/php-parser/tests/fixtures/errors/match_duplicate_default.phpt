@@ -47,7 +47,10 @@ match expression may only contain one default arm
                         ],
                         "body": {
                           "kind": {
-                            "String": "one"
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
                           },
                           "span": {
                             "start": 29,
@@ -57,13 +60,20 @@ match expression may only contain one default arm
                         "span": {
                           "start": 24,
                           "end": 34
+                        },
+                        "comma": {
+                          "start": 34,
+                          "end": 35
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "first"
+                            "String": {
+                              "value": "first",
+                              "raw": "first"
+                            }
                           },
                           "span": {
                             "start": 47,
@@ -73,13 +83,20 @@ match expression may only contain one default arm
                         "span": {
                           "start": 36,
                           "end": 54
+                        },
+                        "comma": {
+                          "start": 54,
+                          "end": 55
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "second"
+                            "String": {
+                              "value": "second",
+                              "raw": "second"
+                            }
                           },
                           "span": {
                             "start": 67,
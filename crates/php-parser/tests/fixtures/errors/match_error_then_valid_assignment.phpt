@@ -1,7 +1,7 @@
 ===source===
 <?php $x = match ($y) { 1 => }; $z = 42;
 ===errors===
-expected expression
+expected expression, found '}' while parsing assigned value
 ===ast===
 {
   "stmts": [
@@ -4,8 +4,8 @@
 expected '}', found integer
 expected ';' after expression
 expected ';' after expression
-expected expression
-expected expression
+expected expression, found '=>'
+expected expression, found '}'
 ===ast===
 {
   "stmts": [
@@ -38,7 +38,10 @@ expected expression
                   ],
                   "body": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 23,
@@ -1,7 +1,7 @@
 ===source===
 <?php match($x) { 1 => }
 ===errors===
-expected expression
+expected expression, found '}'
 expected ';' after expression
 ===ast===
 {
@@ -1,21 +1,23 @@
 ===source===
 <?php echo ;
 ===errors===
-expected expression
+expected expression, found ';'
 ===ast===
 {
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": "Error",
-            "span": {
-              "start": 11,
-              "end": 12
+        "Echo": {
+          "exprs": [
+            {
+              "kind": "Error",
+              "span": {
+                "start": 11,
+                "end": 12
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
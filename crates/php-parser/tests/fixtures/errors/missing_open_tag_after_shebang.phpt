@@ -0,0 +1,30 @@
+===description===
+The `#!` line is skipped when looking for code at the start of a file
+without an open tag.
+===source===
+#!/usr/bin/env php
+declare(strict_types=1);
+echo 1;
+===errors===
+file starts with PHP code but has no '<?php' open tag
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "declare(strict_types=1);\necho 1;"
+        }
+      },
+      "span": {
+        "start": 19,
+        "end": 51
+      }
+    }
+  ],
+  "span": {
+    "start": 19,
+    "end": 51
+  },
+  "ends_in_html": true
+}
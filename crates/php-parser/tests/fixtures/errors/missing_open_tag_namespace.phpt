@@ -0,0 +1,27 @@
+===source===
+namespace App;
+
+class User {}
+===errors===
+file starts with PHP code but has no '<?php' open tag
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "namespace App;\n\nclass User {}"
+        }
+      },
+      "span": {
+        "start": 0,
+        "end": 29
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 29
+  },
+  "ends_in_html": true
+}
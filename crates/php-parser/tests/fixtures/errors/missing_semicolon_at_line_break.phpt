@@ -0,0 +1,148 @@
+===config===
+infer_semicolons=true
+===source===
+<?php
+$x = 1
+$y = 2;
+foo()
+return $x;
+===errors===
+missing ';' after expression
+missing ';' after expression
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Int": 1
+                },
+                "span": {
+                  "start": 11,
+                  "end": 12
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 12
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 12
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "y"
+                },
+                "span": {
+                  "start": 13,
+                  "end": 15
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Int": 2
+                },
+                "span": {
+                  "start": 18,
+                  "end": 19
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 13,
+            "end": 19
+          }
+        }
+      },
+      "span": {
+        "start": 13,
+        "end": 20
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 21,
+                      "end": 24
+                    }
+                  }
+                },
+                "span": {
+                  "start": 21,
+                  "end": 24
+                }
+              },
+              "args": []
+            }
+          },
+          "span": {
+            "start": 21,
+            "end": 26
+          }
+        }
+      },
+      "span": {
+        "start": 21,
+        "end": 26
+      }
+    },
+    {
+      "kind": {
+        "Return": {
+          "kind": {
+            "Variable": "x"
+          },
+          "span": {
+            "start": 34,
+            "end": 36
+          }
+        }
+      },
+      "span": {
+        "start": 27,
+        "end": 37
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 37
+  }
+}
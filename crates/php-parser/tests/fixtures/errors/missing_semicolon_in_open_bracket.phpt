@@ -0,0 +1,228 @@
+===config===
+infer_semicolons=true
+===source===
+<?php
+$x = [1,2
+$y = 3;
+foo(1
+$z = 4;
+===errors===
+expected ']', found variable
+expected ';' after expression
+expected ')', found variable
+expected ';' after expression
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "x"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Array": [
+                    {
+                      "key": null,
+                      "value": {
+                        "kind": {
+                          "Int": 1
+                        },
+                        "span": {
+                          "start": 12,
+                          "end": 13
+                        }
+                      },
+                      "unpack": false,
+                      "span": {
+                        "start": 12,
+                        "end": 13
+                      },
+                      "comma": {
+                        "start": 13,
+                        "end": 14
+                      }
+                    },
+                    {
+                      "key": null,
+                      "value": {
+                        "kind": {
+                          "Int": 2
+                        },
+                        "span": {
+                          "start": 14,
+                          "end": 15
+                        }
+                      },
+                      "unpack": false,
+                      "span": {
+                        "start": 14,
+                        "end": 15
+                      }
+                    }
+                  ]
+                },
+                "span": {
+                  "start": 11,
+                  "end": 15
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 15
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 15
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "y"
+                },
+                "span": {
+                  "start": 16,
+                  "end": 18
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Int": 3
+                },
+                "span": {
+                  "start": 21,
+                  "end": 22
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 16,
+            "end": 22
+          }
+        }
+      },
+      "span": {
+        "start": 16,
+        "end": 23
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 24,
+                      "end": 27
+                    }
+                  }
+                },
+                "span": {
+                  "start": 24,
+                  "end": 27
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 28,
+                      "end": 29
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 28,
+                    "end": 29
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 24,
+            "end": 29
+          }
+        }
+      },
+      "span": {
+        "start": 24,
+        "end": 29
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "z"
+                },
+                "span": {
+                  "start": 30,
+                  "end": 32
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Int": 4
+                },
+                "span": {
+                  "start": 35,
+                  "end": 36
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 30,
+            "end": 36
+          }
+        }
+      },
+      "span": {
+        "start": 30,
+        "end": 37
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 37
+  }
+}
@@ -0,0 +1,75 @@
+===source===
+<?php
+function retry() {}
+retyr $attempts;
+===errors===
+unexpected 'retyr', did you mean 'retry'?
+expected ';' after expression
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Function": {
+          "name": "retry",
+          "params": [],
+          "body": [],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 25
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "ConstFetch": {
+              "parts": [
+                "retyr"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 26,
+                "end": 31
+              }
+            }
+          },
+          "span": {
+            "start": 26,
+            "end": 31
+          }
+        }
+      },
+      "span": {
+        "start": 26,
+        "end": 31
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Variable": "attempts"
+          },
+          "span": {
+            "start": 32,
+            "end": 41
+          }
+        }
+      },
+      "span": {
+        "start": 32,
+        "end": 42
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 42
+  }
+}
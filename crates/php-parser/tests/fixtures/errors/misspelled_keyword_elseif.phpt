@@ -0,0 +1,176 @@
+===source===
+<?php
+if ($a) {
+    echo 1;
+} elsif ($b) {
+    echo 2;
+}
+===errors===
+unexpected 'elsif', did you mean 'elseif'?
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+expected expression, found 'echo'
+expected '}', found 'echo'
+expected ';' after expression
+expected expression, found '}'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "If": {
+          "condition": {
+            "kind": {
+              "Variable": "a"
+            },
+            "span": {
+              "start": 10,
+              "end": 12
+            }
+          },
+          "then_branch": {
+            "kind": {
+              "Block": [
+                {
+                  "kind": {
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 25,
+                            "end": 26
+                          }
+                        }
+                      ]
+                    }
+                  },
+                  "span": {
+                    "start": 20,
+                    "end": 27
+                  }
+                }
+              ]
+            },
+            "span": {
+              "start": 14,
+              "end": 29
+            }
+          },
+          "elseif_branches": [],
+          "else_branch": null
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 29
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "ArrayAccess": {
+              "array": {
+                "kind": {
+                  "FunctionCall": {
+                    "name": {
+                      "kind": {
+                        "Name": {
+                          "parts": [
+                            "elsif"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 30,
+                            "end": 35
+                          }
+                        }
+                      },
+                      "span": {
+                        "start": 30,
+                        "end": 35
+                      }
+                    },
+                    "args": [
+                      {
+                        "name": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 37,
+                            "end": 39
+                          }
+                        },
+                        "unpack": false,
+                        "by_ref": false,
+                        "span": {
+                          "start": 37,
+                          "end": 39
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 30,
+                  "end": 40
+                }
+              },
+              "index": {
+                "kind": "Error",
+                "span": {
+                  "start": 47,
+                  "end": 51
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 30,
+            "end": 42
+          }
+        }
+      },
+      "span": {
+        "start": 30,
+        "end": 42
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 2
+              },
+              "span": {
+                "start": 52,
+                "end": 53
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 47,
+        "end": 54
+      }
+    },
+    {
+      "kind": "Error",
+      "span": {
+        "start": 55,
+        "end": 54
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 54
+  }
+}
@@ -0,0 +1,149 @@
+===source===
+<?php
+funtion greet($name) {
+    echo $name;
+}
+===errors===
+unexpected 'funtion', did you mean 'function'?
+expected ';' after expression
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+expected expression, found 'echo'
+expected '}', found 'echo'
+expected ';' after expression
+expected expression, found '}'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "ConstFetch": {
+              "parts": [
+                "funtion"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 6,
+                "end": 13
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 13
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 13
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "ArrayAccess": {
+              "array": {
+                "kind": {
+                  "FunctionCall": {
+                    "name": {
+                      "kind": {
+                        "Name": {
+                          "parts": [
+                            "greet"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 14,
+                            "end": 19
+                          }
+                        }
+                      },
+                      "span": {
+                        "start": 14,
+                        "end": 19
+                      }
+                    },
+                    "args": [
+                      {
+                        "name": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 20,
+                            "end": 25
+                          }
+                        },
+                        "unpack": false,
+                        "by_ref": false,
+                        "span": {
+                          "start": 20,
+                          "end": 25
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 14,
+                  "end": 26
+                }
+              },
+              "index": {
+                "kind": "Error",
+                "span": {
+                  "start": 33,
+                  "end": 37
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 14,
+            "end": 28
+          }
+        }
+      },
+      "span": {
+        "start": 14,
+        "end": 28
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "name"
+              },
+              "span": {
+                "start": 38,
+                "end": 43
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 33,
+        "end": 44
+      }
+    },
+    {
+      "kind": "Error",
+      "span": {
+        "start": 45,
+        "end": 44
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 44
+  }
+}
@@ -0,0 +1,25 @@
+===source===
+<? php echo 1;
+===errors===
+open tag '<? php' should be written '<?php'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "<? php echo 1;"
+        }
+      },
+      "span": {
+        "start": 0,
+        "end": 14
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 14
+  },
+  "ends_in_html": true
+}
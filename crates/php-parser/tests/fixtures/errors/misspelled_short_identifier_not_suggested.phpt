@@ -0,0 +1,100 @@
+===description===
+A short identifier is not matched against keywords: `foo` is one edit from
+`for`, but no suggestion is made.
+===source===
+<?php
+foo($a $b);
+===errors===
+expected ')', found variable
+expected ';' after expression
+expected ';' after expression
+expected expression, found ')'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "FunctionCall": {
+              "name": {
+                "kind": {
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
+                },
+                "span": {
+                  "start": 6,
+                  "end": 9
+                }
+              },
+              "args": [
+                {
+                  "name": null,
+                  "value": {
+                    "kind": {
+                      "Variable": "a"
+                    },
+                    "span": {
+                      "start": 10,
+                      "end": 12
+                    }
+                  },
+                  "unpack": false,
+                  "by_ref": false,
+                  "span": {
+                    "start": 10,
+                    "end": 12
+                  }
+                }
+              ]
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 12
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 12
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Variable": "b"
+          },
+          "span": {
+            "start": 13,
+            "end": 15
+          }
+        }
+      },
+      "span": {
+        "start": 13,
+        "end": 15
+      }
+    },
+    {
+      "kind": "Error",
+      "span": {
+        "start": 15,
+        "end": 17
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 17
+  }
+}
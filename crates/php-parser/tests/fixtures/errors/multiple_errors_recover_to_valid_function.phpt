@@ -4,8 +4,8 @@ $a = ;
 $b = ;
 function healthy(): string { return 'ok'; }
 ===errors===
-expected expression
-expected expression
+expected expression, found ';' while parsing assigned value
+expected expression, found ';' while parsing assigned value
 ===ast===
 {
   "stmts": [
@@ -89,7 +89,10 @@ expected expression
               "kind": {
                 "Return": {
                   "kind": {
-                    "String": "ok"
+                    "String": {
+                      "value": "ok",
+                      "raw": "ok"
+                    }
                   },
                   "span": {
                     "start": 56,
@@ -12,7 +12,16 @@ cannot use positional argument after named argument
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "func"
+                  "Name": {
+                    "parts": [
+                      "func"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -31,6 +40,10 @@ cannot use positional argument after named argument
                       "end": 12
                     }
                   },
+                  "colon": {
+                    "start": 12,
+                    "end": 13
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -45,6 +58,10 @@ cannot use positional argument after named argument
                   "span": {
                     "start": 11,
                     "end": 15
+                  },
+                  "comma": {
+                    "start": 15,
+                    "end": 16
                   }
                 },
                 {
@@ -12,12 +12,15 @@ Cannot create Closure for new expression
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 13
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 13
+                  }
                 }
               },
               "args": []
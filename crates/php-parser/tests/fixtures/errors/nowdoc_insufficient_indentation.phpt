@@ -4,7 +4,7 @@ $x = <<<'END'
   line with only 2 spaces
     END;
 ===errors===
-Invalid body indentation level
+Invalid body indentation level (expecting an indentation level of at least 4)
 ===ast===
 {
   "stmts": [
@@ -27,7 +27,8 @@ Invalid body indentation level
                 "kind": {
                   "Nowdoc": {
                     "label": "END",
-                    "value": "  line with only 2 spaces"
+                    "raw": "  line with only 2 spaces",
+                    "value": "line with only 2 spaces"
                   }
                 },
                 "span": {
@@ -4,7 +4,7 @@ $x = <<<'END'
 	    mixed line
     END;
 ===errors===
-Invalid body indentation level
+Invalid indentation - tabs and spaces cannot be mixed
 ===ast===
 {
   "stmts": [
@@ -27,7 +27,8 @@ Invalid body indentation level
                 "kind": {
                   "Nowdoc": {
                     "label": "END",
-                    "value": "\t    mixed line"
+                    "raw": "\t    mixed line",
+                    "value": " mixed line"
                   }
                 },
                 "span": {
@@ -0,0 +1,35 @@
+===source===
+<?PHP
+echo 1;
+===errors===
+open tag '<?PHP' should be written '<?php'
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Int": 1
+              },
+              "span": {
+                "start": 11,
+                "end": 12
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 13
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 13
+  }
+}
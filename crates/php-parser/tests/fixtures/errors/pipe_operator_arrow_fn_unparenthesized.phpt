@@ -64,7 +64,16 @@ arrow function on the right side of pipe operator must be parenthesized
                               "FunctionCall": {
                                 "name": {
                                   "kind": {
-                                    "Identifier": "trim"
+                                    "Name": {
+                                      "parts": [
+                                        "trim"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 31,
+                                        "end": 35
+                                      }
+                                    }
                                   },
                                   "span": {
                                     "start": 31,
@@ -92,6 +92,10 @@ set hook must have exactly one parameter
                           "span": {
                             "start": 50,
                             "end": 56
+                          },
+                          "comma": {
+                            "start": 56,
+                            "end": 57
                           }
                         },
                         {
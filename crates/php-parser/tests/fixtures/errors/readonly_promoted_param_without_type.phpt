@@ -48,6 +48,10 @@ readonly promoted property must have type
                       "span": {
                         "start": 59,
                         "end": 77
+                      },
+                      "comma": {
+                        "start": 77,
+                        "end": 78
                       }
                     }
                   ],
@@ -0,0 +1,58 @@
+===description===
+An octal escape above `\377` keeps only its low byte and PHP emits a
+compile-time warning: "Octal escape sequence overflow \400 is greater than
+\377". The parser surfaces it as a ForbiddenWarning diagnostic; `php -l`
+still exits 0 so there is no ===php_error=== section.
+===source===
+<?php $s = "\400\101";
+===errors===
+Octal escape sequence overflow \400 is greater than \377
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "s"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "String": {
+                    "value": "\u0000A",
+                    "raw": "\\400\\101"
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 21
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 21
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 22
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 22
+  }
+}
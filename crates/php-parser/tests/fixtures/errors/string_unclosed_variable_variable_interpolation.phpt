@@ -26,7 +26,13 @@ expected ';' after expression
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "text "
+                      "Literal": {
+                        "value": "text ",
+                        "span": {
+                          "start": 12,
+                          "end": 17
+                        }
+                      }
                     },
                     {
                       "Expr": {
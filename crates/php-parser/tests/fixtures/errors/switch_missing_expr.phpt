@@ -2,9 +2,9 @@
 <?php switch { }
 ===errors===
 expected '(', found '{'
-expected expression
-Array and string offset access syntax with curly braces is no longer supported
-unclosed '')'' opened at Span { start: 13, end: 14 }
+expected expression, found '{' while parsing switch condition
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+unclosed ')' opened at Span { start: 13, end: 14 }
 expected '{', found end of file
 expected '}', found end of file
 ===ast===
@@ -1,7 +1,7 @@
 ===source===
 <?php switch () { case 1: break; } if (true) { echo 'ok'; }
 ===errors===
-expected expression
+expected expression, found ')' while parsing switch condition
 ===ast===
 {
   "stmts": [
@@ -67,17 +67,22 @@ expected expression
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "ok"
-                        },
-                        "span": {
-                          "start": 52,
-                          "end": 56
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "ok",
+                              "raw": "ok"
+                            }
+                          },
+                          "span": {
+                            "start": 52,
+                            "end": 56
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 47,
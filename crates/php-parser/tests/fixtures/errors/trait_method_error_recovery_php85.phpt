@@ -82,17 +82,19 @@ expected class member, found '@'
                   "body": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "Variable": "msg"
-                            },
-                            "span": {
-                              "start": 76,
-                              "end": 80
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "Variable": "msg"
+                              },
+                              "span": {
+                                "start": 76,
+                                "end": 80
+                              }
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 71,
@@ -173,37 +175,42 @@ expected class member, found '@'
                   "body": [
                     {
                       "kind": {
-                        "Echo": [
-                          {
-                            "kind": {
-                              "Binary": {
-                                "left": {
-                                  "kind": {
-                                    "String": "ERROR: "
+                        "Echo": {
+                          "exprs": [
+                            {
+                              "kind": {
+                                "Binary": {
+                                  "left": {
+                                    "kind": {
+                                      "String": {
+                                        "value": "ERROR: ",
+                                        "raw": "ERROR: "
+                                      }
+                                    },
+                                    "span": {
+                                      "start": 149,
+                                      "end": 158
+                                    }
                                   },
-                                  "span": {
-                                    "start": 149,
-                                    "end": 158
-                                  }
-                                },
-                                "op": "Concat",
-                                "right": {
-                                  "kind": {
-                                    "Variable": "msg"
-                                  },
-                                  "span": {
-                                    "start": 161,
-                                    "end": 165
+                                  "op": "Concat",
+                                  "right": {
+                                    "kind": {
+                                      "Variable": "msg"
+                                    },
+                                    "span": {
+                                      "start": 161,
+                                      "end": 165
+                                    }
                                   }
                                 }
+                              },
+                              "span": {
+                                "start": 149,
+                                "end": 165
                               }
-                            },
-                            "span": {
-                              "start": 149,
-                              "end": 165
                             }
-                          }
-                        ]
+                          ]
+                        }
                       },
                       "span": {
                         "start": 144,
@@ -58,17 +58,22 @@ expected catch or finally clause, found 'echo'
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "after"
-            },
-            "span": {
-              "start": 27,
-              "end": 34
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "after",
+                  "raw": "after"
+                }
+              },
+              "span": {
+                "start": 27,
+                "end": 34
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 22,
@@ -1,7 +1,7 @@
 ===source===
 <?php if (true) { $x = 1;
 ===errors===
-unclosed ''}'' opened at Span { start: 16, end: 17 }
+unclosed '}' opened at Span { start: 16, end: 17 }
 ===ast===
 {
   "stmts": [
@@ -39,6 +39,10 @@ expected ';' after expression
                       "span": {
                         "start": 12,
                         "end": 13
+                      },
+                      "comma": {
+                        "start": 13,
+                        "end": 14
                       }
                     },
                     {
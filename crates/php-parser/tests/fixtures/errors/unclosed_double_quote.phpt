@@ -10,7 +10,10 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "String": "unclosed string"
+            "String": {
+              "value": "unclosed string",
+              "raw": "unclosed string"
+            }
           },
           "span": {
             "start": 6,
@@ -2,8 +2,8 @@
 <?php <<<EOT
 Content
 ===errors===
-expected expression
-expected expression
+expected expression, found '<<'
+expected expression, found '<'
 expected ';' after expression
 expected ';' after expression
 ===ast===
@@ -42,7 +42,16 @@ expected ';' after expression
               "op": "Less",
               "right": {
                 "kind": {
-                  "Identifier": "EOT"
+                  "ConstFetch": {
+                    "parts": [
+                      "EOT"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 9,
+                      "end": 12
+                    }
+                  }
                 },
                 "span": {
                   "start": 9,
@@ -66,7 +75,16 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "Content"
+            "ConstFetch": {
+              "parts": [
+                "Content"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 13,
+                "end": 20
+              }
+            }
           },
           "span": {
             "start": 13,
@@ -13,7 +13,16 @@ expected ';' after expression
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -37,6 +46,10 @@ expected ';' after expression
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
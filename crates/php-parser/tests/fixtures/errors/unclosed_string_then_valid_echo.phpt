@@ -2,8 +2,8 @@
 <?php $x = 'unterminated
  echo 'hello';
 ===errors===
-unterminated string literal
 expected ';' after expression
+unterminated string literal
 expected ';' after expression
 expected ';' after expression
 ===ast===
@@ -26,7 +26,10 @@ expected ';' after expression
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "unterminated\n echo "
+                  "String": {
+                    "value": "unterminated\n echo ",
+                    "raw": "unterminated\n echo "
+                  }
                 },
                 "span": {
                   "start": 11,
@@ -50,7 +53,16 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "hello"
+            "ConstFetch": {
+              "parts": [
+                "hello"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 32,
+                "end": 37
+              }
+            }
           },
           "span": {
             "start": 32,
@@ -67,7 +79,10 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "String": ";"
+            "String": {
+              "value": ";",
+              "raw": ";"
+            }
           },
           "span": {
             "start": 37,
@@ -25,7 +25,13 @@ expected ';' after expression
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": "unclosed;"
+                      "Literal": {
+                        "value": "unclosed;",
+                        "span": {
+                          "start": 12,
+                          "end": 21
+                        }
+                      }
                     }
                   ]
                 },
@@ -2,8 +2,8 @@
 <?php $x = <<<EOT
 hello
 ===errors===
-expected expression
-expected expression
+expected expression, found '<<' while parsing assigned value
+expected expression, found '<' while parsing assigned value
 expected ';' after expression
 expected ';' after expression
 ===ast===
@@ -55,7 +55,16 @@ expected ';' after expression
                     "op": "Less",
                     "right": {
                       "kind": {
-                        "Identifier": "EOT"
+                        "ConstFetch": {
+                          "parts": [
+                            "EOT"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 14,
+                            "end": 17
+                          }
+                        }
                       },
                       "span": {
                         "start": 14,
@@ -86,7 +95,16 @@ expected ';' after expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "hello"
+            "ConstFetch": {
+              "parts": [
+                "hello"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 18,
+                "end": 23
+              }
+            }
           },
           "span": {
             "start": 18,
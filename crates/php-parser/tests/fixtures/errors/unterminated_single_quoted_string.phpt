@@ -23,7 +23,10 @@ expected ';' after expression
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "unclosed;"
+                  "String": {
+                    "value": "unclosed;",
+                    "raw": "unclosed;"
+                  }
                 },
                 "span": {
                   "start": 11,
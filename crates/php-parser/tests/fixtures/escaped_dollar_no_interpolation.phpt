@@ -5,17 +5,22 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "Price: $100"
-            },
-            "span": {
-              "start": 11,
-              "end": 25
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "Price: $100",
+                  "raw": "Price: \\$100"
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 25
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
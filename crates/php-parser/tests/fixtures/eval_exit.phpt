@@ -9,7 +9,10 @@
           "kind": {
             "Eval": {
               "kind": {
-                "String": "echo 1;"
+                "String": {
+                  "value": "echo 1;",
+                  "raw": "echo 1;"
+                }
               },
               "span": {
                 "start": 11,
@@ -76,7 +79,10 @@
           "kind": {
             "Exit": {
               "kind": {
-                "String": "fatal"
+                "String": {
+                  "value": "fatal",
+                  "raw": "fatal"
+                }
               },
               "span": {
                 "start": 42,
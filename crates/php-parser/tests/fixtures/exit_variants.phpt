@@ -102,7 +102,10 @@
           "kind": {
             "Exit": {
               "kind": {
-                "String": "error"
+                "String": {
+                  "value": "error",
+                  "raw": "error"
+                }
               },
               "span": {
                 "start": 45,
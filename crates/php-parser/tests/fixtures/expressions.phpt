@@ -308,7 +308,10 @@ $q = $a ?: 'fallback';
                     },
                     "then_expr": {
                       "kind": {
-                        "String": "positive"
+                        "String": {
+                          "value": "positive",
+                          "raw": "positive"
+                        }
                       },
                       "span": {
                         "start": 72,
@@ -317,7 +320,10 @@ $q = $a ?: 'fallback';
                     },
                     "else_expr": {
                       "kind": {
-                        "String": "non-positive"
+                        "String": {
+                          "value": "non-positive",
+                          "raw": "non-positive"
+                        }
                       },
                       "span": {
                         "start": 85,
@@ -373,7 +379,10 @@ $q = $a ?: 'fallback';
                     },
                     "right": {
                       "kind": {
-                        "String": "default"
+                        "String": {
+                          "value": "default",
+                          "raw": "default"
+                        }
                       },
                       "span": {
                         "start": 112,
@@ -672,7 +681,10 @@ $q = $a ?: 'fallback';
                         "Binary": {
                           "left": {
                             "kind": {
-                              "String": "hello"
+                              "String": {
+                                "value": "hello",
+                                "raw": "hello"
+                              }
                             },
                             "span": {
                               "start": 189,
@@ -682,7 +694,10 @@ $q = $a ?: 'fallback';
                           "op": "Concat",
                           "right": {
                             "kind": {
-                              "String": " "
+                              "String": {
+                                "value": " ",
+                                "raw": " "
+                              }
                             },
                             "span": {
                               "start": 199,
@@ -699,7 +714,10 @@ $q = $a ?: 'fallback';
                     "op": "Concat",
                     "right": {
                       "kind": {
-                        "String": "world"
+                        "String": {
+                          "value": "world",
+                          "raw": "world"
+                        }
                       },
                       "span": {
                         "start": 205,
@@ -1141,7 +1159,10 @@ $q = $a ?: 'fallback';
                     "then_expr": null,
                     "else_expr": {
                       "kind": {
-                        "String": "fallback"
+                        "String": {
+                          "value": "fallback",
+                          "raw": "fallback"
+                        }
                       },
                       "span": {
                         "start": 322,
@@ -64,6 +64,10 @@ final readonly class Money {
                       "span": {
                         "start": 76,
                         "end": 94
+                      },
+                      "comma": {
+                        "start": 94,
+                        "end": 95
                       }
                     },
                     {
@@ -97,6 +101,10 @@ final readonly class Money {
                       "span": {
                         "start": 104,
                         "end": 127
+                      },
+                      "comma": {
+                        "start": 127,
+                        "end": 128
                       }
                     }
                   ],
@@ -64,6 +64,10 @@ final readonly class Coordinate {
                       "span": {
                         "start": 81,
                         "end": 98
+                      },
+                      "comma": {
+                        "start": 98,
+                        "end": 99
                       }
                     },
                     {
@@ -97,6 +101,10 @@ final readonly class Coordinate {
                       "span": {
                         "start": 108,
                         "end": 125
+                      },
+                      "comma": {
+                        "start": 125,
+                        "end": 126
                       }
                     }
                   ],
@@ -27,7 +27,16 @@ $fn = Foo::bar(...);
                     "kind": {
                       "Function": {
                         "kind": {
-                          "Identifier": "strlen"
+                          "Name": {
+                            "parts": [
+                              "strlen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 18
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -136,12 +145,15 @@ $fn = Foo::bar(...);
                     "kind": {
                       "StaticMethod": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 56,
-                            "end": 59
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 56,
+                              "end": 59
+                            }
                           }
                         },
                         "method": {
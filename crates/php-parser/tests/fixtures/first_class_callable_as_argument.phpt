@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "array_map"
+                  "Name": {
+                    "parts": [
+                      "array_map"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 15
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -26,7 +35,16 @@
                         "kind": {
                           "Function": {
                             "kind": {
-                              "Identifier": "strlen"
+                              "Name": {
+                                "parts": [
+                                  "strlen"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 16,
+                                  "end": 22
+                                }
+                              }
                             },
                             "span": {
                               "start": 16,
@@ -46,6 +64,10 @@
                   "span": {
                     "start": 16,
                     "end": 27
+                  },
+                  "comma": {
+                    "start": 27,
+                    "end": 28
                   }
                 },
                 {
@@ -55,7 +77,16 @@
                       "FunctionCall": {
                         "name": {
                           "kind": {
-                            "Identifier": "array_filter"
+                            "Name": {
+                              "parts": [
+                                "array_filter"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 29,
+                                "end": 41
+                              }
+                            }
                           },
                           "span": {
                             "start": 29,
@@ -79,6 +110,10 @@
                             "span": {
                               "start": 42,
                               "end": 46
+                            },
+                            "comma": {
+                              "start": 46,
+                              "end": 47
                             }
                           },
                           {
@@ -89,7 +124,16 @@
                                   "kind": {
                                     "Function": {
                                       "kind": {
-                                        "Identifier": "is_string"
+                                        "Name": {
+                                          "parts": [
+                                            "is_string"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 48,
+                                            "end": 57
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 48,
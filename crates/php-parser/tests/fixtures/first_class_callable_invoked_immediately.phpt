@@ -29,7 +29,16 @@
                               "kind": {
                                 "Function": {
                                   "kind": {
-                                    "Identifier": "strlen"
+                                    "Name": {
+                                      "parts": [
+                                        "strlen"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 12,
+                                        "end": 18
+                                      }
+                                    }
                                   },
                                   "span": {
                                     "start": 12,
@@ -55,7 +64,10 @@
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "hello"
+                            "String": {
+                              "value": "hello",
+                              "raw": "hello"
+                            }
                           },
                           "span": {
                             "start": 25,
@@ -28,7 +28,16 @@ $e = $obj->$dynamic(...);
                     "kind": {
                       "Function": {
                         "kind": {
-                          "Identifier": "strlen"
+                          "Name": {
+                            "parts": [
+                              "strlen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 11,
+                              "end": 17
+                            }
+                          }
                         },
                         "span": {
                           "start": 11,
@@ -137,12 +146,15 @@ $e = $obj->$dynamic(...);
                     "kind": {
                       "StaticMethod": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 53,
-                            "end": 56
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 53,
+                              "end": 56
+                            }
                           }
                         },
                         "method": {
@@ -9,6 +9,18 @@
           "init": [],
           "condition": [],
           "update": [],
+          "init_span": {
+            "start": 11,
+            "end": 11
+          },
+          "condition_span": {
+            "start": 12,
+            "end": 12
+          },
+          "update_span": {
+            "start": 13,
+            "end": 13
+          },
           "body": {
             "kind": {
               "Block": [
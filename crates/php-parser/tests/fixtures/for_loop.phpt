@@ -90,22 +90,36 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 17
+          },
+          "condition_span": {
+            "start": 19,
+            "end": 26
+          },
+          "update_span": {
+            "start": 28,
+            "end": 32
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 41,
-                          "end": 43
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 41,
+                            "end": 43
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 36,
@@ -139,22 +139,36 @@
               }
             }
           ],
+          "init_span": {
+            "start": 11,
+            "end": 26
+          },
+          "condition_span": {
+            "start": 28,
+            "end": 35
+          },
+          "update_span": {
+            "start": 37,
+            "end": 47
+          },
           "body": {
             "kind": {
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "i"
-                        },
-                        "span": {
-                          "start": 56,
-                          "end": 58
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "i"
+                          },
+                          "span": {
+                            "start": 56,
+                            "end": 58
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 51,
@@ -38,17 +38,19 @@
               "Block": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "k"
-                        },
-                        "span": {
-                          "start": 42,
-                          "end": 44
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "k"
+                          },
+                          "span": {
+                            "start": 42,
+                            "end": 44
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 37,
@@ -25,12 +25,15 @@ $x = \strlen('hello');
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "\\Exception"
-                      },
-                      "span": {
-                        "start": 15,
-                        "end": 25
+                      "Name": {
+                        "parts": [
+                          "Exception"
+                        ],
+                        "kind": "FullyQualified",
+                        "span": {
+                          "start": 15,
+                          "end": 25
+                        }
                       }
                     },
                     "args": [
@@ -38,7 +41,10 @@ $x = \strlen('hello');
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "error"
+                            "String": {
+                              "value": "error",
+                              "raw": "error"
+                            }
                           },
                           "span": {
                             "start": 26,
@@ -79,12 +85,17 @@ $x = \strlen('hello');
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "\\App\\Services\\Logger"
-                },
-                "span": {
-                  "start": 36,
-                  "end": 56
+                "Name": {
+                  "parts": [
+                    "App",
+                    "Services",
+                    "Logger"
+                  ],
+                  "kind": "FullyQualified",
+                  "span": {
+                    "start": 36,
+                    "end": 56
+                  }
                 }
               },
               "method": {
@@ -101,7 +112,10 @@ $x = \strlen('hello');
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "msg"
+                      "String": {
+                        "value": "msg",
+                        "raw": "msg"
+                      }
                     },
                     "span": {
                       "start": 62,
@@ -149,7 +163,16 @@ $x = \strlen('hello');
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "\\strlen"
+                        "Name": {
+                          "parts": [
+                            "strlen"
+                          ],
+                          "kind": "FullyQualified",
+                          "span": {
+                            "start": 75,
+                            "end": 82
+                          }
+                        }
                       },
                       "span": {
                         "start": 75,
@@ -161,7 +184,10 @@ $x = \strlen('hello');
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "hello"
+                            "String": {
+                              "value": "hello",
+                              "raw": "hello"
+                            }
                           },
                           "span": {
                             "start": 83,
@@ -50,6 +50,10 @@ function process(?int $x, int|string $y, Countable&Traversable $z): ?string {
               "span": {
                 "start": 23,
                 "end": 30
+              },
+              "comma": {
+                "start": 30,
+                "end": 31
               }
             },
             {
@@ -111,6 +115,10 @@ function process(?int $x, int|string $y, Countable&Traversable $z): ?string {
               "span": {
                 "start": 32,
                 "end": 45
+              },
+              "comma": {
+                "start": 45,
+                "end": 46
               }
             },
             {
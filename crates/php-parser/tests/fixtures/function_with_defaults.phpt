@@ -22,6 +22,10 @@
               "span": {
                 "start": 19,
                 "end": 21
+              },
+              "comma": {
+                "start": 21,
+                "end": 22
               }
             },
             {
@@ -46,6 +50,10 @@
               "span": {
                 "start": 23,
                 "end": 30
+              },
+              "comma": {
+                "start": 30,
+                "end": 31
               }
             },
             {
@@ -53,7 +61,10 @@
               "type_hint": null,
               "default": {
                 "kind": {
-                  "String": "x"
+                  "String": {
+                    "value": "x",
+                    "raw": "x"
+                  }
                 },
                 "span": {
                   "start": 37,
@@ -33,6 +33,10 @@ greet('PHP');
               "span": {
                 "start": 19,
                 "end": 21
+              },
+              "comma": {
+                "start": 21,
+                "end": 22
               }
             },
             {
@@ -111,7 +115,10 @@ greet('PHP');
               "type_hint": null,
               "default": {
                 "kind": {
-                  "String": "World"
+                  "String": {
+                    "value": "World",
+                    "raw": "World"
+                  }
                 },
                 "span": {
                   "start": 75,
@@ -134,37 +141,42 @@ greet('PHP');
           "body": [
             {
               "kind": {
-                "Echo": [
-                  {
-                    "kind": {
-                      "Binary": {
-                        "left": {
-                          "kind": {
-                            "String": "Hello, "
-                          },
-                          "span": {
-                            "start": 95,
-                            "end": 104
-                          }
-                        },
-                        "op": "Concat",
-                        "right": {
-                          "kind": {
-                            "Variable": "name"
+                "Echo": {
+                  "exprs": [
+                    {
+                      "kind": {
+                        "Binary": {
+                          "left": {
+                            "kind": {
+                              "String": {
+                                "value": "Hello, ",
+                                "raw": "Hello, "
+                              }
+                            },
+                            "span": {
+                              "start": 95,
+                              "end": 104
+                            }
                           },
-                          "span": {
-                            "start": 107,
-                            "end": 112
+                          "op": "Concat",
+                          "right": {
+                            "kind": {
+                              "Variable": "name"
+                            },
+                            "span": {
+                              "start": 107,
+                              "end": 112
+                            }
                           }
                         }
+                      },
+                      "span": {
+                        "start": 95,
+                        "end": 112
                       }
-                    },
-                    "span": {
-                      "start": 95,
-                      "end": 112
                     }
-                  }
-                ]
+                  ]
+                }
               },
               "span": {
                 "start": 90,
@@ -202,7 +214,16 @@ greet('PHP');
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "add"
+                        "Name": {
+                          "parts": [
+                            "add"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 127,
+                            "end": 130
+                          }
+                        }
                       },
                       "span": {
                         "start": 127,
@@ -226,6 +247,10 @@ greet('PHP');
                         "span": {
                           "start": 131,
                           "end": 132
+                        },
+                        "comma": {
+                          "start": 132,
+                          "end": 133
                         }
                       },
                       {
@@ -274,7 +299,16 @@ greet('PHP');
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "greet"
+                  "Name": {
+                    "parts": [
+                      "greet"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 138,
+                      "end": 143
+                    }
+                  }
                 },
                 "span": {
                   "start": 138,
@@ -302,7 +336,16 @@ greet('PHP');
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "greet"
+                  "Name": {
+                    "parts": [
+                      "greet"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 147,
+                      "end": 152
+                    }
+                  }
                 },
                 "span": {
                   "start": 147,
@@ -314,7 +357,10 @@ greet('PHP');
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "PHP"
+                      "String": {
+                        "value": "PHP",
+                        "raw": "PHP"
+                      }
                     },
                     "span": {
                       "start": 153,
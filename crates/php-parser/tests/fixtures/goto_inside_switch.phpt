@@ -63,17 +63,22 @@ echo 'done';
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "two"
-                        },
-                        "span": {
-                          "start": 76,
-                          "end": 81
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "two",
+                              "raw": "two"
+                            }
+                          },
+                          "span": {
+                            "start": 76,
+                            "end": 81
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 71,
@@ -105,17 +110,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "done"
-            },
-            "span": {
-              "start": 96,
-              "end": 102
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "done",
+                  "raw": "done"
+                }
+              },
+              "span": {
+                "start": 96,
+                "end": 102
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 91,
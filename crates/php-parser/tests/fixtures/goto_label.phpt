@@ -18,17 +18,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "this is skipped"
-            },
-            "span": {
-              "start": 21,
-              "end": 38
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "this is skipped",
+                  "raw": "this is skipped"
+                }
+              },
+              "span": {
+                "start": 21,
+                "end": 38
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 16,
@@ -46,17 +51,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "done"
-            },
-            "span": {
-              "start": 50,
-              "end": 56
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "done",
+                  "raw": "done"
+                }
+              },
+              "span": {
+                "start": 50,
+                "end": 56
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 45,
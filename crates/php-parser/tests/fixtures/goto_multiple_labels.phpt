@@ -83,17 +83,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "A"
-            },
-            "span": {
-              "start": 61,
-              "end": 64
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "A",
+                  "raw": "A"
+                }
+              },
+              "span": {
+                "start": 61,
+                "end": 64
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 56,
@@ -120,17 +125,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "B"
-            },
-            "span": {
-              "start": 89,
-              "end": 92
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "B",
+                  "raw": "B"
+                }
+              },
+              "span": {
+                "start": 89,
+                "end": 92
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 84,
@@ -148,17 +158,22 @@ echo 'done';
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "done"
-            },
-            "span": {
-              "start": 104,
-              "end": 110
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "done",
+                  "raw": "done"
+                }
+              },
+              "span": {
+                "start": 104,
+                "end": 110
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 99,
@@ -29,6 +29,10 @@ use App\{Models\User as U, Services\Auth as A};
               "span": {
                 "start": 15,
                 "end": 26
+              },
+              "comma": {
+                "start": 26,
+                "end": 27
               }
             },
             {
@@ -80,6 +84,10 @@ use App\{Models\User as U, Services\Auth as A};
               "span": {
                 "start": 70,
                 "end": 76
+              },
+              "comma": {
+                "start": 76,
+                "end": 77
               }
             },
             {
@@ -131,6 +139,10 @@ use App\{Models\User as U, Services\Auth as A};
               "span": {
                 "start": 111,
                 "end": 118
+              },
+              "comma": {
+                "start": 118,
+                "end": 119
               }
             },
             {
@@ -182,6 +194,10 @@ use App\{Models\User as U, Services\Auth as A};
               "span": {
                 "start": 139,
                 "end": 155
+              },
+              "comma": {
+                "start": 155,
+                "end": 156
               }
             },
             {
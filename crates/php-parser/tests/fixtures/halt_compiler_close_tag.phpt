@@ -5,7 +5,12 @@
   "stmts": [
     {
       "kind": {
-        "HaltCompiler": " raw data"
+        "HaltCompiler": {
+          "span": {
+            "start": 26,
+            "end": 35
+          }
+        }
       },
       "span": {
         "start": 6,
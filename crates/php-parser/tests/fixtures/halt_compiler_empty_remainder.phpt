@@ -5,7 +5,12 @@
   "stmts": [
     {
       "kind": {
-        "HaltCompiler": ""
+        "HaltCompiler": {
+          "span": {
+            "start": 24,
+            "end": 24
+          }
+        }
       },
       "span": {
         "start": 6,
@@ -5,7 +5,12 @@
   "stmts": [
     {
       "kind": {
-        "HaltCompiler": " x"
+        "HaltCompiler": {
+          "span": {
+            "start": 26,
+            "end": 28
+          }
+        }
       },
       "span": {
         "start": 6,
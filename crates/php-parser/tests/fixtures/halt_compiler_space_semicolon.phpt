@@ -5,7 +5,12 @@
   "stmts": [
     {
       "kind": {
-        "HaltCompiler": " x"
+        "HaltCompiler": {
+          "span": {
+            "start": 24,
+            "end": 26
+          }
+        }
       },
       "span": {
         "start": 6,
@@ -5,17 +5,22 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "before"
-            },
-            "span": {
-              "start": 11,
-              "end": 19
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "before",
+                  "raw": "before"
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 19
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -24,7 +29,12 @@
     },
     {
       "kind": {
-        "HaltCompiler": " raw data here"
+        "HaltCompiler": {
+          "span": {
+            "start": 39,
+            "end": 53
+          }
+        }
       },
       "span": {
         "start": 21,
@@ -7,7 +7,10 @@
   "stmts": [
     {
       "kind": {
-        "InlineHtml": "\n<span>html</span>\n"
+        "InlineHtml": {
+          "text": "\n<span>html</span>\n",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 18,
@@ -19,6 +19,7 @@ EOT
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "hello",
                     "value": "hello"
                   }
                 },
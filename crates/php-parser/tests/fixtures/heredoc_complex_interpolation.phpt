@@ -26,9 +26,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello {$obj->getName()}\nItem: {$arr[0]['key']}",
                     "parts": [
                       {
-                        "Literal": "Hello "
+                        "Literal": {
+                          "value": "Hello ",
+                          "span": {
+                            "start": 18,
+                            "end": 24
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -62,7 +69,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": "\nItem: "
+                        "Literal": {
+                          "value": "\nItem: ",
+                          "span": {
+                            "start": 41,
+                            "end": 48
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -98,7 +111,10 @@ EOT;
                               },
                               "index": {
                                 "kind": {
-                                  "String": "key"
+                                  "String": {
+                                    "value": "key",
+                                    "raw": "key"
+                                  }
                                 },
                                 "span": {
                                   "start": 57,
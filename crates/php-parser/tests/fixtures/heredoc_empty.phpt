@@ -24,9 +24,16 @@ END;
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "",
                     "parts": [
                       {
-                        "Literal": ""
+                        "Literal": {
+                          "value": "",
+                          "span": {
+                            "start": 18,
+                            "end": 18
+                          }
+                        }
                       }
                     ]
                   }
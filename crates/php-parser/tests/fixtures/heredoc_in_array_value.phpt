@@ -29,7 +29,10 @@ $arr = [
                     {
                       "key": {
                         "kind": {
-                          "String": "key"
+                          "String": {
+                            "value": "key",
+                            "raw": "key"
+                          }
                         },
                         "span": {
                           "start": 19,
@@ -40,9 +43,16 @@ $arr = [
                         "kind": {
                           "Heredoc": {
                             "label": "EOT",
+                            "raw": "    value",
                             "parts": [
                               {
-                                "Literal": "value"
+                                "Literal": {
+                                  "value": "value",
+                                  "span": {
+                                    "start": 35,
+                                    "end": 44
+                                  }
+                                }
                               }
                             ]
                           }
@@ -56,6 +66,10 @@ $arr = [
                       "span": {
                         "start": 19,
                         "end": 52
+                      },
+                      "comma": {
+                        "start": 52,
+                        "end": 53
                       }
                     }
                   ]
@@ -25,9 +25,16 @@ $x = <<<END
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "    content line",
                     "parts": [
                       {
-                        "Literal": "content line"
+                        "Literal": {
+                          "value": "content line",
+                          "span": {
+                            "start": 18,
+                            "end": 34
+                          }
+                        }
                       }
                     ]
                   }
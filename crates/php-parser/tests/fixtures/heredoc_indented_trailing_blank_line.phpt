@@ -0,0 +1,111 @@
+===source===
+<?php
+$a = <<<EOT
+    line
+
+    EOT;
+$b = <<<'EOT'
+    line
+
+    EOT;
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "a"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Heredoc": {
+                    "label": "EOT",
+                    "raw": "    line\n",
+                    "parts": [
+                      {
+                        "Literal": {
+                          "value": "line\n",
+                          "span": {
+                            "start": 18,
+                            "end": 27
+                          }
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 35
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 35
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 36
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "b"
+                },
+                "span": {
+                  "start": 37,
+                  "end": 39
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "Nowdoc": {
+                    "label": "EOT",
+                    "raw": "    line\n",
+                    "value": "line\n"
+                  }
+                },
+                "span": {
+                  "start": 42,
+                  "end": 68
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 37,
+            "end": 68
+          }
+        }
+      },
+      "span": {
+        "start": 37,
+        "end": 69
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 69
+  }
+}
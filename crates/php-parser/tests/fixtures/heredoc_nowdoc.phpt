@@ -42,9 +42,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello World",
                     "parts": [
                       {
-                        "Literal": "Hello World"
+                        "Literal": {
+                          "value": "Hello World",
+                          "span": {
+                            "start": 38,
+                            "end": 49
+                          }
+                        }
                       }
                     ]
                   }
@@ -84,7 +91,10 @@ EOT;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "PHP"
+                  "String": {
+                    "value": "PHP",
+                    "raw": "PHP"
+                  }
                 },
                 "span": {
                   "start": 94,
@@ -123,9 +133,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello $name!",
                     "parts": [
                       {
-                        "Literal": "Hello "
+                        "Literal": {
+                          "value": "Hello ",
+                          "span": {
+                            "start": 115,
+                            "end": 121
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -139,7 +156,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": "!"
+                        "Literal": {
+                          "value": "!",
+                          "span": {
+                            "start": 126,
+                            "end": 127
+                          }
+                        }
                       }
                     ]
                   }
@@ -181,6 +204,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "Hello $name!",
                     "value": "Hello $name!"
                   }
                 },
@@ -204,24 +228,33 @@ EOT;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Heredoc": {
-                "label": "EOT",
-                "parts": [
-                  {
-                    "Literal": "output"
-                  }
-                ]
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Heredoc": {
+                  "label": "EOT",
+                  "raw": "output",
+                  "parts": [
+                    {
+                      "Literal": {
+                        "value": "output",
+                        "span": {
+                          "start": 243,
+                          "end": 249
+                        }
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 236,
+                "end": 253
               }
-            },
-            "span": {
-              "start": 236,
-              "end": 253
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 231,
@@ -26,9 +26,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello {$obj->name}!\n$arr[0] items",
                     "parts": [
                       {
-                        "Literal": "Hello "
+                        "Literal": {
+                          "value": "Hello ",
+                          "span": {
+                            "start": 18,
+                            "end": 24
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -61,7 +68,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": "!\n"
+                        "Literal": {
+                          "value": "!\n",
+                          "span": {
+                            "start": 36,
+                            "end": 38
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -94,7 +107,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": " items"
+                        "Literal": {
+                          "value": " items",
+                          "span": {
+                            "start": 45,
+                            "end": 51
+                          }
+                        }
                       }
                     ]
                   }
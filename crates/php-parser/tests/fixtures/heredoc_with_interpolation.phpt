@@ -24,9 +24,16 @@ EOT;
                 "kind": {
                   "Heredoc": {
                     "label": "EOT",
+                    "raw": "Hello $name!",
                     "parts": [
                       {
-                        "Literal": "Hello "
+                        "Literal": {
+                          "value": "Hello ",
+                          "span": {
+                            "start": 18,
+                            "end": 24
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -40,7 +47,13 @@ EOT;
                         }
                       },
                       {
-                        "Literal": "!"
+                        "Literal": {
+                          "value": "!",
+                          "span": {
+                            "start": 29,
+                            "end": 30
+                          }
+                        }
                       }
                     ]
                   }
@@ -25,9 +25,16 @@ END;
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "    indented content",
                     "parts": [
                       {
-                        "Literal": "    indented content"
+                        "Literal": {
+                          "value": "    indented content",
+                          "span": {
+                            "start": 18,
+                            "end": 38
+                          }
+                        }
                       }
                     ]
                   }
@@ -21,17 +21,22 @@
                         "body": [
                           {
                             "kind": {
-                              "Echo": [
-                                {
-                                  "kind": {
-                                    "String": "hi"
-                                  },
-                                  "span": {
-                                    "start": 25,
-                                    "end": 29
+                              "Echo": {
+                                "exprs": [
+                                  {
+                                    "kind": {
+                                      "String": {
+                                        "value": "hi",
+                                        "raw": "hi"
+                                      }
+                                    },
+                                    "span": {
+                                      "start": 25,
+                                      "end": 29
+                                    }
                                   }
-                                }
-                              ]
+                                ]
+                              }
                             },
                             "span": {
                               "start": 20,
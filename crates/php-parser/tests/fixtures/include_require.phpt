@@ -15,7 +15,10 @@ require_once 'autoload.php';
               "Include",
               {
                 "kind": {
-                  "String": "header.php"
+                  "String": {
+                    "value": "header.php",
+                    "raw": "header.php"
+                  }
                 },
                 "span": {
                   "start": 14,
@@ -43,7 +46,10 @@ require_once 'autoload.php';
               "IncludeOnce",
               {
                 "kind": {
-                  "String": "config.php"
+                  "String": {
+                    "value": "config.php",
+                    "raw": "config.php"
+                  }
                 },
                 "span": {
                   "start": 41,
@@ -71,7 +77,10 @@ require_once 'autoload.php';
               "Require",
               {
                 "kind": {
-                  "String": "bootstrap.php"
+                  "String": {
+                    "value": "bootstrap.php",
+                    "raw": "bootstrap.php"
+                  }
                 },
                 "span": {
                   "start": 63,
@@ -99,7 +108,10 @@ require_once 'autoload.php';
               "RequireOnce",
               {
                 "kind": {
-                  "String": "autoload.php"
+                  "String": {
+                    "value": "autoload.php",
+                    "raw": "autoload.php"
+                  }
                 },
                 "span": {
                   "start": 93,
@@ -26,9 +26,16 @@ $x = <<<END
                 "kind": {
                   "Heredoc": {
                     "label": "END",
+                    "raw": "    Hello {$obj->name}!\n    $arr[0] items",
                     "parts": [
                       {
-                        "Literal": "Hello "
+                        "Literal": {
+                          "value": "Hello ",
+                          "span": {
+                            "start": 22,
+                            "end": 28
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -61,7 +68,13 @@ $x = <<<END
                         }
                       },
                       {
-                        "Literal": "!\n"
+                        "Literal": {
+                          "value": "!\n",
+                          "span": {
+                            "start": 40,
+                            "end": 46
+                          }
+                        }
                       },
                       {
                         "Expr": {
@@ -94,7 +107,13 @@ $x = <<<END
                         }
                       },
                       {
-                        "Literal": " items"
+                        "Literal": {
+                          "value": " items",
+                          "span": {
+                            "start": 53,
+                            "end": 59
+                          }
+                        }
                       }
                     ]
                   }
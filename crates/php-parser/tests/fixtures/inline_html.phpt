@@ -11,7 +11,9 @@
   "stmts": [
     {
       "kind": {
-        "InlineHtml": "<html>\n<body>\n"
+        "InlineHtml": {
+          "text": "<html>\n<body>\n"
+        }
       },
       "span": {
         "start": 0,
@@ -20,17 +22,22 @@
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "Hello"
-            },
-            "span": {
-              "start": 25,
-              "end": 32
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "Hello",
+                  "raw": "Hello"
+                }
+              },
+              "span": {
+                "start": 25,
+                "end": 32
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 20,
@@ -39,7 +46,10 @@
     },
     {
       "kind": {
-        "InlineHtml": "\n<p>Some HTML</p>\n"
+        "InlineHtml": {
+          "text": "\n<p>Some HTML</p>\n",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 36,
@@ -48,17 +58,22 @@
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "String": "World"
-            },
-            "span": {
-              "start": 65,
-              "end": 72
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "String": {
+                  "value": "World",
+                  "raw": "World"
+                }
+              },
+              "span": {
+                "start": 65,
+                "end": 72
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 60,
@@ -67,7 +82,10 @@
     },
     {
       "kind": {
-        "InlineHtml": "\n</body>\n</html>"
+        "InlineHtml": {
+          "text": "\n</body>\n</html>",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 76,
@@ -78,5 +96,10 @@
   "span": {
     "start": 0,
     "end": 92
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 74,
+    "end": 76
   }
 }
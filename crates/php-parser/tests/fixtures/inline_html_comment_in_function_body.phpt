@@ -25,7 +25,16 @@ function tmpl() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 28,
+                              "end": 31
+                            }
+                          }
                         },
                         "span": {
                           "start": 28,
@@ -48,7 +57,10 @@ function tmpl() {
             },
             {
               "kind": {
-                "InlineHtml": "\n<div>\n"
+                "InlineHtml": {
+                  "text": "\n<div>\n",
+                  "newline_swallowed": true
+                }
               },
               "span": {
                 "start": 41,
@@ -64,7 +76,10 @@ function tmpl() {
             },
             {
               "kind": {
-                "InlineHtml": "\n    <p>text</p>\n"
+                "InlineHtml": {
+                  "text": "\n    <p>text</p>\n",
+                  "newline_swallowed": true
+                }
               },
               "span": {
                 "start": 67,
@@ -85,7 +100,16 @@ function tmpl() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "bar"
+                          "Name": {
+                            "parts": [
+                              "bar"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 94,
+                              "end": 97
+                            }
+                          }
                         },
                         "span": {
                           "start": 94,
@@ -36,7 +36,16 @@ function tmpl() {
                           "FunctionCall": {
                             "name": {
                               "kind": {
-                                "Identifier": "foo"
+                                "Name": {
+                                  "parts": [
+                                    "foo"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 33,
+                                    "end": 36
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 33,
@@ -66,7 +75,10 @@ function tmpl() {
             },
             {
               "kind": {
-                "InlineHtml": "\n<div>html</div>\n"
+                "InlineHtml": {
+                  "text": "\n<div>html</div>\n",
+                  "newline_swallowed": true
+                }
               },
               "span": {
                 "start": 46,
@@ -87,7 +99,16 @@ function tmpl() {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "bar"
+                          "Name": {
+                            "parts": [
+                              "bar"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 73,
+                              "end": 76
+                            }
+                          }
                         },
                         "span": {
                           "start": 73,
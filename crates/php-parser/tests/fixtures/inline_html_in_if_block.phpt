@@ -24,7 +24,9 @@ if ($cond) {
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": "<p>html</p>"
+                    "InlineHtml": {
+                      "text": "<p>html</p>"
+                    }
                   },
                   "span": {
                     "start": 25,
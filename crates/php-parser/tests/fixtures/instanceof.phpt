@@ -7,8 +7,8 @@
       "kind": {
         "Expression": {
           "kind": {
-            "Binary": {
-              "left": {
+            "Instanceof": {
+              "expr": {
                 "kind": {
                   "Variable": "obj"
                 },
@@ -17,14 +17,16 @@
                   "end": 10
                 }
               },
-              "op": "Instanceof",
-              "right": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 22,
-                  "end": 25
+              "class": {
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 22,
+                    "end": 25
+                  }
                 }
               }
             }
@@ -47,8 +49,8 @@
             "Binary": {
               "left": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "x"
                       },
@@ -57,14 +59,16 @@
                         "end": 29
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Bar"
-                      },
-                      "span": {
-                        "start": 41,
-                        "end": 44
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Bar"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 41,
+                          "end": 44
+                        }
                       }
                     }
                   }
@@ -77,8 +81,8 @@
               "op": "BooleanOr",
               "right": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "x"
                       },
@@ -87,14 +91,16 @@
                         "end": 50
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Baz"
-                      },
-                      "span": {
-                        "start": 62,
-                        "end": 65
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Baz"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 62,
+                          "end": 65
+                        }
                       }
                     }
                   }
@@ -24,8 +24,8 @@ $d = $obj instanceof static;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "obj"
                       },
@@ -34,14 +34,15 @@ $d = $obj instanceof static;
                         "end": 15
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Variable": "className"
-                      },
-                      "span": {
-                        "start": 27,
-                        "end": 37
+                    "class": {
+                      "Expr": {
+                        "kind": {
+                          "Variable": "className"
+                        },
+                        "span": {
+                          "start": 27,
+                          "end": 37
+                        }
                       }
                     }
                   }
@@ -81,8 +82,8 @@ $d = $obj instanceof static;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "obj"
                       },
@@ -91,12 +92,8 @@ $d = $obj instanceof static;
                         "end": 48
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "self"
-                      },
-                      "span": {
+                    "class": {
+                      "SelfKw": {
                         "start": 60,
                         "end": 64
                       }
@@ -138,8 +135,8 @@ $d = $obj instanceof static;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "obj"
                       },
@@ -148,12 +145,8 @@ $d = $obj instanceof static;
                         "end": 75
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "parent"
-                      },
-                      "span": {
+                    "class": {
+                      "ParentKw": {
                         "start": 87,
                         "end": 93
                       }
@@ -195,8 +188,8 @@ $d = $obj instanceof static;
               "op": "Assign",
               "value": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "obj"
                       },
@@ -205,12 +198,8 @@ $d = $obj instanceof static;
                         "end": 104
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "static"
-                      },
-                      "span": {
+                    "class": {
+                      "StaticKw": {
                         "start": 116,
                         "end": 122
                       }
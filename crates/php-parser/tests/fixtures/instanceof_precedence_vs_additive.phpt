@@ -20,8 +20,8 @@
               "op": "Add",
               "right": {
                 "kind": {
-                  "Binary": {
-                    "left": {
+                  "Instanceof": {
+                    "expr": {
                       "kind": {
                         "Variable": "b"
                       },
@@ -30,14 +30,16 @@
                         "end": 13
                       }
                     },
-                    "op": "Instanceof",
-                    "right": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 25,
-                        "end": 28
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 25,
+                          "end": 28
+                        }
                       }
                     }
                   }
@@ -22,7 +22,10 @@
                     },
                     "index": {
                       "kind": {
-                        "String": "key"
+                        "String": {
+                          "value": "key",
+                          "raw": "key"
+                        }
                       },
                       "span": {
                         "start": 15,
@@ -68,12 +71,14 @@
                 "kind": {
                   "StaticPropertyAccess": {
                     "class": {
-                      "kind": {
-                        "Variable": "c"
-                      },
-                      "span": {
-                        "start": 33,
-                        "end": 35
+                      "Expr": {
+                        "kind": {
+                          "Variable": "c"
+                        },
+                        "span": {
+                          "start": 33,
+                          "end": 35
+                        }
                       }
                     },
                     "member": {
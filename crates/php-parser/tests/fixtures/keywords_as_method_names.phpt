@@ -146,7 +146,10 @@ class Foo {
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": ""
+                            "String": {
+                              "value": "",
+                              "raw": ""
+                            }
                           },
                           "span": {
                             "start": 149,
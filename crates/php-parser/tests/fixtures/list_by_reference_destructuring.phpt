@@ -13,44 +13,50 @@ list(&$x, &$y) = $pair;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 8,
+                            "end": 10
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 8,
+                          "start": 7,
                           "end": 10
+                        },
+                        "comma": {
+                          "start": 10,
+                          "end": 11
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 7,
-                        "end": 10
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 13,
+                            "end": 15
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 13,
+                          "start": 12,
                           "end": 15
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 12,
-                        "end": 15
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -87,44 +93,51 @@ list(&$x, &$y) = $pair;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 31,
+                            "end": 33
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 31,
+                          "start": 30,
                           "end": 33
+                        },
+                        "comma": {
+                          "start": 33,
+                          "end": 34
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 30,
-                        "end": 33
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "y"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "y"
+                          },
+                          "span": {
+                            "start": 36,
+                            "end": 38
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 36,
+                          "start": 35,
                           "end": 38
                         }
-                      },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 35,
-                        "end": 38
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 25,
@@ -161,43 +174,49 @@ list(&$x, &$y) = $pair;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "first"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "first"
+                          },
+                          "span": {
+                            "start": 51,
+                            "end": 57
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 51,
+                          "start": 50,
                           "end": 57
+                        },
+                        "comma": {
+                          "start": 57,
+                          "end": 58
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 50,
-                        "end": 57
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "second"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "second"
+                          },
+                          "span": {
+                            "start": 59,
+                            "end": 66
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 59,
                           "end": 66
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 59,
-                        "end": 66
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 49,
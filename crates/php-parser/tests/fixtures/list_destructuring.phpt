@@ -12,59 +12,70 @@ list($x, , $z) = $array;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 15,
+                            "end": 17
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 15,
                           "end": 17
+                        },
+                        "comma": {
+                          "start": 17,
+                          "end": 18
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 15,
-                        "end": 17
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 19,
+                            "end": 21
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 19,
                           "end": 21
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 19,
-                        "end": 21
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -77,7 +88,16 @@ list($x, , $z) = $array;
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "getValues"
+                        "Name": {
+                          "parts": [
+                            "getValues"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 25,
+                            "end": 34
+                          }
+                        }
                       },
                       "span": {
                         "start": 25,
@@ -112,57 +132,68 @@ list($x, , $z) = $array;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 43,
+                            "end": 45
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 43,
                           "end": 45
+                        },
+                        "comma": {
+                          "start": 45,
+                          "end": 46
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 43,
-                        "end": 45
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 47,
+                            "end": 47
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 47,
+                          "end": 47
+                        },
+                        "comma": {
                           "start": 47,
                           "end": 48
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 47,
-                        "end": 48
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "z"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "z"
+                          },
+                          "span": {
+                            "start": 49,
+                            "end": 51
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 49,
                           "end": 51
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 49,
-                        "end": 51
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 38,
@@ -10,43 +10,50 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 12,
+                            "end": 14
+                          }
                         },
+                        "unpack": false,
+                        "by_ref": true,
                         "span": {
-                          "start": 12,
+                          "start": 11,
                           "end": 14
+                        },
+                        "comma": {
+                          "start": 14,
+                          "end": 15
                         }
                       },
-                      "unpack": false,
-                      "by_ref": true,
-                      "span": {
-                        "start": 11,
-                        "end": 14
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 16,
+                            "end": 18
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 16,
                           "end": 18
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 16,
-                        "end": 18
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
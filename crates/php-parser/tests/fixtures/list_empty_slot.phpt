@@ -10,57 +10,68 @@
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Omit",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Omit",
+                          "span": {
+                            "start": 15,
+                            "end": 15
+                          }
+                        },
+                        "unpack": false,
                         "span": {
+                          "start": 15,
+                          "end": 15
+                        },
+                        "comma": {
                           "start": 15,
                           "end": 16
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 15,
-                        "end": 16
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 17,
+                            "end": 19
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 17,
                           "end": 19
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 17,
-                        "end": 19
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -11,58 +11,71 @@ list('name' => $name, 'age' => $age) = $person;
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "name"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "name",
+                              "raw": "name"
+                            }
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 17
+                          }
                         },
-                        "span": {
-                          "start": 11,
-                          "end": 17
-                        }
-                      },
-                      "value": {
-                        "kind": {
-                          "Variable": "name"
+                        "value": {
+                          "kind": {
+                            "Variable": "name"
+                          },
+                          "span": {
+                            "start": 21,
+                            "end": 26
+                          }
                         },
+                        "unpack": false,
                         "span": {
-                          "start": 21,
+                          "start": 11,
                           "end": 26
-                        }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 26
-                      }
-                    },
-                    {
-                      "key": {
-                        "kind": {
-                          "String": "age"
                         },
-                        "span": {
-                          "start": 28,
-                          "end": 33
+                        "comma": {
+                          "start": 26,
+                          "end": 27
                         }
                       },
-                      "value": {
-                        "kind": {
-                          "Variable": "age"
+                      {
+                        "key": {
+                          "kind": {
+                            "String": {
+                              "value": "age",
+                              "raw": "age"
+                            }
+                          },
+                          "span": {
+                            "start": 28,
+                            "end": 33
+                          }
                         },
+                        "value": {
+                          "kind": {
+                            "Variable": "age"
+                          },
+                          "span": {
+                            "start": 37,
+                            "end": 41
+                          }
+                        },
+                        "unpack": false,
                         "span": {
-                          "start": 37,
+                          "start": 28,
                           "end": 41
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 28,
-                        "end": 41
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -60,17 +60,19 @@ class Foo {
                         "Block": [
                           {
                             "kind": {
-                              "Echo": [
-                                {
-                                  "kind": {
-                                    "MagicConst": "Property"
-                                  },
-                                  "span": {
-                                    "start": 75,
-                                    "end": 87
+                              "Echo": {
+                                "exprs": [
+                                  {
+                                    "kind": {
+                                      "MagicConst": "Property"
+                                    },
+                                    "span": {
+                                      "start": 75,
+                                      "end": 87
+                                    }
                                   }
-                                }
-                              ]
+                                ]
+                              }
                             },
                             "span": {
                               "start": 70,
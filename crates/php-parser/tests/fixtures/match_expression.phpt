@@ -40,7 +40,10 @@ $result = match ($x) {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 33,
@@ -49,7 +52,10 @@ $result = match ($x) {
                           },
                           {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 38,
@@ -59,7 +65,10 @@ $result = match ($x) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "first"
+                            "String": {
+                              "value": "first",
+                              "raw": "first"
+                            }
                           },
                           "span": {
                             "start": 45,
@@ -69,13 +78,20 @@ $result = match ($x) {
                         "span": {
                           "start": 33,
                           "end": 52
+                        },
+                        "comma": {
+                          "start": 52,
+                          "end": 53
                         }
                       },
                       {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "c"
+                              "String": {
+                                "value": "c",
+                                "raw": "c"
+                              }
                             },
                             "span": {
                               "start": 58,
@@ -85,7 +101,10 @@ $result = match ($x) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "second"
+                            "String": {
+                              "value": "second",
+                              "raw": "second"
+                            }
                           },
                           "span": {
                             "start": 65,
@@ -95,13 +114,20 @@ $result = match ($x) {
                         "span": {
                           "start": 58,
                           "end": 73
+                        },
+                        "comma": {
+                          "start": 73,
+                          "end": 74
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "other"
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
                           },
                           "span": {
                             "start": 90,
@@ -111,6 +137,10 @@ $result = match ($x) {
                         "span": {
                           "start": 79,
                           "end": 97
+                        },
+                        "comma": {
+                          "start": 97,
+                          "end": 98
                         }
                       }
                     ]
@@ -70,7 +70,10 @@ $result = match (true) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "positive"
+                            "String": {
+                              "value": "positive",
+                              "raw": "positive"
+                            }
                           },
                           "span": {
                             "start": 45,
@@ -80,6 +83,10 @@ $result = match (true) {
                         "span": {
                           "start": 35,
                           "end": 55
+                        },
+                        "comma": {
+                          "start": 55,
+                          "end": 56
                         }
                       },
                       {
@@ -116,7 +123,10 @@ $result = match (true) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "negative"
+                            "String": {
+                              "value": "negative",
+                              "raw": "negative"
+                            }
                           },
                           "span": {
                             "start": 71,
@@ -126,13 +136,20 @@ $result = match (true) {
                         "span": {
                           "start": 61,
                           "end": 81
+                        },
+                        "comma": {
+                          "start": 81,
+                          "end": 82
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "zero"
+                            "String": {
+                              "value": "zero",
+                              "raw": "zero"
+                            }
                           },
                           "span": {
                             "start": 98,
@@ -142,6 +159,10 @@ $result = match (true) {
                         "span": {
                           "start": 87,
                           "end": 104
+                        },
+                        "comma": {
+                          "start": 104,
+                          "end": 105
                         }
                       }
                     ]
@@ -35,7 +35,10 @@
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "always"
+                            "String": {
+                              "value": "always",
+                              "raw": "always"
+                            }
                           },
                           "span": {
                             "start": 37,
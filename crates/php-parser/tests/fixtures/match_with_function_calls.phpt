@@ -31,7 +31,16 @@ $label = match (getStatus()) {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "getStatus"
+                              "Name": {
+                                "parts": [
+                                  "getStatus"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 22,
+                                  "end": 31
+                                }
+                              }
                             },
                             "span": {
                               "start": 22,
@@ -54,7 +63,16 @@ $label = match (getStatus()) {
                               "FunctionCall": {
                                 "name": {
                                   "kind": {
-                                    "Identifier": "isActive"
+                                    "Name": {
+                                      "parts": [
+                                        "isActive"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 41,
+                                        "end": 49
+                                      }
+                                    }
                                   },
                                   "span": {
                                     "start": 41,
@@ -72,7 +90,10 @@ $label = match (getStatus()) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "active"
+                            "String": {
+                              "value": "active",
+                              "raw": "active"
+                            }
                           },
                           "span": {
                             "start": 55,
@@ -82,6 +103,10 @@ $label = match (getStatus()) {
                         "span": {
                           "start": 41,
                           "end": 63
+                        },
+                        "comma": {
+                          "start": 63,
+                          "end": 64
                         }
                       },
                       {
@@ -91,7 +116,16 @@ $label = match (getStatus()) {
                               "FunctionCall": {
                                 "name": {
                                   "kind": {
-                                    "Identifier": "isPending"
+                                    "Name": {
+                                      "parts": [
+                                        "isPending"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 69,
+                                        "end": 78
+                                      }
+                                    }
                                   },
                                   "span": {
                                     "start": 69,
@@ -109,7 +143,10 @@ $label = match (getStatus()) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "pending"
+                            "String": {
+                              "value": "pending",
+                              "raw": "pending"
+                            }
                           },
                           "span": {
                             "start": 84,
@@ -119,6 +156,10 @@ $label = match (getStatus()) {
                         "span": {
                           "start": 69,
                           "end": 93
+                        },
+                        "comma": {
+                          "start": 93,
+                          "end": 94
                         }
                       },
                       {
@@ -129,12 +170,15 @@ $label = match (getStatus()) {
                               "kind": {
                                 "New": {
                                   "class": {
-                                    "kind": {
-                                      "Identifier": "LogicException"
-                                    },
-                                    "span": {
-                                      "start": 120,
-                                      "end": 134
+                                    "Name": {
+                                      "parts": [
+                                        "LogicException"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 120,
+                                        "end": 134
+                                      }
                                     }
                                   },
                                   "args": [
@@ -142,7 +186,10 @@ $label = match (getStatus()) {
                                       "name": null,
                                       "value": {
                                         "kind": {
-                                          "String": "Unknown"
+                                          "String": {
+                                            "value": "Unknown",
+                                            "raw": "Unknown"
+                                          }
                                         },
                                         "span": {
                                           "start": 135,
@@ -173,6 +220,10 @@ $label = match (getStatus()) {
                         "span": {
                           "start": 99,
                           "end": 145
+                        },
+                        "comma": {
+                          "start": 145,
+                          "end": 146
                         }
                       }
                     ]
@@ -50,7 +50,10 @@ $x = match ($status) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "ok"
+                            "String": {
+                              "value": "ok",
+                              "raw": "ok"
+                            }
                           },
                           "span": {
                             "start": 40,
@@ -60,6 +63,10 @@ $x = match ($status) {
                         "span": {
                           "start": 33,
                           "end": 44
+                        },
+                        "comma": {
+                          "start": 44,
+                          "end": 45
                         }
                       },
                       {
@@ -80,12 +87,15 @@ $x = match ($status) {
                               "kind": {
                                 "New": {
                                   "class": {
-                                    "kind": {
-                                      "Identifier": "NotFoundException"
-                                    },
-                                    "span": {
-                                      "start": 67,
-                                      "end": 84
+                                    "Name": {
+                                      "parts": [
+                                        "NotFoundException"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 67,
+                                        "end": 84
+                                      }
                                     }
                                   },
                                   "args": []
@@ -105,6 +115,10 @@ $x = match ($status) {
                         "span": {
                           "start": 50,
                           "end": 86
+                        },
+                        "comma": {
+                          "start": 86,
+                          "end": 87
                         }
                       },
                       {
@@ -115,12 +129,15 @@ $x = match ($status) {
                               "kind": {
                                 "New": {
                                   "class": {
-                                    "kind": {
-                                      "Identifier": "RuntimeException"
-                                    },
-                                    "span": {
-                                      "start": 113,
-                                      "end": 129
+                                    "Name": {
+                                      "parts": [
+                                        "RuntimeException"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 113,
+                                        "end": 129
+                                      }
                                     }
                                   },
                                   "args": [
@@ -128,7 +145,10 @@ $x = match ($status) {
                                       "name": null,
                                       "value": {
                                         "kind": {
-                                          "String": "Unexpected"
+                                          "String": {
+                                            "value": "Unexpected",
+                                            "raw": "Unexpected"
+                                          }
                                         },
                                         "span": {
                                           "start": 130,
@@ -159,6 +179,10 @@ $x = match ($status) {
                         "span": {
                           "start": 92,
                           "end": 143
+                        },
+                        "comma": {
+                          "start": 143,
+                          "end": 144
                         }
                       }
                     ]
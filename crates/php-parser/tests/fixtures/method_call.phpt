@@ -71,7 +71,10 @@
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "foo"
+                            "String": {
+                              "value": "foo",
+                              "raw": "foo"
+                            }
                           },
                           "span": {
                             "start": 41,
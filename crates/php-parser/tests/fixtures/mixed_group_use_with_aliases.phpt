@@ -26,6 +26,10 @@ use A\B\{C as D, function e as f, const G as H};
               "span": {
                 "start": 15,
                 "end": 21
+              },
+              "comma": {
+                "start": 21,
+                "end": 22
               }
             },
             {
@@ -46,6 +50,10 @@ use A\B\{C as D, function e as f, const G as H};
               "span": {
                 "start": 23,
                 "end": 38
+              },
+              "comma": {
+                "start": 38,
+                "end": 39
               }
             },
             {
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -34,13 +43,20 @@
                   "span": {
                     "start": 10,
                     "end": 11
+                  },
+                  "comma": {
+                    "start": 11,
+                    "end": 12
                   }
                 },
                 {
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "bar"
+                      "String": {
+                        "value": "bar",
+                        "raw": "bar"
+                      }
                     },
                     "span": {
                       "start": 13,
@@ -52,6 +68,10 @@
                   "span": {
                     "start": 13,
                     "end": 18
+                  },
+                  "comma": {
+                    "start": 18,
+                    "end": 19
                   }
                 },
                 {
@@ -65,6 +85,10 @@
                       "end": 24
                     }
                   },
+                  "colon": {
+                    "start": 24,
+                    "end": 25
+                  },
                   "value": {
                     "kind": {
                       "Variable": "val"
@@ -79,6 +103,10 @@
                   "span": {
                     "start": 20,
                     "end": 30
+                  },
+                  "comma": {
+                    "start": 30,
+                    "end": 31
                   }
                 },
                 {
@@ -92,6 +120,10 @@
                       "end": 37
                     }
                   },
+                  "colon": {
+                    "start": 37,
+                    "end": 38
+                  },
                   "value": {
                     "kind": {
                       "Int": 5
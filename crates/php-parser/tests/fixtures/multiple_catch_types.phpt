@@ -21,7 +21,16 @@ try {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "foo"
+                          "Name": {
+                            "parts": [
+                              "foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 16,
+                              "end": 19
+                            }
+                          }
                         },
                         "span": {
                           "start": 16,
@@ -47,37 +56,49 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "TypeError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 32,
-                    "end": 41
+                  "Name": {
+                    "parts": [
+                      "TypeError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 32,
+                      "end": 41
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "ValueError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 44,
-                    "end": 54
+                  "Name": {
+                    "parts": [
+                      "ValueError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 44,
+                      "end": 54
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "RuntimeException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 57,
-                    "end": 73
+                  "Name": {
+                    "parts": [
+                      "RuntimeException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 57,
+                      "end": 73
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 74,
+                  "end": 76
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -86,7 +107,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handle"
+                              "Name": {
+                                "parts": [
+                                  "handle"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 84,
+                                  "end": 90
+                                }
+                              }
                             },
                             "span": {
                               "start": 84,
@@ -135,17 +165,25 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "LogicException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 105,
-                    "end": 119
+                  "Name": {
+                    "parts": [
+                      "LogicException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 105,
+                      "end": 119
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 120,
+                  "end": 122
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -154,7 +192,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "other"
+                              "Name": {
+                                "parts": [
+                                  "other"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 130,
+                                  "end": 135
+                                }
+                              }
                             },
                             "span": {
                               "start": 130,
@@ -35,7 +35,10 @@ function foo() {
                     "name": "b",
                     "default": {
                       "kind": {
-                        "String": "init"
+                        "String": {
+                          "value": "init",
+                          "raw": "init"
+                        }
                       },
                       "span": {
                         "start": 47,
@@ -25,6 +25,10 @@
               "span": {
                 "start": 10,
                 "end": 25
+              },
+              "comma": {
+                "start": 25,
+                "end": 26
               }
             },
             {
@@ -44,6 +48,10 @@
               "span": {
                 "start": 27,
                 "end": 42
+              },
+              "comma": {
+                "start": 42,
+                "end": 43
               }
             },
             {
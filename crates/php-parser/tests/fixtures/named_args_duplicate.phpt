@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "func"
+                  "Name": {
+                    "parts": [
+                      "func"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -29,6 +38,10 @@
                       "end": 12
                     }
                   },
+                  "colon": {
+                    "start": 12,
+                    "end": 13
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -43,6 +56,10 @@
                   "span": {
                     "start": 11,
                     "end": 15
+                  },
+                  "comma": {
+                    "start": 15,
+                    "end": 16
                   }
                 },
                 {
@@ -56,6 +73,10 @@
                       "end": 18
                     }
                   },
+                  "colon": {
+                    "start": 18,
+                    "end": 19
+                  },
                   "value": {
                     "kind": {
                       "Int": 2
@@ -14,7 +14,16 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 9
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -33,6 +42,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 11
                     }
                   },
+                  "colon": {
+                    "start": 11,
+                    "end": 12
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -47,6 +60,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 10,
                     "end": 14
+                  },
+                  "comma": {
+                    "start": 14,
+                    "end": 15
                   }
                 },
                 {
@@ -60,6 +77,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 17
                     }
                   },
+                  "colon": {
+                    "start": 17,
+                    "end": 18
+                  },
                   "value": {
                     "kind": {
                       "Int": 2
@@ -74,6 +95,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 16,
                     "end": 20
+                  },
+                  "comma": {
+                    "start": 20,
+                    "end": 21
                   }
                 },
                 {
@@ -87,6 +112,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 23
                     }
                   },
+                  "colon": {
+                    "start": 23,
+                    "end": 24
+                  },
                   "value": {
                     "kind": {
                       "Int": 3
@@ -124,7 +153,16 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "bar"
+                  "Name": {
+                    "parts": [
+                      "bar"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 29,
+                      "end": 32
+                    }
+                  }
                 },
                 "span": {
                   "start": 29,
@@ -148,6 +186,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 33,
                     "end": 41
+                  },
+                  "comma": {
+                    "start": 41,
+                    "end": 42
                   }
                 },
                 {
@@ -161,6 +203,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 48
                     }
                   },
+                  "colon": {
+                    "start": 48,
+                    "end": 49
+                  },
                   "value": {
                     "kind": {
                       "Bool": true
@@ -198,7 +244,16 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "baz"
+                  "Name": {
+                    "parts": [
+                      "baz"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 57,
+                      "end": 60
+                    }
+                  }
                 },
                 "span": {
                   "start": 57,
@@ -222,6 +277,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 61,
                     "end": 62
+                  },
+                  "comma": {
+                    "start": 62,
+                    "end": 63
                   }
                 },
                 {
@@ -240,6 +299,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 64,
                     "end": 65
+                  },
+                  "comma": {
+                    "start": 65,
+                    "end": 66
                   }
                 },
                 {
@@ -253,9 +316,16 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 71
                     }
                   },
+                  "colon": {
+                    "start": 71,
+                    "end": 72
+                  },
                   "value": {
                     "kind": {
-                      "String": "test"
+                      "String": {
+                        "value": "test",
+                        "raw": "test"
+                      }
                     },
                     "span": {
                       "start": 73,
@@ -290,7 +360,16 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "array_map"
+                  "Name": {
+                    "parts": [
+                      "array_map"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 82,
+                      "end": 91
+                    }
+                  }
                 },
                 "span": {
                   "start": 82,
@@ -309,6 +388,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 100
                     }
                   },
+                  "colon": {
+                    "start": 100,
+                    "end": 101
+                  },
                   "value": {
                     "kind": {
                       "ArrowFunction": {
@@ -375,6 +458,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                   "span": {
                     "start": 92,
                     "end": 118
+                  },
+                  "comma": {
+                    "start": 118,
+                    "end": 119
                   }
                 },
                 {
@@ -388,6 +475,10 @@ array_map(callback: fn($x) => $x * 2, array: $arr);
                       "end": 125
                     }
                   },
+                  "colon": {
+                    "start": 125,
+                    "end": 126
+                  },
                   "value": {
                     "kind": {
                       "Variable": "arr"
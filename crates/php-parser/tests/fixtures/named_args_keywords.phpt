@@ -12,7 +12,16 @@ foo(class: 'MyClass', static: true, match: 'yes');
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "array_slice"
+                  "Name": {
+                    "parts": [
+                      "array_slice"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 17
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -31,6 +40,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 23
                     }
                   },
+                  "colon": {
+                    "start": 23,
+                    "end": 24
+                  },
                   "value": {
                     "kind": {
                       "Variable": "arr"
@@ -45,6 +58,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                   "span": {
                     "start": 18,
                     "end": 29
+                  },
+                  "comma": {
+                    "start": 29,
+                    "end": 30
                   }
                 },
                 {
@@ -58,6 +75,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 37
                     }
                   },
+                  "colon": {
+                    "start": 37,
+                    "end": 38
+                  },
                   "value": {
                     "kind": {
                       "Int": 1
@@ -72,6 +93,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                   "span": {
                     "start": 31,
                     "end": 40
+                  },
+                  "comma": {
+                    "start": 40,
+                    "end": 41
                   }
                 },
                 {
@@ -85,6 +110,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 48
                     }
                   },
+                  "colon": {
+                    "start": 48,
+                    "end": 49
+                  },
                   "value": {
                     "kind": {
                       "Int": 2
@@ -122,7 +151,16 @@ foo(class: 'MyClass', static: true, match: 'yes');
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 54,
+                      "end": 57
+                    }
+                  }
                 },
                 "span": {
                   "start": 54,
@@ -141,9 +179,16 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 63
                     }
                   },
+                  "colon": {
+                    "start": 63,
+                    "end": 64
+                  },
                   "value": {
                     "kind": {
-                      "String": "MyClass"
+                      "String": {
+                        "value": "MyClass",
+                        "raw": "MyClass"
+                      }
                     },
                     "span": {
                       "start": 65,
@@ -155,6 +200,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                   "span": {
                     "start": 58,
                     "end": 74
+                  },
+                  "comma": {
+                    "start": 74,
+                    "end": 75
                   }
                 },
                 {
@@ -168,6 +217,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 82
                     }
                   },
+                  "colon": {
+                    "start": 82,
+                    "end": 83
+                  },
                   "value": {
                     "kind": {
                       "Bool": true
@@ -182,6 +235,10 @@ foo(class: 'MyClass', static: true, match: 'yes');
                   "span": {
                     "start": 76,
                     "end": 88
+                  },
+                  "comma": {
+                    "start": 88,
+                    "end": 89
                   }
                 },
                 {
@@ -195,9 +252,16 @@ foo(class: 'MyClass', static: true, match: 'yes');
                       "end": 95
                     }
                   },
+                  "colon": {
+                    "start": 95,
+                    "end": 96
+                  },
                   "value": {
                     "kind": {
-                      "String": "yes"
+                      "String": {
+                        "value": "yes",
+                        "raw": "yes"
+                      }
                     },
                     "span": {
                       "start": 97,
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "htmlspecialchars"
+                  "Name": {
+                    "parts": [
+                      "htmlspecialchars"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 22
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -29,6 +38,10 @@
                       "end": 29
                     }
                   },
+                  "colon": {
+                    "start": 29,
+                    "end": 30
+                  },
                   "value": {
                     "kind": {
                       "Variable": "str"
@@ -43,6 +56,10 @@
                   "span": {
                     "start": 23,
                     "end": 35
+                  },
+                  "comma": {
+                    "start": 35,
+                    "end": 36
                   }
                 },
                 {
@@ -56,9 +73,22 @@
                       "end": 42
                     }
                   },
+                  "colon": {
+                    "start": 42,
+                    "end": 43
+                  },
                   "value": {
                     "kind": {
-                      "Identifier": "ENT_QUOTES"
+                      "ConstFetch": {
+                        "parts": [
+                          "ENT_QUOTES"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 44,
+                          "end": 54
+                        }
+                      }
                     },
                     "span": {
                       "start": 44,
@@ -70,6 +100,10 @@
                   "span": {
                     "start": 37,
                     "end": 54
+                  },
+                  "comma": {
+                    "start": 54,
+                    "end": 55
                   }
                 },
                 {
@@ -83,9 +117,16 @@
                       "end": 64
                     }
                   },
+                  "colon": {
+                    "start": 64,
+                    "end": 65
+                  },
                   "value": {
                     "kind": {
-                      "String": "UTF-8"
+                      "String": {
+                        "value": "UTF-8",
+                        "raw": "UTF-8"
+                      }
                     },
                     "span": {
                       "start": 66,
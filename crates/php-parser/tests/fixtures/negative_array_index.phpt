@@ -97,7 +97,10 @@
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "last"
+                  "String": {
+                    "value": "last",
+                    "raw": "last"
+                  }
                 },
                 "span": {
                   "start": 27,
@@ -43,6 +43,10 @@ $flat = [...$arrays[0], ...$arrays[1], 5];
                               "span": {
                                 "start": 18,
                                 "end": 19
+                              },
+                              "comma": {
+                                "start": 19,
+                                "end": 20
                               }
                             },
                             {
@@ -73,6 +77,10 @@ $flat = [...$arrays[0], ...$arrays[1], 5];
                       "span": {
                         "start": 17,
                         "end": 23
+                      },
+                      "comma": {
+                        "start": 23,
+                        "end": 24
                       }
                     },
                     {
@@ -95,6 +103,10 @@ $flat = [...$arrays[0], ...$arrays[1], 5];
                               "span": {
                                 "start": 26,
                                 "end": 27
+                              },
+                              "comma": {
+                                "start": 27,
+                                "end": 28
                               }
                             },
                             {
@@ -199,6 +211,10 @@ $flat = [...$arrays[0], ...$arrays[1], 5];
                       "span": {
                         "start": 43,
                         "end": 56
+                      },
+                      "comma": {
+                        "start": 56,
+                        "end": 57
                       }
                     },
                     {
@@ -235,6 +251,10 @@ $flat = [...$arrays[0], ...$arrays[1], 5];
                       "span": {
                         "start": 58,
                         "end": 71
+                      },
+                      "comma": {
+                        "start": 71,
+                        "end": 72
                       }
                     },
                     {
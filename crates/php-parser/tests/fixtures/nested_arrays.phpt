@@ -28,6 +28,10 @@
                         "span": {
                           "start": 8,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
                       {
@@ -58,6 +62,10 @@
                 "span": {
                   "start": 7,
                   "end": 13
+                },
+                "comma": {
+                  "start": 13,
+                  "end": 14
                 }
               },
               {
@@ -80,6 +88,10 @@
                         "span": {
                           "start": 16,
                           "end": 17
+                        },
+                        "comma": {
+                          "start": 17,
+                          "end": 18
                         }
                       },
                       {
@@ -133,7 +145,10 @@
               {
                 "key": {
                   "kind": {
-                    "String": "a"
+                    "String": {
+                      "value": "a",
+                      "raw": "a"
+                    }
                   },
                   "span": {
                     "start": 25,
@@ -146,7 +161,10 @@
                       {
                         "key": {
                           "kind": {
-                            "String": "x"
+                            "String": {
+                              "value": "x",
+                              "raw": "x"
+                            }
                           },
                           "span": {
                             "start": 33,
@@ -179,12 +197,19 @@
                 "span": {
                   "start": 25,
                   "end": 42
+                },
+                "comma": {
+                  "start": 42,
+                  "end": 43
                 }
               },
               {
                 "key": {
                   "kind": {
-                    "String": "b"
+                    "String": {
+                      "value": "b",
+                      "raw": "b"
+                    }
                   },
                   "span": {
                     "start": 44,
@@ -197,7 +222,10 @@
                       {
                         "key": {
                           "kind": {
-                            "String": "y"
+                            "String": {
+                              "value": "y",
+                              "raw": "y"
+                            }
                           },
                           "span": {
                             "start": 52,
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "implode"
+                  "Name": {
+                    "parts": [
+                      "implode"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 13
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -22,7 +31,10 @@
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": ", "
+                      "String": {
+                        "value": ", ",
+                        "raw": ", "
+                      }
                     },
                     "span": {
                       "start": 14,
@@ -34,6 +46,10 @@
                   "span": {
                     "start": 14,
                     "end": 18
+                  },
+                  "comma": {
+                    "start": 18,
+                    "end": 19
                   }
                 },
                 {
@@ -43,7 +59,16 @@
                       "FunctionCall": {
                         "name": {
                           "kind": {
-                            "Identifier": "array_map"
+                            "Name": {
+                              "parts": [
+                                "array_map"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 20,
+                                "end": 29
+                              }
+                            }
                           },
                           "span": {
                             "start": 20,
@@ -55,7 +80,10 @@
                             "name": null,
                             "value": {
                               "kind": {
-                                "String": "strtoupper"
+                                "String": {
+                                  "value": "strtoupper",
+                                  "raw": "strtoupper"
+                                }
                               },
                               "span": {
                                 "start": 30,
@@ -67,6 +95,10 @@
                             "span": {
                               "start": 30,
                               "end": 42
+                            },
+                            "comma": {
+                              "start": 42,
+                              "end": 43
                             }
                           },
                           {
@@ -76,7 +108,16 @@
                                 "FunctionCall": {
                                   "name": {
                                     "kind": {
-                                      "Identifier": "explode"
+                                      "Name": {
+                                        "parts": [
+                                          "explode"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 44,
+                                          "end": 51
+                                        }
+                                      }
                                     },
                                     "span": {
                                       "start": 44,
@@ -88,7 +129,10 @@
                                       "name": null,
                                       "value": {
                                         "kind": {
-                                          "String": " "
+                                          "String": {
+                                            "value": " ",
+                                            "raw": " "
+                                          }
                                         },
                                         "span": {
                                           "start": 52,
@@ -100,6 +144,10 @@
                                       "span": {
                                         "start": 52,
                                         "end": 55
+                                      },
+                                      "comma": {
+                                        "start": 55,
+                                        "end": 56
                                       }
                                     },
                                     {
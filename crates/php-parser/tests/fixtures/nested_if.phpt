@@ -35,17 +35,19 @@
                           "Block": [
                             {
                               "kind": {
-                                "Echo": [
-                                  {
-                                    "kind": {
-                                      "Int": 1
-                                    },
-                                    "span": {
-                                      "start": 31,
-                                      "end": 32
+                                "Echo": {
+                                  "exprs": [
+                                    {
+                                      "kind": {
+                                        "Int": 1
+                                      },
+                                      "span": {
+                                        "start": 31,
+                                        "end": 32
+                                      }
                                     }
-                                  }
-                                ]
+                                  ]
+                                }
                               },
                               "span": {
                                 "start": 26,
@@ -14,95 +14,110 @@ Cannot mix [] and list()
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 13
+                        },
+                        "comma": {
+                          "start": 13,
+                          "end": 14
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 13
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Array": [
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "b"
-                                        },
-                                        "span": {
-                                          "start": 17,
-                                          "end": 19
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 17,
-                                        "end": 19
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "List": {
+                                        "elements": [
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "b"
+                                              },
+                                              "span": {
+                                                "start": 17,
+                                                "end": 19
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 17,
+                                              "end": 19
+                                            },
+                                            "comma": {
+                                              "start": 19,
+                                              "end": 20
+                                            }
+                                          },
+                                          {
+                                            "key": null,
+                                            "value": {
+                                              "kind": {
+                                                "Variable": "c"
+                                              },
+                                              "span": {
+                                                "start": 21,
+                                                "end": 23
+                                              }
+                                            },
+                                            "unpack": false,
+                                            "span": {
+                                              "start": 21,
+                                              "end": 23
+                                            }
+                                          }
+                                        ]
                                       }
                                     },
-                                    {
-                                      "key": null,
-                                      "value": {
-                                        "kind": {
-                                          "Variable": "c"
-                                        },
-                                        "span": {
-                                          "start": 21,
-                                          "end": 23
-                                        }
-                                      },
-                                      "unpack": false,
-                                      "span": {
-                                        "start": 21,
-                                        "end": 23
-                                      }
+                                    "span": {
+                                      "start": 16,
+                                      "end": 24
                                     }
-                                  ]
-                                },
-                                "span": {
-                                  "start": 16,
-                                  "end": 24
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 16,
+                                    "end": 24
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 16,
-                                "end": 24
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 15,
+                            "end": 25
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 15,
                           "end": 25
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 15,
-                        "end": 25
                       }
-                    }
-                  ]
+                    ],
+                    "uses_list": true
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -133,6 +148,10 @@ Cannot mix [] and list()
                               "span": {
                                 "start": 31,
                                 "end": 32
+                              },
+                              "comma": {
+                                "start": 32,
+                                "end": 33
                               }
                             },
                             {
@@ -155,6 +174,10 @@ Cannot mix [] and list()
                                       "span": {
                                         "start": 35,
                                         "end": 36
+                                      },
+                                      "comma": {
+                                        "start": 36,
+                                        "end": 37
                                       }
                                     },
                                     {
@@ -227,77 +250,89 @@ Cannot mix [] and list()
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "x"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "x"
+                          },
+                          "span": {
+                            "start": 45,
+                            "end": 47
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 45,
                           "end": 47
+                        },
+                        "comma": {
+                          "start": 47,
+                          "end": 48
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 45,
-                        "end": 47
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Array": [
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "y"
-                                },
-                                "span": {
-                                  "start": 50,
-                                  "end": 52
-                                }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 50,
-                                "end": 52
-                              }
-                            },
-                            {
-                              "key": null,
-                              "value": {
-                                "kind": {
-                                  "Variable": "z"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "List": {
+                              "elements": [
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "y"
+                                    },
+                                    "span": {
+                                      "start": 50,
+                                      "end": 52
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 50,
+                                    "end": 52
+                                  },
+                                  "comma": {
+                                    "start": 52,
+                                    "end": 53
+                                  }
                                 },
-                                "span": {
-                                  "start": 54,
-                                  "end": 56
+                                {
+                                  "key": null,
+                                  "value": {
+                                    "kind": {
+                                      "Variable": "z"
+                                    },
+                                    "span": {
+                                      "start": 54,
+                                      "end": 56
+                                    }
+                                  },
+                                  "unpack": false,
+                                  "span": {
+                                    "start": 54,
+                                    "end": 56
+                                  }
                                 }
-                              },
-                              "unpack": false,
-                              "span": {
-                                "start": 54,
-                                "end": 56
-                              }
+                              ]
                             }
-                          ]
+                          },
+                          "span": {
+                            "start": 49,
+                            "end": 57
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 49,
                           "end": 57
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 49,
-                        "end": 57
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 44,
@@ -44,7 +44,10 @@ $result = match ($type) {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 36,
@@ -69,7 +72,10 @@ $result = match ($type) {
                                   "conditions": [
                                     {
                                       "kind": {
-                                        "String": "x"
+                                        "String": {
+                                          "value": "x",
+                                          "raw": "x"
+                                        }
                                       },
                                       "span": {
                                         "start": 70,
@@ -89,13 +95,20 @@ $result = match ($type) {
                                   "span": {
                                     "start": 70,
                                     "end": 78
+                                  },
+                                  "comma": {
+                                    "start": 78,
+                                    "end": 79
                                   }
                                 },
                                 {
                                   "conditions": [
                                     {
                                       "kind": {
-                                        "String": "y"
+                                        "String": {
+                                          "value": "y",
+                                          "raw": "y"
+                                        }
                                       },
                                       "span": {
                                         "start": 88,
@@ -115,6 +128,10 @@ $result = match ($type) {
                                   "span": {
                                     "start": 88,
                                     "end": 96
+                                  },
+                                  "comma": {
+                                    "start": 96,
+                                    "end": 97
                                   }
                                 },
                                 {
@@ -131,6 +148,10 @@ $result = match ($type) {
                                   "span": {
                                     "start": 106,
                                     "end": 118
+                                  },
+                                  "comma": {
+                                    "start": 118,
+                                    "end": 119
                                   }
                                 }
                               ]
@@ -144,13 +165,20 @@ $result = match ($type) {
                         "span": {
                           "start": 36,
                           "end": 125
+                        },
+                        "comma": {
+                          "start": 125,
+                          "end": 126
                         }
                       },
                       {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 131,
@@ -170,6 +198,10 @@ $result = match ($type) {
                         "span": {
                           "start": 131,
                           "end": 140
+                        },
+                        "comma": {
+                          "start": 140,
+                          "end": 141
                         }
                       },
                       {
@@ -197,6 +229,10 @@ $result = match ($type) {
                         "span": {
                           "start": 146,
                           "end": 159
+                        },
+                        "comma": {
+                          "start": 159,
+                          "end": 160
                         }
                       }
                     ]
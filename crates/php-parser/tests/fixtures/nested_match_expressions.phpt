@@ -44,7 +44,10 @@ $result = match ($type) {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "a"
+                              "String": {
+                                "value": "a",
+                                "raw": "a"
+                              }
                             },
                             "span": {
                               "start": 36,
@@ -79,7 +82,10 @@ $result = match ($type) {
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "a1"
+                                      "String": {
+                                        "value": "a1",
+                                        "raw": "a1"
+                                      }
                                     },
                                     "span": {
                                       "start": 75,
@@ -89,6 +95,10 @@ $result = match ($type) {
                                   "span": {
                                     "start": 70,
                                     "end": 79
+                                  },
+                                  "comma": {
+                                    "start": 79,
+                                    "end": 80
                                   }
                                 },
                                 {
@@ -105,7 +115,10 @@ $result = match ($type) {
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "a2"
+                                      "String": {
+                                        "value": "a2",
+                                        "raw": "a2"
+                                      }
                                     },
                                     "span": {
                                       "start": 94,
@@ -115,13 +128,20 @@ $result = match ($type) {
                                   "span": {
                                     "start": 89,
                                     "end": 98
+                                  },
+                                  "comma": {
+                                    "start": 98,
+                                    "end": 99
                                   }
                                 },
                                 {
                                   "conditions": null,
                                   "body": {
                                     "kind": {
-                                      "String": "a_other"
+                                      "String": {
+                                        "value": "a_other",
+                                        "raw": "a_other"
+                                      }
                                     },
                                     "span": {
                                       "start": 119,
@@ -131,6 +151,10 @@ $result = match ($type) {
                                   "span": {
                                     "start": 108,
                                     "end": 128
+                                  },
+                                  "comma": {
+                                    "start": 128,
+                                    "end": 129
                                   }
                                 }
                               ]
@@ -144,13 +168,20 @@ $result = match ($type) {
                         "span": {
                           "start": 36,
                           "end": 135
+                        },
+                        "comma": {
+                          "start": 135,
+                          "end": 136
                         }
                       },
                       {
                         "conditions": [
                           {
                             "kind": {
-                              "String": "b"
+                              "String": {
+                                "value": "b",
+                                "raw": "b"
+                              }
                             },
                             "span": {
                               "start": 141,
@@ -160,7 +191,10 @@ $result = match ($type) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "b"
+                            "String": {
+                              "value": "b",
+                              "raw": "b"
+                            }
                           },
                           "span": {
                             "start": 148,
@@ -170,13 +204,20 @@ $result = match ($type) {
                         "span": {
                           "start": 141,
                           "end": 151
+                        },
+                        "comma": {
+                          "start": 151,
+                          "end": 152
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "other"
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
                           },
                           "span": {
                             "start": 168,
@@ -186,6 +227,10 @@ $result = match ($type) {
                         "span": {
                           "start": 157,
                           "end": 175
+                        },
+                        "comma": {
+                          "start": 175,
+                          "end": 176
                         }
                       }
                     ]
@@ -39,7 +39,10 @@
               },
               "then_expr": {
                 "kind": {
-                  "String": "pos"
+                  "String": {
+                    "value": "pos",
+                    "raw": "pos"
+                  }
                 },
                 "span": {
                   "start": 15,
@@ -82,7 +85,10 @@
                         },
                         "then_expr": {
                           "kind": {
-                            "String": "neg"
+                            "String": {
+                              "value": "neg",
+                              "raw": "neg"
+                            }
                           },
                           "span": {
                             "start": 33,
@@ -91,7 +97,10 @@
                         },
                         "else_expr": {
                           "kind": {
-                            "String": "zero"
+                            "String": {
+                              "value": "zero",
+                              "raw": "zero"
+                            }
                           },
                           "span": {
                             "start": 41,
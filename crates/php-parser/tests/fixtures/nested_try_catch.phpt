@@ -28,7 +28,16 @@ try {
                             "FunctionCall": {
                               "name": {
                                 "kind": {
-                                  "Identifier": "dangerousOp"
+                                  "Name": {
+                                    "parts": [
+                                      "dangerousOp"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 30,
+                                      "end": 41
+                                    }
+                                  }
                                 },
                                 "span": {
                                   "start": 30,
@@ -54,17 +63,25 @@ try {
                     {
                       "types": [
                         {
-                          "parts": [
-                            "InnerException"
-                          ],
-                          "kind": "Unqualified",
-                          "span": {
-                            "start": 58,
-                            "end": 72
+                          "Name": {
+                            "parts": [
+                              "InnerException"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 58,
+                              "end": 72
+                            }
                           }
                         }
                       ],
-                      "var": "e",
+                      "var": {
+                        "name": "e",
+                        "span": {
+                          "start": 73,
+                          "end": 75
+                        }
+                      },
                       "body": [
                         {
                           "kind": {
@@ -73,7 +90,16 @@ try {
                                 "FunctionCall": {
                                   "name": {
                                     "kind": {
-                                      "Identifier": "log"
+                                      "Name": {
+                                        "parts": [
+                                          "log"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 87,
+                                          "end": 90
+                                        }
+                                      }
                                     },
                                     "span": {
                                       "start": 87,
@@ -150,17 +176,25 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "OuterException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 129,
-                    "end": 143
+                  "Name": {
+                    "parts": [
+                      "OuterException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 129,
+                      "end": 143
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 144,
+                  "end": 146
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -169,7 +203,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handleOuter"
+                              "Name": {
+                                "parts": [
+                                  "handleOuter"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 154,
+                                  "end": 165
+                                }
+                              }
                             },
                             "span": {
                               "start": 154,
@@ -9,12 +9,15 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 10,
-                  "end": 13
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 10,
+                    "end": 13
+                  }
                 }
               },
               "args": []
@@ -37,12 +40,14 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Variable": "className"
-                },
-                "span": {
-                  "start": 21,
-                  "end": 31
+                "Expr": {
+                  "kind": {
+                    "Variable": "className"
+                  },
+                  "span": {
+                    "start": 21,
+                    "end": 31
+                  }
                 }
               },
               "args": []
@@ -65,10 +70,7 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "self"
-                },
-                "span": {
+                "SelfKw": {
                   "start": 39,
                   "end": 43
                 }
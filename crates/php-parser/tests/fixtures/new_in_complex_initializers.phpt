@@ -25,12 +25,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 33,
-                        "end": 36
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 33,
+                          "end": 36
+                        }
                       }
                     },
                     "args": []
@@ -51,6 +54,10 @@ New expressions are not supported in this context
               "span": {
                 "start": 24,
                 "end": 38
+              },
+              "comma": {
+                "start": 38,
+                "end": 39
               }
             },
             {
@@ -60,12 +67,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Bar"
-                      },
-                      "span": {
-                        "start": 53,
-                        "end": 56
+                      "Name": {
+                        "parts": [
+                          "Bar"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 53,
+                          "end": 56
+                        }
                       }
                     },
                     "args": [
@@ -85,13 +95,20 @@ New expressions are not supported in this context
                         "span": {
                           "start": 57,
                           "end": 58
+                        },
+                        "comma": {
+                          "start": 58,
+                          "end": 59
                         }
                       },
                       {
                         "name": null,
                         "value": {
                           "kind": {
-                            "String": "test"
+                            "String": {
+                              "value": "test",
+                              "raw": "test"
+                            }
                           },
                           "span": {
                             "start": 60,
@@ -123,6 +140,10 @@ New expressions are not supported in this context
               "span": {
                 "start": 44,
                 "end": 67
+              },
+              "comma": {
+                "start": 67,
+                "end": 68
               }
             },
             {
@@ -132,12 +153,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Baz"
-                      },
-                      "span": {
-                        "start": 82,
-                        "end": 85
+                      "Name": {
+                        "parts": [
+                          "Baz"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 82,
+                          "end": 85
+                        }
                       }
                     },
                     "args": [
@@ -152,9 +176,16 @@ New expressions are not supported in this context
                             "end": 90
                           }
                         },
+                        "colon": {
+                          "start": 90,
+                          "end": 91
+                        },
                         "value": {
                           "kind": {
-                            "String": "x"
+                            "String": {
+                              "value": "x",
+                              "raw": "x"
+                            }
                           },
                           "span": {
                             "start": 92,
@@ -186,6 +217,10 @@ New expressions are not supported in this context
               "span": {
                 "start": 73,
                 "end": 96
+              },
+              "comma": {
+                "start": 96,
+                "end": 97
               }
             }
           ],
@@ -222,12 +257,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Settings"
-                          },
-                          "span": {
-                            "start": 142,
-                            "end": 150
+                          "Name": {
+                            "parts": [
+                              "Settings"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 142,
+                              "end": 150
+                            }
                           }
                         },
                         "args": [
@@ -242,6 +280,10 @@ New expressions are not supported in this context
                                 "end": 156
                               }
                             },
+                            "colon": {
+                              "start": 156,
+                              "end": 157
+                            },
                             "value": {
                               "kind": {
                                 "Bool": false
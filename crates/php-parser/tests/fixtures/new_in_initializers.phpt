@@ -37,12 +37,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 28,
-                        "end": 31
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 28,
+                          "end": 31
+                        }
                       }
                     },
                     "args": []
@@ -63,6 +66,10 @@ New expressions are not supported in this context
               "span": {
                 "start": 19,
                 "end": 33
+              },
+              "comma": {
+                "start": 33,
+                "end": 34
               }
             },
             {
@@ -72,12 +79,15 @@ New expressions are not supported in this context
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Bar"
-                      },
-                      "span": {
-                        "start": 44,
-                        "end": 47
+                      "Name": {
+                        "parts": [
+                          "Bar"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 44,
+                          "end": 47
+                        }
                       }
                     },
                     "args": [
@@ -97,6 +107,10 @@ New expressions are not supported in this context
                         "span": {
                           "start": 48,
                           "end": 49
+                        },
+                        "comma": {
+                          "start": 49,
+                          "end": 50
                         }
                       },
                       {
@@ -174,12 +188,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "DefaultObj"
-                          },
-                          "span": {
-                            "start": 98,
-                            "end": 108
+                          "Name": {
+                            "parts": [
+                              "DefaultObj"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 98,
+                              "end": 108
+                            }
                           }
                         },
                         "args": []
@@ -208,12 +225,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Config"
-                          },
-                          "span": {
-                            "start": 130,
-                            "end": 136
+                          "Name": {
+                            "parts": [
+                              "Config"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 130,
+                              "end": 136
+                            }
                           }
                         },
                         "args": []
@@ -249,12 +269,15 @@ New expressions are not supported in this context
                         "kind": {
                           "New": {
                             "class": {
-                              "kind": {
-                                "Identifier": "Param"
-                              },
-                              "span": {
-                                "start": 177,
-                                "end": 182
+                              "Name": {
+                                "parts": [
+                                  "Param"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 177,
+                                  "end": 182
+                                }
                               }
                             },
                             "args": []
@@ -312,12 +335,15 @@ New expressions are not supported in this context
                       "kind": {
                         "New": {
                           "class": {
-                            "kind": {
-                              "Identifier": "Cache"
-                            },
-                            "span": {
-                              "start": 233,
-                              "end": 238
+                            "Name": {
+                              "parts": [
+                                "Cache"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 233,
+                                "end": 238
+                              }
                             }
                           },
                           "args": []
@@ -378,12 +404,15 @@ New expressions are not supported in this context
                     "kind": {
                       "New": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 256,
-                            "end": 259
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 256,
+                              "end": 259
+                            }
                           }
                         },
                         "args": []
@@ -443,12 +472,15 @@ New expressions are not supported in this context
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "Foo"
-                                },
-                                "span": {
-                                  "start": 307,
-                                  "end": 310
+                                "Name": {
+                                  "parts": [
+                                    "Foo"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 307,
+                                    "end": 310
+                                  }
                                 }
                               },
                               "args": []
@@ -524,12 +556,15 @@ New expressions are not supported in this context
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "Foo"
-                                },
-                                "span": {
-                                  "start": 335,
-                                  "end": 338
+                                "Name": {
+                                  "parts": [
+                                    "Foo"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 335,
+                                    "end": 338
+                                  }
                                 }
                               },
                               "args": []
@@ -11,23 +11,25 @@ min_php=8.3
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "AnonymousClass": {
-                    "name": null,
-                    "modifiers": {
-                      "is_abstract": false,
-                      "is_final": false,
-                      "is_readonly": true
-                    },
-                    "extends": null,
-                    "implements": [],
-                    "members": [],
-                    "attributes": []
+                "Expr": {
+                  "kind": {
+                    "AnonymousClass": {
+                      "name": null,
+                      "modifiers": {
+                        "is_abstract": false,
+                        "is_final": false,
+                        "is_readonly": true
+                      },
+                      "extends": null,
+                      "implements": [],
+                      "members": [],
+                      "attributes": []
+                    }
+                  },
+                  "span": {
+                    "start": 6,
+                    "end": 27
                   }
-                },
-                "span": {
-                  "start": 6,
-                  "end": 27
                 }
               },
               "args": []
@@ -22,12 +22,15 @@
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 17,
-                        "end": 20
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 17,
+                          "end": 20
+                        }
                       }
                     },
                     "args": []
@@ -70,12 +73,16 @@
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Bar\\Baz"
-                      },
-                      "span": {
-                        "start": 33,
-                        "end": 40
+                      "Name": {
+                        "parts": [
+                          "Bar",
+                          "Baz"
+                        ],
+                        "kind": "Qualified",
+                        "span": {
+                          "start": 33,
+                          "end": 40
+                        }
                       }
                     },
                     "args": []
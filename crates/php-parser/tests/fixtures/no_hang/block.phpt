@@ -20,7 +20,9 @@
               "Block": [
                 {
                   "kind": {
-                    "InlineHtml": " "
+                    "InlineHtml": {
+                      "text": " "
+                    }
                   },
                   "span": {
                     "start": 20,
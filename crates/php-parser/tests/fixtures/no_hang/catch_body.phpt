@@ -11,21 +11,31 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 20,
-                    "end": 29
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 20,
+                      "end": 29
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 30,
+                  "end": 32
+                }
+              },
               "body": [
                 {
                   "kind": {
-                    "InlineHtml": " "
+                    "InlineHtml": {
+                      "text": " "
+                    }
                   },
                   "span": {
                     "start": 38,
@@ -29,7 +29,9 @@
                     "body": [
                       {
                         "kind": {
-                          "InlineHtml": " "
+                          "InlineHtml": {
+                            "text": " "
+                          }
                         },
                         "span": {
                           "start": 26,
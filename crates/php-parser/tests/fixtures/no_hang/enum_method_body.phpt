@@ -39,7 +39,9 @@ min_php=8.1
                   "body": [
                     {
                       "kind": {
-                        "InlineHtml": " "
+                        "InlineHtml": {
+                          "text": " "
+                        }
                       },
                       "span": {
                         "start": 47,
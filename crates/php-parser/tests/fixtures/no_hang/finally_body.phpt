@@ -11,7 +11,9 @@
           "finally": [
             {
               "kind": {
-                "InlineHtml": " "
+                "InlineHtml": {
+                  "text": " "
+                }
               },
               "span": {
                 "start": 25,
@@ -11,7 +11,9 @@
           "body": [
             {
               "kind": {
-                "InlineHtml": " "
+                "InlineHtml": {
+                  "text": " "
+                }
               },
               "span": {
                 "start": 23,
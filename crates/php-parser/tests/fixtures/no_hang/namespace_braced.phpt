@@ -20,7 +20,9 @@
             "Braced": [
               {
                 "kind": {
-                  "InlineHtml": " "
+                  "InlineHtml": {
+                    "text": " "
+                  }
                 },
                 "span": {
                   "start": 24,
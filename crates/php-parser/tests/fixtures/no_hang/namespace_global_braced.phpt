@@ -11,7 +11,9 @@
             "Braced": [
               {
                 "kind": {
-                  "InlineHtml": " "
+                  "InlineHtml": {
+                    "text": " "
+                  }
                 },
                 "span": {
                   "start": 20,
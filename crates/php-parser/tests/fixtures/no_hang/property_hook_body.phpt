@@ -52,7 +52,9 @@ min_php=8.4
                         "Block": [
                           {
                             "kind": {
-                              "InlineHtml": " "
+                              "InlineHtml": {
+                                "text": " "
+                              }
                             },
                             "span": {
                               "start": 43,
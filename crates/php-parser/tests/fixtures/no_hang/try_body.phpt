@@ -9,7 +9,9 @@
           "body": [
             {
               "kind": {
-                "InlineHtml": " "
+                "InlineHtml": {
+                  "text": " "
+                }
               },
               "span": {
                 "start": 14,
@@ -28,17 +30,25 @@
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 30,
-                    "end": 39
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 30,
+                      "end": 39
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 40,
+                  "end": 42
+                }
+              },
               "body": [],
               "span": {
                 "start": 29,
@@ -1,11 +1,11 @@
 ===source===
 <?php cl[p1$x{`
 ===errors===
-unterminated string literal
 expected ']', found variable
 expected ';' after expression
+array and string offset access with curly braces was removed in PHP 8.0 (targeting PHP 8.5)
+unterminated string literal
 expected '}', found end of file
-Array and string offset access syntax with curly braces is no longer supported
 expected ';' after expression
 ===ast===
 {
@@ -17,7 +17,16 @@ expected ';' after expression
             "ArrayAccess": {
               "array": {
                 "kind": {
-                  "Identifier": "cl"
+                  "ConstFetch": {
+                    "parts": [
+                      "cl"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 8
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -26,7 +35,16 @@ expected ';' after expression
               },
               "index": {
                 "kind": {
-                  "Identifier": "p1"
+                  "ConstFetch": {
+                    "parts": [
+                      "p1"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 9,
+                      "end": 11
+                    }
+                  }
                 },
                 "span": {
                   "start": 9,
@@ -64,7 +82,13 @@ expected ';' after expression
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": ""
+                      "Literal": {
+                        "value": "",
+                        "span": {
+                          "start": 15,
+                          "end": 15
+                        }
+                      }
                     }
                   ]
                 },
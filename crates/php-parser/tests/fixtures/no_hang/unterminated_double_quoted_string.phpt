@@ -23,7 +23,10 @@ expected ';' after expression
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": ""
+                  "String": {
+                    "value": "",
+                    "raw": ""
+                  }
                 },
                 "span": {
                   "start": 11,
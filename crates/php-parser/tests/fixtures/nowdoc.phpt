@@ -24,6 +24,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "Hello $name!",
                     "value": "Hello $name!"
                   }
                 },
@@ -42,6 +42,7 @@ $r = match(true) {
                           "kind": {
                             "Nowdoc": {
                               "label": "NOW",
+                              "raw": "    literal",
                               "value": "literal"
                             }
                           },
@@ -53,6 +54,10 @@ $r = match(true) {
                         "span": {
                           "start": 29,
                           "end": 68
+                        },
+                        "comma": {
+                          "start": 68,
+                          "end": 69
                         }
                       }
                     ]
@@ -25,6 +25,7 @@ $y = <<<'NOW'
                 "kind": {
                   "Nowdoc": {
                     "label": "NOW",
+                    "raw": "    nowdoc content",
                     "value": "nowdoc content"
                   }
                 },
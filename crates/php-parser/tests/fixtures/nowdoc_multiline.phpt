@@ -27,6 +27,7 @@ EOT;
                 "kind": {
                   "Nowdoc": {
                     "label": "EOT",
+                    "raw": "No $interpolation\nJust literal text\nWith 'quotes' and \"doubles\"",
                     "value": "No $interpolation\nJust literal text\nWith 'quotes' and \"doubles\""
                   }
                 },
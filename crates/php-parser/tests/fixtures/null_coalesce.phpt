@@ -31,7 +31,10 @@
                     },
                     "right": {
                       "kind": {
-                        "String": "fallback"
+                        "String": {
+                          "value": "fallback",
+                          "raw": "fallback"
+                        }
                       },
                       "span": {
                         "start": 18,
@@ -12,57 +12,67 @@ Assignments can only happen to writable values
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 7,
+                            "end": 9
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 7,
                           "end": 9
+                        },
+                        "comma": {
+                          "start": 9,
+                          "end": 10
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 7,
-                        "end": 9
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": "Null",
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": "Null",
+                          "span": {
+                            "start": 11,
+                            "end": 15
+                          }
+                        },
+                        "unpack": false,
                         "span": {
                           "start": 11,
                           "end": 15
+                        },
+                        "comma": {
+                          "start": 15,
+                          "end": 16
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 11,
-                        "end": 15
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "c"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "c"
+                          },
+                          "span": {
+                            "start": 17,
+                            "end": 19
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 17,
                           "end": 19
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 17,
-                        "end": 19
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 6,
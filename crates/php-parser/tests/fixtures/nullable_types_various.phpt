@@ -50,6 +50,10 @@ function process(?string $name, ?int $count, ?array $items): ?bool {
               "span": {
                 "start": 23,
                 "end": 36
+              },
+              "comma": {
+                "start": 36,
+                "end": 37
               }
             },
             {
@@ -91,6 +95,10 @@ function process(?string $name, ?int $count, ?array $items): ?bool {
               "span": {
                 "start": 38,
                 "end": 49
+              },
+              "comma": {
+                "start": 49,
+                "end": 50
               }
             },
             {
@@ -304,8 +304,8 @@ $m = $a && $b or $c;
                       "Binary": {
                         "left": {
                           "kind": {
-                            "Binary": {
-                              "left": {
+                            "Instanceof": {
+                              "expr": {
                                 "kind": {
                                   "Variable": "x"
                                 },
@@ -314,14 +314,16 @@ $m = $a && $b or $c;
                                   "end": 196
                                 }
                               },
-                              "op": "Instanceof",
-                              "right": {
-                                "kind": {
-                                  "Identifier": "A"
-                                },
-                                "span": {
-                                  "start": 208,
-                                  "end": 209
+                              "class": {
+                                "Name": {
+                                  "parts": [
+                                    "A"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 208,
+                                    "end": 209
+                                  }
                                 }
                               }
                             }
@@ -334,7 +336,16 @@ $m = $a && $b or $c;
                         "op": "BitwiseOr",
                         "right": {
                           "kind": {
-                            "Identifier": "B"
+                            "ConstFetch": {
+                              "parts": [
+                                "B"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 212,
+                                "end": 213
+                              }
+                            }
                           },
                           "span": {
                             "start": 212,
@@ -387,8 +398,8 @@ $m = $a && $b or $c;
                   "Binary": {
                     "left": {
                       "kind": {
-                        "Binary": {
-                          "left": {
+                        "Instanceof": {
+                          "expr": {
                             "kind": {
                               "Variable": "x"
                             },
@@ -397,42 +408,61 @@ $m = $a && $b or $c;
                               "end": 223
                             }
                           },
-                          "op": "Instanceof",
-                          "right": {
-                            "kind": {
-                              "Parenthesized": {
-                                "kind": {
-                                  "Binary": {
-                                    "left": {
-                                      "kind": {
-                                        "Identifier": "int"
-                                      },
-                                      "span": {
-                                        "start": 236,
-                                        "end": 239
-                                      }
-                                    },
-                                    "op": "BitwiseAnd",
-                                    "right": {
-                                      "kind": {
-                                        "Identifier": "string"
+                          "class": {
+                            "Expr": {
+                              "kind": {
+                                "Parenthesized": {
+                                  "kind": {
+                                    "Binary": {
+                                      "left": {
+                                        "kind": {
+                                          "ConstFetch": {
+                                            "parts": [
+                                              "int"
+                                            ],
+                                            "kind": "Unqualified",
+                                            "span": {
+                                              "start": 236,
+                                              "end": 239
+                                            }
+                                          }
+                                        },
+                                        "span": {
+                                          "start": 236,
+                                          "end": 239
+                                        }
                                       },
-                                      "span": {
-                                        "start": 242,
-                                        "end": 248
+                                      "op": "BitwiseAnd",
+                                      "right": {
+                                        "kind": {
+                                          "ConstFetch": {
+                                            "parts": [
+                                              "string"
+                                            ],
+                                            "kind": "Unqualified",
+                                            "span": {
+                                              "start": 242,
+                                              "end": 248
+                                            }
+                                          }
+                                        },
+                                        "span": {
+                                          "start": 242,
+                                          "end": 248
+                                        }
                                       }
                                     }
+                                  },
+                                  "span": {
+                                    "start": 236,
+                                    "end": 248
                                   }
-                                },
-                                "span": {
-                                  "start": 236,
-                                  "end": 248
                                 }
+                              },
+                              "span": {
+                                "start": 235,
+                                "end": 249
                               }
-                            },
-                            "span": {
-                              "start": 235,
-                              "end": 249
                             }
                           }
                         }
@@ -445,7 +475,16 @@ $m = $a && $b or $c;
                     "op": "BitwiseOr",
                     "right": {
                       "kind": {
-                        "Identifier": "bool"
+                        "ConstFetch": {
+                          "parts": [
+                            "bool"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 252,
+                            "end": 256
+                          }
+                        }
                       },
                       "span": {
                         "start": 252,
@@ -490,40 +529,46 @@ $m = $a && $b or $c;
               "value": {
                 "kind": {
                   "ErrorSuppress": {
-                    "kind": {
-                      "Parenthesized": {
-                        "kind": {
-                          "Binary": {
-                            "left": {
-                              "kind": {
-                                "Variable": "x"
-                              },
-                              "span": {
-                                "start": 309,
-                                "end": 311
-                              }
-                            },
-                            "op": "Add",
-                            "right": {
-                              "kind": {
-                                "Variable": "y"
+                    "expr": {
+                      "kind": {
+                        "Parenthesized": {
+                          "kind": {
+                            "Binary": {
+                              "left": {
+                                "kind": {
+                                  "Variable": "x"
+                                },
+                                "span": {
+                                  "start": 309,
+                                  "end": 311
+                                }
                               },
-                              "span": {
-                                "start": 314,
-                                "end": 316
+                              "op": "Add",
+                              "right": {
+                                "kind": {
+                                  "Variable": "y"
+                                },
+                                "span": {
+                                  "start": 314,
+                                  "end": 316
+                                }
                               }
                             }
+                          },
+                          "span": {
+                            "start": 309,
+                            "end": 316
                           }
-                        },
-                        "span": {
-                          "start": 309,
-                          "end": 316
                         }
+                      },
+                      "span": {
+                        "start": 308,
+                        "end": 317
                       }
                     },
-                    "span": {
-                      "start": 308,
-                      "end": 317
+                    "operator_span": {
+                      "start": 307,
+                      "end": 308
                     }
                   }
                 },
@@ -566,12 +611,18 @@ $m = $a && $b or $c;
                     "left": {
                       "kind": {
                         "ErrorSuppress": {
-                          "kind": {
-                            "Variable": "x"
+                          "expr": {
+                            "kind": {
+                              "Variable": "x"
+                            },
+                            "span": {
+                              "start": 325,
+                              "end": 327
+                            }
                           },
-                          "span": {
-                            "start": 325,
-                            "end": 327
+                          "operator_span": {
+                            "start": 324,
+                            "end": 325
                           }
                         }
                       },
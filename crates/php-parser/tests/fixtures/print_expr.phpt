@@ -8,12 +8,21 @@
         "Expression": {
           "kind": {
             "Print": {
-              "kind": {
-                "String": "hello"
+              "expr": {
+                "kind": {
+                  "String": {
+                    "value": "hello",
+                    "raw": "hello"
+                  }
+                },
+                "span": {
+                  "start": 12,
+                  "end": 19
+                }
               },
-              "span": {
-                "start": 12,
-                "end": 19
+              "keyword_span": {
+                "start": 6,
+                "end": 11
               }
             }
           },
@@ -21,12 +21,21 @@
               "value": {
                 "kind": {
                   "Print": {
-                    "kind": {
-                      "String": "hello"
+                    "expr": {
+                      "kind": {
+                        "String": {
+                          "value": "hello",
+                          "raw": "hello"
+                        }
+                      },
+                      "span": {
+                        "start": 17,
+                        "end": 24
+                      }
                     },
-                    "span": {
-                      "start": 17,
-                      "end": 24
+                    "keyword_span": {
+                      "start": 11,
+                      "end": 16
                     }
                   }
                 },
@@ -54,12 +63,21 @@
           "condition": {
             "kind": {
               "Print": {
-                "kind": {
-                  "String": "check"
+                "expr": {
+                  "kind": {
+                    "String": {
+                      "value": "check",
+                      "raw": "check"
+                    }
+                  },
+                  "span": {
+                    "start": 36,
+                    "end": 43
+                  }
                 },
-                "span": {
-                  "start": 36,
-                  "end": 43
+                "keyword_span": {
+                  "start": 30,
+                  "end": 35
                 }
               }
             },
@@ -24,12 +24,17 @@ App\Helpers\format($data);
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "App\\Models\\User"
-                      },
-                      "span": {
-                        "start": 18,
-                        "end": 33
+                      "Name": {
+                        "parts": [
+                          "App",
+                          "Models",
+                          "User"
+                        ],
+                        "kind": "Qualified",
+                        "span": {
+                          "start": 18,
+                          "end": 33
+                        }
                       }
                     },
                     "args": []
@@ -60,7 +65,18 @@ App\Helpers\format($data);
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "App\\Helpers\\format"
+                  "Name": {
+                    "parts": [
+                      "App",
+                      "Helpers",
+                      "format"
+                    ],
+                    "kind": "Qualified",
+                    "span": {
+                      "start": 37,
+                      "end": 55
+                    }
+                  }
                 },
                 "span": {
                   "start": 37,
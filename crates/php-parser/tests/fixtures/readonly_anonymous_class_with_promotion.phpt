@@ -30,111 +30,121 @@ $obj = new readonly class(1, 2) {
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "AnonymousClass": {
-                          "name": null,
-                          "modifiers": {
-                            "is_abstract": false,
-                            "is_final": false,
-                            "is_readonly": true
-                          },
-                          "extends": null,
-                          "implements": [],
-                          "members": [
-                            {
-                              "kind": {
-                                "Method": {
-                                  "name": "__construct",
-                                  "visibility": "Public",
-                                  "is_static": false,
-                                  "is_abstract": false,
-                                  "is_final": false,
-                                  "by_ref": false,
-                                  "params": [
-                                    {
-                                      "name": "x",
-                                      "type_hint": {
-                                        "kind": {
-                                          "Named": {
-                                            "parts": [
-                                              "int"
-                                            ],
-                                            "kind": "Unqualified",
-                                            "span": {
-                                              "start": 88,
-                                              "end": 91
+                      "Expr": {
+                        "kind": {
+                          "AnonymousClass": {
+                            "name": null,
+                            "modifiers": {
+                              "is_abstract": false,
+                              "is_final": false,
+                              "is_readonly": true
+                            },
+                            "extends": null,
+                            "implements": [],
+                            "members": [
+                              {
+                                "kind": {
+                                  "Method": {
+                                    "name": "__construct",
+                                    "visibility": "Public",
+                                    "is_static": false,
+                                    "is_abstract": false,
+                                    "is_final": false,
+                                    "by_ref": false,
+                                    "params": [
+                                      {
+                                        "name": "x",
+                                        "type_hint": {
+                                          "kind": {
+                                            "Named": {
+                                              "parts": [
+                                                "int"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 88,
+                                                "end": 91
+                                              }
                                             }
+                                          },
+                                          "span": {
+                                            "start": 88,
+                                            "end": 91
                                           }
                                         },
+                                        "default": null,
+                                        "by_ref": false,
+                                        "variadic": false,
+                                        "is_readonly": false,
+                                        "is_final": false,
+                                        "visibility": "Public",
+                                        "set_visibility": null,
+                                        "attributes": [],
                                         "span": {
-                                          "start": 88,
-                                          "end": 91
+                                          "start": 81,
+                                          "end": 94
+                                        },
+                                        "comma": {
+                                          "start": 94,
+                                          "end": 95
                                         }
                                       },
-                                      "default": null,
-                                      "by_ref": false,
-                                      "variadic": false,
-                                      "is_readonly": false,
-                                      "is_final": false,
-                                      "visibility": "Public",
-                                      "set_visibility": null,
-                                      "attributes": [],
-                                      "span": {
-                                        "start": 81,
-                                        "end": 94
-                                      }
-                                    },
-                                    {
-                                      "name": "y",
-                                      "type_hint": {
-                                        "kind": {
-                                          "Named": {
-                                            "parts": [
-                                              "int"
-                                            ],
-                                            "kind": "Unqualified",
-                                            "span": {
-                                              "start": 111,
-                                              "end": 114
+                                      {
+                                        "name": "y",
+                                        "type_hint": {
+                                          "kind": {
+                                            "Named": {
+                                              "parts": [
+                                                "int"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 111,
+                                                "end": 114
+                                              }
                                             }
+                                          },
+                                          "span": {
+                                            "start": 111,
+                                            "end": 114
                                           }
                                         },
+                                        "default": null,
+                                        "by_ref": false,
+                                        "variadic": false,
+                                        "is_readonly": false,
+                                        "is_final": false,
+                                        "visibility": "Public",
+                                        "set_visibility": null,
+                                        "attributes": [],
                                         "span": {
-                                          "start": 111,
-                                          "end": 114
+                                          "start": 104,
+                                          "end": 117
+                                        },
+                                        "comma": {
+                                          "start": 117,
+                                          "end": 118
                                         }
-                                      },
-                                      "default": null,
-                                      "by_ref": false,
-                                      "variadic": false,
-                                      "is_readonly": false,
-                                      "is_final": false,
-                                      "visibility": "Public",
-                                      "set_visibility": null,
-                                      "attributes": [],
-                                      "span": {
-                                        "start": 104,
-                                        "end": 117
                                       }
-                                    }
-                                  ],
-                                  "return_type": null,
-                                  "body": [],
-                                  "attributes": []
+                                    ],
+                                    "return_type": null,
+                                    "body": [],
+                                    "attributes": []
+                                  }
+                                },
+                                "span": {
+                                  "start": 44,
+                                  "end": 127
                                 }
-                              },
-                              "span": {
-                                "start": 44,
-                                "end": 127
                               }
-                            }
-                          ],
-                          "attributes": []
+                            ],
+                            "attributes": []
+                          }
+                        },
+                        "span": {
+                          "start": 13,
+                          "end": 129
                         }
-                      },
-                      "span": {
-                        "start": 13,
-                        "end": 129
                       }
                     },
                     "args": [
@@ -154,6 +164,10 @@ $obj = new readonly class(1, 2) {
                         "span": {
                           "start": 32,
                           "end": 33
+                        },
+                        "comma": {
+                          "start": 33,
+                          "end": 34
                         }
                       },
                       {
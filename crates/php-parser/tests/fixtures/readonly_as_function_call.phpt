@@ -26,7 +26,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "readonly"
+                  "Name": {
+                    "parts": [
+                      "readonly"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 29,
+                      "end": 37
+                    }
+                  }
                 },
                 "span": {
                   "start": 29,
@@ -65,6 +65,10 @@ readonly class Point {
                       "span": {
                         "start": 70,
                         "end": 85
+                      },
+                      "comma": {
+                        "start": 85,
+                        "end": 86
                       }
                     },
                     {
@@ -98,6 +102,10 @@ readonly class Point {
                       "span": {
                         "start": 95,
                         "end": 110
+                      },
+                      "comma": {
+                        "start": 110,
+                        "end": 111
                       }
                     },
                     {
@@ -139,6 +147,10 @@ readonly class Point {
                       "span": {
                         "start": 120,
                         "end": 141
+                      },
+                      "comma": {
+                        "start": 141,
+                        "end": 142
                       }
                     }
                   ],
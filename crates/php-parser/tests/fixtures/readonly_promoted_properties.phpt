@@ -65,6 +65,10 @@ readonly class Point {
                       "span": {
                         "start": 70,
                         "end": 83
+                      },
+                      "comma": {
+                        "start": 83,
+                        "end": 84
                       }
                     },
                     {
@@ -98,6 +102,10 @@ readonly class Point {
                       "span": {
                         "start": 93,
                         "end": 106
+                      },
+                      "comma": {
+                        "start": 106,
+                        "end": 107
                       }
                     },
                     {
@@ -139,6 +147,10 @@ readonly class Point {
                       "span": {
                         "start": 116,
                         "end": 133
+                      },
+                      "comma": {
+                        "start": 133,
+                        "end": 134
                       }
                     }
                   ],
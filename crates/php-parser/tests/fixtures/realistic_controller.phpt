@@ -402,12 +402,15 @@ class UserController extends BaseController implements JsonResponder
                                               "kind": {
                                                 "StaticMethodCall": {
                                                   "class": {
-                                                    "kind": {
-                                                      "Identifier": "User"
-                                                    },
-                                                    "span": {
-                                                      "start": 380,
-                                                      "end": 384
+                                                    "Name": {
+                                                      "parts": [
+                                                        "User"
+                                                      ],
+                                                      "kind": "Unqualified",
+                                                      "span": {
+                                                        "start": 380,
+                                                        "end": 384
+                                                      }
                                                     }
                                                   },
                                                   "method": {
@@ -452,12 +455,15 @@ class UserController extends BaseController implements JsonResponder
                                                   "kind": {
                                                     "New": {
                                                       "class": {
-                                                        "kind": {
-                                                          "Identifier": "NotFoundException"
-                                                        },
-                                                        "span": {
-                                                          "start": 409,
-                                                          "end": 426
+                                                        "Name": {
+                                                          "parts": [
+                                                            "NotFoundException"
+                                                          ],
+                                                          "kind": "Unqualified",
+                                                          "span": {
+                                                            "start": 409,
+                                                            "end": 426
+                                                          }
                                                         }
                                                       },
                                                       "args": [
@@ -465,7 +471,10 @@ class UserController extends BaseController implements JsonResponder
                                                           "name": null,
                                                           "value": {
                                                             "kind": {
-                                                              "String": "User not found"
+                                                              "String": {
+                                                                "value": "User not found",
+                                                                "raw": "User not found"
+                                                              }
                                                             },
                                                             "span": {
                                                               "start": 427,
@@ -564,7 +573,10 @@ class UserController extends BaseController implements JsonResponder
                                                 "conditions": [
                                                   {
                                                     "kind": {
-                                                      "String": "admin"
+                                                      "String": {
+                                                        "value": "admin",
+                                                        "raw": "admin"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 504,
@@ -574,7 +586,10 @@ class UserController extends BaseController implements JsonResponder
                                                 ],
                                                 "body": {
                                                   "kind": {
-                                                    "String": "Administrator"
+                                                    "String": {
+                                                      "value": "Administrator",
+                                                      "raw": "Administrator"
+                                                    }
                                                   },
                                                   "span": {
                                                     "start": 515,
@@ -584,13 +599,20 @@ class UserController extends BaseController implements JsonResponder
                                                 "span": {
                                                   "start": 504,
                                                   "end": 530
+                                                },
+                                                "comma": {
+                                                  "start": 530,
+                                                  "end": 531
                                                 }
                                               },
                                               {
                                                 "conditions": [
                                                   {
                                                     "kind": {
-                                                      "String": "editor"
+                                                      "String": {
+                                                        "value": "editor",
+                                                        "raw": "editor"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 548,
@@ -600,7 +622,10 @@ class UserController extends BaseController implements JsonResponder
                                                 ],
                                                 "body": {
                                                   "kind": {
-                                                    "String": "Editor"
+                                                    "String": {
+                                                      "value": "Editor",
+                                                      "raw": "Editor"
+                                                    }
                                                   },
                                                   "span": {
                                                     "start": 560,
@@ -610,13 +635,20 @@ class UserController extends BaseController implements JsonResponder
                                                 "span": {
                                                   "start": 548,
                                                   "end": 568
+                                                },
+                                                "comma": {
+                                                  "start": 568,
+                                                  "end": 569
                                                 }
                                               },
                                               {
                                                 "conditions": null,
                                                 "body": {
                                                   "kind": {
-                                                    "String": "User"
+                                                    "String": {
+                                                      "value": "User",
+                                                      "raw": "User"
+                                                    }
                                                   },
                                                   "span": {
                                                     "start": 597,
@@ -626,6 +658,10 @@ class UserController extends BaseController implements JsonResponder
                                                 "span": {
                                                   "start": 586,
                                                   "end": 603
+                                                },
+                                                "comma": {
+                                                  "start": 603,
+                                                  "end": 604
                                                 }
                                               }
                                             ]
@@ -670,7 +706,10 @@ class UserController extends BaseController implements JsonResponder
                                             {
                                               "key": {
                                                 "kind": {
-                                                  "String": "name"
+                                                  "String": {
+                                                    "value": "name",
+                                                    "raw": "name"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 658,
@@ -709,12 +748,19 @@ class UserController extends BaseController implements JsonResponder
                                               "span": {
                                                 "start": 658,
                                                 "end": 679
+                                              },
+                                              "comma": {
+                                                "start": 679,
+                                                "end": 680
                                               }
                                             },
                                             {
                                               "key": {
                                                 "kind": {
-                                                  "String": "email"
+                                                  "String": {
+                                                    "value": "email",
+                                                    "raw": "email"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 697,
@@ -753,12 +799,19 @@ class UserController extends BaseController implements JsonResponder
                                               "span": {
                                                 "start": 697,
                                                 "end": 721
+                                              },
+                                              "comma": {
+                                                "start": 721,
+                                                "end": 722
                                               }
                                             },
                                             {
                                               "key": {
                                                 "kind": {
-                                                  "String": "role"
+                                                  "String": {
+                                                    "value": "role",
+                                                    "raw": "role"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 739,
@@ -778,6 +831,10 @@ class UserController extends BaseController implements JsonResponder
                                               "span": {
                                                 "start": 739,
                                                 "end": 754
+                                              },
+                                              "comma": {
+                                                "start": 754,
+                                                "end": 755
                                               }
                                             }
                                           ]
@@ -822,17 +879,25 @@ class UserController extends BaseController implements JsonResponder
                             {
                               "types": [
                                 {
-                                  "parts": [
-                                    "NotFoundException"
-                                  ],
-                                  "kind": "Unqualified",
-                                  "span": {
-                                    "start": 814,
-                                    "end": 831
+                                  "Name": {
+                                    "parts": [
+                                      "NotFoundException"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 814,
+                                      "end": 831
+                                    }
                                   }
                                 }
                               ],
-                              "var": "e",
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 832,
+                                  "end": 834
+                                }
+                              },
                               "body": [
                                 {
                                   "kind": {
@@ -842,7 +907,10 @@ class UserController extends BaseController implements JsonResponder
                                           {
                                             "key": {
                                               "kind": {
-                                                "String": "error"
+                                                "String": {
+                                                  "value": "error",
+                                                  "raw": "error"
+                                                }
                                               },
                                               "span": {
                                                 "start": 858,
@@ -1045,12 +1113,15 @@ class UserController extends BaseController implements JsonResponder
                                 "kind": {
                                   "StaticMethodCall": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "User"
-                                      },
-                                      "span": {
-                                        "start": 1023,
-                                        "end": 1027
+                                      "Name": {
+                                        "parts": [
+                                          "User"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 1023,
+                                          "end": 1027
+                                        }
                                       }
                                     },
                                     "method": {
@@ -1103,7 +1174,16 @@ class UserController extends BaseController implements JsonResponder
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "array_map"
+                                        "Name": {
+                                          "parts": [
+                                            "array_map"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 1053,
+                                            "end": 1062
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 1053,
@@ -1179,6 +1259,10 @@ class UserController extends BaseController implements JsonResponder
                                         "span": {
                                           "start": 1063,
                                           "end": 1086
+                                        },
+                                        "comma": {
+                                          "start": 1086,
+                                          "end": 1087
                                         }
                                       },
                                       {
@@ -1240,7 +1324,16 @@ class UserController extends BaseController implements JsonResponder
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "array_filter"
+                                        "Name": {
+                                          "parts": [
+                                            "array_filter"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 1117,
+                                            "end": 1129
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 1117,
@@ -1264,6 +1357,10 @@ class UserController extends BaseController implements JsonResponder
                                         "span": {
                                           "start": 1130,
                                           "end": 1136
+                                        },
+                                        "comma": {
+                                          "start": 1136,
+                                          "end": 1137
                                         }
                                       },
                                       {
@@ -1313,7 +1410,16 @@ class UserController extends BaseController implements JsonResponder
                                                               "FunctionCall": {
                                                                 "name": {
                                                                   "kind": {
-                                                                    "Identifier": "strlen"
+                                                                    "Name": {
+                                                                      "parts": [
+                                                                        "strlen"
+                                                                      ],
+                                                                      "kind": "Unqualified",
+                                                                      "span": {
+                                                                        "start": 1188,
+                                                                        "end": 1194
+                                                                      }
+                                                                    }
                                                                   },
                                                                   "span": {
                                                                     "start": 1188,
@@ -221,12 +221,15 @@ class TaskService
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Priority"
-                                            },
-                                            "span": {
-                                              "start": 216,
-                                              "end": 224
+                                            "Name": {
+                                              "parts": [
+                                                "Priority"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 216,
+                                                "end": 224
+                                              }
                                             }
                                           },
                                           "member": {
@@ -248,7 +251,10 @@ class TaskService
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "Low Priority"
+                                      "String": {
+                                        "value": "Low Priority",
+                                        "raw": "Low Priority"
+                                      }
                                     },
                                     "span": {
                                       "start": 233,
@@ -258,6 +264,10 @@ class TaskService
                                   "span": {
                                     "start": 216,
                                     "end": 247
+                                  },
+                                  "comma": {
+                                    "start": 247,
+                                    "end": 248
                                   }
                                 },
                                 {
@@ -266,12 +276,15 @@ class TaskService
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Priority"
-                                            },
-                                            "span": {
-                                              "start": 261,
-                                              "end": 269
+                                            "Name": {
+                                              "parts": [
+                                                "Priority"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 261,
+                                                "end": 269
+                                              }
                                             }
                                           },
                                           "member": {
@@ -293,7 +306,10 @@ class TaskService
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "Medium Priority"
+                                      "String": {
+                                        "value": "Medium Priority",
+                                        "raw": "Medium Priority"
+                                      }
                                     },
                                     "span": {
                                       "start": 281,
@@ -303,6 +319,10 @@ class TaskService
                                   "span": {
                                     "start": 261,
                                     "end": 298
+                                  },
+                                  "comma": {
+                                    "start": 298,
+                                    "end": 299
                                   }
                                 },
                                 {
@@ -311,12 +331,15 @@ class TaskService
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Priority"
-                                            },
-                                            "span": {
-                                              "start": 312,
-                                              "end": 320
+                                            "Name": {
+                                              "parts": [
+                                                "Priority"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 312,
+                                                "end": 320
+                                              }
                                             }
                                           },
                                           "member": {
@@ -338,7 +361,10 @@ class TaskService
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "High Priority"
+                                      "String": {
+                                        "value": "High Priority",
+                                        "raw": "High Priority"
+                                      }
                                     },
                                     "span": {
                                       "start": 330,
@@ -348,6 +374,10 @@ class TaskService
                                   "span": {
                                     "start": 312,
                                     "end": 345
+                                  },
+                                  "comma": {
+                                    "start": 345,
+                                    "end": 346
                                   }
                                 },
                                 {
@@ -356,12 +386,15 @@ class TaskService
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Priority"
-                                            },
-                                            "span": {
-                                              "start": 359,
-                                              "end": 367
+                                            "Name": {
+                                              "parts": [
+                                                "Priority"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 359,
+                                                "end": 367
+                                              }
                                             }
                                           },
                                           "member": {
@@ -383,7 +416,10 @@ class TaskService
                                   ],
                                   "body": {
                                     "kind": {
-                                      "String": "Critical Priority"
+                                      "String": {
+                                        "value": "Critical Priority",
+                                        "raw": "Critical Priority"
+                                      }
                                     },
                                     "span": {
                                       "start": 381,
@@ -393,6 +429,10 @@ class TaskService
                                   "span": {
                                     "start": 359,
                                     "end": 400
+                                  },
+                                  "comma": {
+                                    "start": 400,
+                                    "end": 401
                                   }
                                 }
                               ]
@@ -511,10 +551,7 @@ class TaskService
                           "kind": {
                             "ClassConstAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "static"
-                                },
-                                "span": {
+                                "StaticKw": {
                                   "start": 527,
                                   "end": 533
                                 }
@@ -655,6 +692,10 @@ class TaskService
                       "span": {
                         "start": 646,
                         "end": 659
+                      },
+                      "comma": {
+                        "start": 659,
+                        "end": 660
                       }
                     },
                     {
@@ -719,10 +760,7 @@ class TaskService
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 702,
                                         "end": 706
                                       }
@@ -776,10 +814,7 @@ class TaskService
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 734,
                                         "end": 738
                                       }
@@ -865,6 +900,10 @@ class TaskService
                                         "span": {
                                           "start": 781,
                                           "end": 790
+                                        },
+                                        "comma": {
+                                          "start": 790,
+                                          "end": 791
                                         }
                                       },
                                       {
@@ -937,7 +976,10 @@ class TaskService
                                                 "op": "Concat",
                                                 "right": {
                                                   "kind": {
-                                                    "String": "#"
+                                                    "String": {
+                                                      "value": "#",
+                                                      "raw": "#"
+                                                    }
                                                   },
                                                   "span": {
                                                     "start": 816,
@@ -1033,6 +1075,10 @@ class TaskService
                                         "span": {
                                           "start": 858,
                                           "end": 864
+                                        },
+                                        "comma": {
+                                          "start": 864,
+                                          "end": 865
                                         }
                                       },
                                       {
@@ -1082,7 +1128,10 @@ class TaskService
                               {
                                 "key": {
                                   "kind": {
-                                    "String": "id"
+                                    "String": {
+                                      "value": "id",
+                                      "raw": "id"
+                                    }
                                   },
                                   "span": {
                                     "start": 901,
@@ -1102,12 +1151,19 @@ class TaskService
                                 "span": {
                                   "start": 901,
                                   "end": 912
+                                },
+                                "comma": {
+                                  "start": 912,
+                                  "end": 913
                                 }
                               },
                               {
                                 "key": {
                                   "kind": {
-                                    "String": "title"
+                                    "String": {
+                                      "value": "title",
+                                      "raw": "title"
+                                    }
                                   },
                                   "span": {
                                     "start": 926,
@@ -1127,12 +1183,19 @@ class TaskService
                                 "span": {
                                   "start": 926,
                                   "end": 947
+                                },
+                                "comma": {
+                                  "start": 947,
+                                  "end": 948
                                 }
                               },
                               {
                                 "key": {
                                   "kind": {
-                                    "String": "priority"
+                                    "String": {
+                                      "value": "priority",
+                                      "raw": "priority"
+                                    }
                                   },
                                   "span": {
                                     "start": 961,
@@ -1172,12 +1235,19 @@ class TaskService
                                 "span": {
                                   "start": 961,
                                   "end": 993
+                                },
+                                "comma": {
+                                  "start": 993,
+                                  "end": 994
                                 }
                               },
                               {
                                 "key": {
                                   "kind": {
-                                    "String": "is_urgent"
+                                    "String": {
+                                      "value": "is_urgent",
+                                      "raw": "is_urgent"
+                                    }
                                   },
                                   "span": {
                                     "start": 1007,
@@ -1204,12 +1274,15 @@ class TaskService
                                               "kind": {
                                                 "ClassConstAccess": {
                                                   "class": {
-                                                    "kind": {
-                                                      "Identifier": "Priority"
-                                                    },
-                                                    "span": {
-                                                      "start": 1036,
-                                                      "end": 1044
+                                                    "Name": {
+                                                      "parts": [
+                                                        "Priority"
+                                                      ],
+                                                      "kind": "Unqualified",
+                                                      "span": {
+                                                        "start": 1036,
+                                                        "end": 1044
+                                                      }
                                                     }
                                                   },
                                                   "member": {
@@ -1253,12 +1326,15 @@ class TaskService
                                               "kind": {
                                                 "ClassConstAccess": {
                                                   "class": {
-                                                    "kind": {
-                                                      "Identifier": "Priority"
-                                                    },
-                                                    "span": {
-                                                      "start": 1072,
-                                                      "end": 1080
+                                                    "Name": {
+                                                      "parts": [
+                                                        "Priority"
+                                                      ],
+                                                      "kind": "Unqualified",
+                                                      "span": {
+                                                        "start": 1072,
+                                                        "end": 1080
+                                                      }
                                                     }
                                                   },
                                                   "member": {
@@ -1295,6 +1371,10 @@ class TaskService
                                 "span": {
                                   "start": 1007,
                                   "end": 1086
+                                },
+                                "comma": {
+                                  "start": 1086,
+                                  "end": 1087
                                 }
                               }
                             ]
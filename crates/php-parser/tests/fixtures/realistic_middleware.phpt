@@ -281,6 +281,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                       "span": {
                         "start": 198,
                         "end": 214
+                      },
+                      "comma": {
+                        "start": 214,
+                        "end": 215
                       }
                     },
                     {
@@ -606,6 +610,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                       "span": {
                         "start": 533,
                         "end": 549
+                      },
+                      "comma": {
+                        "start": 549,
+                        "end": 550
                       }
                     },
                     {
@@ -725,10 +733,7 @@ class RateLimitMiddleware implements MiddlewareInterface
                             "kind": {
                               "StaticPropertyAccess": {
                                 "class": {
-                                  "kind": {
-                                    "Identifier": "self"
-                                  },
-                                  "span": {
+                                  "SelfKw": {
                                     "start": 638,
                                     "end": 642
                                   }
@@ -918,7 +923,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                               "name": null,
                                               "value": {
                                                 "kind": {
-                                                  "String": "Authorization"
+                                                  "String": {
+                                                    "value": "Authorization",
+                                                    "raw": "Authorization"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 816,
@@ -942,7 +950,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                     },
                                     "right": {
                                       "kind": {
-                                        "String": ""
+                                        "String": {
+                                          "value": "",
+                                          "raw": ""
+                                        }
                                       },
                                       "span": {
                                         "start": 836,
@@ -989,7 +1000,16 @@ class RateLimitMiddleware implements MiddlewareInterface
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "str_replace"
+                                        "Name": {
+                                          "parts": [
+                                            "str_replace"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 857,
+                                            "end": 868
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 857,
@@ -1001,7 +1021,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                         "name": null,
                                         "value": {
                                           "kind": {
-                                            "String": "Bearer "
+                                            "String": {
+                                              "value": "Bearer ",
+                                              "raw": "Bearer "
+                                            }
                                           },
                                           "span": {
                                             "start": 869,
@@ -1013,13 +1036,20 @@ class RateLimitMiddleware implements MiddlewareInterface
                                         "span": {
                                           "start": 869,
                                           "end": 878
+                                        },
+                                        "comma": {
+                                          "start": 878,
+                                          "end": 879
                                         }
                                       },
                                       {
                                         "name": null,
                                         "value": {
                                           "kind": {
-                                            "String": ""
+                                            "String": {
+                                              "value": "",
+                                              "raw": ""
+                                            }
                                           },
                                           "span": {
                                             "start": 880,
@@ -1031,6 +1061,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                         "span": {
                                           "start": 880,
                                           "end": 882
+                                        },
+                                        "comma": {
+                                          "start": 882,
+                                          "end": 883
                                         }
                                       },
                                       {
@@ -1101,12 +1135,15 @@ class RateLimitMiddleware implements MiddlewareInterface
                                       "kind": {
                                         "New": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Response"
-                                            },
-                                            "span": {
-                                              "start": 946,
-                                              "end": 954
+                                            "Name": {
+                                              "parts": [
+                                                "Response"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 946,
+                                                "end": 954
+                                              }
                                             }
                                           },
                                           "args": [
@@ -1126,6 +1163,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                               "span": {
                                                 "start": 955,
                                                 "end": 958
+                                              },
+                                              "comma": {
+                                                "start": 958,
+                                                "end": 959
                                               }
                                             },
                                             {
@@ -1136,7 +1177,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                     {
                                                       "key": {
                                                         "kind": {
-                                                          "String": "error"
+                                                          "String": {
+                                                            "value": "error",
+                                                            "raw": "error"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 961,
@@ -1145,7 +1189,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                       },
                                                       "value": {
                                                         "kind": {
-                                                          "String": "Missing token"
+                                                          "String": {
+                                                            "value": "Missing token",
+                                                            "raw": "Missing token"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 972,
@@ -1329,7 +1376,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                           "name": null,
                                           "value": {
                                             "kind": {
-                                              "String": "user_id"
+                                              "String": {
+                                                "value": "user_id",
+                                                "raw": "user_id"
+                                              }
                                             },
                                             "span": {
                                               "start": 1109,
@@ -1341,6 +1391,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                           "span": {
                                             "start": 1109,
                                             "end": 1118
+                                          },
+                                          "comma": {
+                                            "start": 1118,
+                                            "end": 1119
                                           }
                                         },
                                         {
@@ -1363,7 +1417,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                       },
                                                       "index": {
                                                         "kind": {
-                                                          "String": "sub"
+                                                          "String": {
+                                                            "value": "sub",
+                                                            "raw": "sub"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 1133,
@@ -1433,7 +1490,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                           "name": null,
                                           "value": {
                                             "kind": {
-                                              "String": "roles"
+                                              "String": {
+                                                "value": "roles",
+                                                "raw": "roles"
+                                              }
                                             },
                                             "span": {
                                               "start": 1177,
@@ -1445,6 +1505,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                           "span": {
                                             "start": 1177,
                                             "end": 1184
+                                          },
+                                          "comma": {
+                                            "start": 1184,
+                                            "end": 1185
                                           }
                                         },
                                         {
@@ -1466,7 +1530,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                       },
                                                       "index": {
                                                         "kind": {
-                                                          "String": "roles"
+                                                          "String": {
+                                                            "value": "roles",
+                                                            "raw": "roles"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 1194,
@@ -1522,17 +1589,25 @@ class RateLimitMiddleware implements MiddlewareInterface
                             {
                               "types": [
                                 {
-                                  "parts": [
-                                    "InvalidArgumentException"
-                                  ],
-                                  "kind": "FullyQualified",
-                                  "span": {
-                                    "start": 1228,
-                                    "end": 1253
+                                  "Name": {
+                                    "parts": [
+                                      "InvalidArgumentException"
+                                    ],
+                                    "kind": "FullyQualified",
+                                    "span": {
+                                      "start": 1228,
+                                      "end": 1253
+                                    }
                                   }
                                 }
                               ],
-                              "var": "e",
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 1254,
+                                  "end": 1256
+                                }
+                              },
                               "body": [
                                 {
                                   "kind": {
@@ -1540,12 +1615,15 @@ class RateLimitMiddleware implements MiddlewareInterface
                                       "kind": {
                                         "New": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Response"
-                                            },
-                                            "span": {
-                                              "start": 1283,
-                                              "end": 1291
+                                            "Name": {
+                                              "parts": [
+                                                "Response"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 1283,
+                                                "end": 1291
+                                              }
                                             }
                                           },
                                           "args": [
@@ -1565,6 +1643,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                               "span": {
                                                 "start": 1292,
                                                 "end": 1295
+                                              },
+                                              "comma": {
+                                                "start": 1295,
+                                                "end": 1296
                                               }
                                             },
                                             {
@@ -1575,7 +1657,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                     {
                                                       "key": {
                                                         "kind": {
-                                                          "String": "error"
+                                                          "String": {
+                                                            "value": "error",
+                                                            "raw": "error"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 1298,
@@ -1654,109 +1739,137 @@ class RateLimitMiddleware implements MiddlewareInterface
                             {
                               "types": [
                                 {
-                                  "parts": [
-                                    "RuntimeException"
-                                  ],
-                                  "kind": "FullyQualified",
-                                  "span": {
-                                    "start": 1346,
-                                    "end": 1363
+                                  "Name": {
+                                    "parts": [
+                                      "RuntimeException"
+                                    ],
+                                    "kind": "FullyQualified",
+                                    "span": {
+                                      "start": 1346,
+                                      "end": 1363
+                                    }
                                   }
                                 },
                                 {
-                                  "parts": [
-                                    "LogicException"
-                                  ],
-                                  "kind": "FullyQualified",
-                                  "span": {
-                                    "start": 1366,
-                                    "end": 1381
+                                  "Name": {
+                                    "parts": [
+                                      "LogicException"
+                                    ],
+                                    "kind": "FullyQualified",
+                                    "span": {
+                                      "start": 1366,
+                                      "end": 1381
+                                    }
                                   }
                                 }
                               ],
-                              "var": "e",
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 1382,
+                                  "end": 1384
+                                }
+                              },
                               "body": [
                                 {
                                   "kind": {
                                     "Expression": {
                                       "kind": {
                                         "ErrorSuppress": {
-                                          "kind": {
-                                            "FunctionCall": {
-                                              "name": {
-                                                "kind": {
-                                                  "Identifier": "error_log"
+                                          "expr": {
+                                            "kind": {
+                                              "FunctionCall": {
+                                                "name": {
+                                                  "kind": {
+                                                    "Name": {
+                                                      "parts": [
+                                                        "error_log"
+                                                      ],
+                                                      "kind": "Unqualified",
+                                                      "span": {
+                                                        "start": 1401,
+                                                        "end": 1410
+                                                      }
+                                                    }
+                                                  },
+                                                  "span": {
+                                                    "start": 1401,
+                                                    "end": 1410
+                                                  }
                                                 },
-                                                "span": {
-                                                  "start": 1401,
-                                                  "end": 1410
-                                                }
-                                              },
-                                              "args": [
-                                                {
-                                                  "name": null,
-                                                  "value": {
-                                                    "kind": {
-                                                      "Binary": {
-                                                        "left": {
-                                                          "kind": {
-                                                            "String": "Auth error: "
+                                                "args": [
+                                                  {
+                                                    "name": null,
+                                                    "value": {
+                                                      "kind": {
+                                                        "Binary": {
+                                                          "left": {
+                                                            "kind": {
+                                                              "String": {
+                                                                "value": "Auth error: ",
+                                                                "raw": "Auth error: "
+                                                              }
+                                                            },
+                                                            "span": {
+                                                              "start": 1411,
+                                                              "end": 1425
+                                                            }
                                                           },
-                                                          "span": {
-                                                            "start": 1411,
-                                                            "end": 1425
-                                                          }
-                                                        },
-                                                        "op": "Concat",
-                                                        "right": {
-                                                          "kind": {
-                                                            "MethodCall": {
-                                                              "object": {
-                                                                "kind": {
-                                                                  "Variable": "e"
+                                                          "op": "Concat",
+                                                          "right": {
+                                                            "kind": {
+                                                              "MethodCall": {
+                                                                "object": {
+                                                                  "kind": {
+                                                                    "Variable": "e"
+                                                                  },
+                                                                  "span": {
+                                                                    "start": 1428,
+                                                                    "end": 1430
+                                                                  }
                                                                 },
-                                                                "span": {
-                                                                  "start": 1428,
-                                                                  "end": 1430
-                                                                }
-                                                              },
-                                                              "method": {
-                                                                "kind": {
-                                                                  "Identifier": "getMessage"
+                                                                "method": {
+                                                                  "kind": {
+                                                                    "Identifier": "getMessage"
+                                                                  },
+                                                                  "span": {
+                                                                    "start": 1432,
+                                                                    "end": 1442
+                                                                  }
                                                                 },
-                                                                "span": {
-                                                                  "start": 1432,
-                                                                  "end": 1442
-                                                                }
-                                                              },
-                                                              "args": []
+                                                                "args": []
+                                                              }
+                                                            },
+                                                            "span": {
+                                                              "start": 1428,
+                                                              "end": 1444
                                                             }
-                                                          },
-                                                          "span": {
-                                                            "start": 1428,
-                                                            "end": 1444
                                                           }
                                                         }
+                                                      },
+                                                      "span": {
+                                                        "start": 1411,
+                                                        "end": 1444
                                                       }
                                                     },
+                                                    "unpack": false,
+                                                    "by_ref": false,
                                                     "span": {
                                                       "start": 1411,
                                                       "end": 1444
                                                     }
-                                                  },
-                                                  "unpack": false,
-                                                  "by_ref": false,
-                                                  "span": {
-                                                    "start": 1411,
-                                                    "end": 1444
                                                   }
-                                                }
-                                              ]
+                                                ]
+                                              }
+                                            },
+                                            "span": {
+                                              "start": 1401,
+                                              "end": 1445
                                             }
                                           },
-                                          "span": {
-                                            "start": 1401,
-                                            "end": 1445
+                                          "operator_span": {
+                                            "start": 1400,
+                                            "end": 1401
                                           }
                                         }
                                       },
@@ -1777,12 +1890,15 @@ class RateLimitMiddleware implements MiddlewareInterface
                                       "kind": {
                                         "New": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "Response"
-                                            },
-                                            "span": {
-                                              "start": 1470,
-                                              "end": 1478
+                                            "Name": {
+                                              "parts": [
+                                                "Response"
+                                              ],
+                                              "kind": "Unqualified",
+                                              "span": {
+                                                "start": 1470,
+                                                "end": 1478
+                                              }
                                             }
                                           },
                                           "args": [
@@ -1802,6 +1918,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                               "span": {
                                                 "start": 1479,
                                                 "end": 1482
+                                              },
+                                              "comma": {
+                                                "start": 1482,
+                                                "end": 1483
                                               }
                                             },
                                             {
@@ -1812,7 +1932,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                     {
                                                       "key": {
                                                         "kind": {
-                                                          "String": "error"
+                                                          "String": {
+                                                            "value": "error",
+                                                            "raw": "error"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 1485,
@@ -1821,7 +1944,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                       },
                                                       "value": {
                                                         "kind": {
-                                                          "String": "Internal error"
+                                                          "String": {
+                                                            "value": "Internal error",
+                                                            "raw": "Internal error"
+                                                          }
                                                         },
                                                         "span": {
                                                           "start": 1496,
@@ -2005,10 +2131,7 @@ class RateLimitMiddleware implements MiddlewareInterface
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 1639,
                                         "end": 1643
                                       }
@@ -2039,10 +2162,7 @@ class RateLimitMiddleware implements MiddlewareInterface
                                         "kind": {
                                           "StaticPropertyAccess": {
                                             "class": {
-                                              "kind": {
-                                                "Identifier": "self"
-                                              },
-                                              "span": {
+                                              "SelfKw": {
                                                 "start": 1666,
                                                 "end": 1670
                                               }
@@ -2067,6 +2187,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                       "span": {
                                         "start": 1663,
                                         "end": 1686
+                                      },
+                                      "comma": {
+                                        "start": 1686,
+                                        "end": 1687
                                       }
                                     },
                                     {
@@ -2234,6 +2358,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                       "span": {
                         "start": 1873,
                         "end": 1889
+                      },
+                      "comma": {
+                        "start": 1889,
+                        "end": 1890
                       }
                     },
                     {
@@ -2366,7 +2494,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                   "Binary": {
                                     "left": {
                                       "kind": {
-                                        "String": "rate:"
+                                        "String": {
+                                          "value": "rate:",
+                                          "raw": "rate:"
+                                        }
                                       },
                                       "span": {
                                         "start": 1971,
@@ -2501,10 +2632,7 @@ class RateLimitMiddleware implements MiddlewareInterface
                                       "kind": {
                                         "ClassConstAccess": {
                                           "class": {
-                                            "kind": {
-                                              "Identifier": "self"
-                                            },
-                                            "span": {
+                                            "SelfKw": {
                                               "start": 2048,
                                               "end": 2052
                                             }
@@ -2654,12 +2782,15 @@ class RateLimitMiddleware implements MiddlewareInterface
                                     "kind": {
                                       "New": {
                                         "class": {
-                                          "kind": {
-                                            "Identifier": "Response"
-                                          },
-                                          "span": {
-                                            "start": 2172,
-                                            "end": 2180
+                                          "Name": {
+                                            "parts": [
+                                              "Response"
+                                            ],
+                                            "kind": "Unqualified",
+                                            "span": {
+                                              "start": 2172,
+                                              "end": 2180
+                                            }
                                           }
                                         },
                                         "args": [
@@ -2679,6 +2810,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                             "span": {
                                               "start": 2181,
                                               "end": 2184
+                                            },
+                                            "comma": {
+                                              "start": 2184,
+                                              "end": 2185
                                             }
                                           },
                                           {
@@ -2689,7 +2824,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                   {
                                                     "key": {
                                                       "kind": {
-                                                        "String": "error"
+                                                        "String": {
+                                                          "value": "error",
+                                                          "raw": "error"
+                                                        }
                                                       },
                                                       "span": {
                                                         "start": 2187,
@@ -2698,7 +2836,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                                     },
                                                     "value": {
                                                       "kind": {
-                                                        "String": "Too many requests"
+                                                        "String": {
+                                                          "value": "Too many requests",
+                                                          "raw": "Too many requests"
+                                                        }
                                                       },
                                                       "span": {
                                                         "start": 2198,
@@ -2736,6 +2877,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                   "span": {
                                     "start": 2155,
                                     "end": 2219
+                                  },
+                                  "comma": {
+                                    "start": 2219,
+                                    "end": 2220
                                   }
                                 },
                                 {
@@ -2782,6 +2927,10 @@ class RateLimitMiddleware implements MiddlewareInterface
                                   "span": {
                                     "start": 2233,
                                     "end": 2259
+                                  },
+                                  "comma": {
+                                    "start": 2259,
+                                    "end": 2260
                                   }
                                 }
                               ]
@@ -883,7 +883,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                             "Binary": {
                                               "left": {
                                                 "kind": {
-                                                  "String": "SELECT COUNT(*) FROM "
+                                                  "String": {
+                                                    "value": "SELECT COUNT(*) FROM ",
+                                                    "raw": "SELECT COUNT(*) FROM "
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 694,
@@ -1184,7 +1187,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                       "kind": {
                         "Return": {
                           "kind": {
-                            "String": "users"
+                            "String": {
+                              "value": "users",
+                              "raw": "users"
+                            }
                           },
                           "span": {
                             "start": 1030,
@@ -1275,12 +1281,15 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "User"
-                                },
-                                "span": {
-                                  "start": 1120,
-                                  "end": 1124
+                                "Name": {
+                                  "parts": [
+                                    "User"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 1120,
+                                    "end": 1124
+                                  }
                                 }
                               },
                               "args": [
@@ -1295,6 +1304,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                       "end": 1140
                                     }
                                   },
+                                  "colon": {
+                                    "start": 1140,
+                                    "end": 1141
+                                  },
                                   "value": {
                                     "kind": {
                                       "Cast": [
@@ -1313,7 +1326,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                               },
                                               "index": {
                                                 "kind": {
-                                                  "String": "id"
+                                                  "String": {
+                                                    "value": "id",
+                                                    "raw": "id"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 1152,
@@ -1339,6 +1355,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "span": {
                                     "start": 1138,
                                     "end": 1157
+                                  },
+                                  "comma": {
+                                    "start": 1157,
+                                    "end": 1158
                                   }
                                 },
                                 {
@@ -1352,6 +1372,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                       "end": 1175
                                     }
                                   },
+                                  "colon": {
+                                    "start": 1175,
+                                    "end": 1176
+                                  },
                                   "value": {
                                     "kind": {
                                       "ArrayAccess": {
@@ -1366,7 +1390,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                         },
                                         "index": {
                                           "kind": {
-                                            "String": "name"
+                                            "String": {
+                                              "value": "name",
+                                              "raw": "name"
+                                            }
                                           },
                                           "span": {
                                             "start": 1182,
@@ -1385,6 +1412,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "span": {
                                     "start": 1171,
                                     "end": 1189
+                                  },
+                                  "comma": {
+                                    "start": 1189,
+                                    "end": 1190
                                   }
                                 },
                                 {
@@ -1398,6 +1429,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                       "end": 1208
                                     }
                                   },
+                                  "colon": {
+                                    "start": 1208,
+                                    "end": 1209
+                                  },
                                   "value": {
                                     "kind": {
                                       "NullCoalesce": {
@@ -1415,7 +1450,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                               },
                                               "index": {
                                                 "kind": {
-                                                  "String": "email"
+                                                  "String": {
+                                                    "value": "email",
+                                                    "raw": "email"
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 1215,
@@ -1448,6 +1486,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "span": {
                                     "start": 1203,
                                     "end": 1231
+                                  },
+                                  "comma": {
+                                    "start": 1231,
+                                    "end": 1232
                                   }
                                 }
                               ]
@@ -1553,10 +1595,7 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 1306,
                                         "end": 1310
                                       }
@@ -1651,7 +1690,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                         "name": null,
                                         "value": {
                                           "kind": {
-                                            "String": "SELECT * FROM users WHERE id = ?"
+                                            "String": {
+                                              "value": "SELECT * FROM users WHERE id = ?",
+                                              "raw": "SELECT * FROM users WHERE id = ?"
+                                            }
                                           },
                                           "span": {
                                             "start": 1360,
@@ -1863,10 +1905,7 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                 "kind": {
                                   "StaticPropertyAccess": {
                                     "class": {
-                                      "kind": {
-                                        "Identifier": "self"
-                                      },
-                                      "span": {
+                                      "SelfKw": {
                                         "start": 1521,
                                         "end": 1525
                                       }
@@ -1964,7 +2003,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                             "Binary": {
                                               "left": {
                                                 "kind": {
-                                                  "String": "SELECT * FROM "
+                                                  "String": {
+                                                    "value": "SELECT * FROM ",
+                                                    "raw": "SELECT * FROM "
+                                                  }
                                                 },
                                                 "span": {
                                                   "start": 1575,
@@ -2043,7 +2085,16 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                             "FunctionCall": {
                               "name": {
                                 "kind": {
-                                  "Identifier": "array_map"
+                                  "Name": {
+                                    "parts": [
+                                      "array_map"
+                                    ],
+                                    "kind": "Unqualified",
+                                    "span": {
+                                      "start": 1630,
+                                      "end": 1639
+                                    }
+                                  }
                                 },
                                 "span": {
                                   "start": 1630,
@@ -2155,6 +2206,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "span": {
                                     "start": 1640,
                                     "end": 1678
+                                  },
+                                  "comma": {
+                                    "start": 1678,
+                                    "end": 1679
                                   }
                                 },
                                 {
@@ -2289,7 +2344,16 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "sanitize"
+                                        "Name": {
+                                          "parts": [
+                                            "sanitize"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 1776,
+                                            "end": 1784
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 1776,
@@ -2413,7 +2477,16 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "array_filter"
+                                        "Name": {
+                                          "parts": [
+                                            "array_filter"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 1849,
+                                            "end": 1861
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 1849,
@@ -2437,6 +2510,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                         "span": {
                                           "start": 1875,
                                           "end": 1881
+                                        },
+                                        "comma": {
+                                          "start": 1881,
+                                          "end": 1882
                                         }
                                       },
                                       {
@@ -2779,7 +2856,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                           "name": null,
                                           "value": {
                                             "kind": {
-                                              "String": "INSERT INTO users VALUES (?)"
+                                              "String": {
+                                                "value": "INSERT INTO users VALUES (?)",
+                                                "raw": "INSERT INTO users VALUES (?)"
+                                              }
                                             },
                                             "span": {
                                               "start": 2148,
@@ -2838,7 +2918,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                               "Binary": {
                                                 "left": {
                                                   "kind": {
-                                                    "String": "User saved: "
+                                                    "String": {
+                                                      "value": "User saved: ",
+                                                      "raw": "User saved: "
+                                                    }
                                                   },
                                                   "span": {
                                                     "start": 2204,
@@ -2907,17 +2990,25 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                             {
                               "types": [
                                 {
-                                  "parts": [
-                                    "PDOException"
-                                  ],
-                                  "kind": "FullyQualified",
-                                  "span": {
-                                    "start": 2252,
-                                    "end": 2265
+                                  "Name": {
+                                    "parts": [
+                                      "PDOException"
+                                    ],
+                                    "kind": "FullyQualified",
+                                    "span": {
+                                      "start": 2252,
+                                      "end": 2265
+                                    }
                                   }
                                 }
                               ],
-                              "var": "e",
+                              "var": {
+                                "name": "e",
+                                "span": {
+                                  "start": 2266,
+                                  "end": 2268
+                                }
+                              },
                               "body": [
                                 {
                                   "kind": {
@@ -2950,7 +3041,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                                   "Binary": {
                                                     "left": {
                                                       "kind": {
-                                                        "String": "Save failed: "
+                                                        "String": {
+                                                          "value": "Save failed: ",
+                                                          "raw": "Save failed: "
+                                                        }
                                                       },
                                                       "span": {
                                                         "start": 2295,
@@ -3191,12 +3285,15 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                           "kind": {
                                             "New": {
                                               "class": {
-                                                "kind": {
-                                                  "Identifier": "\\RuntimeException"
-                                                },
-                                                "span": {
-                                                  "start": 2465,
-                                                  "end": 2482
+                                                "Name": {
+                                                  "parts": [
+                                                    "RuntimeException"
+                                                  ],
+                                                  "kind": "FullyQualified",
+                                                  "span": {
+                                                    "start": 2465,
+                                                    "end": 2482
+                                                  }
                                                 }
                                               },
                                               "args": [
@@ -3204,7 +3301,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                                   "name": null,
                                                   "value": {
                                                     "kind": {
-                                                      "String": "User not found"
+                                                      "String": {
+                                                        "value": "User not found",
+                                                        "raw": "User not found"
+                                                      }
                                                     },
                                                     "span": {
                                                       "start": 2483,
@@ -3299,7 +3399,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                   "name": null,
                                   "value": {
                                     "kind": {
-                                      "String": "DELETE FROM users WHERE id = ?"
+                                      "String": {
+                                        "value": "DELETE FROM users WHERE id = ?",
+                                        "raw": "DELETE FROM users WHERE id = ?"
+                                      }
                                     },
                                     "span": {
                                       "start": 2527,
@@ -3358,7 +3461,10 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                                       "Binary": {
                                         "left": {
                                           "kind": {
-                                            "String": "User deleted: "
+                                            "String": {
+                                              "value": "User deleted: ",
+                                              "raw": "User deleted: "
+                                            }
                                           },
                                           "span": {
                                             "start": 2581,
@@ -3466,10 +3572,7 @@ class UserRepository extends AbstractRepository implements RepositoryInterface
                           "kind": {
                             "StaticPropertyAccess": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "self"
-                                },
-                                "span": {
+                                "SelfKw": {
                                   "start": 2690,
                                   "end": 2694
                                 }
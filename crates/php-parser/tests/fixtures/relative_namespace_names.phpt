@@ -48,12 +48,15 @@ echo namespace\SOME_CONST;
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "MyClass"
-                      },
-                      "span": {
-                        "start": 41,
-                        "end": 58
+                      "Name": {
+                        "parts": [
+                          "MyClass"
+                        ],
+                        "kind": "Relative",
+                        "span": {
+                          "start": 41,
+                          "end": 58
+                        }
                       }
                     },
                     "args": []
@@ -84,7 +87,16 @@ echo namespace\SOME_CONST;
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "namespace\\helper_func"
+                  "Name": {
+                    "parts": [
+                      "helper_func"
+                    ],
+                    "kind": "Relative",
+                    "span": {
+                      "start": 62,
+                      "end": 83
+                    }
+                  }
                 },
                 "span": {
                   "start": 62,
@@ -107,17 +119,28 @@ echo namespace\SOME_CONST;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "Identifier": "namespace\\SOME_CONST"
-            },
-            "span": {
-              "start": 92,
-              "end": 112
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "ConstFetch": {
+                  "parts": [
+                    "SOME_CONST"
+                  ],
+                  "kind": "Relative",
+                  "span": {
+                    "start": 92,
+                    "end": 112
+                  }
+                }
+              },
+              "span": {
+                "start": 92,
+                "end": 112
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 87,
@@ -143,10 +143,7 @@ class Builder {
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "static"
-                                },
-                                "span": {
+                                "StaticKw": {
                                   "start": 145,
                                   "end": 151
                                 }
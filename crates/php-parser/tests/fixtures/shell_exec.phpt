@@ -25,7 +25,13 @@ $complex = `{$obj->getCmd()} --flag`;
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": "ls -la"
+                      "Literal": {
+                        "value": "ls -la",
+                        "span": {
+                          "start": 14,
+                          "end": 20
+                        }
+                      }
                     }
                   ]
                 },
@@ -66,7 +72,13 @@ $complex = `{$obj->getCmd()} --flag`;
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": "echo "
+                      "Literal": {
+                        "value": "echo ",
+                        "span": {
+                          "start": 31,
+                          "end": 36
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -149,7 +161,13 @@ $complex = `{$obj->getCmd()} --flag`;
                       }
                     },
                     {
-                      "Literal": " --flag"
+                      "Literal": {
+                        "value": " --flag",
+                        "span": {
+                          "start": 71,
+                          "end": 78
+                        }
+                      }
                     }
                   ]
                 },
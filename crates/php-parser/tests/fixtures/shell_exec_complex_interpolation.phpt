@@ -25,7 +25,13 @@ $c = `echo $arr[0]`;
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": "ls "
+                      "Literal": {
+                        "value": "ls ",
+                        "span": {
+                          "start": 12,
+                          "end": 15
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -42,7 +48,10 @@ $c = `echo $arr[0]`;
                             },
                             "index": {
                               "kind": {
-                                "String": "home"
+                                "String": {
+                                  "value": "home",
+                                  "raw": "home"
+                                }
                               },
                               "span": {
                                 "start": 22,
@@ -127,7 +136,13 @@ $c = `echo $arr[0]`;
                       }
                     },
                     {
-                      "Literal": " --flag="
+                      "Literal": {
+                        "value": " --flag=",
+                        "span": {
+                          "start": 59,
+                          "end": 67
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -179,7 +194,13 @@ $c = `echo $arr[0]`;
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": "echo "
+                      "Literal": {
+                        "value": "echo ",
+                        "span": {
+                          "start": 82,
+                          "end": 87
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -22,7 +22,13 @@
                 "kind": {
                   "ShellExec": [
                     {
-                      "Literal": ""
+                      "Literal": {
+                        "value": "",
+                        "span": {
+                          "start": 12,
+                          "end": 12
+                        }
+                      }
                     }
                   ]
                 },
@@ -0,0 +1,122 @@
+===source===
+<p><?= $a, $b ?></p><?= $c; echo $d; ?>
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "<p>"
+        }
+      },
+      "span": {
+        "start": 0,
+        "end": 3
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "a"
+              },
+              "span": {
+                "start": 7,
+                "end": 9
+              }
+            },
+            {
+              "kind": {
+                "Variable": "b"
+              },
+              "span": {
+                "start": 11,
+                "end": 13
+              }
+            }
+          ],
+          "open_tag": {
+            "start": 3,
+            "end": 6
+          },
+          "close_tag": {
+            "start": 14,
+            "end": 16
+          }
+        }
+      },
+      "span": {
+        "start": 3,
+        "end": 13
+      }
+    },
+    {
+      "kind": {
+        "InlineHtml": {
+          "text": "</p>"
+        }
+      },
+      "span": {
+        "start": 16,
+        "end": 20
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "c"
+              },
+              "span": {
+                "start": 24,
+                "end": 26
+              }
+            }
+          ],
+          "open_tag": {
+            "start": 20,
+            "end": 23
+          }
+        }
+      },
+      "span": {
+        "start": 20,
+        "end": 27
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Variable": "d"
+              },
+              "span": {
+                "start": 33,
+                "end": 35
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 28,
+        "end": 36
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 36
+  },
+  "ends_in_html": true,
+  "close_tag": {
+    "start": 37,
+    "end": 39
+  }
+}
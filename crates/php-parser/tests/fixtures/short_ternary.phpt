@@ -20,7 +20,10 @@
               "then_expr": null,
               "else_expr": {
                 "kind": {
-                  "String": "default"
+                  "String": {
+                    "value": "default",
+                    "raw": "default"
+                  }
                 },
                 "span": {
                   "start": 12,
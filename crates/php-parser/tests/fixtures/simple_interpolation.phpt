@@ -5,32 +5,40 @@
   "stmts": [
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "InterpolatedString": [
-                {
-                  "Literal": "Hello "
-                },
-                {
-                  "Expr": {
-                    "kind": {
-                      "Variable": "name"
-                    },
-                    "span": {
-                      "start": 18,
-                      "end": 23
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "InterpolatedString": [
+                  {
+                    "Literal": {
+                      "value": "Hello ",
+                      "span": {
+                        "start": 12,
+                        "end": 18
+                      }
+                    }
+                  },
+                  {
+                    "Expr": {
+                      "kind": {
+                        "Variable": "name"
+                      },
+                      "span": {
+                        "start": 18,
+                        "end": 23
+                      }
                     }
                   }
-                }
-              ]
-            },
-            "span": {
-              "start": 11,
-              "end": 24
+                ]
+              },
+              "span": {
+                "start": 11,
+                "end": 24
+              }
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 6,
@@ -7,7 +7,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "a\\bc"
+            "String": {
+              "value": "a\\bc",
+              "raw": "a\\bc"
+            }
           },
           "span": {
             "start": 6,
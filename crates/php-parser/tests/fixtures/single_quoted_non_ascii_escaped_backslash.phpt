@@ -7,7 +7,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "naïve\\path"
+            "String": {
+              "value": "naïve\\path",
+              "raw": "naïve\\\\path"
+            }
           },
           "span": {
             "start": 6,
@@ -7,7 +7,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "hél'lo"
+            "String": {
+              "value": "hél'lo",
+              "raw": "hél\\'lo"
+            }
           },
           "span": {
             "start": 6,
@@ -7,7 +7,10 @@
   "stmts": [
     {
       "kind": {
-        "InlineHtml": "\n<div>html</div>\n"
+        "InlineHtml": {
+          "text": "\n<div>html</div>\n",
+          "newline_swallowed": true
+        }
       },
       "span": {
         "start": 19,
@@ -12,7 +12,16 @@ $merged = [...$a, ...$b, 1, 2];
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "call"
+                  "Name": {
+                    "parts": [
+                      "call"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -85,6 +94,10 @@ $merged = [...$a, ...$b, 1, 2];
                       "span": {
                         "start": 33,
                         "end": 38
+                      },
+                      "comma": {
+                        "start": 38,
+                        "end": 39
                       }
                     },
                     {
@@ -102,6 +115,10 @@ $merged = [...$a, ...$b, 1, 2];
                       "span": {
                         "start": 40,
                         "end": 45
+                      },
+                      "comma": {
+                        "start": 45,
+                        "end": 46
                       }
                     },
                     {
@@ -119,6 +136,10 @@ $merged = [...$a, ...$b, 1, 2];
                       "span": {
                         "start": 47,
                         "end": 48
+                      },
+                      "comma": {
+                        "start": 48,
+                        "end": 49
                       }
                     },
                     {
@@ -10,7 +10,16 @@
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "func"
+                  "Name": {
+                    "parts": [
+                      "func"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 6,
+                      "end": 10
+                    }
+                  }
                 },
                 "span": {
                   "start": 6,
@@ -34,6 +43,10 @@
                   "span": {
                     "start": 11,
                     "end": 19
+                  },
+                  "comma": {
+                    "start": 19,
+                    "end": 20
                   }
                 },
                 {
@@ -47,9 +60,16 @@
                       "end": 25
                     }
                   },
+                  "colon": {
+                    "start": 25,
+                    "end": 26
+                  },
                   "value": {
                     "kind": {
-                      "String": "end"
+                      "String": {
+                        "value": "end",
+                        "raw": "end"
+                      }
                     },
                     "span": {
                       "start": 27,
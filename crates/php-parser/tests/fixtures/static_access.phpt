@@ -15,12 +15,15 @@ static::factory();
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 6,
-                  "end": 9
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 6,
+                    "end": 9
+                  }
                 }
               },
               "member": {
@@ -51,12 +54,15 @@ static::factory();
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 16,
-                  "end": 19
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 16,
+                    "end": 19
+                  }
                 }
               },
               "member": {
@@ -87,12 +93,15 @@ static::factory();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 32,
-                  "end": 35
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 32,
+                    "end": 35
+                  }
                 }
               },
               "method": {
@@ -124,10 +133,7 @@ static::factory();
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "self"
-                },
-                "span": {
+                "SelfKw": {
                   "start": 47,
                   "end": 51
                 }
@@ -160,10 +166,7 @@ static::factory();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "parent"
-                },
-                "span": {
+                "ParentKw": {
                   "start": 57,
                   "end": 63
                 }
@@ -197,10 +200,7 @@ static::factory();
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 80,
                   "end": 86
                 }
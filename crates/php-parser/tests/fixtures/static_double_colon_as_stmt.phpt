@@ -12,10 +12,7 @@ static::class;
           "kind": {
             "StaticPropertyAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 6,
                   "end": 12
                 }
@@ -48,10 +45,7 @@ static::class;
           "kind": {
             "StaticMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 21,
                   "end": 27
                 }
@@ -85,10 +79,7 @@ static::class;
           "kind": {
             "ClassConstAccess": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 39,
                   "end": 45
                 }
@@ -22,12 +22,15 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 7,
-                  "end": 10
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 7,
+                    "end": 10
+                  }
                 }
               },
               "method": {
@@ -59,12 +62,15 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "Foo"
-                },
-                "span": {
-                  "start": 23,
-                  "end": 26
+                "Name": {
+                  "parts": [
+                    "Foo"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 23,
+                    "end": 26
+                  }
                 }
               },
               "method": {
@@ -93,6 +99,10 @@ Foo::$method['key']();
                   "span": {
                     "start": 36,
                     "end": 37
+                  },
+                  "comma": {
+                    "start": 37,
+                    "end": 38
                   }
                 },
                 {
@@ -133,10 +143,7 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "static"
-                },
-                "span": {
+                "StaticKw": {
                   "start": 43,
                   "end": 49
                 }
@@ -170,12 +177,14 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Variable": "obj"
-                },
-                "span": {
-                  "start": 58,
-                  "end": 62
+                "Expr": {
+                  "kind": {
+                    "Variable": "obj"
+                  },
+                  "span": {
+                    "start": 58,
+                    "end": 62
+                  }
                 }
               },
               "method": {
@@ -207,10 +216,7 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "self"
-                },
-                "span": {
+                "SelfKw": {
                   "start": 71,
                   "end": 75
                 }
@@ -244,10 +250,7 @@ Foo::$method['key']();
           "kind": {
             "StaticDynMethodCall": {
               "class": {
-                "kind": {
-                  "Identifier": "parent"
-                },
-                "span": {
+                "ParentKw": {
                   "start": 88,
                   "end": 94
                 }
@@ -283,12 +286,15 @@ Foo::$method['key']();
               "kind": {
                 "StaticMethod": {
                   "class": {
-                    "kind": {
-                      "Identifier": "Foo"
-                    },
-                    "span": {
-                      "start": 185,
-                      "end": 188
+                    "Name": {
+                      "parts": [
+                        "Foo"
+                      ],
+                      "kind": "Unqualified",
+                      "span": {
+                        "start": 185,
+                        "end": 188
+                      }
                     }
                   },
                   "method": {
@@ -327,12 +333,15 @@ Foo::$method['key']();
                       "kind": {
                         "StaticPropertyAccess": {
                           "class": {
-                            "kind": {
-                              "Identifier": "Foo"
-                            },
-                            "span": {
-                              "start": 302,
-                              "end": 305
+                            "Name": {
+                              "parts": [
+                                "Foo"
+                              ],
+                              "kind": "Unqualified",
+                              "span": {
+                                "start": 302,
+                                "end": 305
+                              }
                             }
                           },
                           "member": {
@@ -353,7 +362,10 @@ Foo::$method['key']();
                     },
                     "index": {
                       "kind": {
-                        "String": "key"
+                        "String": {
+                          "value": "key",
+                          "raw": "key"
+                        }
                       },
                       "span": {
                         "start": 315,
@@ -30,12 +30,15 @@ $f = self::$prop;
                     "kind": {
                       "StaticMethod": {
                         "class": {
-                          "kind": {
-                            "Identifier": "Foo"
-                          },
-                          "span": {
-                            "start": 11,
-                            "end": 14
+                          "Name": {
+                            "parts": [
+                              "Foo"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 11,
+                              "end": 14
+                            }
                           }
                         },
                         "method": {
@@ -88,12 +91,15 @@ $f = self::$prop;
                 "kind": {
                   "StaticPropertyAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 31,
-                        "end": 34
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 31,
+                          "end": 34
+                        }
                       }
                     },
                     "member": {
@@ -144,12 +150,15 @@ $f = self::$prop;
                 "kind": {
                   "ClassConstAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 48,
-                        "end": 51
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 48,
+                          "end": 51
+                        }
                       }
                     },
                     "member": {
@@ -200,10 +209,7 @@ $f = self::$prop;
                 "kind": {
                   "StaticMethodCall": {
                     "class": {
-                      "kind": {
-                        "Identifier": "static"
-                      },
-                      "span": {
+                      "StaticKw": {
                         "start": 65,
                         "end": 71
                       }
@@ -257,10 +263,7 @@ $f = self::$prop;
                 "kind": {
                   "StaticMethodCall": {
                     "class": {
-                      "kind": {
-                        "Identifier": "parent"
-                      },
-                      "span": {
+                      "ParentKw": {
                         "start": 88,
                         "end": 94
                       }
@@ -314,10 +317,7 @@ $f = self::$prop;
                 "kind": {
                   "StaticPropertyAccess": {
                     "class": {
-                      "kind": {
-                        "Identifier": "self"
-                      },
-                      "span": {
+                      "SelfKw": {
                         "start": 111,
                         "end": 115
                       }
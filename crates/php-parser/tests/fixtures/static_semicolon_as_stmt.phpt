@@ -9,7 +9,16 @@ Cannot use 'static' as a standalone expression
       "kind": {
         "Expression": {
           "kind": {
-            "Identifier": "static"
+            "Name": {
+              "parts": [
+                "static"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 6,
+                "end": 12
+              }
+            }
           },
           "span": {
             "start": 6,
@@ -57,7 +57,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Hello "
+                      "Literal": {
+                        "value": "Hello ",
+                        "span": {
+                          "start": 52,
+                          "end": 58
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -109,7 +115,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Name: "
+                      "Literal": {
+                        "value": "Name: ",
+                        "span": {
+                          "start": 108,
+                          "end": 114
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -180,7 +192,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Item: "
+                      "Literal": {
+                        "value": "Item: ",
+                        "span": {
+                          "start": 183,
+                          "end": 189
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -251,7 +269,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Value: "
+                      "Literal": {
+                        "value": "Value: ",
+                        "span": {
+                          "start": 249,
+                          "end": 256
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -268,7 +292,10 @@ $escapes = "line1\nline2\ttab";
                             },
                             "index": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 261,
@@ -322,7 +349,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Full: "
+                      "Literal": {
+                        "value": "Full: ",
+                        "span": {
+                          "start": 318,
+                          "end": 324
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -394,7 +427,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Start "
+                      "Literal": {
+                        "value": "Start ",
+                        "span": {
+                          "start": 386,
+                          "end": 392
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -408,7 +447,13 @@ $escapes = "line1\nline2\ttab";
                       }
                     },
                     {
-                      "Literal": " middle "
+                      "Literal": {
+                        "value": " middle ",
+                        "span": {
+                          "start": 394,
+                          "end": 402
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -422,7 +467,13 @@ $escapes = "line1\nline2\ttab";
                       }
                     },
                     {
-                      "Literal": " end"
+                      "Literal": {
+                        "value": " end",
+                        "span": {
+                          "start": 404,
+                          "end": 408
+                        }
+                      }
                     }
                   ]
                 },
@@ -461,7 +512,10 @@ $escapes = "line1\nline2\ttab";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "Price: $100"
+                  "String": {
+                    "value": "Price: $100",
+                    "raw": "Price: \\$100"
+                  }
                 },
                 "span": {
                   "start": 460,
@@ -500,7 +554,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Key: "
+                      "Literal": {
+                        "value": "Key: ",
+                        "span": {
+                          "start": 518,
+                          "end": 523
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -517,7 +577,10 @@ $escapes = "line1\nline2\ttab";
                             },
                             "index": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 529,
@@ -582,7 +645,13 @@ $escapes = "line1\nline2\ttab";
                       }
                     },
                     {
-                      "Literal": " is great"
+                      "Literal": {
+                        "value": " is great",
+                        "span": {
+                          "start": 580,
+                          "end": 589
+                        }
+                      }
                     }
                   ]
                 },
@@ -623,7 +692,13 @@ $escapes = "line1\nline2\ttab";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "Hello "
+                      "Literal": {
+                        "value": "Hello ",
+                        "span": {
+                          "start": 624,
+                          "end": 630
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -673,7 +748,10 @@ $escapes = "line1\nline2\ttab";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "just a plain string"
+                  "String": {
+                    "value": "just a plain string",
+                    "raw": "just a plain string"
+                  }
                 },
                 "span": {
                   "start": 692,
@@ -710,7 +788,10 @@ $escapes = "line1\nline2\ttab";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "line1\nline2\ttab"
+                  "String": {
+                    "value": "line1\nline2\ttab",
+                    "raw": "line1\\nline2\\ttab"
+                  }
                 },
                 "span": {
                   "start": 747,
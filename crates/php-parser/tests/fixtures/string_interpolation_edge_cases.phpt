@@ -44,7 +44,10 @@ $nested = "{{ {$x} }}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "test"
+                  "String": {
+                    "value": "test",
+                    "raw": "test"
+                  }
                 },
                 "span": {
                   "start": 43,
@@ -268,12 +271,15 @@ $nested = "{{ {$x} }}";
                 "kind": {
                   "New": {
                     "class": {
-                      "kind": {
-                        "Identifier": "stdClass"
-                      },
-                      "span": {
-                        "start": 185,
-                        "end": 193
+                      "Name": {
+                        "parts": [
+                          "stdClass"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 185,
+                          "end": 193
+                        }
                       }
                     },
                     "args": []
@@ -333,7 +339,10 @@ $nested = "{{ {$x} }}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "result"
+                  "String": {
+                    "value": "result",
+                    "raw": "result"
+                  }
                 },
                 "span": {
                   "start": 212,
@@ -442,7 +451,10 @@ $nested = "{{ {$x} }}";
                     {
                       "key": {
                         "kind": {
-                          "String": "key"
+                          "String": {
+                            "value": "key",
+                            "raw": "key"
+                          }
                         },
                         "span": {
                           "start": 287,
@@ -451,7 +463,10 @@ $nested = "{{ {$x} }}";
                       },
                       "value": {
                         "kind": {
-                          "String": "value"
+                          "String": {
+                            "value": "value",
+                            "raw": "value"
+                          }
                         },
                         "span": {
                           "start": 296,
@@ -517,7 +532,10 @@ $nested = "{{ {$x} }}";
                             },
                             "index": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 318,
@@ -569,7 +587,10 @@ $nested = "{{ {$x} }}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "foo"
+                  "String": {
+                    "value": "foo",
+                    "raw": "foo"
+                  }
                 },
                 "span": {
                   "start": 362,
@@ -606,7 +627,10 @@ $nested = "{{ {$x} }}";
               "op": "Assign",
               "value": {
                 "kind": {
-                  "String": "bar"
+                  "String": {
+                    "value": "bar",
+                    "raw": "bar"
+                  }
                 },
                 "span": {
                   "start": 376,
@@ -702,7 +726,13 @@ $nested = "{{ {$x} }}";
                 "kind": {
                   "InterpolatedString": [
                     {
-                      "Literal": "{{ "
+                      "Literal": {
+                        "value": "{{ ",
+                        "span": {
+                          "start": 433,
+                          "end": 436
+                        }
+                      }
                     },
                     {
                       "Expr": {
@@ -716,7 +746,13 @@ $nested = "{{ {$x} }}";
                       }
                     },
                     {
-                      "Literal": " }}"
+                      "Literal": {
+                        "value": " }}",
+                        "span": {
+                          "start": 440,
+                          "end": 443
+                        }
+                      }
                     }
                   ]
                 },
@@ -7,7 +7,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "single"
+            "String": {
+              "value": "single",
+              "raw": "single"
+            }
           },
           "span": {
             "start": 6,
@@ -24,7 +27,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "double"
+            "String": {
+              "value": "double",
+              "raw": "double"
+            }
           },
           "span": {
             "start": 16,
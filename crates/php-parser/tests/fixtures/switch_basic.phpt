@@ -40,17 +40,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "one"
-                        },
-                        "span": {
-                          "start": 45,
-                          "end": 50
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "one",
+                              "raw": "one"
+                            }
+                          },
+                          "span": {
+                            "start": 45,
+                            "end": 50
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 40,
@@ -101,17 +106,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "two or three"
-                        },
-                        "span": {
-                          "start": 104,
-                          "end": 118
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "two or three",
+                              "raw": "two or three"
+                            }
+                          },
+                          "span": {
+                            "start": 104,
+                            "end": 118
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 99,
@@ -138,17 +148,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "other"
-                        },
-                        "span": {
-                          "start": 161,
-                          "end": 168
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
+                          },
+                          "span": {
+                            "start": 161,
+                            "end": 168
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 156,
@@ -74,17 +74,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "1-3"
-                        },
-                        "span": {
-                          "start": 69,
-                          "end": 74
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "1-3",
+                              "raw": "1-3"
+                            }
+                          },
+                          "span": {
+                            "start": 69,
+                            "end": 74
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 64,
@@ -135,17 +140,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "4-5"
-                        },
-                        "span": {
-                          "start": 128,
-                          "end": 133
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "4-5",
+                              "raw": "4-5"
+                            }
+                          },
+                          "span": {
+                            "start": 128,
+                            "end": 133
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 123,
@@ -172,17 +182,22 @@ switch ($x) {
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "String": "other"
-                        },
-                        "span": {
-                          "start": 176,
-                          "end": 183
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "String": {
+                              "value": "other",
+                              "raw": "other"
+                            }
+                          },
+                          "span": {
+                            "start": 176,
+                            "end": 183
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 171,
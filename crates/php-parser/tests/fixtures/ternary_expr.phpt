@@ -39,7 +39,10 @@
               },
               "then_expr": {
                 "kind": {
-                  "String": "yes"
+                  "String": {
+                    "value": "yes",
+                    "raw": "yes"
+                  }
                 },
                 "span": {
                   "start": 15,
@@ -48,7 +51,10 @@
               },
               "else_expr": {
                 "kind": {
-                  "String": "no"
+                  "String": {
+                    "value": "no",
+                    "raw": "no"
+                  }
                 },
                 "span": {
                   "start": 23,
@@ -41,12 +41,15 @@ $result = match ($status) {
                           "kind": {
                             "New": {
                               "class": {
-                                "kind": {
-                                  "Identifier": "InvalidArgumentException"
-                                },
-                                "span": {
-                                  "start": 31,
-                                  "end": 55
+                                "Name": {
+                                  "parts": [
+                                    "InvalidArgumentException"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 31,
+                                    "end": 55
+                                  }
                                 }
                               },
                               "args": [
@@ -54,7 +57,10 @@ $result = match ($status) {
                                   "name": null,
                                   "value": {
                                     "kind": {
-                                      "String": "Missing value"
+                                      "String": {
+                                        "value": "Missing value",
+                                        "raw": "Missing value"
+                                      }
                                     },
                                     "span": {
                                       "start": 56,
@@ -144,7 +150,10 @@ $result = match ($status) {
                         ],
                         "body": {
                           "kind": {
-                            "String": "ok"
+                            "String": {
+                              "value": "ok",
+                              "raw": "ok"
+                            }
                           },
                           "span": {
                             "start": 113,
@@ -154,6 +163,10 @@ $result = match ($status) {
                         "span": {
                           "start": 106,
                           "end": 117
+                        },
+                        "comma": {
+                          "start": 117,
+                          "end": 118
                         }
                       },
                       {
@@ -164,12 +177,15 @@ $result = match ($status) {
                               "kind": {
                                 "New": {
                                   "class": {
-                                    "kind": {
-                                      "Identifier": "RuntimeException"
-                                    },
-                                    "span": {
-                                      "start": 144,
-                                      "end": 160
+                                    "Name": {
+                                      "parts": [
+                                        "RuntimeException"
+                                      ],
+                                      "kind": "Unqualified",
+                                      "span": {
+                                        "start": 144,
+                                        "end": 160
+                                      }
                                     }
                                   },
                                   "args": [
@@ -177,7 +193,10 @@ $result = match ($status) {
                                       "name": null,
                                       "value": {
                                         "kind": {
-                                          "String": "Unexpected status"
+                                          "String": {
+                                            "value": "Unexpected status",
+                                            "raw": "Unexpected status"
+                                          }
                                         },
                                         "span": {
                                           "start": 161,
@@ -208,6 +227,10 @@ $result = match ($status) {
                         "span": {
                           "start": 123,
                           "end": 181
+                        },
+                        "comma": {
+                          "start": 181,
+                          "end": 182
                         }
                       }
                     ]
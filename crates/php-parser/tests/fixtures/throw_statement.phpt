@@ -9,12 +9,15 @@
           "kind": {
             "New": {
               "class": {
-                "kind": {
-                  "Identifier": "Exception"
-                },
-                "span": {
-                  "start": 16,
-                  "end": 25
+                "Name": {
+                  "parts": [
+                    "Exception"
+                  ],
+                  "kind": "Unqualified",
+                  "span": {
+                    "start": 16,
+                    "end": 25
+                  }
                 }
               },
               "args": [
@@ -22,7 +25,10 @@
                   "name": null,
                   "value": {
                     "kind": {
-                      "String": "Something went wrong"
+                      "String": {
+                        "value": "Something went wrong",
+                        "raw": "Something went wrong"
+                      }
                     },
                     "span": {
                       "start": 26,
@@ -39,6 +39,10 @@ function bar($x, $y,) {}
                       "span": {
                         "start": 14,
                         "end": 15
+                      },
+                      "comma": {
+                        "start": 15,
+                        "end": 16
                       }
                     },
                     {
@@ -56,6 +60,10 @@ function bar($x, $y,) {}
                       "span": {
                         "start": 17,
                         "end": 18
+                      },
+                      "comma": {
+                        "start": 18,
+                        "end": 19
                       }
                     },
                     {
@@ -73,6 +81,10 @@ function bar($x, $y,) {}
                       "span": {
                         "start": 20,
                         "end": 21
+                      },
+                      "comma": {
+                        "start": 21,
+                        "end": 22
                       }
                     }
                   ]
@@ -102,7 +114,16 @@ function bar($x, $y,) {}
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 25,
+                      "end": 28
+                    }
+                  }
                 },
                 "span": {
                   "start": 25,
@@ -126,6 +147,10 @@ function bar($x, $y,) {}
                   "span": {
                     "start": 29,
                     "end": 31
+                  },
+                  "comma": {
+                    "start": 31,
+                    "end": 32
                   }
                 },
                 {
@@ -144,6 +169,10 @@ function bar($x, $y,) {}
                   "span": {
                     "start": 33,
                     "end": 35
+                  },
+                  "comma": {
+                    "start": 35,
+                    "end": 36
                   }
                 },
                 {
@@ -162,6 +191,10 @@ function bar($x, $y,) {}
                   "span": {
                     "start": 37,
                     "end": 39
+                  },
+                  "comma": {
+                    "start": 39,
+                    "end": 40
                   }
                 }
               ]
@@ -197,6 +230,10 @@ function bar($x, $y,) {}
               "span": {
                 "start": 56,
                 "end": 58
+              },
+              "comma": {
+                "start": 58,
+                "end": 59
               }
             },
             {
@@ -213,6 +250,10 @@ function bar($x, $y,) {}
               "span": {
                 "start": 60,
                 "end": 62
+              },
+              "comma": {
+                "start": 62,
+                "end": 63
               }
             }
           ],
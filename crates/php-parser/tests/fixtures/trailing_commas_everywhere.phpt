@@ -29,6 +29,10 @@ use App\{A, B,};
               "span": {
                 "start": 19,
                 "end": 21
+              },
+              "comma": {
+                "start": 21,
+                "end": 22
               }
             },
             {
@@ -45,6 +49,10 @@ use App\{A, B,};
               "span": {
                 "start": 23,
                 "end": 25
+              },
+              "comma": {
+                "start": 25,
+                "end": 26
               }
             }
           ],
@@ -66,7 +74,16 @@ use App\{A, B,};
             "FunctionCall": {
               "name": {
                 "kind": {
-                  "Identifier": "foo"
+                  "Name": {
+                    "parts": [
+                      "foo"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 31,
+                      "end": 34
+                    }
+                  }
                 },
                 "span": {
                   "start": 31,
@@ -90,6 +107,10 @@ use App\{A, B,};
                   "span": {
                     "start": 35,
                     "end": 36
+                  },
+                  "comma": {
+                    "start": 36,
+                    "end": 37
                   }
                 },
                 {
@@ -108,6 +129,10 @@ use App\{A, B,};
                   "span": {
                     "start": 38,
                     "end": 39
+                  },
+                  "comma": {
+                    "start": 39,
+                    "end": 40
                   }
                 }
               ]
@@ -159,6 +184,10 @@ use App\{A, B,};
                         "span": {
                           "start": 57,
                           "end": 59
+                        },
+                        "comma": {
+                          "start": 59,
+                          "end": 60
                         }
                       }
                     ],
@@ -169,6 +198,10 @@ use App\{A, B,};
                         "span": {
                           "start": 67,
                           "end": 69
+                        },
+                        "comma": {
+                          "start": 69,
+                          "end": 70
                         }
                       }
                     ],
@@ -230,6 +263,10 @@ use App\{A, B,};
                         "span": {
                           "start": 84,
                           "end": 86
+                        },
+                        "comma": {
+                          "start": 86,
+                          "end": 87
                         }
                       }
                     ],
@@ -271,42 +308,52 @@ use App\{A, B,};
             "Assign": {
               "target": {
                 "kind": {
-                  "Array": [
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "a"
+                  "List": {
+                    "elements": [
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 97,
+                            "end": 99
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 97,
                           "end": 99
+                        },
+                        "comma": {
+                          "start": 99,
+                          "end": 100
                         }
                       },
-                      "unpack": false,
-                      "span": {
-                        "start": 97,
-                        "end": 99
-                      }
-                    },
-                    {
-                      "key": null,
-                      "value": {
-                        "kind": {
-                          "Variable": "b"
+                      {
+                        "key": null,
+                        "value": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 101,
+                            "end": 103
+                          }
                         },
+                        "unpack": false,
                         "span": {
                           "start": 101,
                           "end": 103
+                        },
+                        "comma": {
+                          "start": 103,
+                          "end": 104
                         }
-                      },
-                      "unpack": false,
-                      "span": {
-                        "start": 101,
-                        "end": 103
                       }
-                    }
-                  ]
+                    ]
+                  }
                 },
                 "span": {
                   "start": 96,
@@ -365,7 +412,10 @@ use App\{A, B,};
                   ],
                   "body": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 132,
@@ -375,6 +425,10 @@ use App\{A, B,};
                   "span": {
                     "start": 127,
                     "end": 135
+                  },
+                  "comma": {
+                    "start": 135,
+                    "end": 136
                   }
                 },
                 {
@@ -391,7 +445,10 @@ use App\{A, B,};
                   ],
                   "body": {
                     "kind": {
-                      "String": "b"
+                      "String": {
+                        "value": "b",
+                        "raw": "b"
+                      }
                     },
                     "span": {
                       "start": 142,
@@ -401,6 +458,10 @@ use App\{A, B,};
                   "span": {
                     "start": 137,
                     "end": 145
+                  },
+                  "comma": {
+                    "start": 145,
+                    "end": 146
                   }
                 }
               ]
@@ -438,6 +499,10 @@ use App\{A, B,};
               "span": {
                 "start": 159,
                 "end": 160
+              },
+              "comma": {
+                "start": 160,
+                "end": 161
               }
             },
             {
@@ -456,6 +521,10 @@ use App\{A, B,};
               "span": {
                 "start": 162,
                 "end": 163
+              },
+              "comma": {
+                "start": 163,
+                "end": 164
               }
             }
           ]
@@ -34,7 +34,16 @@ try {
                           "FunctionCall": {
                             "name": {
                               "kind": {
-                                "Identifier": "riskyOperation"
+                                "Name": {
+                                  "parts": [
+                                    "riskyOperation"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 21,
+                                    "end": 35
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 21,
@@ -67,31 +76,41 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "Exception"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 48,
-                    "end": 57
+                  "Name": {
+                    "parts": [
+                      "Exception"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 48,
+                      "end": 57
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 58,
+                  "end": 60
+                }
+              },
               "body": [
                 {
                   "kind": {
-                    "Echo": [
-                      {
-                        "kind": {
-                          "Variable": "e"
-                        },
-                        "span": {
-                          "start": 73,
-                          "end": 75
+                    "Echo": {
+                      "exprs": [
+                        {
+                          "kind": {
+                            "Variable": "e"
+                          },
+                          "span": {
+                            "start": 73,
+                            "end": 75
+                          }
                         }
-                      }
-                    ]
+                      ]
+                    }
                   },
                   "span": {
                     "start": 68,
@@ -113,7 +132,16 @@ try {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "cleanup"
+                          "Name": {
+                            "parts": [
+                              "cleanup"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 93,
+                              "end": 100
+                            }
+                          }
                         },
                         "span": {
                           "start": 93,
@@ -21,7 +21,16 @@ try {
                     "FunctionCall": {
                       "name": {
                         "kind": {
-                          "Identifier": "process"
+                          "Name": {
+                            "parts": [
+                              "process"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 16,
+                              "end": 23
+                            }
+                          }
                         },
                         "span": {
                           "start": 16,
@@ -47,27 +56,37 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "TypeError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 36,
-                    "end": 45
+                  "Name": {
+                    "parts": [
+                      "TypeError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 36,
+                      "end": 45
+                    }
                   }
                 },
                 {
-                  "parts": [
-                    "ValueError"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 46,
-                    "end": 56
+                  "Name": {
+                    "parts": [
+                      "ValueError"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 46,
+                      "end": 56
+                    }
                   }
                 }
               ],
-              "var": "e",
+              "var": {
+                "name": "e",
+                "span": {
+                  "start": 57,
+                  "end": 59
+                }
+              },
               "body": [
                 {
                   "kind": {
@@ -76,7 +95,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handleTypeError"
+                              "Name": {
+                                "parts": [
+                                  "handleTypeError"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 67,
+                                  "end": 82
+                                }
+                              }
                             },
                             "span": {
                               "start": 67,
@@ -125,13 +153,15 @@ try {
             {
               "types": [
                 {
-                  "parts": [
-                    "RuntimeException"
-                  ],
-                  "kind": "Unqualified",
-                  "span": {
-                    "start": 97,
-                    "end": 113
+                  "Name": {
+                    "parts": [
+                      "RuntimeException"
+                    ],
+                    "kind": "Unqualified",
+                    "span": {
+                      "start": 97,
+                      "end": 113
+                    }
                   }
                 }
               ],
@@ -144,7 +174,16 @@ try {
                         "FunctionCall": {
                           "name": {
                             "kind": {
-                              "Identifier": "handleRuntime"
+                              "Name": {
+                                "parts": [
+                                  "handleRuntime"
+                                ],
+                                "kind": "Unqualified",
+                                "span": {
+                                  "start": 121,
+                                  "end": 134
+                                }
+                              }
                             },
                             "span": {
                               "start": 121,
@@ -84,7 +84,10 @@ min_php=8.3
                   },
                   "value": {
                     "kind": {
-                      "String": "a"
+                      "String": {
+                        "value": "a",
+                        "raw": "a"
+                      }
                     },
                     "span": {
                       "start": 58,
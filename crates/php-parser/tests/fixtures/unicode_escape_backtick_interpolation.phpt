@@ -11,7 +11,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "💩"
+                "Literal": {
+                  "value": "💩",
+                  "span": {
+                    "start": 7,
+                    "end": 16
+                  }
+                }
               },
               {
                 "Expr": {
@@ -25,7 +31,13 @@
                 }
               },
               {
-                "Literal": "❤"
+                "Literal": {
+                  "value": "❤",
+                  "span": {
+                    "start": 20,
+                    "end": 28
+                  }
+                }
               }
             ]
           },
@@ -76,7 +88,13 @@
                 }
               },
               {
-                "Literal": "😀"
+                "Literal": {
+                  "value": "😀",
+                  "span": {
+                    "start": 41,
+                    "end": 50
+                  }
+                }
               },
               {
                 "Expr": {
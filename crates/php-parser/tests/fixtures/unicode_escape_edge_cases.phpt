@@ -12,7 +12,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "\u0000"
+            "String": {
+              "value": "\u0000",
+              "raw": "\\u{0}"
+            }
           },
           "span": {
             "start": 6,
@@ -29,7 +32,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "􏿿"
+            "String": {
+              "value": "􏿿",
+              "raw": "\\u{10FFFF}"
+            }
           },
           "span": {
             "start": 15,
@@ -46,7 +52,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": ""
+            "String": {
+              "value": "",
+              "raw": "\\u{80}"
+            }
           },
           "span": {
             "start": 29,
@@ -63,7 +72,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "ÿ"
+            "String": {
+              "value": "ÿ",
+              "raw": "\\u{FF}"
+            }
           },
           "span": {
             "start": 39,
@@ -80,7 +92,10 @@
       "kind": {
         "Expression": {
           "kind": {
-            "String": "Ā"
+            "String": {
+              "value": "Ā",
+              "raw": "\\u{100}"
+            }
           },
           "span": {
             "start": 49,
@@ -12,7 +12,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "echo 😀"
+                "Literal": {
+                  "value": "echo 😀",
+                  "span": {
+                    "start": 7,
+                    "end": 21
+                  }
+                }
               }
             ]
           },
@@ -33,7 +39,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "e"
+                "Literal": {
+                  "value": "e",
+                  "span": {
+                    "start": 25,
+                    "end": 31
+                  }
+                }
               }
             ]
           },
@@ -54,7 +66,13 @@
           "kind": {
             "ShellExec": [
               {
-                "Literal": "prefix☺suffix"
+                "Literal": {
+                  "value": "prefix☺suffix",
+                  "span": {
+                    "start": 35,
+                    "end": 55
+                  }
+                }
               }
             ]
           },
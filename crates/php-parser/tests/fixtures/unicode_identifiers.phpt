@@ -0,0 +1,671 @@
+===source===
+<?php
+namespace Prüfung\Ünicode;
+
+class Ünicode extends Базовый implements Ñame {
+    const ЁЖ = 1;
+    public int $größe = 0;
+    public function 日本語(string $täst): string {
+        return "$täst {$this->größe} ${täst}" . self::ЁЖ . $this->größe;
+    }
+}
+
+function 🐘($🦀) { return $🦀; }
+$привет = new Ünicode();
+echo $привет->日本語('x'), 🐘(1), \Prüfung\Ünicode\🐘(2);
+goto метка;
+метка:
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Namespace": {
+          "name": {
+            "parts": [
+              "Prüfung",
+              "Ünicode"
+            ],
+            "kind": "Qualified",
+            "span": {
+              "start": 16,
+              "end": 33
+            }
+          },
+          "body": "Simple"
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 34
+      }
+    },
+    {
+      "kind": {
+        "Class": {
+          "name": "Ünicode",
+          "modifiers": {
+            "is_abstract": false,
+            "is_final": false,
+            "is_readonly": false
+          },
+          "extends": {
+            "parts": [
+              "Базовый"
+            ],
+            "kind": "Unqualified",
+            "span": {
+              "start": 59,
+              "end": 73
+            }
+          },
+          "implements": [
+            {
+              "parts": [
+                "Ñame"
+              ],
+              "kind": "Unqualified",
+              "span": {
+                "start": 85,
+                "end": 90
+              }
+            }
+          ],
+          "members": [
+            {
+              "kind": {
+                "ClassConst": {
+                  "name": "ЁЖ",
+                  "visibility": null,
+                  "is_final": false,
+                  "value": {
+                    "kind": {
+                      "Int": 1
+                    },
+                    "span": {
+                      "start": 110,
+                      "end": 111
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 97,
+                "end": 112
+              }
+            },
+            {
+              "kind": {
+                "Property": {
+                  "name": "größe",
+                  "visibility": "Public",
+                  "set_visibility": null,
+                  "is_static": false,
+                  "is_readonly": false,
+                  "type_hint": {
+                    "kind": {
+                      "Named": {
+                        "parts": [
+                          "int"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 124,
+                          "end": 127
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 124,
+                      "end": 127
+                    }
+                  },
+                  "default": {
+                    "kind": {
+                      "Int": 0
+                    },
+                    "span": {
+                      "start": 139,
+                      "end": 140
+                    }
+                  },
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 117,
+                "end": 140
+              }
+            },
+            {
+              "kind": {
+                "Method": {
+                  "name": "日本語",
+                  "visibility": "Public",
+                  "is_static": false,
+                  "is_abstract": false,
+                  "is_final": false,
+                  "by_ref": false,
+                  "params": [
+                    {
+                      "name": "täst",
+                      "type_hint": {
+                        "kind": {
+                          "Named": {
+                            "parts": [
+                              "string"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 172,
+                              "end": 178
+                            }
+                          }
+                        },
+                        "span": {
+                          "start": 172,
+                          "end": 178
+                        }
+                      },
+                      "default": null,
+                      "by_ref": false,
+                      "variadic": false,
+                      "is_readonly": false,
+                      "is_final": false,
+                      "visibility": null,
+                      "set_visibility": null,
+                      "attributes": [],
+                      "span": {
+                        "start": 172,
+                        "end": 185
+                      }
+                    }
+                  ],
+                  "return_type": {
+                    "kind": {
+                      "Named": {
+                        "parts": [
+                          "string"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 188,
+                          "end": 194
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 188,
+                      "end": 194
+                    }
+                  },
+                  "body": [
+                    {
+                      "kind": {
+                        "Return": {
+                          "kind": {
+                            "Binary": {
+                              "left": {
+                                "kind": {
+                                  "Binary": {
+                                    "left": {
+                                      "kind": {
+                                        "InterpolatedString": [
+                                          {
+                                            "Expr": {
+                                              "kind": {
+                                                "Variable": "täst"
+                                              },
+                                              "span": {
+                                                "start": 213,
+                                                "end": 219
+                                              }
+                                            }
+                                          },
+                                          {
+                                            "Literal": {
+                                              "value": " ",
+                                              "span": {
+                                                "start": 219,
+                                                "end": 220
+                                              }
+                                            }
+                                          },
+                                          {
+                                            "Expr": {
+                                              "kind": {
+                                                "PropertyAccess": {
+                                                  "object": {
+                                                    "kind": {
+                                                      "Variable": "this"
+                                                    },
+                                                    "span": {
+                                                      "start": 221,
+                                                      "end": 226
+                                                    }
+                                                  },
+                                                  "property": {
+                                                    "kind": {
+                                                      "Identifier": "größe"
+                                                    },
+                                                    "span": {
+                                                      "start": 228,
+                                                      "end": 235
+                                                    }
+                                                  }
+                                                }
+                                              },
+                                              "span": {
+                                                "start": 221,
+                                                "end": 235
+                                              }
+                                            }
+                                          },
+                                          {
+                                            "Literal": {
+                                              "value": " ",
+                                              "span": {
+                                                "start": 236,
+                                                "end": 237
+                                              }
+                                            }
+                                          },
+                                          {
+                                            "Expr": {
+                                              "kind": {
+                                                "Variable": "täst"
+                                              },
+                                              "span": {
+                                                "start": 239,
+                                                "end": 244
+                                              }
+                                            }
+                                          }
+                                        ]
+                                      },
+                                      "span": {
+                                        "start": 212,
+                                        "end": 246
+                                      }
+                                    },
+                                    "op": "Concat",
+                                    "right": {
+                                      "kind": {
+                                        "ClassConstAccess": {
+                                          "class": {
+                                            "SelfKw": {
+                                              "start": 249,
+                                              "end": 253
+                                            }
+                                          },
+                                          "member": {
+                                            "kind": {
+                                              "Identifier": "ЁЖ"
+                                            },
+                                            "span": {
+                                              "start": 255,
+                                              "end": 259
+                                            }
+                                          }
+                                        }
+                                      },
+                                      "span": {
+                                        "start": 249,
+                                        "end": 259
+                                      }
+                                    }
+                                  }
+                                },
+                                "span": {
+                                  "start": 212,
+                                  "end": 259
+                                }
+                              },
+                              "op": "Concat",
+                              "right": {
+                                "kind": {
+                                  "PropertyAccess": {
+                                    "object": {
+                                      "kind": {
+                                        "Variable": "this"
+                                      },
+                                      "span": {
+                                        "start": 262,
+                                        "end": 267
+                                      }
+                                    },
+                                    "property": {
+                                      "kind": {
+                                        "Identifier": "größe"
+                                      },
+                                      "span": {
+                                        "start": 269,
+                                        "end": 276
+                                      }
+                                    }
+                                  }
+                                },
+                                "span": {
+                                  "start": 262,
+                                  "end": 276
+                                }
+                              }
+                            }
+                          },
+                          "span": {
+                            "start": 212,
+                            "end": 276
+                          }
+                        }
+                      },
+                      "span": {
+                        "start": 205,
+                        "end": 277
+                      }
+                    }
+                  ],
+                  "attributes": []
+                }
+              },
+              "span": {
+                "start": 146,
+                "end": 283
+              }
+            }
+          ],
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 36,
+        "end": 285
+      }
+    },
+    {
+      "kind": {
+        "Function": {
+          "name": "🐘",
+          "params": [
+            {
+              "name": "🦀",
+              "type_hint": null,
+              "default": null,
+              "by_ref": false,
+              "variadic": false,
+              "is_readonly": false,
+              "is_final": false,
+              "visibility": null,
+              "set_visibility": null,
+              "attributes": [],
+              "span": {
+                "start": 301,
+                "end": 306
+              }
+            }
+          ],
+          "body": [
+            {
+              "kind": {
+                "Return": {
+                  "kind": {
+                    "Variable": "🦀"
+                  },
+                  "span": {
+                    "start": 317,
+                    "end": 322
+                  }
+                }
+              },
+              "span": {
+                "start": 310,
+                "end": 323
+              }
+            }
+          ],
+          "return_type": null,
+          "by_ref": false,
+          "attributes": []
+        }
+      },
+      "span": {
+        "start": 287,
+        "end": 325
+      }
+    },
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "привет"
+                },
+                "span": {
+                  "start": 326,
+                  "end": 339
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "New": {
+                    "class": {
+                      "Name": {
+                        "parts": [
+                          "Ünicode"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 346,
+                          "end": 354
+                        }
+                      }
+                    },
+                    "args": []
+                  }
+                },
+                "span": {
+                  "start": 342,
+                  "end": 356
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 326,
+            "end": 356
+          }
+        }
+      },
+      "span": {
+        "start": 326,
+        "end": 357
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "MethodCall": {
+                  "object": {
+                    "kind": {
+                      "Variable": "привет"
+                    },
+                    "span": {
+                      "start": 363,
+                      "end": 376
+                    }
+                  },
+                  "method": {
+                    "kind": {
+                      "Identifier": "日本語"
+                    },
+                    "span": {
+                      "start": 378,
+                      "end": 387
+                    }
+                  },
+                  "args": [
+                    {
+                      "name": null,
+                      "value": {
+                        "kind": {
+                          "String": {
+                            "value": "x",
+                            "raw": "x"
+                          }
+                        },
+                        "span": {
+                          "start": 388,
+                          "end": 391
+                        }
+                      },
+                      "unpack": false,
+                      "by_ref": false,
+                      "span": {
+                        "start": 388,
+                        "end": 391
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 363,
+                "end": 392
+              }
+            },
+            {
+              "kind": {
+                "FunctionCall": {
+                  "name": {
+                    "kind": {
+                      "Name": {
+                        "parts": [
+                          "🐘"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 394,
+                          "end": 398
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 394,
+                      "end": 398
+                    }
+                  },
+                  "args": [
+                    {
+                      "name": null,
+                      "value": {
+                        "kind": {
+                          "Int": 1
+                        },
+                        "span": {
+                          "start": 399,
+                          "end": 400
+                        }
+                      },
+                      "unpack": false,
+                      "by_ref": false,
+                      "span": {
+                        "start": 399,
+                        "end": 400
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 394,
+                "end": 401
+              }
+            },
+            {
+              "kind": {
+                "FunctionCall": {
+                  "name": {
+                    "kind": {
+                      "Name": {
+                        "parts": [
+                          "Prüfung",
+                          "Ünicode",
+                          "🐘"
+                        ],
+                        "kind": "FullyQualified",
+                        "span": {
+                          "start": 403,
+                          "end": 426
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 403,
+                      "end": 426
+                    }
+                  },
+                  "args": [
+                    {
+                      "name": null,
+                      "value": {
+                        "kind": {
+                          "Int": 2
+                        },
+                        "span": {
+                          "start": 427,
+                          "end": 428
+                        }
+                      },
+                      "unpack": false,
+                      "by_ref": false,
+                      "span": {
+                        "start": 427,
+                        "end": 428
+                      }
+                    }
+                  ]
+                }
+              },
+              "span": {
+                "start": 403,
+                "end": 429
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 358,
+        "end": 430
+      }
+    },
+    {
+      "kind": {
+        "Goto": "метка"
+      },
+      "span": {
+        "start": 431,
+        "end": 447
+      }
+    },
+    {
+      "kind": {
+        "Label": "метка"
+      },
+      "span": {
+        "start": 448,
+        "end": 459
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 459
+  }
+}
@@ -93,7 +93,10 @@
               "kind": {
                 "Return": {
                   "kind": {
-                    "String": ""
+                    "String": {
+                      "value": "",
+                      "raw": ""
+                    }
                   },
                   "span": {
                     "start": 62,
@@ -55,25 +55,27 @@ ${$a . $b} = 4;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "VariableVariable": {
-                "kind": {
-                  "Variable": "name"
-                },
-                "span": {
-                  "start": 23,
-                  "end": 28
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "VariableVariable": {
+                  "kind": {
+                    "Variable": "name"
+                  },
+                  "span": {
+                    "start": 23,
+                    "end": 28
+                  }
                 }
+              },
+              "span": {
+                "start": 22,
+                "end": 28
               }
-            },
-            "span": {
-              "start": 22,
-              "end": 28
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 17,
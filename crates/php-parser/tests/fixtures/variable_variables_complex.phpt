@@ -172,44 +172,46 @@ echo $$obj->prop;
     },
     {
       "kind": {
-        "Echo": [
-          {
-            "kind": {
-              "PropertyAccess": {
-                "object": {
-                  "kind": {
-                    "VariableVariable": {
-                      "kind": {
-                        "Variable": "obj"
-                      },
-                      "span": {
-                        "start": 51,
-                        "end": 55
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "PropertyAccess": {
+                  "object": {
+                    "kind": {
+                      "VariableVariable": {
+                        "kind": {
+                          "Variable": "obj"
+                        },
+                        "span": {
+                          "start": 51,
+                          "end": 55
+                        }
                       }
+                    },
+                    "span": {
+                      "start": 50,
+                      "end": 55
                     }
                   },
-                  "span": {
-                    "start": 50,
-                    "end": 55
-                  }
-                },
-                "property": {
-                  "kind": {
-                    "Identifier": "prop"
-                  },
-                  "span": {
-                    "start": 57,
-                    "end": 61
+                  "property": {
+                    "kind": {
+                      "Identifier": "prop"
+                    },
+                    "span": {
+                      "start": 57,
+                      "end": 61
+                    }
                   }
                 }
+              },
+              "span": {
+                "start": 50,
+                "end": 61
               }
-            },
-            "span": {
-              "start": 50,
-              "end": 61
             }
-          }
-        ]
+          ]
+        }
       },
       "span": {
         "start": 45,
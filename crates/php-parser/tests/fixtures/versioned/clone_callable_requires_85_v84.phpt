@@ -29,7 +29,16 @@ max_php=8.4
                     "kind": {
                       "Function": {
                         "kind": {
-                          "Identifier": "clone"
+                          "Name": {
+                            "parts": [
+                              "clone"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 17
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
@@ -38,7 +38,10 @@ min_php=8.5
                           {
                             "key": {
                               "kind": {
-                                "String": "prop"
+                                "String": {
+                                  "value": "prop",
+                                  "raw": "prop"
+                                }
                               },
                               "span": {
                                 "start": 24,
@@ -50,7 +53,16 @@ min_php=8.5
                                 "FunctionCall": {
                                   "name": {
                                     "kind": {
-                                      "Identifier": "strtolower"
+                                      "Name": {
+                                        "parts": [
+                                          "strtolower"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 34,
+                                          "end": 44
+                                        }
+                                      }
                                     },
                                     "span": {
                                       "start": 34,
@@ -62,7 +74,10 @@ min_php=8.5
                                       "name": null,
                                       "value": {
                                         "kind": {
-                                          "String": "A"
+                                          "String": {
+                                            "value": "A",
+                                            "raw": "A"
+                                          }
                                         },
                                         "span": {
                                           "start": 45,
@@ -38,7 +38,10 @@ min_php=8.5
                           {
                             "key": {
                               "kind": {
-                                "String": "a"
+                                "String": {
+                                  "value": "a",
+                                  "raw": "a"
+                                }
                               },
                               "span": {
                                 "start": 24,
@@ -58,12 +61,19 @@ min_php=8.5
                             "span": {
                               "start": 24,
                               "end": 32
+                            },
+                            "comma": {
+                              "start": 32,
+                              "end": 33
                             }
                           },
                           {
                             "key": {
                               "kind": {
-                                "String": "b"
+                                "String": {
+                                  "value": "b",
+                                  "raw": "b"
+                                }
                               },
                               "span": {
                                 "start": 34,
@@ -83,12 +93,19 @@ min_php=8.5
                             "span": {
                               "start": 34,
                               "end": 42
+                            },
+                            "comma": {
+                              "start": 42,
+                              "end": 43
                             }
                           },
                           {
                             "key": {
                               "kind": {
-                                "String": "c"
+                                "String": {
+                                  "value": "c",
+                                  "raw": "c"
+                                }
                               },
                               "span": {
                                 "start": 44,
@@ -38,7 +38,10 @@ min_php=8.5
                           {
                             "key": {
                               "kind": {
-                                "String": "prop"
+                                "String": {
+                                  "value": "prop",
+                                  "raw": "prop"
+                                }
                               },
                               "span": {
                                 "start": 24,
@@ -51,7 +54,10 @@ min_php=8.5
                                   {
                                     "key": {
                                       "kind": {
-                                        "String": "nested"
+                                        "String": {
+                                          "value": "nested",
+                                          "raw": "nested"
+                                        }
                                       },
                                       "span": {
                                         "start": 35,
@@ -38,7 +38,10 @@ min_php=8.5
                           {
                             "key": {
                               "kind": {
-                                "String": "prop"
+                                "String": {
+                                  "value": "prop",
+                                  "raw": "prop"
+                                }
                               },
                               "span": {
                                 "start": 24,
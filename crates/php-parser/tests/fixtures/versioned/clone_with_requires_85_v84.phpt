@@ -41,7 +41,10 @@ max_php=8.4
                           {
                             "key": {
                               "kind": {
-                                "String": "alpha"
+                                "String": {
+                                  "value": "alpha",
+                                  "raw": "alpha"
+                                }
                               },
                               "span": {
                                 "start": 22,
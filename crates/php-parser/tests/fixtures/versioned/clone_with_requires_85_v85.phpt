@@ -38,7 +38,10 @@ min_php=8.5
                           {
                             "key": {
                               "kind": {
-                                "String": "alpha"
+                                "String": {
+                                  "value": "alpha",
+                                  "raw": "alpha"
+                                }
                               },
                               "span": {
                                 "start": 22,
@@ -15,7 +15,10 @@ max_php=8.4
             "name": "VERSION",
             "value": {
               "kind": {
-                "String": "8.5"
+                "String": {
+                  "value": "8.5",
+                  "raw": "8.5"
+                }
               },
               "span": {
                 "start": 32,
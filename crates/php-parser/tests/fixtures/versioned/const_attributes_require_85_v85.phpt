@@ -12,7 +12,10 @@ min_php=8.5
             "name": "VERSION",
             "value": {
               "kind": {
-                "String": "8.5"
+                "String": {
+                  "value": "8.5",
+                  "raw": "8.5"
+                }
               },
               "span": {
                 "start": 32,
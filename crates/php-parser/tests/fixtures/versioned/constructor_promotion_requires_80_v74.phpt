@@ -67,6 +67,10 @@ class Point {
                       "span": {
                         "start": 61,
                         "end": 76
+                      },
+                      "comma": {
+                        "start": 76,
+                        "end": 77
                       }
                     },
                     {
@@ -100,6 +104,10 @@ class Point {
                       "span": {
                         "start": 86,
                         "end": 101
+                      },
+                      "comma": {
+                        "start": 101,
+                        "end": 102
                       }
                     }
                   ],
@@ -0,0 +1,128 @@
+===config===
+min_php=7.4
+max_php=7.4
+===source===
+<?php $s = "abc"; echo $s{0}, $a{'b'};
+===errors===
+array and string offset access with curly braces is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
+array and string offset access with curly braces is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Expression": {
+          "kind": {
+            "Assign": {
+              "target": {
+                "kind": {
+                  "Variable": "s"
+                },
+                "span": {
+                  "start": 6,
+                  "end": 8
+                }
+              },
+              "op": "Assign",
+              "value": {
+                "kind": {
+                  "String": {
+                    "value": "abc",
+                    "raw": "abc"
+                  }
+                },
+                "span": {
+                  "start": 11,
+                  "end": 16
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 6,
+            "end": 16
+          }
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 17
+      }
+    },
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "ArrayAccess": {
+                  "array": {
+                    "kind": {
+                      "Variable": "s"
+                    },
+                    "span": {
+                      "start": 23,
+                      "end": 25
+                    }
+                  },
+                  "index": {
+                    "kind": {
+                      "Int": 0
+                    },
+                    "span": {
+                      "start": 26,
+                      "end": 27
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 23,
+                "end": 28
+              }
+            },
+            {
+              "kind": {
+                "ArrayAccess": {
+                  "array": {
+                    "kind": {
+                      "Variable": "a"
+                    },
+                    "span": {
+                      "start": 30,
+                      "end": 32
+                    }
+                  },
+                  "index": {
+                    "kind": {
+                      "String": {
+                        "value": "b",
+                        "raw": "b"
+                      }
+                    },
+                    "span": {
+                      "start": 33,
+                      "end": 36
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 30,
+                "end": 37
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 18,
+        "end": 38
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 38
+  }
+}
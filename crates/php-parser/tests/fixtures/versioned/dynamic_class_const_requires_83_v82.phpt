@@ -28,12 +28,15 @@ $x = Foo::{$name};
                 "kind": {
                   "ClassConstAccessDynamic": {
                     "class": {
-                      "kind": {
-                        "Identifier": "Foo"
-                      },
-                      "span": {
-                        "start": 11,
-                        "end": 14
+                      "Name": {
+                        "parts": [
+                          "Foo"
+                        ],
+                        "kind": "Unqualified",
+                        "span": {
+                          "start": 11,
+                          "end": 14
+                        }
                       }
                     },
                     "member": {
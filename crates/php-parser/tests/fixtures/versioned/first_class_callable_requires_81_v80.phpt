@@ -28,7 +28,16 @@ min_php=8.0
                     "kind": {
                       "Function": {
                         "kind": {
-                          "Identifier": "strlen"
+                          "Name": {
+                            "parts": [
+                              "strlen"
+                            ],
+                            "kind": "Unqualified",
+                            "span": {
+                              "start": 12,
+                              "end": 18
+                            }
+                          }
                         },
                         "span": {
                           "start": 12,
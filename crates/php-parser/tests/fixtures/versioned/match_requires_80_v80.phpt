@@ -47,7 +47,10 @@ min_php=8.0
                         ],
                         "body": {
                           "kind": {
-                            "String": "a"
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
                           },
                           "span": {
                             "start": 28,
@@ -57,13 +60,20 @@ min_php=8.0
                         "span": {
                           "start": 23,
                           "end": 31
+                        },
+                        "comma": {
+                          "start": 31,
+                          "end": 32
                         }
                       },
                       {
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "b"
+                            "String": {
+                              "value": "b",
+                              "raw": "b"
+                            }
                           },
                           "span": {
                             "start": 44,
@@ -0,0 +1,93 @@
+===config===
+min_php=7.4
+max_php=7.4
+===source===
+<?php echo $a ? 1 : $b ? 2 : 3;
+===errors===
+nesting ternaries without parentheses (`a ? b : c ? d : e`) is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
+===ast===
+{
+  "stmts": [
+    {
+      "kind": {
+        "Echo": {
+          "exprs": [
+            {
+              "kind": {
+                "Ternary": {
+                  "condition": {
+                    "kind": {
+                      "Ternary": {
+                        "condition": {
+                          "kind": {
+                            "Variable": "a"
+                          },
+                          "span": {
+                            "start": 11,
+                            "end": 13
+                          }
+                        },
+                        "then_expr": {
+                          "kind": {
+                            "Int": 1
+                          },
+                          "span": {
+                            "start": 16,
+                            "end": 17
+                          }
+                        },
+                        "else_expr": {
+                          "kind": {
+                            "Variable": "b"
+                          },
+                          "span": {
+                            "start": 20,
+                            "end": 22
+                          }
+                        }
+                      }
+                    },
+                    "span": {
+                      "start": 11,
+                      "end": 22
+                    }
+                  },
+                  "then_expr": {
+                    "kind": {
+                      "Int": 2
+                    },
+                    "span": {
+                      "start": 25,
+                      "end": 26
+                    }
+                  },
+                  "else_expr": {
+                    "kind": {
+                      "Int": 3
+                    },
+                    "span": {
+                      "start": 29,
+                      "end": 30
+                    }
+                  }
+                }
+              },
+              "span": {
+                "start": 11,
+                "end": 30
+              }
+            }
+          ]
+        }
+      },
+      "span": {
+        "start": 6,
+        "end": 31
+      }
+    }
+  ],
+  "span": {
+    "start": 0,
+    "end": 31
+  }
+}
@@ -19,12 +19,15 @@ min_php=8.0
                   "kind": {
                     "New": {
                       "class": {
-                        "kind": {
-                          "Identifier": "\\Exception"
-                        },
-                        "span": {
-                          "start": 38,
-                          "end": 48
+                        "Name": {
+                          "parts": [
+                            "Exception"
+                          ],
+                          "kind": "FullyQualified",
+                          "span": {
+                            "start": 38,
+                            "end": 48
+                          }
                         }
                       },
                       "args": []
@@ -38,7 +38,10 @@ min_php=8.5
                           "op": "Pipe",
                           "right": {
                             "kind": {
-                              "String": "strtoupper"
+                              "String": {
+                                "value": "strtoupper",
+                                "raw": "strtoupper"
+                              }
                             },
                             "span": {
                               "start": 21,
@@ -84,7 +87,16 @@ min_php=8.5
                                   "FunctionCall": {
                                     "name": {
                                       "kind": {
-                                        "Identifier": "trim"
+                                        "Name": {
+                                          "parts": [
+                                            "trim"
+                                          ],
+                                          "kind": "Unqualified",
+                                          "span": {
+                                            "start": 48,
+                                            "end": 52
+                                          }
+                                        }
                                       },
                                       "span": {
                                         "start": 48,
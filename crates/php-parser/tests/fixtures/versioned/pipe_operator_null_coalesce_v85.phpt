@@ -36,7 +36,10 @@ min_php=8.5
                         },
                         "right": {
                           "kind": {
-                            "String": "default"
+                            "String": {
+                              "value": "default",
+                              "raw": "default"
+                            }
                           },
                           "span": {
                             "start": 19,
@@ -46,7 +46,16 @@ max_php=8.4
                                 "kind": {
                                   "Function": {
                                     "kind": {
-                                      "Identifier": "trim"
+                                      "Name": {
+                                        "parts": [
+                                          "trim"
+                                        ],
+                                        "kind": "Unqualified",
+                                        "span": {
+                                          "start": 21,
+                                          "end": 25
+                                        }
+                                      }
                                     },
                                     "span": {
                                       "start": 21,
@@ -75,7 +84,16 @@ max_php=8.4
                           "kind": {
                             "Function": {
                               "kind": {
-                                "Identifier": "strtolower"
+                                "Name": {
+                                  "parts": [
+                                    "strtolower"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 34,
+                                    "end": 44
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 34,
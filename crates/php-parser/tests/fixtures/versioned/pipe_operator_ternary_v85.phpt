@@ -36,7 +36,10 @@ min_php=8.5
                         },
                         "then_expr": {
                           "kind": {
-                            "String": "a"
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
                           },
                           "span": {
                             "start": 18,
@@ -45,7 +48,10 @@ min_php=8.5
                         },
                         "else_expr": {
                           "kind": {
-                            "String": "b"
+                            "String": {
+                              "value": "b",
+                              "raw": "b"
+                            }
                           },
                           "span": {
                             "start": 24,
@@ -37,7 +37,10 @@ min_php=8.5
                         "conditions": null,
                         "body": {
                           "kind": {
-                            "String": "a"
+                            "String": {
+                              "value": "a",
+                              "raw": "a"
+                            }
                           },
                           "span": {
                             "start": 37,
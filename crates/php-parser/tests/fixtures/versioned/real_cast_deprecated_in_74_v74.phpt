@@ -3,6 +3,8 @@ min_php=7.4
 max_php=7.4
 ===source===
 <?php $a = (real) 1.5;
+===errors===
+the (real) cast is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
 ===ast===
 {
   "stmts": [
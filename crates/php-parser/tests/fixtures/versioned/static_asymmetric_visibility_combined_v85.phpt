@@ -92,7 +92,10 @@ class Foo {
                   },
                   "default": {
                     "kind": {
-                      "String": ""
+                      "String": {
+                        "value": "",
+                        "raw": ""
+                      }
                     },
                     "span": {
                       "start": 113,
@@ -48,7 +48,10 @@ max_php=8.4
                   },
                   "default": {
                     "kind": {
-                      "String": "x"
+                      "String": {
+                        "value": "x",
+                        "raw": "x"
+                      }
                     },
                     "span": {
                       "start": 59,
@@ -45,7 +45,10 @@ min_php=8.5
                   },
                   "default": {
                     "kind": {
-                      "String": "x"
+                      "String": {
+                        "value": "x",
+                        "raw": "x"
+                      }
                     },
                     "span": {
                       "start": 59,
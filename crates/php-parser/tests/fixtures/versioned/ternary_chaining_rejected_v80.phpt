@@ -4,7 +4,7 @@ min_php=8.0
 <?php
 $b = $x ? 1 : $y ? 2 : 3;
 ===errors===
-Unparenthesized `a ? b : c ? d : e` is not supported. Use parentheses to make the order explicit.
+nesting ternaries without parentheses (`a ? b : c ? d : e`) was removed in PHP 8.0 (targeting PHP 8.0)
 ===ast===
 {
   "stmts": [
@@ -46,7 +46,10 @@ max_php=8.2
                   },
                   "value": {
                     "kind": {
-                      "String": "foo"
+                      "String": {
+                        "value": "foo",
+                        "raw": "foo"
+                      }
                     },
                     "span": {
                       "start": 45,
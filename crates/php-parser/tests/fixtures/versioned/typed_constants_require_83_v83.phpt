@@ -43,7 +43,10 @@ min_php=8.3
                   },
                   "value": {
                     "kind": {
-                      "String": "foo"
+                      "String": {
+                        "value": "foo",
+                        "raw": "foo"
+                      }
                     },
                     "span": {
                       "start": 45,
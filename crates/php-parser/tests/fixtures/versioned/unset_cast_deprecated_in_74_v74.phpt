@@ -3,6 +3,8 @@ min_php=7.4
 max_php=7.4
 ===source===
 <?php (unset)$x;
+===errors===
+the (unset) cast is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
 ===ast===
 {
   "stmts": [
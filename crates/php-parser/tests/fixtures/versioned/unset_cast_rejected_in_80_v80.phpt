@@ -3,7 +3,7 @@ min_php=8.0
 ===source===
 <?php (unset)$x;
 ===errors===
-the (unset) cast is no longer supported
+the (unset) cast was removed in PHP 8.0 (targeting PHP 8.0)
 ===ast===
 {
   "stmts": [
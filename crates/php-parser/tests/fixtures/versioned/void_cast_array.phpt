@@ -29,6 +29,10 @@ min_php=8.5
                       "span": {
                         "start": 13,
                         "end": 14
+                      },
+                      "comma": {
+                        "start": 14,
+                        "end": 15
                       }
                     },
                     {
@@ -46,6 +50,10 @@ min_php=8.5
                       "span": {
                         "start": 16,
                         "end": 17
+                      },
+                      "comma": {
+                        "start": 17,
+                        "end": 18
                       }
                     },
                     {
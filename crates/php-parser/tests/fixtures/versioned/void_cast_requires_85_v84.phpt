@@ -19,7 +19,16 @@ max_php=8.4
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "getVersion"
+                        "Name": {
+                          "parts": [
+                            "getVersion"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 13,
+                            "end": 23
+                          }
+                        }
                       },
                       "span": {
                         "start": 13,
@@ -16,7 +16,16 @@ min_php=8.5
                   "FunctionCall": {
                     "name": {
                       "kind": {
-                        "Identifier": "getVersion"
+                        "Name": {
+                          "parts": [
+                            "getVersion"
+                          ],
+                          "kind": "Unqualified",
+                          "span": {
+                            "start": 13,
+                            "end": 23
+                          }
+                        }
                       },
                       "span": {
                         "start": 13,
@@ -34,7 +34,10 @@ function gen() {
                             "key": null,
                             "value": {
                               "kind": {
-                                "String": "value"
+                                "String": {
+                                  "value": "value",
+                                  "raw": "value"
+                                }
                               },
                               "span": {
                                 "start": 45,
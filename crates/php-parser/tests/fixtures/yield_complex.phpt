@@ -78,7 +78,10 @@ function gen() {
                             "key": null,
                             "value": {
                               "kind": {
-                                "String": "value"
+                                "String": {
+                                  "value": "value",
+                                  "raw": "value"
+                                }
                               },
                               "span": {
                                 "start": 54,
@@ -126,7 +129,10 @@ function gen() {
                           "Yield": {
                             "key": {
                               "kind": {
-                                "String": "key"
+                                "String": {
+                                  "value": "key",
+                                  "raw": "key"
+                                }
                               },
                               "span": {
                                 "start": 78,
@@ -135,7 +141,10 @@ function gen() {
                             },
                             "value": {
                               "kind": {
-                                "String": "value"
+                                "String": {
+                                  "value": "value",
+                                  "raw": "value"
+                                }
                               },
                               "span": {
                                 "start": 87,
@@ -174,7 +183,16 @@ function gen() {
                           "FunctionCall": {
                             "name": {
                               "kind": {
-                                "Identifier": "otherGen"
+                                "Name": {
+                                  "parts": [
+                                    "otherGen"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 111,
+                                    "end": 119
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 111,
@@ -229,7 +247,10 @@ function gen() {
                                       "key": null,
                                       "value": {
                                         "kind": {
-                                          "String": "x"
+                                          "String": {
+                                            "value": "x",
+                                            "raw": "x"
+                                          }
                                         },
                                         "span": {
                                           "start": 139,
@@ -37,6 +37,10 @@ function combined() {
                               "span": {
                                 "start": 44,
                                 "end": 45
+                              },
+                              "comma": {
+                                "start": 45,
+                                "end": 46
                               }
                             },
                             {
@@ -54,6 +58,10 @@ function combined() {
                               "span": {
                                 "start": 47,
                                 "end": 48
+                              },
+                              "comma": {
+                                "start": 48,
+                                "end": 49
                               }
                             },
                             {
@@ -105,7 +113,16 @@ function combined() {
                           "FunctionCall": {
                             "name": {
                               "kind": {
-                                "Identifier": "otherGenerator"
+                                "Name": {
+                                  "parts": [
+                                    "otherGenerator"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 69,
+                                    "end": 83
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 69,
@@ -23,7 +23,16 @@ function gen() {
                           "FunctionCall": {
                             "name": {
                               "kind": {
-                                "Identifier": "array_map"
+                                "Name": {
+                                  "parts": [
+                                    "array_map"
+                                  ],
+                                  "kind": "Unqualified",
+                                  "span": {
+                                    "start": 38,
+                                    "end": 47
+                                  }
+                                }
                               },
                               "span": {
                                 "start": 38,
@@ -99,6 +108,10 @@ function gen() {
                                 "span": {
                                   "start": 48,
                                   "end": 64
+                                },
+                                "comma": {
+                                  "start": 64,
+                                  "end": 65
                                 }
                               },
                               {
@@ -121,6 +134,10 @@ function gen() {
                                         "span": {
                                           "start": 67,
                                           "end": 68
+                                        },
+                                        "comma": {
+                                          "start": 68,
+                                          "end": 69
                                         }
                                       },
                                       {
@@ -138,6 +155,10 @@ function gen() {
                                         "span": {
                                           "start": 70,
                                           "end": 71
+                                        },
+                                        "comma": {
+                                          "start": 71,
+                                          "end": 72
                                         }
                                       },
                                       {
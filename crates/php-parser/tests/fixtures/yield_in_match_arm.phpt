@@ -116,6 +116,10 @@ function gen($x) {
                                 "span": {
                                   "start": 56,
                                   "end": 74
+                                },
+                                "comma": {
+                                  "start": 74,
+                                  "end": 75
                                 }
                               },
                               {
@@ -144,6 +148,10 @@ function gen($x) {
                                 "span": {
                                   "start": 84,
                                   "end": 102
+                                },
+                                "comma": {
+                                  "start": 102,
+                                  "end": 103
                                 }
                               }
                             ]
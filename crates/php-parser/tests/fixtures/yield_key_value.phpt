@@ -20,7 +20,10 @@ function indexedGen() {
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "a"
+                          "String": {
+                            "value": "a",
+                            "raw": "a"
+                          }
                         },
                         "span": {
                           "start": 40,
@@ -57,7 +60,10 @@ function indexedGen() {
                     "Yield": {
                       "key": {
                         "kind": {
-                          "String": "b"
+                          "String": {
+                            "value": "b",
+                            "raw": "b"
+                          }
                         },
                         "span": {
                           "start": 60,
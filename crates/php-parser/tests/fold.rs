@@ -50,11 +50,12 @@ fn identity_fold_matches_original_json_across_corpus() {
         let (min_php, source) = common::parse_fixture(&content);
         let src_arena = Bump::new();
 
-        let result = if let Some(ver) = min_php {
-            php_rs_parser::parse_versioned(&src_arena, source, php_version(ver))
-        } else {
-            php_rs_parser::parse(&src_arena, source)
+        let options = php_rs_parser::ParserOptions {
+            version: min_php.map(php_version).unwrap_or_default(),
+            infer_semicolons: common::infer_semicolons(&content),
+            ..Default::default()
         };
+        let result = php_rs_parser::parse_with_options(&src_arena, source, &options);
 
         let original_json = to_json(&result.program);
 
@@ -0,0 +1,65 @@
+//! Tests for `parse_expr_str` and `parse_stmt_str`: code fragments without
+//! an open tag.
+
+use php_ast::{ExprKind, Span, StmtKind};
+use php_rs_parser::diagnostics::ParseError;
+use php_rs_parser::{parse_expr_str, parse_stmt_str, parse_stmt_str_versioned, PhpVersion};
+
+#[test]
+fn expression_spans_the_fragment() {
+    let arena = bumpalo::Bump::new();
+    let src = "1 + $x * foo()";
+    let result = parse_expr_str(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.node.span, Span::new(0, src.len() as u32));
+    assert!(matches!(result.node.kind, ExprKind::Binary(_)));
+}
+
+#[test]
+fn leftover_input_is_reported() {
+    let arena = bumpalo::Bump::new();
+    let result = parse_expr_str(&arena, "$a; $b");
+    assert!(matches!(result.node.kind, ExprKind::Variable(_)));
+    assert!(matches!(
+        result.errors.as_slice(),
+        [ParseError::Expected { span, .. }] if *span == Span::new(2, 3)
+    ));
+
+    let result = parse_stmt_str(&arena, "echo 1; echo 2;");
+    assert!(matches!(result.node.kind, StmtKind::Echo(_)));
+    assert_eq!(result.errors.len(), 1);
+}
+
+#[test]
+fn empty_expression_is_an_error() {
+    let arena = bumpalo::Bump::new();
+    let result = parse_expr_str(&arena, "  ");
+    assert!(matches!(
+        result.errors.as_slice(),
+        [ParseError::ExpectedExpression { .. }]
+    ));
+}
+
+#[test]
+fn statement_keeps_comments_and_checks_the_version() {
+    let arena = bumpalo::Bump::new();
+    let src = "/* pick */ $x = match ($y) { 1 => 'a', default => 'b' };";
+    let result = parse_stmt_str(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.comments.len(), 1);
+    assert!(matches!(result.node.kind, StmtKind::Expression(_)));
+
+    let result = parse_stmt_str_versioned(&arena, src, PhpVersion::Php74);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| matches!(e, ParseError::VersionTooLow { .. })));
+}
+
+#[test]
+fn statement_without_semicolon_is_an_error() {
+    let arena = bumpalo::Bump::new();
+    let result = parse_stmt_str(&arena, "return $x");
+    assert!(matches!(result.node.kind, StmtKind::Return(_)));
+    assert!(!result.errors.is_empty());
+}
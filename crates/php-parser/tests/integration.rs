@@ -80,11 +80,12 @@ fn fixtures() {
         let (min_php, source) = common::parse_fixture(&content);
         let arena = bumpalo::Bump::new();
 
-        let result = if let Some(ver) = min_php {
-            php_rs_parser::parse_versioned(&arena, source, php_version(ver))
-        } else {
-            php_rs_parser::parse(&arena, source)
+        let options = php_rs_parser::ParserOptions {
+            version: min_php.map(php_version).unwrap_or_default(),
+            infer_semicolons: common::infer_semicolons(&content),
+            ..Default::default()
         };
+        let result = php_rs_parser::parse_with_options(&arena, source, &options);
 
         let expect_errors = content.contains("===errors===\n");
         if expect_errors {
@@ -103,6 +104,16 @@ fn fixtures() {
             return;
         }
 
+        // Diagnostics are reported in source order, whatever recovery path
+        // produced them.
+        if !result.errors.windows(2).all(|w| w[0].span() <= w[1].span()) {
+            failures
+                .lock()
+                .unwrap()
+                .push(format!("parse errors out of source order in {rel}"));
+            return;
+        }
+
         // Closure check: the parser must emit at least one diagnostic for every
         // input that `php -l` rejects. A non-empty ===php_error=== section on a
         // fixture that has no ===errors=== means the parser should have caught it.
@@ -0,0 +1,95 @@
+//! Tests for `parse_islands`: PHP regions of a host document.
+
+use php_ast::{Span, StmtKind};
+use php_rs_parser::parse_islands;
+
+/// The spans of each of `codes`, in order, within `host`.
+fn islands(host: &str, codes: &[&str]) -> Vec<Span> {
+    let mut from = 0;
+    codes
+        .iter()
+        .map(|code| {
+            let start = from + host[from..].find(code).unwrap();
+            from = start + code.len();
+            Span::new(start as u32, from as u32)
+        })
+        .collect()
+}
+
+#[test]
+fn control_flow_spans_islands() {
+    let host = "<ul>{% foreach ($rows as $row): %}<li>{% endforeach %}</ul>";
+    let islands = islands(host, &["foreach ($rows as $row):", "endforeach"]);
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, host, &islands);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+}
+
+#[test]
+fn statements_need_no_semicolon_at_island_end() {
+    let host = "<p>{{ $a = 1 }}</p>\n<p>{{ echo $a }}</p>";
+    let islands = islands(host, &["$a = 1", "echo $a"]);
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, host, &islands);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    let spans: Vec<Span> = result.program.stmts.iter().map(|s| s.span).collect();
+    assert_eq!(spans, islands);
+    assert!(matches!(result.program.stmts[1].kind, StmtKind::Echo(_)));
+}
+
+#[test]
+fn errors_and_comments_are_located_in_the_host() {
+    let host = "line one\n{{ $a + /* why */ }}\n{{ $b = ; }}";
+    let islands = islands(host, &["$a + /* why */", "$b = ;"]);
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, host, &islands);
+    assert_eq!(result.comments.len(), 1);
+    let comment = result.comments[0].span;
+    assert_eq!(
+        &host[comment.start as usize..comment.end as usize],
+        "/* why */"
+    );
+    assert!(!result.errors.is_empty());
+    for error in &result.errors {
+        let span = error.span();
+        assert!(
+            islands
+                .iter()
+                .any(|i| i.start <= span.start && span.end <= i.end),
+            "{error:?} outside the islands"
+        );
+    }
+    let second = result.errors.last().unwrap().span();
+    assert_eq!(result.source_map.line_col(second.start).line, 2);
+}
+
+#[test]
+fn host_text_is_not_lexed() {
+    // The quote and the comment opener in the host would swallow the
+    // second island if the host were lexed as PHP.
+    let host = "{{ $a }} it's /* {{ $b }}";
+    let islands = islands(host, &["$a", "$b"]);
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, host, &islands);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.program.stmts.len(), 2);
+    assert!(result.comments.is_empty());
+}
+
+#[test]
+fn close_tag_inside_an_island_leaves_php() {
+    let host = "[[ if ($a): ?>yes<?php endif ]]";
+    let islands = islands(host, &["if ($a): ?>yes<?php endif"]);
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, host, &islands);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.program.stmts.len(), 1);
+}
+
+#[test]
+fn no_islands_is_an_empty_program() {
+    let arena = bumpalo::Bump::new();
+    let result = parse_islands(&arena, "<html></html>", &[]);
+    assert!(result.errors.is_empty());
+    assert!(result.program.stmts.is_empty());
+}
@@ -1,7 +1,8 @@
 //! Programmatic tests that cannot be expressed as static fixture files.
 //!
 //! All other error-case tests live in `tests/fixtures/errors/*.phpt` and are
-//! run automatically by `integration::fixtures()`.
+//! run automatically by `integration::fixtures()`; inputs that must only
+//! parse without panicking or hanging live in `tests/regressions/`.
 
 #[path = "common.rs"]
 mod common;
@@ -37,16 +38,6 @@ fn assert_depth_exceeded(code: &str) {
     );
 }
 
-fn assert_no_errors(code: &str) {
-    let arena = bumpalo::Bump::new();
-    let result = php_rs_parser::parse(&arena, code);
-    assert!(
-        result.errors.is_empty(),
-        "unexpected errors: {}",
-        format_errors(&result)
-    );
-}
-
 // ============================================================================
 // NESTING DEPTH LIMIT
 // These tests generate input programmatically and must stay inline.
@@ -98,123 +89,369 @@ fn deeply_nested_match_hit_depth_limit() {
 }
 
 // ============================================================================
-// LARGE INPUT / REPETITIVE PATTERNS
-// Ensures the parser handles high volume without panicking or hanging.
+// NULL BYTES
+// Cannot be expressed in .phpt fixture files.
+// ============================================================================
+
+#[test]
+fn null_bytes_in_source() {
+    assert_has_errors("<?php $x = \0;");
+}
+
+// ============================================================================
+// MISSING SEMICOLON FIX-IT
+// Fixtures do not record error spans, so the insertion point is checked here.
 // ============================================================================
 
+/// Parse `src` with `ParserOptions::infer_semicolons`.
+fn parse_inferring_semicolons<'a>(
+    arena: &'a bumpalo::Bump,
+    src: &'a str,
+) -> php_rs_parser::ParseResult<'a, 'a> {
+    let options = php_rs_parser::ParserOptions {
+        infer_semicolons: true,
+        ..Default::default()
+    };
+    php_rs_parser::parse_with_options(arena, src, &options)
+}
+
+/// A `;` missing at a line break is reported as an empty span where it belongs.
 #[test]
-fn many_sequential_statements() {
-    let code = format!("<?php {}", "$x = 1;\n".repeat(10_000));
+fn missing_semicolon_at_line_break_points_at_insertion() {
+    let src = "<?php\n$x = 1\n$y = 2;";
     let arena = bumpalo::Bump::new();
-    let result = php_rs_parser::parse(&arena, &code);
-    assert!(result.errors.is_empty());
+    let result = parse_inferring_semicolons(&arena, src);
+    assert_eq!(result.program.stmts.len(), 2);
+    match result.errors.as_slice() {
+        [php_rs_parser::diagnostics::ParseError::MissingSemicolon { span, .. }] => {
+            assert_eq!((span.start, span.end), (12, 12));
+            assert_eq!(&src[..span.start as usize], "<?php\n$x = 1");
+        }
+        other => panic!("expected one MissingSemicolon, got {other:?}"),
+    }
 }
 
+/// Without a line break there is no clear statement boundary.
 #[test]
-fn very_long_concatenation_chain() {
-    // "a" . "b" . "c" . ... (flat, not nested — should not hit depth limit)
-    let parts: Vec<&str> = (0..5_000).map(|_| "\"a\"").collect();
-    let code = format!("<?php echo {};", parts.join(" . "));
-    assert_no_errors(&code);
+fn missing_semicolon_on_same_line_stays_expected_after() {
+    let arena = bumpalo::Bump::new();
+    let result = parse_inferring_semicolons(&arena, "<?php $x = 1 $y = 2;");
+    assert!(matches!(
+        result.errors.as_slice(),
+        [php_rs_parser::diagnostics::ParseError::ExpectedAfter { .. }]
+    ));
 }
 
+/// Inside an unclosed `[` or `(` the line break ends the list, not the
+/// statement, so no `;` is inferred.
 #[test]
-fn many_function_parameters() {
-    let params: Vec<String> = (0..500).map(|i| format!("$p{i}")).collect();
-    let code = format!("<?php function f({}) {{}}", params.join(", "));
-    assert_no_errors(&code);
+fn missing_semicolon_not_inferred_in_open_bracket() {
+    let arena = bumpalo::Bump::new();
+    for src in ["<?php $x = [1,2\n$y = 3;", "<?php foo(1\n$y = 3;"] {
+        let result = parse_inferring_semicolons(&arena, src);
+        assert!(
+            !result.errors.iter().any(|e| matches!(
+                e,
+                php_rs_parser::diagnostics::ParseError::MissingSemicolon { .. }
+            )),
+            "{src:?}: {:?}",
+            result.errors
+        );
+    }
 }
 
+/// Semicolon inference is opt-in.
 #[test]
-fn many_array_elements() {
-    let elements: Vec<String> = (0..5_000).map(|i| i.to_string()).collect();
-    let code = format!("<?php [{}];", elements.join(", "));
-    assert_no_errors(&code);
+fn missing_semicolon_not_inferred_by_default() {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, "<?php\n$x = 1\n$y = 2;");
+    assert!(matches!(
+        result.errors.as_slice(),
+        [php_rs_parser::diagnostics::ParseError::ExpectedAfter { .. }]
+    ));
+}
+
+// ============================================================================
+// RESOURCE LIMITS
+// ParserOptions cannot be set from fixture files.
+// ============================================================================
+
+fn parse_limited(src: &str, options: php_rs_parser::ParserOptions) -> (usize, Vec<String>) {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_with_options(&arena, src, &options);
+    let messages = result.errors.iter().map(|e| e.to_string()).collect();
+    (result.program.stmts.len(), messages)
 }
 
 #[test]
-fn many_match_arms() {
-    let arms: Vec<String> = (0..500).map(|i| format!("{i} => {i}")).collect();
-    let code = format!("<?php match($x) {{ {} }};", arms.join(", "));
-    assert_no_errors(&code);
+fn token_limit_rejects_file_with_exact_count() {
+    let options = php_rs_parser::ParserOptions {
+        max_tokens: Some(5),
+        ..Default::default()
+    };
+    // `<?php`, `echo`, `1`, `;` — within the limit. Comments count too.
+    assert_eq!(parse_limited("<?php echo 1;", options), (1, vec![]));
+    assert_eq!(
+        parse_limited("<?php echo 1; echo 2; // done", options),
+        (
+            0,
+            vec!["token count of 8 tokens exceeds the configured maximum of 5 tokens".to_string()]
+        )
+    );
 }
 
 #[test]
-fn many_method_chains() {
-    let chain = "->m()".repeat(1_000);
-    let code = format!("<?php $obj{chain};");
-    assert_no_errors(&code);
+fn length_limits_report_each_token_and_keep_parsing() {
+    let options = php_rs_parser::ParserOptions {
+        max_string_length: Some(6),
+        max_identifier_length: Some(4),
+        ..Default::default()
+    };
+    let (stmts, errors) =
+        parse_limited("<?php $name = 'long string'; $ok = 'x'; longfn();", options);
+    assert_eq!(stmts, 3);
+    assert_eq!(
+        errors,
+        [
+            "string literal length of 13 bytes exceeds the configured maximum of 6 bytes",
+            "identifier length of 6 bytes exceeds the configured maximum of 4 bytes",
+        ]
+    );
 }
 
 #[test]
-fn many_class_members() {
-    let members: Vec<String> = (0..500)
-        .map(|i| format!("public int $p{i} = {i};"))
-        .collect();
-    let code = format!("<?php class C {{ {} }}", members.join("\n"));
-    assert_no_errors(&code);
+fn limits_are_unbounded_by_default() {
+    let src = format!("<?php echo '{}';", "x".repeat(100_000));
+    assert_eq!(
+        parse_limited(&src, php_rs_parser::ParserOptions::default()),
+        (1, vec![])
+    );
 }
 
-// ============================================================================
-// NULL BYTES
-// Cannot be expressed in .phpt fixture files.
-// ============================================================================
+#[test]
+fn expired_timeout_returns_partial_program_and_marker() {
+    use std::time::Duration;
+    // Unclosed constructs past the stop point must not add recovery errors.
+    let src = format!("<?php {} function f() {{ if (", "echo 1;\n".repeat(50_000));
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse_with_deadline(&arena, &src, Duration::from_millis(1));
+    assert!(result.timed_out);
+    assert_eq!(
+        result
+            .errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>(),
+        ["parsing timed out; the rest of the file was not parsed"]
+    );
+    let stopped_at = result.errors[0].span().start;
+    assert!(result.program.stmts.len() < 50_000);
+    assert!(result
+        .program
+        .stmts
+        .iter()
+        .all(|stmt| stmt.span.end <= stopped_at));
+}
 
 #[test]
-fn null_bytes_in_source() {
-    assert_has_errors("<?php $x = \0;");
+fn generous_timeout_matches_unbounded_parse() {
+    let src = "<?php function f($a) { return $a + ; }";
+    let arena = bumpalo::Bump::new();
+    let bounded =
+        php_rs_parser::parse_with_deadline(&arena, src, std::time::Duration::from_secs(60));
+    let unbounded = php_rs_parser::parse(&arena, src);
+    assert!(!bounded.timed_out);
+    assert_eq!(bounded.errors, unbounded.errors);
+    assert_eq!(
+        format!("{:?}", bounded.program),
+        format!("{:?}", unbounded.program)
+    );
 }
 
 // ============================================================================
-// UNTERMINATED STRINGS ENDING WITH MULTI-BYTE UTF-8 CHARACTERS
-// Slicing &str by byte index panics when the index lands inside a multi-byte
-// character (non-char boundary). These tests cover each string-literal kind.
+// DIAGNOSTIC ORDER
 // ============================================================================
 
-/// Original fuzzer crash: unterminated double-quoted string whose last bytes
-/// are the 2-byte UTF-8 encoding of U+05C7 (ׇ, bytes 0xD7 0x87).
+/// Errors come back in source order even when recovery reports a later
+/// position first, and repeated parses — on any thread — render identically.
 #[test]
-fn fuzz_crash_repro_double_quoted_multibyte_end() {
-    let data = b"\x3c\x3f\x3c\x3f\x70\x68\x70\x20\x63\x6c\x61\x73\x12\x24\x78\x22\x68\x65\x20\x3d\x20\x5b\x74\x70\x68\x70\x20\xd7\x87";
-    if let Ok(src) = std::str::from_utf8(data) {
+fn diagnostics_are_sorted_and_stable_across_threads() {
+    const SRC: &str = "<?php\ntry {\n    foo(\n} catch (Exception $e) {}\n$a = ;\nclass { }\n";
+    let render = || {
         let arena = bumpalo::Bump::new();
-        let _ = php_rs_parser::parse(&arena, src);
+        let result = php_rs_parser::parse(&arena, SRC);
+        let spans: Vec<_> = result.errors.iter().map(|e| e.span()).collect();
+        assert!(spans.windows(2).all(|w| w[0] <= w[1]), "{spans:?}");
+        result
+            .errors
+            .iter()
+            .map(|e| e.render(&result.source_map))
+            .collect::<Vec<_>>()
+    };
+    let expected = render();
+    assert!(expected.len() > 1);
+    let threads: Vec<_> = (0..4).map(|_| std::thread::spawn(render)).collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), expected);
     }
 }
 
-/// Unterminated double-quoted string ending mid-3-byte character (U+4E16 世).
 #[test]
-fn unterminated_double_quoted_ends_with_3byte_char() {
-    // "世 — opening quote, then the first 2 of 3 bytes of 世 (0xE4 0xB8 0x96)
-    let src = "<?php \"世\u{4E16}";
+fn render_underlines_related_spans_and_elides_lines_between() {
+    let src = "<?php\nfunction f() {\n    if ($a) {\n\t\techo 1;\n\n\n}\n";
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, src);
+    let options = php_rs_parser::diagnostics::RenderOptions {
+        path: Some("src/f.php"),
+        color: false,
+    };
+    assert_eq!(
+        php_rs_parser::diagnostics::render_with(&result.source_map, &result.errors, &options),
+        "\
+error: unclosed delimiter: expected '}'
+ --> src/f.php:8:1
+  |
+2 | function f() {
+  |              - opened here
+...
+8 |
+  | ^
+  = help: insert '}'
+",
+    );
+}
+
+#[test]
+fn render_names_the_feature_and_where_it_arrived() {
+    let arena = bumpalo::Bump::new();
+    let src = "<?php\nenum Suit {}\n";
+    let result = php_rs_parser::parse_versioned(&arena, src, php_rs_parser::PhpVersion::Php80);
+    assert_eq!(
+        php_rs_parser::diagnostics::render(&result.source_map, &result.errors),
+        "\
+error[enums]: 'enums' requires PHP 8.1 or higher (targeting PHP 8.0)
+ --> 2:1
+  |
+2 | enum Suit {}
+  | ^^^^
+  = note: enums arrived in PHP 8.1: https://www.php.net/manual/en/migration81.new-features.php
+",
+    );
+}
+
+#[test]
+fn render_expands_tabs_and_separates_diagnostics() {
+    let arena = bumpalo::Bump::new();
+    let src = "<?php\n\t$x = (real) $y;\n\t$s = \"abc";
+    let result = php_rs_parser::parse_versioned(&arena, src, php_rs_parser::PhpVersion::Php74);
+    assert_eq!(
+        php_rs_parser::diagnostics::render(&result.source_map, &result.errors),
+        "\
+warning: the (real) cast is deprecated and removed in PHP 8.0 (targeting PHP 7.4)
+ --> 2:8
+  |
+2 |     $x = (real) $y;
+  |           ^^^^
+  = note: deprecated since PHP 7.4
+
+error: unterminated string literal
+ --> 3:7
+  |
+3 |     $s = \"abc
+  |          ^^^^
+
+error: expected ';' after expression
+ --> 3:11
+  |
+3 |     $s = \"abc
+  |              ^
+",
+    );
+}
+
+#[test]
+fn render_colors_with_ansi_escapes() {
     let arena = bumpalo::Bump::new();
-    let _ = php_rs_parser::parse(&arena, src);
+    let result = php_rs_parser::parse(&arena, "<?php\n$a = ;");
+    let options = php_rs_parser::diagnostics::RenderOptions {
+        path: None,
+        color: true,
+    };
+    let rendered =
+        php_rs_parser::diagnostics::render_with(&result.source_map, &result.errors, &options);
+    assert!(rendered.starts_with("\x1b[1;31merror\x1b[0m\x1b[1m: expected expression"));
+    assert!(rendered.ends_with("\x1b[1;34m|\x1b[0m      \x1b[1;31m^\x1b[0m\n"));
 }
 
-/// Unterminated single-quoted string ending with a 2-byte UTF-8 character.
 #[test]
-fn unterminated_single_quoted_ends_with_multibyte_char() {
-    let src = "<?php 'héllo"; // unterminated, ends with 'o' but has 2-byte é
+fn render_points_at_the_alternative_block_missing_its_end() {
     let arena = bumpalo::Bump::new();
-    let _ = php_rs_parser::parse(&arena, src);
-    // Variant: last char is the multi-byte one
-    let src2 = "<?php 'hé"; // unterminated, last char is 2-byte é
-    let arena2 = bumpalo::Bump::new();
-    let _ = php_rs_parser::parse(&arena2, src2);
+    let src = "<?php\nfunction f($a) {\n    if ($a):\n        echo 1;\n}\n";
+    let result = php_rs_parser::parse(&arena, src);
+    assert_eq!(
+        php_rs_parser::diagnostics::render(&result.source_map, &result.errors),
+        "\
+error: expected 'endif' to close the block, found '}'
+ --> 5:1
+  |
+3 |     if ($a):
+  |     -- block opened here
+...
+5 | }
+  | ^
+  = help: insert 'endif;'
+",
+    );
 }
 
-/// Unterminated backtick string ending with a 2-byte UTF-8 character.
 #[test]
-fn unterminated_backtick_ends_with_multibyte_char() {
-    let src = "<?php `cmd é"; // unterminated backtick, last char is 2-byte
+fn max_errors_stops_parsing_with_partial_program() {
+    // Each `$x = ;` is one error; the statements after the third are not parsed.
+    let src = format!("<?php echo 1;\n{}", "$x = ;\n".repeat(1_000));
+    let options = php_rs_parser::ParserOptions {
+        max_errors: Some(3),
+        ..Default::default()
+    };
     let arena = bumpalo::Bump::new();
-    let _ = php_rs_parser::parse(&arena, src);
+    let result = php_rs_parser::parse_with_options(&arena, &src, &options);
+    assert!(result.truncated);
+    assert!(!result.errors_truncated);
+    assert_eq!(result.errors.len(), 3);
+    let stopped_at = result.errors[2].span().end;
+    assert!(result.program.stmts.len() <= 4);
+    assert!(result
+        .program
+        .stmts
+        .iter()
+        .all(|stmt| stmt.span.start <= stopped_at));
+
+    let unbounded = php_rs_parser::parse(&arena, &src);
+    assert!(!unbounded.truncated);
+    assert!(unbounded.errors_truncated);
+    assert_eq!(unbounded.program.stmts.len(), 1_001);
 }
 
-/// b-prefixed double-quoted string, unterminated, ending with multi-byte char.
 #[test]
-fn unterminated_b_prefixed_double_quoted_ends_with_multibyte_char() {
-    let src = "<?php b\"héllo"; // b-prefix, unterminated
+fn max_errors_ignores_warnings_and_counts_limit_errors() {
+    let options = php_rs_parser::ParserOptions {
+        max_errors: Some(1),
+        ..Default::default()
+    };
     let arena = bumpalo::Bump::new();
-    let _ = php_rs_parser::parse(&arena, src);
+    // A misspelled open tag is a warning, not an error.
+    let result = php_rs_parser::parse_with_options(&arena, "<?PHP $a = 1; $b = 2;", &options);
+    assert_eq!(result.errors.len(), 1);
+    assert!(!result.truncated);
+    assert_eq!(result.program.stmts.len(), 2);
+
+    // Errors found before parsing count as well.
+    let options = php_rs_parser::ParserOptions {
+        max_identifier_length: Some(2),
+        ..options
+    };
+    let result =
+        php_rs_parser::parse_with_options(&arena, "<?php $a = 1; $long = 2; $c = ;", &options);
+    assert!(result.truncated);
+    assert_eq!(result.errors.len(), 1);
+    assert!(result.program.stmts.is_empty());
 }
@@ -0,0 +1,90 @@
+//! The operator metadata in `php_ast::precedence` against the parser's
+//! grouping.
+
+use php_ast::precedence::{Associativity, Operator};
+use php_ast::{Expr, ExprKind};
+use php_rs_parser::parse_expr_str;
+
+/// The symbol of the operator at the root of `src`.
+fn root(src: &str) -> &'static str {
+    let arena = bumpalo::Bump::new();
+    let result = parse_expr_str(&arena, src);
+    assert!(result.errors.is_empty(), "{src}: {:?}", result.errors);
+    Operator::of(&result.node.kind)
+        .unwrap_or_else(|| panic!("{src}: no operator at the root"))
+        .symbol()
+}
+
+fn operands<'a, 'arena, 'src>(
+    expr: &'a Expr<'arena, 'src>,
+) -> Option<(&'a Expr<'arena, 'src>, &'a Expr<'arena, 'src>)> {
+    match &expr.kind {
+        ExprKind::Binary(binary) => Some((binary.left, binary.right)),
+        ExprKind::Assign(assign) => Some((assign.target, assign.value)),
+        ExprKind::NullCoalesce(coalesce) => Some((coalesce.left, coalesce.right)),
+        _ => None,
+    }
+}
+
+#[test]
+fn keyword_operators_group_by_their_level() {
+    assert_eq!(root("print $a and $b"), "and");
+    assert_eq!(root("$a and print $b"), "and");
+    assert_eq!(root("require $a or $b"), "require");
+    assert_eq!(root("include $dir . '/file.php'"), "include");
+    assert_eq!(root("throw $a ?? $b"), "throw");
+    assert_eq!(root("throw $a or $b"), "throw");
+    assert_eq!(root("!$a instanceof B"), "!");
+    assert_eq!(root("@$a + 1"), "+");
+    assert_eq!(root("(int) $a . 'x'"), ".");
+    assert_eq!(root("clone $a ?? $b"), "??");
+    assert_eq!(root("-$a ** 2"), "-");
+}
+
+#[test]
+fn symbolic_operators_group_by_their_level() {
+    assert_eq!(root("$a = $b or $c"), "or");
+    assert_eq!(root("$a ?? $b ? 1 : 2"), "?:");
+    assert_eq!(root("$a < $b == $c"), "==");
+    assert_eq!(root("$a . $b |> strlen(...)"), "|>");
+    assert_eq!(root("1 << 2 . '3'"), ".");
+}
+
+#[test]
+fn infix_operands_bind_tighter_or_associate() {
+    for src in [
+        "$a - $b - $c",
+        "$a ** $b ** $c",
+        "$a = $b = $c",
+        "$a ?? $b ?? $c",
+        "$a || $b && $c | $d ^ $e & $f",
+        "$a or $b xor $c and $d",
+        "$a . $b + $c * $d",
+    ] {
+        let arena = bumpalo::Bump::new();
+        let result = parse_expr_str(&arena, src);
+        assert!(result.errors.is_empty(), "{src}: {:?}", result.errors);
+        let mut pending = vec![&result.node];
+        while let Some(expr) = pending.pop() {
+            let Some((left, right)) = operands(expr) else {
+                continue;
+            };
+            let op = Operator::of(&expr.kind).unwrap();
+            for (operand, associates) in
+                [(left, Associativity::Left), (right, Associativity::Right)]
+            {
+                if let Some(inner) = Operator::of(&operand.kind) {
+                    assert!(
+                        inner.precedence() > op.precedence()
+                            || inner.precedence() == op.precedence()
+                                && op.associativity() == associates,
+                        "{src}: `{}` under `{}`",
+                        inner.symbol(),
+                        op.symbol()
+                    );
+                }
+                pending.push(operand);
+            }
+        }
+    }
+}
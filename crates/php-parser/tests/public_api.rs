@@ -0,0 +1,157 @@
+//! Pins the signatures of the stable public API. A change that would break
+//! downstream code — a renamed item, a changed parameter or field type, an
+//! item dropped from the prelude — stops this file from compiling. Update it
+//! only together with a breaking-change entry in the changelog.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use bumpalo::Bump;
+use php_ast::source_map::SourceMap;
+use php_ast::{Comment, Expr, Program, Span, Stmt};
+use php_rs_parser::diagnostics::{self, Diagnostic, ParseError, RenderOptions, Severity};
+use php_rs_parser::parser::Parser;
+use php_rs_parser::{FragmentResult, FunctionReparse, ParseResult, ParserOptions, PhpVersion};
+
+type Parse = for<'arena, 'src> fn(&'arena Bump, &'src str) -> ParseResult<'arena, 'src>;
+type ParseVersioned =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, PhpVersion) -> ParseResult<'arena, 'src>;
+type ParseWithOptions =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, &ParserOptions) -> ParseResult<'arena, 'src>;
+type ParseWithDeadline =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, Duration) -> ParseResult<'arena, 'src>;
+type ParseIslands =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, &[Span]) -> ParseResult<'arena, 'src>;
+type ParseIslandsVersioned =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, &[Span], PhpVersion) -> ParseResult<'arena, 'src>;
+type ParseBytes = for<'arena> fn(&'arena Bump, &'arena [u8]) -> ParseResult<'arena, 'arena>;
+type ParseBytesWithOptions =
+    for<'arena> fn(&'arena Bump, &'arena [u8], &ParserOptions) -> ParseResult<'arena, 'arena>;
+type ParseExprStr =
+    for<'arena, 'src> fn(&'arena Bump, &'src str) -> FragmentResult<'src, Expr<'arena, 'src>>;
+type ParseStmtStr =
+    for<'arena, 'src> fn(&'arena Bump, &'src str) -> FragmentResult<'src, Stmt<'arena, 'src>>;
+type ReparseFunction =
+    for<'arena, 'src> fn(&'arena Bump, &'src str, Span) -> FunctionReparse<'arena, 'src>;
+
+#[test]
+fn entry_points() {
+    let _: Parse = php_rs_parser::parse;
+    let _: ParseVersioned = php_rs_parser::parse_versioned;
+    let _: ParseWithOptions = php_rs_parser::parse_with_options;
+    let _: ParseWithDeadline = php_rs_parser::parse_with_deadline;
+    let _: ParseIslands = php_rs_parser::parse_islands;
+    let _: ParseIslandsVersioned = php_rs_parser::parse_islands_versioned;
+    let _: ParseExprStr = php_rs_parser::parse_expr_str;
+    let _: ParseStmtStr = php_rs_parser::parse_stmt_str;
+    let _: ReparseFunction = php_rs_parser::reparse_function;
+    let _: ParseBytes = php_rs_parser::parse_bytes;
+    let _: ParseBytesWithOptions = php_rs_parser::parse_bytes_with_options;
+    let _: fn(&[u8]) -> std::borrow::Cow<'_, str> = php_rs_parser::bytes::decode;
+    let _: fn(&str) -> std::borrow::Cow<'_, [u8]> = php_rs_parser::bytes::encode;
+}
+
+#[test]
+fn prelude_entry_points() {
+    use php_rs_parser::prelude::*;
+
+    let _: Parse = parse;
+    let _: ParseVersioned = parse_versioned;
+    let _: ParseWithOptions = parse_with_options;
+    let _: ParseExprStr = parse_expr_str;
+    let _: ParseStmtStr = parse_stmt_str;
+    let _: fn() -> ParserContext = ParserContext::new;
+    let _: fn(&'static Bump) -> Session<'static> = Session::new;
+    let _: fn(FileId) -> usize = FileId::index;
+    let _: fn(&ParseError) -> Severity = ParseError::severity;
+    let _: fn(&ParseError) -> Span = ParseError::span;
+}
+
+#[test]
+fn parse_result_fields() {
+    fn fields<'arena, 'src>(result: &ParseResult<'arena, 'src>) {
+        let _: &&'src str = &result.source;
+        let _: &Program<'arena, 'src> = &result.program;
+        let _: &Vec<Comment<'src>> = &result.comments;
+        let _: &Vec<ParseError> = &result.errors;
+        let _: &bool = &result.errors_truncated;
+        let _: &bool = &result.timed_out;
+        let _: &bool = &result.truncated;
+        let _: &SourceMap<'src> = &result.source_map;
+    }
+
+    let arena = Bump::new();
+    fields(&php_rs_parser::parse(&arena, ""));
+}
+
+#[test]
+fn fragment_result_fields() {
+    fn fields<'src, T>(result: &FragmentResult<'src, T>) {
+        let _: &&'src str = &result.source;
+        let _: &T = &result.node;
+        let _: &Vec<Comment<'src>> = &result.comments;
+        let _: &Vec<ParseError> = &result.errors;
+        let _: &bool = &result.errors_truncated;
+        let _: &SourceMap<'src> = &result.source_map;
+    }
+
+    let arena = Bump::new();
+    fields(&php_rs_parser::parse_expr_str(&arena, "1"));
+}
+
+#[test]
+fn parser_options_fields() {
+    // Options are built with struct update syntax, so they stay exhaustive.
+    let options = ParserOptions {
+        version: PhpVersion::Php80,
+        max_file_size: Some(1),
+        max_tokens: Some(1),
+        max_string_length: Some(1),
+        max_identifier_length: Some(1),
+        timeout: Some(Duration::ZERO),
+        max_errors: Some(1),
+        infer_semicolons: true,
+    };
+    let _ = ParserOptions {
+        version: options.version,
+        ..Default::default()
+    };
+}
+
+#[test]
+fn diagnostics_helpers() {
+    let _: fn(&mut [ParseError]) = diagnostics::sort_diagnostics;
+    let _: for<'e> fn(&'e [ParseError], &SourceMap<'_>) -> BTreeMap<u32, Vec<&'e ParseError>> =
+        diagnostics::group_by_line;
+    let _: fn(&SourceMap<'_>, &[ParseError]) -> String = diagnostics::render;
+    let _: fn(&SourceMap<'_>, &[ParseError], &RenderOptions<'_>) -> String =
+        diagnostics::render_with;
+    let _: fn(&ParseError) -> Diagnostic = ParseError::to_diagnostic;
+    let _: fn(Severity, &'static str, Span) -> Diagnostic = Diagnostic::new;
+}
+
+#[test]
+fn diagnostic_fields() {
+    let diagnostic = Diagnostic::new(Severity::Warning, "w", Span::new(0, 1));
+    let _: &Option<&'static str> = &diagnostic.code;
+    let _: &Severity = &diagnostic.severity;
+    let _: &String = &diagnostic.message;
+    let _: &Span = &diagnostic.span;
+    let _: &[diagnostics::Label] = &diagnostic.labels;
+    let _: &[String] = &diagnostic.notes;
+    let _: &[diagnostics::Suggestion] = &diagnostic.suggestions;
+}
+
+#[test]
+fn embedder_parser() {
+    // The impl's lifetimes are early-bound, so pin each method at one pair.
+    fn methods<'arena, 'src: 'arena>() {
+        let _: fn(&'arena Bump, &'src str) -> Parser<'arena, 'src> = Parser::new;
+        let _: fn(&'arena Bump, &'src str, usize, PhpVersion) -> Parser<'arena, 'src> =
+            Parser::new_at;
+        let _: fn(&mut Parser<'arena, 'src>) -> Program<'arena, 'src> = Parser::parse_program;
+        let _: fn(Parser<'arena, 'src>) -> Vec<ParseError> = Parser::into_errors;
+    }
+
+    methods();
+}
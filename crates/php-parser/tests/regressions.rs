@@ -0,0 +1,94 @@
+//! Replays the crash, hang, and stress inputs in `tests/regressions/`.
+//!
+//! Every file in that directory (except `README.md`) is fed to the parser the
+//! way the `fuzz_parse` target does: raw bytes, parsed only if they are valid
+//! UTF-8. Each case must return without panicking, without overflowing the
+//! stack, and within the time limit; a `valid-…` case must also parse
+//! without errors. Add fuzzer cases with
+//! `scripts/add-fuzz-regression.sh <artifact>`.
+//!
+//! A stack overflow aborts the whole test binary; rerun with `--nocapture` to
+//! see which case was being replayed.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Wall-clock budget per case in milliseconds, generous enough for an
+/// unoptimized build. Override with `REGRESSION_TIME_LIMIT_MS`.
+const TIME_LIMIT_MS: u64 = 2_000;
+
+/// Stack size of the thread each case runs on: the large stack the
+/// nesting stress tests use, since unoptimized frames are bigger than those
+/// of the optimized fuzz build.
+const STACK_SIZE: usize = 16 * 1024 * 1024;
+
+fn collect_cases(dir: &Path) -> Vec<PathBuf> {
+    let mut cases: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file() && path.file_name().is_some_and(|n| n != "README.md"))
+        .collect();
+    cases.sort();
+    cases
+}
+
+/// Parse `data` on a fresh thread and return its number of errors; `Err`
+/// describes a panic or a timeout.
+fn replay(data: Vec<u8>, limit: Duration) -> Result<usize, String> {
+    let (done, finished) = mpsc::channel();
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let mut errors = 0;
+            if let Ok(src) = std::str::from_utf8(&data) {
+                let arena = bumpalo::Bump::new();
+                errors = php_rs_parser::parse(&arena, src).errors.len();
+            }
+            let _ = done.send(errors);
+        })
+        .expect("failed to spawn replay thread");
+    match finished.recv_timeout(limit) {
+        Ok(errors) => Ok(errors),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            Err(format!("did not finish within {} ms", limit.as_millis()))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err("panicked".to_string()),
+    }
+}
+
+#[test]
+fn fuzz_regressions() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/regressions");
+    let limit = std::env::var("REGRESSION_TIME_LIMIT_MS")
+        .ok()
+        .and_then(|ms| ms.parse().ok())
+        .map_or(Duration::from_millis(TIME_LIMIT_MS), Duration::from_millis);
+
+    let cases = collect_cases(&dir);
+    assert!(!cases.is_empty(), "no cases in {}", dir.display());
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|path| {
+            let data = std::fs::read(path).unwrap();
+            let name = path.file_name().unwrap().to_string_lossy();
+            eprintln!("replaying {name}");
+            match replay(data, limit) {
+                Ok(errors) if errors > 0 && name.starts_with("valid-") => {
+                    Some(format!("{name}: {errors} parse errors"))
+                }
+                Ok(_) => None,
+                Err(why) => Some(format!("{name}: {why}")),
+            }
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} regressions failed:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,107 @@
+//! Tests for `reparse_function`: re-parsing one declaration in place.
+
+use php_ast::{ClassMemberKind, Span, StmtKind};
+use php_rs_parser::{reparse_function, ReparsedFunction};
+
+/// Span from the first occurrence of `from` to the end of the first `to`
+/// after it.
+fn span_of(src: &str, from: &str, to: &str) -> Span {
+    let start = src.find(from).unwrap();
+    let end = start + src[start..].find(to).unwrap() + to.len();
+    Span::new(start as u32, end as u32)
+}
+
+fn method_name(function: &Option<ReparsedFunction<'_, '_>>) -> Option<String> {
+    match function {
+        Some(ReparsedFunction::Method(member)) => match &member.kind {
+            ClassMemberKind::Method(method) => method.name.as_str().map(str::to_string),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Every function and method span from a full parse reparses to a node with
+/// the same span and no errors.
+#[test]
+fn reparses_declarations_from_full_parse() {
+    let src = "<?php
+namespace App;
+echo Foo::class;
+#[Pure]
+function top(int $a): int { return $a + 1; }
+interface I { public function shape(): string; }
+enum E: string { case A = 'a'; public function label(): string { return 'x'; } }
+final class C extends B {
+    public function __construct(private int $x) {}
+    public static function make(): static { return new static(1); }
+}
+";
+    let arena = bumpalo::Bump::new();
+    let full = php_rs_parser::parse(&arena, src);
+    assert!(full.errors.is_empty());
+
+    let mut spans = Vec::new();
+    for stmt in full.program.stmts.iter() {
+        match &stmt.kind {
+            StmtKind::Function(_) => spans.push((stmt.span, false)),
+            StmtKind::Class(class) => {
+                spans.extend(class.members.iter().map(|m| (m.span, true)));
+            }
+            StmtKind::Interface(interface) => {
+                spans.extend(interface.members.iter().map(|m| (m.span, true)));
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(spans.len(), 4);
+
+    for (span, is_method) in spans {
+        let arena = bumpalo::Bump::new();
+        let result = reparse_function(&arena, src, span);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        match result.function {
+            Some(ReparsedFunction::Method(member)) if is_method => assert_eq!(member.span, span),
+            Some(ReparsedFunction::Function(stmt)) if !is_method => assert_eq!(stmt.span, span),
+            other => panic!("unexpected reparse of {span:?}: {other:?}"),
+        }
+    }
+
+    let span = span_of(src, "public function label", "'x'; }");
+    let result = reparse_function(&arena, src, span);
+    assert_eq!(method_name(&result.function).as_deref(), Some("label"));
+}
+
+/// A method of an anonymous class inside a function body is still a method;
+/// a closure body before it does not hide the class.
+#[test]
+fn reparses_method_of_anonymous_class() {
+    let src = "<?php function f() { $g = function () {}; return new class { function run() {} }; }";
+    let arena = bumpalo::Bump::new();
+    let result = reparse_function(&arena, src, span_of(src, "function run", "{}"));
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(method_name(&result.function).as_deref(), Some("run"));
+}
+
+/// Errors inside the range carry absolute offsets, and leftover tokens are
+/// reported.
+#[test]
+fn reports_errors_with_absolute_spans() {
+    let src = "<?php class A {\n    function broken() { $x = ; }\n    function ok() {}\n}";
+    let arena = bumpalo::Bump::new();
+    let result = reparse_function(&arena, src, span_of(src, "function broken", "; }"));
+    assert_eq!(method_name(&result.function).as_deref(), Some("broken"));
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(
+        result.errors[0].span().start as usize,
+        src.find("; }").unwrap()
+    );
+
+    let span = span_of(src, "function ok", "}\n}");
+    let result = reparse_function(&arena, src, span);
+    assert_eq!(method_name(&result.function).as_deref(), Some("ok"));
+    assert!(matches!(
+        result.errors.as_slice(),
+        [php_rs_parser::diagnostics::ParseError::Expected { .. }]
+    ));
+}
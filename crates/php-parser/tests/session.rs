@@ -0,0 +1,46 @@
+//! Tests for `Session`: many files, one context.
+
+use php_rs_parser::{ParserOptions, PhpVersion, Session};
+
+#[test]
+fn reparsing_a_path_keeps_its_handle() {
+    let arena = bumpalo::Bump::new();
+    let mut session = Session::new(&arena);
+    let a = session.parse("a.php", "<?php a(;");
+    let b = session.parse("b.php", "<?php echo 1;");
+    assert!(session.has_errors());
+
+    assert_eq!(session.parse("a.php", "<?php a();"), a);
+    assert_eq!(session.len(), 2);
+    assert_eq!(session.files().collect::<Vec<_>>(), [a, b]);
+    assert_eq!(session.source(a), "<?php a();");
+    assert!(!session.has_errors());
+    assert_eq!(session.render_diagnostics(), "");
+}
+
+#[test]
+fn options_apply_to_every_file() {
+    let arena = bumpalo::Bump::new();
+    let options = ParserOptions {
+        version: PhpVersion::Php80,
+        ..ParserOptions::default()
+    };
+    let mut session = Session::with_options(&arena, options);
+    let a = session.parse("a.php", "<?php enum A {}");
+    let b = session.parse("b.php", "<?php enum B {}");
+
+    let files: Vec<_> = session.diagnostics().map(|(file, _)| file).collect();
+    assert_eq!(files, [a, b]);
+    let rendered = session.render_diagnostics();
+    assert!(rendered.contains("--> a.php:1:7"), "{rendered}");
+    assert!(rendered.contains("--> b.php:1:7"), "{rendered}");
+}
+
+#[test]
+fn unknown_paths_have_no_handle() {
+    let arena = bumpalo::Bump::new();
+    let mut session = Session::new(&arena);
+    assert!(session.is_empty());
+    session.parse("a.php", "<?php");
+    assert_eq!(session.file_id("b.php"), None);
+}
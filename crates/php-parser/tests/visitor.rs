@@ -826,7 +826,26 @@ fn visits_catch_clause_types() {
         |_src, program| {
             let mut v = NameCollector::default();
             let _ = v.visit_program(program);
-            assert_eq!(v.names, vec!["RuntimeException", "LogicException"]);
+            // The callees `foo` and `bar` are expression names and are visited too.
+            assert_eq!(
+                v.names,
+                vec!["foo", "RuntimeException", "LogicException", "bar"]
+            );
+        },
+    );
+}
+
+#[test]
+fn visits_expression_names() {
+    with_parsed(
+        "<?php new \\App\\User(); Foo\\Bar::create(); namespace\\helper(); echo PHP_EOL; $x->method();",
+        |_src, program| {
+            let mut v = NameCollector::default();
+            let _ = v.visit_program(program);
+            assert_eq!(
+                v.names,
+                vec!["\\App\\User", "Foo\\Bar", "namespace\\helper", "PHP_EOL"]
+            );
         },
     );
 }
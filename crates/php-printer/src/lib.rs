@@ -9,10 +9,18 @@
 //! assert_eq!(output, "<?php\necho 1 + 2;");
 //! ```
 
+mod options;
 mod precedence;
 mod printer;
+mod range;
+mod signature;
 
-pub use printer::{Indent, PrinterConfig};
+pub use options::{
+    BraceStyle, CommaList, CommentPolicy, FinalCloseTag, HeredocStyle, Indent, PrintOptions,
+    PrintOptionsError, QuoteStyle, TrailingCommas,
+};
+pub use range::format_range;
+pub use signature::{print_signature, print_type_hint};
 
 use php_ast::{Comment, Program};
 
@@ -21,19 +29,22 @@ use php_ast::{Comment, Program};
 /// For programs that start with PHP code, the output begins with `<?php\n`.
 /// For programs that start with inline HTML, the HTML is emitted as-is.
 pub fn pretty_print(program: &Program) -> String {
-    pretty_print_with_config(program, &PrinterConfig::default())
+    pretty_print_with_config(program, &PrintOptions::default())
 }
 
-/// Pretty-print a PHP program and append a trailing newline.
+/// Pretty-print a PHP program and append a trailing newline, unless it ends
+/// with the data after `__halt_compiler();`, which is printed byte for byte.
 pub fn pretty_print_file(program: &Program) -> String {
     let mut out = pretty_print(program);
-    out.push('\n');
+    if program.halt_offset().is_none() {
+        out.push('\n');
+    }
     out
 }
 
-/// Pretty-print with custom configuration.
-pub fn pretty_print_with_config(program: &Program, config: &PrinterConfig) -> String {
-    let mut p = printer::Printer::new(config);
+/// Pretty-print in the given output style.
+pub fn pretty_print_with_config(program: &Program, options: &PrintOptions) -> String {
+    let mut p = printer::Printer::new(options);
     p.print_program(program);
     p.into_output()
 }
@@ -44,17 +55,17 @@ pub fn pretty_print_with_comments<'src>(
     source: &'src str,
     comments: &'src [Comment<'src>],
 ) -> String {
-    pretty_print_with_comments_and_config(program, source, comments, &PrinterConfig::default())
+    pretty_print_with_comments_and_config(program, source, comments, &PrintOptions::default())
 }
 
-/// Pretty-print with all comments preserved and custom configuration.
+/// Pretty-print with all comments preserved, in the given output style.
 pub fn pretty_print_with_comments_and_config<'src>(
     program: &Program<'_, 'src>,
     source: &'src str,
     comments: &'src [Comment<'src>],
-    config: &PrinterConfig,
+    options: &PrintOptions,
 ) -> String {
-    let mut p = printer::Printer::with_comments(config, source, comments);
+    let mut p = printer::Printer::with_comments(options, source, comments);
     p.print_program(program);
     p.into_output()
 }
@@ -0,0 +1,361 @@
+//! Output style of the printer.
+
+use std::fmt;
+
+/// Output style of the printer, shared by everything that prints PHP.
+///
+/// Options are set with the `with_*` methods, which reject values the
+/// printer cannot honour:
+///
+/// ```
+/// use php_printer::{BraceStyle, Indent, PrintOptions, PrintOptionsError};
+///
+/// let options = PrintOptions::default()
+///     .with_indent(Indent::Spaces(2))?
+///     .with_brace_style(BraceStyle::SameLine);
+/// assert_eq!(options.indent(), Indent::Spaces(2));
+///
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, "<?php function f() { return 1; }");
+/// let output = php_printer::pretty_print_with_config(&result.program, &options);
+/// assert_eq!(output, "<?php\nfunction f() {\n  return 1;\n}");
+///
+/// assert_eq!(
+///     PrintOptions::default().with_indent(Indent::Spaces(0)),
+///     Err(PrintOptionsError::IndentWidth(0)),
+/// );
+/// # Ok::<(), PrintOptionsError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintOptions {
+    indent: Indent,
+    newline: &'static str,
+    blank_lines_upper_bound: usize,
+    brace_style: BraceStyle,
+    quote_style: QuoteStyle,
+    trailing_commas: [TrailingCommas; CommaList::ALL.len()],
+    line_width: usize,
+    heredoc: HeredocStyle,
+    comment_policy: CommentPolicy,
+    final_close_tag: FinalCloseTag,
+}
+
+/// Indentation style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// 1 to [`PrintOptions::MAX_INDENT_WIDTH`] spaces per level.
+    Spaces(usize),
+    Tabs,
+}
+
+/// Where the opening `{` of a body goes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BraceStyle {
+    /// PSR-12: on its own line for functions, methods, and class-likes; on
+    /// the same line for control structures, closures, and namespaces.
+    #[default]
+    Psr12,
+    /// Always on the same line: `function f() {`.
+    SameLine,
+    /// Always on its own line, with `else`, `catch`, `finally`, and the
+    /// `while` of a `do` on their own lines too.
+    NextLine,
+}
+
+/// The quotes of string literals without interpolation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Single quotes, unless the text has characters only double quotes can
+    /// escape, such as a newline.
+    #[default]
+    Single,
+    /// Double quotes, unless the text has a `"` or `\` that single quotes
+    /// would print without an escape.
+    Double,
+}
+
+/// Whether the last element of a list is followed by a comma.
+///
+/// A comma after an omitted destructuring slot, as in `[$a, ,] = $b`, is
+/// part of the target and is printed whatever the setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrailingCommas {
+    /// When the list is printed over several lines. The printer only breaks
+    /// argument lists and arrays, and always breaks `match` arms.
+    #[default]
+    MultiLine,
+    Never,
+    /// When the source has one, recorded as the `comma` of the last
+    /// element. Lists built without a source get none.
+    Preserve,
+}
+
+/// A kind of comma-separated list, to set [`TrailingCommas`] for with
+/// [`PrintOptions::with_trailing_commas_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommaList {
+    /// Arguments of calls, `new`, and attributes.
+    Args,
+    /// Parameters of functions, methods, closures, and arrow functions.
+    Params,
+    /// Array literals and `list()` targets.
+    Arrays,
+    /// The arms of `match`.
+    MatchArms,
+    /// The variables of a closure's `use (...)`.
+    ClosureUses,
+    /// The items of a group use, `use A\{B, C}`.
+    GroupUses,
+}
+
+impl CommaList {
+    /// Every kind of list, in declaration order.
+    pub const ALL: [CommaList; 6] = [
+        CommaList::Args,
+        CommaList::Params,
+        CommaList::Arrays,
+        CommaList::MatchArms,
+        CommaList::ClosureUses,
+        CommaList::GroupUses,
+    ];
+}
+
+/// How heredocs and nowdocs are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeredocStyle {
+    /// As heredocs, with the body and closing label at the start of the line.
+    #[default]
+    Flush,
+    /// As heredocs with the body and closing label indented one level past
+    /// the enclosing statement (PHP 7.3+).
+    Indented,
+    /// As double-quoted strings, and nowdocs as string literals.
+    String,
+}
+
+/// Where comments go when printing with the source's comments.
+///
+/// Every comment is printed exactly once, so an AST rewritten by a codemod
+/// keeps the comments of the nodes it moved or dropped. A comment the
+/// printer has no place for, such as one between the arguments of a call,
+/// goes on its own line at the next place comments can go: before the next
+/// statement or member, or before the closing brace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommentPolicy {
+    /// A comment after a statement or class member on the same line stays at
+    /// the end of that line: `$a = 1; // why`. Other comments go on their own
+    /// line before the next statement or member.
+    #[default]
+    Trailing,
+    /// Every comment goes on its own line before the next statement or member.
+    OwnLine,
+}
+
+/// What becomes of a `?>` that ends the PHP code of a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FinalCloseTag {
+    /// Printed as in the source, so the file still ends in HTML mode.
+    #[default]
+    Keep,
+    /// Dropped, as PSR-12 asks of files containing only PHP, when nothing
+    /// PHP would output follows it: no text, or only the line break the
+    /// `?>` swallows.
+    Omit,
+}
+
+/// A value rejected by a [`PrintOptions`] setter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrintOptionsError {
+    /// An indentation of zero spaces, or more than
+    /// [`PrintOptions::MAX_INDENT_WIDTH`].
+    IndentWidth(usize),
+    /// A line ending other than `\n` or `\r\n`.
+    Newline(String),
+    /// A line width that does not leave room for one level of indentation.
+    LineWidth(usize),
+}
+
+impl fmt::Display for PrintOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrintOptionsError::IndentWidth(width) => write!(
+                f,
+                "indent width must be between 1 and {} spaces, got {width}",
+                PrintOptions::MAX_INDENT_WIDTH
+            ),
+            PrintOptionsError::Newline(newline) => {
+                write!(f, "line ending must be \\n or \\r\\n, got {newline:?}")
+            }
+            PrintOptionsError::LineWidth(width) => {
+                write!(
+                    f,
+                    "line width {width} is not wider than one indentation level"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrintOptionsError {}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            indent: Indent::Spaces(4),
+            newline: "\n",
+            blank_lines_upper_bound: 1,
+            brace_style: BraceStyle::Psr12,
+            quote_style: QuoteStyle::Single,
+            trailing_commas: [TrailingCommas::MultiLine; CommaList::ALL.len()],
+            line_width: 120,
+            heredoc: HeredocStyle::Flush,
+            comment_policy: CommentPolicy::Trailing,
+            final_close_tag: FinalCloseTag::Keep,
+        }
+    }
+}
+
+impl PrintOptions {
+    /// The widest indentation level, in spaces.
+    pub const MAX_INDENT_WIDTH: usize = 16;
+
+    pub fn with_indent(self, indent: Indent) -> Result<Self, PrintOptionsError> {
+        if let Indent::Spaces(width) = indent {
+            if !(1..=Self::MAX_INDENT_WIDTH).contains(&width) {
+                return Err(PrintOptionsError::IndentWidth(width));
+            }
+        }
+        let options = Self { indent, ..self };
+        if options.line_width <= options.indent_width() {
+            return Err(PrintOptionsError::LineWidth(options.line_width));
+        }
+        Ok(options)
+    }
+
+    /// The line ending, `\n` or `\r\n`.
+    pub fn with_newline(self, newline: &'static str) -> Result<Self, PrintOptionsError> {
+        if !matches!(newline, "\n" | "\r\n") {
+            return Err(PrintOptionsError::Newline(newline.to_string()));
+        }
+        Ok(Self { newline, ..self })
+    }
+
+    /// Maximum blank lines preserved between statements. 0 normalizes all
+    /// blank lines away.
+    pub fn with_blank_lines_upper_bound(self, blank_lines_upper_bound: usize) -> Self {
+        Self {
+            blank_lines_upper_bound,
+            ..self
+        }
+    }
+
+    pub fn with_brace_style(self, brace_style: BraceStyle) -> Self {
+        Self {
+            brace_style,
+            ..self
+        }
+    }
+
+    pub fn with_quote_style(self, quote_style: QuoteStyle) -> Self {
+        Self {
+            quote_style,
+            ..self
+        }
+    }
+
+    /// The trailing commas of every kind of list.
+    pub fn with_trailing_commas(self, trailing_commas: TrailingCommas) -> Self {
+        Self {
+            trailing_commas: [trailing_commas; CommaList::ALL.len()],
+            ..self
+        }
+    }
+
+    /// The trailing commas of one kind of list, leaving the others as they
+    /// are.
+    pub fn with_trailing_commas_in(
+        mut self,
+        list: CommaList,
+        trailing_commas: TrailingCommas,
+    ) -> Self {
+        self.trailing_commas[list as usize] = trailing_commas;
+        self
+    }
+
+    /// The column lines should stay within, counting a tab as four columns.
+    /// Argument lists, arrays, and method chains that would pass it are
+    /// broken over several lines. It must be wider than one level of
+    /// indentation.
+    pub fn with_line_width(self, line_width: usize) -> Result<Self, PrintOptionsError> {
+        if line_width <= self.indent_width() {
+            return Err(PrintOptionsError::LineWidth(line_width));
+        }
+        Ok(Self { line_width, ..self })
+    }
+
+    pub fn with_heredoc(self, heredoc: HeredocStyle) -> Self {
+        Self { heredoc, ..self }
+    }
+
+    pub fn with_comment_policy(self, comment_policy: CommentPolicy) -> Self {
+        Self {
+            comment_policy,
+            ..self
+        }
+    }
+
+    pub fn with_final_close_tag(self, final_close_tag: FinalCloseTag) -> Self {
+        Self {
+            final_close_tag,
+            ..self
+        }
+    }
+
+    pub fn indent(&self) -> Indent {
+        self.indent
+    }
+
+    pub fn newline(&self) -> &'static str {
+        self.newline
+    }
+
+    pub fn blank_lines_upper_bound(&self) -> usize {
+        self.blank_lines_upper_bound
+    }
+
+    pub fn brace_style(&self) -> BraceStyle {
+        self.brace_style
+    }
+
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    pub fn trailing_commas(&self, list: CommaList) -> TrailingCommas {
+        self.trailing_commas[list as usize]
+    }
+
+    pub fn line_width(&self) -> usize {
+        self.line_width
+    }
+
+    pub fn heredoc(&self) -> HeredocStyle {
+        self.heredoc
+    }
+
+    pub fn comment_policy(&self) -> CommentPolicy {
+        self.comment_policy
+    }
+
+    pub fn final_close_tag(&self) -> FinalCloseTag {
+        self.final_close_tag
+    }
+
+    /// The width of one indentation level in columns.
+    pub(crate) fn indent_width(&self) -> usize {
+        match self.indent {
+            Indent::Spaces(width) => width,
+            Indent::Tabs => 4,
+        }
+    }
+}
@@ -71,8 +71,9 @@ pub fn binary_op_precedence(op: BinaryOp) -> (i8, i8, i8) {
             PREC_LOGICAL_XOR_WORD,
             PREC_LOGICAL_XOR_WORD + 1,
         ),
-        BinaryOp::Instanceof => (PREC_INSTANCEOF, PREC_INSTANCEOF + 1, PREC_INSTANCEOF + 1),
         BinaryOp::Pipe => (PREC_PIPE, PREC_PIPE, PREC_PIPE + 1),
+        // Unknown operators are always parenthesized.
+        _ => (PREC_LOWEST, PREC_LOWEST + 1, PREC_LOWEST + 1),
     }
 }
 
@@ -87,6 +88,7 @@ pub fn expr_precedence(kind: &ExprKind) -> i8 {
     match kind {
         ExprKind::Binary(b) => binary_op_precedence(b.op).0,
         ExprKind::Assign(a) => assign_op_precedence(a.op).0,
+        ExprKind::Instanceof(_) => PREC_INSTANCEOF,
         ExprKind::Ternary(_) => PREC_TERNARY,
         ExprKind::NullCoalesce(_) => PREC_NULL_COALESCE,
         ExprKind::Yield(_) => PREC_YIELD,
@@ -1,5 +1,6 @@
 use php_ast::ast::*;
 
+use crate::options::CommaList;
 use crate::precedence::*;
 
 use super::helpers::*;
@@ -9,6 +10,21 @@ impl<'src> Printer<'src> {
     pub(crate) fn print_function(&mut self, func: &FunctionDecl, stmt: &Stmt) {
         self.print_doc_comment(&func.doc_comment);
         self.print_attributes(&func.attributes);
+        self.print_function_header(func);
+        self.open_declaration_body();
+        if !func.body.is_empty() {
+            self.newline();
+            self.print_stmts_ensure_php(&func.body, true);
+            self.newline();
+            self.flush_leading_comments(stmt.span.end);
+            self.write_indent();
+        } else {
+            self.flush_empty_body_comments(stmt.span.end);
+        }
+        self.w("}");
+    }
+
+    pub(crate) fn print_function_header(&mut self, func: &FunctionDecl) {
         self.w("function ");
         if func.by_ref {
             self.w("&");
@@ -21,19 +37,6 @@ impl<'src> Printer<'src> {
             self.w(": ");
             self.print_type_hint(ret);
         }
-        self.newline();
-        self.write_indent();
-        self.w("{");
-        if !func.body.is_empty() {
-            self.newline();
-            self.print_stmts_ensure_php(&func.body, true);
-            self.newline();
-            self.flush_leading_comments(stmt.span.end);
-            self.write_indent();
-        } else {
-            self.flush_leading_comments(stmt.span.end);
-        }
-        self.w("}");
     }
 
     pub(crate) fn print_class(&mut self, class: &ClassDecl, stmt: &Stmt) {
@@ -49,6 +52,7 @@ impl<'src> Printer<'src> {
         args: &[Arg],
         closing_offset: u32,
     ) {
+        self.print_attributes_inline(&class.attributes);
         if class.modifiers.is_abstract {
             self.w("abstract ");
         }
@@ -60,9 +64,7 @@ impl<'src> Printer<'src> {
         }
         self.w("class");
         if !args.is_empty() {
-            self.w("(");
-            self.print_args(args);
-            self.w(")");
+            self.print_arg_list(args);
         }
         if let Some(extends) = &class.extends {
             self.w(" extends ");
@@ -111,9 +113,7 @@ impl<'src> Printer<'src> {
     }
 
     pub(crate) fn print_class_body(&mut self, members: &[ClassMember], closing_offset: u32) {
-        self.newline();
-        self.write_indent();
-        self.w("{");
+        self.open_declaration_body();
         if !members.is_empty() {
             self.newline();
             self.indent();
@@ -130,6 +130,7 @@ impl<'src> Printer<'src> {
                 self.flush_leading_comments(member.span.start);
                 self.write_indent();
                 self.print_class_member(member);
+                self.flush_trailing_comments(member.span.end);
                 self.newline();
             }
             self.flush_leading_comments(closing_offset);
@@ -145,12 +146,30 @@ impl<'src> Printer<'src> {
             ClassMemberKind::Method(method) => self.print_method(method, member.span.end),
             ClassMemberKind::ClassConst(cc) => self.print_class_const(cc),
             ClassMemberKind::TraitUse(tu) => self.print_trait_use(tu),
+            _ => self.w_source(member.span),
         }
     }
 
     pub(crate) fn print_method(&mut self, method: &MethodDecl, span_end: u32) {
         self.print_doc_comment(&method.doc_comment);
         self.print_attributes(&method.attributes);
+        self.print_method_header(method);
+        if let Some(body) = &method.body {
+            self.open_declaration_body();
+            if !body.is_empty() {
+                self.newline();
+                self.print_stmts_ensure_php(body, true);
+                self.newline();
+                self.flush_leading_comments(span_end);
+                self.write_indent();
+            }
+            self.w("}");
+        } else {
+            self.w(";");
+        }
+    }
+
+    pub(crate) fn print_method_header(&mut self, method: &MethodDecl) {
         if method.is_abstract {
             self.w("abstract ");
         }
@@ -176,26 +195,22 @@ impl<'src> Printer<'src> {
             self.w(": ");
             self.print_type_hint(ret);
         }
-        if let Some(body) = &method.body {
-            self.newline();
-            self.write_indent();
-            self.w("{");
-            if !body.is_empty() {
-                self.newline();
-                self.print_stmts_ensure_php(body, true);
-                self.newline();
-                self.flush_leading_comments(span_end);
-                self.write_indent();
-            }
-            self.w("}");
-        } else {
-            self.w(";");
-        }
     }
 
     fn print_property(&mut self, prop: &PropertyDecl) {
         self.print_doc_comment(&prop.doc_comment);
         self.print_attributes(&prop.attributes);
+        self.print_property_header(prop);
+        if !prop.hooks.is_empty() {
+            self.w(" ");
+            self.print_property_hooks(&prop.hooks);
+        } else {
+            self.w(";");
+        }
+    }
+
+    /// The property up to its hooks or `;`.
+    pub(crate) fn print_property_header(&mut self, prop: &PropertyDecl) {
         if let Some(vis) = &prop.visibility {
             self.w(visibility_str(*vis));
             self.w(" ");
@@ -220,12 +235,6 @@ impl<'src> Printer<'src> {
             self.w(" = ");
             self.print_expr(default, PREC_LOWEST);
         }
-        if !prop.hooks.is_empty() {
-            self.w(" ");
-            self.print_property_hooks(&prop.hooks);
-        } else {
-            self.w(";");
-        }
     }
 
     fn print_property_hooks(&mut self, hooks: &[PropertyHook]) {
@@ -278,6 +287,12 @@ impl<'src> Printer<'src> {
     fn print_class_const(&mut self, cc: &ClassConstDecl) {
         self.print_doc_comment(&cc.doc_comment);
         self.print_attributes(&cc.attributes);
+        self.print_class_const_header(cc);
+        self.w(";");
+    }
+
+    /// The constant without its `;`.
+    pub(crate) fn print_class_const_header(&mut self, cc: &ClassConstDecl) {
         if cc.is_final {
             self.w("final ");
         }
@@ -293,7 +308,6 @@ impl<'src> Printer<'src> {
         self.w(cc.name.or_error());
         self.w(" = ");
         self.print_expr(&cc.value, PREC_LOWEST);
-        self.w(";");
     }
 
     fn print_trait_use(&mut self, tu: &TraitUseDecl) {
@@ -363,6 +377,11 @@ impl<'src> Printer<'src> {
     pub(crate) fn print_interface(&mut self, iface: &InterfaceDecl, stmt: &Stmt) {
         self.print_doc_comment(&iface.doc_comment);
         self.print_attributes(&iface.attributes);
+        self.print_interface_header(iface);
+        self.print_class_body(&iface.members, stmt.span.end);
+    }
+
+    pub(crate) fn print_interface_header(&mut self, iface: &InterfaceDecl) {
         self.w("interface ");
         self.w(iface.name.or_error());
         if !iface.extends.is_empty() {
@@ -374,7 +393,6 @@ impl<'src> Printer<'src> {
                 self.print_name(name);
             }
         }
-        self.print_class_body(&iface.members, stmt.span.end);
     }
 
     pub(crate) fn print_trait(&mut self, trait_decl: &TraitDecl, stmt: &Stmt) {
@@ -388,24 +406,8 @@ impl<'src> Printer<'src> {
     pub(crate) fn print_enum(&mut self, enum_decl: &EnumDecl, stmt: &Stmt) {
         self.print_doc_comment(&enum_decl.doc_comment);
         self.print_attributes(&enum_decl.attributes);
-        self.w("enum ");
-        self.w(enum_decl.name.or_error());
-        if let Some(scalar) = &enum_decl.scalar_type {
-            self.w(": ");
-            self.print_name(scalar);
-        }
-        if !enum_decl.implements.is_empty() {
-            self.w(" implements ");
-            for (i, name) in enum_decl.implements.iter().enumerate() {
-                if i > 0 {
-                    self.w(", ");
-                }
-                self.print_name(name);
-            }
-        }
-        self.newline();
-        self.write_indent();
-        self.w("{");
+        self.print_enum_header(enum_decl);
+        self.open_declaration_body();
         if !enum_decl.members.is_empty() {
             self.newline();
             self.indent();
@@ -420,15 +422,34 @@ impl<'src> Printer<'src> {
                 self.flush_leading_comments(member.span.start);
                 self.write_indent();
                 self.print_enum_member(member);
+                self.flush_trailing_comments(member.span.end);
                 self.newline();
             }
-            self.dedent();
             self.flush_leading_comments(stmt.span.end);
+            self.dedent();
             self.write_indent();
         }
         self.w("}");
     }
 
+    pub(crate) fn print_enum_header(&mut self, enum_decl: &EnumDecl) {
+        self.w("enum ");
+        self.w(enum_decl.name.or_error());
+        if let Some(scalar) = &enum_decl.scalar_type {
+            self.w(": ");
+            self.print_name(scalar);
+        }
+        if !enum_decl.implements.is_empty() {
+            self.w(" implements ");
+            for (i, name) in enum_decl.implements.iter().enumerate() {
+                if i > 0 {
+                    self.w(", ");
+                }
+                self.print_name(name);
+            }
+        }
+    }
+
     fn print_enum_member(&mut self, member: &EnumMember) {
         match &member.kind {
             EnumMemberKind::Case(case) => {
@@ -445,6 +466,7 @@ impl<'src> Printer<'src> {
             EnumMemberKind::Method(method) => self.print_method(method, member.span.end),
             EnumMemberKind::ClassConst(cc) => self.print_class_const(cc),
             EnumMemberKind::TraitUse(tu) => self.print_trait_use(tu),
+            _ => self.w_source(member.span),
         }
     }
 
@@ -484,6 +506,14 @@ impl<'src> Printer<'src> {
                 self.w(" = ");
                 self.print_expr(default, PREC_LOWEST);
             }
+            if !param.hooks.is_empty() {
+                self.w(" ");
+                self.print_property_hooks(&param.hooks);
+            }
+        }
+        let last = params.last().and_then(|param| param.comma);
+        if self.trailing_comma(CommaList::Params, last, false) {
+            self.w(",");
         }
     }
 
@@ -492,9 +522,7 @@ impl<'src> Printer<'src> {
             self.w("#[");
             self.print_name(&attr.name);
             if !attr.args.is_empty() {
-                self.w("(");
-                self.print_args(&attr.args);
-                self.w(")");
+                self.print_arg_list(&attr.args);
             }
             self.w("]");
             self.newline();
@@ -507,9 +535,7 @@ impl<'src> Printer<'src> {
             self.w("#[");
             self.print_name(&attr.name);
             if !attr.args.is_empty() {
-                self.w("(");
-                self.print_args(&attr.args);
-                self.w(")");
+                self.print_arg_list(&attr.args);
             }
             self.w("] ");
         }
@@ -522,8 +548,7 @@ impl<'src> Printer<'src> {
                     self.newline();
                     self.write_indent();
                 }
-                let trimmed = if i == 0 { line.trim_end() } else { line.trim() };
-                self.w(trimmed);
+                self.w(&doc_comment_line(i, line));
             }
             self.newline();
             self.write_indent();
@@ -1,5 +1,6 @@
 use php_ast::ast::*;
 
+use crate::options::{CommaList, HeredocStyle, QuoteStyle};
 use crate::precedence::*;
 
 use super::helpers::*;
@@ -45,33 +46,26 @@ impl<'src> Printer<'src> {
                     }
                 }
             }
-            ExprKind::String(s) => self.print_string_literal(s),
-            ExprKind::InterpolatedString(parts) => {
-                if parts.is_empty() {
-                    // Empty string — use single quotes for consistency
-                    self.w("''");
+            ExprKind::String { value, .. } => self.print_string_literal(value),
+            ExprKind::InterpolatedString(parts) => self.print_interpolated_string(parts),
+            ExprKind::Heredoc { label, parts, .. } => {
+                if self.options.heredoc() == HeredocStyle::String {
+                    self.print_interpolated_string(parts);
                 } else {
-                    self.w("\"");
-                    self.print_string_parts(parts);
-                    self.w("\"");
+                    self.w("<<<");
+                    self.w(label);
+                    self.print_heredoc_body(label, |p| p.print_heredoc_parts(parts));
                 }
             }
-            ExprKind::Heredoc { label, parts } => {
-                self.w("<<<");
-                self.w(label);
-                self.newline();
-                self.print_heredoc_parts(parts);
-                self.newline();
-                self.w(label);
-            }
-            ExprKind::Nowdoc { label, value } => {
-                self.w("<<<'");
-                self.w(label);
-                self.w("'");
-                self.newline();
-                self.w(value);
-                self.newline();
-                self.w(label);
+            ExprKind::Nowdoc { label, value, .. } => {
+                if self.options.heredoc() == HeredocStyle::String {
+                    self.print_string_literal(value);
+                } else {
+                    self.w("<<<'");
+                    self.w(label);
+                    self.w("'");
+                    self.print_heredoc_body(label, |p| p.w(value));
+                }
             }
             ExprKind::ShellExec(parts) => {
                 self.w("`");
@@ -95,6 +89,7 @@ impl<'src> Printer<'src> {
                 }
             }
             ExprKind::Identifier(name) => self.w(name.as_str()),
+            ExprKind::Name(name) | ExprKind::ConstFetch(name) => self.print_name(name),
             ExprKind::Assign(assign) => {
                 let (_, lhs_prec, rhs_prec) = assign_op_precedence(assign.op);
                 self.print_expr(assign.target, lhs_prec);
@@ -111,10 +106,15 @@ impl<'src> Printer<'src> {
                 let (_, lhs_prec, rhs_prec) = binary_op_precedence(binary.op);
                 self.print_expr(binary.left, lhs_prec);
                 self.w(" ");
-                self.w(binary_op_str(binary.op));
+                self.w(binary.op.as_str());
                 self.w(" ");
                 self.print_expr(binary.right, rhs_prec);
             }
+            ExprKind::Instanceof(instanceof) => {
+                self.print_expr(instanceof.expr, PREC_INSTANCEOF + 1);
+                self.w(" instanceof ");
+                self.print_class_ref(&instanceof.class, PREC_INSTANCEOF + 1);
+            }
             ExprKind::UnaryPrefix(unary) => {
                 self.w(unary_prefix_op_str(unary.op));
                 self.print_expr(unary.operand, PREC_UNARY);
@@ -141,15 +141,13 @@ impl<'src> Printer<'src> {
             }
             ExprKind::FunctionCall(call) => {
                 self.print_expr(call.name, PREC_PRIMARY);
-                self.w("(");
-                self.print_args(&call.args);
-                self.w(")");
+                self.print_arg_list(&call.args);
             }
-            ExprKind::Array(elements) => {
-                self.w("[");
-                self.print_array_elements(elements);
-                self.w("]");
+            ExprKind::Array(elements) => self.print_array(elements, "[", "]"),
+            ExprKind::List(list) if list.uses_list => {
+                self.print_array(&list.elements, "list(", ")")
             }
+            ExprKind::List(list) => self.print_array(&list.elements, "[", "]"),
             ExprKind::ArrayAccess(access) => {
                 self.print_expr(access.array, PREC_PRIMARY);
                 self.w("[");
@@ -158,9 +156,9 @@ impl<'src> Printer<'src> {
                 }
                 self.w("]");
             }
-            ExprKind::Print(e) => {
+            ExprKind::Print(print) => {
                 self.w("print ");
-                self.print_expr(e, PREC_PRINT);
+                self.print_expr(print.expr, PREC_PRINT);
             }
             ExprKind::Parenthesized(e) => {
                 self.w("(");
@@ -171,9 +169,9 @@ impl<'src> Printer<'src> {
                 self.w(cast_str(*kind));
                 self.print_expr(e, PREC_CAST);
             }
-            ExprKind::ErrorSuppress(e) => {
+            ExprKind::ErrorSuppress(suppress) => {
                 self.w("@");
-                self.print_expr(e, PREC_UNARY);
+                self.print_expr(suppress.expr, PREC_UNARY);
             }
             ExprKind::Isset(exprs) => {
                 self.w("isset(");
@@ -204,9 +202,9 @@ impl<'src> Printer<'src> {
                 }
             }
             ExprKind::MagicConst(kind) => self.w(magic_const_str(*kind)),
-            ExprKind::Clone(e) => {
+            ExprKind::Clone(clone) => {
                 self.w("clone ");
-                self.print_expr(e, PREC_CLONE);
+                self.print_expr(clone.object, PREC_CLONE);
             }
             ExprKind::CloneWith(obj, overrides) => {
                 self.w("clone(");
@@ -217,13 +215,15 @@ impl<'src> Printer<'src> {
             }
             ExprKind::New(new_expr) => {
                 self.w("new ");
-                if let ExprKind::AnonymousClass(class) = &new_expr.class.kind {
-                    self.print_anonymous_class(class, &new_expr.args, new_expr.class.span.end);
+                if let ClassRef::Expr(Expr {
+                    kind: ExprKind::AnonymousClass(class),
+                    span,
+                }) = &new_expr.class
+                {
+                    self.print_anonymous_class(class, &new_expr.args, span.end);
                 } else {
-                    self.print_expr(new_expr.class, PREC_PRIMARY);
-                    self.w("(");
-                    self.print_args(&new_expr.args);
-                    self.w(")");
+                    self.print_class_ref(&new_expr.class, PREC_PRIMARY);
+                    self.print_arg_list(&new_expr.args);
                 }
             }
             ExprKind::PropertyAccess(access) => {
@@ -248,6 +248,9 @@ impl<'src> Printer<'src> {
                     self.print_expr(access.property, PREC_PRIMARY);
                 }
             }
+            ExprKind::MethodCall(_) | ExprKind::NullsafeMethodCall(_) if is_method_chain(expr) => {
+                self.print_method_chain(expr)
+            }
             ExprKind::MethodCall(call) => {
                 self.print_expr(call.object, PREC_PRIMARY);
                 self.w("->");
@@ -258,9 +261,7 @@ impl<'src> Printer<'src> {
                 } else {
                     self.print_expr(call.method, PREC_PRIMARY);
                 }
-                self.w("(");
-                self.print_args(&call.args);
-                self.w(")");
+                self.print_arg_list(&call.args);
             }
             ExprKind::NullsafeMethodCall(call) => {
                 self.print_expr(call.object, PREC_PRIMARY);
@@ -272,46 +273,40 @@ impl<'src> Printer<'src> {
                 } else {
                     self.print_expr(call.method, PREC_PRIMARY);
                 }
-                self.w("(");
-                self.print_args(&call.args);
-                self.w(")");
+                self.print_arg_list(&call.args);
             }
             ExprKind::StaticPropertyAccess(access) => {
-                self.print_expr(access.class, PREC_PRIMARY);
+                self.print_class_ref(&access.class, PREC_PRIMARY);
                 self.w("::$");
                 self.print_expr(access.member, PREC_PRIMARY);
             }
             ExprKind::ClassConstAccess(access) => {
-                self.print_expr(access.class, PREC_PRIMARY);
+                self.print_class_ref(&access.class, PREC_PRIMARY);
                 self.w("::");
                 self.print_expr(access.member, PREC_PRIMARY);
             }
             ExprKind::ClassConstAccessDynamic { class, member } => {
-                self.print_expr(class, PREC_PRIMARY);
+                self.print_class_ref(class, PREC_PRIMARY);
                 self.w("::{");
                 self.print_expr(member, PREC_LOWEST);
                 self.w("}");
             }
             ExprKind::StaticPropertyAccessDynamic { class, member } => {
-                self.print_expr(class, PREC_PRIMARY);
+                self.print_class_ref(class, PREC_PRIMARY);
                 self.w("::");
                 self.print_expr(member, PREC_PRIMARY);
             }
             ExprKind::StaticMethodCall(call) => {
-                self.print_expr(call.class, PREC_PRIMARY);
+                self.print_class_ref(&call.class, PREC_PRIMARY);
                 self.w("::");
                 self.print_expr(call.method, PREC_PRIMARY);
-                self.w("(");
-                self.print_args(&call.args);
-                self.w(")");
+                self.print_arg_list(&call.args);
             }
             ExprKind::StaticDynMethodCall(call) => {
-                self.print_expr(call.class, PREC_PRIMARY);
+                self.print_class_ref(&call.class, PREC_PRIMARY);
                 self.w("::");
                 self.print_expr(call.method, PREC_PRIMARY);
-                self.w("(");
-                self.print_args(&call.args);
-                self.w(")");
+                self.print_arg_list(&call.args);
             }
             ExprKind::Closure(closure) => self.print_closure(closure),
             ExprKind::ArrowFunction(af) => self.print_arrow_function(af),
@@ -362,14 +357,16 @@ impl<'src> Printer<'src> {
                     self.w("(...)");
                 }
                 CallableCreateKind::StaticMethod { class, method } => {
-                    self.print_expr(class, PREC_PRIMARY);
+                    self.print_class_ref(class, PREC_PRIMARY);
                     self.w("::");
                     self.print_expr(method, PREC_PRIMARY);
                     self.w("(...)");
                 }
+                _ => self.w_source(expr.span),
             },
             ExprKind::Omit => {}
             ExprKind::Error => self.w("/* error */"),
+            _ => self.w_source(expr.span),
         }
     }
 
@@ -397,13 +394,17 @@ impl<'src> Printer<'src> {
                 self.w("$");
                 self.w(var.name);
             }
+            let last = &closure.use_vars[closure.use_vars.len() - 1];
+            if self.trailing_comma(CommaList::ClosureUses, last.comma, false) {
+                self.w(",");
+            }
             self.w(")");
         }
         if let Some(ret) = &closure.return_type {
             self.w(": ");
             self.print_type_hint(ret);
         }
-        self.w(" {");
+        self.open_block();
         if !closure.body.is_empty() {
             self.newline();
             self.print_stmts(&closure.body, true);
@@ -440,7 +441,7 @@ impl<'src> Printer<'src> {
         self.w(") {");
         self.newline();
         self.indent();
-        for arm in m.arms.iter() {
+        for (i, arm) in m.arms.iter().enumerate() {
             self.write_indent();
             if let Some(conds) = &arm.conditions {
                 for (i, cond) in conds.iter().enumerate() {
@@ -454,7 +455,9 @@ impl<'src> Printer<'src> {
             }
             self.w(" => ");
             self.print_expr(&arm.body, PREC_LOWEST);
-            self.w(",");
+            if i + 1 < m.arms.len() || self.trailing_comma(CommaList::MatchArms, arm.comma, true) {
+                self.w(",");
+            }
             self.newline();
         }
         self.dedent();
@@ -462,22 +465,95 @@ impl<'src> Printer<'src> {
         self.w("}");
     }
 
-    pub(crate) fn print_args(&mut self, args: &[Arg]) {
-        for (i, arg) in args.iter().enumerate() {
-            if i > 0 {
-                self.w(", ");
-            }
-            if let Some(name) = &arg.name {
-                self.print_name(name);
-                self.w(": ");
-            }
-            if arg.unpack {
-                self.w("...");
-            }
-            if arg.by_ref {
-                self.w("&");
+    /// Print `(args)`, with one argument per line when they do not fit.
+    pub(crate) fn print_arg_list(&mut self, args: &[Arg]) {
+        self.w("(");
+        if !args.is_empty() {
+            self.group(|p| {
+                p.soft_indent();
+                p.soft_line("");
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        p.w(",");
+                        p.soft_line(" ");
+                    }
+                    p.print_arg(arg);
+                }
+                let last = &args[args.len() - 1];
+                if p.trailing_comma(CommaList::Args, last.comma, p.is_broken()) {
+                    p.w(",");
+                }
+                p.soft_dedent();
+                p.soft_line("");
+            });
+        }
+        self.w(")");
+    }
+
+    fn print_arg(&mut self, arg: &Arg) {
+        if let Some(name) = &arg.name {
+            self.print_name(name);
+            self.w(": ");
+        }
+        if arg.unpack {
+            self.w("...");
+        }
+        if arg.by_ref {
+            self.w("&");
+        }
+        self.print_expr(&arg.value, PREC_LOWEST);
+    }
+
+    /// Print a chain of two or more method calls, such as
+    /// `$query->where($a)->orderBy($b)`, breaking before each call when the
+    /// chain does not fit. Property accesses stay with the call they lead to.
+    fn print_method_chain(&mut self, expr: &Expr) {
+        let mut links = Vec::new();
+        let mut base = expr;
+        while let Some(object) = chain_object(base) {
+            links.push(base);
+            base = object;
+        }
+        links.reverse();
+        self.print_expr(base, PREC_PRIMARY);
+        let first_call = links.iter().position(|link| is_call(link)).unwrap_or(0);
+        for link in &links[..first_call] {
+            self.print_chain_link(link);
+        }
+        self.group(|p| {
+            p.soft_indent();
+            let mut segment_start = true;
+            for link in &links[first_call..] {
+                if segment_start {
+                    p.soft_line("");
+                }
+                p.print_chain_link(link);
+                segment_start = is_call(link);
             }
-            self.print_expr(&arg.value, PREC_LOWEST);
+            p.soft_dedent();
+        });
+    }
+
+    /// Print one `->member`, `?->member`, `->method(args)`, or
+    /// `?->method(args)` of a method chain.
+    fn print_chain_link(&mut self, link: &Expr) {
+        let (operator, member, args) = match &link.kind {
+            ExprKind::PropertyAccess(access) => ("->", access.property, None),
+            ExprKind::NullsafePropertyAccess(access) => ("?->", access.property, None),
+            ExprKind::MethodCall(call) => ("->", call.method, Some(&call.args)),
+            ExprKind::NullsafeMethodCall(call) => ("?->", call.method, Some(&call.args)),
+            _ => return,
+        };
+        self.w(operator);
+        if self.needs_braces_for_property(&member.kind) {
+            self.w("{");
+            self.print_expr(member, PREC_LOWEST);
+            self.w("}");
+        } else {
+            self.print_expr(member, PREC_PRIMARY);
+        }
+        if let Some(args) = args {
+            self.print_arg_list(args);
         }
     }
 
@@ -490,8 +566,14 @@ impl<'src> Printer<'src> {
         }
     }
 
-    fn print_array_elements(&mut self, elements: &[ArrayElement]) {
+    /// Print `[elements]`, with one element per line when they do not fit
+    /// or have comments between them.
+    /// Print array elements between `open` and `close`, which are `[` and `]`
+    /// or, for a `list()` target, `list(` and `)`.
+    fn print_array(&mut self, elements: &[ArrayElement], open: &str, close: &str) {
+        self.w(open);
         if elements.is_empty() {
+            self.w(close);
             return;
         }
         let multi_line = elements.len() > 1
@@ -505,18 +587,14 @@ impl<'src> Printer<'src> {
             for (i, elem) in elements.iter().enumerate() {
                 self.flush_leading_comments(elem.span.start);
                 self.write_indent();
-                if elem.unpack {
-                    self.w("...");
-                }
-                if let Some(key) = &elem.key {
-                    self.print_expr(key, PREC_LOWEST);
-                    self.w(" => ");
-                }
-                if elem.by_ref {
-                    self.w("&");
+                self.print_array_element(elem);
+                // A trailing `,` after an omitted element is significant.
+                if i < elements.len() - 1
+                    || self.trailing_comma(CommaList::Arrays, elem.comma, true)
+                    || matches!(elem.value.kind, ExprKind::Omit)
+                {
+                    self.w(",");
                 }
-                self.print_expr(&elem.value, PREC_LOWEST);
-                self.w(",");
                 if i < elements.len() - 1 {
                     self.newline();
                 }
@@ -525,38 +603,67 @@ impl<'src> Printer<'src> {
             self.dedent();
             self.write_indent();
         } else {
-            for (i, elem) in elements.iter().enumerate() {
-                if i > 0 {
-                    self.w(", ");
-                }
-                if elem.unpack {
-                    self.w("...");
-                }
-                if let Some(key) = &elem.key {
-                    self.print_expr(key, PREC_LOWEST);
-                    self.w(" => ");
-                }
-                if elem.by_ref {
-                    self.w("&");
+            self.group(|p| {
+                p.soft_indent();
+                p.soft_line("");
+                for (i, elem) in elements.iter().enumerate() {
+                    if i > 0 {
+                        p.w(",");
+                        p.soft_line(" ");
+                    }
+                    p.print_array_element(elem);
                 }
-                self.print_expr(&elem.value, PREC_LOWEST);
-                if i == elements.len() - 1 && matches!(elem.value.kind, ExprKind::Omit) {
-                    self.w(",");
+                let last = &elements[elements.len() - 1];
+                if matches!(last.value.kind, ExprKind::Omit)
+                    || p.trailing_comma(CommaList::Arrays, last.comma, p.is_broken())
+                {
+                    p.w(",");
                 }
-            }
+                p.soft_dedent();
+                p.soft_line("");
+            });
+        }
+        self.w(close);
+    }
+
+    fn print_array_element(&mut self, elem: &ArrayElement) {
+        if elem.unpack {
+            self.w("...");
+        }
+        if let Some(key) = &elem.key {
+            self.print_expr(key, PREC_LOWEST);
+            self.w(" => ");
+        }
+        if elem.by_ref {
+            self.w("&");
         }
+        self.print_expr(&elem.value, PREC_LOWEST);
     }
 
     fn print_string_parts(&mut self, parts: &[StringPart]) {
+        self.print_interpolated_parts(parts, escape_double_quoted);
+    }
+
+    fn print_backtick_parts(&mut self, parts: &[StringPart]) {
+        self.print_interpolated_parts(parts, escape_shell_exec);
+    }
+
+    /// Print the parts of a double-quoted string, heredoc, or backtick string,
+    /// escaping literals with `escape`.
+    fn print_interpolated_parts(&mut self, parts: &[StringPart], escape: fn(&str) -> String) {
         let mut last_literal_ends_with_brace = false;
-        for part in parts.iter() {
+        for (i, part) in parts.iter().enumerate() {
             match part {
-                StringPart::Literal(s) => {
-                    self.w(&escape_double_quoted(s));
-                    last_literal_ends_with_brace = s.ends_with('{');
+                StringPart::Literal { value, .. } => {
+                    self.w(&escape(value));
+                    last_literal_ends_with_brace = value.ends_with('{');
                 }
                 StringPart::Expr(expr) => {
-                    if last_literal_ends_with_brace {
+                    let next = match parts.get(i + 1) {
+                        Some(StringPart::Literal { value, .. }) => value,
+                        _ => "",
+                    };
+                    if last_literal_ends_with_brace || extends_simple_interpolation(next) {
                         self.print_string_part_expr_with_braces(expr);
                     } else {
                         self.print_string_part_expr(expr);
@@ -567,22 +674,61 @@ impl<'src> Printer<'src> {
         }
     }
 
-    fn print_backtick_parts(&mut self, parts: &[StringPart]) {
-        for part in parts.iter() {
-            match part {
-                StringPart::Literal(s) => self.w(&escape_shell_exec(s)),
-                StringPart::Expr(expr) => self.print_string_part_expr(expr),
+    /// A string with interpolation in double quotes, or a plain string literal
+    /// when there is nothing to interpolate.
+    fn print_interpolated_string(&mut self, parts: &[StringPart]) {
+        if parts
+            .iter()
+            .all(|part| matches!(part, StringPart::Literal { .. }))
+        {
+            let mut text = String::new();
+            for part in parts.iter() {
+                if let StringPart::Literal { value, .. } = part {
+                    text.push_str(value);
+                }
             }
+            self.print_string_literal(&text);
+        } else {
+            self.w("\"");
+            self.print_string_parts(parts);
+            self.w("\"");
         }
     }
 
-    fn print_heredoc_parts(&mut self, parts: &[StringPart]) {
-        for part in parts.iter() {
-            match part {
-                StringPart::Literal(s) => self.w(&escape_heredoc(s)),
-                StringPart::Expr(expr) => self.print_string_part_expr(expr),
+    /// Print the body and closing label of a heredoc or nowdoc, starting
+    /// with the newline after the opening label.
+    fn print_heredoc_body(&mut self, label: &str, body: impl FnOnce(&mut Self)) {
+        self.newline();
+        if self.options.heredoc() != HeredocStyle::Indented {
+            body(self);
+            self.newline();
+            self.w(label);
+            return;
+        }
+        // The closing label's indentation is stripped from every body line,
+        // so indent both by the same amount. Blank lines stay empty.
+        let (start, line_start) = (self.output.len(), self.line_start);
+        body(self);
+        let text = self.output.split_off(start);
+        self.line_start = line_start;
+        self.indent();
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.w("\n");
+            }
+            if !line.is_empty() {
+                self.write_indent();
             }
+            self.w(line);
         }
+        self.newline();
+        self.write_indent();
+        self.dedent();
+        self.w(label);
+    }
+
+    fn print_heredoc_parts(&mut self, parts: &[StringPart]) {
+        self.print_interpolated_parts(parts, escape_heredoc);
     }
 
     fn print_string_part_expr(&mut self, expr: &Expr) {
@@ -591,6 +737,25 @@ impl<'src> Printer<'src> {
                 self.w("$");
                 self.w(name.as_str());
             }
+            // `{$a[-1]}` would read back as a negation, so keep the simple
+            // syntax that gave the offset.
+            ExprKind::ArrayAccess(ArrayAccessExpr {
+                array:
+                    Expr {
+                        kind: ExprKind::Variable(name),
+                        ..
+                    },
+                index:
+                    Some(Expr {
+                        kind: ExprKind::Int(n),
+                        ..
+                    }),
+                ..
+            }) if *n < 0 => {
+                self.w("$");
+                self.w(name.as_str());
+                self.w(&format!("[{n}]"));
+            }
             _ => {
                 self.w("{");
                 self.print_expr(expr, PREC_LOWEST);
@@ -621,7 +786,11 @@ impl<'src> Printer<'src> {
     }
 
     fn print_string_literal(&mut self, s: &str) {
-        if needs_double_quotes(s) {
+        let double = match self.options.quote_style() {
+            QuoteStyle::Single => needs_double_quotes(s),
+            QuoteStyle::Double => needs_double_quotes(s) || !s.contains(['"', '\\']),
+        };
+        if double {
             self.w("\"");
             self.w(&escape_double_quoted(s));
             self.w("\"");
@@ -632,3 +801,43 @@ impl<'src> Printer<'src> {
         }
     }
 }
+
+/// Whether `expr` ends a chain of two or more method calls.
+fn is_method_chain(expr: &Expr) -> bool {
+    let mut calls = 0;
+    let mut link = expr;
+    while let Some(object) = chain_object(link) {
+        calls += usize::from(is_call(link));
+        link = object;
+    }
+    calls >= 2
+}
+
+/// The object of a member access or method call.
+fn chain_object<'a, 'arena, 'src>(expr: &'a Expr<'arena, 'src>) -> Option<&'a Expr<'arena, 'src>> {
+    match &expr.kind {
+        ExprKind::PropertyAccess(access) | ExprKind::NullsafePropertyAccess(access) => {
+            Some(access.object)
+        }
+        ExprKind::MethodCall(call) | ExprKind::NullsafeMethodCall(call) => Some(call.object),
+        _ => None,
+    }
+}
+
+fn is_call(expr: &Expr) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::MethodCall(_) | ExprKind::NullsafeMethodCall(_)
+    )
+}
+
+/// Whether `literal`, following `$name` in a string, would be read as part of
+/// it: a name character, an offset `[`, or a property access `->name`.
+fn extends_simple_interpolation(literal: &str) -> bool {
+    let is_name_start = |b: u8| b.is_ascii_alphabetic() || b == b'_' || b >= 0x80;
+    match literal.as_bytes() {
+        [b, ..] if is_name_start(*b) || b.is_ascii_digit() || *b == b'[' => true,
+        [b'-', b'>', b, ..] | [b'?', b'-', b'>', b, ..] => is_name_start(*b),
+        _ => false,
+    }
+}
@@ -12,13 +12,32 @@ pub(crate) fn is_declaration(kind: &StmtKind) -> bool {
     )
 }
 
+/// Line `i` of a doc comment, re-indented: continuation lines starting with
+/// `*` are aligned one space in, under the first `*` of `/**`.
+pub(crate) fn doc_comment_line(i: usize, line: &str) -> std::borrow::Cow<'_, str> {
+    let line = if i == 0 { line.trim_end() } else { line.trim() };
+    if i > 0 && line.starts_with('*') {
+        std::borrow::Cow::Owned(format!(" {line}"))
+    } else {
+        std::borrow::Cow::Borrowed(line)
+    }
+}
+
 pub(crate) fn needs_double_quotes(s: &str) -> bool {
     s.bytes().any(|b| {
         matches!(
             b,
             b'\n' | b'\r' | b'\t' | b'\x1b' | b'\x0c' | b'\x0b' | b'$'
         )
-    })
+    }) || s.chars().any(|ch| raw_byte(ch).is_some())
+}
+
+/// The byte a string value holds where its source had a `\xNN` or octal
+/// escape that is not valid UTF-8: one of the reserved characters U+10FF80
+/// to U+10FFFF of `php_rs_parser::bytes`. Printed back as `\xNN`.
+pub(crate) fn raw_byte(ch: char) -> Option<u8> {
+    let b = (ch as u32).checked_sub(0x10FF00)?;
+    u8::try_from(b).ok().filter(|&b| b >= 0x80)
 }
 
 pub(crate) fn escape_single_quoted(s: &str) -> String {
@@ -46,7 +65,10 @@ pub(crate) fn escape_heredoc(s: &str) -> String {
             '\\' => out.push_str("\\\\"),
             '$' => out.push_str("\\$"),
             '\r' => out.push_str("\\r"),
-            _ => out.push(ch),
+            _ => match raw_byte(ch) {
+                Some(b) => out.push_str(&format!("\\x{b:02X}")),
+                None => out.push(ch),
+            },
         }
     }
     out
@@ -65,7 +87,10 @@ pub(crate) fn escape_double_quoted(s: &str) -> String {
             '\x1b' => out.push_str("\\e"),
             '\x0c' => out.push_str("\\f"),
             '\x0b' => out.push_str("\\v"),
-            _ => out.push(ch),
+            _ => match raw_byte(ch) {
+                Some(b) => out.push_str(&format!("\\x{b:02X}")),
+                None => out.push(ch),
+            },
         }
     }
     out
@@ -110,67 +135,12 @@ pub(crate) fn escape_shell_exec(s: &str) -> String {
     out
 }
 
-pub(crate) fn binary_op_str(op: BinaryOp) -> &'static str {
-    match op {
-        BinaryOp::Add => "+",
-        BinaryOp::Sub => "-",
-        BinaryOp::Mul => "*",
-        BinaryOp::Div => "/",
-        BinaryOp::Mod => "%",
-        BinaryOp::Pow => "**",
-        BinaryOp::Concat => ".",
-        BinaryOp::Equal => "==",
-        BinaryOp::NotEqual => "!=",
-        BinaryOp::Identical => "===",
-        BinaryOp::NotIdentical => "!==",
-        BinaryOp::Less => "<",
-        BinaryOp::Greater => ">",
-        BinaryOp::LessOrEqual => "<=",
-        BinaryOp::GreaterOrEqual => ">=",
-        BinaryOp::Spaceship => "<=>",
-        BinaryOp::BooleanAnd => "&&",
-        BinaryOp::BooleanOr => "||",
-        BinaryOp::BitwiseAnd => "&",
-        BinaryOp::BitwiseOr => "|",
-        BinaryOp::BitwiseXor => "^",
-        BinaryOp::ShiftLeft => "<<",
-        BinaryOp::ShiftRight => ">>",
-        BinaryOp::LogicalAnd => "and",
-        BinaryOp::LogicalOr => "or",
-        BinaryOp::LogicalXor => "xor",
-        BinaryOp::Instanceof => "instanceof",
-        BinaryOp::Pipe => "|>",
-    }
-}
-
 pub(crate) fn assign_op_str(op: AssignOp) -> &'static str {
-    match op {
-        AssignOp::Assign => "=",
-        AssignOp::Plus => "+=",
-        AssignOp::Minus => "-=",
-        AssignOp::Mul => "*=",
-        AssignOp::Div => "/=",
-        AssignOp::Mod => "%=",
-        AssignOp::Pow => "**=",
-        AssignOp::Concat => ".=",
-        AssignOp::BitwiseAnd => "&=",
-        AssignOp::BitwiseOr => "|=",
-        AssignOp::BitwiseXor => "^=",
-        AssignOp::ShiftLeft => "<<=",
-        AssignOp::ShiftRight => ">>=",
-        AssignOp::Coalesce => "??=",
-    }
+    op.as_str()
 }
 
 pub(crate) fn unary_prefix_op_str(op: UnaryPrefixOp) -> &'static str {
-    match op {
-        UnaryPrefixOp::Negate => "-",
-        UnaryPrefixOp::Plus => "+",
-        UnaryPrefixOp::BooleanNot => "!",
-        UnaryPrefixOp::BitwiseNot => "~",
-        UnaryPrefixOp::PreIncrement => "++",
-        UnaryPrefixOp::PreDecrement => "--",
-    }
+    op.as_str()
 }
 
 pub(crate) fn unary_postfix_op_str(op: UnaryPostfixOp) -> &'static str {
@@ -181,16 +151,7 @@ pub(crate) fn unary_postfix_op_str(op: UnaryPostfixOp) -> &'static str {
 }
 
 pub(crate) fn cast_str(kind: CastKind) -> &'static str {
-    match kind {
-        CastKind::Int => "(int)",
-        CastKind::Float => "(float)",
-        CastKind::String => "(string)",
-        CastKind::Bool => "(bool)",
-        CastKind::Array => "(array)",
-        CastKind::Object => "(object)",
-        CastKind::Unset => "(unset)",
-        CastKind::Void => "(void)",
-    }
+    kind.as_str()
 }
 
 pub(crate) fn include_kind_str(kind: IncludeKind) -> &'static str {
@@ -203,17 +164,7 @@ pub(crate) fn include_kind_str(kind: IncludeKind) -> &'static str {
 }
 
 pub(crate) fn magic_const_str(kind: MagicConstKind) -> &'static str {
-    match kind {
-        MagicConstKind::Class => "__CLASS__",
-        MagicConstKind::Dir => "__DIR__",
-        MagicConstKind::File => "__FILE__",
-        MagicConstKind::Function => "__FUNCTION__",
-        MagicConstKind::Line => "__LINE__",
-        MagicConstKind::Method => "__METHOD__",
-        MagicConstKind::Namespace => "__NAMESPACE__",
-        MagicConstKind::Trait => "__TRAIT__",
-        MagicConstKind::Property => "__PROPERTY__",
-    }
+    kind.as_str()
 }
 
 pub(crate) fn visibility_str(vis: Visibility) -> &'static str {
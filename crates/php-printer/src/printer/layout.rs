@@ -0,0 +1,110 @@
+//! Line-width aware layout.
+//!
+//! Constructs that may wrap — argument lists, arrays, method chains — are
+//! printed inside a [`group`](Printer::group) and mark the places they may
+//! break with [`soft_line`](Printer::soft_line). A group is printed flat
+//! when the line it starts on fits in [`PrintOptions::line_width`]; otherwise
+//! every soft line of the group becomes a newline, and the groups nested in
+//! it decide for themselves on their new lines. This is the layout of
+//! Wadler's "prettier printer", with the fit measured on the rendered text.
+//!
+//! [`PrintOptions::line_width`]: crate::PrintOptions::line_width
+
+use super::Printer;
+
+/// Printer state to roll back to when a flat group does not fit.
+struct Checkpoint {
+    output_len: usize,
+    line_start: usize,
+    comment_cursor: usize,
+    in_html_mode: bool,
+    has_php_content: bool,
+}
+
+impl<'src> Printer<'src> {
+    /// Print `f` flat if the first line it produces fits, otherwise with its
+    /// soft lines broken.
+    pub(crate) fn group(&mut self, f: impl Fn(&mut Self)) {
+        // Nested in a flat group on the same line: that group's fit decides.
+        if self.flat && self.line_start <= self.flat_start {
+            f(self);
+            return;
+        }
+        let checkpoint = self.checkpoint();
+        let outer = (self.flat, self.flat_start);
+        self.flat = true;
+        self.flat_start = checkpoint.output_len;
+        f(self);
+        self.flat = false;
+        if !self.first_line_fits(&checkpoint) {
+            self.restore(checkpoint);
+            f(self);
+        }
+        (self.flat, self.flat_start) = outer;
+    }
+
+    /// A point where the enclosing group may break: `flat` when the group is
+    /// flat, a newline and indentation otherwise.
+    pub(crate) fn soft_line(&mut self, flat: &str) {
+        if self.flat {
+            self.w(flat);
+        } else {
+            self.newline();
+            self.write_indent();
+        }
+    }
+
+    /// Indent the lines the enclosing group breaks into. A flat group is not
+    /// indented, so a closure in it keeps the indentation of its statement.
+    pub(crate) fn soft_indent(&mut self) {
+        if !self.flat {
+            self.indent();
+        }
+    }
+
+    /// Undo [`soft_indent`](Self::soft_indent).
+    pub(crate) fn soft_dedent(&mut self) {
+        if !self.flat {
+            self.dedent();
+        }
+    }
+
+    /// Whether the enclosing group is broken.
+    pub(crate) fn is_broken(&self) -> bool {
+        !self.flat
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            output_len: self.output.len(),
+            line_start: self.line_start,
+            comment_cursor: self.comment_cursor,
+            in_html_mode: self.in_html_mode,
+            has_php_content: self.has_php_content,
+        }
+    }
+
+    fn restore(&mut self, checkpoint: Checkpoint) {
+        self.output.truncate(checkpoint.output_len);
+        self.line_start = checkpoint.line_start;
+        self.comment_cursor = checkpoint.comment_cursor;
+        self.in_html_mode = checkpoint.in_html_mode;
+        self.has_php_content = checkpoint.has_php_content;
+    }
+
+    /// Whether the line holding the start of the output since `checkpoint`
+    /// is within the line width, counting a tab as one indentation level.
+    fn first_line_fits(&self, checkpoint: &Checkpoint) -> bool {
+        let from = checkpoint.output_len;
+        let end = self.output[from..]
+            .find('\n')
+            .map_or(self.output.len(), |i| from + i);
+        let tab_width = self.options.indent_width();
+        let width: usize = self.output[checkpoint.line_start..end]
+            .trim_end_matches('\r')
+            .chars()
+            .map(|c| if c == '\t' { tab_width } else { 1 })
+            .sum();
+        width <= self.options.line_width()
+    }
+}
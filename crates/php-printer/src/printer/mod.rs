@@ -1,34 +1,16 @@
 mod decls;
 mod exprs;
 mod helpers;
+mod layout;
 mod stmts;
 mod types;
 
-use php_ast::Comment;
+use php_ast::ast::StmtKind;
+use php_ast::{Comment, Span};
 
-/// Configuration for the pretty printer.
-pub struct PrinterConfig {
-    pub indent: Indent,
-    pub newline: &'static str,
-    /// Maximum blank lines preserved between statements. 0 normalizes all blank lines away.
-    pub blank_lines_upper_bound: usize,
-}
-
-/// Indentation style.
-pub enum Indent {
-    Spaces(usize),
-    Tabs,
-}
-
-impl Default for PrinterConfig {
-    fn default() -> Self {
-        Self {
-            indent: Indent::Spaces(4),
-            newline: "\n",
-            blank_lines_upper_bound: 1,
-        }
-    }
-}
+use crate::options::{
+    BraceStyle, CommaList, CommentPolicy, FinalCloseTag, Indent, PrintOptions, TrailingCommas,
+};
 
 const SPACES: [&str; 17] = [
     "",
@@ -58,6 +40,7 @@ pub(crate) struct Printer<'src> {
     indent_str: &'static str,
     nl: &'static str,
     blank_lines_upper_bound: usize,
+    pub(crate) options: PrintOptions,
     pub(crate) depth: usize,
     source: &'src str,
     comments: &'src [Comment<'src>],
@@ -68,34 +51,44 @@ pub(crate) struct Printer<'src> {
     /// True when at least one PHP statement has been emitted in the current PHP block.
     /// `?>` is only emitted when this is true — empty `<?php ?>` blocks are suppressed.
     has_php_content: bool,
+    /// Offset in `output` where the current line starts.
+    line_start: usize,
+    /// True while a group is printed flat; see [`layout`].
+    flat: bool,
+    /// Offset in `output` where the innermost flat group starts.
+    flat_start: usize,
 }
 
 impl<'src> Printer<'src> {
-    pub fn new(config: &PrinterConfig) -> Self {
-        Self::with_comments(config, "", &[])
+    pub fn new(options: &PrintOptions) -> Self {
+        Self::with_comments(options, "", &[])
     }
 
     pub fn with_comments(
-        config: &PrinterConfig,
+        options: &PrintOptions,
         source: &'src str,
         comments: &'src [Comment<'src>],
     ) -> Self {
-        let indent_str = match config.indent {
-            Indent::Spaces(n) => SPACES[n.min(16)],
+        let indent_str = match options.indent() {
+            Indent::Spaces(n) => SPACES[n],
             Indent::Tabs => "\t",
         };
         Self {
             output: String::with_capacity(4096),
             indent_level: 0,
             indent_str,
-            nl: config.newline,
-            blank_lines_upper_bound: config.blank_lines_upper_bound,
+            nl: options.newline(),
+            blank_lines_upper_bound: options.blank_lines_upper_bound(),
+            options: options.clone(),
             depth: 0,
             source,
             comments,
             comment_cursor: 0,
             in_html_mode: false,
             has_php_content: false,
+            line_start: 0,
+            flat: false,
+            flat_start: 0,
         }
     }
 
@@ -104,9 +97,21 @@ impl<'src> Printer<'src> {
     }
 
     pub(crate) fn w(&mut self, s: &str) {
+        if let Some(i) = s.rfind('\n') {
+            self.line_start = self.output.len() + i + 1;
+        }
         self.output.push_str(s);
     }
 
+    /// Write the source of `span` as written. The fallback for node kinds
+    /// this printer does not know, which a newer `php-ast` may add.
+    pub(crate) fn w_source(&mut self, span: Span) {
+        let source = self.source;
+        self.w(source
+            .get(span.start as usize..span.end as usize)
+            .unwrap_or_default());
+    }
+
     /// Re-enter PHP mode if currently in HTML mode.
     /// Call this after `print_stmts` and before emitting any PHP-syntax token
     /// (closing brace, `endforeach`, etc.) that must appear inside a PHP block.
@@ -121,6 +126,7 @@ impl<'src> Printer<'src> {
 
     pub(crate) fn newline(&mut self) {
         self.output.push_str(self.nl);
+        self.line_start = self.output.len();
     }
 
     pub(crate) fn write_indent(&mut self) {
@@ -129,6 +135,41 @@ impl<'src> Printer<'src> {
         }
     }
 
+    /// Open the body of a function, method, or class-like declaration.
+    pub(crate) fn open_declaration_body(&mut self) {
+        match self.options.brace_style() {
+            BraceStyle::SameLine => self.w(" {"),
+            BraceStyle::Psr12 | BraceStyle::NextLine => self.brace_on_next_line(),
+        }
+    }
+
+    /// Open a block after a control structure header, closure signature, or
+    /// namespace name.
+    pub(crate) fn open_block(&mut self) {
+        match self.options.brace_style() {
+            BraceStyle::NextLine => self.brace_on_next_line(),
+            BraceStyle::Psr12 | BraceStyle::SameLine => self.w(" {"),
+        }
+    }
+
+    /// Separate a closing `}` from a following `else`, `elseif`, `catch`,
+    /// `finally`, or `while`.
+    pub(crate) fn after_closing_brace(&mut self) {
+        match self.options.brace_style() {
+            BraceStyle::NextLine => {
+                self.newline();
+                self.write_indent();
+            }
+            BraceStyle::Psr12 | BraceStyle::SameLine => self.w(" "),
+        }
+    }
+
+    fn brace_on_next_line(&mut self) {
+        self.newline();
+        self.write_indent();
+        self.w("{");
+    }
+
     pub(crate) fn indent(&mut self) {
         self.indent_level += 1;
     }
@@ -176,14 +217,59 @@ impl<'src> Printer<'src> {
         }
     }
 
-    pub(crate) fn flush_trailing_comments(&mut self, _after_offset: u32, _before_offset: u32) {
-        // Trailing comments are not emitted here.
-        // They are always emitted as leading (standalone) comments before the next node.
-        // This ensures round-trip stability: when we re-parse and re-print, comments
-        // maintain consistent positioning rather than drifting between being inline vs leading.
+    /// Emit the comments that follow `after_offset` on the same source line
+    /// at the end of the current line. Nothing is emitted under
+    /// [`CommentPolicy::OwnLine`], or while comments from before
+    /// `after_offset` are pending, since they must be emitted first.
+    pub(crate) fn flush_trailing_comments(&mut self, after_offset: u32) {
+        if self.options.comment_policy() == CommentPolicy::OwnLine
+            || self.has_comments_before(after_offset)
+        {
+            return;
+        }
+        let mut end = after_offset as usize;
+        while let Some(c) = self.comments.get(self.comment_cursor) {
+            let same_line = self
+                .source
+                .get(end..c.span.start as usize)
+                .is_some_and(|gap| gap.bytes().all(|b| matches!(b, b' ' | b'\t' | b';')));
+            if !same_line {
+                break;
+            }
+            self.w(" ");
+            self.w(c.text.trim_end());
+            self.comment_cursor += 1;
+            end = c.span.end as usize;
+            if matches!(
+                c.kind,
+                php_ast::CommentKind::Line | php_ast::CommentKind::Hash
+            ) {
+                break;
+            }
+        }
+    }
+
+    /// Emit the comments pending before `end` inside an empty `{}` body, one
+    /// level deeper than the braces.
+    pub(crate) fn flush_empty_body_comments(&mut self, end: u32) {
+        if !self.has_comments_before(end) {
+            return;
+        }
+        self.newline();
+        self.indent();
+        self.flush_leading_comments(end);
+        self.dedent();
+        self.write_indent();
     }
 
     pub(crate) fn flush_remaining_comments(&mut self) {
+        if self.comment_cursor == self.comments.len() {
+            return;
+        }
+        self.open_php_for_comments();
+        if !self.output[self.line_start..].trim().is_empty() {
+            self.newline();
+        }
         while self.comment_cursor < self.comments.len() {
             let c = &self.comments[self.comment_cursor];
             self.emit_comment_standalone(c, false);
@@ -191,6 +277,17 @@ impl<'src> Printer<'src> {
         }
     }
 
+    /// Open a PHP block when comments are about to be emitted after inline
+    /// HTML, as for `<?php // note ?>`, so they do not become HTML text.
+    fn open_php_for_comments(&mut self) {
+        if self.in_html_mode {
+            self.w("<?php");
+            self.newline();
+            self.in_html_mode = false;
+            self.has_php_content = true;
+        }
+    }
+
     fn emit_comment_standalone(&mut self, comment: &Comment, inline: bool) {
         use php_ast::CommentKind;
         match comment.kind {
@@ -205,13 +302,9 @@ impl<'src> Printer<'src> {
                         self.newline();
                         self.write_indent();
                     }
-                    let trimmed = if i == 0 { line.trim_end() } else { line.trim() };
-                    self.w(trimmed);
+                    self.w(&helpers::doc_comment_line(i, line));
                 }
                 self.newline();
-                if !inline {
-                    self.write_indent();
-                }
             }
             CommentKind::Line | CommentKind::Hash => {
                 let text = comment.text.trim_start().trim_end();
@@ -251,6 +344,21 @@ impl<'src> Printer<'src> {
             .any(|c| c.span.start < offset)
     }
 
+    /// Whether a list ends in a comma, given the `comma` after its last
+    /// element in the source and whether it is printed over several lines.
+    pub(crate) fn trailing_comma(
+        &self,
+        list: CommaList,
+        comma: Option<Span>,
+        multi_line: bool,
+    ) -> bool {
+        match self.options.trailing_commas(list) {
+            TrailingCommas::MultiLine => multi_line,
+            TrailingCommas::Never => false,
+            TrailingCommas::Preserve => comma.is_some(),
+        }
+    }
+
     // =========================================================================
     // Top-level
     // =========================================================================
@@ -258,19 +366,50 @@ impl<'src> Printer<'src> {
     pub fn print_program(&mut self, program: &php_ast::ast::Program) {
         // Check if first statement is InlineHtml at position 0 (truly starts with HTML, no PHP before it).
         // If span.start > 0, there was PHP content before this statement.
+        // A short echo tag opening the file leaves HTML mode by itself.
         let html_at_start = matches!(
             program.stmts.first().map(|s| (s.span.start, &s.kind)),
-            Some((0, php_ast::ast::StmtKind::InlineHtml(_)))
+            Some((0, StmtKind::InlineHtml(_)))
+        ) || matches!(
+            program.stmts.first().map(|s| &s.kind),
+            Some(StmtKind::Echo(echo)) if echo.open_tag.is_some_and(|tag| tag.start == 0)
         );
         if html_at_start {
             self.in_html_mode = true;
-        } else if !program.stmts.is_empty() {
+        } else if !program.stmts.is_empty() || !self.comments.is_empty() {
             self.w("<?php");
             self.newline();
             self.has_php_content = true;
         }
-        self.print_stmts(&program.stmts, false);
+        let mut stmts = &program.stmts[..];
+        // The inline HTML after the final `?>`, which prints the `?>` too.
+        let trailing_html = program.close_tag.and_then(|tag| match stmts.last() {
+            Some(last) if last.span.start == tag.end => match &last.kind {
+                StmtKind::InlineHtml(html) => Some(html),
+                _ => None,
+            },
+            _ => None,
+        });
+        let omit = self.options.final_close_tag() == FinalCloseTag::Omit;
+        if omit && trailing_html.is_some_and(|html| html.output().is_empty()) {
+            stmts = &stmts[..stmts.len() - 1];
+        }
+        self.print_stmts(stmts, false);
         self.flush_remaining_comments();
+        if program.close_tag.is_some() && trailing_html.is_none() && !omit && !self.in_html_mode {
+            if !self.has_php_content {
+                self.w("<?php");
+            }
+            self.newline();
+            self.w("?>");
+        }
+    }
+
+    /// Print statements nested `depth` levels deep, as part of a PHP block.
+    pub(crate) fn print_stmts_at(&mut self, stmts: &[php_ast::ast::Stmt], depth: usize) {
+        self.indent_level = depth;
+        self.has_php_content = true;
+        self.print_stmts(stmts, false);
     }
 
     pub(crate) fn print_stmts(&mut self, stmts: &[php_ast::ast::Stmt], indent: bool) {
@@ -312,13 +451,15 @@ impl<'src> Printer<'src> {
                     self.newline();
                 }
             }
+            if self.has_comments_before(stmt.span.start) {
+                self.open_php_for_comments();
+            }
             self.flush_leading_comments(stmt.span.start);
             if !self.in_html_mode {
                 self.write_indent();
             }
             self.print_stmt(stmt);
-            let next_start = stmts.get(i + 1).map(|s| s.span.start).unwrap_or(u32::MAX);
-            self.flush_trailing_comments(stmt.span.end.saturating_sub(1), next_start);
+            self.flush_trailing_comments(stmt.span.end);
 
             prev_was_inline_html = is_inline_html;
         }
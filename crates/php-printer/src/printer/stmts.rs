@@ -1,5 +1,6 @@
 use php_ast::ast::*;
 
+use crate::options::CommaList;
 use crate::precedence::*;
 
 use super::{Printer, MAX_DEPTH};
@@ -17,6 +18,12 @@ impl<'src> Printer<'src> {
     }
 
     fn print_stmt_inner(&mut self, stmt: &Stmt) {
+        if let StmtKind::Echo(echo) = &stmt.kind {
+            if echo.is_short_echo() {
+                self.print_short_echo(echo);
+                return;
+            }
+        }
         // Re-enter PHP mode lazily: emit <?php before the first PHP statement after HTML.
         let is_inline_html = matches!(&stmt.kind, StmtKind::InlineHtml(_));
         if self.in_html_mode && !is_inline_html {
@@ -33,9 +40,9 @@ impl<'src> Printer<'src> {
                 self.print_expr(expr, PREC_LOWEST);
                 self.w(";");
             }
-            StmtKind::Echo(exprs) => {
+            StmtKind::Echo(echo) => {
                 self.w("echo ");
-                self.print_comma_separated_exprs(exprs);
+                self.print_comma_separated_exprs(&echo.exprs);
                 self.w(";");
             }
             StmtKind::Return(expr) => {
@@ -54,7 +61,7 @@ impl<'src> Printer<'src> {
                     self.newline();
                     self.write_indent();
                 } else {
-                    self.flush_leading_comments(stmt.span.end);
+                    self.flush_empty_body_comments(stmt.span.end);
                 }
                 self.w("}");
             }
@@ -67,7 +74,7 @@ impl<'src> Printer<'src> {
                     self.print_alt_section(w.body);
                     self.w("endwhile;");
                 } else {
-                    self.w(") ");
+                    self.w(")");
                     self.print_block_or_stmt(w.body);
                 }
             }
@@ -83,7 +90,7 @@ impl<'src> Printer<'src> {
                     self.print_alt_section(f.body);
                     self.w("endfor;");
                 } else {
-                    self.w(") ");
+                    self.w(")");
                     self.print_block_or_stmt(f.body);
                 }
             }
@@ -101,14 +108,15 @@ impl<'src> Printer<'src> {
                     self.print_alt_section(f.body);
                     self.w("endforeach;");
                 } else {
-                    self.w(") ");
+                    self.w(")");
                     self.print_block_or_stmt(f.body);
                 }
             }
             StmtKind::DoWhile(dw) => {
-                self.w("do ");
+                self.w("do");
                 self.print_block_or_stmt(dw.body);
-                self.w(" while (");
+                self.after_closing_brace();
+                self.w("while (");
                 self.print_expr(&dw.condition, PREC_LOWEST);
                 self.w(");");
             }
@@ -135,7 +143,8 @@ impl<'src> Printer<'src> {
                 if sw.uses_alternative {
                     self.w("):");
                 } else {
-                    self.w(") {");
+                    self.w(")");
+                    self.open_block();
                 }
                 self.newline();
                 self.indent();
@@ -203,7 +212,6 @@ impl<'src> Printer<'src> {
                         self.w("enddeclare;");
                     }
                     (Some(body), false) => {
-                        self.w(" ");
                         self.print_block_or_stmt(body);
                     }
                     (None, _) => {
@@ -266,7 +274,7 @@ impl<'src> Printer<'src> {
             }
             StmtKind::HaltCompiler(data) => {
                 self.w("__halt_compiler();");
-                self.w(data);
+                self.w(data.text);
             }
             StmtKind::Nop => {
                 self.w(";");
@@ -275,13 +283,33 @@ impl<'src> Printer<'src> {
                 if !self.in_html_mode && self.has_php_content {
                     self.w("?>");
                 }
-                self.w(html);
+                self.w(html.text);
                 self.in_html_mode = true;
                 self.has_php_content = false;
             }
             StmtKind::Error => {
                 self.w("/* error */");
             }
+            _ => self.w_source(stmt.span),
+        }
+    }
+
+    /// `<?= … ?>`, leaving PHP mode first. Without its `?>` the short echo
+    /// opens a PHP block for the statements after it.
+    fn print_short_echo(&mut self, echo: &EchoStmt) {
+        if !self.in_html_mode && self.has_php_content {
+            self.w("?>");
+        }
+        self.w("<?= ");
+        self.print_comma_separated_exprs(&echo.exprs);
+        if echo.close_tag.is_some() {
+            self.w(" ?>");
+            self.in_html_mode = true;
+            self.has_php_content = false;
+        } else {
+            self.w(";");
+            self.in_html_mode = false;
+            self.has_php_content = true;
         }
     }
 
@@ -310,16 +338,18 @@ impl<'src> Printer<'src> {
         } else {
             self.w("if (");
             self.print_expr(&if_stmt.condition, PREC_LOWEST);
-            self.w(") ");
+            self.w(")");
             self.print_block_or_stmt(if_stmt.then_branch);
             for elseif in if_stmt.elseif_branches.iter() {
-                self.w(" elseif (");
+                self.after_closing_brace();
+                self.w("elseif (");
                 self.print_expr(&elseif.condition, PREC_LOWEST);
-                self.w(") ");
+                self.w(")");
                 self.print_block_or_stmt(&elseif.body);
             }
             if let Some(else_branch) = &if_stmt.else_branch {
-                self.w(" else ");
+                self.after_closing_brace();
+                self.w("else");
                 self.print_block_or_stmt(else_branch);
             }
         }
@@ -346,7 +376,8 @@ impl<'src> Printer<'src> {
             .first()
             .map(|c| c.span.start)
             .unwrap_or(stmt.span.end);
-        self.w("try {");
+        self.w("try");
+        self.open_block();
         if !tc.body.is_empty() {
             self.newline();
             self.indent();
@@ -356,22 +387,24 @@ impl<'src> Printer<'src> {
             self.dedent();
             self.write_indent();
         } else {
-            self.flush_leading_comments(try_end);
+            self.flush_empty_body_comments(try_end);
         }
         self.w("}");
         for catch in tc.catches.iter() {
-            self.w(" catch (");
+            self.after_closing_brace();
+            self.w("catch (");
             for (j, ty) in catch.types.iter().enumerate() {
                 if j > 0 {
                     self.w("|");
                 }
-                self.print_name(ty);
+                self.print_class_ref(ty, PREC_PRIMARY);
             }
             if let Some(var) = catch.var {
                 self.w(" $");
-                self.w(var);
+                self.w(var.name);
             }
-            self.w(") {");
+            self.w(")");
+            self.open_block();
             if !catch.body.is_empty() {
                 self.newline();
                 self.indent();
@@ -381,12 +414,14 @@ impl<'src> Printer<'src> {
                 self.dedent();
                 self.write_indent();
             } else {
-                self.flush_leading_comments(catch.span.end);
+                self.flush_empty_body_comments(catch.span.end);
             }
             self.w("}");
         }
         if let Some(finally) = &tc.finally {
-            self.w(" finally {");
+            self.after_closing_brace();
+            self.w("finally");
+            self.open_block();
             if !finally.is_empty() {
                 self.newline();
                 self.indent();
@@ -396,15 +431,15 @@ impl<'src> Printer<'src> {
                 self.dedent();
                 self.write_indent();
             } else {
-                self.flush_leading_comments(stmt.span.end);
+                self.flush_empty_body_comments(stmt.span.end);
             }
             self.w("}");
         }
     }
 
     pub(crate) fn print_block_or_stmt(&mut self, stmt: &Stmt) {
+        self.open_block();
         if let StmtKind::Block(stmts) = &stmt.kind {
-            self.w("{");
             if !stmts.is_empty() {
                 self.newline();
                 self.indent();
@@ -414,20 +449,19 @@ impl<'src> Printer<'src> {
                 self.dedent();
                 self.write_indent();
             } else {
-                self.flush_leading_comments(stmt.span.end);
+                self.flush_empty_body_comments(stmt.span.end);
             }
             self.w("}");
         } else {
-            self.w("{");
             self.newline();
             self.indent();
             self.write_indent();
             self.print_stmt(stmt);
             self.ensure_php_mode();
             self.newline();
+            self.flush_leading_comments(stmt.span.end);
             self.dedent();
             self.write_indent();
-            self.flush_leading_comments(stmt.span.end);
             self.w("}");
         }
     }
@@ -440,7 +474,7 @@ impl<'src> Printer<'src> {
         }
         match &ns.body {
             NamespaceBody::Braced(stmts) => {
-                self.w(" {");
+                self.open_block();
                 if !stmts.is_empty() {
                     self.newline();
                     self.indent();
@@ -451,7 +485,7 @@ impl<'src> Printer<'src> {
                     self.dedent();
                     self.write_indent();
                 } else {
-                    self.flush_leading_comments(stmt.span.end);
+                    self.flush_empty_body_comments(stmt.span.end);
                 }
                 self.w("}");
             }
@@ -466,16 +500,66 @@ impl<'src> Printer<'src> {
             UseKind::Const => self.w("const "),
             UseKind::Normal => {}
         }
+        // The items of a mixed group use, `use A\{B, function c}`, have kinds
+        // of their own, which only the group syntax can express.
+        let names: Vec<_> = use_decl
+            .uses
+            .iter()
+            .map(|item| item.name.join_parts())
+            .collect();
+        let prefix_len = if use_decl.uses.iter().any(|item| item.kind.is_some()) {
+            common_namespace_len(&names)
+        } else {
+            0
+        };
+        if prefix_len > 0 {
+            self.w(&names[0][..prefix_len]);
+            self.w("{");
+        }
         for (i, item) in use_decl.uses.iter().enumerate() {
             if i > 0 {
                 self.w(", ");
             }
-            self.print_name(&item.name);
+            if prefix_len > 0 {
+                match item.kind {
+                    Some(UseKind::Function) => self.w("function "),
+                    Some(UseKind::Const) => self.w("const "),
+                    _ => {}
+                }
+                self.w(&names[i][prefix_len..]);
+            } else {
+                self.print_name(&item.name);
+            }
             if let Some(alias) = item.alias {
                 self.w(" as ");
                 self.w(alias);
             }
         }
+        if prefix_len > 0 {
+            let last = use_decl.uses.last().and_then(|item| item.comma);
+            if self.trailing_comma(CommaList::GroupUses, last, false) {
+                self.w(",");
+            }
+            self.w("}");
+        }
         self.w(";");
     }
 }
+
+/// The length of the longest namespace, `\`-terminated, that every name is
+/// in, or 0 if they share none.
+fn common_namespace_len(names: &[std::borrow::Cow<str>]) -> usize {
+    let Some(first) = names.first() else {
+        return 0;
+    };
+    first
+        .match_indices('\\')
+        .map(|(i, _)| i + 1)
+        .rev()
+        .find(|&len| {
+            names
+                .iter()
+                .all(|name| name.len() > len && name.starts_with(&first[..len]))
+        })
+        .unwrap_or(0)
+}
@@ -23,6 +23,18 @@ impl<'src> Printer<'src> {
         }
     }
 
+    /// Print the class operand of `new`, `instanceof`, `::`, or `catch`;
+    /// a computed class binds tighter than `prec`.
+    pub(crate) fn print_class_ref(&mut self, class: &ClassRef, prec: i8) {
+        match class {
+            ClassRef::Name(name) => self.print_name(name),
+            ClassRef::SelfKw(_) | ClassRef::ParentKw(_) | ClassRef::StaticKw(_) => {
+                self.w(class.keyword().unwrap_or_default())
+            }
+            ClassRef::Expr(expr) => self.print_expr(expr, prec),
+        }
+    }
+
     pub(crate) fn print_type_hint(&mut self, hint: &TypeHint) {
         self.print_type_hint_inner(hint, TypeContext::Top);
     }
@@ -65,6 +77,7 @@ impl<'src> Printer<'src> {
                     self.w(")");
                 }
             }
+            _ => self.w_source(hint.span),
         }
     }
 }
@@ -0,0 +1,193 @@
+//! Formatting part of a file.
+
+use php_ast::ast::{ClassMemberKind, EnumMemberKind, MethodDecl, NamespaceBody, Stmt, StmtKind};
+use php_ast::{Comment, Program, Span};
+
+use crate::printer::Printer;
+use crate::PrintOptions;
+
+/// Format the statements of `source` that overlap `range`, leaving every
+/// other byte as it is. Returns the whole source with those statements
+/// replaced.
+///
+/// The statements formatted are those of the innermost statement list the
+/// range falls in: a selection inside a function body formats the selected
+/// statements of that body at its indentation, while a selection that also
+/// covers the function's signature formats the whole function. A doc comment
+/// goes with the statement it documents. An empty range formats the
+/// statement it is in, and a range that overlaps no statement, or one in a
+/// statement list holding inline HTML, leaves the source unchanged.
+///
+/// `program` and `comments` must come from parsing `source`.
+///
+/// ```
+/// let src = "<?php\nfunction f() {\n  $a=1;\n  $b  =  2;\n}\n$c=3;\n";
+/// let arena = bumpalo::Bump::new();
+/// let result = php_rs_parser::parse(&arena, src);
+/// let start = src.find("$b").unwrap() as u32;
+/// let range = php_ast::Span::new(start, start);
+/// let output = php_printer::format_range(
+///     &result.program,
+///     result.source,
+///     &result.comments,
+///     range,
+///     &php_printer::PrintOptions::default(),
+/// );
+/// assert_eq!(output, "<?php\nfunction f() {\n  $a=1;\n    $b = 2;\n}\n$c=3;\n");
+/// ```
+pub fn format_range<'src>(
+    program: &Program<'_, 'src>,
+    source: &'src str,
+    comments: &'src [Comment<'src>],
+    range: Span,
+    options: &PrintOptions,
+) -> String {
+    let Some((stmts, depth)) = select(&program.stmts, range, 0) else {
+        return source.to_string();
+    };
+    let (first, last) = (&stmts[0], &stmts[stmts.len() - 1]);
+    let mut start = doc_comment(first).map_or(first.span.start, |doc| doc.span.start) as usize;
+    let end = last.span.end as usize;
+    // Re-indent the first line too when the statements start it.
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let starts_line = source[line_start..start].trim().is_empty();
+    if starts_line {
+        start = line_start;
+    }
+
+    let from = comments.partition_point(|c| (c.span.start as usize) < start);
+    let to = comments.partition_point(|c| (c.span.start as usize) < end);
+    let mut printer = Printer::with_comments(options, source, &comments[from..to]);
+    printer.print_stmts_at(stmts, depth);
+    let printed = printer.into_output();
+    let printed = if starts_line {
+        printed.as_str()
+    } else {
+        printed.trim_start()
+    };
+
+    let mut output = String::with_capacity(source.len() + printed.len());
+    output.push_str(&source[..start]);
+    output.push_str(printed);
+    output.push_str(&source[end..]);
+    output
+}
+
+/// The statements of the innermost list that `range` falls in that overlap
+/// it, with the indentation level of the list.
+fn select<'s, 'arena, 'src>(
+    stmts: &'s [Stmt<'arena, 'src>],
+    range: Span,
+    depth: usize,
+) -> Option<(&'s [Stmt<'arena, 'src>], usize)> {
+    let from = stmts.iter().position(|s| overlaps(s.span, range))?;
+    let to = stmts.iter().rposition(|s| overlaps(s.span, range))? + 1;
+    if stmts
+        .iter()
+        .any(|s| matches!(s.kind, StmtKind::InlineHtml(_)))
+    {
+        return None;
+    }
+    let stmt = &stmts[from];
+    if to - from == 1 && stmt.span.start <= range.start && range.end <= stmt.span.end {
+        for (body, levels) in bodies(stmt) {
+            let (Some(first), Some(last)) = (body.first(), body.last()) else {
+                continue;
+            };
+            if first.span.start <= range.start && range.end <= last.span.end {
+                return select(body, range, depth + levels);
+            }
+        }
+    }
+    Some((&stmts[from..to], depth))
+}
+
+/// Whether `span` and `range` share a byte, or `span` holds the empty
+/// `range`.
+fn overlaps(span: Span, range: Span) -> bool {
+    span.start < range.end.max(range.start + 1) && range.start < span.end
+}
+
+/// The braced statement lists nested directly in `stmt`, with how many
+/// levels deeper than `stmt` the printer indents each.
+fn bodies<'s, 'arena, 'src>(
+    stmt: &'s Stmt<'arena, 'src>,
+) -> Vec<(&'s [Stmt<'arena, 'src>], usize)> {
+    fn block<'s, 'arena, 'src>(
+        stmt: &'s Stmt<'arena, 'src>,
+    ) -> Option<(&'s [Stmt<'arena, 'src>], usize)> {
+        match &stmt.kind {
+            StmtKind::Block(stmts) => Some((stmts, 1)),
+            _ => None,
+        }
+    }
+    fn method<'s, 'arena, 'src>(
+        method: &'s MethodDecl<'arena, 'src>,
+    ) -> Option<(&'s [Stmt<'arena, 'src>], usize)> {
+        method.body.as_ref().map(|body| (&body[..], 2))
+    }
+    let mut bodies = Vec::new();
+    match &stmt.kind {
+        StmtKind::Block(stmts) => bodies.push((&stmts[..], 1)),
+        StmtKind::If(if_stmt) => {
+            bodies.extend(block(if_stmt.then_branch));
+            bodies.extend(
+                if_stmt
+                    .elseif_branches
+                    .iter()
+                    .filter_map(|b| block(&b.body)),
+            );
+            bodies.extend(if_stmt.else_branch.and_then(block));
+        }
+        StmtKind::While(w) => bodies.extend(block(w.body)),
+        StmtKind::For(f) => bodies.extend(block(f.body)),
+        StmtKind::Foreach(f) => bodies.extend(block(f.body)),
+        StmtKind::DoWhile(d) => bodies.extend(block(d.body)),
+        StmtKind::Declare(d) => bodies.extend(d.body.and_then(block)),
+        StmtKind::Function(func) => bodies.push((&func.body[..], 1)),
+        StmtKind::Switch(sw) => bodies.extend(sw.cases.iter().map(|case| (&case.body[..], 2))),
+        StmtKind::TryCatch(tc) => {
+            bodies.push((&tc.body[..], 1));
+            bodies.extend(tc.catches.iter().map(|catch| (&catch.body[..], 1)));
+            bodies.extend(tc.finally.as_ref().map(|finally| (&finally[..], 1)));
+        }
+        StmtKind::Namespace(ns) => {
+            if let NamespaceBody::Braced(stmts) = &ns.body {
+                bodies.push((&stmts[..], 1));
+            }
+        }
+        StmtKind::Class(class) => {
+            bodies.extend(class.members.iter().filter_map(|m| match &m.kind {
+                ClassMemberKind::Method(m) => method(m),
+                _ => None,
+            }))
+        }
+        StmtKind::Trait(trait_decl) => {
+            bodies.extend(trait_decl.members.iter().filter_map(|m| match &m.kind {
+                ClassMemberKind::Method(m) => method(m),
+                _ => None,
+            }))
+        }
+        StmtKind::Enum(enum_decl) => {
+            bodies.extend(enum_decl.members.iter().filter_map(|m| match &m.kind {
+                EnumMemberKind::Method(m) => method(m),
+                _ => None,
+            }))
+        }
+        _ => {}
+    }
+    bodies
+}
+
+/// The doc comment the printer prints with `stmt`, which precedes its span.
+fn doc_comment<'s, 'src>(stmt: &'s Stmt<'_, 'src>) -> Option<&'s Comment<'src>> {
+    match &stmt.kind {
+        StmtKind::Function(func) => func.doc_comment.as_ref(),
+        StmtKind::Class(class) => class.doc_comment.as_ref(),
+        StmtKind::Interface(iface) => iface.doc_comment.as_ref(),
+        StmtKind::Trait(trait_decl) => trait_decl.doc_comment.as_ref(),
+        StmtKind::Enum(enum_decl) => enum_decl.doc_comment.as_ref(),
+        StmtKind::Const(items) => items.first().and_then(|item| item.doc_comment.as_ref()),
+        _ => None,
+    }
+}
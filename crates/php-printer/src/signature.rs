@@ -0,0 +1,88 @@
+//! Declaration signatures, for hovers and outlines.
+
+use php_ast::ast::{ClassMemberKind, EnumMemberKind, StmtKind, TypeHint};
+use php_ast::node::AnyNode;
+
+use crate::precedence::PREC_LOWEST;
+use crate::printer::Printer;
+use crate::PrintOptions;
+
+/// Render the signature of the declaration `node`: its modifiers, name,
+/// parameters, types, and initial value, without attributes, doc comment,
+/// body, or terminating `;`.
+///
+/// Declarations are functions, classes, interfaces, traits, enums, and
+/// `const` statements; methods, properties, class constants, and enum cases;
+/// parameters; and the items of a `const` statement. Any other node returns
+/// `None`, as does a `const` statement declaring several constants.
+///
+/// ```
+/// use php_ast::{node::AnyNode, ClassMemberKind, StmtKind};
+///
+/// let arena = bumpalo::Bump::new();
+/// let src = "<?php\nfinal class User {\n    /** Save it. */\n    public static function find(int $id, ?string $name = null): ?self { return null; }\n}";
+/// let result = php_rs_parser::parse(&arena, src);
+/// let StmtKind::Class(class) = &result.program.stmts[0].kind else { unreachable!() };
+/// assert_eq!(
+///     php_printer::print_signature(AnyNode::from(&result.program.stmts[0])).as_deref(),
+///     Some("final class User"),
+/// );
+/// assert_eq!(
+///     php_printer::print_signature(AnyNode::from(&class.members[0])).as_deref(),
+///     Some("public static function find(int $id, ?string $name = null): ?self"),
+/// );
+/// ```
+pub fn print_signature(node: AnyNode<'_, '_, '_>) -> Option<String> {
+    let mut p = Printer::new(&PrintOptions::default());
+    match node {
+        AnyNode::Stmt(stmt) => match &stmt.kind {
+            StmtKind::Function(func) => p.print_function_header(func),
+            StmtKind::Class(class) => p.print_class_header(class),
+            StmtKind::Interface(iface) => p.print_interface_header(iface),
+            StmtKind::Trait(trait_decl) => {
+                p.w("trait ");
+                p.w(trait_decl.name.or_error());
+            }
+            StmtKind::Enum(enum_decl) => p.print_enum_header(enum_decl),
+            StmtKind::Const(items) if items.len() == 1 => {
+                return print_signature(AnyNode::from(&items[0]));
+            }
+            _ => return None,
+        },
+        AnyNode::ClassMember(member) => match &member.kind {
+            ClassMemberKind::Method(method) => p.print_method_header(method),
+            ClassMemberKind::Property(prop) => p.print_property_header(prop),
+            ClassMemberKind::ClassConst(cc) => p.print_class_const_header(cc),
+            _ => return None,
+        },
+        AnyNode::EnumMember(member) => match &member.kind {
+            EnumMemberKind::Case(case) => {
+                p.w("case ");
+                p.w(case.name.or_error());
+                if let Some(value) = &case.value {
+                    p.w(" = ");
+                    p.print_expr(value, PREC_LOWEST);
+                }
+            }
+            EnumMemberKind::Method(method) => p.print_method_header(method),
+            EnumMemberKind::ClassConst(cc) => p.print_class_const_header(cc),
+            _ => return None,
+        },
+        AnyNode::Param(param) => p.print_params(std::slice::from_ref(param)),
+        AnyNode::ConstItem(item) => {
+            p.w("const ");
+            p.w(item.name.or_error());
+            p.w(" = ");
+            p.print_expr(&item.value, PREC_LOWEST);
+        }
+        _ => return None,
+    }
+    Some(p.into_output())
+}
+
+/// Render a type hint as written, e.g. `?Foo` or `(A&B)|null`.
+pub fn print_type_hint(hint: &TypeHint<'_, '_>) -> String {
+    let mut p = Printer::new(&PrintOptions::default());
+    p.print_type_hint(hint);
+    p.into_output()
+}
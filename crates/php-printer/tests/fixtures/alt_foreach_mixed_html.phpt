@@ -7,3 +7,4 @@ foreach ($items as $item):
     echo $item;
     ?></li><?php
 endforeach;
+?>
@@ -3,5 +3,6 @@
 ===print===
 <?php
 foreach ($xs as $x):
-    echo $x;
+    ?><?= $x ?><?php
 endforeach;
+?>
@@ -5,3 +5,4 @@
 if ($x):
     ?>hello<?php
 endif;
+?>
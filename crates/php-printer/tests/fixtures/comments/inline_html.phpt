@@ -0,0 +1,25 @@
+===source===
+<?php
+function tmpl() {
+    foo();
+    ?>
+<div>
+<?php // comment ?>
+    <p>text</p>
+<?php
+    bar();
+}
+===print===
+<?php
+function tmpl()
+{
+    foo();
+    ?>
+<div>
+<?php
+    // comment
+    ?>
+    <p>text</p>
+<?php
+    bar();
+}
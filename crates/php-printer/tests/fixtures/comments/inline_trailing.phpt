@@ -4,6 +4,5 @@ $x = 1; // inline comment
 echo $x;
 ===print===
 <?php
-$x = 1;
-// inline comment
+$x = 1; // inline comment
 echo $x;
@@ -0,0 +1,11 @@
+===source===
+<?php
+$a = [
+    1,
+    2, /* after */
+];
+===print===
+<?php
+$a = [1, 2];
+/* after */
+
@@ -0,0 +1,64 @@
+===config===
+comment_policy=own_line
+===source===
+<?php
+$x = 1; // why one
+$y = 2; /* two */ # and a hash
+foo($a, /* inside */ $b); // after call
+if ($x) {
+    bar(); // in block
+} // end if
+class A {
+    public $p = 1; // property
+    public function f() {} /** doc */
+    const C = 2; /* after const */
+}
+enum E {
+    case A; // first
+}
+$z = 3; /* between */ $w = 4;
+function g(
+    $a, // the a
+    $b,
+) {}
+===print===
+<?php
+$x = 1;
+// why one
+$y = 2;
+/* two */
+# and a hash
+foo($a, $b);
+/* inside */
+// after call
+if ($x) {
+    bar();
+    // in block
+}
+
+// end if
+class A
+{
+    public $p = 1;
+    // property
+    public function f()
+    {}
+
+    /** doc */
+    const C = 2;
+    /* after const */
+}
+
+enum E
+{
+    case A;
+    // first
+}
+$z = 3;
+/* between */
+$w = 4;
+
+function g($a, $b)
+{
+    // the a
+}
@@ -0,0 +1,8 @@
+===config===
+final_close_tag=omit
+===source===
+<?php echo 1; ?>
+
+===print===
+<?php
+echo 1;
@@ -0,0 +1,10 @@
+===config===
+final_close_tag=omit
+===source===
+<?php echo 1; ?>
+<footer>end</footer>
+===print===
+<?php
+echo 1;
+?>
+<footer>end</footer>
@@ -0,0 +1,8 @@
+===source===
+<?php echo 1; ?>
+
+===print===
+<?php
+echo 1;
+?>
+
@@ -9,3 +9,4 @@ if ($cond) {
 <div>hello</div>
 <?php
 }
+?>
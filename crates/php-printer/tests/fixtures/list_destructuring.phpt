@@ -0,0 +1,11 @@
+===source===
+<?php list($a, list($b, , $c)) = $x;
+list('id' => $id, 'tags' => &$tags) = $row;
+foreach ($rows as [$id, [$name]]) {}
+foreach ($pairs as $k => list($l, $r)) {}
+===print===
+<?php
+list($a, list($b, , $c)) = $x;
+list('id' => $id, 'tags' => &$tags) = $row;
+foreach ($rows as [$id, [$name]]) {}
+foreach ($pairs as $k => list($l, $r)) {}
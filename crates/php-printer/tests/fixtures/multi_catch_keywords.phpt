@@ -0,0 +1,13 @@
+===source===
+<?php class A extends B { function f() { try { g(); } catch (SELF | parent | \Foo\Bar   $e) {} } }
+===print===
+<?php
+class A extends B
+{
+    function f()
+    {
+        try {
+            g();
+        } catch (self|parent|\Foo\Bar $e) {}
+    }
+}
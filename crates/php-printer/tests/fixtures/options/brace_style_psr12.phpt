@@ -0,0 +1,51 @@
+===config===
+brace_style=psr12
+===source===
+<?php
+namespace App {
+    class A {
+        public function f($x) {
+            if ($x) { return 1; } elseif ($x > 1) { return 2; } else { return 3; }
+        }
+    }
+    function g() {
+        try { do { $i++; } while ($i < 3); } catch (E $e) { } finally { h(); }
+        $c = function () { return 1; };
+        switch ($x) { case 1: break; }
+    }
+}
+===print===
+<?php
+namespace App {
+    class A
+    {
+        public function f($x)
+        {
+            if ($x) {
+                return 1;
+            } elseif ($x > 1) {
+                return 2;
+            } else {
+                return 3;
+            }
+        }
+    }
+
+    function g()
+    {
+        try {
+            do {
+                $i++;
+            } while ($i < 3);
+        } catch (E $e) {} finally {
+            h();
+        }
+        $c = function() {
+            return 1;
+        };
+        switch ($x) {
+            case 1:
+                break;
+        }
+    }
+}
@@ -0,0 +1,34 @@
+===config===
+heredoc=flush
+===source===
+<?php
+function f() {
+    $a = <<<EOT
+    Hello $name
+      indented
+
+    done {$x->y}
+    EOT;
+    $b = <<<'EOT'
+    raw $text
+    EOT;
+    return <<<EOT
+    EOT;
+}
+===print===
+<?php
+function f()
+{
+    $a = <<<EOT
+Hello $name
+  indented
+
+done {$x->y}
+EOT;
+    $b = <<<'EOT'
+raw $text
+EOT;
+    return <<<EOT
+
+EOT;
+}
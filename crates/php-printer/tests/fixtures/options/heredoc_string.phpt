@@ -0,0 +1,25 @@
+===config===
+heredoc=string
+===source===
+<?php
+function f() {
+    $a = <<<EOT
+    Hello $name
+      indented
+
+    done {$x->y}
+    EOT;
+    $b = <<<'EOT'
+    raw $text
+    EOT;
+    return <<<EOT
+    EOT;
+}
+===print===
+<?php
+function f()
+{
+    $a = "Hello $name\n  indented\n\ndone {$x->y}";
+    $b = "raw \$text";
+    return '';
+}
@@ -0,0 +1,22 @@
+===config===
+indent=2
+===source===
+<?php
+class A {
+    public function f() {
+        if ($x) {
+            return 1;
+        }
+    }
+}
+===print===
+<?php
+class A
+{
+  public function f()
+  {
+    if ($x) {
+      return 1;
+    }
+  }
+}
@@ -0,0 +1,22 @@
+===config===
+indent=tabs
+===source===
+<?php
+class A {
+    public function f() {
+        if ($x) {
+            return 1;
+        }
+    }
+}
+===print===
+<?php
+class A
+{
+	public function f()
+	{
+		if ($x) {
+			return 1;
+		}
+	}
+}
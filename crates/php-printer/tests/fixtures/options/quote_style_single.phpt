@@ -0,0 +1,20 @@
+===config===
+quote_style=single
+===source===
+<?php
+$a = "plain";
+$b = 'it\'s';
+$c = "say \"hi\"";
+$d = 'back\slash';
+$e = "tab\t";
+$f = '$notvar';
+$g = "";
+===print===
+<?php
+$a = 'plain';
+$b = 'it\'s';
+$c = 'say "hi"';
+$d = 'back\\slash';
+$e = "tab\t";
+$f = "\$notvar";
+$g = '';
@@ -0,0 +1,23 @@
+===config===
+trailing_commas=never
+===source===
+<?php
+$r = match ($x) { 1 => "a", default => "b" };
+$a = [
+    1, // one
+    2, // two
+];
+[, $b, ] = $c;
+===print===
+<?php
+$r = match ($x) {
+    1 => 'a',
+    default => 'b'
+};
+$a = [
+    1,
+    // one
+    2
+];
+// two
+[, $b] = $c;
@@ -0,0 +1,30 @@
+===config===
+trailing_commas=never
+trailing_commas.params=preserve
+trailing_commas.match_arms=multi_line
+===source===
+<?php
+function f($a, $b,) {}
+f(1, 2,);
+$r = match ($x) { 1 => "a", default => "b" };
+$a = [
+    1, // one
+    2, // two
+];
+$b = [1, 2,];
+===print===
+<?php
+function f($a, $b,)
+{}
+f(1, 2);
+$r = match ($x) {
+    1 => 'a',
+    default => 'b',
+};
+$a = [
+    1,
+    // one
+    2
+];
+// two
+$b = [1, 2];
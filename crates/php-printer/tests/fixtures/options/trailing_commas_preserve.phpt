@@ -0,0 +1,32 @@
+===config===
+trailing_commas=preserve
+===source===
+<?php
+use A\{B, function c,};
+function f($a, $b,) {}
+f(1, 2,);
+g(1, 2);
+$a = [1, 2,];
+$b = [1, 2];
+$r = match ($x) { 1 => "a", default => "b", };
+$s = match ($x) { 1 => "a", default => "b" };
+$c = function () use ($a, $b,) {};
+===print===
+<?php
+use A\{B, function c,};
+
+function f($a, $b,)
+{}
+f(1, 2,);
+g(1, 2);
+$a = [1, 2,];
+$b = [1, 2];
+$r = match ($x) {
+    1 => 'a',
+    default => 'b',
+};
+$s = match ($x) {
+    1 => 'a',
+    default => 'b'
+};
+$c = function() use ($a, $b,) {};
@@ -3,3 +3,4 @@
 ===print===
 <?php
 echo 1;
+?>
@@ -0,0 +1,8 @@
+===source===
+<?= $a, $b ?>
+<p><?= $c; echo $d; ?></p>
+===print===
+<?= $a, $b ?>
+<p><?= $c;
+echo $d;
+?></p>
@@ -0,0 +1,13 @@
+===source===
+<?php $a = "caf\xE9"; $b = "\351"; $c = "\xC3\xA9"; $d = "\xE9$x"; $e = <<<EOT
+\xFF
+EOT;
+===print===
+<?php
+$a = "caf\xE9";
+$b = "\xE9";
+$c = 'é';
+$d = "\xE9$x";
+$e = <<<EOT
+\xFF
+EOT;
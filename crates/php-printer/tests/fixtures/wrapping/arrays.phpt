@@ -0,0 +1,33 @@
+===config===
+line_width=40
+===source===
+<?php
+$short = [1, 2, 3];
+$config = ['host' => 'localhost', 'port' => 3306, 'options' => [1, 2, 3]];
+$nested = ['first' => ['alpha', 'beta', 'gamma', 'delta', 'epsilon'], 'x' => 1];
+[$first, $second, $third, $fourth, $fifth] = $values;
+===print===
+<?php
+$short = [1, 2, 3];
+$config = [
+    'host' => 'localhost',
+    'port' => 3306,
+    'options' => [1, 2, 3],
+];
+$nested = [
+    'first' => [
+        'alpha',
+        'beta',
+        'gamma',
+        'delta',
+        'epsilon',
+    ],
+    'x' => 1,
+];
+[
+    $first,
+    $second,
+    $third,
+    $fourth,
+    $fifth,
+] = $values;
@@ -0,0 +1,30 @@
+===config===
+line_width=40
+===source===
+<?php
+foo($a, $b);
+$result = someFunction($firstArgument, $secondArgument, named: $third);
+$x = new Connection($host, $port, $username, $password, ...$options);
+Logger::log($message, context: ['user' => $user, 'request' => $requestId]);
+===print===
+<?php
+foo($a, $b);
+$result = someFunction(
+    $firstArgument,
+    $secondArgument,
+    named: $third,
+);
+$x = new Connection(
+    $host,
+    $port,
+    $username,
+    $password,
+    ...$options,
+);
+Logger::log(
+    $message,
+    context: [
+        'user' => $user,
+        'request' => $requestId,
+    ],
+);
@@ -0,0 +1,14 @@
+===config===
+line_width=40
+===source===
+<?php
+array_map(function ($item) { return $item * 2; }, $items);
+$promise->then(function ($value) { return process($value); })->otherwise(fn($e) => log($e));
+===print===
+<?php
+array_map(function($item) {
+    return $item * 2;
+}, $items);
+$promise->then(function($value) {
+    return process($value);
+})->otherwise(fn($e) => log($e));
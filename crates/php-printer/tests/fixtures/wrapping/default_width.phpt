@@ -0,0 +1,13 @@
+===source===
+<?php
+function f() {
+    return $this->container->get(SomeVeryLongServiceInterfaceName::class)->handle($request, $response, $next, $options, $extra);
+}
+===print===
+<?php
+function f()
+{
+    return $this->container
+        ->get(SomeVeryLongServiceInterfaceName::class)
+        ->handle($request, $response, $next, $options, $extra);
+}
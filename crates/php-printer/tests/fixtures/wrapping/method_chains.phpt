@@ -0,0 +1,23 @@
+===config===
+line_width=50
+===source===
+<?php
+$q->where('a', 1)->get();
+$users = $this->db->table('users')->where('active', true)->orderBy('name')->get();
+$name = $user?->profile?->getDisplayName()?->trim()->value;
+return $builder->select(['id', 'name', 'email', 'created_at'])->from('users')->first();
+===print===
+<?php
+$q->where('a', 1)->get();
+$users = $this->db
+    ->table('users')
+    ->where('active', true)
+    ->orderBy('name')
+    ->get();
+$name = $user?->profile
+    ?->getDisplayName()
+    ?->trim()->value;
+return $builder
+    ->select(['id', 'name', 'email', 'created_at'])
+    ->from('users')
+    ->first();
@@ -0,0 +1,17 @@
+===config===
+line_width=40
+trailing_commas=never
+===source===
+<?php
+$result = someFunction($firstArgument, $secondArgument);
+$config = ['host' => 'localhost', 'port' => 3306];
+===print===
+<?php
+$result = someFunction(
+    $firstArgument,
+    $secondArgument
+);
+$config = [
+    'host' => 'localhost',
+    'port' => 3306
+];
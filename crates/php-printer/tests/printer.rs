@@ -1,4 +1,7 @@
-use php_printer::{pretty_print_with_comments_and_config, PrinterConfig};
+use php_printer::{
+    pretty_print_with_comments_and_config, BraceStyle, CommaList, CommentPolicy, FinalCloseTag,
+    HeredocStyle, Indent, PrintOptions, PrintOptionsError, QuoteStyle, TrailingCommas,
+};
 use rayon::prelude::*;
 use std::sync::Mutex;
 
@@ -8,6 +11,16 @@ use std::sync::Mutex;
 /// ```text
 /// ===config===          (optional)
 /// blank_lines_upper_bound=0
+/// indent=2              (or `tabs`)
+/// brace_style=same_line (psr12, same_line, next_line)
+/// quote_style=double    (single, double)
+/// trailing_commas=never (multi_line, never, preserve)
+/// trailing_commas.params=preserve (args, params, arrays, match_arms,
+///                       closure_uses, group_uses)
+/// line_width=80
+/// heredoc=indented      (flush, indented, string)
+/// comment_policy=own_line (trailing, own_line)
+/// final_close_tag=omit  (keep, omit)
 /// no_source=true
 /// ===source===
 /// <?php ...
@@ -19,11 +32,20 @@ struct PrinterFixture {
     raw_config: String,
     source: String,
     expected: String,
-    config: PrinterConfig,
+    config: PrintOptions,
     /// When true, source is not passed to the printer (tests the no-source fallback).
     no_source: bool,
 }
 
+fn trailing_commas(val: &str) -> TrailingCommas {
+    match val {
+        "multi_line" => TrailingCommas::MultiLine,
+        "never" => TrailingCommas::Never,
+        "preserve" => TrailingCommas::Preserve,
+        _ => panic!("invalid trailing_commas: {val}"),
+    }
+}
+
 fn parse_printer_fixture(content: &str) -> PrinterFixture {
     let (raw_config, rest) = if let Some(after) = content.strip_prefix("===config===\n") {
         let end = after
@@ -51,14 +73,73 @@ fn parse_printer_fixture(content: &str) -> PrinterFixture {
     let expected = &after_source[print_pos + "===print===\n".len()..];
     let expected = expected.strip_suffix('\n').unwrap_or(expected);
 
-    let mut config = PrinterConfig::default();
+    let mut config = PrintOptions::default();
     let mut no_source = false;
     for line in raw_config.lines() {
-        if let Some(val) = line.strip_prefix("blank_lines_upper_bound=") {
-            config.blank_lines_upper_bound = val.parse().expect("invalid blank_lines_upper_bound");
-        } else if line == "no_source=true" {
-            no_source = true;
-        }
+        let Some((key, val)) = line.split_once('=') else {
+            panic!("invalid config line: {line}");
+        };
+        config = match key {
+            "blank_lines_upper_bound" => config.with_blank_lines_upper_bound(
+                val.parse().expect("invalid blank_lines_upper_bound"),
+            ),
+            "indent" => config
+                .with_indent(match val {
+                    "tabs" => Indent::Tabs,
+                    n => Indent::Spaces(n.parse().expect("invalid indent")),
+                })
+                .unwrap(),
+            "brace_style" => config.with_brace_style(match val {
+                "psr12" => BraceStyle::Psr12,
+                "same_line" => BraceStyle::SameLine,
+                "next_line" => BraceStyle::NextLine,
+                _ => panic!("invalid brace_style: {val}"),
+            }),
+            "quote_style" => config.with_quote_style(match val {
+                "single" => QuoteStyle::Single,
+                "double" => QuoteStyle::Double,
+                _ => panic!("invalid quote_style: {val}"),
+            }),
+            "trailing_commas" => config.with_trailing_commas(trailing_commas(val)),
+            "line_width" => config
+                .with_line_width(val.parse().expect("invalid line_width"))
+                .unwrap(),
+            "heredoc" => config.with_heredoc(match val {
+                "flush" => HeredocStyle::Flush,
+                "indented" => HeredocStyle::Indented,
+                "string" => HeredocStyle::String,
+                _ => panic!("invalid heredoc: {val}"),
+            }),
+            "comment_policy" => config.with_comment_policy(match val {
+                "trailing" => CommentPolicy::Trailing,
+                "own_line" => CommentPolicy::OwnLine,
+                _ => panic!("invalid comment_policy: {val}"),
+            }),
+            "final_close_tag" => config.with_final_close_tag(match val {
+                "keep" => FinalCloseTag::Keep,
+                "omit" => FinalCloseTag::Omit,
+                _ => panic!("invalid final_close_tag: {val}"),
+            }),
+            "no_source" => {
+                no_source = val == "true";
+                config
+            }
+            _ => match key.strip_prefix("trailing_commas.") {
+                Some(list) => config.with_trailing_commas_in(
+                    match list {
+                        "args" => CommaList::Args,
+                        "params" => CommaList::Params,
+                        "arrays" => CommaList::Arrays,
+                        "match_arms" => CommaList::MatchArms,
+                        "closure_uses" => CommaList::ClosureUses,
+                        "group_uses" => CommaList::GroupUses,
+                        _ => panic!("invalid trailing_commas list: {list}"),
+                    },
+                    trailing_commas(val),
+                ),
+                None => panic!("unknown config key: {key}"),
+            },
+        };
     }
 
     PrinterFixture {
@@ -171,6 +252,22 @@ fn fixtures() {
                 "round-trip mismatch in {rel}\nfirst:  {first}\nsecond: {second}"
             ));
         }
+
+        // Every comment is printed once: none dropped, none duplicated.
+        if !fixture.no_source {
+            let comments = |comments: &[php_ast::Comment]| -> Vec<String> {
+                comments
+                    .iter()
+                    .map(|c| c.text.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .collect()
+            };
+            let (before, after) = (comments(&result1.comments), comments(&result2.comments));
+            if before != after {
+                failures.lock().unwrap().push(format!(
+                    "comments not preserved in {rel}\nsource:  {before:?}\nprinted: {after:?}"
+                ));
+            }
+        }
     });
 
     let f = failures.into_inner().unwrap();
@@ -181,6 +278,45 @@ fn fixtures() {
     );
 }
 
+#[test]
+fn print_options_validation() {
+    let options = PrintOptions::default();
+    assert_eq!(
+        options.clone().with_indent(Indent::Spaces(0)),
+        Err(PrintOptionsError::IndentWidth(0))
+    );
+    assert_eq!(
+        options.clone().with_indent(Indent::Spaces(17)),
+        Err(PrintOptionsError::IndentWidth(17))
+    );
+    assert!(options.clone().with_indent(Indent::Spaces(16)).is_ok());
+    assert_eq!(
+        options.clone().with_newline("\r"),
+        Err(PrintOptionsError::Newline("\r".to_string()))
+    );
+    assert_eq!(
+        options.clone().with_newline("\r\n").unwrap().newline(),
+        "\r\n"
+    );
+    assert_eq!(
+        options.clone().with_line_width(4),
+        Err(PrintOptionsError::LineWidth(4))
+    );
+    assert_eq!(options.clone().with_line_width(5).unwrap().line_width(), 5);
+
+    // The line width is checked against the new indentation too.
+    let narrow = options.with_line_width(10).unwrap();
+    assert_eq!(
+        narrow.clone().with_indent(Indent::Spaces(12)),
+        Err(PrintOptionsError::LineWidth(10))
+    );
+    assert!(narrow.with_indent(Indent::Tabs).is_ok());
+    assert_eq!(
+        PrintOptionsError::IndentWidth(0).to_string(),
+        "indent width must be between 1 and 16 spaces, got 0"
+    );
+}
+
 // =============================================================================
 // Parser corpus round-trip
 // =============================================================================
@@ -251,6 +387,141 @@ fn php_version(major: u32, minor: u32) -> php_rs_parser::PhpVersion {
     }
 }
 
+/// The output styles the corpus is formatted in: the default, and one
+/// that differs from it in every option that keeps the AST as is.
+fn corpus_styles() -> Vec<(&'static str, PrintOptions)> {
+    let contrasting = PrintOptions::default()
+        .with_indent(Indent::Tabs)
+        .unwrap()
+        .with_line_width(40)
+        .unwrap()
+        .with_blank_lines_upper_bound(0)
+        .with_brace_style(BraceStyle::NextLine)
+        .with_quote_style(QuoteStyle::Double)
+        .with_trailing_commas(TrailingCommas::Never)
+        .with_heredoc(HeredocStyle::Indented)
+        .with_comment_policy(CommentPolicy::OwnLine);
+    vec![
+        ("default", PrintOptions::default()),
+        ("contrasting", contrasting),
+    ]
+}
+
+/// The AST of `program` as JSON without what formatting may change:
+/// source positions, redundant parentheses, braces around a single
+/// statement, empty statements, and the indentation of doc comments.
+fn ast_shape(program: &php_ast::Program) -> serde_json::Value {
+    use serde_json::Value;
+    fn normalize(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                map.retain(|_, v| !is_span(v));
+                // `Parenthesized` keeps the parentheses of the source, which the
+                // printer drops or adds by precedence.
+                while let Some(Value::Object(inner)) = map
+                    .get_mut("kind")
+                    .and_then(|kind| kind.get_mut("Parenthesized"))
+                    .map(std::mem::take)
+                {
+                    *map = inner;
+                    map.retain(|_, v| !is_span(v));
+                }
+                if let Some(Value::String(text)) = map
+                    .get_mut("doc_comment")
+                    .and_then(|doc| doc.get_mut("text"))
+                {
+                    *text = text.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+                }
+                // Raw text keeps the escapes and heredoc indentation the
+                // printer chose; the decoded value is what must survive.
+                for kind in ["String", "Heredoc", "Nowdoc"] {
+                    if let Some(Value::Object(doc)) = map.get_mut(kind) {
+                        doc.remove("raw");
+                    }
+                }
+                map.values_mut().for_each(normalize);
+                // The printer braces the body of every control structure.
+                if let Some(Value::Array(stmts)) =
+                    map.get_mut("kind").and_then(|kind| kind.get_mut("Block"))
+                {
+                    match stmts.len() {
+                        0 => map.insert("kind".into(), Value::String("Nop".into())),
+                        1 => {
+                            let stmt = stmts.pop().unwrap();
+                            map.insert("kind".into(), stmt["kind"].clone())
+                        }
+                        _ => None,
+                    };
+                }
+            }
+            Value::Array(items) => {
+                items.iter_mut().for_each(normalize);
+                items.retain(|item| item.get("kind") != Some(&Value::String("Nop".into())));
+                // An empty `<?php ?>` between two pieces of HTML is dropped,
+                // which joins them.
+                items.dedup_by(|next, prev| {
+                    let html = |item: &Value| {
+                        item["kind"]["InlineHtml"]["text"]
+                            .as_str()
+                            .map(String::from)
+                    };
+                    match (html(prev), html(next)) {
+                        (Some(a), Some(b)) => {
+                            prev["kind"]["InlineHtml"]["text"] = Value::String(a + &b);
+                            true
+                        }
+                        _ => false,
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+    fn is_span(value: &Value) -> bool {
+        value.as_object().is_some_and(|map| {
+            map.len() == 2
+                && map.get("start").is_some_and(|v| v.is_u64())
+                && map.contains_key("end")
+        })
+    }
+    let mut value = serde_json::to_value(program).unwrap();
+    normalize(&mut value);
+    value
+}
+
+/// The path to the first difference between two JSON values.
+fn first_difference(a: &serde_json::Value, b: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Object(x), Value::Object(y)) => {
+            for (key, v) in x {
+                match y.get(key) {
+                    Some(w) => {
+                        if let Some(path) = first_difference(v, w) {
+                            return Some(format!(".{key}{path}"));
+                        }
+                    }
+                    None => return Some(format!(".{key}: missing from output")),
+                }
+            }
+            y.keys()
+                .find(|key| !x.contains_key(*key))
+                .map(|key| format!(".{key}: only in output"))
+        }
+        (Value::Array(x), Value::Array(y)) if x.len() == y.len() => x
+            .iter()
+            .zip(y)
+            .enumerate()
+            .find_map(|(i, (v, w))| first_difference(v, w).map(|path| format!("[{i}]{path}"))),
+        _ if a == b => None,
+        _ => Some(format!(": {a} != {b}")),
+    }
+}
+
+/// Format every error-free parser fixture in each corpus style, with and
+/// without comments, and check that formatting is a fixpoint —
+/// `format(format(x)) == format(x)` — and that the output parses without
+/// errors to the AST of the input.
 #[test]
 fn parser_corpus_round_trip() {
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -262,6 +533,7 @@ fn parser_corpus_round_trip() {
     let mut paths = collect_phpt_files(&parser_fixtures);
     paths.sort();
 
+    let styles = corpus_styles();
     let failures = Mutex::new(Vec::new());
     let checked = Arc::new(AtomicUsize::new(0));
     let skipped = Arc::new(AtomicUsize::new(0));
@@ -277,29 +549,70 @@ fn parser_corpus_round_trip() {
 
         let rel = path.strip_prefix(&parser_fixtures).unwrap();
         let source = extract_parser_fixture_source(&content, &header);
-
-        let first_print = {
-            let arena = bumpalo::Bump::new();
-            let result = match header.min_php {
-                Some((maj, min)) => {
-                    php_rs_parser::parse_versioned(&arena, source, php_version(maj, min))
-                }
-                None => php_rs_parser::parse(&arena, source),
-            };
-            php_printer::pretty_print(&result.program)
+        let version = header
+            .min_php
+            .map_or(php_rs_parser::PhpVersion::Php85, |(maj, min)| {
+                php_version(maj, min)
+            });
+        let fail = |style: &str, comments: bool, message: String| {
+            failures.lock().unwrap().push(format!(
+                "FAIL {} ({style}{})\n{message}",
+                rel.display(),
+                if comments { ", with comments" } else { "" }
+            ));
         };
 
-        let second_print = {
-            let arena = bumpalo::Bump::new();
-            let result = php_rs_parser::parse(&arena, &first_print);
-            php_printer::pretty_print(&result.program)
-        };
+        let arena = bumpalo::Bump::new();
+        let input = php_rs_parser::parse_versioned(&arena, source, version);
+        let input_shape = ast_shape(&input.program);
+
+        for (style, options) in &styles {
+            for comments in [false, true] {
+                let format = |result: &php_rs_parser::ParseResult| {
+                    if comments {
+                        pretty_print_with_comments_and_config(
+                            &result.program,
+                            result.source,
+                            &result.comments,
+                            options,
+                        )
+                    } else {
+                        php_printer::pretty_print_with_config(&result.program, options)
+                    }
+                };
+                let first_print = format(&input);
+
+                let arena = bumpalo::Bump::new();
+                let reparsed = php_rs_parser::parse_versioned(&arena, &first_print, version);
+                if !reparsed.errors.is_empty() {
+                    fail(
+                        style,
+                        comments,
+                        format!("  errors: {:?}\n  output: {first_print}", reparsed.errors),
+                    );
+                    continue;
+                }
+                let output_shape = ast_shape(&reparsed.program);
+                if let Some(path) = first_difference(&input_shape, &output_shape) {
+                    fail(
+                        style,
+                        comments,
+                        format!(
+                            "  AST changed at {path}\n  source: {source}\n  output: {first_print}"
+                        ),
+                    );
+                    continue;
+                }
 
-        if first_print != second_print {
-            failures.lock().unwrap().push(format!(
-                "FAIL {}\n  first:  {first_print}\n  second: {second_print}",
-                rel.display()
-            ));
+                let second_print = format(&reparsed);
+                if first_print != second_print {
+                    fail(
+                        style,
+                        comments,
+                        format!("  first:  {first_print}\n  second: {second_print}"),
+                    );
+                }
+            }
         }
         checked.fetch_add(1, Ordering::Relaxed);
     });
@@ -327,3 +640,163 @@ fn pretty_print_file() {
     let output = php_printer::pretty_print_file(&result.program);
     assert_eq!(output, "<?php\necho 'hello';\n");
 }
+
+#[test]
+fn halt_compiler_data_without_source() {
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, "<?php namespace A; __halt_compiler();raw\0data");
+    let output = php_printer::pretty_print_file(&result.program);
+    assert_eq!(output, "<?php\nnamespace A;\n__halt_compiler();raw\0data");
+    let output = php_printer::pretty_print(&result.program);
+    assert_eq!(output, "<?php\nnamespace A;\n__halt_compiler();raw\0data");
+}
+
+// =============================================================================
+// Range formatting
+// =============================================================================
+
+#[test]
+fn format_range() {
+    // (source, selected text, expected output)
+    let cases = [
+        // A cursor formats the statement it is in, at its nesting depth.
+        (
+            "<?php\nif ($a) {\n  foo( 1 );\n  bar( 2 );\n}\n",
+            "bar",
+            "<?php\nif ($a) {\n  foo( 1 );\n    bar(2);\n}\n",
+        ),
+        // Every overlapped statement of the list is formatted.
+        (
+            "<?php\n$a=1;\n\n\n$b=2;\n$c=3;\n",
+            "1;\n\n\n$b",
+            "<?php\n$a = 1;\n\n$b = 2;\n$c=3;\n",
+        ),
+        // A selection reaching outside a body formats the enclosing statement.
+        (
+            "<?php\nfunction f($x) { return $x+1; }\n$y=2;\n",
+            "f($x) { return",
+            "<?php\nfunction f($x)\n{\n    return $x + 1;\n}\n$y=2;\n",
+        ),
+        // Method bodies are two levels deep; comments in the range are kept.
+        (
+            "<?php\nclass A {\n    function f() {\n    $a=1; // one\n    }\n}\n",
+            "$a",
+            "<?php\nclass A {\n    function f() {\n        $a = 1; // one\n    }\n}\n",
+        ),
+        // A doc comment goes with its declaration.
+        (
+            "<?php\n/**\n   * Doc\n   */\nfunction  f() {}\n",
+            "f()",
+            "<?php\n/**\n * Doc\n */\nfunction f()\n{}\n",
+        ),
+        // A statement that does not start its line keeps what precedes it.
+        ("<?php $a=1;  $b=2;\n", "$b", "<?php $a=1;  $b = 2;\n"),
+        // Nothing is formatted between statements.
+        ("<?php\n$a=1;\n\n$b=2;\n", "\n\n", "<?php\n$a=1;\n\n$b=2;\n"),
+    ];
+    for (source, selected, expected) in cases {
+        let start = source.find(selected).unwrap() as u32;
+        let range = php_ast::Span::new(start, start + selected.len() as u32);
+        let arena = bumpalo::Bump::new();
+        let result = php_rs_parser::parse(&arena, source);
+        let output = php_printer::format_range(
+            &result.program,
+            result.source,
+            &result.comments,
+            range,
+            &PrintOptions::default(),
+        );
+        assert_eq!(output, expected, "selecting {selected:?} in {source:?}");
+    }
+}
+
+// =============================================================================
+// Signatures
+// =============================================================================
+
+#[test]
+fn print_signature() {
+    use php_ast::node::AnyNode;
+    use php_ast::StmtKind;
+
+    let src = r#"<?php
+/** Doc. */
+function &f(int|string $a, Foo &...$rest): never { exit; }
+const A = 1, B = 2;
+const C = [1, 2];
+#[Entity]
+abstract class Base extends Model implements Countable, \JsonSerializable {
+    final public const int MAX = 10;
+    protected static ?array $cache = null;
+    public private(set) string $name { get => $this->name; }
+    abstract protected function count(): int;
+    use Helpers;
+}
+interface Shape extends Countable {}
+trait Helpers {}
+enum Suit: string implements HasLabel {
+    case Hearts = 'H';
+    const Wild = self::Hearts;
+    public static function fromChar(string $c): self { return self::from($c); }
+}
+echo 1;
+"#;
+    let arena = bumpalo::Bump::new();
+    let result = php_rs_parser::parse(&arena, src);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    let mut signatures = Vec::new();
+    for stmt in result.program.stmts.iter() {
+        signatures.push(php_printer::print_signature(AnyNode::from(stmt)));
+        match &stmt.kind {
+            StmtKind::Function(func) => {
+                for param in func.params.iter() {
+                    signatures.push(php_printer::print_signature(AnyNode::from(param)));
+                }
+            }
+            StmtKind::Const(items) => {
+                for item in items.iter() {
+                    signatures.push(php_printer::print_signature(AnyNode::from(item)));
+                }
+            }
+            StmtKind::Class(class) => {
+                for member in class.members.iter() {
+                    signatures.push(php_printer::print_signature(AnyNode::from(member)));
+                }
+            }
+            StmtKind::Enum(enum_decl) => {
+                for member in enum_decl.members.iter() {
+                    signatures.push(php_printer::print_signature(AnyNode::from(member)));
+                }
+            }
+            _ => {}
+        }
+    }
+    let signatures: Vec<_> = signatures.iter().map(|s| s.as_deref()).collect();
+    assert_eq!(
+        signatures,
+        [
+            Some("function &f(int|string $a, Foo &...$rest): never"),
+            Some("int|string $a"),
+            Some("Foo &...$rest"),
+            // Several constants in one statement have no single signature.
+            None,
+            Some("const A = 1"),
+            Some("const B = 2"),
+            Some("const C = [1, 2]"),
+            Some("const C = [1, 2]"),
+            Some("abstract class Base extends Model implements Countable, \\JsonSerializable"),
+            Some("final public const int MAX = 10"),
+            Some("protected static ?array $cache = null"),
+            Some("public private(set) string $name"),
+            Some("abstract protected function count(): int"),
+            None,
+            Some("interface Shape extends Countable"),
+            Some("trait Helpers"),
+            Some("enum Suit: string implements HasLabel"),
+            Some("case Hearts = 'H'"),
+            Some("const Wild = self::Hearts"),
+            Some("public static function fromChar(string $c): self"),
+            None,
+        ]
+    );
+}
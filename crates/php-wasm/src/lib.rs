@@ -46,11 +46,11 @@ pub fn parse(source: &str, version: Option<String>) -> Result<JsValue, JsError>
         .errors
         .iter()
         .map(|e| {
-            let span = e.span();
+            let diagnostic = e.to_diagnostic();
             WasmError {
-                message: e.to_string(),
-                start: span.start,
-                end: span.end,
+                message: diagnostic.message,
+                start: diagnostic.span.start,
+                end: diagnostic.span.end,
             }
         })
         .collect();
@@ -1,4 +1,5 @@
 use crate::Span;
+#[cfg(feature = "serde")]
 use serde::Serialize;
 
 // =============================================================================
@@ -6,7 +7,8 @@ use serde::Serialize;
 // =============================================================================
 
 /// An inline `{@tagname body}` tag embedded in text.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct InlineTag {
     pub name: String,
     pub body: Option<String>,
@@ -14,21 +16,24 @@ pub struct InlineTag {
 }
 
 /// A segment of prose text — either plain text or an inline tag.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum TextSegment {
     Text(String),
     InlineTag(InlineTag),
 }
 
 /// A prose run (summary, description, or tag body) that may contain inline tags.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PhpDocText {
     pub segments: Vec<TextSegment>,
     pub span: Span,
 }
 
 /// A block-level `@tag` — generic, no semantic interpretation.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PhpDocTag {
     /// Raw tag name, e.g. `"param"`, `"psalm-type"`, `"return"`.
     pub name: String,
@@ -40,7 +45,8 @@ pub struct PhpDocTag {
 // Top-level document
 // =============================================================================
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PhpDoc {
     pub summary: Option<PhpDocText>,
     pub description: Option<PhpDocText>,
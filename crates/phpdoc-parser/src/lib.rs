@@ -4,9 +4,15 @@
 //! spans and support for inline tags. Designed for type checkers, linters, IDEs,
 //! and documentation generators.
 //!
-//! The crate is **agnostic** — it does not interpret tag semantics or parse type
-//! expressions. Tag bodies are exposed as raw [`PhpDocText`], letting tools apply
-//! their own type parsers and validation rules.
+//! The core [`parse`] is **agnostic** — it does not interpret tag semantics or
+//! parse type expressions. Tag bodies are exposed as raw [`PhpDocText`], letting
+//! tools apply their own type parsers and validation rules.
+//!
+//! On top of it, [`types`] parses PHPDoc type expressions such as
+//! `array<int, Foo>` and `array{id: int, name?: string}`, and [`tags`] reads
+//! `@param`, `@return`, `@var`, `@throws`, `@template`, and `@property` into
+//! structured tags with parsed types, with spans relative to the file when given
+//! the comment's offset.
 //!
 //! # Quick start
 //!
@@ -51,6 +57,8 @@
 pub(crate) mod ast;
 pub(crate) mod parser;
 pub(crate) mod span;
+pub mod tags;
+pub mod types;
 
 pub use ast::{InlineTag, PhpDoc, PhpDocTag, PhpDocText, TextSegment};
 pub use parser::parse;
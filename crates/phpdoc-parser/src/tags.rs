@@ -0,0 +1,321 @@
+//! Tags with a known structure, with their types parsed.
+//!
+//! [`parse_tags`] reads `@param`, `@return`, `@var`, `@throws`,
+//! `@template` (with its covariant and contravariant forms), and
+//! `@property` (with `-read` and `-write`), including their `psalm-`,
+//! `phpstan-`, and `phan-` prefixed spellings, into [`DocTag`]s whose types
+//! are [`DocType`]s. Other tags are left to the generic [`PhpDocTag`].
+//!
+//! [`parse_tags_at`] takes the offset of the comment in its file and
+//! reports spans relative to the file, as the spans of the rest of the AST
+//! are; pass the start of a doc comment's span.
+//!
+//! ```
+//! use phpdoc_parser::tags::{parse_tags_at, DocTagKind};
+//!
+//! let comment = "/**\n * @param array<int, Foo> $items The items\n * @return ?Foo\n */";
+//! let tags = parse_tags_at(comment, 100);
+//!
+//! let DocTagKind::Param { ty: Some(ty), name, description, .. } = &tags[0].kind else {
+//!     panic!()
+//! };
+//! assert_eq!(ty.to_string(), "array<int, Foo>");
+//! assert_eq!((ty.span.start, ty.span.end), (114, 129));
+//! assert_eq!(name, "items");
+//! assert_eq!(description.as_deref(), Some("The items"));
+//! assert!(matches!(&tags[1].kind, DocTagKind::Return { ty, .. } if ty.to_string() == "?Foo"));
+//! ```
+
+use crate::types::{DocType, TypeError, TypeParser};
+use crate::{PhpDocTag, Span};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// A tag with a known structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DocTag {
+    /// The tag name as written, without `@`: `param`, `psalm-param`, …
+    pub name: String,
+    pub kind: DocTagKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
+pub enum DocTagKind {
+    /// `@param [type] [&][...]$name [description]`.
+    Param {
+        ty: Option<DocType>,
+        /// The parameter name, without `$`.
+        name: String,
+        by_ref: bool,
+        variadic: bool,
+        description: Option<String>,
+    },
+    /// `@return type [description]`.
+    Return {
+        ty: DocType,
+        description: Option<String>,
+    },
+    /// `@var type [$name] [description]`.
+    Var {
+        ty: DocType,
+        name: Option<String>,
+        description: Option<String>,
+    },
+    /// `@throws type [description]`.
+    Throws {
+        ty: DocType,
+        description: Option<String>,
+    },
+    /// `@template Name [of|as bound] [= default] [description]`.
+    Template {
+        name: String,
+        bound: Option<DocType>,
+        default: Option<DocType>,
+        variance: Variance,
+        description: Option<String>,
+    },
+    /// `@property type $name [description]`.
+    Property {
+        ty: DocType,
+        name: String,
+        access: PropertyAccess,
+        description: Option<String>,
+    },
+    /// A known tag whose body does not have the expected structure.
+    Invalid(TypeError),
+}
+
+/// How a template parameter varies, from `@template-covariant` and
+/// `@template-contravariant`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Variance {
+    Invariant,
+    Covariant,
+    Contravariant,
+}
+
+/// Which of `@property`, `@property-read`, or `@property-write` declared a
+/// property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum PropertyAccess {
+    ReadWrite,
+    Read,
+    Write,
+}
+
+/// Parse the doc comment `text` and read its tags with a known structure.
+/// Spans are offsets in `text`.
+pub fn parse_tags(text: &str) -> Vec<DocTag> {
+    crate::parse(text)
+        .tags
+        .iter()
+        .filter_map(|tag| DocTag::from_tag(text, tag))
+        .collect()
+}
+
+/// [`parse_tags`] for a comment that starts at byte `offset` of its file;
+/// spans are offsets in the file.
+pub fn parse_tags_at(text: &str, offset: u32) -> Vec<DocTag> {
+    let mut tags = parse_tags(text);
+    for tag in &mut tags {
+        tag.shift(offset);
+    }
+    tags
+}
+
+impl DocTag {
+    /// Read `tag`, parsed from the doc comment `text`, or `None` when it is
+    /// not one of the tags this module knows.
+    pub fn from_tag(text: &str, tag: &PhpDocTag) -> Option<DocTag> {
+        let base = ["psalm-", "phpstan-", "phan-"]
+            .iter()
+            .find_map(|prefix| tag.name.strip_prefix(prefix))
+            .unwrap_or(&tag.name);
+        let body = match &tag.body {
+            Some(body) => body.span,
+            None => Span::new(tag.span.end, tag.span.end),
+        };
+        let text = text.get(..body.end as usize).unwrap_or(text);
+        let mut reader = BodyReader {
+            text,
+            parser: TypeParser::new(text, body.start as usize),
+        };
+        let kind = match base {
+            "param" => reader.param(),
+            "return" => reader.ty().map(|ty| DocTagKind::Return {
+                ty,
+                description: reader.description(),
+            }),
+            "var" => reader.ty().map(|ty| DocTagKind::Var {
+                ty,
+                name: reader.variable(),
+                description: reader.description(),
+            }),
+            "throws" => reader.ty().map(|ty| DocTagKind::Throws {
+                ty,
+                description: reader.description(),
+            }),
+            "template" => reader.template(Variance::Invariant),
+            "template-covariant" => reader.template(Variance::Covariant),
+            "template-contravariant" => reader.template(Variance::Contravariant),
+            "property" => reader.property(PropertyAccess::ReadWrite),
+            "property-read" => reader.property(PropertyAccess::Read),
+            "property-write" => reader.property(PropertyAccess::Write),
+            _ => return None,
+        };
+        Some(DocTag {
+            name: tag.name.clone(),
+            kind: kind.unwrap_or_else(DocTagKind::Invalid),
+            span: tag.span,
+        })
+    }
+
+    fn shift(&mut self, by: u32) {
+        self.span = Span::new(self.span.start + by, self.span.end + by);
+        match &mut self.kind {
+            DocTagKind::Param { ty, .. } => {
+                if let Some(ty) = ty {
+                    ty.shift(by);
+                }
+            }
+            DocTagKind::Return { ty, .. }
+            | DocTagKind::Var { ty, .. }
+            | DocTagKind::Throws { ty, .. }
+            | DocTagKind::Property { ty, .. } => ty.shift(by),
+            DocTagKind::Template { bound, default, .. } => {
+                for ty in [bound, default].into_iter().flatten() {
+                    ty.shift(by);
+                }
+            }
+            DocTagKind::Invalid(error) => {
+                error.span = Span::new(error.span.start + by, error.span.end + by);
+            }
+        }
+    }
+}
+
+/// Reads the parts of a tag body in order.
+struct BodyReader<'a> {
+    /// The comment, cut off at the end of the body.
+    text: &'a str,
+    parser: TypeParser<'a>,
+}
+
+impl BodyReader<'_> {
+    fn ty(&mut self) -> Result<DocType, TypeError> {
+        self.parser.parse_type()
+    }
+
+    /// A `$name` after whitespace, without `$`.
+    fn variable(&mut self) -> Option<String> {
+        let save = self.parser.pos;
+        self.parser.skip_ws();
+        if self.parser.eat("$") {
+            return Some(self.parser.word().to_string());
+        }
+        self.parser.pos = save;
+        None
+    }
+
+    /// The rest of the body, with the `*` that starts each line removed and
+    /// lines joined by spaces.
+    fn description(&mut self) -> Option<String> {
+        let rest = self.text.get(self.parser.pos..).unwrap_or_default();
+        self.parser.pos = self.text.len();
+        let words: Vec<&str> = rest
+            .lines()
+            .map(|line| line.trim().trim_start_matches('*').trim())
+            .filter(|line| !line.is_empty())
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    fn param(&mut self) -> Result<DocTagKind, TypeError> {
+        let untyped = matches!(self.peek(), Some(b'$' | b'&')) || self.starts_with("...");
+        let ty = if untyped { None } else { Some(self.ty()?) };
+        self.parser.skip_ws();
+        let by_ref = self.parser.eat("&");
+        let variadic = self.parser.eat("...");
+        let Some(name) = self.variable().filter(|name| !name.is_empty()) else {
+            return Err(self.parser.error("expected a parameter name"));
+        };
+        Ok(DocTagKind::Param {
+            ty,
+            name,
+            by_ref,
+            variadic,
+            description: self.description(),
+        })
+    }
+
+    fn template(&mut self, variance: Variance) -> Result<DocTagKind, TypeError> {
+        let name = self.parser.word().to_string();
+        if name.is_empty() {
+            return Err(self.parser.error("expected a template name"));
+        }
+        let save = self.parser.pos;
+        self.parser.skip_ws();
+        let bound = if self.eat_keyword("of") || self.eat_keyword("as") {
+            self.parser.skip_ws();
+            Some(self.ty()?)
+        } else {
+            self.parser.pos = save;
+            None
+        };
+        let save = self.parser.pos;
+        self.parser.skip_ws();
+        let default = if self.parser.eat("=") {
+            self.parser.skip_ws();
+            Some(self.ty()?)
+        } else {
+            self.parser.pos = save;
+            None
+        };
+        Ok(DocTagKind::Template {
+            name,
+            bound,
+            default,
+            variance,
+            description: self.description(),
+        })
+    }
+
+    fn property(&mut self, access: PropertyAccess) -> Result<DocTagKind, TypeError> {
+        let ty = self.ty()?;
+        let Some(name) = self.variable().filter(|name| !name.is_empty()) else {
+            return Err(self.parser.error("expected a property name"));
+        };
+        Ok(DocTagKind::Property {
+            ty,
+            name,
+            access,
+            description: self.description(),
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.parser.pos).copied()
+    }
+
+    fn starts_with(&self, token: &str) -> bool {
+        self.text
+            .get(self.parser.pos..)
+            .is_some_and(|rest| rest.starts_with(token))
+    }
+
+    /// `keyword` followed by whitespace.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let followed = self
+            .text
+            .as_bytes()
+            .get(self.parser.pos + keyword.len())
+            .is_some_and(u8::is_ascii_whitespace);
+        followed && self.parser.eat(keyword)
+    }
+}
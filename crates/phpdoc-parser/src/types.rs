@@ -0,0 +1,762 @@
+//! PHPDoc type expressions.
+//!
+//! [`parse_type`] reads the types PHPStan and Psalm accept in tags: names and
+//! keywords (`int`, `Foo\Bar`, `non-empty-string`), generics
+//! (`array<int, Foo>`), nullable types, unions and intersections, `T[]`,
+//! array and object shapes (`array{id: int, name?: string}`), callable
+//! signatures (`callable(int, string...): bool`), literals, class constants
+//! (`Foo::STATUS_*`), and conditional return types.
+//!
+//! A type may continue over several lines of a doc comment; the leading `*`
+//! of each line is skipped like whitespace.
+
+use std::fmt;
+
+use crate::Span;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// A parsed type expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DocType {
+    pub kind: DocTypeKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
+pub enum DocTypeKind {
+    /// A class, keyword, or pseudo-type name as written: `int`, `\Foo\Bar`,
+    /// `non-empty-string`.
+    Name(String),
+    /// `Name<Arg, ...>`.
+    Generic { name: String, args: Vec<DocType> },
+    /// `?T`.
+    Nullable(Box<DocType>),
+    /// `A|B`.
+    Union(Vec<DocType>),
+    /// `A&B`.
+    Intersection(Vec<DocType>),
+    /// `T[]`.
+    Array(Box<DocType>),
+    /// `array{...}`, `list{...}`, or `object{...}`. `sealed` is false when
+    /// the shape ends with `...`.
+    Shape {
+        name: String,
+        items: Vec<ShapeItem>,
+        sealed: bool,
+    },
+    /// `callable(...)` or `Closure(...)`, with an optional return type.
+    Callable {
+        name: String,
+        params: Vec<CallableParam>,
+        return_type: Option<Box<DocType>>,
+    },
+    /// An integer, float, or quoted string literal, as written.
+    Literal(String),
+    /// `Foo::BAR`, or a wildcard such as `Foo::STATUS_*`.
+    ClassConst { class: String, name: String },
+    /// `$this`, or the parameter a conditional type tests, without `$`.
+    Variable(String),
+    /// `(subject is target ? then : otherwise)`; `is not` when `negated`.
+    Conditional {
+        subject: Box<DocType>,
+        negated: bool,
+        target: Box<DocType>,
+        then: Box<DocType>,
+        otherwise: Box<DocType>,
+    },
+}
+
+/// An entry of an array or object shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ShapeItem {
+    /// The key without quotes; `None` for a positional entry.
+    pub key: Option<String>,
+    /// Whether the key is marked optional with `?`.
+    pub optional: bool,
+    pub value: DocType,
+}
+
+/// A parameter of a callable type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CallableParam {
+    pub ty: DocType,
+    pub by_ref: bool,
+    pub variadic: bool,
+    /// The parameter name without `$`, when given.
+    pub name: Option<String>,
+    /// Whether the parameter is marked optional with `=`.
+    pub optional: bool,
+}
+
+/// A type expression that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TypeError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TypeError {}
+
+/// Parse `text` as a single type expression. Spans are offsets in `text`.
+///
+/// # Example
+/// ```
+/// use phpdoc_parser::types::{parse_type, DocTypeKind};
+///
+/// let ty = parse_type("array<int, Foo|null>").unwrap();
+/// let DocTypeKind::Generic { name, args } = &ty.kind else { panic!() };
+/// assert_eq!(name, "array");
+/// assert_eq!(args[1].to_string(), "Foo|null");
+/// assert!(parse_type("array<int").is_err());
+/// ```
+pub fn parse_type(text: &str) -> Result<DocType, TypeError> {
+    let mut parser = TypeParser::new(text, 0);
+    parser.skip_ws();
+    let ty = parser.parse_type()?;
+    parser.skip_ws();
+    if parser.pos < text.len() {
+        return Err(parser.error("unexpected text after the type"));
+    }
+    Ok(ty)
+}
+
+/// Parse the type that begins at byte `start` of `text`, as in a tag body
+/// followed by a variable name or description. Returns the type and the
+/// offset just past it; spans are offsets in `text`.
+///
+/// # Example
+/// ```
+/// use phpdoc_parser::types::parse_type_at;
+///
+/// let text = "/** @param list<int> $ids */";
+/// let (ty, end) = parse_type_at(text, 11).unwrap();
+/// assert_eq!(ty.to_string(), "list<int>");
+/// assert_eq!(&text[end..], " $ids */");
+/// ```
+pub fn parse_type_at(text: &str, start: usize) -> Result<(DocType, usize), TypeError> {
+    let mut parser = TypeParser::new(text, start);
+    let ty = parser.parse_type()?;
+    Ok((ty, parser.pos))
+}
+
+/// Names that take a shape in braces.
+const SHAPE_NAMES: &[&str] = &[
+    "array",
+    "list",
+    "object",
+    "non-empty-array",
+    "non-empty-list",
+];
+
+/// Names that take a signature in parentheses.
+const CALLABLE_NAMES: &[&str] = &[
+    "callable",
+    "closure",
+    "\\closure",
+    "pure-callable",
+    "pure-closure",
+];
+
+/// A recursive-descent parser over the text of a doc comment.
+pub(crate) struct TypeParser<'a> {
+    text: &'a str,
+    pub(crate) pos: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    pub(crate) fn new(text: &'a str, pos: usize) -> Self {
+        Self { text, pos }
+    }
+
+    fn bytes(&self) -> &'a [u8] {
+        self.text.as_bytes()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes().get(self.pos).copied()
+    }
+
+    fn rest(&self) -> &'a str {
+        self.text.get(self.pos..).unwrap_or_default()
+    }
+
+    pub(crate) fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn error(&self, message: &str) -> TypeError {
+        let end = (self.pos + 1).min(self.text.len());
+        TypeError {
+            message: message.to_string(),
+            span: Span::new(self.pos as u32, end as u32),
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), TypeError> {
+        self.skip_ws();
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{token}`")))
+        }
+    }
+
+    /// Skip whitespace, line breaks, and the `*` that starts each line of a
+    /// doc comment.
+    pub(crate) fn skip_ws(&mut self) {
+        while let Some(b) = self.peek() {
+            match b {
+                b' ' | b'\t' | b'\r' => self.pos += 1,
+                b'\n' => {
+                    self.pos += 1;
+                    while matches!(self.peek(), Some(b' ' | b'\t')) {
+                        self.pos += 1;
+                    }
+                    if self.peek() == Some(b'*') && !self.rest().starts_with("*/") {
+                        self.pos += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Consume `op` after optional whitespace, or leave the position as it
+    /// was. Whitespace ends a type in a tag body unless an operator follows.
+    fn eat_after_ws(&mut self, op: &str) -> bool {
+        let save = self.pos;
+        self.skip_ws();
+        if self.eat(op) {
+            true
+        } else {
+            self.pos = save;
+            false
+        }
+    }
+
+    fn span_from(&self, start: usize) -> Span {
+        Span::new(start as u32, self.pos as u32)
+    }
+
+    pub(crate) fn parse_type(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        let first = self.parse_intersection()?;
+        let mut types = vec![first];
+        while self.eat_after_ws("|") {
+            self.skip_ws();
+            types.push(self.parse_intersection()?);
+        }
+        Ok(self.combine(start, types, DocTypeKind::Union))
+    }
+
+    fn parse_intersection(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        let first = self.parse_prefix()?;
+        let mut types = vec![first];
+        loop {
+            let save = self.pos;
+            if !self.eat_after_ws("&") {
+                break;
+            }
+            self.skip_ws();
+            // `Foo &$x` and `Foo &...$x` pass a parameter by reference.
+            if self.peek() == Some(b'$') || self.rest().starts_with("...") {
+                self.pos = save;
+                break;
+            }
+            types.push(self.parse_prefix()?);
+        }
+        Ok(self.combine(start, types, DocTypeKind::Intersection))
+    }
+
+    fn combine(
+        &self,
+        start: usize,
+        mut types: Vec<DocType>,
+        kind: fn(Vec<DocType>) -> DocTypeKind,
+    ) -> DocType {
+        if types.len() == 1 {
+            return types.remove(0);
+        }
+        DocType {
+            kind: kind(types),
+            span: self.span_from(start),
+        }
+    }
+
+    fn parse_prefix(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        if self.eat("?") {
+            let inner = self.parse_postfix()?;
+            return Ok(DocType {
+                kind: DocTypeKind::Nullable(Box::new(inner)),
+                span: self.span_from(start),
+            });
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        let mut ty = self.parse_atom()?;
+        while self.eat("[]") {
+            ty = DocType {
+                kind: DocTypeKind::Array(Box::new(ty)),
+                span: self.span_from(start),
+            };
+        }
+        Ok(ty)
+    }
+
+    fn parse_atom(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some(b'(') => return self.parse_parenthesized(),
+            Some(b'$') => {
+                self.pos += 1;
+                let name = self.word();
+                if name.is_empty() {
+                    return Err(self.error("expected a variable name"));
+                }
+                DocTypeKind::Variable(name.to_string())
+            }
+            Some(b'\'' | b'"') => DocTypeKind::Literal(self.string_literal()?.to_string()),
+            Some(b) if b.is_ascii_digit() || b == b'-' || b == b'.' => {
+                DocTypeKind::Literal(self.number()?.to_string())
+            }
+            Some(b) if is_name_start(b) => {
+                let name = self.name();
+                if self.rest().starts_with("::") {
+                    self.pos += 2;
+                    let constant = self.constant_name();
+                    if constant.is_empty() {
+                        return Err(self.error("expected a constant name"));
+                    }
+                    DocTypeKind::ClassConst {
+                        class: name.to_string(),
+                        name: constant.to_string(),
+                    }
+                } else if self.peek() == Some(b'<') {
+                    self.pos += 1;
+                    let args = self.parse_list(">")?;
+                    DocTypeKind::Generic {
+                        name: name.to_string(),
+                        args,
+                    }
+                } else if self.peek() == Some(b'{') && is_one_of(name, SHAPE_NAMES) {
+                    self.pos += 1;
+                    let (items, sealed) = self.parse_shape()?;
+                    DocTypeKind::Shape {
+                        name: name.to_string(),
+                        items,
+                        sealed,
+                    }
+                } else if self.peek() == Some(b'(') && is_one_of(name, CALLABLE_NAMES) {
+                    self.pos += 1;
+                    let params = self.parse_callable_params()?;
+                    let return_type = if self.eat_after_ws(":") {
+                        self.skip_ws();
+                        Some(Box::new(self.parse_prefix()?))
+                    } else {
+                        None
+                    };
+                    DocTypeKind::Callable {
+                        name: name.to_string(),
+                        params,
+                        return_type,
+                    }
+                } else {
+                    DocTypeKind::Name(name.to_string())
+                }
+            }
+            _ => return Err(self.error("expected a type")),
+        };
+        Ok(DocType {
+            kind,
+            span: self.span_from(start),
+        })
+    }
+
+    /// `(T)` or a conditional type, `(T is U ? A : B)`.
+    fn parse_parenthesized(&mut self) -> Result<DocType, TypeError> {
+        let start = self.pos;
+        self.pos += 1;
+        self.skip_ws();
+        let inner = self.parse_type()?;
+        self.skip_ws();
+        if !self.eat_word("is") {
+            self.expect(")")?;
+            return Ok(inner);
+        }
+        self.skip_ws();
+        let negated = self.eat_word("not");
+        self.skip_ws();
+        let target = self.parse_type()?;
+        self.expect("?")?;
+        self.skip_ws();
+        let then = self.parse_type()?;
+        self.expect(":")?;
+        self.skip_ws();
+        let otherwise = self.parse_type()?;
+        self.expect(")")?;
+        Ok(DocType {
+            kind: DocTypeKind::Conditional {
+                subject: Box::new(inner),
+                negated,
+                target: Box::new(target),
+                then: Box::new(then),
+                otherwise: Box::new(otherwise),
+            },
+            span: self.span_from(start),
+        })
+    }
+
+    /// Comma-separated types up to `close`; a trailing comma is allowed.
+    fn parse_list(&mut self, close: &str) -> Result<Vec<DocType>, TypeError> {
+        let mut types = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat(close) {
+                return Ok(types);
+            }
+            types.push(self.parse_type()?);
+            self.skip_ws();
+            if !self.eat(",") {
+                self.expect(close)?;
+                return Ok(types);
+            }
+        }
+    }
+
+    fn parse_shape(&mut self) -> Result<(Vec<ShapeItem>, bool), TypeError> {
+        let mut items = Vec::new();
+        let mut sealed = true;
+        loop {
+            self.skip_ws();
+            if self.eat("}") {
+                return Ok((items, sealed));
+            }
+            if self.eat("...") {
+                sealed = false;
+                // `...<K, V>` describes the unlisted entries.
+                if self.eat("<") {
+                    self.parse_list(">")?;
+                }
+            } else {
+                let (key, optional) = self.shape_key().unzip();
+                self.skip_ws();
+                items.push(ShapeItem {
+                    key,
+                    optional: optional.unwrap_or(false),
+                    value: self.parse_type()?,
+                });
+            }
+            self.skip_ws();
+            if !self.eat(",") {
+                self.expect("}")?;
+                return Ok((items, sealed));
+            }
+        }
+    }
+
+    /// A shape key with its optional marker, followed by `:`, or nothing.
+    fn shape_key(&mut self) -> Option<(String, bool)> {
+        let save = self.pos;
+        let key = match self.peek() {
+            Some(b'\'' | b'"') => self
+                .string_literal()
+                .ok()
+                .map(|s| s[1..s.len() - 1].to_string()),
+            Some(b) if b.is_ascii_digit() || b == b'-' => self.number().ok().map(str::to_string),
+            Some(b) if is_name_start(b) => Some(self.name().to_string()),
+            _ => None,
+        };
+        let optional = self.eat("?");
+        self.skip_ws();
+        if key.is_some() && self.peek() == Some(b':') && !self.rest().starts_with("::") {
+            self.pos += 1;
+            return key.map(|key| (key, optional));
+        }
+        self.pos = save;
+        None
+    }
+
+    fn parse_callable_params(&mut self) -> Result<Vec<CallableParam>, TypeError> {
+        let mut params = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat(")") {
+                return Ok(params);
+            }
+            let ty = self.parse_type()?;
+            self.skip_ws();
+            let by_ref = self.eat("&");
+            self.skip_ws();
+            let variadic = self.eat("...");
+            self.skip_ws();
+            let name = if self.eat("$") {
+                Some(self.word().to_string())
+            } else {
+                None
+            };
+            self.skip_ws();
+            let optional = self.eat("=");
+            params.push(CallableParam {
+                ty,
+                by_ref,
+                variadic,
+                name,
+                optional,
+            });
+            self.skip_ws();
+            if !self.eat(",") {
+                self.expect(")")?;
+                return Ok(params);
+            }
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.pos += 1;
+        }
+        &self.text[start..self.pos]
+    }
+
+    /// An identifier, such as a variable name.
+    pub(crate) fn word(&mut self) -> &'a str {
+        self.take_while(is_word)
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let save = self.pos;
+        if self.word() == word {
+            return true;
+        }
+        self.pos = save;
+        false
+    }
+
+    /// A possibly qualified name; `-` joins words, as in `class-string`.
+    fn name(&mut self) -> &'a str {
+        let start = self.pos;
+        loop {
+            self.take_while(|b| is_word(b) || b == b'\\');
+            let joined = self.peek() == Some(b'-')
+                && self.bytes().get(self.pos + 1).copied().is_some_and(is_word);
+            if !joined {
+                return &self.text[start..self.pos];
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn constant_name(&mut self) -> &'a str {
+        self.take_while(|b| is_word(b) || b == b'*')
+    }
+
+    fn number(&mut self) -> Result<&'a str, TypeError> {
+        let start = self.pos;
+        self.eat("-");
+        let digits = self.take_while(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'.');
+        if !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            self.pos = start;
+            return Err(self.error("expected a type"));
+        }
+        Ok(&self.text[start..self.pos])
+    }
+
+    fn string_literal(&mut self) -> Result<&'a str, TypeError> {
+        let start = self.pos;
+        let quote = self.bytes()[start];
+        self.pos += 1;
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            if b == b'\\' {
+                self.pos = (self.pos + 1).min(self.text.len());
+            } else if b == quote {
+                return Ok(&self.text[start..self.pos]);
+            }
+        }
+        self.pos = start;
+        Err(self.error("unterminated string literal"))
+    }
+}
+
+fn is_word(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
+
+fn is_name_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b == b'\\' || b >= 0x80
+}
+
+fn is_one_of(name: &str, names: &[&str]) -> bool {
+    names.iter().any(|n| n.eq_ignore_ascii_case(name))
+}
+
+impl DocType {
+    /// Move every span `by` bytes later, to make them relative to the file
+    /// the comment is in.
+    pub(crate) fn shift(&mut self, by: u32) {
+        self.span = Span::new(self.span.start + by, self.span.end + by);
+        match &mut self.kind {
+            DocTypeKind::Name(_)
+            | DocTypeKind::Literal(_)
+            | DocTypeKind::ClassConst { .. }
+            | DocTypeKind::Variable(_) => {}
+            DocTypeKind::Nullable(inner) | DocTypeKind::Array(inner) => inner.shift(by),
+            DocTypeKind::Generic { args: types, .. }
+            | DocTypeKind::Union(types)
+            | DocTypeKind::Intersection(types) => {
+                types.iter_mut().for_each(|ty| ty.shift(by));
+            }
+            DocTypeKind::Shape { items, .. } => {
+                items.iter_mut().for_each(|item| item.value.shift(by));
+            }
+            DocTypeKind::Callable {
+                params,
+                return_type,
+                ..
+            } => {
+                params.iter_mut().for_each(|param| param.ty.shift(by));
+                if let Some(ty) = return_type {
+                    ty.shift(by);
+                }
+            }
+            DocTypeKind::Conditional {
+                subject,
+                target,
+                then,
+                otherwise,
+                ..
+            } => {
+                for ty in [subject, target, then, otherwise] {
+                    ty.shift(by);
+                }
+            }
+        }
+    }
+
+    /// Whether the type needs parentheses as an element of `T[]`.
+    fn is_compound(&self) -> bool {
+        matches!(
+            self.kind,
+            DocTypeKind::Union(_) | DocTypeKind::Intersection(_) | DocTypeKind::Nullable(_)
+        )
+    }
+}
+
+/// Renders the type in a normalized form: no whitespace around `|` and `&`,
+/// one space after each comma.
+impl fmt::Display for DocType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(f: &mut fmt::Formatter<'_>, types: &[DocType], sep: &str) -> fmt::Result {
+            for (i, ty) in types.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(sep)?;
+                }
+                write!(f, "{ty}")?;
+            }
+            Ok(())
+        }
+        match &self.kind {
+            DocTypeKind::Name(name) | DocTypeKind::Literal(name) => f.write_str(name),
+            DocTypeKind::Generic { name, args } => {
+                write!(f, "{name}<")?;
+                list(f, args, ", ")?;
+                f.write_str(">")
+            }
+            DocTypeKind::Nullable(inner) => write!(f, "?{inner}"),
+            DocTypeKind::Union(types) => list(f, types, "|"),
+            DocTypeKind::Intersection(types) => list(f, types, "&"),
+            DocTypeKind::Array(inner) if inner.is_compound() => write!(f, "({inner})[]"),
+            DocTypeKind::Array(inner) => write!(f, "{inner}[]"),
+            DocTypeKind::Shape {
+                name,
+                items,
+                sealed,
+            } => {
+                write!(f, "{name}{{")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    if let Some(key) = &item.key {
+                        let optional = if item.optional { "?" } else { "" };
+                        write!(f, "{key}{optional}: ")?;
+                    }
+                    write!(f, "{}", item.value)?;
+                }
+                if !sealed {
+                    f.write_str(if items.is_empty() { "..." } else { ", ..." })?;
+                }
+                f.write_str("}")
+            }
+            DocTypeKind::Callable {
+                name,
+                params,
+                return_type,
+            } => {
+                write!(f, "{name}(")?;
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", param.ty)?;
+                    if param.by_ref || param.variadic || param.name.is_some() {
+                        f.write_str(" ")?;
+                    }
+                    if param.by_ref {
+                        f.write_str("&")?;
+                    }
+                    if param.variadic {
+                        f.write_str("...")?;
+                    }
+                    if let Some(name) = &param.name {
+                        write!(f, "${name}")?;
+                    }
+                    if param.optional {
+                        f.write_str("=")?;
+                    }
+                }
+                f.write_str(")")?;
+                match return_type {
+                    Some(ty) => write!(f, ": {ty}"),
+                    None => Ok(()),
+                }
+            }
+            DocTypeKind::ClassConst { class, name } => write!(f, "{class}::{name}"),
+            DocTypeKind::Variable(name) => write!(f, "${name}"),
+            DocTypeKind::Conditional {
+                subject,
+                negated,
+                target,
+                then,
+                otherwise,
+            } => {
+                let not = if *negated { "not " } else { "" };
+                write!(f, "({subject} is {not}{target} ? {then} : {otherwise})")
+            }
+        }
+    }
+}
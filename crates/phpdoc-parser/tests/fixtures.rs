@@ -28,7 +28,11 @@ fn parse_fixture(content: &str) -> (String, String) {
     (input, output)
 }
 
-fn run_fixture(input: &str) -> String {
+fn run_fixture(rel: &str, input: &str) -> String {
+    if rel.starts_with("typed") {
+        let tags = phpdoc_parser::tags::parse_tags(input);
+        return serde_json::to_string_pretty(&tags).unwrap();
+    }
     let doc = phpdoc_parser::parse(input);
     serde_json::to_string_pretty(&doc).unwrap()
 }
@@ -62,7 +66,7 @@ fn fixtures() {
             .to_string();
         let content = std::fs::read_to_string(path).unwrap();
         let (input, expected) = parse_fixture(&content);
-        let actual = run_fixture(&input);
+        let actual = run_fixture(&rel, &input);
 
         if update {
             write_fixture(path, &input, &actual);
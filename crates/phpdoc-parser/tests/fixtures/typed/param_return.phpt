@@ -0,0 +1,253 @@
+===input===
+/**
+ * @param array<int, Foo>|null $items The items
+ *        to process.
+ * @param callable(int, string...): bool &$check
+ * @param $untyped
+ * @return ($items is null ? void : list<Foo>)
+ * @throws \RuntimeException When it fails.
+ */
+===output===
+[
+  {
+    "name": "param",
+    "kind": {
+      "Param": {
+        "ty": {
+          "kind": {
+            "Union": [
+              {
+                "kind": {
+                  "Generic": {
+                    "name": "array",
+                    "args": [
+                      {
+                        "kind": {
+                          "Name": "int"
+                        },
+                        "span": {
+                          "start": 20,
+                          "end": 23
+                        }
+                      },
+                      {
+                        "kind": {
+                          "Name": "Foo"
+                        },
+                        "span": {
+                          "start": 25,
+                          "end": 28
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 14,
+                  "end": 29
+                }
+              },
+              {
+                "kind": {
+                  "Name": "null"
+                },
+                "span": {
+                  "start": 30,
+                  "end": 34
+                }
+              }
+            ]
+          },
+          "span": {
+            "start": 14,
+            "end": 34
+          }
+        },
+        "name": "items",
+        "by_ref": false,
+        "variadic": false,
+        "description": "The items to process."
+      }
+    },
+    "span": {
+      "start": 7,
+      "end": 73
+    }
+  },
+  {
+    "name": "param",
+    "kind": {
+      "Param": {
+        "ty": {
+          "kind": {
+            "Callable": {
+              "name": "callable",
+              "params": [
+                {
+                  "ty": {
+                    "kind": {
+                      "Name": "int"
+                    },
+                    "span": {
+                      "start": 93,
+                      "end": 96
+                    }
+                  },
+                  "by_ref": false,
+                  "variadic": false,
+                  "name": null,
+                  "optional": false
+                },
+                {
+                  "ty": {
+                    "kind": {
+                      "Name": "string"
+                    },
+                    "span": {
+                      "start": 98,
+                      "end": 104
+                    }
+                  },
+                  "by_ref": false,
+                  "variadic": true,
+                  "name": null,
+                  "optional": false
+                }
+              ],
+              "return_type": {
+                "kind": {
+                  "Name": "bool"
+                },
+                "span": {
+                  "start": 110,
+                  "end": 114
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 84,
+            "end": 114
+          }
+        },
+        "name": "check",
+        "by_ref": true,
+        "variadic": false,
+        "description": null
+      }
+    },
+    "span": {
+      "start": 77,
+      "end": 122
+    }
+  },
+  {
+    "name": "param",
+    "kind": {
+      "Param": {
+        "ty": null,
+        "name": "untyped",
+        "by_ref": false,
+        "variadic": false,
+        "description": null
+      }
+    },
+    "span": {
+      "start": 126,
+      "end": 141
+    }
+  },
+  {
+    "name": "return",
+    "kind": {
+      "Return": {
+        "ty": {
+          "kind": {
+            "Conditional": {
+              "subject": {
+                "kind": {
+                  "Variable": "items"
+                },
+                "span": {
+                  "start": 154,
+                  "end": 160
+                }
+              },
+              "negated": false,
+              "target": {
+                "kind": {
+                  "Name": "null"
+                },
+                "span": {
+                  "start": 164,
+                  "end": 168
+                }
+              },
+              "then": {
+                "kind": {
+                  "Name": "void"
+                },
+                "span": {
+                  "start": 171,
+                  "end": 175
+                }
+              },
+              "otherwise": {
+                "kind": {
+                  "Generic": {
+                    "name": "list",
+                    "args": [
+                      {
+                        "kind": {
+                          "Name": "Foo"
+                        },
+                        "span": {
+                          "start": 183,
+                          "end": 186
+                        }
+                      }
+                    ]
+                  }
+                },
+                "span": {
+                  "start": 178,
+                  "end": 187
+                }
+              }
+            }
+          },
+          "span": {
+            "start": 153,
+            "end": 188
+          }
+        },
+        "description": null
+      }
+    },
+    "span": {
+      "start": 145,
+      "end": 188
+    }
+  },
+  {
+    "name": "throws",
+    "kind": {
+      "Throws": {
+        "ty": {
+          "kind": {
+            "Name": "\\RuntimeException"
+          },
+          "span": {
+            "start": 200,
+            "end": 217
+          }
+        },
+        "description": "When it fails."
+      }
+    },
+    "span": {
+      "start": 192,
+      "end": 234
+    }
+  }
+]
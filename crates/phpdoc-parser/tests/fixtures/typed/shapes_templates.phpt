@@ -0,0 +1,167 @@
+===input===
+/**
+ * @template-covariant T of object
+ * @psalm-template K as array-key = int
+ * @phpstan-var array{
+ *   id: int,
+ *   'name'?: non-empty-string,
+ *   ...
+ * } $row
+ * @property-read Foo::STATUS_* $status
+ * @see Foo
+ * @param array<int $broken
+ */
+===output===
+[
+  {
+    "name": "template-covariant",
+    "kind": {
+      "Template": {
+        "name": "T",
+        "bound": {
+          "kind": {
+            "Name": "object"
+          },
+          "span": {
+            "start": 32,
+            "end": 38
+          }
+        },
+        "default": null,
+        "variance": "Covariant",
+        "description": null
+      }
+    },
+    "span": {
+      "start": 7,
+      "end": 38
+    }
+  },
+  {
+    "name": "psalm-template",
+    "kind": {
+      "Template": {
+        "name": "K",
+        "bound": {
+          "kind": {
+            "Name": "array-key"
+          },
+          "span": {
+            "start": 63,
+            "end": 72
+          }
+        },
+        "default": {
+          "kind": {
+            "Name": "int"
+          },
+          "span": {
+            "start": 75,
+            "end": 78
+          }
+        },
+        "variance": "Invariant",
+        "description": null
+      }
+    },
+    "span": {
+      "start": 42,
+      "end": 78
+    }
+  },
+  {
+    "name": "phpstan-var",
+    "kind": {
+      "Var": {
+        "ty": {
+          "kind": {
+            "Shape": {
+              "name": "array",
+              "items": [
+                {
+                  "key": "id",
+                  "optional": false,
+                  "value": {
+                    "kind": {
+                      "Name": "int"
+                    },
+                    "span": {
+                      "start": 111,
+                      "end": 114
+                    }
+                  }
+                },
+                {
+                  "key": "name",
+                  "optional": true,
+                  "value": {
+                    "kind": {
+                      "Name": "non-empty-string"
+                    },
+                    "span": {
+                      "start": 130,
+                      "end": 146
+                    }
+                  }
+                }
+              ],
+              "sealed": false
+            }
+          },
+          "span": {
+            "start": 95,
+            "end": 161
+          }
+        },
+        "name": "row",
+        "description": null
+      }
+    },
+    "span": {
+      "start": 82,
+      "end": 166
+    }
+  },
+  {
+    "name": "property-read",
+    "kind": {
+      "Property": {
+        "ty": {
+          "kind": {
+            "ClassConst": {
+              "class": "Foo",
+              "name": "STATUS_*"
+            }
+          },
+          "span": {
+            "start": 185,
+            "end": 198
+          }
+        },
+        "name": "status",
+        "access": "Read",
+        "description": null
+      }
+    },
+    "span": {
+      "start": 170,
+      "end": 206
+    }
+  },
+  {
+    "name": "param",
+    "kind": {
+      "Invalid": {
+        "message": "expected `>`",
+        "span": {
+          "start": 239,
+          "end": 240
+        }
+      }
+    },
+    "span": {
+      "start": 222,
+      "end": 248
+    }
+  }
+]
@@ -142,7 +142,9 @@ export const astNodes: AstNode[] = [
       exprs: ['$greeting', '$name', 'PHP_EOL', '"done"']
     },
     fields: [
-      { name: 'exprs', type: 'Vec<Expr>', description: 'Values to output' }
+      { name: 'exprs', type: 'Vec<Expr>', description: 'Values to output' },
+      { name: 'open_tag', type: 'Option<Span>', description: 'The <?= of a short echo tag' },
+      { name: 'close_tag', type: 'Option<Span>', description: 'The ?> ending a short echo tag' }
     ]
   },
   {
@@ -183,6 +185,9 @@ export const astNodes: AstNode[] = [
       { name: 'init', type: 'Vec<Expr>', description: 'Initialization expressions' },
       { name: 'condition', type: 'Vec<Expr>', description: 'Loop conditions' },
       { name: 'update', type: 'Vec<Expr>', description: 'Update expressions' },
+      { name: 'init_span', type: 'Span', description: 'Span of the init clause; empty when omitted' },
+      { name: 'condition_span', type: 'Span', description: 'Span of the condition clause; empty when omitted' },
+      { name: 'update_span', type: 'Span', description: 'Span of the update clause; empty when omitted' },
       { name: 'body', type: 'Stmt', description: 'Loop body' }
     ]
   },
@@ -269,7 +274,7 @@ export const astNodes: AstNode[] = [
       data: ['This data is ignored by PHP.']
     },
     fields: [
-      { name: 'data', type: 'string', description: 'Raw data after __halt_compiler()' }
+      { name: 'data', type: 'HaltCompilerData', description: 'The raw data after __halt_compiler(), to the end of the file: its span (the text itself is not serialized)' }
     ]
   },
   {
@@ -299,10 +304,11 @@ export const astNodes: AstNode[] = [
     description: 'Inline HTML outside <?php ... ?>',
     phpExample: `<?php echo $php; ?>\nThis is HTML`,
     fieldHighlights: {
-      html: ['This is HTML']
+      text: ['This is HTML']
     },
     fields: [
-      { name: 'html', type: 'string', description: 'HTML content' }
+      { name: 'text', type: 'string', description: 'HTML content as in the source' },
+      { name: 'newline_swallowed', type: 'bool', description: 'Whether text starts with the line break the ?> before it swallows' }
     ]
   },
   {
@@ -532,6 +538,21 @@ export const astNodes: AstNode[] = [
       { name: 'elements', type: 'Vec<ArrayElement>', description: 'Array elements' }
     ]
   },
+  {
+    id: 'expr-list',
+    group: 'Variables & Values',
+    name: 'List',
+    description: 'Destructuring target: list() or [...] on the left of = or as a foreach value',
+    phpExample: `list($a, list($b, $c)) = $x;\n['id' => $id, 'tags' => &$tags] = $row;`,
+    keywordInExample: 'list',
+    fieldHighlights: {
+      elements: ['$a', 'list($b, $c)', "'id' => $id", "'tags' => &$tags"]
+    },
+    fields: [
+      { name: 'elements', type: 'Vec<ArrayElement>', description: 'Targets, with their keys and by-reference flags' },
+      { name: 'uses_list', type: 'bool', description: 'Written list(...) rather than [...]' }
+    ]
+  },
   {
     id: 'expr-array-access',
     group: 'Operations',
@@ -836,6 +857,7 @@ export const astNodes: AstNode[] = [
     },
     fields: [
       { name: 'label', type: 'string', description: 'Heredoc label' },
+      { name: 'raw', type: 'string', description: 'Body as written, indentation and escapes intact' },
       { name: 'parts', type: 'Vec<StringPart>', description: 'String parts (text and variables)' }
     ]
   },
@@ -985,7 +1007,8 @@ export const astNodes: AstNode[] = [
     },
     fields: [
       { name: 'label', type: 'string', description: 'Nowdoc label' },
-      { name: 'value', type: 'string', description: 'String content' }
+      { name: 'raw', type: 'string', description: 'Body as written, indentation intact' },
+      { name: 'value', type: 'string', description: 'String content, closing-marker indentation removed' }
     ]
   },
   {
@@ -1050,8 +1073,8 @@ export const astNodes: AstNode[] = [
     id: 'expr-omit',
     group: 'Other Expressions',
     name: 'Omit',
-    description: 'Omitted array element (skipped slot)',
-    phpExample: `[$a, , $c];\nlist($first, , $last) = ["John", "M", "Doe"];`,
+    description: 'Omitted array element (skipped slot), with an empty span before its comma',
+    phpExample: `[$a, , $c] = $abc;\nlist($first, , $last) = ["John", "M", "Doe"];`,
     fields: []
   },
   {
@@ -1183,7 +1206,8 @@ export const astNodes: AstNode[] = [
       value: ['hello world', 'goodbye']
     },
     fields: [
-      { name: 'value', type: 'string', description: 'String content' }
+      { name: 'value', type: 'string', description: 'String content, escape sequences decoded' },
+      { name: 'raw', type: 'string', description: 'Text between the quotes as written' }
     ]
   },
   {
@@ -1388,7 +1412,8 @@ export const astNodes: AstNode[] = [
       { name: 'is_readonly', type: 'bool', description: 'Readonly promoted property (PHP 8.1+)' },
       { name: 'is_final', type: 'bool', description: 'Final promoted property (PHP 8.4+)' },
       { name: 'attributes', type: 'Vec<Attribute>', description: 'PHP attributes (#[...])' },
-      { name: 'hooks', type: 'Vec<PropertyHook>', description: 'Property hooks on promoted parameter (PHP 8.4+)' }
+      { name: 'hooks', type: 'Vec<PropertyHook>', description: 'Property hooks on promoted parameter (PHP 8.4+)' },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the parameter; after the last one, a trailing comma', optional: true }
     ]
   },
   {
@@ -1524,9 +1549,11 @@ export const astNodes: AstNode[] = [
     },
     fields: [
       { name: 'name', type: 'Option<Name>', description: 'Named argument name', optional: true },
+      { name: 'colon', type: 'Option<Span>', description: 'The : after a named argument', optional: true },
       { name: 'value', type: 'Expr', description: 'Argument value' },
       { name: 'unpack', type: 'bool', description: 'Is spread argument (...)' },
-      { name: 'by_ref', type: 'bool', description: 'Passed by reference (&)' }
+      { name: 'by_ref', type: 'bool', description: 'Passed by reference (&)' },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the argument; after the last one, a trailing comma', optional: true }
     ]
   },
   {
@@ -1545,7 +1572,8 @@ export const astNodes: AstNode[] = [
       { name: 'key', type: 'Option<Expr>', description: 'Array key', optional: true },
       { name: 'value', type: 'Expr', description: 'Array value' },
       { name: 'unpack', type: 'bool', description: 'Spread element (...)' },
-      { name: 'by_ref', type: 'bool', description: 'By reference' }
+      { name: 'by_ref', type: 'bool', description: 'By reference' },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the element; after the last one, a trailing comma', optional: true }
     ]
   },
   {
@@ -1595,7 +1623,8 @@ export const astNodes: AstNode[] = [
     },
     fields: [
       { name: 'name', type: 'string', description: 'Variable name' },
-      { name: 'by_ref', type: 'bool', description: 'Captured by reference' }
+      { name: 'by_ref', type: 'bool', description: 'Captured by reference' },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the variable; after the last one, a trailing comma', optional: true }
     ]
   },
   {
@@ -1644,7 +1673,8 @@ export const astNodes: AstNode[] = [
     },
     fields: [
       { name: 'conditions', type: 'Option<Vec<Expr>>', description: 'Match conditions (None = default)', optional: true },
-      { name: 'body', type: 'Expr', description: 'Arm body expression' }
+      { name: 'body', type: 'Expr', description: 'Arm body expression' },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the arm; after the last one, a trailing comma', optional: true }
     ]
   },
   {
@@ -1672,7 +1702,9 @@ export const astNodes: AstNode[] = [
       stmts: ['$x = 1;', 'echo $x;']
     },
     fields: [
-      { name: 'stmts', type: 'Vec<Stmt>', description: 'Top-level statements' }
+      { name: 'stmts', type: 'Vec<Stmt>', description: 'Top-level statements' },
+      { name: 'ends_in_html', type: 'bool', description: 'Whether the file ends in inline HTML rather than PHP code' },
+      { name: 'close_tag', type: 'Option<Span>', description: 'The final ?> when the file ends in inline HTML after it' }
     ]
   },
   {
@@ -1766,7 +1798,8 @@ export const astNodes: AstNode[] = [
     fields: [
       { name: 'name', type: 'Name', description: 'Imported name' },
       { name: 'alias', type: 'Option<string>', description: 'Alias name', optional: true },
-      { name: 'kind', type: 'Option<UseKind>', description: 'Override kind for group use', optional: true }
+      { name: 'kind', type: 'Option<UseKind>', description: 'Override kind for group use', optional: true },
+      { name: 'comma', type: 'Option<Span>', description: 'The , after the item; after the last item of a group use, a trailing comma', optional: true }
     ]
   },
 ]
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# Copies a cargo-fuzz artifact into crates/php-parser/tests/regressions/ and
+# replays the regression suite.
+#
+# Usage: scripts/add-fuzz-regression.sh <artifact> [name]
+#
+# <artifact> is a file written by `cargo fuzz run` or `cargo fuzz tmin`, e.g.
+# fuzz/artifacts/fuzz_parse/crash-<hash>. [name] defaults to the artifact's
+# file name; prefer a short description such as crash-heredoc-unterminated.
+set -euo pipefail
+
+if [ $# -lt 1 ] || [ $# -gt 2 ]; then
+    echo "usage: $0 <artifact> [name]" >&2
+    exit 2
+fi
+
+artifact=$1
+name=${2:-$(basename "$artifact")}
+dest_dir="$(dirname "$0")/../crates/php-parser/tests/regressions"
+dest="$dest_dir/$name"
+
+if [ ! -f "$artifact" ]; then
+    echo "error: $artifact is not a file" >&2
+    exit 1
+fi
+if [ -e "$dest" ]; then
+    echo "error: $dest already exists" >&2
+    exit 1
+fi
+
+cp "$artifact" "$dest"
+echo "added $dest ($(wc -c < "$dest") bytes)"
+
+cargo test -p php-rs-parser --test regressions
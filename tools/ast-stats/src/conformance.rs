@@ -0,0 +1,297 @@
+//! The conformance scoreboard: how each pinned corpus project parses.
+//!
+//! Every non-test PHP file of a project is parsed once and counted as clean
+//! (warnings at most), parsed with recoverable errors, failed (timed out,
+//! over a limit, or unreadable), or panicked. The scoreboard is written as
+//! JSON; when the output file already holds one from an earlier run, the
+//! change in each count is printed next to it.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use bumpalo::Bump;
+use php_rs_parser::bytes::ByteOffsets;
+use php_rs_parser::diagnostics::{ParseError, Severity};
+use php_rs_parser::ParserOptions;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{collect_php_files, is_test_path, ProjectDef};
+
+/// A file taking longer than this counts as failed.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Slowest files listed per project.
+const SLOWEST: usize = 5;
+
+#[derive(Serialize, Deserialize)]
+pub struct Scoreboard {
+    /// The workspace version the scoreboard was produced with.
+    pub parser_version: String,
+    pub projects: Vec<ProjectScore>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProjectScore {
+    pub name: String,
+    pub slug: String,
+    pub repo: String,
+    pub version: String,
+    pub files: u64,
+    pub bytes: u64,
+    pub clean: u64,
+    pub with_errors: u64,
+    pub failed: u64,
+    pub panics: u64,
+    /// Share of files that parsed clean, in percent.
+    pub clean_percent: f64,
+    /// Wall-clock time for the project, parsing in parallel.
+    pub elapsed_ms: u64,
+    /// Sum of the per-file parse times.
+    pub parse_ms: u64,
+    pub slowest: Vec<SlowFile>,
+    /// Every file that did not parse clean, by path.
+    pub problems: Vec<Problem>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SlowFile {
+    pub path: String,
+    pub ms: f64,
+    pub bytes: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Problem {
+    pub path: String,
+    pub outcome: Outcome,
+    /// The first error, the panic message, or the read error.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Clean,
+    WithErrors,
+    Failed,
+    Panic,
+}
+
+struct FileResult {
+    path: PathBuf,
+    bytes: u64,
+    elapsed: Duration,
+    outcome: Outcome,
+    message: Option<String>,
+}
+
+/// Score the projects in `projects` found under `corpus`, write the
+/// scoreboard to `out`, and print a summary. Returns whether any file
+/// panicked.
+pub fn run(corpus: &Path, projects: &[&ProjectDef], out: &Path) -> bool {
+    let previous: Option<Scoreboard> = std::fs::read_to_string(out)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
+
+    let mut scores = Vec::new();
+    for p in projects {
+        let base = corpus.join(p.slug);
+        if !base.exists() {
+            eprintln!("[{}] corpus not found at {:?}, skipping", p.slug, base);
+            continue;
+        }
+        scores.push(score_project(p, &base));
+    }
+
+    print_table(&scores, previous.as_ref());
+    let panicked = scores.iter().any(|score| score.panics > 0);
+
+    let scoreboard = Scoreboard {
+        parser_version: env!("CARGO_PKG_VERSION").to_string(),
+        projects: scores,
+    };
+    let json = serde_json::to_string_pretty(&scoreboard).unwrap();
+    std::fs::write(out, json).unwrap_or_else(|e| {
+        eprintln!("failed to write {out:?}: {e}");
+    });
+    eprintln!("wrote {out:?}");
+    panicked
+}
+
+fn score_project(p: &ProjectDef, base: &Path) -> ProjectScore {
+    let dirs: Vec<PathBuf> = p.src_dirs.iter().map(|d| base.join(d)).collect();
+    let dir_refs: Vec<&Path> = dirs.iter().map(PathBuf::as_path).collect();
+    let files: Vec<PathBuf> = collect_php_files(&dir_refs)
+        .into_iter()
+        .filter(|path| !is_test_path(path))
+        .collect();
+    eprintln!("[{}] parsing {} PHP files", p.slug, files.len());
+
+    let started = Instant::now();
+    let mut results: Vec<FileResult> = files.par_iter().map(|path| parse_file(path)).collect();
+    let elapsed = started.elapsed();
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let relative = |path: &Path| {
+        path.strip_prefix(base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+    let count = |outcome| results.iter().filter(|r| r.outcome == outcome).count() as u64;
+    let clean = count(Outcome::Clean);
+    let total = results.len() as u64;
+
+    let mut by_time: Vec<&FileResult> = results.iter().collect();
+    by_time.sort_by_key(|r| std::cmp::Reverse(r.elapsed));
+    let slowest = by_time
+        .iter()
+        .take(SLOWEST)
+        .map(|r| SlowFile {
+            path: relative(&r.path),
+            ms: r.elapsed.as_secs_f64() * 1000.0,
+            bytes: r.bytes,
+        })
+        .collect();
+
+    let problems = results
+        .iter()
+        .filter(|r| r.outcome != Outcome::Clean)
+        .map(|r| Problem {
+            path: relative(&r.path),
+            outcome: r.outcome,
+            message: r.message.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    ProjectScore {
+        name: p.name.to_string(),
+        slug: p.slug.to_string(),
+        repo: p.repo.to_string(),
+        version: p.version.to_string(),
+        files: total,
+        bytes: results.iter().map(|r| r.bytes).sum(),
+        clean,
+        with_errors: count(Outcome::WithErrors),
+        failed: count(Outcome::Failed),
+        panics: count(Outcome::Panic),
+        clean_percent: if total == 0 {
+            100.0
+        } else {
+            clean as f64 * 100.0 / total as f64
+        },
+        elapsed_ms: elapsed.as_millis() as u64,
+        parse_ms: results
+            .iter()
+            .map(|r| r.elapsed)
+            .sum::<Duration>()
+            .as_millis() as u64,
+        slowest,
+        problems,
+    }
+}
+
+fn parse_file(path: &Path) -> FileResult {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return FileResult {
+                path: path.to_path_buf(),
+                bytes: 0,
+                elapsed: Duration::ZERO,
+                outcome: Outcome::Failed,
+                message: Some(e.to_string()),
+            }
+        }
+    };
+    let options = ParserOptions {
+        timeout: Some(TIMEOUT),
+        ..Default::default()
+    };
+    let started = Instant::now();
+    let parsed = catch_unwind(AssertUnwindSafe(|| {
+        let arena = Bump::new();
+        let result = php_rs_parser::parse_bytes_with_options(&arena, &bytes, &options);
+        let limited = result
+            .errors
+            .iter()
+            .any(|e| matches!(e, ParseError::LimitExceeded { .. }));
+        let first_error = result
+            .errors
+            .iter()
+            .find(|e| e.severity() == Severity::Error)
+            .map(|e| {
+                let (line, col) = ByteOffsets::new(result.source)
+                    .to_original_line_col(&result.source_map, e.span().start)
+                    .to_one_based();
+                format!("{line}:{col}: {e}")
+            });
+        let outcome = if result.timed_out || limited {
+            Outcome::Failed
+        } else if first_error.is_some() {
+            Outcome::WithErrors
+        } else {
+            Outcome::Clean
+        };
+        (outcome, first_error)
+    }));
+    let elapsed = started.elapsed();
+    let (outcome, message) = match parsed {
+        Ok(parsed) => parsed,
+        Err(payload) => (Outcome::Panic, Some(panic_message(&payload))),
+    };
+    FileResult {
+        path: path.to_path_buf(),
+        bytes: bytes.len() as u64,
+        elapsed,
+        outcome,
+        message,
+    }
+}
+
+fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&'static str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<non-string panic>".to_string()
+    }
+}
+
+/// Print one row per project, with the change since `previous` where the
+/// project was scored before.
+fn print_table(scores: &[ProjectScore], previous: Option<&Scoreboard>) {
+    println!(
+        "{:<20} {:>7} {:>16} {:>12} {:>10} {:>10} {:>9}",
+        "project", "files", "clean", "errors", "failed", "panics", "ms"
+    );
+    for score in scores {
+        let before = previous.and_then(|board| {
+            board
+                .projects
+                .iter()
+                .find(|p| p.slug == score.slug && p.version == score.version)
+        });
+        let cell = |now: u64, then: Option<u64>| match then {
+            Some(then) if then != now => format!("{now} ({:+})", now as i64 - then as i64),
+            _ => now.to_string(),
+        };
+        println!(
+            "{:<20} {:>7} {:>16} {:>12} {:>10} {:>10} {:>9}",
+            score.slug,
+            score.files,
+            format!(
+                "{} {:.1}%",
+                cell(score.clean, before.map(|p| p.clean)),
+                score.clean_percent
+            ),
+            cell(score.with_errors, before.map(|p| p.with_errors)),
+            cell(score.failed, before.map(|p| p.failed)),
+            cell(score.panics, before.map(|p| p.panics)),
+            score.elapsed_ms,
+        );
+    }
+}
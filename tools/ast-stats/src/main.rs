@@ -11,6 +11,8 @@ use php_rs_parser::parse;
 use rayon::prelude::*;
 use serde::Serialize;
 
+mod conformance;
+
 #[derive(Default)]
 struct NodeCounter {
     counts: HashMap<&'static str, u64>,
@@ -67,6 +69,7 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
             StmtKind::HaltCompiler(_) => "HaltCompiler",
             StmtKind::Nop => "Nop",
             StmtKind::Error => "Error",
+            _ => "Other",
         };
         self.bump(name);
         walk_stmt(self, stmt)
@@ -76,7 +79,7 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
         match &expr.kind {
             ExprKind::Int(_) => self.bump("Int"),
             ExprKind::Float(_) => self.bump("Float"),
-            ExprKind::String(_) => self.bump("String"),
+            ExprKind::String { .. } => self.bump("String"),
             ExprKind::InterpolatedString(_) => self.bump("InterpolatedString"),
             ExprKind::Heredoc { .. } => self.bump("Heredoc"),
             ExprKind::Nowdoc { .. } => self.bump("Nowdoc"),
@@ -86,6 +89,8 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
             ExprKind::Variable(_) => self.bump("Variable"),
             ExprKind::VariableVariable(_) => self.bump("VariableVariable"),
             ExprKind::Identifier(_) => self.bump("Identifier"),
+            ExprKind::Name(_) => self.bump("Name"),
+            ExprKind::ConstFetch(_) => self.bump("ConstFetch"),
             ExprKind::Assign(_) => self.bump("Assign"),
             ExprKind::Binary(_) => self.bump("Binary"),
             ExprKind::UnaryPrefix(_) => self.bump("UnaryPrefix"),
@@ -94,10 +99,12 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
             ExprKind::NullCoalesce(_) => self.bump("NullCoalesce"),
             ExprKind::FunctionCall(_) => self.bump("FunctionCall"),
             ExprKind::Array(_) => self.bump("Array"),
+            ExprKind::List(_) => self.bump("List"),
             ExprKind::ArrayAccess(_) => self.bump("ArrayAccess"),
             ExprKind::Print(_) => self.bump("Print"),
             ExprKind::Parenthesized(_) => self.bump("Parenthesized"),
             ExprKind::Cast(_, _) => self.bump("Cast"),
+            ExprKind::Instanceof(_) => self.bump("Instanceof"),
             ExprKind::ErrorSuppress(_) => self.bump("ErrorSuppress"),
             ExprKind::Isset(_) => self.bump("Isset"),
             ExprKind::Empty(_) => self.bump("Empty"),
@@ -146,6 +153,7 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
             ExprKind::CallableCreate(_) => self.bump("CallableCreate"),
             ExprKind::Omit => self.bump("Omit"),
             ExprKind::Error => self.bump("Error"),
+            _ => self.bump("Other"),
         }
         walk_expr(self, expr)
     }
@@ -188,6 +196,7 @@ impl<'a, 'src> Visitor<'a, 'src> for NodeCounter {
             ClassMemberKind::TraitUse(_) => {
                 self.bump("TraitUse");
             }
+            _ => self.bump("Other"),
         }
         walk_class_member(self, member)
     }
@@ -880,6 +889,36 @@ fn main() {
 
     let out_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../playground/src/data");
 
+    // `--conformance [--out <path>] [<slug>...]`: score every project, or
+    // the given ones, and write the scoreboard.
+    if args.get(1).map(|s| s.as_str()) == Some("--conformance") {
+        let mut out = out_dir.join("conformance.json");
+        let mut slugs = Vec::new();
+        let mut rest = args[2..].iter();
+        while let Some(arg) = rest.next() {
+            match (arg.as_str(), rest.as_slice().first()) {
+                ("--out", Some(path)) => {
+                    out = path.into();
+                    rest.next();
+                }
+                (slug, _) => slugs.push(slug),
+            }
+        }
+        let all = projects();
+        if let Some(unknown) = slugs.iter().find(|s| all.iter().all(|p| p.slug != **s)) {
+            eprintln!("unknown slug: {unknown}");
+            std::process::exit(2);
+        }
+        let selected: Vec<&ProjectDef> = all
+            .iter()
+            .filter(|p| slugs.is_empty() || slugs.contains(&p.slug))
+            .collect();
+        if conformance::run(&corpus, &selected, &out) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let all = projects();
     let mut summaries: Vec<serde_json::Value> = Vec::new();
 